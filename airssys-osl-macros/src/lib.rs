@@ -6,6 +6,7 @@
 //! # Available Macros
 //!
 //! - `#[executor]`: Generate `OSExecutor<O>` trait implementations
+//! - `pipeline!`: Compose an executor with ordered middleware
 //!
 //! # The `#[executor]` Macro
 //!
@@ -135,6 +136,7 @@ use proc_macro::TokenStream;
 
 // Layer 3: Internal imports
 mod executor;
+mod pipeline;
 mod utils;
 
 /// Generates `OSExecutor<O>` trait implementations from method names.
@@ -158,3 +160,40 @@ pub fn executor(attr: TokenStream, item: TokenStream) -> TokenStream {
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
+
+/// Composes an executor with an ordered list of middleware.
+///
+/// Middleware runs in declaration order: the first entry sees each
+/// operation first. The macro expands to nested
+/// `ExecutorExt::with_middleware` calls, so the result is the same typed
+/// `MiddlewareExecutor` chain as hand-written builder code.
+///
+/// ```rust,ignore
+/// use airssys_osl::prelude::*;
+///
+/// let executor = pipeline! {
+///     executor: FilesystemExecutor::new(),
+///     security: SecurityMiddlewareBuilder::new()
+///         .add_policy(Box::new(acl))
+///         .build()?,
+///     logger: LoggerMiddleware::with_default_config(ConsoleActivityLogger::default()),
+///     throttle: ThrottleMiddleware::new(ThrottleConfig::new(1024 * 1024)),
+/// };
+/// ```
+///
+/// # Entries
+///
+/// - `executor: <expr>` (required, first)
+/// - `security`, `logger`, `throttle`, `custom`: `<kind>: <middleware expr>`
+///
+/// # Compile-Time Checks
+///
+/// - `security` may appear at most once and must be the first middleware,
+///   so nothing observes an operation before it is authorized
+/// - unknown kinds and a missing or repeated `executor` are rejected
+#[proc_macro]
+pub fn pipeline(input: TokenStream) -> TokenStream {
+    pipeline::expand(input.into())
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -0,0 +1,213 @@
+//! pipeline! macro implementation
+//!
+//! This module parses an ordered middleware declaration, checks the
+//! ordering rules, and expands it to nested `ExecutorExt::with_middleware`
+//! calls.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse2, Error, Expr, Ident, Result, Token};
+
+/// Middleware roles accepted in a pipeline declaration.
+const MIDDLEWARE_KINDS: &[&str] = &["security", "logger", "throttle", "custom"];
+
+/// One `kind: expr` entry of the declaration.
+struct Entry {
+    kind: Ident,
+    expr: Expr,
+}
+
+impl Parse for Entry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let kind: Ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let expr: Expr = input.parse()?;
+        Ok(Self { kind, expr })
+    }
+}
+
+/// Parsed pipeline: the executor followed by middleware in run order.
+struct Pipeline {
+    executor: Expr,
+    middleware: Vec<Entry>,
+}
+
+impl Parse for Pipeline {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let entries = input.parse_terminated(Entry::parse, Token![,])?;
+        let mut entries = entries.into_iter();
+
+        let executor = match entries.next() {
+            Some(entry) if entry.kind == "executor" => entry.expr,
+            Some(entry) => {
+                return Err(Error::new(
+                    entry.kind.span(),
+                    "pipeline! must start with `executor: <expr>`",
+                ))
+            }
+            None => {
+                return Err(Error::new(
+                    input.span(),
+                    "pipeline! requires an `executor: <expr>` entry",
+                ))
+            }
+        };
+
+        Ok(Self {
+            executor,
+            middleware: entries.collect(),
+        })
+    }
+}
+
+/// Checks middleware kinds and ordering constraints.
+///
+/// Rules:
+/// - `executor` appears exactly once, first
+/// - every other entry uses a known middleware kind
+/// - at most one `security` entry, and it must be the first middleware so
+///   no other middleware sees an operation before it is authorized
+fn validate(pipeline: &Pipeline) -> Result<()> {
+    let mut seen_security = false;
+
+    for (index, entry) in pipeline.middleware.iter().enumerate() {
+        let kind = entry.kind.to_string();
+        if kind == "executor" {
+            return Err(Error::new(
+                entry.kind.span(),
+                "Duplicate `executor` entry. A pipeline wraps exactly one executor",
+            ));
+        }
+        if !MIDDLEWARE_KINDS.contains(&kind.as_str()) {
+            return Err(Error::new(
+                entry.kind.span(),
+                format!(
+                    "Unknown middleware kind '{kind}'. Expected one of: {}",
+                    MIDDLEWARE_KINDS.join(", ")
+                ),
+            ));
+        }
+        if kind == "security" {
+            if seen_security {
+                return Err(Error::new(
+                    entry.kind.span(),
+                    "Duplicate `security` middleware. Combine policies in a single SecurityMiddleware",
+                ));
+            }
+            if index != 0 {
+                return Err(Error::new(
+                    entry.kind.span(),
+                    "`security` middleware must run first. Move it directly after `executor`",
+                ));
+            }
+            seen_security = true;
+        }
+    }
+
+    Ok(())
+}
+
+/// Main expansion function for the pipeline! macro.
+///
+/// Middleware declared first runs first, so it must be wrapped last
+/// (outermost): entries are applied in reverse declaration order.
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let pipeline: Pipeline = parse2(input)?;
+    validate(&pipeline)?;
+
+    let executor = &pipeline.executor;
+    let layers = pipeline.middleware.iter().rev().map(|entry| {
+        let expr = &entry.expr;
+        quote! {
+            let __pipeline = airssys_osl::middleware::ext::ExecutorExt::with_middleware(
+                __pipeline,
+                #expr,
+            );
+        }
+    });
+
+    Ok(quote! {
+        {
+            let __pipeline = #executor;
+            #(#layers)*
+            __pipeline
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    fn error_of(input: TokenStream) -> String {
+        match expand(input) {
+            Ok(_) => String::new(),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_expands_in_reverse_declaration_order() {
+        let input = quote! {
+            executor: FilesystemExecutor::new(),
+            security: make_security(),
+            logger: make_logger(),
+        };
+
+        let output = expand(input);
+        assert!(output.is_ok(), "Valid pipeline should expand");
+        let text = output.map(|t| t.to_string()).unwrap_or_default();
+
+        // Logger wraps the executor first; security wraps last (outermost)
+        let logger = text.find("make_logger").unwrap_or(usize::MAX);
+        let security = text.find("make_security").unwrap_or(0);
+        assert!(logger < security, "security must be the outermost layer");
+        assert!(text.contains("ExecutorExt :: with_middleware"));
+    }
+
+    #[test]
+    fn test_executor_only_pipeline() {
+        let input = quote! { executor: FilesystemExecutor::new() };
+        assert!(expand(input).is_ok());
+    }
+
+    #[test]
+    fn test_reject_security_not_first() {
+        let input = quote! {
+            executor: FilesystemExecutor::new(),
+            logger: make_logger(),
+            security: make_security(),
+        };
+        assert!(error_of(input).contains("must run first"));
+    }
+
+    #[test]
+    fn test_reject_duplicate_security() {
+        let input = quote! {
+            executor: FilesystemExecutor::new(),
+            security: make_security(),
+            security: make_security(),
+        };
+        assert!(error_of(input).contains("Duplicate `security`"));
+    }
+
+    #[test]
+    fn test_reject_unknown_kind_and_missing_executor() {
+        let unknown = quote! {
+            executor: FilesystemExecutor::new(),
+            metrics: make_metrics(),
+        };
+        assert!(error_of(unknown).contains("Unknown middleware kind 'metrics'"));
+
+        let missing = quote! { security: make_security() };
+        assert!(error_of(missing).contains("must start with `executor"));
+
+        let duplicate = quote! {
+            executor: FilesystemExecutor::new(),
+            executor: FilesystemExecutor::new(),
+        };
+        assert!(error_of(duplicate).contains("Duplicate `executor`"));
+    }
+}
@@ -0,0 +1,116 @@
+//! Security Policy Evaluation Benchmarks
+//!
+//! Measures `SecurityMiddleware::before_execution` as the number of
+//! filesystem-scoped policies grows:
+//! - Network operations: only the one `All`-scoped policy applies, so cost
+//!   should stay flat regardless of filesystem policy count
+//! - Filesystem operations: every policy applies (linear reference)
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+// Layer 1: Standard library imports
+use std::hint::black_box;
+use std::sync::Arc;
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use async_trait::async_trait;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+// Layer 3: Internal module imports
+use airssys_osl::core::context::{ExecutionContext, SecurityContext};
+use airssys_osl::core::middleware::Middleware;
+use airssys_osl::middleware::security::audit::{AuditError, SecurityAuditLog, SecurityAuditLogger};
+use airssys_osl::middleware::security::middleware::{
+    SecurityMiddleware, SecurityMiddlewareBuilder,
+};
+use airssys_osl::middleware::security::policy::{PolicyDecision, PolicyScope, SecurityPolicy};
+use airssys_osl::operations::filesystem::read::FileReadOperation;
+use airssys_osl::operations::network::connect::NetworkConnectOperation;
+
+/// Audit logger that discards events, so only policy evaluation is measured
+#[derive(Debug)]
+struct NullAuditLogger;
+
+#[async_trait]
+impl SecurityAuditLogger for NullAuditLogger {
+    async fn log_security_event(&self, _event: SecurityAuditLog) -> Result<(), AuditError> {
+        Ok(())
+    }
+}
+
+/// Policy that allows everything within its scope
+#[derive(Debug)]
+struct AllowPolicy(PolicyScope);
+
+impl SecurityPolicy for AllowPolicy {
+    fn evaluate(&self, context: &SecurityContext) -> PolicyDecision {
+        black_box(context);
+        PolicyDecision::Allow
+    }
+
+    fn description(&self) -> &str {
+        "allow"
+    }
+
+    fn scope(&self) -> PolicyScope {
+        self.0
+    }
+}
+
+fn middleware_with(filesystem_policies: usize) -> SecurityMiddleware {
+    (0..filesystem_policies)
+        .fold(
+            SecurityMiddlewareBuilder::new()
+                .with_audit_logger(Arc::new(NullAuditLogger))
+                .add_policy(Box::new(AllowPolicy(PolicyScope::All))),
+            |builder, _| builder.add_policy(Box::new(AllowPolicy(PolicyScope::Filesystem))),
+        )
+        .build()
+        .unwrap()
+}
+
+/// Benchmark: Policy evaluation cost vs. filesystem policy count
+fn scoped_policy_evaluation(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let context = ExecutionContext::new(SecurityContext::new("bench".to_string()));
+    let mut group = c.benchmark_group("scoped_policy_evaluation");
+
+    for count in [1usize, 10, 100, 1000] {
+        let middleware = middleware_with(count);
+
+        group.bench_with_input(BenchmarkId::new("network_op", count), &count, |b, _| {
+            b.to_async(&rt).iter(|| async {
+                let operation = NetworkConnectOperation::new("127.0.0.1:8080".to_string());
+                black_box(middleware.before_execution(operation, &context).await)
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("filesystem_op", count), &count, |b, _| {
+            b.to_async(&rt).iter(|| async {
+                let operation = FileReadOperation::new("/tmp/data".to_string());
+                black_box(middleware.before_execution(operation, &context).await)
+            })
+        });
+    }
+
+    group.finish();
+}
+
+/// Configure criterion for resource-conscious benchmarking
+fn configure_criterion() -> Criterion {
+    Criterion::default()
+        .sample_size(30)
+        .measurement_time(Duration::from_secs(3))
+        .warm_up_time(Duration::from_secs(1))
+        .without_plots()
+}
+
+criterion_group! {
+    name = benches;
+    config = configure_criterion();
+    targets = scoped_policy_evaluation
+}
+
+criterion_main!(benches);
@@ -2,13 +2,23 @@
 //!
 //! This module defines the context types that carry state and security
 //! information throughout the execution of operations.
+//!
+//! An [`ExecutionContext`] also carries a [`CancellationToken`]. Executors
+//! race their I/O against it, so cancelling the token aborts a long-running
+//! read, connect, or spawn with [`OSError::Cancelled`]. Clones of a context
+//! share its token.
 
 use std::collections::HashMap;
+use std::future::Future;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::core::result::{OSError, OSResult};
+
+pub use tokio_util::sync::CancellationToken;
+
 /// Execution context for operation processing.
 ///
 /// Contains all the contextual information needed for executing operations,
@@ -26,6 +36,12 @@ pub struct ExecutionContext {
 
     /// Additional metadata for this execution
     pub metadata: HashMap<String, String>,
+
+    /// Cancellation signal for operations run under this context
+    ///
+    /// Not serialized; a deserialized context gets a fresh token.
+    #[serde(skip)]
+    pub cancellation: CancellationToken,
 }
 
 impl ExecutionContext {
@@ -36,6 +52,65 @@ impl ExecutionContext {
             created_at: Utc::now(),
             security_context,
             metadata: HashMap::new(),
+            cancellation: CancellationToken::new(),
+        }
+    }
+
+    /// Uses `token` to cancel operations run under this context.
+    ///
+    /// Pass a child token (`parent.child_token()`) to let a supervisor
+    /// cancel many operations at once.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    /// Returns true if operations under this context have been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation.is_cancelled()
+    }
+
+    /// Fails with [`OSError::Cancelled`] if this context has been cancelled.
+    ///
+    /// Executors call this before starting work that cannot be interrupted
+    /// once begun.
+    pub fn check_cancelled(&self, operation: &str) -> OSResult<()> {
+        if self.is_cancelled() {
+            return Err(OSError::cancelled(operation));
+        }
+        Ok(())
+    }
+
+    /// Runs `future` until it completes or this context is cancelled.
+    ///
+    /// On cancellation the future is dropped, which aborts the tokio I/O it
+    /// was waiting on, and [`OSError::Cancelled`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airssys_osl::core::context::{ExecutionContext, SecurityContext};
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let context = ExecutionContext::new(SecurityContext::new("svc".to_string()));
+    /// context.cancellation.cancel();
+    ///
+    /// let result = context
+    ///     .cancellable("wait", async {
+    ///         std::future::pending::<airssys_osl::core::result::OSResult<()>>().await
+    ///     })
+    ///     .await;
+    /// assert!(result.unwrap_err().is_cancelled());
+    /// # });
+    /// ```
+    pub async fn cancellable<F, T>(&self, operation: &str, future: F) -> OSResult<T>
+    where
+        F: Future<Output = OSResult<T>>,
+    {
+        tokio::select! {
+            biased;
+            _ = self.cancellation.cancelled() => Err(OSError::cancelled(operation)),
+            result = future => result,
         }
     }
 
@@ -224,4 +299,29 @@ mod tests {
         assert_eq!(exec_ctx.get_metadata("key1"), Some("value1"));
         assert_eq!(exec_ctx.get_metadata("key2"), Some("value2"));
     }
+
+    #[tokio::test]
+    async fn test_cancellable_aborts_pending_work() {
+        let parent = CancellationToken::new();
+        let exec_ctx = ExecutionContext::new(SecurityContext::new("svc".to_string()))
+            .with_cancellation(parent.child_token());
+        let clone = exec_ctx.clone();
+
+        let waiter = tokio::spawn(async move {
+            clone
+                .cancellable("sleep", async {
+                    tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+                    Ok(())
+                })
+                .await
+        });
+        parent.cancel();
+
+        let result = waiter.await;
+        assert!(
+            matches!(result, Ok(Err(OSError::Cancelled { ref operation })) if operation == "sleep")
+        );
+        assert!(exec_ctx.is_cancelled());
+        assert!(exec_ctx.check_cancelled("next").is_err());
+    }
 }
@@ -38,6 +38,16 @@ pub enum MiddlewareError {
 
     /// Dependency error when middleware dependencies are not met
     Dependency(String),
+
+    /// Sustained rate limit exceeded for a throttling key
+    RateLimited {
+        /// Throttling key (principal and/or destination) that is over its limit
+        key: String,
+        /// Configured sustained limit in bytes per second
+        limit_bytes_per_sec: u64,
+        /// Time until the key is admitted again
+        retry_after: Duration,
+    },
 }
 
 impl MiddlewareError {
@@ -62,7 +72,9 @@ impl MiddlewareError {
     pub fn is_retryable(&self) -> bool {
         matches!(
             self,
-            MiddlewareError::NonFatal(_) | MiddlewareError::Timeout(_)
+            MiddlewareError::NonFatal(_)
+                | MiddlewareError::Timeout(_)
+                | MiddlewareError::RateLimited { .. }
         )
     }
 
@@ -75,6 +87,7 @@ impl MiddlewareError {
             MiddlewareError::Timeout(_) => "timeout",
             MiddlewareError::Configuration(_) => "configuration",
             MiddlewareError::Dependency(_) => "dependency",
+            MiddlewareError::RateLimited { .. } => "rate_limited",
         }
     }
 
@@ -95,6 +108,16 @@ impl MiddlewareError {
                 middleware: middleware_name.to_string(),
                 reason: format!("Timeout after {duration:?}"),
             },
+            MiddlewareError::RateLimited {
+                key,
+                limit_bytes_per_sec,
+                retry_after,
+            } => OSError::MiddlewareFailed {
+                middleware: middleware_name.to_string(),
+                reason: format!(
+                    "Rate limit of {limit_bytes_per_sec} bytes/s exceeded for '{key}', retry after {retry_after:?}"
+                ),
+            },
         }
     }
 }
@@ -395,6 +418,15 @@ mod tests {
         assert!(!config_error.is_retryable());
         assert_eq!(config_error.category(), "configuration");
 
+        let rate_limited = MiddlewareError::RateLimited {
+            key: "alice".to_string(),
+            limit_bytes_per_sec: 1024,
+            retry_after: Duration::from_millis(500),
+        };
+        assert!(rate_limited.is_retryable());
+        assert!(!rate_limited.is_fatal());
+        assert_eq!(rate_limited.category(), "rate_limited");
+
         let dependency_error = MiddlewareError::Dependency("test".to_string());
         assert!(!dependency_error.is_retryable());
         assert_eq!(dependency_error.category(), "dependency");
@@ -53,6 +53,14 @@ pub enum OSError {
     /// Configuration error (legacy compatibility)
     #[error("Configuration error: {reason}")]
     ConfigurationError { reason: String },
+
+    /// Operation aborted through its execution context's cancellation token
+    #[error("Operation cancelled: {operation}")]
+    Cancelled { operation: String },
+
+    /// Operation exceeded the timeout configured for it
+    #[error("Operation timed out after {timeout_ms}ms: {operation}")]
+    TimedOut { operation: String, timeout_ms: u64 },
 }
 
 impl OSError {
@@ -114,6 +122,21 @@ impl OSError {
         }
     }
 
+    /// Creates a new cancellation error.
+    pub fn cancelled(operation: impl Into<String>) -> Self {
+        Self::Cancelled {
+            operation: operation.into(),
+        }
+    }
+
+    /// Creates a new timeout error.
+    pub fn timed_out(operation: impl Into<String>, timeout_ms: u64) -> Self {
+        Self::TimedOut {
+            operation: operation.into(),
+            timeout_ms,
+        }
+    }
+
     /// Returns true if this error represents a security policy violation.
     pub fn is_security_violation(&self) -> bool {
         matches!(self, OSError::SecurityViolation { .. })
@@ -144,6 +167,16 @@ impl OSError {
         matches!(self, OSError::ConfigurationError { .. })
     }
 
+    /// Returns true if the operation was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, OSError::Cancelled { .. })
+    }
+
+    /// Returns true if the operation exceeded its configured timeout.
+    pub fn is_timed_out(&self) -> bool {
+        matches!(self, OSError::TimedOut { .. })
+    }
+
     /// Returns true if this error should be retried automatically.
     pub fn is_retryable(&self) -> bool {
         matches!(
@@ -164,6 +197,8 @@ impl OSError {
             OSError::ProcessError { .. } => "process",
             OSError::NetworkError { .. } => "network",
             OSError::ConfigurationError { .. } => "configuration",
+            OSError::Cancelled { .. } => "cancelled",
+            OSError::TimedOut { .. } => "timeout",
         }
     }
 }
@@ -198,6 +233,16 @@ mod tests {
         assert!(process_err.is_process_error());
         assert!(!process_err.is_retryable());
         assert_eq!(process_err.category(), "process");
+
+        let cancelled = OSError::cancelled("read '/tmp/big'");
+        assert!(cancelled.is_cancelled());
+        assert!(!cancelled.is_retryable());
+        assert_eq!(cancelled.category(), "cancelled");
+
+        let timed_out = OSError::timed_out("read '/tmp/big'", 250);
+        assert!(timed_out.is_timed_out());
+        assert!(!timed_out.is_cancelled());
+        assert_eq!(timed_out.category(), "timeout");
     }
 
     #[test]
@@ -0,0 +1,191 @@
+//! FileAclReadOperation and FileAclModifyOperation executor implementations (Windows).
+//!
+//! Reads and edits NTFS ACLs through the system `icacls` utility, the same
+//! way process termination delegates to `taskkill` on Windows.
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::core::context::ExecutionContext;
+use crate::core::executor::{ExecutionResult, OSExecutor};
+use crate::core::operation::OperationType;
+use crate::core::result::{OSError, OSResult};
+use crate::operations::filesystem::{AclChange, FileAclModifyOperation, FileAclReadOperation};
+
+use super::FilesystemExecutor;
+
+/// Run `icacls` with `args` and return its standard output.
+async fn icacls(path: &str, args: &[String]) -> OSResult<Vec<u8>> {
+    let output = tokio::process::Command::new("icacls")
+        .arg(path)
+        .args(args)
+        .output()
+        .await
+        .map_err(|e| OSError::filesystem_error("icacls", path, e.to_string()))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let reason = if stderr.trim().is_empty() {
+            stdout
+        } else {
+            stderr
+        };
+        return Err(OSError::filesystem_error("icacls", path, reason.trim()));
+    }
+
+    Ok(output.stdout)
+}
+
+async fn validate_path(path: &str) -> OSResult<()> {
+    if !tokio::fs::try_exists(path)
+        .await
+        .map_err(|e| OSError::filesystem_error("validate", path, e.to_string()))?
+    {
+        return Err(OSError::filesystem_error(
+            "validate",
+            path,
+            "Path does not exist",
+        ));
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl OSExecutor<FileAclReadOperation> for FilesystemExecutor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn supported_operation_types(&self) -> Vec<OperationType> {
+        vec![OperationType::Filesystem]
+    }
+
+    async fn execute(
+        &self,
+        operation: FileAclReadOperation,
+        context: &ExecutionContext,
+    ) -> OSResult<ExecutionResult> {
+        let started_at = Utc::now();
+
+        let output = icacls(&operation.path, &[]).await?;
+
+        let completed_at = Utc::now();
+
+        let result = ExecutionResult::success_with_timing(output, started_at, completed_at)
+            .with_metadata("path".to_string(), operation.path.clone())
+            .with_metadata("executor".to_string(), self.name.to_string())
+            .with_metadata("user".to_string(), context.principal().to_string());
+
+        Ok(result)
+    }
+
+    async fn validate_operation(
+        &self,
+        operation: &FileAclReadOperation,
+        _context: &ExecutionContext,
+    ) -> OSResult<()> {
+        validate_path(&operation.path).await
+    }
+}
+
+#[async_trait]
+impl OSExecutor<FileAclModifyOperation> for FilesystemExecutor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn supported_operation_types(&self) -> Vec<OperationType> {
+        vec![OperationType::Filesystem]
+    }
+
+    async fn execute(
+        &self,
+        operation: FileAclModifyOperation,
+        context: &ExecutionContext,
+    ) -> OSResult<ExecutionResult> {
+        let started_at = Utc::now();
+
+        let args = match &operation.change {
+            AclChange::Grant { principal, access } => {
+                vec![
+                    "/grant".to_string(),
+                    format!("{principal}:{}", access.code()),
+                ]
+            }
+            AclChange::Deny { principal, access } => {
+                vec![
+                    "/deny".to_string(),
+                    format!("{principal}:{}", access.code()),
+                ]
+            }
+            AclChange::Remove { principal } => vec!["/remove".to_string(), principal.clone()],
+        };
+        icacls(&operation.path, &args).await?;
+
+        let completed_at = Utc::now();
+
+        let result = ExecutionResult::success_with_timing(Vec::new(), started_at, completed_at)
+            .with_metadata("path".to_string(), operation.path.clone())
+            .with_metadata("change".to_string(), operation.change.to_string())
+            .with_metadata("executor".to_string(), self.name.to_string())
+            .with_metadata("user".to_string(), context.principal().to_string());
+
+        Ok(result)
+    }
+
+    async fn validate_operation(
+        &self,
+        operation: &FileAclModifyOperation,
+        _context: &ExecutionContext,
+    ) -> OSResult<()> {
+        let principal = operation.change.principal();
+        // A ':' or leading '/' would be parsed by icacls as part of the command
+        if principal.is_empty() || principal.contains(':') || principal.starts_with('/') {
+            return Err(OSError::execution_failed(format!(
+                "Invalid ACL principal: '{principal}'"
+            )));
+        }
+
+        validate_path(&operation.path).await
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::core::context::SecurityContext;
+    use crate::operations::filesystem::AclAccess;
+    use tempfile::NamedTempFile;
+
+    #[tokio::test]
+    async fn test_acl_grant_read_and_remove() {
+        let executor = FilesystemExecutor::new();
+        let context = ExecutionContext::new(SecurityContext::new("test-user".to_string()));
+        let file = NamedTempFile::new().unwrap();
+        let path = file.path().to_string_lossy().to_string();
+
+        let grant = FileAclModifyOperation::grant(&path, "Guests", AclAccess::Read);
+        executor.validate_operation(&grant, &context).await.unwrap();
+        executor.execute(grant, &context).await.unwrap();
+
+        let read = executor
+            .execute(FileAclReadOperation::new(&path), &context)
+            .await
+            .unwrap();
+        assert!(String::from_utf8_lossy(&read.output).contains("Guests"));
+
+        let remove = FileAclModifyOperation::remove(&path, "Guests");
+        executor.execute(remove, &context).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_validate_rejects_bad_principal() {
+        let executor = FilesystemExecutor::new();
+        let context = ExecutionContext::new(SecurityContext::new("test-user".to_string()));
+        let op = FileAclModifyOperation::grant("C:\\", "Guests:F", AclAccess::Read);
+
+        assert!(executor.validate_operation(&op, &context).await.is_err());
+    }
+}
@@ -0,0 +1,228 @@
+//! AtomicFileWriteOperation executor implementation.
+//!
+//! Writes to a temporary file next to the target, fsyncs it, renames it over
+//! the target and (on Unix) fsyncs the directory so the rename itself
+//! survives a crash. The temporary file is removed if any step fails. All
+//! steps run on one blocking thread under the operation's priority.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::core::context::ExecutionContext;
+use crate::core::executor::{ExecutionResult, OSExecutor};
+use crate::core::operation::OperationType;
+use crate::core::result::{OSError, OSResult};
+use crate::operations::filesystem::AtomicFileWriteOperation;
+
+use super::priority::run_with_priority;
+use super::FilesystemExecutor;
+
+/// Temporary sibling of `target`, hidden and unique per write.
+fn temp_path(target: &Path) -> PathBuf {
+    let name = target
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    target.with_file_name(format!(".{name}.{}.tmp", Uuid::new_v4().simple()))
+}
+
+/// Directory the target lives in (`.` for bare file names).
+fn parent_dir(target: &Path) -> &Path {
+    match target.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    }
+}
+
+fn write_temp(temp: &Path, target: &Path, content: &[u8]) -> OSResult<()> {
+    let path = temp.display().to_string();
+    let mut file = std::fs::File::create(temp)
+        .map_err(|e| OSError::filesystem_error("create_temp", &path, e.to_string()))?;
+
+    // Keep the permissions of the file being replaced
+    if let Ok(metadata) = std::fs::metadata(target) {
+        file.set_permissions(metadata.permissions())
+            .map_err(|e| OSError::filesystem_error("set_permissions", &path, e.to_string()))?;
+    }
+
+    file.write_all(content)
+        .map_err(|e| OSError::filesystem_error("write_temp", &path, e.to_string()))?;
+    file.sync_all()
+        .map_err(|e| OSError::filesystem_error("fsync", &path, e.to_string()))?;
+    Ok(())
+}
+
+#[cfg(unix)]
+fn sync_dir(dir: &Path) -> OSResult<()> {
+    let dir_file = std::fs::File::open(dir).map_err(|e| {
+        OSError::filesystem_error("open_dir", dir.display().to_string(), e.to_string())
+    })?;
+    dir_file.sync_all().map_err(|e| {
+        OSError::filesystem_error("fsync_dir", dir.display().to_string(), e.to_string())
+    })
+}
+
+#[cfg(not(unix))]
+fn sync_dir(_dir: &Path) -> OSResult<()> {
+    // Directories cannot be opened for syncing on this platform
+    Ok(())
+}
+
+fn replace_atomically(target: &Path, content: &[u8]) -> OSResult<()> {
+    let temp = temp_path(target);
+
+    let written = write_temp(&temp, target, content).and_then(|()| {
+        std::fs::rename(&temp, target).map_err(|e| {
+            OSError::filesystem_error("rename", target.display().to_string(), e.to_string())
+        })
+    });
+    if let Err(e) = written {
+        let _ = std::fs::remove_file(&temp);
+        return Err(e);
+    }
+    sync_dir(parent_dir(target))
+}
+
+#[async_trait]
+impl OSExecutor<AtomicFileWriteOperation> for FilesystemExecutor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn supported_operation_types(&self) -> Vec<OperationType> {
+        vec![OperationType::Filesystem]
+    }
+
+    async fn execute(
+        &self,
+        operation: AtomicFileWriteOperation,
+        context: &ExecutionContext,
+    ) -> OSResult<ExecutionResult> {
+        let started_at = Utc::now();
+        let bytes_written = operation.content.len();
+        let target = PathBuf::from(&operation.path);
+        let content = operation.content;
+        run_with_priority(operation.priority, move || {
+            replace_atomically(&target, &content)
+        })
+        .await?;
+
+        let completed_at = Utc::now();
+
+        let result = ExecutionResult::success_with_timing(Vec::new(), started_at, completed_at)
+            .with_metadata("path".to_string(), operation.path.clone())
+            .with_metadata("bytes_written".to_string(), bytes_written.to_string())
+            .with_metadata("mode".to_string(), "atomic".to_string())
+            .with_metadata("priority".to_string(), operation.priority.to_string())
+            .with_metadata("executor".to_string(), self.name.to_string())
+            .with_metadata("user".to_string(), context.principal().to_string());
+
+        Ok(result)
+    }
+
+    async fn validate_operation(
+        &self,
+        operation: &AtomicFileWriteOperation,
+        _context: &ExecutionContext,
+    ) -> OSResult<()> {
+        let target = Path::new(&operation.path);
+        if target.file_name().is_none() {
+            return Err(OSError::filesystem_error(
+                "validate",
+                &operation.path,
+                "Path does not name a file",
+            ));
+        }
+
+        let parent = parent_dir(target);
+        if !tokio::fs::try_exists(parent).await.map_err(|e| {
+            OSError::filesystem_error(
+                "validate",
+                &operation.path,
+                format!("Cannot check parent directory: {e}"),
+            )
+        })? {
+            return Err(OSError::filesystem_error(
+                "validate",
+                &operation.path,
+                "Parent directory does not exist",
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn cleanup(&self, _context: &ExecutionContext) -> OSResult<()> {
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::core::context::SecurityContext;
+
+    fn context() -> ExecutionContext {
+        ExecutionContext::new(SecurityContext::new("test-user".to_string()))
+    }
+
+    #[tokio::test]
+    async fn test_atomic_write_replaces_file_without_leftovers() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let file_path = temp_dir.path().join("state.json");
+        let path = file_path.to_str().expect("Invalid UTF-8 path").to_string();
+        std::fs::write(&file_path, "old").expect("Failed to seed file");
+
+        let executor = FilesystemExecutor::new();
+        let operation = AtomicFileWriteOperation::new(&path, b"new".to_vec());
+        let result = executor
+            .execute(operation, &context())
+            .await
+            .expect("Execution failed");
+        assert_eq!(result.exit_code, 0);
+
+        let content = std::fs::read_to_string(&file_path).expect("Failed to read file");
+        assert_eq!(content, "new");
+        let entries = std::fs::read_dir(temp_dir.path())
+            .expect("Failed to list dir")
+            .count();
+        assert_eq!(entries, 1, "temporary file must not be left behind");
+    }
+
+    #[tokio::test]
+    async fn test_atomic_write_failure_keeps_original() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        // Renaming a file over a non-empty directory fails
+        let target = temp_dir.path().join("occupied");
+        std::fs::create_dir(&target).expect("Failed to create dir");
+        std::fs::write(target.join("keep"), "x").expect("Failed to seed dir");
+        let path = target.to_str().expect("Invalid UTF-8 path").to_string();
+
+        let executor = FilesystemExecutor::new();
+        let result = executor
+            .execute(
+                AtomicFileWriteOperation::new(&path, b"new".to_vec()),
+                &context(),
+            )
+            .await;
+        assert!(result.is_err());
+        assert!(target.join("keep").exists());
+        let entries = std::fs::read_dir(temp_dir.path())
+            .expect("Failed to list dir")
+            .count();
+        assert_eq!(entries, 1, "temporary file must be removed on failure");
+    }
+}
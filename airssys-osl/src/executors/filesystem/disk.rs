@@ -0,0 +1,365 @@
+//! FilesystemSpaceOperation, MountListOperation and DiskUsageOperation
+//! executor implementations.
+//!
+//! Free space comes from `statvfs(3)` and the mount table from
+//! `/proc/self/mounts`, so both are Unix (respectively Linux) only; other
+//! platforms get an execution error. Disk usage walks the tree with
+//! `std::fs` on the blocking thread pool.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Serialize;
+
+use crate::core::context::ExecutionContext;
+use crate::core::executor::{ExecutionResult, OSExecutor};
+use crate::core::operation::OperationType;
+use crate::core::result::{OSError, OSResult};
+use crate::operations::filesystem::{
+    DiskUsageEntry, DiskUsageOperation, FilesystemSpace, FilesystemSpaceOperation,
+    MountListOperation, MountPoint,
+};
+
+use super::priority::run_with_priority;
+use super::FilesystemExecutor;
+
+fn encode<T: Serialize>(report: &T) -> OSResult<Vec<u8>> {
+    serde_json::to_vec(report)
+        .map_err(|e| OSError::execution_failed(format!("failed to encode disk report: {e}")))
+}
+
+#[cfg(unix)]
+fn filesystem_space(path: &str) -> OSResult<FilesystemSpace> {
+    let stat = nix::sys::statvfs::statvfs(path)
+        .map_err(|e| OSError::filesystem_error("statvfs", path, e.to_string()))?;
+    let fragment = stat.fragment_size() as u64;
+    Ok(FilesystemSpace {
+        total_bytes: (stat.blocks() as u64).saturating_mul(fragment),
+        free_bytes: (stat.blocks_free() as u64).saturating_mul(fragment),
+        available_bytes: (stat.blocks_available() as u64).saturating_mul(fragment),
+    })
+}
+
+#[cfg(not(unix))]
+fn filesystem_space(path: &str) -> OSResult<FilesystemSpace> {
+    Err(OSError::filesystem_error(
+        "statvfs",
+        path,
+        "Free space queries are not supported on this platform",
+    ))
+}
+
+/// Undo the octal escapes (`\040` for space, etc.) used in the mount table.
+fn unescape_mount_field(field: &str) -> String {
+    let bytes = field.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && i + 4 <= bytes.len() {
+            let digits = std::str::from_utf8(&bytes[i + 1..i + 4]).unwrap_or_default();
+            if let Ok(value) = u8::from_str_radix(digits, 8) {
+                out.push(value);
+                i += 4;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Parse the `/proc/mounts` format: `device path type options dump pass`.
+fn parse_mount_table(table: &str) -> Vec<MountPoint> {
+    table
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let device = fields.next()?;
+            let mount_path = fields.next()?;
+            let fs_type = fields.next()?;
+            let options = fields.next().unwrap_or_default();
+            Some(MountPoint {
+                device: unescape_mount_field(device),
+                mount_path: unescape_mount_field(mount_path),
+                fs_type: fs_type.to_string(),
+                options: options.split(',').map(str::to_string).collect(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+async fn mount_table() -> OSResult<Vec<MountPoint>> {
+    const MOUNTS: &str = "/proc/self/mounts";
+    let table = tokio::fs::read_to_string(MOUNTS)
+        .await
+        .map_err(|e| OSError::filesystem_error("read", MOUNTS, e.to_string()))?;
+    Ok(parse_mount_table(&table))
+}
+
+#[cfg(not(target_os = "linux"))]
+async fn mount_table() -> OSResult<Vec<MountPoint>> {
+    Err(OSError::filesystem_error(
+        "mounts",
+        crate::operations::filesystem::disk::MOUNT_TABLE_PATH,
+        "Mount table queries are not supported on this platform",
+    ))
+}
+
+#[cfg(unix)]
+fn allocated_bytes(metadata: &std::fs::Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    metadata.blocks().saturating_mul(512)
+}
+
+#[cfg(not(unix))]
+fn allocated_bytes(metadata: &std::fs::Metadata) -> u64 {
+    metadata.len()
+}
+
+/// Walk `root` without following symlinks, reporting `root` and the
+/// directories at most `max_depth` levels below it. Every file is counted
+/// in all of its reported ancestors.
+fn disk_usage(root: &Path, max_depth: Option<usize>) -> OSResult<Vec<DiskUsageEntry>> {
+    let fs_error = |path: &Path, e: std::io::Error| {
+        OSError::filesystem_error("disk_usage", path.display().to_string(), e.to_string())
+    };
+    let reported = |depth: usize| max_depth.is_none_or(|limit| depth <= limit);
+
+    let mut entries: BTreeMap<PathBuf, DiskUsageEntry> = BTreeMap::new();
+    let mut pending = vec![(root.to_path_buf(), 0usize)];
+
+    while let Some((path, depth)) = pending.pop() {
+        let metadata = std::fs::symlink_metadata(&path).map_err(|e| fs_error(&path, e))?;
+        if depth == 0 || (metadata.is_dir() && reported(depth)) {
+            entries.insert(
+                path.clone(),
+                DiskUsageEntry {
+                    path: path.display().to_string(),
+                    depth,
+                    apparent_bytes: 0,
+                    allocated_bytes: 0,
+                    files: 0,
+                },
+            );
+        }
+
+        if metadata.is_dir() {
+            for child in std::fs::read_dir(&path).map_err(|e| fs_error(&path, e))? {
+                let child = child.map_err(|e| fs_error(&path, e))?;
+                pending.push((child.path(), depth + 1));
+            }
+            continue;
+        }
+
+        let apparent = metadata.len();
+        let allocated = allocated_bytes(&metadata);
+        for ancestor in path.ancestors().take(depth + 1) {
+            if let Some(entry) = entries.get_mut(ancestor) {
+                entry.apparent_bytes += apparent;
+                entry.allocated_bytes += allocated;
+                if metadata.is_file() {
+                    entry.files += 1;
+                }
+            }
+        }
+    }
+
+    Ok(entries.into_values().collect())
+}
+
+#[async_trait]
+impl OSExecutor<FilesystemSpaceOperation> for FilesystemExecutor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn supported_operation_types(&self) -> Vec<OperationType> {
+        vec![OperationType::Filesystem]
+    }
+
+    async fn execute(
+        &self,
+        operation: FilesystemSpaceOperation,
+        context: &ExecutionContext,
+    ) -> OSResult<ExecutionResult> {
+        let started_at = Utc::now();
+
+        let space = filesystem_space(&operation.path)?;
+
+        let completed_at = Utc::now();
+
+        let result =
+            ExecutionResult::success_with_timing(encode(&space)?, started_at, completed_at)
+                .with_metadata("path".to_string(), operation.path.clone())
+                .with_metadata("total_bytes".to_string(), space.total_bytes.to_string())
+                .with_metadata(
+                    "available_bytes".to_string(),
+                    space.available_bytes.to_string(),
+                )
+                .with_metadata("executor".to_string(), self.name.to_string())
+                .with_metadata("user".to_string(), context.principal().to_string());
+
+        Ok(result)
+    }
+
+    async fn validate_operation(
+        &self,
+        operation: &FilesystemSpaceOperation,
+        _context: &ExecutionContext,
+    ) -> OSResult<()> {
+        validate_exists(&operation.path).await
+    }
+}
+
+#[async_trait]
+impl OSExecutor<MountListOperation> for FilesystemExecutor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn supported_operation_types(&self) -> Vec<OperationType> {
+        vec![OperationType::Filesystem]
+    }
+
+    async fn execute(
+        &self,
+        _operation: MountListOperation,
+        context: &ExecutionContext,
+    ) -> OSResult<ExecutionResult> {
+        let started_at = Utc::now();
+
+        let mounts = mount_table().await?;
+
+        let completed_at = Utc::now();
+
+        let result =
+            ExecutionResult::success_with_timing(encode(&mounts)?, started_at, completed_at)
+                .with_metadata("mount_count".to_string(), mounts.len().to_string())
+                .with_metadata("executor".to_string(), self.name.to_string())
+                .with_metadata("user".to_string(), context.principal().to_string());
+
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl OSExecutor<DiskUsageOperation> for FilesystemExecutor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn supported_operation_types(&self) -> Vec<OperationType> {
+        vec![OperationType::Filesystem]
+    }
+
+    async fn execute(
+        &self,
+        operation: DiskUsageOperation,
+        context: &ExecutionContext,
+    ) -> OSResult<ExecutionResult> {
+        let started_at = Utc::now();
+
+        let root = PathBuf::from(&operation.path);
+        let max_depth = operation.max_depth;
+        let entries =
+            run_with_priority(operation.priority, move || disk_usage(&root, max_depth)).await?;
+
+        let completed_at = Utc::now();
+
+        let total = entries.first().map_or(0, |e| e.apparent_bytes);
+        let result =
+            ExecutionResult::success_with_timing(encode(&entries)?, started_at, completed_at)
+                .with_metadata("path".to_string(), operation.path.clone())
+                .with_metadata("apparent_bytes".to_string(), total.to_string())
+                .with_metadata("priority".to_string(), operation.priority.to_string())
+                .with_metadata("executor".to_string(), self.name.to_string())
+                .with_metadata("user".to_string(), context.principal().to_string());
+
+        Ok(result)
+    }
+
+    async fn validate_operation(
+        &self,
+        operation: &DiskUsageOperation,
+        _context: &ExecutionContext,
+    ) -> OSResult<()> {
+        validate_exists(&operation.path).await
+    }
+}
+
+async fn validate_exists(path: &str) -> OSResult<()> {
+    if !tokio::fs::try_exists(path)
+        .await
+        .map_err(|e| OSError::filesystem_error("validate", path, e.to_string()))?
+    {
+        return Err(OSError::filesystem_error(
+            "validate",
+            path,
+            "Path does not exist",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::core::context::SecurityContext;
+
+    fn context() -> ExecutionContext {
+        ExecutionContext::new(SecurityContext::new("test-user".to_string()))
+    }
+
+    #[test]
+    fn test_parse_mount_table_unescapes_paths() {
+        let table = "/dev/sda1 / ext4 rw,relatime 0 0\n\
+                     tmpfs /mnt/my\\040disk tmpfs ro,nosuid 0 0\n";
+        let mounts = parse_mount_table(table);
+        assert_eq!(mounts.len(), 2);
+        assert_eq!(mounts[0].fs_type, "ext4");
+        assert_eq!(mounts[1].mount_path, "/mnt/my disk");
+        assert!(mounts[1].is_read_only());
+        assert!(!mounts[0].is_read_only());
+    }
+
+    #[tokio::test]
+    async fn test_disk_usage_respects_max_depth() {
+        let root = std::env::temp_dir().join(format!("osl-du-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(root.join("a/b")).unwrap();
+        std::fs::write(root.join("top.bin"), vec![0u8; 10]).unwrap();
+        std::fs::write(root.join("a/one.bin"), vec![0u8; 20]).unwrap();
+        std::fs::write(root.join("a/b/two.bin"), vec![0u8; 30]).unwrap();
+
+        let executor = FilesystemExecutor::new();
+        let op = DiskUsageOperation::new(root.display().to_string()).with_max_depth(1);
+        let result = executor.execute(op, &context()).await.unwrap();
+        let entries = DiskUsageEntry::from_output(&result.output).unwrap();
+
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].depth, 0);
+        assert_eq!(entries[0].apparent_bytes, 60);
+        assert_eq!(entries[0].files, 3);
+        assert!(entries[1].path.ends_with("a"));
+        assert_eq!(entries[1].depth, 1);
+        assert_eq!(entries[1].apparent_bytes, 50);
+        assert_eq!(entries[1].files, 2);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_filesystem_space_reports_capacity() {
+        let executor = FilesystemExecutor::new();
+        let op = FilesystemSpaceOperation::new(std::env::temp_dir().display().to_string());
+        let result = executor.execute(op, &context()).await.unwrap();
+        let space = FilesystemSpace::from_output(&result.output).unwrap();
+        assert!(space.total_bytes >= space.free_bytes);
+        assert!(space.free_bytes >= space.available_bytes);
+    }
+}
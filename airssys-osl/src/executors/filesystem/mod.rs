@@ -11,6 +11,9 @@
 //! - `write` - FileWriteOperation executor implementation
 //! - `create_dir` - DirectoryCreateOperation executor implementation
 //! - `delete` - FileDeleteOperation executor implementation
+//! - `disk` - Free space, mount table, and disk usage query executors
+//! - `priority` - Runs blocking work under a lowered IO/CPU priority
+//! - `acl` - NTFS ACL read/modify executor implementations (Windows only)
 //!
 //! # Example
 //!
@@ -33,9 +36,14 @@
 //! ```
 
 // Module declarations (private - internal implementation)
+#[cfg(target_os = "windows")]
+mod acl;
+mod atomic_write;
 mod create_dir;
 mod delete;
+mod disk;
 mod executor;
+mod priority;
 mod read;
 mod write;
 
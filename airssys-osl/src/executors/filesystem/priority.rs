@@ -0,0 +1,146 @@
+//! Running blocking filesystem work under a [`ResourcePriority`].
+//!
+//! Lowered priorities are applied to a dedicated thread that exits when the
+//! work finishes. Unprivileged processes cannot raise a thread's priority
+//! back, so reusing a tokio blocking-pool thread would leave it degraded for
+//! unrelated tasks.
+
+use crate::core::result::{OSError, OSResult};
+use crate::operations::filesystem::ResourcePriority;
+
+/// Run `work` on a blocking thread with `priority` applied.
+///
+/// Work without a priority change goes through the tokio blocking pool.
+pub(super) async fn run_with_priority<T, F>(priority: ResourcePriority, work: F) -> OSResult<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> OSResult<T> + Send + 'static,
+{
+    if priority.is_unchanged() {
+        return tokio::task::spawn_blocking(work)
+            .await
+            .map_err(|e| OSError::execution_failed(format!("blocking task failed: {e}")))?;
+    }
+
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    std::thread::Builder::new()
+        .name("airssys-osl-lowprio".to_string())
+        .spawn(move || {
+            let result = apply(&priority).and_then(|()| work());
+            let _ = tx.send(result);
+        })
+        .map_err(|e| OSError::execution_failed(format!("failed to spawn worker: {e}")))?;
+
+    rx.await
+        .map_err(|_| OSError::execution_failed("prioritized worker panicked"))?
+}
+
+#[cfg(target_os = "linux")]
+fn apply(priority: &ResourcePriority) -> OSResult<()> {
+    use crate::operations::filesystem::IoPriority;
+    use nix::libc;
+
+    // ioprio_set(2): class in the top bits, level in the low bits
+    const IOPRIO_CLASS_SHIFT: i32 = 13;
+    const IOPRIO_CLASS_BE: i32 = 2;
+    const IOPRIO_CLASS_IDLE: i32 = 3;
+    const IOPRIO_WHO_PROCESS: i32 = 1;
+
+    // SAFETY: gettid takes no arguments and cannot fail
+    let tid = unsafe { libc::syscall(libc::SYS_gettid) };
+
+    if let Some(io) = priority.io {
+        let value = match io {
+            IoPriority::BestEffort(level) => {
+                (IOPRIO_CLASS_BE << IOPRIO_CLASS_SHIFT) | i32::from(level)
+            }
+            IoPriority::Idle => IOPRIO_CLASS_IDLE << IOPRIO_CLASS_SHIFT,
+        };
+        // SAFETY: ioprio_set only reads its integer arguments
+        let rc = unsafe { libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, tid, value) };
+        if rc != 0 {
+            return Err(OSError::execution_failed(format!(
+                "ioprio_set({io}) failed: {}",
+                std::io::Error::last_os_error()
+            )));
+        }
+    }
+
+    if let Some(nice) = priority.nice {
+        // On Linux, PRIO_PROCESS with a thread id targets just that thread
+        // SAFETY: setpriority only reads its integer arguments
+        let rc = unsafe { libc::setpriority(libc::PRIO_PROCESS, tid as libc::id_t, nice) };
+        if rc != 0 {
+            return Err(OSError::execution_failed(format!(
+                "setpriority(nice={nice}) failed: {}",
+                std::io::Error::last_os_error()
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn apply(priority: &ResourcePriority) -> OSResult<()> {
+    use crate::operations::filesystem::IoPriority;
+    use windows_sys::Win32::System::Threading::{
+        GetCurrentThread, SetThreadPriority, THREAD_MODE_BACKGROUND_BEGIN,
+    };
+
+    let lowered = priority.io == Some(IoPriority::Idle) || priority.nice.is_some_and(|n| n > 0);
+    if !lowered {
+        return Ok(());
+    }
+
+    // SAFETY: GetCurrentThread returns a pseudo handle that needs no closing
+    let ok = unsafe { SetThreadPriority(GetCurrentThread(), THREAD_MODE_BACKGROUND_BEGIN) };
+    if ok == 0 {
+        return Err(OSError::execution_failed(format!(
+            "failed to enter background mode: {}",
+            std::io::Error::last_os_error()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn apply(_priority: &ResourcePriority) -> OSResult<()> {
+    // No per-thread IO priority on this platform; run at normal priority
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::operations::filesystem::IoPriority;
+
+    #[tokio::test]
+    async fn test_run_with_priority_returns_work_result() {
+        let value = run_with_priority(ResourcePriority::default(), || Ok(7))
+            .await
+            .unwrap();
+        assert_eq!(value, 7);
+
+        let priority = ResourcePriority::default()
+            .with_io(IoPriority::BestEffort(7))
+            .with_nice(5)
+            .unwrap();
+        let name = run_with_priority(priority, || {
+            Ok(std::thread::current().name().map(str::to_string))
+        })
+        .await
+        .unwrap();
+        assert_eq!(name.as_deref(), Some("airssys-osl-lowprio"));
+    }
+
+    #[tokio::test]
+    async fn test_run_with_priority_propagates_work_error() {
+        let result: OSResult<()> = run_with_priority(ResourcePriority::background(), || {
+            Err(OSError::execution_failed("boom"))
+        })
+        .await;
+        assert!(result.is_err());
+    }
+}
@@ -31,10 +31,14 @@ impl OSExecutor<FileReadOperation> for FilesystemExecutor {
     ) -> OSResult<ExecutionResult> {
         let started_at = Utc::now();
 
-        // Read file using tokio::fs
-        let content = tokio::fs::read(&operation.path)
-            .await
-            .map_err(|e| OSError::filesystem_error("read", &operation.path, e.to_string()))?;
+        // Read file using tokio::fs, aborting if the context is cancelled
+        let content = context
+            .cancellable(&format!("read '{}'", operation.path), async {
+                tokio::fs::read(&operation.path)
+                    .await
+                    .map_err(|e| OSError::filesystem_error("read", &operation.path, e.to_string()))
+            })
+            .await?;
 
         let completed_at = Utc::now();
 
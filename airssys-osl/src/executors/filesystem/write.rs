@@ -1,11 +1,14 @@
 //! FileWriteOperation executor implementation.
 //!
-//! Provides async file writing using tokio::fs with support for both
-//! append and overwrite modes.
+//! Writes on a blocking thread with support for both append and overwrite
+//! modes, honouring the operation's [`ResourcePriority`].
+//!
+//! [`ResourcePriority`]: crate::operations::filesystem::ResourcePriority
+
+use std::io::Write;
 
 use async_trait::async_trait;
 use chrono::Utc;
-use tokio::io::AsyncWriteExt;
 
 use crate::core::context::ExecutionContext;
 use crate::core::executor::{ExecutionResult, OSExecutor};
@@ -13,8 +16,28 @@ use crate::core::operation::OperationType;
 use crate::core::result::{OSError, OSResult};
 use crate::operations::filesystem::FileWriteOperation;
 
+use super::priority::run_with_priority;
 use super::FilesystemExecutor;
 
+fn write_file(path: &str, content: &[u8], append: bool) -> OSResult<()> {
+    if append {
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(path)
+            .map_err(|e| OSError::filesystem_error("open_append", path, e.to_string()))?;
+
+        file.write_all(content)
+            .map_err(|e| OSError::filesystem_error("write_append", path, e.to_string()))?;
+
+        file.flush()
+            .map_err(|e| OSError::filesystem_error("flush", path, e.to_string()))
+    } else {
+        std::fs::write(path, content)
+            .map_err(|e| OSError::filesystem_error("write", path, e.to_string()))
+    }
+}
+
 #[async_trait]
 impl OSExecutor<FileWriteOperation> for FilesystemExecutor {
     fn name(&self) -> &str {
@@ -32,39 +55,20 @@ impl OSExecutor<FileWriteOperation> for FilesystemExecutor {
     ) -> OSResult<ExecutionResult> {
         let started_at = Utc::now();
 
-        if operation.append {
-            // Append mode
-            let mut file = tokio::fs::OpenOptions::new()
-                .append(true)
-                .create(true)
-                .open(&operation.path)
-                .await
-                .map_err(|e| {
-                    OSError::filesystem_error("open_append", &operation.path, e.to_string())
-                })?;
-
-            file.write_all(&operation.content).await.map_err(|e| {
-                OSError::filesystem_error("write_append", &operation.path, e.to_string())
-            })?;
-
-            file.flush()
-                .await
-                .map_err(|e| OSError::filesystem_error("flush", &operation.path, e.to_string()))?;
-        } else {
-            // Overwrite mode
-            tokio::fs::write(&operation.path, &operation.content)
-                .await
-                .map_err(|e| OSError::filesystem_error("write", &operation.path, e.to_string()))?;
-        }
+        let bytes_written = operation.content.len();
+        let path = operation.path.clone();
+        let content = operation.content;
+        let append = operation.append;
+        run_with_priority(operation.priority, move || {
+            write_file(&path, &content, append)
+        })
+        .await?;
 
         let completed_at = Utc::now();
 
         let result = ExecutionResult::success_with_timing(Vec::new(), started_at, completed_at)
             .with_metadata("path".to_string(), operation.path.clone())
-            .with_metadata(
-                "bytes_written".to_string(),
-                operation.content.len().to_string(),
-            )
+            .with_metadata("bytes_written".to_string(), bytes_written.to_string())
             .with_metadata(
                 "mode".to_string(),
                 if operation.append {
@@ -74,6 +78,7 @@ impl OSExecutor<FileWriteOperation> for FilesystemExecutor {
                 }
                 .to_string(),
             )
+            .with_metadata("priority".to_string(), operation.priority.to_string())
             .with_metadata("executor".to_string(), self.name.to_string())
             .with_metadata("user".to_string(), context.principal().to_string());
 
@@ -143,4 +148,32 @@ mod tests {
         let content = std::fs::read_to_string(&file_path).expect("Failed to read file");
         assert_eq!(content, "Test content");
     }
+
+    #[tokio::test]
+    async fn test_file_write_operation_with_background_priority() {
+        use crate::operations::filesystem::ResourcePriority;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let file_path = temp_dir.path().join("bulk.log");
+        let path = file_path.to_str().expect("Invalid UTF-8 path").to_string();
+
+        let executor = FilesystemExecutor::new();
+        let context = ExecutionContext::new(SecurityContext::new("test-user".to_string()));
+        for chunk in ["first\n", "second\n"] {
+            let operation = FileWriteOperation::append(&path, chunk.as_bytes().to_vec())
+                .with_priority(ResourcePriority::background());
+            let result = executor
+                .execute(operation, &context)
+                .await
+                .expect("Execution failed");
+            assert_eq!(
+                result.metadata.get("priority").map(String::as_str),
+                Some("io=idle,nice=10")
+            );
+        }
+
+        let content = std::fs::read_to_string(&file_path).expect("Failed to read file");
+        assert_eq!(content, "first\nsecond\n");
+    }
 }
@@ -11,6 +11,7 @@
 //! - **FilesystemExecutor**: Handles file and directory operations using tokio::fs
 //! - **ProcessExecutor**: Manages process spawning and control using tokio::process
 //! - **NetworkExecutor**: Handles network connections using tokio::net
+//! - **ExecutorRegistry**: Maps operation types to executors for dispatch
 //!
 //! # Usage
 //!
@@ -49,16 +50,31 @@
 //! Each executor is organized into a submodule with implementation files
 //! for each operation type, mirroring the structure of the operations module:
 //!
-//! - `filesystem/` - Filesystem operations (read, write, create_dir, delete)
+//! - `filesystem/` - Filesystem operations (read, write, create_dir, delete, acl)
 //! - `process/` - Process operations (spawn, kill, signal)
-//! - `network/` - Network operations (connect, listen, socket)
+//! - `network/` - Network operations (connect, listen, socket, named_pipe)
+//! - `registry` - Capability-based executor selection
+//!
+//! # Windows Support
+//!
+//! On Windows the executors use native semantics rather than POSIX
+//! emulation, compiled only under `cfg(target_os = "windows")`:
+//!
+//! - NTFS ACLs: `FileAclReadOperation` / `FileAclModifyOperation` via `icacls`
+//! - Job objects: `ProcessSpawnOperation::with_job_limits` confines the
+//!   spawned process (memory, active process count, CPU time)
+//! - ConPTY: `ProcessSpawnOperation::with_pty` attaches a pseudo console
+//!   (a pty on Unix) for interactive sessions
+//! - Named pipes: `NamedPipeOperation` via tokio's named pipe support
 
 // Re-export executor implementations
 pub mod filesystem;
 pub mod network;
 pub mod process;
+pub mod registry;
 
 // Re-export main types for convenience
 pub use filesystem::FilesystemExecutor;
 pub use network::NetworkExecutor;
 pub use process::ProcessExecutor;
+pub use registry::ExecutorRegistry;
@@ -27,32 +27,38 @@ impl OSExecutor<NetworkConnectOperation> for NetworkExecutor {
     ) -> OSResult<ExecutionResult> {
         let started_at = Utc::now();
 
-        // Connect to the address with timeout if specified
-        let stream = if let Some(timeout) = operation.timeout {
-            tokio::time::timeout(timeout, tokio::net::TcpStream::connect(&operation.address))
-                .await
-                .map_err(|_| {
-                    OSError::network_error(
-                        format!("connect to {}", operation.address),
-                        "Connection timeout",
-                    )
-                })?
-                .map_err(|e| {
-                    OSError::network_error(
-                        format!("connect to {}", operation.address),
-                        e.to_string(),
-                    )
-                })?
-        } else {
-            tokio::net::TcpStream::connect(&operation.address)
-                .await
-                .map_err(|e| {
-                    OSError::network_error(
-                        format!("connect to {}", operation.address),
-                        e.to_string(),
-                    )
-                })?
+        // Connect to the address with timeout if specified, aborting if the
+        // context is cancelled
+        let connect = async {
+            if let Some(timeout) = operation.timeout {
+                tokio::time::timeout(timeout, tokio::net::TcpStream::connect(&operation.address))
+                    .await
+                    .map_err(|_| {
+                        OSError::network_error(
+                            format!("connect to {}", operation.address),
+                            "Connection timeout",
+                        )
+                    })?
+                    .map_err(|e| {
+                        OSError::network_error(
+                            format!("connect to {}", operation.address),
+                            e.to_string(),
+                        )
+                    })
+            } else {
+                tokio::net::TcpStream::connect(&operation.address)
+                    .await
+                    .map_err(|e| {
+                        OSError::network_error(
+                            format!("connect to {}", operation.address),
+                            e.to_string(),
+                        )
+                    })
+            }
         };
+        let stream = context
+            .cancellable(&format!("connect to {}", operation.address), connect)
+            .await?;
 
         let completed_at = Utc::now();
 
@@ -4,6 +4,8 @@
 mod connect;
 mod executor;
 mod listen;
+#[cfg(target_os = "windows")]
+mod named_pipe;
 mod socket;
 
 // Public re-exports
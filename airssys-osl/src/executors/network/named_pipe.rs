@@ -0,0 +1,153 @@
+//! NamedPipeOperation executor implementation (Windows).
+//!
+//! Uses tokio's native named pipe support. A server creates the first pipe
+//! instance and waits for one client; a client opens the pipe, retrying
+//! while every server instance is busy.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::net::windows::named_pipe::{ClientOptions, ServerOptions};
+
+use super::NetworkExecutor;
+use crate::core::context::ExecutionContext;
+use crate::core::executor::{ExecutionResult, OSExecutor};
+use crate::core::operation::OperationType;
+use crate::core::result::{OSError, OSResult};
+use crate::operations::network::{NamedPipeOperation, PipeRole};
+
+/// Win32 ERROR_PIPE_BUSY: all server instances are in use.
+const ERROR_PIPE_BUSY: i32 = 231;
+
+/// Delay between client connection attempts while the pipe is busy.
+const BUSY_RETRY_DELAY: Duration = Duration::from_millis(50);
+
+const PIPE_PREFIX: &str = r"\\.\pipe\";
+
+#[async_trait]
+impl OSExecutor<NamedPipeOperation> for NetworkExecutor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn supported_operation_types(&self) -> Vec<OperationType> {
+        vec![OperationType::Network]
+    }
+
+    async fn execute(
+        &self,
+        operation: NamedPipeOperation,
+        context: &ExecutionContext,
+    ) -> OSResult<ExecutionResult> {
+        let started_at = Utc::now();
+
+        let connect = async {
+            match operation.role {
+                PipeRole::Server => {
+                    let server = ServerOptions::new()
+                        .first_pipe_instance(true)
+                        .create(&operation.name)?;
+                    server.connect().await
+                }
+                PipeRole::Client => loop {
+                    match ClientOptions::new().open(&operation.name) {
+                        Ok(_client) => return Ok(()),
+                        Err(e) if e.raw_os_error() == Some(ERROR_PIPE_BUSY) => {
+                            tokio::time::sleep(BUSY_RETRY_DELAY).await;
+                        }
+                        Err(e) => return Err(e),
+                    }
+                },
+            }
+        };
+
+        let pipe_operation = format!("named pipe {}", operation.name);
+        let connected = match operation.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, connect)
+                .await
+                .map_err(|_| OSError::network_error(&pipe_operation, "Connection timeout"))?,
+            None => connect.await,
+        };
+        connected.map_err(|e| OSError::network_error(&pipe_operation, e.to_string()))?;
+
+        let completed_at = Utc::now();
+
+        let output = format!("Connected on {}", operation.name).into_bytes();
+        let mut result = ExecutionResult::success_with_timing(output, started_at, completed_at)
+            .with_metadata("pipe_name".to_string(), operation.name.clone())
+            .with_metadata("role".to_string(), format!("{:?}", operation.role))
+            .with_metadata("executor".to_string(), self.name.clone())
+            .with_metadata("user".to_string(), context.principal().to_string());
+
+        if let Some(timeout) = operation.timeout {
+            result = result.with_metadata("timeout".to_string(), format!("{timeout:?}"));
+        }
+
+        Ok(result)
+    }
+
+    async fn validate_operation(
+        &self,
+        operation: &NamedPipeOperation,
+        _context: &ExecutionContext,
+    ) -> OSResult<()> {
+        let Some(pipe) = operation.name.strip_prefix(PIPE_PREFIX) else {
+            return Err(OSError::execution_failed(format!(
+                "Invalid pipe name: {} (expected {PIPE_PREFIX}<name>)",
+                operation.name
+            )));
+        };
+
+        if pipe.is_empty() || pipe.contains('\\') {
+            return Err(OSError::execution_failed(format!(
+                "Invalid pipe name: {}",
+                operation.name
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::core::context::SecurityContext;
+
+    #[tokio::test]
+    async fn test_named_pipe_server_and_client() {
+        let executor = NetworkExecutor::new("test-executor");
+        let context = ExecutionContext::new(SecurityContext::new("test-user".to_string()));
+        let name = format!(r"\\.\pipe\airssys-test-{}", std::process::id());
+
+        let server = NamedPipeOperation::server(&name).with_timeout(Duration::from_secs(5));
+        let server_executor = executor.clone();
+        let server_context = context.clone();
+        let server_task =
+            tokio::spawn(async move { server_executor.execute(server, &server_context).await });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let client = NamedPipeOperation::client(&name).with_timeout(Duration::from_secs(5));
+        let client_result = executor.execute(client, &context).await.unwrap();
+        assert_eq!(client_result.get_metadata("role").unwrap(), "Client");
+
+        let server_result = server_task.await.unwrap().unwrap();
+        assert_eq!(
+            server_result.get_metadata("pipe_name").unwrap(),
+            name.as_str()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_rejects_bad_pipe_name() {
+        let executor = NetworkExecutor::new("test-executor");
+        let context = ExecutionContext::new(SecurityContext::new("test-user".to_string()));
+
+        let result = executor
+            .validate_operation(&NamedPipeOperation::client("not-a-pipe"), &context)
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,353 @@
+//! Windows ConPTY support for ProcessSpawnOperation.
+//!
+//! The process is created with `CreateProcessW` and a pseudo console
+//! attached through its startup attribute list; the pseudo console reads
+//! input from one pipe and writes rendered terminal output to another.
+//! The pseudo console keeps the output pipe open until it is closed, so it
+//! is closed as soon as the process exits to let readers see EOF.
+//! A process with job limits is created suspended and resumed once it is
+//! in its job object.
+
+use std::collections::BTreeMap;
+use std::ffi::{c_void, OsStr, OsString};
+use std::fs::File;
+use std::mem::{size_of, zeroed};
+use std::os::windows::ffi::OsStrExt;
+use std::os::windows::io::{FromRawHandle, RawHandle};
+use std::ptr::{null, null_mut};
+
+use windows_sys::Win32::Foundation::{CloseHandle, HANDLE, WAIT_OBJECT_0};
+use windows_sys::Win32::System::Console::{
+    ClosePseudoConsole, CreatePseudoConsole, ResizePseudoConsole, COORD, HPCON,
+};
+use windows_sys::Win32::System::Pipes::CreatePipe;
+use windows_sys::Win32::System::Threading::{
+    CreateProcessW, DeleteProcThreadAttributeList, GetExitCodeProcess,
+    InitializeProcThreadAttributeList, TerminateProcess, UpdateProcThreadAttribute,
+    WaitForSingleObject, CREATE_SUSPENDED, CREATE_UNICODE_ENVIRONMENT,
+    EXTENDED_STARTUPINFO_PRESENT, INFINITE, LPPROC_THREAD_ATTRIBUTE_LIST, PROCESS_INFORMATION,
+    PROC_THREAD_ATTRIBUTE_PSEUDOCONSOLE, STARTUPINFOEXW,
+};
+
+use crate::core::result::{OSError, OSResult};
+use crate::operations::process::PtySize;
+use crate::operations::ProcessSpawnOperation;
+
+/// Owned kernel handle, closed on drop.
+struct OwnedHandle(HANDLE);
+
+impl OwnedHandle {
+    /// Give up ownership of the handle.
+    fn into_raw(self) -> HANDLE {
+        let handle = self.0;
+        std::mem::forget(self);
+        handle
+    }
+}
+
+impl Drop for OwnedHandle {
+    fn drop(&mut self) {
+        // SAFETY: the handle is owned and closed exactly once.
+        unsafe {
+            CloseHandle(self.0);
+        }
+    }
+}
+
+/// Owned pseudo console, closed on drop.
+struct PseudoConsole(HPCON);
+
+impl Drop for PseudoConsole {
+    fn drop(&mut self) {
+        // SAFETY: the pseudo console was created by CreatePseudoConsole and
+        // is closed exactly once.
+        unsafe { ClosePseudoConsole(self.0) };
+    }
+}
+
+/// Initialized process thread attribute list, deleted on drop.
+struct AttributeList(LPPROC_THREAD_ATTRIBUTE_LIST);
+
+impl Drop for AttributeList {
+    fn drop(&mut self) {
+        // SAFETY: the list was initialized by InitializeProcThreadAttributeList.
+        unsafe { DeleteProcThreadAttributeList(self.0) };
+    }
+}
+
+/// Pseudo console and the process attached to it.
+pub(super) struct Pty {
+    /// Open until the process has exited
+    console: Option<PseudoConsole>,
+    process: OwnedHandle,
+    pid: u32,
+    exited: bool,
+}
+
+// SAFETY: process and pseudo console handles are process-wide kernel
+// objects that may be used from any thread.
+unsafe impl Send for Pty {}
+
+impl Pty {
+    pub(super) fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    pub(super) fn resize(&self, size: PtySize) -> OSResult<()> {
+        let console = self.console.as_ref().ok_or_else(|| {
+            OSError::process_error("resize pseudo console", "Process has already exited")
+        })?;
+        // SAFETY: the pseudo console is open.
+        let result = unsafe { ResizePseudoConsole(console.0, coord(size)) };
+        if result < 0 {
+            return Err(hresult_error("resize pseudo console", result));
+        }
+        Ok(())
+    }
+
+    pub(super) fn kill(&mut self) -> OSResult<()> {
+        // SAFETY: the process handle is open.
+        if unsafe { TerminateProcess(self.process.0, 1) } == 0 {
+            return Err(last_error(&format!("terminate process {}", self.pid)));
+        }
+        Ok(())
+    }
+
+    pub(super) async fn wait(&mut self) -> OSResult<i32> {
+        // Handles are not Send; the address is valid while `self` is alive,
+        // and `self` is borrowed for the whole wait
+        let process = self.process.0 as usize;
+        let pid = self.pid;
+        let code = tokio::task::spawn_blocking(move || {
+            let process = process as HANDLE;
+            // SAFETY: the process handle stays open for the duration of the wait.
+            if unsafe { WaitForSingleObject(process, INFINITE) } != WAIT_OBJECT_0 {
+                return Err(last_error(&format!("wait for process {pid}")));
+            }
+            let mut code = 0u32;
+            // SAFETY: the process has exited and `code` outlives the call.
+            if unsafe { GetExitCodeProcess(process, &mut code) } == 0 {
+                return Err(last_error(&format!("get exit code of process {pid}")));
+            }
+            Ok(code)
+        })
+        .await
+        .map_err(|e| OSError::process_error(format!("wait for process {pid}"), e.to_string()))??;
+
+        self.exited = true;
+        // Release the output pipe so readers reach EOF
+        self.console = None;
+        // Exit codes are DWORDs; NTSTATUS codes map to negative values
+        Ok(code as i32)
+    }
+}
+
+impl Drop for Pty {
+    fn drop(&mut self) {
+        if !self.exited {
+            // SAFETY: the process handle is still open.
+            unsafe { TerminateProcess(self.process.0, 1) };
+        }
+    }
+}
+
+/// Spawn `operation` attached to a new pseudo console of `size`.
+///
+/// Returns the pseudo console with a reader of its output and a writer of
+/// its input.
+pub(super) fn spawn(
+    operation: &ProcessSpawnOperation,
+    size: PtySize,
+) -> OSResult<(Pty, File, File)> {
+    let (input_read, input_write) = pipe()?;
+    let (output_read, output_write) = pipe()?;
+
+    // SAFETY: HPCON is a plain handle value; zero is a valid placeholder.
+    let mut handle: HPCON = unsafe { zeroed() };
+    // SAFETY: both pipe ends are open and `handle` outlives the call.
+    let result =
+        unsafe { CreatePseudoConsole(coord(size), input_read.0, output_write.0, 0, &mut handle) };
+    if result < 0 {
+        return Err(hresult_error("create pseudo console", result));
+    }
+    let console = PseudoConsole(handle);
+    // The pseudo console holds its own duplicates of these ends
+    drop(input_read);
+    drop(output_write);
+
+    let mut list_size = 0usize;
+    // SAFETY: a null list queries the required size; the call is expected to fail.
+    unsafe { InitializeProcThreadAttributeList(null_mut(), 1, 0, &mut list_size) };
+    let mut list_buffer = vec![0u8; list_size];
+    let list: LPPROC_THREAD_ATTRIBUTE_LIST = list_buffer.as_mut_ptr().cast();
+    // SAFETY: the buffer has the size requested above and outlives the list.
+    if unsafe { InitializeProcThreadAttributeList(list, 1, 0, &mut list_size) } == 0 {
+        return Err(last_error("initialize process attribute list"));
+    }
+    let list = AttributeList(list);
+    // SAFETY: the pseudo console attribute takes the HPCON value itself.
+    let updated = unsafe {
+        UpdateProcThreadAttribute(
+            list.0,
+            0,
+            PROC_THREAD_ATTRIBUTE_PSEUDOCONSOLE as usize,
+            console.0 as *const c_void,
+            size_of::<HPCON>(),
+            null_mut(),
+            null(),
+        )
+    };
+    if updated == 0 {
+        return Err(last_error("attach pseudo console"));
+    }
+
+    // SAFETY: STARTUPINFOEXW is plain data; all-zero is valid.
+    let mut startup: STARTUPINFOEXW = unsafe { zeroed() };
+    startup.StartupInfo.cb = size_of::<STARTUPINFOEXW>() as u32;
+    startup.lpAttributeList = list.0;
+
+    let mut command_line = wide(&command_line(operation));
+    let environment = environment_block(operation);
+    let working_dir = operation.working_dir.as_deref().map(wide);
+    let mut flags = EXTENDED_STARTUPINFO_PRESENT | CREATE_UNICODE_ENVIRONMENT;
+    if operation.job_limits.is_some() {
+        flags |= CREATE_SUSPENDED;
+    }
+    // SAFETY: PROCESS_INFORMATION is plain data; all-zero is valid.
+    let mut info: PROCESS_INFORMATION = unsafe { zeroed() };
+    // SAFETY: every buffer is NUL-terminated and outlives the call; handles
+    // are not inherited, the pseudo console is attached via the attribute list.
+    let created = unsafe {
+        CreateProcessW(
+            null(),
+            command_line.as_mut_ptr(),
+            null(),
+            null(),
+            0,
+            flags,
+            environment.as_ptr().cast(),
+            working_dir.as_ref().map_or(null(), |dir| dir.as_ptr()),
+            &startup.StartupInfo,
+            &mut info,
+        )
+    };
+    if created == 0 {
+        return Err(last_error(&format!("spawn '{}'", operation.command)));
+    }
+    let thread = OwnedHandle(info.hThread);
+    drop(list);
+    drop(list_buffer);
+    let pty = Pty {
+        console: Some(console),
+        process: OwnedHandle(info.hProcess),
+        pid: info.dwProcessId,
+        exited: false,
+    };
+
+    // Dropping `pty` on error terminates the still suspended process
+    if let Some(limits) = &operation.job_limits {
+        super::job::assign_to_job(pty.process.0, limits)?;
+        super::job::resume_thread(thread.0)?;
+    }
+    drop(thread);
+
+    // SAFETY: the pipe ends are owned and transferred to the files.
+    let reader = unsafe { File::from_raw_handle(output_read.into_raw() as RawHandle) };
+    // SAFETY: as above.
+    let writer = unsafe { File::from_raw_handle(input_write.into_raw() as RawHandle) };
+    Ok((pty, reader, writer))
+}
+
+fn pipe() -> OSResult<(OwnedHandle, OwnedHandle)> {
+    let mut read: HANDLE = null_mut();
+    let mut write: HANDLE = null_mut();
+    // SAFETY: both out-pointers are valid; default security, default size.
+    if unsafe { CreatePipe(&mut read, &mut write, null(), 0) } == 0 {
+        return Err(last_error("create pseudo console pipe"));
+    }
+    Ok((OwnedHandle(read), OwnedHandle(write)))
+}
+
+fn coord(size: PtySize) -> COORD {
+    COORD {
+        X: i16::try_from(size.cols).unwrap_or(i16::MAX),
+        Y: i16::try_from(size.rows).unwrap_or(i16::MAX),
+    }
+}
+
+/// Build the command line, quoting arguments per the MSVC runtime rules.
+fn command_line(operation: &ProcessSpawnOperation) -> OsString {
+    let mut line = OsString::new();
+    for (i, arg) in std::iter::once(&operation.command)
+        .chain(&operation.args)
+        .enumerate()
+    {
+        if i > 0 {
+            line.push(" ");
+        }
+        line.push(quote_arg(arg));
+    }
+    line
+}
+
+fn quote_arg(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains([' ', '\t', '\n', '"']) {
+        return arg.to_string();
+    }
+    let mut quoted = String::from("\"");
+    let mut backslashes = 0;
+    for c in arg.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                // Escape the backslashes preceding a quote, then the quote
+                quoted.extend(std::iter::repeat_n('\\', backslashes * 2 + 1));
+                quoted.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                quoted.extend(std::iter::repeat_n('\\', backslashes));
+                quoted.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+    // Backslashes before the closing quote must be escaped as well
+    quoted.extend(std::iter::repeat_n('\\', backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
+/// Inherited environment with the operation's overrides, as a sorted,
+/// double-NUL-terminated UTF-16 block.
+fn environment_block(operation: &ProcessSpawnOperation) -> Vec<u16> {
+    // Variable names are case-insensitive on Windows
+    let mut vars: BTreeMap<String, (OsString, OsString)> = std::env::vars_os()
+        .map(|(key, value)| (key.to_string_lossy().to_uppercase(), (key, value)))
+        .collect();
+    for (key, value) in &operation.env {
+        vars.insert(key.to_uppercase(), (key.into(), value.into()));
+    }
+
+    let mut block = Vec::new();
+    for (key, value) in vars.values() {
+        block.extend(key.encode_wide());
+        block.push(u16::from(b'='));
+        block.extend(value.encode_wide());
+        block.push(0);
+    }
+    block.push(0);
+    block
+}
+
+fn wide(s: impl AsRef<OsStr>) -> Vec<u16> {
+    s.as_ref().encode_wide().chain(std::iter::once(0)).collect()
+}
+
+fn hresult_error(operation: &str, result: i32) -> OSError {
+    OSError::process_error(operation, format!("HRESULT {result:#010x}"))
+}
+
+fn last_error(operation: &str) -> OSError {
+    OSError::process_error(operation, std::io::Error::last_os_error().to_string())
+}
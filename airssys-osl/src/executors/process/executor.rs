@@ -3,11 +3,19 @@
 // Layer 1: Standard library imports
 use std::fmt;
 
+// Layer 3: Internal module imports
+use super::pty::{PtySession, PtySessions};
+
 /// Process executor for executing process management operations.
 ///
 /// This executor provides real implementations for process operations using
 /// tokio's async process management capabilities.
 ///
+/// Processes spawned on a pseudo-terminal are kept as [`PtySession`]s until
+/// claimed with [`take_pty_session`](Self::take_pty_session). Clones of the
+/// executor share these sessions, so a clone wrapped in middleware can
+/// spawn sessions the original claims.
+///
 /// # Examples
 ///
 /// ```rust
@@ -20,6 +28,9 @@ use std::fmt;
 pub struct ProcessExecutor {
     /// Executor name for identification and logging
     pub(super) name: String,
+
+    /// Unclaimed pseudo-terminal sessions, by process ID
+    pub(super) pty_sessions: PtySessions,
 }
 
 impl ProcessExecutor {
@@ -37,13 +48,31 @@ impl ProcessExecutor {
     /// let executor = ProcessExecutor::new("my-process-executor");
     /// ```
     pub fn new(name: impl Into<String>) -> Self {
-        Self { name: name.into() }
+        Self {
+            name: name.into(),
+            pty_sessions: PtySessions::default(),
+        }
     }
 
     /// Get the executor name.
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// Claim the pseudo-terminal session of the process `pid`.
+    ///
+    /// Returns `None` if no unclaimed session exists for `pid`.
+    pub fn take_pty_session(&self, pid: u32) -> Option<PtySession> {
+        self.pty_sessions.lock().ok()?.remove(&pid)
+    }
+
+    /// Number of pseudo-terminal sessions not yet claimed.
+    pub fn pending_pty_sessions(&self) -> usize {
+        self.pty_sessions
+            .lock()
+            .map(|sessions| sessions.len())
+            .unwrap_or(0)
+    }
 }
 
 impl fmt::Display for ProcessExecutor {
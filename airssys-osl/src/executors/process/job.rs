@@ -0,0 +1,137 @@
+//! Windows job object support for ProcessSpawnOperation.
+//!
+//! A process with job limits is created suspended, assigned to a fresh,
+//! unnamed job object and only then resumed, so it never runs, or starts
+//! children, outside its limits. The job handle is closed once the limits
+//! are applied: the job object stays alive (and keeps enforcing its limits)
+//! for as long as any process is assigned to it.
+
+use std::mem::{size_of, zeroed};
+
+use windows_sys::Win32::Foundation::{CloseHandle, HANDLE, INVALID_HANDLE_VALUE};
+use windows_sys::Win32::System::Diagnostics::ToolHelp::{
+    CreateToolhelp32Snapshot, Thread32First, Thread32Next, TH32CS_SNAPTHREAD, THREADENTRY32,
+};
+use windows_sys::Win32::System::JobObjects::{
+    AssignProcessToJobObject, CreateJobObjectW, JobObjectExtendedLimitInformation,
+    SetInformationJobObject, JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JOB_OBJECT_LIMIT_ACTIVE_PROCESS,
+    JOB_OBJECT_LIMIT_PROCESS_MEMORY, JOB_OBJECT_LIMIT_PROCESS_TIME,
+};
+use windows_sys::Win32::System::Threading::{OpenThread, ResumeThread, THREAD_SUSPEND_RESUME};
+
+use crate::core::result::{OSError, OSResult};
+use crate::operations::process::JobLimits;
+
+/// Owned kernel handle, closed on drop.
+struct OwnedHandle(HANDLE);
+
+impl Drop for OwnedHandle {
+    fn drop(&mut self) {
+        // SAFETY: the handle is owned and closed exactly once.
+        unsafe {
+            CloseHandle(self.0);
+        }
+    }
+}
+
+/// Create a job object with `limits` and assign `process` to it.
+pub(super) fn assign_to_job(process: HANDLE, limits: &JobLimits) -> OSResult<()> {
+    // SAFETY: null attributes and name create an unnamed job with default security.
+    let job = unsafe { CreateJobObjectW(std::ptr::null(), std::ptr::null()) };
+    if job.is_null() {
+        return Err(job_error("create job object"));
+    }
+    let job = OwnedHandle(job);
+
+    // SAFETY: JOBOBJECT_EXTENDED_LIMIT_INFORMATION is plain data; all-zero is valid.
+    let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = unsafe { zeroed() };
+    if let Some(bytes) = limits.process_memory_bytes {
+        info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_PROCESS_MEMORY;
+        info.ProcessMemoryLimit = usize::try_from(bytes).unwrap_or(usize::MAX);
+    }
+    if let Some(count) = limits.active_processes {
+        info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_ACTIVE_PROCESS;
+        info.BasicLimitInformation.ActiveProcessLimit = count;
+    }
+    if let Some(cpu_time) = limits.cpu_time {
+        // Job object times are expressed in 100-nanosecond ticks
+        let ticks = i64::try_from(cpu_time.as_nanos() / 100).unwrap_or(i64::MAX);
+        info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_PROCESS_TIME;
+        info.BasicLimitInformation.PerProcessUserTimeLimit = ticks;
+    }
+
+    // SAFETY: `info` outlives the call and the size matches the information class.
+    let applied = unsafe {
+        SetInformationJobObject(
+            job.0,
+            JobObjectExtendedLimitInformation,
+            std::ptr::addr_of!(info).cast(),
+            size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+        )
+    };
+    if applied == 0 {
+        return Err(job_error("set job object limits"));
+    }
+
+    // SAFETY: both handles are valid for the duration of the call.
+    if unsafe { AssignProcessToJobObject(job.0, process) } == 0 {
+        return Err(job_error("assign process to job object"));
+    }
+
+    Ok(())
+}
+
+/// Resume `thread`, the main thread of a process created suspended.
+pub(super) fn resume_thread(thread: HANDLE) -> OSResult<()> {
+    // SAFETY: the handle is valid for the duration of the call.
+    if unsafe { ResumeThread(thread) } == u32::MAX {
+        return Err(job_error("resume process"));
+    }
+    Ok(())
+}
+
+/// Resume the process `pid`, created suspended.
+///
+/// For processes whose main thread handle was not kept: a suspended
+/// process has no threads but its main thread, so every thread owned by
+/// `pid` is resumed.
+pub(super) fn resume_process(pid: u32) -> OSResult<()> {
+    // SAFETY: a thread snapshot takes no process argument.
+    let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0) };
+    if snapshot == INVALID_HANDLE_VALUE {
+        return Err(job_error("list process threads"));
+    }
+    let snapshot = OwnedHandle(snapshot);
+
+    // SAFETY: THREADENTRY32 is plain data; all-zero is valid.
+    let mut entry: THREADENTRY32 = unsafe { zeroed() };
+    entry.dwSize = size_of::<THREADENTRY32>() as u32;
+    let mut resumed = false;
+    // SAFETY: the snapshot is open and `entry` outlives the call.
+    let mut found = unsafe { Thread32First(snapshot.0, &mut entry) } != 0;
+    while found {
+        if entry.th32OwnerProcessID == pid {
+            // SAFETY: opening a thread by ID has no memory-safety preconditions.
+            let thread = unsafe { OpenThread(THREAD_SUSPEND_RESUME, 0, entry.th32ThreadID) };
+            if thread.is_null() {
+                return Err(job_error("open process thread"));
+            }
+            resume_thread(OwnedHandle(thread).0)?;
+            resumed = true;
+        }
+        // SAFETY: as above.
+        found = unsafe { Thread32Next(snapshot.0, &mut entry) } != 0;
+    }
+
+    if !resumed {
+        return Err(OSError::process_error(
+            "resume process",
+            format!("No thread of process {pid} found"),
+        ));
+    }
+    Ok(())
+}
+
+fn job_error(operation: &str) -> OSError {
+    OSError::process_error(operation, std::io::Error::last_os_error().to_string())
+}
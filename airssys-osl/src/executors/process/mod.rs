@@ -4,8 +4,13 @@
 //! process management operations with real tokio I/O.
 
 // Module declarations (private - internal implementation)
+#[cfg(target_os = "windows")]
+mod conpty;
 mod executor;
+#[cfg(target_os = "windows")]
+mod job;
 mod kill;
+pub mod pty;
 mod signal;
 mod spawn;
 
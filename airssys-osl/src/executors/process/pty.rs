@@ -0,0 +1,431 @@
+//! Pseudo-terminal sessions for ProcessSpawnOperation.
+//!
+//! A process spawned with `ProcessSpawnOperation::with_pty` runs on a
+//! pseudo-terminal instead of pipes: a pty on Unix, a ConPTY pseudo console
+//! on Windows. The executor keeps the resulting [`PtySession`] until the
+//! caller claims it with `ProcessExecutor::take_pty_session`, so the spawn
+//! itself goes through the usual middleware pipeline (security checks and
+//! audit logging) while the terminal I/O happens on the returned handle.
+//!
+//! The session owns the process: dropping an unclaimed or claimed session
+//! terminates the process if it is still running.
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+// Layer 2: Third-party crate imports
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+// Layer 3: Internal module imports
+#[cfg(target_os = "windows")]
+use super::conpty as sys;
+use crate::core::result::{OSError, OSResult};
+use crate::operations::process::PtySize;
+use crate::operations::ProcessSpawnOperation;
+
+/// Unclaimed sessions of an executor, by process ID.
+pub(super) type PtySessions = Arc<Mutex<HashMap<u32, PtySession>>>;
+
+/// An interactive process running on a pseudo-terminal.
+///
+/// Obtained from `ProcessExecutor::take_pty_session` after executing a
+/// `ProcessSpawnOperation` with a pty. The terminal output and input are
+/// available once each through [`take_reader`](Self::take_reader) and
+/// [`take_writer`](Self::take_writer), so they can be driven from separate
+/// tasks.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use airssys_osl::core::context::{ExecutionContext, SecurityContext};
+/// use airssys_osl::core::executor::OSExecutor;
+/// use airssys_osl::executors::ProcessExecutor;
+/// use airssys_osl::operations::process::PtySize;
+/// use airssys_osl::operations::ProcessSpawnOperation;
+/// use tokio::io::{AsyncReadExt, AsyncWriteExt};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let executor = ProcessExecutor::new("terminal");
+/// let context = ExecutionContext::new(SecurityContext::new("admin".to_string()));
+/// let operation = ProcessSpawnOperation::new("sh").with_pty(PtySize::new(24, 80));
+///
+/// let result = executor.execute(operation, &context).await?;
+/// let pid: u32 = result.get_metadata("pid").unwrap_or_default().parse()?;
+/// let mut session = executor.take_pty_session(pid).ok_or("session not found")?;
+///
+/// let mut input = session.take_writer().ok_or("writer taken")?;
+/// input.write_all(b"echo hello; exit\n").await?;
+/// input.flush().await?;
+///
+/// session.resize(PtySize::new(40, 120))?;
+///
+/// let mut output = Vec::new();
+/// session.take_reader().ok_or("reader taken")?.read_to_end(&mut output).await?;
+/// let exit_code = session.wait().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct PtySession {
+    /// Platform pseudo-terminal and process
+    pty: sys::Pty,
+    /// Current terminal size
+    size: PtySize,
+    /// Terminal output, until taken
+    reader: Option<PtyReader>,
+    /// Terminal input, until taken
+    writer: Option<PtyWriter>,
+}
+
+impl PtySession {
+    /// Process ID of the process on the terminal.
+    pub fn pid(&self) -> u32 {
+        self.pty.pid()
+    }
+
+    /// Current terminal size.
+    pub fn size(&self) -> PtySize {
+        self.size
+    }
+
+    /// Resize the terminal.
+    ///
+    /// The process is notified as it would be by a terminal emulator
+    /// (`SIGWINCH` on Unix).
+    ///
+    /// # Errors
+    ///
+    /// Returns `OSError::ProcessError` if the size is empty or the
+    /// terminal cannot be resized.
+    pub fn resize(&mut self, size: PtySize) -> OSResult<()> {
+        if size.is_empty() {
+            return Err(OSError::process_error(
+                "resize pty",
+                format!("invalid terminal size {size}"),
+            ));
+        }
+        self.pty.resize(size)?;
+        self.size = size;
+        Ok(())
+    }
+
+    /// Take the terminal output stream.
+    ///
+    /// Returns `None` if it was already taken. The stream ends once the
+    /// process has exited and its output is drained.
+    pub fn take_reader(&mut self) -> Option<PtyReader> {
+        self.reader.take()
+    }
+
+    /// Take the terminal input stream.
+    ///
+    /// Returns `None` if it was already taken.
+    pub fn take_writer(&mut self) -> Option<PtyWriter> {
+        self.writer.take()
+    }
+
+    /// Terminate the process.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OSError::ProcessError` if the process cannot be signalled.
+    pub fn kill(&mut self) -> OSResult<()> {
+        self.pty.kill()
+    }
+
+    /// Wait for the process to exit and return its exit code.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OSError::ProcessError` if waiting fails.
+    pub async fn wait(&mut self) -> OSResult<i32> {
+        self.pty.wait().await
+    }
+}
+
+impl fmt::Debug for PtySession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PtySession")
+            .field("pid", &self.pid())
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+/// Output of a pseudo-terminal.
+///
+/// Reads end with EOF once the terminal is closed, rather than with the
+/// platform's "terminal gone" error.
+#[derive(Debug)]
+pub struct PtyReader {
+    file: tokio::fs::File,
+    closed: bool,
+}
+
+impl AsyncRead for PtyReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        if self.closed {
+            return Poll::Ready(Ok(()));
+        }
+        match Pin::new(&mut self.file).poll_read(cx, buf) {
+            Poll::Ready(Err(e)) if is_closed(&e) => {
+                self.closed = true;
+                Poll::Ready(Ok(()))
+            }
+            other => other,
+        }
+    }
+}
+
+/// Input of a pseudo-terminal.
+#[derive(Debug)]
+pub struct PtyWriter {
+    file: tokio::fs::File,
+}
+
+impl AsyncWrite for PtyWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.file).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.file).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.file).poll_shutdown(cx)
+    }
+}
+
+/// Spawn the process of `operation` on a new pseudo-terminal of `size`.
+pub(super) fn spawn(operation: &ProcessSpawnOperation, size: PtySize) -> OSResult<PtySession> {
+    let (pty, reader, writer) = sys::spawn(operation, size)?;
+    Ok(PtySession {
+        pty,
+        size,
+        reader: Some(PtyReader {
+            file: tokio::fs::File::from_std(reader),
+            closed: false,
+        }),
+        writer: Some(PtyWriter {
+            file: tokio::fs::File::from_std(writer),
+        }),
+    })
+}
+
+/// Whether `error` means the other side of the terminal has gone away.
+fn is_closed(error: &io::Error) -> bool {
+    // Linux reports EIO on the master once every slave descriptor is closed
+    #[cfg(unix)]
+    if error.raw_os_error() == Some(nix::libc::EIO) {
+        return true;
+    }
+    error.kind() == io::ErrorKind::BrokenPipe
+}
+
+#[cfg(unix)]
+mod sys {
+    //! Unix pseudo-terminals via `openpty(3)`.
+
+    use std::fs::File;
+    use std::io;
+    use std::os::fd::{AsRawFd, OwnedFd};
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::Stdio;
+
+    use nix::libc;
+    use nix::pty::{openpty, Winsize};
+    use nix::sys::termios::Termios;
+
+    use crate::core::result::{OSError, OSResult};
+    use crate::operations::process::PtySize;
+    use crate::operations::ProcessSpawnOperation;
+
+    /// Master side of the pty and the process on its slave side.
+    pub(super) struct Pty {
+        master: OwnedFd,
+        child: tokio::process::Child,
+        pid: u32,
+    }
+
+    impl Pty {
+        pub(super) fn pid(&self) -> u32 {
+            self.pid
+        }
+
+        pub(super) fn resize(&self, size: PtySize) -> OSResult<()> {
+            let winsize = winsize(size);
+            // SAFETY: the descriptor is an open pty master and `winsize`
+            // outlives the call.
+            let status = unsafe {
+                libc::ioctl(
+                    self.master.as_raw_fd(),
+                    libc::TIOCSWINSZ as _,
+                    std::ptr::addr_of!(winsize),
+                )
+            };
+            if status == -1 {
+                return Err(pty_error("resize pty", &io::Error::last_os_error()));
+            }
+            Ok(())
+        }
+
+        pub(super) fn kill(&mut self) -> OSResult<()> {
+            self.child
+                .start_kill()
+                .map_err(|e| pty_error(format!("kill process {}", self.pid), &e))
+        }
+
+        pub(super) async fn wait(&mut self) -> OSResult<i32> {
+            let status = self
+                .child
+                .wait()
+                .await
+                .map_err(|e| pty_error(format!("wait for process {}", self.pid), &e))?;
+            // Mirror the shell convention for processes killed by a signal
+            Ok(status
+                .code()
+                .or_else(|| status.signal().map(|signal| 128 + signal))
+                .unwrap_or(-1))
+        }
+    }
+
+    /// Spawn `operation` with a new pty as its controlling terminal.
+    ///
+    /// Returns the pty with a reader and a writer on its master side.
+    pub(super) fn spawn(
+        operation: &ProcessSpawnOperation,
+        size: PtySize,
+    ) -> OSResult<(Pty, File, File)> {
+        let pty = openpty(&winsize(size), None::<&Termios>)
+            .map_err(|e| pty_error("open pty", &e.into()))?;
+
+        let mut cmd = tokio::process::Command::new(&operation.command);
+        cmd.args(&operation.args)
+            .envs(&operation.env)
+            .stdin(Stdio::from(clone_fd(&pty.slave)?))
+            .stdout(Stdio::from(clone_fd(&pty.slave)?))
+            .stderr(Stdio::from(pty.slave))
+            .kill_on_drop(true);
+        if let Some(working_dir) = &operation.working_dir {
+            cmd.current_dir(working_dir);
+        }
+        // SAFETY: only async-signal-safe calls run between fork and exec.
+        unsafe {
+            cmd.pre_exec(|| {
+                // Start a new session and make the pty (stdin) its
+                // controlling terminal
+                nix::unistd::setsid().map_err(io::Error::from)?;
+                if libc::ioctl(0, libc::TIOCSCTTY as _, 0) == -1 {
+                    return Err(io::Error::last_os_error());
+                }
+                Ok(())
+            });
+        }
+
+        let child = cmd
+            .spawn()
+            .map_err(|e| pty_error(format!("spawn '{}'", operation.command), &e))?;
+        // The slave descriptors must be closed here, or reads on the master
+        // never see the process exit
+        drop(cmd);
+        let pid = child
+            .id()
+            .ok_or_else(|| OSError::process_error("spawn", "Failed to get process ID"))?;
+
+        let reader = File::from(clone_fd(&pty.master)?);
+        let writer = File::from(clone_fd(&pty.master)?);
+        Ok((
+            Pty {
+                master: pty.master,
+                child,
+                pid,
+            },
+            reader,
+            writer,
+        ))
+    }
+
+    fn winsize(size: PtySize) -> Winsize {
+        Winsize {
+            ws_row: size.rows,
+            ws_col: size.cols,
+            ws_xpixel: 0,
+            ws_ypixel: 0,
+        }
+    }
+
+    fn clone_fd(fd: &OwnedFd) -> OSResult<OwnedFd> {
+        fd.try_clone()
+            .map_err(|e| pty_error("duplicate pty descriptor", &e))
+    }
+
+    fn pty_error(operation: impl Into<String>, error: &io::Error) -> OSError {
+        OSError::process_error(operation, error.to_string())
+    }
+}
+
+#[cfg(all(test, unix))]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[tokio::test]
+    async fn test_pty_session_runs_interactive_shell() {
+        let operation = ProcessSpawnOperation::new("sh");
+        let mut session = spawn(&operation, PtySize::new(24, 80)).unwrap();
+
+        // The process sees a terminal of the requested size, then the new one
+        let mut input = session.take_writer().unwrap();
+        let mut reader = session.take_reader().unwrap();
+        input
+            .write_all(b"stty size; read x; stty size; test -t 0 && echo is-tty; exit 3\n")
+            .await
+            .unwrap();
+        input.flush().await.unwrap();
+
+        let mut output = Vec::new();
+        let mut chunk = [0u8; 256];
+        while !String::from_utf8_lossy(&output).contains("24 80") {
+            let n = reader.read(&mut chunk).await.unwrap();
+            assert!(n > 0, "{}", String::from_utf8_lossy(&output));
+            output.extend_from_slice(&chunk[..n]);
+        }
+
+        session.resize(PtySize::new(40, 120)).unwrap();
+        assert_eq!(session.size(), PtySize::new(40, 120));
+        input.write_all(b"\n").await.unwrap();
+        input.flush().await.unwrap();
+
+        reader.read_to_end(&mut output).await.unwrap();
+        let output = String::from_utf8_lossy(&output);
+        assert!(output.contains("40 120"), "{output}");
+        assert!(output.contains("is-tty"), "{output}");
+
+        assert_eq!(session.wait().await.unwrap(), 3);
+        assert!(session.take_reader().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_pty_session_rejects_empty_size() {
+        let mut session = spawn(&ProcessSpawnOperation::new("sh"), PtySize::default()).unwrap();
+        assert!(session.resize(PtySize::new(0, 80)).is_err());
+        assert_eq!(session.size(), PtySize::default());
+
+        session.kill().unwrap();
+        assert_eq!(session.wait().await.unwrap(), 128 + 9);
+    }
+}
@@ -8,6 +8,7 @@ use crate::core::context::ExecutionContext;
 use crate::core::executor::{ExecutionResult, OSExecutor};
 use crate::core::operation::OperationType;
 use crate::core::result::{OSError, OSResult};
+use crate::operations::process::PtySize;
 use crate::operations::ProcessSpawnOperation;
 
 #[async_trait]
@@ -27,32 +28,13 @@ impl OSExecutor<ProcessSpawnOperation> for ProcessExecutor {
     ) -> OSResult<ExecutionResult> {
         let started_at = Utc::now();
 
-        // Build the command
-        let mut cmd = tokio::process::Command::new(&operation.command);
-
-        // Add arguments
-        if !operation.args.is_empty() {
-            cmd.args(&operation.args);
-        }
+        // A cancelled context never starts the process
+        context.check_cancelled(&format!("spawn '{}'", operation.command))?;
 
-        // Add environment variables
-        for (key, value) in &operation.env {
-            cmd.env(key, value);
-        }
-
-        // Set working directory if provided
-        if let Some(working_dir) = &operation.working_dir {
-            cmd.current_dir(working_dir);
-        }
-
-        // Spawn the process
-        let child = cmd.spawn().map_err(|e| {
-            OSError::process_error(format!("spawn '{}'", operation.command), e.to_string())
-        })?;
-
-        let pid = child
-            .id()
-            .ok_or_else(|| OSError::process_error("spawn", "Failed to get process ID"))?;
+        let pid = match operation.pty {
+            Some(size) => self.spawn_pty(&operation, size)?,
+            None => self.spawn_piped(&operation)?,
+        };
 
         let completed_at = Utc::now();
 
@@ -63,7 +45,17 @@ impl OSExecutor<ProcessSpawnOperation> for ProcessExecutor {
             .with_metadata("pid".to_string(), pid.to_string())
             .with_metadata("args".to_string(), operation.args.join(" "))
             .with_metadata("executor".to_string(), self.name.clone())
-            .with_metadata("user".to_string(), context.principal().to_string());
+            .with_metadata("user".to_string(), context.principal().to_string())
+            .with_metadata(
+                "job_object".to_string(),
+                operation.job_limits.is_some().to_string(),
+            )
+            .with_metadata(
+                "pty".to_string(),
+                operation
+                    .pty
+                    .map_or_else(|| "none".to_string(), |size| size.to_string()),
+            );
 
         Ok(result)
     }
@@ -78,6 +70,20 @@ impl OSExecutor<ProcessSpawnOperation> for ProcessExecutor {
             return Err(OSError::execution_failed("Command cannot be empty"));
         }
 
+        // Job objects are a Windows facility; refuse rather than run unconfined
+        #[cfg(not(target_os = "windows"))]
+        if operation.job_limits.is_some() {
+            return Err(OSError::execution_failed(
+                "Job object limits are only supported on Windows",
+            ));
+        }
+
+        if operation.pty.is_some_and(|size| size.is_empty()) {
+            return Err(OSError::execution_failed(
+                "Pseudo-terminal size must be non-zero",
+            ));
+        }
+
         // Validate working directory exists if provided
         if let Some(working_dir) = &operation.working_dir {
             let path = std::path::Path::new(working_dir);
@@ -97,6 +103,79 @@ impl OSExecutor<ProcessSpawnOperation> for ProcessExecutor {
     }
 }
 
+impl ProcessExecutor {
+    /// Spawn `operation` with piped stdio and return its PID.
+    fn spawn_piped(&self, operation: &ProcessSpawnOperation) -> OSResult<u32> {
+        // Build the command
+        let mut cmd = tokio::process::Command::new(&operation.command);
+
+        // Add arguments
+        if !operation.args.is_empty() {
+            cmd.args(&operation.args);
+        }
+
+        // Add environment variables
+        for (key, value) in &operation.env {
+            cmd.env(key, value);
+        }
+
+        // Set working directory if provided
+        if let Some(working_dir) = &operation.working_dir {
+            cmd.current_dir(working_dir);
+        }
+
+        // A confined process stays suspended until it is in its job object
+        #[cfg(target_os = "windows")]
+        if operation.job_limits.is_some() {
+            cmd.creation_flags(windows_sys::Win32::System::Threading::CREATE_SUSPENDED);
+        }
+
+        // Spawn the process
+        let child = cmd.spawn().map_err(|e| {
+            OSError::process_error(format!("spawn '{}'", operation.command), e.to_string())
+        })?;
+
+        let pid = child
+            .id()
+            .ok_or_else(|| OSError::process_error("spawn", "Failed to get process ID"))?;
+
+        // Confine the process to a job object when limits are requested
+        #[cfg(target_os = "windows")]
+        if let Some(limits) = &operation.job_limits {
+            let mut child = child;
+            let confined = match child.raw_handle() {
+                Some(handle) => super::job::assign_to_job(handle, limits)
+                    .and_then(|()| super::job::resume_process(pid)),
+                None => Err(OSError::process_error(
+                    "assign process to job object",
+                    "Process has already exited",
+                )),
+            };
+            if let Err(e) = confined {
+                // Never leave a process running without its requested limits
+                let _ = child.start_kill();
+                return Err(e);
+            }
+        }
+
+        Ok(pid)
+    }
+
+    /// Spawn `operation` on a pseudo-terminal, keep the session for the
+    /// caller to claim and return its PID.
+    fn spawn_pty(&self, operation: &ProcessSpawnOperation, size: PtySize) -> OSResult<u32> {
+        // Job limits, if any, are applied before the process starts running
+        let session = super::pty::spawn(operation, size)?;
+        let pid = session.pid();
+
+        self.pty_sessions
+            .lock()
+            .map_err(|_| OSError::process_error("register pty session", "Lock poisoned"))?
+            .insert(pid, session);
+        Ok(pid)
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -196,6 +275,23 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("does not exist"));
     }
 
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn test_validate_job_limits_rejected_off_windows() {
+        use crate::operations::process::JobLimits;
+
+        let executor = ProcessExecutor::new("test-executor");
+        let operation = ProcessSpawnOperation::new("echo")
+            .with_job_limits(JobLimits::new().max_active_processes(1));
+        let context = ExecutionContext::new(SecurityContext::new("test-user".to_string()));
+
+        let result = executor.validate_operation(&operation, &context).await;
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("only supported on Windows"));
+    }
+
     #[allow(clippy::expect_used)]
     #[tokio::test]
     async fn test_validate_file_as_working_dir() {
@@ -216,6 +312,42 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("not a directory"));
     }
 
+    #[tokio::test]
+    async fn test_validate_empty_pty_size() {
+        let executor = ProcessExecutor::new("test-executor");
+        let operation = ProcessSpawnOperation::new("sh").with_pty(PtySize::new(0, 80));
+        let context = ExecutionContext::new(SecurityContext::new("test-user".to_string()));
+
+        let result = executor.validate_operation(&operation, &context).await;
+        assert!(result.unwrap_err().to_string().contains("non-zero"));
+    }
+
+    #[cfg(unix)]
+    #[allow(clippy::expect_used)]
+    #[tokio::test]
+    async fn test_spawn_with_pty_keeps_session() {
+        let executor = ProcessExecutor::new("test-executor");
+        let operation = ProcessSpawnOperation::new("sh").with_pty(PtySize::new(24, 80));
+        let context = ExecutionContext::new(SecurityContext::new("test-user".to_string()));
+
+        // Sessions spawned through a clone are claimed from the original
+        let result = executor
+            .clone()
+            .execute(operation, &context)
+            .await
+            .expect("Failed to execute spawn operation");
+        assert_eq!(result.get_metadata("pty").unwrap(), "80x24");
+        assert_eq!(executor.pending_pty_sessions(), 1);
+
+        let pid: u32 = result.get_metadata("pid").unwrap().parse().unwrap();
+        let mut session = executor.take_pty_session(pid).unwrap();
+        assert_eq!(session.pid(), pid);
+        assert!(executor.take_pty_session(pid).is_none());
+
+        session.kill().unwrap();
+        session.wait().await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_spawn_invalid_command() {
         let executor = ProcessExecutor::new("test-executor");
@@ -0,0 +1,624 @@
+//! Executor registry with capability-based executor selection.
+//!
+//! The [`ExecutorRegistry`] maps operation types to executor instances so
+//! that dispatch is no longer hardcoded per helper. Each registration carries
+//! an [`ExecutorDescriptor`] describing the platforms it runs on, the
+//! features it provides, and its priority. At dispatch time the registry
+//! picks the highest-priority executor whose descriptor satisfies the
+//! current platform and any [`ExecutorRequirements`] supplied by the caller.
+//!
+//! # Example
+//!
+//! ```rust
+//! use airssys_osl::executors::registry::{ExecutorDescriptor, ExecutorRegistry};
+//! use airssys_osl::executors::FilesystemExecutor;
+//! use airssys_osl::operations::FileReadOperation;
+//!
+//! let registry = ExecutorRegistry::with_defaults();
+//! let operation = FileReadOperation::new("/etc/hosts");
+//! let executor = registry.resolve(&operation).unwrap();
+//! assert_eq!(executor.name(), "filesystem-executor");
+//!
+//! // Custom executors override defaults when given a higher priority
+//! registry
+//!     .register::<FileReadOperation, _>(
+//!         FilesystemExecutor::new(),
+//!         ExecutorDescriptor::new().with_priority(10).with_feature("cached"),
+//!     )
+//!     .unwrap();
+//! ```
+
+// Layer 1: Standard library imports
+use std::any::{Any, TypeId};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::{Arc, OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+// Layer 2: Third-party crate imports
+use async_trait::async_trait;
+
+// Layer 3: Internal module imports
+use crate::core::context::ExecutionContext;
+use crate::core::executor::{ExecutionResult, OSExecutor};
+use crate::core::operation::{Operation, OperationType};
+use crate::core::result::{OSError, OSResult};
+use crate::executors::filesystem::FilesystemExecutor;
+use crate::executors::network::NetworkExecutor;
+use crate::executors::process::ProcessExecutor;
+use crate::operations::filesystem::{
+    AtomicFileWriteOperation, DirectoryCreateOperation, FileDeleteOperation, FileReadOperation,
+    FileWriteOperation,
+};
+use crate::operations::network::{
+    NetworkConnectOperation, NetworkListenOperation, NetworkSocketOperation,
+};
+use crate::operations::process::{
+    ProcessKillOperation, ProcessSignalOperation, ProcessSpawnOperation,
+};
+
+/// Describes what a registered executor provides.
+///
+/// An empty platform list means the executor runs on every platform.
+/// Platforms are compared against [`std::env::consts::OS`]
+/// (e.g. `"linux"`, `"macos"`, `"windows"`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecutorDescriptor {
+    platforms: Vec<String>,
+    features: HashSet<String>,
+    priority: i32,
+}
+
+impl ExecutorDescriptor {
+    /// Creates a descriptor that runs everywhere with no features and priority 0.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts the executor to the given platform (may be called repeatedly).
+    pub fn with_platform(mut self, platform: impl Into<String>) -> Self {
+        self.platforms.push(platform.into());
+        self
+    }
+
+    /// Declares a feature provided by the executor (e.g. `"acl"`, `"pty"`).
+    pub fn with_feature(mut self, feature: impl Into<String>) -> Self {
+        self.features.insert(feature.into());
+        self
+    }
+
+    /// Sets the selection priority; higher values win.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Returns the selection priority.
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Returns true if the executor runs on `platform`.
+    pub fn supports_platform(&self, platform: &str) -> bool {
+        self.platforms.is_empty() || self.platforms.iter().any(|p| p == platform)
+    }
+
+    /// Returns true if the executor provides `feature`.
+    pub fn provides(&self, feature: &str) -> bool {
+        self.features.contains(feature)
+    }
+}
+
+/// Requirements a caller places on the executor selected for an operation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecutorRequirements {
+    features: Vec<String>,
+    platform: Option<String>,
+}
+
+impl ExecutorRequirements {
+    /// Creates empty requirements (any executor for the current platform).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires the selected executor to provide `feature`.
+    pub fn require_feature(mut self, feature: impl Into<String>) -> Self {
+        self.features.push(feature.into());
+        self
+    }
+
+    /// Overrides the platform used for matching (defaults to the host OS).
+    pub fn for_platform(mut self, platform: impl Into<String>) -> Self {
+        self.platform = Some(platform.into());
+        self
+    }
+
+    fn platform(&self) -> &str {
+        self.platform.as_deref().unwrap_or(std::env::consts::OS)
+    }
+
+    fn is_satisfied_by(&self, descriptor: &ExecutorDescriptor) -> bool {
+        descriptor.supports_platform(self.platform())
+            && self.features.iter().all(|f| descriptor.provides(f))
+    }
+}
+
+/// Executor selected from an [`ExecutorRegistry`].
+///
+/// Wraps the registered executor behind a shared pointer so it can be
+/// composed with middleware via
+/// [`ExecutorExt::with_middleware`](crate::middleware::ext::ExecutorExt::with_middleware)
+/// exactly like a concrete executor.
+pub struct RegisteredExecutor<O: Operation> {
+    inner: Arc<dyn OSExecutor<O>>,
+}
+
+impl<O: Operation> RegisteredExecutor<O> {
+    /// Returns the name of the selected executor.
+    pub fn name(&self) -> &str {
+        self.inner.name()
+    }
+}
+
+impl<O: Operation> Clone for RegisteredExecutor<O> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<O: Operation> fmt::Debug for RegisteredExecutor<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegisteredExecutor")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl<O: Operation> OSExecutor<O> for RegisteredExecutor<O> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn supported_operation_types(&self) -> Vec<OperationType> {
+        self.inner.supported_operation_types()
+    }
+
+    async fn can_execute(&self, operation: &O, context: &ExecutionContext) -> OSResult<bool> {
+        self.inner.can_execute(operation, context).await
+    }
+
+    async fn execute(&self, operation: O, context: &ExecutionContext) -> OSResult<ExecutionResult> {
+        self.inner.execute(operation, context).await
+    }
+
+    async fn validate_operation(&self, operation: &O, context: &ExecutionContext) -> OSResult<()> {
+        self.inner.validate_operation(operation, context).await
+    }
+
+    async fn cleanup(&self, context: &ExecutionContext) -> OSResult<()> {
+        self.inner.cleanup(context).await
+    }
+}
+
+/// A single registration: descriptor plus a type-erased `Arc<dyn OSExecutor<O>>`.
+struct Registration {
+    descriptor: ExecutorDescriptor,
+    executor: Box<dyn Any + Send + Sync>,
+    sequence: u64,
+}
+
+#[derive(Default)]
+struct RegistryState {
+    entries: HashMap<TypeId, Vec<Registration>>,
+    next_sequence: u64,
+}
+
+/// Registry mapping operation types to executor instances.
+///
+/// Executors are stored per concrete operation type. Selection filters by
+/// the operation's [`OperationType`], the target platform, and required
+/// features, then picks the highest priority; ties go to the most recent
+/// registration so custom executors override built-in defaults.
+///
+/// # Thread Safety
+///
+/// Uses `RwLock` for interior mutability: dispatch takes a read lock,
+/// registration takes a write lock.
+pub struct ExecutorRegistry {
+    state: RwLock<RegistryState>,
+}
+
+impl ExecutorRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self {
+            state: RwLock::new(RegistryState::default()),
+        }
+    }
+
+    /// Creates a registry pre-populated with the built-in platform executors.
+    pub fn with_defaults() -> Self {
+        let registry = Self::new();
+        registry.register_defaults();
+        registry
+    }
+
+    /// Returns the process-wide registry used by helper functions.
+    ///
+    /// Initialized with the built-in executors on first access. Custom
+    /// executors registered here are picked up by all `helpers::*` calls.
+    pub fn global() -> &'static ExecutorRegistry {
+        static GLOBAL: OnceLock<ExecutorRegistry> = OnceLock::new();
+        GLOBAL.get_or_init(Self::with_defaults)
+    }
+
+    fn register_defaults(&self) {
+        let descriptor = ExecutorDescriptor::new;
+        // Registration on a fresh registry cannot hit a poisoned lock.
+        let _ = self.register::<FileReadOperation, _>(FilesystemExecutor::new(), descriptor());
+        let _ = self.register::<FileWriteOperation, _>(FilesystemExecutor::new(), descriptor());
+        let _ =
+            self.register::<AtomicFileWriteOperation, _>(FilesystemExecutor::new(), descriptor());
+        let _ =
+            self.register::<DirectoryCreateOperation, _>(FilesystemExecutor::new(), descriptor());
+        let _ = self.register::<FileDeleteOperation, _>(FilesystemExecutor::new(), descriptor());
+        let _ = self.register::<ProcessSpawnOperation, _>(
+            ProcessExecutor::new("helper_executor"),
+            descriptor(),
+        );
+        let _ = self.register::<ProcessKillOperation, _>(
+            ProcessExecutor::new("helper_executor"),
+            descriptor(),
+        );
+        let _ = self.register::<ProcessSignalOperation, _>(
+            ProcessExecutor::new("helper_executor"),
+            descriptor(),
+        );
+        let _ = self.register::<NetworkConnectOperation, _>(
+            NetworkExecutor::new("helper_executor"),
+            descriptor(),
+        );
+        let _ = self.register::<NetworkListenOperation, _>(
+            NetworkExecutor::new("helper_executor"),
+            descriptor(),
+        );
+        let _ = self.register::<NetworkSocketOperation, _>(
+            NetworkExecutor::new("helper_executor"),
+            descriptor(),
+        );
+
+        #[cfg(target_os = "windows")]
+        {
+            use crate::operations::filesystem::{FileAclModifyOperation, FileAclReadOperation};
+            use crate::operations::network::NamedPipeOperation;
+
+            let windows = || descriptor().with_platform("windows");
+            let _ = self.register::<FileAclReadOperation, _>(
+                FilesystemExecutor::new(),
+                windows().with_feature("ntfs_acl"),
+            );
+            let _ = self.register::<FileAclModifyOperation, _>(
+                FilesystemExecutor::new(),
+                windows().with_feature("ntfs_acl"),
+            );
+            let _ = self.register::<NamedPipeOperation, _>(
+                NetworkExecutor::new("helper_executor"),
+                windows().with_feature("named_pipe"),
+            );
+        }
+    }
+
+    /// Registers an executor for operations of type `O`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OSError::ConfigurationError` if the registry lock is poisoned.
+    pub fn register<O, E>(&self, executor: E, descriptor: ExecutorDescriptor) -> OSResult<()>
+    where
+        O: Operation,
+        E: OSExecutor<O>,
+    {
+        let executor: Arc<dyn OSExecutor<O>> = Arc::new(executor);
+        let mut state = self.write_state()?;
+        let sequence = state.next_sequence;
+        state.next_sequence += 1;
+        state
+            .entries
+            .entry(TypeId::of::<O>())
+            .or_default()
+            .push(Registration {
+                descriptor,
+                executor: Box::new(executor),
+                sequence,
+            });
+        Ok(())
+    }
+
+    /// Removes every executor registered for `O` under the given name.
+    ///
+    /// Returns the number of registrations removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OSError::ConfigurationError` if the registry lock is poisoned.
+    pub fn unregister<O: Operation>(&self, name: &str) -> OSResult<usize> {
+        let mut state = self.write_state()?;
+        let Some(entries) = state.entries.get_mut(&TypeId::of::<O>()) else {
+            return Ok(0);
+        };
+        let before = entries.len();
+        entries.retain(|r| {
+            r.executor
+                .downcast_ref::<Arc<dyn OSExecutor<O>>>()
+                .is_none_or(|e| e.name() != name)
+        });
+        Ok(before - entries.len())
+    }
+
+    /// Returns the number of executors registered for `O`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OSError::ConfigurationError` if the registry lock is poisoned.
+    pub fn count<O: Operation>(&self) -> OSResult<usize> {
+        let state = self.read_state()?;
+        Ok(state.entries.get(&TypeId::of::<O>()).map_or(0, Vec::len))
+    }
+
+    /// Selects the executor for `operation` on the current platform.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OSError::ExecutionFailed` if no registered executor matches.
+    pub fn resolve<O: Operation>(&self, operation: &O) -> OSResult<RegisteredExecutor<O>> {
+        self.resolve_with(operation, &ExecutorRequirements::new())
+    }
+
+    /// Selects the executor for `operation` that satisfies `requirements`.
+    ///
+    /// # Errors
+    ///
+    /// - `OSError::ExecutionFailed` - No registered executor matches
+    /// - `OSError::ConfigurationError` - Registry lock is poisoned
+    pub fn resolve_with<O: Operation>(
+        &self,
+        operation: &O,
+        requirements: &ExecutorRequirements,
+    ) -> OSResult<RegisteredExecutor<O>> {
+        let op_type = operation.operation_type();
+        let state = self.read_state()?;
+
+        let selected = state
+            .entries
+            .get(&TypeId::of::<O>())
+            .into_iter()
+            .flatten()
+            .filter(|r| requirements.is_satisfied_by(&r.descriptor))
+            .filter_map(|r| {
+                let executor = r.executor.downcast_ref::<Arc<dyn OSExecutor<O>>>()?;
+                let supported = executor.supported_operation_types();
+                (supported.is_empty() || supported.contains(&op_type)).then_some((r, executor))
+            })
+            .max_by_key(|(r, _)| (r.descriptor.priority, r.sequence))
+            .map(|(_, executor)| Arc::clone(executor));
+
+        selected
+            .map(|inner| RegisteredExecutor { inner })
+            .ok_or_else(|| {
+                OSError::execution_failed(format!(
+                    "No executor registered for {} operation '{}' matching {:?}",
+                    op_type.as_str(),
+                    std::any::type_name::<O>(),
+                    requirements
+                ))
+            })
+    }
+
+    fn read_state(&self) -> OSResult<RwLockReadGuard<'_, RegistryState>> {
+        self.state.read().map_err(|e| {
+            OSError::configuration_error(format!("Executor registry lock poisoned: {e}"))
+        })
+    }
+
+    fn write_state(&self) -> OSResult<RwLockWriteGuard<'_, RegistryState>> {
+        self.state.write().map_err(|e| {
+            OSError::configuration_error(format!("Executor registry lock poisoned: {e}"))
+        })
+    }
+}
+
+impl Default for ExecutorRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ExecutorRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let registrations = self
+            .state
+            .read()
+            .map(|s| s.entries.values().map(Vec::len).sum::<usize>())
+            .unwrap_or_default();
+        f.debug_struct("ExecutorRegistry")
+            .field("registrations", &registrations)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::core::context::SecurityContext;
+
+    #[derive(Debug)]
+    struct TaggedExecutor {
+        name: String,
+    }
+
+    impl TaggedExecutor {
+        fn new(name: &str) -> Self {
+            Self {
+                name: name.to_string(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl OSExecutor<FileReadOperation> for TaggedExecutor {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn supported_operation_types(&self) -> Vec<OperationType> {
+            vec![OperationType::Filesystem]
+        }
+
+        async fn execute(
+            &self,
+            _operation: FileReadOperation,
+            _context: &ExecutionContext,
+        ) -> OSResult<ExecutionResult> {
+            Ok(ExecutionResult::success(self.name.clone().into_bytes()))
+        }
+    }
+
+    #[test]
+    fn test_defaults_cover_builtin_operations() {
+        let registry = ExecutorRegistry::with_defaults();
+        assert_eq!(registry.count::<FileReadOperation>().unwrap(), 1);
+        assert_eq!(registry.count::<ProcessSpawnOperation>().unwrap(), 1);
+        assert_eq!(registry.count::<NetworkSocketOperation>().unwrap(), 1);
+
+        let op = ProcessSpawnOperation::new("echo");
+        assert_eq!(registry.resolve(&op).unwrap().name(), "helper_executor");
+    }
+
+    #[test]
+    fn test_empty_registry_reports_missing_executor() {
+        let registry = ExecutorRegistry::new();
+        let err = registry
+            .resolve(&FileReadOperation::new("/tmp/x"))
+            .unwrap_err();
+        assert!(err.to_string().contains("No executor registered"));
+    }
+
+    #[test]
+    fn test_later_registration_wins_on_equal_priority() {
+        let registry = ExecutorRegistry::with_defaults();
+        registry
+            .register::<FileReadOperation, _>(
+                TaggedExecutor::new("custom"),
+                ExecutorDescriptor::new(),
+            )
+            .unwrap();
+
+        let op = FileReadOperation::new("/tmp/x");
+        assert_eq!(registry.resolve(&op).unwrap().name(), "custom");
+    }
+
+    #[test]
+    fn test_priority_beats_registration_order() {
+        let registry = ExecutorRegistry::new();
+        registry
+            .register::<FileReadOperation, _>(
+                TaggedExecutor::new("preferred"),
+                ExecutorDescriptor::new().with_priority(5),
+            )
+            .unwrap();
+        registry
+            .register::<FileReadOperation, _>(
+                TaggedExecutor::new("late"),
+                ExecutorDescriptor::new(),
+            )
+            .unwrap();
+
+        let op = FileReadOperation::new("/tmp/x");
+        assert_eq!(registry.resolve(&op).unwrap().name(), "preferred");
+    }
+
+    #[test]
+    fn test_feature_requirements_filter_candidates() {
+        let registry = ExecutorRegistry::with_defaults();
+        registry
+            .register::<FileReadOperation, _>(
+                TaggedExecutor::new("cached"),
+                ExecutorDescriptor::new()
+                    .with_feature("cache")
+                    .with_priority(-1),
+            )
+            .unwrap();
+
+        let op = FileReadOperation::new("/tmp/x");
+        assert_eq!(registry.resolve(&op).unwrap().name(), "filesystem-executor");
+
+        let needs_cache = ExecutorRequirements::new().require_feature("cache");
+        assert_eq!(
+            registry.resolve_with(&op, &needs_cache).unwrap().name(),
+            "cached"
+        );
+
+        let needs_missing = ExecutorRequirements::new().require_feature("missing");
+        assert!(registry.resolve_with(&op, &needs_missing).is_err());
+    }
+
+    #[test]
+    fn test_platform_restriction() {
+        let registry = ExecutorRegistry::new();
+        registry
+            .register::<FileReadOperation, _>(
+                TaggedExecutor::new("windows-only"),
+                ExecutorDescriptor::new().with_platform("windows"),
+            )
+            .unwrap();
+
+        let op = FileReadOperation::new("/tmp/x");
+        let on_windows = ExecutorRequirements::new().for_platform("windows");
+        let on_linux = ExecutorRequirements::new().for_platform("linux");
+        assert!(registry.resolve_with(&op, &on_windows).is_ok());
+        assert!(registry.resolve_with(&op, &on_linux).is_err());
+    }
+
+    #[test]
+    fn test_unregister_by_name() {
+        let registry = ExecutorRegistry::with_defaults();
+        registry
+            .register::<FileReadOperation, _>(
+                TaggedExecutor::new("custom"),
+                ExecutorDescriptor::new(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            registry.unregister::<FileReadOperation>("custom").unwrap(),
+            1
+        );
+        assert_eq!(
+            registry.unregister::<FileReadOperation>("custom").unwrap(),
+            0
+        );
+        let op = FileReadOperation::new("/tmp/x");
+        assert_eq!(registry.resolve(&op).unwrap().name(), "filesystem-executor");
+    }
+
+    #[tokio::test]
+    async fn test_registered_executor_delegates_execution() {
+        let registry = ExecutorRegistry::new();
+        registry
+            .register::<FileReadOperation, _>(
+                TaggedExecutor::new("echo"),
+                ExecutorDescriptor::new(),
+            )
+            .unwrap();
+
+        let op = FileReadOperation::new("/tmp/x");
+        let executor = registry.resolve(&op).unwrap();
+        let context = ExecutionContext::new(SecurityContext::new("tester".to_string()));
+        let result = executor.execute(op, &context).await.unwrap();
+        assert_eq!(result.output, b"echo");
+    }
+}
@@ -0,0 +1,584 @@
+//! Batch execution of OS operations with dependency ordering.
+//!
+//! [`OperationBatch`] collects operations (possibly of different types, each
+//! with its own executor) together with dependencies between them, then runs
+//! the whole set as one unit:
+//!
+//! - Operations with no unmet dependencies run concurrently
+//! - An operation starts only after all of its dependencies succeeded
+//! - If a dependency fails, everything downstream of it is skipped
+//! - The caller gets one [`BatchResult`] describing every step
+//!
+//! Middleware runs exactly once per operation: each step is executed through
+//! the executor it was queued with, so pass a middleware-wrapped executor
+//! (e.g. from [`ExecutorExt::with_middleware`](crate::middleware::ext::ExecutorExt))
+//! to get security/logging/throttling per step.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::sync::Arc;
+//! use airssys_osl::core::context::{ExecutionContext, SecurityContext};
+//! use airssys_osl::executors::filesystem::FilesystemExecutor;
+//! use airssys_osl::helpers::batch::OperationBatch;
+//! use airssys_osl::operations::filesystem::{DirectoryCreateOperation, FileWriteOperation};
+//!
+//! # async fn example() -> airssys_osl::core::result::OSResult<()> {
+//! let fs = Arc::new(FilesystemExecutor::new());
+//!
+//! let mut batch = OperationBatch::new();
+//! let dir = batch.add(Arc::clone(&fs), DirectoryCreateOperation::new("/tmp/app"));
+//! batch.add_after(
+//!     Arc::clone(&fs),
+//!     FileWriteOperation::new("/tmp/app/config.toml", b"x = 1".to_vec()),
+//!     &[dir],
+//! )?;
+//!
+//! let context = ExecutionContext::new(SecurityContext::new("deployer".to_string()));
+//! let result = batch.execute(&context).await;
+//! assert!(result.is_success());
+//! # Ok(())
+//! # }
+//! ```
+
+// Layer 1: Standard library imports
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+// Layer 2: Third-party crate imports
+use tokio::task::JoinSet;
+
+// Layer 3: Internal module imports
+use crate::core::context::ExecutionContext;
+use crate::core::executor::{ExecutionResult, OSExecutor};
+use crate::core::operation::Operation;
+use crate::core::result::{OSError, OSResult};
+
+/// Identifier of a step within an [`OperationBatch`], returned when queueing.
+pub type BatchStepId = usize;
+
+type StepFuture = Pin<Box<dyn Future<Output = OSResult<ExecutionResult>> + Send>>;
+type StepRunner = Box<dyn FnOnce(ExecutionContext) -> StepFuture + Send>;
+
+struct BatchStep {
+    label: String,
+    dependencies: Vec<BatchStepId>,
+    run: StepRunner,
+}
+
+/// Outcome of a single step after the batch ran.
+#[derive(Debug, Clone)]
+pub enum StepOutcome {
+    /// The operation executed successfully.
+    Succeeded(ExecutionResult),
+
+    /// The operation (or its middleware) returned an error.
+    Failed(OSError),
+
+    /// The operation was not run because a dependency did not succeed.
+    Skipped {
+        /// The dependency that failed or was itself skipped.
+        dependency: BatchStepId,
+    },
+}
+
+impl StepOutcome {
+    /// Returns true if the step succeeded.
+    pub fn is_success(&self) -> bool {
+        matches!(self, StepOutcome::Succeeded(_))
+    }
+}
+
+/// Aggregate result of running an [`OperationBatch`].
+///
+/// Outcomes are indexed by [`BatchStepId`], i.e. in the order steps were queued.
+#[derive(Debug, Clone)]
+pub struct BatchResult {
+    labels: Vec<String>,
+    outcomes: Vec<StepOutcome>,
+}
+
+impl BatchResult {
+    /// Returns true if every step succeeded.
+    pub fn is_success(&self) -> bool {
+        self.outcomes.iter().all(StepOutcome::is_success)
+    }
+
+    /// Returns the outcome of one step.
+    pub fn outcome(&self, id: BatchStepId) -> Option<&StepOutcome> {
+        self.outcomes.get(id)
+    }
+
+    /// Returns the label (operation ID) of one step.
+    pub fn label(&self, id: BatchStepId) -> Option<&str> {
+        self.labels.get(id).map(String::as_str)
+    }
+
+    /// Returns all outcomes in queue order.
+    pub fn outcomes(&self) -> &[StepOutcome] {
+        &self.outcomes
+    }
+
+    /// Number of steps that succeeded.
+    pub fn succeeded(&self) -> usize {
+        self.count(|o| matches!(o, StepOutcome::Succeeded(_)))
+    }
+
+    /// Number of steps that failed.
+    pub fn failed(&self) -> usize {
+        self.count(|o| matches!(o, StepOutcome::Failed(_)))
+    }
+
+    /// Number of steps skipped because of a failed dependency.
+    pub fn skipped(&self) -> usize {
+        self.count(|o| matches!(o, StepOutcome::Skipped { .. }))
+    }
+
+    /// Returns the first failure in queue order, if any.
+    pub fn first_error(&self) -> Option<(BatchStepId, &OSError)> {
+        self.outcomes
+            .iter()
+            .enumerate()
+            .find_map(|(id, o)| match o {
+                StepOutcome::Failed(err) => Some((id, err)),
+                _ => None,
+            })
+    }
+
+    fn count(&self, pred: impl Fn(&StepOutcome) -> bool) -> usize {
+        self.outcomes.iter().filter(|o| pred(o)).count()
+    }
+}
+
+/// A set of operations executed together with dependency ordering.
+///
+/// Dependencies can only refer to steps queued earlier, so a batch can never
+/// contain a cycle.
+#[derive(Default)]
+pub struct OperationBatch {
+    steps: Vec<BatchStep>,
+    max_concurrency: Option<usize>,
+}
+
+impl std::fmt::Debug for OperationBatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OperationBatch")
+            .field(
+                "steps",
+                &self
+                    .steps
+                    .iter()
+                    .map(|s| (&s.label, &s.dependencies))
+                    .collect::<Vec<_>>(),
+            )
+            .field("max_concurrency", &self.max_concurrency)
+            .finish()
+    }
+}
+
+impl OperationBatch {
+    /// Creates an empty batch with unbounded concurrency.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits how many independent operations run at the same time.
+    ///
+    /// A limit of 0 is treated as 1.
+    pub fn with_max_concurrency(mut self, limit: usize) -> Self {
+        self.max_concurrency = Some(limit.max(1));
+        self
+    }
+
+    /// Returns the number of queued steps.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns true if no steps are queued.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Queues an operation with no dependencies.
+    pub fn add<O, E>(&mut self, executor: Arc<E>, operation: O) -> BatchStepId
+    where
+        O: Operation,
+        E: OSExecutor<O>,
+    {
+        self.push(executor, operation, Vec::new())
+    }
+
+    /// Queues an operation that runs only after all `dependencies` succeeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OSError::ConfigurationError` if a dependency does not refer to
+    /// a previously queued step.
+    pub fn add_after<O, E>(
+        &mut self,
+        executor: Arc<E>,
+        operation: O,
+        dependencies: &[BatchStepId],
+    ) -> OSResult<BatchStepId>
+    where
+        O: Operation,
+        E: OSExecutor<O>,
+    {
+        if let Some(unknown) = dependencies.iter().find(|&&d| d >= self.steps.len()) {
+            return Err(OSError::configuration_error(format!(
+                "Batch dependency {} does not refer to an earlier step (have {})",
+                unknown,
+                self.steps.len()
+            )));
+        }
+        let mut dependencies = dependencies.to_vec();
+        dependencies.sort_unstable();
+        dependencies.dedup();
+        Ok(self.push(executor, operation, dependencies))
+    }
+
+    fn push<O, E>(
+        &mut self,
+        executor: Arc<E>,
+        operation: O,
+        dependencies: Vec<BatchStepId>,
+    ) -> BatchStepId
+    where
+        O: Operation,
+        E: OSExecutor<O>,
+    {
+        let label = operation.operation_id();
+        let run: StepRunner = Box::new(move |context: ExecutionContext| {
+            Box::pin(async move { executor.execute(operation, &context).await })
+        });
+        self.steps.push(BatchStep {
+            label,
+            dependencies,
+            run,
+        });
+        self.steps.len() - 1
+    }
+
+    /// Executes the batch and returns the outcome of every step.
+    ///
+    /// Each step receives its own clone of `context`. This method never
+    /// fails as a whole; individual failures are reported per step.
+    pub async fn execute(self, context: &ExecutionContext) -> BatchResult {
+        let step_count = self.steps.len();
+        let limit = self.max_concurrency.unwrap_or(usize::MAX);
+
+        let mut labels = Vec::with_capacity(step_count);
+        let mut runners = Vec::with_capacity(step_count);
+        let mut pending = Vec::with_capacity(step_count);
+        let mut dependents: Vec<Vec<BatchStepId>> = vec![Vec::new(); step_count];
+        for (id, step) in self.steps.into_iter().enumerate() {
+            for &dep in &step.dependencies {
+                dependents[dep].push(id);
+            }
+            pending.push(step.dependencies.len());
+            labels.push(step.label);
+            runners.push(Some(step.run));
+        }
+
+        let mut outcomes: Vec<Option<StepOutcome>> = vec![None; step_count];
+        let mut ready: VecDeque<BatchStepId> =
+            (0..step_count).filter(|&id| pending[id] == 0).collect();
+        let mut running = JoinSet::new();
+        let mut task_steps = HashMap::new();
+
+        loop {
+            while running.len() < limit {
+                let Some(id) = ready.pop_front() else { break };
+                // Steps not yet started are failed once the batch is cancelled
+                if context.is_cancelled() {
+                    outcomes[id] = Some(StepOutcome::Failed(OSError::cancelled(format!(
+                        "batch step '{}'",
+                        labels[id]
+                    ))));
+                    skip_dependents(id, &dependents, &mut outcomes);
+                    continue;
+                }
+                if let Some(run) = runners[id].take() {
+                    let handle = running.spawn(run(context.clone()));
+                    task_steps.insert(handle.id(), id);
+                }
+            }
+
+            let Some(joined) = running.join_next_with_id().await else {
+                break;
+            };
+            let (id, result) = match joined {
+                Ok((task, result)) => (task_steps.remove(&task), result),
+                Err(err) => (
+                    task_steps.remove(&err.id()),
+                    Err(OSError::execution_failed(format!(
+                        "Batch step aborted: {}",
+                        err
+                    ))),
+                ),
+            };
+            let Some(id) = id else { continue };
+
+            match result {
+                Ok(output) => {
+                    outcomes[id] = Some(StepOutcome::Succeeded(output));
+                    for &next in &dependents[id] {
+                        pending[next] -= 1;
+                        if pending[next] == 0 && outcomes[next].is_none() {
+                            ready.push_back(next);
+                        }
+                    }
+                }
+                Err(err) => {
+                    outcomes[id] = Some(StepOutcome::Failed(err));
+                    skip_dependents(id, &dependents, &mut outcomes);
+                }
+            }
+        }
+
+        BatchResult {
+            labels,
+            outcomes: outcomes
+                .into_iter()
+                .enumerate()
+                .map(|(id, outcome)| {
+                    // Unreachable for well-formed batches; report rather than panic
+                    outcome.unwrap_or_else(|| {
+                        StepOutcome::Failed(OSError::execution_failed(format!(
+                            "Batch step {} was never scheduled",
+                            id
+                        )))
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Marks every transitive dependent of `failed` as skipped.
+fn skip_dependents(
+    failed: BatchStepId,
+    dependents: &[Vec<BatchStepId>],
+    outcomes: &mut [Option<StepOutcome>],
+) {
+    let mut stack = vec![failed];
+    while let Some(id) = stack.pop() {
+        for &next in &dependents[id] {
+            if outcomes[next].is_none() {
+                outcomes[next] = Some(StepOutcome::Skipped { dependency: id });
+                stack.push(next);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::core::context::SecurityContext;
+    use crate::core::operation::{OperationType, Permission};
+    use chrono::{DateTime, Utc};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    #[derive(Debug, Clone)]
+    struct TestOp {
+        name: &'static str,
+        fail: bool,
+        delay_ms: u64,
+        created_at: DateTime<Utc>,
+    }
+
+    impl TestOp {
+        fn new(name: &'static str) -> Self {
+            Self {
+                name,
+                fail: false,
+                delay_ms: 0,
+                created_at: Utc::now(),
+            }
+        }
+
+        fn failing(mut self) -> Self {
+            self.fail = true;
+            self
+        }
+
+        fn delayed(mut self, ms: u64) -> Self {
+            self.delay_ms = ms;
+            self
+        }
+    }
+
+    impl Operation for TestOp {
+        fn operation_type(&self) -> OperationType {
+            OperationType::Utility
+        }
+
+        fn required_permissions(&self) -> Vec<Permission> {
+            Vec::new()
+        }
+
+        fn created_at(&self) -> DateTime<Utc> {
+            self.created_at
+        }
+
+        fn operation_id(&self) -> String {
+            self.name.to_string()
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingExecutor {
+        log: Mutex<Vec<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl OSExecutor<TestOp> for RecordingExecutor {
+        fn name(&self) -> &str {
+            "recording"
+        }
+
+        fn supported_operation_types(&self) -> Vec<OperationType> {
+            vec![OperationType::Utility]
+        }
+
+        async fn execute(
+            &self,
+            operation: TestOp,
+            _context: &ExecutionContext,
+        ) -> OSResult<ExecutionResult> {
+            tokio::time::sleep(Duration::from_millis(operation.delay_ms)).await;
+            self.log.lock().unwrap().push(operation.name.to_string());
+            if operation.fail {
+                Err(OSError::execution_failed(operation.name))
+            } else {
+                Ok(ExecutionResult::success(operation.name.as_bytes().to_vec()))
+            }
+        }
+    }
+
+    fn context() -> ExecutionContext {
+        ExecutionContext::new(SecurityContext::new("tester".to_string()))
+    }
+
+    #[tokio::test]
+    async fn test_dependencies_run_in_order() {
+        let exec = Arc::new(RecordingExecutor::default());
+        let mut batch = OperationBatch::new();
+        let a = batch.add(Arc::clone(&exec), TestOp::new("a").delayed(20));
+        let b = batch
+            .add_after(Arc::clone(&exec), TestOp::new("b"), &[a])
+            .unwrap();
+        batch
+            .add_after(Arc::clone(&exec), TestOp::new("c"), &[a, b])
+            .unwrap();
+
+        let result = batch.execute(&context()).await;
+
+        assert!(result.is_success());
+        assert_eq!(result.succeeded(), 3);
+        assert_eq!(*exec.log.lock().unwrap(), vec!["a", "b", "c"]);
+        assert_eq!(result.label(b), Some("b"));
+    }
+
+    #[tokio::test]
+    async fn test_independent_operations_run_concurrently() {
+        let exec = Arc::new(RecordingExecutor::default());
+        let mut batch = OperationBatch::new();
+        batch.add(Arc::clone(&exec), TestOp::new("slow").delayed(50));
+        batch.add(Arc::clone(&exec), TestOp::new("fast"));
+
+        let result = batch.execute(&context()).await;
+
+        assert!(result.is_success());
+        // The fast step finished while the slow one was still running
+        assert_eq!(*exec.log.lock().unwrap(), vec!["fast", "slow"]);
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrency_serializes() {
+        let exec = Arc::new(RecordingExecutor::default());
+        let mut batch = OperationBatch::new().with_max_concurrency(1);
+        batch.add(Arc::clone(&exec), TestOp::new("slow").delayed(30));
+        batch.add(Arc::clone(&exec), TestOp::new("fast"));
+
+        batch.execute(&context()).await;
+
+        assert_eq!(*exec.log.lock().unwrap(), vec!["slow", "fast"]);
+    }
+
+    #[tokio::test]
+    async fn test_failure_skips_dependents() {
+        let exec = Arc::new(RecordingExecutor::default());
+        let mut batch = OperationBatch::new();
+        let a = batch.add(Arc::clone(&exec), TestOp::new("a").failing());
+        let b = batch
+            .add_after(Arc::clone(&exec), TestOp::new("b"), &[a])
+            .unwrap();
+        let c = batch
+            .add_after(Arc::clone(&exec), TestOp::new("c"), &[b])
+            .unwrap();
+        let d = batch.add(Arc::clone(&exec), TestOp::new("d"));
+
+        let result = batch.execute(&context()).await;
+
+        assert!(!result.is_success());
+        assert_eq!(
+            (result.succeeded(), result.failed(), result.skipped()),
+            (1, 1, 2)
+        );
+        assert_eq!(result.first_error().map(|(id, _)| id), Some(a));
+        assert!(matches!(
+            result.outcome(c),
+            Some(StepOutcome::Skipped { dependency }) if *dependency == b
+        ));
+        assert!(result.outcome(d).unwrap().is_success());
+        assert!(!exec.log.lock().unwrap().contains(&"b".to_string()));
+    }
+
+    #[test]
+    fn test_add_after_rejects_unknown_dependency() {
+        let exec = Arc::new(RecordingExecutor::default());
+        let mut batch = OperationBatch::new();
+        let result = batch.add_after(exec, TestOp::new("a"), &[0]);
+
+        assert!(matches!(result, Err(OSError::ConfigurationError { .. })));
+        assert!(batch.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_context_fails_unstarted_steps() {
+        let exec = Arc::new(RecordingExecutor::default());
+        let mut batch = OperationBatch::new();
+        let a = batch.add(Arc::clone(&exec), TestOp::new("a"));
+        let b = batch
+            .add_after(Arc::clone(&exec), TestOp::new("b"), &[a])
+            .unwrap();
+
+        let context = context();
+        context.cancellation.cancel();
+        let result = batch.execute(&context).await;
+
+        assert!(matches!(
+            result.outcome(a),
+            Some(StepOutcome::Failed(err)) if err.is_cancelled()
+        ));
+        assert!(matches!(
+            result.outcome(b),
+            Some(StepOutcome::Skipped { dependency }) if *dependency == a
+        ));
+        assert!(exec.log.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_empty_batch() {
+        let result = OperationBatch::new().execute(&context()).await;
+        assert!(result.is_success());
+        assert!(result.outcomes().is_empty());
+    }
+}
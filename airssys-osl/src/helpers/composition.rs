@@ -118,21 +118,23 @@
 
 // Layer 1: Standard library imports
 use std::marker::PhantomData;
+use std::time::Duration;
 
 // Layer 2: Third-party crate imports
 // (none needed)
 
 // Layer 3: Internal module imports
-use crate::core::context::{ExecutionContext, SecurityContext};
-use crate::core::executor::OSExecutor;
+use crate::core::executor::{ExecutionResult, OSExecutor};
 use crate::core::middleware::Middleware;
 use crate::core::operation::Operation;
-use crate::core::result::OSResult;
+use crate::core::result::{OSError, OSResult};
 use crate::executors::filesystem::FilesystemExecutor;
 use crate::executors::network::NetworkExecutor;
 use crate::executors::process::ProcessExecutor;
+use crate::middleware::controls::{MiddlewareSafetyPolicy, OperationControls};
 use crate::middleware::ext::{ExecutorExt, MiddlewareExecutor};
-use crate::middleware::security::SecurityMiddleware;
+use crate::middleware::security::policy::SecurityPolicy;
+use crate::middleware::security::{SecurityMiddleware, SecurityMiddlewareBuilder};
 use crate::operations::filesystem::{
     DirectoryCreateOperation, FileDeleteOperation, FileReadOperation, FileWriteOperation,
 };
@@ -271,6 +273,7 @@ where
     E: OSExecutor<O>,
 {
     executor: E,
+    controls: OperationControls,
     _phantom: PhantomData<O>,
 }
 
@@ -283,8 +286,13 @@ where
     ///
     /// This is typically called by helper builders, not user code.
     pub fn new(executor: E) -> Self {
+        Self::with_controls(executor, OperationControls::new())
+    }
+
+    fn with_controls(executor: E, controls: OperationControls) -> Self {
         Self {
             executor,
+            controls,
             _phantom: PhantomData,
         }
     }
@@ -295,6 +303,141 @@ where
     pub fn executor(&self) -> &E {
         &self.executor
     }
+
+    /// Get the per-operation controls applied by this helper.
+    pub fn controls(&self) -> &OperationControls {
+        &self.controls
+    }
+
+    /// Skip the logger middleware for operations run by this helper.
+    ///
+    /// # Errors
+    ///
+    /// `OSError::ConfigurationError` if the safety policy protects the logger.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use airssys_osl::helpers::composition::*;
+    /// use airssys_osl::middleware::security::SecurityMiddleware;
+    ///
+    /// # async fn example() -> airssys_osl::core::result::OSResult<()> {
+    /// let reader = FileHelper::builder()
+    ///     .with_security(SecurityMiddleware::default())
+    ///     .skip_logging()?;
+    ///
+    /// let data = reader.read("/var/cache/hot.bin", "svc").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn skip_logging(mut self) -> OSResult<Self> {
+        self.controls.skip_logging()?;
+        Ok(self)
+    }
+
+    /// Skip the throttle middleware for operations run by this helper.
+    ///
+    /// # Errors
+    ///
+    /// `OSError::ConfigurationError` if the safety policy protects throttling.
+    pub fn skip_throttling(mut self) -> OSResult<Self> {
+        self.controls.skip_throttling()?;
+        Ok(self)
+    }
+
+    /// Skip the middleware called `name` for operations run by this helper.
+    ///
+    /// Security middleware can never be skipped.
+    ///
+    /// # Errors
+    ///
+    /// `OSError::ConfigurationError` if the safety policy protects `name`.
+    pub fn skip_middleware(mut self, name: impl Into<String>) -> OSResult<Self> {
+        self.controls.skip(name)?;
+        Ok(self)
+    }
+
+    /// Validate skips against `policy` instead of the default safety policy.
+    ///
+    /// # Errors
+    ///
+    /// `OSError::ConfigurationError` if an already requested skip is protected
+    /// by `policy`.
+    pub fn with_safety_policy(mut self, policy: MiddlewareSafetyPolicy) -> OSResult<Self> {
+        self.controls.set_safety_policy(policy)?;
+        Ok(self)
+    }
+
+    /// Fail operations that run longer than `timeout` with `OSError::TimedOut`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.controls.set_timeout(timeout);
+        self
+    }
+
+    /// Execute `operation` as `user` under this helper's controls.
+    async fn run(&self, operation: O, user: String) -> OSResult<ExecutionResult>
+    where
+        E: Send + Sync,
+        O: Send,
+    {
+        let context = self.controls.context_for(user);
+        let operation_type = operation.operation_type();
+        let future = self.executor.execute(operation, &context);
+        self.controls
+            .run(&context, operation_type.as_str(), future)
+            .await
+    }
+}
+
+impl<O, E> ComposedHelper<O, E>
+where
+    O: Operation,
+    E: OSExecutor<O> + Send + Sync + std::fmt::Debug,
+{
+    /// Add a security policy evaluated on top of the existing pipeline.
+    ///
+    /// The policy runs in its own security layer, so it can only narrow
+    /// what the pipeline allows. As with any security middleware, operation
+    /// types the policy does not apply to are denied.
+    ///
+    /// # Errors
+    ///
+    /// `OSError::ConfigurationError` if the security layer cannot be built.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use airssys_osl::helpers::composition::*;
+    /// use airssys_osl::middleware::security::{AccessControlList, AclEntry, AclPolicy};
+    /// use airssys_osl::middleware::security::SecurityMiddleware;
+    ///
+    /// # async fn example() -> airssys_osl::core::result::OSResult<()> {
+    /// let acl = AccessControlList::new().add_entry(AclEntry::new(
+    ///     "alice".to_string(),
+    ///     "/data/*".to_string(),
+    ///     vec!["read".to_string()],
+    ///     AclPolicy::Allow,
+    /// ));
+    ///
+    /// let reader = FileHelper::builder()
+    ///     .with_security(SecurityMiddleware::default())
+    ///     .with_extra_policy(acl)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_extra_policy<P>(
+        self,
+        policy: P,
+    ) -> OSResult<ComposedHelper<O, MiddlewareExecutor<E, SecurityMiddleware, O>>>
+    where
+        P: SecurityPolicy,
+    {
+        let security = SecurityMiddlewareBuilder::new()
+            .add_policy(Box::new(policy))
+            .build()
+            .map_err(OSError::configuration_error)?;
+        Ok(self.with_security(security))
+    }
 }
 
 // Implement HelperPipeline for ComposedHelper to enable chaining
@@ -309,14 +452,14 @@ where
         self,
         middleware: SecurityMiddleware,
     ) -> ComposedHelper<O, MiddlewareExecutor<E, SecurityMiddleware, O>> {
-        ComposedHelper::new(self.executor.with_middleware(middleware))
+        ComposedHelper::with_controls(self.executor.with_middleware(middleware), self.controls)
     }
 
     fn with_middleware<M>(self, middleware: M) -> ComposedHelper<O, MiddlewareExecutor<E, M, O>>
     where
         M: Middleware<O> + Send + Sync + std::fmt::Debug + 'static,
     {
-        ComposedHelper::new(self.executor.with_middleware(middleware))
+        ComposedHelper::with_controls(self.executor.with_middleware(middleware), self.controls)
     }
 
     fn executor(&self) -> &E {
@@ -469,8 +612,7 @@ where
     ) -> OSResult<Vec<u8>> {
         let path_str = path.as_ref().display().to_string();
         let operation = FileReadOperation::new(path_str);
-        let context = ExecutionContext::new(SecurityContext::new(user.into()));
-        let result = self.executor.execute(operation, &context).await?;
+        let result = self.run(operation, user.into()).await?;
         Ok(result.output)
     }
 }
@@ -522,8 +664,7 @@ where
     ) -> OSResult<Vec<u8>> {
         let path_str = path.as_ref().display().to_string();
         let operation = FileWriteOperation::new(path_str, data);
-        let context = ExecutionContext::new(SecurityContext::new(user.into()));
-        let result = self.executor.execute(operation, &context).await?;
+        let result = self.run(operation, user.into()).await?;
         Ok(result.output)
     }
 }
@@ -573,8 +714,7 @@ where
     ) -> OSResult<()> {
         let path_str = path.as_ref().display().to_string();
         let operation = DirectoryCreateOperation::new(path_str);
-        let context = ExecutionContext::new(SecurityContext::new(user.into()));
-        self.executor.execute(operation, &context).await?;
+        self.run(operation, user.into()).await?;
         Ok(())
     }
 }
@@ -624,8 +764,7 @@ where
     ) -> OSResult<()> {
         let path_str = path.as_ref().display().to_string();
         let operation = FileDeleteOperation::new(path_str);
-        let context = ExecutionContext::new(SecurityContext::new(user.into()));
-        self.executor.execute(operation, &context).await?;
+        self.run(operation, user.into()).await?;
         Ok(())
     }
 }
@@ -679,8 +818,7 @@ where
         user: impl Into<String>,
     ) -> OSResult<Vec<u8>> {
         let operation = ProcessSpawnOperation::new(command.into()).with_args(args);
-        let context = ExecutionContext::new(SecurityContext::new(user.into()));
-        let result = self.executor.execute(operation, &context).await?;
+        let result = self.run(operation, user.into()).await?;
         Ok(result.output)
     }
 }
@@ -725,8 +863,7 @@ where
     /// ```
     pub async fn kill(&self, pid: u32, user: impl Into<String>) -> OSResult<()> {
         let operation = ProcessKillOperation::new(pid);
-        let context = ExecutionContext::new(SecurityContext::new(user.into()));
-        self.executor.execute(operation, &context).await?;
+        self.run(operation, user.into()).await?;
         Ok(())
     }
 }
@@ -777,8 +914,7 @@ where
         user: impl Into<String>,
     ) -> OSResult<()> {
         let operation = ProcessSignalOperation::new(pid, signal);
-        let context = ExecutionContext::new(SecurityContext::new(user.into()));
-        self.executor.execute(operation, &context).await?;
+        self.run(operation, user.into()).await?;
         Ok(())
     }
 }
@@ -829,8 +965,7 @@ where
         user: impl Into<String>,
     ) -> OSResult<Vec<u8>> {
         let operation = NetworkConnectOperation::new(address.into());
-        let context = ExecutionContext::new(SecurityContext::new(user.into()));
-        let result = self.executor.execute(operation, &context).await?;
+        let result = self.run(operation, user.into()).await?;
         Ok(result.output)
     }
 }
@@ -879,8 +1014,7 @@ where
         user: impl Into<String>,
     ) -> OSResult<Vec<u8>> {
         let operation = NetworkListenOperation::new(address.into());
-        let context = ExecutionContext::new(SecurityContext::new(user.into()));
-        let result = self.executor.execute(operation, &context).await?;
+        let result = self.run(operation, user.into()).await?;
         Ok(result.output)
     }
 }
@@ -929,8 +1063,7 @@ where
         user: impl Into<String>,
     ) -> OSResult<Vec<u8>> {
         let operation = NetworkSocketOperation::new(socket_type.into());
-        let context = ExecutionContext::new(SecurityContext::new(user.into()));
-        let result = self.executor.execute(operation, &context).await?;
+        let result = self.run(operation, user.into()).await?;
         Ok(result.output)
     }
 }
@@ -196,6 +196,7 @@ pub(crate) mod context; // Security context building utilities
 pub(crate) mod factories;
 
 // Module declarations for simple helpers and composition
+pub mod batch; // Dependency-ordered batch execution
 pub mod composition;
 pub(crate) mod simple; // Phase 2-4: Simple helper functions // Phase 8: Trait-based composition layer
 
@@ -210,3 +211,6 @@ pub use self::simple::*;
 pub use self::composition::{
     ComposedHelper, FileHelper, HelperPipeline, NetworkHelper, ProcessHelper,
 };
+
+// Re-export batch execution
+pub use self::batch::{BatchResult, BatchStepId, OperationBatch, StepOutcome};
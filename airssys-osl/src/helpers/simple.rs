@@ -4,6 +4,9 @@
 //! via middleware integration. Each function has two variants:
 //! - Simple: Uses default security middleware (Level 1 API)
 //! - With middleware: Accepts custom middleware (Level 2 API)
+//!
+//! Executors are selected through [`ExecutorRegistry::global()`], so custom
+//! executors registered there are used by every helper.
 
 // Layer 1: Standard library imports
 use std::path::Path;
@@ -15,13 +18,12 @@ use crate::core::context::ExecutionContext;
 use crate::core::executor::OSExecutor;
 use crate::core::middleware::Middleware;
 use crate::core::result::OSResult;
-use crate::executors::filesystem::FilesystemExecutor;
-use crate::executors::network::NetworkExecutor;
-use crate::executors::process::ProcessExecutor;
+use crate::executors::registry::ExecutorRegistry;
 use crate::helpers::context::build_security_context;
 use crate::middleware::ext::ExecutorExt;
 use crate::operations::filesystem::{
-    DirectoryCreateOperation, FileDeleteOperation, FileReadOperation, FileWriteOperation,
+    AtomicFileWriteOperation, DirectoryCreateOperation, FileDeleteOperation, FileReadOperation,
+    FileWriteOperation,
 };
 use crate::operations::network::{
     NetworkConnectOperation, NetworkListenOperation, NetworkSocketOperation,
@@ -34,7 +36,7 @@ use crate::operations::process::{
 use super::factories::default_security_middleware;
 
 // ============================================================================
-// Filesystem Helpers (4 functions × 2 variants = 8 total)
+// Filesystem Helpers (5 functions × 2 variants = 10 total)
 // ============================================================================
 
 /// Read file contents with default security middleware.
@@ -113,7 +115,9 @@ where
     let security_context = build_security_context(&operation, &user_str);
     let context = ExecutionContext::new(security_context);
 
-    let executor = FilesystemExecutor::new().with_middleware(middleware);
+    let executor = ExecutorRegistry::global()
+        .resolve(&operation)?
+        .with_middleware(middleware);
 
     let result = executor.execute(operation, &context).await?;
     Ok(result.output)
@@ -191,7 +195,83 @@ where
     let security_context = build_security_context(&operation, &user_str);
     let context = ExecutionContext::new(security_context);
 
-    let executor = FilesystemExecutor::new().with_middleware(middleware);
+    let executor = ExecutorRegistry::global()
+        .resolve(&operation)?
+        .with_middleware(middleware);
+
+    executor.execute(operation, &context).await?;
+    Ok(())
+}
+
+/// Atomically replace a file's contents with default security middleware.
+///
+/// Unlike [`write_file()`], a crash mid-write never leaves a torn file: the
+/// data goes to a temporary file in the same directory, is flushed to disk,
+/// and is renamed over the target.
+///
+/// # Security
+///
+/// Same policy as [`write_file()`]: requires write permission on `path`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use airssys_osl::helpers::*;
+///
+/// # async fn example() -> airssys_osl::core::result::OSResult<()> {
+/// write_file_atomic("/tmp/state.json", b"{}".to_vec(), "admin").await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Custom Security
+///
+/// For custom middleware, use [`write_file_atomic_with_middleware()`].
+pub async fn write_file_atomic<P: AsRef<Path>>(
+    path: P,
+    data: Vec<u8>,
+    user: impl Into<String>,
+) -> OSResult<()> {
+    write_file_atomic_with_middleware(path, data, user, default_security_middleware()).await
+}
+
+/// Atomically replace a file's contents with custom middleware.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use airssys_osl::helpers::*;
+/// use airssys_osl::middleware::security::*;
+///
+/// # async fn example() -> airssys_osl::core::result::OSResult<()> {
+/// let security = SecurityMiddlewareBuilder::new()
+///     .build()
+///     .expect("Failed to build security middleware");
+///
+/// write_file_atomic_with_middleware("/tmp/state.json", b"{}".to_vec(), "admin", security)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn write_file_atomic_with_middleware<P, M>(
+    path: P,
+    data: Vec<u8>,
+    user: impl Into<String>,
+    middleware: M,
+) -> OSResult<()>
+where
+    P: AsRef<Path>,
+    M: Middleware<AtomicFileWriteOperation>,
+{
+    let path_str = path.as_ref().display().to_string();
+    let operation = AtomicFileWriteOperation::new(path_str, data);
+    let user_str = user.into();
+    let security_context = build_security_context(&operation, &user_str);
+    let context = ExecutionContext::new(security_context);
+
+    let executor = ExecutorRegistry::global()
+        .resolve(&operation)?
+        .with_middleware(middleware);
 
     executor.execute(operation, &context).await?;
     Ok(())
@@ -262,7 +342,9 @@ where
     let security_context = build_security_context(&operation, &user_str);
     let context = ExecutionContext::new(security_context);
 
-    let executor = FilesystemExecutor::new().with_middleware(middleware);
+    let executor = ExecutorRegistry::global()
+        .resolve(&operation)?
+        .with_middleware(middleware);
 
     executor.execute(operation, &context).await?;
     Ok(())
@@ -333,7 +415,9 @@ where
     let security_context = build_security_context(&operation, &user_str);
     let context = ExecutionContext::new(security_context);
 
-    let executor = FilesystemExecutor::new().with_middleware(middleware);
+    let executor = ExecutorRegistry::global()
+        .resolve(&operation)?
+        .with_middleware(middleware);
 
     executor.execute(operation, &context).await?;
     Ok(())
@@ -420,7 +504,9 @@ where
     let security_context = build_security_context(&operation, &user_str);
     let context = ExecutionContext::new(security_context);
 
-    let executor = ProcessExecutor::new("helper_executor").with_middleware(middleware);
+    let executor = ExecutorRegistry::global()
+        .resolve(&operation)?
+        .with_middleware(middleware);
 
     let result = executor.execute(operation, &context).await?;
     Ok(result.output)
@@ -489,7 +575,9 @@ where
     let security_context = build_security_context(&operation, &user_str);
     let context = ExecutionContext::new(security_context);
 
-    let executor = ProcessExecutor::new("helper_executor").with_middleware(middleware);
+    let executor = ExecutorRegistry::global()
+        .resolve(&operation)?
+        .with_middleware(middleware);
 
     executor.execute(operation, &context).await?;
     Ok(())
@@ -559,7 +647,9 @@ where
     let security_context = build_security_context(&operation, &user_str);
     let context = ExecutionContext::new(security_context);
 
-    let executor = ProcessExecutor::new("helper_executor").with_middleware(middleware);
+    let executor = ExecutorRegistry::global()
+        .resolve(&operation)?
+        .with_middleware(middleware);
 
     executor.execute(operation, &context).await?;
     Ok(())
@@ -635,7 +725,9 @@ where
     let security_context = build_security_context(&operation, &user_str);
     let context = ExecutionContext::new(security_context);
 
-    let executor = NetworkExecutor::new("helper_executor").with_middleware(middleware);
+    let executor = ExecutorRegistry::global()
+        .resolve(&operation)?
+        .with_middleware(middleware);
 
     let result = executor.execute(operation, &context).await?;
     Ok(result.output)
@@ -704,7 +796,9 @@ where
     let security_context = build_security_context(&operation, &user_str);
     let context = ExecutionContext::new(security_context);
 
-    let executor = NetworkExecutor::new("helper_executor").with_middleware(middleware);
+    let executor = ExecutorRegistry::global()
+        .resolve(&operation)?
+        .with_middleware(middleware);
 
     let result = executor.execute(operation, &context).await?;
     Ok(result.output)
@@ -776,7 +870,9 @@ where
     let security_context = build_security_context(&operation, &user_str);
     let context = ExecutionContext::new(security_context);
 
-    let executor = NetworkExecutor::new("helper_executor").with_middleware(middleware);
+    let executor = ExecutorRegistry::global()
+        .resolve(&operation)?
+        .with_middleware(middleware);
 
     let result = executor.execute(operation, &context).await?;
     Ok(result.output)
@@ -0,0 +1,301 @@
+//! Per-operation middleware controls.
+//!
+//! Composed helpers normally run every middleware in their pipeline. This
+//! module lets a caller relax that for a single helper - skipping logging on
+//! a hot path, or bounding how long an operation may run - while a
+//! [`MiddlewareSafetyPolicy`] guarantees that protected middleware (security,
+//! always) keeps running.
+//!
+//! Skips travel to the pipeline through [`ExecutionContext`] metadata under
+//! [`SKIP_MIDDLEWARE_METADATA_KEY`]. [`MiddlewareExecutor`] consults
+//! [`is_skip_requested`] before applying its middleware, and that check
+//! refuses to skip [`NON_SKIPPABLE_MIDDLEWARE`] no matter what the metadata
+//! says, so a hand-built context cannot bypass security either.
+//!
+//! [`MiddlewareExecutor`]: crate::middleware::ext::MiddlewareExecutor
+
+// Layer 1: Standard library imports
+use std::collections::{BTreeSet, HashSet};
+use std::future::Future;
+use std::time::Duration;
+
+// Layer 2: Third-party imports
+// (none)
+
+// Layer 3: Internal module imports
+use crate::core::context::{ExecutionContext, SecurityContext};
+use crate::core::result::{OSError, OSResult};
+
+/// Context metadata key listing the middleware to skip, comma separated.
+pub const SKIP_MIDDLEWARE_METADATA_KEY: &str = "osl.skip_middleware";
+
+/// Middleware names that can never be skipped, regardless of policy.
+pub const NON_SKIPPABLE_MIDDLEWARE: &[&str] = &["security"];
+
+/// Name of the logger middleware, as reported by `Middleware::name`.
+const LOGGER_MIDDLEWARE: &str = "logger";
+
+/// Name of the throttle middleware, as reported by `Middleware::name`.
+const THROTTLE_MIDDLEWARE: &str = "throttle";
+
+/// Returns true if `context` asks to skip the middleware called `name`.
+///
+/// Always false for [`NON_SKIPPABLE_MIDDLEWARE`].
+pub fn is_skip_requested(context: &ExecutionContext, name: &str) -> bool {
+    if NON_SKIPPABLE_MIDDLEWARE.contains(&name) {
+        return false;
+    }
+    context
+        .get_metadata(SKIP_MIDDLEWARE_METADATA_KEY)
+        .is_some_and(|skipped| skipped.split(',').any(|entry| entry.trim() == name))
+}
+
+/// Decides which middleware a caller may skip.
+///
+/// Security middleware is always protected. Deployments can protect more
+/// (for example the logger, where every operation must leave an audit
+/// trail) with [`protect`](Self::protect).
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_osl::middleware::controls::MiddlewareSafetyPolicy;
+///
+/// let policy = MiddlewareSafetyPolicy::new().protect("logger");
+/// assert!(policy.validate_skip("security").is_err());
+/// assert!(policy.validate_skip("logger").is_err());
+/// assert!(policy.validate_skip("throttle").is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct MiddlewareSafetyPolicy {
+    protected: HashSet<String>,
+}
+
+impl MiddlewareSafetyPolicy {
+    /// Creates a policy protecting only [`NON_SKIPPABLE_MIDDLEWARE`].
+    pub fn new() -> Self {
+        Self {
+            protected: NON_SKIPPABLE_MIDDLEWARE
+                .iter()
+                .map(|name| (*name).to_string())
+                .collect(),
+        }
+    }
+
+    /// Additionally protects the middleware called `name`.
+    pub fn protect(mut self, name: impl Into<String>) -> Self {
+        self.protected.insert(name.into());
+        self
+    }
+
+    /// Returns true if the middleware called `name` may be skipped.
+    pub fn is_skippable(&self, name: &str) -> bool {
+        !self.protected.contains(name)
+    }
+
+    /// Fails with a configuration error if `name` may not be skipped.
+    pub fn validate_skip(&self, name: &str) -> OSResult<()> {
+        if self.is_skippable(name) {
+            return Ok(());
+        }
+        Err(OSError::configuration_error(format!(
+            "middleware '{name}' is protected by the safety policy and cannot be skipped"
+        )))
+    }
+}
+
+impl Default for MiddlewareSafetyPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Per-operation adjustments applied by a composed helper.
+///
+/// Holds the middleware to skip and an optional timeout. Every skip is
+/// checked against the controls' [`MiddlewareSafetyPolicy`] when it is
+/// requested, so an invalid combination fails while the helper is being
+/// built rather than when it runs.
+#[derive(Debug, Clone, Default)]
+pub struct OperationControls {
+    safety: MiddlewareSafetyPolicy,
+    skipped: BTreeSet<String>,
+    timeout: Option<Duration>,
+}
+
+impl OperationControls {
+    /// Creates controls that skip nothing and never time out.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the safety policy, re-validating any skips already requested.
+    pub fn set_safety_policy(&mut self, safety: MiddlewareSafetyPolicy) -> OSResult<()> {
+        for name in &self.skipped {
+            safety.validate_skip(name)?;
+        }
+        self.safety = safety;
+        Ok(())
+    }
+
+    /// Requests that the middleware called `name` be skipped.
+    pub fn skip(&mut self, name: impl Into<String>) -> OSResult<()> {
+        let name = name.into();
+        self.safety.validate_skip(&name)?;
+        self.skipped.insert(name);
+        Ok(())
+    }
+
+    /// Requests that the logger middleware be skipped.
+    pub fn skip_logging(&mut self) -> OSResult<()> {
+        self.skip(LOGGER_MIDDLEWARE)
+    }
+
+    /// Requests that the throttle middleware be skipped.
+    pub fn skip_throttling(&mut self) -> OSResult<()> {
+        self.skip(THROTTLE_MIDDLEWARE)
+    }
+
+    /// Bounds how long each operation may run.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+    }
+
+    /// Returns the names of the skipped middleware, in sorted order.
+    pub fn skipped(&self) -> impl Iterator<Item = &str> + '_ {
+        self.skipped.iter().map(String::as_str)
+    }
+
+    /// Returns the configured timeout, if any.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Returns the safety policy skips are validated against.
+    pub fn safety_policy(&self) -> &MiddlewareSafetyPolicy {
+        &self.safety
+    }
+
+    /// Builds the execution context for an operation run by `principal`.
+    pub fn context_for(&self, principal: impl Into<String>) -> ExecutionContext {
+        let context = ExecutionContext::new(SecurityContext::new(principal.into()));
+        if self.skipped.is_empty() {
+            return context;
+        }
+        let skipped = self.skipped().collect::<Vec<_>>().join(",");
+        context.with_metadata(SKIP_MIDDLEWARE_METADATA_KEY.to_string(), skipped)
+    }
+
+    /// Runs `future` under the configured timeout.
+    ///
+    /// On timeout the future is dropped, the context's cancellation token is
+    /// cancelled so work sharing the context stops too, and
+    /// [`OSError::TimedOut`] is returned.
+    pub async fn run<F, T>(
+        &self,
+        context: &ExecutionContext,
+        operation: &str,
+        future: F,
+    ) -> OSResult<T>
+    where
+        F: Future<Output = OSResult<T>>,
+    {
+        let Some(timeout) = self.timeout else {
+            return future.await;
+        };
+        match tokio::time::timeout(timeout, future).await {
+            Ok(result) => result,
+            Err(_) => {
+                context.cancellation.cancel();
+                let timeout_ms = u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX);
+                Err(OSError::timed_out(operation, timeout_ms))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_security_is_never_skippable() {
+        let policy = MiddlewareSafetyPolicy::new();
+        assert!(!policy.is_skippable("security"));
+        assert!(policy.is_skippable("logger"));
+
+        let mut controls = OperationControls::new();
+        let err = controls.skip("security").unwrap_err();
+        assert!(err.is_configuration_error());
+        assert_eq!(controls.skipped().count(), 0);
+    }
+
+    #[test]
+    fn test_protected_middleware_rejected() {
+        let mut controls = OperationControls::new();
+        controls
+            .set_safety_policy(MiddlewareSafetyPolicy::new().protect("logger"))
+            .unwrap();
+        assert!(controls.skip_logging().is_err());
+        assert!(controls.skip_throttling().is_ok());
+    }
+
+    #[test]
+    fn test_safety_policy_revalidates_existing_skips() {
+        let mut controls = OperationControls::new();
+        controls.skip_logging().unwrap();
+        let result = controls.set_safety_policy(MiddlewareSafetyPolicy::new().protect("logger"));
+        assert!(result.is_err());
+        assert!(controls.safety_policy().is_skippable("logger"));
+    }
+
+    #[test]
+    fn test_context_carries_skips() {
+        let mut controls = OperationControls::new();
+        controls.skip_logging().unwrap();
+        controls.skip_throttling().unwrap();
+
+        let context = controls.context_for("alice");
+        assert_eq!(context.principal(), "alice");
+        assert!(is_skip_requested(&context, "logger"));
+        assert!(is_skip_requested(&context, "throttle"));
+        assert!(!is_skip_requested(&context, "metrics"));
+    }
+
+    #[test]
+    fn test_forged_metadata_cannot_skip_security() {
+        let context = ExecutionContext::new(SecurityContext::new("mallory".to_string()))
+            .with_metadata(
+                SKIP_MIDDLEWARE_METADATA_KEY.to_string(),
+                "security,logger".to_string(),
+            );
+        assert!(!is_skip_requested(&context, "security"));
+        assert!(is_skip_requested(&context, "logger"));
+    }
+
+    #[tokio::test]
+    async fn test_run_times_out() {
+        let mut controls = OperationControls::new();
+        controls.set_timeout(Duration::from_millis(10));
+        let context = controls.context_for("alice");
+
+        let result = controls
+            .run(&context, "sleep", async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(())
+            })
+            .await;
+
+        assert!(result.unwrap_err().is_timed_out());
+        assert!(context.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_run_without_timeout_passes_through() {
+        let controls = OperationControls::new();
+        let context = controls.context_for("alice");
+        let result = controls.run(&context, "noop", async { Ok(7) }).await;
+        assert_eq!(result.unwrap(), 7);
+    }
+}
@@ -21,6 +21,7 @@ use crate::core::executor::{ExecutionResult, OSExecutor};
 use crate::core::middleware::Middleware;
 use crate::core::operation::{Operation, OperationType};
 use crate::core::result::OSResult;
+use crate::middleware::controls::is_skip_requested;
 
 /// Middleware-wrapped executor that applies middleware to operation execution.
 ///
@@ -81,6 +82,11 @@ where
     }
 
     async fn execute(&self, operation: O, context: &ExecutionContext) -> OSResult<ExecutionResult> {
+        // Honour per-operation skips; protected middleware is never skipped
+        if is_skip_requested(context, self.middleware.name()) {
+            return self.executor.execute(operation, context).await;
+        }
+
         // Check if middleware can process this operation
         if !self.middleware.can_process(&operation, context).await {
             // Middleware doesn't apply, execute directly
@@ -343,6 +349,33 @@ mod tests {
         assert!(after_ref.load(std::sync::atomic::Ordering::SeqCst));
     }
 
+    #[tokio::test]
+    async fn test_middleware_executor_honours_skip() {
+        use crate::middleware::controls::SKIP_MIDDLEWARE_METADATA_KEY;
+        use std::io::Write;
+        use tempfile::NamedTempFile;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        write!(temp_file, "test data").unwrap();
+        let file_path = temp_file.path().display().to_string();
+
+        let middleware = MockMiddleware::new();
+        let before_ref = Arc::clone(&middleware.before_called);
+        let wrapped = FilesystemExecutor::new().with_middleware(middleware);
+
+        let context = ExecutionContext::new(SecurityContext::new("test_user".to_string()))
+            .with_metadata(
+                SKIP_MIDDLEWARE_METADATA_KEY.to_string(),
+                "mock_middleware".to_string(),
+            );
+        let result = wrapped
+            .execute(FileReadOperation::new(file_path), &context)
+            .await;
+
+        assert!(result.is_ok());
+        assert!(!before_ref.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
     #[test]
     fn test_middleware_chaining() {
         // Test that multiple middleware can be chained
@@ -6,16 +6,20 @@
 //! # Available Middleware
 //!
 //! - **[`security`]** - Security policy enforcement and access control (Priority 100)
+//! - **[`throttle`]** - Network bandwidth throttling with token buckets (Priority 150)
 //! - **[`logger`]** - Activity logging and audit trail middleware (Priority 200)
 //! - **[`ext`]** - Extension trait for ergonomic middleware composition
+//! - **[`controls`]** - Per-operation skip and timeout controls with a safety policy
 
 // Layer 3: Internal module imports
 // (none for this module)
 
 // Public middleware modules
+pub mod controls;
 pub mod ext;
 pub mod logger;
 pub mod security;
+pub mod throttle;
 
 // Re-export extension trait for ergonomic imports
 pub use ext::ExecutorExt;
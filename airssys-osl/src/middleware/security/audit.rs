@@ -14,6 +14,7 @@ use serde::{Deserialize, Serialize};
 // Layer 3: Internal module imports
 use crate::core::context::SecurityContext;
 use crate::middleware::security::policy::PolicyDecision;
+use crate::middleware::security::siem::AuditFormat;
 
 /// Security event type for audit logging.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -148,22 +149,41 @@ pub trait SecurityAuditLogger: Debug + Send + Sync + 'static {
 }
 
 /// Console-based security audit logger for development and testing.
+///
+/// Prints pretty JSON by default; use [`with_format`](Self::with_format)
+/// to emit CEF or LEEF lines for a SIEM collector reading stdout.
 #[derive(Debug, Default)]
-pub struct ConsoleSecurityAuditLogger;
+pub struct ConsoleSecurityAuditLogger {
+    format: AuditFormat,
+}
 
 impl ConsoleSecurityAuditLogger {
     /// Create a new console security audit logger.
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Set the output format.
+    pub fn with_format(mut self, format: AuditFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Output format of this logger.
+    pub fn format(&self) -> AuditFormat {
+        self.format
     }
 }
 
 #[async_trait]
 impl SecurityAuditLogger for ConsoleSecurityAuditLogger {
     async fn log_security_event(&self, event: SecurityAuditLog) -> Result<(), AuditError> {
-        // Format as JSON for structured logging
-        let json = serde_json::to_string_pretty(&event)?;
-        println!("[SECURITY AUDIT] {json}");
+        let line = self.format.render(&event)?;
+        match self.format {
+            AuditFormat::Json => println!("[SECURITY AUDIT] {line}"),
+            // SIEM collectors expect the bare record
+            AuditFormat::Cef | AuditFormat::Leef => println!("{line}"),
+        }
         Ok(())
     }
 }
@@ -13,11 +13,53 @@ use async_trait::async_trait;
 // Layer 3: Internal module imports
 use crate::core::context::ExecutionContext;
 use crate::core::middleware::{Middleware, MiddlewareError, MiddlewareResult};
-use crate::core::operation::Operation;
+use crate::core::operation::{Operation, OperationType};
 use crate::core::security::SecurityConfig;
 use crate::middleware::security::audit::{ConsoleSecurityAuditLogger, SecurityAuditLogger};
 use crate::middleware::security::policy::SecurityPolicy;
 
+/// Operation types in index slot order.
+const INDEXED_OPERATION_TYPES: [OperationType; 4] = [
+    OperationType::Filesystem,
+    OperationType::Process,
+    OperationType::Network,
+    OperationType::Utility,
+];
+
+/// Policy positions per operation type, in evaluation order.
+///
+/// Built once from each policy's `scope()` so evaluation cost depends on the
+/// policies that apply to an operation, not on the total policy count.
+#[derive(Debug, Default)]
+struct PolicyIndex {
+    slots: [Vec<usize>; 4],
+}
+
+impl PolicyIndex {
+    fn build(policies: &[Box<dyn SecurityPolicy>]) -> Self {
+        let mut index = Self::default();
+        for (position, policy) in policies.iter().enumerate() {
+            let scope = policy.scope();
+            for (slot, operation_type) in INDEXED_OPERATION_TYPES.iter().enumerate() {
+                if scope.applies_to(*operation_type) {
+                    index.slots[slot].push(position);
+                }
+            }
+        }
+        index
+    }
+
+    fn positions(&self, operation_type: OperationType) -> &[usize] {
+        let slot = match operation_type {
+            OperationType::Filesystem => 0,
+            OperationType::Process => 1,
+            OperationType::Network => 2,
+            OperationType::Utility => 3,
+        };
+        &self.slots[slot]
+    }
+}
+
 /// SecurityMiddleware for policy-based access control.
 ///
 /// This middleware enforces security policies before operations are executed.
@@ -29,6 +71,8 @@ use crate::middleware::security::policy::SecurityPolicy;
 /// - **Priority 100**: Runs FIRST before all other middleware
 /// - **Deny-by-default**: Operations denied unless explicitly allowed
 /// - **Policy composition**: Multiple policies evaluated in order
+/// - **Scoped evaluation**: Only policies whose `PolicyScope` covers the
+///   operation's `OperationType` are evaluated
 /// - **Comprehensive audit**: All decisions logged for compliance
 ///
 /// # Example Usage
@@ -55,6 +99,7 @@ pub struct SecurityMiddleware {
     config: SecurityConfig,
     audit_logger: Arc<dyn SecurityAuditLogger>,
     policies: Vec<Box<dyn SecurityPolicy>>,
+    index: PolicyIndex,
 }
 
 impl SecurityMiddleware {
@@ -64,6 +109,7 @@ impl SecurityMiddleware {
             config: SecurityConfig::default(),
             audit_logger: Arc::new(ConsoleSecurityAuditLogger::new()),
             policies: Vec::new(),
+            index: PolicyIndex::default(),
         }
     }
 
@@ -73,6 +119,7 @@ impl SecurityMiddleware {
             config,
             audit_logger: Arc::new(ConsoleSecurityAuditLogger::new()),
             policies: Vec::new(),
+            index: PolicyIndex::default(),
         }
     }
 
@@ -85,6 +132,7 @@ impl SecurityMiddleware {
             config,
             audit_logger,
             policies: Vec::new(),
+            index: PolicyIndex::default(),
         }
     }
 
@@ -102,6 +150,19 @@ impl SecurityMiddleware {
     pub fn policy_count(&self) -> usize {
         self.policies.len()
     }
+
+    /// Iterate over the policies that apply to an operation type, in the
+    /// order they were added.
+    pub fn policies_for(
+        &self,
+        operation_type: OperationType,
+    ) -> impl Iterator<Item = &dyn SecurityPolicy> + '_ {
+        self.index
+            .positions(operation_type)
+            .iter()
+            .filter_map(|&position| self.policies.get(position))
+            .map(|policy| policy.as_ref())
+    }
 }
 
 impl Default for SecurityMiddleware {
@@ -133,9 +194,19 @@ impl<O: Operation> Middleware<O> for SecurityMiddleware {
         use crate::middleware::security::audit::{SecurityAuditLog, SecurityEventType};
         use crate::middleware::security::policy::PolicyDecision;
 
-        // If no policies configured, deny by default (secure default)
-        if self.policies.is_empty() {
-            let reason = "No security policies configured - deny by default".to_string();
+        let operation_type = operation.operation_type();
+        let applicable = self.index.positions(operation_type);
+
+        // If no policies apply, deny by default (secure default)
+        if applicable.is_empty() {
+            let reason = if self.policies.is_empty() {
+                "No security policies configured - deny by default".to_string()
+            } else {
+                format!(
+                    "No security policies apply to {} operations - deny by default",
+                    operation_type.as_str()
+                )
+            };
 
             // Log security denial
             let log = SecurityAuditLog::new(
@@ -153,10 +224,10 @@ impl<O: Operation> Middleware<O> for SecurityMiddleware {
             return Err(MiddlewareError::SecurityViolation(reason));
         }
 
-        // Evaluate all policies - deny if ANY policy denies
+        // Evaluate applicable policies - deny if ANY policy denies
         let mut auth_requirements = Vec::new();
 
-        for policy in &self.policies {
+        for policy in self.policies_for(operation_type) {
             // Evaluate policy using only SecurityContext
             let decision = policy.evaluate(&context.security_context);
 
@@ -212,7 +283,7 @@ impl<O: Operation> Middleware<O> for SecurityMiddleware {
 
         // All policies passed (or only required additional auth)
         // Log overall approval
-        let policy_count_msg = format!("{} policies evaluated", self.policies.len());
+        let policy_count_msg = format!("{} policies evaluated", applicable.len());
         let log = SecurityAuditLog::new(
             SecurityEventType::PolicyEvaluated,
             operation.operation_id().to_string(),
@@ -264,8 +335,9 @@ impl SecurityMiddlewareBuilder {
 
     /// Add a security policy.
     ///
-    /// Policies are evaluated in the order they are added. If any policy
-    /// denies an operation, the operation is immediately denied.
+    /// Policies are evaluated in the order they are added, skipping those
+    /// whose scope does not cover the operation type. If any policy denies
+    /// an operation, the operation is immediately denied.
     ///
     /// # Example
     ///
@@ -301,6 +373,7 @@ impl SecurityMiddlewareBuilder {
         Ok(SecurityMiddleware {
             config: self.config,
             audit_logger,
+            index: PolicyIndex::build(&self.policies),
             policies: self.policies,
         })
     }
@@ -11,6 +11,7 @@
 //! - **Policy Framework** - Abstract policy evaluation engine with ACL and RBAC support
 //! - **Access Control** - ACL (Access Control Lists) and RBAC (Role-Based Access Control)
 //! - **Audit Logging** - Comprehensive security event logging and audit trails
+//! - **SIEM Export** - CEF and LEEF rendering of audit events
 //! - **Middleware Integration** - SecurityMiddleware implementing the Middleware trait
 //!
 //! # Security Model
@@ -497,6 +498,7 @@ pub mod audit;
 pub mod middleware;
 pub mod policy;
 pub mod rbac;
+pub mod siem;
 
 // Re-export primary types for ergonomic imports
 pub use acl::{AccessControlList, AclEntry, AclPolicy};
@@ -504,3 +506,4 @@ pub use audit::{SecurityAuditLog, SecurityAuditLogger, SecurityEventType};
 pub use middleware::{SecurityMiddleware, SecurityMiddlewareBuilder};
 pub use policy::{AuthRequirement, PolicyDecision, PolicyScope, SecurityPolicy};
 pub use rbac::{Permission, PermissionId, Role, RoleBasedAccessControl, RoleId, UserId};
+pub use siem::AuditFormat;
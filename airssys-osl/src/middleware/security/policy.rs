@@ -11,6 +11,7 @@ use std::fmt::Debug;
 
 // Layer 3: Internal module imports
 use crate::core::context::SecurityContext;
+use crate::core::operation::OperationType;
 
 /// Security policy evaluation result.
 ///
@@ -49,8 +50,10 @@ pub enum AuthRequirement {
 
 /// Scope of a security policy.
 ///
-/// Defines which operation types a policy applies to.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Defines which operation types a policy applies to. `SecurityMiddleware`
+/// indexes policies by scope and only evaluates those that apply to the
+/// operation being checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PolicyScope {
     /// Policy applies to filesystem operations
     Filesystem,
@@ -65,6 +68,21 @@ pub enum PolicyScope {
     All,
 }
 
+impl PolicyScope {
+    /// Returns true if a policy with this scope applies to `operation_type`.
+    ///
+    /// Utility operations are only covered by `PolicyScope::All`.
+    pub fn applies_to(&self, operation_type: OperationType) -> bool {
+        matches!(
+            (self, operation_type),
+            (PolicyScope::All, _)
+                | (PolicyScope::Filesystem, OperationType::Filesystem)
+                | (PolicyScope::Process, OperationType::Process)
+                | (PolicyScope::Network, OperationType::Network)
+        )
+    }
+}
+
 /// Core trait for security policy implementations.
 ///
 /// Security policies evaluate operations against security rules and return
@@ -149,8 +167,9 @@ pub trait SecurityPolicy: Debug + Send + Sync + 'static {
 
     /// Get the scope of this policy.
     ///
-    /// Defines which operation types this policy applies to. Policies with
-    /// more specific scopes may be evaluated before broader policies.
+    /// Defines which operation types this policy applies to. The scope is
+    /// read once when the policy is added to `SecurityMiddleware`; the policy
+    /// is never evaluated for operations outside it.
     fn scope(&self) -> PolicyScope;
 }
 
@@ -189,4 +208,13 @@ mod tests {
         assert_eq!(PolicyScope::All, PolicyScope::All);
         assert_ne!(PolicyScope::Filesystem, PolicyScope::Process);
     }
+
+    #[test]
+    fn test_policy_scope_applies_to() {
+        assert!(PolicyScope::Filesystem.applies_to(OperationType::Filesystem));
+        assert!(!PolicyScope::Filesystem.applies_to(OperationType::Network));
+        assert!(PolicyScope::Network.applies_to(OperationType::Network));
+        assert!(!PolicyScope::Process.applies_to(OperationType::Utility));
+        assert!(PolicyScope::All.applies_to(OperationType::Utility));
+    }
 }
@@ -0,0 +1,313 @@
+//! SIEM export formats for security audit logs.
+//!
+//! Converts [`SecurityAuditLog`] entries into the line formats expected by
+//! enterprise SIEMs:
+//!
+//! - **CEF** (ArcSight Common Event Format)
+//! - **LEEF** (QRadar Log Event Extended Format, version 2.0)
+//!
+//! Audit sinks select a format with [`AuditFormat`]; the console logger
+//! takes one via
+//! [`ConsoleSecurityAuditLogger::with_format`](super::audit::ConsoleSecurityAuditLogger::with_format).
+//!
+//! # Field Mappings
+//!
+//! | Audit field      | CEF                      | LEEF          |
+//! |------------------|--------------------------|---------------|
+//! | `timestamp`      | `rt` (epoch millis)      | `devTime`     |
+//! | `event_type`     | Signature ID / Name      | Event ID, `cat` |
+//! | (severity)       | Severity                 | `sev`         |
+//! | `principal`      | `suser`                  | `usrName`     |
+//! | `decision`       | `act`, `outcome`         | `action`, `outcome` |
+//! | `operation_id`   | `externalId`             | `operationId` |
+//! | `session_id`     | `cs1` (`sessionId`)      | `sessionId`   |
+//! | `policy_applied` | `cs2` (`policy`)         | `policy`      |
+//! | `metadata`       | `cs3` (`metadata`, JSON) | `metadata`    |
+//!
+//! Empty values and `null` metadata are omitted.
+
+// Layer 1: Standard library imports
+use std::fmt;
+
+// Layer 2: Third-party crate imports
+use serde::{Deserialize, Serialize};
+
+// Layer 3: Internal module imports
+use crate::middleware::security::audit::{AuditError, SecurityAuditLog, SecurityEventType};
+
+/// Vendor reported in CEF and LEEF headers.
+pub const SIEM_VENDOR: &str = "AirsStack";
+
+/// Product reported in CEF and LEEF headers.
+pub const SIEM_PRODUCT: &str = "airssys-osl";
+
+/// Output format of an audit sink.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditFormat {
+    /// Pretty-printed JSON (the serde representation)
+    #[default]
+    Json,
+
+    /// ArcSight Common Event Format
+    Cef,
+
+    /// QRadar Log Event Extended Format 2.0
+    Leef,
+}
+
+impl AuditFormat {
+    /// Render `event` in this format.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuditError::SerializationError` if JSON serialization fails.
+    pub fn render(&self, event: &SecurityAuditLog) -> Result<String, AuditError> {
+        match self {
+            Self::Json => Ok(serde_json::to_string_pretty(event)?),
+            Self::Cef => Ok(to_cef(event)),
+            Self::Leef => Ok(to_leef(event)),
+        }
+    }
+}
+
+impl fmt::Display for AuditFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json => write!(f, "json"),
+            Self::Cef => write!(f, "cef"),
+            Self::Leef => write!(f, "leef"),
+        }
+    }
+}
+
+/// SIEM severity (0-10) of an event type.
+pub fn severity(event_type: SecurityEventType) -> u8 {
+    match event_type {
+        SecurityEventType::AccessGranted | SecurityEventType::PolicyEvaluated => 1,
+        SecurityEventType::AuthenticationRequired => 5,
+        SecurityEventType::AccessDenied => 7,
+        SecurityEventType::SecurityViolation => 10,
+    }
+}
+
+fn event_id(event_type: SecurityEventType) -> &'static str {
+    match event_type {
+        SecurityEventType::AccessGranted => "AccessGranted",
+        SecurityEventType::AccessDenied => "AccessDenied",
+        SecurityEventType::SecurityViolation => "SecurityViolation",
+        SecurityEventType::AuthenticationRequired => "AuthenticationRequired",
+        SecurityEventType::PolicyEvaluated => "PolicyEvaluated",
+    }
+}
+
+fn event_name(event_type: SecurityEventType) -> &'static str {
+    match event_type {
+        SecurityEventType::AccessGranted => "Access granted",
+        SecurityEventType::AccessDenied => "Access denied",
+        SecurityEventType::SecurityViolation => "Security violation",
+        SecurityEventType::AuthenticationRequired => "Authentication required",
+        SecurityEventType::PolicyEvaluated => "Policy evaluated",
+    }
+}
+
+fn outcome(event: &SecurityAuditLog) -> &'static str {
+    if event.decision == "Allow" {
+        "success"
+    } else {
+        "failure"
+    }
+}
+
+fn metadata(event: &SecurityAuditLog) -> Option<String> {
+    (!event.metadata.is_null()).then(|| event.metadata.to_string())
+}
+
+/// Render `event` as a single CEF line.
+///
+/// Header fields escape `\` and `|`; extension values escape `\`, `=`
+/// and line breaks, as required by the CEF specification.
+pub fn to_cef(event: &SecurityAuditLog) -> String {
+    let mut ext: Vec<(&str, String)> = vec![
+        ("rt", event.timestamp.timestamp_millis().to_string()),
+        ("suser", event.principal.clone()),
+        ("act", event.decision.clone()),
+        ("outcome", outcome(event).to_string()),
+        ("externalId", event.operation_id.clone()),
+        ("cs1Label", "sessionId".to_string()),
+        ("cs1", event.session_id.clone()),
+        ("cs2Label", "policy".to_string()),
+        ("cs2", event.policy_applied.clone()),
+    ];
+    if let Some(metadata) = metadata(event) {
+        ext.push(("cs3Label", "metadata".to_string()));
+        ext.push(("cs3", metadata));
+    }
+
+    let extension = ext
+        .iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(key, value)| format!("{key}={}", escape_cef_value(value)))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!(
+        "CEF:0|{}|{}|{}|{}|{}|{}|{extension}",
+        escape_cef_header(SIEM_VENDOR),
+        escape_cef_header(SIEM_PRODUCT),
+        escape_cef_header(env!("CARGO_PKG_VERSION")),
+        event_id(event.event_type),
+        event_name(event.event_type),
+        severity(event.event_type),
+    )
+}
+
+/// Render `event` as a single LEEF 2.0 line with tab-delimited attributes.
+///
+/// Header fields escape `\` and `|`; attribute values escape `\`, tabs
+/// and line breaks so every event stays on one line.
+pub fn to_leef(event: &SecurityAuditLog) -> String {
+    let mut attrs: Vec<(&str, String)> = vec![
+        ("devTime", event.timestamp.timestamp_millis().to_string()),
+        ("cat", event_id(event.event_type).to_string()),
+        ("sev", severity(event.event_type).to_string()),
+        ("usrName", event.principal.clone()),
+        ("action", event.decision.clone()),
+        ("outcome", outcome(event).to_string()),
+        ("operationId", event.operation_id.clone()),
+        ("sessionId", event.session_id.clone()),
+        ("policy", event.policy_applied.clone()),
+    ];
+    if let Some(metadata) = metadata(event) {
+        attrs.push(("metadata", metadata));
+    }
+
+    let attributes = attrs
+        .iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(key, value)| format!("{key}={}", escape_leef_value(value)))
+        .collect::<Vec<_>>()
+        .join("\t");
+
+    format!(
+        "LEEF:2.0|{}|{}|{}|{}|x09|{attributes}",
+        escape_cef_header(SIEM_VENDOR),
+        escape_cef_header(SIEM_PRODUCT),
+        escape_cef_header(env!("CARGO_PKG_VERSION")),
+        event_id(event.event_type),
+    )
+}
+
+fn escape_cef_header(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '|' => out.push_str("\\|"),
+            '\n' | '\r' => out.push(' '),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn escape_cef_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '=' => out.push_str("\\="),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn escape_leef_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::core::context::SecurityContext;
+    use crate::middleware::security::policy::PolicyDecision;
+    use chrono::{TimeZone, Utc};
+
+    fn denied() -> SecurityAuditLog {
+        let context = SecurityContext::new("ops|admin".to_string());
+        let mut log = SecurityAuditLog::new(
+            SecurityEventType::AccessDenied,
+            "op-1".to_string(),
+            &context,
+            &PolicyDecision::Deny("path=/etc\\shadow\nblocked".to_string()),
+            "acl\tpolicy",
+        );
+        log.timestamp = Utc.with_ymd_and_hms(2025, 1, 8, 10, 0, 0).unwrap();
+        log.session_id = "s-1".to_string();
+        log
+    }
+
+    #[test]
+    fn test_cef_mapping_and_escaping() {
+        let cef = to_cef(&denied());
+        let version = env!("CARGO_PKG_VERSION");
+        assert!(cef.starts_with(&format!(
+            "CEF:0|AirsStack|airssys-osl|{version}|AccessDenied|Access denied|7|"
+        )));
+        assert!(cef.contains("rt=1736330400000 "));
+        assert!(cef.contains("suser=ops|admin "));
+        assert!(cef.contains("act=Deny: path\\=/etc\\\\shadow\\nblocked "));
+        assert!(cef.contains("outcome=failure "));
+        assert!(cef.contains("cs1Label=sessionId cs1=s-1 "));
+        assert!(cef.ends_with("cs2Label=policy cs2=acl\tpolicy"));
+        assert!(!cef.contains('\n'));
+    }
+
+    #[test]
+    fn test_leef_mapping_and_escaping() {
+        let log = denied().with_metadata(serde_json::json!({"path": "/etc"}));
+        let leef = to_leef(&log);
+        let (header, attributes) = leef.split_once("|x09|").unwrap();
+        assert_eq!(
+            header,
+            format!(
+                "LEEF:2.0|AirsStack|airssys-osl|{}|AccessDenied",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+
+        let attrs: Vec<_> = attributes.split('\t').collect();
+        assert!(attrs.contains(&"devTime=1736330400000"));
+        assert!(attrs.contains(&"sev=7"));
+        assert!(attrs.contains(&"usrName=ops|admin"));
+        assert!(attrs.contains(&"action=Deny: path=/etc\\\\shadow\\nblocked"));
+        assert!(attrs.contains(&"policy=acl\\tpolicy"));
+        assert!(attrs.contains(&r#"metadata={"path":"/etc"}"#));
+    }
+
+    #[test]
+    fn test_render_selects_format() {
+        let log = denied();
+        assert!(AuditFormat::Cef.render(&log).unwrap().starts_with("CEF:0|"));
+        assert!(AuditFormat::Leef
+            .render(&log)
+            .unwrap()
+            .starts_with("LEEF:2.0|"));
+        assert!(AuditFormat::Json.render(&log).unwrap().starts_with('{'));
+        assert_eq!(AuditFormat::default(), AuditFormat::Json);
+    }
+}
@@ -0,0 +1,469 @@
+//! Network bandwidth throttling middleware.
+//!
+//! [`ThrottleMiddleware`] rate-limits network traffic in bytes per second using
+//! token-bucket accounting. Buckets are keyed per principal, per destination,
+//! or per (principal, destination) pair, so tenants sharing an uplink cannot
+//! starve each other.
+//!
+//! # Accounting Model
+//!
+//! Each key owns a bucket that refills at `bytes_per_second` up to
+//! `burst_bytes`. Traffic is charged after it happens, so a single transfer may
+//! push a bucket into debt. While a bucket is in debt, new network operations
+//! for that key are rejected with [`MiddlewareError::RateLimited`], carrying the
+//! time until the debt is repaid. Short bursts are absorbed by the burst
+//! capacity; only sustained overuse is rejected.
+//!
+//! Bytes are charged from:
+//! - the executor result: `output.len()` plus the `bytes_sent` and
+//!   `bytes_received` metadata entries when present
+//! - explicit [`ThrottleMiddleware::record`] calls for traffic on streams
+//!   opened by a previous operation
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use airssys_osl::middleware::throttle::{ThrottleConfig, ThrottleMiddleware, ThrottleScope};
+//!
+//! let throttle = ThrottleMiddleware::new(
+//!     ThrottleConfig::new(1024 * 1024) // 1 MiB/s sustained
+//!         .with_burst_bytes(4 * 1024 * 1024)
+//!         .with_scope(ThrottleScope::Principal)
+//!         .with_key_limit("batch-jobs", 256 * 1024),
+//! );
+//! ```
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// Layer 2: Third-party crate imports
+use async_trait::async_trait;
+use uuid::Uuid;
+
+// Layer 3: Internal module imports
+use crate::core::context::ExecutionContext;
+use crate::core::executor::ExecutionResult;
+use crate::core::middleware::{Middleware, MiddlewareError, MiddlewareResult};
+use crate::core::operation::{Operation, OperationType, Permission};
+use crate::core::result::OSResult;
+
+/// Destination key used when an operation names no remote endpoint.
+const ANY_DESTINATION: &str = "*";
+
+/// How throttling keys are derived from an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThrottleScope {
+    /// One bucket per security principal.
+    #[default]
+    Principal,
+    /// One bucket per remote destination (`host:port`).
+    Destination,
+    /// One bucket per (principal, destination) pair.
+    PrincipalAndDestination,
+}
+
+impl ThrottleScope {
+    fn key(&self, principal: &str, destination: &str) -> String {
+        match self {
+            ThrottleScope::Principal => principal.to_string(),
+            ThrottleScope::Destination => destination.to_string(),
+            ThrottleScope::PrincipalAndDestination => format!("{principal}->{destination}"),
+        }
+    }
+}
+
+/// Configuration for [`ThrottleMiddleware`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThrottleConfig {
+    /// Sustained rate in bytes per second.
+    pub bytes_per_second: u64,
+    /// Bucket capacity; defaults to one second of traffic.
+    pub burst_bytes: u64,
+    /// Key derivation strategy.
+    pub scope: ThrottleScope,
+    /// Per-key sustained rates overriding `bytes_per_second`.
+    pub key_limits: HashMap<String, u64>,
+}
+
+impl ThrottleConfig {
+    /// Creates a configuration with the given sustained rate.
+    pub fn new(bytes_per_second: u64) -> Self {
+        Self {
+            bytes_per_second,
+            burst_bytes: bytes_per_second,
+            scope: ThrottleScope::default(),
+            key_limits: HashMap::new(),
+        }
+    }
+
+    /// Sets the bucket capacity.
+    pub fn with_burst_bytes(mut self, burst_bytes: u64) -> Self {
+        self.burst_bytes = burst_bytes;
+        self
+    }
+
+    /// Sets the key derivation strategy.
+    pub fn with_scope(mut self, scope: ThrottleScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Overrides the sustained rate for one key.
+    ///
+    /// The key must match the configured [`ThrottleScope`]: a principal, a
+    /// destination, or `principal->destination`.
+    pub fn with_key_limit(mut self, key: impl Into<String>, bytes_per_second: u64) -> Self {
+        self.key_limits.insert(key.into(), bytes_per_second);
+        self
+    }
+
+    fn rate_for(&self, key: &str) -> u64 {
+        self.key_limits
+            .get(key)
+            .copied()
+            .unwrap_or(self.bytes_per_second)
+    }
+}
+
+/// Token bucket measured in bytes. Tokens may go negative (debt).
+#[derive(Debug)]
+struct TokenBucket {
+    rate: f64,
+    capacity: f64,
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: u64, capacity: u64, now: Instant) -> Self {
+        Self {
+            rate: rate as f64,
+            capacity: capacity as f64,
+            tokens: capacity as f64,
+            updated_at: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.updated_at = now;
+    }
+
+    /// Returns the wait until the bucket is out of debt, or `None` if admitted.
+    fn admit(&mut self, now: Instant) -> Option<Duration> {
+        self.refill(now);
+        if self.tokens >= 0.0 {
+            return None;
+        }
+        if self.rate <= 0.0 {
+            return Some(Duration::MAX);
+        }
+        Some(Duration::from_secs_f64(-self.tokens / self.rate))
+    }
+
+    fn charge(&mut self, bytes: u64, now: Instant) {
+        self.refill(now);
+        self.tokens -= bytes as f64;
+    }
+
+    fn available(&mut self, now: Instant) -> i64 {
+        self.refill(now);
+        self.tokens as i64
+    }
+}
+
+/// Middleware enforcing per-key network bandwidth limits.
+///
+/// Runs with priority 150: after security (100) so denied operations never
+/// consume bandwidth, and before logging (200) so rejections are logged.
+#[derive(Debug)]
+pub struct ThrottleMiddleware {
+    config: ThrottleConfig,
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+    /// Keys of admitted operations awaiting `after_execution`.
+    in_flight: Mutex<HashMap<Uuid, String>>,
+}
+
+impl ThrottleMiddleware {
+    /// Creates a throttle middleware with the given configuration.
+    pub fn new(config: ThrottleConfig) -> Self {
+        Self {
+            config,
+            buckets: Mutex::new(HashMap::new()),
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Get reference to the throttle configuration.
+    pub fn config(&self) -> &ThrottleConfig {
+        &self.config
+    }
+
+    /// Derives the throttling key for a principal and destination.
+    pub fn key_for(&self, principal: &str, destination: &str) -> String {
+        self.config.scope.key(principal, destination)
+    }
+
+    /// Charges `bytes` of traffic to the key for `principal` and `destination`.
+    ///
+    /// Use this for traffic on connections opened by an earlier operation.
+    pub fn record(&self, principal: &str, destination: &str, bytes: u64) {
+        let key = self.key_for(principal, destination);
+        self.charge(&key, bytes, Instant::now());
+    }
+
+    /// Checks whether the key for `principal` and `destination` is admitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MiddlewareError::RateLimited`] while the key is in debt.
+    pub fn check(&self, principal: &str, destination: &str) -> MiddlewareResult<()> {
+        let key = self.key_for(principal, destination);
+        self.admit(&key, Instant::now())
+    }
+
+    /// Returns the bytes currently available to `key` (negative while in debt).
+    pub fn available_bytes(&self, key: &str) -> i64 {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        self.bucket(&mut buckets, key, now).available(now)
+    }
+
+    fn bucket<'a>(
+        &self,
+        buckets: &'a mut HashMap<String, TokenBucket>,
+        key: &str,
+        now: Instant,
+    ) -> &'a mut TokenBucket {
+        buckets.entry(key.to_string()).or_insert_with(|| {
+            let rate = self.config.rate_for(key);
+            let capacity = if self.config.key_limits.contains_key(key) {
+                rate
+            } else {
+                self.config.burst_bytes
+            };
+            TokenBucket::new(rate, capacity, now)
+        })
+    }
+
+    fn admit(&self, key: &str, now: Instant) -> MiddlewareResult<()> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        match self.bucket(&mut buckets, key, now).admit(now) {
+            None => Ok(()),
+            Some(retry_after) => Err(MiddlewareError::RateLimited {
+                key: key.to_string(),
+                limit_bytes_per_sec: self.config.rate_for(key),
+                retry_after,
+            }),
+        }
+    }
+
+    fn charge(&self, key: &str, bytes: u64, now: Instant) {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        self.bucket(&mut buckets, key, now).charge(bytes, now);
+    }
+}
+
+/// Extracts the remote endpoint named by an operation's permissions.
+fn destination_of<O: Operation>(operation: &O) -> String {
+    operation
+        .required_permissions()
+        .into_iter()
+        .find_map(|p| match p {
+            Permission::NetworkConnect(address) => Some(address),
+            _ => None,
+        })
+        .unwrap_or_else(|| ANY_DESTINATION.to_string())
+}
+
+/// Bytes transferred according to an execution result.
+fn transferred_bytes(result: &ExecutionResult) -> u64 {
+    let counted = |key: &str| {
+        result
+            .metadata
+            .get(key)
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(0)
+    };
+    (result.output.len() as u64)
+        .saturating_add(counted("bytes_sent"))
+        .saturating_add(counted("bytes_received"))
+}
+
+#[async_trait]
+impl<O: Operation> Middleware<O> for ThrottleMiddleware {
+    fn name(&self) -> &str {
+        "throttle"
+    }
+
+    fn priority(&self) -> u32 {
+        150
+    }
+
+    fn supported_operation_types(&self) -> Vec<OperationType> {
+        vec![OperationType::Network]
+    }
+
+    async fn before_execution(
+        &self,
+        operation: O,
+        context: &ExecutionContext,
+    ) -> MiddlewareResult<Option<O>> {
+        let key = self.key_for(context.principal(), &destination_of(&operation));
+        self.admit(&key, Instant::now())?;
+
+        self.in_flight
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(context.execution_id, key);
+        Ok(Some(operation))
+    }
+
+    async fn after_execution(
+        &self,
+        context: &ExecutionContext,
+        result: &OSResult<ExecutionResult>,
+    ) -> MiddlewareResult<()> {
+        let key = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&context.execution_id);
+
+        if let (Some(key), Ok(result)) = (key, result) {
+            self.charge(&key, transferred_bytes(result), Instant::now());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::core::context::SecurityContext;
+    use crate::operations::network::NetworkConnectOperation;
+
+    fn context(principal: &str) -> ExecutionContext {
+        ExecutionContext::new(SecurityContext::new(principal.to_string()))
+    }
+
+    #[test]
+    fn test_bucket_debt_and_refill() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(100, 100, start);
+
+        assert_eq!(bucket.admit(start), None);
+        bucket.charge(300, start);
+
+        let wait = bucket.admit(start).unwrap();
+        assert_eq!(wait, Duration::from_secs(2));
+
+        let later = start + Duration::from_secs(2);
+        assert_eq!(bucket.admit(later), None);
+    }
+
+    #[test]
+    fn test_bucket_refill_is_capped() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(100, 50, start);
+        assert_eq!(bucket.available(start + Duration::from_secs(60)), 50);
+    }
+
+    #[test]
+    fn test_scope_keys() {
+        assert_eq!(ThrottleScope::Principal.key("alice", "db:5432"), "alice");
+        assert_eq!(
+            ThrottleScope::Destination.key("alice", "db:5432"),
+            "db:5432"
+        );
+        assert_eq!(
+            ThrottleScope::PrincipalAndDestination.key("alice", "db:5432"),
+            "alice->db:5432"
+        );
+    }
+
+    #[test]
+    fn test_sustained_overuse_is_rejected() {
+        let throttle = ThrottleMiddleware::new(ThrottleConfig::new(1000));
+        assert!(throttle.check("alice", "api:443").is_ok());
+
+        throttle.record("alice", "api:443", 5000);
+        let err = throttle.check("alice", "api:443").unwrap_err();
+        assert!(matches!(
+            err,
+            MiddlewareError::RateLimited {
+                ref key,
+                limit_bytes_per_sec: 1000,
+                retry_after,
+            } if key == "alice" && retry_after > Duration::from_secs(3)
+        ));
+
+        // Other principals are unaffected.
+        assert!(throttle.check("bob", "api:443").is_ok());
+    }
+
+    #[test]
+    fn test_key_limit_override() {
+        let throttle = ThrottleMiddleware::new(
+            ThrottleConfig::new(1_000_000)
+                .with_scope(ThrottleScope::Destination)
+                .with_key_limit("slow:80", 10),
+        );
+        throttle.record("alice", "slow:80", 100);
+        throttle.record("alice", "fast:80", 100);
+
+        assert!(throttle.check("bob", "slow:80").is_err());
+        assert!(throttle.check("bob", "fast:80").is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_middleware_charges_result_bytes() {
+        let throttle = ThrottleMiddleware::new(
+            ThrottleConfig::new(10).with_scope(ThrottleScope::PrincipalAndDestination),
+        );
+        let ctx = context("alice");
+        let op = NetworkConnectOperation::new("example.com:443");
+
+        let admitted = throttle.before_execution(op.clone(), &ctx).await.unwrap();
+        assert!(admitted.is_some());
+
+        let result = ExecutionResult::success(vec![0; 8])
+            .with_metadata("bytes_sent".to_string(), "100".to_string());
+        <ThrottleMiddleware as Middleware<NetworkConnectOperation>>::after_execution(
+            &throttle,
+            &ctx,
+            &Ok(result),
+        )
+        .await
+        .unwrap();
+
+        // 10-byte burst minus 108 charged bytes, plus a sliver of refill.
+        let available = throttle.available_bytes("alice->example.com:443");
+        assert!((-98..-90).contains(&available), "available = {available}");
+        let err = throttle.before_execution(op, &context("alice")).await;
+        assert!(matches!(err, Err(MiddlewareError::RateLimited { .. })));
+    }
+
+    #[test]
+    fn test_middleware_metadata() {
+        let throttle = ThrottleMiddleware::new(ThrottleConfig::new(1));
+        assert_eq!(
+            <ThrottleMiddleware as Middleware<NetworkConnectOperation>>::name(&throttle),
+            "throttle"
+        );
+        assert_eq!(
+            <ThrottleMiddleware as Middleware<NetworkConnectOperation>>::priority(&throttle),
+            150
+        );
+        assert_eq!(
+            <ThrottleMiddleware as Middleware<NetworkConnectOperation>>::supported_operation_types(
+                &throttle
+            ),
+            vec![OperationType::Network]
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//! NTFS access control list operations (Windows).
+
+// Layer 1: Standard library imports
+use std::fmt;
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// Layer 3: Internal module imports
+use crate::core::operation::{Operation, OperationType, Permission};
+
+/// Access right granted or denied by an NTFS ACL entry.
+///
+/// Maps to the simple rights understood by `icacls`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AclAccess {
+    /// Read-only access (`R`)
+    Read,
+    /// Read and execute access (`RX`)
+    ReadExecute,
+    /// Write-only access (`W`)
+    Write,
+    /// Modify access: read, write, execute, and delete (`M`)
+    Modify,
+    /// Full control (`F`)
+    FullControl,
+}
+
+impl AclAccess {
+    /// Returns the `icacls` right code for this access level.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AclAccess::Read => "R",
+            AclAccess::ReadExecute => "RX",
+            AclAccess::Write => "W",
+            AclAccess::Modify => "M",
+            AclAccess::FullControl => "F",
+        }
+    }
+}
+
+/// A change to the ACL of a file or directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AclChange {
+    /// Add an allow entry for `principal`
+    Grant {
+        /// User or group name (e.g. `"BUILTIN\\Users"`)
+        principal: String,
+        /// Access right to allow
+        access: AclAccess,
+    },
+    /// Add a deny entry for `principal`
+    Deny {
+        /// User or group name
+        principal: String,
+        /// Access right to deny
+        access: AclAccess,
+    },
+    /// Remove every explicit entry for `principal`
+    Remove {
+        /// User or group name
+        principal: String,
+    },
+}
+
+impl AclChange {
+    /// Returns the principal this change applies to.
+    pub fn principal(&self) -> &str {
+        match self {
+            AclChange::Grant { principal, .. }
+            | AclChange::Deny { principal, .. }
+            | AclChange::Remove { principal } => principal,
+        }
+    }
+}
+
+impl fmt::Display for AclChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AclChange::Grant { principal, access } => {
+                write!(f, "grant {principal}:{}", access.code())
+            }
+            AclChange::Deny { principal, access } => {
+                write!(f, "deny {principal}:{}", access.code())
+            }
+            AclChange::Remove { principal } => write!(f, "remove {principal}"),
+        }
+    }
+}
+
+/// Operation to read the NTFS ACL of a file or directory.
+///
+/// Requires read permission for the specified path. Only executable on
+/// Windows.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_osl::operations::FileAclReadOperation;
+///
+/// let op = FileAclReadOperation::new("C:\\data\\report.txt");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileAclReadOperation {
+    /// Path to the file or directory
+    pub path: String,
+
+    /// When this operation was created
+    pub created_at: DateTime<Utc>,
+
+    /// Optional operation ID
+    pub operation_id: Option<String>,
+}
+
+impl FileAclReadOperation {
+    /// Create a new ACL read operation.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            created_at: Utc::now(),
+            operation_id: None,
+        }
+    }
+
+    /// Set custom operation ID.
+    pub fn with_operation_id(mut self, id: impl Into<String>) -> Self {
+        self.operation_id = Some(id.into());
+        self
+    }
+}
+
+impl Operation for FileAclReadOperation {
+    fn operation_type(&self) -> OperationType {
+        OperationType::Filesystem
+    }
+
+    fn required_permissions(&self) -> Vec<Permission> {
+        vec![Permission::FilesystemRead(self.path.clone())]
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn operation_id(&self) -> String {
+        self.operation_id
+            .clone()
+            .unwrap_or_else(|| format!("{}:{}", self.operation_type().as_str(), Uuid::new_v4()))
+    }
+}
+
+impl fmt::Display for FileAclReadOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FileAclRead({})", self.path)
+    }
+}
+
+/// Operation to modify the NTFS ACL of a file or directory.
+///
+/// Requires write permission for the specified path. Only executable on
+/// Windows.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_osl::operations::FileAclModifyOperation;
+/// use airssys_osl::operations::filesystem::AclAccess;
+///
+/// let op = FileAclModifyOperation::grant("C:\\data", "BUILTIN\\Users", AclAccess::Read);
+/// assert_eq!(op.change.principal(), "BUILTIN\\Users");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileAclModifyOperation {
+    /// Path to the file or directory
+    pub path: String,
+
+    /// ACL change to apply
+    pub change: AclChange,
+
+    /// When this operation was created
+    pub created_at: DateTime<Utc>,
+
+    /// Optional operation ID
+    pub operation_id: Option<String>,
+}
+
+impl FileAclModifyOperation {
+    /// Create a new ACL modify operation.
+    pub fn new(path: impl Into<String>, change: AclChange) -> Self {
+        Self {
+            path: path.into(),
+            change,
+            created_at: Utc::now(),
+            operation_id: None,
+        }
+    }
+
+    /// Grant `access` to `principal`.
+    pub fn grant(path: impl Into<String>, principal: impl Into<String>, access: AclAccess) -> Self {
+        Self::new(
+            path,
+            AclChange::Grant {
+                principal: principal.into(),
+                access,
+            },
+        )
+    }
+
+    /// Deny `access` to `principal`.
+    pub fn deny(path: impl Into<String>, principal: impl Into<String>, access: AclAccess) -> Self {
+        Self::new(
+            path,
+            AclChange::Deny {
+                principal: principal.into(),
+                access,
+            },
+        )
+    }
+
+    /// Remove every explicit entry for `principal`.
+    pub fn remove(path: impl Into<String>, principal: impl Into<String>) -> Self {
+        Self::new(
+            path,
+            AclChange::Remove {
+                principal: principal.into(),
+            },
+        )
+    }
+
+    /// Set custom operation ID.
+    pub fn with_operation_id(mut self, id: impl Into<String>) -> Self {
+        self.operation_id = Some(id.into());
+        self
+    }
+}
+
+impl Operation for FileAclModifyOperation {
+    fn operation_type(&self) -> OperationType {
+        OperationType::Filesystem
+    }
+
+    fn required_permissions(&self) -> Vec<Permission> {
+        vec![Permission::FilesystemWrite(self.path.clone())]
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn operation_id(&self) -> String {
+        self.operation_id
+            .clone()
+            .unwrap_or_else(|| format!("{}:{}", self.operation_type().as_str(), Uuid::new_v4()))
+    }
+}
+
+impl fmt::Display for FileAclModifyOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FileAclModify({}, {})", self.path, self.change)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acl_read_operation_permissions() {
+        let op = FileAclReadOperation::new("C:\\data");
+        assert_eq!(op.operation_type(), OperationType::Filesystem);
+        assert_eq!(
+            op.required_permissions(),
+            vec![Permission::FilesystemRead("C:\\data".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_acl_modify_operation_permissions_and_display() {
+        let op = FileAclModifyOperation::deny("C:\\data", "Guest", AclAccess::Write);
+        assert_eq!(
+            op.required_permissions(),
+            vec![Permission::FilesystemWrite("C:\\data".to_string())]
+        );
+        assert_eq!(op.to_string(), "FileAclModify(C:\\data, deny Guest:W)");
+
+        let op = FileAclModifyOperation::remove("C:\\data", "Guest");
+        assert_eq!(op.change.principal(), "Guest");
+    }
+}
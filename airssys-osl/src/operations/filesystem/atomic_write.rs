@@ -0,0 +1,146 @@
+//! Atomic file write operation.
+
+// Layer 1: Standard library imports
+use std::fmt;
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// Layer 3: Internal module imports
+use super::ResourcePriority;
+use crate::core::operation::{Operation, OperationType, Permission};
+
+/// Operation to replace a file's contents atomically.
+///
+/// The content is written to a temporary file in the target's directory,
+/// flushed to disk, and renamed over the target. Readers see either the old
+/// or the new contents, never a torn file, even if the process crashes
+/// mid-write. Use [`FileWriteOperation`](super::FileWriteOperation) for
+/// appends.
+///
+/// Requires write permission for the target path.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_osl::operations::AtomicFileWriteOperation;
+///
+/// let op = AtomicFileWriteOperation::new("/tmp/state.json", b"{}".to_vec());
+/// assert_eq!(op.path, "/tmp/state.json");
+/// ```
+#[derive(Debug, Clone)]
+pub struct AtomicFileWriteOperation {
+    /// Path to the file to replace
+    pub path: String,
+
+    /// New content of the file
+    pub content: Vec<u8>,
+
+    /// When this operation was created
+    pub created_at: DateTime<Utc>,
+
+    /// Optional operation ID
+    pub operation_id: Option<String>,
+
+    /// IO and CPU priority applied while the operation runs
+    pub priority: ResourcePriority,
+}
+
+impl AtomicFileWriteOperation {
+    /// Create a new atomic file write operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the file to replace
+    /// * `content` - New content of the file
+    pub fn new(path: impl Into<String>, content: Vec<u8>) -> Self {
+        Self {
+            path: path.into(),
+            content,
+            created_at: Utc::now(),
+            operation_id: None,
+            priority: ResourcePriority::default(),
+        }
+    }
+
+    /// Create with explicit timestamp (for testing).
+    pub fn with_timestamp(
+        path: impl Into<String>,
+        content: Vec<u8>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            content,
+            created_at,
+            operation_id: None,
+            priority: ResourcePriority::default(),
+        }
+    }
+
+    /// Set custom operation ID.
+    pub fn with_operation_id(mut self, id: impl Into<String>) -> Self {
+        self.operation_id = Some(id.into());
+        self
+    }
+
+    /// Run with the given IO and CPU priority.
+    pub fn with_priority(mut self, priority: ResourcePriority) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+impl Operation for AtomicFileWriteOperation {
+    fn operation_type(&self) -> OperationType {
+        OperationType::Filesystem
+    }
+
+    fn required_permissions(&self) -> Vec<Permission> {
+        vec![Permission::FilesystemWrite(self.path.clone())]
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn operation_id(&self) -> String {
+        self.operation_id
+            .clone()
+            .unwrap_or_else(|| format!("{}:{}", self.operation_type().as_str(), Uuid::new_v4()))
+    }
+}
+
+impl fmt::Display for AtomicFileWriteOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "AtomicFileWrite({}, {} bytes)",
+            self.path,
+            self.content.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atomic_file_write_operation_new() {
+        let op = AtomicFileWriteOperation::new("/tmp/state.json", b"{}".to_vec());
+        assert_eq!(op.path, "/tmp/state.json");
+        assert_eq!(op.content, b"{}");
+        assert_eq!(op.to_string(), "AtomicFileWrite(/tmp/state.json, 2 bytes)");
+    }
+
+    #[test]
+    fn test_atomic_file_write_operation_permissions() {
+        let op = AtomicFileWriteOperation::new("/tmp/state.json", vec![1]);
+        assert_eq!(
+            op.required_permissions(),
+            vec![Permission::FilesystemWrite("/tmp/state.json".to_string())]
+        );
+    }
+}
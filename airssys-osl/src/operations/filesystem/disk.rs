@@ -0,0 +1,376 @@
+//! Disk space, mount point, and disk usage query operations.
+//!
+//! These operations only read filesystem metadata and require
+//! [`Permission::FilesystemRead`] on the queried path. Their executors
+//! return JSON-encoded reports in `ExecutionResult::output`, which can be
+//! decoded with the `from_output` helpers on the report types.
+
+// Layer 1: Standard library imports
+use std::fmt;
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Layer 3: Internal module imports
+use super::ResourcePriority;
+use crate::core::operation::{Operation, OperationType, Permission};
+use crate::core::result::{OSError, OSResult};
+
+/// Path whose read permission guards the system mount table.
+pub const MOUNT_TABLE_PATH: &str = "/";
+
+/// Capacity of the filesystem containing a path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilesystemSpace {
+    /// Total size of the filesystem in bytes
+    pub total_bytes: u64,
+    /// Free bytes, including those reserved for the superuser
+    pub free_bytes: u64,
+    /// Free bytes available to unprivileged users
+    pub available_bytes: u64,
+}
+
+impl FilesystemSpace {
+    /// Bytes in use (`total_bytes - free_bytes`).
+    pub fn used_bytes(&self) -> u64 {
+        self.total_bytes.saturating_sub(self.free_bytes)
+    }
+
+    /// Decode the report returned by a [`FilesystemSpaceOperation`].
+    pub fn from_output(output: &[u8]) -> OSResult<Self> {
+        decode(output)
+    }
+}
+
+/// One entry of the system mount table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MountPoint {
+    /// Mounted device or source (e.g. `/dev/sda1`, `tmpfs`)
+    pub device: String,
+    /// Directory the filesystem is mounted on
+    pub mount_path: String,
+    /// Filesystem type (e.g. `ext4`)
+    pub fs_type: String,
+    /// Mount options
+    pub options: Vec<String>,
+}
+
+impl MountPoint {
+    /// Whether the filesystem is mounted read-only.
+    pub fn is_read_only(&self) -> bool {
+        self.options.iter().any(|o| o == "ro")
+    }
+
+    /// Decode the mount table returned by a [`MountListOperation`].
+    pub fn from_output(output: &[u8]) -> OSResult<Vec<Self>> {
+        decode(output)
+    }
+}
+
+/// Disk usage of one path, including everything below it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiskUsageEntry {
+    /// Path of the file or directory
+    pub path: String,
+    /// Depth below the queried path (the queried path itself is 0)
+    pub depth: usize,
+    /// Sum of file lengths
+    pub apparent_bytes: u64,
+    /// Bytes allocated on disk (equal to `apparent_bytes` where the
+    /// platform does not report allocation)
+    pub allocated_bytes: u64,
+    /// Number of regular files counted
+    pub files: u64,
+}
+
+impl DiskUsageEntry {
+    /// Decode the report returned by a [`DiskUsageOperation`].
+    ///
+    /// Entries are ordered by path; the first entry is the queried path.
+    pub fn from_output(output: &[u8]) -> OSResult<Vec<Self>> {
+        decode(output)
+    }
+}
+
+fn decode<T: serde::de::DeserializeOwned>(output: &[u8]) -> OSResult<T> {
+    serde_json::from_slice(output)
+        .map_err(|e| OSError::execution_failed(format!("invalid disk report: {e}")))
+}
+
+/// Operation to query free space on the filesystem containing a path.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_osl::operations::FilesystemSpaceOperation;
+///
+/// let op = FilesystemSpaceOperation::new("/var/lib/app");
+/// assert_eq!(op.path, "/var/lib/app");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FilesystemSpaceOperation {
+    /// Any path on the filesystem to query
+    pub path: String,
+
+    /// When this operation was created
+    pub created_at: DateTime<Utc>,
+
+    /// Optional operation ID
+    pub operation_id: Option<String>,
+}
+
+impl FilesystemSpaceOperation {
+    /// Create a new free-space query for the filesystem containing `path`.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            created_at: Utc::now(),
+            operation_id: None,
+        }
+    }
+
+    /// Set custom operation ID.
+    pub fn with_operation_id(mut self, id: impl Into<String>) -> Self {
+        self.operation_id = Some(id.into());
+        self
+    }
+}
+
+impl Operation for FilesystemSpaceOperation {
+    fn operation_type(&self) -> OperationType {
+        OperationType::Filesystem
+    }
+
+    fn required_permissions(&self) -> Vec<Permission> {
+        vec![Permission::FilesystemRead(self.path.clone())]
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn operation_id(&self) -> String {
+        self.operation_id
+            .clone()
+            .unwrap_or_else(|| format!("{}:{}", self.operation_type().as_str(), Uuid::new_v4()))
+    }
+}
+
+impl fmt::Display for FilesystemSpaceOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FilesystemSpace({})", self.path)
+    }
+}
+
+/// Operation to list mounted filesystems.
+///
+/// Reading the mount table requires read permission on
+/// [`MOUNT_TABLE_PATH`].
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_osl::operations::MountListOperation;
+///
+/// let op = MountListOperation::new();
+/// ```
+#[derive(Debug, Clone)]
+pub struct MountListOperation {
+    /// When this operation was created
+    pub created_at: DateTime<Utc>,
+
+    /// Optional operation ID
+    pub operation_id: Option<String>,
+}
+
+impl MountListOperation {
+    /// Create a new mount table query.
+    pub fn new() -> Self {
+        Self {
+            created_at: Utc::now(),
+            operation_id: None,
+        }
+    }
+
+    /// Set custom operation ID.
+    pub fn with_operation_id(mut self, id: impl Into<String>) -> Self {
+        self.operation_id = Some(id.into());
+        self
+    }
+}
+
+impl Default for MountListOperation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operation for MountListOperation {
+    fn operation_type(&self) -> OperationType {
+        OperationType::Filesystem
+    }
+
+    fn required_permissions(&self) -> Vec<Permission> {
+        vec![Permission::FilesystemRead(MOUNT_TABLE_PATH.to_string())]
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn operation_id(&self) -> String {
+        self.operation_id
+            .clone()
+            .unwrap_or_else(|| format!("{}:{}", self.operation_type().as_str(), Uuid::new_v4()))
+    }
+}
+
+impl fmt::Display for MountListOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MountList")
+    }
+}
+
+/// Operation to measure disk usage below a path.
+///
+/// Like `du --max-depth`, every file below `path` is counted, but entries
+/// are only reported for paths at most `max_depth` levels below it.
+/// Symbolic links are not followed.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_osl::operations::DiskUsageOperation;
+///
+/// let op = DiskUsageOperation::new("/var/lib/app").with_max_depth(1);
+/// assert_eq!(op.max_depth, Some(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DiskUsageOperation {
+    /// Root of the measured tree
+    pub path: String,
+
+    /// Deepest level to report (`None` reports every directory)
+    pub max_depth: Option<usize>,
+
+    /// When this operation was created
+    pub created_at: DateTime<Utc>,
+
+    /// Optional operation ID
+    pub operation_id: Option<String>,
+
+    /// IO and CPU priority applied while the operation runs
+    pub priority: ResourcePriority,
+}
+
+impl DiskUsageOperation {
+    /// Create a new disk usage query reporting only `path` itself.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            max_depth: Some(0),
+            created_at: Utc::now(),
+            operation_id: None,
+            priority: ResourcePriority::default(),
+        }
+    }
+
+    /// Report directories up to `depth` levels below the path.
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Report every directory below the path.
+    pub fn unlimited_depth(mut self) -> Self {
+        self.max_depth = None;
+        self
+    }
+
+    /// Set custom operation ID.
+    pub fn with_operation_id(mut self, id: impl Into<String>) -> Self {
+        self.operation_id = Some(id.into());
+        self
+    }
+
+    /// Run with the given IO and CPU priority.
+    pub fn with_priority(mut self, priority: ResourcePriority) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+impl Operation for DiskUsageOperation {
+    fn operation_type(&self) -> OperationType {
+        OperationType::Filesystem
+    }
+
+    fn required_permissions(&self) -> Vec<Permission> {
+        vec![Permission::FilesystemRead(self.path.clone())]
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn operation_id(&self) -> String {
+        self.operation_id
+            .clone()
+            .unwrap_or_else(|| format!("{}:{}", self.operation_type().as_str(), Uuid::new_v4()))
+    }
+}
+
+impl fmt::Display for DiskUsageOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.max_depth {
+            Some(depth) => write!(f, "DiskUsage({}, depth={depth})", self.path),
+            None => write!(f, "DiskUsage({})", self.path),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disk_operations_require_read_permission() {
+        let space = FilesystemSpaceOperation::new("/data");
+        assert_eq!(
+            space.required_permissions(),
+            vec![Permission::FilesystemRead("/data".to_string())]
+        );
+
+        let usage = DiskUsageOperation::new("/data/cache");
+        assert_eq!(usage.max_depth, Some(0));
+        assert_eq!(
+            usage.required_permissions(),
+            vec![Permission::FilesystemRead("/data/cache".to_string())]
+        );
+
+        let mounts = MountListOperation::new();
+        assert_eq!(mounts.operation_type(), OperationType::Filesystem);
+        assert_eq!(
+            mounts.required_permissions(),
+            vec![Permission::FilesystemRead(MOUNT_TABLE_PATH.to_string())]
+        );
+    }
+
+    #[test]
+    fn test_reports_roundtrip_through_output() {
+        let space = FilesystemSpace {
+            total_bytes: 100,
+            free_bytes: 40,
+            available_bytes: 30,
+        };
+        let output = serde_json::to_vec(&space).unwrap();
+        let decoded = FilesystemSpace::from_output(&output).unwrap();
+        assert_eq!(decoded, space);
+        assert_eq!(decoded.used_bytes(), 60);
+
+        assert!(MountPoint::from_output(b"not json").is_err());
+    }
+}
@@ -8,9 +8,18 @@
 //!
 //! - [`FileReadOperation`] - Read file contents
 //! - [`FileWriteOperation`] - Write or append to files
+//! - [`AtomicFileWriteOperation`] - Replace a file via write-temp-then-rename
 //! - [`DirectoryCreateOperation`] - Create directories (single or recursive)
 //! - [`DirectoryListOperation`] - List directory contents
 //! - [`FileDeleteOperation`] - Delete files
+//! - [`FilesystemSpaceOperation`] - Query free space on a filesystem
+//! - [`MountListOperation`] - List mounted filesystems
+//! - [`DiskUsageOperation`] - Measure disk usage below a path (depth-limited)
+//! - [`FileAclReadOperation`] - Read NTFS ACLs (Windows)
+//! - [`FileAclModifyOperation`] - Grant, deny, or remove NTFS ACL entries (Windows)
+//!
+//! Write and disk usage operations accept a [`ResourcePriority`] that lowers
+//! the IO class and nice level of the thread doing the work.
 //!
 //! # Examples
 //!
@@ -29,15 +38,26 @@
 //! ```
 
 // Operation modules
+pub mod acl;
+pub mod atomic_write;
 pub mod create_dir;
 pub mod delete;
+pub mod disk;
 pub mod list_dir;
+pub mod priority;
 pub mod read;
 pub mod write;
 
 // Re-export all operation types
+pub use acl::{AclAccess, AclChange, FileAclModifyOperation, FileAclReadOperation};
+pub use atomic_write::AtomicFileWriteOperation;
 pub use create_dir::DirectoryCreateOperation;
 pub use delete::FileDeleteOperation;
+pub use disk::{
+    DiskUsageEntry, DiskUsageOperation, FilesystemSpace, FilesystemSpaceOperation,
+    MountListOperation, MountPoint,
+};
 pub use list_dir::DirectoryListOperation;
+pub use priority::{IoPriority, ResourcePriority};
 pub use read::FileReadOperation;
 pub use write::FileWriteOperation;
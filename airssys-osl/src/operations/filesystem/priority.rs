@@ -0,0 +1,157 @@
+//! IO and CPU priority settings for heavy filesystem operations.
+//!
+//! Bulk writes and large directory walks can starve latency-sensitive
+//! workloads sharing the same disks and cores. Operations that accept a
+//! [`ResourcePriority`] run on a dedicated thread whose IO class and nice
+//! level are lowered for the duration of the operation.
+//!
+//! # Platform Support
+//!
+//! - **Linux**: IO class via `ioprio_set(2)`, CPU niceness via
+//!   `setpriority(2)` on the worker thread.
+//! - **Windows**: the worker thread enters background processing mode
+//!   (`THREAD_MODE_BACKGROUND_BEGIN`), which lowers both IO and CPU priority.
+//!   Best-effort IO levels are not distinguished.
+//! - **Other platforms**: the settings are accepted and ignored.
+
+// Layer 1: Standard library imports
+use std::fmt;
+
+// Layer 2: Third-party crate imports
+use serde::{Deserialize, Serialize};
+
+// Layer 3: Internal module imports
+use crate::core::result::{OSError, OSResult};
+
+/// Lowest (least favourable) best-effort IO level.
+pub const MAX_BEST_EFFORT_LEVEL: u8 = 7;
+
+/// Nice level range accepted by [`ResourcePriority::with_nice`].
+pub const NICE_RANGE: std::ops::RangeInclusive<i32> = -20..=19;
+
+/// IO scheduling class for an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IoPriority {
+    /// Best-effort scheduling at a level from 0 (highest) to 7 (lowest)
+    BestEffort(u8),
+    /// Only served when no other process needs the disk
+    Idle,
+}
+
+impl fmt::Display for IoPriority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BestEffort(level) => write!(f, "best-effort:{level}"),
+            Self::Idle => write!(f, "idle"),
+        }
+    }
+}
+
+/// IO and CPU priority applied while an operation runs.
+///
+/// The default leaves both untouched, in which case the operation runs
+/// exactly as it would without a priority.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_osl::operations::{IoPriority, ResourcePriority};
+///
+/// let priority = ResourcePriority::background();
+/// assert_eq!(priority.io, Some(IoPriority::Idle));
+///
+/// let custom = ResourcePriority::default()
+///     .with_io(IoPriority::BestEffort(6))
+///     .with_nice(5)
+///     .unwrap();
+/// assert_eq!(custom.nice, Some(5));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourcePriority {
+    /// IO scheduling class (`None` keeps the current class)
+    pub io: Option<IoPriority>,
+
+    /// CPU nice level (`None` keeps the current level)
+    pub nice: Option<i32>,
+}
+
+impl ResourcePriority {
+    /// Idle IO class and nice level 10, for bulk work that should only use
+    /// otherwise spare capacity.
+    pub fn background() -> Self {
+        Self {
+            io: Some(IoPriority::Idle),
+            nice: Some(10),
+        }
+    }
+
+    /// Set the IO scheduling class.
+    ///
+    /// Best-effort levels above [`MAX_BEST_EFFORT_LEVEL`] are clamped.
+    pub fn with_io(mut self, io: IoPriority) -> Self {
+        self.io = Some(match io {
+            IoPriority::BestEffort(level) => {
+                IoPriority::BestEffort(level.min(MAX_BEST_EFFORT_LEVEL))
+            }
+            IoPriority::Idle => IoPriority::Idle,
+        });
+        self
+    }
+
+    /// Set the CPU nice level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `nice` is outside [`NICE_RANGE`].
+    pub fn with_nice(mut self, nice: i32) -> OSResult<Self> {
+        if !NICE_RANGE.contains(&nice) {
+            return Err(OSError::configuration_error(format!(
+                "nice level {nice} outside {}..={}",
+                NICE_RANGE.start(),
+                NICE_RANGE.end()
+            )));
+        }
+        self.nice = Some(nice);
+        Ok(self)
+    }
+
+    /// Whether neither IO class nor nice level is changed.
+    pub fn is_unchanged(&self) -> bool {
+        self.io.is_none() && self.nice.is_none()
+    }
+}
+
+impl fmt::Display for ResourcePriority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.io, self.nice) {
+            (Some(io), Some(nice)) => write!(f, "io={io},nice={nice}"),
+            (Some(io), None) => write!(f, "io={io}"),
+            (None, Some(nice)) => write!(f, "nice={nice}"),
+            (None, None) => write!(f, "unchanged"),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resource_priority_builders() {
+        let priority = ResourcePriority::default()
+            .with_io(IoPriority::BestEffort(42))
+            .with_nice(19)
+            .unwrap();
+        assert_eq!(priority.io, Some(IoPriority::BestEffort(7)));
+        assert_eq!(priority.to_string(), "io=best-effort:7,nice=19");
+        assert!(ResourcePriority::default().is_unchanged());
+        assert!(!ResourcePriority::background().is_unchanged());
+    }
+
+    #[test]
+    fn test_resource_priority_rejects_out_of_range_nice() {
+        assert!(ResourcePriority::default().with_nice(20).is_err());
+        assert!(ResourcePriority::default().with_nice(-21).is_err());
+    }
+}
@@ -8,6 +8,7 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 // Layer 3: Internal module imports
+use super::ResourcePriority;
 use crate::core::operation::{Operation, OperationType, Permission};
 
 /// Operation to write data to a file.
@@ -42,6 +43,9 @@ pub struct FileWriteOperation {
 
     /// Optional operation ID
     pub operation_id: Option<String>,
+
+    /// IO and CPU priority applied while the operation runs
+    pub priority: ResourcePriority,
 }
 
 impl FileWriteOperation {
@@ -67,6 +71,7 @@ impl FileWriteOperation {
             append: false,
             created_at: Utc::now(),
             operation_id: None,
+            priority: ResourcePriority::default(),
         }
     }
 
@@ -92,6 +97,7 @@ impl FileWriteOperation {
             append: true,
             created_at: Utc::now(),
             operation_id: None,
+            priority: ResourcePriority::default(),
         }
     }
 
@@ -108,6 +114,7 @@ impl FileWriteOperation {
             append,
             created_at,
             operation_id: None,
+            priority: ResourcePriority::default(),
         }
     }
 
@@ -116,6 +123,12 @@ impl FileWriteOperation {
         self.operation_id = Some(id.into());
         self
     }
+
+    /// Run with the given IO and CPU priority.
+    pub fn with_priority(mut self, priority: ResourcePriority) -> Self {
+        self.priority = priority;
+        self
+    }
 }
 
 impl Operation for FileWriteOperation {
@@ -55,8 +55,11 @@ pub mod network;
 
 // Re-export all operation types for convenient access
 pub use filesystem::{
-    DirectoryCreateOperation, DirectoryListOperation, FileDeleteOperation, FileReadOperation,
-    FileWriteOperation,
+    AtomicFileWriteOperation, DirectoryCreateOperation, DirectoryListOperation, DiskUsageOperation,
+    FileAclModifyOperation, FileAclReadOperation, FileDeleteOperation, FileReadOperation,
+    FileWriteOperation, FilesystemSpaceOperation, IoPriority, MountListOperation, ResourcePriority,
+};
+pub use network::{
+    NamedPipeOperation, NetworkConnectOperation, NetworkListenOperation, NetworkSocketOperation,
 };
-pub use network::{NetworkConnectOperation, NetworkListenOperation, NetworkSocketOperation};
 pub use process::{ProcessKillOperation, ProcessSignalOperation, ProcessSpawnOperation};
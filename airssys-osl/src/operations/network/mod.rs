@@ -7,9 +7,11 @@
 // Module declarations
 pub mod connect;
 pub mod listen;
+pub mod named_pipe;
 pub mod socket;
 
 // Re-export all operation types for convenient access
 pub use connect::NetworkConnectOperation;
 pub use listen::NetworkListenOperation;
+pub use named_pipe::{NamedPipeOperation, PipeRole};
 pub use socket::NetworkSocketOperation;
@@ -0,0 +1,140 @@
+//! Named pipe operation (Windows).
+
+// Layer 1: Standard library imports
+use std::fmt;
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// Layer 3: Internal module imports
+use crate::core::operation::{Operation, OperationType, Permission};
+
+/// Side of a named pipe connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipeRole {
+    /// Create the pipe and wait for a client to connect
+    Server,
+    /// Connect to an existing pipe
+    Client,
+}
+
+/// Operation to create or connect to a Windows named pipe.
+///
+/// Pipe names use the `\\.\pipe\<name>` form. Creating a pipe server
+/// requires the NetworkSocket permission (elevated), connecting as a client
+/// requires NetworkConnect for the pipe name. Only executable on Windows.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_osl::operations::NamedPipeOperation;
+/// use std::time::Duration;
+///
+/// let server = NamedPipeOperation::server(r"\\.\pipe\airssys-control")
+///     .with_timeout(Duration::from_secs(5));
+/// let client = NamedPipeOperation::client(r"\\.\pipe\airssys-control");
+/// ```
+#[derive(Debug, Clone)]
+pub struct NamedPipeOperation {
+    /// Full pipe name (e.g. `\\.\pipe\airssys-control`)
+    pub name: String,
+
+    /// Whether to create the pipe or connect to it
+    pub role: PipeRole,
+
+    /// How long to wait for the peer (None = wait indefinitely)
+    pub timeout: Option<Duration>,
+
+    /// When this operation was created
+    pub created_at: DateTime<Utc>,
+
+    /// Optional operation ID
+    pub operation_id: Option<String>,
+}
+
+impl NamedPipeOperation {
+    /// Create a named pipe operation.
+    pub fn new(name: impl Into<String>, role: PipeRole) -> Self {
+        Self {
+            name: name.into(),
+            role,
+            timeout: None,
+            created_at: Utc::now(),
+            operation_id: None,
+        }
+    }
+
+    /// Create the pipe and wait for a client.
+    pub fn server(name: impl Into<String>) -> Self {
+        Self::new(name, PipeRole::Server)
+    }
+
+    /// Connect to an existing pipe.
+    pub fn client(name: impl Into<String>) -> Self {
+        Self::new(name, PipeRole::Client)
+    }
+
+    /// Set how long to wait for the peer.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set custom operation ID.
+    pub fn with_operation_id(mut self, id: impl Into<String>) -> Self {
+        self.operation_id = Some(id.into());
+        self
+    }
+}
+
+impl Operation for NamedPipeOperation {
+    fn operation_type(&self) -> OperationType {
+        OperationType::Network
+    }
+
+    fn required_permissions(&self) -> Vec<Permission> {
+        match self.role {
+            PipeRole::Server => vec![Permission::NetworkSocket],
+            PipeRole::Client => vec![Permission::NetworkConnect(self.name.clone())],
+        }
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn operation_id(&self) -> String {
+        self.operation_id
+            .clone()
+            .unwrap_or_else(|| Uuid::new_v4().to_string())
+    }
+}
+
+impl fmt::Display for NamedPipeOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NamedPipe({:?}, {})", self.role, self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_pipe_permissions_by_role() {
+        let server = NamedPipeOperation::server(r"\\.\pipe\test");
+        assert_eq!(
+            server.required_permissions(),
+            vec![Permission::NetworkSocket]
+        );
+        assert!(server.requires_elevated_privileges());
+
+        let client = NamedPipeOperation::client(r"\\.\pipe\test");
+        assert_eq!(
+            client.required_permissions(),
+            vec![Permission::NetworkConnect(r"\\.\pipe\test".to_string())]
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Job object limits for spawned processes (Windows).
+
+// Layer 1: Standard library imports
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+// (none needed for this module)
+
+// Layer 3: Internal module imports
+// (none needed for this module)
+
+/// Resource limits enforced through a Windows job object.
+///
+/// When attached to a [`ProcessSpawnOperation`](super::ProcessSpawnOperation),
+/// the process executor creates a job object with these limits and assigns
+/// the spawned process to it. Limits are inherited by every child process
+/// the spawned process creates.
+///
+/// Job objects are a Windows facility; on other platforms the process
+/// executor rejects operations carrying job limits instead of silently
+/// ignoring them.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_osl::operations::process::JobLimits;
+/// use std::time::Duration;
+///
+/// let limits = JobLimits::new()
+///     .max_process_memory(256 * 1024 * 1024)
+///     .max_active_processes(4)
+///     .max_cpu_time(Duration::from_secs(30));
+/// assert!(!limits.is_empty());
+/// assert!(JobLimits::new().is_empty());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JobLimits {
+    /// Committed memory limit per process, in bytes
+    pub process_memory_bytes: Option<u64>,
+
+    /// Maximum number of simultaneously active processes in the job
+    pub active_processes: Option<u32>,
+
+    /// User-mode CPU time limit per process
+    pub cpu_time: Option<Duration>,
+}
+
+impl JobLimits {
+    /// Create an empty set of limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit the committed memory of each process in the job.
+    pub fn max_process_memory(mut self, bytes: u64) -> Self {
+        self.process_memory_bytes = Some(bytes);
+        self
+    }
+
+    /// Limit the number of simultaneously active processes in the job.
+    pub fn max_active_processes(mut self, count: u32) -> Self {
+        self.active_processes = Some(count);
+        self
+    }
+
+    /// Limit the user-mode CPU time of each process in the job.
+    pub fn max_cpu_time(mut self, limit: Duration) -> Self {
+        self.cpu_time = Some(limit);
+        self
+    }
+
+    /// Returns true if no limit is set.
+    pub fn is_empty(&self) -> bool {
+        self.process_memory_bytes.is_none()
+            && self.active_processes.is_none()
+            && self.cpu_time.is_none()
+    }
+}
@@ -9,6 +9,8 @@
 //! - [`ProcessSpawnOperation`] - Spawn new processes with command, args, and environment
 //! - [`ProcessKillOperation`] - Terminate processes by PID
 //! - [`ProcessSignalOperation`] - Send signals to processes
+//! - [`JobLimits`] - Windows job object limits for spawned processes
+//! - [`PtySize`] - Pseudo-terminal size for interactive processes
 //!
 //! # Security Notes
 //!
@@ -34,11 +36,15 @@
 //! ```
 
 // Operation modules
+pub mod job;
 pub mod kill;
+pub mod pty;
 pub mod signal;
 pub mod spawn;
 
 // Re-export all operation types
+pub use job::JobLimits;
 pub use kill::ProcessKillOperation;
+pub use pty::PtySize;
 pub use signal::ProcessSignalOperation;
 pub use spawn::ProcessSpawnOperation;
@@ -0,0 +1,61 @@
+//! Pseudo-terminal dimensions for interactive processes.
+
+// Layer 1: Standard library imports
+use std::fmt;
+
+// Layer 2: Third-party crate imports
+// (none needed for this module)
+
+// Layer 3: Internal module imports
+// (none needed for this module)
+
+/// Size of a pseudo-terminal, in character cells.
+///
+/// When attached to a [`ProcessSpawnOperation`](super::ProcessSpawnOperation),
+/// the process executor runs the process on a pseudo-terminal of this size
+/// (a pty on Unix, a ConPTY pseudo console on Windows) instead of pipes.
+/// The terminal can be resized later through the session handle.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_osl::operations::process::PtySize;
+///
+/// let size = PtySize::new(24, 80);
+/// assert_eq!(size.rows, 24);
+/// assert_eq!(size.to_string(), "80x24");
+/// assert_eq!(PtySize::default(), size);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PtySize {
+    /// Number of rows
+    pub rows: u16,
+
+    /// Number of columns
+    pub cols: u16,
+}
+
+impl PtySize {
+    /// Create a terminal size of `rows` by `cols` cells.
+    pub fn new(rows: u16, cols: u16) -> Self {
+        Self { rows, cols }
+    }
+
+    /// Returns true if either dimension is zero.
+    pub fn is_empty(&self) -> bool {
+        self.rows == 0 || self.cols == 0
+    }
+}
+
+impl Default for PtySize {
+    /// The classic 80x24 terminal.
+    fn default() -> Self {
+        Self::new(24, 80)
+    }
+}
+
+impl fmt::Display for PtySize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{}", self.cols, self.rows)
+    }
+}
@@ -9,6 +9,8 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 // Layer 3: Internal module imports
+use super::job::JobLimits;
+use super::pty::PtySize;
 use crate::core::operation::{Operation, OperationType, Permission};
 
 /// Operation to spawn a new process.
@@ -56,6 +58,12 @@ pub struct ProcessSpawnOperation {
     /// Working directory (None = inherit from parent)
     pub working_dir: Option<String>,
 
+    /// Job object limits (Windows only, None = no job object)
+    pub job_limits: Option<JobLimits>,
+
+    /// Pseudo-terminal to run the process on (None = pipes)
+    pub pty: Option<PtySize>,
+
     /// When this operation was created
     pub created_at: DateTime<Utc>,
 
@@ -85,6 +93,8 @@ impl ProcessSpawnOperation {
             args: Vec::new(),
             env: HashMap::new(),
             working_dir: None,
+            job_limits: None,
+            pty: None,
             created_at: Utc::now(),
             operation_id: None,
         }
@@ -176,6 +186,47 @@ impl ProcessSpawnOperation {
         self
     }
 
+    /// Run the process inside a Windows job object with the given limits.
+    ///
+    /// Only supported on Windows; other platforms reject the operation
+    /// during validation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use airssys_osl::operations::ProcessSpawnOperation;
+    /// use airssys_osl::operations::process::JobLimits;
+    ///
+    /// let op = ProcessSpawnOperation::new("worker.exe")
+    ///     .with_job_limits(JobLimits::new().max_active_processes(1));
+    /// assert!(op.job_limits.is_some());
+    /// ```
+    pub fn with_job_limits(mut self, limits: JobLimits) -> Self {
+        self.job_limits = Some(limits);
+        self
+    }
+
+    /// Run the process on a pseudo-terminal of the given size.
+    ///
+    /// The process gets a pty (Unix) or ConPTY pseudo console (Windows) as
+    /// its controlling terminal, so shells and terminal tools behave as
+    /// they would interactively. The executor keeps the session; see
+    /// `ProcessExecutor::take_pty_session`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use airssys_osl::operations::ProcessSpawnOperation;
+    /// use airssys_osl::operations::process::PtySize;
+    ///
+    /// let op = ProcessSpawnOperation::new("bash").with_pty(PtySize::new(24, 80));
+    /// assert_eq!(op.pty, Some(PtySize::new(24, 80)));
+    /// ```
+    pub fn with_pty(mut self, size: PtySize) -> Self {
+        self.pty = Some(size);
+        self
+    }
+
     /// Create with explicit timestamp (for testing).
     pub fn with_timestamp(
         command: impl Into<String>,
@@ -187,6 +238,8 @@ impl ProcessSpawnOperation {
             args,
             env: HashMap::new(),
             working_dir: None,
+            job_limits: None,
+            pty: None,
             created_at,
             operation_id: None,
         }
@@ -225,16 +278,14 @@ impl Operation for ProcessSpawnOperation {
 
 impl fmt::Display for ProcessSpawnOperation {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.args.is_empty() {
-            write!(f, "ProcessSpawn({})", self.command)
-        } else {
-            write!(
-                f,
-                "ProcessSpawn({} [{}])",
-                self.command,
-                self.args.join(" ")
-            )
+        write!(f, "ProcessSpawn({}", self.command)?;
+        if !self.args.is_empty() {
+            write!(f, " [{}]", self.args.join(" "))?;
         }
+        if let Some(size) = self.pty {
+            write!(f, " pty {size}")?;
+        }
+        write!(f, ")")
     }
 }
 
@@ -288,6 +339,15 @@ mod tests {
         assert!(op.requires_elevated_privileges());
     }
 
+    #[test]
+    fn test_process_spawn_display_includes_pty() {
+        let op = ProcessSpawnOperation::new("bash").arg("-l");
+        assert_eq!(op.to_string(), "ProcessSpawn(bash [-l])");
+
+        let op = op.with_pty(PtySize::new(30, 100));
+        assert_eq!(op.to_string(), "ProcessSpawn(bash [-l] pty 100x30)");
+    }
+
     #[test]
     fn test_process_spawn_operation_type() {
         let op = ProcessSpawnOperation::new("test");
@@ -93,7 +93,7 @@ pub use crate::middleware::logger::{LogFormat, LogLevel};
 
 // Procedural macros for ergonomic implementations (optional feature)
 #[cfg(feature = "macros")]
-pub use airssys_osl_macros::executor;
+pub use airssys_osl_macros::{executor, pipeline};
 
 // Standard library re-exports for convenience
 pub use chrono::{DateTime, Utc};
@@ -0,0 +1,160 @@
+//! Integration tests for per-operation middleware controls in composition API.
+//!
+//! Tests skipping middleware, the safety policy that protects security
+//! middleware, extra policies layered onto a pipeline, and timeouts.
+
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+use std::fs;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use airssys_osl::core::context::ExecutionContext;
+use airssys_osl::core::middleware::{Middleware, MiddlewareResult};
+use airssys_osl::core::security::SecurityConfig;
+use airssys_osl::helpers::composition::{FileHelper, HelperPipeline};
+use airssys_osl::middleware::controls::MiddlewareSafetyPolicy;
+use airssys_osl::middleware::security::acl::{AccessControlList, AclEntry, AclPolicy};
+use airssys_osl::middleware::security::middleware::SecurityMiddlewareBuilder;
+use airssys_osl::operations::filesystem::FileReadOperation;
+
+/// Middleware that records each time its `before_execution` hook runs.
+#[derive(Debug, Clone)]
+struct RecordingMiddleware {
+    name: String,
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl RecordingMiddleware {
+    fn new(name: impl Into<String>, calls: Arc<Mutex<Vec<String>>>) -> Self {
+        Self {
+            name: name.into(),
+            calls,
+        }
+    }
+}
+
+#[async_trait]
+impl Middleware<FileReadOperation> for RecordingMiddleware {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn before_execution(
+        &self,
+        operation: FileReadOperation,
+        _context: &ExecutionContext,
+    ) -> MiddlewareResult<Option<FileReadOperation>> {
+        self.calls.lock().unwrap().push(self.name.clone());
+        Ok(Some(operation))
+    }
+}
+
+fn acl(policy: AclPolicy) -> AccessControlList {
+    AccessControlList::new().add_entry(AclEntry::new(
+        "testuser".to_string(),
+        "*".to_string(),
+        vec!["*".to_string()],
+        policy,
+    ))
+}
+
+fn temp_file(content: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("test_controls_{}.txt", uuid::Uuid::new_v4()));
+    fs::write(&path, content).expect("Failed to create test file");
+    path
+}
+
+#[tokio::test]
+async fn test_skip_logging_bypasses_logger_only() {
+    let path = temp_file("hot path");
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let helper = FileHelper::builder()
+        .with_middleware(RecordingMiddleware::new("logger", Arc::clone(&calls)))
+        .with_middleware(RecordingMiddleware::new("metrics", Arc::clone(&calls)))
+        .skip_logging()
+        .unwrap();
+
+    let data = helper.read(&path, "testuser").await.unwrap();
+
+    assert_eq!(data, b"hot path");
+    assert_eq!(*calls.lock().unwrap(), vec!["metrics".to_string()]);
+    fs::remove_file(path).ok();
+}
+
+#[tokio::test]
+async fn test_controls_survive_later_middleware() {
+    let path = temp_file("data");
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    // Skip requested before the logger is added still applies to it
+    let helper = FileHelper::builder()
+        .skip_logging()
+        .unwrap()
+        .with_middleware(RecordingMiddleware::new("logger", Arc::clone(&calls)));
+
+    helper.read(&path, "testuser").await.unwrap();
+
+    assert!(calls.lock().unwrap().is_empty());
+    assert_eq!(
+        helper.controls().skipped().collect::<Vec<_>>(),
+        vec!["logger"]
+    );
+    fs::remove_file(path).ok();
+}
+
+#[test]
+fn test_security_cannot_be_skipped() {
+    let result = FileHelper::builder().skip_middleware("security");
+    assert!(result.unwrap_err().is_configuration_error());
+}
+
+#[test]
+fn test_safety_policy_can_protect_logging() {
+    let result = FileHelper::builder()
+        .with_safety_policy(MiddlewareSafetyPolicy::new().protect("logger"))
+        .unwrap()
+        .skip_logging();
+    assert!(result.is_err());
+
+    let result = FileHelper::builder()
+        .skip_logging()
+        .unwrap()
+        .with_safety_policy(MiddlewareSafetyPolicy::new().protect("logger"));
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_extra_policy_narrows_pipeline() {
+    let path = temp_file("secret");
+    let security = SecurityMiddlewareBuilder::new()
+        .with_config(SecurityConfig::default())
+        .add_policy(Box::new(acl(AclPolicy::Allow)))
+        .build()
+        .unwrap();
+
+    let helper = FileHelper::builder()
+        .with_security(security)
+        .with_extra_policy(acl(AclPolicy::Deny))
+        .unwrap();
+
+    let result = helper.read(&path, "testuser").await;
+
+    assert!(result.is_err(), "extra deny policy should block the read");
+    fs::remove_file(path).ok();
+}
+
+#[tokio::test]
+async fn test_timeout_allows_fast_operations() {
+    let path = temp_file("quick");
+
+    let helper = FileHelper::builder().with_timeout(Duration::from_secs(5));
+
+    assert_eq!(helper.controls().timeout(), Some(Duration::from_secs(5)));
+    assert_eq!(helper.read(&path, "testuser").await.unwrap(), b"quick");
+    fs::remove_file(path).ok();
+}
@@ -0,0 +1,103 @@
+//! Integration tests for the pipeline! macro with real airssys-osl types
+//!
+//! These tests verify that declared middleware runs in declaration order
+//! around the wrapped executor.
+
+#![cfg(feature = "macros")]
+#![allow(clippy::unwrap_used)]
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+
+use airssys_osl::core::executor::OSExecutor;
+use airssys_osl::core::middleware::{Middleware, MiddlewareResult};
+use airssys_osl::prelude::*;
+
+/// Middleware that records its hooks in a shared log.
+#[derive(Debug)]
+struct Recorder {
+    name: &'static str,
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl Middleware<FileReadOperation> for Recorder {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    async fn before_execution(
+        &self,
+        operation: FileReadOperation,
+        _context: &ExecutionContext,
+    ) -> MiddlewareResult<Option<FileReadOperation>> {
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{} before", self.name));
+        Ok(Some(operation))
+    }
+
+    async fn after_execution(
+        &self,
+        _context: &ExecutionContext,
+        _result: &OSResult<ExecutionResult>,
+    ) -> MiddlewareResult<()> {
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{} after", self.name));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct StubExecutor;
+
+#[executor]
+impl StubExecutor {
+    async fn file_read(
+        &self,
+        operation: FileReadOperation,
+        context: &ExecutionContext,
+    ) -> OSResult<ExecutionResult> {
+        let _ = (operation, context);
+        Ok(ExecutionResult::success(b"content".to_vec()))
+    }
+}
+
+#[tokio::test]
+async fn test_pipeline_runs_middleware_in_declaration_order() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let recorder = |name| Recorder {
+        name,
+        log: Arc::clone(&log),
+    };
+
+    let executor = pipeline! {
+        executor: StubExecutor,
+        security: recorder("security"),
+        logger: recorder("logger"),
+        custom: recorder("custom"),
+    };
+
+    let context = ExecutionContext::new(SecurityContext::new("test-user".to_string()));
+    let result = executor
+        .execute(FileReadOperation::new("/tmp/test.txt"), &context)
+        .await
+        .unwrap();
+
+    assert_eq!(result.output, b"content");
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            "security before",
+            "logger before",
+            "custom before",
+            "custom after",
+            "logger after",
+            "security after",
+        ]
+    );
+}
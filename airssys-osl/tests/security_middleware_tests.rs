@@ -554,3 +554,78 @@ async fn test_middleware_with_disabled_logging() {
         !<airssys_osl::middleware::security::SecurityMiddleware as Middleware<FileReadOperation>>::is_enabled(&middleware)
     );
 }
+
+/// Policy that denies everything within a fixed scope.
+#[derive(Debug)]
+struct ScopedDenyPolicy(airssys_osl::middleware::security::policy::PolicyScope);
+
+impl airssys_osl::middleware::security::policy::SecurityPolicy for ScopedDenyPolicy {
+    fn evaluate(
+        &self,
+        _context: &SecurityContext,
+    ) -> airssys_osl::middleware::security::policy::PolicyDecision {
+        airssys_osl::middleware::security::policy::PolicyDecision::Deny("scoped deny".to_string())
+    }
+
+    fn description(&self) -> &str {
+        "scoped deny policy"
+    }
+
+    fn scope(&self) -> airssys_osl::middleware::security::policy::PolicyScope {
+        self.0
+    }
+}
+
+#[tokio::test]
+async fn test_scoped_policies_only_evaluate_for_their_operation_type() {
+    use airssys_osl::core::operation::OperationType;
+    use airssys_osl::middleware::security::policy::PolicyScope;
+    use airssys_osl::operations::network::connect::NetworkConnectOperation;
+
+    let acl = AccessControlList::new().add_entry(AclEntry::new(
+        "testuser".to_string(),
+        "*".to_string(),
+        vec!["*".to_string()],
+        AclPolicy::Allow,
+    ));
+
+    let middleware = SecurityMiddlewareBuilder::new()
+        .add_policy(Box::new(acl))
+        .add_policy(Box::new(ScopedDenyPolicy(PolicyScope::Filesystem)))
+        .build()
+        .expect("Failed to build middleware");
+
+    assert_eq!(middleware.policy_count(), 2);
+    assert_eq!(
+        middleware.policies_for(OperationType::Filesystem).count(),
+        2
+    );
+    assert_eq!(middleware.policies_for(OperationType::Network).count(), 1);
+
+    let context = ExecutionContext::new(SecurityContext::new("testuser".to_string()));
+
+    // Filesystem-only deny does not run for network operations
+    let network = NetworkConnectOperation::new("127.0.0.1:8080".to_string());
+    assert!(middleware.before_execution(network, &context).await.is_ok());
+
+    let read = FileReadOperation::new("/tmp/data".to_string());
+    let result = middleware.before_execution(read, &context).await;
+    assert!(format!("{:?}", result.unwrap_err()).contains("scoped deny"));
+}
+
+#[tokio::test]
+async fn test_no_applicable_policy_denies_by_default() {
+    use airssys_osl::middleware::security::policy::PolicyScope;
+
+    let middleware = SecurityMiddlewareBuilder::new()
+        .add_policy(Box::new(ScopedDenyPolicy(PolicyScope::Network)))
+        .build()
+        .expect("Failed to build middleware");
+
+    let operation = FileReadOperation::new("/tmp/data".to_string());
+    let context = ExecutionContext::new(SecurityContext::new("testuser".to_string()));
+
+    let result = middleware.before_execution(operation, &context).await;
+    let error_msg = format!("{:?}", result.unwrap_err());
+    assert!(error_msg.contains("No security policies apply to filesystem operations"));
+}
@@ -25,7 +25,9 @@ use serde::{Deserialize, Serialize};
 use airssys_rt::broker::in_memory::InMemoryMessageBroker;
 use airssys_rt::broker::MessageBroker;
 use airssys_rt::mailbox::traits::MailboxSender;
-use airssys_rt::mailbox::{AtomicMetrics, BoundedMailbox};
+use airssys_rt::mailbox::{
+    AtomicMetrics, BoundedMailbox, MetricsRecorder, NoOpMetrics, SampledMetrics,
+};
 use airssys_rt::message::{Message, MessageEnvelope};
 use airssys_rt::{Actor, ActorContext};
 
@@ -150,7 +152,7 @@ fn mailbox_operations(c: &mut Criterion) {
 
     c.bench_function("mailbox_operations", |b| {
         b.to_async(&rt).iter(|| async {
-            let (mailbox, sender) = BoundedMailbox::<TestMessage, AtomicMetrics>::new(1000);
+            let (mailbox, sender) = BoundedMailbox::with_metrics(1000, AtomicMetrics::new());
 
             // Enqueue 100 messages
             for i in 0..100 {
@@ -167,6 +169,54 @@ fn mailbox_operations(c: &mut Criterion) {
     });
 }
 
+/// Send 100 messages through a mailbox with the given metrics recorder
+async fn fill_mailbox<R: MetricsRecorder + Clone + 'static>(metrics: R) {
+    let (mailbox, sender) = BoundedMailbox::<TestMessage, R>::with_metrics(1000, metrics);
+    for i in 0..100 {
+        let msg = TestMessage {
+            id: i,
+            payload: String::new(),
+        };
+        sender.send(MessageEnvelope::new(msg)).await.unwrap();
+    }
+    black_box((mailbox, sender));
+}
+
+/// Benchmark: Per-operation cost of each metrics recorder
+///
+/// Compares full counting, 1-in-64 sampling, and no-op recording, both as
+/// raw recorder calls and inside mailbox sends.
+fn mailbox_metrics_overhead(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mailbox_metrics_overhead");
+
+    let atomic = AtomicMetrics::new();
+    group.bench_function("record_1000/atomic", |b| {
+        b.iter(|| (0..1000).for_each(|_| black_box(&atomic).record_sent()))
+    });
+    let sampled = SampledMetrics::new(64);
+    group.bench_function("record_1000/sampled_64", |b| {
+        b.iter(|| (0..1000).for_each(|_| black_box(&sampled).record_sent()))
+    });
+    let noop = NoOpMetrics;
+    group.bench_function("record_1000/noop", |b| {
+        b.iter(|| (0..1000).for_each(|_| black_box(&noop).record_sent()))
+    });
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    group.bench_function("send_100/atomic", |b| {
+        b.to_async(&rt).iter(|| fill_mailbox(AtomicMetrics::new()))
+    });
+    group.bench_function("send_100/sampled_64", |b| {
+        b.to_async(&rt)
+            .iter(|| fill_mailbox(SampledMetrics::new(64)))
+    });
+    group.bench_function("send_100/noop", |b| {
+        b.to_async(&rt).iter(|| fill_mailbox(NoOpMetrics))
+    });
+
+    group.finish();
+}
+
 /// Configure criterion for resource-conscious benchmarking
 fn configure_criterion() -> Criterion {
     Criterion::default()
@@ -183,7 +233,8 @@ criterion_group! {
         message_send_receive,
         message_throughput,
         message_broadcast_small,
-        mailbox_operations
+        mailbox_operations,
+        mailbox_metrics_overhead
 }
 
 criterion_main!(benches);
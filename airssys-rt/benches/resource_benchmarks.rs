@@ -125,7 +125,7 @@ fn mailbox_memory_comparison(c: &mut Criterion) {
     group.bench_function("unbounded_mailbox", |b| {
         b.to_async(&rt).iter(|| async {
             let mailboxes: Vec<_> = (0..10)
-                .map(|_| UnboundedMailbox::<BenchMessage, AtomicMetrics>::new())
+                .map(|_| UnboundedMailbox::<BenchMessage, _>::with_metrics(AtomicMetrics::new()))
                 .collect();
 
             black_box(mailboxes);
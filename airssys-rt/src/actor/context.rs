@@ -9,8 +9,12 @@ use std::marker::PhantomData;
 use chrono::{DateTime, Utc}; // §3.2 MANDATORY
 
 // Layer 3: Internal module imports
+use super::retry::{CircuitBreakerConfig, CircuitBreakers, CircuitState, RetryError, RetryPolicy};
+use super::service::{Service, ServiceError, ServiceKey, ServiceRegistry};
+use super::stash::{Stash, StashConfig, StashFull};
 use crate::broker::MessageBroker;
-use crate::message::{Message, MessageEnvelope};
+use crate::message::envelope::remaining_until;
+use crate::message::{Message, MessageEnvelope, MessagePriority};
 use crate::util::{ActorAddress, ActorId};
 
 /// Actor context with metadata and state management.
@@ -158,6 +162,12 @@ pub struct ActorContext<M: Message, B: MessageBroker<M>> {
     created_at: DateTime<Utc>,
     last_message_at: Option<DateTime<Utc>>,
     message_count: u64,
+    deadline: Option<DateTime<Utc>>,
+    priority: MessagePriority,
+    priority_cap: MessagePriority,
+    stash: Stash<M>,
+    circuits: CircuitBreakers,
+    services: ServiceRegistry,
     broker: B, // Dependency injection (ADR-006)
     _marker: PhantomData<M>,
 }
@@ -195,11 +205,25 @@ impl<M: Message, B: MessageBroker<M>> ActorContext<M, B> {
             created_at: Utc::now(), // §3.2
             last_message_at: None,
             message_count: 0,
+            deadline: None,
+            priority: MessagePriority::Normal,
+            priority_cap: MessagePriority::DEFAULT_INHERITANCE_CAP,
+            stash: Stash::default(),
+            circuits: CircuitBreakers::default(),
+            services: ServiceRegistry::new(),
             broker,
             _marker: PhantomData,
         }
     }
 
+    /// Use `services` to resolve [`Service`] handles.
+    ///
+    /// The actor system passes its own registry to every context it creates.
+    pub fn with_services(mut self, services: ServiceRegistry) -> Self {
+        self.services = services;
+        self
+    }
+
     /// Get the actor's address.
     ///
     /// Returns the full actor address including ID and optional name.
@@ -274,6 +298,138 @@ impl<M: Message, B: MessageBroker<M>> ActorContext<M, B> {
         self.message_count += 1;
     }
 
+    /// Get the deadline of the message currently being handled, if any.
+    pub fn deadline(&self) -> Option<DateTime<Utc>> {
+        self.deadline
+    }
+
+    /// Get the time left before the current message's deadline.
+    ///
+    /// Returns `None` when the message has no deadline and
+    /// `Some(Duration::ZERO)` once the deadline has passed. Long-running
+    /// handlers can check this to abandon work nobody is waiting for.
+    pub fn remaining_deadline(&self) -> Option<std::time::Duration> {
+        self.deadline.map(remaining_until)
+    }
+
+    /// Set the deadline inherited by messages sent from this context.
+    ///
+    /// The actor system sets this from the incoming envelope before each
+    /// `handle_message()` call and clears it afterwards.
+    pub fn set_deadline(&mut self, deadline: Option<DateTime<Utc>>) {
+        self.deadline = deadline;
+    }
+
+    /// Get the priority of the message currently being handled.
+    pub fn priority(&self) -> MessagePriority {
+        self.priority
+    }
+
+    /// Set the priority inherited by messages sent from this context.
+    ///
+    /// The actor system sets this from the incoming envelope before each
+    /// `handle_message()` call and resets it to `Normal` afterwards. See
+    /// [`MessagePriority::inherit`] for how it applies to outgoing messages.
+    pub fn set_priority(&mut self, priority: MessagePriority) {
+        self.priority = priority;
+    }
+
+    /// Highest priority outgoing messages may inherit from this context.
+    ///
+    /// Defaults to [`MessagePriority::DEFAULT_INHERITANCE_CAP`].
+    pub fn set_priority_cap(&mut self, cap: MessagePriority) {
+        self.priority_cap = cap;
+    }
+
+    /// Apply this context's inherited priority to an outgoing envelope.
+    fn inherit_priority(&self, envelope: &mut MessageEnvelope<M>) {
+        envelope.priority = envelope.priority.inherit(self.priority, self.priority_cap);
+    }
+
+    /// Stash a message the actor cannot handle in its current state.
+    ///
+    /// The message keeps the deadline of the envelope it arrived in. Call
+    /// [`unstash`](Self::unstash) or [`unstash_all`](Self::unstash_all)
+    /// after the state transition to have it redelivered.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StashFull`] with the message when the stash is at capacity
+    /// and the overflow policy is [`StashOverflow::Reject`](super::StashOverflow::Reject).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// async fn handle_message<B: MessageBroker<Self::Message>>(
+    ///     &mut self,
+    ///     msg: Self::Message,
+    ///     ctx: &mut ActorContext<Self::Message, B>,
+    /// ) -> Result<(), Self::Error> {
+    ///     match (&self.state, msg) {
+    ///         (State::Connecting, Msg::Connected) => {
+    ///             self.state = State::Ready;
+    ///             ctx.unstash_all();
+    ///         }
+    ///         (State::Connecting, other) => {
+    ///             ctx.stash(other).map_err(|e| MyError::Busy(e.capacity))?;
+    ///         }
+    ///         (State::Ready, msg) => self.process(msg),
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn stash(&mut self, message: M) -> Result<(), StashFull<M>> {
+        self.stash.push(message, self.deadline)
+    }
+
+    /// Redeliver the oldest stashed message before any new mailbox message.
+    ///
+    /// Returns `false` if the stash is empty.
+    pub fn unstash(&mut self) -> bool {
+        self.stash.unstash()
+    }
+
+    /// Redeliver all stashed messages, in stash order, before any new
+    /// mailbox message. Returns how many were unstashed.
+    pub fn unstash_all(&mut self) -> usize {
+        self.stash.unstash_all()
+    }
+
+    /// Discard all stashed messages. Returns how many were discarded.
+    pub fn clear_stash(&mut self) -> usize {
+        self.stash.clear()
+    }
+
+    /// Number of messages currently stashed.
+    pub fn stash_len(&self) -> usize {
+        self.stash.len()
+    }
+
+    /// Number of messages discarded by the overflow policy so far.
+    pub fn stash_dropped(&self) -> u64 {
+        self.stash.dropped()
+    }
+
+    /// Set the stash capacity and overflow policy.
+    ///
+    /// Typically called from `pre_start()`. Messages already stashed are
+    /// kept even if they exceed the new capacity.
+    pub fn set_stash_config(&mut self, config: StashConfig) {
+        self.stash.set_config(config);
+    }
+
+    /// Whether unstashed messages are waiting for redelivery.
+    pub(crate) fn has_unstashed(&self) -> bool {
+        self.stash.has_unstashed()
+    }
+
+    /// Take the next unstashed message and its original deadline.
+    pub(crate) fn take_unstashed(&mut self) -> Option<(M, Option<DateTime<Utc>>)> {
+        self.stash
+            .next_unstashed()
+            .map(|entry| (entry.message, entry.deadline))
+    }
+
     /// Send a message to another actor (fire-and-forget pattern).
     ///
     /// Publishes the message to the broker, which broadcasts it to all subscribers.
@@ -282,6 +438,11 @@ impl<M: Message, B: MessageBroker<M>> ActorContext<M, B> {
     ///
     /// **Does not wait for the recipient to process the message.**
     ///
+    /// The envelope inherits the deadline of the message being handled and,
+    /// if that message is elevated above `Normal`, its priority (capped by
+    /// [`set_priority_cap`](Self::set_priority_cap)), so a whole request
+    /// chain keeps the urgency of the request that started it.
+    ///
     /// # Arguments
     ///
     /// * `message` - The message to send (must be serializable)
@@ -319,6 +480,8 @@ impl<M: Message, B: MessageBroker<M>> ActorContext<M, B> {
     {
         let mut envelope = MessageEnvelope::new(message);
         envelope.reply_to = Some(recipient);
+        envelope.deadline = self.deadline;
+        self.inherit_priority(&mut envelope);
 
         self.broker
             .publish(envelope)
@@ -326,6 +489,42 @@ impl<M: Message, B: MessageBroker<M>> ActorContext<M, B> {
             .map_err(|e| e.to_string())
     }
 
+    /// Resolve the singleton actor registered for service `S`.
+    ///
+    /// Only services accepting this context's message type can be
+    /// resolved; a mismatched key is rejected at compile time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServiceError::NotRegistered` if nothing is registered for `S`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let config = ctx.service::<ConfigStore>()?;
+    /// ctx.send(AppMessage::Reload, config.address().clone()).await?;
+    /// ```
+    pub fn service<S>(&self) -> Result<Service<S>, ServiceError>
+    where
+        S: ServiceKey<Message = M>,
+    {
+        self.services.resolve::<S>()
+    }
+
+    /// Send a message to the singleton actor registered for service `S`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `S` is not registered or the broker rejects the message.
+    pub async fn send_to_service<S>(&self, message: M) -> Result<(), String>
+    where
+        S: ServiceKey<Message = M>,
+        M: serde::Serialize,
+    {
+        let service = self.service::<S>().map_err(|e| e.to_string())?;
+        self.send(message, service.address().clone()).await
+    }
+
     /// Send a request and wait for a reply (request/reply pattern).
     ///
     /// Sends a message and waits for a response from the recipient, with a timeout.
@@ -385,6 +584,18 @@ impl<M: Message, B: MessageBroker<M>> ActorContext<M, B> {
     /// # See Also
     ///
     /// - [`send()`](#method.send) - For fire-and-forget pattern (no response expected)
+    ///
+    /// # Deadline Propagation
+    ///
+    /// When the message being handled carries a deadline, `timeout` is capped
+    /// at the remaining budget and the request envelope carries the reduced
+    /// deadline. If the budget is already spent, `Ok(None)` is returned
+    /// without sending anything.
+    ///
+    /// # Priority Inheritance
+    ///
+    /// Like [`send()`](#method.send), the request inherits the priority of
+    /// the message being handled, up to the context's priority cap.
     pub async fn request(
         &self,
         request: M,
@@ -394,21 +605,126 @@ impl<M: Message, B: MessageBroker<M>> ActorContext<M, B> {
     where
         M: serde::Serialize + for<'de> serde::Deserialize<'de>,
     {
-        let mut envelope = MessageEnvelope::new(request);
+        // Nested requests inherit the remaining deadline of the current message
+        let timeout = match self.remaining_deadline() {
+            Some(remaining) if remaining.is_zero() => return Ok(None),
+            Some(remaining) => timeout.min(remaining),
+            None => timeout,
+        };
+
+        let mut envelope = MessageEnvelope::new(request).with_timeout(timeout);
         envelope.reply_to = Some(recipient);
+        self.inherit_priority(&mut envelope);
 
         self.broker
             .publish_request(envelope, timeout)
             .await
             .map_err(|e| e.to_string())
     }
+
+    /// Send a request with retries, backoff and per-target circuit breaking.
+    ///
+    /// Each attempt is a [`request()`](#method.request) with
+    /// `policy.attempt_timeout`; a send error or missing reply counts as a
+    /// failure and is retried after [`RetryPolicy::delay_for`]. Failures also
+    /// feed this actor's circuit breaker for `recipient` (see
+    /// [`set_circuit_breaker_config`](Self::set_circuit_breaker_config)).
+    ///
+    /// # Errors
+    ///
+    /// - `RetryError::CircuitOpen` if the circuit to `recipient` is open or
+    ///   opens during the retries
+    /// - `RetryError::DeadlineExceeded` if the current message's deadline
+    ///   leaves no time for the next attempt
+    /// - `RetryError::Exhausted` once `policy.max_attempts` attempts failed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let policy = RetryPolicy::new(4)
+    ///     .with_attempt_timeout(Duration::from_millis(200))
+    ///     .with_backoff(Duration::from_millis(20), Duration::from_secs(1));
+    ///
+    /// match ctx.retrying_request(Query::Lookup(key), db_addr, &policy).await {
+    ///     Ok(reply) => self.apply(reply.payload),
+    ///     Err(RetryError::CircuitOpen { .. }) => self.serve_stale(),
+    ///     Err(e) => return Err(MyError::Upstream(e.to_string())),
+    /// }
+    /// ```
+    pub async fn retrying_request(
+        &mut self,
+        request: M,
+        recipient: ActorAddress,
+        policy: &RetryPolicy,
+    ) -> Result<MessageEnvelope<M>, RetryError>
+    where
+        M: serde::Serialize + for<'de> serde::Deserialize<'de>,
+    {
+        if let CircuitState::Open { until } = self.circuits.state(&recipient) {
+            return Err(RetryError::CircuitOpen {
+                target: recipient,
+                until,
+            });
+        }
+
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let last_error = match self
+                .request(request.clone(), recipient.clone(), policy.attempt_timeout)
+                .await
+            {
+                Ok(Some(reply)) => {
+                    self.circuits.record_success(&recipient);
+                    return Ok(reply);
+                }
+                Ok(None) => "no reply".to_string(),
+                Err(e) => e,
+            };
+
+            if let Some(until) = self.circuits.record_failure(&recipient) {
+                return Err(RetryError::CircuitOpen {
+                    target: recipient,
+                    until,
+                });
+            }
+            if attempts >= policy.max_attempts {
+                return Err(RetryError::Exhausted {
+                    target: recipient,
+                    attempts,
+                    last_error,
+                });
+            }
+
+            let delay = policy.delay_for(attempts);
+            if self.remaining_deadline().is_some_and(|left| left <= delay) {
+                return Err(RetryError::DeadlineExceeded {
+                    target: recipient,
+                    attempts,
+                });
+            }
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Set the circuit breaker thresholds used by
+    /// [`retrying_request`](Self::retrying_request).
+    pub fn set_circuit_breaker_config(&mut self, config: CircuitBreakerConfig) {
+        self.circuits.set_config(config);
+    }
+
+    /// Current circuit state towards `target`.
+    pub fn circuit_state(&self, target: &ActorAddress) -> CircuitState {
+        self.circuits.state(target)
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::actor::stash::StashOverflow;
     use crate::broker::in_memory::InMemoryMessageBroker;
-    use crate::message::MessagePriority;
 
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
     struct TestMessage;
@@ -455,6 +771,148 @@ mod tests {
         assert_eq!(context.id(), &id);
     }
 
+    #[test]
+    fn test_context_deadline_accessors() {
+        let mut context = create_test_context();
+        assert!(context.deadline().is_none());
+        assert!(context.remaining_deadline().is_none());
+
+        let deadline = Utc::now() + chrono::Duration::seconds(10);
+        context.set_deadline(Some(deadline));
+        assert_eq!(context.deadline(), Some(deadline));
+        let remaining = context.remaining_deadline().unwrap_or_default();
+        assert!(remaining > std::time::Duration::from_secs(5));
+
+        context.set_deadline(Some(Utc::now() - chrono::Duration::seconds(1)));
+        assert_eq!(
+            context.remaining_deadline(),
+            Some(std::time::Duration::ZERO)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_with_spent_deadline_returns_none() {
+        let mut context = create_test_context();
+        context.set_deadline(Some(Utc::now() - chrono::Duration::seconds(1)));
+
+        let result = context
+            .request(
+                TestMessage,
+                ActorAddress::anonymous(),
+                std::time::Duration::from_secs(5),
+            )
+            .await;
+
+        assert!(matches!(result, Ok(None)));
+    }
+
+    #[test]
+    fn test_stash_keeps_deadline_and_respects_config() {
+        let mut context = create_test_context();
+        context.set_stash_config(StashConfig::new(1, StashOverflow::Reject));
+        let deadline = Utc::now() + chrono::Duration::seconds(5);
+
+        context.set_deadline(Some(deadline));
+        assert!(context.stash(TestMessage).is_ok());
+        context.set_deadline(None);
+        let err = context.stash(TestMessage).unwrap_err();
+        assert_eq!(err.capacity, 1);
+        assert_eq!(context.stash_len(), 1);
+
+        assert!(!context.has_unstashed());
+        assert_eq!(context.unstash_all(), 1);
+        assert_eq!(context.stash_len(), 0);
+        let (_, unstashed_deadline) = context.take_unstashed().unwrap();
+        assert_eq!(unstashed_deadline, Some(deadline));
+        assert!(context.take_unstashed().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_retrying_request_retries_then_trips_circuit() {
+        use crate::actor::retry::{CircuitBreakerConfig, RetryError, RetryPolicy};
+        use crate::broker::MessageBroker;
+        use std::time::Duration;
+
+        let mut context = create_test_context();
+        let broker = context.broker.clone();
+        let mut requests = broker.subscribe().await.unwrap();
+
+        // Ignore the first two requests, answer the third
+        tokio::spawn(async move {
+            let mut seen = 0;
+            while let Some(request) = requests.recv().await {
+                let Some(corr_id) = request.correlation_id else {
+                    continue;
+                };
+                seen += 1;
+                if seen == 3 {
+                    let mut reply = MessageEnvelope::new(TestMessage);
+                    reply.correlation_id = Some(corr_id);
+                    let _ = broker.publish(reply).await;
+                }
+            }
+        });
+
+        let target = ActorAddress::named("flaky");
+        let policy = RetryPolicy::new(3)
+            .with_attempt_timeout(Duration::from_millis(50))
+            .with_backoff(Duration::from_millis(1), Duration::from_millis(5));
+        let reply = context
+            .retrying_request(TestMessage, target.clone(), &policy)
+            .await;
+        assert!(reply.is_ok());
+        assert_eq!(context.circuit_state(&target), CircuitState::Closed);
+
+        // Nobody answers any more: the breaker opens and then fails fast
+        context.set_circuit_breaker_config(CircuitBreakerConfig::new(2, Duration::from_secs(60)));
+        let err = context
+            .retrying_request(TestMessage, target.clone(), &policy)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RetryError::CircuitOpen { .. }));
+        assert!(matches!(
+            context.retrying_request(TestMessage, target, &policy).await,
+            Err(RetryError::CircuitOpen { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_inherits_capped_priority() {
+        use crate::broker::MessageBroker;
+
+        let mut context = create_test_context();
+        let mut published = context.broker.subscribe().await.unwrap();
+        let target = ActorAddress::named("downstream");
+
+        context.send(TestMessage, target.clone()).await.unwrap();
+        assert_eq!(
+            published.recv().await.unwrap().priority,
+            MessagePriority::Normal
+        );
+
+        context.set_priority(MessagePriority::High);
+        context.send(TestMessage, target.clone()).await.unwrap();
+        assert_eq!(
+            published.recv().await.unwrap().priority,
+            MessagePriority::High
+        );
+
+        // Critical is capped at the default inheritance cap
+        context.set_priority(MessagePriority::Critical);
+        context.send(TestMessage, target.clone()).await.unwrap();
+        assert_eq!(
+            published.recv().await.unwrap().priority,
+            MessagePriority::High
+        );
+
+        context.set_priority_cap(MessagePriority::Normal);
+        context.send(TestMessage, target).await.unwrap();
+        assert_eq!(
+            published.recv().await.unwrap().priority,
+            MessagePriority::Normal
+        );
+    }
+
     #[test]
     fn test_record_message() {
         let mut context = create_test_context();
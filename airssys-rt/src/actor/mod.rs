@@ -9,6 +9,9 @@
 //! - [`ActorContext`] - Actor metadata and messaging interface
 //! - [`ActorLifecycle`] - State management and restart tracking
 //! - [`ActorState`] - Lifecycle state enum (Starting, Running, Stopping, etc.)
+//! - [`StashConfig`] - Bounded message stash for state-dependent handling
+//! - [`ServiceRegistry`] - Typed singleton services resolved by key type
+//! - [`RetryPolicy`] - Retry/backoff settings for `ActorContext::retrying_request`
 //! - [`ErrorAction`] - Supervision decision enum (Stop, Resume, Restart, Escalate)
 //!
 //! # Design Philosophy
@@ -98,8 +101,14 @@
 
 pub mod context;
 pub mod lifecycle;
+pub mod retry;
+pub mod service;
+pub mod stash;
 pub mod traits;
 
 pub use context::ActorContext;
 pub use lifecycle::{ActorLifecycle, ActorState};
+pub use retry::{CircuitBreakerConfig, CircuitState, RetryError, RetryPolicy};
+pub use service::{Service, ServiceError, ServiceKey, ServiceRegistry};
+pub use stash::{StashConfig, StashFull, StashOverflow, DEFAULT_STASH_CAPACITY};
 pub use traits::{Actor, ErrorAction};
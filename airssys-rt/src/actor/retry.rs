@@ -0,0 +1,283 @@
+//! Retry, backoff and circuit breaking for actor requests.
+//!
+//! [`ActorContext::retrying_request`](super::ActorContext::retrying_request)
+//! wraps `request()` with a shared retry policy so actors do not each
+//! re-implement retry loops:
+//!
+//! - A failed attempt (send error or no reply within the attempt timeout)
+//!   is retried after an exponential backoff delay with random jitter.
+//! - Each actor keeps a circuit breaker per target address. After
+//!   `failure_threshold` consecutive failures the circuit opens and requests
+//!   to that target fail fast until `open_duration` has passed; the next
+//!   request is then a single trial (half-open) that closes the circuit on
+//!   success or reopens it on failure.
+//! - Retries never outlive the deadline of the message being handled.
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+use crate::util::ActorAddress;
+
+/// Retry and backoff settings for [`retrying_request`](super::ActorContext::retrying_request).
+///
+/// # Backoff Formula
+///
+/// ```text
+/// delay = min(base_delay * 2^(attempt - 1), max_delay)
+/// delay = delay ± delay * jitter (random)
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_rt::actor::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new(5)
+///     .with_attempt_timeout(Duration::from_millis(500))
+///     .with_backoff(Duration::from_millis(50), Duration::from_secs(2))
+///     .with_jitter(0.2);
+///
+/// assert_eq!(policy.max_attempts, 5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts including the first one (at least 1).
+    pub max_attempts: u32,
+    /// Reply timeout of each attempt.
+    pub attempt_timeout: Duration,
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+    /// Upper bound on the delay between attempts.
+    pub max_delay: Duration,
+    /// Random spread applied to each delay, as a fraction (0.0 - 1.0).
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            attempt_timeout: Duration::from_secs(5),
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            jitter: 0.1,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a policy with `max_attempts` and default timings.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// Set the reply timeout of each attempt.
+    pub fn with_attempt_timeout(mut self, timeout: Duration) -> Self {
+        self.attempt_timeout = timeout;
+        self
+    }
+
+    /// Set the initial and maximum backoff delays.
+    pub fn with_backoff(mut self, base_delay: Duration, max_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Set the jitter fraction, clamped to 0.0 - 1.0.
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Delay before retry number `retry` (1 for the first retry).
+    pub fn delay_for(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(16);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+        if self.jitter <= 0.0 || delay.is_zero() {
+            return delay;
+        }
+        let spread = rand::thread_rng().gen_range(-self.jitter..=self.jitter);
+        delay.mul_f64(1.0 + spread)
+    }
+}
+
+/// Circuit breaker thresholds, shared by all targets of one actor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failed attempts that open the circuit.
+    pub failure_threshold: u32,
+    /// How long an open circuit rejects requests before allowing a trial.
+    pub open_duration: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(30),
+        }
+    }
+}
+
+impl CircuitBreakerConfig {
+    /// Create a config with the given threshold and open duration.
+    pub fn new(failure_threshold: u32, open_duration: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            open_duration,
+        }
+    }
+}
+
+/// State of the circuit to one target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests flow normally.
+    Closed,
+    /// Requests fail fast until the given time.
+    Open {
+        /// When a trial request will be allowed.
+        until: DateTime<Utc>,
+    },
+    /// One trial request is allowed to probe the target.
+    HalfOpen,
+}
+
+/// Errors returned by [`retrying_request`](super::ActorContext::retrying_request).
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum RetryError {
+    /// The circuit to the target is open; nothing was sent.
+    #[error("Circuit open for {target} until {until}")]
+    CircuitOpen {
+        /// Target of the request.
+        target: ActorAddress,
+        /// When a trial request will be allowed.
+        until: DateTime<Utc>,
+    },
+
+    /// Every attempt failed.
+    #[error("Request to {target} failed after {attempts} attempts: {last_error}")]
+    Exhausted {
+        /// Target of the request.
+        target: ActorAddress,
+        /// Attempts made.
+        attempts: u32,
+        /// Failure of the last attempt.
+        last_error: String,
+    },
+
+    /// The deadline of the message being handled left no time to retry.
+    #[error("Deadline exceeded after {attempts} attempts to {target}")]
+    DeadlineExceeded {
+        /// Target of the request.
+        target: ActorAddress,
+        /// Attempts made.
+        attempts: u32,
+    },
+}
+
+#[derive(Debug, Default)]
+struct Breaker {
+    consecutive_failures: u32,
+    open_until: Option<DateTime<Utc>>,
+}
+
+/// Per-target circuit breakers of one actor.
+#[derive(Debug, Default)]
+pub(crate) struct CircuitBreakers {
+    config: CircuitBreakerConfig,
+    breakers: HashMap<ActorAddress, Breaker>,
+}
+
+impl CircuitBreakers {
+    pub(crate) fn set_config(&mut self, config: CircuitBreakerConfig) {
+        self.config = config;
+    }
+
+    pub(crate) fn state(&self, target: &ActorAddress) -> CircuitState {
+        match self.breakers.get(target).and_then(|b| b.open_until) {
+            Some(until) if Utc::now() < until => CircuitState::Open { until },
+            Some(_) => CircuitState::HalfOpen,
+            None => CircuitState::Closed,
+        }
+    }
+
+    pub(crate) fn record_success(&mut self, target: &ActorAddress) {
+        self.breakers.remove(target);
+    }
+
+    /// Record a failed attempt; returns the reopen time if the circuit is
+    /// now open.
+    pub(crate) fn record_failure(&mut self, target: &ActorAddress) -> Option<DateTime<Utc>> {
+        let half_open = self.state(target) == CircuitState::HalfOpen;
+        let breaker = self.breakers.entry(target.clone()).or_default();
+        breaker.consecutive_failures += 1;
+        if half_open || breaker.consecutive_failures >= self.config.failure_threshold {
+            let open_for = chrono::Duration::from_std(self.config.open_duration)
+                .unwrap_or(chrono::Duration::MAX);
+            let until = Utc::now()
+                .checked_add_signed(open_for)
+                .unwrap_or(DateTime::<Utc>::MAX_UTC);
+            breaker.open_until = Some(until);
+            return Some(until);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let policy = RetryPolicy::new(10)
+            .with_backoff(Duration::from_millis(10), Duration::from_millis(50))
+            .with_jitter(0.0);
+        assert_eq!(policy.delay_for(1), Duration::from_millis(10));
+        assert_eq!(policy.delay_for(2), Duration::from_millis(20));
+        assert_eq!(policy.delay_for(3), Duration::from_millis(40));
+        assert_eq!(policy.delay_for(4), Duration::from_millis(50));
+
+        let jittered = policy.with_jitter(0.5).delay_for(1);
+        assert!(jittered >= Duration::from_millis(5) && jittered <= Duration::from_millis(15));
+    }
+
+    #[test]
+    fn test_circuit_opens_after_threshold_and_half_opens() {
+        let target = ActorAddress::named("db");
+        let mut breakers = CircuitBreakers::default();
+        breakers.set_config(CircuitBreakerConfig::new(2, Duration::ZERO));
+
+        assert!(breakers.record_failure(&target).is_none());
+        assert_eq!(breakers.state(&target), CircuitState::Closed);
+        assert!(breakers.record_failure(&target).is_some());
+
+        // Zero open duration: immediately ready for a trial
+        assert_eq!(breakers.state(&target), CircuitState::HalfOpen);
+        // A failed trial reopens at once
+        assert!(breakers.record_failure(&target).is_some());
+
+        breakers.record_success(&target);
+        assert_eq!(breakers.state(&target), CircuitState::Closed);
+
+        breakers.set_config(CircuitBreakerConfig::new(1, Duration::from_secs(60)));
+        breakers.record_failure(&target);
+        assert!(matches!(breakers.state(&target), CircuitState::Open { .. }));
+    }
+}
@@ -0,0 +1,256 @@
+//! Typed service registry for singleton actors.
+//!
+//! A service is a singleton actor registered under a key type instead of a
+//! name string. The key fixes the message type the service accepts, so
+//! resolving [`Service<ConfigStore>`](Service) from an
+//! [`ActorContext`](super::ActorContext) only compiles where the context's
+//! message type matches, and sending to it goes through the same type.
+//!
+//! ```rust,ignore
+//! struct ConfigStore;
+//!
+//! impl ServiceKey for ConfigStore {
+//!     type Message = AppMessage;
+//!     const NAME: &'static str = "config-store";
+//! }
+//!
+//! // At startup
+//! system.spawn_service::<ConfigStore, _>(ConfigStoreActor::default()).await?;
+//!
+//! // From any actor of the system
+//! ctx.send_to_service::<ConfigStore>(AppMessage::Get("db.url".into())).await?;
+//! ```
+
+// Layer 1: Standard library imports
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+// Layer 2: Third-party crate imports
+use parking_lot::RwLock;
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+use crate::message::Message;
+use crate::util::ActorAddress;
+
+/// Key type identifying a singleton service.
+///
+/// Implemented by a marker type per service; the associated message type
+/// is the only message the service can be sent.
+pub trait ServiceKey: 'static {
+    /// Message type accepted by the service actor.
+    type Message: Message;
+
+    /// Human-readable service name, used as the actor name when spawned
+    /// through the system and in errors.
+    const NAME: &'static str;
+}
+
+/// Errors from registering or resolving services.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ServiceError {
+    /// No actor is registered for the service
+    #[error("Service not registered: {0}")]
+    NotRegistered(&'static str),
+
+    /// Another actor is already registered for the service
+    #[error("Service already registered: {name} at {address}")]
+    AlreadyRegistered {
+        name: &'static str,
+        address: ActorAddress,
+    },
+}
+
+/// Resolved handle to a service actor.
+pub struct Service<S: ServiceKey> {
+    address: ActorAddress,
+    _marker: PhantomData<fn() -> S>,
+}
+
+impl<S: ServiceKey> Service<S> {
+    fn new(address: ActorAddress) -> Self {
+        Self {
+            address,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Address of the service actor.
+    pub fn address(&self) -> &ActorAddress {
+        &self.address
+    }
+
+    /// Service name from the key.
+    pub fn name(&self) -> &'static str {
+        S::NAME
+    }
+}
+
+impl<S: ServiceKey> Clone for Service<S> {
+    fn clone(&self) -> Self {
+        Self::new(self.address.clone())
+    }
+}
+
+impl<S: ServiceKey> fmt::Debug for Service<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Service")
+            .field("name", &S::NAME)
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    name: &'static str,
+    address: ActorAddress,
+}
+
+/// Shared map from service keys to actor addresses.
+///
+/// Cloning shares the registry; the actor system hands a clone to every
+/// actor context it creates.
+#[derive(Debug, Clone, Default)]
+pub struct ServiceRegistry {
+    entries: Arc<RwLock<HashMap<TypeId, Entry>>>,
+}
+
+impl ServiceRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `address` as the singleton actor for `S`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServiceError::AlreadyRegistered` if `S` already has an actor.
+    pub fn register<S: ServiceKey>(
+        &self,
+        address: ActorAddress,
+    ) -> Result<Service<S>, ServiceError> {
+        let mut entries = self.entries.write();
+        if let Some(existing) = entries.get(&TypeId::of::<S>()) {
+            return Err(ServiceError::AlreadyRegistered {
+                name: S::NAME,
+                address: existing.address.clone(),
+            });
+        }
+        entries.insert(
+            TypeId::of::<S>(),
+            Entry {
+                name: S::NAME,
+                address: address.clone(),
+            },
+        );
+        Ok(Service::new(address))
+    }
+
+    /// Resolve the actor registered for `S`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServiceError::NotRegistered` if `S` has no actor.
+    pub fn resolve<S: ServiceKey>(&self) -> Result<Service<S>, ServiceError> {
+        self.entries
+            .read()
+            .get(&TypeId::of::<S>())
+            .map(|entry| Service::new(entry.address.clone()))
+            .ok_or(ServiceError::NotRegistered(S::NAME))
+    }
+
+    /// Remove the registration for `S`, returning its address.
+    pub fn unregister<S: ServiceKey>(&self) -> Option<ActorAddress> {
+        self.entries
+            .write()
+            .remove(&TypeId::of::<S>())
+            .map(|entry| entry.address)
+    }
+
+    /// Remove every registration pointing at `address`.
+    ///
+    /// Called when an actor stops so stale services fail to resolve
+    /// instead of swallowing messages.
+    pub fn remove_address(&self, address: &ActorAddress) {
+        self.entries
+            .write()
+            .retain(|_, entry| &entry.address != address);
+    }
+
+    /// Names of all registered services, sorted.
+    pub fn names(&self) -> Vec<&'static str> {
+        let mut names: Vec<_> = self.entries.read().values().map(|e| e.name).collect();
+        names.sort_unstable();
+        names
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    struct ConfigMessage;
+
+    impl Message for ConfigMessage {
+        const MESSAGE_TYPE: &'static str = "config";
+    }
+
+    struct ConfigStore;
+
+    impl ServiceKey for ConfigStore {
+        type Message = ConfigMessage;
+        const NAME: &'static str = "config-store";
+    }
+
+    struct Metrics;
+
+    impl ServiceKey for Metrics {
+        type Message = ConfigMessage;
+        const NAME: &'static str = "metrics";
+    }
+
+    #[test]
+    fn test_register_and_resolve_by_key_type() {
+        let registry = ServiceRegistry::new();
+        let address = ActorAddress::named("config-store");
+        registry.register::<ConfigStore>(address.clone()).unwrap();
+
+        let service = registry.resolve::<ConfigStore>().unwrap();
+        assert_eq!(service.address(), &address);
+        assert_eq!(service.name(), "config-store");
+        assert_eq!(
+            registry.resolve::<Metrics>().unwrap_err(),
+            ServiceError::NotRegistered("metrics")
+        );
+    }
+
+    #[test]
+    fn test_singleton_registration() {
+        let registry = ServiceRegistry::new();
+        let first = ActorAddress::named("config-store");
+        registry.register::<ConfigStore>(first.clone()).unwrap();
+
+        let err = registry
+            .register::<ConfigStore>(ActorAddress::anonymous())
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ServiceError::AlreadyRegistered {
+                name: "config-store",
+                address: first.clone(),
+            }
+        );
+
+        registry.remove_address(&first);
+        assert!(registry.names().is_empty());
+        assert!(registry.register::<ConfigStore>(first).is_ok());
+        assert!(registry.unregister::<ConfigStore>().is_some());
+    }
+}
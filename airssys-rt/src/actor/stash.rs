@@ -0,0 +1,235 @@
+//! Message stash for state-dependent message handling.
+//!
+//! An actor that receives a message it cannot handle in its current state
+//! (e.g. a request arriving before a handshake completes) stashes it via
+//! [`ActorContext::stash`](super::ActorContext::stash). After the state
+//! transition it unstashes the messages, which the actor system then
+//! redelivers in their original order ahead of the mailbox.
+
+// Layer 1: Standard library imports
+use std::collections::VecDeque;
+use std::fmt;
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Utc};
+
+// Layer 3: Internal module imports
+// (none)
+
+/// Default maximum number of stashed messages per actor.
+pub const DEFAULT_STASH_CAPACITY: usize = 1024;
+
+/// What happens when a message is stashed into a full stash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StashOverflow {
+    /// Refuse the new message and hand it back in [`StashFull`].
+    #[default]
+    Reject,
+    /// Discard the oldest stashed message to make room.
+    DropOldest,
+    /// Discard the new message.
+    DropNewest,
+}
+
+/// Stash bounds and overflow behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StashConfig {
+    /// Maximum number of stashed messages.
+    pub capacity: usize,
+    /// Policy applied when the stash is full.
+    pub overflow: StashOverflow,
+}
+
+impl Default for StashConfig {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_STASH_CAPACITY,
+            overflow: StashOverflow::Reject,
+        }
+    }
+}
+
+impl StashConfig {
+    /// Create a config with the given capacity and overflow policy.
+    pub fn new(capacity: usize, overflow: StashOverflow) -> Self {
+        Self { capacity, overflow }
+    }
+}
+
+/// Error returned when stashing into a full stash with
+/// [`StashOverflow::Reject`].
+///
+/// Carries the refused message so the actor can still handle or drop it.
+#[derive(Debug)]
+pub struct StashFull<M> {
+    /// The message that was not stashed.
+    pub message: M,
+    /// Capacity of the stash.
+    pub capacity: usize,
+}
+
+impl<M> fmt::Display for StashFull<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "stash full (capacity {})", self.capacity)
+    }
+}
+
+impl<M: fmt::Debug> std::error::Error for StashFull<M> {}
+
+/// A stashed message with the deadline it arrived with.
+#[derive(Debug)]
+pub(crate) struct Stashed<M> {
+    pub(crate) message: M,
+    pub(crate) deadline: Option<DateTime<Utc>>,
+}
+
+/// Bounded stash plus the queue of unstashed messages awaiting redelivery.
+#[derive(Debug)]
+pub(crate) struct Stash<M> {
+    config: StashConfig,
+    stashed: VecDeque<Stashed<M>>,
+    unstashed: VecDeque<Stashed<M>>,
+    dropped: u64,
+}
+
+impl<M> Default for Stash<M> {
+    fn default() -> Self {
+        Self {
+            config: StashConfig::default(),
+            stashed: VecDeque::new(),
+            unstashed: VecDeque::new(),
+            dropped: 0,
+        }
+    }
+}
+
+impl<M> Stash<M> {
+    pub(crate) fn set_config(&mut self, config: StashConfig) {
+        self.config = config;
+    }
+
+    pub(crate) fn push(
+        &mut self,
+        message: M,
+        deadline: Option<DateTime<Utc>>,
+    ) -> Result<(), StashFull<M>> {
+        if self.stashed.len() >= self.config.capacity {
+            match self.config.overflow {
+                StashOverflow::Reject => {
+                    return Err(StashFull {
+                        message,
+                        capacity: self.config.capacity,
+                    });
+                }
+                StashOverflow::DropNewest => {
+                    self.dropped += 1;
+                    return Ok(());
+                }
+                StashOverflow::DropOldest => {
+                    self.dropped += 1;
+                    if self.stashed.pop_front().is_none() {
+                        // Zero capacity: nothing to evict, discard the new one
+                        return Ok(());
+                    }
+                }
+            }
+        }
+        self.stashed.push_back(Stashed { message, deadline });
+        Ok(())
+    }
+
+    /// Move the oldest stashed message to the redelivery queue.
+    pub(crate) fn unstash(&mut self) -> bool {
+        match self.stashed.pop_front() {
+            Some(entry) => {
+                self.unstashed.push_back(entry);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move every stashed message to the redelivery queue, in order.
+    pub(crate) fn unstash_all(&mut self) -> usize {
+        let count = self.stashed.len();
+        self.unstashed.append(&mut self.stashed);
+        count
+    }
+
+    pub(crate) fn clear(&mut self) -> usize {
+        let count = self.stashed.len();
+        self.stashed.clear();
+        count
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.stashed.len()
+    }
+
+    pub(crate) fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    pub(crate) fn has_unstashed(&self) -> bool {
+        !self.unstashed.is_empty()
+    }
+
+    pub(crate) fn next_unstashed(&mut self) -> Option<Stashed<M>> {
+        self.unstashed.pop_front()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn stash(capacity: usize, overflow: StashOverflow) -> Stash<u32> {
+        let mut stash = Stash::default();
+        stash.set_config(StashConfig::new(capacity, overflow));
+        stash
+    }
+
+    fn drain(stash: &mut Stash<u32>) -> Vec<u32> {
+        std::iter::from_fn(|| stash.next_unstashed().map(|s| s.message)).collect()
+    }
+
+    #[test]
+    fn test_unstash_preserves_order() {
+        let mut stash = stash(8, StashOverflow::Reject);
+        for i in 1..=3 {
+            stash.push(i, None).unwrap();
+        }
+        assert!(stash.unstash());
+        assert_eq!(stash.len(), 2);
+        assert_eq!(stash.unstash_all(), 2);
+        assert_eq!(drain(&mut stash), vec![1, 2, 3]);
+        assert!(!stash.unstash());
+    }
+
+    #[test]
+    fn test_overflow_policies() {
+        let mut reject = stash(2, StashOverflow::Reject);
+        reject.push(1, None).unwrap();
+        reject.push(2, None).unwrap();
+        let err = reject.push(3, None).unwrap_err();
+        assert_eq!(err.message, 3);
+        assert_eq!(err.capacity, 2);
+
+        let mut oldest = stash(2, StashOverflow::DropOldest);
+        for i in 1..=3 {
+            oldest.push(i, None).unwrap();
+        }
+        oldest.unstash_all();
+        assert_eq!(drain(&mut oldest), vec![2, 3]);
+        assert_eq!(oldest.dropped(), 1);
+
+        let mut newest = stash(2, StashOverflow::DropNewest);
+        for i in 1..=3 {
+            newest.push(i, None).unwrap();
+        }
+        newest.unstash_all();
+        assert_eq!(drain(&mut newest), vec![1, 2]);
+        assert_eq!(newest.dropped(), 1);
+    }
+}
@@ -2,6 +2,11 @@
 //!
 //! This broker implements a pure publish-subscribe pattern with no actor registry.
 //! Subscribers receive all published messages and are responsible for routing.
+//!
+//! Subscriptions made with [`MessageBroker::subscribe`] are unbounded.
+//! [`InMemoryMessageBroker::subscribe_with_qos`] gives a subscriber its own
+//! bounded buffer and overflow policy, so a slow subscriber loses its own
+//! messages instead of holding memory on behalf of everyone else.
 
 // Layer 1: Standard library imports
 use std::sync::Arc;
@@ -15,6 +20,7 @@ use tokio::time::timeout;
 
 // Layer 3: Internal module imports
 use super::error::BrokerError;
+use super::qos::{QosBuffer, SubscriptionQos};
 use super::traits::{MessageBroker, MessageStream};
 use crate::message::{Message, MessageEnvelope};
 use crate::util::ActorAddress;
@@ -54,12 +60,47 @@ pub struct InMemoryMessageBroker<M: Message> {
 
 struct InMemoryBrokerInner<M: Message> {
     /// Pub-sub subscribers: each receives all published messages
-    subscribers: RwLock<Vec<mpsc::UnboundedSender<MessageEnvelope<M>>>>,
+    subscribers: RwLock<Vec<Subscriber<M>>>,
 
     /// Pending request-reply channels: correlation_id -> response sender
     pending_requests: DashMap<uuid::Uuid, oneshot::Sender<Vec<u8>>>,
 }
 
+/// Delivery channel of one subscriber.
+enum Subscriber<M: Message> {
+    Unbounded(mpsc::UnboundedSender<MessageEnvelope<M>>),
+    Buffered(Arc<QosBuffer<MessageEnvelope<M>>>),
+}
+
+impl<M: Message> Subscriber<M> {
+    /// Deliver `envelope`; returns `false` once the subscriber is gone.
+    fn deliver(&self, envelope: MessageEnvelope<M>) -> bool {
+        match self {
+            Subscriber::Unbounded(sender) => sender.send(envelope).is_ok(),
+            Subscriber::Buffered(buffer) => !buffer.push(envelope).is_closed(),
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        match self {
+            Subscriber::Unbounded(sender) => sender.is_closed(),
+            Subscriber::Buffered(buffer) => buffer.is_closed(),
+        }
+    }
+}
+
+impl<M: Message> Drop for InMemoryBrokerInner<M> {
+    fn drop(&mut self) {
+        // Unbounded streams end when their sender drops; buffered ones
+        // must be closed explicitly
+        for subscriber in self.subscribers.get_mut().iter() {
+            if let Subscriber::Buffered(buffer) = subscriber {
+                buffer.close();
+            }
+        }
+    }
+}
+
 impl<M: Message> InMemoryMessageBroker<M> {
     /// Create a new pure pub-sub broker.
     pub fn new() -> Self {
@@ -73,7 +114,25 @@ impl<M: Message> InMemoryMessageBroker<M> {
 
     /// Get the number of active subscribers.
     pub async fn subscriber_count(&self) -> usize {
-        self.inner.subscribers.read().await.len()
+        let mut subscribers = self.inner.subscribers.write().await;
+        subscribers.retain(|subscriber| !subscriber.is_closed());
+        subscribers.len()
+    }
+
+    /// Subscribe with a bounded buffer and overflow policy.
+    ///
+    /// The subscriber's buffer fills independently of other subscribers;
+    /// when full, `qos.overflow` decides whether the oldest or newest
+    /// message is dropped, or the subscription is closed. Dropped messages
+    /// are counted in [`MessageStream::dropped`].
+    pub async fn subscribe_with_qos(&self, qos: SubscriptionQos) -> MessageStream<M> {
+        let buffer = Arc::new(QosBuffer::new(qos));
+        self.inner
+            .subscribers
+            .write()
+            .await
+            .push(Subscriber::Buffered(Arc::clone(&buffer)));
+        MessageStream::buffered(buffer)
     }
 
     /// Check if a message is a reply to a pending request and route it.
@@ -106,12 +165,18 @@ impl<M: Message + serde::Serialize> MessageBroker<M> for InMemoryMessageBroker<M
             return Ok(());
         }
 
-        // Broadcast to all subscribers
-        let subscribers = self.inner.subscribers.read().await;
+        // Broadcast to all subscribers; each applies its own QoS
+        let mut any_closed = false;
+        {
+            let subscribers = self.inner.subscribers.read().await;
+            for subscriber in subscribers.iter() {
+                any_closed |= !subscriber.deliver(envelope.clone());
+            }
+        }
 
-        for subscriber in subscribers.iter() {
-            // Send clone to each subscriber (ignore closed channels)
-            let _ = subscriber.send(envelope.clone());
+        if any_closed {
+            let mut subscribers = self.inner.subscribers.write().await;
+            subscribers.retain(|subscriber| !subscriber.is_closed());
         }
 
         Ok(())
@@ -121,7 +186,7 @@ impl<M: Message + serde::Serialize> MessageBroker<M> for InMemoryMessageBroker<M
         let (tx, rx) = mpsc::unbounded_channel();
 
         let mut subscribers = self.inner.subscribers.write().await;
-        subscribers.push(tx);
+        subscribers.push(Subscriber::Unbounded(tx));
 
         Ok(MessageStream::new(rx))
     }
@@ -248,6 +313,66 @@ mod tests {
         assert_eq!(r3.payload.data, "broadcast");
     }
 
+    fn envelope(data: &str) -> MessageEnvelope<TestMessage> {
+        MessageEnvelope::new(TestMessage {
+            data: data.to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_slow_subscriber_does_not_affect_others() {
+        use crate::broker::qos::OverflowPolicy;
+
+        let broker = TestBroker::new();
+        let mut fast = broker.subscribe().await.unwrap();
+        let mut slow = broker
+            .subscribe_with_qos(SubscriptionQos::new(2).with_overflow(OverflowPolicy::DropOldest))
+            .await;
+
+        for data in ["a", "b", "c", "d"] {
+            broker.publish(envelope(data)).await.unwrap();
+        }
+
+        for expected in ["a", "b", "c", "d"] {
+            assert_eq!(fast.recv().await.unwrap().payload.data, expected);
+        }
+        assert_eq!(slow.recv().await.unwrap().payload.data, "c");
+        assert_eq!(slow.recv().await.unwrap().payload.data, "d");
+        assert_eq!(slow.dropped(), 2);
+        assert_eq!(fast.dropped(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_policy_removes_subscriber() {
+        use crate::broker::qos::OverflowPolicy;
+
+        let broker = TestBroker::new();
+        let _fast = broker.subscribe().await.unwrap();
+        let mut slow = broker
+            .subscribe_with_qos(SubscriptionQos::new(1).with_overflow(OverflowPolicy::Disconnect))
+            .await;
+        assert_eq!(broker.subscriber_count().await, 2);
+
+        broker.publish(envelope("kept")).await.unwrap();
+        broker.publish(envelope("overflow")).await.unwrap();
+        assert_eq!(broker.subscriber_count().await, 1);
+
+        // Buffered messages are still delivered before the stream ends
+        assert_eq!(slow.recv().await.unwrap().payload.data, "kept");
+        assert!(slow.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_dropped_buffered_stream_is_unsubscribed() {
+        let broker = TestBroker::new();
+        let stream = broker.subscribe_with_qos(SubscriptionQos::default()).await;
+        assert_eq!(broker.subscriber_count().await, 1);
+
+        drop(stream);
+        broker.publish(envelope("gone")).await.unwrap();
+        assert_eq!(broker.subscriber_count().await, 0);
+    }
+
     #[tokio::test]
     async fn test_publish_without_subscribers() {
         let broker = TestBroker::new();
@@ -11,6 +11,7 @@
 //! - [`InMemoryMessageBroker`] - Default lock-free in-memory implementation
 //! - [`ActorRegistry`] - Concurrent actor address resolution and routing
 //! - [`MessageStream`] - Async stream for topic-based subscriptions
+//! - [`SubscriptionQos`] - Per-subscriber buffer size and [`OverflowPolicy`]
 //! - [`BrokerError`] - Comprehensive error types for routing failures
 //!
 //! # Architecture
@@ -118,7 +119,9 @@
 //! - [`error`]: Comprehensive broker error types
 //! - [`traits`]: Generic `MessageBroker<M>` trait definition
 //! - [`registry`]: Actor registry with lock-free routing table
+//! - [`routing`]: Pluggable pool load-balancing strategies and routing metrics
 //! - [`in_memory`]: Default `InMemoryMessageBroker` implementation
+//! - [`qos`]: Per-subscriber buffer sizing and overflow policy
 //!
 //! # See Also
 //!
@@ -128,10 +131,16 @@
 
 pub mod error;
 pub mod in_memory;
+pub mod qos;
 pub mod registry;
+pub mod routing;
 pub mod traits;
 
 pub use error::BrokerError;
 pub use in_memory::InMemoryMessageBroker;
-pub use registry::{ActorRegistry, PoolStrategy};
+pub use qos::{OverflowPolicy, PushOutcome, QosBuffer, SubscriptionQos};
+pub use registry::{ActorMetadata, ActorPage, ActorRegistry, PoolStrategy};
+pub use routing::{
+    LeastOutstanding, RoundRobin, RoutingStats, RoutingStrategy, StickyByKey, Weighted,
+};
 pub use traits::{MessageBroker, MessageStream};
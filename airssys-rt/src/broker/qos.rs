@@ -0,0 +1,332 @@
+//! Per-subscriber buffering and overflow policy for broadcast delivery.
+//!
+//! By default every broker subscriber gets an unbounded channel, so a
+//! subscriber that stops reading grows host memory without limit. A
+//! [`SubscriptionQos`] gives one subscriber its own bounded [`QosBuffer`]
+//! and decides what happens when that buffer is full, without affecting
+//! any other subscriber:
+//!
+//! - [`OverflowPolicy::DropOldest`] evicts the oldest buffered message
+//! - [`OverflowPolicy::DropNewest`] discards the incoming message
+//! - [`OverflowPolicy::Disconnect`] closes the subscription; messages
+//!   already buffered can still be read
+
+// Layer 1: Standard library imports
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Layer 2: Third-party crate imports
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
+
+/// What a full subscriber buffer does with the next message.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverflowPolicy {
+    /// Evict the oldest buffered message to make room
+    DropOldest,
+    /// Discard the incoming message
+    #[default]
+    DropNewest,
+    /// Close the subscription
+    Disconnect,
+}
+
+/// Buffer size and overflow policy of one subscription.
+///
+/// # Examples
+///
+/// ```
+/// use airssys_rt::broker::{OverflowPolicy, SubscriptionQos};
+///
+/// let qos = SubscriptionQos::new(128).with_overflow(OverflowPolicy::DropOldest);
+/// assert_eq!(qos.buffer_size, 128);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubscriptionQos {
+    /// Maximum number of undelivered messages (at least 1)
+    pub buffer_size: usize,
+
+    /// Behavior when the buffer is full
+    pub overflow: OverflowPolicy,
+}
+
+impl SubscriptionQos {
+    /// Buffer of `buffer_size` messages that drops new messages when full.
+    pub fn new(buffer_size: usize) -> Self {
+        Self {
+            buffer_size: buffer_size.max(1),
+            overflow: OverflowPolicy::default(),
+        }
+    }
+
+    /// Set the overflow policy.
+    pub fn with_overflow(mut self, overflow: OverflowPolicy) -> Self {
+        self.overflow = overflow;
+        self
+    }
+}
+
+impl Default for SubscriptionQos {
+    fn default() -> Self {
+        Self::new(1024)
+    }
+}
+
+/// Result of pushing a message into a [`QosBuffer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushOutcome {
+    /// The message was buffered
+    Queued,
+    /// The message was buffered after evicting the oldest one
+    DroppedOldest,
+    /// The buffer was full and the message was discarded
+    DroppedNewest,
+    /// The buffer was full and the subscription was closed by this push
+    Disconnected,
+    /// The subscription was already closed; the message was discarded
+    Closed,
+}
+
+impl PushOutcome {
+    /// Whether the subscription is closed after this push.
+    pub fn is_closed(self) -> bool {
+        matches!(self, Self::Disconnected | Self::Closed)
+    }
+}
+
+/// Bounded single-consumer buffer applying a [`SubscriptionQos`].
+///
+/// Producers never block: a push either buffers the message or applies the
+/// overflow policy. Closing the buffer (from either side) ends the stream
+/// once the remaining messages have been read.
+///
+/// # Examples
+///
+/// ```
+/// use airssys_rt::broker::{OverflowPolicy, PushOutcome, QosBuffer, SubscriptionQos};
+///
+/// let buffer = QosBuffer::new(SubscriptionQos::new(2).with_overflow(OverflowPolicy::DropOldest));
+/// buffer.push(1);
+/// buffer.push(2);
+/// assert_eq!(buffer.push(3), PushOutcome::DroppedOldest);
+/// assert_eq!(buffer.try_pop(), Some(2));
+/// assert_eq!(buffer.dropped(), 1);
+/// ```
+#[derive(Debug)]
+pub struct QosBuffer<T> {
+    qos: SubscriptionQos,
+    state: Mutex<BufferState<T>>,
+    notify: Notify,
+    dropped: AtomicU64,
+}
+
+#[derive(Debug)]
+struct BufferState<T> {
+    items: VecDeque<T>,
+    closed: bool,
+}
+
+impl<T> QosBuffer<T> {
+    /// Create an empty buffer.
+    pub fn new(qos: SubscriptionQos) -> Self {
+        let qos = SubscriptionQos::new(qos.buffer_size).with_overflow(qos.overflow);
+        Self {
+            qos,
+            state: Mutex::new(BufferState {
+                items: VecDeque::with_capacity(qos.buffer_size.min(1024)),
+                closed: false,
+            }),
+            notify: Notify::new(),
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// The buffer's QoS settings.
+    pub fn qos(&self) -> SubscriptionQos {
+        self.qos
+    }
+
+    /// Buffer `item`, applying the overflow policy if full.
+    pub fn push(&self, item: T) -> PushOutcome {
+        self.push_evicting(item).0
+    }
+
+    /// Like [`push`](Self::push), but also returns the message the
+    /// overflow policy discarded: the evicted oldest message for
+    /// `DropOldest`, or `item` itself when it was not buffered.
+    pub fn push_evicting(&self, item: T) -> (PushOutcome, Option<T>) {
+        let (outcome, discarded) = {
+            let mut state = self.state.lock();
+            if state.closed {
+                return (PushOutcome::Closed, Some(item));
+            }
+            if state.items.len() < self.qos.buffer_size {
+                state.items.push_back(item);
+                (PushOutcome::Queued, None)
+            } else {
+                match self.qos.overflow {
+                    OverflowPolicy::DropOldest => {
+                        let evicted = state.items.pop_front();
+                        state.items.push_back(item);
+                        self.dropped.fetch_add(1, Ordering::Relaxed);
+                        (PushOutcome::DroppedOldest, evicted)
+                    }
+                    OverflowPolicy::DropNewest => {
+                        self.dropped.fetch_add(1, Ordering::Relaxed);
+                        return (PushOutcome::DroppedNewest, Some(item));
+                    }
+                    OverflowPolicy::Disconnect => {
+                        state.closed = true;
+                        self.dropped.fetch_add(1, Ordering::Relaxed);
+                        (PushOutcome::Disconnected, Some(item))
+                    }
+                }
+            }
+        };
+        self.notify.notify_one();
+        (outcome, discarded)
+    }
+
+    /// Return an item taken with [`try_pop`](Self::try_pop) to the head of
+    /// the buffer, e.g. after a failed delivery.
+    ///
+    /// The item already counted against the buffer, so capacity is not
+    /// checked.
+    pub fn requeue(&self, item: T) {
+        self.state.lock().items.push_front(item);
+        self.notify.notify_one();
+    }
+
+    /// Take the oldest buffered item without waiting.
+    pub fn try_pop(&self) -> Option<T> {
+        self.state.lock().items.pop_front()
+    }
+
+    /// Wait for the oldest buffered item.
+    ///
+    /// Returns `None` once the buffer is closed and drained.
+    pub async fn pop(&self) -> Option<T> {
+        loop {
+            {
+                let mut state = self.state.lock();
+                if let Some(item) = state.items.pop_front() {
+                    return Some(item);
+                }
+                if state.closed {
+                    return None;
+                }
+            }
+            // Single consumer: notify_one stores a permit if we are not yet
+            // waiting, so a push between the check and here is not missed
+            self.notify.notified().await;
+        }
+    }
+
+    /// Close the buffer; further pushes are discarded.
+    pub fn close(&self) {
+        self.state.lock().closed = true;
+        self.notify.notify_one();
+    }
+
+    /// Whether the buffer has been closed.
+    pub fn is_closed(&self) -> bool {
+        self.state.lock().closed
+    }
+
+    /// Number of buffered items.
+    pub fn len(&self) -> usize {
+        self.state.lock().items.len()
+    }
+
+    /// Whether no items are buffered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of messages lost to the overflow policy.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    fn buffer(size: usize, overflow: OverflowPolicy) -> QosBuffer<u32> {
+        QosBuffer::new(SubscriptionQos::new(size).with_overflow(overflow))
+    }
+
+    #[test]
+    fn test_drop_newest_keeps_buffered_messages() {
+        let buffer = buffer(2, OverflowPolicy::DropNewest);
+        assert_eq!(buffer.push(1), PushOutcome::Queued);
+        assert_eq!(buffer.push(2), PushOutcome::Queued);
+        assert_eq!(buffer.push(3), PushOutcome::DroppedNewest);
+        assert_eq!(buffer.try_pop(), Some(1));
+        assert_eq!(buffer.try_pop(), Some(2));
+        assert_eq!(buffer.try_pop(), None);
+        assert_eq!(buffer.dropped(), 1);
+    }
+
+    #[test]
+    fn test_push_evicting_returns_discarded_message() {
+        let oldest = buffer(2, OverflowPolicy::DropOldest);
+        assert_eq!(oldest.push_evicting(1), (PushOutcome::Queued, None));
+        oldest.push(2);
+        assert_eq!(
+            oldest.push_evicting(3),
+            (PushOutcome::DroppedOldest, Some(1))
+        );
+
+        let newest = buffer(1, OverflowPolicy::DropNewest);
+        newest.push(1);
+        assert_eq!(
+            newest.push_evicting(2),
+            (PushOutcome::DroppedNewest, Some(2))
+        );
+        newest.close();
+        assert_eq!(newest.push_evicting(3), (PushOutcome::Closed, Some(3)));
+    }
+
+    #[test]
+    fn test_disconnect_closes_but_drains() {
+        let buffer = buffer(1, OverflowPolicy::Disconnect);
+        buffer.push(1);
+        assert_eq!(buffer.push(2), PushOutcome::Disconnected);
+        assert!(buffer.is_closed());
+        assert_eq!(buffer.push(3), PushOutcome::Closed);
+        assert_eq!(buffer.try_pop(), Some(1));
+        assert_eq!(buffer.try_pop(), None);
+    }
+
+    #[test]
+    fn test_requeue_restores_order_and_zero_size_is_clamped() {
+        let buffer = buffer(0, OverflowPolicy::DropNewest);
+        assert_eq!(buffer.qos().buffer_size, 1);
+        buffer.push(7);
+        let item = buffer.try_pop().unwrap();
+        buffer.push(8);
+        buffer.requeue(item);
+        assert_eq!(buffer.try_pop(), Some(7));
+        assert_eq!(buffer.try_pop(), Some(8));
+    }
+
+    #[tokio::test]
+    async fn test_pop_waits_for_push_and_ends_on_close() {
+        let buffer = Arc::new(buffer(4, OverflowPolicy::DropNewest));
+        let producer = Arc::clone(&buffer);
+        let handle = tokio::spawn(async move {
+            producer.push(5);
+            producer.close();
+        });
+        assert_eq!(buffer.pop().await, Some(5));
+        assert_eq!(buffer.pop().await, None);
+        handle.await.unwrap();
+    }
+}
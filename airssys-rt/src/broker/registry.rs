@@ -1,10 +1,12 @@
 //! Lock-free actor registry with pre-computed routing keys.
 //!
 //! Provides O(1) actor address resolution using DashMap for concurrent access
-//! without locks. Supports actor pools with configurable routing strategies.
+//! without locks. Supports actor pools with configurable routing strategies,
+//! and tag/attribute metadata for discovery queries.
 
 // Layer 1: Standard library imports
 use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet};
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::Arc;
@@ -15,6 +17,7 @@ use rand::Rng;
 
 // Layer 3: Internal module imports
 use super::error::BrokerError;
+use super::routing::{RoundRobin, RoutingStats, RoutingStrategy};
 use crate::mailbox::MailboxSender;
 use crate::message::Message;
 use crate::util::ActorAddress;
@@ -29,10 +32,9 @@ use crate::util::ActorAddress;
 /// - **RoundRobin**: Sequential distribution for even load distribution
 /// - **Random**: Uniform random selection for simple load balancing
 ///
-/// # Future Strategies (Deferred - YAGNI §6.1)
-///
-/// - **LeastLoaded**: Requires metrics integration (RT-TASK-008)
-/// - **Custom**: Application-specific routing logic
+/// Stateful strategies (least-outstanding, weighted, sticky-by-key, custom)
+/// implement [`RoutingStrategy`] and are attached per pool with
+/// [`ActorRegistry::set_pool_strategy`].
 ///
 /// # Example
 ///
@@ -58,7 +60,80 @@ pub enum PoolStrategy {
     /// Selects pool members randomly with uniform distribution.
     /// Best for simple load balancing without state tracking.
     Random,
-    // Future: LeastLoaded - requires metrics (deferred to RT-TASK-008)
+}
+
+/// Discovery metadata attached to a registered actor.
+///
+/// Tags mark roles (`"ingest"`, `"gpu"`); attributes carry key/value
+/// details. Both are used by [`ActorRegistry::find_by_tag`] and
+/// [`ActorRegistry::find_by_attribute`].
+///
+/// # Example
+///
+/// ```rust
+/// use airssys_rt::broker::ActorMetadata;
+///
+/// let metadata = ActorMetadata::new()
+///     .with_tag("worker")
+///     .with_attribute("region", "eu-west");
+///
+/// assert!(metadata.has_tag("worker"));
+/// assert_eq!(metadata.attribute("region"), Some("eu-west"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActorMetadata {
+    tags: BTreeSet<String>,
+    attributes: BTreeMap<String, String>,
+}
+
+impl ActorMetadata {
+    /// Create empty metadata.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a tag.
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.insert(tag.into());
+        self
+    }
+
+    /// Set an attribute, replacing any previous value.
+    pub fn with_attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.insert(key.into(), value.into());
+        self
+    }
+
+    /// Whether the actor carries `tag`.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(tag)
+    }
+
+    /// Tags in sorted order.
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        self.tags.iter().map(String::as_str)
+    }
+
+    /// Value of attribute `key`, if set.
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.get(key).map(String::as_str)
+    }
+
+    /// Whether no tags or attributes are set.
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.attributes.is_empty()
+    }
+}
+
+/// One page of registered actors returned by [`ActorRegistry::list`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActorPage {
+    /// Actors on this page, ordered by name then id.
+    pub actors: Vec<ActorAddress>,
+    /// Total number of registered actors.
+    pub total: usize,
+    /// Offset of the next page, or `None` if this is the last page.
+    pub next_offset: Option<usize>,
 }
 
 /// Lock-free actor registry with pre-computed routing keys.
@@ -77,10 +152,11 @@ pub enum PoolStrategy {
 ///
 /// # Architecture
 ///
-/// The registry maintains three concurrent data structures:
+/// The registry maintains four concurrent data structures:
 /// - **Routing Table**: ActorAddress → MailboxSender mapping
 /// - **Routing Keys**: Pre-computed hash → ActorAddress cache
 /// - **Actor Pools**: Pool name → [ActorAddress] collections
+/// - **Metadata**: ActorAddress → [`ActorMetadata`] for discovery queries
 ///
 /// # Example (System-Level Usage)
 ///
@@ -111,6 +187,15 @@ pub struct ActorRegistry<M: Message, S: MailboxSender<M>> {
     /// Round-robin counters for pool strategies: pool_name → counter
     pool_counters: Arc<DashMap<String, usize>>,
 
+    /// Pluggable routing strategies: pool_name → strategy
+    pool_strategies: Arc<DashMap<String, Arc<dyn RoutingStrategy>>>,
+
+    /// Routing decision metrics: pool_name → stats
+    routing_stats: Arc<DashMap<String, RoutingStats>>,
+
+    /// Discovery metadata: address → tags/attributes (non-empty only)
+    metadata: Arc<DashMap<ActorAddress, ActorMetadata>>,
+
     /// Phantom data for message type (zero-sized marker)
     _phantom: PhantomData<M>,
 }
@@ -132,6 +217,9 @@ impl<M: Message, S: MailboxSender<M>> ActorRegistry<M, S> {
             routing_keys: Arc::new(DashMap::new()),
             pools: Arc::new(DashMap::new()),
             pool_counters: Arc::new(DashMap::new()),
+            pool_strategies: Arc::new(DashMap::new()),
+            routing_stats: Arc::new(DashMap::new()),
+            metadata: Arc::new(DashMap::new()),
             _phantom: PhantomData,
         }
     }
@@ -191,6 +279,148 @@ impl<M: Message, S: MailboxSender<M>> ActorRegistry<M, S> {
         Ok(())
     }
 
+    /// Register an actor together with its discovery metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BrokerError::DuplicateRegistration` if the address is
+    /// already registered.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// registry.register_with_metadata(
+    ///     address,
+    ///     mailbox_sender,
+    ///     ActorMetadata::new().with_tag("ingest"),
+    /// )?;
+    /// ```
+    pub fn register_with_metadata(
+        &self,
+        address: ActorAddress,
+        sender: S,
+        metadata: ActorMetadata,
+    ) -> Result<(), BrokerError> {
+        self.register(address.clone(), sender)?;
+        if !metadata.is_empty() {
+            self.metadata.insert(address, metadata);
+        }
+        Ok(())
+    }
+
+    /// Replace the metadata of a registered actor.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BrokerError::ActorNotFound` if the address is not registered.
+    pub fn set_metadata(
+        &self,
+        address: &ActorAddress,
+        metadata: ActorMetadata,
+    ) -> Result<(), BrokerError> {
+        if !self.routing_table.contains_key(address) {
+            return Err(BrokerError::ActorNotFound(address.clone()));
+        }
+        if metadata.is_empty() {
+            self.metadata.remove(address);
+        } else {
+            self.metadata.insert(address.clone(), metadata);
+        }
+        Ok(())
+    }
+
+    /// Metadata of a registered actor (empty if none was set).
+    ///
+    /// Returns `None` if the address is not registered.
+    pub fn metadata(&self, address: &ActorAddress) -> Option<ActorMetadata> {
+        if !self.routing_table.contains_key(address) {
+            return None;
+        }
+        Some(
+            self.metadata
+                .get(address)
+                .map(|entry| entry.value().clone())
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Find actors carrying `tag`, ordered by name then id.
+    pub fn find_by_tag(&self, tag: &str) -> Vec<ActorAddress> {
+        self.find_by_metadata(|metadata| metadata.has_tag(tag))
+    }
+
+    /// Find actors whose attribute `key` equals `value`, ordered by name then id.
+    pub fn find_by_attribute(&self, key: &str, value: &str) -> Vec<ActorAddress> {
+        self.find_by_metadata(|metadata| metadata.attribute(key) == Some(value))
+    }
+
+    /// Find named actors whose name starts with `prefix`, ordered by name then id.
+    pub fn find_by_name_prefix(&self, prefix: &str) -> Vec<ActorAddress> {
+        let mut found: Vec<ActorAddress> = self
+            .routing_table
+            .iter()
+            .filter(|entry| {
+                entry
+                    .key()
+                    .name()
+                    .is_some_and(|name| name.starts_with(prefix))
+            })
+            .map(|entry| entry.key().clone())
+            .collect();
+        Self::sort_addresses(&mut found);
+        found
+    }
+
+    /// List registered actors one page at a time.
+    ///
+    /// Actors are ordered by name (anonymous actors first) then id, so
+    /// consecutive pages are stable while the registry is unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut offset = Some(0);
+    /// while let Some(start) = offset {
+    ///     let page = registry.list(start, 100);
+    ///     // ... render page.actors ...
+    ///     offset = page.next_offset;
+    /// }
+    /// ```
+    pub fn list(&self, offset: usize, limit: usize) -> ActorPage {
+        let mut all: Vec<ActorAddress> = self
+            .routing_table
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect();
+        Self::sort_addresses(&mut all);
+
+        let total = all.len();
+        let actors: Vec<ActorAddress> = all.into_iter().skip(offset).take(limit).collect();
+        let end = offset.saturating_add(actors.len());
+        let next_offset = (!actors.is_empty() && end < total).then_some(end);
+
+        ActorPage {
+            actors,
+            total,
+            next_offset,
+        }
+    }
+
+    fn find_by_metadata(&self, matches: impl Fn(&ActorMetadata) -> bool) -> Vec<ActorAddress> {
+        let mut found: Vec<ActorAddress> = self
+            .metadata
+            .iter()
+            .filter(|entry| matches(entry.value()))
+            .map(|entry| entry.key().clone())
+            .collect();
+        Self::sort_addresses(&mut found);
+        found
+    }
+
+    fn sort_addresses(addresses: &mut [ActorAddress]) {
+        addresses.sort_by(|a, b| (a.name(), a.id().as_uuid()).cmp(&(b.name(), b.id().as_uuid())));
+    }
+
     /// Unregister an actor by address.
     ///
     /// Removes the actor from all registry data structures including
@@ -220,6 +450,9 @@ impl<M: Message, S: MailboxSender<M>> ActorRegistry<M, S> {
         let routing_key = Self::compute_routing_key(address);
         self.routing_keys.remove(&routing_key);
 
+        // Drop discovery metadata
+        self.metadata.remove(address);
+
         // Remove from pool if applicable
         if let ActorAddress::Named { ref name, .. } = address {
             if let Some(pool_name) = name.split(':').next() {
@@ -336,6 +569,93 @@ impl<M: Message, S: MailboxSender<M>> ActorRegistry<M, S> {
         }
     }
 
+    /// Attach a routing strategy to a pool, replacing any previous one.
+    ///
+    /// May be called at any time, including while messages are being
+    /// routed; subsequent [`route_pool`](Self::route_pool) calls use the new
+    /// strategy. Switches are counted in [`RoutingStats::strategy_switches`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use airssys_rt::broker::routing::LeastOutstanding;
+    ///
+    /// registry.set_pool_strategy("workers", Arc::new(LeastOutstanding::new()));
+    /// ```
+    pub fn set_pool_strategy(&self, pool_name: &str, strategy: Arc<dyn RoutingStrategy>) {
+        let name = strategy.name();
+        let previous = self.pool_strategies.insert(pool_name.to_string(), strategy);
+
+        let mut stats = self
+            .routing_stats
+            .entry(pool_name.to_string())
+            .or_insert_with(|| RoutingStats::new(name));
+        if previous.is_some() {
+            stats.strategy_switches += 1;
+        }
+        stats.strategy = name;
+    }
+
+    /// Get the name of the routing strategy attached to a pool.
+    ///
+    /// Pools without an explicit strategy use round-robin.
+    pub fn pool_strategy_name(&self, pool_name: &str) -> &'static str {
+        self.pool_strategies
+            .get(pool_name)
+            .map_or_else(|| RoundRobin::new().name(), |s| s.name())
+    }
+
+    /// Route a message to a pool member using the pool's routing strategy.
+    ///
+    /// `key` is the optional routing key used by key-aware strategies such
+    /// as [`StickyByKey`](super::routing::StickyByKey). A round-robin
+    /// strategy is attached on first use if none was set.
+    ///
+    /// # Returns
+    ///
+    /// The selected member address, or None if the pool doesn't exist or
+    /// is empty. Both outcomes are recorded in the pool's [`RoutingStats`].
+    pub fn route_pool(&self, pool_name: &str, key: Option<u64>) -> Option<ActorAddress> {
+        let strategy = Arc::clone(
+            self.pool_strategies
+                .entry(pool_name.to_string())
+                .or_insert_with(|| Arc::new(RoundRobin::new()))
+                .value(),
+        );
+
+        let selected = self.pools.get(pool_name).and_then(|pool| {
+            strategy
+                .select(&pool, key)
+                .and_then(|index| pool.get(index).cloned())
+        });
+
+        self.routing_stats
+            .entry(pool_name.to_string())
+            .or_insert_with(|| RoutingStats::new(strategy.name()))
+            .record(selected.as_ref());
+        selected
+    }
+
+    /// Report that a request routed to a pool member has completed.
+    ///
+    /// Forwards to [`RoutingStrategy::on_complete`] so strategies tracking
+    /// in-flight work (e.g. least-outstanding) stay accurate.
+    pub fn complete_pool_request(&self, pool_name: &str, member: &ActorAddress) {
+        if let Some(strategy) = self.pool_strategies.get(pool_name) {
+            strategy.on_complete(member);
+        }
+    }
+
+    /// Get a snapshot of the routing decision metrics for a pool.
+    ///
+    /// Returns None if no routing decision or strategy change has been
+    /// made for the pool yet.
+    pub fn routing_stats(&self, pool_name: &str) -> Option<RoutingStats> {
+        self.routing_stats
+            .get(pool_name)
+            .map(|stats| stats.value().clone())
+    }
+
     /// Compute routing key from address (for pre-computation and caching).
     ///
     /// Uses DefaultHasher to compute a stable hash of the address.
@@ -420,6 +740,9 @@ impl<M: Message, S: MailboxSender<M>> Clone for ActorRegistry<M, S> {
             routing_keys: Arc::clone(&self.routing_keys),
             pools: Arc::clone(&self.pools),
             pool_counters: Arc::clone(&self.pool_counters),
+            pool_strategies: Arc::clone(&self.pool_strategies),
+            routing_stats: Arc::clone(&self.routing_stats),
+            metadata: Arc::clone(&self.metadata),
             _phantom: PhantomData,
         }
     }
@@ -429,7 +752,7 @@ impl<M: Message, S: MailboxSender<M>> Clone for ActorRegistry<M, S> {
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
-    use crate::mailbox::metrics::AtomicMetrics;
+    use crate::mailbox::metrics::DefaultMetrics;
     use crate::mailbox::UnboundedMailbox;
     use crate::message::{Message, MessagePriority};
     use crate::util::ActorId;
@@ -449,8 +772,8 @@ mod tests {
     }
 
     // Type alias for clarity in tests
-    type TestMailbox = UnboundedMailbox<TestMessage, AtomicMetrics>;
-    type TestSender = crate::mailbox::UnboundedMailboxSender<TestMessage, AtomicMetrics>;
+    type TestMailbox = UnboundedMailbox<TestMessage, DefaultMetrics>;
+    type TestSender = crate::mailbox::UnboundedMailboxSender<TestMessage, DefaultMetrics>;
 
     #[test]
     fn test_new_registry() {
@@ -681,10 +1004,169 @@ mod tests {
         assert!(registry_clone.resolve(&address).is_ok());
     }
 
+    #[test]
+    fn test_find_by_tag_and_attribute() {
+        let registry = ActorRegistry::<TestMessage, TestSender>::new();
+        let ingest = ActorAddress::named("ingest-1");
+        let gpu = ActorAddress::named("render-1");
+        for (address, metadata) in [
+            (
+                ingest.clone(),
+                ActorMetadata::new()
+                    .with_tag("worker")
+                    .with_attribute("region", "eu"),
+            ),
+            (
+                gpu.clone(),
+                ActorMetadata::new()
+                    .with_tag("worker")
+                    .with_tag("gpu")
+                    .with_attribute("region", "us"),
+            ),
+        ] {
+            let (_receiver, sender) = TestMailbox::new();
+            registry
+                .register_with_metadata(address, sender, metadata)
+                .unwrap();
+        }
+
+        assert_eq!(
+            registry.find_by_tag("worker"),
+            vec![ingest.clone(), gpu.clone()]
+        );
+        assert_eq!(registry.find_by_tag("gpu"), vec![gpu.clone()]);
+        assert_eq!(
+            registry.find_by_attribute("region", "eu"),
+            vec![ingest.clone()]
+        );
+
+        registry.unregister(&gpu).unwrap();
+        assert!(registry.find_by_tag("gpu").is_empty());
+        assert!(registry.metadata(&gpu).is_none());
+    }
+
+    #[test]
+    fn test_set_metadata_requires_registration() {
+        let registry = ActorRegistry::<TestMessage, TestSender>::new();
+        let (_receiver, sender) = TestMailbox::new();
+        let address = ActorAddress::anonymous();
+
+        let result = registry.set_metadata(&address, ActorMetadata::new().with_tag("x"));
+        assert!(matches!(result, Err(BrokerError::ActorNotFound(_))));
+
+        registry.register(address.clone(), sender).unwrap();
+        assert_eq!(registry.metadata(&address), Some(ActorMetadata::new()));
+        registry
+            .set_metadata(&address, ActorMetadata::new().with_tag("x"))
+            .unwrap();
+        assert_eq!(registry.find_by_tag("x"), vec![address]);
+    }
+
+    #[test]
+    fn test_find_by_name_prefix_and_list_pages() {
+        let registry = ActorRegistry::<TestMessage, TestSender>::new();
+        for name in ["db-2", "api-1", "db-1", "cache-1", "db-3"] {
+            let (_receiver, sender) = TestMailbox::new();
+            registry
+                .register(ActorAddress::named(name), sender)
+                .unwrap();
+        }
+
+        let names = |addresses: &[ActorAddress]| -> Vec<String> {
+            addresses
+                .iter()
+                .map(|a| a.name().unwrap().to_string())
+                .collect()
+        };
+        assert_eq!(
+            names(&registry.find_by_name_prefix("db-")),
+            vec!["db-1", "db-2", "db-3"]
+        );
+
+        let first = registry.list(0, 2);
+        assert_eq!(names(&first.actors), vec!["api-1", "cache-1"]);
+        assert_eq!(first.total, 5);
+        assert_eq!(first.next_offset, Some(2));
+
+        let last = registry.list(4, 2);
+        assert_eq!(names(&last.actors), vec!["db-3"]);
+        assert_eq!(last.next_offset, None);
+    }
+
     #[test]
     fn test_pool_strategy_equality() {
         assert_eq!(PoolStrategy::RoundRobin, PoolStrategy::RoundRobin);
         assert_eq!(PoolStrategy::Random, PoolStrategy::Random);
         assert_ne!(PoolStrategy::RoundRobin, PoolStrategy::Random);
     }
+
+    fn register_pool(
+        registry: &ActorRegistry<TestMessage, TestSender>,
+        size: usize,
+    ) -> Vec<ActorAddress> {
+        (0..size)
+            .map(|i| {
+                let (_receiver, sender) = TestMailbox::new();
+                let address = ActorAddress::Named {
+                    id: ActorId::new(),
+                    name: format!("workers:worker-{i}"),
+                };
+                registry.register(address.clone(), sender).unwrap();
+                address
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_route_pool_defaults_to_round_robin() {
+        let registry = ActorRegistry::<TestMessage, TestSender>::new();
+        let members = register_pool(&registry, 2);
+
+        assert_eq!(
+            registry.route_pool("workers", None),
+            Some(members[0].clone())
+        );
+        assert_eq!(
+            registry.route_pool("workers", None),
+            Some(members[1].clone())
+        );
+        assert_eq!(registry.route_pool("missing", None), None);
+
+        let stats = registry.routing_stats("workers").unwrap();
+        assert_eq!(stats.strategy, "round_robin");
+        assert_eq!(stats.decisions, 2);
+        assert_eq!(stats.selections(&members[0]), 1);
+        assert_eq!(registry.routing_stats("missing").unwrap().misses, 1);
+    }
+
+    #[test]
+    fn test_route_pool_runtime_strategy_switch() {
+        use crate::broker::routing::{LeastOutstanding, StickyByKey};
+
+        let registry = ActorRegistry::<TestMessage, TestSender>::new();
+        let members = register_pool(&registry, 3);
+
+        registry.set_pool_strategy("workers", Arc::new(LeastOutstanding::new()));
+        let busy = registry.route_pool("workers", None).unwrap();
+        assert_eq!(busy, members[0]);
+        assert_eq!(
+            registry.route_pool("workers", None),
+            Some(members[1].clone())
+        );
+        registry.complete_pool_request("workers", &busy);
+        assert_eq!(
+            registry.route_pool("workers", None),
+            Some(members[0].clone())
+        );
+
+        registry.set_pool_strategy("workers", Arc::new(StickyByKey::new()));
+        assert_eq!(registry.pool_strategy_name("workers"), "sticky_by_key");
+        let sticky = registry.route_pool("workers", Some(7));
+        assert_eq!(registry.route_pool("workers", Some(7)), sticky);
+
+        let stats = registry.routing_stats("workers").unwrap();
+        assert_eq!(stats.strategy, "sticky_by_key");
+        assert_eq!(stats.strategy_switches, 1);
+        assert_eq!(stats.decisions, 5);
+    }
 }
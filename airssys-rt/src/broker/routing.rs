@@ -0,0 +1,397 @@
+//! Pluggable load-balancing strategies for actor pool routing.
+//!
+//! [`PoolStrategy`](super::PoolStrategy) covers the stateless cases. This
+//! module adds the [`RoutingStrategy`] trait for strategies that keep state
+//! between decisions, plus the built-in implementations:
+//!
+//! - [`RoundRobin`]: Sequential distribution across members
+//! - [`LeastOutstanding`]: Member with the fewest in-flight requests
+//! - [`Weighted`]: Smooth weighted round-robin (nginx-style)
+//! - [`StickyByKey`]: Same key always routes to the same member
+//!
+//! Strategies are attached per pool via
+//! [`ActorRegistry::set_pool_strategy`](super::ActorRegistry::set_pool_strategy)
+//! and can be swapped at runtime. Every decision is recorded in
+//! [`RoutingStats`].
+
+// Layer 1: Standard library imports
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Layer 2: Third-party crate imports
+use dashmap::DashMap;
+use parking_lot::Mutex;
+
+// Layer 3: Internal module imports
+use crate::util::ActorAddress;
+
+/// Load-balancing strategy selecting one member of an actor pool.
+///
+/// Implementations must be cheap and non-blocking: `select` runs on the
+/// message routing path.
+///
+/// # Example
+///
+/// ```rust
+/// use airssys_rt::broker::routing::{RoutingStrategy, StickyByKey};
+/// use airssys_rt::util::ActorAddress;
+///
+/// let members = vec![ActorAddress::named("workers:a"), ActorAddress::named("workers:b")];
+/// let strategy = StickyByKey::new();
+///
+/// let first = strategy.select(&members, Some(42));
+/// assert_eq!(first, strategy.select(&members, Some(42)));
+/// ```
+pub trait RoutingStrategy: Debug + Send + Sync {
+    /// Strategy name used in [`RoutingStats`].
+    fn name(&self) -> &'static str;
+
+    /// Select the index of the member that receives the next message.
+    ///
+    /// `key` is the optional routing key of the message (e.g. a session or
+    /// entity ID). Returns `None` only if `members` is empty.
+    fn select(&self, members: &[ActorAddress], key: Option<u64>) -> Option<usize>;
+
+    /// Notify the strategy that a request routed to `member` has completed.
+    ///
+    /// Only strategies tracking in-flight work need to override this.
+    fn on_complete(&self, _member: &ActorAddress) {}
+}
+
+/// Sequential distribution across pool members.
+#[derive(Debug, Default)]
+pub struct RoundRobin {
+    counter: AtomicUsize,
+}
+
+impl RoundRobin {
+    /// Create a round-robin strategy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl RoutingStrategy for RoundRobin {
+    fn name(&self) -> &'static str {
+        "round_robin"
+    }
+
+    fn select(&self, members: &[ActorAddress], _key: Option<u64>) -> Option<usize> {
+        if members.is_empty() {
+            return None;
+        }
+        Some(self.counter.fetch_add(1, Ordering::Relaxed) % members.len())
+    }
+}
+
+/// Routes to the member with the fewest outstanding requests.
+///
+/// Each selection counts as one outstanding request until
+/// [`RoutingStrategy::on_complete`] is called for that member. Ties go to
+/// the earliest member in the pool.
+#[derive(Debug, Default)]
+pub struct LeastOutstanding {
+    outstanding: DashMap<ActorAddress, usize>,
+}
+
+impl LeastOutstanding {
+    /// Create a least-outstanding-requests strategy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the number of outstanding requests for a member.
+    pub fn outstanding(&self, member: &ActorAddress) -> usize {
+        self.outstanding.get(member).map_or(0, |count| *count)
+    }
+}
+
+impl RoutingStrategy for LeastOutstanding {
+    fn name(&self) -> &'static str {
+        "least_outstanding"
+    }
+
+    fn select(&self, members: &[ActorAddress], _key: Option<u64>) -> Option<usize> {
+        let index = members
+            .iter()
+            .enumerate()
+            .min_by_key(|(i, member)| (self.outstanding(member), *i))
+            .map(|(i, _)| i)?;
+        *self.outstanding.entry(members[index].clone()).or_insert(0) += 1;
+        Some(index)
+    }
+
+    fn on_complete(&self, member: &ActorAddress) {
+        if let Some(mut count) = self.outstanding.get_mut(member) {
+            *count = count.saturating_sub(1);
+        }
+    }
+}
+
+/// Smooth weighted round-robin.
+///
+/// Members receive traffic in proportion to their weight, interleaved
+/// rather than in bursts. Members without an explicit weight use the
+/// default weight (1); a weight of 0 excludes a member unless every
+/// member has weight 0.
+#[derive(Debug)]
+pub struct Weighted {
+    weights: HashMap<ActorAddress, u32>,
+    default_weight: u32,
+    current: Mutex<HashMap<ActorAddress, i64>>,
+}
+
+impl Weighted {
+    /// Create a weighted strategy where every member has weight 1.
+    pub fn new() -> Self {
+        Self {
+            weights: HashMap::new(),
+            default_weight: 1,
+            current: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Set the weight of a member.
+    pub fn with_weight(mut self, member: ActorAddress, weight: u32) -> Self {
+        self.weights.insert(member, weight);
+        self
+    }
+
+    /// Set the weight used for members without an explicit weight.
+    pub fn with_default_weight(mut self, weight: u32) -> Self {
+        self.default_weight = weight;
+        self
+    }
+
+    /// Get the weight of a member.
+    pub fn weight(&self, member: &ActorAddress) -> u32 {
+        self.weights
+            .get(member)
+            .copied()
+            .unwrap_or(self.default_weight)
+    }
+}
+
+impl Default for Weighted {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RoutingStrategy for Weighted {
+    fn name(&self) -> &'static str {
+        "weighted"
+    }
+
+    fn select(&self, members: &[ActorAddress], _key: Option<u64>) -> Option<usize> {
+        if members.is_empty() {
+            return None;
+        }
+
+        let total: i64 = members.iter().map(|m| i64::from(self.weight(m))).sum();
+        if total == 0 {
+            // All members excluded: fall back to the first member
+            return Some(0);
+        }
+
+        let mut current = self.current.lock();
+        current.retain(|member, _| members.contains(member));
+
+        let mut best: Option<(usize, i64)> = None;
+        for (i, member) in members.iter().enumerate() {
+            let value = current.entry(member.clone()).or_insert(0);
+            *value += i64::from(self.weight(member));
+            if best.is_none_or(|(_, v)| *value > v) {
+                best = Some((i, *value));
+            }
+        }
+
+        let (index, _) = best?;
+        if let Some(value) = current.get_mut(&members[index]) {
+            *value -= total;
+        }
+        Some(index)
+    }
+}
+
+/// Routes messages with the same key to the same member.
+///
+/// Uses rendezvous (highest-random-weight) hashing, so adding or removing a
+/// member only remaps the keys owned by that member. Messages without a key
+/// are distributed round-robin.
+#[derive(Debug, Default)]
+pub struct StickyByKey {
+    fallback: RoundRobin,
+}
+
+impl StickyByKey {
+    /// Create a sticky-by-key strategy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn score(key: u64, member: &ActorAddress) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        member.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl RoutingStrategy for StickyByKey {
+    fn name(&self) -> &'static str {
+        "sticky_by_key"
+    }
+
+    fn select(&self, members: &[ActorAddress], key: Option<u64>) -> Option<usize> {
+        let Some(key) = key else {
+            return self.fallback.select(members, None);
+        };
+        members
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, member)| Self::score(key, member))
+            .map(|(i, _)| i)
+    }
+}
+
+/// Routing decision metrics for one actor pool.
+///
+/// Returned by [`ActorRegistry::routing_stats`](super::ActorRegistry::routing_stats).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoutingStats {
+    /// Name of the strategy currently in use.
+    pub strategy: &'static str,
+
+    /// Total routing decisions made.
+    pub decisions: u64,
+
+    /// Routing attempts that found no member (empty pool).
+    pub misses: u64,
+
+    /// Number of times the strategy was switched at runtime.
+    pub strategy_switches: u64,
+
+    /// Decisions per member.
+    pub per_member: HashMap<ActorAddress, u64>,
+}
+
+impl RoutingStats {
+    pub(crate) fn new(strategy: &'static str) -> Self {
+        Self {
+            strategy,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn record(&mut self, member: Option<&ActorAddress>) {
+        match member {
+            Some(member) => {
+                self.decisions += 1;
+                *self.per_member.entry(member.clone()).or_insert(0) += 1;
+            }
+            None => self.misses += 1,
+        }
+    }
+
+    /// Number of decisions that routed to `member`.
+    pub fn selections(&self, member: &ActorAddress) -> u64 {
+        self.per_member.get(member).copied().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn members(n: usize) -> Vec<ActorAddress> {
+        (0..n)
+            .map(|i| ActorAddress::named(format!("pool:m-{i}")))
+            .collect()
+    }
+
+    #[test]
+    fn test_round_robin_cycles() {
+        let pool = members(3);
+        let strategy = RoundRobin::new();
+        let picks: Vec<_> = (0..4)
+            .map(|_| strategy.select(&pool, None).unwrap())
+            .collect();
+        assert_eq!(picks, vec![0, 1, 2, 0]);
+        assert_eq!(strategy.select(&[], None), None);
+    }
+
+    #[test]
+    fn test_least_outstanding_prefers_idle_member() {
+        let pool = members(2);
+        let strategy = LeastOutstanding::new();
+
+        assert_eq!(strategy.select(&pool, None), Some(0));
+        assert_eq!(strategy.select(&pool, None), Some(1));
+        assert_eq!(strategy.select(&pool, None), Some(0));
+        assert_eq!(strategy.outstanding(&pool[0]), 2);
+
+        strategy.on_complete(&pool[0]);
+        strategy.on_complete(&pool[0]);
+        assert_eq!(strategy.select(&pool, None), Some(0));
+    }
+
+    #[test]
+    fn test_weighted_distributes_proportionally() {
+        let pool = members(2);
+        let strategy = Weighted::new().with_weight(pool[0].clone(), 3);
+
+        let picks: Vec<_> = (0..8)
+            .map(|_| strategy.select(&pool, None).unwrap())
+            .collect();
+        assert_eq!(picks.iter().filter(|i| **i == 0).count(), 6);
+        assert_eq!(picks.iter().filter(|i| **i == 1).count(), 2);
+        // Smooth: the light member is not starved for a whole cycle
+        assert!(picks[..4].contains(&1));
+    }
+
+    #[test]
+    fn test_weighted_zero_weight_excludes_member() {
+        let pool = members(2);
+        let strategy = Weighted::new().with_weight(pool[1].clone(), 0);
+        assert!((0..5).all(|_| strategy.select(&pool, None) == Some(0)));
+    }
+
+    #[test]
+    fn test_sticky_by_key_is_stable() {
+        let pool = members(4);
+        let strategy = StickyByKey::new();
+
+        for key in 0..32u64 {
+            let first = strategy.select(&pool, Some(key)).unwrap();
+            assert_eq!(strategy.select(&pool, Some(key)), Some(first));
+
+            // Removing another member does not remap this key
+            let other = (first + 1) % pool.len();
+            let reduced: Vec<_> = pool
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != other)
+                .map(|(_, m)| m.clone())
+                .collect();
+            let picked = strategy.select(&reduced, Some(key)).unwrap();
+            assert_eq!(reduced[picked], pool[first]);
+        }
+    }
+
+    #[test]
+    fn test_routing_stats_record() {
+        let pool = members(1);
+        let mut stats = RoutingStats::new("round_robin");
+        stats.record(Some(&pool[0]));
+        stats.record(None);
+
+        assert_eq!(stats.decisions, 1);
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.selections(&pool[0]), 1);
+    }
+}
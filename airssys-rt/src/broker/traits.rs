@@ -6,6 +6,7 @@
 
 // Layer 1: Standard library imports
 use std::error::Error;
+use std::sync::Arc;
 use std::time::Duration;
 
 // Layer 2: Third-party crate imports
@@ -13,6 +14,7 @@ use async_trait::async_trait;
 use tokio::sync::mpsc;
 
 // Layer 3: Internal module imports
+use super::qos::QosBuffer;
 use crate::message::{Message, MessageEnvelope};
 
 /// Message stream from broker subscriptions.
@@ -31,7 +33,12 @@ use crate::message::{Message, MessageEnvelope};
 /// }
 /// ```
 pub struct MessageStream<M: Message> {
-    receiver: mpsc::UnboundedReceiver<MessageEnvelope<M>>,
+    source: StreamSource<M>,
+}
+
+enum StreamSource<M: Message> {
+    Unbounded(mpsc::UnboundedReceiver<MessageEnvelope<M>>),
+    Buffered(Arc<QosBuffer<MessageEnvelope<M>>>),
 }
 
 impl<M: Message> MessageStream<M> {
@@ -39,14 +46,30 @@ impl<M: Message> MessageStream<M> {
     ///
     /// This is typically called internally by broker implementations.
     pub fn new(receiver: mpsc::UnboundedReceiver<MessageEnvelope<M>>) -> Self {
-        Self { receiver }
+        Self {
+            source: StreamSource::Unbounded(receiver),
+        }
+    }
+
+    /// Create a stream reading from a QoS-bounded buffer.
+    ///
+    /// Dropping the stream closes the buffer so the broker can forget the
+    /// subscriber.
+    pub fn buffered(buffer: Arc<QosBuffer<MessageEnvelope<M>>>) -> Self {
+        Self {
+            source: StreamSource::Buffered(buffer),
+        }
     }
 
     /// Receive next message from stream.
     ///
-    /// Returns `None` when the stream is closed (all publishers dropped).
+    /// Returns `None` when the stream is closed (all publishers dropped, or
+    /// a buffered subscription was disconnected by its overflow policy).
     pub async fn recv(&mut self) -> Option<MessageEnvelope<M>> {
-        self.receiver.recv().await
+        match &mut self.source {
+            StreamSource::Unbounded(receiver) => receiver.recv().await,
+            StreamSource::Buffered(buffer) => buffer.pop().await,
+        }
     }
 
     /// Try to receive without blocking.
@@ -56,7 +79,32 @@ impl<M: Message> MessageStream<M> {
     /// - `Err(TryRecvError::Empty)` - No messages available
     /// - `Err(TryRecvError::Disconnected)` - Stream closed
     pub fn try_recv(&mut self) -> Result<MessageEnvelope<M>, mpsc::error::TryRecvError> {
-        self.receiver.try_recv()
+        match &mut self.source {
+            StreamSource::Unbounded(receiver) => receiver.try_recv(),
+            StreamSource::Buffered(buffer) => match buffer.try_pop() {
+                Some(envelope) => Ok(envelope),
+                None if buffer.is_closed() => Err(mpsc::error::TryRecvError::Disconnected),
+                None => Err(mpsc::error::TryRecvError::Empty),
+            },
+        }
+    }
+
+    /// Number of messages lost to the subscription's overflow policy.
+    ///
+    /// Always zero for unbounded subscriptions.
+    pub fn dropped(&self) -> u64 {
+        match &self.source {
+            StreamSource::Unbounded(_) => 0,
+            StreamSource::Buffered(buffer) => buffer.dropped(),
+        }
+    }
+}
+
+impl<M: Message> Drop for MessageStream<M> {
+    fn drop(&mut self) {
+        if let StreamSource::Buffered(buffer) = &self.source {
+            buffer.close();
+        }
     }
 }
 
@@ -8,7 +8,7 @@ use tokio::sync::mpsc;
 
 // Layer 3: Internal module imports
 use super::backpressure::BackpressureStrategy;
-use super::metrics::{AtomicMetrics, MetricsRecorder};
+use super::metrics::{DefaultMetrics, MetricsRecorder};
 use super::traits::{MailboxCapacity, MailboxError, MailboxReceiver, MailboxSender, TryRecvError};
 use crate::message::{Message, MessageEnvelope};
 
@@ -21,12 +21,12 @@ use crate::message::{Message, MessageEnvelope};
 /// # Type Parameters
 ///
 /// * `M` - The message type implementing [`Message`]
-/// * `R` - The metrics recorder implementing [`MetricsRecorder`] (default: [`AtomicMetrics`])
+/// * `R` - The metrics recorder implementing [`MetricsRecorder`] (constructed by `new` with [`DefaultMetrics`])
 ///
 /// # Example
 ///
 /// ```ignore
-/// use airssys_rt::mailbox::{BoundedMailbox, BackpressureStrategy, AtomicMetrics};
+/// use airssys_rt::mailbox::{BoundedMailbox, BackpressureStrategy, DefaultMetrics};
 /// use airssys_rt::message::{Message, MessageEnvelope};
 ///
 /// #[derive(Debug, Clone)]
@@ -36,7 +36,7 @@ use crate::message::{Message, MessageEnvelope};
 /// }
 ///
 /// // Create bounded mailbox with capacity 100 and default metrics
-/// let (mailbox, sender) = BoundedMailbox::<MyMessage, AtomicMetrics>::new(100);
+/// let (mailbox, sender) = BoundedMailbox::<MyMessage, DefaultMetrics>::new(100);
 /// ```
 pub struct BoundedMailbox<M: Message, R: MetricsRecorder> {
     receiver: mpsc::Receiver<MessageEnvelope<M>>,
@@ -118,9 +118,9 @@ impl<M: Message, R: MetricsRecorder> BoundedMailbox<M, R> {
     }
 }
 
-// Convenience constructors for AtomicMetrics (common case)
-impl<M: Message> BoundedMailbox<M, AtomicMetrics> {
-    /// Create a new bounded mailbox with default backpressure strategy and AtomicMetrics.
+// Convenience constructors for DefaultMetrics (common case)
+impl<M: Message> BoundedMailbox<M, DefaultMetrics> {
+    /// Create a new bounded mailbox with default backpressure strategy and DefaultMetrics.
     ///
     /// # Example
     ///
@@ -136,11 +136,11 @@ impl<M: Message> BoundedMailbox<M, AtomicMetrics> {
     ///
     /// let (mailbox, sender) = BoundedMailbox::new(100);
     /// ```
-    pub fn new(capacity: usize) -> (Self, BoundedMailboxSender<M, AtomicMetrics>) {
-        Self::with_metrics(capacity, AtomicMetrics::new())
+    pub fn new(capacity: usize) -> (Self, BoundedMailboxSender<M, DefaultMetrics>) {
+        Self::with_metrics(capacity, DefaultMetrics::default())
     }
 
-    /// Create a bounded mailbox with custom backpressure strategy and AtomicMetrics.
+    /// Create a bounded mailbox with custom backpressure strategy and DefaultMetrics.
     ///
     /// # Example
     ///
@@ -159,8 +159,8 @@ impl<M: Message> BoundedMailbox<M, AtomicMetrics> {
     pub fn with_backpressure(
         capacity: usize,
         strategy: BackpressureStrategy,
-    ) -> (Self, BoundedMailboxSender<M, AtomicMetrics>) {
-        Self::with_backpressure_and_metrics(capacity, strategy, AtomicMetrics::new())
+    ) -> (Self, BoundedMailboxSender<M, DefaultMetrics>) {
+        Self::with_backpressure_and_metrics(capacity, strategy, DefaultMetrics::default())
     }
 }
 
@@ -263,6 +263,7 @@ impl<M: Message, R: MetricsRecorder + Clone> MailboxSender<M> for BoundedMailbox
 #[allow(clippy::unwrap_used)] // Tests are allowed to use unwrap for simplicity
 mod tests {
     use super::*;
+    use crate::mailbox::metrics::AtomicMetrics;
     use crate::message::MessagePriority;
 
     #[derive(Debug, Clone)]
@@ -329,7 +330,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_metrics_tracking() {
-        let (mut mailbox, sender) = BoundedMailbox::new(10);
+        let (mut mailbox, sender) = BoundedMailbox::with_metrics(10, AtomicMetrics::new());
 
         let envelope = MessageEnvelope::new(TestMessage {
             content: "test".to_string(),
@@ -420,7 +421,7 @@ mod tests {
         use std::time::Duration;
         use tokio::time::sleep;
 
-        let (mut mailbox, sender) = BoundedMailbox::new(10);
+        let (mut mailbox, sender) = BoundedMailbox::with_metrics(10, AtomicMetrics::new());
 
         let msg = TestMessage {
             content: "expired".to_string(),
@@ -6,8 +6,11 @@
 //! # Design
 //!
 //! - `MetricsRecorder` trait: Interface for recording metrics
-//! - `AtomicMetrics`: Default lock-free implementation using atomics
-//! - Future: `AsyncMetrics`, `NoOpMetrics`, `PrometheusMetrics`
+//! - `AtomicMetrics`: Lock-free implementation counting every operation
+//! - `SampledMetrics`: Records 1-in-N operations and scales counts on query
+//! - `NoOpMetrics`: Records nothing (zero overhead)
+//! - `DefaultMetrics`: `AtomicMetrics` in debug builds, `NoOpMetrics` in release
+//! - Future: `AsyncMetrics`, `PrometheusMetrics`
 //!
 //! # Architecture
 //!
@@ -24,7 +27,7 @@
 /// # Example
 ///
 /// ```rust
-/// use airssys_rt::mailbox::{BoundedMailbox, AtomicMetrics};
+/// use airssys_rt::mailbox::{AtomicMetrics, BoundedMailbox, DefaultMetrics};
 ///
 /// # #[derive(Debug, Clone)]
 /// # struct MyMessage;
@@ -32,8 +35,8 @@
 /// #     const MESSAGE_TYPE: &'static str = "my_message";
 /// # }
 /// # async fn example() {
-/// // Uses AtomicMetrics by default
-/// let (mailbox, sender) = BoundedMailbox::<MyMessage, AtomicMetrics>::new(100);
+/// // Uses DefaultMetrics
+/// let (mailbox, sender) = BoundedMailbox::<MyMessage, DefaultMetrics>::new(100);
 ///
 /// // Or inject custom metrics
 /// let metrics = AtomicMetrics::default();
@@ -58,18 +61,6 @@
 /// }
 /// ```
 ///
-/// ## NoOpMetrics (Zero Overhead)
-///
-/// ```ignore
-/// pub struct NoOpMetrics;
-///
-/// impl MetricsRecorder for NoOpMetrics {
-///     fn record_sent(&self) {}  // No-op
-///     fn sent_count(&self) -> u64 { 0 }
-///     // All operations are no-ops
-/// }
-/// ```
-///
 /// ## PrometheusMetrics (Remote Export)
 ///
 /// ```ignore
@@ -88,7 +79,27 @@
 /// }
 /// ```
 mod atomic;
+mod noop;
 mod recorder;
+mod sampled;
 
 pub use atomic::AtomicMetrics;
+pub use noop::NoOpMetrics;
 pub use recorder::MetricsRecorder;
+pub use sampled::SampledMetrics;
+
+/// Metrics recorder selected by build profile.
+///
+/// `AtomicMetrics` in debug builds; `NoOpMetrics` when debug assertions are
+/// off, so release mailboxes pay nothing for metrics unless they opt in by
+/// naming a recorder explicitly.
+#[cfg(debug_assertions)]
+pub type DefaultMetrics = AtomicMetrics;
+
+/// Metrics recorder selected by build profile.
+///
+/// `AtomicMetrics` in debug builds; `NoOpMetrics` when debug assertions are
+/// off, so release mailboxes pay nothing for metrics unless they opt in by
+/// naming a recorder explicitly.
+#[cfg(not(debug_assertions))]
+pub type DefaultMetrics = NoOpMetrics;
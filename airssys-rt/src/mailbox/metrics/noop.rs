@@ -0,0 +1,84 @@
+//! No-op metrics implementation.
+//!
+//! Every recording method is empty and every query returns zero, so the
+//! compiler removes metrics calls from the mailbox hot path entirely.
+//!
+//! # Example
+//!
+//! ```rust
+//! use airssys_rt::mailbox::metrics::{MetricsRecorder, NoOpMetrics};
+//!
+//! let metrics = NoOpMetrics;
+//! metrics.record_sent();
+//!
+//! assert_eq!(metrics.sent_count(), 0);
+//! ```
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Utc}; // §3.2 MANDATORY
+
+// Layer 3: Internal module imports
+use super::MetricsRecorder;
+
+/// Metrics recorder that records nothing (zero overhead).
+///
+/// Use it for mailboxes on hot paths where counters are not needed. It is
+/// the [`DefaultMetrics`](super::DefaultMetrics) recorder in release builds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NoOpMetrics;
+
+impl NoOpMetrics {
+    /// Create a new NoOpMetrics instance.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl MetricsRecorder for NoOpMetrics {
+    #[inline(always)]
+    fn record_sent(&self) {}
+
+    #[inline(always)]
+    fn record_received(&self) {}
+
+    #[inline(always)]
+    fn record_dropped(&self) {}
+
+    #[inline(always)]
+    fn update_last_message(&self, _timestamp: DateTime<Utc>) {}
+
+    fn sent_count(&self) -> u64 {
+        0
+    }
+
+    fn received_count(&self) -> u64 {
+        0
+    }
+
+    fn dropped_count(&self) -> u64 {
+        0
+    }
+
+    fn last_message_at(&self) -> Option<DateTime<Utc>> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_noop_metrics_records_nothing() {
+        let metrics = NoOpMetrics::new();
+        metrics.record_sent();
+        metrics.record_received();
+        metrics.record_dropped();
+        metrics.update_last_message(Utc::now());
+
+        assert_eq!(metrics.sent_count(), 0);
+        assert_eq!(metrics.dropped_count(), 0);
+        assert_eq!(metrics.in_flight(), 0);
+        assert!(metrics.last_message_at().is_none());
+    }
+}
@@ -47,8 +47,10 @@ use chrono::{DateTime, Utc}; // §3.2 MANDATORY
 ///
 /// # Implementations
 ///
-/// - `AtomicMetrics`: Lock-free atomic counters (default, 10-30ns overhead)
-/// - Future: `AsyncMetrics`, `NoOpMetrics`, `PrometheusMetrics`
+/// - `AtomicMetrics`: Lock-free atomic counters (10-30ns overhead)
+/// - `SampledMetrics`: 1-in-N sampling with corrected counts (~2-3ns unsampled)
+/// - `NoOpMetrics`: Records nothing (zero overhead)
+/// - Future: `AsyncMetrics`, `PrometheusMetrics`
 ///
 /// # Example
 ///
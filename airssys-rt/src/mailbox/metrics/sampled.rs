@@ -0,0 +1,304 @@
+//! Sampling metrics implementation.
+//!
+//! Records one in every N operations and scales the counts back up when
+//! queried. Every recorder keeps its own tick per operation kind, so sends,
+//! receives, drops and timestamp updates are each sampled at exactly the
+//! configured rate regardless of how they interleave or how many mailboxes
+//! share a thread.
+//!
+//! # Accuracy
+//!
+//! Counts are estimates: each is a multiple of the sample rate and trails the
+//! true count by at most `rate - 1`. Sent and received counts are sampled
+//! independently, so `in_flight()` is off by at most `rate - 1` as well.
+//!
+//! # Example
+//!
+//! ```rust
+//! use airssys_rt::mailbox::metrics::{MetricsRecorder, SampledMetrics};
+//!
+//! let metrics = SampledMetrics::new(16);
+//! for _ in 0..1600 {
+//!     metrics.record_sent();
+//! }
+//!
+//! assert_eq!(metrics.sent_count(), 1600);
+//! assert_eq!(metrics.raw_sent_count(), 100);
+//! ```
+
+// Layer 1: Standard library imports
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Utc}; // §3.2 MANDATORY
+use parking_lot::RwLock;
+
+// Layer 3: Internal module imports
+use super::MetricsRecorder;
+
+/// Operation tick and sample count for one kind of operation.
+#[derive(Debug, Default)]
+struct SampledCounter {
+    tick: AtomicU64,
+    samples: AtomicU64,
+}
+
+impl SampledCounter {
+    /// Advance the tick and return true when this operation is sampled.
+    #[inline]
+    fn tick(&self, rate: u64) -> bool {
+        rate == 1
+            || self
+                .tick
+                .fetch_add(1, Ordering::Relaxed)
+                .wrapping_add(1)
+                .is_multiple_of(rate)
+    }
+
+    #[inline]
+    fn record(&self, rate: u64) {
+        if self.tick(rate) {
+            self.samples.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn samples(&self) -> u64 {
+        self.samples.load(Ordering::Relaxed)
+    }
+}
+
+impl Clone for SampledCounter {
+    fn clone(&self) -> Self {
+        Self {
+            tick: AtomicU64::new(self.tick.load(Ordering::Relaxed)),
+            samples: AtomicU64::new(self.samples()),
+        }
+    }
+}
+
+/// Metrics recorder that samples 1-in-N operations.
+///
+/// Query methods multiply the sampled counts by the rate (the correction
+/// factor); the `raw_*` methods return the samples themselves. A rate of 1
+/// records every operation and behaves like
+/// [`AtomicMetrics`](super::AtomicMetrics).
+///
+/// # Performance
+///
+/// - Unsampled operations: one relaxed atomic increment and compare
+/// - Sampled operations: one more relaxed increment, as in `AtomicMetrics`
+///
+/// # Example
+///
+/// ```rust
+/// use airssys_rt::mailbox::{BoundedMailbox, SampledMetrics};
+///
+/// # #[derive(Debug, Clone)]
+/// # struct MyMessage;
+/// # impl airssys_rt::message::Message for MyMessage {
+/// #     const MESSAGE_TYPE: &'static str = "my_message";
+/// # }
+/// let (mailbox, sender) =
+///     BoundedMailbox::<MyMessage, SampledMetrics>::with_metrics(100, SampledMetrics::new(64));
+/// ```
+#[derive(Debug)]
+pub struct SampledMetrics {
+    rate: u64,
+    sent: SampledCounter,
+    received: SampledCounter,
+    dropped: SampledCounter,
+    last_message_tick: AtomicU64,
+    last_message_at: RwLock<Option<DateTime<Utc>>>,
+}
+
+impl SampledMetrics {
+    /// Default sample rate (1 in 64 operations).
+    pub const DEFAULT_RATE: u64 = 64;
+
+    /// Create a recorder sampling one in every `rate` operations.
+    ///
+    /// A rate of 0 is treated as 1.
+    pub fn new(rate: u64) -> Self {
+        Self {
+            rate: rate.max(1),
+            sent: SampledCounter::default(),
+            received: SampledCounter::default(),
+            dropped: SampledCounter::default(),
+            last_message_tick: AtomicU64::new(0),
+            last_message_at: RwLock::new(None),
+        }
+    }
+
+    /// Returns the sample rate (correction factor).
+    pub fn rate(&self) -> u64 {
+        self.rate
+    }
+
+    /// Returns the number of sampled send operations.
+    pub fn raw_sent_count(&self) -> u64 {
+        self.sent.samples()
+    }
+
+    /// Returns the number of sampled receive operations.
+    pub fn raw_received_count(&self) -> u64 {
+        self.received.samples()
+    }
+
+    /// Returns the number of sampled drops.
+    pub fn raw_dropped_count(&self) -> u64 {
+        self.dropped.samples()
+    }
+}
+
+impl Default for SampledMetrics {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_RATE)
+    }
+}
+
+// Manual Clone implementation: creates a new instance with current values copied
+impl Clone for SampledMetrics {
+    fn clone(&self) -> Self {
+        Self {
+            rate: self.rate,
+            sent: self.sent.clone(),
+            received: self.received.clone(),
+            dropped: self.dropped.clone(),
+            last_message_tick: AtomicU64::new(self.last_message_tick.load(Ordering::Relaxed)),
+            last_message_at: RwLock::new(*self.last_message_at.read()),
+        }
+    }
+}
+
+impl MetricsRecorder for SampledMetrics {
+    fn record_sent(&self) {
+        self.sent.record(self.rate);
+    }
+
+    fn record_received(&self) {
+        self.received.record(self.rate);
+    }
+
+    fn record_dropped(&self) {
+        self.dropped.record(self.rate);
+    }
+
+    fn update_last_message(&self, timestamp: DateTime<Utc>) {
+        let tick = self.last_message_tick.fetch_add(1, Ordering::Relaxed);
+        if tick.wrapping_add(1).is_multiple_of(self.rate) {
+            *self.last_message_at.write() = Some(timestamp);
+        }
+    }
+
+    fn sent_count(&self) -> u64 {
+        self.raw_sent_count().saturating_mul(self.rate)
+    }
+
+    fn received_count(&self) -> u64 {
+        self.raw_received_count().saturating_mul(self.rate)
+    }
+
+    fn dropped_count(&self) -> u64 {
+        self.raw_dropped_count().saturating_mul(self.rate)
+    }
+
+    fn last_message_at(&self) -> Option<DateTime<Utc>> {
+        *self.last_message_at.read()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_rate_one_records_everything() {
+        let metrics = SampledMetrics::new(0);
+        assert_eq!(metrics.rate(), 1);
+
+        for _ in 0..7 {
+            metrics.record_sent();
+        }
+        metrics.record_dropped();
+        metrics.update_last_message(Utc::now());
+
+        assert_eq!(metrics.sent_count(), 7);
+        assert_eq!(metrics.dropped_count(), 1);
+        assert!(metrics.last_message_at().is_some());
+    }
+
+    #[test]
+    fn test_counts_are_corrected_by_rate() {
+        let metrics = SampledMetrics::new(10);
+        for _ in 0..1000 {
+            metrics.record_received();
+        }
+
+        assert_eq!(metrics.raw_received_count(), 100);
+        assert_eq!(metrics.received_count(), 1000);
+    }
+
+    #[test]
+    fn test_estimate_across_threads() {
+        let metrics = Arc::new(SampledMetrics::new(8));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let metrics = Arc::clone(&metrics);
+                thread::spawn(move || {
+                    for _ in 0..8000 {
+                        metrics.record_sent();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // The tick is shared across threads, so no sample is lost
+        assert_eq!(metrics.sent_count(), 32_000);
+    }
+
+    #[test]
+    fn test_clone_copies_samples() {
+        let metrics = SampledMetrics::new(1);
+        metrics.record_sent();
+        let cloned = metrics.clone();
+        metrics.record_sent();
+
+        assert_eq!(cloned.sent_count(), 1);
+        assert_eq!(cloned.rate(), 1);
+    }
+
+    #[test]
+    fn test_interleaved_kinds_are_sampled_independently() {
+        let metrics = SampledMetrics::new(4);
+        // A receive also updates the timestamp; neither may skew the other
+        for _ in 0..400 {
+            metrics.record_sent();
+            metrics.record_received();
+            metrics.update_last_message(Utc::now());
+        }
+
+        assert_eq!(metrics.raw_sent_count(), 100);
+        assert_eq!(metrics.raw_received_count(), 100);
+        assert_eq!(metrics.in_flight(), 0);
+        assert!(metrics.last_message_at().is_some());
+    }
+
+    #[test]
+    fn test_instances_do_not_share_ticks() {
+        let first = SampledMetrics::new(3);
+        let second = SampledMetrics::new(3);
+        for _ in 0..3 {
+            first.record_sent();
+            second.record_sent();
+        }
+
+        assert_eq!(first.raw_sent_count(), 1);
+        assert_eq!(second.raw_sent_count(), 1);
+    }
+}
@@ -168,6 +168,6 @@ pub mod unbounded;
 
 pub use backpressure::BackpressureStrategy;
 pub use bounded::{BoundedMailbox, BoundedMailboxSender};
-pub use metrics::{AtomicMetrics, MetricsRecorder};
+pub use metrics::{AtomicMetrics, DefaultMetrics, MetricsRecorder, NoOpMetrics, SampledMetrics};
 pub use traits::{MailboxCapacity, MailboxError, MailboxReceiver, MailboxSender, TryRecvError};
 pub use unbounded::{UnboundedMailbox, UnboundedMailboxSender};
@@ -41,7 +41,7 @@ use chrono::Utc;
 use tokio::sync::mpsc;
 
 // Layer 3: Internal module imports
-use super::metrics::{AtomicMetrics, MetricsRecorder};
+use super::metrics::{DefaultMetrics, MetricsRecorder};
 use super::traits::{MailboxCapacity, MailboxError, MailboxReceiver, MailboxSender, TryRecvError};
 use crate::message::{Message, MessageEnvelope};
 
@@ -54,7 +54,7 @@ use crate::message::{Message, MessageEnvelope};
 /// # Type Parameters
 ///
 /// * `M` - The message type implementing [`Message`]
-/// * `R` - The metrics recorder implementing [`MetricsRecorder`] (default: [`AtomicMetrics`])
+/// * `R` - The metrics recorder implementing [`MetricsRecorder`] (constructed by `new` with [`DefaultMetrics`])
 ///
 /// # Memory Safety
 ///
@@ -64,7 +64,7 @@ use crate::message::{Message, MessageEnvelope};
 /// # Example
 ///
 /// ```ignore
-/// use airssys_rt::mailbox::{UnboundedMailbox, AtomicMetrics};
+/// use airssys_rt::mailbox::{UnboundedMailbox, DefaultMetrics};
 /// use airssys_rt::message::{Message, MessageEnvelope};
 ///
 /// #[derive(Debug, Clone)]
@@ -74,7 +74,7 @@ use crate::message::{Message, MessageEnvelope};
 /// }
 ///
 /// // Create unbounded mailbox with default metrics
-/// let (mailbox, sender) = UnboundedMailbox::<MyMessage, AtomicMetrics>::new();
+/// let (mailbox, sender) = UnboundedMailbox::<MyMessage, DefaultMetrics>::new();
 /// ```
 pub struct UnboundedMailbox<M: Message, R: MetricsRecorder> {
     receiver: mpsc::UnboundedReceiver<MessageEnvelope<M>>,
@@ -124,9 +124,9 @@ impl<M: Message, R: MetricsRecorder> UnboundedMailbox<M, R> {
     }
 }
 
-// Convenience constructor for AtomicMetrics (common case)
-impl<M: Message> UnboundedMailbox<M, AtomicMetrics> {
-    /// Create a new unbounded mailbox with AtomicMetrics.
+// Convenience constructor for DefaultMetrics (common case)
+impl<M: Message> UnboundedMailbox<M, DefaultMetrics> {
+    /// Create a new unbounded mailbox with DefaultMetrics.
     ///
     /// # Example
     ///
@@ -142,8 +142,8 @@ impl<M: Message> UnboundedMailbox<M, AtomicMetrics> {
     ///
     /// let (mailbox, sender) = UnboundedMailbox::new();
     /// ```
-    pub fn new() -> (Self, UnboundedMailboxSender<M, AtomicMetrics>) {
-        Self::with_metrics(AtomicMetrics::new())
+    pub fn new() -> (Self, UnboundedMailboxSender<M, DefaultMetrics>) {
+        Self::with_metrics(DefaultMetrics::default())
     }
 }
 
@@ -254,6 +254,7 @@ impl<M: Message, R: MetricsRecorder + Clone> MailboxSender<M> for UnboundedMailb
 #[allow(clippy::unwrap_used, clippy::panic)]
 mod tests {
     use super::*;
+    use crate::mailbox::metrics::AtomicMetrics;
     use crate::message::Message;
 
     #[derive(Debug, Clone, PartialEq)]
@@ -362,7 +363,7 @@ mod tests {
         use std::time::Duration;
         use tokio::time::sleep;
 
-        let (mut mailbox, sender) = UnboundedMailbox::new();
+        let (mut mailbox, sender) = UnboundedMailbox::with_metrics(AtomicMetrics::new());
 
         // Send message with 1 second TTL
         let msg = TestMessage {
@@ -394,7 +395,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_unbounded_metrics() {
-        let (mut mailbox, sender) = UnboundedMailbox::new();
+        let (mut mailbox, sender) = UnboundedMailbox::with_metrics(AtomicMetrics::new());
 
         // Send 5 messages
         for i in 0..5 {
@@ -1,5 +1,6 @@
 // Layer 1: Standard library imports
 use std::fmt::Debug;
+use std::time::Duration;
 
 // Layer 2: Third-party crate imports
 use chrono::{DateTime, Utc}; // §3.2 MANDATORY
@@ -22,6 +23,7 @@ use crate::util::ids::ActorAddress;
 /// - **Routing**: Sender and reply-to addresses for message routing
 /// - **Tracking**: Correlation IDs for request/response correlation
 /// - **Expiration**: Time-to-live (TTL) for message expiration
+/// - **Deadlines**: Absolute deadline propagated across nested requests
 /// - **Priority**: Priority-based message processing
 /// - **Timestamps**: Message creation time for ordering and diagnostics
 ///
@@ -170,6 +172,13 @@ pub struct MessageEnvelope<M: Message> {
 
     /// Optional time-to-live in seconds
     pub ttl: Option<u64>,
+
+    /// Optional absolute deadline by which the message must be handled.
+    ///
+    /// Propagated to nested sends/requests via [`ActorContext`](crate::actor::ActorContext)
+    /// so downstream work inherits the remaining budget of the original request.
+    #[serde(default)]
+    pub deadline: Option<DateTime<Utc>>,
 }
 
 impl<M: Message> MessageEnvelope<M> {
@@ -179,7 +188,7 @@ impl<M: Message> MessageEnvelope<M> {
     /// - The provided message payload
     /// - Current timestamp (UTC, §3.2)
     /// - Priority extracted from payload
-    /// - No sender, reply_to, correlation_id, or TTL (all None)
+    /// - No sender, reply_to, correlation_id, TTL, or deadline (all None)
     ///
     /// Use builder methods to add optional metadata.
     ///
@@ -214,6 +223,7 @@ impl<M: Message> MessageEnvelope<M> {
             correlation_id: None,
             priority,
             ttl: None,
+            deadline: None,
         }
     }
 
@@ -352,6 +362,60 @@ impl<M: Message> MessageEnvelope<M> {
         }
     }
 
+    /// Builder method: Set an absolute deadline.
+    ///
+    /// If a deadline is already set, the earlier of the two is kept so a
+    /// nested request can never extend the budget it inherited.
+    pub fn with_deadline(mut self, deadline: DateTime<Utc>) -> Self {
+        self.deadline = Some(match self.deadline {
+            Some(existing) => existing.min(deadline),
+            None => deadline,
+        });
+        self
+    }
+
+    /// Builder method: Set a deadline relative to now.
+    ///
+    /// Timeouts too large to represent leave the deadline unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use airssys_rt::message::{Message, MessageEnvelope};
+    ///
+    /// #[derive(Debug, Clone)]
+    /// struct TestMsg;
+    /// impl Message for TestMsg {
+    ///     const MESSAGE_TYPE: &'static str = "test";
+    /// }
+    ///
+    /// let envelope = MessageEnvelope::new(TestMsg).with_timeout(Duration::from_secs(5));
+    ///
+    /// assert!(envelope.deadline.is_some());
+    /// assert!(!envelope.is_deadline_exceeded());
+    /// ```
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        match deadline_after(timeout) {
+            Some(deadline) => self.with_deadline(deadline),
+            None => self,
+        }
+    }
+
+    /// Remaining time before the deadline, or `None` if no deadline is set.
+    ///
+    /// Returns `Some(Duration::ZERO)` once the deadline has passed.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.deadline.map(remaining_until)
+    }
+
+    /// Check if the message's deadline has passed.
+    ///
+    /// Returns `false` if no deadline is set.
+    pub fn is_deadline_exceeded(&self) -> bool {
+        self.deadline.is_some_and(|deadline| Utc::now() >= deadline)
+    }
+
     /// Get message type from payload's const MESSAGE_TYPE.
     ///
     /// Returns the compile-time message type identifier without runtime
@@ -376,6 +440,21 @@ impl<M: Message> MessageEnvelope<M> {
     }
 }
 
+/// Absolute deadline `timeout` from now, or `None` if it cannot be represented.
+pub(crate) fn deadline_after(timeout: Duration) -> Option<DateTime<Utc>> {
+    chrono::Duration::from_std(timeout)
+        .ok()
+        .and_then(|delta| Utc::now().checked_add_signed(delta))
+}
+
+/// Time left until `deadline`, saturating at zero.
+pub(crate) fn remaining_until(deadline: DateTime<Utc>) -> Duration {
+    deadline
+        .signed_duration_since(Utc::now())
+        .to_std()
+        .unwrap_or(Duration::ZERO)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -531,6 +610,53 @@ mod tests {
         assert!(envelope.is_expired());
     }
 
+    #[test]
+    fn test_deadline_not_set_by_default() {
+        let envelope = MessageEnvelope::new(TestMessage {
+            content: "test".to_string(),
+        });
+
+        assert!(envelope.deadline.is_none());
+        assert!(envelope.remaining().is_none());
+        assert!(!envelope.is_deadline_exceeded());
+    }
+
+    #[test]
+    fn test_deadline_keeps_earliest() {
+        let soon = Utc::now() + chrono::Duration::seconds(1);
+        let later = Utc::now() + chrono::Duration::seconds(60);
+        let envelope = MessageEnvelope::new(TestMessage {
+            content: "test".to_string(),
+        })
+        .with_deadline(soon)
+        .with_deadline(later);
+
+        assert_eq!(envelope.deadline, Some(soon));
+        let remaining = envelope.remaining().unwrap_or_default();
+        assert!(remaining <= Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_deadline_exceeded() {
+        let envelope = MessageEnvelope::new(TestMessage {
+            content: "test".to_string(),
+        })
+        .with_deadline(Utc::now() - chrono::Duration::seconds(1));
+
+        assert!(envelope.is_deadline_exceeded());
+        assert_eq!(envelope.remaining(), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_with_timeout_overflow_leaves_deadline_unset() {
+        let envelope = MessageEnvelope::new(TestMessage {
+            content: "test".to_string(),
+        })
+        .with_timeout(Duration::MAX);
+
+        assert!(envelope.deadline.is_none());
+    }
+
     #[test]
     fn test_message_type_accessor() {
         let msg = TestMessage {
@@ -203,6 +203,46 @@ pub enum MessagePriority {
     Critical = 3,
 }
 
+impl MessagePriority {
+    /// Default ceiling for priorities inherited along a request chain.
+    ///
+    /// `Critical` is never inherited by default so that ordinary work
+    /// triggered by a system message cannot crowd out other system messages.
+    pub const DEFAULT_INHERITANCE_CAP: MessagePriority = MessagePriority::High;
+
+    /// Priority of a message sent while handling a `parent` message.
+    ///
+    /// Only elevated priorities (above `Normal`) propagate, and never beyond
+    /// `cap`; a message keeps its own priority if that is already higher.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use airssys_rt::message::MessagePriority;
+    ///
+    /// let cap = MessagePriority::DEFAULT_INHERITANCE_CAP;
+    /// assert_eq!(
+    ///     MessagePriority::Normal.inherit(MessagePriority::High, cap),
+    ///     MessagePriority::High
+    /// );
+    /// assert_eq!(
+    ///     MessagePriority::Normal.inherit(MessagePriority::Critical, cap),
+    ///     MessagePriority::High
+    /// );
+    /// assert_eq!(
+    ///     MessagePriority::Low.inherit(MessagePriority::Normal, cap),
+    ///     MessagePriority::Low
+    /// );
+    /// ```
+    pub fn inherit(self, parent: MessagePriority, cap: MessagePriority) -> MessagePriority {
+        if parent > MessagePriority::Normal {
+            self.max(parent.min(cap))
+        } else {
+            self
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -251,6 +291,32 @@ mod tests {
         assert_eq!(msg.priority(), MessagePriority::High);
     }
 
+    #[test]
+    fn test_priority_inheritance_is_capped() {
+        let cap = MessagePriority::High;
+        assert_eq!(
+            MessagePriority::Low.inherit(MessagePriority::High, cap),
+            MessagePriority::High
+        );
+        assert_eq!(
+            MessagePriority::Low.inherit(MessagePriority::Critical, cap),
+            MessagePriority::High
+        );
+        // A message's own priority is never lowered by the chain
+        assert_eq!(
+            MessagePriority::Critical.inherit(MessagePriority::High, cap),
+            MessagePriority::Critical
+        );
+        assert_eq!(
+            MessagePriority::Normal.inherit(MessagePriority::Low, cap),
+            MessagePriority::Normal
+        );
+        assert_eq!(
+            MessagePriority::Normal.inherit(MessagePriority::High, MessagePriority::Normal),
+            MessagePriority::Normal
+        );
+    }
+
     #[test]
     fn test_priority_ordering() {
         assert!(MessagePriority::Critical > MessagePriority::High);
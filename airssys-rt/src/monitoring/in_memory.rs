@@ -8,6 +8,7 @@ use async_trait::async_trait;
 use chrono::Utc;
 
 use super::error::MonitoringError;
+use super::subscription::{EventSubscription, SubscriberList, SubscriptionConfig};
 use super::traits::{EventSeverity, Monitor, MonitoringEvent};
 use super::types::{MonitoringConfig, MonitoringSnapshot};
 
@@ -22,6 +23,7 @@ use super::types::{MonitoringConfig, MonitoringSnapshot};
 /// - Atomic counters for lock-free event counting
 /// - RwLock for ring buffer (read-heavy optimization)
 /// - Bounded memory with configurable history size
+/// - Live subscribers fed from `record` (see [`Monitor::subscribe`])
 ///
 /// # Examples
 ///
@@ -70,6 +72,9 @@ struct InMemoryMonitorInner<E: MonitoringEvent> {
 
     // Ring buffer for event history (read-heavy optimization with RwLock)
     history: RwLock<VecDeque<E>>,
+
+    // Live event subscribers
+    subscribers: SubscriberList<E>,
 }
 
 impl<E: MonitoringEvent> InMemoryMonitor<E> {
@@ -95,10 +100,16 @@ impl<E: MonitoringEvent> InMemoryMonitor<E> {
                 error_count: AtomicU64::new(0),
                 critical_count: AtomicU64::new(0),
                 history: RwLock::new(VecDeque::new()),
+                subscribers: SubscriberList::new(),
             }),
         }
     }
 
+    /// Returns the number of live subscriptions.
+    pub fn subscriber_count(&self) -> usize {
+        self.inner.subscribers.len()
+    }
+
     /// Increments the appropriate severity counter atomically.
     fn increment_severity_counter(&self, severity: EventSeverity) {
         match severity {
@@ -131,6 +142,9 @@ impl<E: MonitoringEvent> Monitor<E> for InMemoryMonitor<E> {
 
         let severity = event.severity();
 
+        // Subscribers apply their own severity filters
+        self.inner.subscribers.publish(&event);
+
         // Early return if below severity threshold
         if severity < self.inner.config.severity_filter {
             return Ok(());
@@ -209,6 +223,10 @@ impl<E: MonitoringEvent> Monitor<E> for InMemoryMonitor<E> {
 
         Ok(())
     }
+
+    fn subscribe(&self, config: SubscriptionConfig) -> EventSubscription<E> {
+        self.inner.subscribers.subscribe(config)
+    }
 }
 
 #[cfg(test)]
@@ -418,6 +436,48 @@ mod tests {
         assert_eq!(snapshot.error_count, 1);
     }
 
+    #[tokio::test]
+    #[allow(clippy::expect_used)]
+    async fn test_subscribe_streams_recorded_events() {
+        use futures::StreamExt;
+
+        let monitor = InMemoryMonitor::new(MonitoringConfig::default());
+        let mut all = monitor.subscribe(SubscriptionConfig {
+            severity_filter: EventSeverity::Trace,
+            ..SubscriptionConfig::default()
+        });
+        let mut errors = monitor.clone().subscribe(SubscriptionConfig {
+            severity_filter: EventSeverity::Error,
+            ..SubscriptionConfig::default()
+        });
+        assert_eq!(monitor.subscriber_count(), 2);
+
+        // Spawned is Debug: below the monitor filter but still streamed
+        monitor
+            .record(create_test_event_with_kind(ActorEventKind::Spawned))
+            .await
+            .expect("Record should succeed");
+        monitor
+            .record(create_test_event_with_kind(ActorEventKind::ErrorOccurred {
+                error: "test".to_string(),
+            }))
+            .await
+            .expect("Record should succeed");
+
+        let first = all.next().await.expect("Event should be streamed");
+        assert!(matches!(first.event_kind, ActorEventKind::Spawned));
+        assert!(all.next().await.is_some());
+
+        let error = errors.next().await.expect("Error should be streamed");
+        assert!(matches!(
+            error.event_kind,
+            ActorEventKind::ErrorOccurred { .. }
+        ));
+
+        drop(monitor);
+        assert!(errors.next().await.is_none());
+    }
+
     #[tokio::test]
     #[allow(clippy::expect_used)]
     async fn test_concurrent_recording() {
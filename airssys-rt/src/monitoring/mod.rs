@@ -158,12 +158,14 @@
 pub mod error;
 pub mod in_memory;
 pub mod noop;
+pub mod subscription;
 pub mod traits;
 pub mod types;
 
 pub use error::MonitoringError;
 pub use in_memory::InMemoryMonitor;
 pub use noop::NoopMonitor;
+pub use subscription::{EventSubscription, SubscriptionConfig};
 pub use traits::{EventSeverity, Monitor, MonitoringEvent};
 pub use types::{
     ActorEvent, ActorEventKind, BrokerEvent, BrokerEventKind, MailboxEvent, MailboxEventKind,
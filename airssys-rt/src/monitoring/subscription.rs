@@ -0,0 +1,255 @@
+//! Push-based event subscriptions for monitors.
+//!
+//! [`Monitor::subscribe`](super::traits::Monitor::subscribe) hands out an
+//! [`EventSubscription`], an async stream fed by the monitor as events are
+//! recorded. Each subscriber has its own severity filter and a bounded
+//! buffer; when a slow subscriber's buffer is full, new events are dropped
+//! for that subscriber only and counted in [`EventSubscription::dropped`].
+
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::Stream;
+use parking_lot::Mutex;
+use serde::Serialize;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
+
+use super::traits::{EventSeverity, MonitoringEvent};
+
+/// Configuration for a monitor subscription.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct SubscriptionConfig {
+    /// Minimum severity level delivered to the subscriber
+    pub severity_filter: EventSeverity,
+
+    /// Number of undelivered events buffered before new ones are dropped
+    pub buffer_size: usize,
+}
+
+impl Default for SubscriptionConfig {
+    fn default() -> Self {
+        Self {
+            severity_filter: EventSeverity::Info,
+            buffer_size: 256,
+        }
+    }
+}
+
+/// Async stream of events pushed by a monitor.
+///
+/// The stream ends when the monitor (and all its clones) is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use airssys_rt::monitoring::{
+///     ActorEvent, ActorEventKind, EventSeverity, InMemoryMonitor, Monitor, MonitoringConfig,
+///     SubscriptionConfig,
+/// };
+/// use chrono::Utc;
+/// use std::collections::HashMap;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let monitor = InMemoryMonitor::<ActorEvent>::new(MonitoringConfig::default());
+/// let mut events = monitor.subscribe(SubscriptionConfig {
+///     severity_filter: EventSeverity::Error,
+///     ..SubscriptionConfig::default()
+/// });
+///
+/// monitor
+///     .record(ActorEvent {
+///         timestamp: Utc::now(),
+///         actor_id: airssys_rt::util::ActorId::new(),
+///         event_kind: ActorEventKind::ErrorOccurred { error: "boom".into() },
+///         metadata: HashMap::new(),
+///     })
+///     .await?;
+///
+/// let event = events.recv().await;
+/// assert!(event.is_some());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct EventSubscription<E: MonitoringEvent> {
+    receiver: mpsc::Receiver<E>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl<E: MonitoringEvent> EventSubscription<E> {
+    /// Creates a subscription that never yields events.
+    ///
+    /// Returned by monitors that do not support streaming.
+    pub fn closed() -> Self {
+        let (_, receiver) = mpsc::channel(1);
+        Self {
+            receiver,
+            dropped: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Receives the next event, or `None` once the monitor is gone.
+    pub async fn recv(&mut self) -> Option<E> {
+        self.receiver.recv().await
+    }
+
+    /// Receives a buffered event without waiting.
+    pub fn try_recv(&mut self) -> Option<E> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// Number of events dropped because the buffer was full.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl<E: MonitoringEvent> Stream for EventSubscription<E> {
+    type Item = E;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<E>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+/// One registered subscriber.
+#[derive(Debug)]
+struct Subscriber<E> {
+    sender: mpsc::Sender<E>,
+    severity_filter: EventSeverity,
+    dropped: Arc<AtomicU64>,
+}
+
+/// Subscriber registry shared by a monitor and its clones.
+#[derive(Debug)]
+pub(crate) struct SubscriberList<E: MonitoringEvent> {
+    subscribers: Mutex<Vec<Subscriber<E>>>,
+}
+
+impl<E: MonitoringEvent> SubscriberList<E> {
+    pub(crate) fn new() -> Self {
+        Self {
+            subscribers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Registers a subscriber and returns its stream.
+    pub(crate) fn subscribe(&self, config: SubscriptionConfig) -> EventSubscription<E> {
+        let (sender, receiver) = mpsc::channel(config.buffer_size.max(1));
+        let dropped = Arc::new(AtomicU64::new(0));
+        self.subscribers.lock().push(Subscriber {
+            sender,
+            severity_filter: config.severity_filter,
+            dropped: Arc::clone(&dropped),
+        });
+        EventSubscription { receiver, dropped }
+    }
+
+    /// Pushes `event` to every matching subscriber without blocking.
+    ///
+    /// Subscribers whose stream has been dropped are removed.
+    pub(crate) fn publish(&self, event: &E) {
+        let severity = event.severity();
+        let mut subscribers = self.subscribers.lock();
+        subscribers.retain(|subscriber| {
+            if severity < subscriber.severity_filter {
+                return !subscriber.sender.is_closed();
+            }
+            match subscriber.sender.try_send(event.clone()) {
+                Ok(()) => true,
+                Err(TrySendError::Full(_)) => {
+                    subscriber.dropped.fetch_add(1, Ordering::Relaxed);
+                    true
+                }
+                Err(TrySendError::Closed(_)) => false,
+            }
+        });
+    }
+
+    /// Number of live subscribers.
+    pub(crate) fn len(&self) -> usize {
+        let mut subscribers = self.subscribers.lock();
+        subscribers.retain(|subscriber| !subscriber.sender.is_closed());
+        subscribers.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::Utc;
+
+    use super::*;
+    use crate::monitoring::types::{ActorEvent, ActorEventKind};
+    use crate::util::ActorId;
+
+    fn event(kind: ActorEventKind) -> ActorEvent {
+        ActorEvent {
+            timestamp: Utc::now(),
+            actor_id: ActorId::new(),
+            event_kind: kind,
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_publish_respects_severity_filter() {
+        let list = SubscriberList::new();
+        let mut errors = list.subscribe(SubscriptionConfig {
+            severity_filter: EventSeverity::Error,
+            ..SubscriptionConfig::default()
+        });
+
+        list.publish(&event(ActorEventKind::Started));
+        list.publish(&event(ActorEventKind::ErrorOccurred {
+            error: "boom".to_string(),
+        }));
+
+        let received = errors.try_recv();
+        assert!(matches!(
+            received.map(|e| e.event_kind),
+            Some(ActorEventKind::ErrorOccurred { .. })
+        ));
+        assert!(errors.try_recv().is_none());
+    }
+
+    #[test]
+    fn test_full_buffer_drops_and_counts() {
+        let list = SubscriberList::new();
+        let mut sub = list.subscribe(SubscriptionConfig {
+            severity_filter: EventSeverity::Trace,
+            buffer_size: 2,
+        });
+
+        for _ in 0..5 {
+            list.publish(&event(ActorEventKind::Started));
+        }
+
+        assert_eq!(sub.dropped(), 3);
+        assert!(sub.try_recv().is_some());
+        assert!(sub.try_recv().is_some());
+        assert!(sub.try_recv().is_none());
+    }
+
+    #[test]
+    fn test_dropped_subscription_is_removed() {
+        let list = SubscriberList::<ActorEvent>::new();
+        let sub = list.subscribe(SubscriptionConfig::default());
+        assert_eq!(list.len(), 1);
+
+        drop(sub);
+        list.publish(&event(ActorEventKind::Started));
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn test_closed_subscription_yields_nothing() {
+        let mut sub = EventSubscription::<ActorEvent>::closed();
+        assert!(sub.try_recv().is_none());
+        assert_eq!(sub.dropped(), 0);
+    }
+}
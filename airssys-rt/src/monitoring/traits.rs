@@ -7,6 +7,7 @@ use chrono::{DateTime, Utc};
 use serde::Serialize;
 
 use super::error::MonitoringError;
+use super::subscription::{EventSubscription, SubscriptionConfig};
 use super::types::MonitoringSnapshot;
 
 /// Event severity levels for filtering and categorization.
@@ -129,6 +130,20 @@ pub trait Monitor<E: MonitoringEvent>: Send + Sync + Clone {
     /// # Errors
     /// Returns `MonitoringError::ResetError` if the reset operation fails.
     async fn reset(&self) -> Result<(), MonitoringError>;
+
+    /// Subscribes to events as they are recorded.
+    ///
+    /// Returns an async stream of events at or above
+    /// `config.severity_filter`. A subscriber that falls behind by more than
+    /// `config.buffer_size` events misses the overflow rather than slowing
+    /// down `record`.
+    ///
+    /// The default implementation returns a subscription that never yields,
+    /// for monitors that keep no live event feed.
+    fn subscribe(&self, config: SubscriptionConfig) -> EventSubscription<E> {
+        let _ = config;
+        EventSubscription::closed()
+    }
 }
 
 #[cfg(test)]
@@ -110,6 +110,9 @@ impl MonitoringEvent for SupervisionEvent {
             SupervisionEventKind::ChildRestarted { .. } => EventSeverity::Warning,
             SupervisionEventKind::RestartLimitExceeded { .. } => EventSeverity::Critical,
             SupervisionEventKind::StrategyApplied { .. } => EventSeverity::Info,
+            SupervisionEventKind::SupervisorPaused { .. } => EventSeverity::Warning,
+            SupervisionEventKind::RestartDeferred => EventSeverity::Warning,
+            SupervisionEventKind::SupervisorResumed { .. } => EventSeverity::Info,
         }
     }
 }
@@ -154,6 +157,24 @@ pub enum SupervisionEventKind {
         /// Number of children affected
         affected_count: usize,
     },
+
+    /// Supervisor entered a maintenance window; restarts are deferred
+    SupervisorPaused {
+        /// Operator-supplied reason for the pause
+        reason: String,
+    },
+
+    /// Child restart was queued because the supervisor is paused
+    RestartDeferred,
+
+    /// Supervisor left its maintenance window
+    SupervisorResumed {
+        /// Number of deferred restarts now being performed
+        deferred_restarts: usize,
+        /// How long the supervisor was paused
+        #[serde(with = "crate::util::duration_serde")]
+        paused_for: Duration,
+    },
 }
 
 // ============================================================================
@@ -101,7 +101,10 @@
 //! ```
 
 // Core actor system
-pub use crate::actor::{Actor, ActorContext, ActorLifecycle, ActorState, ErrorAction};
+pub use crate::actor::{
+    Actor, ActorContext, ActorLifecycle, ActorState, ErrorAction, RetryError, RetryPolicy, Service,
+    ServiceKey, StashConfig, StashOverflow,
+};
 
 // Messaging
 pub use crate::message::{Message, MessageEnvelope, MessagePriority};
@@ -0,0 +1,309 @@
+//! Restart cause analytics.
+//!
+//! Restart counts say how often a child restarts; this module records why.
+//! Every failure that leads a supervisor to restart a child is captured as a
+//! [`RestartCause`] (error classification, the message type the child was
+//! processing, and how long it had been up) and folded into per-child
+//! [`ChildFailureStats`].
+//!
+//! Causes are also attached to the `ChildFailed` supervision event as
+//! metadata (see [`RestartCause::to_metadata`]), so any `Monitor` receives
+//! them.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use airssys_rt::supervisor::{ChildFailureStats, FailureClass, RestartCause};
+//! use std::time::Duration;
+//!
+//! let mut stats = ChildFailureStats::default();
+//! stats.record(RestartCause::new(
+//!     FailureClass::Timeout,
+//!     "request timed out",
+//!     Some("fetch".to_string()),
+//!     Duration::from_secs(30),
+//! ));
+//! stats.record(RestartCause::new(
+//!     FailureClass::Timeout,
+//!     "request timed out",
+//!     Some("fetch".to_string()),
+//!     Duration::from_secs(10),
+//! ));
+//!
+//! assert_eq!(stats.failures, 2);
+//! assert_eq!(stats.dominant_class(), Some(FailureClass::Timeout));
+//! assert_eq!(stats.mean_uptime(), Some(Duration::from_secs(20)));
+//! ```
+
+// Layer 1: Standard library imports
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error as StdError;
+use std::fmt;
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+// Layer 3: Internal module imports
+use super::error::SupervisorError;
+
+/// Metadata key holding [`RestartCause::class`].
+pub const METADATA_FAILURE_CLASS: &str = "failure_class";
+
+/// Metadata key holding [`RestartCause::message_type`].
+pub const METADATA_MESSAGE_TYPE: &str = "message_type";
+
+/// Metadata key holding [`RestartCause::uptime`] in milliseconds.
+pub const METADATA_UPTIME_MS: &str = "uptime_ms";
+
+/// Coarse classification of a child failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureClass {
+    /// The child returned an error.
+    Error,
+    /// The child panicked or crashed (e.g. a trap).
+    Crash,
+    /// An operation did not complete in time.
+    Timeout,
+    /// The child ran out of a resource (memory, fuel, quota).
+    ResourceExhausted,
+    /// The child failed to (re)start.
+    StartFailure,
+    /// Health checks failed past the configured threshold.
+    HealthCheck,
+}
+
+impl FailureClass {
+    /// Classifies an error reported by a child.
+    ///
+    /// Supervisor errors are classified by variant; other errors by their
+    /// message.
+    pub fn classify(error: &(dyn StdError + 'static)) -> Self {
+        if let Some(error) = error.downcast_ref::<SupervisorError>() {
+            return match error {
+                SupervisorError::ChildStartFailed { .. }
+                | SupervisorError::ChildFactoryFailed { .. } => FailureClass::StartFailure,
+                SupervisorError::ShutdownTimeout { .. } => FailureClass::Timeout,
+                _ => FailureClass::Error,
+            };
+        }
+
+        let message = error.to_string().to_lowercase();
+        if message.contains("panic") || message.contains("trap") {
+            FailureClass::Crash
+        } else if message.contains("timeout") || message.contains("timed out") {
+            FailureClass::Timeout
+        } else if message.contains("limit exceeded") || message.contains("out of memory") {
+            FailureClass::ResourceExhausted
+        } else {
+            FailureClass::Error
+        }
+    }
+
+    /// Returns the classification name used in event metadata.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FailureClass::Error => "error",
+            FailureClass::Crash => "crash",
+            FailureClass::Timeout => "timeout",
+            FailureClass::ResourceExhausted => "resource_exhausted",
+            FailureClass::StartFailure => "start_failure",
+            FailureClass::HealthCheck => "health_check",
+        }
+    }
+}
+
+impl fmt::Display for FailureClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Why a child failed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RestartCause {
+    /// When the failure was observed.
+    pub timestamp: DateTime<Utc>,
+    /// Failure classification.
+    pub class: FailureClass,
+    /// Error message.
+    pub error: String,
+    /// Type of the message being processed when the child failed, if known.
+    pub message_type: Option<String>,
+    /// Time the child had been running since its last (re)start.
+    pub uptime: Duration,
+}
+
+impl RestartCause {
+    /// Creates a cause observed now.
+    pub fn new(
+        class: FailureClass,
+        error: impl Into<String>,
+        message_type: Option<String>,
+        uptime: Duration,
+    ) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            class,
+            error: error.into(),
+            message_type,
+            uptime,
+        }
+    }
+
+    /// Encodes the cause as supervision event metadata.
+    pub fn to_metadata(&self) -> HashMap<String, String> {
+        let mut metadata = HashMap::from([
+            (METADATA_FAILURE_CLASS.to_string(), self.class.to_string()),
+            (
+                METADATA_UPTIME_MS.to_string(),
+                self.uptime.as_millis().to_string(),
+            ),
+        ]);
+        if let Some(message_type) = &self.message_type {
+            metadata.insert(METADATA_MESSAGE_TYPE.to_string(), message_type.clone());
+        }
+        metadata
+    }
+}
+
+/// Aggregated failure statistics of one child.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ChildFailureStats {
+    /// Failures recorded.
+    pub failures: u64,
+    /// Successful restarts.
+    pub restarts: u64,
+    /// Failures per classification.
+    pub by_class: BTreeMap<FailureClass, u64>,
+    /// Failures per message type being processed.
+    pub by_message_type: BTreeMap<String, u64>,
+    /// Sum of the uptimes before each failure.
+    pub total_uptime: Duration,
+    /// Shortest uptime before a failure.
+    pub shortest_uptime: Option<Duration>,
+    /// Most recent failure.
+    pub last_cause: Option<RestartCause>,
+}
+
+impl ChildFailureStats {
+    /// Folds `cause` into the statistics.
+    pub fn record(&mut self, cause: RestartCause) {
+        self.failures += 1;
+        *self.by_class.entry(cause.class).or_default() += 1;
+        if let Some(message_type) = &cause.message_type {
+            *self
+                .by_message_type
+                .entry(message_type.clone())
+                .or_default() += 1;
+        }
+        self.total_uptime += cause.uptime;
+        self.shortest_uptime = Some(
+            self.shortest_uptime
+                .map_or(cause.uptime, |shortest| shortest.min(cause.uptime)),
+        );
+        self.last_cause = Some(cause);
+    }
+
+    /// Counts a successful restart.
+    pub fn record_restart(&mut self) {
+        self.restarts += 1;
+    }
+
+    /// Mean uptime before a failure.
+    pub fn mean_uptime(&self) -> Option<Duration> {
+        let failures = u32::try_from(self.failures).ok().filter(|&n| n > 0)?;
+        Some(self.total_uptime / failures)
+    }
+
+    /// The most frequent failure classification.
+    pub fn dominant_class(&self) -> Option<FailureClass> {
+        self.by_class
+            .iter()
+            .max_by_key(|(_, count)| **count)
+            .map(|(class, _)| *class)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_errors() {
+        let start = SupervisorError::ChildStartFailed {
+            id: "worker".to_string(),
+            source: Box::new(std::io::Error::other("boom")),
+        };
+        assert_eq!(FailureClass::classify(&start), FailureClass::StartFailure);
+
+        let io = |msg: &str| std::io::Error::other(msg.to_string());
+        assert_eq!(
+            FailureClass::classify(&io("wasm trap: unreachable")),
+            FailureClass::Crash
+        );
+        assert_eq!(
+            FailureClass::classify(&io("Execution timeout")),
+            FailureClass::Timeout
+        );
+        assert_eq!(
+            FailureClass::classify(&io("Resource limit exceeded: memory")),
+            FailureClass::ResourceExhausted
+        );
+        assert_eq!(
+            FailureClass::classify(&io("bad input")),
+            FailureClass::Error
+        );
+    }
+
+    #[test]
+    fn test_stats_aggregate_causes() {
+        let mut stats = ChildFailureStats::default();
+        assert_eq!(stats.mean_uptime(), None);
+
+        stats.record(RestartCause::new(
+            FailureClass::Crash,
+            "trap",
+            Some("resize".to_string()),
+            Duration::from_millis(400),
+        ));
+        stats.record(RestartCause::new(
+            FailureClass::Crash,
+            "trap",
+            Some("resize".to_string()),
+            Duration::from_millis(200),
+        ));
+        stats.record(RestartCause::new(
+            FailureClass::Error,
+            "bad input",
+            None,
+            Duration::from_millis(600),
+        ));
+        stats.record_restart();
+
+        assert_eq!(stats.failures, 3);
+        assert_eq!(stats.restarts, 1);
+        assert_eq!(stats.by_message_type["resize"], 2);
+        assert_eq!(stats.dominant_class(), Some(FailureClass::Crash));
+        assert_eq!(stats.shortest_uptime, Some(Duration::from_millis(200)));
+        assert_eq!(stats.mean_uptime(), Some(Duration::from_millis(400)));
+        assert_eq!(stats.last_cause.unwrap().error, "bad input");
+    }
+
+    #[test]
+    fn test_cause_metadata() {
+        let cause = RestartCause::new(
+            FailureClass::HealthCheck,
+            "unhealthy",
+            Some("ping".to_string()),
+            Duration::from_millis(1500),
+        );
+        let metadata = cause.to_metadata();
+        assert_eq!(metadata[METADATA_FAILURE_CLASS], "health_check");
+        assert_eq!(metadata[METADATA_MESSAGE_TYPE], "ping");
+        assert_eq!(metadata[METADATA_UPTIME_MS], "1500");
+    }
+}
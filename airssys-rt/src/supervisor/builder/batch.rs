@@ -5,6 +5,7 @@
 //! per-child customization.
 
 use std::collections::HashMap;
+use std::future::Future;
 use std::time::Duration;
 
 use crate::monitoring::traits::Monitor;
@@ -14,16 +15,16 @@ use crate::supervisor::builder::constants::{
     DEFAULT_START_TIMEOUT,
 };
 use crate::supervisor::builder::customizer::BatchChildCustomizer;
+use crate::supervisor::builder::factory::{ChildFactory, SpawnSettings};
 use crate::supervisor::node::SupervisorNode;
-use crate::supervisor::traits::{Child, SupervisionStrategy, Supervisor};
+use crate::supervisor::traits::{Child, SupervisionStrategy};
 use crate::supervisor::types::{ChildId, RestartPolicy, ShutdownPolicy};
-use crate::supervisor::ChildSpec;
 use crate::supervisor::SupervisorError;
 
 /// Internal struct representing a child spec with possible overrides
 pub(super) struct BatchChildSpec<C> {
     pub(super) id: String,
-    pub(super) factory: Box<dyn Fn() -> C + Send + Sync + 'static>,
+    pub(super) factory: ChildFactory<C>,
     pub(super) restart_policy: Option<RestartPolicy>,
     pub(super) shutdown_policy: Option<ShutdownPolicy>,
     pub(super) start_timeout: Option<Duration>,
@@ -31,7 +32,7 @@ pub(super) struct BatchChildSpec<C> {
 }
 
 impl<C> BatchChildSpec<C> {
-    fn new(id: String, factory: Box<dyn Fn() -> C + Send + Sync + 'static>) -> Self {
+    fn new(id: String, factory: ChildFactory<C>) -> Self {
         Self {
             id,
             factory,
@@ -96,7 +97,17 @@ where
     where
         F: Fn() -> C + Send + Sync + 'static,
     {
-        let spec = BatchChildSpec::new(id.into(), Box::new(factory));
+        let spec = BatchChildSpec::new(id.into(), ChildFactory::from_sync(factory));
+        self.children.push(spec);
+        self
+    }
+    /// Add a child built by an async factory, with shared defaults
+    pub fn child_async<F, Fut>(mut self, id: impl Into<String>, factory: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = C> + Send + 'static,
+    {
+        let spec = BatchChildSpec::new(id.into(), ChildFactory::from_async(factory));
         self.children.push(spec);
         self
     }
@@ -109,7 +120,7 @@ where
     where
         F: Fn() -> C + Send + Sync + 'static,
     {
-        let spec = BatchChildSpec::new(id.into(), Box::new(factory));
+        let spec = BatchChildSpec::new(id.into(), ChildFactory::from_sync(factory));
         self.children.push(spec);
         let child_index = self.children.len() - 1;
         BatchChildCustomizer::new(self, child_index)
@@ -118,9 +129,8 @@ where
     pub async fn spawn_all(self) -> Result<Vec<ChildId>, SupervisorError> {
         let mut ids = Vec::new();
         for child in self.children {
-            let spec = ChildSpec {
+            let settings = SpawnSettings {
                 id: child.id,
-                factory: child.factory,
                 restart_policy: child.restart_policy.unwrap_or(self.shared_restart_policy),
                 shutdown_policy: child.shutdown_policy.unwrap_or(self.shared_shutdown_policy),
                 start_timeout: child.start_timeout.unwrap_or(self.shared_start_timeout),
@@ -128,7 +138,7 @@ where
                     .shutdown_timeout
                     .unwrap_or(self.shared_shutdown_timeout),
             };
-            let id = self.supervisor.start_child(spec).await?;
+            let id = child.factory.spawn(self.supervisor, settings).await?;
             ids.push(id);
         }
         Ok(ids)
@@ -139,9 +149,8 @@ where
         let mut map = HashMap::new();
         for child in self.children {
             let id = child.id.clone();
-            let spec = ChildSpec {
+            let settings = SpawnSettings {
                 id: child.id,
-                factory: child.factory,
                 restart_policy: child.restart_policy.unwrap_or(self.shared_restart_policy),
                 shutdown_policy: child.shutdown_policy.unwrap_or(self.shared_shutdown_policy),
                 start_timeout: child.start_timeout.unwrap_or(self.shared_start_timeout),
@@ -149,7 +158,7 @@ where
                     .shutdown_timeout
                     .unwrap_or(self.shared_shutdown_timeout),
             };
-            let child_id = self.supervisor.start_child(spec).await?;
+            let child_id = child.factory.spawn(self.supervisor, settings).await?;
             map.insert(id, child_id);
         }
         Ok(map)
@@ -401,4 +410,22 @@ mod tests {
         assert!(map.contains_key("custom"));
         assert!(map.contains_key("default-2"));
     }
+
+    #[tokio::test]
+    async fn test_batch_child_async() {
+        let mut supervisor: SupervisorNode<OneForOne, TestChild, NoopMonitor<SupervisionEvent>> =
+            SupervisorNode::new(OneForOne, NoopMonitor::new());
+
+        let map = supervisor
+            .children()
+            .child("sync", || TestChild::new("sync"))
+            .child_async("async", || async { TestChild::new("async") })
+            .spawn_all_map()
+            .await
+            .unwrap();
+
+        assert_eq!(map.len(), 2);
+        assert!(map.contains_key("async"));
+        assert_eq!(supervisor.child_count(), 2);
+    }
 }
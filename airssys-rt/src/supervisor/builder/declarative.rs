@@ -0,0 +1,826 @@
+//! Declarative supervisor trees built from configuration.
+//!
+//! A [`SupervisorDescription`] describes a supervision topology as data:
+//! each supervisor's strategy, optional health checks, its children and its
+//! nested supervisors. Children name a factory instead of holding one; the
+//! factories are registered in code on a [`TreeBuilder`], which validates the
+//! description and starts the tree.
+//!
+//! The description implements `serde::Deserialize`, so it can be loaded from
+//! any serde format. [`SupervisorDescription::from_toml_str`] covers TOML:
+//!
+//! ```toml
+//! name = "app"
+//! strategy = "one_for_one"
+//!
+//! [[children]]
+//! name = "db"
+//! factory = "db_pool"
+//! restart = "Permanent"
+//! shutdown_timeout = 10
+//!
+//! [[supervisors]]
+//! name = "api"
+//! strategy = "rest_for_one"
+//! health = { check_interval = 30, check_timeout = 5, failure_threshold = 3 }
+//!
+//! [[supervisors.children]]
+//! name = "http"
+//! factory = "http_server"
+//! ```
+//!
+//! Durations are given in seconds. Supervisors and children are addressed by
+//! their path from the root, e.g. `app/api/http`.
+//!
+//! # Strategies
+//!
+//! Strategies are a type parameter of [`SupervisorNode`], so a tree mixing
+//! strategies holds each supervisor as a [`DeclaredSupervisor`], an enum with
+//! one variant per strategy (§6.2 - no trait objects).
+
+// Layer 1: Standard library imports
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use serde::{Deserialize, Serialize};
+
+// Layer 3: Internal module imports
+use super::constants::{
+    DEFAULT_RESTART_POLICY, DEFAULT_SHUTDOWN_POLICY, DEFAULT_SHUTDOWN_TIMEOUT,
+    DEFAULT_START_TIMEOUT,
+};
+use super::factory::{ChildFactory, ChildFuture, SpawnSettings};
+use crate::monitoring::{Monitor, SupervisionEvent};
+use crate::supervisor::traits::Supervisor;
+use crate::supervisor::{
+    Child, ChildId, HealthConfig, OneForAll, OneForOne, RestForOne, RestartPolicy, ShutdownPolicy,
+    SupervisorError, SupervisorNode,
+};
+use crate::util::{duration_serde, optional_duration_serde};
+
+/// Separator between names in a supervisor or child path.
+pub const PATH_SEPARATOR: char = '/';
+
+/// Supervision strategy named in a description.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StrategyKind {
+    /// [`OneForOne`]
+    OneForOne,
+    /// [`OneForAll`]
+    OneForAll,
+    /// [`RestForOne`]
+    RestForOne,
+}
+
+/// Health check settings of a described supervisor.
+///
+/// See [`SupervisorNode::enable_health_checks`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HealthDescription {
+    /// How often to check child health, in seconds
+    #[serde(with = "duration_serde")]
+    pub check_interval: Duration,
+
+    /// Timeout for each check, in seconds
+    #[serde(with = "duration_serde")]
+    pub check_timeout: Duration,
+
+    /// Consecutive failures before a child is restarted
+    pub failure_threshold: u32,
+}
+
+/// A supervised child in a description.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChildDescription {
+    /// Child name, unique among its siblings
+    pub name: String,
+
+    /// Name of the factory registered on the [`TreeBuilder`]
+    pub factory: String,
+
+    /// Restart policy (defaults to [`DEFAULT_RESTART_POLICY`])
+    #[serde(default = "default_restart_policy")]
+    pub restart: RestartPolicy,
+
+    /// Graceful shutdown timeout in seconds; absent uses
+    /// [`DEFAULT_SHUTDOWN_POLICY`]
+    #[serde(default, with = "optional_duration_serde")]
+    pub shutdown_timeout: Option<Duration>,
+
+    /// Startup timeout in seconds; absent uses [`DEFAULT_START_TIMEOUT`]
+    #[serde(default, with = "optional_duration_serde")]
+    pub start_timeout: Option<Duration>,
+}
+
+fn default_restart_policy() -> RestartPolicy {
+    DEFAULT_RESTART_POLICY
+}
+
+/// A supervisor and everything below it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SupervisorDescription {
+    /// Supervisor name, unique among its siblings
+    pub name: String,
+
+    /// Strategy applied to this supervisor's children
+    pub strategy: StrategyKind,
+
+    /// Health checks; disabled when absent
+    #[serde(default)]
+    pub health: Option<HealthDescription>,
+
+    /// Children, started in order
+    #[serde(default)]
+    pub children: Vec<ChildDescription>,
+
+    /// Nested supervisors, started after this supervisor's children
+    #[serde(default)]
+    pub supervisors: Vec<SupervisorDescription>,
+}
+
+impl SupervisorDescription {
+    /// Parses a description from TOML.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SupervisorError::InvalidConfiguration` if the document does
+    /// not describe a supervisor.
+    pub fn from_toml_str(source: &str) -> Result<Self, SupervisorError> {
+        toml::from_str(source).map_err(|e| SupervisorError::InvalidConfiguration {
+            reason: format!("invalid supervisor tree description: {e}"),
+        })
+    }
+
+    /// Checks names and health settings throughout the tree.
+    ///
+    /// Factories are checked by [`TreeBuilder::build`], which knows which
+    /// ones are registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SupervisorError::InvalidConfiguration` for empty names,
+    /// names containing [`PATH_SEPARATOR`], duplicate sibling names, or a
+    /// zero health failure threshold.
+    pub fn validate(&self) -> Result<(), SupervisorError> {
+        self.walk(&mut |_, _| Ok(()))
+    }
+
+    /// Visits every child with its path, after validating each supervisor.
+    fn walk<F>(&self, visit: &mut F) -> Result<(), SupervisorError>
+    where
+        F: FnMut(&str, &ChildDescription) -> Result<(), SupervisorError>,
+    {
+        self.walk_at(&check_name(&self.name, None)?, visit)
+    }
+
+    fn walk_at<F>(&self, path: &str, visit: &mut F) -> Result<(), SupervisorError>
+    where
+        F: FnMut(&str, &ChildDescription) -> Result<(), SupervisorError>,
+    {
+        if self
+            .health
+            .as_ref()
+            .is_some_and(|h| h.failure_threshold == 0)
+        {
+            return Err(invalid(format!(
+                "{path}: health failure_threshold must be at least 1"
+            )));
+        }
+
+        let mut siblings = HashSet::new();
+        let names = self
+            .children
+            .iter()
+            .map(|c| &c.name)
+            .chain(self.supervisors.iter().map(|s| &s.name));
+        for name in names {
+            if !siblings.insert(name) {
+                return Err(invalid(format!("{path}: duplicate name '{name}'")));
+            }
+        }
+
+        for child in &self.children {
+            visit(&check_name(&child.name, Some(path))?, child)?;
+        }
+        for supervisor in &self.supervisors {
+            supervisor.walk_at(&check_name(&supervisor.name, Some(path))?, visit)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the path of `name` below `parent`, rejecting unusable names.
+fn check_name(name: &str, parent: Option<&str>) -> Result<String, SupervisorError> {
+    let path = match parent {
+        Some(parent) => format!("{parent}{PATH_SEPARATOR}{name}"),
+        None => name.to_string(),
+    };
+    if name.is_empty() || name.contains(PATH_SEPARATOR) {
+        return Err(invalid(format!(
+            "{path}: names must be non-empty and must not contain '{PATH_SEPARATOR}'"
+        )));
+    }
+    Ok(path)
+}
+
+fn invalid(reason: String) -> SupervisorError {
+    SupervisorError::InvalidConfiguration { reason }
+}
+
+/// Factory registered under a name.
+enum NamedFactory<C> {
+    Sync(Arc<dyn Fn() -> C + Send + Sync>),
+    Async(Arc<dyn Fn() -> ChildFuture<C> + Send + Sync>),
+}
+
+impl<C: 'static> NamedFactory<C> {
+    fn to_child_factory(&self) -> ChildFactory<C> {
+        match self {
+            Self::Sync(factory) => {
+                let factory = Arc::clone(factory);
+                ChildFactory::from_sync(move || factory())
+            }
+            Self::Async(factory) => {
+                let factory = Arc::clone(factory);
+                ChildFactory::from_async(move || factory())
+            }
+        }
+    }
+}
+
+/// Registry of named child factories that builds trees from descriptions.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_rt::monitoring::{NoopMonitor, SupervisionEvent};
+/// use airssys_rt::supervisor::builder::declarative::{
+///     StrategyKind, SupervisorDescription, TreeBuilder,
+/// };
+/// use async_trait::async_trait;
+/// use std::time::Duration;
+///
+/// struct Worker;
+///
+/// #[async_trait]
+/// impl airssys_rt::supervisor::Child for Worker {
+///     type Error = std::io::Error;
+///     async fn start(&mut self) -> Result<(), Self::Error> { Ok(()) }
+///     async fn stop(&mut self, _: Duration) -> Result<(), Self::Error> { Ok(()) }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), airssys_rt::supervisor::SupervisorError> {
+/// let description = SupervisorDescription::from_toml_str(r#"
+///     name = "app"
+///     strategy = "one_for_all"
+///
+///     [[children]]
+///     name = "worker"
+///     factory = "worker"
+/// "#)?;
+///
+/// let mut tree = TreeBuilder::new()
+///     .with_factory("worker", || Worker)
+///     .build(&description, NoopMonitor::<SupervisionEvent>::new())
+///     .await?;
+///
+/// let app = tree.supervisor("app").unwrap();
+/// assert_eq!(app.strategy(), StrategyKind::OneForAll);
+/// assert!(tree.child_id("app/worker").is_some());
+/// tree.shutdown().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct TreeBuilder<C> {
+    factories: HashMap<String, NamedFactory<C>>,
+}
+
+impl<C> TreeBuilder<C>
+where
+    C: Child + Send + Sync + 'static,
+{
+    /// Creates a builder with no factories.
+    pub fn new() -> Self {
+        Self {
+            factories: HashMap::new(),
+        }
+    }
+
+    /// Registers a factory under `name`, replacing any previous one.
+    pub fn with_factory<F>(mut self, name: impl Into<String>, factory: F) -> Self
+    where
+        F: Fn() -> C + Send + Sync + 'static,
+    {
+        self.factories
+            .insert(name.into(), NamedFactory::Sync(Arc::new(factory)));
+        self
+    }
+
+    /// Registers an async factory under `name`, replacing any previous one.
+    pub fn with_async_factory<F, Fut>(mut self, name: impl Into<String>, factory: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = C> + Send + 'static,
+    {
+        let factory = move || -> ChildFuture<C> { Box::pin(factory()) };
+        self.factories
+            .insert(name.into(), NamedFactory::Async(Arc::new(factory)));
+        self
+    }
+
+    /// Returns true if a factory is registered under `name`.
+    pub fn has_factory(&self, name: &str) -> bool {
+        self.factories.contains_key(name)
+    }
+
+    /// Validates `description` and starts the tree it describes.
+    ///
+    /// Supervisors are created top-down; each supervisor's children are
+    /// started in order before its nested supervisors. Every supervisor
+    /// gets a clone of `monitor`. If a child fails to start, the children
+    /// already started are stopped (best-effort) and the error is returned.
+    ///
+    /// # Errors
+    ///
+    /// - `SupervisorError::InvalidConfiguration` if the description is
+    ///   invalid or names an unregistered factory
+    /// - Any error from starting a child
+    pub async fn build<M>(
+        &self,
+        description: &SupervisorDescription,
+        monitor: M,
+    ) -> Result<DeclaredTree<C, M>, SupervisorError>
+    where
+        M: Monitor<SupervisionEvent> + Clone + Send + Sync + 'static,
+    {
+        description.walk(&mut |path, child| {
+            if self.has_factory(&child.factory) {
+                Ok(())
+            } else {
+                Err(invalid(format!(
+                    "{path}: no factory registered as '{}'",
+                    child.factory
+                )))
+            }
+        })?;
+
+        let mut tree = DeclaredTree {
+            supervisors: Vec::new(),
+            children: HashMap::new(),
+        };
+        if let Err(e) = self
+            .start(&mut tree, description, description.name.clone(), &monitor)
+            .await
+        {
+            let _ = tree.shutdown().await;
+            return Err(e);
+        }
+        Ok(tree)
+    }
+
+    async fn start<M>(
+        &self,
+        tree: &mut DeclaredTree<C, M>,
+        description: &SupervisorDescription,
+        path: String,
+        monitor: &M,
+    ) -> Result<(), SupervisorError>
+    where
+        M: Monitor<SupervisionEvent> + Clone + Send + Sync + 'static,
+    {
+        let mut supervisor = DeclaredSupervisor::new(description.strategy, monitor.clone());
+        if let Some(health) = &description.health {
+            supervisor.enable_health_checks(health);
+        }
+        tree.supervisors.push((path.clone(), supervisor));
+        let index = tree.supervisors.len() - 1;
+
+        for child in &description.children {
+            let factory = self
+                .factories
+                .get(&child.factory)
+                .ok_or_else(|| invalid(format!("no factory registered as '{}'", child.factory)))?
+                .to_child_factory();
+            let child_path = format!("{path}{PATH_SEPARATOR}{}", child.name);
+            let settings = SpawnSettings {
+                id: child_path.clone(),
+                restart_policy: child.restart,
+                shutdown_policy: child
+                    .shutdown_timeout
+                    .map(ShutdownPolicy::Graceful)
+                    .unwrap_or(DEFAULT_SHUTDOWN_POLICY),
+                start_timeout: child.start_timeout.unwrap_or(DEFAULT_START_TIMEOUT),
+                shutdown_timeout: child.shutdown_timeout.unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT),
+            };
+            let id = tree.supervisors[index].1.spawn(factory, settings).await?;
+            tree.children.insert(child_path, id);
+        }
+
+        for nested in &description.supervisors {
+            let nested_path = format!("{path}{PATH_SEPARATOR}{}", nested.name);
+            Box::pin(self.start(tree, nested, nested_path, monitor)).await?;
+        }
+        Ok(())
+    }
+}
+
+impl<C> Default for TreeBuilder<C>
+where
+    C: Child + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A supervisor of a declared tree, with its strategy chosen at runtime.
+#[derive(Debug)]
+pub enum DeclaredSupervisor<C, M>
+where
+    C: Child,
+    M: Monitor<SupervisionEvent>,
+{
+    /// Supervisor using [`OneForOne`]
+    OneForOne(SupervisorNode<OneForOne, C, M>),
+    /// Supervisor using [`OneForAll`]
+    OneForAll(SupervisorNode<OneForAll, C, M>),
+    /// Supervisor using [`RestForOne`]
+    RestForOne(SupervisorNode<RestForOne, C, M>),
+}
+
+/// Evaluates `$body` with `$node` bound to the inner `SupervisorNode`.
+macro_rules! dispatch {
+    ($supervisor:expr, $node:ident => $body:expr) => {
+        match $supervisor {
+            DeclaredSupervisor::OneForOne($node) => $body,
+            DeclaredSupervisor::OneForAll($node) => $body,
+            DeclaredSupervisor::RestForOne($node) => $body,
+        }
+    };
+}
+
+impl<C, M> DeclaredSupervisor<C, M>
+where
+    C: Child + Send + Sync + 'static,
+    M: Monitor<SupervisionEvent> + Send + Sync + 'static,
+{
+    fn new(strategy: StrategyKind, monitor: M) -> Self {
+        match strategy {
+            StrategyKind::OneForOne => Self::OneForOne(SupervisorNode::new(OneForOne, monitor)),
+            StrategyKind::OneForAll => Self::OneForAll(SupervisorNode::new(OneForAll, monitor)),
+            StrategyKind::RestForOne => Self::RestForOne(SupervisorNode::new(RestForOne, monitor)),
+        }
+    }
+
+    /// Returns the supervisor's strategy.
+    pub fn strategy(&self) -> StrategyKind {
+        match self {
+            Self::OneForOne(_) => StrategyKind::OneForOne,
+            Self::OneForAll(_) => StrategyKind::OneForAll,
+            Self::RestForOne(_) => StrategyKind::RestForOne,
+        }
+    }
+
+    /// Returns the number of children.
+    pub fn child_count(&self) -> usize {
+        dispatch!(self, node => node.child_count())
+    }
+
+    /// Returns the children's IDs in start order.
+    pub fn child_ids(&self) -> &[ChildId] {
+        dispatch!(self, node => node.child_ids())
+    }
+
+    /// Returns the health check configuration, if enabled.
+    pub fn health_config(&self) -> Option<&HealthConfig> {
+        dispatch!(self, node => node.health_config())
+    }
+
+    /// Restarts a child.
+    ///
+    /// # Errors
+    ///
+    /// See [`Supervisor::restart_child`].
+    pub async fn restart_child(&mut self, id: &ChildId) -> Result<(), SupervisorError> {
+        dispatch!(self, node => node.restart_child(id).await)
+    }
+
+    /// Stops and removes a child.
+    ///
+    /// # Errors
+    ///
+    /// See [`Supervisor::stop_child`].
+    pub async fn stop_child(&mut self, id: &ChildId) -> Result<(), SupervisorError> {
+        dispatch!(self, node => node.stop_child(id).await)
+    }
+
+    fn enable_health_checks(&mut self, health: &HealthDescription) {
+        dispatch!(self, node => node.enable_health_checks(
+            health.check_interval,
+            health.check_timeout,
+            health.failure_threshold,
+        ))
+    }
+
+    async fn spawn(
+        &mut self,
+        factory: ChildFactory<C>,
+        settings: SpawnSettings,
+    ) -> Result<ChildId, SupervisorError> {
+        dispatch!(self, node => factory.spawn(node, settings).await)
+    }
+}
+
+/// A running supervisor tree built by [`TreeBuilder::build`].
+///
+/// Supervisors and children are looked up by path, e.g. `app/api/http`.
+#[derive(Debug)]
+pub struct DeclaredTree<C, M>
+where
+    C: Child,
+    M: Monitor<SupervisionEvent>,
+{
+    /// Supervisors in creation order (parents before their nested supervisors)
+    supervisors: Vec<(String, DeclaredSupervisor<C, M>)>,
+
+    /// Child IDs by path
+    children: HashMap<String, ChildId>,
+}
+
+impl<C, M> DeclaredTree<C, M>
+where
+    C: Child + Send + Sync + 'static,
+    M: Monitor<SupervisionEvent> + Send + Sync + 'static,
+{
+    /// Returns the supervisor at `path`.
+    pub fn supervisor(&self, path: &str) -> Option<&DeclaredSupervisor<C, M>> {
+        self.supervisors
+            .iter()
+            .find(|(p, _)| p == path)
+            .map(|(_, supervisor)| supervisor)
+    }
+
+    /// Returns the supervisor at `path` mutably.
+    pub fn supervisor_mut(&mut self, path: &str) -> Option<&mut DeclaredSupervisor<C, M>> {
+        self.supervisors
+            .iter_mut()
+            .find(|(p, _)| p == path)
+            .map(|(_, supervisor)| supervisor)
+    }
+
+    /// Returns the paths of all supervisors, parents first.
+    pub fn supervisor_paths(&self) -> impl Iterator<Item = &str> {
+        self.supervisors.iter().map(|(path, _)| path.as_str())
+    }
+
+    /// Returns the ID of the child at `path`.
+    pub fn child_id(&self, path: &str) -> Option<&ChildId> {
+        self.children.get(path)
+    }
+
+    /// Returns the number of supervisors in the tree.
+    pub fn supervisor_count(&self) -> usize {
+        self.supervisors.len()
+    }
+
+    /// Stops every child, nested supervisors first and each supervisor's
+    /// children in reverse start order.
+    ///
+    /// All children are stopped even if some fail; the first error is
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from stopping a child.
+    pub async fn shutdown(&mut self) -> Result<(), SupervisorError> {
+        let mut first_error = None;
+        for (_, supervisor) in self.supervisors.iter_mut().rev() {
+            let ids: Vec<ChildId> = supervisor.child_ids().iter().rev().cloned().collect();
+            for id in ids {
+                if let Err(e) = supervisor.stop_child(&id).await {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        self.children.clear();
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::monitoring::NoopMonitor;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    type Log = Arc<Mutex<Vec<String>>>;
+
+    struct Worker {
+        name: &'static str,
+        log: Log,
+        fail_start: bool,
+    }
+
+    #[derive(Debug)]
+    struct WorkerError;
+
+    impl std::fmt::Display for WorkerError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "worker error")
+        }
+    }
+
+    impl std::error::Error for WorkerError {}
+
+    #[async_trait]
+    impl Child for Worker {
+        type Error = WorkerError;
+
+        async fn start(&mut self) -> Result<(), Self::Error> {
+            if self.fail_start {
+                return Err(WorkerError);
+            }
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("start {}", self.name));
+            Ok(())
+        }
+
+        async fn stop(&mut self, _timeout: Duration) -> Result<(), Self::Error> {
+            self.log.lock().unwrap().push(format!("stop {}", self.name));
+            Ok(())
+        }
+    }
+
+    fn builder(log: &Log) -> TreeBuilder<Worker> {
+        let factory = |name: &'static str, fail_start: bool| {
+            let log = Arc::clone(log);
+            move || Worker {
+                name,
+                log: Arc::clone(&log),
+                fail_start,
+            }
+        };
+        let async_log = Arc::clone(log);
+        TreeBuilder::new()
+            .with_factory("db", factory("db", false))
+            .with_factory("http", factory("http", false))
+            .with_factory("broken", factory("broken", true))
+            .with_async_factory("cache", move || {
+                let log = Arc::clone(&async_log);
+                async move {
+                    Worker {
+                        name: "cache",
+                        log,
+                        fail_start: false,
+                    }
+                }
+            })
+    }
+
+    const TREE: &str = r#"
+        name = "app"
+        strategy = "one_for_one"
+
+        [[children]]
+        name = "db"
+        factory = "db"
+        shutdown_timeout = 10
+
+        [[supervisors]]
+        name = "api"
+        strategy = "rest_for_one"
+        health = { check_interval = 30, check_timeout = 5, failure_threshold = 3 }
+
+        [[supervisors.children]]
+        name = "cache"
+        factory = "cache"
+        restart = "Transient"
+
+        [[supervisors.children]]
+        name = "http"
+        factory = "http"
+    "#;
+
+    #[test]
+    fn test_parse_toml_description() {
+        let description = SupervisorDescription::from_toml_str(TREE).unwrap();
+        assert_eq!(description.strategy, StrategyKind::OneForOne);
+        assert_eq!(description.children[0].restart, DEFAULT_RESTART_POLICY);
+        assert_eq!(
+            description.children[0].shutdown_timeout,
+            Some(Duration::from_secs(10))
+        );
+
+        let api = &description.supervisors[0];
+        assert_eq!(api.strategy, StrategyKind::RestForOne);
+        assert_eq!(api.health.as_ref().unwrap().failure_threshold, 3);
+        assert_eq!(api.children[0].restart, RestartPolicy::Transient);
+        assert!(description.validate().is_ok());
+
+        let typo = SupervisorDescription::from_toml_str("name = \"a\"\nstrategy = \"one_for_two\"");
+        assert!(matches!(
+            typo,
+            Err(SupervisorError::InvalidConfiguration { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_names() {
+        let mut description = SupervisorDescription::from_toml_str(TREE).unwrap();
+        description.supervisors[0].name = "db".to_string();
+        let err = description.validate().unwrap_err().to_string();
+        assert!(err.contains("app: duplicate name 'db'"), "{err}");
+
+        description.supervisors[0].name = "a/b".to_string();
+        assert!(description.validate().is_err());
+
+        description.supervisors[0].name = "api".to_string();
+        description.supervisors[0]
+            .health
+            .as_mut()
+            .unwrap()
+            .failure_threshold = 0;
+        let err = description.validate().unwrap_err().to_string();
+        assert!(err.contains("app/api: health"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_build_starts_tree_in_order() {
+        let log = Log::default();
+        let description = SupervisorDescription::from_toml_str(TREE).unwrap();
+        let mut tree = builder(&log)
+            .build(&description, NoopMonitor::<SupervisionEvent>::new())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            tree.supervisor_paths().collect::<Vec<_>>(),
+            vec!["app", "app/api"]
+        );
+        let api = tree.supervisor("app/api").unwrap();
+        assert_eq!(api.strategy(), StrategyKind::RestForOne);
+        assert_eq!(api.child_count(), 2);
+        assert_eq!(api.health_config().unwrap().failure_threshold, 3);
+        assert!(tree.supervisor("app").unwrap().health_config().is_none());
+        assert!(tree.child_id("app/api/http").is_some());
+
+        tree.shutdown().await.unwrap();
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "start db",
+                "start cache",
+                "start http",
+                "stop http",
+                "stop cache",
+                "stop db"
+            ]
+        );
+        assert_eq!(tree.supervisor("app/api").unwrap().child_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_build_rejects_unknown_factory_before_starting() {
+        let log = Log::default();
+        let mut description = SupervisorDescription::from_toml_str(TREE).unwrap();
+        description.supervisors[0].children[1].factory = "missing".to_string();
+
+        let result = builder(&log)
+            .build(&description, NoopMonitor::<SupervisionEvent>::new())
+            .await;
+        let err = result.err().unwrap().to_string();
+        assert!(
+            err.contains("app/api/http: no factory registered as 'missing'"),
+            "{err}"
+        );
+        assert!(log.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_build_failure_stops_started_children() {
+        let log = Log::default();
+        let mut description = SupervisorDescription::from_toml_str(TREE).unwrap();
+        description.supervisors[0].children[1].factory = "broken".to_string();
+
+        let result = builder(&log)
+            .build(&description, NoopMonitor::<SupervisionEvent>::new())
+            .await;
+        assert!(result.is_err());
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["start db", "start cache", "stop cache", "stop db"]
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Type-erased child factories shared by the builders.
+//!
+//! Builders accept either a synchronous factory (`Fn() -> C`) or an async
+//! one (`Fn() -> impl Future<Output = C>`). Both are stored behind
+//! [`ChildFactory`] until spawn time, where they are turned into a
+//! [`ChildSpec`] or [`AsyncChildSpec`] respectively.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use crate::monitoring::{Monitor, SupervisionEvent};
+use crate::supervisor::traits::Supervisor;
+use crate::supervisor::{
+    AsyncChildSpec, Child, ChildId, ChildSpec, RestartPolicy, ShutdownPolicy, SupervisionStrategy,
+    SupervisorError, SupervisorNode,
+};
+
+/// Boxed future produced by an async child factory.
+pub(crate) type ChildFuture<C> = Pin<Box<dyn Future<Output = C> + Send>>;
+
+/// Child factory stored by a builder until spawn.
+pub(crate) enum ChildFactory<C> {
+    /// Factory returning the child directly.
+    Sync(Box<dyn Fn() -> C + Send + Sync>),
+    /// Factory returning a future that resolves to the child.
+    Async(Box<dyn Fn() -> ChildFuture<C> + Send + Sync>),
+}
+
+impl<C> ChildFactory<C> {
+    /// Wrap a synchronous factory.
+    pub(crate) fn from_sync<F>(factory: F) -> Self
+    where
+        F: Fn() -> C + Send + Sync + 'static,
+    {
+        Self::Sync(Box::new(factory))
+    }
+
+    /// Wrap an async factory.
+    pub(crate) fn from_async<F, Fut>(factory: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = C> + Send + 'static,
+    {
+        Self::Async(Box::new(move || Box::pin(factory())))
+    }
+}
+
+/// Resolved spawn settings for one child.
+pub(crate) struct SpawnSettings {
+    pub(crate) id: String,
+    pub(crate) restart_policy: RestartPolicy,
+    pub(crate) shutdown_policy: ShutdownPolicy,
+    pub(crate) start_timeout: Duration,
+    pub(crate) shutdown_timeout: Duration,
+}
+
+impl<C> ChildFactory<C>
+where
+    C: Child + Send + Sync + 'static,
+{
+    /// Start the child on `supervisor` using the matching spec type.
+    pub(crate) async fn spawn<S, M>(
+        self,
+        supervisor: &mut SupervisorNode<S, C, M>,
+        settings: SpawnSettings,
+    ) -> Result<ChildId, SupervisorError>
+    where
+        S: SupervisionStrategy + Send + Sync,
+        M: Monitor<SupervisionEvent> + Send + Sync + 'static,
+    {
+        match self {
+            Self::Sync(factory) => {
+                supervisor
+                    .start_child(ChildSpec {
+                        id: settings.id,
+                        factory,
+                        restart_policy: settings.restart_policy,
+                        shutdown_policy: settings.shutdown_policy,
+                        start_timeout: settings.start_timeout,
+                        shutdown_timeout: settings.shutdown_timeout,
+                    })
+                    .await
+            }
+            Self::Async(factory) => {
+                supervisor
+                    .start_child_async(AsyncChildSpec {
+                        id: settings.id,
+                        factory,
+                        restart_policy: settings.restart_policy,
+                        shutdown_policy: settings.shutdown_policy,
+                        start_timeout: settings.start_timeout,
+                        shutdown_timeout: settings.shutdown_timeout,
+                    })
+                    .await
+            }
+        }
+    }
+}
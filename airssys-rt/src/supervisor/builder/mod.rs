@@ -7,6 +7,10 @@
 //! 2. **SingleChildBuilder** (Phase 1) - Fluent API with sensible defaults
 //! 3. **ChildrenBatchBuilder** (Phase 2) - Batch operations with shared configuration
 //!
+//! Whole supervision trees can also be described as data (TOML or any serde
+//! format) and started by a [`TreeBuilder`] from named child factories; see
+//! [`declarative`].
+//!
 //! # Design Philosophy
 //!
 //! The builder pattern follows the principle of **progressive disclosure**:
@@ -410,6 +414,7 @@
 //! - [`SingleChildBuilder`] - Single child builder API reference
 //! - [`ChildrenBatchBuilder`] - Batch builder API reference
 //! - [`BatchChildCustomizer`] - Per-child customization API reference
+//! - [`TreeBuilder`] - Supervisor trees from configuration
 //! - [`constants`] - Default values and configuration rationale
 //! - `examples/supervisor_builder_phase1.rs` - Comprehensive Phase 1 examples
 //! - `examples/supervisor_builder_phase2.rs` - Comprehensive Phase 2 examples
@@ -430,6 +435,8 @@
 pub mod batch;
 pub mod constants;
 pub mod customizer;
+pub mod declarative;
+pub(crate) mod factory;
 pub mod single;
 
 // Re-exports for convenient access
@@ -439,4 +446,8 @@ pub use constants::{
     DEFAULT_START_TIMEOUT,
 };
 pub use customizer::BatchChildCustomizer;
+pub use declarative::{
+    ChildDescription, DeclaredSupervisor, DeclaredTree, HealthDescription, StrategyKind,
+    SupervisorDescription, TreeBuilder,
+};
 pub use single::SingleChildBuilder;
@@ -2,10 +2,11 @@
 
 use std::time::Duration;
 
+use std::future::Future;
+
 use crate::monitoring::{Monitor, SupervisionEvent};
-use crate::supervisor::traits::Supervisor;
 use crate::supervisor::{
-    Child, ChildId, ChildSpec, RestartPolicy, ShutdownPolicy, SupervisionStrategy, SupervisorError,
+    Child, ChildId, RestartPolicy, ShutdownPolicy, SupervisionStrategy, SupervisorError,
     SupervisorNode,
 };
 
@@ -13,6 +14,7 @@ use super::constants::{
     DEFAULT_RESTART_POLICY, DEFAULT_SHUTDOWN_POLICY, DEFAULT_SHUTDOWN_TIMEOUT,
     DEFAULT_START_TIMEOUT,
 };
+use super::factory::{ChildFactory, SpawnSettings};
 
 /// Fluent builder for configuring a single supervised child.
 ///
@@ -128,7 +130,7 @@ where
 {
     supervisor: &'a mut SupervisorNode<S, C, M>,
     id: String,
-    factory: Option<Box<dyn Fn() -> C + Send + Sync>>,
+    factory: Option<ChildFactory<C>>,
     restart_policy: Option<RestartPolicy>,
     shutdown_policy: Option<ShutdownPolicy>,
     start_timeout: Option<Duration>,
@@ -222,7 +224,53 @@ where
     /// # }
     /// ```
     pub fn factory(mut self, f: impl Fn() -> C + Send + Sync + 'static) -> Self {
-        self.factory = Some(Box::new(f));
+        self.factory = Some(ChildFactory::from_sync(f));
+        self
+    }
+
+    /// Sets an async factory function that creates child instances.
+    ///
+    /// Use this when constructing the child requires async work such as
+    /// opening sockets or connection pools. The factory future runs inside
+    /// the start timeout, so a hung factory fails the spawn instead of
+    /// blocking the supervisor.
+    ///
+    /// Replaces any factory set earlier with [`factory`](Self::factory).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use airssys_rt::supervisor::*;
+    /// use airssys_rt::monitoring::{NoopMonitor, SupervisionEvent};
+    /// use async_trait::async_trait;
+    /// use std::time::Duration;
+    ///
+    /// # struct DbWorker;
+    /// # async fn connect_pool() -> DbWorker { DbWorker }
+    /// # #[async_trait]
+    /// # impl Child for DbWorker {
+    /// #     type Error = std::io::Error;
+    /// #     async fn start(&mut self) -> Result<(), Self::Error> { Ok(()) }
+    /// #     async fn stop(&mut self, _: Duration) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # async fn example() -> Result<(), SupervisorError> {
+    /// let mut supervisor = SupervisorNode::new(OneForOne, NoopMonitor::<SupervisionEvent>::new());
+    ///
+    /// let id = supervisor
+    ///     .child("db")
+    ///     .async_factory(|| connect_pool())
+    ///     .start_timeout(Duration::from_secs(5))  // Covers connect_pool() + start()
+    ///     .spawn()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn async_factory<F, Fut>(mut self, f: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = C> + Send + 'static,
+    {
+        self.factory = Some(ChildFactory::from_async(f));
         self
     }
 
@@ -729,16 +777,15 @@ where
                 reason: "Factory function is required. Call .factory() before .spawn()".into(),
             })?;
 
-        let spec = ChildSpec {
+        let settings = SpawnSettings {
             id: self.id,
-            factory,
             restart_policy: self.restart_policy.unwrap_or(DEFAULT_RESTART_POLICY),
             shutdown_policy: self.shutdown_policy.unwrap_or(DEFAULT_SHUTDOWN_POLICY),
             start_timeout: self.start_timeout.unwrap_or(DEFAULT_START_TIMEOUT),
             shutdown_timeout: self.shutdown_timeout.unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT),
         };
 
-        self.supervisor.start_child(spec).await
+        factory.spawn(self.supervisor, settings).await
     }
 }
 
@@ -856,6 +903,50 @@ mod tests {
         assert!(!child_id.to_string().is_empty());
     }
 
+    #[tokio::test]
+    async fn test_async_factory() {
+        let mut supervisor = create_supervisor();
+        let child = TestChild::new();
+        let starts = Arc::clone(&child.start_count);
+
+        let child_id = supervisor
+            .child("test")
+            .async_factory(move || {
+                let child = child.clone();
+                async move {
+                    tokio::time::sleep(Duration::from_millis(5)).await;
+                    child
+                }
+            })
+            .spawn()
+            .await
+            .unwrap();
+
+        assert!(supervisor.child_ids().contains(&child_id));
+        assert_eq!(starts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_async_factory_counts_against_start_timeout() {
+        let mut supervisor = create_supervisor();
+
+        let result = supervisor
+            .child("slow")
+            .async_factory(|| async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                TestChild::new()
+            })
+            .start_timeout(Duration::from_millis(20))
+            .spawn()
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(SupervisorError::ChildStartFailed { .. })
+        ));
+        assert!(supervisor.child_ids().is_empty());
+    }
+
     // -------------------------------------------------------------------------
     // Restart Policy Tests
     // -------------------------------------------------------------------------
@@ -212,6 +212,7 @@
 ///     .build();
 /// ```
 // Module declarations
+pub mod analytics;
 pub mod backoff;
 pub mod builder;
 pub mod error;
@@ -223,6 +224,7 @@ pub mod tree;
 pub mod types;
 
 // Re-exports for convenient access
+pub use analytics::{ChildFailureStats, FailureClass, RestartCause};
 pub use backoff::RestartBackoff;
 pub use builder::{
     SingleChildBuilder, DEFAULT_RESTART_POLICY, DEFAULT_SHUTDOWN_POLICY, DEFAULT_SHUTDOWN_TIMEOUT,
@@ -234,6 +236,6 @@ pub use strategy::{should_restart, should_restart_any, OneForAll, OneForOne, Res
 pub use traits::{Child, SupervisionStrategy, Supervisor};
 pub use tree::{SupervisorId, SupervisorTree};
 pub use types::{
-    ChildHealth, ChildId, ChildSpec, ChildState, RestartPolicy, ShutdownPolicy, StrategyContext,
-    SupervisionDecision,
+    AsyncChildSpec, ChildHealth, ChildId, ChildSpec, ChildState, RestartPolicy, ShutdownPolicy,
+    StrategyContext, SupervisionDecision,
 };
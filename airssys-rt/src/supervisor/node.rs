@@ -7,17 +7,19 @@
 // Layer 1: Standard library imports
 use std::collections::HashMap;
 use std::error::Error as StdError;
+use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
 
 // Layer 2: Third-party crate imports
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use tokio::sync::Mutex;
-use tokio::time::timeout;
+use tokio::time::{timeout, Instant};
 use uuid::Uuid;
 
 // Layer 3: Internal module imports
+use super::analytics::{ChildFailureStats, FailureClass, RestartCause};
 use super::backoff::RestartBackoff;
 use super::builder::{ChildrenBatchBuilder, SingleChildBuilder};
 use super::error::SupervisorError;
@@ -25,8 +27,8 @@ use super::health_monitor::spawn_health_monitor;
 use super::strategy::should_restart;
 use super::traits::{Child, SupervisionStrategy, Supervisor};
 use super::types::{
-    ChildHealth, ChildId, ChildSpec, ChildState, RestartPolicy, ShutdownPolicy, StrategyContext,
-    SupervisionDecision,
+    AsyncChildSpec, ChildHealth, ChildId, ChildSpec, ChildState, RestartPolicy, ShutdownPolicy,
+    StrategyContext, SupervisionDecision,
 };
 use crate::monitoring::{Monitor, SupervisionEvent, SupervisionEventKind};
 
@@ -160,6 +162,23 @@ where
     pub fn child(&self) -> &C {
         &self.child
     }
+
+    /// Time since the child was last (re)started.
+    pub fn uptime(&self) -> Duration {
+        (Utc::now() - self.last_restart)
+            .to_std()
+            .unwrap_or_default()
+    }
+
+    /// Captures why the child failed right now.
+    pub fn restart_cause(&self, class: FailureClass, error: impl Into<String>) -> RestartCause {
+        RestartCause::new(
+            class,
+            error,
+            self.child.current_message_type(),
+            self.uptime(),
+        )
+    }
 }
 
 /// Generic supervisor node with strategy, child, and monitor type parameters.
@@ -348,6 +367,21 @@ where
 
     /// Optional health monitoring configuration (YAGNI §6.1 - opt-in feature)
     health_config: Option<HealthConfig>,
+
+    /// Per-child restart cause statistics
+    failure_stats: HashMap<ChildId, ChildFailureStats>,
+
+    /// Open maintenance window, if the supervisor is paused
+    pause: Option<PauseWindow>,
+}
+
+/// A maintenance window during which restarts are deferred.
+#[derive(Debug, Clone)]
+struct PauseWindow {
+    reason: String,
+    paused_at: DateTime<Utc>,
+    started: Instant,
+    deferred: Vec<ChildId>,
 }
 
 impl<S, C, M> SupervisorNode<S, C, M>
@@ -395,6 +429,8 @@ where
             monitor,
             child_order: Vec::new(),
             health_config: None, // Health monitoring disabled by default
+            failure_stats: HashMap::new(),
+            pause: None,
         }
     }
 
@@ -461,6 +497,26 @@ where
         &self.child_order
     }
 
+    /// Returns the restart cause statistics of a child, if it ever failed.
+    pub fn failure_stats(&self, id: &ChildId) -> Option<&ChildFailureStats> {
+        self.failure_stats.get(id)
+    }
+
+    /// Returns the restart cause statistics of every child that failed.
+    pub fn all_failure_stats(&self) -> &HashMap<ChildId, ChildFailureStats> {
+        &self.failure_stats
+    }
+
+    /// Records `cause` for `id` and returns it as event metadata.
+    fn record_failure(&mut self, id: &ChildId, cause: RestartCause) -> HashMap<String, String> {
+        let metadata = cause.to_metadata();
+        self.failure_stats
+            .entry(id.clone())
+            .or_default()
+            .record(cause);
+        metadata
+    }
+
     // ========================================================================
     // Health Monitoring API (Phase 4a)
     // ========================================================================
@@ -841,6 +897,21 @@ where
                     // Check if threshold exceeded
                     let exceeded = config.has_exceeded_threshold(child_id);
                     let failure_count = config.get_failure_count(child_id);
+                    let error = format!("Health check failed ({failure_count}): {reason}");
+
+                    // The failure that triggers the restart is its cause
+                    let metadata = match self.children.get(child_id) {
+                        Some(handle) if exceeded => {
+                            let cause = handle.restart_cause(FailureClass::HealthCheck, &error);
+                            let metadata = cause.to_metadata();
+                            self.failure_stats
+                                .entry(child_id.clone())
+                                .or_default()
+                                .record(cause);
+                            metadata
+                        }
+                        _ => HashMap::new(),
+                    };
 
                     // Emit health check failure event
                     let _ = self
@@ -850,10 +921,10 @@ where
                             supervisor_id: self.id.to_string(),
                             child_id: Some(child_id.to_string()),
                             event_kind: SupervisionEventKind::ChildFailed {
-                                error: format!("Health check failed ({failure_count}): {reason}"),
+                                error,
                                 restart_count: failure_count,
                             },
-                            metadata: HashMap::new(),
+                            metadata,
                         })
                         .await;
 
@@ -1144,6 +1215,212 @@ where
     }
 }
 
+impl<S, C, M> SupervisorNode<S, C, M>
+where
+    S: SupervisionStrategy + Send + Sync,
+    C: Child + Send + Sync,
+    M: Monitor<SupervisionEvent> + Send + Sync + 'static,
+{
+    /// Start a child whose factory performs async setup.
+    ///
+    /// The spec's `start_timeout` is a single budget shared by the factory
+    /// future and the child's `start()`: whatever the factory consumes is
+    /// no longer available to `start()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SupervisorError::ChildStartFailed` if the factory or `start()`
+    /// exceeds the timeout, or if `start()` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use airssys_rt::supervisor::*;
+    /// use airssys_rt::monitoring::{NoopMonitor, SupervisionEvent};
+    /// use async_trait::async_trait;
+    /// use std::time::Duration;
+    ///
+    /// # struct DbWorker;
+    /// # async fn connect() -> DbWorker { DbWorker }
+    /// # #[async_trait]
+    /// # impl Child for DbWorker {
+    /// #     type Error = std::io::Error;
+    /// #     async fn start(&mut self) -> Result<(), Self::Error> { Ok(()) }
+    /// #     async fn stop(&mut self, _: Duration) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # async fn example() -> Result<(), SupervisorError> {
+    /// let mut supervisor = SupervisorNode::new(OneForOne, NoopMonitor::<SupervisionEvent>::new());
+    ///
+    /// let spec = AsyncChildSpec {
+    ///     id: "db".into(),
+    ///     factory: || connect(),
+    ///     restart_policy: RestartPolicy::Permanent,
+    ///     shutdown_policy: ShutdownPolicy::Graceful(Duration::from_secs(5)),
+    ///     start_timeout: Duration::from_secs(10),
+    ///     shutdown_timeout: Duration::from_secs(10),
+    /// };
+    /// let id = supervisor.start_child_async(spec).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn start_child_async<F, Fut>(
+        &mut self,
+        spec: AsyncChildSpec<C, F, Fut>,
+    ) -> Result<ChildId, SupervisorError>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = C> + Send,
+    {
+        let child_id = ChildId::new();
+        let started = Instant::now();
+
+        // Factory execution counts against the start timeout
+        let child = timeout(spec.start_timeout, (spec.factory)())
+            .await
+            .map_err(|_| SupervisorError::ChildStartFailed {
+                id: child_id.to_string(),
+                source: Box::new(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    format!("Child factory timeout after {:?}", spec.start_timeout),
+                )) as Box<dyn StdError + Send + Sync>,
+            })?;
+
+        let remaining = spec.start_timeout.saturating_sub(started.elapsed());
+        let mut child_handle = ChildHandle::new(child, spec.restart_policy, spec.shutdown_policy);
+
+        self.start_child_with_timeout(&child_id, &mut child_handle, remaining)
+            .await?;
+
+        self.children.insert(child_id.clone(), child_handle);
+        self.child_order.push(child_id.clone());
+
+        Ok(child_id)
+    }
+
+    // ========================================================================
+    // Maintenance Windows
+    // ========================================================================
+
+    /// Pauses automatic restarts for a maintenance window.
+    ///
+    /// While paused, child failures are still recorded (failure statistics
+    /// and monitor events), but `restart_child` - including restarts
+    /// triggered by health checks - only queues the child and emits a
+    /// `RestartDeferred` event. Queued restarts run on
+    /// [`resume`](Self::resume). A `SupervisorPaused` event marks the start
+    /// of the window.
+    ///
+    /// Returns `false` if the supervisor was already paused.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use airssys_rt::supervisor::*;
+    /// use airssys_rt::monitoring::{NoopMonitor, SupervisionEvent};
+    /// use async_trait::async_trait;
+    /// use std::time::Duration;
+    ///
+    /// # struct Worker;
+    /// # #[async_trait]
+    /// # impl Child for Worker {
+    /// #     type Error = std::io::Error;
+    /// #     async fn start(&mut self) -> Result<(), Self::Error> { Ok(()) }
+    /// #     async fn stop(&mut self, _: Duration) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # async fn example(id: ChildId) {
+    /// let mut supervisor =
+    ///     SupervisorNode::<_, Worker, _>::new(OneForOne, NoopMonitor::<SupervisionEvent>::new());
+    ///
+    /// supervisor.pause("upgrading database driver").await;
+    /// // A failing child is queued instead of restarted
+    /// supervisor.restart_child(&id).await.ok();
+    ///
+    /// for (child, result) in supervisor.resume().await {
+    ///     println!("{child}: {result:?}");
+    /// }
+    /// # }
+    /// ```
+    pub async fn pause(&mut self, reason: impl Into<String>) -> bool {
+        if self.pause.is_some() {
+            return false;
+        }
+        let reason = reason.into();
+        self.pause = Some(PauseWindow {
+            reason: reason.clone(),
+            paused_at: Utc::now(),
+            started: Instant::now(),
+            deferred: Vec::new(),
+        });
+        self.record_event(None, SupervisionEventKind::SupervisorPaused { reason })
+            .await;
+        true
+    }
+
+    /// Ends the maintenance window and performs the deferred restarts in
+    /// the order they were queued.
+    ///
+    /// Emits a `SupervisorResumed` event first. Children removed while the
+    /// supervisor was paused are skipped. Returns the result of each
+    /// restart; empty if the supervisor was not paused.
+    pub async fn resume(&mut self) -> Vec<(ChildId, Result<(), SupervisorError>)> {
+        let Some(window) = self.pause.take() else {
+            return Vec::new();
+        };
+        self.record_event(
+            None,
+            SupervisionEventKind::SupervisorResumed {
+                deferred_restarts: window.deferred.len(),
+                paused_for: window.started.elapsed(),
+            },
+        )
+        .await;
+
+        let mut results = Vec::with_capacity(window.deferred.len());
+        for id in window.deferred {
+            if self.children.contains_key(&id) {
+                let result = self.restart_child(&id).await;
+                results.push((id, result));
+            }
+        }
+        results
+    }
+
+    /// Returns `true` while the supervisor is in a maintenance window.
+    pub fn is_paused(&self) -> bool {
+        self.pause.is_some()
+    }
+
+    /// Returns when the current maintenance window started.
+    pub fn paused_since(&self) -> Option<DateTime<Utc>> {
+        self.pause.as_ref().map(|window| window.paused_at)
+    }
+
+    /// Returns the reason given for the current maintenance window.
+    pub fn pause_reason(&self) -> Option<&str> {
+        self.pause.as_ref().map(|window| window.reason.as_str())
+    }
+
+    /// Returns the children whose restart is waiting for [`resume`](Self::resume).
+    pub fn deferred_restarts(&self) -> &[ChildId] {
+        self.pause
+            .as_ref()
+            .map_or(&[], |window| window.deferred.as_slice())
+    }
+
+    async fn record_event(&self, child_id: Option<&ChildId>, event_kind: SupervisionEventKind) {
+        let _ = self
+            .monitor
+            .record(SupervisionEvent {
+                timestamp: Utc::now(),
+                supervisor_id: self.id.to_string(),
+                child_id: child_id.map(ToString::to_string),
+                event_kind,
+                metadata: HashMap::new(),
+            })
+            .await;
+    }
+}
+
 #[async_trait]
 impl<S, C, M> Supervisor for SupervisorNode<S, C, M>
 where
@@ -1229,6 +1506,20 @@ where
     }
 
     async fn restart_child(&mut self, id: &ChildId) -> Result<(), SupervisorError> {
+        if !self.children.contains_key(id) {
+            return Err(SupervisorError::ChildNotFound { id: id.clone() });
+        }
+
+        // In a maintenance window the restart is queued for resume()
+        if let Some(window) = self.pause.as_mut() {
+            if !window.deferred.contains(id) {
+                window.deferred.push(id.clone());
+            }
+            self.record_event(Some(id), SupervisionEventKind::RestartDeferred)
+                .await;
+            return Ok(());
+        }
+
         // Get child handle
         let child_handle = self
             .children
@@ -1293,6 +1584,10 @@ where
         match start_result {
             Ok(()) => {
                 child_handle.set_state(ChildState::Running);
+                self.failure_stats
+                    .entry(id.clone())
+                    .or_default()
+                    .record_restart();
                 let _ = self
                     .monitor
                     .record(SupervisionEvent {
@@ -1309,6 +1604,11 @@ where
             }
             Err(e) => {
                 child_handle.set_state(ChildState::Failed);
+                let cause = child_handle.restart_cause(FailureClass::StartFailure, e.to_string());
+                self.failure_stats
+                    .entry(id.clone())
+                    .or_default()
+                    .record(cause);
                 Err(SupervisorError::ChildStartFailed {
                     id: id.to_string(),
                     source: Box::new(e) as Box<dyn StdError + Send + Sync>,
@@ -1320,8 +1620,18 @@ where
     async fn handle_child_error(
         &mut self,
         id: &ChildId,
-        _error: Box<dyn StdError + Send + Sync>,
+        error: Box<dyn StdError + Send + Sync>,
     ) -> SupervisionDecision {
+        // Record why the child failed before deciding what to do about it
+        let cause_metadata = match self.children.get(id) {
+            Some(handle) => {
+                let cause =
+                    handle.restart_cause(FailureClass::classify(error.as_ref()), error.to_string());
+                Some(self.record_failure(id, cause))
+            }
+            None => None,
+        };
+
         // TODO: Parse error to determine if it was a normal exit
         // For now, assume abnormal exit
         let is_normal_exit = false;
@@ -1343,7 +1653,24 @@ where
         let child_id_str = id.to_string();
         let strategy_name = std::any::type_name::<S>().to_string();
 
+        let error = error.to_string();
+        let restart_count = self.children.get(id).map_or(0, |h| h.restart_count());
+
         tokio::spawn(async move {
+            if let Some(metadata) = cause_metadata {
+                let _ = monitor
+                    .record(SupervisionEvent {
+                        timestamp: Utc::now(),
+                        supervisor_id: supervisor_id.clone(),
+                        child_id: Some(child_id_str.clone()),
+                        event_kind: SupervisionEventKind::ChildFailed {
+                            error,
+                            restart_count,
+                        },
+                        metadata,
+                    })
+                    .await;
+            }
             let _ = monitor
                 .record(SupervisionEvent {
                     timestamp: Utc::now(),
@@ -1531,6 +1858,115 @@ mod tests {
         assert_eq!(handle.restart_count(), restart_count_before + 1);
     }
 
+    #[tokio::test]
+    async fn test_child_error_records_restart_cause() {
+        let monitor = InMemoryMonitor::new(Default::default());
+        let mut supervisor =
+            SupervisorNode::<OneForOne, TestChild, _>::new(OneForOne, monitor.clone());
+
+        let spec = ChildSpec {
+            id: "test-child".into(),
+            factory: || TestChild {
+                should_fail_start: false,
+                should_fail_stop: false,
+            },
+            restart_policy: RestartPolicy::Permanent,
+            shutdown_policy: ShutdownPolicy::Graceful(Duration::from_secs(5)),
+            start_timeout: Duration::from_secs(10),
+            shutdown_timeout: Duration::from_secs(10),
+        };
+        let child_id = supervisor.start_child(spec).await.unwrap();
+        assert!(supervisor.failure_stats(&child_id).is_none());
+
+        let error = Box::new(std::io::Error::other("wasm trap: unreachable"));
+        supervisor.handle_child_error(&child_id, error).await;
+        supervisor.restart_child(&child_id).await.unwrap();
+
+        let stats = supervisor.failure_stats(&child_id).unwrap();
+        assert_eq!((stats.failures, stats.restarts), (1, 1));
+        assert_eq!(stats.dominant_class(), Some(FailureClass::Crash));
+        assert_eq!(supervisor.all_failure_stats().len(), 1);
+
+        // The cause reaches the monitor as event metadata
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let snapshot = monitor.snapshot().await.unwrap();
+        let failed = snapshot
+            .recent_events
+            .iter()
+            .find(|e| matches!(e.event_kind, SupervisionEventKind::ChildFailed { .. }))
+            .unwrap();
+        assert_eq!(failed.metadata["failure_class"], "crash");
+        assert!(failed.metadata.contains_key("uptime_ms"));
+    }
+
+    #[tokio::test]
+    async fn test_pause_defers_restarts_until_resume() {
+        let monitor = InMemoryMonitor::new(Default::default());
+        let mut supervisor =
+            SupervisorNode::<OneForOne, TestChild, _>::new(OneForOne, monitor.clone());
+
+        let spec = ChildSpec {
+            id: "test-child".into(),
+            factory: || TestChild {
+                should_fail_start: false,
+                should_fail_stop: false,
+            },
+            restart_policy: RestartPolicy::Permanent,
+            shutdown_policy: ShutdownPolicy::Graceful(Duration::from_secs(5)),
+            start_timeout: Duration::from_secs(10),
+            shutdown_timeout: Duration::from_secs(10),
+        };
+        let child_id = supervisor.start_child(spec).await.unwrap();
+
+        assert!(supervisor.pause("driver upgrade").await);
+        assert!(!supervisor.pause("again").await);
+        assert_eq!(supervisor.pause_reason(), Some("driver upgrade"));
+        assert!(supervisor.paused_since().is_some());
+
+        let error = Box::new(std::io::Error::other("connection reset"));
+        supervisor.handle_child_error(&child_id, error).await;
+        supervisor.restart_child(&child_id).await.unwrap();
+        supervisor.restart_child(&child_id).await.unwrap();
+
+        // Failure recorded, restart queued once, nothing restarted yet
+        assert_eq!(supervisor.failure_stats(&child_id).unwrap().failures, 1);
+        assert_eq!(
+            supervisor.deferred_restarts(),
+            std::slice::from_ref(&child_id)
+        );
+        assert_eq!(supervisor.get_child(&child_id).unwrap().restart_count(), 0);
+
+        let results = supervisor.resume().await;
+        assert_eq!(results.len(), 1);
+        assert!(results[0].1.is_ok());
+        assert!(!supervisor.is_paused());
+        assert_eq!(supervisor.get_child(&child_id).unwrap().restart_count(), 1);
+        assert!(supervisor.resume().await.is_empty());
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let kinds: Vec<_> = monitor
+            .snapshot()
+            .await
+            .unwrap()
+            .recent_events
+            .into_iter()
+            .map(|e| e.event_kind)
+            .collect();
+        assert!(kinds
+            .iter()
+            .any(|k| matches!(k, SupervisionEventKind::SupervisorPaused { reason } if reason == "driver upgrade")));
+        assert!(kinds
+            .iter()
+            .any(|k| matches!(k, SupervisionEventKind::RestartDeferred)));
+        assert!(kinds.iter().any(|k| matches!(
+            k,
+            SupervisionEventKind::SupervisorResumed {
+                deferred_restarts: 1,
+                ..
+            }
+        )));
+    }
+
     #[tokio::test]
     async fn test_child_order_tracking() {
         let monitor = InMemoryMonitor::new(Default::default());
@@ -2345,4 +2781,87 @@ mod tests {
             assert_eq!(handle.state(), &ChildState::Running);
         }
     }
+
+    fn async_spec<F, Fut>(factory: F, start_timeout: Duration) -> AsyncChildSpec<TestChild, F, Fut>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = TestChild> + Send,
+    {
+        AsyncChildSpec {
+            id: "async-child".into(),
+            factory,
+            restart_policy: RestartPolicy::Permanent,
+            shutdown_policy: ShutdownPolicy::Graceful(Duration::from_secs(5)),
+            start_timeout,
+            shutdown_timeout: Duration::from_secs(10),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_start_child_async_success() {
+        let monitor = InMemoryMonitor::new(Default::default());
+        let mut supervisor = SupervisorNode::<OneForOne, TestChild, _>::new(OneForOne, monitor);
+
+        let spec = async_spec(
+            || async {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                TestChild {
+                    should_fail_start: false,
+                    should_fail_stop: false,
+                }
+            },
+            Duration::from_secs(10),
+        );
+
+        let child_id = supervisor.start_child_async(spec).await.unwrap();
+
+        assert_eq!(supervisor.child_count(), 1);
+        let handle = supervisor.get_child(&child_id).unwrap();
+        assert_eq!(handle.state(), &ChildState::Running);
+    }
+
+    #[tokio::test]
+    async fn test_start_child_async_factory_timeout() {
+        let monitor = InMemoryMonitor::new(Default::default());
+        let mut supervisor = SupervisorNode::<OneForOne, TestChild, _>::new(OneForOne, monitor);
+
+        let spec = async_spec(
+            || async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                TestChild {
+                    should_fail_start: false,
+                    should_fail_stop: false,
+                }
+            },
+            Duration::from_millis(20),
+        );
+
+        let result = supervisor.start_child_async(spec).await;
+
+        assert!(matches!(
+            result,
+            Err(SupervisorError::ChildStartFailed { ref source, .. })
+                if source.to_string().contains("factory timeout")
+        ));
+        assert_eq!(supervisor.child_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_start_child_async_start_failure() {
+        let monitor = InMemoryMonitor::new(Default::default());
+        let mut supervisor = SupervisorNode::<OneForOne, TestChild, _>::new(OneForOne, monitor);
+
+        let spec = async_spec(
+            || async {
+                TestChild {
+                    should_fail_start: true,
+                    should_fail_stop: false,
+                }
+            },
+            Duration::from_secs(10),
+        );
+
+        assert!(supervisor.start_child_async(spec).await.is_err());
+        assert_eq!(supervisor.child_count(), 0);
+    }
 }
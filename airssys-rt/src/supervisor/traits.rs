@@ -395,6 +395,14 @@ pub trait Child: Send + Sync + 'static {
     async fn health_check(&self) -> ChildHealth {
         ChildHealth::Healthy
     }
+
+    /// Type of the message the child is currently processing, if any.
+    ///
+    /// Recorded in the child's restart cause when it fails, so operators can
+    /// tell which messages bring it down. The default reports nothing.
+    fn current_message_type(&self) -> Option<String> {
+        None
+    }
 }
 
 // NOTE: No blanket implementation for Actor → Child
@@ -6,6 +6,7 @@
 
 // Layer 1: Standard library imports
 use std::fmt;
+use std::future::Future;
 use std::time::Duration;
 
 // Layer 2: Third-party crate imports
@@ -137,6 +138,70 @@ where
     pub shutdown_timeout: Duration,
 }
 
+/// Child specification with an asynchronous factory.
+///
+/// Identical to [`ChildSpec`] except that the factory returns a future, so
+/// children can perform async setup (opening sockets, connection pools)
+/// while being constructed. The `start_timeout` covers both the factory
+/// future and the child's `start()`.
+///
+/// # Type Parameters
+///
+/// - `C`: Child type implementing the `Child` trait
+/// - `F`: Factory function type returning a future
+/// - `Fut`: Future type resolving to a new child instance
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_rt::supervisor::{AsyncChildSpec, RestartPolicy, ShutdownPolicy};
+/// use std::time::Duration;
+///
+/// # use airssys_rt::supervisor::Child;
+/// # use async_trait::async_trait;
+/// # struct PoolWorker { size: usize }
+/// # async fn open_pool() -> usize { 4 }
+/// # #[async_trait]
+/// # impl Child for PoolWorker {
+/// #     type Error = std::io::Error;
+/// #     async fn start(&mut self) -> Result<(), Self::Error> { Ok(()) }
+/// #     async fn stop(&mut self, _: Duration) -> Result<(), Self::Error> { Ok(()) }
+/// # }
+/// #
+/// let spec = AsyncChildSpec {
+///     id: "pool-worker".into(),
+///     factory: || async { PoolWorker { size: open_pool().await } },
+///     restart_policy: RestartPolicy::Permanent,
+///     shutdown_policy: ShutdownPolicy::Graceful(Duration::from_secs(5)),
+///     start_timeout: Duration::from_secs(10),
+///     shutdown_timeout: Duration::from_secs(10),
+/// };
+/// ```
+#[derive(Debug)]
+pub struct AsyncChildSpec<C, F, Fut>
+where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = C> + Send,
+{
+    /// Unique identifier for this child (for logging and monitoring)
+    pub id: String,
+
+    /// Async factory function that creates new child instances.
+    pub factory: F,
+
+    /// Restart policy determining when to restart this child
+    pub restart_policy: RestartPolicy,
+
+    /// Shutdown policy determining how to stop this child
+    pub shutdown_policy: ShutdownPolicy,
+
+    /// Maximum time to wait for the factory and child startup combined
+    pub start_timeout: Duration,
+
+    /// Maximum time to wait for child shutdown
+    pub shutdown_timeout: Duration,
+}
+
 /// Restart policy for supervised children.
 ///
 /// Determines when a child should be restarted after termination.
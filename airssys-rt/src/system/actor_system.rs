@@ -2,20 +2,25 @@
 
 // Layer 1: Standard library
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 // Layer 2: Third-party
 use chrono::{DateTime, Utc};
 use parking_lot::RwLock;
 use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tokio::sync::oneshot;
 use tokio::task::{spawn, JoinHandle};
-use tokio::time::{sleep, timeout};
+use tokio::time::{sleep, timeout, Duration};
 
 // Layer 3: Internal
+use super::control::{ActorHealth, ControlSignal};
 use super::{builder::ActorSpawnBuilder, SystemConfig, SystemError};
-use crate::actor::{Actor, ActorContext, ErrorAction};
+use crate::actor::{
+    Actor, ActorContext, ErrorAction, Service, ServiceError, ServiceKey, ServiceRegistry,
+};
 use crate::broker::MessageBroker;
-use crate::message::{Message, MessageEnvelope};
+use crate::message::{Message, MessageEnvelope, MessagePriority};
 use crate::util::{ActorAddress, ActorId};
 
 /// System state enumeration.
@@ -37,6 +42,7 @@ struct ActorMetadata<M: Message> {
     name: Option<String>,
     spawned_at: DateTime<Utc>,
     mailbox_sender: UnboundedSender<MessageEnvelope<M>>,
+    control_sender: UnboundedSender<ControlSignal>,
     task_handle: JoinHandle<()>,
 }
 
@@ -91,6 +97,16 @@ pub(crate) struct ActorSystemInner<M: Message, B: MessageBroker<M>> {
     actors: RwLock<HashMap<ActorAddress, ActorMetadata<M>>>,
     pub(crate) state: RwLock<SystemState>,
     router_handle: RwLock<Option<JoinHandle<()>>>,
+    deadline_exceeded: AtomicU64,
+    services: ServiceRegistry,
+}
+
+impl<M: Message, B: MessageBroker<M>> ActorSystemInner<M, B> {
+    /// Forget a stopped actor and any services it was registered for.
+    fn remove_actor(&self, address: &ActorAddress) {
+        self.actors.write().remove(address);
+        self.services.remove_address(address);
+    }
 }
 
 impl<M: Message + serde::Serialize, B: MessageBroker<M> + Clone + Send + Sync + 'static>
@@ -121,6 +137,8 @@ impl<M: Message + serde::Serialize, B: MessageBroker<M> + Clone + Send + Sync +
             actors: RwLock::new(HashMap::new()),
             state: RwLock::new(SystemState::Running),
             router_handle: RwLock::new(None),
+            deadline_exceeded: AtomicU64::new(0),
+            services: ServiceRegistry::new(),
         });
 
         // Start router task
@@ -166,14 +184,73 @@ impl<M: Message + serde::Serialize, B: MessageBroker<M> + Clone + Send + Sync +
         self.inner.actors.read().len()
     }
 
+    /// Get the number of messages dropped because their deadline had passed.
+    ///
+    /// Expired messages are skipped before `handle_message()` is called, so
+    /// no actor spends time on work whose requester already gave up.
+    pub fn deadline_exceeded_count(&self) -> u64 {
+        self.inner.deadline_exceeded.load(Ordering::Relaxed)
+    }
+
     /// Check if system is shutting down.
     pub fn is_shutting_down(&self) -> bool {
         *self.inner.state.read() != SystemState::Running
     }
 
+    /// Stop an actor via its control lane.
+    ///
+    /// The stop signal bypasses the actor's mailbox, so the actor stops after
+    /// the message it is currently handling even if the mailbox is deep.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SystemError::ActorNotFound` if no live actor has this address.
+    pub fn stop_actor(&self, address: &ActorAddress) -> Result<(), SystemError> {
+        self.send_control(address, ControlSignal::Stop)
+    }
+
+    /// Probe an actor's health via its control lane.
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::ActorNotFound` - No live actor has this address, or it
+    ///   stopped before answering
+    /// - `SystemError::ProbeTimeout` - The actor did not answer within `wait`
+    pub async fn probe_actor(
+        &self,
+        address: &ActorAddress,
+        wait: Duration,
+    ) -> Result<ActorHealth, SystemError> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.send_control(address, ControlSignal::HealthProbe(reply_tx))?;
+
+        match timeout(wait, reply_rx).await {
+            Ok(Ok(health)) => Ok(health),
+            Ok(Err(_)) => Err(SystemError::ActorNotFound(*address.id())),
+            Err(_) => Err(SystemError::ProbeTimeout(wait)),
+        }
+    }
+
+    /// Deliver a signal on an actor's control lane.
+    fn send_control(
+        &self,
+        address: &ActorAddress,
+        signal: ControlSignal,
+    ) -> Result<(), SystemError> {
+        let actors = self.inner.actors.read();
+        let metadata = actors
+            .get(address)
+            .ok_or(SystemError::ActorNotFound(*address.id()))?;
+        metadata
+            .control_sender
+            .send(signal)
+            .map_err(|_| SystemError::ActorNotFound(*address.id()))
+    }
+
     /// Gracefully shutdown the system.
     ///
-    /// Waits for all actors to finish processing before returning.
+    /// Sends a stop signal on every actor's control lane, then waits for all
+    /// actors to finish before returning.
     pub async fn shutdown(&self) -> Result<(), SystemError> {
         // Set shutting down state
         {
@@ -189,6 +266,11 @@ impl<M: Message + serde::Serialize, B: MessageBroker<M> + Clone + Send + Sync +
             handle.abort();
         }
 
+        // Stop actors via control lane (ignore actors that already exited)
+        for metadata in self.inner.actors.read().values() {
+            let _ = metadata.control_sender.send(ControlSignal::Stop);
+        }
+
         // Wait for all actors to finish (with timeout)
         let timeout_duration = self.inner.config.shutdown_timeout;
         let result = timeout(timeout_duration, self.wait_for_actors()).await;
@@ -272,11 +354,19 @@ impl<M: Message + serde::Serialize, B: MessageBroker<M> + Clone + Send + Sync +
         // Create unbounded mailbox (bounded not yet supported in pub-sub)
         let (mailbox_sender, mailbox_receiver) = unbounded_channel();
 
+        // Control lane, drained ahead of the mailbox
+        let (control_sender, control_receiver) = unbounded_channel();
+
         // Create actor context
-        let context = ActorContext::new(address.clone(), self.inner.broker.clone());
+        let context = ActorContext::new(address.clone(), self.inner.broker.clone())
+            .with_services(self.inner.services.clone());
+
+        // Hold the registry lock across spawn + insert so the task cannot
+        // deregister itself before it has been registered
+        let mut actors = self.inner.actors.write();
 
         // Spawn actor task
-        let task_handle = self.spawn_actor_task(actor, mailbox_receiver, context);
+        let task_handle = self.spawn_actor_task(actor, mailbox_receiver, control_receiver, context);
 
         // Store metadata
         let metadata = ActorMetadata {
@@ -285,40 +375,107 @@ impl<M: Message + serde::Serialize, B: MessageBroker<M> + Clone + Send + Sync +
             name,
             spawned_at: Utc::now(),
             mailbox_sender,
+            control_sender,
             task_handle,
         };
 
-        self.inner.actors.write().insert(address.clone(), metadata);
+        actors.insert(address.clone(), metadata);
 
         Ok(address)
     }
 
     /// Spawn the actor task.
+    ///
+    /// The loop uses a biased select so the control lane is always drained
+    /// before the next user message, and messages unstashed by the actor are
+    /// redelivered before the next one is taken from the mailbox.
     fn spawn_actor_task<A>(
         &self,
         mut actor: A,
         mut mailbox_receiver: UnboundedReceiver<MessageEnvelope<M>>,
+        mut control_receiver: UnboundedReceiver<ControlSignal>,
         mut context: ActorContext<M, B>,
     ) -> JoinHandle<()>
     where
         A: Actor<Message = M> + Send + 'static,
     {
+        let inner = Arc::clone(&self.inner);
+
         spawn(async move {
             // Call pre_start lifecycle hook
             if let Err(error) = actor.pre_start(&mut context).await {
                 let action = actor.on_error(error, &mut context).await;
                 match action {
-                    ErrorAction::Stop | ErrorAction::Restart => return,
-                    ErrorAction::Escalate => return, // TODO: escalate to supervisor
-                    ErrorAction::Resume => {}        // Continue with message processing
+                    ErrorAction::Stop | ErrorAction::Restart => {
+                        inner.remove_actor(context.address());
+                        return;
+                    }
+                    ErrorAction::Escalate => {
+                        // TODO: escalate to supervisor
+                        inner.remove_actor(context.address());
+                        return;
+                    }
+                    ErrorAction::Resume => {} // Continue with message processing
                 }
             }
 
+            let mut messages_processed: u64 = 0;
+
             // Actor message loop
-            while let Some(envelope) = mailbox_receiver.recv().await {
+            loop {
+                let envelope = tokio::select! {
+                    biased;
+
+                    signal = control_receiver.recv() => {
+                        match signal {
+                            Some(ControlSignal::HealthProbe(reply)) => {
+                                let _ = reply.send(ActorHealth {
+                                    address: context.address().clone(),
+                                    messages_processed,
+                                    responded_at: Utc::now(),
+                                });
+                                continue;
+                            }
+                            // Stop requested, or control lane dropped with the actor entry
+                            Some(ControlSignal::Stop) | None => break,
+                        }
+                    }
+                    // Unstashed messages go ahead of anything newer in the mailbox
+                    _ = std::future::ready(()), if context.has_unstashed() => {
+                        match context.take_unstashed() {
+                            Some((message, deadline)) => {
+                                let mut envelope = MessageEnvelope::new(message);
+                                envelope.deadline = deadline;
+                                envelope
+                            }
+                            None => continue,
+                        }
+                    }
+                    envelope = mailbox_receiver.recv() => match envelope {
+                        Some(envelope) => envelope,
+                        None => break,
+                    },
+                };
+
+                // Skip work nobody is waiting for any more
+                if envelope.is_deadline_exceeded() {
+                    inner.deadline_exceeded.fetch_add(1, Ordering::Relaxed);
+                    continue;
+                }
+
+                let deadline = envelope.deadline;
+                let priority = envelope.priority;
                 let message = envelope.payload;
+                messages_processed += 1;
 
-                match actor.handle_message(message, &mut context).await {
+                // Nested sends/requests inherit this message's deadline and priority
+                context.set_deadline(deadline);
+                context.set_priority(priority);
+                let result = actor.handle_message(message, &mut context).await;
+                context.set_deadline(None);
+                context.set_priority(MessagePriority::Normal);
+
+                match result {
                     Ok(()) => {
                         // Message handled successfully
                     }
@@ -348,9 +505,71 @@ impl<M: Message + serde::Serialize, B: MessageBroker<M> + Clone + Send + Sync +
 
             // Call post_stop lifecycle hook
             let _ = actor.post_stop(&mut context).await;
+
+            inner.remove_actor(context.address());
         })
     }
 
+    /// Typed registry of the system's singleton services.
+    pub fn services(&self) -> &ServiceRegistry {
+        &self.inner.services
+    }
+
+    /// Register a running actor as the singleton for service `S`.
+    ///
+    /// The registration is dropped when the actor stops.
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::ActorNotFound` - No live actor has this address
+    /// - `SystemError::Service` - `S` already has an actor
+    pub fn register_service<S>(&self, address: ActorAddress) -> Result<Service<S>, SystemError>
+    where
+        S: ServiceKey<Message = M>,
+    {
+        // Hold the actor table so the actor cannot stop in between
+        let actors = self.inner.actors.read();
+        if !actors.contains_key(&address) {
+            return Err(SystemError::ActorNotFound(*address.id()));
+        }
+        Ok(self.inner.services.register::<S>(address)?)
+    }
+
+    /// Spawn `actor` under the service's name and register it for `S`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with `SystemError::Service` if `S` already has an actor (the
+    /// new actor is not spawned), or with any error of a regular spawn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let config = system.spawn_service::<ConfigStore, _>(ConfigStoreActor::default()).await?;
+    /// ```
+    pub async fn spawn_service<S, A>(&self, actor: A) -> Result<Service<S>, SystemError>
+    where
+        S: ServiceKey<Message = M>,
+        A: Actor<Message = M> + Send + 'static,
+    {
+        if let Ok(existing) = self.inner.services.resolve::<S>() {
+            return Err(ServiceError::AlreadyRegistered {
+                name: S::NAME,
+                address: existing.address().clone(),
+            }
+            .into());
+        }
+        let address = self.spawn().with_name(S::NAME).spawn(actor).await?;
+        match self.register_service::<S>(address.clone()) {
+            Ok(service) => Ok(service),
+            Err(err) => {
+                // Lost a race with another registration
+                let _ = self.stop_actor(&address);
+                Err(err)
+            }
+        }
+    }
+
     /// Create a builder for spawning actors.
     ///
     /// # Examples
@@ -462,4 +681,341 @@ mod tests {
         system.force_shutdown().await;
         assert_eq!(system.actor_count(), 0);
     }
+
+    struct SlowActor;
+
+    #[async_trait::async_trait]
+    impl Actor for SlowActor {
+        type Message = TestMessage;
+        type Error = std::io::Error;
+
+        async fn handle_message<B: crate::broker::MessageBroker<Self::Message>>(
+            &mut self,
+            _message: Self::Message,
+            _context: &mut ActorContext<Self::Message, B>,
+        ) -> Result<(), Self::Error> {
+            sleep(Duration::from_millis(20)).await;
+            Ok(())
+        }
+    }
+
+    /// Fill an actor's mailbox directly, bypassing the broker.
+    fn flood_mailbox(
+        system: &ActorSystem<TestMessage, InMemoryMessageBroker<TestMessage>>,
+        address: &ActorAddress,
+        count: usize,
+    ) {
+        let actors = system.inner.actors.read();
+        let metadata = actors.get(address).unwrap();
+        for i in 0..count {
+            metadata
+                .mailbox_sender
+                .send(MessageEnvelope::new(TestMessage {
+                    data: i.to_string(),
+                }))
+                .unwrap();
+        }
+    }
+
+    struct DeadlineActor {
+        seen: Arc<parking_lot::Mutex<Vec<Option<DateTime<Utc>>>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Actor for DeadlineActor {
+        type Message = TestMessage;
+        type Error = std::io::Error;
+
+        async fn handle_message<B: crate::broker::MessageBroker<Self::Message>>(
+            &mut self,
+            _message: Self::Message,
+            context: &mut ActorContext<Self::Message, B>,
+        ) -> Result<(), Self::Error> {
+            self.seen.lock().push(context.deadline());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_expired_messages_skipped_and_deadline_propagated() {
+        let broker = InMemoryMessageBroker::<TestMessage>::new();
+        let system = ActorSystem::new(SystemConfig::default(), broker);
+        let seen = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let address = system
+            .spawn_actor_internal(
+                DeadlineActor {
+                    seen: Arc::clone(&seen),
+                },
+                None,
+                100,
+            )
+            .await
+            .unwrap();
+
+        let future = Utc::now() + chrono::Duration::seconds(30);
+        {
+            let actors = system.inner.actors.read();
+            let metadata = actors.get(&address).unwrap();
+            let expired = MessageEnvelope::new(TestMessage {
+                data: "expired".to_string(),
+            })
+            .with_deadline(Utc::now() - chrono::Duration::seconds(1));
+            let live = MessageEnvelope::new(TestMessage {
+                data: "live".to_string(),
+            })
+            .with_deadline(future);
+            metadata.mailbox_sender.send(expired).unwrap();
+            metadata.mailbox_sender.send(live).unwrap();
+        }
+
+        for _ in 0..50 {
+            if !seen.lock().is_empty() {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+
+        assert_eq!(system.deadline_exceeded_count(), 1);
+        assert_eq!(*seen.lock(), vec![Some(future)]);
+    }
+
+    /// Stashes everything until it sees "open", then unstashes.
+    struct GateActor {
+        open: bool,
+        handled: Arc<parking_lot::Mutex<Vec<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Actor for GateActor {
+        type Message = TestMessage;
+        type Error = std::io::Error;
+
+        async fn handle_message<B: crate::broker::MessageBroker<Self::Message>>(
+            &mut self,
+            message: Self::Message,
+            context: &mut ActorContext<Self::Message, B>,
+        ) -> Result<(), Self::Error> {
+            if message.data == "open" {
+                self.open = true;
+                context.unstash_all();
+            } else if !self.open {
+                context
+                    .stash(message)
+                    .map_err(|e| std::io::Error::other(e.to_string()))?;
+                return Ok(());
+            }
+            self.handled.lock().push(message.data);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unstashed_messages_redelivered_before_mailbox() {
+        let broker = InMemoryMessageBroker::<TestMessage>::new();
+        let system = ActorSystem::new(SystemConfig::default(), broker);
+        let handled = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let address = system
+            .spawn_actor_internal(
+                GateActor {
+                    open: false,
+                    handled: Arc::clone(&handled),
+                },
+                None,
+                100,
+            )
+            .await
+            .unwrap();
+
+        {
+            let actors = system.inner.actors.read();
+            let metadata = actors.get(&address).unwrap();
+            for data in ["a", "b", "open", "c"] {
+                metadata
+                    .mailbox_sender
+                    .send(MessageEnvelope::new(TestMessage {
+                        data: data.to_string(),
+                    }))
+                    .unwrap();
+            }
+        }
+
+        for _ in 0..50 {
+            if handled.lock().len() == 4 {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+
+        assert_eq!(*handled.lock(), vec!["open", "a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn test_probe_answers_ahead_of_deep_mailbox() {
+        let broker = InMemoryMessageBroker::<TestMessage>::new();
+        let system = ActorSystem::new(SystemConfig::default(), broker);
+        let address = system
+            .spawn_actor_internal(SlowActor, None, 100)
+            .await
+            .unwrap();
+
+        // 500 messages x 20ms = 10s of queued work
+        flood_mailbox(&system, &address, 500);
+
+        let health = system
+            .probe_actor(&address, Duration::from_millis(500))
+            .await
+            .unwrap();
+        assert_eq!(health.address, address);
+        assert!(health.messages_processed < 500);
+
+        system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_stop_actor_skips_pending_messages() {
+        let broker = InMemoryMessageBroker::<TestMessage>::new();
+        let system = ActorSystem::new(SystemConfig::default(), broker);
+        let address = system
+            .spawn_actor_internal(SlowActor, None, 100)
+            .await
+            .unwrap();
+        flood_mailbox(&system, &address, 500);
+
+        system.stop_actor(&address).unwrap();
+
+        timeout(Duration::from_millis(500), system.wait_for_actors())
+            .await
+            .unwrap();
+        assert_eq!(system.actor_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_stops_busy_actors() {
+        let broker = InMemoryMessageBroker::<TestMessage>::new();
+        let system = ActorSystem::new(SystemConfig::default(), broker);
+        let address = system
+            .spawn_actor_internal(SlowActor, None, 100)
+            .await
+            .unwrap();
+        flood_mailbox(&system, &address, 500);
+
+        assert!(system.shutdown().await.is_ok());
+        assert_eq!(system.actor_count(), 0);
+    }
+
+    struct Journal;
+
+    impl ServiceKey for Journal {
+        type Message = TestMessage;
+        const NAME: &'static str = "journal";
+    }
+
+    /// Forwards every message to the `Journal` service.
+    struct ForwardActor;
+
+    #[async_trait::async_trait]
+    impl Actor for ForwardActor {
+        type Message = TestMessage;
+        type Error = std::io::Error;
+
+        async fn handle_message<B: crate::broker::MessageBroker<Self::Message>>(
+            &mut self,
+            message: Self::Message,
+            context: &mut ActorContext<Self::Message, B>,
+        ) -> Result<(), Self::Error> {
+            context
+                .send_to_service::<Journal>(message)
+                .await
+                .map_err(std::io::Error::other)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_service_resolved_from_actor_context() {
+        let broker = InMemoryMessageBroker::<TestMessage>::new();
+        let system = ActorSystem::new(SystemConfig::default(), broker);
+        let handled = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let journal = system
+            .spawn_service::<Journal, _>(GateActor {
+                open: true,
+                handled: Arc::clone(&handled),
+            })
+            .await
+            .unwrap();
+        assert_eq!(journal.address().name(), Some("journal"));
+
+        // Singleton: a second journal is refused and never spawned
+        let err = system
+            .spawn_service::<Journal, _>(TestActor)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            SystemError::Service(ServiceError::AlreadyRegistered { .. })
+        ));
+        assert_eq!(system.actor_count(), 1);
+
+        let forwarder = system
+            .spawn_actor_internal(ForwardActor, None, 100)
+            .await
+            .unwrap();
+        {
+            let actors = system.inner.actors.read();
+            let metadata = actors.get(&forwarder).unwrap();
+            metadata
+                .mailbox_sender
+                .send(MessageEnvelope::new(TestMessage {
+                    data: "hello".to_string(),
+                }))
+                .unwrap();
+        }
+        for _ in 0..50 {
+            if !handled.lock().is_empty() {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(*handled.lock(), vec!["hello"]);
+
+        // Stopping the service actor drops its registration
+        system.stop_actor(journal.address()).unwrap();
+        for _ in 0..50 {
+            if system.services().resolve::<Journal>().is_err() {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(
+            system.services().resolve::<Journal>().unwrap_err(),
+            ServiceError::NotRegistered("journal")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_register_service_requires_live_actor() {
+        let broker = InMemoryMessageBroker::<TestMessage>::new();
+        let system = ActorSystem::new(SystemConfig::default(), broker);
+        assert!(matches!(
+            system.register_service::<Journal>(ActorAddress::anonymous()),
+            Err(SystemError::ActorNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_control_unknown_actor() {
+        let broker = InMemoryMessageBroker::<TestMessage>::new();
+        let system = ActorSystem::new(SystemConfig::default(), broker);
+        let missing = ActorAddress::anonymous();
+
+        assert!(matches!(
+            system.stop_actor(&missing),
+            Err(SystemError::ActorNotFound(_))
+        ));
+        assert!(matches!(
+            system
+                .probe_actor(&missing, Duration::from_millis(10))
+                .await,
+            Err(SystemError::ActorNotFound(_))
+        ));
+    }
 }
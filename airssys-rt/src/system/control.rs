@@ -0,0 +1,41 @@
+//! Control lane signals for actor tasks.
+//!
+//! Every actor spawned by [`ActorSystem`](super::ActorSystem) owns two queues:
+//! the regular mailbox for user messages and a separate control lane for
+//! runtime signals. The actor loop always drains the control lane first, so a
+//! stop request or health probe is handled after at most the message that is
+//! currently being processed, regardless of mailbox depth.
+
+// Layer 1: Standard library
+// (none)
+
+// Layer 2: Third-party
+use chrono::{DateTime, Utc};
+use tokio::sync::oneshot;
+
+// Layer 3: Internal
+use crate::util::ActorAddress;
+
+/// Runtime signal delivered on an actor's control lane.
+#[derive(Debug)]
+pub enum ControlSignal {
+    /// Stop the actor after the in-flight message; pending mailbox messages
+    /// are discarded and `post_stop` is called.
+    Stop,
+
+    /// Request a health snapshot from the actor task.
+    HealthProbe(oneshot::Sender<ActorHealth>),
+}
+
+/// Health snapshot returned by an actor in response to a probe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActorHealth {
+    /// Address of the probed actor.
+    pub address: ActorAddress,
+
+    /// User messages handled since the actor started.
+    pub messages_processed: u64,
+
+    /// When the actor task answered the probe (§3.2).
+    pub responded_at: DateTime<Utc>,
+}
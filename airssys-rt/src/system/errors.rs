@@ -7,6 +7,7 @@ use std::time::Duration;
 use thiserror::Error;
 
 // Layer 3: Internal
+use crate::actor::ServiceError;
 use crate::broker::BrokerError;
 use crate::util::ActorId;
 
@@ -47,6 +48,14 @@ pub enum SystemError {
     /// Shutdown timeout exceeded
     #[error("Shutdown timeout exceeded after {0:?}")]
     ShutdownTimeout(Duration),
+
+    /// Actor did not answer a control-lane health probe in time
+    #[error("Health probe timed out after {0:?}")]
+    ProbeTimeout(Duration),
+
+    /// Service registration or lookup failed
+    #[error("Service error: {0}")]
+    Service(#[from] ServiceError),
 }
 
 impl SystemError {
@@ -55,7 +64,10 @@ impl SystemError {
     /// Transient errors are temporary conditions that may resolve
     /// with retry logic (e.g., mailbox full).
     pub fn is_transient(&self) -> bool {
-        matches!(self, SystemError::MailboxFull(_))
+        matches!(
+            self,
+            SystemError::MailboxFull(_) | SystemError::ProbeTimeout(_)
+        )
     }
 
     /// Check if error is fatal (system must stop).
@@ -147,6 +159,10 @@ mod tests {
 
         let spawn_err = SystemError::SpawnFailed("error".to_string());
         assert!(!spawn_err.is_transient());
+
+        let probe_err = SystemError::ProbeTimeout(Duration::from_millis(50));
+        assert!(probe_err.is_transient());
+        assert!(!probe_err.is_fatal());
     }
 
     #[test]
@@ -177,6 +193,16 @@ mod tests {
         assert!(matches!(system_err, SystemError::BrokerError(_)));
     }
 
+    #[test]
+    fn test_service_error_conversion() {
+        let system_err: SystemError = ServiceError::NotRegistered("config-store").into();
+        assert_eq!(
+            system_err.to_string(),
+            "Service error: Service not registered: config-store"
+        );
+        assert!(system_err.is_recoverable());
+    }
+
     #[test]
     fn test_error_debug_impl() {
         let err = SystemError::SpawnFailed("test".to_string());
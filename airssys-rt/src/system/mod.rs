@@ -5,6 +5,7 @@
 pub mod actor_system;
 pub mod builder;
 pub mod config;
+pub mod control;
 pub mod errors;
 
 // Re-exports
@@ -14,4 +15,5 @@ pub use config::{
     SystemConfig, DEFAULT_ENABLE_METRICS, DEFAULT_MAILBOX_CAPACITY, DEFAULT_MAX_ACTORS,
     DEFAULT_SHUTDOWN_TIMEOUT, DEFAULT_SPAWN_TIMEOUT,
 };
+pub use control::{ActorHealth, ControlSignal};
 pub use errors::SystemError;
@@ -4,4 +4,4 @@ pub mod ids;
 pub mod serde_helpers;
 
 pub use ids::{ActorAddress, ActorId, MessageId};
-pub use serde_helpers::duration_serde;
+pub use serde_helpers::{duration_serde, optional_duration_serde};
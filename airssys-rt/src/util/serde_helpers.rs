@@ -26,6 +26,30 @@ pub mod duration_serde {
     }
 }
 
+/// Serde serialization module for `Option<Duration>` as seconds.
+///
+/// Use together with `#[serde(default)]` so absent fields become `None`.
+pub mod optional_duration_serde {
+    use super::*;
+
+    /// Serializes `Option<Duration>` as optional seconds (u64).
+    pub fn serialize<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        duration.map(|d| d.as_secs()).serialize(serializer)
+    }
+
+    /// Deserializes `Option<Duration>` from optional seconds (u64).
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let secs = Option::<u64>::deserialize(deserializer)?;
+        Ok(secs.map(Duration::from_secs))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,4 +87,22 @@ mod tests {
             serde_json::from_str(&json).expect("Deserialization should succeed");
         assert_eq!(test.duration, deserialized.duration);
     }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct OptionalStruct {
+        #[serde(default, with = "optional_duration_serde")]
+        timeout: Option<Duration>,
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_optional_duration_serde() {
+        let some: OptionalStruct =
+            serde_json::from_str(r#"{"timeout":5}"#).expect("Deserialization should succeed");
+        assert_eq!(some.timeout, Some(Duration::from_secs(5)));
+
+        let none: OptionalStruct =
+            serde_json::from_str("{}").expect("Deserialization should succeed");
+        assert_eq!(none.timeout, None);
+    }
 }
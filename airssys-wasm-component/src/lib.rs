@@ -0,0 +1,35 @@
+//! Guest-side helpers for building airssys-wasm components.
+//!
+//! Components talk to the host through the `airssys:core` WIT interfaces.
+//! This crate wraps those byte-level calls in typed, ergonomic APIs so guest
+//! authors don't handcraft keys, encodings, and call sequences.
+//!
+//! # Features
+//!
+//! - `persistence`: [`persistence::PersistentCell`] and
+//!   [`persistence::PersistentMap`] over the host `storage` interface, with
+//!   serialization, namespacing, and write-batching
+//! - `requests`: [`requests::tracker::RequestTracker`] over the host
+//!   `host-messaging` interface, matching callbacks to pending requests
+//!
+//! # Targets
+//!
+//! Host bindings are generated only for `wasm32` targets. On other targets
+//! the helpers run against in-memory hosts
+//! ([`persistence::store::MemoryStorage`],
+//! [`requests::transport::MemoryMessaging`]), which is how they are unit
+//! tested.
+
+#[cfg(feature = "persistence")]
+pub mod persistence;
+#[cfg(feature = "requests")]
+pub mod requests;
+
+/// Guest bindings for the `airssys:core` WIT package.
+#[cfg(target_arch = "wasm32")]
+mod bindings {
+    wit_bindgen::generate!({
+        world: "runtime-host",
+        path: "../airssys-wasm/wit/core",
+    });
+}
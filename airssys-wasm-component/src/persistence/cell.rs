@@ -0,0 +1,184 @@
+//! A single persisted value.
+
+// Layer 1: Standard library imports
+use std::rc::Rc;
+
+// Layer 2: Third-party crate imports
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+// Layer 3: Internal module imports
+use super::errors::PersistenceError;
+use super::store::HostStorage;
+use super::{decode, encode, namespace_prefix};
+
+/// A typed value persisted under `{namespace}/{name}`.
+///
+/// The value is read from the host on first access and cached. Changes stay
+/// local until [`flush`](Self::flush) (or drop), so a handler that updates a
+/// cell many times costs one host write.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+/// use airssys_wasm_component::persistence::cell::PersistentCell;
+/// use airssys_wasm_component::persistence::store::MemoryStorage;
+///
+/// let storage = Rc::new(MemoryStorage::new());
+/// {
+///     let mut name = PersistentCell::new(Rc::clone(&storage), "profile", "name").unwrap();
+///     name.set("ada".to_string());
+/// } // flushed on drop
+///
+/// let mut name = PersistentCell::<String, _>::new(storage, "profile", "name").unwrap();
+/// assert_eq!(name.get().unwrap().map(String::as_str), Some("ada"));
+/// ```
+pub struct PersistentCell<T, S>
+where
+    T: Serialize + DeserializeOwned,
+    S: HostStorage,
+{
+    storage: Rc<S>,
+    key: String,
+    /// Whether `value` reflects the host (or a local change).
+    loaded: bool,
+    value: Option<T>,
+    dirty: bool,
+}
+
+impl<T, S> PersistentCell<T, S>
+where
+    T: Serialize + DeserializeOwned,
+    S: HostStorage,
+{
+    /// Creates a cell for `name` within `namespace`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::InvalidNamespace` if the namespace is empty
+    /// or contains `/`.
+    pub fn new(storage: Rc<S>, namespace: &str, name: &str) -> Result<Self, PersistenceError> {
+        Ok(Self {
+            storage,
+            key: format!("{}{name}", namespace_prefix(namespace)?),
+            loaded: false,
+            value: None,
+            dirty: false,
+        })
+    }
+
+    /// Returns the storage key of this cell.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the current value, loading it from the host on first access.
+    ///
+    /// # Errors
+    ///
+    /// Returns a storage or codec error if the value cannot be loaded.
+    pub fn get(&mut self) -> Result<Option<&T>, PersistenceError> {
+        if !self.loaded {
+            self.value = match self.storage.get(&self.key)? {
+                Some(bytes) => Some(decode(&bytes)?),
+                None => None,
+            };
+            self.loaded = true;
+        }
+        Ok(self.value.as_ref())
+    }
+
+    /// Replaces the value (written on flush).
+    pub fn set(&mut self, value: T) {
+        self.value = Some(value);
+        self.loaded = true;
+        self.dirty = true;
+    }
+
+    /// Removes the value (deleted on flush).
+    pub fn clear(&mut self) {
+        self.value = None;
+        self.loaded = true;
+        self.dirty = true;
+    }
+
+    /// Replaces the value with `f(current)` and returns a reference to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a storage or codec error if the current value cannot be loaded.
+    pub fn update(&mut self, f: impl FnOnce(Option<&T>) -> T) -> Result<&T, PersistenceError> {
+        let next = f(self.get()?);
+        self.dirty = true;
+        Ok(self.value.insert(next))
+    }
+
+    /// Returns true if there are unflushed changes.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Writes unflushed changes to the host.
+    ///
+    /// # Errors
+    ///
+    /// Returns a storage or codec error; the change stays pending on failure.
+    pub fn flush(&mut self) -> Result<(), PersistenceError> {
+        if !self.dirty {
+            return Ok(());
+        }
+        match &self.value {
+            Some(value) => self.storage.set(&self.key, &encode(value)?)?,
+            None => self.storage.delete(&self.key)?,
+        }
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+impl<T, S> Drop for PersistentCell<T, S>
+where
+    T: Serialize + DeserializeOwned,
+    S: HostStorage,
+{
+    fn drop(&mut self) {
+        // Best effort: callers who need the error call flush() explicitly
+        let _ = self.flush();
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::persistence::store::MemoryStorage;
+
+    #[test]
+    fn test_cell_caches_and_flushes_once() {
+        let storage = Rc::new(MemoryStorage::new());
+        let mut counter = PersistentCell::<u32, _>::new(Rc::clone(&storage), "stats", "n").unwrap();
+        assert_eq!(counter.get().unwrap(), None);
+
+        for _ in 0..10 {
+            counter.update(|n| n.copied().unwrap_or(0) + 1).unwrap();
+        }
+        assert!(counter.is_dirty());
+        counter.flush().unwrap();
+        assert_eq!(storage.write_calls(), 1);
+        assert_eq!(storage.raw("stats/n").unwrap(), b"10");
+
+        counter.clear();
+        counter.flush().unwrap();
+        assert!(storage.raw("stats/n").is_none());
+    }
+
+    #[test]
+    fn test_cell_rejects_undecodable_value() {
+        let storage = Rc::new(MemoryStorage::new());
+        storage.set("stats/n", b"not json").unwrap();
+
+        let mut counter = PersistentCell::<u32, _>::new(storage, "stats", "n").unwrap();
+        assert!(matches!(counter.get(), Err(PersistenceError::Codec(_))));
+    }
+}
@@ -0,0 +1,29 @@
+//! Key namespacing and value encoding shared by cells and maps.
+
+// Layer 2: Third-party crate imports
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+// Layer 3: Internal module imports
+use super::errors::PersistenceError;
+
+/// Separator between a namespace and the key inside it.
+pub const NAMESPACE_SEPARATOR: char = '/';
+
+/// Checks a namespace and returns its key prefix (`"{namespace}/"`).
+pub(crate) fn namespace_prefix(namespace: &str) -> Result<String, PersistenceError> {
+    if namespace.is_empty() || namespace.contains(NAMESPACE_SEPARATOR) {
+        return Err(PersistenceError::InvalidNamespace(namespace.to_string()));
+    }
+    Ok(format!("{namespace}{NAMESPACE_SEPARATOR}"))
+}
+
+/// Encodes a value as JSON.
+pub(crate) fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, PersistenceError> {
+    serde_json::to_vec(value).map_err(|e| PersistenceError::Codec(e.to_string()))
+}
+
+/// Decodes a JSON value written by [`encode`].
+pub(crate) fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, PersistenceError> {
+    serde_json::from_slice(bytes).map_err(|e| PersistenceError::Codec(e.to_string()))
+}
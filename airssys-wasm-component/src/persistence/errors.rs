@@ -0,0 +1,20 @@
+//! Persistence error types.
+
+// Layer 2: Third-party crate imports
+use thiserror::Error;
+
+/// Errors from typed persistence helpers.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum PersistenceError {
+    /// The host storage call failed.
+    #[error("Host storage error: {0}")]
+    Storage(String),
+
+    /// A value could not be encoded or decoded.
+    #[error("Value encoding error: {0}")]
+    Codec(String),
+
+    /// Namespaces must be non-empty and must not contain `/`.
+    #[error("Invalid namespace: '{0}'")]
+    InvalidNamespace(String),
+}
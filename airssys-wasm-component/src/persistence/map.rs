@@ -0,0 +1,309 @@
+//! A persisted keyed collection.
+
+// Layer 1: Standard library imports
+use std::collections::{BTreeMap, BTreeSet};
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+// Layer 2: Third-party crate imports
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+// Layer 3: Internal module imports
+use super::errors::PersistenceError;
+use super::store::HostStorage;
+use super::{decode, encode, namespace_prefix};
+
+/// Map keys that can be embedded in a storage key.
+pub trait MapKey: Sized {
+    /// Encodes the key as a string.
+    fn encode_key(&self) -> String;
+
+    /// Decodes a key produced by [`encode_key`](Self::encode_key).
+    fn decode_key(encoded: &str) -> Option<Self>;
+}
+
+impl MapKey for String {
+    fn encode_key(&self) -> String {
+        self.clone()
+    }
+
+    fn decode_key(encoded: &str) -> Option<Self> {
+        Some(encoded.to_string())
+    }
+}
+
+macro_rules! impl_map_key_for_int {
+    ($($ty:ty),*) => {
+        $(
+            impl MapKey for $ty {
+                fn encode_key(&self) -> String {
+                    self.to_string()
+                }
+
+                fn decode_key(encoded: &str) -> Option<Self> {
+                    encoded.parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_map_key_for_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+/// A typed map persisted as one host key per entry, under `{namespace}/`.
+///
+/// Inserts and removals are buffered and written in one batch when
+/// `batch_size` changes are pending, on [`flush`](Self::flush), or on drop.
+/// Reads see buffered changes. Values are not cached once flushed, so memory
+/// stays bounded however large the map grows on the host.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+/// use airssys_wasm_component::persistence::map::PersistentMap;
+/// use airssys_wasm_component::persistence::store::MemoryStorage;
+///
+/// let storage = Rc::new(MemoryStorage::new());
+/// let mut scores = PersistentMap::<String, u32, _>::new(storage, "scores").unwrap();
+///
+/// scores.insert(&"ada".to_string(), &3).unwrap();
+/// scores.insert(&"alan".to_string(), &5).unwrap();
+/// assert_eq!(scores.get(&"ada".to_string()).unwrap(), Some(3));
+///
+/// scores.flush().unwrap();
+/// assert_eq!(scores.keys().unwrap(), vec!["ada".to_string(), "alan".to_string()]);
+/// ```
+pub struct PersistentMap<K, V, S>
+where
+    K: MapKey,
+    V: Serialize + DeserializeOwned,
+    S: HostStorage,
+{
+    storage: Rc<S>,
+    prefix: String,
+    /// Encoded bytes per storage key; `None` = pending delete.
+    pending: BTreeMap<String, Option<Vec<u8>>>,
+    batch_size: usize,
+    _types: PhantomData<(K, V)>,
+}
+
+impl<K, V, S> PersistentMap<K, V, S>
+where
+    K: MapKey,
+    V: Serialize + DeserializeOwned,
+    S: HostStorage,
+{
+    /// Default number of pending changes that triggers a flush.
+    pub const DEFAULT_BATCH_SIZE: usize = 32;
+
+    /// Creates a map stored under `namespace`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::InvalidNamespace` if the namespace is empty
+    /// or contains `/`.
+    pub fn new(storage: Rc<S>, namespace: &str) -> Result<Self, PersistenceError> {
+        Ok(Self {
+            storage,
+            prefix: namespace_prefix(namespace)?,
+            pending: BTreeMap::new(),
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+            _types: PhantomData,
+        })
+    }
+
+    /// Sets the number of pending changes that triggers a flush.
+    ///
+    /// A batch size of 0 or 1 writes every change immediately.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Returns the value for `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a storage or codec error if the value cannot be loaded.
+    pub fn get(&self, key: &K) -> Result<Option<V>, PersistenceError> {
+        let storage_key = self.storage_key(key);
+        let bytes = match self.pending.get(&storage_key) {
+            Some(pending) => pending.clone(),
+            None => self.storage.get(&storage_key)?,
+        };
+        bytes.map(|bytes| decode(&bytes)).transpose()
+    }
+
+    /// Returns true if `key` has a value.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`get`](Self::get).
+    pub fn contains_key(&self, key: &K) -> Result<bool, PersistenceError> {
+        let storage_key = self.storage_key(key);
+        match self.pending.get(&storage_key) {
+            Some(pending) => Ok(pending.is_some()),
+            None => Ok(self.storage.get(&storage_key)?.is_some()),
+        }
+    }
+
+    /// Inserts or replaces the value for `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a codec error, or a storage error if this insert fills the
+    /// batch and the flush fails.
+    pub fn insert(&mut self, key: &K, value: &V) -> Result<(), PersistenceError> {
+        let bytes = encode(value)?;
+        self.pending.insert(self.storage_key(key), Some(bytes));
+        self.flush_if_full()
+    }
+
+    /// Removes the value for `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a storage error if this removal fills the batch and the flush
+    /// fails.
+    pub fn remove(&mut self, key: &K) -> Result<(), PersistenceError> {
+        self.pending.insert(self.storage_key(key), None);
+        self.flush_if_full()
+    }
+
+    /// Returns every key in the map, including unflushed inserts.
+    ///
+    /// Keys that cannot be decoded as `K` are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a storage error if the host cannot list keys.
+    pub fn keys(&self) -> Result<Vec<K>, PersistenceError> {
+        let mut keys: BTreeSet<String> =
+            self.storage.list_keys(&self.prefix)?.into_iter().collect();
+        for (storage_key, pending) in &self.pending {
+            match pending {
+                Some(_) => keys.insert(storage_key.clone()),
+                None => keys.remove(storage_key),
+            };
+        }
+        Ok(keys
+            .iter()
+            .filter_map(|key| key.strip_prefix(&self.prefix))
+            .filter_map(K::decode_key)
+            .collect())
+    }
+
+    /// Returns the number of unflushed changes.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Writes all unflushed changes to the host.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first storage error; changes not yet written stay pending.
+    pub fn flush(&mut self) -> Result<(), PersistenceError> {
+        while let Some((storage_key, change)) = self.pending.pop_first() {
+            let written = match &change {
+                Some(bytes) => self.storage.set(&storage_key, bytes),
+                None => self.storage.delete(&storage_key),
+            };
+            if let Err(e) = written {
+                self.pending.insert(storage_key, change);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    fn flush_if_full(&mut self) -> Result<(), PersistenceError> {
+        if self.pending.len() >= self.batch_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn storage_key(&self, key: &K) -> String {
+        format!("{}{}", self.prefix, key.encode_key())
+    }
+}
+
+impl<K, V, S> Drop for PersistentMap<K, V, S>
+where
+    K: MapKey,
+    V: Serialize + DeserializeOwned,
+    S: HostStorage,
+{
+    fn drop(&mut self) {
+        // Best effort: callers who need the error call flush() explicitly
+        let _ = self.flush();
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::persistence::store::MemoryStorage;
+
+    #[test]
+    fn test_writes_are_batched() {
+        let storage = Rc::new(MemoryStorage::new());
+        let mut map = PersistentMap::<u32, String, _>::new(Rc::clone(&storage), "items")
+            .unwrap()
+            .with_batch_size(3);
+
+        map.insert(&1, &"a".to_string()).unwrap();
+        map.insert(&1, &"b".to_string()).unwrap();
+        map.insert(&2, &"c".to_string()).unwrap();
+        assert_eq!(storage.write_calls(), 0);
+        assert_eq!(map.get(&1).unwrap().as_deref(), Some("b"));
+
+        // Third distinct change fills the batch
+        map.remove(&3).unwrap();
+        assert_eq!(storage.write_calls(), 3);
+        assert_eq!(map.pending(), 0);
+        assert_eq!(storage.raw("items/1").unwrap(), br#""b""#);
+    }
+
+    #[test]
+    fn test_keys_merge_pending_changes() {
+        let storage = Rc::new(MemoryStorage::new());
+        let mut map = PersistentMap::<u64, u64, _>::new(Rc::clone(&storage), "ids").unwrap();
+        map.insert(&1, &10).unwrap();
+        map.insert(&2, &20).unwrap();
+        map.flush().unwrap();
+
+        map.remove(&1).unwrap();
+        map.insert(&3, &30).unwrap();
+        assert_eq!(map.keys().unwrap(), vec![2, 3]);
+        assert!(!map.contains_key(&1).unwrap());
+        assert!(storage.raw("ids/1").is_some());
+
+        drop(map);
+        assert!(storage.raw("ids/1").is_none());
+        assert!(storage.raw("ids/3").is_some());
+    }
+
+    #[test]
+    fn test_namespaces_are_isolated() {
+        let storage = Rc::new(MemoryStorage::new());
+        let mut a = PersistentMap::<String, u8, _>::new(Rc::clone(&storage), "a").unwrap();
+        let mut ab = PersistentMap::<String, u8, _>::new(Rc::clone(&storage), "ab").unwrap();
+        a.insert(&"k".to_string(), &1).unwrap();
+        ab.insert(&"k".to_string(), &2).unwrap();
+        a.flush().unwrap();
+        ab.flush().unwrap();
+
+        assert_eq!(a.keys().unwrap(), vec!["k".to_string()]);
+        assert_eq!(ab.get(&"k".to_string()).unwrap(), Some(2));
+
+        assert_eq!(
+            PersistentMap::<String, u8, _>::new(Rc::clone(&storage), "a/b").err(),
+            Some(PersistenceError::InvalidNamespace("a/b".to_string()))
+        );
+    }
+}
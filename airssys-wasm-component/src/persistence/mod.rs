@@ -0,0 +1,48 @@
+//! Typed state persistence over host storage.
+//!
+//! The host `storage` interface stores raw bytes under string keys within the
+//! component's own namespace. This module layers typed values on top:
+//!
+//! - [`cell::PersistentCell<T, S>`] - a single value
+//! - [`map::PersistentMap<K, V, S>`] - a keyed collection
+//!
+//! Values are encoded as JSON. Keys are namespaced as `{namespace}/{name}` so
+//! several cells and maps can share the component's storage without
+//! colliding. Writes are buffered and sent to the host on `flush()`, when a
+//! map's batch fills up, or on drop.
+//!
+//! # Submodules
+//!
+//! - [`store`] - `HostStorage` trait, `MemoryStorage`, and (on wasm32) `WitStorage`
+//! - [`cell`] - `PersistentCell`
+//! - [`map`] - `PersistentMap` and the `MapKey` trait
+//! - [`errors`] - `PersistenceError`
+//! - `codec` - key namespacing and JSON value encoding
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::rc::Rc;
+//! use airssys_wasm_component::persistence::cell::PersistentCell;
+//! use airssys_wasm_component::persistence::store::MemoryStorage;
+//!
+//! let storage = Rc::new(MemoryStorage::new());
+//! let mut visits = PersistentCell::<u64, _>::new(Rc::clone(&storage), "stats", "visits").unwrap();
+//!
+//! visits.update(|n| n.copied().unwrap_or(0) + 1).unwrap();
+//! visits.flush().unwrap();
+//!
+//! assert_eq!(storage.raw("stats/visits").as_deref(), Some(&b"1"[..]));
+//! ```
+
+// Module declarations (per PROJECTS_STANDARD.md §4.3)
+pub mod cell;
+mod codec;
+pub mod errors;
+pub mod map;
+pub mod store;
+
+// NOTE: No glob re-exports per module grouping policy.
+// Callers use namespaced access: persistence::cell::PersistentCell
+pub use codec::NAMESPACE_SEPARATOR;
+pub(crate) use codec::{decode, encode, namespace_prefix};
@@ -0,0 +1,121 @@
+//! Byte-level storage backends for the persistence helpers.
+
+// Layer 1: Standard library imports
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+
+// Layer 3: Internal module imports
+use super::errors::PersistenceError;
+#[cfg(target_arch = "wasm32")]
+use crate::bindings;
+
+/// Byte-level key-value storage, as exposed by the host `storage` interface.
+///
+/// Keys are already scoped to the calling component by the host; the
+/// persistence helpers add their own namespace prefix on top.
+pub trait HostStorage {
+    /// Returns the value stored under `key`.
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, PersistenceError>;
+
+    /// Stores `value` under `key`.
+    fn set(&self, key: &str, value: &[u8]) -> Result<(), PersistenceError>;
+
+    /// Deletes `key`; deleting a missing key is not an error.
+    fn delete(&self, key: &str) -> Result<(), PersistenceError>;
+
+    /// Lists keys starting with `prefix`.
+    fn list_keys(&self, prefix: &str) -> Result<Vec<String>, PersistenceError>;
+}
+
+/// In-memory storage for native builds and tests.
+///
+/// Counts write calls (`set` and `delete`) so batching behaviour can be
+/// observed.
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    entries: RefCell<BTreeMap<String, Vec<u8>>>,
+    writes: Cell<usize>,
+}
+
+impl MemoryStorage {
+    /// Creates empty storage.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the raw bytes under `key`, bypassing any helper.
+    pub fn raw(&self, key: &str) -> Option<Vec<u8>> {
+        self.entries.borrow().get(key).cloned()
+    }
+
+    /// Returns the number of `set` and `delete` calls made so far.
+    pub fn write_calls(&self) -> usize {
+        self.writes.get()
+    }
+}
+
+impl HostStorage for MemoryStorage {
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, PersistenceError> {
+        Ok(self.raw(key))
+    }
+
+    fn set(&self, key: &str, value: &[u8]) -> Result<(), PersistenceError> {
+        self.writes.set(self.writes.get() + 1);
+        self.entries
+            .borrow_mut()
+            .insert(key.to_string(), value.to_vec());
+        Ok(())
+    }
+
+    fn delete(&self, key: &str) -> Result<(), PersistenceError> {
+        self.writes.set(self.writes.get() + 1);
+        self.entries.borrow_mut().remove(key);
+        Ok(())
+    }
+
+    fn list_keys(&self, prefix: &str) -> Result<Vec<String>, PersistenceError> {
+        Ok(self
+            .entries
+            .borrow()
+            .keys()
+            .filter(|key| key.starts_with(prefix))
+            .cloned()
+            .collect())
+    }
+}
+
+/// Storage backed by the host `airssys:core/storage` interface.
+///
+/// Available when compiling the guest for `wasm32`.
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WitStorage;
+
+#[cfg(target_arch = "wasm32")]
+impl WitStorage {
+    fn error(err: bindings::airssys::core::errors::StorageError) -> PersistenceError {
+        PersistenceError::Storage(format!("{err:?}"))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl HostStorage for WitStorage {
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, PersistenceError> {
+        bindings::airssys::core::storage::get(key).map_err(Self::error)
+    }
+
+    fn set(&self, key: &str, value: &[u8]) -> Result<(), PersistenceError> {
+        bindings::airssys::core::storage::set(key, value).map_err(Self::error)
+    }
+
+    fn delete(&self, key: &str) -> Result<(), PersistenceError> {
+        match bindings::airssys::core::storage::delete(key) {
+            Err(bindings::airssys::core::errors::StorageError::NotFound(_)) => Ok(()),
+            other => other.map_err(Self::error),
+        }
+    }
+
+    fn list_keys(&self, prefix: &str) -> Result<Vec<String>, PersistenceError> {
+        bindings::airssys::core::storage::list_keys(Some(prefix)).map_err(Self::error)
+    }
+}
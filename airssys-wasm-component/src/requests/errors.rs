@@ -0,0 +1,16 @@
+//! Request tracking error types.
+
+// Layer 2: Third-party crate imports
+use thiserror::Error;
+
+/// Errors from request-response helpers.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum RequestError {
+    /// The host messaging call failed.
+    #[error("Host messaging error: {0}")]
+    Messaging(String),
+
+    /// No request with this correlation ID is pending.
+    #[error("Unknown correlation ID: '{0}'")]
+    UnknownCorrelation(String),
+}
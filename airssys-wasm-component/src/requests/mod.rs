@@ -0,0 +1,50 @@
+//! Request-response helpers over host messaging.
+//!
+//! The host `request` call returns a correlation ID; the response arrives
+//! later as a separate `handle-callback` invocation carrying that ID. This
+//! module keeps the bookkeeping inside the component:
+//!
+//! - [`tracker::RequestTracker`] - sends requests, remembers what to do with
+//!   each response, and routes callbacks to it
+//! - [`transport`] - `HostMessaging` trait, `MemoryMessaging`, and (on
+//!   wasm32) `WitMessaging`
+//! - [`errors`] - `RequestError`
+//!
+//! Callbacks that arrive before a handler is attached are buffered and
+//! handed over when it is, so a guest never has to maintain its own
+//! correlation map.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//! use airssys_wasm_component::requests::tracker::{CallbackOutcome, RequestTracker};
+//! use airssys_wasm_component::requests::transport::{ComponentAddress, MemoryMessaging};
+//!
+//! let messaging = Rc::new(MemoryMessaging::new());
+//! let mut requests = RequestTracker::new(Rc::clone(&messaging));
+//! let prices = ComponentAddress::new("acme", "prices", "v1");
+//!
+//! // In handle-message: ask another component and say what to do with the answer
+//! let answer = Rc::new(RefCell::new(None));
+//! let slot = Rc::clone(&answer);
+//! requests
+//!     .send_request(&prices, b"EURUSD", 1_000)
+//!     .unwrap()
+//!     .await_response(move |response| *slot.borrow_mut() = Some(response));
+//!
+//! // In handle-callback: route the response
+//! let correlation_id = messaging.sent()[0].correlation_id.clone();
+//! let outcome = requests.on_callback(Some(&correlation_id), b"1.08".to_vec());
+//! assert_eq!(outcome, CallbackOutcome::Handled);
+//! assert_eq!(answer.borrow().as_deref(), Some(&b"1.08"[..]));
+//! ```
+
+// Module declarations (per PROJECTS_STANDARD.md §4.3)
+pub mod errors;
+pub mod tracker;
+pub mod transport;
+
+// NOTE: No glob re-exports per module grouping policy.
+// Callers use namespaced access: requests::tracker::RequestTracker
@@ -0,0 +1,320 @@
+//! Pending request tracking.
+
+// Layer 1: Standard library imports
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::rc::Rc;
+
+// Layer 3: Internal module imports
+use super::errors::RequestError;
+use super::transport::{ComponentAddress, HostMessaging};
+
+/// What to do with a response payload.
+type ResponseHandler = Box<dyn FnOnce(Vec<u8>)>;
+
+/// What [`RequestTracker::on_callback`] did with a callback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallbackOutcome {
+    /// The response was passed to its request's handler.
+    Handled,
+
+    /// The request has no handler yet; the response is kept until one is
+    /// attached or it is taken with [`RequestTracker::take_response`].
+    Buffered,
+
+    /// The callback does not answer a tracked request (e.g. a stream
+    /// chunk); the payload is handed back.
+    Unmatched(Vec<u8>),
+}
+
+/// Sends requests and matches callbacks to them by correlation ID.
+///
+/// Each request either gets a response handler right away
+/// ([`PendingRequest::await_response`]) or is detached and handled later.
+/// Handlers run once, from [`on_callback`](Self::on_callback), which the
+/// guest calls from its `handle-callback` export.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+/// use airssys_wasm_component::requests::tracker::{CallbackOutcome, RequestTracker};
+/// use airssys_wasm_component::requests::transport::{ComponentAddress, MemoryMessaging};
+///
+/// let mut requests = RequestTracker::new(Rc::new(MemoryMessaging::new()));
+/// let geo = ComponentAddress::new("acme", "geo", "v1");
+///
+/// // Fire off a lookup and pick the answer up later
+/// let id = requests.send_request(&geo, b"10.0.0.1", 500).unwrap().detach();
+/// assert_eq!(requests.on_callback(Some(&id), b"NL".to_vec()), CallbackOutcome::Buffered);
+/// assert_eq!(requests.take_response(&id), Some(b"NL".to_vec()));
+/// assert_eq!(requests.pending(), 0);
+/// ```
+pub struct RequestTracker<M: HostMessaging> {
+    messaging: Rc<M>,
+    handlers: HashMap<String, ResponseHandler>,
+    /// Sent requests without a handler.
+    detached: HashSet<String>,
+    /// Responses to detached requests.
+    buffered: HashMap<String, Vec<u8>>,
+}
+
+impl<M: HostMessaging> RequestTracker<M> {
+    /// Creates a tracker sending through `messaging`.
+    pub fn new(messaging: Rc<M>) -> Self {
+        Self {
+            messaging,
+            handlers: HashMap::new(),
+            detached: HashSet::new(),
+            buffered: HashMap::new(),
+        }
+    }
+
+    /// Sends `payload` to `target` as a request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RequestError::Messaging` if the host refuses the request.
+    pub fn send_request(
+        &mut self,
+        target: &ComponentAddress,
+        payload: &[u8],
+        timeout_ms: u64,
+    ) -> Result<PendingRequest<'_, M>, RequestError> {
+        let correlation_id = self.messaging.request(target, payload, timeout_ms)?;
+        self.detached.insert(correlation_id.clone());
+        Ok(PendingRequest {
+            tracker: self,
+            correlation_id,
+        })
+    }
+
+    /// Attaches `handler` to a detached request.
+    ///
+    /// Runs the handler immediately if the response is already buffered.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RequestError::UnknownCorrelation` if no detached request or
+    /// buffered response has this ID.
+    pub fn await_response<F>(
+        &mut self,
+        correlation_id: &str,
+        handler: F,
+    ) -> Result<(), RequestError>
+    where
+        F: FnOnce(Vec<u8>) + 'static,
+    {
+        if let Some(response) = self.buffered.remove(correlation_id) {
+            handler(response);
+            return Ok(());
+        }
+        if !self.detached.remove(correlation_id) {
+            return Err(RequestError::UnknownCorrelation(correlation_id.to_string()));
+        }
+        self.handlers
+            .insert(correlation_id.to_string(), Box::new(handler));
+        Ok(())
+    }
+
+    /// Routes a `handle-callback` message to its request.
+    pub fn on_callback(
+        &mut self,
+        correlation_id: Option<&str>,
+        payload: Vec<u8>,
+    ) -> CallbackOutcome {
+        let Some(correlation_id) = correlation_id else {
+            return CallbackOutcome::Unmatched(payload);
+        };
+        if let Some(handler) = self.handlers.remove(correlation_id) {
+            handler(payload);
+            return CallbackOutcome::Handled;
+        }
+        if self.detached.remove(correlation_id) {
+            self.buffered.insert(correlation_id.to_string(), payload);
+            return CallbackOutcome::Buffered;
+        }
+        CallbackOutcome::Unmatched(payload)
+    }
+
+    /// Takes the buffered response to a detached request.
+    pub fn take_response(&mut self, correlation_id: &str) -> Option<Vec<u8>> {
+        self.buffered.remove(correlation_id)
+    }
+
+    /// Cancels a pending request; its handler is dropped without running.
+    ///
+    /// # Errors
+    ///
+    /// - `RequestError::UnknownCorrelation` - the request is not pending
+    /// - `RequestError::Messaging` - the host cancel call failed
+    pub fn cancel(&mut self, correlation_id: &str) -> Result<(), RequestError> {
+        let pending =
+            self.handlers.remove(correlation_id).is_some() || self.detached.remove(correlation_id);
+        if !pending {
+            return Err(RequestError::UnknownCorrelation(correlation_id.to_string()));
+        }
+        self.messaging.cancel_request(correlation_id)
+    }
+
+    /// Returns `true` if the request is still waiting for its response.
+    pub fn is_pending(&self, correlation_id: &str) -> bool {
+        self.handlers.contains_key(correlation_id) || self.detached.contains(correlation_id)
+    }
+
+    /// Number of requests still waiting for their response.
+    pub fn pending(&self) -> usize {
+        self.handlers.len() + self.detached.len()
+    }
+}
+
+impl<M: HostMessaging> fmt::Debug for RequestTracker<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestTracker")
+            .field("awaiting", &self.handlers.len())
+            .field("detached", &self.detached.len())
+            .field("buffered", &self.buffered.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A request that was just sent.
+///
+/// Attach a handler with [`await_response`](Self::await_response), or keep
+/// the correlation ID with [`detach`](Self::detach) to handle the response
+/// later.
+#[must_use = "attach a response handler or detach the request"]
+pub struct PendingRequest<'a, M: HostMessaging> {
+    tracker: &'a mut RequestTracker<M>,
+    correlation_id: String,
+}
+
+impl<M: HostMessaging> PendingRequest<'_, M> {
+    /// The request's correlation ID.
+    pub fn correlation_id(&self) -> &str {
+        &self.correlation_id
+    }
+
+    /// Runs `handler` with the response when its callback arrives.
+    ///
+    /// Returns the correlation ID.
+    pub fn await_response<F>(self, handler: F) -> String
+    where
+        F: FnOnce(Vec<u8>) + 'static,
+    {
+        self.tracker.detached.remove(&self.correlation_id);
+        self.tracker
+            .handlers
+            .insert(self.correlation_id.clone(), Box::new(handler));
+        self.correlation_id
+    }
+
+    /// Leaves the request without a handler; its response is buffered.
+    ///
+    /// Returns the correlation ID.
+    pub fn detach(self) -> String {
+        self.correlation_id
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::requests::transport::MemoryMessaging;
+    use std::cell::RefCell;
+
+    fn tracker() -> (Rc<MemoryMessaging>, RequestTracker<MemoryMessaging>) {
+        let messaging = Rc::new(MemoryMessaging::new());
+        (Rc::clone(&messaging), RequestTracker::new(messaging))
+    }
+
+    #[test]
+    fn test_responses_reach_their_own_handler() {
+        let (messaging, mut requests) = tracker();
+        let target = ComponentAddress::new("acme", "prices", "v1");
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        let mut ids = Vec::new();
+        for symbol in ["EUR", "GBP"] {
+            let seen = Rc::clone(&seen);
+            let id = requests
+                .send_request(&target, symbol.as_bytes(), 100)
+                .unwrap()
+                .await_response(move |response| seen.borrow_mut().push((symbol, response)));
+            ids.push(id);
+        }
+        assert_eq!(messaging.sent()[1].payload, b"GBP");
+        assert_eq!(requests.pending(), 2);
+
+        // Out of order
+        assert_eq!(
+            requests.on_callback(Some(&ids[1]), b"0.85".to_vec()),
+            CallbackOutcome::Handled
+        );
+        assert_eq!(
+            requests.on_callback(Some(&ids[0]), b"1.08".to_vec()),
+            CallbackOutcome::Handled
+        );
+        assert_eq!(
+            *seen.borrow(),
+            vec![("GBP", b"0.85".to_vec()), ("EUR", b"1.08".to_vec())]
+        );
+
+        // A duplicate callback no longer matches
+        assert_eq!(
+            requests.on_callback(Some(&ids[0]), b"x".to_vec()),
+            CallbackOutcome::Unmatched(b"x".to_vec())
+        );
+        assert_eq!(requests.pending(), 0);
+    }
+
+    #[test]
+    fn test_buffered_response_runs_late_handler() {
+        let (_, mut requests) = tracker();
+        let target = ComponentAddress::new("acme", "geo", "v1");
+        let id = requests.send_request(&target, b"q", 100).unwrap().detach();
+
+        assert_eq!(
+            requests.on_callback(Some(&id), b"NL".to_vec()),
+            CallbackOutcome::Buffered
+        );
+        let seen = Rc::new(RefCell::new(None));
+        let slot = Rc::clone(&seen);
+        requests
+            .await_response(&id, move |response| *slot.borrow_mut() = Some(response))
+            .unwrap();
+        assert_eq!(seen.borrow().as_deref(), Some(&b"NL"[..]));
+
+        assert_eq!(
+            requests.await_response(&id, |_| {}),
+            Err(RequestError::UnknownCorrelation(id))
+        );
+        assert_eq!(
+            requests.on_callback(None, b"chunk".to_vec()),
+            CallbackOutcome::Unmatched(b"chunk".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_cancel_drops_handler() {
+        let (messaging, mut requests) = tracker();
+        let target = ComponentAddress::new("acme", "geo", "v1");
+        let ran = Rc::new(RefCell::new(false));
+        let flag = Rc::clone(&ran);
+        let id = requests
+            .send_request(&target, b"q", 100)
+            .unwrap()
+            .await_response(move |_| *flag.borrow_mut() = true);
+
+        requests.cancel(&id).unwrap();
+        assert_eq!(messaging.cancelled(), vec![id.clone()]);
+        assert!(!requests.is_pending(&id));
+        assert!(matches!(
+            requests.on_callback(Some(&id), Vec::new()),
+            CallbackOutcome::Unmatched(_)
+        ));
+        assert!(requests.cancel(&id).is_err());
+        assert!(!*ran.borrow());
+    }
+}
@@ -0,0 +1,150 @@
+//! Host messaging backends for the request helpers.
+
+// Layer 1: Standard library imports
+use std::cell::{Cell, RefCell};
+
+// Layer 3: Internal module imports
+use super::errors::RequestError;
+#[cfg(target_arch = "wasm32")]
+use crate::bindings;
+
+/// Address of another component (the WIT `component-id` record).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ComponentAddress {
+    /// Component namespace.
+    pub namespace: String,
+    /// Component name.
+    pub name: String,
+    /// Component instance.
+    pub instance: String,
+}
+
+impl ComponentAddress {
+    /// Creates an address.
+    pub fn new(
+        namespace: impl Into<String>,
+        name: impl Into<String>,
+        instance: impl Into<String>,
+    ) -> Self {
+        Self {
+            namespace: namespace.into(),
+            name: name.into(),
+            instance: instance.into(),
+        }
+    }
+}
+
+/// Request calls of the host `host-messaging` interface.
+pub trait HostMessaging {
+    /// Sends `payload` to `target` and returns the request's correlation ID.
+    fn request(
+        &self,
+        target: &ComponentAddress,
+        payload: &[u8],
+        timeout_ms: u64,
+    ) -> Result<String, RequestError>;
+
+    /// Cancels the pending request with `correlation_id`.
+    fn cancel_request(&self, correlation_id: &str) -> Result<(), RequestError>;
+}
+
+/// A request recorded by [`MemoryMessaging`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SentRequest {
+    /// Correlation ID handed back to the caller.
+    pub correlation_id: String,
+    /// Request target.
+    pub target: ComponentAddress,
+    /// Request payload.
+    pub payload: Vec<u8>,
+    /// Requested timeout.
+    pub timeout_ms: u64,
+}
+
+/// In-memory messaging for native builds and tests.
+///
+/// Records requests and cancellations and hands out sequential correlation
+/// IDs (`req-1`, `req-2`, ...).
+#[derive(Debug, Default)]
+pub struct MemoryMessaging {
+    sent: RefCell<Vec<SentRequest>>,
+    cancelled: RefCell<Vec<String>>,
+    next_id: Cell<u64>,
+}
+
+impl MemoryMessaging {
+    /// Creates messaging with nothing sent.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests sent so far, oldest first.
+    pub fn sent(&self) -> Vec<SentRequest> {
+        self.sent.borrow().clone()
+    }
+
+    /// Correlation IDs cancelled so far.
+    pub fn cancelled(&self) -> Vec<String> {
+        self.cancelled.borrow().clone()
+    }
+}
+
+impl HostMessaging for MemoryMessaging {
+    fn request(
+        &self,
+        target: &ComponentAddress,
+        payload: &[u8],
+        timeout_ms: u64,
+    ) -> Result<String, RequestError> {
+        self.next_id.set(self.next_id.get() + 1);
+        let correlation_id = format!("req-{}", self.next_id.get());
+        self.sent.borrow_mut().push(SentRequest {
+            correlation_id: correlation_id.clone(),
+            target: target.clone(),
+            payload: payload.to_vec(),
+            timeout_ms,
+        });
+        Ok(correlation_id)
+    }
+
+    fn cancel_request(&self, correlation_id: &str) -> Result<(), RequestError> {
+        self.cancelled.borrow_mut().push(correlation_id.to_string());
+        Ok(())
+    }
+}
+
+/// Messaging backed by the host `airssys:core/host-messaging` interface.
+///
+/// Available when compiling the guest for `wasm32`.
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WitMessaging;
+
+#[cfg(target_arch = "wasm32")]
+impl WitMessaging {
+    fn error(err: bindings::airssys::core::errors::MessagingError) -> RequestError {
+        RequestError::Messaging(format!("{err:?}"))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl HostMessaging for WitMessaging {
+    fn request(
+        &self,
+        target: &ComponentAddress,
+        payload: &[u8],
+        timeout_ms: u64,
+    ) -> Result<String, RequestError> {
+        let target = bindings::airssys::core::types::ComponentId {
+            namespace: target.namespace.clone(),
+            name: target.name.clone(),
+            instance: target.instance.clone(),
+        };
+        bindings::airssys::core::host_messaging::request(&target, payload, timeout_ms)
+            .map_err(Self::error)
+    }
+
+    fn cancel_request(&self, correlation_id: &str) -> Result<(), RequestError> {
+        bindings::airssys::core::host_messaging::cancel_request(correlation_id).map_err(Self::error)
+    }
+}
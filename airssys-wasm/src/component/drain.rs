@@ -0,0 +1,219 @@
+//! # Shutdown Drain - `prepare-shutdown` notifications
+//!
+//! Before the host shuts down or stops a component, it gives the component
+//! a chance to flush caches, persist counters and refuse new work. The host
+//! sends `ComponentActorMessage::PrepareShutdown` with a deadline, the
+//! wrapper calls the guest's `prepare-shutdown` export, and the guest's
+//! answer is reported here.
+//!
+//! # Flow
+//!
+//! 1. `begin()` records the components being drained under a new round.
+//! 2. Each wrapper reports its component's answer with `complete()`.
+//! 3. The host awaits the round with `wait()`, which returns as soon as
+//!    every component answered or the deadline expired, whichever comes
+//!    first. The host proceeds with the stop either way.
+//!
+//! `ShutdownDrain` is shared (via `Arc`) between the host and every wrapper
+//! the spawner creates.
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use tokio::sync::watch;
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+
+/// A component's answer to a `prepare-shutdown` notification.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrainOutcome {
+    /// The component finished its shutdown work.
+    Ready,
+
+    /// The component could not prepare (the host stops it anyway).
+    Failed(String),
+}
+
+/// Result of one drain round.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DrainReport {
+    /// Components that confirmed readiness.
+    pub ready: Vec<ComponentId>,
+    /// Components that answered with an error.
+    pub failed: Vec<(ComponentId, String)>,
+    /// Components that did not answer before the deadline.
+    pub timed_out: Vec<ComponentId>,
+}
+
+impl DrainReport {
+    /// Returns `true` if every component confirmed readiness.
+    pub fn all_ready(&self) -> bool {
+        self.failed.is_empty() && self.timed_out.is_empty()
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    round: u64,
+    answers: HashMap<ComponentId, Option<DrainOutcome>>,
+}
+
+/// Collects `prepare-shutdown` answers from running components.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use airssys_wasm::component::drain::{DrainOutcome, ShutdownDrain};
+/// use airssys_wasm::core::component::id::ComponentId;
+///
+/// # tokio_test::block_on(async {
+/// let drain = ShutdownDrain::new();
+/// let cache = ComponentId::new("acme", "cache", "v1");
+///
+/// let round = drain.begin(std::slice::from_ref(&cache));
+/// drain.complete(round, &cache, DrainOutcome::Ready);
+///
+/// let report = drain.wait(round, Duration::from_secs(1)).await;
+/// assert!(report.all_ready());
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct ShutdownDrain {
+    state: Mutex<State>,
+    answered: watch::Sender<u64>,
+}
+
+impl Default for ShutdownDrain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShutdownDrain {
+    /// Creates an idle drain.
+    pub fn new() -> Self {
+        let (answered, _) = watch::channel(0);
+        Self {
+            state: Mutex::new(State::default()),
+            answered,
+        }
+    }
+
+    /// Starts a new round for `components` and returns its number.
+    ///
+    /// Answers to earlier rounds are ignored from now on.
+    pub fn begin(&self, components: &[ComponentId]) -> u64 {
+        let mut state = self.lock();
+        state.round += 1;
+        state.answers = components.iter().map(|id| (id.clone(), None)).collect();
+        state.round
+    }
+
+    /// Records a component's answer for `round`.
+    pub fn complete(&self, round: u64, id: &ComponentId, outcome: DrainOutcome) {
+        let mut state = self.lock();
+        if state.round != round {
+            return;
+        }
+        if let Some(answer) = state.answers.get_mut(id) {
+            *answer = Some(outcome);
+            drop(state);
+            self.answered.send_modify(|count| *count += 1);
+        }
+    }
+
+    /// Waits until every component of `round` answered or `deadline` passed.
+    pub async fn wait(&self, round: u64, deadline: Duration) -> DrainReport {
+        let mut answered = self.answered.subscribe();
+        let _ = tokio::time::timeout(deadline, async {
+            loop {
+                if self.is_settled(round) {
+                    break;
+                }
+                if answered.changed().await.is_err() {
+                    break;
+                }
+            }
+        })
+        .await;
+        self.report(round)
+    }
+
+    fn is_settled(&self, round: u64) -> bool {
+        let state = self.lock();
+        state.round != round || state.answers.values().all(Option::is_some)
+    }
+
+    fn report(&self, round: u64) -> DrainReport {
+        let state = self.lock();
+        let mut report = DrainReport::default();
+        if state.round != round {
+            return report;
+        }
+        for (id, answer) in &state.answers {
+            match answer {
+                Some(DrainOutcome::Ready) => report.ready.push(id.clone()),
+                Some(DrainOutcome::Failed(reason)) => {
+                    report.failed.push((id.clone(), reason.clone()))
+                }
+                None => report.timed_out.push(id.clone()),
+            }
+        }
+        report
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(name: &str) -> ComponentId {
+        ComponentId::new("acme", name, "v1")
+    }
+
+    #[tokio::test]
+    async fn test_wait_returns_early_when_all_answered() {
+        let drain = std::sync::Arc::new(ShutdownDrain::new());
+        let round = drain.begin(&[id("a"), id("b")]);
+
+        let answering = std::sync::Arc::clone(&drain);
+        tokio::spawn(async move {
+            answering.complete(round, &id("a"), DrainOutcome::Ready);
+            answering.complete(
+                round,
+                &id("b"),
+                DrainOutcome::Failed("disk full".to_string()),
+            );
+        });
+
+        let started = std::time::Instant::now();
+        let report = drain.wait(round, Duration::from_secs(10)).await;
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(report.ready, vec![id("a")]);
+        assert_eq!(report.failed, vec![(id("b"), "disk full".to_string())]);
+        assert!(!report.all_ready());
+    }
+
+    #[tokio::test]
+    async fn test_deadline_reports_silent_components_and_stale_rounds_ignored() {
+        let drain = ShutdownDrain::new();
+        let old = drain.begin(&[id("a")]);
+        let round = drain.begin(&[id("a"), id("b")]);
+
+        drain.complete(old, &id("a"), DrainOutcome::Ready);
+        drain.complete(round, &id("b"), DrainOutcome::Ready);
+
+        let report = drain.wait(round, Duration::from_millis(20)).await;
+        assert_eq!(report.ready, vec![id("b")]);
+        assert_eq!(report.timed_out, vec![id("a")]);
+    }
+}
@@ -0,0 +1,205 @@
+//! # Live Config - `[config]` updates for running components
+//!
+//! Lets the host push updated `[config]` values to a running component
+//! without restarting it. The host validates the update against the
+//! declared values, sends `ComponentActorMessage::Reconfigure` to the
+//! component actor, and waits for the guest's accept/reject answer.
+//!
+//! # Flow
+//!
+//! 1. `begin()` validates the update and assigns it a generation number.
+//! 2. The wrapper calls the guest's `reconfigure` export and reports the
+//!    answer with `complete()`. Accepted values are merged into the applied
+//!    set; rejected ones leave it unchanged.
+//! 3. The host awaits the outcome for its generation with `wait_for()`.
+//!
+//! `LiveConfig` is shared (via `Arc`) between the host and every wrapper the
+//! supervisor creates for the component, so the applied values outlive any
+//! single actor.
+//!
+//! # References
+//!
+//! - ADR-WASM-031: Component & Messaging Module Design
+
+// Layer 1: Standard library imports
+use std::sync::Mutex;
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use tokio::sync::watch;
+
+// Layer 3: Internal module imports
+use crate::core::config::values::{ConfigUpdateError, ConfigValues};
+
+/// Outcome of one config update, as answered by the component.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReconfigureOutcome {
+    /// The component applied the update.
+    Accepted,
+
+    /// The component rejected the update and kept its previous values.
+    Rejected(String),
+}
+
+#[derive(Debug)]
+struct State {
+    declared: ConfigValues,
+    applied: ConfigValues,
+    next_generation: u64,
+}
+
+/// Shared record of a component's applied `[config]` values.
+///
+/// # Thread Safety
+///
+/// Values are guarded by `Mutex` (poisoned locks are recovered since the
+/// state stays consistent between operations). Outcomes are published on a
+/// `tokio::sync::watch` channel so hosts can await them.
+#[derive(Debug)]
+pub struct LiveConfig {
+    state: Mutex<State>,
+    outcomes: watch::Sender<Option<(u64, ReconfigureOutcome)>>,
+}
+
+impl LiveConfig {
+    /// Creates a live config starting from the declared values.
+    pub fn new(declared: ConfigValues) -> Self {
+        let (outcomes, _) = watch::channel(None);
+        Self {
+            state: Mutex::new(State {
+                applied: declared.clone(),
+                declared,
+                next_generation: 1,
+            }),
+            outcomes,
+        }
+    }
+
+    /// Returns the values currently applied by the component.
+    pub fn applied(&self) -> ConfigValues {
+        self.lock().applied.clone()
+    }
+
+    /// Validates an update and assigns it a generation number.
+    ///
+    /// # Errors
+    ///
+    /// Returns the validation error if the update does not match the
+    /// declared values.
+    pub fn begin(&self, update: &ConfigValues) -> Result<u64, ConfigUpdateError> {
+        let mut state = self.lock();
+        state.declared.validate_update(update)?;
+        let generation = state.next_generation;
+        state.next_generation += 1;
+        Ok(generation)
+    }
+
+    /// Records the component's answer to an update.
+    pub fn complete(&self, generation: u64, update: &ConfigValues, outcome: ReconfigureOutcome) {
+        if outcome == ReconfigureOutcome::Accepted {
+            let mut state = self.lock();
+            state.applied = state.applied.merged(update);
+        }
+        self.outcomes.send_replace(Some((generation, outcome)));
+    }
+
+    /// Waits for the outcome of `generation`.
+    ///
+    /// # Errors
+    ///
+    /// - `ConfigUpdateError::Rejected` if the component rejected the update
+    /// - `ConfigUpdateError::Timeout` if no answer arrived within `timeout`
+    pub async fn wait_for(
+        &self,
+        generation: u64,
+        timeout: Duration,
+    ) -> Result<(), ConfigUpdateError> {
+        let mut outcomes = self.outcomes.subscribe();
+        let answered = tokio::time::timeout(
+            timeout,
+            outcomes.wait_for(|o| matches!(o, Some((g, _)) if *g >= generation)),
+        )
+        .await
+        .map_err(|_| ConfigUpdateError::Timeout)?
+        .map_err(|_| ConfigUpdateError::Timeout)?
+        .clone();
+
+        match answered {
+            Some((g, ReconfigureOutcome::Accepted)) if g == generation => Ok(()),
+            Some((g, ReconfigureOutcome::Rejected(reason))) if g == generation => {
+                Err(ConfigUpdateError::Rejected(reason))
+            }
+            // A later update was answered first; this one was superseded
+            _ => Err(ConfigUpdateError::Timeout),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::values::ConfigValue;
+
+    fn live() -> LiveConfig {
+        LiveConfig::new(ConfigValues::new().with("threshold", ConfigValue::Integer(10)))
+    }
+
+    fn update(n: i64) -> ConfigValues {
+        ConfigValues::new().with("threshold", ConfigValue::Integer(n))
+    }
+
+    #[tokio::test]
+    async fn test_accepted_update_is_applied() {
+        let live = live();
+        let generation = live.begin(&update(20)).unwrap();
+        live.complete(generation, &update(20), ReconfigureOutcome::Accepted);
+
+        assert!(live
+            .wait_for(generation, Duration::from_millis(50))
+            .await
+            .is_ok());
+        assert_eq!(
+            live.applied().get("threshold"),
+            Some(&ConfigValue::Integer(20))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejected_update_keeps_previous_values() {
+        let live = live();
+        let generation = live.begin(&update(-1)).unwrap();
+        live.complete(
+            generation,
+            &update(-1),
+            ReconfigureOutcome::Rejected("must be positive".to_string()),
+        );
+
+        let result = live.wait_for(generation, Duration::from_millis(50)).await;
+        assert_eq!(
+            result,
+            Err(ConfigUpdateError::Rejected("must be positive".to_string()))
+        );
+        assert_eq!(
+            live.applied().get("threshold"),
+            Some(&ConfigValue::Integer(10))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_update_and_timeout() {
+        let live = live();
+        let unknown = ConfigValues::new().with("other", ConfigValue::Boolean(true));
+        assert!(matches!(
+            live.begin(&unknown),
+            Err(ConfigUpdateError::UnknownKey(_))
+        ));
+
+        let generation = live.begin(&update(5)).unwrap();
+        let result = live.wait_for(generation, Duration::from_millis(10)).await;
+        assert_eq!(result, Err(ConfigUpdateError::Timeout));
+    }
+}
@@ -8,6 +8,12 @@
 //! - `ComponentRegistry` - Thread-safe registry for component tracking
 //! - `ComponentSpawner` - Orchestrates component lifecycle (load, validate, spawn, register)
 //! - `SupervisorConfig` - Supervision configuration for component actors
+//! - `RequeuePolicy` - Requeue of the message that crashed a component across restarts
+//! - `LiveConfig` - Live `[config]` updates with accept/reject outcomes
+//! - `ShutdownDrain` - `prepare-shutdown` notifications with a deadline
+//! - `RestartTelemetry` - Per-component restart cause statistics
+//! - `SnapshotLedger` - Component state carried across restarts
+//! - `LoadShedder` - Deadline-aware shedding of queued messages under overload
 //!
 //! # Architecture
 //!
@@ -28,7 +34,13 @@
 //! - KNOWLEDGE-WASM-038: Component Module Responsibility
 
 // Module declarations (per PROJECTS_STANDARD.md S4.3)
+pub mod drain;
+pub mod live_config;
 pub mod registry;
+pub mod requeue;
+pub mod restart_telemetry;
+pub mod shedding;
+pub mod snapshots;
 pub mod spawner;
 pub mod supervisor;
 pub mod wrapper;
@@ -38,3 +50,9 @@ pub mod wrapper;
 // Callers use: crate::component::wrapper::ComponentWrapper
 // Callers use: crate::component::spawner::ComponentSpawner
 // Callers use: crate::component::supervisor::SupervisorConfig
+// Callers use: crate::component::requeue::RequeuePolicy
+// Callers use: crate::component::live_config::LiveConfig
+// Callers use: crate::component::drain::ShutdownDrain
+// Callers use: crate::component::restart_telemetry::RestartTelemetry
+// Callers use: crate::component::snapshots::SnapshotLedger
+// Callers use: crate::component::shedding::LoadShedder
@@ -0,0 +1,542 @@
+//! # Requeue - Retry-aware supervision for failed messages
+//!
+//! When a `ComponentWrapper` fails while processing a message, the supervisor
+//! restarts the actor and the triggering message would normally be lost. This
+//! module provides the per-component policy and the shared ledger that carry
+//! the failed message across the restart so it can be retried.
+//!
+//! # Flow
+//!
+//! 1. `handle_message()` fails - the wrapper records the message (and any
+//!    messages it had not processed yet) in the `RequeueLedger`.
+//! 2. The supervisor restarts the component with a fresh wrapper that shares
+//!    the same ledger (`Arc<RequeueLedger>`).
+//! 3. `pre_start()` asks the ledger for the pending messages. Messages below
+//!    `max_attempts` are replayed at the head of the incoming stream (the
+//!    wrapper schedules a `Replay` for itself) or along with the next new
+//!    message; messages that exhausted their attempts are moved to the
+//!    dead-letter queue.
+//!
+//! # Poison Messages
+//!
+//! Dead-lettered messages are kept in the ledger together with the optional
+//! dead-letter target from the policy. `route_dead_letters()` hands the ones
+//! with a target to a delivery function supplied by the caller, since this
+//! module cannot depend on the messaging layer (Layer 3B); the system
+//! coordinator drives it periodically. Letters without a target stay in the
+//! ledger for inspection, up to its dead-letter capacity, after which the
+//! oldest are evicted. The load shedder (`shedding`) files messages that
+//! could not meet their deadline in the same queue, tagged
+//! `DeadLetterReason::Shedded`.
+//!
+//! # References
+//!
+//! - ADR-WASM-031: Component & Messaging Module Design
+
+// Layer 1: Standard library imports
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+// Layer 2: Third-party crate imports
+// (none needed for this module)
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::ComponentMessage;
+
+/// Dead letters kept by a ledger before the oldest are evicted.
+pub const DEFAULT_DEAD_LETTER_CAPACITY: usize = 1024;
+
+/// Where a requeued message is replayed relative to newly arriving messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RequeuePosition {
+    /// Replay the failed message before any new message (preserves ordering).
+    #[default]
+    Head,
+
+    /// Replay the failed message after the next new message; it waits
+    /// until one arrives.
+    Tail,
+}
+
+/// Per-component policy for requeueing a message that crashed the component.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::component::requeue::{RequeuePolicy, RequeuePosition};
+/// use airssys_wasm::core::component::id::ComponentId;
+///
+/// let policy = RequeuePolicy::new(3)
+///     .with_position(RequeuePosition::Tail)
+///     .with_dead_letter(ComponentId::new("system", "dlq", "0"));
+///
+/// assert_eq!(policy.max_attempts(), 3);
+/// assert_eq!(policy.position(), RequeuePosition::Tail);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequeuePolicy {
+    position: RequeuePosition,
+    max_attempts: u32,
+    dead_letter: Option<ComponentId>,
+}
+
+impl RequeuePolicy {
+    /// Creates a policy allowing `max_attempts` processing attempts per message.
+    ///
+    /// A value of 0 is treated as 1 (the original attempt only).
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            position: RequeuePosition::default(),
+            max_attempts: max_attempts.max(1),
+            dead_letter: None,
+        }
+    }
+
+    /// Sets where the failed message is replayed after restart.
+    pub fn with_position(mut self, position: RequeuePosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the component that should receive poison messages.
+    pub fn with_dead_letter(mut self, target: ComponentId) -> Self {
+        self.dead_letter = Some(target);
+        self
+    }
+
+    /// Returns the replay position.
+    pub fn position(&self) -> RequeuePosition {
+        self.position
+    }
+
+    /// Returns the maximum number of processing attempts per message.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns the dead-letter target, if any.
+    pub fn dead_letter(&self) -> Option<&ComponentId> {
+        self.dead_letter.as_ref()
+    }
+}
+
+impl Default for RequeuePolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+/// A message waiting to be replayed after a restart.
+#[derive(Debug, Clone)]
+pub struct PendingMessage {
+    /// The message to replay.
+    pub message: ComponentMessage,
+
+    /// Number of failed processing attempts so far.
+    pub attempts: u32,
+
+    /// Error from the last failed attempt, if the message failed.
+    pub last_error: Option<String>,
+}
+
+impl PendingMessage {
+    /// Creates a pending message that has not been attempted yet.
+    pub fn fresh(message: ComponentMessage) -> Self {
+        Self {
+            message,
+            attempts: 0,
+            last_error: None,
+        }
+    }
+}
+
+/// Why a message was moved to the dead-letter queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeadLetterReason {
+    /// The message exhausted its processing attempts.
+    Exhausted,
+
+    /// The message was shed because it could not meet its deadline.
+    Shedded,
+}
+
+impl DeadLetterReason {
+    /// Returns the reason as reported to operators.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Exhausted => "exhausted",
+            Self::Shedded => "shedded",
+        }
+    }
+}
+
+/// A message moved to the dead-letter queue.
+#[derive(Debug, Clone)]
+pub struct DeadLetter {
+    /// Component that failed to process the message.
+    pub component: ComponentId,
+
+    /// The poison or shed message.
+    pub message: ComponentMessage,
+
+    /// Why the message was dead-lettered.
+    pub reason: DeadLetterReason,
+
+    /// Number of failed processing attempts.
+    pub attempts: u32,
+
+    /// Error from the last failed attempt.
+    pub last_error: Option<String>,
+
+    /// Dead-letter target configured in the component's policy.
+    pub route_to: Option<ComponentId>,
+}
+
+/// Shared store that carries failed messages across component restarts.
+///
+/// One ledger is shared (via `Arc`) by every wrapper instance the supervisor
+/// creates for a component, so its contents outlive any single actor.
+///
+/// The dead-letter queue holds at most `dead_letter_capacity` letters;
+/// filing one more evicts the oldest.
+///
+/// # Thread Safety
+///
+/// Internal state is guarded by `Mutex`. A poisoned lock is recovered since
+/// the ledger holds plain data that stays consistent between operations.
+#[derive(Debug)]
+pub struct RequeueLedger {
+    pending: Mutex<HashMap<ComponentId, VecDeque<PendingMessage>>>,
+    dead_letters: Mutex<VecDeque<DeadLetter>>,
+    dead_letter_capacity: usize,
+    evicted: AtomicU64,
+}
+
+impl RequeueLedger {
+    /// Creates an empty ledger holding up to
+    /// [`DEFAULT_DEAD_LETTER_CAPACITY`] dead letters.
+    pub fn new() -> Self {
+        Self::with_dead_letter_capacity(DEFAULT_DEAD_LETTER_CAPACITY)
+    }
+
+    /// Creates an empty ledger holding up to `capacity` dead letters.
+    ///
+    /// A capacity of 0 is treated as 1.
+    pub fn with_dead_letter_capacity(capacity: usize) -> Self {
+        Self {
+            pending: Mutex::new(HashMap::new()),
+            dead_letters: Mutex::new(VecDeque::new()),
+            dead_letter_capacity: capacity.max(1),
+            evicted: AtomicU64::new(0),
+        }
+    }
+
+    /// Records the messages a component left unprocessed when it failed.
+    ///
+    /// `messages` must be in replay order. They are appended after anything
+    /// already pending for the component.
+    pub fn record_failure(&self, id: &ComponentId, messages: Vec<PendingMessage>) {
+        if messages.is_empty() {
+            return;
+        }
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        pending.entry(id.clone()).or_default().extend(messages);
+    }
+
+    /// Takes the messages to replay after a restart.
+    ///
+    /// Messages that already failed `policy.max_attempts()` times are moved
+    /// to the dead-letter queue instead of being returned.
+    pub fn take_for_restart(
+        &self,
+        id: &ComponentId,
+        policy: &RequeuePolicy,
+    ) -> VecDeque<PendingMessage> {
+        let taken = {
+            let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+            pending.remove(id).unwrap_or_default()
+        };
+
+        let mut replay = VecDeque::with_capacity(taken.len());
+        let mut poisoned = Vec::new();
+        for entry in taken {
+            if entry.attempts >= policy.max_attempts() {
+                poisoned.push(DeadLetter {
+                    component: id.clone(),
+                    message: entry.message,
+                    reason: DeadLetterReason::Exhausted,
+                    attempts: entry.attempts,
+                    last_error: entry.last_error,
+                    route_to: policy.dead_letter().cloned(),
+                });
+            } else {
+                replay.push_back(entry);
+            }
+        }
+
+        if !poisoned.is_empty() {
+            self.file_dead_letters(poisoned);
+        }
+        replay
+    }
+
+    /// Adds a message that is given up on without further attempts.
+    pub fn dead_letter(&self, letter: DeadLetter) {
+        self.file_dead_letters([letter]);
+    }
+
+    /// Hands every dead letter with a `route_to` target to `deliver`.
+    ///
+    /// Letters are removed from the ledger before delivery; those `deliver`
+    /// refuses are filed again so the next call retries them. Letters
+    /// without a target are left in place. Returns the number delivered.
+    pub fn route_dead_letters<F, E>(&self, mut deliver: F) -> usize
+    where
+        F: FnMut(&ComponentId, ComponentMessage) -> Result<(), E>,
+        E: Display,
+    {
+        let routable: VecDeque<DeadLetter> = {
+            let mut letters = self.dead_letters.lock().unwrap_or_else(|e| e.into_inner());
+            let (routable, kept) = std::mem::take(&mut *letters)
+                .into_iter()
+                .partition(|letter| letter.route_to.is_some());
+            *letters = kept;
+            routable
+        };
+
+        let mut routed = 0;
+        let mut refused = Vec::new();
+        for letter in routable {
+            let Some(target) = &letter.route_to else {
+                continue;
+            };
+            match deliver(target, letter.message.clone()) {
+                Ok(()) => routed += 1,
+                Err(e) => {
+                    tracing::warn!(
+                        component = %letter.component,
+                        target = %target,
+                        error = %e,
+                        "dead letter not delivered; retrying later"
+                    );
+                    refused.push(letter);
+                }
+            }
+        }
+        if !refused.is_empty() {
+            self.file_dead_letters(refused);
+        }
+        routed
+    }
+
+    /// Returns the number of dead letters evicted because the queue was full.
+    pub fn evicted_dead_letters(&self) -> u64 {
+        self.evicted.load(Ordering::Relaxed)
+    }
+
+    /// Appends `letters`, evicting the oldest beyond the capacity.
+    fn file_dead_letters(&self, letters: impl IntoIterator<Item = DeadLetter>) {
+        let mut queue = self.dead_letters.lock().unwrap_or_else(|e| e.into_inner());
+        for letter in letters {
+            if queue.len() >= self.dead_letter_capacity {
+                if let Some(oldest) = queue.pop_front() {
+                    self.evicted.fetch_add(1, Ordering::Relaxed);
+                    tracing::warn!(
+                        component = %oldest.component,
+                        reason = oldest.reason.as_str(),
+                        "dead-letter queue full; evicting oldest letter"
+                    );
+                }
+            }
+            queue.push_back(letter);
+        }
+    }
+
+    /// Returns the number of messages pending replay for a component.
+    pub fn pending_count(&self, id: &ComponentId) -> usize {
+        self.pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(id)
+            .map_or(0, VecDeque::len)
+    }
+
+    /// Returns a snapshot of the dead-letter queue.
+    pub fn dead_letters(&self) -> Vec<DeadLetter> {
+        self.dead_letters
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Removes and returns all dead letters.
+    pub fn drain_dead_letters(&self) -> Vec<DeadLetter> {
+        self.dead_letters
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .drain(..)
+            .collect()
+    }
+}
+
+impl Default for RequeueLedger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::component::message::{MessageMetadata, MessagePayload};
+
+    fn test_id() -> ComponentId {
+        ComponentId::new("test", "worker", "0")
+    }
+
+    fn message(byte: u8) -> ComponentMessage {
+        ComponentMessage::new(
+            test_id(),
+            MessagePayload::new(vec![byte]),
+            MessageMetadata::default(),
+        )
+    }
+
+    fn failed(byte: u8, attempts: u32) -> PendingMessage {
+        PendingMessage {
+            message: message(byte),
+            attempts,
+            last_error: Some("boom".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_policy_defaults_and_builders() {
+        let policy = RequeuePolicy::default();
+        assert_eq!(policy.position(), RequeuePosition::Head);
+        assert_eq!(policy.max_attempts(), 3);
+        assert!(policy.dead_letter().is_none());
+
+        let dlq = ComponentId::new("system", "dlq", "0");
+        let policy = RequeuePolicy::new(0)
+            .with_position(RequeuePosition::Tail)
+            .with_dead_letter(dlq.clone());
+        assert_eq!(policy.max_attempts(), 1);
+        assert_eq!(policy.position(), RequeuePosition::Tail);
+        assert_eq!(policy.dead_letter(), Some(&dlq));
+    }
+
+    #[test]
+    fn test_ledger_replays_below_max_attempts() {
+        let ledger = RequeueLedger::new();
+        let id = test_id();
+        ledger.record_failure(&id, vec![failed(1, 1), PendingMessage::fresh(message(2))]);
+        assert_eq!(ledger.pending_count(&id), 2);
+
+        let replay = ledger.take_for_restart(&id, &RequeuePolicy::new(3));
+        assert_eq!(replay.len(), 2);
+        assert_eq!(replay[0].message.payload.as_bytes(), &[1]);
+        assert_eq!(replay[1].attempts, 0);
+        assert_eq!(ledger.pending_count(&id), 0);
+        assert!(ledger.dead_letters().is_empty());
+    }
+
+    #[test]
+    fn test_ledger_dead_letters_exhausted_messages() {
+        let ledger = RequeueLedger::new();
+        let id = test_id();
+        let dlq = ComponentId::new("system", "dlq", "0");
+        let policy = RequeuePolicy::new(2).with_dead_letter(dlq.clone());
+
+        ledger.record_failure(&id, vec![failed(1, 2), PendingMessage::fresh(message(2))]);
+        let replay = ledger.take_for_restart(&id, &policy);
+
+        assert_eq!(replay.len(), 1);
+        assert_eq!(replay[0].message.payload.as_bytes(), &[2]);
+
+        let dead = ledger.drain_dead_letters();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].attempts, 2);
+        assert_eq!(dead[0].reason, DeadLetterReason::Exhausted);
+        assert_eq!(dead[0].route_to, Some(dlq));
+        assert_eq!(dead[0].last_error.as_deref(), Some("boom"));
+        assert!(ledger.dead_letters().is_empty());
+    }
+
+    fn letter(byte: u8, route_to: Option<ComponentId>) -> DeadLetter {
+        DeadLetter {
+            component: test_id(),
+            message: message(byte),
+            reason: DeadLetterReason::Exhausted,
+            attempts: 1,
+            last_error: None,
+            route_to,
+        }
+    }
+
+    #[test]
+    fn test_ledger_evicts_oldest_dead_letters() {
+        let ledger = RequeueLedger::with_dead_letter_capacity(2);
+        for byte in 1..=3 {
+            ledger.dead_letter(letter(byte, None));
+        }
+
+        let dead = ledger.dead_letters();
+        assert_eq!(dead.len(), 2);
+        assert_eq!(dead[0].message.payload.as_bytes(), &[2]);
+        assert_eq!(ledger.evicted_dead_letters(), 1);
+    }
+
+    #[test]
+    fn test_route_dead_letters_delivers_to_target() {
+        let ledger = RequeueLedger::new();
+        let dlq = ComponentId::new("system", "dlq", "0");
+        ledger.dead_letter(letter(1, Some(dlq.clone())));
+        ledger.dead_letter(letter(2, None));
+
+        let mut delivered = Vec::new();
+        let routed = ledger.route_dead_letters(|target, message| {
+            delivered.push((target.clone(), message.payload.as_bytes().to_vec()));
+            Ok::<(), String>(())
+        });
+
+        assert_eq!(routed, 1);
+        assert_eq!(delivered, vec![(dlq, vec![1])]);
+        // Letters without a target stay for inspection
+        let dead = ledger.dead_letters();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].message.payload.as_bytes(), &[2]);
+    }
+
+    #[test]
+    fn test_route_dead_letters_keeps_refused_letters() {
+        let ledger = RequeueLedger::new();
+        let dlq = ComponentId::new("system", "dlq", "0");
+        ledger.dead_letter(letter(1, Some(dlq)));
+
+        let routed = ledger.route_dead_letters(|_, _| Err("no mailbox"));
+        assert_eq!(routed, 0);
+        assert_eq!(ledger.dead_letters().len(), 1);
+
+        let routed = ledger.route_dead_letters(|_, _| Ok::<(), String>(()));
+        assert_eq!(routed, 1);
+        assert!(ledger.dead_letters().is_empty());
+    }
+
+    #[test]
+    fn test_ledger_isolates_components() {
+        let ledger = RequeueLedger::new();
+        let other = ComponentId::new("test", "other", "0");
+        ledger.record_failure(&test_id(), vec![failed(1, 1)]);
+
+        assert!(ledger
+            .take_for_restart(&other, &RequeuePolicy::default())
+            .is_empty());
+        assert_eq!(ledger.pending_count(&test_id()), 1);
+    }
+}
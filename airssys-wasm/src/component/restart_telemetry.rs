@@ -0,0 +1,165 @@
+//! # Restart Telemetry - why components restart
+//!
+//! Restart counts show how often a component restarts, not why. When a
+//! `ComponentWrapper` fails, it records a `RestartCause` here: the failure
+//! classification, the type of message it was processing, and how long it
+//! had been running. Causes are aggregated per component into
+//! `ChildFailureStats`, the same statistics airssys-rt supervisors keep for
+//! their children.
+//!
+//! `RestartTelemetry` is shared (via `Arc`) between the host and every
+//! wrapper the spawner creates, so statistics survive restarts. Operators
+//! read them with [`RestartTelemetry::stats`] and
+//! [`RestartTelemetry::snapshot`].
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+// Layer 2: Third-party crate imports
+use airssys_rt::supervisor::{ChildFailureStats, FailureClass, RestartCause};
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::runtime::errors::WasmError;
+
+/// Classifies a runtime error for restart analytics.
+pub fn failure_class(error: &WasmError) -> FailureClass {
+    match error {
+        WasmError::Timeout => FailureClass::Timeout,
+        WasmError::ResourceLimitExceeded(_) => FailureClass::ResourceExhausted,
+        // Traps surface as runtime errors
+        WasmError::RuntimeError(_) => FailureClass::Crash,
+        WasmError::ComponentNotFound(_)
+        | WasmError::InstantiationFailed(_)
+        | WasmError::InvalidComponent(_)
+        | WasmError::StoreNotInitialized => FailureClass::StartFailure,
+        WasmError::ExportNotFound(_) | WasmError::ConfigRejected(_) => FailureClass::Error,
+    }
+}
+
+/// Per-component restart cause statistics.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use airssys_rt::supervisor::{FailureClass, RestartCause};
+/// use airssys_wasm::component::restart_telemetry::RestartTelemetry;
+/// use airssys_wasm::core::component::id::ComponentId;
+///
+/// let telemetry = RestartTelemetry::new();
+/// let id = ComponentId::new("acme", "resizer", "v1");
+///
+/// telemetry.record_failure(
+///     &id,
+///     RestartCause::new(
+///         FailureClass::Crash,
+///         "wasm trap: out of bounds memory access",
+///         Some("image/png".to_string()),
+///         Duration::from_secs(42),
+///     ),
+/// );
+/// telemetry.record_start(&id);
+///
+/// let stats = telemetry.stats(&id).unwrap();
+/// assert_eq!((stats.failures, stats.restarts), (1, 1));
+/// assert_eq!(stats.by_message_type["image/png"], 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct RestartTelemetry {
+    stats: Mutex<HashMap<ComponentId, ChildFailureStats>>,
+}
+
+impl RestartTelemetry {
+    /// Creates empty telemetry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records why `id` failed.
+    pub fn record_failure(&self, id: &ComponentId, cause: RestartCause) {
+        tracing::info!(
+            component = %id,
+            class = %cause.class,
+            message_type = cause.message_type.as_deref().unwrap_or("-"),
+            uptime_ms = cause.uptime.as_millis() as u64,
+            "component failed"
+        );
+        self.lock().entry(id.clone()).or_default().record(cause);
+    }
+
+    /// Records that `id` started.
+    ///
+    /// A start that follows an unrecovered failure counts as a restart.
+    pub fn record_start(&self, id: &ComponentId) {
+        if let Some(stats) = self.lock().get_mut(id) {
+            if stats.restarts < stats.failures {
+                stats.record_restart();
+            }
+        }
+    }
+
+    /// Statistics of `id`, if it ever failed.
+    pub fn stats(&self, id: &ComponentId) -> Option<ChildFailureStats> {
+        self.lock().get(id).cloned()
+    }
+
+    /// Statistics of every component that failed.
+    pub fn snapshot(&self) -> HashMap<ComponentId, ChildFailureStats> {
+        self.lock().clone()
+    }
+
+    /// Forgets the statistics of `id`.
+    pub fn remove(&self, id: &ComponentId) {
+        self.lock().remove(id);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<ComponentId, ChildFailureStats>> {
+        self.stats.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_failure_class_mapping() {
+        assert_eq!(failure_class(&WasmError::Timeout), FailureClass::Timeout);
+        assert_eq!(
+            failure_class(&WasmError::ResourceLimitExceeded("fuel".into())),
+            FailureClass::ResourceExhausted
+        );
+        assert_eq!(
+            failure_class(&WasmError::RuntimeError("unreachable".into())),
+            FailureClass::Crash
+        );
+        assert_eq!(
+            failure_class(&WasmError::InstantiationFailed("link".into())),
+            FailureClass::StartFailure
+        );
+    }
+
+    #[test]
+    fn test_only_starts_after_failures_count_as_restarts() {
+        let telemetry = RestartTelemetry::new();
+        let id = ComponentId::new("acme", "resizer", "v1");
+
+        telemetry.record_start(&id);
+        assert!(telemetry.stats(&id).is_none());
+
+        let cause = RestartCause::new(FailureClass::Timeout, "slow", None, Duration::ZERO);
+        telemetry.record_failure(&id, cause);
+        telemetry.record_start(&id);
+        telemetry.record_start(&id);
+
+        let stats = telemetry.stats(&id).unwrap();
+        assert_eq!((stats.failures, stats.restarts), (1, 1));
+        assert_eq!(telemetry.snapshot().len(), 1);
+
+        telemetry.remove(&id);
+        assert!(telemetry.stats(&id).is_none());
+    }
+}
@@ -0,0 +1,363 @@
+//! # Load Shedding - dropping work that will miss its deadline anyway
+//!
+//! Under overload a component's queue grows until messages wait longer
+//! than their senders care about. Handling such a message wastes an
+//! execution slot on a result nobody will use and delays every message
+//! behind it. `LoadShedder` tracks each component's queue depth and an
+//! exponentially weighted average of its service time, and moves messages
+//! whose `deadline_ms` can no longer be met to the dead-letter queue of a
+//! shared `RequeueLedger`, tagged `DeadLetterReason::Shedded`.
+//!
+//! Messages are checked twice:
+//!
+//! 1. On enqueue (`QueueAdmission::admit`, called by the subscriber),
+//!    against the messages already queued ahead of them. Shed messages are
+//!    reported to the sender as `MessagingError::Shed`.
+//! 2. On dequeue (`ComponentWrapper`), against the time one more message
+//!    takes, in case the queue drained slower than expected.
+//!
+//! Only components with a `ShedPolicy` are shed from, and only messages
+//! that carry a deadline.
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use chrono::Utc;
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::ComponentMessage;
+use crate::core::config::shedding::ShedPolicy;
+use crate::core::messaging::errors::MessagingError;
+use crate::core::messaging::traits::QueueAdmission;
+
+use super::requeue::{DeadLetter, DeadLetterReason, RequeueLedger};
+
+/// Weight of the latest measurement in the service time average.
+const SERVICE_TIME_WEIGHT: u32 = 4;
+
+/// Queue statistics of one component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShedStats {
+    /// Messages admitted to the queue and not yet dequeued.
+    pub depth: usize,
+
+    /// Expected time to handle one message.
+    pub service_estimate: Duration,
+
+    /// Messages shed so far.
+    pub shed: u64,
+}
+
+#[derive(Debug)]
+struct QueueState {
+    policy: ShedPolicy,
+    depth: usize,
+    service: Option<Duration>,
+    shed: u64,
+}
+
+impl QueueState {
+    fn service_estimate(&self) -> Duration {
+        self.service.unwrap_or(self.policy.initial_estimate())
+    }
+}
+
+/// Sheds queued messages that cannot meet their deadline.
+///
+/// Shared (via `Arc`) by the subscriber and every wrapper the spawner
+/// creates, so queue depth and service times survive restarts.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use airssys_wasm::component::requeue::{DeadLetterReason, RequeueLedger};
+/// use airssys_wasm::component::shedding::LoadShedder;
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::component::message::{ComponentMessage, MessageMetadata, MessagePayload};
+/// use airssys_wasm::core::config::shedding::ShedPolicy;
+///
+/// let ledger = Arc::new(RequeueLedger::new());
+/// let shedder = LoadShedder::new(Arc::clone(&ledger));
+/// let worker = ComponentId::new("app", "worker", "v1");
+/// shedder.set_policy(worker.clone(), ShedPolicy::new());
+///
+/// // Already past its deadline
+/// let expired = ComponentMessage::new(
+///     ComponentId::new("app", "client", "v1"),
+///     MessagePayload::new(vec![1]),
+///     MessageMetadata { deadline_ms: Some(1), ..MessageMetadata::default() },
+/// );
+/// assert!(shedder.shed_if_doomed(&worker, &expired));
+/// assert_eq!(ledger.dead_letters()[0].reason, DeadLetterReason::Shedded);
+/// ```
+#[derive(Debug)]
+pub struct LoadShedder {
+    ledger: Arc<RequeueLedger>,
+    queues: Mutex<HashMap<ComponentId, QueueState>>,
+}
+
+impl LoadShedder {
+    /// Creates a shedder filing shed messages in `ledger`.
+    pub fn new(ledger: Arc<RequeueLedger>) -> Self {
+        Self {
+            ledger,
+            queues: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the ledger shed messages are filed in.
+    pub fn ledger(&self) -> &Arc<RequeueLedger> {
+        &self.ledger
+    }
+
+    /// Sheds messages to `id` per `policy`.
+    ///
+    /// Replacing a policy keeps the component's queue statistics.
+    pub fn set_policy(&self, id: ComponentId, policy: ShedPolicy) {
+        let mut queues = self.lock();
+        match queues.get_mut(&id) {
+            Some(state) => state.policy = policy,
+            None => {
+                queues.insert(
+                    id,
+                    QueueState {
+                        policy,
+                        depth: 0,
+                        service: None,
+                        shed: 0,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Stops shedding messages to `id`, returning its policy.
+    pub fn remove_component(&self, id: &ComponentId) -> Option<ShedPolicy> {
+        self.lock().remove(id).map(|state| state.policy)
+    }
+
+    /// Returns the policy of `id`, if it is shed from.
+    pub fn policy(&self, id: &ComponentId) -> Option<ShedPolicy> {
+        self.lock().get(id).map(|state| state.policy.clone())
+    }
+
+    /// Returns the queue statistics of `id`, if it is shed from.
+    pub fn stats(&self, id: &ComponentId) -> Option<ShedStats> {
+        self.lock().get(id).map(|state| ShedStats {
+            depth: state.depth,
+            service_estimate: state.service_estimate(),
+            shed: state.shed,
+        })
+    }
+
+    /// Records that `id` took a message off its queue.
+    pub fn dequeued(&self, id: &ComponentId) {
+        if let Some(state) = self.lock().get_mut(id) {
+            state.depth = state.depth.saturating_sub(1);
+        }
+    }
+
+    /// Records that `id` took `elapsed` to handle a message.
+    pub fn record_service(&self, id: &ComponentId, elapsed: Duration) {
+        if let Some(state) = self.lock().get_mut(id) {
+            state.service = Some(match state.service {
+                Some(average) => {
+                    (average * (SERVICE_TIME_WEIGHT - 1) + elapsed) / SERVICE_TIME_WEIGHT
+                }
+                None => elapsed,
+            });
+        }
+    }
+
+    /// Dead-letters `message` if `id` cannot handle it before its deadline.
+    ///
+    /// Called when the message is about to be handled, so nothing is
+    /// queued ahead of it. Returns true if the message was shed.
+    pub fn shed_if_doomed(&self, id: &ComponentId, message: &ComponentMessage) -> bool {
+        let mut queues = self.lock();
+        let Some(state) = queues.get_mut(id) else {
+            return false;
+        };
+        if !is_doomed(state, message, 0) {
+            return false;
+        }
+        let letter = shed(id, state, message);
+        drop(queues);
+        self.ledger.dead_letter(letter);
+        true
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<ComponentId, QueueState>> {
+        self.queues.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl QueueAdmission for LoadShedder {
+    fn admit(
+        &self,
+        target: &ComponentId,
+        message: &ComponentMessage,
+    ) -> Result<(), MessagingError> {
+        let mut queues = self.lock();
+        let Some(state) = queues.get_mut(target) else {
+            return Ok(());
+        };
+        if !is_doomed(state, message, state.depth) {
+            state.depth += 1;
+            return Ok(());
+        }
+        let depth = state.depth;
+        let letter = shed(target, state, message);
+        drop(queues);
+        self.ledger.dead_letter(letter);
+        Err(MessagingError::Shed(format!(
+            "{} cannot meet the deadline with {depth} messages queued",
+            target.to_string_id()
+        )))
+    }
+
+    fn withdraw(&self, target: &ComponentId) {
+        self.dequeued(target);
+    }
+}
+
+/// Counts `message` as shed from `id` and builds its dead letter.
+fn shed(id: &ComponentId, state: &mut QueueState, message: &ComponentMessage) -> DeadLetter {
+    state.shed += 1;
+    tracing::debug!(
+        component = %id,
+        deadline_ms = message.metadata.deadline_ms,
+        depth = state.depth,
+        "shedding message that cannot meet its deadline"
+    );
+    DeadLetter {
+        component: id.clone(),
+        message: message.clone(),
+        reason: DeadLetterReason::Shedded,
+        attempts: 0,
+        last_error: None,
+        route_to: state.policy.dead_letter().cloned(),
+    }
+}
+
+/// Whether `message`, with `ahead` messages queued before it, will finish
+/// after its deadline.
+fn is_doomed(state: &QueueState, message: &ComponentMessage, ahead: usize) -> bool {
+    let Some(deadline_ms) = message.metadata.deadline_ms else {
+        return false;
+    };
+    let now_ms = u64::try_from(Utc::now().timestamp_millis()).unwrap_or(0);
+    state
+        .policy
+        .would_miss(deadline_ms, now_ms, ahead, state.service_estimate())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::core::component::message::{MessageMetadata, MessagePayload};
+
+    fn worker() -> ComponentId {
+        ComponentId::new("app", "worker", "v1")
+    }
+
+    fn message(deadline_ms: Option<u64>) -> ComponentMessage {
+        ComponentMessage::new(
+            ComponentId::new("app", "client", "v1"),
+            MessagePayload::new(vec![1]),
+            MessageMetadata {
+                deadline_ms,
+                ..MessageMetadata::default()
+            },
+        )
+    }
+
+    fn in_ms(ms: u64) -> Option<u64> {
+        Some(Utc::now().timestamp_millis() as u64 + ms)
+    }
+
+    fn shedder() -> LoadShedder {
+        let shedder = LoadShedder::new(Arc::new(RequeueLedger::new()));
+        shedder.set_policy(
+            worker(),
+            ShedPolicy::new()
+                .with_initial_estimate(Duration::from_millis(100))
+                .with_dead_letter(ComponentId::new("system", "dlq", "0")),
+        );
+        shedder
+    }
+
+    #[test]
+    fn test_admit_sheds_when_queue_is_too_deep() {
+        let shedder = shedder();
+
+        // Room for two 100ms messages before the deadline, not three
+        let deadline = in_ms(250);
+        shedder.admit(&worker(), &message(deadline)).unwrap();
+        shedder.admit(&worker(), &message(deadline)).unwrap();
+        let err = shedder.admit(&worker(), &message(deadline)).unwrap_err();
+
+        assert!(matches!(err, MessagingError::Shed(_)));
+        let stats = shedder.stats(&worker()).unwrap();
+        assert_eq!((stats.depth, stats.shed), (2, 1));
+
+        let dead = shedder.ledger().drain_dead_letters();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].reason, DeadLetterReason::Shedded);
+        assert_eq!(
+            dead[0].route_to,
+            Some(ComponentId::new("system", "dlq", "0"))
+        );
+    }
+
+    #[test]
+    fn test_messages_without_deadline_or_policy_are_kept() {
+        let shedder = shedder();
+        for _ in 0..100 {
+            shedder.admit(&worker(), &message(None)).unwrap();
+        }
+        assert_eq!(shedder.stats(&worker()).unwrap().depth, 100);
+
+        let other = ComponentId::new("app", "other", "v1");
+        assert!(shedder.admit(&other, &message(Some(1))).is_ok());
+        assert!(!shedder.shed_if_doomed(&other, &message(Some(1))));
+        assert!(shedder.stats(&other).is_none());
+    }
+
+    #[test]
+    fn test_dequeue_uses_measured_service_time() {
+        let shedder = shedder();
+        shedder.admit(&worker(), &message(None)).unwrap();
+        shedder.dequeued(&worker());
+        shedder.withdraw(&worker());
+        assert_eq!(shedder.stats(&worker()).unwrap().depth, 0);
+
+        // The initial estimate is too slow for a 50ms deadline...
+        assert!(shedder.shed_if_doomed(&worker(), &message(in_ms(50))));
+
+        // ...but measurements bring it down
+        for _ in 0..20 {
+            shedder.record_service(&worker(), Duration::from_millis(1));
+        }
+        assert!(shedder.stats(&worker()).unwrap().service_estimate < Duration::from_millis(5));
+        assert!(!shedder.shed_if_doomed(&worker(), &message(in_ms(50))));
+    }
+
+    #[test]
+    fn test_set_policy_keeps_stats() {
+        let shedder = shedder();
+        shedder.admit(&worker(), &message(None)).unwrap();
+        shedder.set_policy(worker(), ShedPolicy::new());
+
+        assert_eq!(shedder.stats(&worker()).unwrap().depth, 1);
+        assert_eq!(shedder.policy(&worker()), Some(ShedPolicy::new()));
+        assert!(shedder.remove_component(&worker()).is_some());
+        assert!(shedder.policy(&worker()).is_none());
+    }
+}
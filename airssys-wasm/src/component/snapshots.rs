@@ -0,0 +1,235 @@
+//! # Snapshots - component state across restarts
+//!
+//! A supervised restart replaces the component instance, and with it every
+//! piece of state the guest kept in linear memory. Components that export
+//! `component-state` can hand that state to the host as a
+//! `ComponentSnapshot`; this module decides when wrappers take snapshots and
+//! keeps the latest one per component so the next instance starts from it.
+//!
+//! # Flow
+//!
+//! 1. `ComponentWrapper` snapshots its instance every
+//!    [`SnapshotPolicy::interval`] handled messages and before it unloads
+//!    the component, recording the result in the `SnapshotLedger`.
+//! 2. The supervisor restarts the component with a fresh wrapper that shares
+//!    the same ledger (`Arc<SnapshotLedger>`).
+//! 3. `pre_start()` restores the latest snapshot into the new instance.
+//!
+//! After a crash the instance is not trusted to produce a snapshot, so the
+//! state restored is the one from the last interval.
+//!
+//! # Host Restarts
+//!
+//! The ledger lives in memory. Hosts persist it through the component's
+//! storage with [`SnapshotLedger::persist_to`] on shutdown and reload it
+//! with [`SnapshotLedger::load_from`] before spawning the component.
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+// Layer 2: Third-party crate imports
+// (none needed for this module)
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::runtime::errors::WasmError;
+use crate::core::runtime::snapshot::ComponentSnapshot;
+use crate::core::storage::traits::ComponentStorage;
+
+/// When a component wrapper snapshots its instance.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::component::snapshots::SnapshotPolicy;
+///
+/// // Snapshot after every 10th message and on stop
+/// let policy = SnapshotPolicy::new(10);
+/// assert_eq!(policy.interval(), 10);
+///
+/// // Graceful stops only
+/// assert_eq!(SnapshotPolicy::on_stop().interval(), 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotPolicy {
+    interval: u32,
+}
+
+impl SnapshotPolicy {
+    /// Snapshots after every `interval` handled messages and on stop.
+    ///
+    /// An interval of 0 only snapshots on stop.
+    pub fn new(interval: u32) -> Self {
+        Self { interval }
+    }
+
+    /// Snapshots only when the component is stopped gracefully.
+    pub fn on_stop() -> Self {
+        Self::new(0)
+    }
+
+    /// Messages handled between snapshots (0 = on stop only).
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+}
+
+impl Default for SnapshotPolicy {
+    /// Snapshots after every message, so a crash loses no state.
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+/// Latest snapshot of each component, shared across restarts.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::component::snapshots::SnapshotLedger;
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::runtime::snapshot::ComponentSnapshot;
+///
+/// let ledger = SnapshotLedger::new();
+/// let id = ComponentId::new("acme", "counter", "v1");
+///
+/// ledger.record(ComponentSnapshot::new(id.clone(), vec![1], 0));
+/// ledger.record(ComponentSnapshot::new(id.clone(), vec![2], 0));
+/// assert_eq!(ledger.latest(&id).unwrap().state, vec![2]);
+/// ```
+#[derive(Debug, Default)]
+pub struct SnapshotLedger {
+    snapshots: Mutex<HashMap<ComponentId, ComponentSnapshot>>,
+}
+
+impl SnapshotLedger {
+    /// Creates an empty ledger.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps `snapshot` as its component's latest.
+    pub fn record(&self, snapshot: ComponentSnapshot) {
+        self.lock().insert(snapshot.component_id.clone(), snapshot);
+    }
+
+    /// The latest snapshot of `id`.
+    pub fn latest(&self, id: &ComponentId) -> Option<ComponentSnapshot> {
+        self.lock().get(id).cloned()
+    }
+
+    /// Forgets the snapshot of `id`, so its next start is fresh.
+    pub fn remove(&self, id: &ComponentId) -> Option<ComponentSnapshot> {
+        self.lock().remove(id)
+    }
+
+    /// Number of components with a snapshot.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if no component has a snapshot.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Saves the latest snapshot of `id` in its storage.
+    ///
+    /// Returns `false` if `id` has no snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::RuntimeError` if the storage write fails.
+    pub fn persist_to<S: ComponentStorage + ?Sized>(
+        &self,
+        id: &ComponentId,
+        storage: &S,
+    ) -> Result<bool, WasmError> {
+        match self.latest(id) {
+            Some(snapshot) => snapshot.save_to(storage).map(|()| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Loads the snapshot saved in the storage of `id`.
+    ///
+    /// Returns `false` if the storage holds no snapshot of `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::RuntimeError` if the storage read fails or the
+    /// stored snapshot cannot be decoded.
+    pub fn load_from<S: ComponentStorage + ?Sized>(
+        &self,
+        id: &ComponentId,
+        storage: &S,
+    ) -> Result<bool, WasmError> {
+        match ComponentSnapshot::load_from(storage)? {
+            Some(snapshot) if snapshot.component_id == *id => {
+                self.record(snapshot);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<ComponentId, ComponentSnapshot>> {
+        self.snapshots.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::storage::errors::StorageError;
+    use crate::core::storage::value::StorageValue;
+
+    #[derive(Default)]
+    struct MemoryStorage(Mutex<HashMap<String, StorageValue>>);
+
+    impl ComponentStorage for MemoryStorage {
+        fn get(&self, key: &str) -> Result<Option<StorageValue>, StorageError> {
+            Ok(self.0.lock().unwrap().get(key).cloned())
+        }
+
+        fn set(&self, key: &str, value: StorageValue) -> Result<(), StorageError> {
+            self.0.lock().unwrap().insert(key.to_string(), value);
+            Ok(())
+        }
+
+        fn delete(&self, key: &str) -> Result<(), StorageError> {
+            self.0.lock().unwrap().remove(key);
+            Ok(())
+        }
+
+        fn exists(&self, key: &str) -> Result<bool, StorageError> {
+            Ok(self.0.lock().unwrap().contains_key(key))
+        }
+
+        fn list_keys(&self, _prefix: Option<&str>) -> Result<Vec<String>, StorageError> {
+            Ok(self.0.lock().unwrap().keys().cloned().collect())
+        }
+    }
+
+    #[test]
+    fn test_ledger_survives_host_restart_through_storage() {
+        let id = ComponentId::new("acme", "counter", "v1");
+        let storage = MemoryStorage::default();
+
+        let before = SnapshotLedger::new();
+        assert!(!before.persist_to(&id, &storage).unwrap());
+        before.record(ComponentSnapshot::new(id.clone(), vec![42], 65_536));
+        assert!(before.persist_to(&id, &storage).unwrap());
+
+        let after = SnapshotLedger::new();
+        assert!(after.load_from(&id, &storage).unwrap());
+        assert_eq!(after.latest(&id), before.latest(&id));
+
+        // Only loaded for the component it was taken from
+        let other = ComponentId::new("acme", "other", "v1");
+        assert!(!after.load_from(&other, &storage).unwrap());
+        assert!(after.remove(&id).is_some());
+        assert!(after.is_empty());
+    }
+}
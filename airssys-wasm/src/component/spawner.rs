@@ -7,6 +7,10 @@
 //! - Spawn actor in [`ActorSystem`] via builder pattern
 //! - Register in [`ComponentRegistry`]
 //!
+//! [`ComponentSpawner::spawn_all`] runs the same lifecycle for many
+//! components at once with bounded parallelism, optionally warming each
+//! component up before its actor is spawned.
+//!
 //! # Architecture
 //!
 //! ComponentSpawner is part of Layer 3A (component/ module). It:
@@ -42,22 +46,32 @@
 //! - PROJECTS_STANDARD.md S6.2: Avoid `dyn` Patterns
 
 // Layer 1: Standard library imports
+use std::collections::HashSet;
 use std::fmt;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 // Layer 2: Third-party crate imports
 use airssys_rt::broker::MessageBroker;
 use airssys_rt::system::ActorSystem;
 use airssys_rt::util::ActorAddress;
 use airssys_rt::SystemError;
+use futures::stream::{self, StreamExt};
 use thiserror::Error;
 
 // Layer 3: Internal module imports
 use crate::core::component::id::ComponentId;
+use crate::core::component::message::{ComponentMessage, MessageMetadata, MessagePayload};
 use crate::core::runtime::errors::WasmError;
 use crate::core::runtime::traits::{ComponentLoader, RuntimeEngine};
 
+use super::drain::ShutdownDrain;
+use super::live_config::LiveConfig;
 use super::registry::{ComponentRegistry, RegistryError};
+use super::requeue::{RequeueLedger, RequeuePolicy};
+use super::restart_telemetry::RestartTelemetry;
+use super::shedding::LoadShedder;
+use super::snapshots::{SnapshotLedger, SnapshotPolicy};
 use super::wrapper::{ComponentActorMessage, ComponentWrapper};
 
 /// Errors that can occur during component spawning operations.
@@ -88,6 +102,63 @@ pub enum SpawnerError {
     /// Component is not spawned (stop called on unknown component).
     #[error("Component not spawned: {0}")]
     NotSpawned(String),
+
+    /// A warm-up invocation failed; the actor was not spawned.
+    #[error("Warm-up failed for component '{0}': {1}")]
+    WarmUpFailed(String, WasmError),
+
+    /// The preparation task panicked or was cancelled.
+    #[error("Spawn task failed for component '{0}': {1}")]
+    TaskFailed(String, String),
+}
+
+/// One entry of a bulk spawn: the component and its warm-up invocations.
+///
+/// Warm-up payloads are delivered to the component's `handle-message`
+/// export on a throwaway instance before the actor is spawned, so compiled
+/// code and engine caches are hot when the first real message arrives.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpawnManifest {
+    /// Component to spawn.
+    pub id: ComponentId,
+    /// Payloads sent to the component during warm-up, in order.
+    pub warm_up: Vec<MessagePayload>,
+}
+
+impl SpawnManifest {
+    /// Creates a manifest with no warm-up invocations.
+    pub fn new(id: ComponentId) -> Self {
+        Self {
+            id,
+            warm_up: Vec::new(),
+        }
+    }
+
+    /// Adds a warm-up invocation.
+    pub fn with_warm_up(mut self, payload: MessagePayload) -> Self {
+        self.warm_up.push(payload);
+        self
+    }
+}
+
+/// Result of spawning one component from [`ComponentSpawner::spawn_all`].
+#[derive(Debug)]
+pub struct SpawnOutcome {
+    /// Component the outcome is for.
+    pub id: ComponentId,
+    /// Actor address on success, or the error that stopped this component.
+    pub result: Result<ActorAddress, SpawnerError>,
+    /// Number of warm-up invocations that completed.
+    pub warmed_up: usize,
+    /// Time from start of this component's spawn to its outcome.
+    pub elapsed: Duration,
+}
+
+impl SpawnOutcome {
+    /// Returns true if the component was spawned.
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
 }
 
 /// Spawns and manages component actors in the airssys-rt actor system.
@@ -137,6 +208,24 @@ pub struct ComponentSpawner<E: RuntimeEngine, L: ComponentLoader> {
 
     /// Registry for tracking spawned components (shared)
     registry: Arc<ComponentRegistry>,
+
+    /// Collects `prepare-shutdown` answers from every spawned wrapper
+    shutdown_drain: Option<Arc<ShutdownDrain>>,
+
+    /// Warm instances kept per spawned component (0 = no pooling)
+    warm_pool: usize,
+
+    /// Collects restart causes from every spawned wrapper
+    restart_telemetry: Option<Arc<RestartTelemetry>>,
+
+    /// Carries every spawned component's state across restarts
+    snapshots: Option<(SnapshotPolicy, Arc<SnapshotLedger>)>,
+
+    /// Sheds every spawned component's messages that miss their deadline
+    load_shedder: Option<Arc<LoadShedder>>,
+
+    /// Carries every spawned component's failed messages across restarts
+    requeue: Option<(RequeuePolicy, Arc<RequeueLedger>)>,
 }
 
 impl<E: RuntimeEngine, L: ComponentLoader> ComponentSpawner<E, L> {
@@ -152,9 +241,60 @@ impl<E: RuntimeEngine, L: ComponentLoader> ComponentSpawner<E, L> {
             engine,
             loader,
             registry,
+            shutdown_drain: None,
+            warm_pool: 0,
+            restart_telemetry: None,
+            snapshots: None,
+            load_shedder: None,
+            requeue: None,
         }
     }
 
+    /// Keeps `size` pre-instantiated instances of every spawned component
+    /// in the engine, so restarts and re-spawns start from a warm instance.
+    ///
+    /// Requires an engine that pools (`RuntimeEngine::prewarm`); with
+    /// other engines this has no effect.
+    pub fn with_instance_pool(mut self, size: usize) -> Self {
+        self.warm_pool = size;
+        self
+    }
+
+    /// Reports every spawned component's `prepare-shutdown` answer to `drain`.
+    pub fn with_shutdown_drain(mut self, drain: Arc<ShutdownDrain>) -> Self {
+        self.shutdown_drain = Some(drain);
+        self
+    }
+
+    /// Records why spawned components fail in `telemetry`.
+    pub fn with_restart_telemetry(mut self, telemetry: Arc<RestartTelemetry>) -> Self {
+        self.restart_telemetry = Some(telemetry);
+        self
+    }
+
+    /// Sheds messages to spawned components that can no longer meet their
+    /// deadline, per the policies registered with `shedder`.
+    pub fn with_load_shedder(mut self, shedder: Arc<LoadShedder>) -> Self {
+        self.load_shedder = Some(shedder);
+        self
+    }
+
+    /// Requeues the message that crashed a spawned component per `policy`,
+    /// carrying it across the restart in `ledger`.
+    ///
+    /// Messages that exhaust their attempts are dead-lettered in `ledger`.
+    pub fn with_requeue(mut self, policy: RequeuePolicy, ledger: Arc<RequeueLedger>) -> Self {
+        self.requeue = Some((policy, ledger));
+        self
+    }
+
+    /// Snapshots spawned components per `policy` into `ledger` and
+    /// restores them from it on (re)start.
+    pub fn with_snapshots(mut self, policy: SnapshotPolicy, ledger: Arc<SnapshotLedger>) -> Self {
+        self.snapshots = Some((policy, ledger));
+        self
+    }
+
     /// Spawns a new component actor in the given actor system.
     ///
     /// Performs the full spawn lifecycle:
@@ -193,6 +333,45 @@ impl<E: RuntimeEngine, L: ComponentLoader> ComponentSpawner<E, L> {
     where
         E: 'static,
         B: MessageBroker<ComponentActorMessage> + Clone + Send + Sync + 'static,
+    {
+        self.spawn_wrapper(actor_system, id, |wrapper| wrapper)
+            .await
+    }
+
+    /// Spawns a component whose `[config]` values can be updated live.
+    ///
+    /// Same lifecycle as [`spawn`](Self::spawn); the wrapper reports
+    /// reconfigure outcomes to `live_config`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`spawn`](Self::spawn).
+    pub async fn spawn_with_live_config<B>(
+        &self,
+        actor_system: &ActorSystem<ComponentActorMessage, B>,
+        id: ComponentId,
+        live_config: Arc<LiveConfig>,
+    ) -> Result<ActorAddress, SpawnerError>
+    where
+        E: 'static,
+        B: MessageBroker<ComponentActorMessage> + Clone + Send + Sync + 'static,
+    {
+        self.spawn_wrapper(actor_system, id, |wrapper| {
+            wrapper.with_live_config(live_config)
+        })
+        .await
+    }
+
+    async fn spawn_wrapper<B, F>(
+        &self,
+        actor_system: &ActorSystem<ComponentActorMessage, B>,
+        id: ComponentId,
+        configure: F,
+    ) -> Result<ActorAddress, SpawnerError>
+    where
+        E: 'static,
+        B: MessageBroker<ComponentActorMessage> + Clone + Send + Sync + 'static,
+        F: FnOnce(ComponentWrapper<E>) -> ComponentWrapper<E>,
     {
         // Step 1: Check if already spawned
         let already_exists = self.registry.contains(&id)?;
@@ -210,10 +389,50 @@ impl<E: RuntimeEngine, L: ComponentLoader> ComponentSpawner<E, L> {
         // Step 3: Validate WASM binary
         self.loader
             .validate(&bytes)
-            .map_err(|e| SpawnerError::ValidationFailed(id_str.clone(), e))?;
+            .map_err(|e| SpawnerError::ValidationFailed(id_str, e))?;
+
+        self.spawn_actor(actor_system, id, bytes, configure).await
+    }
+
+    /// Steps 4-6 of the spawn lifecycle for already validated bytes.
+    async fn spawn_actor<B, F>(
+        &self,
+        actor_system: &ActorSystem<ComponentActorMessage, B>,
+        id: ComponentId,
+        bytes: Vec<u8>,
+        configure: F,
+    ) -> Result<ActorAddress, SpawnerError>
+    where
+        E: 'static,
+        B: MessageBroker<ComponentActorMessage> + Clone + Send + Sync + 'static,
+        F: FnOnce(ComponentWrapper<E>) -> ComponentWrapper<E>,
+    {
+        let id_str = id.to_string();
 
         // Step 4: Create ComponentWrapper<E> actor (static dispatch)
-        let wrapper = ComponentWrapper::new(id.clone(), Arc::clone(&self.engine), bytes);
+        let mut wrapper = configure(ComponentWrapper::new(
+            id.clone(),
+            Arc::clone(&self.engine),
+            bytes,
+        ));
+        if let Some(drain) = &self.shutdown_drain {
+            wrapper = wrapper.with_shutdown_drain(Arc::clone(drain));
+        }
+        if self.warm_pool > 0 {
+            wrapper = wrapper.with_warm_pool(self.warm_pool);
+        }
+        if let Some(telemetry) = &self.restart_telemetry {
+            wrapper = wrapper.with_restart_telemetry(Arc::clone(telemetry));
+        }
+        if let Some((policy, ledger)) = &self.snapshots {
+            wrapper = wrapper.with_snapshots(*policy, Arc::clone(ledger));
+        }
+        if let Some(shedder) = &self.load_shedder {
+            wrapper = wrapper.with_load_shedder(Arc::clone(shedder));
+        }
+        if let Some((policy, ledger)) = &self.requeue {
+            wrapper = wrapper.with_requeue(policy.clone(), Arc::clone(ledger));
+        }
 
         // Step 5: Spawn actor via builder pattern
         let actor_name = format!("wasm-component-{}", id_str);
@@ -230,6 +449,127 @@ impl<E: RuntimeEngine, L: ComponentLoader> ComponentSpawner<E, L> {
         Ok(address)
     }
 
+    /// Spawns many components concurrently, at most `max_parallel` at a time.
+    ///
+    /// Each manifest goes through the same lifecycle as [`spawn`](Self::spawn);
+    /// loading, validation, and warm-up run on the blocking thread pool so
+    /// large binaries are prepared in parallel. A failure affects only its own
+    /// component: every manifest gets a [`SpawnOutcome`], returned in manifest
+    /// order. A component listed twice is spawned once; later entries report
+    /// [`SpawnerError::AlreadySpawned`].
+    ///
+    /// `max_parallel` of 0 is treated as 1.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `B` - The message broker type used by the actor system
+    pub async fn spawn_all<B>(
+        &self,
+        actor_system: &ActorSystem<ComponentActorMessage, B>,
+        manifests: Vec<SpawnManifest>,
+        max_parallel: usize,
+    ) -> Vec<SpawnOutcome>
+    where
+        E: 'static,
+        L: 'static,
+        B: MessageBroker<ComponentActorMessage> + Clone + Send + Sync + 'static,
+    {
+        let mut seen = HashSet::new();
+        let tasks = manifests.into_iter().map(|manifest| {
+            let duplicate = !seen.insert(manifest.id.clone());
+            async move {
+                let started = Instant::now();
+                let id = manifest.id.clone();
+                let (result, warmed_up) = if duplicate {
+                    (Err(SpawnerError::AlreadySpawned(id.to_string())), 0)
+                } else {
+                    self.spawn_manifest(actor_system, manifest).await
+                };
+                SpawnOutcome {
+                    id,
+                    result,
+                    warmed_up,
+                    elapsed: started.elapsed(),
+                }
+            }
+        });
+
+        stream::iter(tasks)
+            .buffered(max_parallel.max(1))
+            .collect()
+            .await
+    }
+
+    async fn spawn_manifest<B>(
+        &self,
+        actor_system: &ActorSystem<ComponentActorMessage, B>,
+        manifest: SpawnManifest,
+    ) -> (Result<ActorAddress, SpawnerError>, usize)
+    where
+        E: 'static,
+        L: 'static,
+        B: MessageBroker<ComponentActorMessage> + Clone + Send + Sync + 'static,
+    {
+        let id = manifest.id.clone();
+        match self.registry.contains(&id) {
+            Ok(true) => return (Err(SpawnerError::AlreadySpawned(id.to_string())), 0),
+            Ok(false) => {}
+            Err(e) => return (Err(e.into()), 0),
+        }
+
+        let engine = Arc::clone(&self.engine);
+        let loader = Arc::clone(&self.loader);
+        let prepared =
+            tokio::task::spawn_blocking(move || Self::prepare(&engine, &loader, &manifest)).await;
+        let (bytes, warmed_up) = match prepared {
+            Ok(Ok(prepared)) => prepared,
+            Ok(Err(e)) => return (Err(e), 0),
+            Err(e) => {
+                return (
+                    Err(SpawnerError::TaskFailed(id.to_string(), e.to_string())),
+                    0,
+                )
+            }
+        };
+
+        let result = self
+            .spawn_actor(actor_system, id, bytes, |wrapper| wrapper)
+            .await;
+        (result, warmed_up)
+    }
+
+    /// Loads, validates, and warms up a component; returns its bytes and the
+    /// number of warm-up invocations run.
+    fn prepare(
+        engine: &E,
+        loader: &L,
+        manifest: &SpawnManifest,
+    ) -> Result<(Vec<u8>, usize), SpawnerError> {
+        let id = &manifest.id;
+        let bytes = loader
+            .load_bytes(id)
+            .map_err(|e| SpawnerError::LoadFailed(id.to_string(), e))?;
+        loader
+            .validate(&bytes)
+            .map_err(|e| SpawnerError::ValidationFailed(id.to_string(), e))?;
+
+        if manifest.warm_up.is_empty() {
+            return Ok((bytes, 0));
+        }
+
+        let warm_up_failed = |e| SpawnerError::WarmUpFailed(id.to_string(), e);
+        let handle = engine.load_component(id, &bytes).map_err(warm_up_failed)?;
+        let invoked = manifest.warm_up.iter().try_for_each(|payload| {
+            let msg =
+                ComponentMessage::new(id.clone(), payload.clone(), MessageMetadata::default());
+            engine.call_handle_message(&handle, &msg).map(|_| ())
+        });
+        let unloaded = engine.unload_component(&handle);
+        invoked.and(unloaded).map_err(warm_up_failed)?;
+
+        Ok((bytes, manifest.warm_up.len()))
+    }
+
     /// Stops a component by unregistering it from the registry.
     ///
     /// # Arguments
@@ -299,6 +639,14 @@ impl<E: RuntimeEngine, L: ComponentLoader> fmt::Debug for ComponentSpawner<E, L>
             .field("engine", &"<RuntimeEngine>")
             .field("loader", &"<ComponentLoader>")
             .field("registry", &self.registry)
+            .field("shutdown_drain", &self.shutdown_drain.is_some())
+            .field("restart_telemetry", &self.restart_telemetry.is_some())
+            .field(
+                "snapshots",
+                &self.snapshots.as_ref().map(|(policy, _)| policy),
+            )
+            .field("load_shedder", &self.load_shedder.is_some())
+            .field("requeue", &self.requeue.as_ref().map(|(policy, _)| policy))
             .finish()
     }
 }
@@ -308,7 +656,7 @@ mod tests {
     use super::*;
     use crate::core::component::handle::ComponentHandle;
     use crate::core::component::message::{ComponentMessage, MessagePayload};
-    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
     // ========================================
     // Mock RuntimeEngine for Testing
@@ -316,12 +664,14 @@ mod tests {
 
     struct MockRuntimeEngine {
         should_fail_load: AtomicBool,
+        handled: AtomicUsize,
     }
 
     impl MockRuntimeEngine {
         fn new() -> Self {
             Self {
                 should_fail_load: AtomicBool::new(false),
+                handled: AtomicUsize::new(0),
             }
         }
     }
@@ -349,6 +699,7 @@ mod tests {
             _handle: &ComponentHandle,
             _msg: &ComponentMessage,
         ) -> Result<Option<MessagePayload>, WasmError> {
+            self.handled.fetch_add(1, Ordering::SeqCst);
             Ok(None)
         }
 
@@ -821,4 +1172,140 @@ mod tests {
 
         system.force_shutdown().await;
     }
+
+    // ========================================
+    // spawn_all() Tests
+    // ========================================
+
+    #[tokio::test]
+    async fn test_spawn_all_reports_per_component_outcomes() {
+        use airssys_rt::broker::InMemoryMessageBroker;
+        use airssys_rt::system::SystemConfig;
+
+        let broker = InMemoryMessageBroker::<ComponentActorMessage>::new();
+        let system = ActorSystem::new(SystemConfig::default(), broker);
+        let spawner = create_spawner(MockComponentLoader::new());
+
+        // Already running before the bulk spawn
+        spawner
+            .registry()
+            .register(create_test_id("existing"), ActorAddress::named("existing"))
+            .unwrap();
+
+        let mut manifests: Vec<SpawnManifest> = (0..6)
+            .map(|i| SpawnManifest::new(create_test_id(&format!("bulk-{i}"))))
+            .collect();
+        manifests[0] = manifests[0]
+            .clone()
+            .with_warm_up(MessagePayload::new(b"ping".to_vec()))
+            .with_warm_up(MessagePayload::new(b"ping".to_vec()));
+        manifests.push(SpawnManifest::new(create_test_id("bulk-1")));
+        manifests.push(SpawnManifest::new(create_test_id("existing")));
+
+        let outcomes = spawner.spawn_all(&system, manifests, 3).await;
+
+        assert_eq!(outcomes.len(), 8);
+        assert!(outcomes[..6].iter().all(SpawnOutcome::is_ok));
+        assert_eq!(outcomes[0].id, create_test_id("bulk-0"));
+        assert_eq!(outcomes[0].warmed_up, 2);
+        assert_eq!(outcomes[1].warmed_up, 0);
+        assert!(matches!(
+            outcomes[6].result,
+            Err(SpawnerError::AlreadySpawned(_))
+        ));
+        assert!(matches!(
+            outcomes[7].result,
+            Err(SpawnerError::AlreadySpawned(_))
+        ));
+        assert_eq!(spawner.spawned_count().unwrap(), 7);
+
+        system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_spawn_all_warm_up_failure_does_not_spawn() {
+        use airssys_rt::broker::InMemoryMessageBroker;
+        use airssys_rt::system::SystemConfig;
+
+        let broker = InMemoryMessageBroker::<ComponentActorMessage>::new();
+        let system = ActorSystem::new(SystemConfig::default(), broker);
+        let spawner = create_spawner(MockComponentLoader::new());
+        spawner
+            .engine
+            .should_fail_load
+            .store(true, Ordering::SeqCst);
+
+        let manifests = vec![
+            SpawnManifest::new(create_test_id("cold")),
+            SpawnManifest::new(create_test_id("warm"))
+                .with_warm_up(MessagePayload::new(b"ping".to_vec())),
+        ];
+        let outcomes = spawner.spawn_all(&system, manifests, 0).await;
+
+        assert!(outcomes[0].is_ok());
+        assert!(matches!(
+            outcomes[1].result,
+            Err(SpawnerError::WarmUpFailed(_, _))
+        ));
+        assert!(!spawner.is_spawned(&create_test_id("warm")).unwrap());
+
+        system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_spawn_all_load_failures_are_isolated() {
+        use airssys_rt::broker::InMemoryMessageBroker;
+        use airssys_rt::system::SystemConfig;
+
+        let broker = InMemoryMessageBroker::<ComponentActorMessage>::new();
+        let system = ActorSystem::new(SystemConfig::default(), broker);
+        let spawner = create_spawner(MockComponentLoader::with_load_failure());
+
+        let manifests = vec![
+            SpawnManifest::new(create_test_id("a")),
+            SpawnManifest::new(create_test_id("b")),
+        ];
+        let outcomes = spawner.spawn_all(&system, manifests, 2).await;
+
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes
+            .iter()
+            .all(|o| matches!(o.result, Err(SpawnerError::LoadFailed(_, _)))));
+        assert_eq!(spawner.spawned_count().unwrap(), 0);
+
+        system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_requeued_messages_replayed_on_spawn() {
+        use crate::component::requeue::PendingMessage;
+        use crate::core::component::message::MessageMetadata;
+        use airssys_rt::broker::InMemoryMessageBroker;
+        use airssys_rt::system::SystemConfig;
+
+        let broker = InMemoryMessageBroker::<ComponentActorMessage>::new();
+        let system = ActorSystem::new(SystemConfig::default(), broker);
+        let ledger = Arc::new(RequeueLedger::new());
+        let spawner = create_spawner(MockComponentLoader::new())
+            .with_requeue(RequeuePolicy::new(3), Arc::clone(&ledger));
+
+        // Left behind by a previous incarnation that crashed
+        let id = create_test_id("replayed");
+        ledger.record_failure(
+            &id,
+            vec![PendingMessage::fresh(ComponentMessage::new(
+                create_test_id("sender"),
+                MessagePayload::new(vec![1]),
+                MessageMetadata::default(),
+            ))],
+        );
+        spawner.spawn(&system, id.clone()).await.unwrap();
+
+        // Replayed without any new message arriving
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert_eq!(spawner.engine.handled.load(Ordering::SeqCst), 1);
+        assert_eq!(ledger.pending_count(&id), 0);
+
+        system.force_shutdown().await;
+    }
 }
@@ -23,12 +23,15 @@
 //! - KNOWLEDGE-WASM-038: Component Module Responsibility
 
 // Layer 1: Standard library imports
+use std::collections::VecDeque;
 use std::fmt;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 // Layer 2: Third-party crate imports
 use airssys_rt::broker::MessageBroker;
 use airssys_rt::message::MessagePriority;
+use airssys_rt::supervisor::{FailureClass, RestartCause};
 use airssys_rt::{Actor, ActorContext, ErrorAction, Message};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -38,9 +41,19 @@ use thiserror::Error;
 use crate::core::component::handle::ComponentHandle;
 use crate::core::component::id::ComponentId;
 use crate::core::component::message::ComponentMessage;
+use crate::core::component::message::MessagePriority as CorePriority;
+use crate::core::config::values::ConfigValues;
+use crate::core::messaging::traits::DeliveryAcknowledger;
 use crate::core::runtime::errors::WasmError;
 use crate::core::runtime::traits::RuntimeEngine;
 
+use super::drain::{DrainOutcome, ShutdownDrain};
+use super::live_config::{LiveConfig, ReconfigureOutcome};
+use super::requeue::{PendingMessage, RequeueLedger, RequeuePolicy, RequeuePosition};
+use super::restart_telemetry::{failure_class, RestartTelemetry};
+use super::shedding::LoadShedder;
+use super::snapshots::{SnapshotLedger, SnapshotPolicy};
+
 /// Message type for ComponentWrapper actor.
 ///
 /// Defines the messages that a ComponentWrapper can receive from the
@@ -50,6 +63,9 @@ use crate::core::runtime::traits::RuntimeEngine;
 ///
 /// - `HandleMessage` - Invoke the WASM component's handle-message export
 /// - `HandleCallback` - Deliver a response via handle-callback export
+/// - `Replay` - Process messages requeued after a restart
+/// - `Reconfigure` - Push updated `[config]` values via the reconfigure export
+/// - `PrepareShutdown` - Let the component drain before it is stopped
 /// - `Shutdown` - Gracefully stop the component
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ComponentActorMessage {
@@ -59,6 +75,25 @@ pub enum ComponentActorMessage {
     /// Deliver a callback/response to the WASM component's handle-callback export.
     HandleCallback(ComponentMessage),
 
+    /// Process messages requeued after a restart without waiting for new traffic.
+    Replay,
+
+    /// Apply updated `[config]` values via the reconfigure export.
+    Reconfigure {
+        /// Update generation assigned by `LiveConfig::begin()`.
+        generation: u64,
+        /// The validated update.
+        values: ConfigValues,
+    },
+
+    /// Notify the component that it is about to be stopped.
+    PrepareShutdown {
+        /// Drain round assigned by `ShutdownDrain::begin()`.
+        round: u64,
+        /// Time the component has to finish, in milliseconds.
+        deadline_ms: u64,
+    },
+
     /// Request graceful shutdown of the component.
     Shutdown,
 }
@@ -70,8 +105,18 @@ pub enum ComponentActorMessage {
 impl Message for ComponentActorMessage {
     const MESSAGE_TYPE: &'static str = "component_actor_message";
 
+    /// Component messages carry their (possibly inherited) priority in
+    /// their metadata; control messages use `Normal`.
     fn priority(&self) -> MessagePriority {
-        MessagePriority::Normal
+        match self {
+            Self::HandleMessage(msg) | Self::HandleCallback(msg) => match msg.metadata.priority {
+                CorePriority::Low => MessagePriority::Low,
+                CorePriority::Normal => MessagePriority::Normal,
+                CorePriority::High => MessagePriority::High,
+                CorePriority::Critical => MessagePriority::Critical,
+            },
+            _ => MessagePriority::Normal,
+        }
     }
 }
 
@@ -123,6 +168,45 @@ pub struct ComponentWrapper<E: RuntimeEngine> {
 
     /// WASM component binary bytes
     wasm_bytes: Vec<u8>,
+
+    /// Requeue policy and the ledger shared across restarts (None = drop failed messages)
+    requeue: Option<(RequeuePolicy, Arc<RequeueLedger>)>,
+
+    /// Messages taken from the ledger in `pre_start()`, waiting to be replayed
+    replay: VecDeque<PendingMessage>,
+
+    /// Applied `[config]` values shared with the host (None = outcomes not reported)
+    live_config: Option<Arc<LiveConfig>>,
+
+    /// Drain answers shared with the host (None = answers not reported)
+    shutdown_drain: Option<Arc<ShutdownDrain>>,
+
+    /// Warm instances the engine keeps for this component's restarts (0 = none)
+    warm_pool: usize,
+
+    /// Restart causes shared with the host (None = causes not recorded)
+    restart_telemetry: Option<Arc<RestartTelemetry>>,
+
+    /// When `pre_start()` last succeeded
+    started_at: Option<Instant>,
+
+    /// Type of the message being processed (kept if processing fails)
+    in_flight: Option<String>,
+
+    /// Classification of the runtime error behind the last failure
+    last_failure: Option<FailureClass>,
+
+    /// Snapshot policy and the ledger shared across restarts (None = state is lost)
+    snapshots: Option<(SnapshotPolicy, Arc<SnapshotLedger>)>,
+
+    /// Messages handled since the last snapshot
+    since_snapshot: u32,
+
+    /// Receives handle-message outcomes for acknowledged delivery (None = not reported)
+    acknowledger: Option<Arc<dyn DeliveryAcknowledger>>,
+
+    /// Sheds messages that cannot meet their deadline (None = never shed)
+    load_shedder: Option<Arc<LoadShedder>>,
 }
 
 // Manual Debug implementation - engine field uses opaque display
@@ -134,6 +218,18 @@ impl<E: RuntimeEngine> fmt::Debug for ComponentWrapper<E> {
             .field("engine", &"<RuntimeEngine>")
             .field("handle", &self.handle)
             .field("wasm_bytes_len", &self.wasm_bytes.len())
+            .field("requeue", &self.requeue.as_ref().map(|(policy, _)| policy))
+            .field("replay_len", &self.replay.len())
+            .field("live_config", &self.live_config.is_some())
+            .field("shutdown_drain", &self.shutdown_drain.is_some())
+            .field("warm_pool", &self.warm_pool)
+            .field("restart_telemetry", &self.restart_telemetry.is_some())
+            .field(
+                "snapshots",
+                &self.snapshots.as_ref().map(|(policy, _)| policy),
+            )
+            .field("acknowledger", &self.acknowledger.is_some())
+            .field("load_shedder", &self.load_shedder.is_some())
             .finish()
     }
 }
@@ -157,9 +253,107 @@ impl<E: RuntimeEngine> ComponentWrapper<E> {
             engine,
             handle: None,
             wasm_bytes,
+            requeue: None,
+            replay: VecDeque::new(),
+            live_config: None,
+            shutdown_drain: None,
+            warm_pool: 0,
+            restart_telemetry: None,
+            started_at: None,
+            in_flight: None,
+            last_failure: None,
+            snapshots: None,
+            since_snapshot: 0,
+            acknowledger: None,
+            load_shedder: None,
         }
     }
 
+    /// Carries the component's state across restarts.
+    ///
+    /// The same `ledger` must be passed to every wrapper the supervisor
+    /// creates for this component. Components that do not export
+    /// `component-state` run as if snapshots were disabled.
+    pub fn with_snapshots(mut self, policy: SnapshotPolicy, ledger: Arc<SnapshotLedger>) -> Self {
+        self.snapshots = Some((policy, ledger));
+        self
+    }
+
+    /// Records why this component fails in a shared `RestartTelemetry`.
+    ///
+    /// The same `telemetry` must be passed to every wrapper the supervisor
+    /// creates for this component so statistics accumulate across restarts.
+    pub fn with_restart_telemetry(mut self, telemetry: Arc<RestartTelemetry>) -> Self {
+        self.restart_telemetry = Some(telemetry);
+        self
+    }
+
+    /// Keeps `size` pre-instantiated instances of this component in the
+    /// engine so supervisor restarts skip instantiation.
+    ///
+    /// The reserve is refilled in the background after every start.
+    pub fn with_warm_pool(mut self, size: usize) -> Self {
+        self.warm_pool = size;
+        self
+    }
+
+    /// Reports `prepare-shutdown` answers to a shared `ShutdownDrain`.
+    pub fn with_shutdown_drain(mut self, drain: Arc<ShutdownDrain>) -> Self {
+        self.shutdown_drain = Some(drain);
+        self
+    }
+
+    /// Reports config update outcomes to a shared `LiveConfig`.
+    ///
+    /// The same `live_config` must be passed to every wrapper the supervisor
+    /// creates for this component so applied values survive restarts.
+    pub fn with_live_config(mut self, live_config: Arc<LiveConfig>) -> Self {
+        self.live_config = Some(live_config);
+        self
+    }
+
+    /// Enables requeueing of the message that crashed the component.
+    ///
+    /// The same `ledger` must be passed to every wrapper the supervisor
+    /// creates for this component so failed messages survive the restart.
+    /// Without a policy, a failed message is dropped (the default).
+    pub fn with_requeue(mut self, policy: RequeuePolicy, ledger: Arc<RequeueLedger>) -> Self {
+        self.requeue = Some((policy, ledger));
+        self
+    }
+
+    /// Reports the outcome of every `handle-message` call carrying an
+    /// `idempotency_key` to `acknowledger`.
+    ///
+    /// Retrying such messages is then up to the acknowledger, so a failed
+    /// one is not also recorded in the requeue ledger.
+    pub fn with_acknowledger(mut self, acknowledger: Arc<dyn DeliveryAcknowledger>) -> Self {
+        self.acknowledger = Some(acknowledger);
+        self
+    }
+
+    /// Sheds messages that can no longer meet their deadline and measures
+    /// how long this component takes per message.
+    ///
+    /// The same `shedder` must be passed to every wrapper the supervisor
+    /// creates for this component and to the subscriber delivering to it,
+    /// so its queue depth stays accurate. Shed messages carrying an
+    /// `idempotency_key` are acknowledged so they are not redelivered.
+    pub fn with_load_shedder(mut self, shedder: Arc<LoadShedder>) -> Self {
+        self.load_shedder = Some(shedder);
+        self
+    }
+
+    /// Returns the requeue policy, if requeueing is enabled.
+    pub fn requeue_policy(&self) -> Option<&RequeuePolicy> {
+        self.requeue.as_ref().map(|(policy, _)| policy)
+    }
+
+    /// Returns the number of requeued messages waiting to be replayed.
+    pub fn pending_replay(&self) -> usize {
+        self.replay.len()
+    }
+
     /// Returns a reference to the component's identifier.
     pub fn id(&self) -> &ComponentId {
         &self.id
@@ -176,6 +370,125 @@ impl<E: RuntimeEngine> ComponentWrapper<E> {
     pub fn is_loaded(&self) -> bool {
         self.handle.is_some()
     }
+
+    /// Processes `batch` in order, stopping at the first failure.
+    ///
+    /// On failure the failed message (with its attempt count bumped) and
+    /// every message after it are recorded in the requeue ledger, if enabled.
+    /// Messages that can no longer meet their deadline are shed instead of
+    /// processed, if a load shedder is attached.
+    fn process_batch(
+        &mut self,
+        mut batch: VecDeque<PendingMessage>,
+    ) -> Result<(), ComponentWrapperError> {
+        let handle = self.handle.as_ref().ok_or_else(|| {
+            ComponentWrapperError::new(
+                "Component not started - handle_message called before pre_start",
+            )
+        })?;
+
+        while let Some(mut entry) = batch.pop_front() {
+            let acked = self
+                .acknowledger
+                .as_ref()
+                .zip(entry.message.metadata.idempotency_key.as_deref());
+
+            if let Some(shedder) = &self.load_shedder {
+                if shedder.shed_if_doomed(&self.id, &entry.message) {
+                    if let Some((acknowledger, key)) = acked {
+                        acknowledger.ack(&self.id, key);
+                    }
+                    continue;
+                }
+            }
+
+            // Response routing is delegated to messaging module
+            let started = Instant::now();
+            let result = self.engine.call_handle_message(handle, &entry.message);
+            if let Some(shedder) = &self.load_shedder {
+                shedder.record_service(&self.id, started.elapsed());
+            }
+            if let Err(err) = result {
+                self.last_failure = Some(failure_class(&err));
+                let err = ComponentWrapperError::from_wasm_error(err);
+                if let Some((acknowledger, key)) = acked {
+                    acknowledger.nack(&self.id, key, &err.to_string());
+                }
+                if let Some((_, ledger)) = &self.requeue {
+                    if acked.is_none() {
+                        entry.attempts += 1;
+                        entry.last_error = Some(err.to_string());
+                        batch.push_front(entry);
+                    }
+                    ledger.record_failure(&self.id, batch.into());
+                }
+                return Err(err);
+            }
+            if let Some((acknowledger, key)) = acked {
+                acknowledger.ack(&self.id, key);
+            }
+        }
+        Ok(())
+    }
+
+    /// Snapshots the loaded instance into the ledger, if enabled.
+    ///
+    /// Never fails: a missed snapshot only makes the next restart older.
+    fn take_snapshot(&mut self) {
+        self.since_snapshot = 0;
+        let (Some(handle), Some((_, ledger))) = (&self.handle, &self.snapshots) else {
+            return;
+        };
+        match self.engine.snapshot(handle) {
+            Ok(snapshot) => ledger.record(snapshot),
+            Err(WasmError::ExportNotFound(_)) => {
+                tracing::debug!(component = %self.id, "component does not export its state; snapshots disabled");
+                self.snapshots = None;
+            }
+            Err(e) => {
+                tracing::warn!(component = %self.id, error = %e, "failed to snapshot component");
+            }
+        }
+    }
+
+    /// Counts a handled message and snapshots when the interval is reached.
+    fn message_handled(&mut self) {
+        let Some((policy, _)) = &self.snapshots else {
+            return;
+        };
+        self.since_snapshot += 1;
+        if policy.interval() > 0 && self.since_snapshot >= policy.interval() {
+            self.take_snapshot();
+        }
+    }
+
+    /// Records a failure of this component, if telemetry is enabled.
+    fn record_failure(&mut self, class: FailureClass, error: String) {
+        if let Some(telemetry) = &self.restart_telemetry {
+            let uptime = self.started_at.map(|t| t.elapsed()).unwrap_or_default();
+            let cause = RestartCause::new(class, error, self.in_flight.take(), uptime);
+            telemetry.record_failure(&self.id, cause);
+        }
+    }
+}
+
+/// Returns the type recorded for `message` in restart causes.
+///
+/// Component messages report their content type when they have one.
+fn message_type(message: &ComponentActorMessage) -> String {
+    let content_type = |msg: &ComponentMessage| msg.metadata.content_type.clone();
+    match message {
+        ComponentActorMessage::HandleMessage(msg) => {
+            content_type(msg).unwrap_or_else(|| "handle-message".to_string())
+        }
+        ComponentActorMessage::HandleCallback(msg) => {
+            content_type(msg).unwrap_or_else(|| "handle-callback".to_string())
+        }
+        ComponentActorMessage::Replay => "replay".to_string(),
+        ComponentActorMessage::Reconfigure { .. } => "reconfigure".to_string(),
+        ComponentActorMessage::PrepareShutdown { .. } => "prepare-shutdown".to_string(),
+        ComponentActorMessage::Shutdown => "shutdown".to_string(),
+    }
 }
 
 /// Error type for ComponentWrapper operations.
@@ -215,6 +528,7 @@ impl<E: RuntimeEngine + 'static> Actor for ComponentWrapper<E> {
     ///
     /// - `HandleMessage` - Calls engine.call_handle_message()
     /// - `HandleCallback` - Calls engine.call_handle_callback()
+    /// - `Replay` - Calls engine.call_handle_message() for requeued messages
     /// - `Shutdown` - Unloads component and returns
     ///
     /// # Errors
@@ -225,21 +539,66 @@ impl<E: RuntimeEngine + 'static> Actor for ComponentWrapper<E> {
         message: Self::Message,
         _context: &mut ActorContext<Self::Message, B>,
     ) -> Result<(), Self::Error> {
-        match message {
+        self.in_flight = Some(message_type(&message));
+        let result = match message {
             ComponentActorMessage::HandleMessage(component_msg) => {
+                if let Some(shedder) = &self.load_shedder {
+                    shedder.dequeued(&self.id);
+                }
+
+                // Requeued messages go before or after the new one per policy
+                let mut batch = std::mem::take(&mut self.replay);
+                let incoming = PendingMessage::fresh(component_msg);
+                match self.requeue_policy().map(RequeuePolicy::position) {
+                    Some(RequeuePosition::Tail) => batch.push_front(incoming),
+                    _ => batch.push_back(incoming),
+                }
+
+                // Delegate to runtime engine for WASM execution
+                self.process_batch(batch).map(|()| self.message_handled())
+            }
+
+            ComponentActorMessage::Replay => {
+                let batch = std::mem::take(&mut self.replay);
+                self.process_batch(batch).map(|()| self.message_handled())
+            }
+
+            ComponentActorMessage::Reconfigure { generation, values } => {
                 let handle = self.handle.as_ref().ok_or_else(|| {
                     ComponentWrapperError::new(
-                        "Component not started - handle_message called before pre_start",
+                        "Component not started - reconfigure called before pre_start",
                     )
                 })?;
 
-                // Delegate to runtime engine for WASM execution
-                let _response = self
-                    .engine
-                    .call_handle_message(handle, &component_msg)
-                    .map_err(ComponentWrapperError::from_wasm_error)?;
+                // A failed update must not restart the component: report it
+                // as a rejection and keep running with the previous values
+                let outcome = match self.engine.call_reconfigure(handle, &values) {
+                    Ok(()) => ReconfigureOutcome::Accepted,
+                    Err(WasmError::ConfigRejected(reason)) => ReconfigureOutcome::Rejected(reason),
+                    Err(err) => ReconfigureOutcome::Rejected(err.to_string()),
+                };
+                if let Some(live_config) = &self.live_config {
+                    live_config.complete(generation, &values, outcome);
+                }
+                Ok(())
+            }
 
-                // Response routing is delegated to messaging module
+            ComponentActorMessage::PrepareShutdown { round, deadline_ms } => {
+                // Never fails the actor: the host stops the component anyway
+                let outcome = match self.handle.as_ref() {
+                    None => DrainOutcome::Ready,
+                    Some(handle) => match self
+                        .engine
+                        .call_prepare_shutdown(handle, Duration::from_millis(deadline_ms))
+                    {
+                        // Nothing to flush without the export
+                        Ok(()) | Err(WasmError::ExportNotFound(_)) => DrainOutcome::Ready,
+                        Err(err) => DrainOutcome::Failed(err.to_string()),
+                    },
+                };
+                if let Some(drain) = &self.shutdown_drain {
+                    drain.complete(round, &self.id, outcome);
+                }
                 Ok(())
             }
 
@@ -252,13 +611,17 @@ impl<E: RuntimeEngine + 'static> Actor for ComponentWrapper<E> {
 
                 self.engine
                     .call_handle_callback(handle, &component_msg)
-                    .map_err(ComponentWrapperError::from_wasm_error)?;
+                    .map_err(|err| {
+                        self.last_failure = Some(failure_class(&err));
+                        ComponentWrapperError::from_wasm_error(err)
+                    })?;
 
                 Ok(())
             }
 
             ComponentActorMessage::Shutdown => {
                 // Graceful shutdown - unload component
+                self.take_snapshot();
                 if let Some(handle) = self.handle.take() {
                     self.engine
                         .unload_component(&handle)
@@ -266,7 +629,13 @@ impl<E: RuntimeEngine + 'static> Actor for ComponentWrapper<E> {
                 }
                 Ok(())
             }
+        };
+
+        // A failed message stays in flight until on_error() records it
+        if result.is_ok() {
+            self.in_flight = None;
         }
+        result
     }
 
     /// Initialize the component by loading WASM binary.
@@ -275,15 +644,75 @@ impl<E: RuntimeEngine + 'static> Actor for ComponentWrapper<E> {
     /// Loads the WASM component using the injected RuntimeEngine.
     async fn pre_start<B: MessageBroker<Self::Message>>(
         &mut self,
-        _context: &mut ActorContext<Self::Message, B>,
+        context: &mut ActorContext<Self::Message, B>,
     ) -> Result<(), Self::Error> {
         // Load WASM component via injected engine
-        let handle = self
-            .engine
-            .load_component(&self.id, &self.wasm_bytes)
-            .map_err(ComponentWrapperError::from_wasm_error)?;
+        let handle = match self.engine.load_component(&self.id, &self.wasm_bytes) {
+            Ok(handle) => handle,
+            Err(err) => {
+                self.record_failure(FailureClass::StartFailure, err.to_string());
+                return Err(ComponentWrapperError::from_wasm_error(err));
+            }
+        };
 
         self.handle = Some(handle);
+        self.started_at = Some(Instant::now());
+        if let Some(telemetry) = &self.restart_telemetry {
+            telemetry.record_start(&self.id);
+        }
+
+        // Refill the warm reserve off the start path, within the
+        // component's share of blocking threads if the engine bounds it
+        if self.warm_pool > 0 {
+            let engine = Arc::clone(&self.engine);
+            let id = self.id.clone();
+            let bytes = self.wasm_bytes.clone();
+            let size = self.warm_pool;
+            let refill = move || {
+                if let Err(e) = engine.prewarm(&id, &bytes, size) {
+                    tracing::warn!(component = %id, error = %e, "failed to refill warm instance pool");
+                }
+            };
+            match self.engine.blocking_runner() {
+                Some(runner) => {
+                    if let Err(e) = runner.spawn_blocking(&self.id, Box::new(refill)) {
+                        tracing::warn!(component = %self.id, error = %e, "skipped warm instance pool refill");
+                    }
+                }
+                None => {
+                    tokio::task::spawn_blocking(refill);
+                }
+            }
+        }
+
+        // Continue from the state of the previous incarnation
+        if let (Some(handle), Some((_, ledger))) = (&self.handle, &self.snapshots) {
+            if let Some(snapshot) = ledger.latest(&self.id) {
+                match self.engine.restore(handle, &snapshot) {
+                    Ok(()) | Err(WasmError::ExportNotFound(_)) => {}
+                    Err(e) => {
+                        tracing::warn!(component = %self.id, error = %e, "failed to restore snapshot; starting fresh");
+                    }
+                }
+            }
+        }
+
+        // Pick up messages left by a previous incarnation of this component.
+        // Head replays run before anything waiting in the mailbox; tail
+        // replays go out with the next new message.
+        if let Some((policy, ledger)) = &self.requeue {
+            self.replay = ledger.take_for_restart(&self.id, policy);
+            if !self.replay.is_empty() && policy.position() == RequeuePosition::Head {
+                match context.stash(ComponentActorMessage::Replay) {
+                    Ok(()) => {
+                        context.unstash_all();
+                    }
+                    Err(_) => {
+                        tracing::warn!(component = %self.id, "stash full; replaying with the next message");
+                    }
+                }
+            }
+        }
         Ok(())
     }
 
@@ -295,6 +724,7 @@ impl<E: RuntimeEngine + 'static> Actor for ComponentWrapper<E> {
         _context: &mut ActorContext<Self::Message, B>,
     ) -> Result<(), Self::Error> {
         // Unload component if still loaded
+        self.take_snapshot();
         if let Some(handle) = self.handle.take() {
             self.engine
                 .unload_component(&handle)
@@ -309,9 +739,15 @@ impl<E: RuntimeEngine + 'static> Actor for ComponentWrapper<E> {
     /// Supervisors can override this via SupervisorConfig.
     async fn on_error<B: MessageBroker<Self::Message>>(
         &mut self,
-        _error: Self::Error,
+        error: Self::Error,
         _context: &mut ActorContext<Self::Message, B>,
     ) -> ErrorAction {
+        let class = self
+            .last_failure
+            .take()
+            .unwrap_or_else(|| FailureClass::classify(&error));
+        self.record_failure(class, error.to_string());
+
         // Default: stop actor on error
         // Supervisor can restart based on SupervisorConfig
         ErrorAction::Stop
@@ -330,6 +766,9 @@ mod tests {
 
     // Import test-only types from core
     use crate::core::component::message::{MessageMetadata, MessagePayload};
+    use crate::core::config::values::ConfigValue;
+    use crate::core::runtime::snapshot::ComponentSnapshot;
+    use crate::core::runtime::traits::BlockingRunner;
 
     // ========================================
     // Mock RuntimeEngine for Testing
@@ -343,6 +782,23 @@ mod tests {
         should_fail_load: AtomicBool,
         should_fail_message: AtomicBool,
         last_message: Mutex<Option<ComponentMessage>>,
+        prewarmed: Mutex<Option<usize>>,
+        blocking_refused: AtomicBool,
+    }
+
+    /// Blocking runner whose pools are always full.
+    struct SaturatedRunner;
+
+    impl BlockingRunner for SaturatedRunner {
+        fn spawn_blocking(
+            &self,
+            component: &ComponentId,
+            _work: Box<dyn FnOnce() + Send>,
+        ) -> Result<(), WasmError> {
+            Err(WasmError::ResourceLimitExceeded(format!(
+                "blocking pool of {component} is saturated"
+            )))
+        }
     }
 
     impl MockRuntimeEngine {
@@ -355,6 +811,8 @@ mod tests {
                 should_fail_load: AtomicBool::new(false),
                 should_fail_message: AtomicBool::new(false),
                 last_message: Mutex::new(None),
+                prewarmed: Mutex::new(None),
+                blocking_refused: AtomicBool::new(false),
             }
         }
 
@@ -419,6 +877,37 @@ mod tests {
             }
             Ok(())
         }
+
+        fn call_reconfigure(
+            &self,
+            _handle: &ComponentHandle,
+            values: &ConfigValues,
+        ) -> Result<(), WasmError> {
+            match values.get("threshold") {
+                Some(ConfigValue::Integer(n)) if *n < 0 => Err(WasmError::ConfigRejected(
+                    "threshold must be positive".to_string(),
+                )),
+                _ => Ok(()),
+            }
+        }
+
+        fn prewarm(
+            &self,
+            _id: &ComponentId,
+            _bytes: &[u8],
+            count: usize,
+        ) -> Result<usize, WasmError> {
+            *self.prewarmed.lock().unwrap() = Some(count);
+            Ok(count)
+        }
+
+        fn blocking_runner(&self) -> Option<Arc<dyn BlockingRunner>> {
+            if self.blocking_refused.load(Ordering::SeqCst) {
+                Some(Arc::new(SaturatedRunner))
+            } else {
+                None
+            }
+        }
     }
 
     // ========================================
@@ -562,6 +1051,18 @@ mod tests {
         assert_eq!(msg.priority(), MessagePriority::Normal);
     }
 
+    #[test]
+    fn test_message_priority_from_metadata() {
+        let mut msg = create_test_message(create_test_id());
+        msg.metadata.priority = CorePriority::High;
+        let actor_msg = ComponentActorMessage::HandleMessage(msg);
+        assert_eq!(actor_msg.priority(), MessagePriority::High);
+        assert_eq!(
+            airssys_rt::message::MessageEnvelope::new(actor_msg).priority,
+            MessagePriority::High
+        );
+    }
+
     // ========================================
     // ComponentWrapperError Tests
     // ========================================
@@ -618,6 +1119,47 @@ mod tests {
         assert!(mock_engine.load_called.load(Ordering::SeqCst));
     }
 
+    #[tokio::test]
+    async fn test_actor_pre_start_refills_warm_pool() {
+        let mock_engine = Arc::new(MockRuntimeEngine::new());
+        let mut wrapper =
+            ComponentWrapper::new(create_test_id(), Arc::clone(&mock_engine), vec![0u8; 100])
+                .with_warm_pool(3);
+
+        wrapper.pre_start(&mut create_test_context()).await.unwrap();
+        assert!(wrapper.is_loaded());
+
+        for _ in 0..100 {
+            if mock_engine.prewarmed.lock().unwrap().is_some() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        assert_eq!(*mock_engine.prewarmed.lock().unwrap(), Some(3));
+
+        // Without a pool the engine is never asked to prewarm
+        let plain_engine = Arc::new(MockRuntimeEngine::new());
+        let mut plain = ComponentWrapper::new(create_test_id(), Arc::clone(&plain_engine), vec![]);
+        plain.pre_start(&mut create_test_context()).await.unwrap();
+        tokio::task::yield_now().await;
+        assert!(plain_engine.prewarmed.lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_warm_pool_refill_goes_through_blocking_runner() {
+        let mock_engine = Arc::new(MockRuntimeEngine::new());
+        mock_engine.blocking_refused.store(true, Ordering::SeqCst);
+        let mut wrapper =
+            ComponentWrapper::new(create_test_id(), Arc::clone(&mock_engine), vec![0u8; 100])
+                .with_warm_pool(3);
+
+        // A saturated pool skips the refill without failing the start
+        wrapper.pre_start(&mut create_test_context()).await.unwrap();
+        assert!(wrapper.is_loaded());
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(mock_engine.prewarmed.lock().unwrap().is_none());
+    }
+
     #[tokio::test]
     async fn test_actor_pre_start_failure() {
         let id = create_test_id();
@@ -777,6 +1319,29 @@ mod tests {
             .contains("Component not started"));
     }
 
+    #[tokio::test]
+    async fn test_prepare_shutdown_reports_readiness() {
+        let id = create_test_id();
+        let drain = Arc::new(ShutdownDrain::new());
+        let mut wrapper =
+            ComponentWrapper::new(id.clone(), Arc::new(MockRuntimeEngine::new()), vec![])
+                .with_shutdown_drain(Arc::clone(&drain));
+        let mut context = create_test_context();
+        let _ = wrapper.pre_start(&mut context).await;
+
+        // The mock has no prepare-shutdown export, which counts as ready
+        let round = drain.begin(std::slice::from_ref(&id));
+        let msg = ComponentActorMessage::PrepareShutdown {
+            round,
+            deadline_ms: 100,
+        };
+        assert!(wrapper.handle_message(msg, &mut context).await.is_ok());
+
+        let report = drain.wait(round, Duration::from_millis(50)).await;
+        assert_eq!(report.ready, vec![id]);
+        assert!(wrapper.is_loaded(), "draining does not unload");
+    }
+
     #[tokio::test]
     async fn test_actor_shutdown_message() {
         let id = create_test_id();
@@ -870,6 +1435,404 @@ mod tests {
         assert!(mock_engine.unload_called.load(Ordering::SeqCst));
     }
 
+    // ========================================
+    // Requeue Tests
+    // ========================================
+
+    fn payload_message(sender: ComponentId, byte: u8) -> ComponentMessage {
+        ComponentMessage::new(
+            sender,
+            MessagePayload::new(vec![byte]),
+            MessageMetadata::default(),
+        )
+    }
+
+    fn last_payload(engine: &MockRuntimeEngine) -> Option<Vec<u8>> {
+        engine
+            .last_message
+            .lock()
+            .ok()
+            .and_then(|guard| guard.as_ref().map(|m| m.payload.as_bytes().to_vec()))
+    }
+
+    #[tokio::test]
+    async fn test_failed_message_is_replayed_after_restart() {
+        let id = create_test_id();
+        let ledger = Arc::new(RequeueLedger::new());
+        let engine = Arc::new(MockRuntimeEngine::new().with_message_failure());
+        let mut context = create_test_context();
+
+        let mut first = ComponentWrapper::new(id.clone(), Arc::clone(&engine), vec![])
+            .with_requeue(RequeuePolicy::new(3), Arc::clone(&ledger));
+        let _ = first.pre_start(&mut context).await;
+        let msg = ComponentActorMessage::HandleMessage(payload_message(id.clone(), 7));
+        assert!(first.handle_message(msg, &mut context).await.is_err());
+        assert_eq!(ledger.pending_count(&id), 1);
+
+        // Supervisor restarts the component with a fresh wrapper
+        engine.should_fail_message.store(false, Ordering::SeqCst);
+        let mut second = ComponentWrapper::new(id.clone(), Arc::clone(&engine), vec![])
+            .with_requeue(RequeuePolicy::new(3), Arc::clone(&ledger));
+        let _ = second.pre_start(&mut context).await;
+        assert_eq!(second.pending_replay(), 1);
+
+        let result = second
+            .handle_message(ComponentActorMessage::Replay, &mut context)
+            .await;
+        assert!(result.is_ok());
+        assert_eq!(second.pending_replay(), 0);
+        assert_eq!(last_payload(&engine), Some(vec![7]));
+    }
+
+    /// Records ack/nack calls as `("ack" | "nack", key)`.
+    #[derive(Default)]
+    struct RecordingAcknowledger {
+        outcomes: Mutex<Vec<(&'static str, String)>>,
+    }
+
+    impl DeliveryAcknowledger for RecordingAcknowledger {
+        fn ack(&self, _target: &ComponentId, idempotency_key: &str) {
+            self.outcomes
+                .lock()
+                .unwrap()
+                .push(("ack", idempotency_key.to_string()));
+        }
+
+        fn nack(&self, _target: &ComponentId, idempotency_key: &str, _error: &str) {
+            self.outcomes
+                .lock()
+                .unwrap()
+                .push(("nack", idempotency_key.to_string()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_acknowledged_messages_report_outcome_instead_of_requeue() {
+        let id = create_test_id();
+        let ledger = Arc::new(RequeueLedger::new());
+        let acknowledger = Arc::new(RecordingAcknowledger::default());
+        let engine = Arc::new(MockRuntimeEngine::new());
+        let mut context = create_test_context();
+        let mut wrapper = ComponentWrapper::new(id.clone(), Arc::clone(&engine), vec![])
+            .with_requeue(RequeuePolicy::new(3), Arc::clone(&ledger))
+            .with_acknowledger(acknowledger.clone());
+        let _ = wrapper.pre_start(&mut context).await;
+
+        let mut keyed = payload_message(id.clone(), 1);
+        keyed.metadata.idempotency_key = Some("k1".to_string());
+        let msg = ComponentActorMessage::HandleMessage(keyed.clone());
+        assert!(wrapper.handle_message(msg, &mut context).await.is_ok());
+
+        // Messages without a key are not reported
+        let msg = ComponentActorMessage::HandleMessage(payload_message(id.clone(), 2));
+        assert!(wrapper.handle_message(msg, &mut context).await.is_ok());
+
+        engine.should_fail_message.store(true, Ordering::SeqCst);
+        keyed.metadata.idempotency_key = Some("k2".to_string());
+        let msg = ComponentActorMessage::HandleMessage(keyed);
+        assert!(wrapper.handle_message(msg, &mut context).await.is_err());
+
+        assert_eq!(
+            *acknowledger.outcomes.lock().unwrap(),
+            vec![("ack", "k1".to_string()), ("nack", "k2".to_string())]
+        );
+        // The acknowledger owns the retry
+        assert_eq!(ledger.pending_count(&id), 0);
+    }
+
+    #[tokio::test]
+    async fn test_doomed_messages_are_shed_instead_of_handled() {
+        use crate::component::requeue::DeadLetterReason;
+        use crate::core::config::shedding::ShedPolicy;
+        use crate::core::messaging::traits::QueueAdmission;
+
+        let id = create_test_id();
+        let ledger = Arc::new(RequeueLedger::new());
+        let shedder = Arc::new(LoadShedder::new(Arc::clone(&ledger)));
+        shedder.set_policy(id.clone(), ShedPolicy::new());
+        let acknowledger = Arc::new(RecordingAcknowledger::default());
+        let engine = Arc::new(MockRuntimeEngine::new());
+        let mut context = create_test_context();
+        let mut wrapper = ComponentWrapper::new(id.clone(), Arc::clone(&engine), vec![])
+            .with_load_shedder(Arc::clone(&shedder))
+            .with_acknowledger(acknowledger.clone());
+        let _ = wrapper.pre_start(&mut context).await;
+
+        // Admitted with time to spare, but expired by the time it is dequeued
+        let mut expired = payload_message(id.clone(), 1);
+        expired.metadata.idempotency_key = Some("k1".to_string());
+        shedder.admit(&id, &expired).unwrap();
+        expired.metadata.deadline_ms = Some(1);
+        let msg = ComponentActorMessage::HandleMessage(expired);
+        assert!(wrapper.handle_message(msg, &mut context).await.is_ok());
+
+        assert!(!engine.handle_message_called.load(Ordering::SeqCst));
+        assert_eq!(
+            *acknowledger.outcomes.lock().unwrap(),
+            vec![("ack", "k1".to_string())]
+        );
+        let dead = ledger.drain_dead_letters();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].reason, DeadLetterReason::Shedded);
+
+        // Messages without a deadline are handled and timed
+        let msg = ComponentActorMessage::HandleMessage(payload_message(id.clone(), 2));
+        assert!(wrapper.handle_message(msg, &mut context).await.is_ok());
+        assert_eq!(last_payload(&engine), Some(vec![2]));
+        let stats = shedder.stats(&id).unwrap();
+        assert_eq!((stats.depth, stats.shed), (0, 1));
+    }
+
+    #[tokio::test]
+    async fn test_failure_cause_recorded_across_restart() {
+        let id = create_test_id();
+        let telemetry = Arc::new(RestartTelemetry::new());
+        let engine = Arc::new(MockRuntimeEngine::new().with_message_failure());
+        let mut context = create_test_context();
+
+        let mut first = ComponentWrapper::new(id.clone(), Arc::clone(&engine), vec![])
+            .with_restart_telemetry(Arc::clone(&telemetry));
+        let _ = first.pre_start(&mut context).await;
+        let mut msg = create_test_message(id.clone());
+        msg.metadata.content_type = Some("image/png".to_string());
+        let error = first
+            .handle_message(ComponentActorMessage::HandleMessage(msg), &mut context)
+            .await
+            .unwrap_err();
+        first.on_error(error, &mut context).await;
+
+        let mut second = ComponentWrapper::new(id.clone(), Arc::clone(&engine), vec![])
+            .with_restart_telemetry(Arc::clone(&telemetry));
+        let _ = second.pre_start(&mut context).await;
+
+        let stats = telemetry.stats(&id).unwrap();
+        assert_eq!((stats.failures, stats.restarts), (1, 1));
+        let cause = stats.last_cause.unwrap();
+        assert_eq!(cause.class, FailureClass::Crash);
+        assert_eq!(cause.message_type.as_deref(), Some("image/png"));
+    }
+
+    /// Counts messages in per-instance state; payload 0 traps.
+    #[derive(Default)]
+    struct CounterEngine {
+        count: Mutex<u64>,
+    }
+
+    impl RuntimeEngine for CounterEngine {
+        fn load_component(
+            &self,
+            id: &ComponentId,
+            _bytes: &[u8],
+        ) -> Result<ComponentHandle, WasmError> {
+            *self.count.lock().unwrap() = 0;
+            Ok(ComponentHandle::new(id.clone(), 1))
+        }
+
+        fn unload_component(&self, _handle: &ComponentHandle) -> Result<(), WasmError> {
+            Ok(())
+        }
+
+        fn call_handle_message(
+            &self,
+            _handle: &ComponentHandle,
+            msg: &ComponentMessage,
+        ) -> Result<Option<MessagePayload>, WasmError> {
+            let mut count = self.count.lock().unwrap();
+            *count += 1;
+            match msg.payload.as_bytes() {
+                [0] => Err(WasmError::RuntimeError("wasm trap".to_string())),
+                _ => Ok(None),
+            }
+        }
+
+        fn call_handle_callback(
+            &self,
+            _handle: &ComponentHandle,
+            _msg: &ComponentMessage,
+        ) -> Result<(), WasmError> {
+            Ok(())
+        }
+
+        fn snapshot(&self, handle: &ComponentHandle) -> Result<ComponentSnapshot, WasmError> {
+            let count = *self.count.lock().unwrap();
+            Ok(ComponentSnapshot::new(
+                handle.id().clone(),
+                count.to_le_bytes().to_vec(),
+                0,
+            ))
+        }
+
+        fn restore(
+            &self,
+            _handle: &ComponentHandle,
+            snapshot: &ComponentSnapshot,
+        ) -> Result<(), WasmError> {
+            let bytes: [u8; 8] = snapshot.state.as_slice().try_into().unwrap();
+            *self.count.lock().unwrap() = u64::from_le_bytes(bytes);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_state_restored_after_crash() {
+        let id = create_test_id();
+        let engine = Arc::new(CounterEngine::default());
+        let ledger = Arc::new(SnapshotLedger::new());
+        let mut context = create_test_context();
+
+        let mut first = ComponentWrapper::new(id.clone(), Arc::clone(&engine), vec![])
+            .with_snapshots(SnapshotPolicy::new(2), Arc::clone(&ledger));
+        first.pre_start(&mut context).await.unwrap();
+        for byte in 1..=3 {
+            let msg = ComponentActorMessage::HandleMessage(payload_message(id.clone(), byte));
+            first.handle_message(msg, &mut context).await.unwrap();
+        }
+        let crash = ComponentActorMessage::HandleMessage(payload_message(id.clone(), 0));
+        assert!(first.handle_message(crash, &mut context).await.is_err());
+
+        // The snapshot after the second message survives; later state is lost
+        let mut second = ComponentWrapper::new(id.clone(), Arc::clone(&engine), vec![])
+            .with_snapshots(SnapshotPolicy::new(2), Arc::clone(&ledger));
+        second.pre_start(&mut context).await.unwrap();
+        assert_eq!(*engine.count.lock().unwrap(), 2);
+
+        // A graceful stop snapshots the latest state
+        let msg = ComponentActorMessage::HandleMessage(payload_message(id.clone(), 1));
+        second.handle_message(msg, &mut context).await.unwrap();
+        second.post_stop(&mut context).await.unwrap();
+        assert_eq!(ledger.latest(&id).unwrap().state, 3u64.to_le_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_requeue_position_orders_replay() {
+        let id = create_test_id();
+        let engine = Arc::new(MockRuntimeEngine::new());
+        let mut context = create_test_context();
+
+        for (position, expected_last) in [(RequeuePosition::Head, 2), (RequeuePosition::Tail, 1)] {
+            let ledger = Arc::new(RequeueLedger::new());
+            ledger.record_failure(
+                &id,
+                vec![PendingMessage::fresh(payload_message(id.clone(), 1))],
+            );
+
+            let policy = RequeuePolicy::new(3).with_position(position);
+            let mut wrapper = ComponentWrapper::new(id.clone(), Arc::clone(&engine), vec![])
+                .with_requeue(policy, ledger);
+            let _ = wrapper.pre_start(&mut context).await;
+
+            let msg = ComponentActorMessage::HandleMessage(payload_message(id.clone(), 2));
+            assert!(wrapper.handle_message(msg, &mut context).await.is_ok());
+            assert_eq!(last_payload(&engine), Some(vec![expected_last]));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poison_message_routed_to_dead_letter() {
+        let id = create_test_id();
+        let dlq = ComponentId::new("system", "dlq", "0");
+        let policy = RequeuePolicy::new(2).with_dead_letter(dlq.clone());
+        let ledger = Arc::new(RequeueLedger::new());
+        let engine = Arc::new(MockRuntimeEngine::new().with_message_failure());
+        let mut context = create_test_context();
+
+        let mut wrapper = ComponentWrapper::new(id.clone(), Arc::clone(&engine), vec![])
+            .with_requeue(policy.clone(), Arc::clone(&ledger));
+        let _ = wrapper.pre_start(&mut context).await;
+        let msg = ComponentActorMessage::HandleMessage(payload_message(id.clone(), 9));
+        assert!(wrapper.handle_message(msg, &mut context).await.is_err());
+
+        // Second attempt after restart also fails
+        let mut wrapper = ComponentWrapper::new(id.clone(), Arc::clone(&engine), vec![])
+            .with_requeue(policy.clone(), Arc::clone(&ledger));
+        let _ = wrapper.pre_start(&mut context).await;
+        let result = wrapper
+            .handle_message(ComponentActorMessage::Replay, &mut context)
+            .await;
+        assert!(result.is_err());
+
+        // Attempts exhausted: next restart dead-letters instead of replaying
+        let mut wrapper = ComponentWrapper::new(id.clone(), Arc::clone(&engine), vec![])
+            .with_requeue(policy, Arc::clone(&ledger));
+        let _ = wrapper.pre_start(&mut context).await;
+        assert_eq!(wrapper.pending_replay(), 0);
+
+        let dead = ledger.dead_letters();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].attempts, 2);
+        assert_eq!(dead[0].route_to, Some(dlq));
+        assert_eq!(dead[0].message.payload.as_bytes(), &[9]);
+    }
+
+    #[tokio::test]
+    async fn test_failed_message_dropped_without_requeue() {
+        let id = create_test_id();
+        let engine = Arc::new(MockRuntimeEngine::new().with_message_failure());
+        let mut wrapper = ComponentWrapper::new(id.clone(), engine, vec![]);
+        let mut context = create_test_context();
+
+        let _ = wrapper.pre_start(&mut context).await;
+        let msg = ComponentActorMessage::HandleMessage(create_test_message(id));
+        assert!(wrapper.handle_message(msg, &mut context).await.is_err());
+        assert!(wrapper.requeue_policy().is_none());
+        assert_eq!(wrapper.pending_replay(), 0);
+    }
+
+    // ========================================
+    // Live Config Tests
+    // ========================================
+
+    fn threshold(n: i64) -> ConfigValues {
+        ConfigValues::new().with("threshold", ConfigValue::Integer(n))
+    }
+
+    #[tokio::test]
+    async fn test_reconfigure_accepted_and_rejected() {
+        let id = create_test_id();
+        let live = Arc::new(LiveConfig::new(threshold(10)));
+        let mut wrapper = ComponentWrapper::new(id, Arc::new(MockRuntimeEngine::new()), vec![])
+            .with_live_config(Arc::clone(&live));
+        let mut context = create_test_context();
+        let _ = wrapper.pre_start(&mut context).await;
+
+        let generation = live.begin(&threshold(20)).unwrap();
+        let msg = ComponentActorMessage::Reconfigure {
+            generation,
+            values: threshold(20),
+        };
+        assert!(wrapper.handle_message(msg, &mut context).await.is_ok());
+        assert!(live
+            .wait_for(generation, std::time::Duration::from_millis(50))
+            .await
+            .is_ok());
+
+        // Rejection keeps the component running with the previous values
+        let generation = live.begin(&threshold(-1)).unwrap();
+        let msg = ComponentActorMessage::Reconfigure {
+            generation,
+            values: threshold(-1),
+        };
+        assert!(wrapper.handle_message(msg, &mut context).await.is_ok());
+        assert!(live
+            .wait_for(generation, std::time::Duration::from_millis(50))
+            .await
+            .is_err());
+        assert_eq!(live.applied(), threshold(20));
+        assert!(wrapper.is_loaded());
+    }
+
+    #[tokio::test]
+    async fn test_reconfigure_without_start() {
+        let mut wrapper =
+            ComponentWrapper::new(create_test_id(), Arc::new(MockRuntimeEngine::new()), vec![]);
+        let mut context = create_test_context();
+        let msg = ComponentActorMessage::Reconfigure {
+            generation: 1,
+            values: threshold(1),
+        };
+        assert!(wrapper.handle_message(msg, &mut context).await.is_err());
+    }
+
     // ========================================
     // Send + Sync Bounds Tests
     // ========================================
@@ -0,0 +1,30 @@
+//! OS bridge error types.
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+use thiserror::Error;
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use super::operation::OsOperationKind;
+use crate::core::security::errors::SecurityError;
+
+/// Errors returned by an [`OsBridge`](super::traits::OsBridge).
+///
+/// Aligned with the WIT `os-error` variant in `errors.wit`.
+#[derive(Debug, Error)]
+pub enum BridgeError {
+    /// The host does not expose this operation to components.
+    #[error("Operation not exposed: {0}")]
+    NotExposed(OsOperationKind),
+
+    /// The operation's arguments cannot be mapped to a capability.
+    #[error("Invalid operation: {0}")]
+    InvalidOperation(String),
+
+    /// The component lacks the capability the operation requires.
+    #[error(transparent)]
+    Security(#[from] SecurityError),
+
+    /// The operation was permitted but failed.
+    #[error("Operation failed: {0}")]
+    OperationFailed(String),
+}
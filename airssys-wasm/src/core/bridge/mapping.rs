@@ -0,0 +1,188 @@
+//! Per-operation capability mapping for the OS bridge.
+//!
+//! Each bridged operation requires exactly one capability:
+//!
+//! | Operation         | Capability                              |
+//! |-------------------|-----------------------------------------|
+//! | `file-read`       | `Filesystem { Read, path }`             |
+//! | `file-write`      | `Filesystem { Write, path }`            |
+//! | `process-spawn`   | `Filesystem { Execute, program }`       |
+//! | `network-connect` | `Network { Outbound, host, port }`      |
+//!
+//! Operations the host withholds map to no capability and are refused
+//! before any check runs.
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+use std::collections::HashSet;
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use super::errors::BridgeError;
+use super::operation::{OsOperation, OsOperationKind};
+use crate::core::security::capability::{
+    Capability, FilesystemAction, FilesystemCapability, NetworkAction, NetworkCapability,
+};
+
+/// Which bridged operations are exposed, and the capability each requires.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::bridge::mapping::CapabilityMapping;
+/// use airssys_wasm::core::bridge::operation::{OsOperation, OsOperationKind};
+///
+/// // Expose file access only
+/// let mapping = CapabilityMapping::none()
+///     .expose(OsOperationKind::FileRead)
+///     .expose(OsOperationKind::FileWrite);
+///
+/// let read = OsOperation::FileRead { path: "/data/a".to_string() };
+/// assert!(mapping.required_capability(&read).is_ok());
+///
+/// let spawn = OsOperation::ProcessSpawn { program: "sh".to_string(), args: vec![] };
+/// assert!(mapping.required_capability(&spawn).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapabilityMapping {
+    exposed: HashSet<OsOperationKind>,
+}
+
+impl Default for CapabilityMapping {
+    /// Exposes every bridged operation.
+    fn default() -> Self {
+        Self {
+            exposed: OsOperationKind::ALL.into_iter().collect(),
+        }
+    }
+}
+
+impl CapabilityMapping {
+    /// A mapping that exposes no operation.
+    pub fn none() -> Self {
+        Self {
+            exposed: HashSet::new(),
+        }
+    }
+
+    /// Exposes `kind` to components.
+    pub fn expose(mut self, kind: OsOperationKind) -> Self {
+        self.exposed.insert(kind);
+        self
+    }
+
+    /// Withholds `kind` from components.
+    pub fn withhold(mut self, kind: OsOperationKind) -> Self {
+        self.exposed.remove(&kind);
+        self
+    }
+
+    /// Returns `true` if `kind` is exposed.
+    pub fn is_exposed(&self, kind: OsOperationKind) -> bool {
+        self.exposed.contains(&kind)
+    }
+
+    /// The capability a component must hold to run `operation`.
+    ///
+    /// # Errors
+    ///
+    /// - `BridgeError::NotExposed` - the operation is withheld
+    /// - `BridgeError::InvalidOperation` - a connect address is not
+    ///   `host:port`
+    pub fn required_capability(&self, operation: &OsOperation) -> Result<Capability, BridgeError> {
+        let kind = operation.kind();
+        if !self.is_exposed(kind) {
+            return Err(BridgeError::NotExposed(kind));
+        }
+
+        let filesystem = |action, path: &str| {
+            Capability::Filesystem(FilesystemCapability {
+                action,
+                path_pattern: path.to_string(),
+            })
+        };
+        Ok(match operation {
+            OsOperation::FileRead { path } => filesystem(FilesystemAction::Read, path),
+            OsOperation::FileWrite { path, .. } => filesystem(FilesystemAction::Write, path),
+            OsOperation::ProcessSpawn { program, .. } => {
+                filesystem(FilesystemAction::Execute, program)
+            }
+            OsOperation::NetworkConnect { address } => {
+                let (host, port) = split_address(address)?;
+                Capability::Network(NetworkCapability {
+                    action: NetworkAction::Outbound,
+                    host_pattern: host.to_string(),
+                    port: Some(port),
+                })
+            }
+        })
+    }
+}
+
+/// Splits `host:port` or `[v6]:port`.
+fn split_address(address: &str) -> Result<(&str, u16), BridgeError> {
+    let invalid = || BridgeError::InvalidOperation(format!("expected host:port, got {address}"));
+    let (host, port) = address.rsplit_once(':').ok_or_else(invalid)?;
+    let host = host
+        .strip_prefix('[')
+        .and_then(|h| h.strip_suffix(']'))
+        .unwrap_or(host);
+    let port = port.parse().map_err(|_| invalid())?;
+    if host.is_empty() {
+        return Err(invalid());
+    }
+    Ok((host, port))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operations_map_to_capabilities() {
+        let mapping = CapabilityMapping::default();
+
+        let spawn = OsOperation::ProcessSpawn {
+            program: "/usr/bin/git".to_string(),
+            args: vec!["status".to_string()],
+        };
+        match mapping.required_capability(&spawn).unwrap() {
+            Capability::Filesystem(cap) => {
+                assert_eq!(cap.action, FilesystemAction::Execute);
+                assert_eq!(cap.path_pattern, "/usr/bin/git");
+            }
+            other => panic!("unexpected capability: {other:?}"),
+        }
+
+        let connect = OsOperation::NetworkConnect {
+            address: "[::1]:8443".to_string(),
+        };
+        match mapping.required_capability(&connect).unwrap() {
+            Capability::Network(cap) => {
+                assert_eq!(cap.host_pattern, "::1");
+                assert_eq!(cap.port, Some(8443));
+            }
+            other => panic!("unexpected capability: {other:?}"),
+        }
+
+        let bad = OsOperation::NetworkConnect {
+            address: "db.internal".to_string(),
+        };
+        assert!(matches!(
+            mapping.required_capability(&bad),
+            Err(BridgeError::InvalidOperation(_))
+        ));
+    }
+
+    #[test]
+    fn test_withheld_operations_are_not_exposed() {
+        let mapping = CapabilityMapping::default().withhold(OsOperationKind::ProcessSpawn);
+        let spawn = OsOperation::ProcessSpawn {
+            program: "sh".to_string(),
+            args: vec![],
+        };
+        assert!(matches!(
+            mapping.required_capability(&spawn),
+            Err(BridgeError::NotExposed(OsOperationKind::ProcessSpawn))
+        ));
+        assert!(mapping.is_exposed(OsOperationKind::FileRead));
+    }
+}
@@ -0,0 +1,35 @@
+//! # Bridge Module
+//!
+//! Abstractions for letting trusted components drive a curated subset of
+//! airssys-osl operations through the `host-os` interface.
+//!
+//! # Submodules
+//!
+//! - [`operation`] - Bridged operations (`OsOperation`, `OsOperationKind`)
+//! - [`mapping`] - Per-operation capability mapping (`CapabilityMapping`)
+//! - [`errors`] - Bridge error types (`BridgeError`)
+//! - [`traits`] - The `OsBridge` trait implemented by the host
+//!
+//! # Architecture
+//!
+//! This is part of **Layer 1** (core/). Only the types live here; the
+//! implementation that executes operations through airssys-osl is
+//! `security::os_bridge::OslOperationBridge` (Layer 2A).
+//!
+//! # Usage
+//!
+//! ```rust
+//! use airssys_wasm::core::bridge::errors::BridgeError;
+//! use airssys_wasm::core::bridge::mapping::CapabilityMapping;
+//! use airssys_wasm::core::bridge::operation::{OsOperation, OsOperationKind};
+//! use airssys_wasm::core::bridge::traits::OsBridge;
+//! ```
+
+// Module declarations (per PROJECTS_STANDARD.md §4.3)
+pub mod errors;
+pub mod mapping;
+pub mod operation;
+pub mod traits;
+
+// NOTE: No glob re-exports (pub use X::*) per module grouping policy.
+// Callers use namespaced access: core::bridge::mapping::CapabilityMapping
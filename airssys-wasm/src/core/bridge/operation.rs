@@ -0,0 +1,95 @@
+//! Operations a component can request through the OS bridge.
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+use std::fmt;
+
+/// An OS operation requested by a component.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::bridge::operation::{OsOperation, OsOperationKind};
+///
+/// let op = OsOperation::FileRead {
+///     path: "/data/report.csv".to_string(),
+/// };
+/// assert_eq!(op.kind(), OsOperationKind::FileRead);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OsOperation {
+    /// Read a whole file.
+    FileRead {
+        /// Path of the file.
+        path: String,
+    },
+    /// Write (create or truncate) a file.
+    FileWrite {
+        /// Path of the file.
+        path: String,
+        /// New file contents.
+        content: Vec<u8>,
+    },
+    /// Spawn a process.
+    ProcessSpawn {
+        /// Program to execute.
+        program: String,
+        /// Program arguments.
+        args: Vec<String>,
+    },
+    /// Open an outbound TCP connection.
+    NetworkConnect {
+        /// Target in `host:port` form.
+        address: String,
+    },
+}
+
+impl OsOperation {
+    /// The kind of this operation.
+    pub fn kind(&self) -> OsOperationKind {
+        match self {
+            Self::FileRead { .. } => OsOperationKind::FileRead,
+            Self::FileWrite { .. } => OsOperationKind::FileWrite,
+            Self::ProcessSpawn { .. } => OsOperationKind::ProcessSpawn,
+            Self::NetworkConnect { .. } => OsOperationKind::NetworkConnect,
+        }
+    }
+}
+
+/// Kinds of bridged operations, used to expose or withhold them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OsOperationKind {
+    /// [`OsOperation::FileRead`]
+    FileRead,
+    /// [`OsOperation::FileWrite`]
+    FileWrite,
+    /// [`OsOperation::ProcessSpawn`]
+    ProcessSpawn,
+    /// [`OsOperation::NetworkConnect`]
+    NetworkConnect,
+}
+
+impl OsOperationKind {
+    /// All bridged operation kinds.
+    pub const ALL: [OsOperationKind; 4] = [
+        Self::FileRead,
+        Self::FileWrite,
+        Self::ProcessSpawn,
+        Self::NetworkConnect,
+    ];
+
+    /// WIT function name of this operation in `host-os`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::FileRead => "file-read",
+            Self::FileWrite => "file-write",
+            Self::ProcessSpawn => "process-spawn",
+            Self::NetworkConnect => "network-connect",
+        }
+    }
+}
+
+impl fmt::Display for OsOperationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,29 @@
+//! OS bridge trait.
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use super::errors::BridgeError;
+use super::operation::OsOperation;
+use crate::core::component::id::ComponentId;
+
+/// Executes OS operations on behalf of components.
+///
+/// Host functions are synchronous, so implementations block until the
+/// operation completes.
+pub trait OsBridge: Send + Sync {
+    /// Checks `component`'s capabilities and executes `operation`.
+    ///
+    /// Returns the operation's raw output: file contents for
+    /// `FileRead`, the process ID for `ProcessSpawn`, the peer address for
+    /// `NetworkConnect`, and nothing for `FileWrite`.
+    ///
+    /// # Errors
+    ///
+    /// - `BridgeError::NotExposed` - the host withholds this operation
+    /// - `BridgeError::Security` - the component lacks the capability
+    /// - `BridgeError::OperationFailed` - the operation itself failed
+    fn execute(
+        &self,
+        component: &ComponentId,
+        operation: OsOperation,
+    ) -> Result<Vec<u8>, BridgeError>;
+}
@@ -131,6 +131,59 @@ impl From<&[u8]> for MessagePayload {
     }
 }
 
+/// Scheduling priority of a component message.
+///
+/// Mirrors the runtime's mailbox priority levels without making `core/`
+/// depend on the actor runtime; the component actor maps it onto the
+/// envelope priority when the message is delivered.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::message::MessagePriority;
+///
+/// let cap = MessagePriority::DEFAULT_INHERITANCE_CAP;
+/// assert_eq!(
+///     MessagePriority::Normal.inherit(MessagePriority::High, cap),
+///     MessagePriority::High
+/// );
+/// assert_eq!(
+///     MessagePriority::Normal.inherit(MessagePriority::Critical, cap),
+///     MessagePriority::High
+/// );
+/// ```
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum MessagePriority {
+    /// Background work that can be deferred
+    Low,
+    /// Routine traffic
+    #[default]
+    Normal,
+    /// Time-sensitive or user-facing requests
+    High,
+    /// Host-critical messages
+    Critical,
+}
+
+impl MessagePriority {
+    /// Default ceiling for priorities inherited along a request chain.
+    pub const DEFAULT_INHERITANCE_CAP: MessagePriority = MessagePriority::High;
+
+    /// Priority of a message sent while handling a `parent` message.
+    ///
+    /// Only priorities above `Normal` propagate, and never beyond `cap`;
+    /// a message keeps its own priority if that is already higher.
+    pub fn inherit(self, parent: MessagePriority, cap: MessagePriority) -> MessagePriority {
+        if parent > MessagePriority::Normal {
+            self.max(parent.min(cap))
+        } else {
+            self
+        }
+    }
+}
+
 /// Metadata for a component message.
 ///
 /// MessageMetadata contains optional information about a message that supports
@@ -142,6 +195,10 @@ impl From<&[u8]> for MessagePayload {
 /// - `reply_to`: Optional ComponentId to which responses should be routed
 /// - `timestamp_ms`: Message creation timestamp in milliseconds since Unix epoch
 /// - `content_type`: Optional MIME type or content identifier for message payload
+/// - `idempotency_key`: Optional key identifying the message across redeliveries
+/// - `partition_key`: Optional key routing the message to a consistent pool instance
+/// - `deadline_ms`: Optional time (ms since Unix epoch) after which handling is pointless
+/// - `priority`: Scheduling priority, inherited by messages sent while handling it
 ///
 /// # Architecture Note
 ///
@@ -170,6 +227,31 @@ pub struct MessageMetadata {
     pub timestamp_ms: u64,
     /// Optional MIME type or content identifier for payload
     pub content_type: Option<String>,
+    /// Optional key identifying this message across redeliveries
+    ///
+    /// Set by the host for acknowledged delivery; a guest that sees the
+    /// same key twice is receiving a retry and may skip the duplicate.
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
+    /// Optional key pinning the message to one instance of a pool
+    ///
+    /// Messages with the same key for the same component are delivered to
+    /// the same pool instance, so it can keep per-key state locally.
+    #[serde(default)]
+    pub partition_key: Option<String>,
+    /// Optional deadline in milliseconds since Unix epoch
+    ///
+    /// A message still queued when its deadline can no longer be met may be
+    /// shed to the dead-letter queue instead of being handled.
+    #[serde(default)]
+    pub deadline_ms: Option<u64>,
+    /// Scheduling priority of the message
+    ///
+    /// Messages a component sends while handling this one inherit it
+    /// (see [`MessagePriority::inherit`]), so a high-priority request is
+    /// expedited along its whole chain.
+    #[serde(default)]
+    pub priority: MessagePriority,
 }
 
 impl Default for MessageMetadata {
@@ -193,6 +275,10 @@ impl Default for MessageMetadata {
             reply_to: None,
             timestamp_ms: 0,
             content_type: None,
+            idempotency_key: None,
+            partition_key: None,
+            deadline_ms: None,
+            priority: MessagePriority::Normal,
         }
     }
 }
@@ -291,6 +377,10 @@ mod tests {
             reply_to: Some(ComponentId::new("system", "cache", "dev")),
             timestamp_ms: 1234567890,
             content_type: Some("application/json".to_string()),
+            idempotency_key: None,
+            partition_key: None,
+            deadline_ms: None,
+            priority: MessagePriority::Normal,
         };
 
         let message = ComponentMessage::new(sender.clone(), payload.clone(), metadata.clone());
@@ -363,6 +453,10 @@ mod tests {
         // Test with only content_type
         let metadata3 = MessageMetadata {
             content_type: Some("text/plain".to_string()),
+            idempotency_key: None,
+            partition_key: None,
+            deadline_ms: None,
+            priority: MessagePriority::Normal,
             ..Default::default()
         };
         let message3 = ComponentMessage::new(sender, payload, metadata3);
@@ -472,6 +566,10 @@ mod tests {
             reply_to: Some(reply_to),
             timestamp_ms: 12345,
             content_type: Some("application/json".to_string()),
+            idempotency_key: None,
+            partition_key: None,
+            deadline_ms: None,
+            priority: MessagePriority::Normal,
         };
         let metadata2 = metadata1.clone();
 
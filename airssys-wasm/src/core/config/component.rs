@@ -7,6 +7,9 @@ use thiserror::Error;
 
 // Layer 3: Internal module imports
 use crate::core::component::id::ComponentId;
+use crate::core::config::content_type::AcceptedContentTypes;
+use crate::core::config::shedding::ShedPolicy;
+use crate::core::config::values::ConfigValues;
 
 // =============================================================================
 // Constants
@@ -66,7 +69,13 @@ pub struct ComponentConfig {
     max_execution_time_ms: u64,
     max_fuel: Option<u64>,
     storage_namespace: Option<String>,
+    locality_prefixes: Vec<String>,
     debug_mode: bool,
+    critical: bool,
+    pure: bool,
+    accepted_content_types: Option<AcceptedContentTypes>,
+    shed_policy: Option<ShedPolicy>,
+    config_values: ConfigValues,
 }
 
 impl Default for ComponentConfig {
@@ -77,7 +86,13 @@ impl Default for ComponentConfig {
             max_execution_time_ms: DEFAULT_MAX_EXECUTION_TIME_MS,
             max_fuel: None,
             storage_namespace: None,
+            locality_prefixes: Vec::new(),
             debug_mode: false,
+            critical: false,
+            pure: false,
+            accepted_content_types: None,
+            shed_policy: None,
+            config_values: ConfigValues::new(),
         }
     }
 }
@@ -177,6 +192,24 @@ impl ComponentConfig {
         self
     }
 
+    /// Remove the fuel limit, allowing unlimited execution.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use airssys_wasm::core::component::id::ComponentId;
+    /// use airssys_wasm::core::config::component::ComponentConfig;
+    ///
+    /// let config = ComponentConfig::new(ComponentId::new("a", "b", "c"))
+    ///     .with_fuel_limit(1_000)
+    ///     .without_fuel_limit();
+    /// assert_eq!(config.max_fuel(), None);
+    /// ```
+    pub fn without_fuel_limit(mut self) -> Self {
+        self.max_fuel = None;
+        self
+    }
+
     /// Set storage namespace for component isolation.
     ///
     /// # Arguments
@@ -198,6 +231,27 @@ impl ComponentConfig {
         self
     }
 
+    /// Declare a dominant storage key prefix (a data locality hint).
+    ///
+    /// Sharded storage backends keep all keys under a declared prefix on
+    /// one shard, so the component's hot partitions are co-located.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use airssys_wasm::core::component::id::ComponentId;
+    /// use airssys_wasm::core::config::component::ComponentConfig;
+    ///
+    /// let config = ComponentConfig::new(ComponentId::new("a", "b", "c"))
+    ///     .with_locality_prefix("orders/")
+    ///     .with_locality_prefix("cart/");
+    /// assert_eq!(config.locality_prefixes(), ["orders/", "cart/"]);
+    /// ```
+    pub fn with_locality_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.locality_prefixes.push(prefix.into());
+        self
+    }
+
     /// Enable or disable debug mode.
     ///
     /// # Arguments
@@ -219,6 +273,107 @@ impl ComponentConfig {
         self
     }
 
+    /// Mark the component as system-critical.
+    ///
+    /// Critical components get host headroom (memory, instance slot, fuel)
+    /// reserved for them; lower-priority components are refused admission
+    /// when they would eat into those reservations.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use airssys_wasm::core::component::id::ComponentId;
+    /// use airssys_wasm::core::config::component::ComponentConfig;
+    ///
+    /// let config = ComponentConfig::new(ComponentId::new("a", "b", "c"))
+    ///     .with_critical(true);
+    /// assert!(config.is_critical());
+    /// ```
+    pub fn with_critical(mut self, critical: bool) -> Self {
+        self.critical = critical;
+        self
+    }
+
+    /// Declare the component pure (deterministic).
+    ///
+    /// A pure component's response depends only on the request payload,
+    /// so the host may answer repeat requests from its response cache.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use airssys_wasm::core::component::id::ComponentId;
+    /// use airssys_wasm::core::config::component::ComponentConfig;
+    ///
+    /// let config = ComponentConfig::new(ComponentId::new("a", "b", "c"))
+    ///     .with_pure(true);
+    /// assert!(config.is_pure());
+    /// ```
+    pub fn with_pure(mut self, pure: bool) -> Self {
+        self.pure = pure;
+        self
+    }
+
+    /// Restrict delivered payloads to the content types declared in the
+    /// component's `[messaging] accepts` list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use airssys_wasm::core::component::id::ComponentId;
+    /// use airssys_wasm::core::config::component::ComponentConfig;
+    /// use airssys_wasm::core::config::content_type::AcceptedContentTypes;
+    ///
+    /// let config = ComponentConfig::new(ComponentId::new("a", "b", "c"))
+    ///     .with_accepted_content_types(AcceptedContentTypes::new(["json"]).unwrap());
+    /// assert!(config.accepted_content_types().is_some());
+    /// ```
+    pub fn with_accepted_content_types(mut self, accepted: AcceptedContentTypes) -> Self {
+        self.accepted_content_types = Some(accepted);
+        self
+    }
+
+    /// Shed queued messages that would miss their deadline per `policy`
+    /// (the component's `[shedding]` table).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use airssys_wasm::core::component::id::ComponentId;
+    /// use airssys_wasm::core::config::component::ComponentConfig;
+    /// use airssys_wasm::core::config::shedding::ShedPolicy;
+    ///
+    /// let config = ComponentConfig::new(ComponentId::new("a", "b", "c"))
+    ///     .with_shed_policy(ShedPolicy::new());
+    /// assert!(config.shed_policy().is_some());
+    /// ```
+    pub fn with_shed_policy(mut self, policy: ShedPolicy) -> Self {
+        self.shed_policy = Some(policy);
+        self
+    }
+
+    /// Set the tunable `[config]` values declared by the component.
+    ///
+    /// These are the values the component starts with and the schema that
+    /// live updates are validated against.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use airssys_wasm::core::component::id::ComponentId;
+    /// use airssys_wasm::core::config::component::ComponentConfig;
+    /// use airssys_wasm::core::config::values::{ConfigValue, ConfigValues};
+    ///
+    /// let values = ConfigValues::new().with("threshold", ConfigValue::Integer(10));
+    /// let config = ComponentConfig::new(ComponentId::new("a", "b", "c"))
+    ///     .with_config_values(values);
+    /// assert_eq!(config.config_values().len(), 1);
+    /// ```
+    pub fn with_config_values(mut self, values: ConfigValues) -> Self {
+        self.config_values = values;
+        self
+    }
+
     // =========================================================================
     // Validation
     // =========================================================================
@@ -307,10 +462,42 @@ impl ComponentConfig {
         self.storage_namespace.as_deref()
     }
 
+    /// Returns the declared dominant storage key prefixes.
+    pub fn locality_prefixes(&self) -> &[String] {
+        &self.locality_prefixes
+    }
+
     /// Returns whether debug mode is enabled.
     pub fn debug_mode(&self) -> bool {
         self.debug_mode
     }
+
+    /// Returns whether the component is marked system-critical.
+    pub fn is_critical(&self) -> bool {
+        self.critical
+    }
+
+    /// Returns whether the component is declared pure.
+    pub fn is_pure(&self) -> bool {
+        self.pure
+    }
+
+    /// Returns the content types the component accepts; `None` accepts
+    /// every payload.
+    pub fn accepted_content_types(&self) -> Option<&AcceptedContentTypes> {
+        self.accepted_content_types.as_ref()
+    }
+
+    /// Returns the shedding policy; `None` never sheds the component's
+    /// messages.
+    pub fn shed_policy(&self) -> Option<&ShedPolicy> {
+        self.shed_policy.as_ref()
+    }
+
+    /// Returns the declared `[config]` values.
+    pub fn config_values(&self) -> &ConfigValues {
+        &self.config_values
+    }
 }
 
 #[cfg(test)]
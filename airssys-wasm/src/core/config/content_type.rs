@@ -0,0 +1,256 @@
+//! Payload content types a component accepts.
+//!
+//! A component lists the payload encodings it can parse in the `accepts`
+//! array of its manifest's `[messaging]` table:
+//!
+//! ```toml
+//! [messaging]
+//! accepts = ["json", "application/vnd.acme.order+json", "text/*"]
+//! ```
+//!
+//! Entries are either codec names (`json`, `cbor`, `raw`) or MIME types.
+//! A MIME entry may use a `*` subtype (`text/*`) or be `*/*`. Matching
+//! compares the base MIME type case-insensitively and ignores parameters,
+//! so `application/json; type=order.created` matches `json`. A message
+//! without a content type is treated as `application/octet-stream`.
+//!
+//! Without a `[messaging]` table (or without `accepts`) a component
+//! accepts every payload.
+
+// Layer 1: Standard library imports
+use std::fmt;
+
+// Layer 2: Third-party crate imports
+use serde::Deserialize;
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+use crate::core::multicodec::codec::Codec;
+
+/// Entry matching every content type.
+pub const ANY_CONTENT_TYPE: &str = "*/*";
+
+/// Errors raised while reading an `accepts` list.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ContentTypeError {
+    /// The manifest could not be parsed.
+    #[error("Invalid accepted content types: {0}")]
+    InvalidDefinition(String),
+
+    /// An entry is neither a codec name nor a `type/subtype` MIME type.
+    #[error("Invalid accepted content type '{0}' (expected a codec name or type/subtype)")]
+    InvalidEntry(String),
+
+    /// The list is present but empty, which would reject every message.
+    #[error("Accepted content type list is empty")]
+    Empty,
+}
+
+/// Content types one component accepts.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::config::content_type::AcceptedContentTypes;
+///
+/// let accepted = AcceptedContentTypes::new(["json", "text/*"]).unwrap();
+/// assert!(accepted.accepts(Some("application/json; type=order.created")));
+/// assert!(accepted.accepts(Some("text/plain")));
+/// assert!(!accepted.accepts(Some("application/cbor")));
+/// assert!(!accepted.accepts(None));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AcceptedContentTypes {
+    /// Normalized MIME patterns, in declaration order
+    patterns: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ManifestFile {
+    messaging: Option<MessagingSection>,
+}
+
+#[derive(Deserialize)]
+struct MessagingSection {
+    accepts: Option<Vec<String>>,
+}
+
+impl AcceptedContentTypes {
+    /// Creates a list from codec names and MIME types.
+    ///
+    /// # Errors
+    ///
+    /// - `ContentTypeError::InvalidEntry` if an entry is malformed
+    /// - `ContentTypeError::Empty` if `entries` is empty
+    pub fn new<I, S>(entries: I) -> Result<Self, ContentTypeError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut patterns = Vec::new();
+        for entry in entries {
+            let pattern = normalize(entry.as_ref())?;
+            if !patterns.contains(&pattern) {
+                patterns.push(pattern);
+            }
+        }
+        if patterns.is_empty() {
+            return Err(ContentTypeError::Empty);
+        }
+        Ok(Self { patterns })
+    }
+
+    /// Reads the `[messaging] accepts` list from a Component.toml.
+    ///
+    /// Returns `None` when the manifest does not declare one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use airssys_wasm::core::config::content_type::AcceptedContentTypes;
+    ///
+    /// let accepted = AcceptedContentTypes::from_manifest(r#"
+    ///     [component]
+    ///     name = "orders"
+    ///
+    ///     [messaging]
+    ///     accepts = ["cbor"]
+    /// "#).unwrap().unwrap();
+    /// assert_eq!(accepted.patterns(), ["application/cbor"]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - `ContentTypeError::InvalidDefinition` if the TOML is malformed
+    /// - `ContentTypeError::InvalidEntry` / `ContentTypeError::Empty` for a
+    ///   bad list
+    pub fn from_manifest(source: &str) -> Result<Option<Self>, ContentTypeError> {
+        let file: ManifestFile = toml::from_str(source)
+            .map_err(|e| ContentTypeError::InvalidDefinition(e.to_string()))?;
+        match file.messaging.and_then(|m| m.accepts) {
+            Some(entries) => Self::new(entries).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the normalized MIME patterns.
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    /// Whether a payload with `content_type` may be delivered.
+    pub fn accepts(&self, content_type: Option<&str>) -> bool {
+        let mime = content_type
+            .and_then(|ct| ct.split(';').next())
+            .map(|base| base.trim().to_ascii_lowercase())
+            .unwrap_or_else(|| Codec::Raw.content_type().to_string());
+        self.patterns.iter().any(|pattern| matches(pattern, &mime))
+    }
+}
+
+impl fmt::Display for AcceptedContentTypes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.patterns.join(", "))
+    }
+}
+
+/// Maps a codec name to its content type and validates MIME entries.
+fn normalize(entry: &str) -> Result<String, ContentTypeError> {
+    let entry = entry.trim().to_ascii_lowercase();
+    if let Some(codec) = [Codec::Raw, Codec::Json, Codec::Cbor]
+        .into_iter()
+        .find(|codec| codec.name() == entry)
+    {
+        return Ok(codec.content_type().to_string());
+    }
+
+    let invalid = || ContentTypeError::InvalidEntry(entry.clone());
+    let (kind, subtype) = entry.split_once('/').ok_or_else(invalid)?;
+    let token = |part: &str| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "!#$&-^_.+".contains(c))
+    };
+    let valid = match (kind, subtype) {
+        ("*", "*") => true,
+        (kind, "*") => token(kind),
+        (kind, subtype) => token(kind) && token(subtype),
+    };
+    if valid {
+        Ok(entry)
+    } else {
+        Err(invalid())
+    }
+}
+
+/// Whether normalized `pattern` matches the lowercase base `mime`.
+fn matches(pattern: &str, mime: &str) -> bool {
+    if pattern == ANY_CONTENT_TYPE || pattern == mime {
+        return true;
+    }
+    match pattern.strip_suffix('*') {
+        Some(prefix) => mime.starts_with(prefix),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codec_names_and_wildcards() {
+        let accepted = AcceptedContentTypes::new(["JSON", "cbor", "image/*"]).unwrap();
+        assert_eq!(
+            accepted.patterns(),
+            ["application/json", "application/cbor", "image/*"]
+        );
+        assert!(accepted.accepts(Some("Application/JSON; charset=utf-8")));
+        assert!(accepted.accepts(Some("application/cbor; compression=zstd")));
+        assert!(accepted.accepts(Some("image/png")));
+        assert!(!accepted.accepts(Some("imagex/png")));
+        assert!(!accepted.accepts(Some("text/plain")));
+        assert!(!accepted.accepts(None));
+
+        let raw = AcceptedContentTypes::new(["raw"]).unwrap();
+        assert!(raw.accepts(None));
+        assert!(AcceptedContentTypes::new([ANY_CONTENT_TYPE])
+            .unwrap()
+            .accepts(Some("text/plain")));
+    }
+
+    #[test]
+    fn test_rejects_malformed_entries() {
+        for entry in ["yaml", "text/", "/json", "*/json", "text/pl ain"] {
+            assert_eq!(
+                AcceptedContentTypes::new([entry]),
+                Err(ContentTypeError::InvalidEntry(entry.to_string()))
+            );
+        }
+        assert_eq!(
+            AcceptedContentTypes::new(Vec::<String>::new()),
+            Err(ContentTypeError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_from_manifest() {
+        let none = AcceptedContentTypes::from_manifest("[component]\nname = \"a\"\n").unwrap();
+        assert!(none.is_none());
+
+        let accepted = AcceptedContentTypes::from_manifest(
+            "[messaging]\naccepts = [\"json\", \"json\", \"text/csv\"]\n",
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(accepted.patterns(), ["application/json", "text/csv"]);
+        assert_eq!(accepted.to_string(), "application/json, text/csv");
+
+        assert!(matches!(
+            AcceptedContentTypes::from_manifest("[messaging]\naccepts = []\n"),
+            Err(ContentTypeError::Empty)
+        ));
+    }
+}
@@ -0,0 +1,347 @@
+//! Deprecation metadata for component exports.
+//!
+//! A component marks exports it intends to remove, either in its manifest:
+//!
+//! ```toml
+//! [[deprecations]]
+//! export = "get-user"
+//! since = "1.4.0"
+//! removed_in = "2.0.0"
+//! replacement = "get-user-v2"
+//! ```
+//!
+//! or with the WIT `@deprecated` gate on the function:
+//!
+//! ```wit
+//! /// Superseded by get-user-v2.
+//! @deprecated(version = 1.4.0)
+//! get-user: func(id: u64) -> user;
+//! ```
+//!
+//! [`Deprecations::merged`] combines both sources; a manifest entry wins
+//! over a WIT attribute for the same export, since it can also name a
+//! replacement and a removal version.
+
+// Layer 1: Standard library imports
+use std::collections::BTreeMap;
+use std::fmt;
+
+// Layer 2: Third-party crate imports
+use serde::Deserialize;
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+// (none)
+
+/// Errors raised while reading `[[deprecations]]` entries.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum DeprecationError {
+    /// The manifest could not be parsed.
+    #[error("Invalid deprecation metadata: {0}")]
+    InvalidDefinition(String),
+
+    /// Two entries name the same export.
+    #[error("Export '{0}' is deprecated more than once")]
+    Duplicate(String),
+}
+
+/// Deprecation notice for one export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deprecation {
+    /// Deprecated export or function name.
+    pub export: String,
+    /// Version that deprecated it.
+    pub since: String,
+    /// Version that will remove it, if announced.
+    pub removed_in: Option<String>,
+    /// Export callers should migrate to.
+    pub replacement: Option<String>,
+    /// Free-form migration note.
+    pub note: Option<String>,
+}
+
+impl Deprecation {
+    /// Creates a notice for `export`, deprecated since `since`.
+    pub fn new(export: impl Into<String>, since: impl Into<String>) -> Self {
+        Self {
+            export: export.into(),
+            since: since.into(),
+            removed_in: None,
+            replacement: None,
+            note: None,
+        }
+    }
+
+    /// Sets the version that removes the export.
+    pub fn with_removed_in(mut self, version: impl Into<String>) -> Self {
+        self.removed_in = Some(version.into());
+        self
+    }
+
+    /// Sets the export callers should use instead.
+    pub fn with_replacement(mut self, export: impl Into<String>) -> Self {
+        self.replacement = Some(export.into());
+        self
+    }
+
+    /// Sets a migration note.
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    /// Major version that removes the export.
+    ///
+    /// Without an announced `removed_in`, removal is assumed at the major
+    /// version after `since`. Returns `None` if the version is not
+    /// `MAJOR[.MINOR[.PATCH]]`.
+    pub fn removal_major(&self) -> Option<u64> {
+        match &self.removed_in {
+            Some(version) => major(version),
+            None => major(&self.since).map(|m| m + 1),
+        }
+    }
+}
+
+impl fmt::Display for Deprecation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is deprecated since {}", self.export, self.since)?;
+        if let Some(version) = &self.removed_in {
+            write!(f, " and will be removed in {}", version)?;
+        }
+        if let Some(replacement) = &self.replacement {
+            write!(f, "; use '{}' instead", replacement)?;
+        }
+        if let Some(note) = &self.note {
+            write!(f, " ({})", note)?;
+        }
+        Ok(())
+    }
+}
+
+/// Deprecated exports of one component, keyed by export name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Deprecations {
+    entries: BTreeMap<String, Deprecation>,
+}
+
+#[derive(Deserialize)]
+struct ManifestFile {
+    #[serde(default)]
+    deprecations: Vec<DeprecationSection>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DeprecationSection {
+    export: String,
+    since: String,
+    removed_in: Option<String>,
+    replacement: Option<String>,
+    note: Option<String>,
+}
+
+impl Deprecations {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds (or replaces) the notice for its export.
+    pub fn with(mut self, deprecation: Deprecation) -> Self {
+        self.entries.insert(deprecation.export.clone(), deprecation);
+        self
+    }
+
+    /// Reads the `[[deprecations]]` entries of a `Component.toml`.
+    ///
+    /// # Errors
+    ///
+    /// - `DeprecationError::InvalidDefinition` if the TOML is malformed or
+    ///   an entry has an unknown key
+    /// - `DeprecationError::Duplicate` if an export is listed twice
+    pub fn from_manifest(source: &str) -> Result<Self, DeprecationError> {
+        let file: ManifestFile = toml::from_str(source)
+            .map_err(|e| DeprecationError::InvalidDefinition(e.to_string()))?;
+        let mut deprecations = Self::new();
+        for entry in file.deprecations {
+            if deprecations.entries.contains_key(&entry.export) {
+                return Err(DeprecationError::Duplicate(entry.export));
+            }
+            deprecations = deprecations.with(Deprecation {
+                export: entry.export,
+                since: entry.since,
+                removed_in: entry.removed_in,
+                replacement: entry.replacement,
+                note: entry.note,
+            });
+        }
+        Ok(deprecations)
+    }
+
+    /// Collects functions gated with `@deprecated(version = X)` in a WIT
+    /// source.
+    ///
+    /// Doc comments directly above the function become the note.
+    /// Functions without the gate, and gates not followed by a function,
+    /// are ignored.
+    pub fn from_wit(source: &str) -> Self {
+        let mut deprecations = Self::new();
+        let mut pending: Option<String> = None;
+        let mut docs: Vec<&str> = Vec::new();
+
+        for line in source.lines().map(str::trim) {
+            if let Some(doc) = line.strip_prefix("///") {
+                docs.push(doc.trim());
+                continue;
+            }
+            if let Some(args) = line.strip_prefix("@deprecated(") {
+                pending = args
+                    .trim_end_matches(')')
+                    .split(',')
+                    .filter_map(|arg| arg.split_once('='))
+                    .find(|(key, _)| key.trim() == "version")
+                    .map(|(_, version)| version.trim().to_string());
+                continue;
+            }
+            if line.starts_with('@') {
+                // Other gates (`@since`, `@unstable`) may sit between
+                continue;
+            }
+
+            if let Some(since) = pending.take() {
+                if let Some((name, rest)) = line.split_once(':') {
+                    if rest.trim_start().starts_with("func") {
+                        let mut deprecation = Deprecation::new(name.trim(), since);
+                        if !docs.is_empty() {
+                            deprecation = deprecation.with_note(docs.join(" "));
+                        }
+                        deprecations = deprecations.with(deprecation);
+                    }
+                }
+            }
+            docs.clear();
+        }
+        deprecations
+    }
+
+    /// Combines `self` with `other`; entries of `self` win on conflict.
+    pub fn merged(mut self, other: Self) -> Self {
+        for (export, deprecation) in other.entries {
+            self.entries.entry(export).or_insert(deprecation);
+        }
+        self
+    }
+
+    /// Notice for `export`, if deprecated.
+    pub fn get(&self, export: &str) -> Option<&Deprecation> {
+        self.entries.get(export)
+    }
+
+    /// Returns true if no export is deprecated.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Notices sorted by export name.
+    pub fn iter(&self) -> impl Iterator<Item = &Deprecation> {
+        self.entries.values()
+    }
+}
+
+fn major(version: &str) -> Option<u64> {
+    version
+        .trim_start_matches('v')
+        .split('.')
+        .next()
+        .and_then(|major| major.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_manifest() {
+        let deprecations = Deprecations::from_manifest(
+            r#"
+[component]
+name = "users"
+
+[[deprecations]]
+export = "get-user"
+since = "1.4.0"
+removed_in = "3.0.0"
+replacement = "get-user-v2"
+
+[[deprecations]]
+export = "list-users"
+since = "1.2.0"
+"#,
+        )
+        .unwrap();
+
+        let get_user = deprecations.get("get-user").unwrap();
+        assert_eq!(get_user.removal_major(), Some(3));
+        assert_eq!(
+            get_user.to_string(),
+            "'get-user' is deprecated since 1.4.0 and will be removed in 3.0.0; use 'get-user-v2' instead"
+        );
+        assert_eq!(
+            deprecations.get("list-users").unwrap().removal_major(),
+            Some(2)
+        );
+        assert!(deprecations.get("create-user").is_none());
+
+        assert_eq!(
+            Deprecations::from_manifest(
+                "[[deprecations]]\nexport = \"a\"\nsince = \"1.0\"\n[[deprecations]]\nexport = \"a\"\nsince = \"1.1\"\n"
+            ),
+            Err(DeprecationError::Duplicate("a".to_string()))
+        );
+        assert!(matches!(
+            Deprecations::from_manifest("[[deprecations]]\nexport = \"a\"\n"),
+            Err(DeprecationError::InvalidDefinition(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_wit_and_merge() {
+        let wit = r#"
+interface users {
+    /// Superseded by get-user-v2.
+    @since(version = 1.0.0)
+    @deprecated(version = 1.4.0)
+    get-user: func(id: u64) -> string;
+
+    /// Current lookup.
+    get-user-v2: func(id: u64) -> string;
+
+    @deprecated(version = 1.1.0)
+    list-users: func() -> list<string>;
+}
+"#;
+        let from_wit = Deprecations::from_wit(wit);
+        assert_eq!(
+            from_wit
+                .iter()
+                .map(|d| d.export.as_str())
+                .collect::<Vec<_>>(),
+            vec!["get-user", "list-users"]
+        );
+        assert_eq!(
+            from_wit.get("get-user").unwrap().note.as_deref(),
+            Some("Superseded by get-user-v2.")
+        );
+        assert_eq!(from_wit.get("list-users").unwrap().note, None);
+
+        let manifest = Deprecations::new()
+            .with(Deprecation::new("get-user", "1.4.0").with_replacement("get-user-v2"));
+        let merged = manifest.merged(from_wit);
+        assert_eq!(
+            merged.get("get-user").unwrap().replacement.as_deref(),
+            Some("get-user-v2")
+        );
+        assert!(merged.get("list-users").is_some());
+    }
+}
@@ -0,0 +1,1409 @@
+//! Manifest schemas and validation for `Component.toml` and `Host.toml`.
+//!
+//! Schemas are embedded as static field tables so validation works without
+//! any files beyond the manifest itself. [`validate_manifest`] reports:
+//!
+//! - TOML syntax errors
+//! - unknown keys and missing required keys
+//! - type errors and out-of-range integers
+//! - invalid capability patterns (bad globs, malformed domains)
+//! - conflicting limits (e.g. a default above its ceiling)
+//!
+//! Every [`Diagnostic`] carries a 1-based line and column so the output can
+//! be consumed by editors and pre-commit hooks. A `config validate` command
+//! only needs to read the file, call [`validate_manifest`], print the
+//! diagnostics and exit non-zero if any is an error.
+
+// Layer 1: Standard library imports
+use std::fmt;
+use std::ops::Range;
+use std::path::Path;
+
+// Layer 2: Third-party crate imports
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+use toml::de::{DeTable, DeValue};
+use toml::Spanned;
+
+// Layer 3: Internal module imports
+use crate::core::security::capability::split_exclusions;
+
+// =============================================================================
+// Schema Definitions
+// =============================================================================
+
+/// Upper bound for linear memory of a wasm32 component (4 GiB).
+const WASM32_MAX_MEMORY_BYTES: i64 = 4 * 1024 * 1024 * 1024;
+
+/// Expected type of a manifest value.
+#[derive(Debug, Clone, Copy)]
+enum FieldType {
+    String,
+    Bool,
+    Integer {
+        min: i64,
+        max: i64,
+    },
+    /// Capability glob (`*`, `prefix/*`, `*.suffix`, or exact).
+    Glob,
+    /// Host pattern (`*`, `*.example.com`, or `api.example.com`).
+    Domain,
+    /// Storage namespace (non-empty, no path separators).
+    Namespace,
+    Array(&'static FieldType),
+    Table(&'static [Field]),
+    /// Free-form table of scalar values (`[config]`).
+    Scalars,
+}
+
+/// A key permitted in a manifest table.
+#[derive(Debug, Clone, Copy)]
+struct Field {
+    name: &'static str,
+    ty: FieldType,
+    required: bool,
+    description: &'static str,
+}
+
+const fn field(name: &'static str, ty: FieldType, description: &'static str) -> Field {
+    Field {
+        name,
+        ty,
+        required: false,
+        description,
+    }
+}
+
+const fn required(name: &'static str, ty: FieldType, description: &'static str) -> Field {
+    Field {
+        name,
+        ty,
+        required: true,
+        description,
+    }
+}
+
+const POSITIVE: FieldType = FieldType::Integer {
+    min: 1,
+    max: i64::MAX,
+};
+const NON_NEGATIVE: FieldType = FieldType::Integer {
+    min: 0,
+    max: i64::MAX,
+};
+const MEMORY_BYTES: FieldType = FieldType::Integer {
+    min: 1,
+    max: WASM32_MAX_MEMORY_BYTES,
+};
+const PORT: FieldType = FieldType::Integer { min: 1, max: 65535 };
+const GLOBS: FieldType = FieldType::Array(&FieldType::Glob);
+
+const COMPONENT_SECTION: &[Field] = &[
+    required("name", FieldType::String, "Component name"),
+    field("version", FieldType::String, "Component version"),
+    field(
+        "description",
+        FieldType::String,
+        "Human-readable description",
+    ),
+    field(
+        "debug_mode",
+        FieldType::Bool,
+        "Enable debug instrumentation",
+    ),
+    field(
+        "critical",
+        FieldType::Bool,
+        "Reserve host headroom for this component",
+    ),
+    field(
+        "pure",
+        FieldType::Bool,
+        "Deterministic component whose responses may be cached",
+    ),
+];
+
+const COMPONENT_LIMITS: &[Field] = &[
+    field("max_memory_bytes", MEMORY_BYTES, "Linear memory limit"),
+    field(
+        "max_execution_time_ms",
+        POSITIVE,
+        "Per-call execution time limit",
+    ),
+    field("max_fuel", POSITIVE, "Per-call fuel limit"),
+];
+
+const COMPONENT_STORAGE: &[Field] = &[
+    field(
+        "namespace",
+        FieldType::Namespace,
+        "Storage namespace isolating this component's keys",
+    ),
+    field(
+        "locality",
+        FieldType::Array(&FieldType::String),
+        "Dominant key prefixes to co-locate on one storage shard",
+    ),
+];
+
+const MESSAGING_CAPS: &[Field] = &[
+    field(
+        "can_send_to",
+        GLOBS,
+        "Component patterns this component may send to",
+    ),
+    field(
+        "can_receive_from",
+        GLOBS,
+        "Component patterns this component may receive from",
+    ),
+];
+
+const STORAGE_CAPS: &[Field] = &[
+    field("can_read_keys", GLOBS, "Key patterns that may be read"),
+    field("can_write_keys", GLOBS, "Key patterns that may be written"),
+    field(
+        "max_bytes",
+        POSITIVE,
+        "Storage quota for keys plus values, in bytes",
+    ),
+    field("max_keys", POSITIVE, "Storage quota in number of keys"),
+    field(
+        "max_value_bytes",
+        POSITIVE,
+        "Size limit for a single value, in bytes",
+    ),
+];
+
+const FILESYSTEM_CAPS: &[Field] = &[
+    field("can_read_paths", GLOBS, "Path patterns that may be read"),
+    field(
+        "can_write_paths",
+        GLOBS,
+        "Path patterns that may be written",
+    ),
+    field(
+        "can_execute_paths",
+        GLOBS,
+        "Program path patterns that may be executed",
+    ),
+    field(
+        "preopen_dirs",
+        FieldType::Array(&FieldType::String),
+        "Host directories exposed read-only to wasi:filesystem",
+    ),
+    field(
+        "preopen_writable_dirs",
+        FieldType::Array(&FieldType::String),
+        "Host directories exposed writable to wasi:filesystem",
+    ),
+];
+
+const NETWORK_CAPS: &[Field] = &[
+    field(
+        "can_connect_to",
+        FieldType::Array(&FieldType::Domain),
+        "Host patterns for outbound connections",
+    ),
+    field(
+        "can_bind_ports",
+        FieldType::Array(&PORT),
+        "Ports that may be bound",
+    ),
+];
+
+const CLOCK_CAPS: &[Field] = &[
+    field("wall", FieldType::Bool, "Access to wasi:clocks/wall-clock"),
+    field(
+        "monotonic",
+        FieldType::Bool,
+        "Access to wasi:clocks/monotonic-clock",
+    ),
+];
+
+const ENVIRONMENT_CAPS: &[Field] = &[
+    field(
+        "can_read_vars",
+        GLOBS,
+        "Environment variable patterns that may be read",
+    ),
+    field(
+        "can_write_vars",
+        GLOBS,
+        "Environment variable patterns that may be set or unset",
+    ),
+];
+
+const RANDOM_CAPS: &[Field] = &[
+    field(
+        "secure",
+        FieldType::Bool,
+        "Access to cryptographically secure randomness",
+    ),
+    field(
+        "insecure",
+        FieldType::Bool,
+        "Access to fast, non-cryptographic randomness",
+    ),
+];
+
+const PROCESS_CAPS: &[Field] = &[
+    field("can_spawn", GLOBS, "Program patterns that may be spawned"),
+    field(
+        "can_signal",
+        GLOBS,
+        "Program patterns whose processes may be signalled",
+    ),
+];
+
+const CAPABILITIES: &[Field] = &[
+    field(
+        "messaging",
+        FieldType::Table(MESSAGING_CAPS),
+        "Messaging grants",
+    ),
+    field("storage", FieldType::Table(STORAGE_CAPS), "Storage grants"),
+    field(
+        "filesystem",
+        FieldType::Table(FILESYSTEM_CAPS),
+        "Filesystem grants",
+    ),
+    field("network", FieldType::Table(NETWORK_CAPS), "Network grants"),
+    field("clocks", FieldType::Table(CLOCK_CAPS), "WASI clock grants"),
+    field(
+        "environment",
+        FieldType::Table(ENVIRONMENT_CAPS),
+        "Environment variable grants",
+    ),
+    field("random", FieldType::Table(RANDOM_CAPS), "Randomness grants"),
+    field(
+        "process",
+        FieldType::Table(PROCESS_CAPS),
+        "Subprocess grants",
+    ),
+];
+
+const POOL_SIZE: FieldType = FieldType::Integer { min: 0, max: 1024 };
+
+const SCALING_SCHEDULE: &[Field] = &[
+    field(
+        "days",
+        FieldType::Array(&FieldType::String),
+        "Days the window applies to (`mon`..`sun`; empty = every day)",
+    ),
+    required("start", FieldType::String, "Window start (HH:MM, local)"),
+    required("end", FieldType::String, "Window end (HH:MM, local)"),
+    required("size", POOL_SIZE, "Warm instances during the window"),
+];
+
+const SCALING_QUEUE: &[Field] = &[
+    required("above", POSITIVE, "Queue depth threshold"),
+    required(
+        "size",
+        POOL_SIZE,
+        "Warm instances at or above the threshold",
+    ),
+];
+
+const COMPONENT_SCALING: &[Field] = &[
+    field(
+        "default_size",
+        POOL_SIZE,
+        "Warm instances outside any window",
+    ),
+    field("max_size", POOL_SIZE, "Upper bound on the pool size"),
+    field(
+        "utc_offset_minutes",
+        FieldType::Integer {
+            min: -720,
+            max: 840,
+        },
+        "Offset of schedule times from UTC",
+    ),
+    field(
+        "schedule",
+        FieldType::Array(&FieldType::Table(SCALING_SCHEDULE)),
+        "Time-based pool sizes (first match wins)",
+    ),
+    field(
+        "queue",
+        FieldType::Array(&FieldType::Table(SCALING_QUEUE)),
+        "Queue-depth thresholds that scale the pool up",
+    ),
+];
+
+const DEPRECATION: &[Field] = &[
+    required("export", FieldType::String, "Deprecated export name"),
+    required("since", FieldType::String, "Version that deprecated it"),
+    field(
+        "removed_in",
+        FieldType::String,
+        "Version that will remove it",
+    ),
+    field(
+        "replacement",
+        FieldType::String,
+        "Export callers should migrate to",
+    ),
+    field("note", FieldType::String, "Migration note"),
+];
+
+const COMPONENT_MESSAGING: &[Field] = &[field(
+    "accepts",
+    FieldType::Array(&FieldType::String),
+    "Payload codecs or MIME types the component can parse",
+)];
+
+const COMPONENT_SHEDDING: &[Field] = &[
+    field(
+        "margin_ms",
+        NON_NEGATIVE,
+        "Slack required before a message's deadline",
+    ),
+    field(
+        "initial_estimate_ms",
+        POSITIVE,
+        "Service time assumed before one was measured",
+    ),
+];
+
+const COMPONENT_SCHEMA: &[Field] = &[
+    required(
+        "component",
+        FieldType::Table(COMPONENT_SECTION),
+        "Component identity",
+    ),
+    field(
+        "limits",
+        FieldType::Table(COMPONENT_LIMITS),
+        "Resource limits",
+    ),
+    field(
+        "storage",
+        FieldType::Table(COMPONENT_STORAGE),
+        "Storage settings",
+    ),
+    field(
+        "capabilities",
+        FieldType::Table(CAPABILITIES),
+        "Capability grants",
+    ),
+    field(
+        "scaling",
+        FieldType::Table(COMPONENT_SCALING),
+        "Warm instance pool scaling",
+    ),
+    field(
+        "messaging",
+        FieldType::Table(COMPONENT_MESSAGING),
+        "Message delivery settings",
+    ),
+    field(
+        "shedding",
+        FieldType::Table(COMPONENT_SHEDDING),
+        "Deadline-aware load shedding",
+    ),
+    field(
+        "deprecations",
+        FieldType::Array(&FieldType::Table(DEPRECATION)),
+        "Exports scheduled for removal",
+    ),
+    field(
+        "config",
+        FieldType::Scalars,
+        "Tunable parameters, updatable at runtime",
+    ),
+];
+
+const HOST_SECTION: &[Field] = &[required("name", FieldType::String, "Host name")];
+
+const HOST_RUNTIME: &[Field] = &[
+    field(
+        "engine",
+        FieldType::String,
+        "Execution engine backend (`wasmtime`, or `mock` with the mock-engine feature)",
+    ),
+    field(
+        "max_components",
+        POSITIVE,
+        "Maximum concurrently loaded components",
+    ),
+    field(
+        "debug_mode",
+        FieldType::Bool,
+        "Enable debug instrumentation",
+    ),
+    field(
+        "code_cache_dir",
+        FieldType::String,
+        "Directory for ahead-of-time compiled components",
+    ),
+    field(
+        "code_cache_max_bytes",
+        POSITIVE,
+        "Size budget of the compiled component cache",
+    ),
+    field(
+        "deterministic",
+        FieldType::Bool,
+        "Run components with seeded randomness and virtual clocks",
+    ),
+    field(
+        "deterministic_seed",
+        NON_NEGATIVE,
+        "Seed of the deterministic random source",
+    ),
+    field(
+        "deterministic_epoch_ms",
+        NON_NEGATIVE,
+        "Virtual wall-clock start, in milliseconds since Unix epoch",
+    ),
+    field(
+        "deterministic_tick_us",
+        NON_NEGATIVE,
+        "Virtual clock advance per read, in microseconds",
+    ),
+];
+
+const HOST_LIMITS: &[Field] = &[
+    field(
+        "max_memory_bytes",
+        MEMORY_BYTES,
+        "Memory ceiling for any component",
+    ),
+    field(
+        "default_memory_bytes",
+        MEMORY_BYTES,
+        "Memory limit for components that set none",
+    ),
+    field(
+        "max_execution_time_ms",
+        POSITIVE,
+        "Execution time ceiling for any component",
+    ),
+    field(
+        "default_execution_time_ms",
+        POSITIVE,
+        "Execution time limit for components that set none",
+    ),
+];
+
+const HOST_CAPACITY: &[Field] = &[
+    field(
+        "memory_bytes",
+        POSITIVE,
+        "Total memory available to components",
+    ),
+    field(
+        "instance_slots",
+        POSITIVE,
+        "Total component instances the host admits",
+    ),
+    field("fuel_budget", POSITIVE, "Total fuel shared by components"),
+];
+
+const HOST_AUDIT: &[Field] = &[
+    field("enabled", FieldType::Bool, "Enable security audit logging"),
+    field("buffer_capacity", POSITIVE, "Audit event buffer size"),
+];
+
+const HOST_PROFILE: &[Field] = &[
+    field(
+        "require_signatures",
+        FieldType::Bool,
+        "Refuse unsigned components",
+    ),
+    field(
+        "debug_logging",
+        FieldType::Bool,
+        "Enable debug instrumentation",
+    ),
+    field(
+        "unlimited_fuel",
+        FieldType::Bool,
+        "Remove the per-call fuel limit",
+    ),
+    field("max_fuel", POSITIVE, "Per-call fuel limit"),
+    field("max_memory_bytes", MEMORY_BYTES, "Memory ceiling"),
+    field("max_execution_time_ms", POSITIVE, "Execution time ceiling"),
+    field(
+        "audit_logging",
+        FieldType::Bool,
+        "Enable security audit logging",
+    ),
+];
+
+const HOST_PROFILES: &[Field] = &[
+    field(
+        "dev",
+        FieldType::Table(HOST_PROFILE),
+        "Overrides for the dev install profile",
+    ),
+    field(
+        "prod",
+        FieldType::Table(HOST_PROFILE),
+        "Overrides for the prod install profile",
+    ),
+];
+
+const HOST_SCHEMA: &[Field] = &[
+    required("host", FieldType::Table(HOST_SECTION), "Host identity"),
+    field(
+        "runtime",
+        FieldType::Table(HOST_RUNTIME),
+        "Runtime settings",
+    ),
+    field(
+        "limits",
+        FieldType::Table(HOST_LIMITS),
+        "Limit ceilings and defaults",
+    ),
+    field(
+        "capacity",
+        FieldType::Table(HOST_CAPACITY),
+        "Host capacity used for critical-component reservations",
+    ),
+    field("audit", FieldType::Table(HOST_AUDIT), "Audit logging"),
+    field(
+        "profiles",
+        FieldType::Table(HOST_PROFILES),
+        "Install profile definitions",
+    ),
+];
+
+/// `(table, default key, ceiling key)` pairs that must satisfy default <= ceiling.
+const HOST_LIMIT_PAIRS: &[(&str, &str, &str)] = &[
+    ("limits", "default_memory_bytes", "max_memory_bytes"),
+    (
+        "limits",
+        "default_execution_time_ms",
+        "max_execution_time_ms",
+    ),
+];
+
+// =============================================================================
+// ManifestKind
+// =============================================================================
+
+/// The manifest formats understood by the validator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestKind {
+    /// Per-component manifest (`Component.toml`).
+    Component,
+    /// Host runtime manifest (`Host.toml`).
+    Host,
+}
+
+impl ManifestKind {
+    /// Canonical file name for this manifest kind.
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Self::Component => "Component.toml",
+            Self::Host => "Host.toml",
+        }
+    }
+
+    /// Infers the manifest kind from a path's file name.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.file_name()?.to_str()? {
+            "Component.toml" => Some(Self::Component),
+            "Host.toml" => Some(Self::Host),
+            _ => None,
+        }
+    }
+
+    /// Returns the embedded schema as a JSON Schema document.
+    ///
+    /// Suitable for editor integrations (e.g. Taplo / Even Better TOML).
+    pub fn json_schema(&self) -> JsonValue {
+        let mut schema = table_schema(self.schema());
+        if let JsonValue::Object(map) = &mut schema {
+            map.insert(
+                "$schema".to_string(),
+                json!("http://json-schema.org/draft-07/schema#"),
+            );
+            map.insert("title".to_string(), json!(self.file_name()));
+        }
+        schema
+    }
+
+    fn schema(&self) -> &'static [Field] {
+        match self {
+            Self::Component => COMPONENT_SCHEMA,
+            Self::Host => HOST_SCHEMA,
+        }
+    }
+}
+
+impl fmt::Display for ManifestKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.file_name())
+    }
+}
+
+// =============================================================================
+// Diagnostics
+// =============================================================================
+
+/// Severity of a validation diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The manifest is invalid.
+    Error,
+    /// The manifest is valid but likely wrong.
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => f.write_str("error"),
+            Self::Warning => f.write_str("warning"),
+        }
+    }
+}
+
+/// A single validation finding with its source position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Finding severity.
+    pub severity: Severity,
+    /// 1-based line of the offending token.
+    pub line: usize,
+    /// 1-based column (in characters) of the offending token.
+    pub column: usize,
+    /// Dotted key path, empty for document-level findings.
+    pub path: String,
+    /// Human-readable description.
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Returns `true` for error-severity diagnostics.
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}: ", self.line, self.column, self.severity)?;
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path)?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+// =============================================================================
+// Validation
+// =============================================================================
+
+/// Validates manifest `source` against the embedded schema for `kind`.
+///
+/// Returns all findings ordered by position; an empty list means the
+/// manifest is valid.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::config::manifest::{validate_manifest, ManifestKind};
+///
+/// let diagnostics = validate_manifest(
+///     ManifestKind::Component,
+///     "[component]\nname = \"echo\"\n[limits]\nmax_fuel = 0\n",
+/// );
+/// assert_eq!(diagnostics.len(), 1);
+/// assert_eq!((diagnostics[0].line, diagnostics[0].column), (4, 12));
+/// ```
+pub fn validate_manifest(kind: ManifestKind, source: &str) -> Vec<Diagnostic> {
+    let mut validator = Validator {
+        source,
+        diagnostics: Vec::new(),
+    };
+
+    match DeTable::parse(source) {
+        Ok(root) => {
+            validator.check_table(root.get_ref(), root.span(), kind.schema(), "");
+            if kind == ManifestKind::Host {
+                validator.check_limit_pairs(root.get_ref(), HOST_LIMIT_PAIRS);
+            }
+        }
+        Err(e) => {
+            let span = e.span().unwrap_or(0..0);
+            validator.error(span, "", format!("TOML syntax error: {}", e.message()));
+        }
+    }
+
+    let mut diagnostics = validator.diagnostics;
+    diagnostics.sort_by_key(|d| (d.line, d.column));
+    diagnostics
+}
+
+struct Validator<'s> {
+    source: &'s str,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Validator<'_> {
+    fn push(&mut self, severity: Severity, span: Range<usize>, path: &str, message: String) {
+        let (line, column) = line_column(self.source, span.start);
+        self.diagnostics.push(Diagnostic {
+            severity,
+            line,
+            column,
+            path: path.to_string(),
+            message,
+        });
+    }
+
+    fn error(&mut self, span: Range<usize>, path: &str, message: String) {
+        self.push(Severity::Error, span, path, message);
+    }
+
+    fn check_table(
+        &mut self,
+        table: &DeTable<'_>,
+        table_span: Range<usize>,
+        fields: &[Field],
+        prefix: &str,
+    ) {
+        for (key, value) in table.iter() {
+            let path = join(prefix, key.get_ref());
+            match fields.iter().find(|f| f.name == key.get_ref().as_ref()) {
+                Some(field) => self.check_value(value, field.ty, &path),
+                None => {
+                    let hint = closest(key.get_ref(), fields)
+                        .map(|name| format!(" (did you mean `{}`?)", name))
+                        .unwrap_or_default();
+                    self.error(key.span(), &path, format!("unknown key{}", hint));
+                }
+            }
+        }
+
+        for field in fields.iter().filter(|f| f.required) {
+            if !table.keys().any(|k| k.get_ref() == field.name) {
+                let path = join(prefix, field.name);
+                self.error(
+                    table_span.clone(),
+                    &path,
+                    format!("missing required key `{}`", field.name),
+                );
+            }
+        }
+    }
+
+    fn check_value(&mut self, value: &Spanned<DeValue<'_>>, ty: FieldType, path: &str) {
+        let span = value.span();
+        match (ty, value.get_ref()) {
+            (FieldType::String, DeValue::String(_)) | (FieldType::Bool, DeValue::Boolean(_)) => {}
+            (FieldType::Integer { min, max }, DeValue::Integer(int)) => {
+                match i64::from_str_radix(int.as_str(), int.radix()) {
+                    Ok(n) if n < min || n > max => self.error(
+                        span,
+                        path,
+                        format!("{} is out of range ({}..={})", n, min, max),
+                    ),
+                    Ok(_) => {}
+                    Err(_) => self.error(span, path, format!("{} does not fit in i64", int)),
+                }
+            }
+            (FieldType::Glob, DeValue::String(s)) => {
+                if let Err(reason) = check_with_exclusions(s, check_glob) {
+                    self.error(span, path, format!("invalid pattern `{}`: {}", s, reason));
+                }
+            }
+            (FieldType::Domain, DeValue::String(s)) => {
+                if let Err(reason) = check_with_exclusions(s, check_domain_pattern) {
+                    self.error(span, path, format!("invalid host `{}`: {}", s, reason));
+                }
+            }
+            (FieldType::Namespace, DeValue::String(s)) => {
+                if s.is_empty() {
+                    self.error(span, path, "namespace cannot be empty".to_string());
+                } else if s.contains('/') || s.contains('\\') {
+                    self.error(
+                        span,
+                        path,
+                        "namespace cannot contain '/' or '\\'".to_string(),
+                    );
+                }
+            }
+            (FieldType::Array(elem), DeValue::Array(items)) => {
+                for (i, item) in items.iter().enumerate() {
+                    self.check_value(item, *elem, &format!("{}[{}]", path, i));
+                }
+            }
+            (FieldType::Table(fields), DeValue::Table(table)) => {
+                self.check_table(table, span, fields, path);
+            }
+            (FieldType::Scalars, DeValue::Table(table)) => {
+                for (key, value) in table.iter() {
+                    if matches!(value.get_ref(), DeValue::Array(_) | DeValue::Table(_)) {
+                        self.error(
+                            value.span(),
+                            &join(path, key.get_ref()),
+                            format!(
+                                "expected a scalar value, found {}",
+                                value_name(value.get_ref())
+                            ),
+                        );
+                    }
+                }
+            }
+            (expected, _) => self.error(
+                span,
+                path,
+                format!(
+                    "expected {}, found {}",
+                    type_name(expected),
+                    value_name(value.get_ref())
+                ),
+            ),
+        }
+    }
+
+    fn check_limit_pairs(&mut self, root: &DeTable<'_>, pairs: &[(&str, &str, &str)]) {
+        for (table_name, default_key, max_key) in pairs {
+            let Some(DeValue::Table(table)) = root.get(*table_name).map(|v| v.get_ref()) else {
+                continue;
+            };
+            let (Some((default, span)), Some((max, _))) =
+                (int_entry(table, default_key), int_entry(table, max_key))
+            else {
+                continue;
+            };
+            if default > max {
+                self.error(
+                    span,
+                    &join(table_name, default_key),
+                    format!(
+                        "{} exceeds `{}` ({}); components would start over the ceiling",
+                        default, max_key, max
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn int_entry(table: &DeTable<'_>, key: &str) -> Option<(i64, Range<usize>)> {
+    let value = table.get(key)?;
+    match value.get_ref() {
+        DeValue::Integer(int) => i64::from_str_radix(int.as_str(), int.radix())
+            .ok()
+            .map(|n| (n, value.span())),
+        _ => None,
+    }
+}
+
+/// Validates an include pattern and each of its `!` exclusions with `check`.
+fn check_with_exclusions(
+    pattern: &str,
+    check: fn(&str) -> Result<(), String>,
+) -> Result<(), String> {
+    let (include, exclusions) = split_exclusions(pattern)
+        .ok_or_else(|| "only `!pattern` exclusions may follow the pattern".to_string())?;
+    check(include)?;
+    for excluded in exclusions {
+        check(excluded).map_err(|reason| format!("exclusion `{}`: {}", excluded, reason))?;
+    }
+    Ok(())
+}
+
+/// Validates a capability glob against the syntax `PatternMatcher` supports.
+fn check_glob(pattern: &str) -> Result<(), String> {
+    if pattern.is_empty() {
+        return Err("pattern cannot be empty".to_string());
+    }
+    if pattern.trim() != pattern || pattern.chars().any(|c| c.is_whitespace() && c != ' ') {
+        return Err("pattern may only contain inner spaces as whitespace".to_string());
+    }
+    if pattern == "*" {
+        return Ok(());
+    }
+    let rest = pattern
+        .strip_suffix("/*")
+        .or_else(|| pattern.strip_prefix("*."))
+        .unwrap_or(pattern);
+    if rest.is_empty() {
+        return Err("wildcard needs a prefix or suffix".to_string());
+    }
+    if rest.contains('*') {
+        return Err("`*` is only supported as `*`, `prefix/*` or `*.suffix`".to_string());
+    }
+    Ok(())
+}
+
+/// Validates a host pattern: `*`, `*.domain`, or a plain domain name.
+///
+/// IP literals are rejected because the egress proxy never dials them.
+fn check_domain_pattern(pattern: &str) -> Result<(), String> {
+    if pattern == "*" {
+        return Ok(());
+    }
+    let domain = pattern.strip_prefix("*.").unwrap_or(pattern);
+    if domain.parse::<std::net::IpAddr>().is_ok() {
+        return Err("IP literals are not allowed; grant a domain name".to_string());
+    }
+    if domain.is_empty() || domain.len() > 253 {
+        return Err("domain must be 1-253 characters".to_string());
+    }
+    for label in domain.split('.') {
+        if label.is_empty() || label.len() > 63 {
+            return Err(format!("label `{}` must be 1-63 characters", label));
+        }
+        if !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return Err(format!(
+                "label `{}` may only contain letters, digits and '-'",
+                label
+            ));
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err(format!("label `{}` cannot start or end with '-'", label));
+        }
+    }
+    Ok(())
+}
+
+/// Converts a byte offset into a 1-based (line, column) pair.
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let offset = offset.min(source.len());
+    let before = source.get(..offset).unwrap_or(source);
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let column = before
+        .get(line_start..)
+        .map(|s| s.chars().count())
+        .unwrap_or(0)
+        + 1;
+    (line, column)
+}
+
+fn join(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+/// Suggests a known key for a likely typo (edit distance <= 2).
+fn closest(key: &str, fields: &[Field]) -> Option<&'static str> {
+    fields
+        .iter()
+        .map(|f| (edit_distance(key, f.name), f.name))
+        .filter(|(d, _)| *d <= 2)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, name)| name)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+fn type_name(ty: FieldType) -> &'static str {
+    match ty {
+        FieldType::String | FieldType::Glob | FieldType::Domain | FieldType::Namespace => {
+            "a string"
+        }
+        FieldType::Bool => "a boolean",
+        FieldType::Integer { .. } => "an integer",
+        FieldType::Array(_) => "an array",
+        FieldType::Table(_) | FieldType::Scalars => "a table",
+    }
+}
+
+fn value_name(value: &DeValue<'_>) -> &'static str {
+    match value {
+        DeValue::String(_) => "a string",
+        DeValue::Integer(_) => "an integer",
+        DeValue::Float(_) => "a float",
+        DeValue::Boolean(_) => "a boolean",
+        DeValue::Datetime(_) => "a datetime",
+        DeValue::Array(_) => "an array",
+        DeValue::Table(_) => "a table",
+    }
+}
+
+// =============================================================================
+// JSON Schema Export
+// =============================================================================
+
+fn table_schema(fields: &[Field]) -> JsonValue {
+    let mut properties = JsonMap::new();
+    for f in fields {
+        let mut schema = type_schema(f.ty);
+        if let JsonValue::Object(map) = &mut schema {
+            map.insert("description".to_string(), json!(f.description));
+        }
+        properties.insert(f.name.to_string(), schema);
+    }
+    let required: Vec<&str> = fields
+        .iter()
+        .filter(|f| f.required)
+        .map(|f| f.name)
+        .collect();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+fn type_schema(ty: FieldType) -> JsonValue {
+    match ty {
+        FieldType::String => json!({ "type": "string" }),
+        FieldType::Bool => json!({ "type": "boolean" }),
+        FieldType::Integer { min, max } => {
+            json!({ "type": "integer", "minimum": min, "maximum": max })
+        }
+        FieldType::Glob => json!({ "type": "string", "minLength": 1 }),
+        FieldType::Domain => json!({ "type": "string", "minLength": 1, "maxLength": 255 }),
+        FieldType::Namespace => {
+            json!({ "type": "string", "minLength": 1, "pattern": "^[^/\\\\]+$" })
+        }
+        FieldType::Array(elem) => json!({ "type": "array", "items": type_schema(*elem) }),
+        FieldType::Table(fields) => table_schema(fields),
+        FieldType::Scalars => json!({
+            "type": "object",
+            "additionalProperties": { "type": ["string", "integer", "number", "boolean"] },
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_COMPONENT: &str = r#"
+[component]
+name = "echo"
+version = "1.0.0"
+critical = true
+pure = true
+
+[limits]
+max_memory_bytes = 16_777_216
+max_fuel = 1_000_000
+
+[storage]
+namespace = "echo"
+locality = ["orders/", "cart/"]
+
+[capabilities.messaging]
+can_send_to = ["org.example/*", "*"]
+
+[capabilities.network]
+can_connect_to = ["*.example.com", "api.test"]
+can_bind_ports = [8080]
+
+[capabilities.filesystem]
+can_read_paths = ["/srv/echo/*"]
+preopen_dirs = ["/srv/echo"]
+
+[capabilities.clocks]
+monotonic = true
+
+[capabilities.environment]
+can_read_vars = ["ECHO_GREETING"]
+
+[capabilities.random]
+secure = true
+
+[scaling]
+default_size = 2
+max_size = 8
+
+[[scaling.schedule]]
+days = ["mon", "fri"]
+start = "09:00"
+end = "17:00"
+size = 8
+
+[[scaling.queue]]
+above = 100
+size = 6
+
+[messaging]
+accepts = ["json", "text/*"]
+
+[shedding]
+margin_ms = 5
+initial_estimate_ms = 20
+
+[[deprecations]]
+export = "echo-v1"
+since = "1.0.0"
+replacement = "echo"
+
+[config]
+threshold = 10
+ratio = 0.5
+mode = "fast"
+"#;
+
+    const VALID_HOST: &str = r#"
+[host]
+name = "edge-1"
+
+[runtime]
+engine = "wasmtime"
+code_cache_dir = "/var/cache/airssys"
+
+[limits]
+max_memory_bytes = 134217728
+default_memory_bytes = 67108864
+
+[capacity]
+memory_bytes = 1073741824
+instance_slots = 64
+
+[profiles.prod]
+max_fuel = 50000000
+audit_logging = true
+"#;
+
+    fn messages(diagnostics: &[Diagnostic]) -> Vec<String> {
+        diagnostics.iter().map(|d| d.to_string()).collect()
+    }
+
+    #[test]
+    fn test_valid_manifests_have_no_diagnostics() {
+        assert!(validate_manifest(ManifestKind::Component, VALID_COMPONENT).is_empty());
+        assert!(validate_manifest(ManifestKind::Host, VALID_HOST).is_empty());
+    }
+
+    #[test]
+    fn test_syntax_error_position() {
+        let diags = validate_manifest(ManifestKind::Component, "[component]\nname = \n");
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0].message.starts_with("TOML syntax error"));
+        assert_eq!(diags[0].line, 2);
+    }
+
+    #[test]
+    fn test_unknown_key_with_suggestion() {
+        let src = "[component]\nname = \"a\"\n[limits]\nmax_memroy_bytes = 1\n";
+        let diags = validate_manifest(ManifestKind::Component, src);
+        assert_eq!(
+            messages(&diags),
+            vec!["4:1: error: limits.max_memroy_bytes: unknown key (did you mean `max_memory_bytes`?)"]
+        );
+    }
+
+    #[test]
+    fn test_runtime_engine() {
+        let diags = validate_manifest(
+            ManifestKind::Host,
+            "[host]\nname = \"h\"\n[runtime]\nengine = 1\n",
+        );
+        assert_eq!(
+            messages(&diags),
+            vec!["4:10: error: runtime.engine: expected a string, found an integer"]
+        );
+    }
+
+    #[test]
+    fn test_runtime_deterministic() {
+        let src = "[host]\nname = \"h\"\n[runtime]\ndeterministic = true\n\
+                   deterministic_seed = 42\ndeterministic_epoch_ms = 0\n\
+                   deterministic_tick_us = 1000\n";
+        assert!(validate_manifest(ManifestKind::Host, src).is_empty());
+
+        let src = "[host]\nname = \"h\"\n[runtime]\ndeterministic = \"yes\"\n\
+                   deterministic_seed = -1\n";
+        let diags = validate_manifest(ManifestKind::Host, src);
+        let paths: Vec<&str> = diags.iter().map(|d| d.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["runtime.deterministic", "runtime.deterministic_seed"]
+        );
+    }
+
+    #[test]
+    fn test_shedding_table() {
+        let src = "[component]\nname = \"a\"\n[shedding]\nmargin_ms = 0\ninitial_estimate_ms = 0\n";
+        let diags = validate_manifest(ManifestKind::Component, src);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].path, "shedding.initial_estimate_ms");
+
+        let src = "[component]\nname = \"a\"\n[shedding]\nmargin = 5\n";
+        let diags = validate_manifest(ManifestKind::Component, src);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].path, "shedding.margin");
+        assert!(diags[0].message.contains("unknown key"));
+    }
+
+    #[test]
+    fn test_config_section_requires_scalars() {
+        let src = "[component]\nname = \"a\"\n[config]\nlevels = [1, 2]\n";
+        let diags = validate_manifest(ManifestKind::Component, src);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].path, "config.levels");
+        assert!(diags[0].message.contains("expected a scalar value"));
+    }
+
+    #[test]
+    fn test_missing_required_key() {
+        let diags = validate_manifest(ManifestKind::Component, "[component]\nversion = \"1\"\n");
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].path, "component.name");
+        assert!(diags[0].message.contains("missing required key"));
+
+        let diags = validate_manifest(ManifestKind::Host, "");
+        assert_eq!(diags[0].path, "host");
+    }
+
+    #[test]
+    fn test_type_error() {
+        let src = "[component]\nname = 42\n";
+        let diags = validate_manifest(ManifestKind::Component, src);
+        assert_eq!(
+            messages(&diags),
+            vec!["2:8: error: component.name: expected a string, found an integer"]
+        );
+    }
+
+    #[test]
+    fn test_integer_range() {
+        let src =
+            "[component]\nname = \"a\"\n[capabilities.network]\ncan_bind_ports = [80, 70000]\n";
+        let diags = validate_manifest(ManifestKind::Component, src);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].path, "capabilities.network.can_bind_ports[1]");
+        assert_eq!((diags[0].line, diags[0].column), (4, 23));
+    }
+
+    #[test]
+    fn test_bad_globs() {
+        for bad in ["", "a*b", "**", "*.", "/*", "org/*/x"] {
+            assert!(check_glob(bad).is_err(), "{:?} should be rejected", bad);
+        }
+        for bad in [" /data/*", "/data/*\t", "/data/a\nb/*"] {
+            assert!(check_glob(bad).is_err(), "{:?} should be rejected", bad);
+        }
+        for good in [
+            "*",
+            "org.example/*",
+            "*.log",
+            "exact-name",
+            "/data/My Files/*",
+        ] {
+            assert!(check_glob(good).is_ok(), "{:?} should be accepted", good);
+        }
+    }
+
+    #[test]
+    fn test_patterns_with_exclusions() {
+        assert!(check_with_exclusions("/data/* !/data/secrets/*", check_glob).is_ok());
+        assert!(
+            check_with_exclusions("/data/My Files/* !/data/My Files/tmp/*", check_glob).is_ok()
+        );
+        assert!(
+            check_with_exclusions("*.example.com !admin.example.com", check_domain_pattern).is_ok()
+        );
+        for bad in ["/data/* /etc/*", "/data/* !", "/data/* !a*b"] {
+            assert!(
+                check_with_exclusions(bad, check_glob).is_err(),
+                "{:?} should be rejected",
+                bad
+            );
+        }
+        assert!(check_with_exclusions("*.example.com !10.0.0.1", check_domain_pattern).is_err());
+    }
+
+    #[test]
+    fn test_bad_domains() {
+        for bad in [
+            "10.0.0.1",
+            "*.",
+            "-bad.com",
+            "a..b",
+            "under_score.com",
+            "*.*.com",
+        ] {
+            assert!(
+                check_domain_pattern(bad).is_err(),
+                "{:?} should be rejected",
+                bad
+            );
+        }
+        for good in ["*", "*.example.com", "api.example.com", "localhost"] {
+            assert!(
+                check_domain_pattern(good).is_ok(),
+                "{:?} should be accepted",
+                good
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_pattern_reported_in_manifest() {
+        let src = "[component]\nname = \"a\"\n[capabilities.messaging]\ncan_send_to = [\"ok/*\", \"bad*glob\"]\n";
+        let diags = validate_manifest(ManifestKind::Component, src);
+        assert_eq!(diags.len(), 1);
+        assert_eq!((diags[0].line, diags[0].column), (4, 24));
+        assert!(diags[0].message.contains("invalid pattern `bad*glob`"));
+    }
+
+    #[test]
+    fn test_conflicting_host_limits() {
+        let src = "[host]\nname = \"h\"\n[limits]\nmax_execution_time_ms = 1000\ndefault_execution_time_ms = 5000\n";
+        let diags = validate_manifest(ManifestKind::Host, src);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].path, "limits.default_execution_time_ms");
+        assert_eq!((diags[0].line, diags[0].column), (5, 29));
+    }
+
+    #[test]
+    fn test_namespace_rules() {
+        let src = "[component]\nname = \"a\"\n[storage]\nnamespace = \"a/b\"\n";
+        let diags = validate_manifest(ManifestKind::Component, src);
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0].message.contains("cannot contain"));
+    }
+
+    #[test]
+    fn test_kind_from_path() {
+        assert_eq!(
+            ManifestKind::from_path(Path::new("plugins/echo/Component.toml")),
+            Some(ManifestKind::Component)
+        );
+        assert_eq!(
+            ManifestKind::from_path(Path::new("Host.toml")),
+            Some(ManifestKind::Host)
+        );
+        assert_eq!(ManifestKind::from_path(Path::new("Cargo.toml")), None);
+    }
+
+    #[test]
+    fn test_json_schema_export() {
+        let schema = ManifestKind::Component.json_schema();
+        assert_eq!(schema["title"], "Component.toml");
+        assert_eq!(schema["required"], json!(["component"]));
+        assert_eq!(
+            schema["properties"]["capabilities"]["properties"]["network"]["properties"]
+                ["can_bind_ports"]["items"]["maximum"],
+            65535
+        );
+        assert_eq!(schema["additionalProperties"], false);
+    }
+
+    #[test]
+    fn test_line_column_counts_chars() {
+        assert_eq!(line_column("ab\ncd", 0), (1, 1));
+        assert_eq!(line_column("ab\ncd", 4), (2, 2));
+        assert_eq!(line_column("é\nx", 3), (2, 1));
+    }
+}
@@ -1,3 +1,11 @@
 //! Configuration types for airssys-wasm.
 
 pub mod component;
+pub mod content_type;
+pub mod deprecation;
+pub mod manifest;
+pub mod profile;
+pub mod scaling;
+pub mod shedding;
+pub mod values;
+pub mod wit;
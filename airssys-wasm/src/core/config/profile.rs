@@ -0,0 +1,384 @@
+//! Installation profiles: named sets of component defaults.
+//!
+//! A profile bundles the trust, logging, and limit settings applied to
+//! every component installed under it, so teams select `dev` or `prod`
+//! instead of hand-tuning each install:
+//!
+//! | Setting            | `dev`       | `prod`          |
+//! |--------------------|-------------|-----------------|
+//! | Signatures         | optional    | required        |
+//! | Debug logging      | on          | off             |
+//! | Fuel               | unlimited   | 10M per call    |
+//! | Memory             | 256 MB      | 64 MB           |
+//! | Execution time     | 5 minutes   | 30 seconds      |
+//! | Audit logging      | off         | on              |
+//!
+//! Built-in definitions can be overridden per host (or per CLI config) with
+//! `[profiles.<name>]` tables in the same TOML format:
+//!
+//! ```toml
+//! [profiles.prod]
+//! max_fuel = 50000000
+//! max_memory_bytes = 134217728
+//! ```
+//!
+//! A `--profile` flag only needs to parse the name with
+//! [`InstallProfile::from_str`](std::str::FromStr::from_str), look up the
+//! [`ProfileSettings`] in [`InstallProfiles`], and
+//! [`apply`](ProfileSettings::apply) them to the component's config.
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+// Layer 2: Third-party crate imports
+use serde::Deserialize;
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+use crate::core::config::component::{
+    ComponentConfig, DEFAULT_MAX_EXECUTION_TIME_MS, DEFAULT_MAX_MEMORY_BYTES,
+};
+
+// =============================================================================
+// ProfileError
+// =============================================================================
+
+/// Errors raised while selecting or applying an installation profile.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ProfileError {
+    /// The profile name is not `dev` or `prod`.
+    #[error("Unknown install profile '{0}' (expected 'dev' or 'prod')")]
+    UnknownProfile(String),
+
+    /// Profile definitions could not be parsed.
+    #[error("Invalid profile definitions: {0}")]
+    InvalidDefinition(String),
+
+    /// The profile requires a signed component but none was provided.
+    #[error("Profile '{0}' requires a signed component")]
+    SignatureRequired(InstallProfile),
+}
+
+// =============================================================================
+// InstallProfile
+// =============================================================================
+
+/// Named installation profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InstallProfile {
+    /// Relaxed trust, debug logging, and unlimited fuel for local development.
+    Dev,
+    /// Enforced signatures, strict limits, and audit logging.
+    Prod,
+}
+
+impl InstallProfile {
+    /// Profile name as used on the command line and in config files.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Dev => "dev",
+            Self::Prod => "prod",
+        }
+    }
+
+    /// Built-in settings for this profile.
+    pub fn defaults(&self) -> ProfileSettings {
+        match self {
+            Self::Dev => ProfileSettings {
+                require_signatures: false,
+                debug_logging: true,
+                max_fuel: None,
+                max_memory_bytes: 4 * DEFAULT_MAX_MEMORY_BYTES,
+                max_execution_time_ms: 10 * DEFAULT_MAX_EXECUTION_TIME_MS,
+                audit_logging: false,
+            },
+            Self::Prod => ProfileSettings {
+                require_signatures: true,
+                debug_logging: false,
+                max_fuel: Some(10_000_000),
+                max_memory_bytes: DEFAULT_MAX_MEMORY_BYTES,
+                max_execution_time_ms: DEFAULT_MAX_EXECUTION_TIME_MS,
+                audit_logging: true,
+            },
+        }
+    }
+}
+
+impl fmt::Display for InstallProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for InstallProfile {
+    type Err = ProfileError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dev" => Ok(Self::Dev),
+            "prod" => Ok(Self::Prod),
+            other => Err(ProfileError::UnknownProfile(other.to_string())),
+        }
+    }
+}
+
+// =============================================================================
+// ProfileSettings
+// =============================================================================
+
+/// Component defaults enforced by a profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileSettings {
+    /// Refuse unsigned components.
+    pub require_signatures: bool,
+    /// Enable debug instrumentation in installed components.
+    pub debug_logging: bool,
+    /// Per-call fuel limit, `None` for unlimited.
+    pub max_fuel: Option<u64>,
+    /// Linear memory ceiling in bytes.
+    pub max_memory_bytes: u64,
+    /// Per-call execution time ceiling in milliseconds.
+    pub max_execution_time_ms: u64,
+    /// Record security audit events for installed components.
+    pub audit_logging: bool,
+}
+
+impl ProfileSettings {
+    /// Checks that an install satisfies the profile's trust requirements.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProfileError::SignatureRequired` if signatures are required
+    /// and `signed` is false.
+    pub fn check_install(&self, profile: InstallProfile, signed: bool) -> Result<(), ProfileError> {
+        if self.require_signatures && !signed {
+            return Err(ProfileError::SignatureRequired(profile));
+        }
+        Ok(())
+    }
+
+    /// Applies the profile to a component's configuration.
+    ///
+    /// Memory and execution-time limits are capped at the profile ceilings
+    /// (lower limits requested by the component are kept). The fuel limit
+    /// is set to the profile's value, or removed when the profile allows
+    /// unlimited fuel. Debug mode follows `debug_logging`.
+    pub fn apply(&self, config: ComponentConfig) -> ComponentConfig {
+        let memory = config.max_memory_bytes().min(self.max_memory_bytes);
+        let time = config
+            .max_execution_time_ms()
+            .min(self.max_execution_time_ms);
+        let config = config
+            .with_max_memory(memory)
+            .with_max_execution_time(time)
+            .with_debug_mode(self.debug_logging);
+        match self.max_fuel {
+            Some(fuel) => config.with_fuel_limit(fuel),
+            None => config.without_fuel_limit(),
+        }
+    }
+
+    fn merge(&mut self, overrides: ProfileOverrides) {
+        if let Some(v) = overrides.require_signatures {
+            self.require_signatures = v;
+        }
+        if let Some(v) = overrides.debug_logging {
+            self.debug_logging = v;
+        }
+        if let Some(v) = overrides.unlimited_fuel {
+            if v {
+                self.max_fuel = None;
+            }
+        }
+        if let Some(v) = overrides.max_fuel {
+            self.max_fuel = Some(v);
+        }
+        if let Some(v) = overrides.max_memory_bytes {
+            self.max_memory_bytes = v;
+        }
+        if let Some(v) = overrides.max_execution_time_ms {
+            self.max_execution_time_ms = v;
+        }
+        if let Some(v) = overrides.audit_logging {
+            self.audit_logging = v;
+        }
+    }
+}
+
+/// `[profiles.<name>]` table as written in host or CLI config.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProfileOverrides {
+    require_signatures: Option<bool>,
+    debug_logging: Option<bool>,
+    unlimited_fuel: Option<bool>,
+    max_fuel: Option<u64>,
+    max_memory_bytes: Option<u64>,
+    max_execution_time_ms: Option<u64>,
+    audit_logging: Option<bool>,
+}
+
+/// Top-level view of a config file; other sections are ignored.
+#[derive(Debug, Default, Deserialize)]
+struct ProfilesFile {
+    #[serde(default)]
+    profiles: HashMap<String, ProfileOverrides>,
+}
+
+// =============================================================================
+// InstallProfiles
+// =============================================================================
+
+/// Profile definitions in effect for a host.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::config::profile::{InstallProfile, InstallProfiles};
+///
+/// let profiles = InstallProfiles::from_toml("[profiles.prod]\nmax_fuel = 500\n").unwrap();
+/// let prod = profiles.get(InstallProfile::Prod);
+/// assert_eq!(prod.max_fuel, Some(500));
+/// assert!(prod.require_signatures);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstallProfiles {
+    dev: ProfileSettings,
+    prod: ProfileSettings,
+}
+
+impl Default for InstallProfiles {
+    fn default() -> Self {
+        Self {
+            dev: InstallProfile::Dev.defaults(),
+            prod: InstallProfile::Prod.defaults(),
+        }
+    }
+}
+
+impl InstallProfiles {
+    /// Built-in profile definitions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Built-ins overridden by the `[profiles.*]` tables of a config file.
+    ///
+    /// # Errors
+    ///
+    /// - `ProfileError::InvalidDefinition` if the TOML is malformed or a
+    ///   profile table has an unknown key
+    /// - `ProfileError::UnknownProfile` if a table names another profile
+    pub fn from_toml(source: &str) -> Result<Self, ProfileError> {
+        let file: ProfilesFile =
+            toml::from_str(source).map_err(|e| ProfileError::InvalidDefinition(e.to_string()))?;
+        let mut profiles = Self::default();
+        for (name, overrides) in file.profiles {
+            let profile = name.parse::<InstallProfile>()?;
+            profiles.get_mut(profile).merge(overrides);
+        }
+        Ok(profiles)
+    }
+
+    /// Settings for `profile`.
+    pub fn get(&self, profile: InstallProfile) -> &ProfileSettings {
+        match profile {
+            InstallProfile::Dev => &self.dev,
+            InstallProfile::Prod => &self.prod,
+        }
+    }
+
+    fn get_mut(&mut self, profile: InstallProfile) -> &mut ProfileSettings {
+        match profile {
+            InstallProfile::Dev => &mut self.dev,
+            InstallProfile::Prod => &mut self.prod,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::component::id::ComponentId;
+
+    fn config() -> ComponentConfig {
+        ComponentConfig::new(ComponentId::new("a", "b", "c"))
+    }
+
+    #[test]
+    fn test_parse_profile_names() {
+        assert_eq!("dev".parse::<InstallProfile>(), Ok(InstallProfile::Dev));
+        assert_eq!("prod".parse::<InstallProfile>(), Ok(InstallProfile::Prod));
+        assert_eq!(
+            "staging".parse::<InstallProfile>(),
+            Err(ProfileError::UnknownProfile("staging".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_dev_relaxes_limits_and_trust() {
+        let dev = InstallProfile::Dev.defaults();
+        let applied = dev.apply(config().with_fuel_limit(1_000));
+
+        assert_eq!(applied.max_fuel(), None);
+        assert!(applied.debug_mode());
+        assert!(dev.check_install(InstallProfile::Dev, false).is_ok());
+    }
+
+    #[test]
+    fn test_prod_caps_limits_and_requires_signature() {
+        let prod = InstallProfile::Prod.defaults();
+        let applied = prod.apply(
+            config()
+                .with_max_memory(1024 * 1024 * 1024)
+                .with_max_execution_time(1_000)
+                .with_debug_mode(true),
+        );
+
+        assert_eq!(applied.max_memory_bytes(), DEFAULT_MAX_MEMORY_BYTES);
+        assert_eq!(applied.max_execution_time_ms(), 1_000);
+        assert_eq!(applied.max_fuel(), Some(10_000_000));
+        assert!(!applied.debug_mode());
+        assert_eq!(
+            prod.check_install(InstallProfile::Prod, false),
+            Err(ProfileError::SignatureRequired(InstallProfile::Prod))
+        );
+        assert!(prod.check_install(InstallProfile::Prod, true).is_ok());
+    }
+
+    #[test]
+    fn test_from_toml_overrides_builtins() {
+        let src = r#"
+[host]
+name = "edge-1"
+
+[profiles.dev]
+audit_logging = true
+
+[profiles.prod]
+unlimited_fuel = true
+max_memory_bytes = 1024
+"#;
+        let profiles = InstallProfiles::from_toml(src).unwrap();
+
+        assert!(profiles.get(InstallProfile::Dev).audit_logging);
+        let prod = profiles.get(InstallProfile::Prod);
+        assert_eq!(prod.max_fuel, None);
+        assert_eq!(prod.max_memory_bytes, 1024);
+        assert!(prod.require_signatures);
+    }
+
+    #[test]
+    fn test_from_toml_rejects_unknown_profile_and_keys() {
+        assert_eq!(
+            InstallProfiles::from_toml("[profiles.qa]\n"),
+            Err(ProfileError::UnknownProfile("qa".to_string()))
+        );
+        assert!(matches!(
+            InstallProfiles::from_toml("[profiles.dev]\nfuel = 1\n"),
+            Err(ProfileError::InvalidDefinition(_))
+        ));
+    }
+}
@@ -0,0 +1,439 @@
+//! Pool scaling policies: how many warm instances to keep, and when.
+//!
+//! A component's `[scaling]` table sizes its warm instance pool by time of
+//! day and by queue depth:
+//!
+//! ```toml
+//! [scaling]
+//! default_size = 2
+//! max_size = 16
+//! utc_offset_minutes = 60
+//!
+//! [[scaling.schedule]]
+//! days = ["mon", "tue", "wed", "thu", "fri"]
+//! start = "09:00"
+//! end = "17:00"
+//! size = 8
+//!
+//! [[scaling.queue]]
+//! above = 100
+//! size = 12
+//! ```
+//!
+//! # Precedence
+//!
+//! 1. The first schedule rule covering the current local time sets the
+//!    base size; without one, `default_size` applies.
+//! 2. Queue rules only scale up: the largest `size` among rules whose
+//!    `above` threshold the queue depth reaches replaces a smaller base.
+//! 3. `max_size`, if set, caps the result.
+//!
+//! Schedules are evaluated at UTC shifted by `utc_offset_minutes`. A rule
+//! whose `end` is not after its `start` runs past midnight; its `days`
+//! refer to the day the current time falls on. An empty `days` list
+//! matches every day.
+
+// Layer 1: Standard library imports
+use std::fmt;
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Timelike, Utc, Weekday};
+use serde::Deserialize;
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+// (none)
+
+// =============================================================================
+// ScalingError
+// =============================================================================
+
+/// Errors raised while reading a `[scaling]` table.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ScalingError {
+    /// The table could not be parsed.
+    #[error("Invalid scaling policy: {0}")]
+    InvalidDefinition(String),
+
+    /// A day name is not `mon`..`sun`.
+    #[error("Invalid day '{0}' in scaling schedule")]
+    InvalidDay(String),
+
+    /// A time is not `HH:MM`.
+    #[error("Invalid time '{0}' in scaling schedule (expected HH:MM)")]
+    InvalidTime(String),
+}
+
+// =============================================================================
+// Rules
+// =============================================================================
+
+/// Pool size for a recurring time window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleRule {
+    /// Days the window applies to (empty = every day).
+    pub days: Vec<Weekday>,
+    /// Local start time (inclusive).
+    pub start: NaiveTime,
+    /// Local end time (exclusive).
+    pub end: NaiveTime,
+    /// Warm instances to keep during the window.
+    pub size: usize,
+}
+
+impl ScheduleRule {
+    /// Creates a rule for every day.
+    pub fn new(start: NaiveTime, end: NaiveTime, size: usize) -> Self {
+        Self {
+            days: Vec::new(),
+            start,
+            end,
+            size,
+        }
+    }
+
+    /// Restricts the rule to `days`.
+    pub fn with_days(mut self, days: impl IntoIterator<Item = Weekday>) -> Self {
+        self.days = days.into_iter().collect();
+        self
+    }
+
+    /// Whether the rule covers local day `day` at local time `time`.
+    pub fn covers(&self, day: Weekday, time: NaiveTime) -> bool {
+        let in_window = if self.start < self.end {
+            time >= self.start && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        };
+        in_window && (self.days.is_empty() || self.days.contains(&day))
+    }
+}
+
+/// Minimum pool size once the queue is at least `above` deep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueDepthRule {
+    /// Queue depth threshold (inclusive).
+    pub above: usize,
+    /// Warm instances to keep at or above the threshold.
+    pub size: usize,
+}
+
+/// What decided a pool size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalingReason {
+    /// No rule matched; `default_size` applies.
+    Default,
+    /// The schedule rule at this index matched.
+    Schedule(usize),
+    /// The queue rule with this threshold scaled the pool up.
+    QueueDepth {
+        /// Observed queue depth.
+        depth: usize,
+        /// Threshold of the deciding rule.
+        above: usize,
+    },
+}
+
+impl fmt::Display for ScalingReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScalingReason::Default => write!(f, "default size"),
+            ScalingReason::Schedule(index) => write!(f, "schedule rule #{}", index),
+            ScalingReason::QueueDepth { depth, above } => {
+                write!(f, "queue depth {} >= {}", depth, above)
+            }
+        }
+    }
+}
+
+// =============================================================================
+// ScalingPolicy
+// =============================================================================
+
+/// Warm pool sizing rules of one component.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use airssys_wasm::core::config::scaling::{ScalingPolicy, ScalingReason};
+///
+/// let policy = ScalingPolicy::from_manifest(r#"
+/// [scaling]
+/// default_size = 2
+///
+/// [[scaling.schedule]]
+/// days = ["mon", "tue", "wed", "thu", "fri"]
+/// start = "09:00"
+/// end = "17:00"
+/// size = 8
+///
+/// [[scaling.queue]]
+/// above = 100
+/// size = 12
+/// "#).unwrap().unwrap();
+///
+/// // Wednesday 10:30 UTC: business hours
+/// let wednesday = Utc.with_ymd_and_hms(2025, 1, 8, 10, 30, 0).unwrap();
+/// assert_eq!(policy.desired_size(wednesday, 0), (8, ScalingReason::Schedule(0)));
+///
+/// // Saturday: default, unless the queue backs up
+/// let saturday = Utc.with_ymd_and_hms(2025, 1, 11, 10, 30, 0).unwrap();
+/// assert_eq!(policy.desired_size(saturday, 0).0, 2);
+/// assert_eq!(policy.desired_size(saturday, 150).0, 12);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScalingPolicy {
+    default_size: usize,
+    max_size: Option<usize>,
+    utc_offset_minutes: i32,
+    schedule: Vec<ScheduleRule>,
+    queue: Vec<QueueDepthRule>,
+}
+
+#[derive(Deserialize)]
+struct ManifestFile {
+    scaling: Option<ScalingSection>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScalingSection {
+    #[serde(default)]
+    default_size: usize,
+    max_size: Option<usize>,
+    #[serde(default)]
+    utc_offset_minutes: i32,
+    #[serde(default)]
+    schedule: Vec<ScheduleSection>,
+    #[serde(default)]
+    queue: Vec<QueueSection>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScheduleSection {
+    #[serde(default)]
+    days: Vec<String>,
+    start: String,
+    end: String,
+    size: usize,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct QueueSection {
+    above: usize,
+    size: usize,
+}
+
+impl ScalingPolicy {
+    /// A policy keeping `default_size` warm instances at all times.
+    pub fn new(default_size: usize) -> Self {
+        Self {
+            default_size,
+            ..Self::default()
+        }
+    }
+
+    /// Caps every computed size at `max_size`.
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Evaluates schedules at UTC shifted by `minutes`.
+    pub fn with_utc_offset_minutes(mut self, minutes: i32) -> Self {
+        self.utc_offset_minutes = minutes;
+        self
+    }
+
+    /// Appends a schedule rule (earlier rules take precedence).
+    pub fn with_schedule(mut self, rule: ScheduleRule) -> Self {
+        self.schedule.push(rule);
+        self
+    }
+
+    /// Appends a queue-depth rule.
+    pub fn with_queue_rule(mut self, rule: QueueDepthRule) -> Self {
+        self.queue.push(rule);
+        self
+    }
+
+    /// Reads the `[scaling]` table of a `Component.toml`.
+    ///
+    /// Returns `Ok(None)` if the manifest has no `[scaling]` table.
+    ///
+    /// # Errors
+    ///
+    /// - `ScalingError::InvalidDefinition` if the TOML is malformed or the
+    ///   table has an unknown key
+    /// - `ScalingError::InvalidDay` / `ScalingError::InvalidTime` for bad
+    ///   schedule entries
+    pub fn from_manifest(source: &str) -> Result<Option<Self>, ScalingError> {
+        let file: ManifestFile =
+            toml::from_str(source).map_err(|e| ScalingError::InvalidDefinition(e.to_string()))?;
+        let Some(section) = file.scaling else {
+            return Ok(None);
+        };
+
+        let mut policy =
+            Self::new(section.default_size).with_utc_offset_minutes(section.utc_offset_minutes);
+        policy.max_size = section.max_size;
+        for entry in section.schedule {
+            let days = entry
+                .days
+                .iter()
+                .map(|day| {
+                    day.parse::<Weekday>()
+                        .map_err(|_| ScalingError::InvalidDay(day.clone()))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let rule = ScheduleRule::new(
+                parse_time(&entry.start)?,
+                parse_time(&entry.end)?,
+                entry.size,
+            )
+            .with_days(days);
+            policy = policy.with_schedule(rule);
+        }
+        for entry in section.queue {
+            policy = policy.with_queue_rule(QueueDepthRule {
+                above: entry.above,
+                size: entry.size,
+            });
+        }
+        Ok(Some(policy))
+    }
+
+    /// Pool size at `now` with `queue_depth` pending messages, and what
+    /// decided it.
+    pub fn desired_size(&self, now: DateTime<Utc>, queue_depth: usize) -> (usize, ScalingReason) {
+        let local = now.naive_utc() + Duration::minutes(i64::from(self.utc_offset_minutes));
+        let (day, time) = (local.weekday(), local.time());
+        let time = time.with_nanosecond(0).unwrap_or(time);
+
+        let (mut size, mut reason) = self
+            .schedule
+            .iter()
+            .position(|rule| rule.covers(day, time))
+            .map_or((self.default_size, ScalingReason::Default), |index| {
+                (self.schedule[index].size, ScalingReason::Schedule(index))
+            });
+
+        let pressure = self
+            .queue
+            .iter()
+            .filter(|rule| queue_depth >= rule.above)
+            .max_by_key(|rule| rule.size);
+        if let Some(rule) = pressure.filter(|rule| rule.size > size) {
+            size = rule.size;
+            reason = ScalingReason::QueueDepth {
+                depth: queue_depth,
+                above: rule.above,
+            };
+        }
+
+        (self.max_size.map_or(size, |max| size.min(max)), reason)
+    }
+
+    /// Size when no rule matches.
+    pub fn default_size(&self) -> usize {
+        self.default_size
+    }
+
+    /// Upper bound on any computed size.
+    pub fn max_size(&self) -> Option<usize> {
+        self.max_size
+    }
+
+    /// Schedule rules in precedence order.
+    pub fn schedule(&self) -> &[ScheduleRule] {
+        &self.schedule
+    }
+
+    /// Queue-depth rules.
+    pub fn queue_rules(&self) -> &[QueueDepthRule] {
+        &self.queue
+    }
+}
+
+fn parse_time(value: &str) -> Result<NaiveTime, ScalingError> {
+    NaiveTime::parse_from_str(value, "%H:%M")
+        .map_err(|_| ScalingError::InvalidTime(value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_overnight_window_and_utc_offset() {
+        let policy = ScalingPolicy::new(1)
+            .with_utc_offset_minutes(-300)
+            .with_schedule(ScheduleRule::new(at(22, 0), at(6, 0), 4));
+
+        // 03:00 UTC is 22:00 at UTC-5
+        let evening = Utc.with_ymd_and_hms(2025, 1, 8, 3, 0, 0).unwrap();
+        assert_eq!(
+            policy.desired_size(evening, 0),
+            (4, ScalingReason::Schedule(0))
+        );
+        // 12:00 UTC is 07:00 at UTC-5
+        let morning = Utc.with_ymd_and_hms(2025, 1, 8, 12, 0, 0).unwrap();
+        assert_eq!(policy.desired_size(morning, 0), (1, ScalingReason::Default));
+    }
+
+    #[test]
+    fn test_queue_rules_only_scale_up_and_max_caps() {
+        let policy = ScalingPolicy::new(6)
+            .with_max_size(10)
+            .with_queue_rule(QueueDepthRule { above: 10, size: 4 })
+            .with_queue_rule(QueueDepthRule {
+                above: 50,
+                size: 20,
+            });
+        let now = Utc::now();
+
+        assert_eq!(policy.desired_size(now, 20), (6, ScalingReason::Default));
+        assert_eq!(
+            policy.desired_size(now, 50),
+            (
+                10,
+                ScalingReason::QueueDepth {
+                    depth: 50,
+                    above: 50
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_from_manifest_errors() {
+        assert_eq!(
+            ScalingPolicy::from_manifest("[component]\nname = \"x\"\n"),
+            Ok(None)
+        );
+        assert!(matches!(
+            ScalingPolicy::from_manifest("[scaling]\nsize = 2\n"),
+            Err(ScalingError::InvalidDefinition(_))
+        ));
+        assert_eq!(
+            ScalingPolicy::from_manifest(
+                "[[scaling.schedule]]\ndays = [\"someday\"]\nstart = \"09:00\"\nend = \"17:00\"\nsize = 1\n"
+            ),
+            Err(ScalingError::InvalidDay("someday".to_string()))
+        );
+        assert_eq!(
+            ScalingPolicy::from_manifest(
+                "[[scaling.schedule]]\nstart = \"9am\"\nend = \"17:00\"\nsize = 1\n"
+            ),
+            Err(ScalingError::InvalidTime("9am".to_string()))
+        );
+    }
+}
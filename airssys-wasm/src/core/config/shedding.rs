@@ -0,0 +1,210 @@
+//! Deadline-aware load shedding policies.
+//!
+//! A component opts into shedding with a `[shedding]` table in its
+//! manifest:
+//!
+//! ```toml
+//! [shedding]
+//! margin_ms = 5
+//! initial_estimate_ms = 20
+//! ```
+//!
+//! While its queue is backed up, a message carrying a deadline
+//! (`MessageMetadata::deadline_ms`) is moved to the dead-letter queue once
+//! the host expects it to finish after that deadline. The expected finish
+//! is `now + (queued ahead + 1) * service time + margin`, where the service
+//! time is measured per component and starts at `initial_estimate_ms`.
+//!
+//! Messages without a deadline are never shed, and components without a
+//! `[shedding]` table are never shed from.
+
+// Layer 1: Standard library imports
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use serde::Deserialize;
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+
+/// Service time assumed before a component handled its first message.
+pub const DEFAULT_INITIAL_ESTIMATE: Duration = Duration::from_millis(10);
+
+/// Errors raised while reading a `[shedding]` table.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ShedPolicyError {
+    /// The table could not be parsed.
+    #[error("Invalid shedding policy: {0}")]
+    InvalidDefinition(String),
+}
+
+/// When a component's queued messages are shed.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use airssys_wasm::core::config::shedding::ShedPolicy;
+///
+/// let policy = ShedPolicy::new()
+///     .with_margin(Duration::from_millis(5))
+///     .with_initial_estimate(Duration::from_millis(20));
+///
+/// // 3 queued ahead: expected to finish at 1_000 + 4 * 20 + 5 = 1_085
+/// assert!(!policy.would_miss(1_100, 1_000, 3, Duration::from_millis(20)));
+/// assert!(policy.would_miss(1_080, 1_000, 3, Duration::from_millis(20)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShedPolicy {
+    margin: Duration,
+    initial_estimate: Duration,
+    dead_letter: Option<ComponentId>,
+}
+
+#[derive(Deserialize)]
+struct ManifestFile {
+    shedding: Option<ShedSection>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ShedSection {
+    #[serde(default)]
+    margin_ms: u64,
+    initial_estimate_ms: Option<u64>,
+}
+
+impl ShedPolicy {
+    /// A policy with no margin and the default initial estimate.
+    pub fn new() -> Self {
+        Self {
+            margin: Duration::ZERO,
+            initial_estimate: DEFAULT_INITIAL_ESTIMATE,
+            dead_letter: None,
+        }
+    }
+
+    /// Requires messages to be expected to finish `margin` before their
+    /// deadline.
+    pub fn with_margin(mut self, margin: Duration) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Assumes `estimate` per message until the component's service time
+    /// has been measured.
+    pub fn with_initial_estimate(mut self, estimate: Duration) -> Self {
+        self.initial_estimate = estimate;
+        self
+    }
+
+    /// Sets the component that should receive shed messages.
+    pub fn with_dead_letter(mut self, target: ComponentId) -> Self {
+        self.dead_letter = Some(target);
+        self
+    }
+
+    /// Reads the `[shedding]` table of a `Component.toml`.
+    ///
+    /// Returns `Ok(None)` if the manifest has no `[shedding]` table.
+    ///
+    /// # Errors
+    ///
+    /// `ShedPolicyError::InvalidDefinition` if the TOML is malformed or the
+    /// table has an unknown key.
+    pub fn from_manifest(source: &str) -> Result<Option<Self>, ShedPolicyError> {
+        let file: ManifestFile = toml::from_str(source)
+            .map_err(|e| ShedPolicyError::InvalidDefinition(e.to_string()))?;
+        let Some(section) = file.shedding else {
+            return Ok(None);
+        };
+
+        let mut policy = Self::new().with_margin(Duration::from_millis(section.margin_ms));
+        if let Some(estimate) = section.initial_estimate_ms {
+            policy = policy.with_initial_estimate(Duration::from_millis(estimate));
+        }
+        Ok(Some(policy))
+    }
+
+    /// Returns the required slack before the deadline.
+    pub fn margin(&self) -> Duration {
+        self.margin
+    }
+
+    /// Returns the service time assumed before any was measured.
+    pub fn initial_estimate(&self) -> Duration {
+        self.initial_estimate
+    }
+
+    /// Returns the dead-letter target, if any.
+    pub fn dead_letter(&self) -> Option<&ComponentId> {
+        self.dead_letter.as_ref()
+    }
+
+    /// Whether a message due at `deadline_ms`, with `ahead` messages queued
+    /// before it, is expected to finish too late.
+    ///
+    /// Times are milliseconds since Unix epoch; `service` is the expected
+    /// time to handle one message.
+    pub fn would_miss(
+        &self,
+        deadline_ms: u64,
+        now_ms: u64,
+        ahead: usize,
+        service: Duration,
+    ) -> bool {
+        let queued = u32::try_from(ahead.saturating_add(1)).unwrap_or(u32::MAX);
+        let wait = service.saturating_mul(queued).saturating_add(self.margin);
+        let wait_ms = u64::try_from(wait.as_millis()).unwrap_or(u64::MAX);
+        now_ms.saturating_add(wait_ms) > deadline_ms
+    }
+}
+
+impl Default for ShedPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_would_miss_accounts_for_queue_and_margin() {
+        let service = Duration::from_millis(10);
+        let policy = ShedPolicy::new();
+        assert!(!policy.would_miss(1_010, 1_000, 0, service));
+        assert!(policy.would_miss(1_010, 1_000, 1, service));
+
+        let policy = policy.with_margin(Duration::from_millis(5));
+        assert!(policy.would_miss(1_010, 1_000, 0, service));
+        assert!(policy.would_miss(999, 1_000, 0, Duration::ZERO));
+    }
+
+    #[test]
+    fn test_from_manifest() {
+        assert_eq!(
+            ShedPolicy::from_manifest("[component]\nname = \"x\"\n"),
+            Ok(None)
+        );
+
+        let policy = ShedPolicy::from_manifest("[shedding]\nmargin_ms = 5\n")
+            .unwrap()
+            .unwrap();
+        assert_eq!(policy.margin(), Duration::from_millis(5));
+        assert_eq!(policy.initial_estimate(), DEFAULT_INITIAL_ESTIMATE);
+
+        let policy = ShedPolicy::from_manifest("[shedding]\ninitial_estimate_ms = 50\n")
+            .unwrap()
+            .unwrap();
+        assert_eq!(policy.initial_estimate(), Duration::from_millis(50));
+
+        assert!(matches!(
+            ShedPolicy::from_manifest("[shedding]\nmargin = 5\n"),
+            Err(ShedPolicyError::InvalidDefinition(_))
+        ));
+    }
+}
@@ -0,0 +1,244 @@
+//! Tunable `[config]` values pushed to running components.
+//!
+//! A component declares its tuning parameters (thresholds, batch sizes,
+//! feature toggles) in the `[config]` section of `Component.toml`. The host
+//! can push updated values to a running component via the `reconfigure`
+//! export. Updates are validated on the host first: only declared keys can
+//! be changed, and each value must keep its declared type.
+
+// Layer 1: Standard library imports
+use std::collections::BTreeMap;
+use std::fmt;
+
+// Layer 2: Third-party crate imports
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+// (none needed for this module)
+
+/// Error raised when a config update is rejected.
+#[derive(Debug, Clone, Error, PartialEq)]
+pub enum ConfigUpdateError {
+    /// The update is empty.
+    #[error("Config update contains no values")]
+    Empty,
+
+    /// The key is not declared in the component's `[config]` section.
+    #[error("Unknown config key: {0}")]
+    UnknownKey(String),
+
+    /// The value's type differs from the declared type.
+    #[error("Config key '{key}' expects {expected}, got {found}")]
+    TypeMismatch {
+        /// Offending key.
+        key: String,
+        /// Declared type.
+        expected: &'static str,
+        /// Type of the pushed value.
+        found: &'static str,
+    },
+
+    /// The component rejected the update.
+    #[error("Component rejected config update: {0}")]
+    Rejected(String),
+
+    /// The component did not answer in time.
+    #[error("Component did not acknowledge config update in time")]
+    Timeout,
+}
+
+/// A scalar `[config]` value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConfigValue {
+    /// String value.
+    Text(String),
+    /// Integer value.
+    Integer(i64),
+    /// Floating-point value.
+    Float(f64),
+    /// Boolean value.
+    Boolean(bool),
+}
+
+impl ConfigValue {
+    /// Returns the type name used in validation errors.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            ConfigValue::Text(_) => "a string",
+            ConfigValue::Integer(_) => "an integer",
+            ConfigValue::Float(_) => "a float",
+            ConfigValue::Boolean(_) => "a boolean",
+        }
+    }
+}
+
+impl fmt::Display for ConfigValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigValue::Text(s) => write!(f, "{}", s),
+            ConfigValue::Integer(n) => write!(f, "{}", n),
+            ConfigValue::Float(n) => write!(f, "{}", n),
+            ConfigValue::Boolean(b) => write!(f, "{}", b),
+        }
+    }
+}
+
+/// A set of `[config]` values keyed by name.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::config::values::{ConfigValue, ConfigValues};
+///
+/// let declared = ConfigValues::new()
+///     .with("threshold", ConfigValue::Integer(10))
+///     .with("mode", ConfigValue::Text("fast".to_string()));
+///
+/// let update = ConfigValues::new().with("threshold", ConfigValue::Integer(20));
+/// assert!(declared.validate_update(&update).is_ok());
+///
+/// let merged = declared.merged(&update);
+/// assert_eq!(merged.get("threshold"), Some(&ConfigValue::Integer(20)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConfigValues {
+    values: BTreeMap<String, ConfigValue>,
+}
+
+impl ConfigValues {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds or replaces a value.
+    pub fn with(mut self, key: impl Into<String>, value: ConfigValue) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    /// Adds or replaces a value in place.
+    pub fn insert(&mut self, key: impl Into<String>, value: ConfigValue) {
+        self.values.insert(key.into(), value);
+    }
+
+    /// Returns the value for a key.
+    pub fn get(&self, key: &str) -> Option<&ConfigValue> {
+        self.values.get(key)
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Iterates over values in key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &ConfigValue)> {
+        self.values.iter()
+    }
+
+    /// Checks an update against these (declared) values.
+    ///
+    /// Every key in `update` must already exist here with the same value
+    /// type. Integers are accepted for float keys.
+    ///
+    /// # Errors
+    ///
+    /// - `ConfigUpdateError::Empty` if `update` has no values
+    /// - `ConfigUpdateError::UnknownKey` for an undeclared key
+    /// - `ConfigUpdateError::TypeMismatch` for a value of the wrong type
+    pub fn validate_update(&self, update: &ConfigValues) -> Result<(), ConfigUpdateError> {
+        if update.is_empty() {
+            return Err(ConfigUpdateError::Empty);
+        }
+
+        for (key, value) in update.iter() {
+            let declared = self
+                .get(key)
+                .ok_or_else(|| ConfigUpdateError::UnknownKey(key.clone()))?;
+            let compatible = matches!(
+                (declared, value),
+                (ConfigValue::Text(_), ConfigValue::Text(_))
+                    | (ConfigValue::Integer(_), ConfigValue::Integer(_))
+                    | (ConfigValue::Float(_), ConfigValue::Float(_))
+                    | (ConfigValue::Float(_), ConfigValue::Integer(_))
+                    | (ConfigValue::Boolean(_), ConfigValue::Boolean(_))
+            );
+            if !compatible {
+                return Err(ConfigUpdateError::TypeMismatch {
+                    key: key.clone(),
+                    expected: declared.type_name(),
+                    found: value.type_name(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns these values with `update` applied on top.
+    pub fn merged(&self, update: &ConfigValues) -> ConfigValues {
+        let mut merged = self.clone();
+        for (key, value) in update.iter() {
+            merged.insert(key.clone(), value.clone());
+        }
+        merged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn declared() -> ConfigValues {
+        ConfigValues::new()
+            .with("threshold", ConfigValue::Integer(10))
+            .with("ratio", ConfigValue::Float(0.5))
+            .with("enabled", ConfigValue::Boolean(true))
+    }
+
+    #[test]
+    fn test_validate_update_accepts_declared_keys() {
+        let update = ConfigValues::new()
+            .with("threshold", ConfigValue::Integer(42))
+            .with("ratio", ConfigValue::Integer(1));
+        assert!(declared().validate_update(&update).is_ok());
+    }
+
+    #[test]
+    fn test_validate_update_rejects_unknown_and_mismatched() {
+        let unknown = ConfigValues::new().with("other", ConfigValue::Integer(1));
+        assert_eq!(
+            declared().validate_update(&unknown),
+            Err(ConfigUpdateError::UnknownKey("other".to_string()))
+        );
+
+        let mismatched = ConfigValues::new().with("enabled", ConfigValue::Text("yes".into()));
+        assert!(matches!(
+            declared().validate_update(&mismatched),
+            Err(ConfigUpdateError::TypeMismatch {
+                expected: "a boolean",
+                found: "a string",
+                ..
+            })
+        ));
+
+        assert_eq!(
+            declared().validate_update(&ConfigValues::new()),
+            Err(ConfigUpdateError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_merged_overrides_values() {
+        let update = ConfigValues::new().with("threshold", ConfigValue::Integer(7));
+        let merged = declared().merged(&update);
+        assert_eq!(merged.get("threshold"), Some(&ConfigValue::Integer(7)));
+        assert_eq!(merged.len(), 3);
+    }
+}
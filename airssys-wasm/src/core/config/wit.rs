@@ -0,0 +1,400 @@
+//! WIT world generation and linting from `Component.toml`.
+//!
+//! A guest may only import the host interfaces its manifest grants, so the
+//! world a guest is built against should follow from its capabilities:
+//!
+//! | Host interface    | Required grant                                          |
+//! |-------------------|---------------------------------------------------------|
+//! | `host-services`   | none (always available)                                 |
+//! | `host-metrics`    | none (always available)                                 |
+//! | `host-messaging`  | `[capabilities.messaging]`                              |
+//! | `host-os`         | `[capabilities.filesystem]` or `[capabilities.network]` |
+//! | `storage`         | `[capabilities.storage]`                                |
+//! | `host-storage`    | `[capabilities.storage]`                                |
+//!
+//! [`generate_world`] emits the expected world skeleton; [`lint_world`]
+//! checks an existing world file against the manifest and reports, with
+//! positions in the WIT source:
+//!
+//! - imports of host interfaces the manifest does not grant (error)
+//! - imports of unknown `airssys:core` interfaces (error)
+//! - a missing `component-lifecycle` export (error)
+//! - grants none of whose interfaces is imported (warning)
+//! - imports from packages the host does not provide (warning)
+//!
+//! A `wit` command only needs to read both files, call these functions and
+//! print the output or diagnostics.
+
+// Layer 1: Standard library imports
+use std::fmt::Write as _;
+
+// Layer 2: Third-party crate imports
+// (none)
+
+// Layer 3: Internal module imports
+use super::manifest::{validate_manifest, Diagnostic, ManifestKind, Severity};
+
+/// WIT package that defines the host interfaces.
+pub const HOST_PACKAGE: &str = "airssys:core";
+
+/// Version of [`HOST_PACKAGE`] guests are generated against.
+pub const HOST_PACKAGE_VERSION: &str = "1.0.0";
+
+/// Host interfaces and the capability tables that grant them (any one
+/// suffices; none means always available).
+const HOST_IMPORTS: &[(&str, &[&str])] = &[
+    ("host-services", &[]),
+    ("host-metrics", &[]),
+    ("host-messaging", &["messaging"]),
+    ("host-os", &["filesystem", "network"]),
+    ("storage", &["storage"]),
+    ("host-storage", &["storage"]),
+];
+
+/// Interfaces every guest must export.
+const GUEST_EXPORTS: &[&str] = &["component-lifecycle"];
+
+/// Returns the host interfaces a manifest allows the guest to import.
+///
+/// # Errors
+///
+/// Returns the manifest's error diagnostics if it does not validate.
+pub fn allowed_imports(manifest: &str) -> Result<Vec<&'static str>, Vec<Diagnostic>> {
+    let errors: Vec<Diagnostic> = validate_manifest(ManifestKind::Component, manifest)
+        .into_iter()
+        .filter(Diagnostic::is_error)
+        .collect();
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    // Validated above, so parsing cannot fail
+    let root: toml::Table = manifest.parse().unwrap_or_default();
+    let capabilities = root.get("capabilities").and_then(|v| v.as_table());
+
+    Ok(HOST_IMPORTS
+        .iter()
+        .filter(|(_, grants)| {
+            grants.is_empty()
+                || grants
+                    .iter()
+                    .any(|grant| capabilities.is_some_and(|c| c.contains_key(*grant)))
+        })
+        .map(|(interface, _)| *interface)
+        .collect())
+}
+
+/// Generates the WIT world a guest with this manifest should implement.
+///
+/// # Errors
+///
+/// Returns the manifest's error diagnostics if it does not validate.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::config::wit::generate_world;
+///
+/// let world = generate_world(
+///     "[component]\nname = \"echo\"\n[capabilities.messaging]\ncan_send_to = [\"*\"]\n",
+/// )
+/// .unwrap();
+///
+/// assert!(world.contains("world echo {"));
+/// assert!(world.contains("import airssys:core/host-messaging@1.0.0;"));
+/// assert!(!world.contains("storage"));
+/// ```
+pub fn generate_world(manifest: &str) -> Result<String, Vec<Diagnostic>> {
+    let imports = allowed_imports(manifest)?;
+    let root: toml::Table = manifest.parse().unwrap_or_default();
+    let name = root
+        .get("component")
+        .and_then(|c| c.get("name"))
+        .and_then(|n| n.as_str())
+        .map(wit_identifier)
+        .unwrap_or_else(|| "component".to_string());
+
+    let mut out = String::new();
+    let _ = writeln!(out, "// Generated from Component.toml. Only interfaces");
+    let _ = writeln!(
+        out,
+        "// granted by the manifest's capabilities are imported."
+    );
+    let _ = writeln!(out, "package local:{name};");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "world {name} {{");
+    for interface in imports {
+        let _ = writeln!(out, "    import {};", qualified(interface));
+    }
+    for interface in GUEST_EXPORTS {
+        let _ = writeln!(out, "    export {};", qualified(interface));
+    }
+    out.push_str("}\n");
+    Ok(out)
+}
+
+/// Lints a WIT world against the manifest.
+///
+/// Diagnostics carry positions in `world`; the path is `import.<name>` or
+/// `export.<name>`. Returns an empty list if the world matches.
+///
+/// # Errors
+///
+/// Returns the manifest's error diagnostics if it does not validate.
+pub fn lint_world(manifest: &str, world: &str) -> Result<Vec<Diagnostic>, Vec<Diagnostic>> {
+    let allowed = allowed_imports(manifest)?;
+    let items = world_items(world);
+    let mut diagnostics = Vec::new();
+    let mut push = |severity, (line, column), path: String, message: String| {
+        diagnostics.push(Diagnostic {
+            severity,
+            line,
+            column,
+            path,
+            message,
+        });
+    };
+
+    let Some(world_at) = items.world else {
+        push(
+            Severity::Error,
+            (1, 1),
+            String::new(),
+            "no `world` declaration found".to_string(),
+        );
+        return Ok(diagnostics);
+    };
+
+    for item in items.entries.iter().filter(|i| i.kind == "import") {
+        let path = format!("import.{}", item.name);
+        match host_interface(&item.name) {
+            Some(interface) if allowed.contains(&interface) => {}
+            Some(interface) => {
+                let grants = HOST_IMPORTS
+                    .iter()
+                    .find(|(name, _)| *name == interface)
+                    .map(|(_, grants)| grants_text(grants))
+                    .unwrap_or_default();
+                push(
+                    Severity::Error,
+                    item.position,
+                    path,
+                    format!("`{interface}` is imported but Component.toml has no {grants}"),
+                );
+            }
+            None if is_host_package(&item.name) => push(
+                Severity::Error,
+                item.position,
+                path,
+                format!("`{}` is not a host interface", item.name),
+            ),
+            None => push(
+                Severity::Warning,
+                item.position,
+                path,
+                format!("`{}` is not provided by the airssys host", item.name),
+            ),
+        }
+    }
+
+    for interface in GUEST_EXPORTS {
+        let exported = items
+            .entries
+            .iter()
+            .any(|i| i.kind == "export" && host_interface(&i.name) == Some(interface));
+        if !exported {
+            push(
+                Severity::Error,
+                world_at,
+                format!("export.{interface}"),
+                format!("world must export `{interface}`"),
+            );
+        }
+    }
+
+    let is_imported = |interface: &str| {
+        items
+            .entries
+            .iter()
+            .any(|i| i.kind == "import" && host_interface(&i.name) == Some(interface))
+    };
+    let mut reported: Vec<&[&str]> = Vec::new();
+    for (interface, grants) in HOST_IMPORTS {
+        if grants.is_empty() || reported.contains(grants) {
+            continue;
+        }
+        // Interfaces sharing a grant are alternatives; importing one suffices
+        let imported = HOST_IMPORTS
+            .iter()
+            .filter(|(_, other)| other == grants)
+            .any(|(other, _)| is_imported(other));
+        if allowed.contains(interface) && !imported {
+            reported.push(grants);
+            push(
+                Severity::Warning,
+                world_at,
+                format!("import.{interface}"),
+                format!(
+                    "{} is granted but `{interface}` is never imported",
+                    grants_text(grants)
+                ),
+            );
+        }
+    }
+
+    diagnostics.sort_by_key(|d| (d.line, d.column));
+    Ok(diagnostics)
+}
+
+/// An `import` or `export` statement of a world.
+struct WorldEntry {
+    kind: &'static str,
+    name: String,
+    position: (usize, usize),
+}
+
+/// Statements of the first world in a WIT file.
+struct WorldItems {
+    world: Option<(usize, usize)>,
+    entries: Vec<WorldEntry>,
+}
+
+/// Line-based scan of the first `world` block.
+///
+/// Covers the statement forms generated above and used in `wit/core`; it is
+/// not a full WIT parser.
+fn world_items(source: &str) -> WorldItems {
+    let mut items = WorldItems {
+        world: None,
+        entries: Vec::new(),
+    };
+    let mut depth = 0usize;
+
+    for (index, raw) in source.lines().enumerate() {
+        let line = raw.split("//").next().unwrap_or_default();
+        let trimmed = line.trim_start();
+        let column = line.chars().count() - trimmed.chars().count() + 1;
+
+        if items.world.is_none() {
+            if trimmed.starts_with("world ") && trimmed.contains('{') {
+                items.world = Some((index + 1, column));
+                depth = 1;
+            }
+            continue;
+        }
+        if depth == 0 {
+            break;
+        }
+
+        if depth == 1 {
+            for kind in ["import", "export"] {
+                if let Some(rest) = trimmed.strip_prefix(kind).and_then(|r| r.strip_prefix(' ')) {
+                    let name = rest.trim().trim_end_matches(';').trim();
+                    items.entries.push(WorldEntry {
+                        kind,
+                        name: name.to_string(),
+                        position: (index + 1, column),
+                    });
+                }
+            }
+        }
+        depth += line.matches('{').count();
+        depth = depth.saturating_sub(line.matches('}').count());
+    }
+    items
+}
+
+/// Maps `storage`, `airssys:core/storage` or `airssys:core/storage@1.0.0`
+/// to the known host or guest interface name.
+fn host_interface(name: &str) -> Option<&'static str> {
+    let local = match name.split_once('/') {
+        Some((package, rest)) if package == HOST_PACKAGE => rest,
+        Some(_) => return None,
+        None if name.contains(':') => return None,
+        None => name,
+    };
+    let local = local.split('@').next().unwrap_or(local);
+    HOST_IMPORTS
+        .iter()
+        .map(|(interface, _)| *interface)
+        .chain(GUEST_EXPORTS.iter().copied())
+        .find(|interface| *interface == local)
+}
+
+fn is_host_package(name: &str) -> bool {
+    !name.contains(':') || name.starts_with(&format!("{HOST_PACKAGE}/"))
+}
+
+/// `[capabilities.a]` or `[capabilities.a] or [capabilities.b]`.
+fn grants_text(grants: &[&str]) -> String {
+    grants
+        .iter()
+        .map(|grant| format!("[capabilities.{grant}]"))
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
+fn qualified(interface: &str) -> String {
+    format!("{HOST_PACKAGE}/{interface}@{HOST_PACKAGE_VERSION}")
+}
+
+/// Lower-kebab-case identifier usable as a WIT package and world name.
+fn wit_identifier(name: &str) -> String {
+    let words: Vec<String> = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect();
+    match words.first() {
+        Some(first) if first.starts_with(|c: char| c.is_ascii_alphabetic()) => words.join("-"),
+        Some(_) => format!("c-{}", words.join("-")),
+        None => "component".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"
+[component]
+name = "Order Service"
+
+[capabilities.storage]
+can_read_keys = ["orders/*"]
+"#;
+
+    #[test]
+    fn test_generated_world_lints_clean() {
+        let world = generate_world(MANIFEST).unwrap();
+        assert!(world.contains("package local:order-service;"));
+        assert!(world.contains("import airssys:core/storage@1.0.0;"));
+        assert!(world.contains("import airssys:core/host-storage@1.0.0;"));
+        assert!(!world.contains("host-messaging"));
+        assert!(lint_world(MANIFEST, &world).unwrap().is_empty());
+
+        // The core world in this repository imports everything
+        let core = include_str!("../../../wit/core/world.wit");
+        let full = "[component]\nname = \"a\"\n[capabilities.messaging]\n[capabilities.network]\n[capabilities.storage]\n";
+        assert!(lint_world(full, core).unwrap().is_empty());
+
+        // Either storage interface satisfies the storage grant
+        let scoped = "world x {\n    import host-storage;\n    export component-lifecycle;\n}\n";
+        assert!(lint_world(MANIFEST, scoped).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_lint_reports_mismatches() {
+        let world = "package local:x;\n\nworld x {\n    import host-messaging;\n    import wasi:http/handler;\n    import airssys:core/files;\n}\n";
+        let diagnostics = lint_world(MANIFEST, world).unwrap();
+        let text: Vec<String> = diagnostics.iter().map(|d| d.to_string()).collect();
+
+        assert_eq!(text.len(), 5, "{text:?}");
+        assert!(text[0].starts_with("3:1: error: export.component-lifecycle"));
+        assert!(text[1].starts_with("3:1: warning: import.storage"));
+        assert!(text[2].contains("4:5: error") && text[2].contains("[capabilities.messaging]"));
+        assert!(text[3].contains("5:5: warning") && text[3].contains("wasi:http/handler"));
+        assert!(text[4].contains("6:5: error") && text[4].contains("not a host interface"));
+
+        assert!(lint_world("[component]\n", world).is_err());
+    }
+}
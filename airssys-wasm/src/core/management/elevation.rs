@@ -0,0 +1,368 @@
+//! Runtime capability elevation requests.
+//!
+//! A running component cannot change its own capabilities. Instead it files
+//! an elevation request through the `host-services` interface; an operator
+//! approves or denies it, and an approved request is applied the next time
+//! the component starts. Until then the component keeps running with the
+//! capabilities it was started with.
+//!
+//! Capabilities are described in the same `class:action:pattern` form used
+//! by [`HostEvent::CapabilityGranted`](super::event::HostEvent).
+
+// Layer 1: Standard library imports
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Mutex, MutexGuard};
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+// Layer 3: Internal module imports
+use super::errors::ElevationError;
+use crate::core::component::id::ComponentId;
+
+/// Capability classes accepted in elevation requests.
+pub const CAPABILITY_CLASSES: [&str; 4] = ["messaging", "storage", "filesystem", "network"];
+
+/// Where an elevation request stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ElevationStatus {
+    /// Waiting for an operator decision.
+    Pending,
+    /// Approved; takes effect on the component's next start.
+    Approved,
+    /// Refused by an operator.
+    Denied,
+    /// Granted to a restarted component.
+    Applied,
+}
+
+impl fmt::Display for ElevationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Pending => "pending",
+            Self::Approved => "approved",
+            Self::Denied => "denied",
+            Self::Applied => "applied",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A component's request for an additional capability.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ElevationRequest {
+    /// Request ID, unique within the registry.
+    pub id: u64,
+    /// Requesting component.
+    pub component: ComponentId,
+    /// Requested capability (`class:action:pattern`).
+    pub capability: String,
+    /// Why the component needs it, as stated by the component.
+    pub reason: String,
+    /// When the request was filed.
+    pub requested_at: DateTime<Utc>,
+    /// Current status.
+    pub status: ElevationStatus,
+    /// Operator who approved or denied the request.
+    pub decided_by: Option<String>,
+    /// Operator's note on the decision.
+    pub note: Option<String>,
+}
+
+/// Registry of elevation requests, shared by the host functions that file
+/// them and the control surface that decides them.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::management::elevation::{ElevationRequests, ElevationStatus};
+///
+/// let requests = ElevationRequests::new();
+/// let component = ComponentId::new("acme", "cache", "v1");
+///
+/// let id = requests
+///     .submit(&component, "network:outbound:api.example.com", "sync upstream")
+///     .unwrap();
+/// requests.approve(id, "ops@example.com").unwrap();
+///
+/// // Nothing changes for the running instance; the grant applies on restart
+/// let applied = requests.apply_approved(&component).unwrap();
+/// assert_eq!(applied.len(), 1);
+/// assert_eq!(requests.get(id).unwrap().unwrap().status, ElevationStatus::Applied);
+/// ```
+#[derive(Debug, Default)]
+pub struct ElevationRequests {
+    inner: Mutex<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    next_id: u64,
+    requests: BTreeMap<u64, ElevationRequest>,
+}
+
+impl ElevationRequests {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Files a request from `component` for `capability`.
+    ///
+    /// A pending or approved request for the same capability is returned
+    /// instead of filing a duplicate.
+    ///
+    /// # Errors
+    ///
+    /// - `ElevationError::InvalidCapability` if `capability` is malformed
+    /// - `ElevationError::LockPoisoned` if the internal lock is poisoned
+    pub fn submit(
+        &self,
+        component: &ComponentId,
+        capability: &str,
+        reason: &str,
+    ) -> Result<u64, ElevationError> {
+        validate_capability(capability)?;
+        let mut inner = self.lock()?;
+
+        let existing = inner.requests.values().find(|r| {
+            &r.component == component
+                && r.capability == capability
+                && matches!(
+                    r.status,
+                    ElevationStatus::Pending | ElevationStatus::Approved
+                )
+        });
+        if let Some(existing) = existing {
+            return Ok(existing.id);
+        }
+
+        inner.next_id += 1;
+        let id = inner.next_id;
+        inner.requests.insert(
+            id,
+            ElevationRequest {
+                id,
+                component: component.clone(),
+                capability: capability.to_string(),
+                reason: reason.to_string(),
+                requested_at: Utc::now(),
+                status: ElevationStatus::Pending,
+                decided_by: None,
+                note: None,
+            },
+        );
+        Ok(id)
+    }
+
+    /// Approves a pending request on behalf of `operator`.
+    ///
+    /// # Errors
+    ///
+    /// - `ElevationError::NotFound` if no request has this ID
+    /// - `ElevationError::AlreadyDecided` if the request is not pending
+    pub fn approve(&self, id: u64, operator: &str) -> Result<ElevationRequest, ElevationError> {
+        self.decide(id, operator, None, ElevationStatus::Approved)
+    }
+
+    /// Denies a pending request on behalf of `operator`.
+    ///
+    /// # Errors
+    ///
+    /// - `ElevationError::NotFound` if no request has this ID
+    /// - `ElevationError::AlreadyDecided` if the request is not pending
+    pub fn deny(
+        &self,
+        id: u64,
+        operator: &str,
+        note: &str,
+    ) -> Result<ElevationRequest, ElevationError> {
+        self.decide(id, operator, Some(note), ElevationStatus::Denied)
+    }
+
+    /// Marks every approved request of `component` as applied and returns
+    /// them. Call when the component (re)starts.
+    ///
+    /// # Errors
+    ///
+    /// - `ElevationError::LockPoisoned` if the internal lock is poisoned
+    pub fn apply_approved(
+        &self,
+        component: &ComponentId,
+    ) -> Result<Vec<ElevationRequest>, ElevationError> {
+        let mut inner = self.lock()?;
+        Ok(inner
+            .requests
+            .values_mut()
+            .filter(|r| &r.component == component && r.status == ElevationStatus::Approved)
+            .map(|r| {
+                r.status = ElevationStatus::Applied;
+                r.clone()
+            })
+            .collect())
+    }
+
+    /// Returns one request.
+    ///
+    /// # Errors
+    ///
+    /// - `ElevationError::LockPoisoned` if the internal lock is poisoned
+    pub fn get(&self, id: u64) -> Result<Option<ElevationRequest>, ElevationError> {
+        Ok(self.lock()?.requests.get(&id).cloned())
+    }
+
+    /// Returns all requests, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// - `ElevationError::LockPoisoned` if the internal lock is poisoned
+    pub fn list(&self) -> Result<Vec<ElevationRequest>, ElevationError> {
+        Ok(self.lock()?.requests.values().cloned().collect())
+    }
+
+    /// Returns requests waiting for an operator decision, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// - `ElevationError::LockPoisoned` if the internal lock is poisoned
+    pub fn pending(&self) -> Result<Vec<ElevationRequest>, ElevationError> {
+        Ok(self
+            .lock()?
+            .requests
+            .values()
+            .filter(|r| r.status == ElevationStatus::Pending)
+            .cloned()
+            .collect())
+    }
+
+    fn decide(
+        &self,
+        id: u64,
+        operator: &str,
+        note: Option<&str>,
+        status: ElevationStatus,
+    ) -> Result<ElevationRequest, ElevationError> {
+        let mut inner = self.lock()?;
+        let request = inner
+            .requests
+            .get_mut(&id)
+            .ok_or(ElevationError::NotFound(id))?;
+        if request.status != ElevationStatus::Pending {
+            return Err(ElevationError::AlreadyDecided {
+                id,
+                status: request.status.to_string(),
+            });
+        }
+        request.status = status;
+        request.decided_by = Some(operator.to_string());
+        request.note = note.map(str::to_string);
+        Ok(request.clone())
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Inner>, ElevationError> {
+        self.inner
+            .lock()
+            .map_err(|e| ElevationError::LockPoisoned(e.to_string()))
+    }
+}
+
+/// Checks that `capability` has the form `class:action:pattern` with a
+/// known class and non-empty action and pattern.
+///
+/// # Errors
+///
+/// Returns `ElevationError::InvalidCapability` otherwise.
+pub fn validate_capability(capability: &str) -> Result<(), ElevationError> {
+    let invalid = || ElevationError::InvalidCapability(capability.to_string());
+    let mut parts = capability.splitn(3, ':');
+    let class = parts.next().ok_or_else(invalid)?;
+    let action = parts.next().ok_or_else(invalid)?;
+    let pattern = parts.next().ok_or_else(invalid)?;
+    if !CAPABILITY_CLASSES.contains(&class) || action.is_empty() || pattern.is_empty() {
+        return Err(invalid());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component() -> ComponentId {
+        ComponentId::new("acme", "cache", "v1")
+    }
+
+    #[test]
+    fn test_submit_validates_and_deduplicates() {
+        let requests = ElevationRequests::new();
+        assert!(matches!(
+            requests.submit(&component(), "storage:write", "x"),
+            Err(ElevationError::InvalidCapability(_))
+        ));
+        assert!(requests.submit(&component(), "gpu:use:*", "x").is_err());
+
+        let first = requests
+            .submit(&component(), "storage:write:cache/*", "warm cache")
+            .unwrap();
+        let again = requests
+            .submit(&component(), "storage:write:cache/*", "still need it")
+            .unwrap();
+        assert_eq!(first, again);
+        assert_eq!(requests.pending().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_decisions_are_final() {
+        let requests = ElevationRequests::new();
+        let id = requests
+            .submit(&component(), "network:outbound:*.example.com", "sync")
+            .unwrap();
+
+        let denied = requests.deny(id, "ops", "not needed").unwrap();
+        assert_eq!(denied.status, ElevationStatus::Denied);
+        assert_eq!(denied.decided_by.as_deref(), Some("ops"));
+        assert!(matches!(
+            requests.approve(id, "ops"),
+            Err(ElevationError::AlreadyDecided { .. })
+        ));
+        assert!(matches!(
+            requests.approve(99, "ops"),
+            Err(ElevationError::NotFound(99))
+        ));
+        // Denied requests are never applied
+        assert!(requests.apply_approved(&component()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_apply_only_touches_approved_requests_of_component() {
+        let requests = ElevationRequests::new();
+        let other = ComponentId::new("acme", "other", "v1");
+        let mine = requests
+            .submit(&component(), "filesystem:read:/data/*", "read data")
+            .unwrap();
+        let theirs = requests
+            .submit(&other, "filesystem:read:/data/*", "read data")
+            .unwrap();
+        let waiting = requests
+            .submit(&component(), "messaging:send:acme/*", "notify")
+            .unwrap();
+        requests.approve(mine, "ops").unwrap();
+        requests.approve(theirs, "ops").unwrap();
+
+        let applied = requests.apply_approved(&component()).unwrap();
+        assert_eq!(applied.len(), 1);
+        assert_eq!(applied[0].id, mine);
+        assert_eq!(
+            requests.get(theirs).unwrap().unwrap().status,
+            ElevationStatus::Approved
+        );
+        assert_eq!(
+            requests.get(waiting).unwrap().unwrap().status,
+            ElevationStatus::Pending
+        );
+    }
+}
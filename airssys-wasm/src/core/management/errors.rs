@@ -0,0 +1,158 @@
+//! Host event log error types.
+
+// Layer 1: Standard library imports
+// (none needed for this module)
+
+// Layer 2: Third-party crate imports
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+// (none - errors have no internal dependencies)
+
+/// Errors raised by the host event log.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::management::errors::EventLogError;
+///
+/// let err = EventLogError::Tampered(7);
+/// assert!(format!("{}", err).contains("7"));
+/// ```
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum EventLogError {
+    /// A record's hash does not match its contents or its predecessor.
+    #[error("Event log tampered at sequence {0}")]
+    Tampered(u64),
+
+    /// Records are not numbered consecutively from zero.
+    #[error("Event log sequence gap: expected {expected}, found {found}")]
+    SequenceGap {
+        /// Sequence number expected at this position.
+        expected: u64,
+        /// Sequence number found.
+        found: u64,
+    },
+
+    /// An event could not be serialized or an export could not be parsed.
+    #[error("Event log serialization failed: {0}")]
+    Serialization(String),
+
+    /// Internal lock was poisoned.
+    #[error("Event log lock poisoned: {0}")]
+    LockPoisoned(String),
+}
+
+/// Errors raised by the capability elevation workflow.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::management::errors::ElevationError;
+///
+/// let err = ElevationError::NotFound(3);
+/// assert!(format!("{}", err).contains("3"));
+/// ```
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum ElevationError {
+    /// The capability description is not `class:action:pattern`.
+    #[error("Invalid capability '{0}': expected class:action:pattern")]
+    InvalidCapability(String),
+
+    /// No request has this ID.
+    #[error("Elevation request {0} not found")]
+    NotFound(u64),
+
+    /// The request has already been decided or applied.
+    #[error("Elevation request {id} is already {status}")]
+    AlreadyDecided {
+        /// Request ID.
+        id: u64,
+        /// Current status of the request.
+        status: String,
+    },
+
+    /// An approved capability could not be applied to enforcement.
+    #[error("Cannot enforce elevated capability '{capability}': {reason}")]
+    NotEnforced {
+        /// The capability, as `class:action:pattern`.
+        capability: String,
+        /// Why the validator or engine refused it.
+        reason: String,
+    },
+
+    /// Internal lock was poisoned.
+    #[error("Elevation registry lock poisoned: {0}")]
+    LockPoisoned(String),
+}
+
+/// Errors raised by the host lockdown switch.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::management::errors::LockdownError;
+///
+/// let err = LockdownError::AlreadyEngaged("oncall".to_string());
+/// assert!(format!("{}", err).contains("oncall"));
+/// ```
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum LockdownError {
+    /// A lockdown is already in effect.
+    #[error("Lockdown already engaged by {0}")]
+    AlreadyEngaged(String),
+
+    /// No lockdown is in effect.
+    #[error("Lockdown is not engaged")]
+    NotEngaged,
+
+    /// An operator command could not be parsed.
+    #[error("Invalid lockdown command: {0}")]
+    InvalidCommand(String),
+
+    /// Internal lock was poisoned.
+    #[error("Lockdown lock poisoned: {0}")]
+    LockPoisoned(String),
+}
+
+/// Errors raised when components emit metrics.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::management::errors::MetricsError;
+///
+/// let err = MetricsError::InvalidName("9lives".to_string());
+/// assert!(format!("{}", err).contains("9lives"));
+/// ```
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum MetricsError {
+    /// A metric or label name is not `[a-zA-Z_][a-zA-Z0-9_]*`, or uses a
+    /// label reserved by the host.
+    #[error("Invalid metric name '{0}'")]
+    InvalidName(String),
+
+    /// The component already has the maximum number of series.
+    #[error("Component {component} exceeded its limit of {limit} metric series")]
+    CardinalityExceeded {
+        /// Emitting component.
+        component: String,
+        /// Series limit per component.
+        limit: usize,
+    },
+
+    /// The series exists with a different metric kind.
+    #[error("Metric '{name}' is a {existing}, not a {requested}")]
+    KindMismatch {
+        /// Metric name.
+        name: String,
+        /// Kind the series was created with.
+        existing: String,
+        /// Kind of the rejected update.
+        requested: String,
+    },
+
+    /// Internal lock was poisoned.
+    #[error("Metrics registry lock poisoned: {0}")]
+    LockPoisoned(String),
+}
@@ -0,0 +1,177 @@
+//! Host state-change events and their hash-chained log records.
+
+// Layer 1: Standard library imports
+// (none needed for this module)
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+// Layer 3: Internal module imports
+use super::errors::EventLogError;
+use crate::core::component::id::ComponentId;
+use crate::core::config::values::ConfigValues;
+
+/// A mutation of host state.
+///
+/// Every change to what is installed, what it may do, and what is running
+/// is recorded as one of these events. Replaying them in order rebuilds the
+/// host state (see [`HostState`](super::state::HostState)).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HostEvent {
+    /// A component was installed on the host.
+    ComponentInstalled {
+        /// Installed component.
+        component: ComponentId,
+        /// Where the component was installed from (path, URL, or digest).
+        source: String,
+    },
+
+    /// A component was removed from the host.
+    ComponentUninstalled {
+        /// Removed component.
+        component: ComponentId,
+    },
+
+    /// A capability was granted to a component.
+    CapabilityGranted {
+        /// Component receiving the capability.
+        component: ComponentId,
+        /// Capability description (e.g. `"storage:write:cache/*"`).
+        capability: String,
+    },
+
+    /// A capability was revoked from a component.
+    CapabilityRevoked {
+        /// Component losing the capability.
+        component: ComponentId,
+        /// Capability description.
+        capability: String,
+    },
+
+    /// A component instance was spawned.
+    ComponentSpawned {
+        /// Spawned component.
+        component: ComponentId,
+    },
+
+    /// A component instance was stopped.
+    ComponentStopped {
+        /// Stopped component.
+        component: ComponentId,
+    },
+
+    /// A component accepted new `[config]` values.
+    ConfigChanged {
+        /// Reconfigured component.
+        component: ComponentId,
+        /// Values that changed.
+        values: ConfigValues,
+    },
+
+    /// A caller invoked a deprecated export for the first time.
+    ///
+    /// Recorded once per caller and export; does not change host state.
+    DeprecatedExportCalled {
+        /// Component owning the deprecated export.
+        component: ComponentId,
+        /// Deprecated export name.
+        export: String,
+        /// Component that called it.
+        caller: ComponentId,
+    },
+}
+
+impl HostEvent {
+    /// Returns the component the event applies to.
+    pub fn component(&self) -> &ComponentId {
+        match self {
+            HostEvent::ComponentInstalled { component, .. }
+            | HostEvent::ComponentUninstalled { component }
+            | HostEvent::CapabilityGranted { component, .. }
+            | HostEvent::CapabilityRevoked { component, .. }
+            | HostEvent::ComponentSpawned { component }
+            | HostEvent::ComponentStopped { component }
+            | HostEvent::ConfigChanged { component, .. }
+            | HostEvent::DeprecatedExportCalled { component, .. } => component,
+        }
+    }
+}
+
+/// Hash used as the predecessor of the first record.
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// One entry of the host event log.
+///
+/// Each record carries the SHA-256 hash of its predecessor, so editing,
+/// removing, or reordering any record breaks every hash after it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventRecord {
+    /// Position in the log, starting at 0.
+    pub sequence: u64,
+    /// When the event was recorded.
+    pub recorded_at: DateTime<Utc>,
+    /// The recorded event.
+    pub event: HostEvent,
+    /// Hash of the previous record (`GENESIS_HASH` for the first).
+    pub prev_hash: String,
+    /// Hash of this record.
+    pub hash: String,
+}
+
+impl EventRecord {
+    /// Creates a record chained to `prev_hash`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EventLogError::Serialization` if the event cannot be encoded.
+    pub fn new(
+        sequence: u64,
+        recorded_at: DateTime<Utc>,
+        event: HostEvent,
+        prev_hash: String,
+    ) -> Result<Self, EventLogError> {
+        let hash = Self::compute_hash(sequence, &recorded_at, &event, &prev_hash)?;
+        Ok(Self {
+            sequence,
+            recorded_at,
+            event,
+            prev_hash,
+            hash,
+        })
+    }
+
+    /// Returns true if the stored hash matches the record's contents.
+    pub fn is_intact(&self) -> bool {
+        Self::compute_hash(
+            self.sequence,
+            &self.recorded_at,
+            &self.event,
+            &self.prev_hash,
+        )
+        .is_ok_and(|hash| hash == self.hash)
+    }
+
+    fn compute_hash(
+        sequence: u64,
+        recorded_at: &DateTime<Utc>,
+        event: &HostEvent,
+        prev_hash: &str,
+    ) -> Result<String, EventLogError> {
+        let payload =
+            serde_json::to_vec(event).map_err(|e| EventLogError::Serialization(e.to_string()))?;
+
+        let mut hasher = Sha256::new();
+        hasher.update(prev_hash.as_bytes());
+        hasher.update(sequence.to_be_bytes());
+        hasher.update(recorded_at.to_rfc3339().as_bytes());
+        hasher.update(&payload);
+        Ok(hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect())
+    }
+}
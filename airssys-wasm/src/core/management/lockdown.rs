@@ -0,0 +1,318 @@
+//! Host-wide read-only lockdown for incident response.
+//!
+//! While a lockdown is engaged every write-class capability check (file
+//! write, storage put, outbound network) fails for every component, no matter
+//! what the component was granted. Read paths keep working, so components can
+//! still serve cached data while operators investigate.
+//!
+//! Each engage/lift pair forms a [`LockdownWindow`] that records who opened
+//! and closed it, why, and every request that was refused while it was open.
+//! Enforcement lives in `security::lockdown`; this module only holds the
+//! switch and its audit trail.
+
+// Layer 1: Standard library imports
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+// Layer 3: Internal module imports
+use super::errors::LockdownError;
+use crate::core::component::id::ComponentId;
+
+/// A write-class request refused during a lockdown.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockdownDenial {
+    /// Component whose request was refused.
+    pub component: ComponentId,
+    /// Refused capability (`class:action:pattern`).
+    pub capability: String,
+    /// When the request was refused.
+    pub denied_at: DateTime<Utc>,
+}
+
+/// One engage/lift period of the lockdown switch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockdownWindow {
+    /// Operator who engaged the lockdown.
+    pub engaged_by: String,
+    /// Reason given when engaging.
+    pub reason: String,
+    /// When the lockdown was engaged.
+    pub engaged_at: DateTime<Utc>,
+    /// Operator who lifted the lockdown; `None` while still engaged.
+    pub lifted_by: Option<String>,
+    /// When the lockdown was lifted; `None` while still engaged.
+    pub lifted_at: Option<DateTime<Utc>>,
+    /// Requests refused during the window, oldest first.
+    pub denials: Vec<LockdownDenial>,
+}
+
+impl LockdownWindow {
+    /// Whether the window is still open.
+    pub fn is_open(&self) -> bool {
+        self.lifted_at.is_none()
+    }
+}
+
+/// Host lockdown switch, shared by the security validators that enforce it
+/// and the control surface that flips it.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::management::lockdown::HostLockdown;
+///
+/// let lockdown = HostLockdown::new();
+/// lockdown.engage("oncall@example.com", "suspected key leak").unwrap();
+/// assert!(lockdown.is_engaged());
+///
+/// let component = ComponentId::new("acme", "cache", "v1");
+/// lockdown.record_denial(&component, "storage:write:cache/*").unwrap();
+///
+/// let window = lockdown.lift("oncall@example.com").unwrap();
+/// assert!(!lockdown.is_engaged());
+/// assert_eq!(window.denials.len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct HostLockdown {
+    engaged: AtomicBool,
+    windows: Mutex<Vec<LockdownWindow>>,
+}
+
+impl HostLockdown {
+    /// Creates a disengaged switch with no history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether write-class capabilities are currently denied.
+    ///
+    /// Lock-free so validators can consult it on every check.
+    pub fn is_engaged(&self) -> bool {
+        self.engaged.load(Ordering::Acquire)
+    }
+
+    /// Engages the lockdown on behalf of `operator`.
+    ///
+    /// # Errors
+    ///
+    /// - `LockdownError::AlreadyEngaged` if a lockdown is already in effect
+    /// - `LockdownError::LockPoisoned` if the internal lock is poisoned
+    pub fn engage(&self, operator: &str, reason: &str) -> Result<LockdownWindow, LockdownError> {
+        let mut windows = self.lock()?;
+        if let Some(open) = windows.last().filter(|w| w.is_open()) {
+            return Err(LockdownError::AlreadyEngaged(open.engaged_by.clone()));
+        }
+        let window = LockdownWindow {
+            engaged_by: operator.to_string(),
+            reason: reason.to_string(),
+            engaged_at: Utc::now(),
+            lifted_by: None,
+            lifted_at: None,
+            denials: Vec::new(),
+        };
+        windows.push(window.clone());
+        self.engaged.store(true, Ordering::Release);
+        Ok(window)
+    }
+
+    /// Lifts the lockdown on behalf of `operator` and returns the closed
+    /// window.
+    ///
+    /// # Errors
+    ///
+    /// - `LockdownError::NotEngaged` if no lockdown is in effect
+    /// - `LockdownError::LockPoisoned` if the internal lock is poisoned
+    pub fn lift(&self, operator: &str) -> Result<LockdownWindow, LockdownError> {
+        let mut windows = self.lock()?;
+        let window = windows
+            .last_mut()
+            .filter(|w| w.is_open())
+            .ok_or(LockdownError::NotEngaged)?;
+        window.lifted_by = Some(operator.to_string());
+        window.lifted_at = Some(Utc::now());
+        self.engaged.store(false, Ordering::Release);
+        Ok(window.clone())
+    }
+
+    /// Records a request refused because of the lockdown.
+    ///
+    /// Ignored if the lockdown was lifted in the meantime.
+    ///
+    /// # Errors
+    ///
+    /// - `LockdownError::LockPoisoned` if the internal lock is poisoned
+    pub fn record_denial(
+        &self,
+        component: &ComponentId,
+        capability: &str,
+    ) -> Result<(), LockdownError> {
+        let mut windows = self.lock()?;
+        if let Some(window) = windows.last_mut().filter(|w| w.is_open()) {
+            window.denials.push(LockdownDenial {
+                component: component.clone(),
+                capability: capability.to_string(),
+                denied_at: Utc::now(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the open window, if a lockdown is in effect.
+    ///
+    /// # Errors
+    ///
+    /// - `LockdownError::LockPoisoned` if the internal lock is poisoned
+    pub fn current(&self) -> Result<Option<LockdownWindow>, LockdownError> {
+        Ok(self.lock()?.last().filter(|w| w.is_open()).cloned())
+    }
+
+    /// Returns every window, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// - `LockdownError::LockPoisoned` if the internal lock is poisoned
+    pub fn windows(&self) -> Result<Vec<LockdownWindow>, LockdownError> {
+        Ok(self.lock()?.clone())
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Vec<LockdownWindow>>, LockdownError> {
+        self.windows
+            .lock()
+            .map_err(|e| LockdownError::LockPoisoned(e.to_string()))
+    }
+}
+
+/// Operator command for the lockdown switch, as typed at the host CLI
+/// (`lockdown on <reason>`, `lockdown off`, `lockdown status`).
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::management::lockdown::LockdownCommand;
+///
+/// let cmd: LockdownCommand = "on exfiltration alert".parse().unwrap();
+/// assert_eq!(
+///     cmd,
+///     LockdownCommand::On { reason: "exfiltration alert".to_string() }
+/// );
+/// assert_eq!("off".parse::<LockdownCommand>().unwrap(), LockdownCommand::Off);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LockdownCommand {
+    /// Engage the lockdown.
+    On {
+        /// Reason recorded in the window.
+        reason: String,
+    },
+    /// Lift the lockdown.
+    Off,
+    /// Report the current window.
+    Status,
+}
+
+impl FromStr for LockdownCommand {
+    type Err = LockdownError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (verb, rest) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+        match (verb, rest.trim()) {
+            ("on", "") => Err(LockdownError::InvalidCommand(
+                "lockdown on requires a reason".to_string(),
+            )),
+            ("on", reason) => Ok(Self::On {
+                reason: reason.to_string(),
+            }),
+            ("off", "") => Ok(Self::Off),
+            ("status", "") => Ok(Self::Status),
+            _ => Err(LockdownError::InvalidCommand(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for LockdownCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::On { reason } => write!(f, "on {reason}"),
+            Self::Off => f.write_str("off"),
+            Self::Status => f.write_str("status"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component() -> ComponentId {
+        ComponentId::new("acme", "cache", "v1")
+    }
+
+    #[test]
+    fn test_engage_and_lift_record_window() {
+        let lockdown = HostLockdown::new();
+        assert!(matches!(
+            lockdown.lift("ops"),
+            Err(LockdownError::NotEngaged)
+        ));
+
+        lockdown.engage("alice", "key leak").unwrap();
+        assert!(matches!(
+            lockdown.engage("bob", "again"),
+            Err(LockdownError::AlreadyEngaged(by)) if by == "alice"
+        ));
+        lockdown
+            .record_denial(&component(), "filesystem:write:/data/x")
+            .unwrap();
+        assert!(lockdown.current().unwrap().is_some());
+
+        let window = lockdown.lift("bob").unwrap();
+        assert_eq!(window.engaged_by, "alice");
+        assert_eq!(window.lifted_by.as_deref(), Some("bob"));
+        assert_eq!(window.denials[0].capability, "filesystem:write:/data/x");
+        assert!(lockdown.current().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_denials_outside_window_are_ignored() {
+        let lockdown = HostLockdown::new();
+        lockdown
+            .record_denial(&component(), "storage:write:x")
+            .unwrap();
+        lockdown.engage("ops", "first").unwrap();
+        lockdown.lift("ops").unwrap();
+        lockdown
+            .record_denial(&component(), "storage:write:x")
+            .unwrap();
+        lockdown.engage("ops", "second").unwrap();
+
+        let windows = lockdown.windows().unwrap();
+        assert_eq!(windows.len(), 2);
+        assert!(windows.iter().all(|w| w.denials.is_empty()));
+        assert!(windows[1].is_open());
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(
+            "  on  breach drill ".parse::<LockdownCommand>().unwrap(),
+            LockdownCommand::On {
+                reason: "breach drill".to_string()
+            }
+        );
+        assert_eq!(
+            "status".parse::<LockdownCommand>().unwrap(),
+            LockdownCommand::Status
+        );
+        assert!("on".parse::<LockdownCommand>().is_err());
+        assert!("off now".parse::<LockdownCommand>().is_err());
+        assert!("enable".parse::<LockdownCommand>().is_err());
+    }
+}
@@ -0,0 +1,296 @@
+//! Append-only, hash-chained host event log.
+
+// Layer 1: Standard library imports
+use std::sync::{RwLock, RwLockReadGuard};
+
+// Layer 2: Third-party crate imports
+use chrono::Utc;
+
+// Layer 3: Internal module imports
+use super::errors::EventLogError;
+use super::event::{EventRecord, HostEvent, GENESIS_HASH};
+use super::state::HostState;
+
+/// Append-only log of host state changes.
+///
+/// Records are never modified or removed. Each record is chained to its
+/// predecessor by hash, so [`verify`](Self::verify) detects any edit to an
+/// exported log. [`replay`](Self::replay) rebuilds the host state from the
+/// records, and [`export_json_lines`](Self::export_json_lines) produces a
+/// one-record-per-line audit export.
+///
+/// # Thread Safety
+///
+/// Records are guarded by `RwLock`; all methods take `&self`.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::management::event::HostEvent;
+/// use airssys_wasm::core::management::log::HostEventLog;
+///
+/// let log = HostEventLog::new();
+/// let component = ComponentId::new("acme", "cache", "v1");
+/// log.append(HostEvent::ComponentSpawned { component: component.clone() }).unwrap();
+///
+/// let exported = log.export_json_lines().unwrap();
+/// let imported = HostEventLog::import_json_lines(&exported).unwrap();
+/// let state = imported.replay().unwrap();
+/// assert!(state.component(&component).unwrap().running);
+/// ```
+#[derive(Debug, Default)]
+pub struct HostEventLog {
+    records: RwLock<Vec<EventRecord>>,
+}
+
+impl HostEventLog {
+    /// Creates an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an event and returns its sequence number.
+    ///
+    /// # Errors
+    ///
+    /// - `EventLogError::Serialization` if the event cannot be encoded
+    /// - `EventLogError::LockPoisoned` if the internal lock is poisoned
+    pub fn append(&self, event: HostEvent) -> Result<u64, EventLogError> {
+        let mut records = self
+            .records
+            .write()
+            .map_err(|e| EventLogError::LockPoisoned(e.to_string()))?;
+        let sequence = records.len() as u64;
+        let prev_hash = records
+            .last()
+            .map_or_else(|| GENESIS_HASH.to_string(), |r| r.hash.clone());
+        records.push(EventRecord::new(sequence, Utc::now(), event, prev_hash)?);
+        Ok(sequence)
+    }
+
+    /// Returns a copy of all records, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EventLogError::LockPoisoned` if the internal lock is poisoned.
+    pub fn records(&self) -> Result<Vec<EventRecord>, EventLogError> {
+        Ok(self.read()?.clone())
+    }
+
+    /// Returns the number of records.
+    pub fn len(&self) -> usize {
+        self.records.read().map_or(0, |records| records.len())
+    }
+
+    /// Returns true if the log has no records.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Checks the sequence numbers and the hash chain.
+    ///
+    /// # Errors
+    ///
+    /// - `EventLogError::SequenceGap` if records are missing or reordered
+    /// - `EventLogError::Tampered` if a record was modified
+    /// - `EventLogError::LockPoisoned` if the internal lock is poisoned
+    pub fn verify(&self) -> Result<(), EventLogError> {
+        Self::verify_records(&self.read()?)
+    }
+
+    /// Rebuilds host state by applying every record in order.
+    ///
+    /// The chain is verified first so a tampered log never yields state.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`verify`](Self::verify).
+    pub fn replay(&self) -> Result<HostState, EventLogError> {
+        let records = self.read()?;
+        Self::verify_records(&records)?;
+
+        let mut state = HostState::new();
+        for record in records.iter() {
+            state.apply(&record.event);
+        }
+        Ok(state)
+    }
+
+    /// Exports the log as JSON lines, one record per line.
+    ///
+    /// # Errors
+    ///
+    /// - `EventLogError::Serialization` if a record cannot be encoded
+    /// - `EventLogError::LockPoisoned` if the internal lock is poisoned
+    pub fn export_json_lines(&self) -> Result<String, EventLogError> {
+        let mut out = String::new();
+        for record in self.read()?.iter() {
+            let line = serde_json::to_string(record)
+                .map_err(|e| EventLogError::Serialization(e.to_string()))?;
+            out.push_str(&line);
+            out.push('\n');
+        }
+        Ok(out)
+    }
+
+    /// Loads a log from a JSON lines export, verifying the chain.
+    ///
+    /// # Errors
+    ///
+    /// - `EventLogError::Serialization` if a line cannot be parsed
+    /// - `EventLogError::SequenceGap` / `EventLogError::Tampered` if the
+    ///   export fails verification
+    pub fn import_json_lines(exported: &str) -> Result<Self, EventLogError> {
+        let records = exported
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str::<EventRecord>(line)
+                    .map_err(|e| EventLogError::Serialization(e.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::verify_records(&records)?;
+        Ok(Self {
+            records: RwLock::new(records),
+        })
+    }
+
+    fn verify_records(records: &[EventRecord]) -> Result<(), EventLogError> {
+        let mut prev_hash = GENESIS_HASH;
+        for (expected, record) in (0u64..).zip(records) {
+            if record.sequence != expected {
+                return Err(EventLogError::SequenceGap {
+                    expected,
+                    found: record.sequence,
+                });
+            }
+            if record.prev_hash != prev_hash || !record.is_intact() {
+                return Err(EventLogError::Tampered(record.sequence));
+            }
+            prev_hash = &record.hash;
+        }
+        Ok(())
+    }
+
+    fn read(&self) -> Result<RwLockReadGuard<'_, Vec<EventRecord>>, EventLogError> {
+        self.records
+            .read()
+            .map_err(|e| EventLogError::LockPoisoned(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::component::id::ComponentId;
+    use crate::core::config::values::{ConfigValue, ConfigValues};
+
+    fn component() -> ComponentId {
+        ComponentId::new("acme", "cache", "v1")
+    }
+
+    fn populated() -> HostEventLog {
+        let log = HostEventLog::new();
+        let events = vec![
+            HostEvent::ComponentInstalled {
+                component: component(),
+                source: "file:///plugins/cache.wasm".to_string(),
+            },
+            HostEvent::CapabilityGranted {
+                component: component(),
+                capability: "storage:write".to_string(),
+            },
+            HostEvent::CapabilityGranted {
+                component: component(),
+                capability: "messaging:send".to_string(),
+            },
+            HostEvent::CapabilityRevoked {
+                component: component(),
+                capability: "messaging:send".to_string(),
+            },
+            HostEvent::ComponentSpawned {
+                component: component(),
+            },
+            HostEvent::ConfigChanged {
+                component: component(),
+                values: ConfigValues::new().with("threshold", ConfigValue::Integer(5)),
+            },
+        ];
+        for event in events {
+            log.append(event).unwrap();
+        }
+        log
+    }
+
+    #[test]
+    fn test_replay_rebuilds_state() {
+        let state = populated().replay().unwrap();
+        let cache = state.component(&component()).unwrap();
+
+        assert_eq!(
+            cache.installed_from.as_deref(),
+            Some("file:///plugins/cache.wasm")
+        );
+        assert_eq!(
+            cache.capabilities.iter().collect::<Vec<_>>(),
+            vec!["storage:write"]
+        );
+        assert!(cache.running);
+        assert_eq!(
+            cache.config.get("threshold"),
+            Some(&ConfigValue::Integer(5))
+        );
+        assert_eq!(state.running(), vec![&component()]);
+    }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let log = populated();
+        let exported = log.export_json_lines().unwrap();
+        assert_eq!(exported.lines().count(), 6);
+
+        let imported = HostEventLog::import_json_lines(&exported).unwrap();
+        assert_eq!(imported.records().unwrap(), log.records().unwrap());
+        assert_eq!(imported.replay().unwrap(), log.replay().unwrap());
+    }
+
+    #[test]
+    fn test_tampering_is_detected() {
+        let exported = populated().export_json_lines().unwrap();
+
+        // Rewrite a granted capability after the fact
+        let edited = exported.replacen("storage:write", "storage:admin", 1);
+        assert_eq!(
+            HostEventLog::import_json_lines(&edited).err(),
+            Some(EventLogError::Tampered(1))
+        );
+
+        // Drop a record from the middle
+        let dropped: String = exported
+            .lines()
+            .enumerate()
+            .filter(|(i, _)| *i != 2)
+            .map(|(_, line)| format!("{line}\n"))
+            .collect();
+        assert_eq!(
+            HostEventLog::import_json_lines(&dropped).err(),
+            Some(EventLogError::SequenceGap {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_uninstall_drops_component_state() {
+        let log = populated();
+        log.append(HostEvent::ComponentUninstalled {
+            component: component(),
+        })
+        .unwrap();
+
+        let state = log.replay().unwrap();
+        assert!(state.is_empty());
+    }
+}
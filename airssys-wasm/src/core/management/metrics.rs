@@ -0,0 +1,546 @@
+//! Application-level metrics emitted by components.
+//!
+//! Components report their own counters, gauges, and histograms through the
+//! `host-metrics` interface. Every series is namespaced under the emitting
+//! component's ID, so two components can use the same metric name without
+//! colliding, and each component may hold at most a fixed number of series
+//! so a guest cannot exhaust host memory with unbounded label values.
+//!
+//! [`MetricsSnapshot`] is the host-wide view: the coordinator merges
+//! component series with plugin metrics into one snapshot, which can be
+//! rendered in the Prometheus text exposition format.
+
+// Layer 1: Standard library imports
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fmt::Write as _;
+use std::sync::{Mutex, MutexGuard};
+
+// Layer 2: Third-party crate imports
+use serde::{Deserialize, Serialize};
+
+// Layer 3: Internal module imports
+use super::errors::MetricsError;
+use crate::core::component::id::ComponentId;
+
+/// Default maximum number of series per component.
+pub const DEFAULT_SERIES_LIMIT: usize = 256;
+
+/// Label the host adds to every component series; guests may not set it.
+pub const COMPONENT_LABEL: &str = "component";
+
+/// Kind of a metric series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricKind {
+    /// Monotonically increasing count.
+    Counter,
+    /// Value that can go up and down.
+    Gauge,
+    /// Distribution of observed values.
+    Histogram,
+}
+
+impl fmt::Display for MetricKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Counter => "counter",
+            Self::Gauge => "gauge",
+            Self::Histogram => "histogram",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Running summary of a histogram's observations.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HistogramSummary {
+    /// Number of observations.
+    pub count: u64,
+    /// Sum of observed values.
+    pub sum: f64,
+    /// Smallest observed value.
+    pub min: f64,
+    /// Largest observed value.
+    pub max: f64,
+}
+
+impl HistogramSummary {
+    fn new(value: f64) -> Self {
+        Self {
+            count: 1,
+            sum: value,
+            min: value,
+            max: value,
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        self.count += 1;
+        self.sum += value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+}
+
+/// Current value of a series.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricValue {
+    /// Counter total.
+    Counter(u64),
+    /// Last gauge value.
+    Gauge(f64),
+    /// Histogram summary.
+    Histogram(HistogramSummary),
+}
+
+impl MetricValue {
+    /// Kind of this value.
+    pub fn kind(&self) -> MetricKind {
+        match self {
+            Self::Counter(_) => MetricKind::Counter,
+            Self::Gauge(_) => MetricKind::Gauge,
+            Self::Histogram(_) => MetricKind::Histogram,
+        }
+    }
+}
+
+/// One series in a [`MetricsSnapshot`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricSample {
+    /// Emitting component; `None` for host-level metrics.
+    pub component: Option<ComponentId>,
+    /// Metric name.
+    pub name: String,
+    /// Labels set by the emitter, sorted by key.
+    pub labels: Vec<(String, String)>,
+    /// Current value.
+    pub value: MetricValue,
+}
+
+/// Point-in-time view of host and component metrics.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetricsSnapshot {
+    samples: Vec<MetricSample>,
+}
+
+impl MetricsSnapshot {
+    /// Creates a snapshot from `samples`.
+    pub fn new(samples: Vec<MetricSample>) -> Self {
+        Self { samples }
+    }
+
+    /// Appends the samples of `other`.
+    pub fn merge(&mut self, other: MetricsSnapshot) {
+        self.samples.extend(other.samples);
+    }
+
+    /// All samples.
+    pub fn samples(&self) -> &[MetricSample] {
+        &self.samples
+    }
+
+    /// Samples emitted by `component`.
+    pub fn for_component<'a>(
+        &'a self,
+        component: &'a ComponentId,
+    ) -> impl Iterator<Item = &'a MetricSample> + 'a {
+        self.samples
+            .iter()
+            .filter(move |s| s.component.as_ref() == Some(component))
+    }
+
+    /// Renders the snapshot in the Prometheus text exposition format.
+    ///
+    /// Component series carry a `component` label with the component ID;
+    /// histograms are exposed as summaries (`_count` and `_sum`). Characters
+    /// outside `[a-zA-Z0-9_]` in names become `_`.
+    pub fn to_prometheus(&self) -> String {
+        let mut families: BTreeMap<String, Vec<&MetricSample>> = BTreeMap::new();
+        for sample in &self.samples {
+            families
+                .entry(sanitize(&sample.name))
+                .or_default()
+                .push(sample);
+        }
+
+        let mut out = String::new();
+        for (name, samples) in families {
+            let kind = match samples[0].value.kind() {
+                MetricKind::Counter => "counter",
+                MetricKind::Gauge => "gauge",
+                MetricKind::Histogram => "summary",
+            };
+            let _ = writeln!(out, "# TYPE {name} {kind}");
+            for sample in samples {
+                let labels = render_labels(sample);
+                match sample.value {
+                    MetricValue::Counter(v) => {
+                        let _ = writeln!(out, "{name}{labels} {v}");
+                    }
+                    MetricValue::Gauge(v) => {
+                        let _ = writeln!(out, "{name}{labels} {v}");
+                    }
+                    MetricValue::Histogram(h) => {
+                        let _ = writeln!(out, "{name}_count{labels} {}", h.count);
+                        let _ = writeln!(out, "{name}_sum{labels} {}", h.sum);
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+type SeriesKey = (String, Vec<(String, String)>);
+type SeriesMap = HashMap<ComponentId, BTreeMap<SeriesKey, MetricValue>>;
+
+/// Registry of component-emitted metrics, shared by the host functions that
+/// record them and the coordinator that exports them.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::management::metrics::{ComponentMetrics, MetricValue};
+///
+/// let metrics = ComponentMetrics::new();
+/// let component = ComponentId::new("acme", "cache", "v1");
+///
+/// metrics
+///     .increment_counter(&component, "hits", &[("tier".to_string(), "l1".to_string())], 3)
+///     .unwrap();
+///
+/// let snapshot = metrics.snapshot().unwrap();
+/// assert_eq!(snapshot.samples()[0].value, MetricValue::Counter(3));
+/// ```
+#[derive(Debug)]
+pub struct ComponentMetrics {
+    series_limit: usize,
+    series: Mutex<SeriesMap>,
+}
+
+impl Default for ComponentMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ComponentMetrics {
+    /// Creates an empty registry allowing [`DEFAULT_SERIES_LIMIT`] series
+    /// per component.
+    pub fn new() -> Self {
+        Self {
+            series_limit: DEFAULT_SERIES_LIMIT,
+            series: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets the maximum number of series a single component may create.
+    pub fn with_series_limit(mut self, limit: usize) -> Self {
+        self.series_limit = limit;
+        self
+    }
+
+    /// Maximum number of series per component.
+    pub fn series_limit(&self) -> usize {
+        self.series_limit
+    }
+
+    /// Adds `delta` to a counter of `component`.
+    ///
+    /// # Errors
+    ///
+    /// - `MetricsError::InvalidName` if a name or label key is malformed
+    /// - `MetricsError::CardinalityExceeded` if this would create a series
+    ///   beyond the component's limit
+    /// - `MetricsError::KindMismatch` if the series is not a counter
+    pub fn increment_counter(
+        &self,
+        component: &ComponentId,
+        name: &str,
+        labels: &[(String, String)],
+        delta: u64,
+    ) -> Result<(), MetricsError> {
+        self.update(
+            component,
+            name,
+            labels,
+            MetricValue::Counter(delta),
+            |value| match value {
+                MetricValue::Counter(total) => {
+                    *total = total.saturating_add(delta);
+                    true
+                }
+                _ => false,
+            },
+        )
+    }
+
+    /// Sets a gauge of `component` to `value`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`increment_counter`](Self::increment_counter).
+    pub fn set_gauge(
+        &self,
+        component: &ComponentId,
+        name: &str,
+        labels: &[(String, String)],
+        value: f64,
+    ) -> Result<(), MetricsError> {
+        self.update(
+            component,
+            name,
+            labels,
+            MetricValue::Gauge(value),
+            |current| match current {
+                MetricValue::Gauge(gauge) => {
+                    *gauge = value;
+                    true
+                }
+                _ => false,
+            },
+        )
+    }
+
+    /// Records one observation in a histogram of `component`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`increment_counter`](Self::increment_counter).
+    pub fn observe_histogram(
+        &self,
+        component: &ComponentId,
+        name: &str,
+        labels: &[(String, String)],
+        value: f64,
+    ) -> Result<(), MetricsError> {
+        self.update(
+            component,
+            name,
+            labels,
+            MetricValue::Histogram(HistogramSummary::new(value)),
+            |current| match current {
+                MetricValue::Histogram(summary) => {
+                    summary.observe(value);
+                    true
+                }
+                _ => false,
+            },
+        )
+    }
+
+    /// Drops every series of `component`, e.g. when it is unloaded.
+    ///
+    /// # Errors
+    ///
+    /// - `MetricsError::LockPoisoned` if the internal lock is poisoned
+    pub fn remove(&self, component: &ComponentId) -> Result<(), MetricsError> {
+        self.lock()?.remove(component);
+        Ok(())
+    }
+
+    /// Returns all series, ordered by component, name, and labels.
+    ///
+    /// # Errors
+    ///
+    /// - `MetricsError::LockPoisoned` if the internal lock is poisoned
+    pub fn snapshot(&self) -> Result<MetricsSnapshot, MetricsError> {
+        let series = self.lock()?;
+        let mut components: Vec<_> = series.iter().collect();
+        components.sort_by_key(|(id, _)| id.to_string_id());
+
+        let samples = components
+            .into_iter()
+            .flat_map(|(component, series)| {
+                series.iter().map(|((name, labels), value)| MetricSample {
+                    component: Some(component.clone()),
+                    name: name.clone(),
+                    labels: labels.clone(),
+                    value: *value,
+                })
+            })
+            .collect();
+        Ok(MetricsSnapshot::new(samples))
+    }
+
+    fn update(
+        &self,
+        component: &ComponentId,
+        name: &str,
+        labels: &[(String, String)],
+        initial: MetricValue,
+        apply: impl FnOnce(&mut MetricValue) -> bool,
+    ) -> Result<(), MetricsError> {
+        validate_name(name)?;
+        for (key, _) in labels {
+            validate_name(key)?;
+            if key == COMPONENT_LABEL {
+                return Err(MetricsError::InvalidName(key.clone()));
+            }
+        }
+        let mut sorted = labels.to_vec();
+        sorted.sort();
+        let key = (name.to_string(), sorted);
+
+        let mut all = self.lock()?;
+        let series = all.entry(component.clone()).or_default();
+        let full = series.len() >= self.series_limit;
+        match series.get_mut(&key) {
+            Some(value) => {
+                let existing = value.kind();
+                if apply(value) {
+                    Ok(())
+                } else {
+                    Err(MetricsError::KindMismatch {
+                        name: name.to_string(),
+                        existing: existing.to_string(),
+                        requested: initial.kind().to_string(),
+                    })
+                }
+            }
+            None if full => Err(MetricsError::CardinalityExceeded {
+                component: component.to_string_id(),
+                limit: self.series_limit,
+            }),
+            None => {
+                series.insert(key, initial);
+                Ok(())
+            }
+        }
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, SeriesMap>, MetricsError> {
+        self.series
+            .lock()
+            .map_err(|e| MetricsError::LockPoisoned(e.to_string()))
+    }
+}
+
+/// Checks that `name` matches `[a-zA-Z_][a-zA-Z0-9_]*`.
+///
+/// # Errors
+///
+/// Returns `MetricsError::InvalidName` otherwise.
+pub fn validate_name(name: &str) -> Result<(), MetricsError> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(MetricsError::InvalidName(name.to_string()))
+    }
+}
+
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn render_labels(sample: &MetricSample) -> String {
+    let component = sample
+        .component
+        .as_ref()
+        .map(|id| (COMPONENT_LABEL.to_string(), id.to_string_id()));
+    let pairs: Vec<String> = component
+        .iter()
+        .chain(sample.labels.iter())
+        .map(|(k, v)| format!("{k}=\"{}\"", escape(v)))
+        .collect();
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", pairs.join(","))
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component() -> ComponentId {
+        ComponentId::new("acme", "cache", "v1")
+    }
+
+    fn label(k: &str, v: &str) -> (String, String) {
+        (k.to_string(), v.to_string())
+    }
+
+    #[test]
+    fn test_series_are_namespaced_and_capped() {
+        let metrics = ComponentMetrics::new().with_series_limit(2);
+        let other = ComponentId::new("acme", "queue", "v1");
+
+        metrics
+            .increment_counter(&component(), "hits", &[], 1)
+            .unwrap();
+        metrics
+            .increment_counter(&component(), "hits", &[], 2)
+            .unwrap();
+        metrics.increment_counter(&other, "hits", &[], 5).unwrap();
+        metrics
+            .set_gauge(&component(), "size", &[label("tier", "l1")], 4.0)
+            .unwrap();
+
+        assert!(matches!(
+            metrics.set_gauge(&component(), "size", &[label("tier", "l2")], 1.0),
+            Err(MetricsError::CardinalityExceeded { limit: 2, .. })
+        ));
+        assert!(matches!(
+            metrics.observe_histogram(&component(), "hits", &[], 1.0),
+            Err(MetricsError::KindMismatch { .. })
+        ));
+        assert!(metrics
+            .increment_counter(&component(), "hits", &[label("component", "x")], 1)
+            .is_err());
+        assert!(metrics
+            .increment_counter(&component(), "9x", &[], 1)
+            .is_err());
+
+        let snapshot = metrics.snapshot().unwrap();
+        let id = component();
+        let mine: Vec<_> = snapshot.for_component(&id).collect();
+        assert_eq!(mine.len(), 2);
+        assert_eq!(mine[0].value, MetricValue::Counter(3));
+
+        metrics.remove(&component()).unwrap();
+        assert_eq!(metrics.snapshot().unwrap().samples().len(), 1);
+    }
+
+    #[test]
+    fn test_prometheus_rendering() {
+        let metrics = ComponentMetrics::new();
+        metrics
+            .observe_histogram(&component(), "latency_ms", &[label("op", "get")], 2.0)
+            .unwrap();
+        metrics
+            .observe_histogram(&component(), "latency_ms", &[label("op", "get")], 4.0)
+            .unwrap();
+
+        let mut snapshot = MetricsSnapshot::new(vec![MetricSample {
+            component: None,
+            name: "audit.records".to_string(),
+            labels: Vec::new(),
+            value: MetricValue::Gauge(7.0),
+        }]);
+        snapshot.merge(metrics.snapshot().unwrap());
+
+        let text = snapshot.to_prometheus();
+        assert!(text.contains("# TYPE audit_records gauge\naudit_records 7\n"));
+        assert!(text.contains("latency_ms_count{component=\"acme/cache/v1\",op=\"get\"} 2\n"));
+        assert!(text.contains("latency_ms_sum{component=\"acme/cache/v1\",op=\"get\"} 6\n"));
+    }
+}
@@ -0,0 +1,52 @@
+//! Host management history: an event-sourced record of host state changes.
+//!
+//! Every host-state mutation (install, grant, spawn, config change) is
+//! appended to a hash-chained event log. The log can rebuild host state by
+//! replay and be exported for audit; any edit to an export is detected on
+//! import.
+//!
+//! # Architecture
+//!
+//! This module is part of the **core/** foundation (Layer 1). It contains:
+//!
+//! - **Types**: `HostEvent`, `EventRecord`, `HostState`
+//! - **Log**: `HostEventLog` (append-only, in-memory)
+//! - **Elevation**: `ElevationRequests` (capability upgrade requests)
+//! - **Lockdown**: `HostLockdown` (host-wide read-only switch)
+//! - **Metrics**: `ComponentMetrics`, `MetricsSnapshot` (component-emitted metrics)
+//! - **Errors**: `EventLogError`, `ElevationError`, `LockdownError`, `MetricsError` (co-located)
+//!
+//! `system::SystemCoordinator` appends spawn, stop, and config events as it
+//! performs them.
+//!
+//! # Submodules
+//!
+//! - [`event`] - `HostEvent` and hash-chained `EventRecord`
+//! - [`log`] - `HostEventLog` (append, verify, replay, export/import)
+//! - [`state`] - `HostState` rebuilt from events
+//! - [`elevation`] - Capability elevation requests awaiting operator approval
+//! - [`lockdown`] - Read-only lockdown switch and its audit windows
+//! - [`metrics`] - Per-component counters, gauges and histograms
+//! - [`errors`] - `EventLogError`, `ElevationError`, `LockdownError` and
+//!   `MetricsError` enums
+//!
+//! # Usage
+//!
+//! ```rust
+//! use airssys_wasm::core::management::event::HostEvent;
+//! use airssys_wasm::core::management::log::HostEventLog;
+//! use airssys_wasm::core::management::state::HostState;
+//! use airssys_wasm::core::management::errors::EventLogError;
+//! ```
+
+// Module declarations (per PROJECTS_STANDARD.md §4.3)
+pub mod elevation;
+pub mod errors;
+pub mod event;
+pub mod lockdown;
+pub mod log;
+pub mod metrics;
+pub mod state;
+
+// NOTE: No glob re-exports per module grouping policy.
+// Callers use namespaced access: core::management::log::HostEventLog
@@ -0,0 +1,104 @@
+//! Host state rebuilt from the event log.
+
+// Layer 1: Standard library imports
+use std::collections::{BTreeSet, HashMap};
+
+// Layer 2: Third-party crate imports
+// (none needed for this module)
+
+// Layer 3: Internal module imports
+use super::event::HostEvent;
+use crate::core::component::id::ComponentId;
+use crate::core::config::values::ConfigValues;
+
+/// State of one component as derived from events.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ComponentState {
+    /// Install source, if the component is installed.
+    pub installed_from: Option<String>,
+    /// Capabilities currently granted.
+    pub capabilities: BTreeSet<String>,
+    /// Whether an instance is running.
+    pub running: bool,
+    /// `[config]` values accepted at runtime (changes only).
+    pub config: ConfigValues,
+}
+
+/// Host state: installed components, their grants, and what is running.
+///
+/// Built by applying [`HostEvent`]s in log order. Uninstalling a component
+/// drops its state entirely.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HostState {
+    components: HashMap<ComponentId, ComponentState>,
+}
+
+impl HostState {
+    /// Creates an empty state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies one event.
+    pub fn apply(&mut self, event: &HostEvent) {
+        match event {
+            HostEvent::ComponentUninstalled { component } => {
+                self.components.remove(component);
+                return;
+            }
+            HostEvent::DeprecatedExportCalled { .. } => return,
+            _ => {}
+        }
+
+        let state = self
+            .components
+            .entry(event.component().clone())
+            .or_default();
+        match event {
+            HostEvent::ComponentInstalled { source, .. } => {
+                state.installed_from = Some(source.clone());
+            }
+            HostEvent::CapabilityGranted { capability, .. } => {
+                state.capabilities.insert(capability.clone());
+            }
+            HostEvent::CapabilityRevoked { capability, .. } => {
+                state.capabilities.remove(capability);
+            }
+            HostEvent::ComponentSpawned { .. } => state.running = true,
+            HostEvent::ComponentStopped { .. } => state.running = false,
+            HostEvent::ConfigChanged { values, .. } => {
+                state.config = state.config.merged(values);
+            }
+            HostEvent::ComponentUninstalled { .. } | HostEvent::DeprecatedExportCalled { .. } => {}
+        }
+    }
+
+    /// Returns the state of a component.
+    pub fn component(&self, id: &ComponentId) -> Option<&ComponentState> {
+        self.components.get(id)
+    }
+
+    /// Returns every known component and its state, in no particular order.
+    pub fn components(&self) -> impl Iterator<Item = (&ComponentId, &ComponentState)> {
+        self.components.iter()
+    }
+
+    /// Returns the components with a running instance.
+    pub fn running(&self) -> Vec<&ComponentId> {
+        self.components
+            .iter()
+            .filter(|(_, state)| state.running)
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Returns the number of known components.
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Returns true if no component is known.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+}
@@ -0,0 +1,118 @@
+//! Component discovery types for federated messaging.
+//!
+//! A component ID may name a component hosted locally or on a peer node.
+//! This module defines what a resolution returns ([`Endpoint`],
+//! [`Resolution`]) and the [`DiscoveryBackend`] trait through which
+//! external discovery systems (DNS-SD, etcd, ...) are plugged in. The
+//! resolving service lives in `messaging/discovery` (Layer 3B).
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+use std::fmt;
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+use serde::{Deserialize, Serialize};
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use crate::core::component::id::ComponentId;
+use crate::core::messaging::errors::MessagingError;
+
+/// A component hosted on another node.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RemoteEndpoint {
+    /// Name of the hosting node.
+    pub node: String,
+    /// Transport address of the node (e.g. `"10.0.0.7:7400"`).
+    pub address: String,
+}
+
+impl RemoteEndpoint {
+    /// Creates a remote endpoint.
+    pub fn new(node: impl Into<String>, address: impl Into<String>) -> Self {
+        Self {
+            node: node.into(),
+            address: address.into(),
+        }
+    }
+}
+
+impl fmt::Display for RemoteEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.node, self.address)
+    }
+}
+
+/// Where messages for a component should be routed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Endpoint {
+    /// The component is registered on this host.
+    Local,
+    /// The component is hosted on a peer node.
+    Remote(RemoteEndpoint),
+}
+
+/// Which source answered a resolution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolutionSource {
+    /// The local component registry.
+    Local,
+    /// A statically configured peer.
+    StaticPeer,
+    /// A pluggable discovery backend, by name.
+    Backend(String),
+}
+
+/// Result of resolving a component ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolution {
+    /// Routable endpoints, healthy ones only, in preference order.
+    pub endpoints: Vec<Endpoint>,
+    /// Source that answered.
+    pub source: ResolutionSource,
+    /// Whether the answer came from the resolution cache.
+    pub cached: bool,
+}
+
+impl Resolution {
+    /// The preferred endpoint.
+    pub fn primary(&self) -> Option<&Endpoint> {
+        self.endpoints.first()
+    }
+}
+
+/// An external discovery system consulted for components that are neither
+/// local nor statically configured.
+///
+/// Implementations wrap a service registry such as DNS-SD or etcd. An empty
+/// result means the backend does not know the component; errors are
+/// reserved for failures to query the backend.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::messaging::discovery::{DiscoveryBackend, RemoteEndpoint};
+/// use airssys_wasm::core::messaging::errors::MessagingError;
+///
+/// struct Fixed;
+///
+/// impl DiscoveryBackend for Fixed {
+///     fn name(&self) -> &str {
+///         "fixed"
+///     }
+///
+///     fn discover(&self, _id: &ComponentId) -> Result<Vec<RemoteEndpoint>, MessagingError> {
+///         Ok(vec![RemoteEndpoint::new("edge-1", "10.0.0.7:7400")])
+///     }
+/// }
+/// ```
+pub trait DiscoveryBackend: Send + Sync {
+    /// Backend name, reported in [`ResolutionSource::Backend`].
+    fn name(&self) -> &str;
+
+    /// Endpoints currently hosting `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError` if the backend cannot be queried.
+    fn discover(&self, id: &ComponentId) -> Result<Vec<RemoteEndpoint>, MessagingError>;
+}
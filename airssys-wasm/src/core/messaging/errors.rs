@@ -24,6 +24,12 @@ use thiserror::Error;
 /// - `InvalidMessage` - Message format or content is invalid
 /// - `QueueFull` - Message queue is at capacity
 /// - `TargetNotFound` - Target component does not exist
+/// - `CodecMismatch` - Sender and target codecs cannot be reconciled
+/// - `PayloadUnavailable` - Referenced payload is unknown, expired, or released
+/// - `SealingFailed` - Payload could not be sealed for, or opened from, a peer host
+/// - `SchemaViolation` - Payload does not match the target's registered schema
+/// - `ContentTypeRejected` - Payload content type is not one the target accepts
+/// - `Shed` - Message would miss its deadline and was dead-lettered instead
 ///
 /// # Examples
 ///
@@ -54,6 +60,34 @@ pub enum MessagingError {
     /// Target component not found.
     #[error("Target component not found: {0}")]
     TargetNotFound(String),
+
+    /// Payload codec is not accepted by the target and cannot be transcoded.
+    #[error("Codec mismatch: {0}")]
+    CodecMismatch(String),
+
+    /// A payload passed by reference is no longer held by the payload store.
+    #[error("Payload reference unavailable: {0}")]
+    PayloadUnavailable(String),
+
+    /// A payload exchanged with a peer host could not be sealed or opened
+    /// (unknown key, failed authentication, replay).
+    #[error("Payload sealing failed: {0}")]
+    SealingFailed(String),
+
+    /// Payload does not match the schema the target registered for its
+    /// message type.
+    #[error("Payload schema violation: {0}")]
+    SchemaViolation(String),
+
+    /// Payload content type is not among those the target declared it
+    /// can parse.
+    #[error("Content type rejected: {0}")]
+    ContentTypeRejected(String),
+
+    /// The target's queue is too deep for the message to meet its
+    /// deadline; it was moved to the dead-letter queue instead.
+    #[error("Message shed: {0}")]
+    Shed(String),
 }
 
 #[cfg(test)]
@@ -96,6 +130,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_codec_mismatch_display() {
+        let err = MessagingError::CodecMismatch("json -> cbor".to_string());
+        assert_eq!(format!("{}", err), "Codec mismatch: json -> cbor");
+    }
+
+    #[test]
+    fn test_schema_violation_display() {
+        let err = MessagingError::SchemaViolation("$.qty: expected integer".to_string());
+        assert_eq!(
+            format!("{}", err),
+            "Payload schema violation: $.qty: expected integer"
+        );
+    }
+
+    #[test]
+    fn test_content_type_rejected_display() {
+        let err = MessagingError::ContentTypeRejected("text/plain".to_string());
+        assert_eq!(format!("{}", err), "Content type rejected: text/plain");
+    }
+
+    #[test]
+    fn test_shed_display() {
+        let err = MessagingError::Shed("app/worker/v1 cannot meet deadline".to_string());
+        assert_eq!(
+            format!("{}", err),
+            "Message shed: app/worker/v1 cannot meet deadline"
+        );
+    }
+
+    #[test]
+    fn test_payload_unavailable_display() {
+        let err = MessagingError::PayloadUnavailable("ref-1 expired".to_string());
+        assert_eq!(
+            format!("{}", err),
+            "Payload reference unavailable: ref-1 expired"
+        );
+    }
+
     #[test]
     fn test_error_is_clone() {
         let err = MessagingError::QueueFull;
@@ -0,0 +1,38 @@
+//! Delivery guarantees for queued messages.
+//!
+//! A [`DeliveryGuarantee`] decides what happens to a target's undelivered
+//! messages across host restarts and whether delivered messages must be
+//! acknowledged. The spool in `messaging/` enforces it.
+
+/// Delivery guarantee applied to queued messages across restarts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeliveryGuarantee {
+    /// Queued messages are dropped when the host shuts down.
+    #[default]
+    AtMostOnce,
+    /// Queued messages are persisted on shutdown and restored on startup.
+    AtLeastOnce,
+    /// As `AtLeastOnce`, and delivered messages are kept and retried until
+    /// the target acknowledges them.
+    Acknowledged,
+}
+
+impl DeliveryGuarantee {
+    /// Returns true if queued messages survive restarts.
+    pub fn is_durable(self) -> bool {
+        self != DeliveryGuarantee::AtMostOnce
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_at_most_once_is_volatile() {
+        assert_eq!(DeliveryGuarantee::default(), DeliveryGuarantee::AtMostOnce);
+        assert!(!DeliveryGuarantee::AtMostOnce.is_durable());
+        assert!(DeliveryGuarantee::AtLeastOnce.is_durable());
+        assert!(DeliveryGuarantee::Acknowledged.is_durable());
+    }
+}
@@ -24,8 +24,12 @@
 //! # Submodules
 //!
 //! - [`correlation`] - `CorrelationId` type for request-response tracking
+//! - [`discovery`] - `Endpoint`, `Resolution` and the `DiscoveryBackend` trait
 //! - [`errors`] - `MessagingError` enum (co-located with messaging)
+//! - [`guarantee`] - `DeliveryGuarantee` for queued messages
+//! - [`stream`] - `StreamChunk` wire format for streamed responses and transfers
 //! - [`traits`] - `MessageRouter` and `CorrelationTracker` traits
+//! - [`transport`] - `RemoteTransport`, `TransportSecurity` and the `RemoteEnvelope` wire type
 //!
 //! # Usage
 //!
@@ -43,8 +47,12 @@
 
 // Module declarations (per PROJECTS_STANDARD.md §4.3)
 pub mod correlation;
+pub mod discovery;
 pub mod errors;
+pub mod guarantee;
+pub mod stream;
 pub mod traits;
+pub mod transport;
 
 // NOTE: No glob re-exports per module grouping policy.
 // Callers use namespaced access: core::messaging::correlation::CorrelationId
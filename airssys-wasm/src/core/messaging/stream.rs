@@ -0,0 +1,270 @@
+//! Streamed payload types.
+//!
+//! A payload too large for one message travels as an ordered sequence of
+//! [`StreamChunk`]s. Two kinds of stream exist (see [`StreamKind`]):
+//!
+//! - a **response** stream answers a request instead of returning a single
+//!   payload; chunks reach the requester as `handle-callback` messages
+//! - a **transfer** stream is opened by the sender itself (e.g. to ship a
+//!   file); chunks reach the target as `handle-message` messages
+//!
+//! Either way, each chunk message carries:
+//!
+//! - `metadata.correlation_id`: the request's correlation ID, or the ID
+//!   assigned to the transfer when it was opened
+//! - `metadata.content_type`: [`STREAM_CHUNK_CONTENT_TYPE`]
+//! - a payload starting with an 11-byte header (magic, flags, sequence)
+//!   followed by the chunk data
+//!
+//! Sequences start at 0 and increase by one per chunk. The final chunk
+//! has `end_of_stream` set and carries no data.
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+// (none)
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+// (none)
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use super::errors::MessagingError;
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::{ComponentMessage, MessageMetadata, MessagePayload};
+
+/// Content type marking a callback message as a stream chunk.
+pub const STREAM_CHUNK_CONTENT_TYPE: &str = "application/vnd.airssys.stream-chunk";
+
+/// Opaque handle identifying an open response stream on the producer side.
+pub type StreamHandle = u64;
+
+/// Magic bytes opening every encoded chunk.
+const CHUNK_MAGIC: [u8; 2] = *b"SC";
+
+/// Magic (2) + flags (1) + sequence (8).
+const HEADER_LEN: usize = 11;
+
+/// Flag bit set on the end-of-stream marker.
+const FLAG_END: u8 = 0x01;
+
+/// Default size of the data carried by one chunk of a transfer, in bytes.
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// How chunks of a stream are delivered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StreamKind {
+    /// Answers a request; chunks go to the requester's `handle-callback`.
+    Response,
+    /// Opened by the sender; chunks go to the target's `handle-message`.
+    Transfer,
+}
+
+/// One piece of a streamed response.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::component::message::MessagePayload;
+/// use airssys_wasm::core::messaging::stream::StreamChunk;
+///
+/// let producer = ComponentId::new("app", "query", "v1");
+/// let chunk = StreamChunk::data("corr-1", 0, MessagePayload::new(b"rows".to_vec()));
+///
+/// let message = chunk.clone().into_message(producer);
+/// let decoded = StreamChunk::from_message(&message).unwrap().unwrap();
+/// assert_eq!(decoded, chunk);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamChunk {
+    /// Correlation ID of the request being answered.
+    pub correlation_id: String,
+    /// Position of this chunk in the stream, starting at 0.
+    pub sequence: u64,
+    /// Chunk data (empty for the end-of-stream marker).
+    pub payload: MessagePayload,
+    /// Whether this is the last chunk of the stream.
+    pub end_of_stream: bool,
+}
+
+impl StreamChunk {
+    /// Creates a data chunk.
+    pub fn data(correlation_id: impl Into<String>, sequence: u64, payload: MessagePayload) -> Self {
+        Self {
+            correlation_id: correlation_id.into(),
+            sequence,
+            payload,
+            end_of_stream: false,
+        }
+    }
+
+    /// Creates the end-of-stream marker.
+    pub fn end(correlation_id: impl Into<String>, sequence: u64) -> Self {
+        Self {
+            correlation_id: correlation_id.into(),
+            sequence,
+            payload: MessagePayload::new(Vec::new()),
+            end_of_stream: true,
+        }
+    }
+
+    /// Splits `payload` into data chunks of at most `chunk_size` bytes
+    /// followed by the end-of-stream marker.
+    ///
+    /// An empty payload yields only the marker; a `chunk_size` of 0 is
+    /// treated as 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use airssys_wasm::core::messaging::stream::StreamChunk;
+    ///
+    /// let chunks = StreamChunk::split("xfer-1", b"hello", 2);
+    /// assert_eq!(chunks.len(), 4);
+    /// assert_eq!(chunks[2].payload.as_bytes(), b"o");
+    /// assert!(chunks[3].end_of_stream);
+    /// ```
+    pub fn split(
+        correlation_id: impl Into<String>,
+        payload: &[u8],
+        chunk_size: usize,
+    ) -> Vec<Self> {
+        let correlation_id = correlation_id.into();
+        let mut chunks: Vec<Self> = payload
+            .chunks(chunk_size.max(1))
+            .enumerate()
+            .map(|(sequence, data)| {
+                Self::data(
+                    correlation_id.clone(),
+                    sequence as u64,
+                    MessagePayload::new(data.to_vec()),
+                )
+            })
+            .collect();
+        chunks.push(Self::end(correlation_id, chunks.len() as u64));
+        chunks
+    }
+
+    /// Wraps the chunk in a message sent by `producer`.
+    pub fn into_message(self, producer: ComponentId) -> ComponentMessage {
+        let flags = if self.end_of_stream { FLAG_END } else { 0 };
+        let data = self.payload.into_bytes();
+        let mut bytes = Vec::with_capacity(HEADER_LEN + data.len());
+        bytes.extend_from_slice(&CHUNK_MAGIC);
+        bytes.push(flags);
+        bytes.extend_from_slice(&self.sequence.to_be_bytes());
+        bytes.extend_from_slice(&data);
+
+        let metadata = MessageMetadata {
+            correlation_id: Some(self.correlation_id),
+            reply_to: Some(producer.clone()),
+            content_type: Some(STREAM_CHUNK_CONTENT_TYPE.to_string()),
+            idempotency_key: None,
+            partition_key: None,
+            ..MessageMetadata::default()
+        };
+        ComponentMessage::new(producer, MessagePayload::new(bytes), metadata)
+    }
+
+    /// Extracts a chunk from a callback or transfer message.
+    ///
+    /// Returns `None` if the message is not a stream chunk (its content type
+    /// is not [`STREAM_CHUNK_CONTENT_TYPE`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::InvalidMessage` if the message is marked as a
+    /// chunk but has no correlation ID or a malformed header.
+    pub fn from_message(message: &ComponentMessage) -> Option<Result<Self, MessagingError>> {
+        if message.metadata.content_type.as_deref() != Some(STREAM_CHUNK_CONTENT_TYPE) {
+            return None;
+        }
+        Some(Self::decode(message))
+    }
+
+    fn decode(message: &ComponentMessage) -> Result<Self, MessagingError> {
+        let correlation_id = message.metadata.correlation_id.clone().ok_or_else(|| {
+            MessagingError::InvalidMessage("stream chunk without correlation ID".to_string())
+        })?;
+        let bytes = message.payload.as_bytes();
+        if bytes.len() < HEADER_LEN || bytes[..2] != CHUNK_MAGIC {
+            return Err(MessagingError::InvalidMessage(
+                "malformed stream chunk header".to_string(),
+            ));
+        }
+        let mut sequence = [0u8; 8];
+        sequence.copy_from_slice(&bytes[3..HEADER_LEN]);
+        Ok(Self {
+            correlation_id,
+            sequence: u64::from_be_bytes(sequence),
+            payload: MessagePayload::new(bytes[HEADER_LEN..].to_vec()),
+            end_of_stream: bytes[2] & FLAG_END != 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn producer() -> ComponentId {
+        ComponentId::new("app", "producer", "v1")
+    }
+
+    #[test]
+    fn test_end_marker_roundtrip() {
+        let message = StreamChunk::end("corr-9", 4).into_message(producer());
+        assert_eq!(message.metadata.reply_to, Some(producer()));
+
+        let chunk = StreamChunk::from_message(&message).unwrap().unwrap();
+        assert!(chunk.end_of_stream);
+        assert_eq!(chunk.sequence, 4);
+        assert!(chunk.payload.is_empty());
+    }
+
+    #[test]
+    fn test_split_roundtrips_in_order() {
+        let data: Vec<u8> = (0..=255).collect();
+        let chunks = StreamChunk::split("xfer-1", &data, 100);
+        assert_eq!(
+            chunks.iter().map(|c| c.payload.len()).collect::<Vec<_>>(),
+            vec![100, 100, 56, 0]
+        );
+
+        let mut reassembled = Vec::new();
+        for (expected, chunk) in chunks.into_iter().enumerate() {
+            let message = chunk.into_message(producer());
+            let decoded = StreamChunk::from_message(&message).unwrap().unwrap();
+            assert_eq!(decoded.sequence, expected as u64);
+            reassembled.extend_from_slice(decoded.payload.as_bytes());
+        }
+        assert_eq!(reassembled, data);
+
+        let empty = StreamChunk::split("xfer-2", &[], DEFAULT_CHUNK_SIZE);
+        assert_eq!(empty, vec![StreamChunk::end("xfer-2", 0)]);
+    }
+
+    #[test]
+    fn test_plain_message_is_not_a_chunk() {
+        let message = ComponentMessage::new(
+            producer(),
+            MessagePayload::new(vec![1, 2, 3]),
+            MessageMetadata::default(),
+        );
+        assert!(StreamChunk::from_message(&message).is_none());
+    }
+
+    #[test]
+    fn test_malformed_chunk_is_rejected() {
+        let metadata = MessageMetadata {
+            correlation_id: Some("corr-1".to_string()),
+            content_type: Some(STREAM_CHUNK_CONTENT_TYPE.to_string()),
+            idempotency_key: None,
+            partition_key: None,
+            ..MessageMetadata::default()
+        };
+        let message = ComponentMessage::new(producer(), MessagePayload::new(vec![0; 4]), metadata);
+        assert!(matches!(
+            StreamChunk::from_message(&message),
+            Some(Err(MessagingError::InvalidMessage(_)))
+        ));
+    }
+}
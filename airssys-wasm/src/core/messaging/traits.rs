@@ -13,8 +13,9 @@
 // Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
 use super::correlation::CorrelationId;
 use super::errors::MessagingError;
+use super::stream::StreamHandle;
 use crate::core::component::id::ComponentId;
-use crate::core::component::message::MessagePayload;
+use crate::core::component::message::{ComponentMessage, MessagePayload};
 
 /// Trait for message routing between components.
 ///
@@ -139,6 +140,99 @@ pub trait MessageRouter: Send + Sync {
     ///
     /// - `MessagingError::DeliveryFailed` - Request not found or already completed
     fn cancel_request(&self, correlation_id: &CorrelationId) -> Result<(), MessagingError>;
+
+    /// Opens a stream answering request `correlation_id` on behalf of
+    /// `producer`, instead of returning a single response.
+    ///
+    /// Chunks pushed to the stream reach the requester as ordered
+    /// `handle_callback` messages (see
+    /// [`StreamChunk`](super::stream::StreamChunk)).
+    ///
+    /// The default implementation reports streaming as unsupported.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::DeliveryFailed` - Streaming unsupported or request unknown
+    fn open_stream(
+        &self,
+        producer: &ComponentId,
+        correlation_id: &CorrelationId,
+    ) -> Result<StreamHandle, MessagingError> {
+        let _ = (producer, correlation_id);
+        Err(streaming_unsupported())
+    }
+
+    /// Opens a transfer stream from `producer` to `target`, for sending a
+    /// payload too large for one message as ordered chunks.
+    ///
+    /// Returns the stream handle and the correlation ID every chunk
+    /// carries; the target acknowledges consumed chunks with
+    /// [`ack_stream`](Self::ack_stream) under that ID. Chunks are pushed
+    /// and the stream closed exactly as for a response stream.
+    ///
+    /// The default implementation reports streaming as unsupported.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::TargetNotFound` - Target component is not registered
+    /// - `MessagingError::DeliveryFailed` - Streaming unsupported
+    fn open_transfer(
+        &self,
+        producer: &ComponentId,
+        target: &ComponentId,
+    ) -> Result<(StreamHandle, CorrelationId), MessagingError> {
+        let _ = (producer, target);
+        Err(streaming_unsupported())
+    }
+
+    /// Pushes the next chunk of an open stream.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::QueueFull` - Flow-control window exhausted; retry
+    ///   after the receiver acknowledges
+    /// - `MessagingError::InvalidMessage` - Stream is not open
+    /// - `MessagingError::DeliveryFailed` - Streaming unsupported
+    fn push_chunk(
+        &self,
+        stream: StreamHandle,
+        chunk: MessagePayload,
+    ) -> Result<(), MessagingError> {
+        let _ = (stream, chunk);
+        Err(streaming_unsupported())
+    }
+
+    /// Closes an open stream, delivering the end-of-stream marker.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` - Stream is not open
+    /// - `MessagingError::DeliveryFailed` - Streaming unsupported
+    fn close_stream(&self, stream: StreamHandle) -> Result<(), MessagingError> {
+        let _ = stream;
+        Err(streaming_unsupported())
+    }
+
+    /// Acknowledges `received` chunks of the stream identified by
+    /// `correlation_id` (a response to one of the caller's requests, or a
+    /// transfer sent to it), granting the producer more flow-control credit.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` - No open stream for the request
+    /// - `MessagingError::DeliveryFailed` - Streaming unsupported
+    fn ack_stream(
+        &self,
+        correlation_id: &CorrelationId,
+        received: u64,
+    ) -> Result<(), MessagingError> {
+        let _ = (correlation_id, received);
+        Err(streaming_unsupported())
+    }
+}
+
+fn streaming_unsupported() -> MessagingError {
+    MessagingError::DeliveryFailed("streaming not supported by this router".to_string())
 }
 
 /// Trait for tracking request-response correlations.
@@ -403,6 +497,54 @@ pub trait CorrelationManager: Send + Sync {
     fn is_pending(&self, id: &str) -> impl std::future::Future<Output = bool> + Send;
 }
 
+/// Trait for reporting the outcome of acknowledged deliveries.
+///
+/// With acknowledged delivery the host keeps a message until the target's
+/// `handle-message` returns `Ok`. The component wrapper (Layer 3A) reports
+/// each outcome through this trait; the implementation (the message spool
+/// in `messaging/`) releases acknowledged messages and schedules failed
+/// ones for redelivery.
+///
+/// Messages are identified by their `MessageMetadata::idempotency_key`.
+/// Unknown keys are ignored, so outcomes of messages that were not sent
+/// with acknowledged delivery can be reported unconditionally.
+///
+/// # Thread Safety
+///
+/// Implementations must be `Send + Sync`; every wrapper of a host shares
+/// one acknowledger.
+pub trait DeliveryAcknowledger: Send + Sync {
+    /// `target` handled the message successfully.
+    fn ack(&self, target: &ComponentId, idempotency_key: &str);
+
+    /// `target` failed to handle the message (trap, timeout, or error).
+    fn nack(&self, target: &ComponentId, idempotency_key: &str, error: &str);
+}
+
+/// Trait for deciding whether a message may join a component's queue.
+///
+/// The subscriber (Layer 3B) asks before pushing a message into a
+/// target's mailbox; the implementation (the load shedder in
+/// `component/`) tracks how deep each queue is and refuses messages that
+/// would miss their `MessageMetadata::deadline_ms` anyway.
+///
+/// # Thread Safety
+///
+/// Implementations must be `Send + Sync`; one instance serves every
+/// delivery of a host.
+pub trait QueueAdmission: Send + Sync {
+    /// Admits `message` to `target`'s queue.
+    ///
+    /// # Errors
+    ///
+    /// `MessagingError::Shed` if the message was dead-lettered instead.
+    fn admit(&self, target: &ComponentId, message: &ComponentMessage)
+        -> Result<(), MessagingError>;
+
+    /// An admitted message could not be enqueued after all.
+    fn withdraw(&self, target: &ComponentId);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -507,6 +649,25 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_message_router_streaming_unsupported_by_default() {
+        let router = MockMessageRouter;
+        let producer = ComponentId::new("app", "service", "001");
+        let correlation_id = CorrelationId::new("stream-me");
+
+        assert!(matches!(
+            router.open_stream(&producer, &correlation_id),
+            Err(MessagingError::DeliveryFailed(_))
+        ));
+        assert!(matches!(
+            router.open_transfer(&producer, &ComponentId::new("app", "sink", "001")),
+            Err(MessagingError::DeliveryFailed(_))
+        ));
+        assert!(router.push_chunk(1, MessagePayload::new(vec![1])).is_err());
+        assert!(router.close_stream(1).is_err());
+        assert!(router.ack_stream(&correlation_id, 1).is_err());
+    }
+
     #[test]
     fn test_correlation_tracker_register() {
         let tracker = MockCorrelationTracker::new();
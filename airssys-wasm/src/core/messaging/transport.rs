@@ -0,0 +1,109 @@
+//! Cross-host transport abstractions for federated messaging.
+//!
+//! When [`DiscoveryService`](crate::messaging::discovery::DiscoveryService)
+//! resolves a component to a [`RemoteEndpoint`], the router hands the
+//! envelope to a [`RemoteTransport`], which delivers it to the peer host.
+//! Connection security is a separate concern: a [`TransportSecurity`]
+//! wraps each TCP stream (for example in TLS) before any frame is sent, so
+//! transports stay independent of the TLS implementation.
+//!
+//! The TCP transport and listener live in `messaging/transport` (Layer 3B).
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+use serde::{Deserialize, Serialize};
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::ComponentMessage;
+use crate::core::messaging::discovery::RemoteEndpoint;
+use crate::core::messaging::errors::MessagingError;
+
+/// A message in transit to a component on another host.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteEnvelope {
+    /// Component the message is addressed to.
+    pub target: ComponentId,
+    /// The message, as built by the sending host's router.
+    pub message: ComponentMessage,
+}
+
+/// Delivers envelopes to peer hosts.
+///
+/// `forward` returns once the peer has accepted the envelope for local
+/// delivery; errors mean the envelope may not have arrived and the caller
+/// may try another endpoint.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Mutex;
+///
+/// use airssys_wasm::core::messaging::discovery::RemoteEndpoint;
+/// use airssys_wasm::core::messaging::errors::MessagingError;
+/// use airssys_wasm::core::messaging::transport::{RemoteEnvelope, RemoteTransport};
+///
+/// #[derive(Default)]
+/// struct Recording(Mutex<Vec<String>>);
+///
+/// impl RemoteTransport for Recording {
+///     fn forward(
+///         &self,
+///         endpoint: &RemoteEndpoint,
+///         _envelope: &RemoteEnvelope,
+///     ) -> Result<(), MessagingError> {
+///         self.0.lock().unwrap().push(endpoint.address.clone());
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait RemoteTransport: Send + Sync {
+    /// Delivers `envelope` to the host at `endpoint`.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::DeliveryFailed` if the peer cannot be reached or
+    ///   the connection fails mid-delivery
+    /// - Any error the peer reports for its local delivery
+    fn forward(
+        &self,
+        endpoint: &RemoteEndpoint,
+        envelope: &RemoteEnvelope,
+    ) -> Result<(), MessagingError>;
+}
+
+/// A byte stream between two hosts, possibly encrypted.
+pub trait Connection: Read + Write + Send {}
+
+impl<T: Read + Write + Send> Connection for T {}
+
+/// Secures transport connections, e.g. with TLS.
+///
+/// `connect` runs on the dialing host and should authenticate the peer
+/// named by the endpoint; `accept` runs on the listening host. Both return
+/// the stream frames are exchanged over.
+pub trait TransportSecurity: Send + Sync {
+    /// Name reported in diagnostics (e.g. `"tls"`).
+    fn name(&self) -> &str;
+
+    /// Secures an outbound connection to `peer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the handshake fails.
+    fn connect(
+        &self,
+        stream: TcpStream,
+        peer: &RemoteEndpoint,
+    ) -> Result<Box<dyn Connection>, MessagingError>;
+
+    /// Secures an inbound connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the handshake fails.
+    fn accept(&self, stream: TcpStream) -> Result<Box<dyn Connection>, MessagingError>;
+}
@@ -7,9 +7,12 @@
 //!
 //! # Submodules
 //!
+//! - [`bridge`] - OS bridge abstractions (OsBridge, OsOperation, CapabilityMapping, BridgeError)
 //! - [`component`] - Component-related types (ComponentId, ComponentHandle, ComponentMessage, ComponentLifecycle)
 //! - [`config`] - Configuration types (ComponentConfig, ConfigValidationError)
+//! - [`management`] - Host event log (HostEventLog, HostEvent, HostState, EventLogError)
 //! - [`messaging`] - Messaging abstractions (MessageRouter, CorrelationTracker, CorrelationId, MessagingError)
+//! - [`multicodec`] - Payload codec identifiers and transcoding (Codec, PayloadTranscoder, CodecError)
 //! - [`runtime`] - WASM runtime abstractions (RuntimeEngine, ComponentLoader, ResourceLimits)
 //! - [`security`] - Security abstractions (SecurityValidator, Capability, SecurityError)
 //! - [`storage`] - Storage abstractions (ComponentStorage, StorageValue, StorageError)
@@ -25,6 +28,7 @@
 //! use airssys_wasm::core::component::id::ComponentId;
 //! use airssys_wasm::core::component::message::ComponentMessage;
 //! use airssys_wasm::core::config::component::ComponentConfig;
+//! use airssys_wasm::core::management::log::HostEventLog;
 //! use airssys_wasm::core::messaging::correlation::CorrelationId;
 //! use airssys_wasm::core::runtime::limits::ResourceLimits;
 //! use airssys_wasm::core::security::capability::Capability;
@@ -32,9 +36,12 @@
 //! ```
 
 // Module declarations (per PROJECTS_STANDARD.md §4.3)
+pub mod bridge;
 pub mod component;
 pub mod config;
+pub mod management;
 pub mod messaging;
+pub mod multicodec;
 pub mod runtime;
 pub mod security;
 pub mod storage;
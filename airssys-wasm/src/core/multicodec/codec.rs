@@ -0,0 +1,258 @@
+//! Codec identifiers following the multicodec table.
+//!
+//! A [`Codec`] names the serialization format of a payload. Payloads may be
+//! tagged with the codec's unsigned-varint code so receivers can identify
+//! the format without out-of-band metadata.
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+use std::fmt;
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+use serde::{Deserialize, Serialize};
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use super::errors::CodecError;
+
+/// Multicodec tag of a zstd-compressed payload.
+///
+/// Taken from the multicodec private-use range; the tag wraps a payload of
+/// any [`Codec`] and is only meaningful between airssys hosts.
+pub const ZSTD_CODE: u64 = 0x30_0001;
+
+/// Payload serialization format.
+///
+/// Codes are taken from the multicodec table so tagged payloads remain
+/// interoperable with other multiformats-aware tooling.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::multicodec::codec::Codec;
+///
+/// assert_eq!(Codec::Cbor.code(), 0x51);
+/// assert_eq!(Codec::from_content_type("application/json"), Some(Codec::Json));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Codec {
+    /// Opaque bytes with no structural interpretation.
+    Raw,
+    /// UTF-8 JSON document.
+    Json,
+    /// Concise Binary Object Representation (RFC 8949).
+    Cbor,
+}
+
+impl Codec {
+    /// Returns the multicodec code for this codec.
+    pub fn code(&self) -> u64 {
+        match self {
+            Codec::Raw => 0x55,
+            Codec::Json => 0x0200,
+            Codec::Cbor => 0x51,
+        }
+    }
+
+    /// Returns the canonical multicodec name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Codec::Raw => "raw",
+            Codec::Json => "json",
+            Codec::Cbor => "cbor",
+        }
+    }
+
+    /// Returns the MIME content type carried in `MessageMetadata::content_type`.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Codec::Raw => "application/octet-stream",
+            Codec::Json => "application/json",
+            Codec::Cbor => "application/cbor",
+        }
+    }
+
+    /// Looks up a codec by its multicodec code.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CodecError::UnknownCode` if the code is not supported.
+    pub fn from_code(code: u64) -> Result<Self, CodecError> {
+        match code {
+            0x55 => Ok(Codec::Raw),
+            0x0200 => Ok(Codec::Json),
+            0x51 => Ok(Codec::Cbor),
+            other => Err(CodecError::UnknownCode(other)),
+        }
+    }
+
+    /// Looks up a codec by MIME content type, ignoring parameters such as
+    /// `; charset=utf-8`.
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type.split(';').next().unwrap_or_default().trim();
+        [Codec::Raw, Codec::Json, Codec::Cbor]
+            .into_iter()
+            .find(|codec| codec.content_type().eq_ignore_ascii_case(mime))
+    }
+
+    /// Returns `data` prefixed with this codec's unsigned-varint code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use airssys_wasm::core::multicodec::codec::Codec;
+    ///
+    /// assert_eq!(Codec::Cbor.prefix(&[0xa0]), vec![0x51, 0xa0]);
+    /// ```
+    pub fn prefix(&self, data: &[u8]) -> Vec<u8> {
+        prefix_code(self.code(), data)
+    }
+
+    /// Splits a tagged payload into its codec and body.
+    ///
+    /// # Errors
+    ///
+    /// - `CodecError::MalformedPrefix` - Varint is truncated or overflows
+    /// - `CodecError::UnknownCode` - Code is not a supported codec
+    pub fn strip_prefix(data: &[u8]) -> Result<(Self, &[u8]), CodecError> {
+        let (code, body) = split_code(data)?;
+        Ok((Self::from_code(code)?, body))
+    }
+}
+
+/// Returns `data` prefixed with `code` as an unsigned varint.
+///
+/// Used for multicodec tags that are not a [`Codec`], such as compression
+/// wrappers.
+pub fn prefix_code(code: u64, data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() + 3);
+    let mut code = code;
+    loop {
+        let byte = (code & 0x7f) as u8;
+        code >>= 7;
+        if code == 0 {
+            out.push(byte);
+            break;
+        }
+        out.push(byte | 0x80);
+    }
+    out.extend_from_slice(data);
+    out
+}
+
+/// Splits an unsigned-varint code from the front of `data`.
+///
+/// # Errors
+///
+/// Returns `CodecError::MalformedPrefix` if the varint is truncated or
+/// overflows.
+pub fn split_code(data: &[u8]) -> Result<(u64, &[u8]), CodecError> {
+    let mut code: u64 = 0;
+    for (index, byte) in data.iter().enumerate() {
+        // u64 varints never exceed 10 bytes
+        if index >= 10 {
+            return Err(CodecError::MalformedPrefix);
+        }
+        code |= u64::from(byte & 0x7f) << (7 * index);
+        if byte & 0x80 == 0 {
+            return Ok((code, &data[index + 1..]));
+        }
+    }
+    Err(CodecError::MalformedPrefix)
+}
+
+impl fmt::Display for Codec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for Codec {
+    type Err = CodecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "raw" => Ok(Codec::Raw),
+            "json" => Ok(Codec::Json),
+            "cbor" => Ok(Codec::Cbor),
+            _ => Self::from_content_type(s).ok_or_else(|| CodecError::UnknownName(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_match_multicodec_table() {
+        assert_eq!(Codec::Raw.code(), 0x55);
+        assert_eq!(Codec::Json.code(), 0x0200);
+        assert_eq!(Codec::Cbor.code(), 0x51);
+    }
+
+    #[test]
+    fn test_from_code_roundtrip() {
+        for codec in [Codec::Raw, Codec::Json, Codec::Cbor] {
+            assert_eq!(Codec::from_code(codec.code()), Ok(codec));
+        }
+        assert_eq!(
+            Codec::from_code(0x1234),
+            Err(CodecError::UnknownCode(0x1234))
+        );
+    }
+
+    #[test]
+    fn test_prefix_single_byte_varint() {
+        assert_eq!(Codec::Raw.prefix(&[1, 2]), vec![0x55, 1, 2]);
+    }
+
+    #[test]
+    fn test_prefix_multi_byte_varint() {
+        // 0x0200 encodes as [0x80, 0x04]
+        assert_eq!(Codec::Json.prefix(b"{}"), vec![0x80, 0x04, b'{', b'}']);
+    }
+
+    #[test]
+    fn test_prefix_code_roundtrip() {
+        let tagged = prefix_code(ZSTD_CODE, b"zz");
+        assert_eq!(split_code(&tagged), Ok((ZSTD_CODE, &b"zz"[..])));
+    }
+
+    #[test]
+    fn test_strip_prefix_roundtrip() {
+        let tagged = Codec::Json.prefix(b"[1]");
+        let (codec, body) = Codec::strip_prefix(&tagged).unwrap();
+        assert_eq!(codec, Codec::Json);
+        assert_eq!(body, b"[1]");
+    }
+
+    #[test]
+    fn test_strip_prefix_truncated() {
+        assert_eq!(
+            Codec::strip_prefix(&[0x80]),
+            Err(CodecError::MalformedPrefix)
+        );
+        assert_eq!(Codec::strip_prefix(&[]), Err(CodecError::MalformedPrefix));
+    }
+
+    #[test]
+    fn test_strip_prefix_overflow() {
+        let data = [0xffu8; 11];
+        assert_eq!(Codec::strip_prefix(&data), Err(CodecError::MalformedPrefix));
+    }
+
+    #[test]
+    fn test_from_content_type_ignores_parameters() {
+        assert_eq!(
+            Codec::from_content_type("application/json; charset=utf-8"),
+            Some(Codec::Json)
+        );
+        assert_eq!(Codec::from_content_type("text/plain"), None);
+    }
+
+    #[test]
+    fn test_from_str_accepts_names_and_mime() {
+        assert_eq!("CBOR".parse::<Codec>(), Ok(Codec::Cbor));
+        assert_eq!("application/octet-stream".parse::<Codec>(), Ok(Codec::Raw));
+        assert!("yaml".parse::<Codec>().is_err());
+    }
+}
@@ -0,0 +1,90 @@
+//! Multicodec error types.
+//!
+//! This module contains error types for codec identification and payload
+//! transcoding. These errors are co-located with the multicodec module per
+//! ADR-WASM-028.
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+// (none needed for this module)
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+use thiserror::Error;
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+// (none - errors have no internal dependencies)
+
+/// Errors raised while identifying or converting payload codecs.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::multicodec::errors::CodecError;
+///
+/// let err = CodecError::UnknownCode(0x9999);
+/// assert!(err.to_string().contains("Unknown multicodec code"));
+/// ```
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum CodecError {
+    /// The multicodec code is not supported by this runtime.
+    #[error("Unknown multicodec code: 0x{0:x}")]
+    UnknownCode(u64),
+
+    /// The codec name is not recognised.
+    #[error("Unknown codec name: {0}")]
+    UnknownName(String),
+
+    /// The varint prefix is truncated or overflows 64 bits.
+    #[error("Malformed multicodec prefix")]
+    MalformedPrefix,
+
+    /// No conversion exists between the two codecs.
+    #[error("Unsupported transcoding: {from} -> {to}")]
+    UnsupportedConversion {
+        /// Source codec name.
+        from: String,
+        /// Target codec name.
+        to: String,
+    },
+
+    /// Payload bytes could not be decoded with the declared codec.
+    #[error("Decode failed: {0}")]
+    DecodeFailed(String),
+
+    /// Payload could not be encoded with the target codec.
+    #[error("Encode failed: {0}")]
+    EncodeFailed(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_code_display() {
+        let err = CodecError::UnknownCode(0x51);
+        assert_eq!(err.to_string(), "Unknown multicodec code: 0x51");
+    }
+
+    #[test]
+    fn test_unsupported_conversion_display() {
+        let err = CodecError::UnsupportedConversion {
+            from: "raw".to_string(),
+            to: "json".to_string(),
+        };
+        assert_eq!(err.to_string(), "Unsupported transcoding: raw -> json");
+    }
+
+    #[test]
+    fn test_malformed_prefix_display() {
+        assert_eq!(
+            CodecError::MalformedPrefix.to_string(),
+            "Malformed multicodec prefix"
+        );
+    }
+
+    #[test]
+    fn test_codec_error_is_send_sync() {
+        fn requires_send_sync<T: Send + Sync>(_val: T) {}
+        requires_send_sync(CodecError::DecodeFailed("x".to_string()));
+    }
+}
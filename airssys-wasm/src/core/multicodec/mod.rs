@@ -0,0 +1,43 @@
+//! Multicodec abstractions for self-describing message payloads.
+//!
+//! This module contains types, traits, and errors for identifying the
+//! serialization format of a payload and converting payloads between
+//! formats when two components advertise different codecs.
+//!
+//! # Architecture
+//!
+//! This module is part of the **core/** foundation (Layer 1). It contains:
+//!
+//! - **Types**: `Codec` (multicodec identifier with varint prefix support)
+//! - **Traits**: `PayloadTranscoder` (abstraction for format conversion)
+//! - **Errors**: `CodecError` (co-located)
+//!
+//! Concrete transcoders live in the `messaging/` module (Layer 3B).
+//!
+//! # Submodules
+//!
+//! - [`codec`] - `Codec` enum, `ZSTD_CODE` and multicodec prefix encoding
+//! - [`errors`] - `CodecError` enum (co-located with multicodec)
+//! - [`traits`] - `PayloadTranscoder` trait
+//!
+//! # Usage
+//!
+//! ```rust
+//! use airssys_wasm::core::multicodec::codec::Codec;
+//! use airssys_wasm::core::multicodec::errors::CodecError;
+//!
+//! let tagged = Codec::Json.prefix(b"{}");
+//! let (codec, body) = Codec::strip_prefix(&tagged).unwrap();
+//! assert_eq!(codec, Codec::Json);
+//! assert_eq!(body, b"{}");
+//!
+//! let error = CodecError::UnknownCode(0xdead);
+//! ```
+
+// Module declarations (per PROJECTS_STANDARD.md §4.3)
+pub mod codec;
+pub mod errors;
+pub mod traits;
+
+// NOTE: No glob re-exports per module grouping policy.
+// Callers use namespaced access: core::multicodec::codec::Codec
@@ -0,0 +1,62 @@
+//! Multicodec trait abstractions.
+//!
+//! This module contains the trait for converting payloads between codecs.
+//! Implementations live in the `messaging/` module (Layer 3B) and are
+//! injected into routers by `system/` (Layer 4).
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+// (none)
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+// (none)
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use super::codec::Codec;
+use super::errors::CodecError;
+
+/// Trait for converting payload bytes from one codec to another.
+///
+/// # Thread Safety
+///
+/// Implementations must be `Send + Sync` so a single transcoder can be
+/// shared across all routers.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::multicodec::codec::Codec;
+/// use airssys_wasm::core::multicodec::errors::CodecError;
+/// use airssys_wasm::core::multicodec::traits::PayloadTranscoder;
+///
+/// struct IdentityOnly;
+///
+/// impl PayloadTranscoder for IdentityOnly {
+///     fn supports(&self, from: Codec, to: Codec) -> bool {
+///         from == to
+///     }
+///
+///     fn transcode(&self, data: &[u8], from: Codec, to: Codec) -> Result<Vec<u8>, CodecError> {
+///         if from == to {
+///             Ok(data.to_vec())
+///         } else {
+///             Err(CodecError::UnsupportedConversion {
+///                 from: from.to_string(),
+///                 to: to.to_string(),
+///             })
+///         }
+///     }
+/// }
+/// ```
+pub trait PayloadTranscoder: Send + Sync {
+    /// Returns `true` if this transcoder can convert `from` into `to`.
+    fn supports(&self, from: Codec, to: Codec) -> bool;
+
+    /// Converts `data` encoded as `from` into an equivalent `to` encoding.
+    ///
+    /// # Errors
+    ///
+    /// - `CodecError::UnsupportedConversion` - No conversion between the codecs
+    /// - `CodecError::DecodeFailed` - `data` is not valid `from` encoding
+    /// - `CodecError::EncodeFailed` - Value cannot be represented as `to`
+    fn transcode(&self, data: &[u8], from: Codec, to: Codec) -> Result<Vec<u8>, CodecError>;
+}
@@ -48,6 +48,10 @@ pub enum WasmError {
     /// Store not initialized.
     #[error("Store not initialized - call initialize() before using")]
     StoreNotInitialized,
+
+    /// Component rejected a config update.
+    #[error("Config rejected: {0}")]
+    ConfigRejected(String),
 }
 
 #[cfg(test)]
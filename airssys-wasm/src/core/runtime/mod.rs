@@ -13,6 +13,8 @@
 //!
 //! - Trait definitions (RuntimeEngine, ComponentLoader)
 //! - Resource constraint types (ResourceLimits)
+//! - Per-invocation accounting (ResourceUsage)
+//! - Captured component state (ComponentSnapshot)
 //! - NO business logic
 //! - NO external dependencies (only std and core/component/)
 //!
@@ -23,6 +25,8 @@
 //! - WASM component loading (ComponentLoader trait)
 //! - WASM component execution (RuntimeEngine trait)
 //! - Resource limits enforcement (ResourceLimits struct)
+//! - Cost reporting for individual calls (ResourceUsage struct)
+//! - Component state that survives restarts (ComponentSnapshot struct)
 //!
 //! # Usage
 //!
@@ -148,7 +152,9 @@
 // Module declarations (per PROJECTS_STANDARD.md §4.3)
 pub mod errors;
 pub mod limits;
+pub mod snapshot;
 pub mod traits;
+pub mod usage;
 
 // NOTE: No type re-exports per PROJECTS_STANDARD.md §4.3.
 // Callers use namespaced access:
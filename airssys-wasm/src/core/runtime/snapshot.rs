@@ -0,0 +1,247 @@
+//! # Component Snapshots
+//!
+//! Captured component state that outlives the instance it was taken from.
+//!
+//! # Types
+//!
+//! - [`ComponentSnapshot`] - State of one component instance at a point in time
+//!
+//! Engines produce snapshots with
+//! [`RuntimeEngine::snapshot`](super::traits::RuntimeEngine::snapshot) and
+//! load them into a fresh instance with
+//! [`RuntimeEngine::restore`](super::traits::RuntimeEngine::restore), so a
+//! stateful component keeps its state across supervised restarts. Snapshots
+//! are persisted in the component's own storage (see
+//! [`ComponentSnapshot::save_to`]) to survive host restarts as well.
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+use serde::{Deserialize, Serialize};
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use super::errors::WasmError;
+use super::limits::ResourceLimits;
+use crate::core::component::id::ComponentId;
+use crate::core::storage::traits::ComponentStorage;
+use crate::core::storage::value::StorageValue;
+
+/// Storage key under which [`ComponentSnapshot::save_to`] keeps the snapshot.
+pub const SNAPSHOT_STORAGE_KEY: &str = "__airssys/snapshot";
+
+/// Leading bytes of an encoded snapshot.
+const SNAPSHOT_MAGIC: &[u8; 4] = b"AWSN";
+
+/// Encoding version written by [`ComponentSnapshot::to_bytes`].
+const SNAPSHOT_VERSION: u8 = 1;
+
+/// State of one component instance at a point in time.
+///
+/// `state` is opaque to the host: the guest produces it from its linear
+/// memory and is the only party that interprets it on restore.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::runtime::limits::ResourceLimits;
+/// use airssys_wasm::core::runtime::snapshot::ComponentSnapshot;
+///
+/// let id = ComponentId::new("acme", "counter", "v1");
+/// let snapshot = ComponentSnapshot::new(id, 7u64.to_le_bytes().to_vec(), 65_536);
+/// snapshot.check_limits(&ResourceLimits::default()).unwrap();
+///
+/// let decoded = ComponentSnapshot::from_bytes(&snapshot.to_bytes()).unwrap();
+/// assert_eq!(decoded, snapshot);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentSnapshot {
+    /// Component the snapshot was taken from.
+    pub component_id: ComponentId,
+
+    /// Guest-serialized state.
+    #[serde(skip)]
+    pub state: Vec<u8>,
+
+    /// Linear memory committed by the instance when the snapshot was taken.
+    pub memory_bytes: u64,
+
+    /// When the snapshot was taken, in milliseconds since the Unix epoch.
+    pub taken_at_ms: u64,
+}
+
+impl ComponentSnapshot {
+    /// Creates a snapshot taken now.
+    pub fn new(component_id: ComponentId, state: Vec<u8>, memory_bytes: u64) -> Self {
+        let taken_at_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        Self {
+            component_id,
+            state,
+            memory_bytes,
+            taken_at_ms,
+        }
+    }
+
+    /// Checks the snapshot against a component's memory limit.
+    ///
+    /// Neither the captured state nor the memory it was captured from may
+    /// exceed `limits.max_memory_bytes`: such a snapshot could not be
+    /// restored into an instance running under the same limits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::ResourceLimitExceeded` if either size is too large.
+    pub fn check_limits(&self, limits: &ResourceLimits) -> Result<(), WasmError> {
+        let state_bytes = self.state.len() as u64;
+        if state_bytes > limits.max_memory_bytes || self.memory_bytes > limits.max_memory_bytes {
+            return Err(WasmError::ResourceLimitExceeded(format!(
+                "snapshot of {} ({} bytes state, {} bytes memory) exceeds memory limit of {} bytes",
+                self.component_id, state_bytes, self.memory_bytes, limits.max_memory_bytes
+            )));
+        }
+        Ok(())
+    }
+
+    /// Encodes the snapshot for storage.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        // Serializing a struct of strings and integers cannot fail
+        let header = serde_json::to_vec(self).unwrap_or_default();
+        let mut bytes = Vec::with_capacity(9 + header.len() + self.state.len());
+        bytes.extend_from_slice(SNAPSHOT_MAGIC);
+        bytes.push(SNAPSHOT_VERSION);
+        bytes.extend_from_slice(&(header.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&header);
+        bytes.extend_from_slice(&self.state);
+        bytes
+    }
+
+    /// Decodes a snapshot produced by [`to_bytes`](Self::to_bytes).
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::RuntimeError` if the bytes are not a snapshot or
+    /// use an unknown encoding version.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, WasmError> {
+        let invalid = |reason: &str| WasmError::RuntimeError(format!("Invalid snapshot: {reason}"));
+
+        if bytes.len() < 9 || &bytes[0..4] != SNAPSHOT_MAGIC {
+            return Err(invalid("missing header"));
+        }
+        if bytes[4] != SNAPSHOT_VERSION {
+            return Err(invalid(&format!("unsupported version {}", bytes[4])));
+        }
+        let header_len = u32::from_le_bytes([bytes[5], bytes[6], bytes[7], bytes[8]]) as usize;
+        let header = bytes
+            .get(9..9 + header_len)
+            .ok_or_else(|| invalid("truncated header"))?;
+
+        let mut snapshot: Self =
+            serde_json::from_slice(header).map_err(|e| invalid(&e.to_string()))?;
+        snapshot.state = bytes[9 + header_len..].to_vec();
+        Ok(snapshot)
+    }
+
+    /// Persists the snapshot in the component's storage.
+    ///
+    /// Replaces any snapshot saved earlier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::RuntimeError` if the storage write fails.
+    pub fn save_to<S: ComponentStorage + ?Sized>(&self, storage: &S) -> Result<(), WasmError> {
+        storage
+            .set(SNAPSHOT_STORAGE_KEY, StorageValue::new(self.to_bytes()))
+            .map_err(|e| WasmError::RuntimeError(format!("Failed to save snapshot: {}", e)))
+    }
+
+    /// Loads the snapshot saved in the component's storage, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::RuntimeError` if the storage read fails or the
+    /// stored snapshot cannot be decoded.
+    pub fn load_from<S: ComponentStorage + ?Sized>(storage: &S) -> Result<Option<Self>, WasmError> {
+        let value = storage
+            .get(SNAPSHOT_STORAGE_KEY)
+            .map_err(|e| WasmError::RuntimeError(format!("Failed to load snapshot: {}", e)))?;
+        value.map(|v| Self::from_bytes(v.as_bytes())).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::storage::errors::StorageError;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemoryStorage(Mutex<HashMap<String, StorageValue>>);
+
+    impl ComponentStorage for MemoryStorage {
+        fn get(&self, key: &str) -> Result<Option<StorageValue>, StorageError> {
+            Ok(self.0.lock().unwrap().get(key).cloned())
+        }
+
+        fn set(&self, key: &str, value: StorageValue) -> Result<(), StorageError> {
+            self.0.lock().unwrap().insert(key.to_string(), value);
+            Ok(())
+        }
+
+        fn delete(&self, key: &str) -> Result<(), StorageError> {
+            self.0.lock().unwrap().remove(key);
+            Ok(())
+        }
+
+        fn exists(&self, key: &str) -> Result<bool, StorageError> {
+            Ok(self.0.lock().unwrap().contains_key(key))
+        }
+
+        fn list_keys(&self, _prefix: Option<&str>) -> Result<Vec<String>, StorageError> {
+            Ok(self.0.lock().unwrap().keys().cloned().collect())
+        }
+    }
+
+    fn snapshot(state_len: usize, memory_bytes: u64) -> ComponentSnapshot {
+        let id = ComponentId::new("acme", "counter", "v1");
+        ComponentSnapshot::new(id, vec![0xAB; state_len], memory_bytes)
+    }
+
+    #[test]
+    fn test_check_limits() {
+        let limits = ResourceLimits {
+            max_memory_bytes: 1024,
+            ..ResourceLimits::default()
+        };
+        assert!(snapshot(1024, 1024).check_limits(&limits).is_ok());
+        assert!(matches!(
+            snapshot(1025, 0).check_limits(&limits),
+            Err(WasmError::ResourceLimitExceeded(_))
+        ));
+        assert!(snapshot(0, 2048).check_limits(&limits).is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_foreign_bytes() {
+        let mut bytes = snapshot(4, 0).to_bytes();
+        assert!(ComponentSnapshot::from_bytes(&bytes[..6]).is_err());
+        assert!(ComponentSnapshot::from_bytes(b"\0asm\x01\0\0\0\0").is_err());
+
+        bytes[4] = 99;
+        assert!(ComponentSnapshot::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_storage_round_trip() {
+        let storage = MemoryStorage::default();
+        assert_eq!(ComponentSnapshot::load_from(&storage).unwrap(), None);
+
+        let saved = snapshot(16, 65_536);
+        saved.save_to(&storage).unwrap();
+        assert_eq!(ComponentSnapshot::load_from(&storage).unwrap(), Some(saved));
+    }
+}
@@ -10,6 +10,7 @@
 //!
 //! - [`RuntimeEngine`] - WASM component execution trait
 //! - [`ComponentLoader`] - Component binary loading trait
+//! - [`BlockingRunner`] - Bounded blocking work on a component's behalf
 //!
 //! # Usage
 //!
@@ -17,16 +18,20 @@
 //! and used by higher-level components to execute WASM code.
 
 // Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
-// (none - WasmError handles errors)
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 // Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
 // None needed for this module
 
 // Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
 use super::errors::WasmError;
+use super::snapshot::ComponentSnapshot;
+use super::usage::ResourceUsage;
 use crate::core::component::handle::ComponentHandle;
 use crate::core::component::id::ComponentId;
 use crate::core::component::message::{ComponentMessage, MessagePayload};
+use crate::core::config::values::ConfigValues;
 
 /// Trait for WASM runtime engine abstraction.
 ///
@@ -320,6 +325,174 @@ pub trait RuntimeEngine: Send + Sync {
         handle: &ComponentHandle,
         msg: &ComponentMessage,
     ) -> Result<(), WasmError>;
+
+    /// Call handle-message export and report the resources it consumed.
+    ///
+    /// Behaves like [`call_handle_message`](Self::call_handle_message) but
+    /// also returns a [`ResourceUsage`] for the invocation, so callers can
+    /// budget or route by cost.
+    ///
+    /// The default implementation only measures wall-clock time; engines
+    /// that meter fuel or track linear memory override it to fill in the
+    /// remaining fields.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`call_handle_message`](Self::call_handle_message).
+    fn call_handle_message_with_usage(
+        &self,
+        handle: &ComponentHandle,
+        msg: &ComponentMessage,
+    ) -> Result<(Option<MessagePayload>, ResourceUsage), WasmError> {
+        let started = Instant::now();
+        let reply = self.call_handle_message(handle, msg)?;
+        Ok((reply, ResourceUsage::new(started.elapsed())))
+    }
+
+    /// Call reconfigure export on a component.
+    ///
+    /// Pushes updated `[config]` values to a running component. The guest
+    /// either accepts them or answers `config-rejected`, in which case it
+    /// keeps its previous values.
+    ///
+    /// The default implementation reports the export as missing, so engines
+    /// without live-update support need not implement it.
+    ///
+    /// # Errors
+    ///
+    /// - `WasmError::ComponentNotFound` - Component handle is invalid
+    /// - `WasmError::ExportNotFound` - reconfigure export not found
+    /// - `WasmError::ConfigRejected` - The component rejected the update
+    /// - `WasmError::RuntimeError` - WASM execution error occurred
+    fn call_reconfigure(
+        &self,
+        handle: &ComponentHandle,
+        values: &ConfigValues,
+    ) -> Result<(), WasmError> {
+        let _ = (handle, values);
+        Err(WasmError::ExportNotFound("reconfigure".to_string()))
+    }
+
+    /// Call prepare-shutdown export on a component.
+    ///
+    /// Tells a component it is about to be stopped so it can flush caches,
+    /// persist counters and refuse new work. `deadline` is the time the
+    /// host waits before stopping it regardless. `Ok` means the component
+    /// is ready to stop.
+    ///
+    /// The default implementation reports the export as missing; callers
+    /// treat that as ready.
+    ///
+    /// # Errors
+    ///
+    /// - `WasmError::ComponentNotFound` - Component handle is invalid
+    /// - `WasmError::ExportNotFound` - prepare-shutdown export not found
+    /// - `WasmError::RuntimeError` - The component failed to prepare
+    fn call_prepare_shutdown(
+        &self,
+        handle: &ComponentHandle,
+        deadline: Duration,
+    ) -> Result<(), WasmError> {
+        let _ = (handle, deadline);
+        Err(WasmError::ExportNotFound("prepare-shutdown".to_string()))
+    }
+
+    /// Keeps `count` pre-instantiated instances of a component ready so the
+    /// next `load_component` calls for `id` skip instantiation.
+    ///
+    /// Tops the reserve up to `count` and returns the number of instances
+    /// ready. A `count` of 0 drops the reserve. The default implementation
+    /// does not pool and returns 0.
+    ///
+    /// # Errors
+    ///
+    /// - `WasmError::InstantiationFailed` - The component cannot be
+    ///   compiled or instantiated
+    fn prewarm(&self, id: &ComponentId, bytes: &[u8], count: usize) -> Result<usize, WasmError> {
+        let _ = (id, bytes, count);
+        Ok(0)
+    }
+
+    /// Captures the state of a loaded component.
+    ///
+    /// The default implementation reports the component as not
+    /// snapshottable.
+    ///
+    /// # Errors
+    ///
+    /// - `WasmError::ComponentNotFound` - The handle is not loaded
+    /// - `WasmError::ExportNotFound` - The component does not export its state
+    /// - `WasmError::ResourceLimitExceeded` - The snapshot exceeds the
+    ///   component's memory limit
+    fn snapshot(&self, handle: &ComponentHandle) -> Result<ComponentSnapshot, WasmError> {
+        let _ = handle;
+        Err(WasmError::ExportNotFound("snapshot".to_string()))
+    }
+
+    /// Replaces the state of a loaded component with `snapshot`.
+    ///
+    /// The snapshot must have been taken from the same component ID. The
+    /// default implementation reports the component as not snapshottable.
+    ///
+    /// # Errors
+    ///
+    /// - `WasmError::ComponentNotFound` - The handle is not loaded
+    /// - `WasmError::ExportNotFound` - The component does not accept state
+    /// - `WasmError::ResourceLimitExceeded` - The snapshot exceeds the
+    ///   component's memory limit
+    /// - `WasmError::RuntimeError` - The snapshot belongs to another
+    ///   component or the guest rejected it
+    fn restore(
+        &self,
+        handle: &ComponentHandle,
+        snapshot: &ComponentSnapshot,
+    ) -> Result<(), WasmError> {
+        let _ = (handle, snapshot);
+        Err(WasmError::ExportNotFound("restore".to_string()))
+    }
+
+    /// Applies an approved elevation to instances of `id` created afterwards.
+    ///
+    /// `capability` has the `class:action:pattern` form of elevation
+    /// requests. Engines only act on the classes they enforce themselves
+    /// (e.g. storage grants); everything else is enforced by the
+    /// `SecurityValidator`. The default implementation enforces nothing.
+    ///
+    /// # Errors
+    ///
+    /// - `WasmError::ConfigRejected` - The engine cannot apply the capability
+    fn grant_elevated(&self, id: &ComponentId, capability: &str) -> Result<(), WasmError> {
+        let _ = (id, capability);
+        Ok(())
+    }
+
+    /// Runner bounding the blocking work done on a component's behalf,
+    /// such as refilling its warm instance reserve.
+    ///
+    /// The default returns `None`; callers then use Tokio's shared
+    /// blocking threads.
+    fn blocking_runner(&self) -> Option<Arc<dyn BlockingRunner>> {
+        None
+    }
+}
+
+/// Runs blocking work on a component's behalf off the calling task.
+///
+/// Implemented by the runtime's per-component blocking pools, so layers
+/// that cannot depend on `runtime/` still share each component's bound.
+pub trait BlockingRunner: Send + Sync {
+    /// Starts `work` on a blocking thread for `component` without waiting
+    /// for it to finish.
+    ///
+    /// # Errors
+    ///
+    /// - `WasmError::ResourceLimitExceeded` - The component's share of
+    ///   blocking threads is in use; `work` was not started
+    fn spawn_blocking(
+        &self,
+        component: &ComponentId,
+        work: Box<dyn FnOnce() + Send>,
+    ) -> Result<(), WasmError>;
 }
 
 /// Trait for loading component binaries.
@@ -538,6 +711,24 @@ mod tests {
         assert_eq!(result.unwrap(), None);
     }
 
+    #[test]
+    fn test_default_call_with_usage_reports_duration_only() {
+        let engine = MockRuntimeEngine;
+        let handle = ComponentHandle::new(ComponentId::new("system", "test", "1"), 1);
+        let msg = ComponentMessage::new(
+            ComponentId::new("system", "sender", "1"),
+            MessagePayload::new(vec![1]),
+            Default::default(),
+        );
+
+        let (reply, usage) = engine
+            .call_handle_message_with_usage(&handle, &msg)
+            .unwrap();
+        assert_eq!(reply, None);
+        assert_eq!(usage.fuel_consumed, None);
+        assert_eq!(usage.memory_delta_bytes, 0);
+    }
+
     #[test]
     fn test_runtime_engine_call_handle_callback_succeeds() {
         let engine = MockRuntimeEngine;
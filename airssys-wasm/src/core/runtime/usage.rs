@@ -0,0 +1,99 @@
+//! # Resource Usage
+//!
+//! Per-invocation resource accounting for WASM component calls.
+//!
+//! # Types
+//!
+//! - [`ResourceUsage`] - Fuel, wall-clock time, and memory growth of one call
+//!
+//! Engines report usage through
+//! [`RuntimeEngine::call_handle_message_with_usage`](super::traits::RuntimeEngine::call_handle_message_with_usage),
+//! letting callers budget or route by cost without a separate metrics query.
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+use serde::{Deserialize, Serialize};
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+// None needed for this module
+
+/// Resources consumed by a single component invocation.
+///
+/// # Fields
+///
+/// - `fuel_consumed`: Fuel burned by the call, `None` if the engine does
+///   not meter fuel
+/// - `duration`: Wall-clock time spent inside the engine
+/// - `memory_delta_bytes`: Change in linear memory size caused by the call
+///   (positive when the guest grew its memory)
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use airssys_wasm::core::runtime::usage::ResourceUsage;
+///
+/// let usage = ResourceUsage::new(Duration::from_millis(3))
+///     .with_fuel(1_200)
+///     .with_memory_delta(65_536);
+///
+/// assert_eq!(usage.fuel_consumed, Some(1_200));
+/// assert_eq!(usage.memory_delta_bytes, 65_536);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceUsage {
+    /// Fuel burned by the call, if the engine meters fuel.
+    pub fuel_consumed: Option<u64>,
+    /// Wall-clock time spent inside the engine.
+    pub duration: Duration,
+    /// Change in linear memory size, in bytes.
+    pub memory_delta_bytes: i64,
+}
+
+impl ResourceUsage {
+    /// Creates a usage record with only the elapsed time known.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            fuel_consumed: None,
+            duration,
+            memory_delta_bytes: 0,
+        }
+    }
+
+    /// Sets the fuel consumed by the call.
+    pub fn with_fuel(mut self, fuel: u64) -> Self {
+        self.fuel_consumed = Some(fuel);
+        self
+    }
+
+    /// Sets the memory growth caused by the call.
+    pub fn with_memory_delta(mut self, bytes: i64) -> Self {
+        self.memory_delta_bytes = bytes;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_has_no_fuel_or_memory_delta() {
+        let usage = ResourceUsage::new(Duration::from_micros(5));
+        assert_eq!(usage.fuel_consumed, None);
+        assert_eq!(usage.duration, Duration::from_micros(5));
+        assert_eq!(usage.memory_delta_bytes, 0);
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let usage = ResourceUsage::new(Duration::from_millis(2))
+            .with_fuel(42)
+            .with_memory_delta(-4096);
+        let json = serde_json::to_string(&usage).unwrap();
+        let back: ResourceUsage = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, usage);
+    }
+}
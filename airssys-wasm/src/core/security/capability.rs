@@ -2,7 +2,19 @@
 //!
 //! This module defines the capability-based security model types.
 //! Each capability represents a permission to perform specific actions
-//! on specific resources.
+//! on specific resources. Every host function category has a class:
+//! messaging, storage, filesystem, network, environment variables, clocks,
+//! randomness, and subprocesses, so deny-by-default covers all of them.
+//!
+//! Grant patterns may carve out exclusions, e.g. `/data/* !/data/secrets/*`
+//! (see [`split_exclusions`]).
+//!
+//! The types serialize with serde so granted capability sets can be
+//! distributed in signed capability tokens (see
+//! [`token`](crate::core::security::token)).
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+use serde::{Deserialize, Serialize};
 
 /// Capability types for security validation.
 ///
@@ -18,7 +30,8 @@
 ///     target_pattern: "org.example/*".to_string(),
 /// });
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
 pub enum Capability {
     /// Messaging-related capability.
     Messaging(MessagingCapability),
@@ -28,12 +41,20 @@ pub enum Capability {
     Filesystem(FilesystemCapability),
     /// Network-related capability.
     Network(NetworkCapability),
+    /// Environment variable access.
+    Environment(EnvironmentCapability),
+    /// Clock access.
+    Clock(ClockCapability),
+    /// Random number generation.
+    Random(RandomCapability),
+    /// Subprocess management.
+    Process(ProcessCapability),
 }
 
 // --- Messaging ---
 
 /// Messaging capability specification.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MessagingCapability {
     /// The messaging action permitted.
     pub action: MessagingAction,
@@ -42,7 +63,8 @@ pub struct MessagingCapability {
 }
 
 /// Messaging action types.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum MessagingAction {
     /// Send fire-and-forget messages.
     Send,
@@ -55,7 +77,7 @@ pub enum MessagingAction {
 // --- Storage ---
 
 /// Storage capability specification.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StorageCapability {
     /// The storage action permitted.
     pub action: StorageAction,
@@ -64,7 +86,8 @@ pub struct StorageCapability {
 }
 
 /// Storage action types.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum StorageAction {
     /// Read from storage.
     Read,
@@ -77,7 +100,7 @@ pub enum StorageAction {
 // --- Filesystem ---
 
 /// Filesystem capability specification.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FilesystemCapability {
     /// The filesystem action permitted.
     pub action: FilesystemAction,
@@ -86,7 +109,8 @@ pub struct FilesystemCapability {
 }
 
 /// Filesystem action types.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum FilesystemAction {
     /// Read files.
     Read,
@@ -96,12 +120,14 @@ pub enum FilesystemAction {
     Delete,
     /// List directory contents.
     ListDir,
+    /// Execute a program.
+    Execute,
 }
 
 // --- Network ---
 
 /// Network capability specification.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct NetworkCapability {
     /// The network action permitted.
     pub action: NetworkAction,
@@ -112,7 +138,8 @@ pub struct NetworkCapability {
 }
 
 /// Network action types.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum NetworkAction {
     /// Outbound network connections.
     Outbound,
@@ -120,6 +147,146 @@ pub enum NetworkAction {
     Inbound,
 }
 
+// --- Environment ---
+
+/// Environment variable capability specification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvironmentCapability {
+    /// The environment action permitted.
+    pub action: EnvironmentAction,
+    /// Variable name pattern (glob-style).
+    pub variable_pattern: String,
+}
+
+/// Environment action types.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnvironmentAction {
+    /// Read a variable.
+    Read,
+    /// Set or unset a variable.
+    Write,
+}
+
+// --- Clock ---
+
+/// Clock capability specification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClockCapability {
+    /// The clock to read.
+    pub clock: ClockKind,
+}
+
+/// Clock types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClockKind {
+    /// Wall-clock time (reveals the host's date and time zone).
+    Wall,
+    /// Monotonic time (usable for timing measurements).
+    Monotonic,
+}
+
+impl ClockKind {
+    /// Name matched against clock grant patterns (`"wall"`, `"monotonic"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClockKind::Wall => "wall",
+            ClockKind::Monotonic => "monotonic",
+        }
+    }
+}
+
+// --- Random ---
+
+/// Randomness capability specification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RandomCapability {
+    /// The randomness source to draw from.
+    pub source: RandomSource,
+}
+
+/// Randomness sources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RandomSource {
+    /// Cryptographically secure randomness.
+    Secure,
+    /// Fast, non-cryptographic randomness.
+    Insecure,
+}
+
+impl RandomSource {
+    /// Name matched against randomness grant patterns (`"secure"`,
+    /// `"insecure"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RandomSource::Secure => "secure",
+            RandomSource::Insecure => "insecure",
+        }
+    }
+}
+
+// --- Process ---
+
+/// Process capability specification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessCapability {
+    /// The process action permitted.
+    pub action: ProcessAction,
+    /// Program pattern (glob-style).
+    pub program_pattern: String,
+}
+
+/// Process action types.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessAction {
+    /// Start a subprocess.
+    Spawn,
+    /// Signal or terminate a subprocess.
+    Signal,
+}
+
+// --- Patterns ---
+
+/// Splits a grant pattern into its include pattern and exclusions.
+///
+/// A grant pattern is an include pattern optionally followed by
+/// exclusions, each introduced by ` !` (a space then `!`):
+/// `/data/* !/data/secrets/* !/data/keys.pem`. A target matches the grant
+/// if it matches the include pattern and none of the exclusions.
+///
+/// Only ` !` separates exclusions, so patterns may contain spaces
+/// (`/data/My Files/*`); surrounding whitespace is trimmed from each part.
+/// Returns `None` for malformed patterns (an empty exclusion such as a bare
+/// `!`), which must match nothing.
+///
+/// # Examples
+///
+/// ```
+/// use airssys_wasm::core::security::capability::split_exclusions;
+///
+/// assert_eq!(
+///     split_exclusions("/data/* !/data/secrets/*"),
+///     Some(("/data/*", vec!["/data/secrets/*"]))
+/// );
+/// assert_eq!(split_exclusions("*.example.com"), Some(("*.example.com", vec![])));
+/// assert_eq!(
+///     split_exclusions("/data/My Files/* !/data/My Files/private/*"),
+///     Some(("/data/My Files/*", vec!["/data/My Files/private/*"]))
+/// );
+/// assert_eq!(split_exclusions("/data/* !"), None);
+/// ```
+pub fn split_exclusions(pattern: &str) -> Option<(&str, Vec<&str>)> {
+    let mut parts = pattern.trim().split(" !");
+    let include = parts.next().unwrap_or("").trim_end();
+    let exclusions = parts
+        .map(|part| Some(part.trim()).filter(|rest| !rest.is_empty()))
+        .collect::<Option<Vec<_>>>()?;
+    Some((include, exclusions))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -162,6 +329,14 @@ mod tests {
         assert!(matches!(cap, Capability::Network(_)));
     }
 
+    #[test]
+    fn test_clock_and_random_grant_names() {
+        assert_eq!(ClockKind::Wall.as_str(), "wall");
+        assert_eq!(ClockKind::Monotonic.as_str(), "monotonic");
+        assert_eq!(RandomSource::Secure.as_str(), "secure");
+        assert_eq!(RandomSource::Insecure.as_str(), "insecure");
+    }
+
     // Action enum equality tests
     #[test]
     fn test_messaging_action_equality() {
@@ -1,7 +1,56 @@
 //! Security error types.
 
+use std::fmt;
+
 use thiserror::Error;
 
+/// Structured description of a denied capability check.
+///
+/// Carried by [`SecurityError::Denied`] and surfaced to guests as the WIT
+/// `permission-denied` record, so components can tell which capability was
+/// missing and degrade gracefully instead of parsing an error string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermissionDenial {
+    /// Capability class that was required (e.g. "messaging", "storage", "network").
+    pub capability: String,
+
+    /// Action attempted under that capability (e.g. "send", "read", "connect").
+    pub action: String,
+
+    /// Requested target/namespace/host pattern that matched no grant.
+    pub pattern: String,
+
+    /// Human-readable explanation.
+    pub reason: String,
+}
+
+impl PermissionDenial {
+    /// Creates a denial for `capability`/`action` on `pattern`.
+    pub fn new(
+        capability: impl Into<String>,
+        action: impl Into<String>,
+        pattern: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            capability: capability.into(),
+            action: action.into(),
+            pattern: pattern.into(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for PermissionDenial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} on '{}': {}",
+            self.capability, self.action, self.pattern, self.reason
+        )
+    }
+}
+
 /// Security-related errors for capability validation and policy enforcement.
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
 pub enum SecurityError {
@@ -20,6 +69,20 @@ pub enum SecurityError {
     /// Permission denied for the requested operation.
     #[error("Permission denied: {0}")]
     PermissionDenied(String),
+
+    /// A capability check failed; carries the structured denial details.
+    #[error("Capability denied: {0}")]
+    Denied(PermissionDenial),
+}
+
+impl SecurityError {
+    /// Returns the structured denial details, if this is a capability denial.
+    pub fn denial(&self) -> Option<&PermissionDenial> {
+        match self {
+            Self::Denied(denial) => Some(denial),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -67,6 +130,21 @@ mod tests {
         assert_ne!(err1, err3);
     }
 
+    #[test]
+    fn test_denied_display_and_accessor() {
+        let denial = PermissionDenial::new("storage", "write", "secrets/*", "no matching grant");
+        let err = SecurityError::Denied(denial.clone());
+
+        assert_eq!(
+            err.to_string(),
+            "Capability denied: storage write on 'secrets/*': no matching grant"
+        );
+        assert_eq!(err.denial(), Some(&denial));
+        assert!(SecurityError::PermissionDenied("x".to_string())
+            .denial()
+            .is_none());
+    }
+
     // Gap analysis tests
 
     #[test]
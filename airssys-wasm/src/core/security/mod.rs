@@ -5,6 +5,8 @@
 //! This module provides the foundation types and traits for the security system:
 //! - [`capability`] - Capability types (Messaging, Storage, Filesystem, Network)
 //! - [`errors`] - Security error types
+//! - [`quota`] - Quotas carried by capability grants
+//! - [`token`] - Signed capability tokens distributed with components
 //! - [`traits`] - Security validation and audit logging traits
 //!
 //! # Architecture
@@ -24,6 +26,8 @@
 // Module declarations (per PROJECTS_STANDARD.md §4.3)
 pub mod capability;
 pub mod errors;
+pub mod quota;
+pub mod token;
 pub mod traits;
 
 // NOTE: No glob re-exports (pub use X::*) per module grouping policy.
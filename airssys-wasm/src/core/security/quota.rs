@@ -0,0 +1,187 @@
+//! Quotas carried by capability grants.
+//!
+//! A grant says *what* a component may touch; a [`ResourceQuota`] attached
+//! to it bounds *how much*: bytes read, requests per minute, and
+//! concurrent connections. Quotas apply per [`QuotaClass`] and are
+//! enforced by the host function layer.
+
+use std::fmt;
+
+/// Capability class a quota applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuotaClass {
+    /// Filesystem grants (reads, writes, executes).
+    Filesystem,
+    /// Outbound network grants.
+    Network,
+}
+
+impl QuotaClass {
+    /// Capability class name used in denials ("filesystem", "network").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Filesystem => "filesystem",
+            Self::Network => "network",
+        }
+    }
+}
+
+impl fmt::Display for QuotaClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Usage limits of one capability class. `None` means unlimited.
+///
+/// # Examples
+///
+/// ```
+/// use airssys_wasm::core::security::quota::ResourceQuota;
+///
+/// let quota = ResourceQuota::new()
+///     .with_max_bytes_read(1024 * 1024)
+///     .with_max_requests_per_minute(60);
+/// assert_eq!(quota.max_bytes_read, Some(1024 * 1024));
+/// assert!(quota.max_concurrent_connections.is_none());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResourceQuota {
+    /// Total bytes the component may read.
+    pub max_bytes_read: Option<u64>,
+    /// Operations allowed in any sliding one-minute window.
+    pub max_requests_per_minute: Option<u32>,
+    /// Connections the component may hold open at once.
+    pub max_concurrent_connections: Option<u32>,
+}
+
+impl ResourceQuota {
+    /// Create an unlimited quota.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit the total bytes read.
+    pub fn with_max_bytes_read(mut self, bytes: u64) -> Self {
+        self.max_bytes_read = Some(bytes);
+        self
+    }
+
+    /// Limit operations per minute.
+    pub fn with_max_requests_per_minute(mut self, requests: u32) -> Self {
+        self.max_requests_per_minute = Some(requests);
+        self
+    }
+
+    /// Limit concurrently open connections.
+    pub fn with_max_concurrent_connections(mut self, connections: u32) -> Self {
+        self.max_concurrent_connections = Some(connections);
+        self
+    }
+
+    /// The stricter of each limit of `self` and `other`.
+    pub fn tightest(&self, other: &ResourceQuota) -> ResourceQuota {
+        fn min<T: Ord>(a: Option<T>, b: Option<T>) -> Option<T> {
+            match (a, b) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            }
+        }
+        ResourceQuota {
+            max_bytes_read: min(self.max_bytes_read, other.max_bytes_read),
+            max_requests_per_minute: min(
+                self.max_requests_per_minute,
+                other.max_requests_per_minute,
+            ),
+            max_concurrent_connections: min(
+                self.max_concurrent_connections,
+                other.max_concurrent_connections,
+            ),
+        }
+    }
+
+    /// The looser of each limit of `self` and `other`; a limit missing
+    /// from either side is unlimited.
+    pub fn loosest(&self, other: &ResourceQuota) -> ResourceQuota {
+        fn max<T: Ord>(a: Option<T>, b: Option<T>) -> Option<T> {
+            Some(a?.max(b?))
+        }
+        ResourceQuota {
+            max_bytes_read: max(self.max_bytes_read, other.max_bytes_read),
+            max_requests_per_minute: max(
+                self.max_requests_per_minute,
+                other.max_requests_per_minute,
+            ),
+            max_concurrent_connections: max(
+                self.max_concurrent_connections,
+                other.max_concurrent_connections,
+            ),
+        }
+    }
+
+    /// Whether every limit of `self` is at least as strict as `outer`'s.
+    ///
+    /// Used when delegating: a delegated grant may tighten quotas but
+    /// never lift or loosen them.
+    pub fn is_within(&self, outer: &ResourceQuota) -> bool {
+        fn within<T: Ord>(inner: Option<T>, outer: Option<T>) -> bool {
+            match (inner, outer) {
+                (_, None) => true,
+                (None, Some(_)) => false,
+                (Some(inner), Some(outer)) => inner <= outer,
+            }
+        }
+        within(self.max_bytes_read, outer.max_bytes_read)
+            && within(self.max_requests_per_minute, outer.max_requests_per_minute)
+            && within(
+                self.max_concurrent_connections,
+                outer.max_concurrent_connections,
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tightest_and_loosest_combine_per_limit() {
+        let a = ResourceQuota::new()
+            .with_max_bytes_read(100)
+            .with_max_requests_per_minute(10);
+        let b = ResourceQuota::new()
+            .with_max_bytes_read(50)
+            .with_max_concurrent_connections(4);
+
+        let tight = a.tightest(&b);
+        assert_eq!(tight.max_bytes_read, Some(50));
+        assert_eq!(tight.max_requests_per_minute, Some(10));
+        assert_eq!(tight.max_concurrent_connections, Some(4));
+
+        let loose = a.loosest(&b);
+        assert_eq!(loose.max_bytes_read, Some(100));
+        assert_eq!(loose.max_requests_per_minute, None);
+        assert_eq!(loose.max_concurrent_connections, None);
+    }
+
+    #[test]
+    fn test_is_within_requires_every_limit_to_be_tighter() {
+        let outer = ResourceQuota::new()
+            .with_max_bytes_read(100)
+            .with_max_requests_per_minute(10);
+
+        assert!(ResourceQuota::new()
+            .with_max_bytes_read(50)
+            .with_max_requests_per_minute(10)
+            .with_max_concurrent_connections(1)
+            .is_within(&outer));
+        assert!(!ResourceQuota::new()
+            .with_max_bytes_read(50)
+            .is_within(&outer));
+        assert!(!ResourceQuota::new()
+            .with_max_bytes_read(200)
+            .with_max_requests_per_minute(10)
+            .is_within(&outer));
+        assert!(outer.is_within(&ResourceQuota::new()));
+    }
+}
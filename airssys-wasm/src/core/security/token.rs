@@ -0,0 +1,517 @@
+//! Signed capability tokens.
+//!
+//! A [`CapabilityToken`] records the capabilities an operator granted to one
+//! component, signed with the operator's Ed25519 key. The token is shipped
+//! alongside the component as JSON, verified by the loader at install time,
+//! and compared with the permissions the component declares in the
+//! `[capabilities]` table of its `Component.toml`:
+//!
+//! - capabilities granted but not declared are reported as *undeclared*,
+//! - capabilities declared but not granted are reported as *ungranted*.
+//!
+//! The signature covers the canonical JSON encoding of every field except
+//! the signature itself, so any edit to the grants invalidates the token.
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+// (none needed for this module)
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+use chrono::{DateTime, Utc};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use crate::core::component::id::ComponentId;
+use crate::core::security::capability::{
+    Capability, ClockCapability, ClockKind, EnvironmentAction, EnvironmentCapability,
+    FilesystemAction, FilesystemCapability, MessagingAction, MessagingCapability, NetworkAction,
+    NetworkCapability, ProcessAction, ProcessCapability, RandomCapability, RandomSource,
+    StorageAction, StorageCapability,
+};
+
+/// Current token format version.
+pub const TOKEN_FORMAT_VERSION: u32 = 1;
+
+/// Errors raised while reading, signing, or verifying capability tokens.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum CapabilityTokenError {
+    /// The token is not valid JSON or uses an unknown format version.
+    #[error("Malformed capability token: {0}")]
+    Malformed(String),
+
+    /// The signature is missing or does not match the token contents.
+    #[error("Capability token signature is invalid")]
+    InvalidSignature,
+
+    /// The key bytes do not form a valid Ed25519 public key.
+    #[error("Invalid token key: {0}")]
+    InvalidKey(String),
+
+    /// The token was issued for a different component.
+    #[error("Capability token is for '{actual}', expected '{expected}'")]
+    ComponentMismatch {
+        /// Component being installed.
+        expected: String,
+        /// Component named by the token.
+        actual: String,
+    },
+
+    /// The `Component.toml` could not be parsed.
+    #[error("Invalid manifest: {0}")]
+    InvalidManifest(String),
+}
+
+/// Capabilities granted to a component, signed by the operator.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::security::capability::{Capability, StorageAction, StorageCapability};
+/// use airssys_wasm::core::security::token::CapabilityToken;
+///
+/// let secret = [7u8; 32];
+/// let token = CapabilityToken::new(
+///     ComponentId::new("acme", "reports", "v1"),
+///     "ops@acme",
+///     vec![Capability::Storage(StorageCapability {
+///         action: StorageAction::Read,
+///         namespace_pattern: "reports/*".to_string(),
+///     })],
+/// )
+/// .sign(&secret);
+///
+/// let shipped = token.to_json().unwrap();
+/// let received = CapabilityToken::from_json(&shipped).unwrap();
+/// let public_key = CapabilityToken::public_key(&secret);
+/// assert!(received.verify(&public_key).is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CapabilityToken {
+    /// Format version ([`TOKEN_FORMAT_VERSION`]).
+    pub version: u32,
+    /// Component the capabilities are granted to.
+    pub component: ComponentId,
+    /// Operator that issued the token.
+    pub issuer: String,
+    /// When the token was issued.
+    pub issued_at: DateTime<Utc>,
+    /// Granted capabilities.
+    pub capabilities: Vec<Capability>,
+    /// Hex-encoded Ed25519 signature; empty until signed.
+    #[serde(default)]
+    pub signature: String,
+}
+
+/// Signed portion of a token.
+#[derive(Serialize)]
+struct SignedFields<'a> {
+    version: u32,
+    component: &'a ComponentId,
+    issuer: &'a str,
+    issued_at: &'a DateTime<Utc>,
+    capabilities: &'a [Capability],
+}
+
+impl CapabilityToken {
+    /// Creates an unsigned token issued now.
+    pub fn new(
+        component: ComponentId,
+        issuer: impl Into<String>,
+        capabilities: Vec<Capability>,
+    ) -> Self {
+        Self {
+            version: TOKEN_FORMAT_VERSION,
+            component,
+            issuer: issuer.into(),
+            issued_at: Utc::now(),
+            capabilities,
+            signature: String::new(),
+        }
+    }
+
+    /// Public key matching the operator `secret` key.
+    pub fn public_key(secret: &[u8; 32]) -> [u8; 32] {
+        SigningKey::from_bytes(secret).verifying_key().to_bytes()
+    }
+
+    /// Signs the token with the operator `secret` key.
+    pub fn sign(mut self, secret: &[u8; 32]) -> Self {
+        let signature = SigningKey::from_bytes(secret).sign(&self.signed_bytes());
+        self.signature = signature
+            .to_bytes()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        self
+    }
+
+    /// Checks the signature against the operator `public_key`.
+    ///
+    /// # Errors
+    ///
+    /// - `CapabilityTokenError::InvalidKey` if `public_key` is not a valid key
+    /// - `CapabilityTokenError::InvalidSignature` if the token is unsigned
+    ///   or was modified after signing
+    pub fn verify(&self, public_key: &[u8; 32]) -> Result<(), CapabilityTokenError> {
+        let key = VerifyingKey::from_bytes(public_key)
+            .map_err(|e| CapabilityTokenError::InvalidKey(e.to_string()))?;
+        let bytes =
+            decode_signature(&self.signature).ok_or(CapabilityTokenError::InvalidSignature)?;
+        key.verify(&self.signed_bytes(), &Signature::from_bytes(&bytes))
+            .map_err(|_| CapabilityTokenError::InvalidSignature)
+    }
+
+    /// Checks the signature and that the token was issued for `component`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`verify`](Self::verify), plus
+    /// `CapabilityTokenError::ComponentMismatch`.
+    pub fn verify_for(
+        &self,
+        component: &ComponentId,
+        public_key: &[u8; 32],
+    ) -> Result<(), CapabilityTokenError> {
+        if &self.component != component {
+            return Err(CapabilityTokenError::ComponentMismatch {
+                expected: component.to_string_id(),
+                actual: self.component.to_string_id(),
+            });
+        }
+        self.verify(public_key)
+    }
+
+    /// Encodes the token as JSON.
+    ///
+    /// # Errors
+    ///
+    /// `CapabilityTokenError::Malformed` if encoding fails.
+    pub fn to_json(&self) -> Result<String, CapabilityTokenError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| CapabilityTokenError::Malformed(e.to_string()))
+    }
+
+    /// Decodes a token from JSON.
+    ///
+    /// # Errors
+    ///
+    /// `CapabilityTokenError::Malformed` for invalid JSON or an unsupported
+    /// format version.
+    pub fn from_json(source: &str) -> Result<Self, CapabilityTokenError> {
+        let token: Self = serde_json::from_str(source)
+            .map_err(|e| CapabilityTokenError::Malformed(e.to_string()))?;
+        if token.version != TOKEN_FORMAT_VERSION {
+            return Err(CapabilityTokenError::Malformed(format!(
+                "unsupported token version {}",
+                token.version
+            )));
+        }
+        Ok(token)
+    }
+
+    /// Compares the granted capabilities with those declared in
+    /// `manifest` (a `Component.toml`).
+    ///
+    /// # Errors
+    ///
+    /// `CapabilityTokenError::InvalidManifest` if the manifest cannot be
+    /// parsed.
+    pub fn diff_against_manifest(
+        &self,
+        manifest: &str,
+    ) -> Result<CapabilityDiff, CapabilityTokenError> {
+        let declared = declared_capabilities(manifest)?;
+        Ok(CapabilityDiff {
+            undeclared: self
+                .capabilities
+                .iter()
+                .filter(|cap| !declared.contains(cap))
+                .cloned()
+                .collect(),
+            ungranted: declared
+                .into_iter()
+                .filter(|cap| !self.capabilities.contains(cap))
+                .collect(),
+        })
+    }
+
+    fn signed_bytes(&self) -> Vec<u8> {
+        let fields = SignedFields {
+            version: self.version,
+            component: &self.component,
+            issuer: &self.issuer,
+            issued_at: &self.issued_at,
+            capabilities: &self.capabilities,
+        };
+        // Serializing plain data structures cannot fail
+        serde_json::to_vec(&fields).unwrap_or_default()
+    }
+}
+
+/// Differences between a token's grants and a manifest's declarations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CapabilityDiff {
+    /// Granted by the token but not declared in the manifest.
+    pub undeclared: Vec<Capability>,
+    /// Declared in the manifest but not granted by the token.
+    pub ungranted: Vec<Capability>,
+}
+
+impl CapabilityDiff {
+    /// Whether the token grants exactly what the manifest declares.
+    pub fn is_empty(&self) -> bool {
+        self.undeclared.is_empty() && self.ungranted.is_empty()
+    }
+}
+
+/// Capabilities declared in the `[capabilities]` table of a
+/// `Component.toml`.
+///
+/// `can_receive_from` has no [`Capability`] counterpart and is not
+/// included.
+///
+/// # Errors
+///
+/// `CapabilityTokenError::InvalidManifest` if the manifest is not valid
+/// TOML or a capability list has the wrong type.
+pub fn declared_capabilities(manifest: &str) -> Result<Vec<Capability>, CapabilityTokenError> {
+    let root: toml::Table = toml::from_str(manifest)
+        .map_err(|e| CapabilityTokenError::InvalidManifest(e.to_string()))?;
+    let Some(caps) = root.get("capabilities") else {
+        return Ok(Vec::new());
+    };
+
+    let mut declared = Vec::new();
+    for pattern in patterns(caps, "messaging", "can_send_to")? {
+        declared.push(Capability::Messaging(MessagingCapability {
+            action: MessagingAction::Send,
+            target_pattern: pattern,
+        }));
+    }
+    for (key, action) in [
+        ("can_read_keys", StorageAction::Read),
+        ("can_write_keys", StorageAction::Write),
+    ] {
+        for pattern in patterns(caps, "storage", key)? {
+            declared.push(Capability::Storage(StorageCapability {
+                action: action.clone(),
+                namespace_pattern: pattern,
+            }));
+        }
+    }
+    for (key, action) in [
+        ("can_read_paths", FilesystemAction::Read),
+        ("can_write_paths", FilesystemAction::Write),
+        ("can_execute_paths", FilesystemAction::Execute),
+    ] {
+        for pattern in patterns(caps, "filesystem", key)? {
+            declared.push(Capability::Filesystem(FilesystemCapability {
+                action: action.clone(),
+                path_pattern: pattern,
+            }));
+        }
+    }
+    for pattern in patterns(caps, "network", "can_connect_to")? {
+        declared.push(Capability::Network(NetworkCapability {
+            action: NetworkAction::Outbound,
+            host_pattern: pattern,
+            port: None,
+        }));
+    }
+    let ports = caps
+        .get("network")
+        .and_then(|network| network.get("can_bind_ports"));
+    for port in ports.map(list).transpose()?.into_iter().flatten() {
+        let port = port
+            .as_integer()
+            .and_then(|port| u16::try_from(port).ok())
+            .ok_or_else(|| invalid("network.can_bind_ports", "a port number"))?;
+        declared.push(Capability::Network(NetworkCapability {
+            action: NetworkAction::Inbound,
+            host_pattern: "*".to_string(),
+            port: Some(port),
+        }));
+    }
+    for (key, action) in [
+        ("can_read_vars", EnvironmentAction::Read),
+        ("can_write_vars", EnvironmentAction::Write),
+    ] {
+        for pattern in patterns(caps, "environment", key)? {
+            declared.push(Capability::Environment(EnvironmentCapability {
+                action: action.clone(),
+                variable_pattern: pattern,
+            }));
+        }
+    }
+    for clock in [ClockKind::Wall, ClockKind::Monotonic] {
+        if flag(caps, "clocks", clock.as_str())? {
+            declared.push(Capability::Clock(ClockCapability { clock }));
+        }
+    }
+    for source in [RandomSource::Secure, RandomSource::Insecure] {
+        if flag(caps, "random", source.as_str())? {
+            declared.push(Capability::Random(RandomCapability { source }));
+        }
+    }
+    for (key, action) in [
+        ("can_spawn", ProcessAction::Spawn),
+        ("can_signal", ProcessAction::Signal),
+    ] {
+        for pattern in patterns(caps, "process", key)? {
+            declared.push(Capability::Process(ProcessCapability {
+                action: action.clone(),
+                program_pattern: pattern,
+            }));
+        }
+    }
+    Ok(declared)
+}
+
+fn flag(caps: &toml::Value, section: &str, key: &str) -> Result<bool, CapabilityTokenError> {
+    match caps.get(section).and_then(|table| table.get(key)) {
+        None => Ok(false),
+        Some(value) => value
+            .as_bool()
+            .ok_or_else(|| invalid_flag(&format!("{section}.{key}"))),
+    }
+}
+
+fn patterns(
+    caps: &toml::Value,
+    section: &str,
+    key: &str,
+) -> Result<Vec<String>, CapabilityTokenError> {
+    let Some(value) = caps.get(section).and_then(|table| table.get(key)) else {
+        return Ok(Vec::new());
+    };
+    list(value)?
+        .iter()
+        .map(|item| {
+            item.as_str()
+                .map(str::to_string)
+                .ok_or_else(|| invalid(&format!("{section}.{key}"), "a string"))
+        })
+        .collect()
+}
+
+fn list(value: &toml::Value) -> Result<&Vec<toml::Value>, CapabilityTokenError> {
+    value
+        .as_array()
+        .ok_or_else(|| CapabilityTokenError::InvalidManifest("expected an array".to_string()))
+}
+
+fn invalid(path: &str, expected: &str) -> CapabilityTokenError {
+    CapabilityTokenError::InvalidManifest(format!(
+        "every entry of capabilities.{path} must be {expected}"
+    ))
+}
+
+fn invalid_flag(path: &str) -> CapabilityTokenError {
+    CapabilityTokenError::InvalidManifest(format!("capabilities.{path} must be a boolean"))
+}
+
+fn decode_signature(text: &str) -> Option<[u8; 64]> {
+    let text = text.trim();
+    if text.len() != 128 {
+        return None;
+    }
+    let mut bytes = [0u8; 64];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(text.get(2 * i..2 * i + 2)?, 16).ok()?;
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: [u8; 32] = [42; 32];
+
+    fn read_key(pattern: &str) -> Capability {
+        Capability::Storage(StorageCapability {
+            action: StorageAction::Read,
+            namespace_pattern: pattern.to_string(),
+        })
+    }
+
+    fn token() -> CapabilityToken {
+        CapabilityToken::new(
+            ComponentId::new("acme", "reports", "v1"),
+            "ops",
+            vec![
+                read_key("reports/*"),
+                Capability::Network(NetworkCapability {
+                    action: NetworkAction::Outbound,
+                    host_pattern: "api.acme.com".to_string(),
+                    port: None,
+                }),
+            ],
+        )
+        .sign(&SECRET)
+    }
+
+    #[test]
+    fn test_signature_round_trip_and_tamper_detection() {
+        let public_key = CapabilityToken::public_key(&SECRET);
+        let token = CapabilityToken::from_json(&token().to_json().unwrap()).unwrap();
+        assert!(token
+            .verify_for(&ComponentId::new("acme", "reports", "v1"), &public_key)
+            .is_ok());
+
+        let mut tampered = token.clone();
+        tampered.capabilities.push(read_key("*"));
+        assert_eq!(
+            tampered.verify(&public_key),
+            Err(CapabilityTokenError::InvalidSignature)
+        );
+        assert!(matches!(
+            token.verify_for(&ComponentId::new("acme", "other", "v1"), &public_key),
+            Err(CapabilityTokenError::ComponentMismatch { .. })
+        ));
+        assert_eq!(
+            CapabilityToken::new(token.component.clone(), "ops", Vec::new()).verify(&public_key),
+            Err(CapabilityTokenError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_diff_against_manifest() {
+        let manifest = r#"
+[component]
+name = "reports"
+
+[capabilities.storage]
+can_read_keys = ["reports/*"]
+can_write_keys = ["reports/out/*"]
+
+[capabilities.clocks]
+wall = false
+"#;
+        let diff = token().diff_against_manifest(manifest).unwrap();
+        assert_eq!(diff.undeclared.len(), 1);
+        assert!(matches!(diff.undeclared[0], Capability::Network(_)));
+        assert_eq!(
+            diff.ungranted,
+            vec![Capability::Storage(StorageCapability {
+                action: StorageAction::Write,
+                namespace_pattern: "reports/out/*".to_string(),
+            })]
+        );
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn test_declared_capabilities_rejects_bad_entries() {
+        let result = declared_capabilities("[capabilities.network]\ncan_bind_ports = [70000]\n");
+        assert!(matches!(
+            result,
+            Err(CapabilityTokenError::InvalidManifest(_))
+        ));
+        assert!(declared_capabilities("[component]\nname = \"x\"\n")
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -4,9 +4,14 @@
 //! - [`SecurityValidator`] - Validates component capabilities
 //! - [`SecurityAuditLogger`] - Logs security events for audit
 //! - [`SecurityEvent`] - Security event data structure
+//! - [`HostResolver`] - Host-side DNS resolution for egress control
+
+use std::net::IpAddr;
+use std::sync::Arc;
 
 use super::capability::Capability;
 use super::errors::SecurityError;
+use super::quota::{QuotaClass, ResourceQuota};
 use crate::core::component::id::ComponentId;
 
 /// Trait for validating component capabilities.
@@ -67,6 +72,59 @@ pub trait SecurityValidator: Send + Sync {
     /// * `Ok(())` if sending is allowed
     /// * `Err(SecurityError)` if sending is denied
     fn can_send_to(&self, sender: &ComponentId, target: &ComponentId) -> Result<(), SecurityError>;
+
+    /// Quota attached to the component's grants of `class`, if any.
+    ///
+    /// The host function layer enforces the returned limits on top of
+    /// [`validate_capability`](Self::validate_capability). Defaults to no
+    /// quota.
+    fn quota(&self, _component: &ComponentId, _class: QuotaClass) -> Option<ResourceQuota> {
+        None
+    }
+
+    /// Grant an approved elevation to the component.
+    ///
+    /// `capability` has the `class:action:pattern` form of elevation
+    /// requests. Defaults to refusing, so a validator that cannot enforce
+    /// runtime grants never reports an elevation as applied.
+    fn grant_elevated(
+        &self,
+        component: &ComponentId,
+        capability: &str,
+    ) -> Result<(), SecurityError> {
+        Err(SecurityError::PolicyViolation(format!(
+            "cannot grant '{}' to {}: validator does not support runtime grants",
+            capability, component
+        )))
+    }
+}
+
+/// A shared validator validates like the validator it points to, so one
+/// instance can be both wrapped (e.g. by a lockdown) and handed out.
+impl<T: SecurityValidator + ?Sized> SecurityValidator for Arc<T> {
+    fn validate_capability(
+        &self,
+        component: &ComponentId,
+        capability: &Capability,
+    ) -> Result<(), SecurityError> {
+        (**self).validate_capability(component, capability)
+    }
+
+    fn can_send_to(&self, sender: &ComponentId, target: &ComponentId) -> Result<(), SecurityError> {
+        (**self).can_send_to(sender, target)
+    }
+
+    fn quota(&self, component: &ComponentId, class: QuotaClass) -> Option<ResourceQuota> {
+        (**self).quota(component, class)
+    }
+
+    fn grant_elevated(
+        &self,
+        component: &ComponentId,
+        capability: &str,
+    ) -> Result<(), SecurityError> {
+        (**self).grant_elevated(component, capability)
+    }
 }
 
 /// Trait for security audit logging.
@@ -80,6 +138,38 @@ pub trait SecurityAuditLogger: Send + Sync {
     fn log_event(&self, event: SecurityEvent);
 }
 
+/// Trait for host-side DNS resolution.
+///
+/// Components never resolve names themselves; the egress proxy resolves on
+/// their behalf through this trait so resolved addresses can be pinned.
+///
+/// # Example
+///
+/// ```rust
+/// use std::net::{IpAddr, Ipv4Addr};
+/// use airssys_wasm::core::security::errors::SecurityError;
+/// use airssys_wasm::core::security::traits::HostResolver;
+///
+/// struct StaticResolver;
+///
+/// impl HostResolver for StaticResolver {
+///     fn resolve(&self, _host: &str) -> Result<Vec<IpAddr>, SecurityError> {
+///         Ok(vec![IpAddr::V4(Ipv4Addr::new(93, 184, 216, 34))])
+///     }
+/// }
+/// ```
+pub trait HostResolver: Send + Sync {
+    /// Resolve a host name to its addresses.
+    ///
+    /// # Arguments
+    /// * `host` - DNS name to resolve (never an IP literal)
+    ///
+    /// # Returns
+    /// * `Ok(addrs)` with every address the name resolved to
+    /// * `Err(SecurityError)` if resolution failed
+    fn resolve(&self, host: &str) -> Result<Vec<IpAddr>, SecurityError>;
+}
+
 /// Security event for audit logging.
 ///
 /// Captures information about security-related actions for audit purposes.
@@ -24,6 +24,9 @@ use thiserror::Error;
 /// - `QuotaExceeded` - Storage quota for component exceeded
 /// - `InvalidKey` - Key format is invalid
 /// - `IoError` - Underlying I/O operation failed
+/// - `Conflict` - A transaction touched a key changed since it first read it
+/// - `InvalidTransaction` - Transaction call out of order (e.g. nested begin)
+/// - `Encryption` - A value could not be encrypted or decrypted
 ///
 /// # Examples
 ///
@@ -54,6 +57,18 @@ pub enum StorageError {
     /// Underlying I/O error.
     #[error("Storage I/O error: {0}")]
     IoError(String),
+
+    /// Transaction aborted because another writer changed a key it touched.
+    #[error("Storage conflict on key: {0}")]
+    Conflict(String),
+
+    /// Transaction operation not valid in the current state.
+    #[error("Invalid transaction: {0}")]
+    InvalidTransaction(String),
+
+    /// Encrypting or decrypting a stored value failed.
+    #[error("Storage encryption error: {0}")]
+    Encryption(String),
 }
 
 #[cfg(test)]
@@ -90,6 +105,21 @@ mod tests {
         assert_eq!(format!("{}", err), "Storage I/O error: disk full");
     }
 
+    #[test]
+    fn test_conflict_display() {
+        let err = StorageError::Conflict("cart/1".to_string());
+        assert_eq!(format!("{}", err), "Storage conflict on key: cart/1");
+    }
+
+    #[test]
+    fn test_encryption_display() {
+        let err = StorageError::Encryption("token: authentication failed".to_string());
+        assert_eq!(
+            format!("{}", err),
+            "Storage encryption error: token: authentication failed"
+        );
+    }
+
     #[test]
     fn test_error_is_clone() {
         let err = StorageError::QuotaExceeded;
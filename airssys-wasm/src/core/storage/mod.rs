@@ -8,10 +8,12 @@
 //! This module is part of the **core/** foundation (Layer 1). It contains:
 //!
 //! - **Types**: `StorageValue` (dedicated ADT for storage values)
-//! - **Traits**: `ComponentStorage` (abstraction for key-value storage)
+//! - **Traits**: `ComponentStorage` (abstraction for key-value storage),
+//!   `TransactionalStorage` / `StorageTransaction` (atomic multi-key updates)
 //! - **Errors**: `StorageError` (co-located)
 //!
-//! Concrete implementations are provided via host functions.
+//! The production implementation is `runtime::kv_store::KvStore`, which
+//! guests reach through the `storage` host functions.
 //!
 //! # Design Decision
 //!
@@ -22,7 +24,8 @@
 //!
 //! - [`value`] - `StorageValue` ADT (dedicated storage value type)
 //! - [`errors`] - `StorageError` enum (co-located with storage)
-//! - [`traits`] - `ComponentStorage` trait
+//! - [`traits`] - `ComponentStorage` and transaction traits
+//! - [`sharding`] - `ShardedStorage` locality-aware routing over storage shards
 //!
 //! # Usage
 //!
@@ -40,6 +43,7 @@
 
 // Module declarations (per PROJECTS_STANDARD.md §4.3)
 pub mod errors;
+pub mod sharding;
 pub mod traits;
 pub mod value;
 
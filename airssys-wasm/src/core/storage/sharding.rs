@@ -0,0 +1,339 @@
+//! Locality-aware routing over sharded storage backends.
+//!
+//! When component storage is spread over several (possibly remote) shards,
+//! a component whose keys land on many shards pays a network hop for most
+//! accesses. Components therefore declare their dominant key prefixes
+//! (`[storage] locality = ["orders/", ...]` in `Component.toml`, or
+//! `ComponentConfig::with_locality_prefix`), and [`ShardedStorage`]:
+//!
+//! - assigns each component a stable *home shard* derived from its storage
+//!   namespace,
+//! - routes every key under a declared prefix to the home shard, so those
+//!   partitions are co-located,
+//! - spreads all other keys over the shards by key hash,
+//! - counts local, cross-shard, and fan-out (all-shard) accesses so
+//!   operators can see whether the hints match the workload.
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+// (none)
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use super::errors::StorageError;
+use super::traits::ComponentStorage;
+use super::value::StorageValue;
+
+/// Index of a shard in a [`ShardedStorage`].
+pub type ShardId = usize;
+
+/// Access counters of one [`ShardedStorage`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LocalityMetrics {
+    /// Single-key accesses served by the home shard.
+    pub local: u64,
+    /// Single-key accesses served by another shard.
+    pub cross_shard: u64,
+    /// Listings that had to query every shard.
+    pub fan_out: u64,
+}
+
+impl LocalityMetrics {
+    /// Fraction of single-key accesses that left the home shard.
+    pub fn cross_shard_ratio(&self) -> f64 {
+        let total = self.local + self.cross_shard;
+        if total == 0 {
+            0.0
+        } else {
+            self.cross_shard as f64 / total as f64
+        }
+    }
+}
+
+/// One component's view of a set of storage shards.
+///
+/// Shards are shared between components; each component gets its own
+/// `ShardedStorage` with its namespace and locality prefixes.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use airssys_wasm::core::storage::sharding::ShardedStorage;
+/// use airssys_wasm::core::storage::traits::ComponentStorage;
+/// # use airssys_wasm::core::storage::errors::StorageError;
+/// # use airssys_wasm::core::storage::value::StorageValue;
+/// # struct NullStorage;
+/// # impl ComponentStorage for NullStorage {
+/// #     fn get(&self, _: &str) -> Result<Option<StorageValue>, StorageError> { Ok(None) }
+/// #     fn set(&self, _: &str, _: StorageValue) -> Result<(), StorageError> { Ok(()) }
+/// #     fn delete(&self, _: &str) -> Result<(), StorageError> { Ok(()) }
+/// #     fn exists(&self, _: &str) -> Result<bool, StorageError> { Ok(false) }
+/// #     fn list_keys(&self, _: Option<&str>) -> Result<Vec<String>, StorageError> { Ok(vec![]) }
+/// # }
+///
+/// let shards: Vec<Arc<dyn ComponentStorage>> =
+///     (0..4).map(|_| Arc::new(NullStorage) as Arc<dyn ComponentStorage>).collect();
+/// let storage = ShardedStorage::new("shop", shards)
+///     .unwrap()
+///     .with_locality_prefixes(["orders/"]);
+///
+/// // Every key under a declared prefix lives on the home shard
+/// assert_eq!(storage.shard_for("orders/1"), storage.home_shard());
+/// assert_eq!(storage.shard_for("orders/99"), storage.home_shard());
+/// ```
+pub struct ShardedStorage {
+    shards: Vec<Arc<dyn ComponentStorage>>,
+    namespace: String,
+    home: ShardId,
+    prefixes: Vec<String>,
+    local: AtomicU64,
+    cross_shard: AtomicU64,
+    fan_out: AtomicU64,
+}
+
+impl ShardedStorage {
+    /// Creates a view of `shards` for the component storing under
+    /// `namespace`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::IoError` if `shards` is empty.
+    pub fn new(
+        namespace: impl Into<String>,
+        shards: Vec<Arc<dyn ComponentStorage>>,
+    ) -> Result<Self, StorageError> {
+        if shards.is_empty() {
+            return Err(StorageError::IoError(
+                "sharded storage needs at least one shard".to_string(),
+            ));
+        }
+        let namespace = namespace.into();
+        let home = bucket(&[namespace.as_bytes()], shards.len());
+        Ok(Self {
+            shards,
+            namespace,
+            home,
+            prefixes: Vec::new(),
+            local: AtomicU64::new(0),
+            cross_shard: AtomicU64::new(0),
+            fan_out: AtomicU64::new(0),
+        })
+    }
+
+    /// Declares the component's dominant key prefixes.
+    pub fn with_locality_prefixes<I, P>(mut self, prefixes: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<String>,
+    {
+        self.prefixes.extend(
+            prefixes
+                .into_iter()
+                .map(Into::into)
+                .filter(|p| !p.is_empty()),
+        );
+        self
+    }
+
+    /// The shard holding this component's declared partitions.
+    pub fn home_shard(&self) -> ShardId {
+        self.home
+    }
+
+    /// Number of shards.
+    pub fn shard_count(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// The shard that stores `key`.
+    pub fn shard_for(&self, key: &str) -> ShardId {
+        if self.is_local_key(key) {
+            self.home
+        } else {
+            bucket(
+                &[self.namespace.as_bytes(), b"/", key.as_bytes()],
+                self.shards.len(),
+            )
+        }
+    }
+
+    /// Current access counters.
+    pub fn metrics(&self) -> LocalityMetrics {
+        LocalityMetrics {
+            local: self.local.load(Ordering::Relaxed),
+            cross_shard: self.cross_shard.load(Ordering::Relaxed),
+            fan_out: self.fan_out.load(Ordering::Relaxed),
+        }
+    }
+
+    fn is_local_key(&self, key: &str) -> bool {
+        self.prefixes.iter().any(|p| key.starts_with(p.as_str()))
+    }
+
+    fn route(&self, key: &str) -> &dyn ComponentStorage {
+        let shard = self.shard_for(key);
+        let counter = if shard == self.home {
+            &self.local
+        } else {
+            &self.cross_shard
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        self.shards[shard].as_ref()
+    }
+}
+
+impl ComponentStorage for ShardedStorage {
+    fn get(&self, key: &str) -> Result<Option<StorageValue>, StorageError> {
+        self.route(key).get(key)
+    }
+
+    fn set(&self, key: &str, value: StorageValue) -> Result<(), StorageError> {
+        self.route(key).set(key, value)
+    }
+
+    fn delete(&self, key: &str) -> Result<(), StorageError> {
+        self.route(key).delete(key)
+    }
+
+    fn exists(&self, key: &str) -> Result<bool, StorageError> {
+        self.route(key).exists(key)
+    }
+
+    fn list_keys(&self, prefix: Option<&str>) -> Result<Vec<String>, StorageError> {
+        // A listing inside a declared partition only needs the home shard
+        if let Some(p) = prefix.filter(|p| self.is_local_key(p)) {
+            self.local.fetch_add(1, Ordering::Relaxed);
+            return self.shards[self.home].list_keys(Some(p));
+        }
+
+        self.fan_out.fetch_add(1, Ordering::Relaxed);
+        let mut keys = Vec::new();
+        for shard in &self.shards {
+            keys.extend(shard.list_keys(prefix)?);
+        }
+        keys.sort();
+        keys.dedup();
+        Ok(keys)
+    }
+}
+
+impl std::fmt::Debug for ShardedStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShardedStorage")
+            .field("namespace", &self.namespace)
+            .field("shards", &self.shards.len())
+            .field("home", &self.home)
+            .field("prefixes", &self.prefixes)
+            .field("metrics", &self.metrics())
+            .finish()
+    }
+}
+
+/// Stable FNV-1a bucket, identical across builds and hosts.
+fn bucket(parts: &[&[u8]], buckets: usize) -> ShardId {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in parts.iter().flat_map(|p| p.iter()) {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    (hash % buckets as u64) as ShardId
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemoryStorage {
+        data: Mutex<BTreeMap<String, StorageValue>>,
+    }
+
+    impl ComponentStorage for MemoryStorage {
+        fn get(&self, key: &str) -> Result<Option<StorageValue>, StorageError> {
+            Ok(self.data.lock().unwrap().get(key).cloned())
+        }
+
+        fn set(&self, key: &str, value: StorageValue) -> Result<(), StorageError> {
+            self.data.lock().unwrap().insert(key.to_string(), value);
+            Ok(())
+        }
+
+        fn delete(&self, key: &str) -> Result<(), StorageError> {
+            self.data.lock().unwrap().remove(key);
+            Ok(())
+        }
+
+        fn exists(&self, key: &str) -> Result<bool, StorageError> {
+            Ok(self.data.lock().unwrap().contains_key(key))
+        }
+
+        fn list_keys(&self, prefix: Option<&str>) -> Result<Vec<String>, StorageError> {
+            let data = self.data.lock().unwrap();
+            Ok(data
+                .keys()
+                .filter(|k| prefix.is_none_or(|p| k.starts_with(p)))
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn shards(n: usize) -> (Vec<Arc<MemoryStorage>>, Vec<Arc<dyn ComponentStorage>>) {
+        let typed: Vec<Arc<MemoryStorage>> = (0..n).map(|_| Arc::default()).collect();
+        let erased = typed
+            .iter()
+            .map(|s| Arc::clone(s) as Arc<dyn ComponentStorage>)
+            .collect();
+        (typed, erased)
+    }
+
+    #[test]
+    fn test_declared_prefixes_are_colocated_on_home_shard() {
+        let (typed, erased) = shards(8);
+        let storage = ShardedStorage::new("shop", erased)
+            .unwrap()
+            .with_locality_prefixes(["orders/"]);
+
+        for i in 0..20 {
+            storage
+                .set(&format!("orders/{i}"), StorageValue::new(vec![1]))
+                .unwrap();
+        }
+        assert_eq!(typed[storage.home_shard()].data.lock().unwrap().len(), 20);
+        assert_eq!(
+            storage.list_keys(Some("orders/")).unwrap().len(),
+            20,
+            "partition listing served by the home shard"
+        );
+
+        let metrics = storage.metrics();
+        assert_eq!(metrics.local, 21);
+        assert_eq!(metrics.cross_shard, 0);
+        assert_eq!(metrics.fan_out, 0);
+    }
+
+    #[test]
+    fn test_undeclared_keys_spread_and_count_cross_shard() {
+        let (_, erased) = shards(4);
+        let storage = ShardedStorage::new("shop", erased).unwrap();
+
+        for i in 0..40 {
+            let key = format!("misc/{i}");
+            storage.set(&key, StorageValue::new(vec![0])).unwrap();
+            assert!(storage.exists(&key).unwrap());
+        }
+        let metrics = storage.metrics();
+        assert_eq!(metrics.local + metrics.cross_shard, 80);
+        assert!(metrics.cross_shard > 0);
+        assert!(metrics.cross_shard_ratio() > 0.0);
+
+        assert_eq!(storage.list_keys(None).unwrap().len(), 40);
+        assert_eq!(storage.metrics().fan_out, 1);
+
+        assert!(ShardedStorage::new("shop", Vec::new()).is_err());
+    }
+}
@@ -157,6 +157,74 @@ pub trait ComponentStorage: Send + Sync {
     fn list_keys(&self, prefix: Option<&str>) -> Result<Vec<String>, StorageError>;
 }
 
+/// A group of storage updates applied all together or not at all.
+///
+/// Writes are buffered until [`commit`](Self::commit); reads see the
+/// transaction's own writes. Dropping a transaction without committing
+/// discards its writes, so a component that traps halfway through an
+/// update leaves storage as it was.
+///
+/// Concurrency is optimistic: commit fails with `StorageError::Conflict`
+/// if any key the transaction read or wrote was changed by another writer
+/// (for example a second instance of the same component) after the
+/// transaction first touched it. Nothing is applied in that case and the
+/// caller may retry with a fresh transaction.
+pub trait StorageTransaction: Send {
+    /// Gets a value by key, including this transaction's own writes.
+    ///
+    /// # Errors
+    ///
+    /// - `StorageError::InvalidKey` - Key format is invalid
+    /// - `StorageError::IoError` - I/O operation failed
+    fn get(&mut self, key: &str) -> Result<Option<StorageValue>, StorageError>;
+
+    /// Buffers setting `key` to `value`.
+    ///
+    /// # Errors
+    ///
+    /// - `StorageError::InvalidKey` - Key format is invalid
+    /// - `StorageError::IoError` - I/O operation failed
+    fn set(&mut self, key: &str, value: StorageValue) -> Result<(), StorageError>;
+
+    /// Buffers deleting `key`.
+    ///
+    /// # Errors
+    ///
+    /// - `StorageError::InvalidKey` - Key format is invalid
+    /// - `StorageError::IoError` - I/O operation failed
+    fn delete(&mut self, key: &str) -> Result<(), StorageError>;
+
+    /// Checks if a key exists, including this transaction's own writes.
+    ///
+    /// # Errors
+    ///
+    /// - `StorageError::InvalidKey` - Key format is invalid
+    /// - `StorageError::IoError` - I/O operation failed
+    fn exists(&mut self, key: &str) -> Result<bool, StorageError>;
+
+    /// Applies every buffered write atomically.
+    ///
+    /// # Errors
+    ///
+    /// - `StorageError::Conflict` - A touched key changed since it was read
+    /// - `StorageError::QuotaExceeded` - The writes would exceed the quota
+    /// - `StorageError::IoError` - I/O operation failed
+    fn commit(self: Box<Self>) -> Result<(), StorageError>;
+
+    /// Discards every buffered write.
+    fn rollback(self: Box<Self>);
+}
+
+/// Storage that can group updates into a [`StorageTransaction`].
+pub trait TransactionalStorage: ComponentStorage {
+    /// Starts a transaction on this storage.
+    ///
+    /// # Errors
+    ///
+    /// - `StorageError::IoError` - I/O operation failed
+    fn begin(&self) -> Result<Box<dyn StorageTransaction>, StorageError>;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -208,6 +276,7 @@ mod tests {
     fn test_component_storage_is_send_sync() {
         fn assert_send_sync<T: Send + Sync + ?Sized>() {}
         assert_send_sync::<dyn ComponentStorage>();
+        assert_send_sync::<dyn TransactionalStorage>();
     }
 
     #[test]
@@ -84,8 +84,11 @@
 //
 // 2. **Host Trait Implementations** for imported interfaces:
 //    - `airssys::core::host_messaging::Host` - 5 messaging functions
+//    - `airssys::core::host_metrics::Host` - 3 metrics functions
 //    - `airssys::core::host_services::Host` - 6 service functions
-//    - `airssys::core::storage::Host` - 6 storage functions
+//    - `airssys::core::host_os::Host` - 4 OS bridge functions
+//    - `airssys::core::storage::Host` - 10 storage functions
+//    - `airssys::core::host_storage::Host` - 4 namespaced storage functions
 //    - These traits MUST be implemented on `HostState` in runtime/host_functions.rs
 //
 // 3. **Type Definitions** from WIT files:
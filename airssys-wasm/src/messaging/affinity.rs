@@ -0,0 +1,318 @@
+//! Sticky routing of keyed messages to pool instances.
+//!
+//! Instances of one component share its namespace and name and differ in
+//! their `instance` part (`shop/cart/0`, `shop/cart/1`, ...). Provides
+//! [`AffinityRouter`], which keeps a consistent-hash ring per pool and maps
+//! a message's `MessageMetadata::partition_key` to one member, so every
+//! message with the same key reaches the same instance and that instance
+//! can cache per-key state locally.
+//!
+//! Each instance owns [`DEFAULT_VIRTUAL_NODES`] points on its pool's ring.
+//! When an instance joins or leaves, only the keys between its points and
+//! their predecessors move; the rest of the keyspace keeps its instance.
+//!
+//! Messages without a partition key, or addressed to a component with no
+//! pool members, are routed to their target unchanged.
+//!
+//! # Architecture
+//!
+//! This module is part of `messaging/` (Layer 3B). It depends on:
+//! - `core/component/` for `ComponentId`, `ComponentMessage`
+//! - `core/messaging/` for `MessagingError`
+//!
+//! # References
+//!
+//! - ADR-WASM-031: Component & Messaging Module Design
+
+// Layer 1: Standard library imports
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+// Layer 2: Third-party crate imports
+use sha2::{Digest, Sha256};
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::ComponentMessage;
+use crate::core::messaging::errors::MessagingError;
+
+/// Ring points per pool instance.
+pub const DEFAULT_VIRTUAL_NODES: usize = 64;
+
+/// Pool a component instance belongs to: its namespace and name.
+type PoolKey = (String, String);
+
+fn pool_key(id: &ComponentId) -> PoolKey {
+    (id.namespace.clone(), id.name.clone())
+}
+
+/// Position of `value` on the ring.
+fn ring_hash(value: &str) -> u64 {
+    let digest = Sha256::digest(value.as_bytes());
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(bytes)
+}
+
+/// Consistent-hash ring over the instances of one pool.
+#[derive(Debug, Default)]
+struct HashRing {
+    points: BTreeMap<u64, ComponentId>,
+    members: Vec<ComponentId>,
+}
+
+impl HashRing {
+    fn insert(&mut self, id: ComponentId, virtual_nodes: usize) -> bool {
+        if self.members.contains(&id) {
+            return false;
+        }
+        let base = id.to_string_id();
+        for node in 0..virtual_nodes {
+            self.points
+                .insert(ring_hash(&format!("{}#{}", base, node)), id.clone());
+        }
+        self.members.push(id);
+        true
+    }
+
+    fn remove(&mut self, id: &ComponentId) -> bool {
+        let before = self.members.len();
+        self.members.retain(|member| member != id);
+        self.points.retain(|_, member| member != id);
+        self.members.len() != before
+    }
+
+    fn locate(&self, key: &str) -> Option<&ComponentId> {
+        let hash = ring_hash(key);
+        self.points
+            .range(hash..)
+            .next()
+            .or_else(|| self.points.iter().next())
+            .map(|(_, id)| id)
+    }
+}
+
+/// Per-pool consistent-hash rings mapping partition keys to instances.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::messaging::affinity::AffinityRouter;
+///
+/// let router = AffinityRouter::new();
+/// for instance in ["0", "1", "2"] {
+///     router.add_instance(ComponentId::new("shop", "cart", instance)).unwrap();
+/// }
+///
+/// let logical = ComponentId::new("shop", "cart", "0");
+/// let first = router.resolve(&logical, Some("customer-42")).unwrap();
+/// assert_eq!(router.resolve(&logical, Some("customer-42")).unwrap(), first);
+///
+/// // Without a key the target is used as addressed
+/// assert_eq!(router.resolve(&logical, None).unwrap(), logical);
+/// ```
+#[derive(Debug)]
+pub struct AffinityRouter {
+    pools: RwLock<HashMap<PoolKey, HashRing>>,
+    virtual_nodes: usize,
+}
+
+impl Default for AffinityRouter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AffinityRouter {
+    /// Creates a router with no pools and [`DEFAULT_VIRTUAL_NODES`] ring
+    /// points per instance.
+    pub fn new() -> Self {
+        Self {
+            pools: RwLock::new(HashMap::new()),
+            virtual_nodes: DEFAULT_VIRTUAL_NODES,
+        }
+    }
+
+    /// Sets the number of ring points per instance (at least 1).
+    ///
+    /// More points spread keys more evenly at the cost of memory per
+    /// instance.
+    pub fn with_virtual_nodes(mut self, virtual_nodes: usize) -> Self {
+        self.virtual_nodes = virtual_nodes.max(1);
+        self
+    }
+
+    /// Adds `instance` to the pool of its namespace and name.
+    ///
+    /// Returns `false` if it was already a member.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn add_instance(&self, instance: ComponentId) -> Result<bool, MessagingError> {
+        let mut pools = self.write()?;
+        Ok(pools
+            .entry(pool_key(&instance))
+            .or_default()
+            .insert(instance, self.virtual_nodes))
+    }
+
+    /// Removes `instance` from its pool; its keys move to the remaining
+    /// members.
+    ///
+    /// Returns whether it was a member.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn remove_instance(&self, instance: &ComponentId) -> Result<bool, MessagingError> {
+        let mut pools = self.write()?;
+        let key = pool_key(instance);
+        let Some(ring) = pools.get_mut(&key) else {
+            return Ok(false);
+        };
+        let removed = ring.remove(instance);
+        if ring.members.is_empty() {
+            pools.remove(&key);
+        }
+        Ok(removed)
+    }
+
+    /// Members of the pool `component` belongs to, in joining order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn instances(&self, component: &ComponentId) -> Result<Vec<ComponentId>, MessagingError> {
+        Ok(self
+            .read()?
+            .get(&pool_key(component))
+            .map(|ring| ring.members.clone())
+            .unwrap_or_default())
+    }
+
+    /// The instance a message for `target` with `partition_key` goes to.
+    ///
+    /// Returns `target` unchanged when there is no key or its pool has no
+    /// members.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn resolve(
+        &self,
+        target: &ComponentId,
+        partition_key: Option<&str>,
+    ) -> Result<ComponentId, MessagingError> {
+        let Some(key) = partition_key else {
+            return Ok(target.clone());
+        };
+        Ok(self
+            .read()?
+            .get(&pool_key(target))
+            .and_then(|ring| ring.locate(key))
+            .unwrap_or(target)
+            .clone())
+    }
+
+    /// The instance `message` for `target` goes to, by its partition key.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`resolve`](Self::resolve).
+    pub fn resolve_message(
+        &self,
+        target: &ComponentId,
+        message: &ComponentMessage,
+    ) -> Result<ComponentId, MessagingError> {
+        self.resolve(target, message.metadata.partition_key.as_deref())
+    }
+
+    fn read(&self) -> Result<RwLockReadGuard<'_, HashMap<PoolKey, HashRing>>, MessagingError> {
+        self.pools
+            .read()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))
+    }
+
+    fn write(&self) -> Result<RwLockWriteGuard<'_, HashMap<PoolKey, HashRing>>, MessagingError> {
+        self.pools
+            .write()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn cart(instance: &str) -> ComponentId {
+        ComponentId::new("shop", "cart", instance)
+    }
+
+    fn keys() -> Vec<String> {
+        (0..1000).map(|i| format!("customer-{}", i)).collect()
+    }
+
+    fn assignments(router: &AffinityRouter) -> HashMap<String, ComponentId> {
+        keys()
+            .into_iter()
+            .map(|key| {
+                let instance = router.resolve(&cart("0"), Some(&key)).unwrap();
+                (key, instance)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_keys_spread_over_pool_and_stay_put() {
+        let router = AffinityRouter::new();
+        for instance in ["0", "1", "2", "3"] {
+            assert!(router.add_instance(cart(instance)).unwrap());
+        }
+        assert!(!router.add_instance(cart("0")).unwrap());
+
+        let before = assignments(&router);
+        for instance in ["0", "1", "2", "3"] {
+            let share = before.values().filter(|id| **id == cart(instance)).count();
+            assert!(share > 100, "instance {} got {} keys", instance, share);
+        }
+        assert_eq!(assignments(&router), before);
+
+        // Other pools and unkeyed messages are untouched
+        let other = ComponentId::new("shop", "billing", "7");
+        assert_eq!(router.resolve(&other, Some("customer-1")).unwrap(), other);
+        assert_eq!(router.resolve(&cart("9"), None).unwrap(), cart("9"));
+    }
+
+    #[test]
+    fn test_pool_changes_only_move_affected_keys() {
+        let router = AffinityRouter::new();
+        for instance in ["0", "1", "2"] {
+            router.add_instance(cart(instance)).unwrap();
+        }
+        let before = assignments(&router);
+
+        router.add_instance(cart("3")).unwrap();
+        let grown = assignments(&router);
+        for (key, instance) in &grown {
+            assert!(instance == &before[key] || instance == &cart("3"));
+        }
+
+        assert!(router.remove_instance(&cart("1")).unwrap());
+        let shrunk = assignments(&router);
+        for (key, instance) in &shrunk {
+            if grown[key] != cart("1") {
+                assert_eq!(instance, &grown[key]);
+            }
+        }
+        assert_eq!(router.instances(&cart("x")).unwrap().len(), 3);
+
+        for instance in ["0", "2", "3"] {
+            router.remove_instance(&cart(instance)).unwrap();
+        }
+        assert!(!router.remove_instance(&cart("0")).unwrap());
+        assert_eq!(router.resolve(&cart("5"), Some("k")).unwrap(), cart("5"));
+    }
+}
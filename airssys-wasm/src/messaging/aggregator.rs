@@ -0,0 +1,489 @@
+//! Topic fan-in aggregation.
+//!
+//! Provides [`MessageAggregator`], which collects messages published to a
+//! topic and delivers them to an aggregator component as one batched
+//! message. High-rate topics (telemetry, metrics, log lines) then cost one
+//! component invocation per batch instead of one per message.
+//!
+//! A batch is delivered when either limit of its [`AggregationRule`] is hit:
+//!
+//! - **Size**: `max_messages` messages are pending
+//! - **Window**: the oldest pending message is `window` old; the host calls
+//!   [`MessageAggregator::flush_expired`] periodically to enforce this
+//!
+//! # Batch Format
+//!
+//! The delivered payload is an [`AggregatedBatch`] encoded as JSON; the
+//! aggregator component decodes it with [`AggregatedBatch::decode`] (or any
+//! JSON decoder in the guest).
+//!
+//! # Architecture
+//!
+//! This module is part of `messaging/` (Layer 3B). It depends only on
+//! `core/component/` and `core/messaging/`; delivery goes through the
+//! [`MessageSender`] implementation supplied by `system/` (Layer 4).
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// Layer 2: Third-party crate imports
+use serde::{Deserialize, Serialize};
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::{ComponentMessage, MessagePayload};
+use crate::core::messaging::errors::MessagingError;
+use crate::core::messaging::traits::MessageSender;
+
+/// Aggregation settings for one topic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggregationRule {
+    /// Topic whose messages are aggregated.
+    pub topic: String,
+    /// Component that receives the batches.
+    pub target: ComponentId,
+    /// Deliver once this many messages are pending (at least 1).
+    pub max_messages: usize,
+    /// Deliver once the oldest pending message is this old.
+    pub window: Duration,
+}
+
+impl AggregationRule {
+    /// Default batch size.
+    pub const DEFAULT_MAX_MESSAGES: usize = 100;
+
+    /// Default batching window.
+    pub const DEFAULT_WINDOW: Duration = Duration::from_millis(500);
+
+    /// Creates a rule with the default size and window.
+    pub fn new(topic: impl Into<String>, target: ComponentId) -> Self {
+        Self {
+            topic: topic.into(),
+            target,
+            max_messages: Self::DEFAULT_MAX_MESSAGES,
+            window: Self::DEFAULT_WINDOW,
+        }
+    }
+
+    /// Sets the batch size (0 is treated as 1).
+    pub fn with_max_messages(mut self, max_messages: usize) -> Self {
+        self.max_messages = max_messages.max(1);
+        self
+    }
+
+    /// Sets the batching window.
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+}
+
+/// One message inside an [`AggregatedBatch`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchEntry {
+    /// Original sender.
+    pub sender: ComponentId,
+    /// Original payload bytes.
+    pub payload: Vec<u8>,
+    /// Original message timestamp (ms since Unix epoch).
+    pub timestamp_ms: u64,
+}
+
+/// Payload delivered to an aggregator component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AggregatedBatch {
+    /// Topic the messages were published to.
+    pub topic: String,
+    /// Messages in publish order.
+    pub entries: Vec<BatchEntry>,
+}
+
+impl AggregatedBatch {
+    /// Encodes the batch as a message payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::InvalidMessage` if encoding fails.
+    pub fn encode(&self) -> Result<MessagePayload, MessagingError> {
+        serde_json::to_vec(self)
+            .map(MessagePayload::new)
+            .map_err(|e| MessagingError::InvalidMessage(e.to_string()))
+    }
+
+    /// Decodes a batch delivered to an aggregator component.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::InvalidMessage` if the payload is not a batch.
+    pub fn decode(payload: &MessagePayload) -> Result<Self, MessagingError> {
+        serde_json::from_slice(payload.as_bytes())
+            .map_err(|e| MessagingError::InvalidMessage(e.to_string()))
+    }
+}
+
+/// Messages waiting to be delivered for one topic.
+#[derive(Debug)]
+struct PendingBatch {
+    entries: Vec<BatchEntry>,
+    opened_at: Instant,
+}
+
+/// Collects topic messages and delivers them as batches.
+///
+/// # Thread Safety
+///
+/// Pending batches are guarded by a `Mutex`, which is never held across a
+/// delivery. A batch whose delivery fails is put back in front of any
+/// messages published meanwhile, so ordering is preserved and nothing is
+/// lost; the error is returned to the caller.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::component::message::ComponentMessage;
+/// use airssys_wasm::core::messaging::traits::MessageSender;
+/// use airssys_wasm::messaging::aggregator::{AggregationRule, MessageAggregator};
+///
+/// # async fn example(sender: &impl MessageSender, message: ComponentMessage) {
+/// let aggregator = MessageAggregator::new().with_rule(
+///     AggregationRule::new("telemetry", ComponentId::new("app", "metrics-sink", "v1"))
+///         .with_max_messages(256)
+///         .with_window(Duration::from_millis(200)),
+/// );
+///
+/// aggregator.publish("telemetry", message, sender).await.unwrap();
+///
+/// // From the host's periodic tick:
+/// aggregator.flush_expired(sender).await.unwrap();
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct MessageAggregator {
+    rules: HashMap<String, AggregationRule>,
+    pending: Mutex<HashMap<String, PendingBatch>>,
+}
+
+impl MessageAggregator {
+    /// Creates an aggregator with no rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds (or replaces) the rule for a topic.
+    pub fn with_rule(mut self, rule: AggregationRule) -> Self {
+        self.rules.insert(rule.topic.clone(), rule);
+        self
+    }
+
+    /// Returns the rule for a topic.
+    pub fn rule(&self, topic: &str) -> Option<&AggregationRule> {
+        self.rules.get(topic)
+    }
+
+    /// Returns the number of messages waiting for a topic.
+    pub fn pending(&self, topic: &str) -> usize {
+        self.pending
+            .lock()
+            .ok()
+            .and_then(|pending| pending.get(topic).map(|batch| batch.entries.len()))
+            .unwrap_or(0)
+    }
+
+    /// Adds a message to its topic's batch, delivering the batch if full.
+    ///
+    /// Returns `true` if this call delivered a batch.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` if no rule exists for `topic`
+    /// - Any error from `sender` when a full batch is delivered
+    pub async fn publish<S: MessageSender>(
+        &self,
+        topic: &str,
+        message: ComponentMessage,
+        sender: &S,
+    ) -> Result<bool, MessagingError> {
+        let rule = self.rules.get(topic).ok_or_else(|| {
+            MessagingError::InvalidMessage(format!("No aggregation rule for topic '{topic}'"))
+        })?;
+
+        let entry = BatchEntry {
+            sender: message.sender,
+            payload: message.payload.as_bytes().to_vec(),
+            timestamp_ms: message.metadata.timestamp_ms,
+        };
+
+        let full = {
+            let mut pending = self.lock()?;
+            let batch = pending
+                .entry(topic.to_string())
+                .or_insert_with(|| PendingBatch {
+                    entries: Vec::new(),
+                    opened_at: Instant::now(),
+                });
+            batch.entries.push(entry);
+            if batch.entries.len() >= rule.max_messages {
+                pending.remove(topic)
+            } else {
+                None
+            }
+        };
+
+        match full {
+            Some(batch) => self.deliver(rule, batch, sender).await.map(|()| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Delivers every batch whose window has elapsed.
+    ///
+    /// Returns the number of batches delivered.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first delivery error; remaining expired batches stay
+    /// pending for the next call.
+    pub async fn flush_expired<S: MessageSender>(
+        &self,
+        sender: &S,
+    ) -> Result<usize, MessagingError> {
+        self.flush_where(sender, |rule, batch| {
+            batch.opened_at.elapsed() >= rule.window
+        })
+        .await
+    }
+
+    /// Delivers every pending batch regardless of size or age.
+    ///
+    /// Used on shutdown so no aggregated message is left behind.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`flush_expired`](Self::flush_expired).
+    pub async fn flush_all<S: MessageSender>(&self, sender: &S) -> Result<usize, MessagingError> {
+        self.flush_where(sender, |_, _| true).await
+    }
+
+    async fn flush_where<S, F>(&self, sender: &S, due: F) -> Result<usize, MessagingError>
+    where
+        S: MessageSender,
+        F: Fn(&AggregationRule, &PendingBatch) -> bool,
+    {
+        let due_batches: Vec<(&AggregationRule, PendingBatch)> = {
+            let mut pending = self.lock()?;
+            let topics: Vec<String> = pending
+                .iter()
+                .filter(|(topic, batch)| self.rules.get(*topic).is_some_and(|r| due(r, batch)))
+                .map(|(topic, _)| topic.clone())
+                .collect();
+            topics
+                .into_iter()
+                .filter_map(|topic| {
+                    let rule = self.rules.get(&topic)?;
+                    pending.remove(&topic).map(|batch| (rule, batch))
+                })
+                .collect()
+        };
+
+        let mut delivered = 0;
+        let mut remaining = due_batches.into_iter();
+        for (rule, batch) in remaining.by_ref() {
+            if let Err(e) = self.deliver(rule, batch, sender).await {
+                // Undelivered batches go back to pending untouched
+                for (rule, batch) in remaining {
+                    self.requeue(&rule.topic, batch)?;
+                }
+                return Err(e);
+            }
+            delivered += 1;
+        }
+        Ok(delivered)
+    }
+
+    async fn deliver<S: MessageSender>(
+        &self,
+        rule: &AggregationRule,
+        batch: PendingBatch,
+        sender: &S,
+    ) -> Result<(), MessagingError> {
+        let aggregated = AggregatedBatch {
+            topic: rule.topic.clone(),
+            entries: batch.entries,
+        };
+        let payload = aggregated.encode()?;
+
+        if let Err(e) = sender.send(&rule.target, payload).await {
+            self.requeue(
+                &rule.topic,
+                PendingBatch {
+                    entries: aggregated.entries,
+                    opened_at: batch.opened_at,
+                },
+            )?;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Puts a batch back in front of anything published since it was taken.
+    fn requeue(&self, topic: &str, mut batch: PendingBatch) -> Result<(), MessagingError> {
+        let mut pending = self.lock()?;
+        if let Some(newer) = pending.remove(topic) {
+            batch.entries.extend(newer.entries);
+        }
+        pending.insert(topic.to_string(), batch);
+        Ok(())
+    }
+
+    fn lock(
+        &self,
+    ) -> Result<std::sync::MutexGuard<'_, HashMap<String, PendingBatch>>, MessagingError> {
+        self.pending
+            .lock()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Aggregator lock poisoned: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::component::message::MessageMetadata;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Records delivered payloads; can be switched to fail.
+    #[derive(Default)]
+    struct RecordingSender {
+        sent: Mutex<Vec<(ComponentId, MessagePayload)>>,
+        fail: AtomicBool,
+    }
+
+    impl RecordingSender {
+        fn batches(&self) -> Vec<AggregatedBatch> {
+            self.sent
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(_, payload)| AggregatedBatch::decode(payload).unwrap())
+                .collect()
+        }
+    }
+
+    impl MessageSender for RecordingSender {
+        async fn send(
+            &self,
+            target: &ComponentId,
+            payload: MessagePayload,
+        ) -> Result<(), MessagingError> {
+            if self.fail.load(Ordering::SeqCst) {
+                return Err(MessagingError::QueueFull);
+            }
+            self.sent.lock().unwrap().push((target.clone(), payload));
+            Ok(())
+        }
+
+        async fn send_with_correlation(
+            &self,
+            target: &ComponentId,
+            payload: MessagePayload,
+            _correlation_id: &str,
+        ) -> Result<(), MessagingError> {
+            self.send(target, payload).await
+        }
+    }
+
+    fn sink() -> ComponentId {
+        ComponentId::new("app", "sink", "v1")
+    }
+
+    fn message(n: u8) -> ComponentMessage {
+        ComponentMessage::new(
+            ComponentId::new("app", "sensor", "v1"),
+            MessagePayload::new(vec![n]),
+            MessageMetadata::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_full_batch_is_delivered_as_one_message() {
+        let aggregator = MessageAggregator::new()
+            .with_rule(AggregationRule::new("telemetry", sink()).with_max_messages(3));
+        let sender = RecordingSender::default();
+
+        for n in 0..7 {
+            aggregator
+                .publish("telemetry", message(n), &sender)
+                .await
+                .unwrap();
+        }
+
+        let batches = sender.batches();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].topic, "telemetry");
+        assert_eq!(
+            batches[1]
+                .entries
+                .iter()
+                .map(|e| e.payload[0])
+                .collect::<Vec<_>>(),
+            vec![3, 4, 5]
+        );
+        assert_eq!(aggregator.pending("telemetry"), 1);
+        assert_eq!(sender.sent.lock().unwrap()[0].0, sink());
+    }
+
+    #[tokio::test]
+    async fn test_window_flush() {
+        let aggregator = MessageAggregator::new()
+            .with_rule(AggregationRule::new("slow", sink()).with_window(Duration::from_millis(20)));
+        let sender = RecordingSender::default();
+
+        aggregator
+            .publish("slow", message(1), &sender)
+            .await
+            .unwrap();
+        assert_eq!(aggregator.flush_expired(&sender).await.unwrap(), 0);
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(aggregator.flush_expired(&sender).await.unwrap(), 1);
+        assert_eq!(aggregator.pending("slow"), 0);
+        assert_eq!(sender.batches()[0].entries.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_delivery_keeps_order() {
+        let aggregator = MessageAggregator::new()
+            .with_rule(AggregationRule::new("t", sink()).with_max_messages(2));
+        let sender = RecordingSender::default();
+
+        sender.fail.store(true, Ordering::SeqCst);
+        aggregator.publish("t", message(1), &sender).await.unwrap();
+        assert!(aggregator.publish("t", message(2), &sender).await.is_err());
+        assert_eq!(aggregator.pending("t"), 2);
+
+        sender.fail.store(false, Ordering::SeqCst);
+        // The requeued batch is still over the limit, so the next publish delivers it
+        assert!(aggregator.publish("t", message(3), &sender).await.unwrap());
+        assert_eq!(aggregator.flush_all(&sender).await.unwrap(), 0);
+        assert_eq!(
+            sender.batches()[0]
+                .entries
+                .iter()
+                .map(|e| e.payload[0])
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unknown_topic_rejected() {
+        let aggregator = MessageAggregator::new();
+        let sender = RecordingSender::default();
+
+        let result = aggregator.publish("nope", message(1), &sender).await;
+        assert!(matches!(result, Err(MessagingError::InvalidMessage(_))));
+    }
+}
@@ -0,0 +1,781 @@
+//! Route-level payload codec negotiation.
+//!
+//! Provides [`CodecNegotiator`], which records the codecs each component
+//! advertises and reconciles them when a message is routed. When a sender's
+//! codec is not accepted by the target, the negotiator either transcodes the
+//! payload through a [`PayloadTranscoder`] or, in strict mode, rejects the
+//! message with `MessagingError::CodecMismatch`.
+//!
+//! [`JsonCborTranscoder`] is the default transcoder and converts between
+//! JSON and CBOR via a self-describing intermediate value.
+//!
+//! [`PayloadCompressor`] adds transparent zstd compression: payloads above
+//! a size threshold are compressed and tagged with the [`ZSTD_CODE`]
+//! multicodec prefix, and the message's content type gains a
+//! [`COMPRESSION_PARAM`] parameter. Compressed payloads are decompressed
+//! on delivery, so components never see them.
+//!
+//! # Architecture
+//!
+//! This module is part of `messaging/` (Layer 3B). It depends on:
+//! - `core/component/` for `ComponentId`, `MessagePayload`
+//! - `core/messaging/` for `MessagingError`
+//! - `core/multicodec/` for `Codec`, `CodecError`, `PayloadTranscoder`
+//! - `core/management/` for `MetricSample` (compression metrics)
+//!
+//! # References
+//!
+//! - ADR-WASM-031: Component & Messaging Module Design
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+use std::time::{Duration, Instant};
+
+// Layer 2: Third-party crate imports
+// (none)
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::{ComponentMessage, MessagePayload};
+use crate::core::management::metrics::{MetricSample, MetricValue};
+use crate::core::messaging::errors::MessagingError;
+use crate::core::multicodec::codec::{prefix_code, split_code, Codec, ZSTD_CODE};
+use crate::core::multicodec::errors::CodecError;
+use crate::core::multicodec::traits::PayloadTranscoder;
+
+/// How the negotiator reacts when the target does not accept the sender's codec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NegotiationMode {
+    /// Transcode the payload into the first codec the target accepts.
+    #[default]
+    Transcode,
+    /// Reject the message with `MessagingError::CodecMismatch`.
+    Strict,
+}
+
+/// Transcoder between JSON and CBOR.
+///
+/// Conversion goes through `serde_json::Value`, so CBOR payloads containing
+/// byte strings or non-string map keys cannot be converted to JSON and yield
+/// `CodecError::DecodeFailed`. `Raw` payloads are only passed through
+/// unchanged.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonCborTranscoder;
+
+impl PayloadTranscoder for JsonCborTranscoder {
+    fn supports(&self, from: Codec, to: Codec) -> bool {
+        from == to
+            || matches!(
+                (from, to),
+                (Codec::Json, Codec::Cbor) | (Codec::Cbor, Codec::Json)
+            )
+    }
+
+    fn transcode(&self, data: &[u8], from: Codec, to: Codec) -> Result<Vec<u8>, CodecError> {
+        match (from, to) {
+            _ if from == to => Ok(data.to_vec()),
+            (Codec::Json, Codec::Cbor) => {
+                let value: serde_json::Value = serde_json::from_slice(data)
+                    .map_err(|e| CodecError::DecodeFailed(e.to_string()))?;
+                serde_cbor::to_vec(&value).map_err(|e| CodecError::EncodeFailed(e.to_string()))
+            }
+            (Codec::Cbor, Codec::Json) => {
+                let value: serde_json::Value = serde_cbor::from_slice(data)
+                    .map_err(|e| CodecError::DecodeFailed(e.to_string()))?;
+                serde_json::to_vec(&value).map_err(|e| CodecError::EncodeFailed(e.to_string()))
+            }
+            _ => Err(CodecError::UnsupportedConversion {
+                from: from.to_string(),
+                to: to.to_string(),
+            }),
+        }
+    }
+}
+
+/// Negotiates payload codecs between sender and target components.
+///
+/// Each component advertises an ordered list of codecs. The first entry is
+/// the codec the component emits; every entry is a codec it accepts.
+/// Components that have not advertised anything are treated as accepting
+/// any payload, and their messages are passed through unchanged.
+///
+/// # Thread Safety
+///
+/// Uses `RwLock<HashMap>` for the advertisement table, so lookups on the
+/// routing path can proceed concurrently.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::component::message::MessagePayload;
+/// use airssys_wasm::core::multicodec::codec::Codec;
+/// use airssys_wasm::messaging::codec::{CodecNegotiator, JsonCborTranscoder, NegotiationMode};
+///
+/// let negotiator = CodecNegotiator::new(Arc::new(JsonCborTranscoder), NegotiationMode::Transcode);
+/// let a = ComponentId::new("app", "a", "v1");
+/// let b = ComponentId::new("app", "b", "v1");
+/// negotiator.advertise(a.clone(), vec![Codec::Json]).unwrap();
+/// negotiator.advertise(b.clone(), vec![Codec::Cbor]).unwrap();
+///
+/// let (payload, codec) = negotiator
+///     .negotiate(&a, &b, MessagePayload::new(b"{\"n\":1}".to_vec()))
+///     .unwrap();
+/// assert_eq!(codec, Some(Codec::Cbor));
+/// assert_ne!(payload.as_bytes(), b"{\"n\":1}");
+/// ```
+pub struct CodecNegotiator {
+    /// Advertised codecs per component (first entry is the emitted codec)
+    advertised: RwLock<HashMap<ComponentId, Vec<Codec>>>,
+    /// Using dyn Trait for dependency injection across module boundaries
+    transcoder: Arc<dyn PayloadTranscoder>,
+    /// Behaviour on codec mismatch
+    mode: NegotiationMode,
+}
+
+impl CodecNegotiator {
+    /// Creates a negotiator with an empty advertisement table.
+    pub fn new(transcoder: Arc<dyn PayloadTranscoder>, mode: NegotiationMode) -> Self {
+        Self {
+            advertised: RwLock::new(HashMap::new()),
+            transcoder,
+            mode,
+        }
+    }
+
+    /// Returns the configured negotiation mode.
+    pub fn mode(&self) -> NegotiationMode {
+        self.mode
+    }
+
+    /// Records the codecs a component emits and accepts.
+    ///
+    /// Replaces any previous advertisement. An empty list removes the entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn advertise(&self, id: ComponentId, codecs: Vec<Codec>) -> Result<(), MessagingError> {
+        let mut advertised = self
+            .advertised
+            .write()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))?;
+        if codecs.is_empty() {
+            advertised.remove(&id);
+        } else {
+            advertised.insert(id, codecs);
+        }
+        Ok(())
+    }
+
+    /// Removes a component's advertisement.
+    ///
+    /// Returns `true` if an advertisement existed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn withdraw(&self, id: &ComponentId) -> Result<bool, MessagingError> {
+        let mut advertised = self
+            .advertised
+            .write()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))?;
+        Ok(advertised.remove(id).is_some())
+    }
+
+    /// Returns the codecs advertised by a component, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn advertised(&self, id: &ComponentId) -> Result<Option<Vec<Codec>>, MessagingError> {
+        let advertised = self
+            .advertised
+            .read()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))?;
+        Ok(advertised.get(id).cloned())
+    }
+
+    /// Reconciles the sender's codec with the target's accepted codecs.
+    ///
+    /// Returns the payload to deliver together with its codec, or `None`
+    /// when the sender has not advertised a codec and the payload is passed
+    /// through untouched.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::CodecMismatch` - Strict mode and target does not
+    ///   accept the sender's codec, or no supported conversion exists
+    /// - `MessagingError::InvalidMessage` - Payload failed to transcode
+    /// - `MessagingError::DeliveryFailed` - Lock poisoned
+    pub fn negotiate(
+        &self,
+        sender: &ComponentId,
+        target: &ComponentId,
+        payload: MessagePayload,
+    ) -> Result<(MessagePayload, Option<Codec>), MessagingError> {
+        let advertised = self
+            .advertised
+            .read()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))?;
+
+        let Some(source) = advertised
+            .get(sender)
+            .and_then(|codecs| codecs.first())
+            .copied()
+        else {
+            return Ok((payload, None));
+        };
+
+        let accepted = match advertised.get(target) {
+            Some(accepted) if !accepted.contains(&source) => accepted,
+            _ => return Ok((payload, Some(source))),
+        };
+
+        if self.mode == NegotiationMode::Strict {
+            return Err(MessagingError::CodecMismatch(format!(
+                "{} sends {} but {} accepts {}",
+                sender,
+                source,
+                target,
+                join_codecs(accepted)
+            )));
+        }
+
+        let Some(destination) = accepted
+            .iter()
+            .copied()
+            .find(|codec| self.transcoder.supports(source, *codec))
+        else {
+            return Err(MessagingError::CodecMismatch(format!(
+                "no conversion from {} to any of {} accepted by {}",
+                source,
+                join_codecs(accepted),
+                target
+            )));
+        };
+
+        let bytes = self
+            .transcoder
+            .transcode(payload.as_bytes(), source, destination)
+            .map_err(|e| MessagingError::InvalidMessage(e.to_string()))?;
+
+        Ok((MessagePayload::new(bytes), Some(destination)))
+    }
+}
+
+/// Content-type parameter marking a zstd-compressed payload.
+pub const COMPRESSION_PARAM: &str = "compression=zstd";
+
+/// Default size above which payloads are compressed (16 KiB).
+pub const DEFAULT_COMPRESSION_THRESHOLD: usize = 16 * 1024;
+
+/// Default zstd compression level.
+pub const DEFAULT_COMPRESSION_LEVEL: i32 = 3;
+
+/// Default upper bound on a decompressed payload (64 MiB).
+pub const DEFAULT_MAX_DECOMPRESSED_SIZE: usize = 64 * 1024 * 1024;
+
+/// Counters kept by a [`PayloadCompressor`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CompressionStats {
+    /// Payloads sent compressed.
+    pub compressed: u64,
+    /// Payloads over the threshold sent uncompressed because compression
+    /// did not make them smaller.
+    pub incompressible: u64,
+    /// Payloads decompressed on delivery.
+    pub decompressed: u64,
+    /// Size of compressed payloads before compression, in bytes.
+    pub original_bytes: u64,
+    /// Size of compressed payloads on the wire, including the tag.
+    pub compressed_bytes: u64,
+    /// Time spent compressing, including incompressible attempts.
+    pub compress_time: Duration,
+    /// Time spent decompressing.
+    pub decompress_time: Duration,
+}
+
+impl CompressionStats {
+    /// Original size divided by compressed size; 1.0 before anything was
+    /// compressed.
+    pub fn ratio(&self) -> f64 {
+        if self.compressed_bytes == 0 {
+            1.0
+        } else {
+            self.original_bytes as f64 / self.compressed_bytes as f64
+        }
+    }
+
+    /// Mean time added to a message by compression and decompression.
+    pub fn overhead_per_message(&self) -> Duration {
+        let messages = self.compressed + self.incompressible;
+        if messages == 0 {
+            return Duration::ZERO;
+        }
+        let total = self.compress_time + self.decompress_time;
+        total / u32::try_from(messages).unwrap_or(u32::MAX)
+    }
+
+    /// The counters as host metric samples.
+    pub fn samples(&self) -> Vec<MetricSample> {
+        let sample = |name: &str, value| MetricSample {
+            component: None,
+            name: name.to_string(),
+            labels: Vec::new(),
+            value,
+        };
+        vec![
+            sample(
+                "messaging_compressed_total",
+                MetricValue::Counter(self.compressed),
+            ),
+            sample(
+                "messaging_incompressible_total",
+                MetricValue::Counter(self.incompressible),
+            ),
+            sample(
+                "messaging_compression_ratio",
+                MetricValue::Gauge(self.ratio()),
+            ),
+            sample(
+                "messaging_compression_overhead_seconds",
+                MetricValue::Gauge(self.overhead_per_message().as_secs_f64()),
+            ),
+        ]
+    }
+}
+
+/// Transparent zstd compression of large payloads.
+///
+/// Payloads larger than the threshold are compressed and prefixed with the
+/// [`ZSTD_CODE`] multicodec tag; payloads that do not shrink are sent as-is.
+/// A compressed message's content type is the original one with
+/// [`COMPRESSION_PARAM`] appended (`application/octet-stream` when the
+/// original had none), so negotiation by content type is unaffected.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::message::MessagePayload;
+/// use airssys_wasm::messaging::codec::PayloadCompressor;
+///
+/// let compressor = PayloadCompressor::new().with_threshold(64);
+/// let payload = MessagePayload::new(vec![b'a'; 4096]);
+///
+/// let (wire, content_type) = compressor
+///     .compress(payload.clone(), Some("application/json".to_string()))
+///     .unwrap();
+/// assert!(wire.len() < payload.len());
+/// assert_eq!(content_type.as_deref(), Some("application/json; compression=zstd"));
+///
+/// let (delivered, content_type) = compressor.decompress(&wire, content_type.as_deref()).unwrap();
+/// assert_eq!(delivered, payload);
+/// assert_eq!(content_type.as_deref(), Some("application/json"));
+/// ```
+#[derive(Debug)]
+pub struct PayloadCompressor {
+    /// Payloads larger than this many bytes are compressed
+    threshold: usize,
+    /// zstd compression level
+    level: i32,
+    /// Largest payload decompression may produce
+    max_decompressed_size: usize,
+    /// Compression counters
+    stats: Mutex<CompressionStats>,
+}
+
+impl Default for PayloadCompressor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PayloadCompressor {
+    /// Creates a compressor with the default threshold, level, and
+    /// decompressed size limit.
+    pub fn new() -> Self {
+        Self {
+            threshold: DEFAULT_COMPRESSION_THRESHOLD,
+            level: DEFAULT_COMPRESSION_LEVEL,
+            max_decompressed_size: DEFAULT_MAX_DECOMPRESSED_SIZE,
+            stats: Mutex::new(CompressionStats::default()),
+        }
+    }
+
+    /// Sets the size above which payloads are compressed.
+    pub fn with_threshold(mut self, bytes: usize) -> Self {
+        self.threshold = bytes;
+        self
+    }
+
+    /// Sets the zstd compression level.
+    pub fn with_level(mut self, level: i32) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets the largest payload decompression may produce.
+    pub fn with_max_decompressed_size(mut self, bytes: usize) -> Self {
+        self.max_decompressed_size = bytes;
+        self
+    }
+
+    /// Returns the compression threshold in bytes.
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Returns a copy of the compression counters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn stats(&self) -> Result<CompressionStats, MessagingError> {
+        Ok(*self.lock()?)
+    }
+
+    /// Compresses `payload` if it is over the threshold and shrinks.
+    ///
+    /// Returns the payload and content type to put on the wire.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` - zstd failed to compress
+    /// - `MessagingError::DeliveryFailed` - Lock poisoned
+    pub fn compress(
+        &self,
+        payload: MessagePayload,
+        content_type: Option<String>,
+    ) -> Result<(MessagePayload, Option<String>), MessagingError> {
+        if payload.len() <= self.threshold || is_compressed(content_type.as_deref()) {
+            return Ok((payload, content_type));
+        }
+
+        let started = Instant::now();
+        let compressed = zstd::bulk::compress(payload.as_bytes(), self.level)
+            .map_err(|e| CodecError::EncodeFailed(e.to_string()))
+            .map_err(|e| MessagingError::InvalidMessage(e.to_string()))?;
+        let tagged = prefix_code(ZSTD_CODE, &compressed);
+
+        let mut stats = self.lock()?;
+        stats.compress_time += started.elapsed();
+        if tagged.len() >= payload.len() {
+            stats.incompressible += 1;
+            return Ok((payload, content_type));
+        }
+        stats.compressed += 1;
+        stats.original_bytes += payload.len() as u64;
+        stats.compressed_bytes += tagged.len() as u64;
+
+        let base = content_type.as_deref().unwrap_or(Codec::Raw.content_type());
+        Ok((
+            MessagePayload::new(tagged),
+            Some(format!("{}; {}", base, COMPRESSION_PARAM)),
+        ))
+    }
+
+    /// Restores a payload produced by [`compress`](Self::compress).
+    ///
+    /// Payloads whose content type lacks [`COMPRESSION_PARAM`] are returned
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` - Missing or unknown multicodec
+    ///   tag, corrupt data, or output above the decompressed size limit
+    /// - `MessagingError::DeliveryFailed` - Lock poisoned
+    pub fn decompress(
+        &self,
+        payload: &MessagePayload,
+        content_type: Option<&str>,
+    ) -> Result<(MessagePayload, Option<String>), MessagingError> {
+        if !is_compressed(content_type) {
+            return Ok((payload.clone(), content_type.map(str::to_string)));
+        }
+
+        let started = Instant::now();
+        let (code, body) = split_code(payload.as_bytes())
+            .map_err(|e| MessagingError::InvalidMessage(e.to_string()))?;
+        if code != ZSTD_CODE {
+            return Err(MessagingError::InvalidMessage(
+                CodecError::UnknownCode(code).to_string(),
+            ));
+        }
+        let bytes = zstd::bulk::decompress(body, self.max_decompressed_size)
+            .map_err(|e| CodecError::DecodeFailed(e.to_string()))
+            .map_err(|e| MessagingError::InvalidMessage(e.to_string()))?;
+
+        let mut stats = self.lock()?;
+        stats.decompressed += 1;
+        stats.decompress_time += started.elapsed();
+
+        let restored = content_type
+            .unwrap_or_default()
+            .split(';')
+            .map(str::trim)
+            .filter(|part| *part != COMPRESSION_PARAM)
+            .collect::<Vec<_>>()
+            .join("; ");
+        Ok((MessagePayload::new(bytes), Some(restored)))
+    }
+
+    /// Decompresses a delivered message in place.
+    ///
+    /// # Errors
+    ///
+    /// See [`decompress`](Self::decompress).
+    pub fn decompress_message(&self, message: &mut ComponentMessage) -> Result<(), MessagingError> {
+        if !is_compressed(message.metadata.content_type.as_deref()) {
+            return Ok(());
+        }
+        let (payload, content_type) =
+            self.decompress(&message.payload, message.metadata.content_type.as_deref())?;
+        message.payload = payload;
+        message.metadata.content_type = content_type;
+        Ok(())
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, CompressionStats>, MessagingError> {
+        self.stats
+            .lock()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))
+    }
+}
+
+/// Whether `content_type` marks a compressed payload.
+pub fn is_compressed(content_type: Option<&str>) -> bool {
+    content_type.is_some_and(|ct| {
+        ct.split(';')
+            .skip(1)
+            .any(|param| param.trim() == COMPRESSION_PARAM)
+    })
+}
+
+fn join_codecs(codecs: &[Codec]) -> String {
+    codecs
+        .iter()
+        .map(Codec::name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::component::message::MessageMetadata;
+
+    fn ids() -> (ComponentId, ComponentId) {
+        (
+            ComponentId::new("app", "sender", "v1"),
+            ComponentId::new("app", "receiver", "v1"),
+        )
+    }
+
+    fn negotiator(mode: NegotiationMode) -> CodecNegotiator {
+        CodecNegotiator::new(Arc::new(JsonCborTranscoder), mode)
+    }
+
+    #[test]
+    fn test_transcoder_json_cbor_roundtrip() {
+        let json = br#"{"count":3,"tags":["a","b"]}"#;
+        let cbor = JsonCborTranscoder
+            .transcode(json, Codec::Json, Codec::Cbor)
+            .unwrap();
+        let back = JsonCborTranscoder
+            .transcode(&cbor, Codec::Cbor, Codec::Json)
+            .unwrap();
+
+        let original: serde_json::Value = serde_json::from_slice(json).unwrap();
+        let roundtrip: serde_json::Value = serde_json::from_slice(&back).unwrap();
+        assert_eq!(original, roundtrip);
+    }
+
+    #[test]
+    fn test_transcoder_rejects_raw_conversion() {
+        assert!(!JsonCborTranscoder.supports(Codec::Raw, Codec::Json));
+        let result = JsonCborTranscoder.transcode(b"x", Codec::Raw, Codec::Json);
+        assert!(matches!(
+            result,
+            Err(CodecError::UnsupportedConversion { .. })
+        ));
+    }
+
+    #[test]
+    fn test_transcoder_invalid_json() {
+        let result = JsonCborTranscoder.transcode(b"{not json", Codec::Json, Codec::Cbor);
+        assert!(matches!(result, Err(CodecError::DecodeFailed(_))));
+    }
+
+    #[test]
+    fn test_negotiate_passthrough_without_advertisement() {
+        let (a, b) = ids();
+        let n = negotiator(NegotiationMode::Strict);
+        let payload = MessagePayload::new(vec![1, 2, 3]);
+
+        let (out, codec) = n.negotiate(&a, &b, payload.clone()).unwrap();
+        assert_eq!(out, payload);
+        assert_eq!(codec, None);
+    }
+
+    #[test]
+    fn test_negotiate_same_codec_passthrough() {
+        let (a, b) = ids();
+        let n = negotiator(NegotiationMode::Strict);
+        n.advertise(a.clone(), vec![Codec::Json]).unwrap();
+        n.advertise(b.clone(), vec![Codec::Cbor, Codec::Json])
+            .unwrap();
+        let payload = MessagePayload::new(b"[1]".to_vec());
+
+        let (out, codec) = n.negotiate(&a, &b, payload.clone()).unwrap();
+        assert_eq!(out, payload);
+        assert_eq!(codec, Some(Codec::Json));
+    }
+
+    #[test]
+    fn test_negotiate_transcodes_json_to_cbor() {
+        let (a, b) = ids();
+        let n = negotiator(NegotiationMode::Transcode);
+        n.advertise(a.clone(), vec![Codec::Json]).unwrap();
+        n.advertise(b.clone(), vec![Codec::Cbor]).unwrap();
+
+        let (out, codec) = n
+            .negotiate(&a, &b, MessagePayload::new(br#"{"k":"v"}"#.to_vec()))
+            .unwrap();
+        assert_eq!(codec, Some(Codec::Cbor));
+        let decoded: serde_json::Value = serde_cbor::from_slice(out.as_bytes()).unwrap();
+        assert_eq!(decoded["k"], "v");
+    }
+
+    #[test]
+    fn test_negotiate_strict_rejects_mismatch() {
+        let (a, b) = ids();
+        let n = negotiator(NegotiationMode::Strict);
+        n.advertise(a.clone(), vec![Codec::Json]).unwrap();
+        n.advertise(b.clone(), vec![Codec::Cbor]).unwrap();
+
+        let result = n.negotiate(&a, &b, MessagePayload::new(b"{}".to_vec()));
+        assert!(matches!(result, Err(MessagingError::CodecMismatch(_))));
+    }
+
+    #[test]
+    fn test_negotiate_no_conversion_available() {
+        let (a, b) = ids();
+        let n = negotiator(NegotiationMode::Transcode);
+        n.advertise(a.clone(), vec![Codec::Raw]).unwrap();
+        n.advertise(b.clone(), vec![Codec::Json]).unwrap();
+
+        let result = n.negotiate(&a, &b, MessagePayload::new(vec![0xff]));
+        assert!(matches!(result, Err(MessagingError::CodecMismatch(_))));
+    }
+
+    #[test]
+    fn test_negotiate_malformed_payload_is_invalid_message() {
+        let (a, b) = ids();
+        let n = negotiator(NegotiationMode::Transcode);
+        n.advertise(a.clone(), vec![Codec::Json]).unwrap();
+        n.advertise(b.clone(), vec![Codec::Cbor]).unwrap();
+
+        let result = n.negotiate(&a, &b, MessagePayload::new(b"{oops".to_vec()));
+        assert!(matches!(result, Err(MessagingError::InvalidMessage(_))));
+    }
+
+    #[test]
+    fn test_advertise_empty_and_withdraw() {
+        let (a, _) = ids();
+        let n = negotiator(NegotiationMode::Transcode);
+        n.advertise(a.clone(), vec![Codec::Json]).unwrap();
+        assert_eq!(n.advertised(&a).unwrap(), Some(vec![Codec::Json]));
+
+        n.advertise(a.clone(), vec![]).unwrap();
+        assert_eq!(n.advertised(&a).unwrap(), None);
+
+        n.advertise(a.clone(), vec![Codec::Cbor]).unwrap();
+        assert!(n.withdraw(&a).unwrap());
+        assert!(!n.withdraw(&a).unwrap());
+    }
+
+    #[test]
+    fn test_compressor_roundtrip_and_stats() {
+        let compressor = PayloadCompressor::new().with_threshold(16);
+        let large = MessagePayload::new(b"abcd".repeat(256));
+
+        let (small, ct) = compressor
+            .compress(MessagePayload::new(vec![1; 8]), None)
+            .unwrap();
+        assert_eq!(small.len(), 8);
+        assert_eq!(ct, None);
+
+        let (wire, ct) = compressor.compress(large.clone(), None).unwrap();
+        assert_eq!(
+            ct.as_deref(),
+            Some("application/octet-stream; compression=zstd")
+        );
+        assert_eq!(split_code(wire.as_bytes()).unwrap().0, ZSTD_CODE);
+
+        let mut message = ComponentMessage::new(
+            ComponentId::new("app", "sender", "v1"),
+            wire,
+            MessageMetadata {
+                content_type: ct,
+                ..MessageMetadata::default()
+            },
+        );
+        compressor.decompress_message(&mut message).unwrap();
+        assert_eq!(message.payload, large);
+        assert_eq!(
+            message.metadata.content_type.as_deref(),
+            Some("application/octet-stream")
+        );
+
+        // Random-looking bytes do not shrink and are sent as-is
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        let noise: Vec<u8> = (0..512)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state >> 32) as u8
+            })
+            .collect();
+        let (same, ct) = compressor
+            .compress(MessagePayload::new(noise.clone()), None)
+            .unwrap();
+        assert_eq!(same.as_bytes(), noise.as_slice());
+        assert_eq!(ct, None);
+
+        let stats = compressor.stats().unwrap();
+        assert_eq!(
+            (stats.compressed, stats.incompressible, stats.decompressed),
+            (1, 1, 1)
+        );
+        assert!(stats.ratio() > 10.0);
+        assert_eq!(stats.samples().len(), 4);
+    }
+
+    #[test]
+    fn test_decompress_rejects_bad_payloads() {
+        let compressor = PayloadCompressor::new().with_max_decompressed_size(100);
+        let ct = Some("application/json; compression=zstd");
+
+        let untagged = MessagePayload::new(Codec::Json.prefix(b"{}"));
+        assert!(matches!(
+            compressor.decompress(&untagged, ct),
+            Err(MessagingError::InvalidMessage(_))
+        ));
+
+        let (bomb, ct) = PayloadCompressor::new()
+            .with_threshold(0)
+            .compress(MessagePayload::new(vec![0; 1000]), None)
+            .unwrap();
+        assert!(compressor.decompress(&bomb, ct.as_deref()).is_err());
+    }
+
+    #[test]
+    fn test_codec_negotiator_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<CodecNegotiator>();
+    }
+}
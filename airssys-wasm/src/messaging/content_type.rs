@@ -0,0 +1,292 @@
+//! Enforcement of the content types components accept.
+//!
+//! Provides [`ContentTypeGuard`], which holds the
+//! [`AcceptedContentTypes`] each component declared in its manifest and
+//! rejects messages whose content type the target cannot parse with
+//! `MessagingError::ContentTypeRejected`. The router checks at the sender,
+//! after codec negotiation; the subscriber checks again after
+//! decompression, before the message reaches the mailbox.
+//!
+//! Components without a declaration accept every payload. Payloads passed
+//! by reference are not checked, since their envelope does not carry the
+//! original content type.
+//!
+//! Every checked message is counted; [`ContentTypeStats::samples`] exposes
+//! the counters, including rejections per target, as host metrics.
+//!
+//! # Architecture
+//!
+//! This module is part of `messaging/` (Layer 3B). It depends on:
+//! - `core/component/` for `ComponentId`, `ComponentMessage`
+//! - `core/config/` for `AcceptedContentTypes`
+//! - `core/management/` for `MetricSample`
+//! - `core/messaging/` for `MessagingError`
+//!
+//! # References
+//!
+//! - ADR-WASM-031: Component & Messaging Module Design
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard, RwLock};
+
+// Layer 2: Third-party crate imports
+// (none)
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::ComponentMessage;
+use crate::core::config::content_type::AcceptedContentTypes;
+use crate::core::management::metrics::{MetricSample, MetricValue};
+use crate::core::messaging::errors::MessagingError;
+use crate::messaging::payload_ref::PAYLOAD_REF_CONTENT_TYPE;
+
+/// Counters kept by a [`ContentTypeGuard`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContentTypeStats {
+    /// Messages to declaring targets whose content type was accepted.
+    pub accepted: u64,
+    /// Messages rejected because of their content type.
+    pub rejected: u64,
+    /// Rejections per target.
+    pub rejected_by_target: HashMap<ComponentId, u64>,
+}
+
+impl ContentTypeStats {
+    /// The counters as host metric samples.
+    ///
+    /// Per-target rejections are reported under the target component.
+    pub fn samples(&self) -> Vec<MetricSample> {
+        let sample = |component: Option<ComponentId>, name: &str, value: u64| MetricSample {
+            component,
+            name: name.to_string(),
+            labels: Vec::new(),
+            value: MetricValue::Counter(value),
+        };
+        let mut samples = vec![
+            sample(None, "messaging_content_type_accepted_total", self.accepted),
+            sample(None, "messaging_content_type_rejected_total", self.rejected),
+        ];
+        let mut targets: Vec<_> = self.rejected_by_target.iter().collect();
+        targets.sort_by_key(|(target, _)| target.to_string_id());
+        samples.extend(targets.into_iter().map(|(target, count)| {
+            sample(
+                Some(target.clone()),
+                "messaging_content_type_rejected_total",
+                *count,
+            )
+        }));
+        samples
+    }
+}
+
+/// Per-component content type policy.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::config::content_type::AcceptedContentTypes;
+/// use airssys_wasm::core::messaging::errors::MessagingError;
+/// use airssys_wasm::messaging::content_type::ContentTypeGuard;
+///
+/// let guard = ContentTypeGuard::new();
+/// let orders = ComponentId::new("shop", "orders", "1");
+/// guard
+///     .register(orders.clone(), AcceptedContentTypes::new(["json"]).unwrap())
+///     .unwrap();
+///
+/// assert!(guard.check(&orders, Some("application/json")).is_ok());
+/// assert!(matches!(
+///     guard.check(&orders, Some("application/cbor")),
+///     Err(MessagingError::ContentTypeRejected(_))
+/// ));
+/// assert_eq!(guard.stats().unwrap().rejected, 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct ContentTypeGuard {
+    /// Declared content types per component
+    accepted: RwLock<HashMap<ComponentId, AcceptedContentTypes>>,
+    /// Check counters
+    stats: Mutex<ContentTypeStats>,
+}
+
+impl ContentTypeGuard {
+    /// Creates a guard with no declarations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets (or replaces) the content types `component` accepts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn register(
+        &self,
+        component: ComponentId,
+        accepted: AcceptedContentTypes,
+    ) -> Result<(), MessagingError> {
+        self.accepted
+            .write()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))?
+            .insert(component, accepted);
+        Ok(())
+    }
+
+    /// Removes the declaration of `component`, which then accepts every
+    /// payload. Returns whether it had one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn remove_component(&self, component: &ComponentId) -> Result<bool, MessagingError> {
+        Ok(self
+            .accepted
+            .write()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))?
+            .remove(component)
+            .is_some())
+    }
+
+    /// Returns the content types `component` declared, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn accepted(
+        &self,
+        component: &ComponentId,
+    ) -> Result<Option<AcceptedContentTypes>, MessagingError> {
+        Ok(self
+            .accepted
+            .read()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))?
+            .get(component)
+            .cloned())
+    }
+
+    /// Returns a copy of the counters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn stats(&self) -> Result<ContentTypeStats, MessagingError> {
+        Ok(self.lock_stats()?.clone())
+    }
+
+    /// Checks that `target` accepts a payload with `content_type`.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::ContentTypeRejected` - `target` declared content
+    ///   types and `content_type` is not among them
+    /// - `MessagingError::DeliveryFailed` - Lock poisoned
+    pub fn check(
+        &self,
+        target: &ComponentId,
+        content_type: Option<&str>,
+    ) -> Result<(), MessagingError> {
+        if content_type == Some(PAYLOAD_REF_CONTENT_TYPE) {
+            return Ok(());
+        }
+        let rejection = {
+            let accepted = self
+                .accepted
+                .read()
+                .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))?;
+            match accepted.get(target) {
+                None => return Ok(()),
+                Some(types) if types.accepts(content_type) => None,
+                Some(types) => Some(format!(
+                    "{} accepts [{}] but the payload is {}",
+                    target,
+                    types,
+                    content_type.unwrap_or("untyped (application/octet-stream)")
+                )),
+            }
+        };
+
+        let mut stats = self.lock_stats()?;
+        match rejection {
+            None => {
+                stats.accepted += 1;
+                Ok(())
+            }
+            Some(reason) => {
+                stats.rejected += 1;
+                *stats.rejected_by_target.entry(target.clone()).or_insert(0) += 1;
+                Err(MessagingError::ContentTypeRejected(reason))
+            }
+        }
+    }
+
+    /// Checks a message envelope bound for `target`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`check`](Self::check).
+    pub fn check_message(
+        &self,
+        target: &ComponentId,
+        message: &ComponentMessage,
+    ) -> Result<(), MessagingError> {
+        self.check(target, message.metadata.content_type.as_deref())
+    }
+
+    fn lock_stats(&self) -> Result<MutexGuard<'_, ContentTypeStats>, MessagingError> {
+        self.stats
+            .lock()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn id(name: &str) -> ComponentId {
+        ComponentId::new("test", name, "1")
+    }
+
+    #[test]
+    fn test_undeclared_targets_accept_everything_uncounted() {
+        let guard = ContentTypeGuard::new();
+        assert!(guard.check(&id("a"), Some("text/plain")).is_ok());
+        assert!(guard.check(&id("a"), None).is_ok());
+        assert_eq!(guard.stats().unwrap(), ContentTypeStats::default());
+    }
+
+    #[test]
+    fn test_rejections_are_counted_per_target() {
+        let guard = ContentTypeGuard::new();
+        let accepted = AcceptedContentTypes::new(["cbor"]).unwrap();
+        guard.register(id("a"), accepted.clone()).unwrap();
+        guard.register(id("b"), accepted).unwrap();
+
+        assert!(guard.check(&id("a"), Some("application/cbor")).is_ok());
+        assert!(guard
+            .check(&id("a"), Some(PAYLOAD_REF_CONTENT_TYPE))
+            .is_ok());
+        let err = guard.check(&id("a"), None).unwrap_err();
+        assert!(err.to_string().contains("application/cbor"));
+        assert!(err.to_string().contains("untyped"));
+        assert!(guard.check(&id("b"), Some("application/json")).is_err());
+        assert!(guard.check(&id("b"), Some("text/plain")).is_err());
+
+        let stats = guard.stats().unwrap();
+        assert_eq!((stats.accepted, stats.rejected), (1, 3));
+        assert_eq!(stats.rejected_by_target[&id("a")], 1);
+        assert_eq!(stats.rejected_by_target[&id("b")], 2);
+
+        let samples = stats.samples();
+        assert_eq!(samples.len(), 4);
+        assert_eq!(samples[2].component, Some(id("a")));
+        assert_eq!(samples[3].value, MetricValue::Counter(2));
+
+        assert!(guard.remove_component(&id("b")).unwrap());
+        assert!(guard.accepted(&id("b")).unwrap().is_none());
+        assert!(guard.check(&id("b"), Some("text/plain")).is_ok());
+    }
+}
@@ -133,6 +133,25 @@ impl CorrelationTrackerImpl {
         }
     }
 
+    /// Lists pending correlations with the time left before each expires.
+    ///
+    /// Expired but not yet cleaned up correlations report a zero duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the internal lock is poisoned.
+    pub fn pending_requests(&self) -> Result<Vec<(String, Duration)>, MessagingError> {
+        let now = Instant::now();
+        let pending = self
+            .pending
+            .read()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))?;
+        Ok(pending
+            .iter()
+            .map(|(id, req)| (id.clone(), req.deadline.saturating_duration_since(now)))
+            .collect())
+    }
+
     /// Cleans up all expired correlations.
     ///
     /// Removes all correlations whose deadline has passed. The oneshot senders
@@ -258,6 +277,18 @@ mod tests {
         assert!(!tracker.is_pending(&id));
     }
 
+    #[test]
+    fn test_pending_requests_report_remaining_time() {
+        let tracker = CorrelationTrackerImpl::new();
+        let id = CorrelationId::new("slow-1");
+        tracker.register(&id, 60_000).unwrap();
+
+        let pending = tracker.pending_requests().unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].0, "slow-1");
+        assert!(pending[0].1 > Duration::from_secs(50));
+    }
+
     #[test]
     fn test_register_correlation() {
         let tracker = CorrelationTrackerImpl::new();
@@ -0,0 +1,373 @@
+//! Federation-aware component ID resolution.
+//!
+//! Provides [`DiscoveryService`], which answers "where does this component
+//! live" for the messaging federation layer. Sources are consulted in
+//! order:
+//!
+//! 1. The local registry, through the [`ComponentResolver`] trait.
+//! 2. Statically configured peers.
+//! 3. Pluggable [`DiscoveryBackend`]s (DNS-SD, etcd, ...), first answer wins.
+//!
+//! Remote answers are cached for a configurable TTL. Endpoints reported
+//! unreachable with [`DiscoveryService::mark_unhealthy`] are filtered out of
+//! every resolution, cached or not, until marked healthy again.
+//!
+//! # Architecture
+//!
+//! This module is part of `messaging/` (Layer 3B). It depends only on
+//! `core/component/` and `core/messaging/`; the concrete registry and
+//! backends are injected by `system/` (Layer 4).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::sync::Arc;
+//!
+//! use airssys_wasm::core::component::errors::ComponentError;
+//! use airssys_wasm::core::component::id::ComponentId;
+//! use airssys_wasm::core::component::traits::ComponentResolver;
+//! use airssys_wasm::core::messaging::discovery::{Endpoint, RemoteEndpoint, ResolutionSource};
+//! use airssys_wasm::messaging::discovery::DiscoveryService;
+//!
+//! struct NothingLocal;
+//!
+//! impl ComponentResolver for NothingLocal {
+//!     fn contains(&self, _id: &ComponentId) -> Result<bool, ComponentError> {
+//!         Ok(false)
+//!     }
+//! }
+//!
+//! let discovery = DiscoveryService::new(Arc::new(NothingLocal));
+//! let billing = ComponentId::new("finance", "billing", "v1");
+//! let peer = RemoteEndpoint::new("node-b", "10.0.0.8:7400");
+//! discovery.add_static_peer(billing.clone(), peer.clone()).unwrap();
+//!
+//! let resolution = discovery.resolve(&billing).unwrap();
+//! assert_eq!(resolution.source, ResolutionSource::StaticPeer);
+//! assert_eq!(resolution.primary(), Some(&Endpoint::Remote(peer)));
+//! ```
+
+// Layer 1: Standard library imports
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::traits::ComponentResolver;
+use crate::core::messaging::discovery::{
+    DiscoveryBackend, Endpoint, RemoteEndpoint, Resolution, ResolutionSource,
+};
+use crate::core::messaging::errors::MessagingError;
+
+/// Default lifetime of cached remote resolutions.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+struct CachedResolution {
+    endpoints: Vec<RemoteEndpoint>,
+    source: ResolutionSource,
+    resolved_at: Instant,
+}
+
+#[derive(Debug, Default)]
+struct DiscoveryState {
+    static_peers: HashMap<ComponentId, Vec<RemoteEndpoint>>,
+    cache: HashMap<ComponentId, CachedResolution>,
+    unhealthy: HashSet<String>,
+}
+
+/// Resolves component IDs to local or remote routing endpoints.
+pub struct DiscoveryService<R: ComponentResolver> {
+    local: Arc<R>,
+    backends: Vec<Arc<dyn DiscoveryBackend>>,
+    cache_ttl: Duration,
+    state: Mutex<DiscoveryState>,
+}
+
+impl<R: ComponentResolver> DiscoveryService<R> {
+    /// Creates a service resolving against `local` only.
+    pub fn new(local: Arc<R>) -> Self {
+        Self {
+            local,
+            backends: Vec::new(),
+            cache_ttl: DEFAULT_CACHE_TTL,
+            state: Mutex::new(DiscoveryState::default()),
+        }
+    }
+
+    /// Adds a discovery backend, consulted after earlier ones.
+    pub fn with_backend(mut self, backend: Arc<dyn DiscoveryBackend>) -> Self {
+        self.backends.push(backend);
+        self
+    }
+
+    /// Sets how long remote resolutions are cached (zero disables caching).
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Routes `id` to `endpoint`, after any peers already configured for it.
+    ///
+    /// # Errors
+    ///
+    /// `MessagingError::DeliveryFailed` if the service state is poisoned.
+    pub fn add_static_peer(
+        &self,
+        id: ComponentId,
+        endpoint: RemoteEndpoint,
+    ) -> Result<(), MessagingError> {
+        let mut state = self.lock()?;
+        state.cache.remove(&id);
+        let peers = state.static_peers.entry(id).or_default();
+        if !peers.contains(&endpoint) {
+            peers.push(endpoint);
+        }
+        Ok(())
+    }
+
+    /// Removes the static peers of `id`, returning how many were removed.
+    ///
+    /// # Errors
+    ///
+    /// `MessagingError::DeliveryFailed` if the service state is poisoned.
+    pub fn remove_static_peers(&self, id: &ComponentId) -> Result<usize, MessagingError> {
+        let mut state = self.lock()?;
+        state.cache.remove(id);
+        Ok(state.static_peers.remove(id).map_or(0, |peers| peers.len()))
+    }
+
+    /// Resolves `id` to its routing endpoints.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::TargetNotFound` if no source knows the component
+    /// - `MessagingError::DeliveryFailed` if every known endpoint is
+    ///   unhealthy, or the service state is poisoned
+    /// - The last backend error, if no source answered and a backend failed
+    pub fn resolve(&self, id: &ComponentId) -> Result<Resolution, MessagingError> {
+        let is_local = self
+            .local
+            .contains(id)
+            .map_err(|e| MessagingError::DeliveryFailed(e.to_string()))?;
+        if is_local {
+            return Ok(Resolution {
+                endpoints: vec![Endpoint::Local],
+                source: ResolutionSource::Local,
+                cached: false,
+            });
+        }
+
+        {
+            let mut state = self.lock()?;
+            if let Some(entry) = state.cache.get(id) {
+                if entry.resolved_at.elapsed() < self.cache_ttl {
+                    let endpoints = entry.endpoints.clone();
+                    let source = entry.source.clone();
+                    return healthy(&state, id, endpoints, source, true);
+                }
+                state.cache.remove(id);
+            }
+            if let Some(peers) = state.static_peers.get(id) {
+                let peers = peers.clone();
+                return healthy(&state, id, peers, ResolutionSource::StaticPeer, false);
+            }
+        }
+
+        // Backends may block on network I/O: query them without the lock
+        let mut last_error = None;
+        for backend in &self.backends {
+            match backend.discover(id) {
+                Ok(endpoints) if !endpoints.is_empty() => {
+                    let source = ResolutionSource::Backend(backend.name().to_string());
+                    let mut state = self.lock()?;
+                    if !self.cache_ttl.is_zero() {
+                        state.cache.insert(
+                            id.clone(),
+                            CachedResolution {
+                                endpoints: endpoints.clone(),
+                                source: source.clone(),
+                                resolved_at: Instant::now(),
+                            },
+                        );
+                    }
+                    return healthy(&state, id, endpoints, source, false);
+                }
+                Ok(_) => {}
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| MessagingError::TargetNotFound(id.to_string_id())))
+    }
+
+    /// Excludes the endpoint at `address` from resolutions.
+    ///
+    /// # Errors
+    ///
+    /// `MessagingError::DeliveryFailed` if the service state is poisoned.
+    pub fn mark_unhealthy(&self, address: &str) -> Result<(), MessagingError> {
+        self.lock()?.unhealthy.insert(address.to_string());
+        Ok(())
+    }
+
+    /// Includes the endpoint at `address` in resolutions again.
+    ///
+    /// # Errors
+    ///
+    /// `MessagingError::DeliveryFailed` if the service state is poisoned.
+    pub fn mark_healthy(&self, address: &str) -> Result<(), MessagingError> {
+        self.lock()?.unhealthy.remove(address);
+        Ok(())
+    }
+
+    /// Drops the cached resolution of `id`, e.g. after a delivery to it
+    /// failed because the component moved.
+    ///
+    /// # Errors
+    ///
+    /// `MessagingError::DeliveryFailed` if the service state is poisoned.
+    pub fn invalidate(&self, id: &ComponentId) -> Result<bool, MessagingError> {
+        Ok(self.lock()?.cache.remove(id).is_some())
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, DiscoveryState>, MessagingError> {
+        self.state.lock().map_err(|e| {
+            MessagingError::DeliveryFailed(format!("Discovery state lock poisoned: {e}"))
+        })
+    }
+}
+
+/// Builds a resolution from the healthy subset of `endpoints`.
+fn healthy(
+    state: &DiscoveryState,
+    id: &ComponentId,
+    endpoints: Vec<RemoteEndpoint>,
+    source: ResolutionSource,
+    cached: bool,
+) -> Result<Resolution, MessagingError> {
+    let endpoints: Vec<Endpoint> = endpoints
+        .into_iter()
+        .filter(|endpoint| !state.unhealthy.contains(&endpoint.address))
+        .map(Endpoint::Remote)
+        .collect();
+    if endpoints.is_empty() {
+        return Err(MessagingError::DeliveryFailed(format!(
+            "No healthy endpoint for {id}"
+        )));
+    }
+    Ok(Resolution {
+        endpoints,
+        source,
+        cached,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::component::errors::ComponentError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct MockResolver {
+        registered: Vec<ComponentId>,
+    }
+
+    impl ComponentResolver for MockResolver {
+        fn contains(&self, id: &ComponentId) -> Result<bool, ComponentError> {
+            Ok(self.registered.contains(id))
+        }
+    }
+
+    struct CountingBackend {
+        endpoints: Vec<RemoteEndpoint>,
+        queries: AtomicUsize,
+    }
+
+    impl DiscoveryBackend for CountingBackend {
+        fn name(&self) -> &str {
+            "etcd"
+        }
+
+        fn discover(&self, _id: &ComponentId) -> Result<Vec<RemoteEndpoint>, MessagingError> {
+            self.queries.fetch_add(1, Ordering::SeqCst);
+            Ok(self.endpoints.clone())
+        }
+    }
+
+    fn local_id() -> ComponentId {
+        ComponentId::new("app", "local", "v1")
+    }
+
+    fn service() -> DiscoveryService<MockResolver> {
+        DiscoveryService::new(Arc::new(MockResolver {
+            registered: vec![local_id()],
+        }))
+    }
+
+    #[test]
+    fn test_local_components_resolve_locally() {
+        let discovery = service();
+        discovery
+            .add_static_peer(local_id(), RemoteEndpoint::new("b", "10.0.0.2:7400"))
+            .unwrap();
+
+        let resolution = discovery.resolve(&local_id()).unwrap();
+        assert_eq!(resolution.source, ResolutionSource::Local);
+        assert_eq!(resolution.endpoints, vec![Endpoint::Local]);
+    }
+
+    #[test]
+    fn test_backend_answers_are_cached() {
+        let backend = Arc::new(CountingBackend {
+            endpoints: vec![RemoteEndpoint::new("c", "10.0.0.3:7400")],
+            queries: AtomicUsize::new(0),
+        });
+        let discovery = service().with_backend(backend.clone());
+        let id = ComponentId::new("app", "remote", "v1");
+
+        let first = discovery.resolve(&id).unwrap();
+        let second = discovery.resolve(&id).unwrap();
+        assert_eq!(first.source, ResolutionSource::Backend("etcd".to_string()));
+        assert!(!first.cached);
+        assert!(second.cached);
+        assert_eq!(backend.queries.load(Ordering::SeqCst), 1);
+
+        assert!(discovery.invalidate(&id).unwrap());
+        discovery.resolve(&id).unwrap();
+        assert_eq!(backend.queries.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_unhealthy_endpoints_are_skipped() {
+        let discovery = service();
+        let id = ComponentId::new("app", "remote", "v1");
+        let a = RemoteEndpoint::new("a", "10.0.0.1:7400");
+        let b = RemoteEndpoint::new("b", "10.0.0.2:7400");
+        discovery.add_static_peer(id.clone(), a.clone()).unwrap();
+        discovery.add_static_peer(id.clone(), b.clone()).unwrap();
+
+        discovery.mark_unhealthy(&a.address).unwrap();
+        let resolution = discovery.resolve(&id).unwrap();
+        assert_eq!(resolution.endpoints, vec![Endpoint::Remote(b.clone())]);
+
+        discovery.mark_unhealthy(&b.address).unwrap();
+        assert!(matches!(
+            discovery.resolve(&id),
+            Err(MessagingError::DeliveryFailed(_))
+        ));
+
+        discovery.mark_healthy(&a.address).unwrap();
+        assert_eq!(
+            discovery.resolve(&id).unwrap().primary(),
+            Some(&Endpoint::Remote(a))
+        );
+    }
+
+    #[test]
+    fn test_unknown_component_is_not_found() {
+        let result = service().resolve(&ComponentId::new("app", "ghost", "v1"));
+        assert!(matches!(result, Err(MessagingError::TargetNotFound(_))));
+    }
+}
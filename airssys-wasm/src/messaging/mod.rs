@@ -7,7 +7,21 @@
 //! - Message types (fire-and-forget, request-response)
 //! - Correlation tracking for request-response patterns
 //! - Message routing via ResponseRouter
+//! - Sticky routing of keyed messages to pool instances via AffinityRouter
+//! - Route-level payload codec negotiation via CodecNegotiator
+//! - Transparent zstd compression of large payloads via PayloadCompressor
+//! - Per-message-type payload validation via SchemaRegistry
+//! - Enforcement of the content types components accept via ContentTypeGuard
 //! - Mailbox management via ComponentSubscriber
+//! - Queued message persistence across restarts and crashes via MessageSpool
+//! - Topic fan-in batching to aggregator components via MessageAggregator
+//! - Streamed responses and chunked transfers with flow control via ResponseStreams
+//! - Reference passing for large payloads via PayloadStore
+//! - Topic broadcast with wildcard filters, per-subscriber buffering, overflow policy and per-topic lag metrics via TopicBus
+//! - Response caching for pure components via ResponseCache
+//! - Federation-aware component ID resolution via DiscoveryService
+//! - Forwarding to components on peer hosts via TcpTransport and TransportListener
+//! - End-to-end payload encryption between federated hosts via PayloadSealer
 //!
 //! ## Module Position
 //!
@@ -41,10 +55,23 @@
 //! - ADR-WASM-009: Component Communication Model
 //! - KNOWLEDGE-WASM-037: Dependency Inversion Principle
 
+pub mod affinity;
+pub mod aggregator;
+pub mod codec;
+pub mod content_type;
 pub mod correlation;
+pub mod discovery;
 pub mod patterns;
+pub mod payload_ref;
+pub mod response_cache;
 pub mod router;
+pub mod schema;
+pub mod sealing;
+pub mod spool;
+pub mod stream;
 pub mod subscriber;
+pub mod topic;
+pub mod transport;
 
 // NOTE: No re-exports per PROJECTS_STANDARD.md section 4.3.
 // Callers use namespaced access: messaging::router::ResponseRouter
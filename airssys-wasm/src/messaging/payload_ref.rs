@@ -0,0 +1,544 @@
+//! Size-adaptive payload routing for intra-host messaging.
+//!
+//! Provides [`PayloadStore`], which keeps large payloads in one shared slot
+//! and lets messages carry a small [`PayloadHandle`] instead of the bytes.
+//! Payloads at or below the inline threshold are passed through unchanged,
+//! so only messages that are expensive to copy pay for the indirection.
+//!
+//! Each slot is reference counted by the number of recipients it was routed
+//! to. A recipient fetches the payload lazily with [`PayloadStore::resolve`]
+//! (or gives it up with [`PayloadStore::release`]); the slot is freed when
+//! the last recipient is done, or when its TTL expires, whichever comes
+//! first. The host calls [`PayloadStore::purge_expired`] periodically so
+//! slots of recipients that never fetched do not accumulate.
+//!
+//! # Reference Format
+//!
+//! A reference message has `MessageMetadata::content_type` set to
+//! [`PAYLOAD_REF_CONTENT_TYPE`] and a JSON-encoded [`PayloadHandle`] as its
+//! payload. The handle records the original content type, which is restored
+//! on resolution.
+//!
+//! # Architecture
+//!
+//! This module is part of `messaging/` (Layer 3B). It depends only on
+//! `core/component/` and `core/messaging/`; delivery goes through the
+//! [`MessageSender`] implementation supplied by `system/` (Layer 4).
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// Layer 2: Third-party crate imports
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::{ComponentMessage, MessagePayload};
+use crate::core::messaging::errors::MessagingError;
+use crate::core::messaging::traits::MessageSender;
+
+/// Content type marking a message whose payload is a [`PayloadHandle`].
+pub const PAYLOAD_REF_CONTENT_TYPE: &str = "application/vnd.airssys.payload-ref+json";
+
+/// Thresholds for switching from inline to reference passing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PayloadRefConfig {
+    /// Payloads larger than this many bytes are passed by reference.
+    pub inline_threshold: usize,
+    /// How long an unfetched payload is kept.
+    pub ttl: Duration,
+}
+
+impl PayloadRefConfig {
+    /// Default inline threshold (64 KiB).
+    pub const DEFAULT_INLINE_THRESHOLD: usize = 64 * 1024;
+
+    /// Default time-to-live of a stored payload.
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(60);
+
+    /// Sets the inline threshold.
+    pub fn with_inline_threshold(mut self, bytes: usize) -> Self {
+        self.inline_threshold = bytes;
+        self
+    }
+
+    /// Sets the time-to-live of stored payloads.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+}
+
+impl Default for PayloadRefConfig {
+    fn default() -> Self {
+        Self {
+            inline_threshold: Self::DEFAULT_INLINE_THRESHOLD,
+            ttl: Self::DEFAULT_TTL,
+        }
+    }
+}
+
+/// Reference to a payload held by a [`PayloadStore`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PayloadHandle {
+    /// Slot identifier.
+    pub id: String,
+    /// Size of the referenced payload in bytes.
+    pub size: usize,
+    /// Content type of the referenced payload.
+    pub content_type: Option<String>,
+}
+
+impl PayloadHandle {
+    /// Encodes the handle as a message payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::InvalidMessage` if encoding fails.
+    pub fn encode(&self) -> Result<MessagePayload, MessagingError> {
+        serde_json::to_vec(self)
+            .map(MessagePayload::new)
+            .map_err(|e| MessagingError::InvalidMessage(e.to_string()))
+    }
+
+    /// Decodes a handle carried by a reference message.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::InvalidMessage` if the payload is not a handle.
+    pub fn decode(payload: &MessagePayload) -> Result<Self, MessagingError> {
+        serde_json::from_slice(payload.as_bytes())
+            .map_err(|e| MessagingError::InvalidMessage(e.to_string()))
+    }
+}
+
+/// Payload and content type as they should be put on the wire.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoutedPayload {
+    /// Either the original payload or an encoded [`PayloadHandle`].
+    pub payload: MessagePayload,
+    /// Content type for `MessageMetadata::content_type`.
+    pub content_type: Option<String>,
+}
+
+impl RoutedPayload {
+    /// Whether the payload was replaced by a reference.
+    pub fn is_reference(&self) -> bool {
+        self.content_type.as_deref() == Some(PAYLOAD_REF_CONTENT_TYPE)
+    }
+}
+
+/// One stored payload.
+#[derive(Debug)]
+struct Slot {
+    data: Arc<Vec<u8>>,
+    remaining: usize,
+    expires_at: Instant,
+}
+
+/// Shared storage for payloads passed by reference.
+///
+/// # Thread Safety
+///
+/// Slots are guarded by a `Mutex` that is never held across a delivery or
+/// while payload bytes are copied.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::message::MessagePayload;
+/// use airssys_wasm::messaging::payload_ref::{PayloadRefConfig, PayloadStore};
+///
+/// let store = PayloadStore::new(PayloadRefConfig::default().with_inline_threshold(4));
+///
+/// let small = store.route(MessagePayload::new(vec![1, 2]), None, 1).unwrap();
+/// assert!(!small.is_reference());
+///
+/// let large = store.route(MessagePayload::new(vec![0; 1024]), None, 2).unwrap();
+/// assert!(large.is_reference());
+/// assert_eq!(store.len(), 1);
+///
+/// let (payload, _) = store.resolve(&large.payload, large.content_type.as_deref()).unwrap();
+/// assert_eq!(payload.len(), 1024);
+/// ```
+#[derive(Debug, Default)]
+pub struct PayloadStore {
+    config: PayloadRefConfig,
+    slots: Mutex<HashMap<String, Slot>>,
+}
+
+impl PayloadStore {
+    /// Creates an empty store.
+    pub fn new(config: PayloadRefConfig) -> Self {
+        Self {
+            config,
+            slots: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the routing configuration.
+    pub fn config(&self) -> &PayloadRefConfig {
+        &self.config
+    }
+
+    /// Returns the number of payloads currently held.
+    pub fn len(&self) -> usize {
+        self.slots.lock().map(|slots| slots.len()).unwrap_or(0)
+    }
+
+    /// Returns `true` if no payloads are held.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the total size of held payloads in bytes.
+    pub fn stored_bytes(&self) -> usize {
+        self.slots
+            .lock()
+            .map(|slots| slots.values().map(|slot| slot.data.len()).sum())
+            .unwrap_or(0)
+    }
+
+    /// Prepares a payload for delivery to `recipients` targets.
+    ///
+    /// Payloads larger than the inline threshold are stored once and
+    /// replaced by a handle that each recipient may resolve exactly once.
+    /// Smaller payloads (and routes with no recipients) are returned as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::InvalidMessage` if the handle cannot be
+    /// encoded, or `MessagingError::DeliveryFailed` if the store is poisoned.
+    pub fn route(
+        &self,
+        payload: MessagePayload,
+        content_type: Option<String>,
+        recipients: usize,
+    ) -> Result<RoutedPayload, MessagingError> {
+        if recipients == 0 || payload.len() <= self.config.inline_threshold {
+            return Ok(RoutedPayload {
+                payload,
+                content_type,
+            });
+        }
+
+        let handle = PayloadHandle {
+            id: Uuid::new_v4().to_string(),
+            size: payload.len(),
+            content_type,
+        };
+        let encoded = handle.encode()?;
+        self.lock()?.insert(
+            handle.id.clone(),
+            Slot {
+                data: Arc::new(payload.into_bytes()),
+                remaining: recipients,
+                expires_at: Instant::now() + self.config.ttl,
+            },
+        );
+
+        Ok(RoutedPayload {
+            payload: encoded,
+            content_type: Some(PAYLOAD_REF_CONTENT_TYPE.to_string()),
+        })
+    }
+
+    /// Returns the actual payload and content type of a received message.
+    ///
+    /// Inline payloads are returned unchanged. For references, this consumes
+    /// the recipient's share of the slot; the last recipient takes the bytes
+    /// without copying them.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` if a reference payload is malformed
+    /// - `MessagingError::PayloadUnavailable` if the slot expired or was
+    ///   already fully consumed
+    pub fn resolve(
+        &self,
+        payload: &MessagePayload,
+        content_type: Option<&str>,
+    ) -> Result<(MessagePayload, Option<String>), MessagingError> {
+        if content_type != Some(PAYLOAD_REF_CONTENT_TYPE) {
+            return Ok((payload.clone(), content_type.map(str::to_string)));
+        }
+        let handle = PayloadHandle::decode(payload)?;
+        let data = self.take_share(&handle)?;
+        let bytes = Arc::try_unwrap(data).unwrap_or_else(|shared| shared.as_ref().clone());
+        Ok((MessagePayload::new(bytes), handle.content_type))
+    }
+
+    /// Resolves the payload of a received [`ComponentMessage`].
+    ///
+    /// # Errors
+    ///
+    /// See [`PayloadStore::resolve`].
+    pub fn resolve_message(
+        &self,
+        message: &ComponentMessage,
+    ) -> Result<(MessagePayload, Option<String>), MessagingError> {
+        self.resolve(&message.payload, message.metadata.content_type.as_deref())
+    }
+
+    /// Gives up one recipient's share without fetching the payload.
+    ///
+    /// Releasing an unknown or already freed handle is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the store is poisoned.
+    pub fn release(&self, handle: &PayloadHandle) -> Result<(), MessagingError> {
+        let mut slots = self.lock()?;
+        if let Some(slot) = slots.get_mut(&handle.id) {
+            slot.remaining = slot.remaining.saturating_sub(1);
+            if slot.remaining == 0 {
+                slots.remove(&handle.id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes every payload whose TTL has elapsed.
+    ///
+    /// Returns the number of payloads removed.
+    pub fn purge_expired(&self) -> usize {
+        let Ok(mut slots) = self.slots.lock() else {
+            return 0;
+        };
+        let now = Instant::now();
+        let before = slots.len();
+        slots.retain(|_, slot| slot.expires_at > now);
+        before - slots.len()
+    }
+
+    /// Sends one payload to several targets, storing it at most once.
+    ///
+    /// Every target is attempted. Shares of targets whose delivery failed
+    /// are released immediately.
+    ///
+    /// Returns the number of targets the message was delivered to.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first delivery error after all targets were attempted.
+    pub async fn fan_out<S: MessageSender>(
+        &self,
+        targets: &[ComponentId],
+        payload: MessagePayload,
+        sender: &S,
+    ) -> Result<usize, MessagingError> {
+        let routed = self.route(payload, None, targets.len())?;
+        let handle = if routed.is_reference() {
+            Some(PayloadHandle::decode(&routed.payload)?)
+        } else {
+            None
+        };
+
+        let mut delivered = 0;
+        let mut first_error = None;
+        for target in targets {
+            match sender.send(target, routed.payload.clone()).await {
+                Ok(()) => delivered += 1,
+                Err(e) => {
+                    if let Some(handle) = &handle {
+                        self.release(handle)?;
+                    }
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(delivered),
+        }
+    }
+
+    /// Takes one share of a slot, freeing it when no shares remain.
+    fn take_share(&self, handle: &PayloadHandle) -> Result<Arc<Vec<u8>>, MessagingError> {
+        let mut slots = self.lock()?;
+        let slot = slots.get_mut(&handle.id).ok_or_else(|| {
+            MessagingError::PayloadUnavailable(format!("{} is not held", handle.id))
+        })?;
+
+        if slot.expires_at <= Instant::now() {
+            slots.remove(&handle.id);
+            return Err(MessagingError::PayloadUnavailable(format!(
+                "{} expired",
+                handle.id
+            )));
+        }
+
+        slot.remaining = slot.remaining.saturating_sub(1);
+        let data = Arc::clone(&slot.data);
+        if slot.remaining == 0 {
+            slots.remove(&handle.id);
+        }
+        Ok(data)
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, HashMap<String, Slot>>, MessagingError> {
+        self.slots.lock().map_err(|e| {
+            MessagingError::DeliveryFailed(format!("Payload store lock poisoned: {e}"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records delivered payloads; can be switched to fail for one target.
+    #[derive(Default)]
+    struct RecordingSender {
+        sent: Mutex<Vec<(ComponentId, MessagePayload)>>,
+        fail_target: Mutex<Option<ComponentId>>,
+    }
+
+    impl MessageSender for RecordingSender {
+        async fn send(
+            &self,
+            target: &ComponentId,
+            payload: MessagePayload,
+        ) -> Result<(), MessagingError> {
+            if self.fail_target.lock().unwrap().as_ref() == Some(target) {
+                return Err(MessagingError::QueueFull);
+            }
+            self.sent.lock().unwrap().push((target.clone(), payload));
+            Ok(())
+        }
+
+        async fn send_with_correlation(
+            &self,
+            target: &ComponentId,
+            payload: MessagePayload,
+            _correlation_id: &str,
+        ) -> Result<(), MessagingError> {
+            self.send(target, payload).await
+        }
+    }
+
+    fn store() -> PayloadStore {
+        PayloadStore::new(PayloadRefConfig::default().with_inline_threshold(8))
+    }
+
+    fn targets(n: usize) -> Vec<ComponentId> {
+        (0..n)
+            .map(|i| ComponentId::new("app", format!("worker-{i}"), "v1"))
+            .collect()
+    }
+
+    #[test]
+    fn test_small_payload_stays_inline() {
+        let store = store();
+        let routed = store
+            .route(MessagePayload::new(vec![1; 8]), Some("json".into()), 3)
+            .unwrap();
+
+        assert!(!routed.is_reference());
+        assert_eq!(routed.payload.len(), 8);
+        assert_eq!(routed.content_type.as_deref(), Some("json"));
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn test_large_payload_is_shared_until_last_recipient() {
+        let store = store();
+        let routed = store
+            .route(MessagePayload::new(vec![7; 100]), Some("cbor".into()), 2)
+            .unwrap();
+        assert!(routed.is_reference());
+        assert_eq!(store.stored_bytes(), 100);
+
+        let ct = routed.content_type.as_deref();
+        let (first, first_ct) = store.resolve(&routed.payload, ct).unwrap();
+        assert_eq!(first.as_bytes(), &[7; 100][..]);
+        assert_eq!(first_ct.as_deref(), Some("cbor"));
+        assert_eq!(store.len(), 1);
+
+        let (second, _) = store.resolve(&routed.payload, ct).unwrap();
+        assert_eq!(second.len(), 100);
+        assert!(store.is_empty());
+
+        assert!(matches!(
+            store.resolve(&routed.payload, ct),
+            Err(MessagingError::PayloadUnavailable(_))
+        ));
+    }
+
+    #[test]
+    fn test_release_frees_slot_without_fetch() {
+        let store = store();
+        let routed = store
+            .route(MessagePayload::new(vec![0; 64]), None, 1)
+            .unwrap();
+        let handle = PayloadHandle::decode(&routed.payload).unwrap();
+
+        store.release(&handle).unwrap();
+        assert!(store.is_empty());
+        // Releasing twice is harmless
+        store.release(&handle).unwrap();
+    }
+
+    #[test]
+    fn test_expired_payload_is_unavailable_and_purged() {
+        let store = PayloadStore::new(
+            PayloadRefConfig::default()
+                .with_inline_threshold(0)
+                .with_ttl(Duration::ZERO),
+        );
+        let expired = store.route(MessagePayload::new(vec![1]), None, 1).unwrap();
+        let result = store.resolve(&expired.payload, expired.content_type.as_deref());
+        assert!(matches!(result, Err(MessagingError::PayloadUnavailable(_))));
+
+        store.route(MessagePayload::new(vec![2]), None, 1).unwrap();
+        assert_eq!(store.purge_expired(), 1);
+        assert!(store.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fan_out_sends_one_handle_per_target() {
+        let store = store();
+        let sender = RecordingSender::default();
+        let targets = targets(3);
+
+        let delivered = store
+            .fan_out(&targets, MessagePayload::new(vec![9; 256]), &sender)
+            .await
+            .unwrap();
+        assert_eq!(delivered, 3);
+        assert_eq!(store.len(), 1);
+
+        let sent = sender.sent.lock().unwrap().clone();
+        assert!(sent.iter().all(|(_, payload)| payload.len() < 256));
+        for (_, payload) in &sent {
+            let (resolved, _) = store
+                .resolve(payload, Some(PAYLOAD_REF_CONTENT_TYPE))
+                .unwrap();
+            assert_eq!(resolved.len(), 256);
+        }
+        assert!(store.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fan_out_releases_shares_of_failed_targets() {
+        let store = store();
+        let sender = RecordingSender::default();
+        let targets = targets(2);
+        *sender.fail_target.lock().unwrap() = Some(targets[1].clone());
+
+        let result = store
+            .fan_out(&targets, MessagePayload::new(vec![3; 64]), &sender)
+            .await;
+        assert!(matches!(result, Err(MessagingError::QueueFull)));
+
+        let (_, payload) = sender.sent.lock().unwrap()[0].clone();
+        store
+            .resolve(&payload, Some(PAYLOAD_REF_CONTENT_TYPE))
+            .unwrap();
+        assert!(store.is_empty());
+    }
+}
@@ -0,0 +1,366 @@
+//! Response caching for pure components.
+//!
+//! Provides [`ResponseCache`], an opt-in cache of request/response pairs
+//! for components declared `pure = true` in `Component.toml`. A pure
+//! component's response depends only on the request payload, so a repeat
+//! request can be answered without invoking the component again.
+//!
+//! Entries are keyed by the target component and the SHA-256 of the
+//! request payload, and expire after the component's TTL. Everything
+//! cached for a component is invalidated when it is enabled again (a new
+//! version was loaded), reconfigured, or unloaded; `system/` (Layer 4)
+//! calls [`ResponseCache::enable`] and [`ResponseCache::disable`] at those
+//! points.
+//!
+//! # Architecture
+//!
+//! This module is part of `messaging/` (Layer 3B). It depends only on
+//! `core/component/` and `core/messaging/`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use airssys_wasm::core::component::id::ComponentId;
+//! use airssys_wasm::core::component::message::MessagePayload;
+//! use airssys_wasm::messaging::response_cache::ResponseCache;
+//!
+//! let cache = ResponseCache::default();
+//! let resizer = ComponentId::new("media", "resizer", "v2");
+//! cache.enable(&resizer, None).unwrap();
+//!
+//! let request = MessagePayload::new(b"resize:320x240".to_vec());
+//! assert!(cache.lookup(&resizer, &request).unwrap().is_none());
+//! cache.store(&resizer, &request, MessagePayload::new(vec![0xFF])).unwrap();
+//! assert_eq!(
+//!     cache.lookup(&resizer, &request).unwrap(),
+//!     Some(MessagePayload::new(vec![0xFF]))
+//! );
+//! ```
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+// Layer 2: Third-party crate imports
+use sha2::{Digest, Sha256};
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::MessagePayload;
+use crate::core::messaging::errors::MessagingError;
+
+/// Response cache settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResponseCacheConfig {
+    /// TTL of components enabled without their own.
+    pub default_ttl: Duration,
+    /// Maximum cached responses across all components (at least 1).
+    pub max_entries: usize,
+}
+
+impl Default for ResponseCacheConfig {
+    /// 60 s TTL, 10 000 entries.
+    fn default() -> Self {
+        Self {
+            default_ttl: Duration::from_secs(60),
+            max_entries: 10_000,
+        }
+    }
+}
+
+/// Cache counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResponseCacheStats {
+    /// Lookups answered from the cache.
+    pub hits: u64,
+    /// Lookups for a pure component that found no live entry.
+    pub misses: u64,
+    /// Responses currently cached.
+    pub entries: usize,
+}
+
+#[derive(Debug)]
+struct CachedResponse {
+    response: MessagePayload,
+    stored_at: Instant,
+    expires_at: Instant,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    /// TTL per component with caching enabled.
+    ttls: HashMap<ComponentId, Duration>,
+    entries: HashMap<(ComponentId, [u8; 32]), CachedResponse>,
+}
+
+impl CacheState {
+    fn remove_component(&mut self, component: &ComponentId) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|(id, _), _| id != component);
+        before - self.entries.len()
+    }
+}
+
+/// Cache of responses from pure components.
+#[derive(Debug, Default)]
+pub struct ResponseCache {
+    config: ResponseCacheConfig,
+    state: Mutex<CacheState>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ResponseCache {
+    /// Creates an empty cache.
+    pub fn new(config: ResponseCacheConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// The cache settings.
+    pub fn config(&self) -> ResponseCacheConfig {
+        self.config
+    }
+
+    /// Enables caching for `component` with `ttl` (or the default TTL).
+    ///
+    /// Called whenever a pure component is (re)loaded; responses cached for
+    /// a previous version are invalidated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the internal lock is
+    /// poisoned.
+    pub fn enable(
+        &self,
+        component: &ComponentId,
+        ttl: Option<Duration>,
+    ) -> Result<(), MessagingError> {
+        let mut state = self.lock()?;
+        state.remove_component(component);
+        state
+            .ttls
+            .insert(component.clone(), ttl.unwrap_or(self.config.default_ttl));
+        Ok(())
+    }
+
+    /// Disables caching for `component` and drops its responses.
+    ///
+    /// Returns the number of responses dropped.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`enable`](Self::enable).
+    pub fn disable(&self, component: &ComponentId) -> Result<usize, MessagingError> {
+        let mut state = self.lock()?;
+        state.ttls.remove(component);
+        Ok(state.remove_component(component))
+    }
+
+    /// Whether caching is enabled for `component`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`enable`](Self::enable).
+    pub fn is_enabled(&self, component: &ComponentId) -> Result<bool, MessagingError> {
+        Ok(self.lock()?.ttls.contains_key(component))
+    }
+
+    /// Drops every response cached for `component`, keeping caching
+    /// enabled.
+    ///
+    /// Returns the number of responses dropped.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`enable`](Self::enable).
+    pub fn invalidate(&self, component: &ComponentId) -> Result<usize, MessagingError> {
+        Ok(self.lock()?.remove_component(component))
+    }
+
+    /// Returns the cached response of `target` to `request`, if live.
+    ///
+    /// Always `None` for components without caching enabled; only lookups
+    /// for enabled components count as hits or misses.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`enable`](Self::enable).
+    pub fn lookup(
+        &self,
+        target: &ComponentId,
+        request: &MessagePayload,
+    ) -> Result<Option<MessagePayload>, MessagingError> {
+        let mut state = self.lock()?;
+        if !state.ttls.contains_key(target) {
+            return Ok(None);
+        }
+
+        let key = (target.clone(), digest(request));
+        let live = match state.entries.get(&key) {
+            Some(entry) if entry.expires_at > Instant::now() => Some(entry.response.clone()),
+            Some(_) => {
+                state.entries.remove(&key);
+                None
+            }
+            None => None,
+        };
+        let counter = if live.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        Ok(live)
+    }
+
+    /// Caches `response` as the answer of `target` to `request`.
+    ///
+    /// Ignored (returns `false`) unless caching is enabled for `target`.
+    /// When the cache is full, expired entries are dropped first, then the
+    /// oldest entry.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`enable`](Self::enable).
+    pub fn store(
+        &self,
+        target: &ComponentId,
+        request: &MessagePayload,
+        response: MessagePayload,
+    ) -> Result<bool, MessagingError> {
+        let mut state = self.lock()?;
+        let Some(ttl) = state.ttls.get(target).copied() else {
+            return Ok(false);
+        };
+
+        let key = (target.clone(), digest(request));
+        if !state.entries.contains_key(&key)
+            && state.entries.len() >= self.config.max_entries.max(1)
+        {
+            let now = Instant::now();
+            state.entries.retain(|_, entry| entry.expires_at > now);
+            if state.entries.len() >= self.config.max_entries.max(1) {
+                let oldest = state
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.stored_at)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    state.entries.remove(&oldest);
+                }
+            }
+        }
+
+        let stored_at = Instant::now();
+        state.entries.insert(
+            key,
+            CachedResponse {
+                response,
+                stored_at,
+                expires_at: stored_at + ttl,
+            },
+        );
+        Ok(true)
+    }
+
+    /// Current counters.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`enable`](Self::enable).
+    pub fn stats(&self) -> Result<ResponseCacheStats, MessagingError> {
+        Ok(ResponseCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.lock()?.entries.len(),
+        })
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, CacheState>, MessagingError> {
+        self.state.lock().map_err(|e| {
+            MessagingError::DeliveryFailed(format!("Response cache lock poisoned: {e}"))
+        })
+    }
+}
+
+fn digest(payload: &MessagePayload) -> [u8; 32] {
+    Sha256::digest(payload.as_bytes()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pure() -> ComponentId {
+        ComponentId::new("media", "resizer", "v1")
+    }
+
+    fn payload(bytes: &[u8]) -> MessagePayload {
+        MessagePayload::new(bytes.to_vec())
+    }
+
+    #[test]
+    fn test_only_enabled_components_are_cached() {
+        let cache = ResponseCache::default();
+        let impure = ComponentId::new("app", "clock", "v1");
+        assert!(!cache
+            .store(&impure, &payload(b"now"), payload(b"1"))
+            .unwrap());
+        assert!(cache.lookup(&impure, &payload(b"now")).unwrap().is_none());
+
+        cache.enable(&pure(), None).unwrap();
+        assert!(cache.store(&pure(), &payload(b"a"), payload(b"A")).unwrap());
+        assert_eq!(
+            cache.lookup(&pure(), &payload(b"a")).unwrap(),
+            Some(payload(b"A"))
+        );
+        assert!(cache.lookup(&pure(), &payload(b"b")).unwrap().is_none());
+
+        let stats = cache.stats().unwrap();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+    }
+
+    #[test]
+    fn test_entries_expire_after_ttl() {
+        let cache = ResponseCache::default();
+        cache.enable(&pure(), Some(Duration::ZERO)).unwrap();
+        cache.store(&pure(), &payload(b"a"), payload(b"A")).unwrap();
+        assert!(cache.lookup(&pure(), &payload(b"a")).unwrap().is_none());
+        assert_eq!(cache.stats().unwrap().entries, 0);
+    }
+
+    #[test]
+    fn test_reload_and_disable_invalidate() {
+        let cache = ResponseCache::default();
+        cache.enable(&pure(), None).unwrap();
+        cache.store(&pure(), &payload(b"a"), payload(b"A")).unwrap();
+
+        // Loading a new version re-enables the component
+        cache.enable(&pure(), None).unwrap();
+        assert!(cache.lookup(&pure(), &payload(b"a")).unwrap().is_none());
+
+        cache.store(&pure(), &payload(b"a"), payload(b"A")).unwrap();
+        assert_eq!(cache.disable(&pure()).unwrap(), 1);
+        assert!(!cache.is_enabled(&pure()).unwrap());
+    }
+
+    #[test]
+    fn test_full_cache_evicts_oldest() {
+        let cache = ResponseCache::new(ResponseCacheConfig {
+            max_entries: 2,
+            ..ResponseCacheConfig::default()
+        });
+        cache.enable(&pure(), None).unwrap();
+        for key in [b"a", b"b", b"c"] {
+            cache.store(&pure(), &payload(key), payload(key)).unwrap();
+        }
+        assert_eq!(cache.stats().unwrap().entries, 2);
+        assert!(cache.lookup(&pure(), &payload(b"a")).unwrap().is_none());
+        assert!(cache.lookup(&pure(), &payload(b"c")).unwrap().is_some());
+    }
+}
@@ -11,6 +11,36 @@
 //! - `core/component/traits` for `ComponentResolver` (abstraction for component lookup)
 //! - `core/messaging/` for `MessagingError`, `CorrelationId`, `MessageRouter` trait
 //!
+//! When a [`CodecNegotiator`] is attached, payloads are reconciled with the
+//! target's advertised codecs before the envelope is created, and the
+//! resulting codec is recorded in `MessageMetadata::content_type`. When a
+//! [`PayloadCompressor`] is attached, payloads above its threshold are then
+//! zstd-compressed. When a [`SchemaRegistry`] is attached, the negotiated
+//! payload is validated against the target's schema before compression, so
+//! malformed messages fail at the sender. When a [`ContentTypeGuard`] is
+//! attached, messages whose negotiated content type the target did not
+//! declare are rejected first. When a [`PayloadStore`] is attached, payloads above its inline threshold are
+//! stored once and the envelope carries a reference instead. When a
+//! [`ResponseCache`] is attached, callers may answer requests to pure
+//! components from [`ResponseRouter::cached_response`] before routing them.
+//!
+//! When an [`AffinityRouter`] is attached, [`ResponseRouter::send_keyed`]
+//! addresses the pool instance a partition key is assigned to, so messages
+//! with the same key keep reaching the same instance.
+//!
+//! When remote routing is attached with [`ResponseRouter::with_remote`],
+//! targets the local resolver does not know are resolved through a
+//! [`DiscoveryService`] and the envelope is forwarded to the first remote
+//! endpoint that accepts it. Endpoints that fail are marked unhealthy.
+//! Payloads bound for other hosts are never passed by reference, since the
+//! payload store is local to this host.
+//!
+//! Messages sent with [`ResponseRouter::send_from`] or
+//! [`ResponseRouter::request_from`] inherit the priority of the message
+//! being handled, up to the router's priority cap, so a request chain keeps
+//! the urgency of the request that started it without inflating routine
+//! traffic to `Critical`.
+//!
 //! **IMPORTANT:** This module does NOT import from `component/` (Layer 3A).
 //! It uses the `ComponentResolver` trait from `core/` instead of the concrete
 //! `ComponentRegistry`. The concrete registry is injected by `system/` (Layer 4).
@@ -32,10 +62,20 @@ use crate::core::component::id::ComponentId;
 use crate::core::component::message::ComponentMessage;
 use crate::core::component::message::MessageMetadata;
 use crate::core::component::message::MessagePayload;
+use crate::core::component::message::MessagePriority;
 use crate::core::component::traits::ComponentResolver;
 use crate::core::messaging::correlation::CorrelationId;
+use crate::core::messaging::discovery::Endpoint;
 use crate::core::messaging::errors::MessagingError;
 use crate::core::messaging::traits::MessageRouter;
+use crate::core::messaging::transport::{RemoteEnvelope, RemoteTransport};
+use crate::messaging::affinity::AffinityRouter;
+use crate::messaging::codec::{CodecNegotiator, PayloadCompressor};
+use crate::messaging::content_type::ContentTypeGuard;
+use crate::messaging::discovery::DiscoveryService;
+use crate::messaging::payload_ref::PayloadStore;
+use crate::messaging::response_cache::ResponseCache;
+use crate::messaging::schema::SchemaRegistry;
 
 /// Routes messages between WASM components via component resolver lookup.
 ///
@@ -91,6 +131,30 @@ pub struct ResponseRouter<R: ComponentResolver> {
     resolver: Arc<R>,
     /// The component ID of the current sender
     current_component: ComponentId,
+    /// Optional route-level codec negotiation
+    codec_negotiator: Option<Arc<CodecNegotiator>>,
+    /// Optional compression of large payloads
+    compressor: Option<Arc<PayloadCompressor>>,
+    /// Optional validation of payloads against target schemas
+    schemas: Option<Arc<SchemaRegistry>>,
+    /// Optional rejection of content types targets cannot parse
+    content_types: Option<Arc<ContentTypeGuard>>,
+    /// Optional shared storage for payloads passed by reference
+    payload_store: Option<Arc<PayloadStore>>,
+    /// Optional cache of responses from pure components
+    response_cache: Option<Arc<ResponseCache>>,
+    /// Optional sticky routing of keyed messages to pool instances
+    affinity: Option<Arc<AffinityRouter>>,
+    /// Highest priority outgoing messages may inherit
+    priority_cap: MessagePriority,
+    /// Optional forwarding to components on peer hosts
+    remote: Option<RemoteRouting<R>>,
+}
+
+/// Resolution and transport used for targets on other hosts.
+struct RemoteRouting<R: ComponentResolver> {
+    discovery: Arc<DiscoveryService<R>>,
+    transport: Arc<dyn RemoteTransport>,
 }
 
 impl<R: ComponentResolver> ResponseRouter<R> {
@@ -105,6 +169,135 @@ impl<R: ComponentResolver> ResponseRouter<R> {
         Self {
             resolver,
             current_component,
+            codec_negotiator: None,
+            compressor: None,
+            schemas: None,
+            content_types: None,
+            payload_store: None,
+            response_cache: None,
+            affinity: None,
+            priority_cap: MessagePriority::DEFAULT_INHERITANCE_CAP,
+            remote: None,
+        }
+    }
+
+    /// Forwards messages for components on other hosts.
+    ///
+    /// # Arguments
+    ///
+    /// * `discovery` - Resolves targets the local resolver does not know
+    /// * `transport` - Delivers envelopes to the resolved peer hosts
+    pub fn with_remote(
+        mut self,
+        discovery: Arc<DiscoveryService<R>>,
+        transport: Arc<dyn RemoteTransport>,
+    ) -> Self {
+        self.remote = Some(RemoteRouting {
+            discovery,
+            transport,
+        });
+        self
+    }
+
+    /// Attaches a codec negotiator applied to every routed payload.
+    ///
+    /// # Arguments
+    ///
+    /// * `negotiator` - Shared negotiator holding component codec advertisements
+    pub fn with_codec_negotiator(mut self, negotiator: Arc<CodecNegotiator>) -> Self {
+        self.codec_negotiator = Some(negotiator);
+        self
+    }
+
+    /// Attaches a compressor applied to payloads after codec negotiation.
+    ///
+    /// # Arguments
+    ///
+    /// * `compressor` - Shared compressor; recipients' subscriber must use
+    ///   the same one (or an equivalent) to decompress on delivery
+    pub fn with_compressor(mut self, compressor: Arc<PayloadCompressor>) -> Self {
+        self.compressor = Some(compressor);
+        self
+    }
+
+    /// Attaches a schema registry checked after codec negotiation.
+    ///
+    /// # Arguments
+    ///
+    /// * `schemas` - Shared registry holding the schemas targets registered
+    pub fn with_schema_registry(mut self, schemas: Arc<SchemaRegistry>) -> Self {
+        self.schemas = Some(schemas);
+        self
+    }
+
+    /// Attaches a content type guard checked after codec negotiation.
+    ///
+    /// # Arguments
+    ///
+    /// * `guard` - Shared guard holding the content types targets declared
+    pub fn with_content_type_guard(mut self, guard: Arc<ContentTypeGuard>) -> Self {
+        self.content_types = Some(guard);
+        self
+    }
+
+    /// Attaches a payload store so large payloads are passed by reference.
+    ///
+    /// # Arguments
+    ///
+    /// * `store` - Shared store that recipients resolve references against
+    pub fn with_payload_store(mut self, store: Arc<PayloadStore>) -> Self {
+        self.payload_store = Some(store);
+        self
+    }
+
+    /// Attaches a response cache for requests to pure components.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - Shared cache populated with responses of pure components
+    pub fn with_response_cache(mut self, cache: Arc<ResponseCache>) -> Self {
+        self.response_cache = Some(cache);
+        self
+    }
+
+    /// Attaches an affinity router used by [`send_keyed`](Self::send_keyed).
+    ///
+    /// # Arguments
+    ///
+    /// * `affinity` - Shared pool rings; the subscriber should use the same
+    pub fn with_affinity_router(mut self, affinity: Arc<AffinityRouter>) -> Self {
+        self.affinity = Some(affinity);
+        self
+    }
+
+    /// Sets the highest priority outgoing messages may inherit.
+    ///
+    /// # Arguments
+    ///
+    /// * `cap` - Ceiling applied by [`MessagePriority::inherit`]; defaults
+    ///   to [`MessagePriority::DEFAULT_INHERITANCE_CAP`]
+    pub fn with_priority_cap(mut self, cap: MessagePriority) -> Self {
+        self.priority_cap = cap;
+        self
+    }
+
+    /// Returns the cached response of `target` to `payload`, if any.
+    ///
+    /// Lets a caller skip invoking a pure component for a repeat request.
+    /// Always `None` without an attached cache or when `target` is not
+    /// cacheable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the cache is unusable.
+    pub fn cached_response(
+        &self,
+        target: &ComponentId,
+        payload: &MessagePayload,
+    ) -> Result<Option<MessagePayload>, MessagingError> {
+        match &self.response_cache {
+            Some(cache) => cache.lookup(target, payload),
+            None => Ok(None),
         }
     }
 
@@ -122,6 +315,8 @@ impl<R: ComponentResolver> ResponseRouter<R> {
     ///
     /// * `payload` - The message payload bytes
     /// * `correlation_id` - Optional correlation ID for request-response patterns
+    /// * `content_type` - Optional content type of the payload
+    /// * `priority` - Scheduling priority of the message
     ///
     /// # Returns
     ///
@@ -130,6 +325,8 @@ impl<R: ComponentResolver> ResponseRouter<R> {
         &self,
         payload: MessagePayload,
         correlation_id: Option<String>,
+        content_type: Option<String>,
+        priority: MessagePriority,
     ) -> ComponentMessage {
         let timestamp_ms = Utc::now().timestamp_millis() as u64;
 
@@ -140,14 +337,69 @@ impl<R: ComponentResolver> ResponseRouter<R> {
                 correlation_id,
                 reply_to: Some(self.current_component.clone()),
                 timestamp_ms,
-                content_type: None,
+                content_type,
+                idempotency_key: None,
+                partition_key: None,
+                deadline_ms: None,
+                priority,
             },
         )
     }
-}
 
-impl<R: ComponentResolver> MessageRouter for ResponseRouter<R> {
-    fn send(&self, target: &ComponentId, payload: MessagePayload) -> Result<(), MessagingError> {
+    /// Applies codec negotiation, content type and schema checks, compression and
+    /// reference passing (if configured) and builds the envelope, inheriting the `parent` priority.
+    fn prepare_message(
+        &self,
+        target: &ComponentId,
+        payload: MessagePayload,
+        correlation_id: Option<String>,
+        parent: MessagePriority,
+    ) -> Result<ComponentMessage, MessagingError> {
+        self.encode_message(target, payload, correlation_id, parent, true)
+    }
+
+    fn encode_message(
+        &self,
+        target: &ComponentId,
+        payload: MessagePayload,
+        correlation_id: Option<String>,
+        parent: MessagePriority,
+        by_reference: bool,
+    ) -> Result<ComponentMessage, MessagingError> {
+        let (payload, codec) = match &self.codec_negotiator {
+            Some(negotiator) => negotiator.negotiate(&self.current_component, target, payload)?,
+            None => (payload, None),
+        };
+        let content_type = codec.map(|c| c.content_type().to_string());
+        if let Some(guard) = &self.content_types {
+            guard.check(target, content_type.as_deref())?;
+        }
+        if let Some(schemas) = &self.schemas {
+            schemas.validate(target, &payload, content_type.as_deref())?;
+        }
+        let (payload, content_type) = match &self.compressor {
+            Some(compressor) => compressor.compress(payload, content_type)?,
+            None => (payload, content_type),
+        };
+        let (payload, content_type) = match &self.payload_store {
+            Some(store) if by_reference => {
+                let routed = store.route(payload, content_type, 1)?;
+                (routed.payload, routed.content_type)
+            }
+            _ => (payload, content_type),
+        };
+        let priority = MessagePriority::Normal.inherit(parent, self.priority_cap);
+        Ok(self.create_message(payload, correlation_id, content_type, priority))
+    }
+
+    /// Verifies `target` exists and builds the envelope for it.
+    fn route(
+        &self,
+        target: &ComponentId,
+        payload: MessagePayload,
+        correlation_id: Option<String>,
+        parent: MessagePriority,
+    ) -> Result<ComponentMessage, MessagingError> {
         // Verify target exists via the resolver trait
         let exists = self
             .resolver
@@ -155,11 +407,117 @@ impl<R: ComponentResolver> MessageRouter for ResponseRouter<R> {
             .map_err(|e| MessagingError::DeliveryFailed(e.to_string()))?;
 
         if !exists {
-            return Err(MessagingError::TargetNotFound(target.to_string_id()));
+            return match &self.remote {
+                Some(remote) => self.forward(remote, target, payload, correlation_id, parent),
+                None => Err(MessagingError::TargetNotFound(target.to_string_id())),
+            };
         }
 
-        // Create the message envelope
-        let _message = self.create_message(payload, None);
+        // Create the message envelope (negotiating codecs when configured)
+        self.prepare_message(target, payload, correlation_id, parent)
+    }
+
+    /// Forwards the envelope for `target` to the first remote endpoint
+    /// that accepts it, marking failed endpoints unhealthy.
+    fn forward(
+        &self,
+        remote: &RemoteRouting<R>,
+        target: &ComponentId,
+        payload: MessagePayload,
+        correlation_id: Option<String>,
+        parent: MessagePriority,
+    ) -> Result<ComponentMessage, MessagingError> {
+        let resolution = remote.discovery.resolve(target)?;
+        let envelope = RemoteEnvelope {
+            target: target.clone(),
+            message: self.encode_message(target, payload, correlation_id, parent, false)?,
+        };
+
+        let mut last_error = None;
+        for endpoint in &resolution.endpoints {
+            let Endpoint::Remote(endpoint) = endpoint else {
+                continue;
+            };
+            match remote.transport.forward(endpoint, &envelope) {
+                Ok(()) => return Ok(envelope.message),
+                Err(e) => {
+                    remote.discovery.mark_unhealthy(&endpoint.address)?;
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(last_error.unwrap_or_else(|| MessagingError::TargetNotFound(target.to_string_id())))
+    }
+
+    /// Builds a message sent while handling `parent`.
+    ///
+    /// Like [`MessageRouter::send`], but the message inherits `parent`'s
+    /// priority up to the router's cap and is returned for delivery.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`MessageRouter::send`].
+    pub fn send_from(
+        &self,
+        parent: &MessageMetadata,
+        target: &ComponentId,
+        payload: MessagePayload,
+    ) -> Result<ComponentMessage, MessagingError> {
+        self.route(target, payload, None, parent.priority)
+    }
+
+    /// Builds a request sent while handling `parent`.
+    ///
+    /// Like [`MessageRouter::request`], but the request inherits `parent`'s
+    /// priority up to the router's cap and is returned for delivery; its
+    /// correlation ID is in the metadata.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`MessageRouter::request`].
+    pub fn request_from(
+        &self,
+        parent: &MessageMetadata,
+        target: &ComponentId,
+        payload: MessagePayload,
+    ) -> Result<ComponentMessage, MessagingError> {
+        let correlation_id = CorrelationId::generate();
+        self.route(
+            target,
+            payload,
+            Some(correlation_id.as_str().to_owned()),
+            parent.priority,
+        )
+    }
+
+    /// Builds a message carrying `partition_key` for the pool instance of
+    /// `target` the key is assigned to.
+    ///
+    /// Returns the chosen instance with the message. Without an affinity
+    /// router, or when `target` has no pool, the message is for `target`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`MessageRouter::send`].
+    pub fn send_keyed(
+        &self,
+        target: &ComponentId,
+        partition_key: &str,
+        payload: MessagePayload,
+    ) -> Result<(ComponentId, ComponentMessage), MessagingError> {
+        let instance = match &self.affinity {
+            Some(affinity) => affinity.resolve(target, Some(partition_key))?,
+            None => target.clone(),
+        };
+        let mut message = self.route(&instance, payload, None, MessagePriority::Normal)?;
+        message.metadata.partition_key = Some(partition_key.to_string());
+        Ok((instance, message))
+    }
+}
+
+impl<R: ComponentResolver> MessageRouter for ResponseRouter<R> {
+    fn send(&self, target: &ComponentId, payload: MessagePayload) -> Result<(), MessagingError> {
+        let _message = self.route(target, payload, None, MessagePriority::Normal)?;
 
         // NOTE: Actual delivery to actor mailbox will be wired up by system/ (Layer 4).
         // The resolver lookup validates the target exists. The message is created and
@@ -177,18 +535,13 @@ impl<R: ComponentResolver> MessageRouter for ResponseRouter<R> {
         // Generate unique correlation ID
         let correlation_id = CorrelationId::generate();
 
-        // Verify target exists via the resolver trait
-        let exists = self
-            .resolver
-            .contains(target)
-            .map_err(|e| MessagingError::DeliveryFailed(e.to_string()))?;
-
-        if !exists {
-            return Err(MessagingError::TargetNotFound(target.to_string_id()));
-        }
-
         // Create the message envelope with correlation ID
-        let _message = self.create_message(payload, Some(correlation_id.as_str().to_owned()));
+        let _message = self.route(
+            target,
+            payload,
+            Some(correlation_id.as_str().to_owned()),
+            MessagePriority::Normal,
+        )?;
 
         // NOTE: Actual delivery and timeout tracking wired up by system/ (Layer 4).
 
@@ -206,6 +559,8 @@ impl<R: ComponentResolver> MessageRouter for ResponseRouter<R> {
 mod tests {
     use super::*;
     use crate::core::component::errors::ComponentError;
+    use crate::core::multicodec::codec::Codec;
+    use crate::messaging::codec::{JsonCborTranscoder, NegotiationMode};
 
     // ---------------------------------------------------------------
     // Mock ComponentResolver for testing (no dependency on component/)
@@ -371,7 +726,7 @@ mod tests {
         let router = create_router();
         let payload = MessagePayload::new(vec![10, 20, 30]);
 
-        let message = router.create_message(payload.clone(), None);
+        let message = router.create_message(payload.clone(), None, None, MessagePriority::Normal);
 
         assert_eq!(message.sender.to_string_id(), "app/sender/v1");
         assert_eq!(message.payload, payload);
@@ -392,7 +747,12 @@ mod tests {
         let payload = MessagePayload::new(vec![1, 2, 3]);
         let correlation = "test-correlation-789".to_string();
 
-        let message = router.create_message(payload.clone(), Some(correlation.clone()));
+        let message = router.create_message(
+            payload.clone(),
+            Some(correlation.clone()),
+            None,
+            MessagePriority::Normal,
+        );
 
         assert_eq!(message.sender.to_string_id(), "app/sender/v1");
         assert_eq!(message.payload, payload);
@@ -405,7 +765,7 @@ mod tests {
         let router = create_router();
         let payload = MessagePayload::new(vec![42]);
 
-        let message = router.create_message(payload, None);
+        let message = router.create_message(payload, None, None, MessagePriority::Normal);
 
         // Timestamp should be a recent value (not zero, and reasonable epoch millis)
         assert!(message.metadata.timestamp_ms > 0);
@@ -413,6 +773,314 @@ mod tests {
         assert!(message.metadata.timestamp_ms > 1_577_836_800_000);
     }
 
+    #[test]
+    fn test_messages_inherit_capped_parent_priority() {
+        let (router, target) = create_router_with_target();
+        let mut parent = MessageMetadata::default();
+        let payload = || MessagePayload::new(vec![1]);
+
+        let message = router.send_from(&parent, &target, payload()).unwrap();
+        assert_eq!(message.metadata.priority, MessagePriority::Normal);
+
+        parent.priority = MessagePriority::High;
+        let message = router.request_from(&parent, &target, payload()).unwrap();
+        assert_eq!(message.metadata.priority, MessagePriority::High);
+        assert!(message.metadata.correlation_id.is_some());
+
+        // Critical is capped so a chain cannot escalate to host-critical traffic
+        parent.priority = MessagePriority::Critical;
+        let message = router.send_from(&parent, &target, payload()).unwrap();
+        assert_eq!(message.metadata.priority, MessagePriority::High);
+
+        let router = router.with_priority_cap(MessagePriority::Normal);
+        let message = router.send_from(&parent, &target, payload()).unwrap();
+        assert_eq!(message.metadata.priority, MessagePriority::Normal);
+
+        assert!(matches!(
+            router.send_from(
+                &parent,
+                &ComponentId::new("app", "missing", "v1"),
+                payload()
+            ),
+            Err(MessagingError::TargetNotFound(_))
+        ));
+    }
+
+    // ---------------------------------------------------------------
+    // Codec negotiation tests
+    // ---------------------------------------------------------------
+
+    fn create_negotiating_router(
+        mode: NegotiationMode,
+    ) -> (
+        ResponseRouter<MockResolver>,
+        ComponentId,
+        Arc<CodecNegotiator>,
+    ) {
+        let (router, target) = create_router_with_target();
+        let negotiator = Arc::new(CodecNegotiator::new(Arc::new(JsonCborTranscoder), mode));
+        negotiator
+            .advertise(router.current_component().clone(), vec![Codec::Json])
+            .unwrap();
+        negotiator
+            .advertise(target.clone(), vec![Codec::Cbor])
+            .unwrap();
+        let router = router.with_codec_negotiator(Arc::clone(&negotiator));
+        (router, target, negotiator)
+    }
+
+    #[test]
+    fn test_prepare_message_transcodes_and_sets_content_type() {
+        let (router, target, _) = create_negotiating_router(NegotiationMode::Transcode);
+        let payload = MessagePayload::new(br#"{"a":1}"#.to_vec());
+
+        let message = router
+            .prepare_message(&target, payload, None, MessagePriority::Normal)
+            .unwrap();
+        assert_eq!(
+            message.metadata.content_type.as_deref(),
+            Some("application/cbor")
+        );
+        let value: serde_json::Value = serde_cbor::from_slice(message.payload.as_bytes()).unwrap();
+        assert_eq!(value["a"], 1);
+    }
+
+    #[test]
+    fn test_send_rejects_payload_violating_target_schema() {
+        use crate::messaging::schema::{PayloadSchema, ANY_MESSAGE_TYPE};
+
+        let (router, target, _) = create_negotiating_router(NegotiationMode::Transcode);
+        let schemas = Arc::new(SchemaRegistry::new());
+        schemas
+            .register(
+                target.clone(),
+                ANY_MESSAGE_TYPE,
+                PayloadSchema::JsonSchema(serde_json::json!({ "required": ["a"] })),
+            )
+            .unwrap();
+        let router = router.with_schema_registry(schemas);
+
+        // Validated after transcoding, so the CBOR form is checked
+        assert!(router
+            .send(&target, MessagePayload::new(br#"{"a":1}"#.to_vec()))
+            .is_ok());
+        let result = router.send(&target, MessagePayload::new(br#"{"b":1}"#.to_vec()));
+        assert!(matches!(result, Err(MessagingError::SchemaViolation(_))));
+    }
+
+    #[test]
+    fn test_send_rejects_content_type_target_does_not_accept() {
+        use crate::core::config::content_type::AcceptedContentTypes;
+
+        let (router, target, _) = create_negotiating_router(NegotiationMode::Transcode);
+        let guard = Arc::new(ContentTypeGuard::new());
+        let router = router.with_content_type_guard(Arc::clone(&guard));
+        guard
+            .register(target.clone(), AcceptedContentTypes::new(["json"]).unwrap())
+            .unwrap();
+
+        // Checked after negotiation transcoded the payload to CBOR
+        let result = router.send(&target, MessagePayload::new(br#"{"a":1}"#.to_vec()));
+        assert!(matches!(
+            result,
+            Err(MessagingError::ContentTypeRejected(_))
+        ));
+
+        guard
+            .register(target.clone(), AcceptedContentTypes::new(["cbor"]).unwrap())
+            .unwrap();
+        assert!(router
+            .send(&target, MessagePayload::new(br#"{"a":1}"#.to_vec()))
+            .is_ok());
+        assert_eq!(guard.stats().unwrap().rejected_by_target[&target], 1);
+    }
+
+    #[test]
+    fn test_send_keyed_targets_assigned_pool_instance() {
+        let instances: Vec<ComponentId> = ["0", "1"]
+            .into_iter()
+            .map(|i| ComponentId::new("shop", "cart", i))
+            .collect();
+        let resolver = Arc::new(MockResolver {
+            registered: instances.clone(),
+        });
+        let affinity = Arc::new(AffinityRouter::new());
+        for id in &instances {
+            affinity.add_instance(id.clone()).unwrap();
+        }
+        let router = ResponseRouter::new(resolver, ComponentId::new("app", "sender", "v1"))
+            .with_affinity_router(Arc::clone(&affinity));
+
+        let (instance, message) = router
+            .send_keyed(&instances[0], "customer-3", MessagePayload::new(vec![1]))
+            .unwrap();
+        assert_eq!(
+            instance,
+            affinity.resolve(&instances[0], Some("customer-3")).unwrap()
+        );
+        assert_eq!(
+            message.metadata.partition_key.as_deref(),
+            Some("customer-3")
+        );
+
+        let (again, _) = router
+            .send_keyed(&instances[1], "customer-3", MessagePayload::new(vec![2]))
+            .unwrap();
+        assert_eq!(again, instance);
+    }
+
+    #[test]
+    fn test_send_strict_codec_mismatch() {
+        let (router, target, _) = create_negotiating_router(NegotiationMode::Strict);
+        let result = router.send(&target, MessagePayload::new(b"{}".to_vec()));
+        assert!(matches!(result, Err(MessagingError::CodecMismatch(_))));
+    }
+
+    #[test]
+    fn test_request_strict_accepts_matching_codec() {
+        let (router, target, negotiator) = create_negotiating_router(NegotiationMode::Strict);
+        negotiator
+            .advertise(target.clone(), vec![Codec::Cbor, Codec::Json])
+            .unwrap();
+        let result = router.request(&target, MessagePayload::new(b"{}".to_vec()), 1000);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_prepare_message_compresses_after_negotiation() {
+        let (router, target, _negotiator) = create_negotiating_router(NegotiationMode::Transcode);
+        let compressor = Arc::new(PayloadCompressor::new().with_threshold(32));
+        let router = router.with_compressor(Arc::clone(&compressor));
+
+        let json = format!("[{}]", vec!["1"; 200].join(","));
+        let message = router
+            .prepare_message(
+                &target,
+                MessagePayload::new(json.into_bytes()),
+                None,
+                MessagePriority::Normal,
+            )
+            .unwrap();
+        assert_eq!(
+            message.metadata.content_type.as_deref(),
+            Some("application/cbor; compression=zstd")
+        );
+
+        let (payload, content_type) = compressor
+            .decompress(&message.payload, message.metadata.content_type.as_deref())
+            .unwrap();
+        assert_eq!(content_type.as_deref(), Some(Codec::Cbor.content_type()));
+        let value: serde_json::Value = serde_cbor::from_slice(payload.as_bytes()).unwrap();
+        assert_eq!(value.as_array().map(Vec::len), Some(200));
+    }
+
+    #[test]
+    fn test_prepare_message_passes_large_payload_by_reference() {
+        use crate::messaging::payload_ref::{PayloadRefConfig, PAYLOAD_REF_CONTENT_TYPE};
+
+        let (router, target, _negotiator) = create_negotiating_router(NegotiationMode::Transcode);
+        let store = Arc::new(PayloadStore::new(
+            PayloadRefConfig::default().with_inline_threshold(4),
+        ));
+        let router = router.with_payload_store(Arc::clone(&store));
+
+        let message = router
+            .prepare_message(
+                &target,
+                MessagePayload::new(b"{\"n\":12345}".to_vec()),
+                None,
+                MessagePriority::Normal,
+            )
+            .unwrap();
+        assert_eq!(
+            message.metadata.content_type.as_deref(),
+            Some(PAYLOAD_REF_CONTENT_TYPE)
+        );
+        assert_eq!(store.len(), 1);
+
+        // Recipient sees the transcoded payload and its codec
+        let (payload, content_type) = store.resolve_message(&message).unwrap();
+        assert_eq!(content_type.as_deref(), Some(Codec::Cbor.content_type()));
+        assert_ne!(payload.as_bytes(), b"{\"n\":12345}");
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn test_send_forwards_unknown_target_to_remote_endpoint() {
+        use crate::core::messaging::discovery::RemoteEndpoint;
+        use std::sync::Mutex;
+
+        struct FlakyTransport(Mutex<Vec<String>>);
+
+        impl RemoteTransport for FlakyTransport {
+            fn forward(
+                &self,
+                endpoint: &RemoteEndpoint,
+                envelope: &RemoteEnvelope,
+            ) -> Result<(), MessagingError> {
+                self.0.lock().unwrap().push(endpoint.address.clone());
+                if endpoint.node == "down" {
+                    return Err(MessagingError::DeliveryFailed("refused".to_string()));
+                }
+                assert_eq!(envelope.message.payload.as_bytes(), b"hi");
+                Ok(())
+            }
+        }
+
+        let resolver = Arc::new(MockResolver::empty());
+        let discovery = Arc::new(DiscoveryService::new(Arc::clone(&resolver)));
+        let target = ComponentId::new("app", "remote", "v1");
+        for (node, address) in [("down", "10.0.0.1:7400"), ("up", "10.0.0.2:7400")] {
+            discovery
+                .add_static_peer(target.clone(), RemoteEndpoint::new(node, address))
+                .unwrap();
+        }
+        let transport = Arc::new(FlakyTransport(Mutex::new(Vec::new())));
+        let router = ResponseRouter::new(resolver, ComponentId::new("app", "sender", "v1"))
+            .with_remote(Arc::clone(&discovery), transport.clone());
+
+        router
+            .send(&target, MessagePayload::new(b"hi".to_vec()))
+            .unwrap();
+        assert_eq!(
+            *transport.0.lock().unwrap(),
+            vec!["10.0.0.1:7400", "10.0.0.2:7400"]
+        );
+
+        // The failed endpoint is skipped from now on
+        router
+            .send(&target, MessagePayload::new(b"hi".to_vec()))
+            .unwrap();
+        assert_eq!(transport.0.lock().unwrap().len(), 3);
+
+        let unknown = ComponentId::new("app", "nowhere", "v1");
+        let result = router.send(&unknown, MessagePayload::new(b"hi".to_vec()));
+        assert!(matches!(result, Err(MessagingError::TargetNotFound(_))));
+    }
+
+    #[test]
+    fn test_cached_response_answers_pure_targets_only() {
+        let (router, target) = create_router_with_target();
+        let request = MessagePayload::new(b"square:4".to_vec());
+        assert!(router.cached_response(&target, &request).unwrap().is_none());
+
+        let cache = Arc::new(ResponseCache::default());
+        let router = router.with_response_cache(Arc::clone(&cache));
+        cache
+            .store(&target, &request, MessagePayload::new(b"16".to_vec()))
+            .unwrap();
+        assert!(router.cached_response(&target, &request).unwrap().is_none());
+
+        cache.enable(&target, None).unwrap();
+        cache
+            .store(&target, &request, MessagePayload::new(b"16".to_vec()))
+            .unwrap();
+        assert_eq!(
+            router.cached_response(&target, &request).unwrap(),
+            Some(MessagePayload::new(b"16".to_vec()))
+        );
+    }
+
     // ---------------------------------------------------------------
     // Thread safety tests
     // ---------------------------------------------------------------
@@ -0,0 +1,757 @@
+//! Per-message-type payload schemas.
+//!
+//! Provides [`SchemaRegistry`], where components register a schema for each
+//! message type they accept. The host validates payloads against the
+//! target's schema when a message is routed and again before it is pushed to
+//! the target's mailbox, so malformed messages are rejected with
+//! `MessagingError::SchemaViolation` before they reach the guest.
+//!
+//! A message's type is the [`MESSAGE_TYPE_PARAM`] parameter of its content
+//! type (`application/json; type=order.created`). Messages without one, or
+//! whose type has no schema, are checked against the schema registered
+//! under [`ANY_MESSAGE_TYPE`], if any. Components that registered nothing
+//! receive any payload.
+//!
+//! Schemas are either JSON Schema documents or [`WitType`] descriptions,
+//! which are lowered to JSON Schema on registration. JSON and CBOR payloads
+//! are validated; a payload without a content type is treated as JSON.
+//!
+//! # Supported JSON Schema keywords
+//!
+//! `type`, `enum`, `const`, `minimum`, `maximum`, `exclusiveMinimum`,
+//! `exclusiveMaximum`, `minLength`, `maxLength`, `pattern`, `properties`,
+//! `required`, `additionalProperties`, `items`, `minItems`, `maxItems`,
+//! `allOf` and `anyOf`. Other keywords are ignored.
+//!
+//! # Architecture
+//!
+//! This module is part of `messaging/` (Layer 3B). It depends on:
+//! - `core/component/` for `ComponentId`, `ComponentMessage`, `MessagePayload`
+//! - `core/messaging/` for `MessagingError`
+//! - `core/multicodec/` for `Codec`
+//!
+//! # References
+//!
+//! - ADR-WASM-031: Component & Messaging Module Design
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+// Layer 2: Third-party crate imports
+use regex::Regex;
+use serde_json::{json, Map, Value};
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::{ComponentMessage, MessagePayload};
+use crate::core::messaging::errors::MessagingError;
+use crate::core::multicodec::codec::Codec;
+use crate::messaging::codec::is_compressed;
+use crate::messaging::payload_ref::PAYLOAD_REF_CONTENT_TYPE;
+
+/// Content-type parameter naming the message type.
+pub const MESSAGE_TYPE_PARAM: &str = "type";
+
+/// Message type whose schema applies to messages without a more specific one.
+pub const ANY_MESSAGE_TYPE: &str = "*";
+
+/// A WIT value type, used to describe payloads in the component's own terms.
+///
+/// Lowered to the JSON Schema matching the JSON form of the value: records
+/// are objects, enums are strings, `option` fields may be absent or `null`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WitType {
+    /// `bool`
+    Bool,
+    /// `u8`
+    U8,
+    /// `u16`
+    U16,
+    /// `u32`
+    U32,
+    /// `u64`
+    U64,
+    /// `s8`
+    S8,
+    /// `s16`
+    S16,
+    /// `s32`
+    S32,
+    /// `s64`
+    S64,
+    /// `f32` or `f64`
+    Float,
+    /// `char`
+    Char,
+    /// `string`
+    String,
+    /// `list<T>`
+    List(Box<WitType>),
+    /// `option<T>`
+    Option(Box<WitType>),
+    /// `record { name: T, ... }`
+    Record(Vec<(String, WitType)>),
+    /// `enum { case, ... }`
+    Enum(Vec<String>),
+}
+
+impl WitType {
+    /// Returns the equivalent JSON Schema.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use airssys_wasm::messaging::schema::WitType;
+    ///
+    /// let schema = WitType::List(Box::new(WitType::U8)).to_json_schema();
+    /// assert_eq!(schema["type"], "array");
+    /// assert_eq!(schema["items"]["maximum"], 255);
+    /// ```
+    pub fn to_json_schema(&self) -> Value {
+        match self {
+            WitType::Bool => json!({ "type": "boolean" }),
+            WitType::U8 => integer(0, u8::MAX),
+            WitType::U16 => integer(0, u16::MAX),
+            WitType::U32 => integer(0, u32::MAX),
+            WitType::U64 => integer(0, u64::MAX),
+            WitType::S8 => integer(i8::MIN, i8::MAX),
+            WitType::S16 => integer(i16::MIN, i16::MAX),
+            WitType::S32 => integer(i32::MIN, i32::MAX),
+            WitType::S64 => integer(i64::MIN, i64::MAX),
+            WitType::Float => json!({ "type": "number" }),
+            WitType::Char => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+            WitType::String => json!({ "type": "string" }),
+            WitType::List(item) => json!({ "type": "array", "items": item.to_json_schema() }),
+            WitType::Option(inner) => {
+                json!({ "anyOf": [{ "type": "null" }, inner.to_json_schema()] })
+            }
+            WitType::Record(fields) => {
+                let properties: Map<String, Value> = fields
+                    .iter()
+                    .map(|(name, ty)| (name.clone(), ty.to_json_schema()))
+                    .collect();
+                let required: Vec<&String> = fields
+                    .iter()
+                    .filter(|(_, ty)| !matches!(ty, WitType::Option(_)))
+                    .map(|(name, _)| name)
+                    .collect();
+                json!({
+                    "type": "object",
+                    "properties": properties,
+                    "required": required,
+                    "additionalProperties": false,
+                })
+            }
+            WitType::Enum(cases) => json!({ "type": "string", "enum": cases }),
+        }
+    }
+}
+
+fn integer(min: impl Into<Value>, max: impl Into<Value>) -> Value {
+    json!({ "type": "integer", "minimum": min.into(), "maximum": max.into() })
+}
+
+/// Schema registered for a message type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PayloadSchema {
+    /// A JSON Schema document (object or boolean).
+    JsonSchema(Value),
+    /// A WIT type, lowered to JSON Schema on registration.
+    Wit(WitType),
+}
+
+impl PayloadSchema {
+    fn into_json_schema(self) -> Value {
+        match self {
+            PayloadSchema::JsonSchema(schema) => schema,
+            PayloadSchema::Wit(ty) => ty.to_json_schema(),
+        }
+    }
+}
+
+/// Registered schemas of one component, keyed by message type.
+type ComponentSchemas = HashMap<String, Arc<Value>>;
+
+/// Registered schemas of every component.
+type SchemaTable = HashMap<ComponentId, ComponentSchemas>;
+
+/// Payload schemas per component and message type.
+///
+/// # Thread Safety
+///
+/// Uses `RwLock<HashMap>`, so validations on the routing and delivery paths
+/// proceed concurrently.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::component::message::MessagePayload;
+/// use airssys_wasm::core::messaging::errors::MessagingError;
+/// use airssys_wasm::messaging::schema::{PayloadSchema, SchemaRegistry, WitType};
+///
+/// let registry = SchemaRegistry::new();
+/// let orders = ComponentId::new("shop", "orders", "v1");
+/// let schema = WitType::Record(vec![
+///     ("sku".to_string(), WitType::String),
+///     ("qty".to_string(), WitType::U32),
+/// ]);
+/// registry
+///     .register(orders.clone(), "order.created", PayloadSchema::Wit(schema))
+///     .unwrap();
+///
+/// let content_type = Some("application/json; type=order.created");
+/// let good = MessagePayload::new(br#"{"sku":"A-1","qty":2}"#.to_vec());
+/// let bad = MessagePayload::new(br#"{"sku":"A-1","qty":-2}"#.to_vec());
+/// assert!(registry.validate(&orders, &good, content_type).is_ok());
+/// assert!(matches!(
+///     registry.validate(&orders, &bad, content_type),
+///     Err(MessagingError::SchemaViolation(_))
+/// ));
+/// ```
+#[derive(Debug, Default)]
+pub struct SchemaRegistry {
+    schemas: RwLock<SchemaTable>,
+}
+
+impl SchemaRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the schema `component` expects for `message_type`.
+    ///
+    /// Replaces any schema previously registered for the same type. Use
+    /// [`ANY_MESSAGE_TYPE`] for messages that carry no type.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` - Schema is not an object or
+    ///   boolean, or contains an invalid `pattern`
+    /// - `MessagingError::DeliveryFailed` - Lock poisoned
+    pub fn register(
+        &self,
+        component: ComponentId,
+        message_type: impl Into<String>,
+        schema: PayloadSchema,
+    ) -> Result<(), MessagingError> {
+        let message_type = message_type.into();
+        let schema = schema.into_json_schema();
+        check_schema(&schema)
+            .map_err(|e| MessagingError::InvalidMessage(format!("{}: {}", message_type, e)))?;
+
+        self.write()?
+            .entry(component)
+            .or_default()
+            .insert(message_type, Arc::new(schema));
+        Ok(())
+    }
+
+    /// Removes the schema `component` registered for `message_type`.
+    ///
+    /// Returns `true` if a schema existed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn unregister(
+        &self,
+        component: &ComponentId,
+        message_type: &str,
+    ) -> Result<bool, MessagingError> {
+        let mut schemas = self.write()?;
+        let Some(types) = schemas.get_mut(component) else {
+            return Ok(false);
+        };
+        let removed = types.remove(message_type).is_some();
+        if types.is_empty() {
+            schemas.remove(component);
+        }
+        Ok(removed)
+    }
+
+    /// Removes every schema registered by `component`.
+    ///
+    /// Returns `true` if any schema existed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn remove_component(&self, component: &ComponentId) -> Result<bool, MessagingError> {
+        Ok(self.write()?.remove(component).is_some())
+    }
+
+    /// Returns the message types `component` registered schemas for.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn message_types(&self, component: &ComponentId) -> Result<Vec<String>, MessagingError> {
+        let mut types: Vec<String> = self
+            .read()?
+            .get(component)
+            .map(|types| types.keys().cloned().collect())
+            .unwrap_or_default();
+        types.sort();
+        Ok(types)
+    }
+
+    /// Validates a payload bound for `target` against the schema for its
+    /// message type.
+    ///
+    /// Payloads passed by reference or still compressed are not inspected.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::SchemaViolation` - Payload cannot be decoded, its
+    ///   content type cannot be validated, or it does not match the schema
+    /// - `MessagingError::DeliveryFailed` - Lock poisoned
+    pub fn validate(
+        &self,
+        target: &ComponentId,
+        payload: &MessagePayload,
+        content_type: Option<&str>,
+    ) -> Result<(), MessagingError> {
+        if content_type == Some(PAYLOAD_REF_CONTENT_TYPE) || is_compressed(content_type) {
+            return Ok(());
+        }
+
+        let message_type = content_type.and_then(message_type);
+        let schema = {
+            let schemas = self.read()?;
+            let Some(types) = schemas.get(target) else {
+                return Ok(());
+            };
+            let schema = message_type
+                .and_then(|t| types.get(t))
+                .or_else(|| types.get(ANY_MESSAGE_TYPE));
+            match schema {
+                Some(schema) => Arc::clone(schema),
+                None => return Ok(()),
+            }
+        };
+
+        let violation = |reason: String| {
+            MessagingError::SchemaViolation(format!(
+                "{} rejected {}: {}",
+                target,
+                message_type.unwrap_or(ANY_MESSAGE_TYPE),
+                reason
+            ))
+        };
+        let value = decode(payload.as_bytes(), content_type).map_err(violation)?;
+        check(&schema, &value, "$").map_err(violation)
+    }
+
+    /// Validates a message bound for `target`.
+    ///
+    /// # Errors
+    ///
+    /// See [`validate`](Self::validate).
+    pub fn validate_message(
+        &self,
+        target: &ComponentId,
+        message: &ComponentMessage,
+    ) -> Result<(), MessagingError> {
+        self.validate(
+            target,
+            &message.payload,
+            message.metadata.content_type.as_deref(),
+        )
+    }
+
+    fn read(&self) -> Result<RwLockReadGuard<'_, SchemaTable>, MessagingError> {
+        self.schemas
+            .read()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))
+    }
+
+    fn write(&self) -> Result<RwLockWriteGuard<'_, SchemaTable>, MessagingError> {
+        self.schemas
+            .write()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))
+    }
+}
+
+/// Extracts the [`MESSAGE_TYPE_PARAM`] parameter from a content type.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::messaging::schema::message_type;
+///
+/// assert_eq!(message_type("application/json; type=\"order.created\""), Some("order.created"));
+/// assert_eq!(message_type("application/json"), None);
+/// ```
+pub fn message_type(content_type: &str) -> Option<&str> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        name.trim()
+            .eq_ignore_ascii_case(MESSAGE_TYPE_PARAM)
+            .then(|| value.trim().trim_matches('"'))
+            .filter(|value| !value.is_empty())
+    })
+}
+
+fn decode(bytes: &[u8], content_type: Option<&str>) -> Result<Value, String> {
+    match content_type.map(Codec::from_content_type) {
+        None | Some(Some(Codec::Json)) => {
+            serde_json::from_slice(bytes).map_err(|e| format!("invalid JSON payload: {}", e))
+        }
+        Some(Some(Codec::Cbor)) => {
+            serde_cbor::from_slice(bytes).map_err(|e| format!("invalid CBOR payload: {}", e))
+        }
+        Some(_) => Err(format!(
+            "cannot validate payload of content type {}",
+            content_type.unwrap_or_default()
+        )),
+    }
+}
+
+/// Rejects schemas the validator cannot evaluate.
+fn check_schema(schema: &Value) -> Result<(), String> {
+    let object = match schema {
+        Value::Bool(_) => return Ok(()),
+        Value::Object(object) => object,
+        _ => return Err("schema must be an object or a boolean".to_string()),
+    };
+    if let Some(pattern) = object.get("pattern") {
+        let pattern = pattern.as_str().ok_or("pattern must be a string")?;
+        Regex::new(pattern).map_err(|e| format!("invalid pattern: {}", e))?;
+    }
+    let nested = object
+        .get("properties")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flat_map(|properties| properties.values())
+        .chain(object.get("items"))
+        .chain(object.get("additionalProperties"))
+        .chain(
+            ["allOf", "anyOf"]
+                .into_iter()
+                .filter_map(|key| object.get(key).and_then(Value::as_array))
+                .flatten(),
+        );
+    for schema in nested {
+        check_schema(schema)?;
+    }
+    Ok(())
+}
+
+/// Checks `value` against `schema`, reporting the first violation at `path`.
+fn check(schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+    let schema = match schema {
+        Value::Bool(true) => return Ok(()),
+        Value::Object(schema) => schema,
+        _ => return Err(format!("{}: not allowed", path)),
+    };
+
+    if let Some(expected) = schema.get("type") {
+        let matches = match expected {
+            Value::Array(types) => types.iter().any(|t| is_type(t, value)),
+            single => is_type(single, value),
+        };
+        if !matches {
+            return Err(format!("{}: expected {}", path, expected));
+        }
+    }
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            return Err(format!(
+                "{}: {} is not one of {}",
+                path,
+                value,
+                Value::from(allowed.clone())
+            ));
+        }
+    }
+    if let Some(constant) = schema.get("const") {
+        if constant != value {
+            return Err(format!("{}: expected {}", path, constant));
+        }
+    }
+
+    match value {
+        Value::Number(number) => check_number(schema, number.as_f64().unwrap_or(f64::NAN), path)?,
+        Value::String(string) => check_string(schema, string, path)?,
+        Value::Array(items) => check_array(schema, items, path)?,
+        Value::Object(object) => check_object(schema, object, path)?,
+        Value::Null | Value::Bool(_) => {}
+    }
+
+    if let Some(all) = schema.get("allOf").and_then(Value::as_array) {
+        for schema in all {
+            check(schema, value, path)?;
+        }
+    }
+    if let Some(any) = schema.get("anyOf").and_then(Value::as_array) {
+        if !any.iter().any(|schema| check(schema, value, path).is_ok()) {
+            return Err(format!("{}: matches none of the allowed schemas", path));
+        }
+    }
+    Ok(())
+}
+
+fn is_type(expected: &Value, value: &Value) -> bool {
+    match expected.as_str() {
+        Some("null") => value.is_null(),
+        Some("boolean") => value.is_boolean(),
+        Some("object") => value.is_object(),
+        Some("array") => value.is_array(),
+        Some("string") => value.is_string(),
+        Some("number") => value.is_number(),
+        Some("integer") => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        _ => false,
+    }
+}
+
+fn check_number(schema: &Map<String, Value>, n: f64, path: &str) -> Result<(), String> {
+    let bound = |key: &str| schema.get(key).and_then(Value::as_f64);
+    if let Some(min) = bound("minimum").filter(|min| n < *min) {
+        return Err(format!("{}: {} is below the minimum {}", path, n, min));
+    }
+    if let Some(max) = bound("maximum").filter(|max| n > *max) {
+        return Err(format!("{}: {} is above the maximum {}", path, n, max));
+    }
+    if let Some(min) = bound("exclusiveMinimum").filter(|min| n <= *min) {
+        return Err(format!("{}: {} must be above {}", path, n, min));
+    }
+    if let Some(max) = bound("exclusiveMaximum").filter(|max| n >= *max) {
+        return Err(format!("{}: {} must be below {}", path, n, max));
+    }
+    Ok(())
+}
+
+fn check_string(schema: &Map<String, Value>, s: &str, path: &str) -> Result<(), String> {
+    let length = s.chars().count() as u64;
+    if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+        if length < min {
+            return Err(format!("{}: shorter than {} characters", path, min));
+        }
+    }
+    if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+        if length > max {
+            return Err(format!("{}: longer than {} characters", path, max));
+        }
+    }
+    if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+        let regex = Regex::new(pattern).map_err(|e| format!("{}: invalid pattern: {}", path, e))?;
+        if !regex.is_match(s) {
+            return Err(format!("{}: does not match {}", path, pattern));
+        }
+    }
+    Ok(())
+}
+
+fn check_array(schema: &Map<String, Value>, items: &[Value], path: &str) -> Result<(), String> {
+    let count = items.len() as u64;
+    if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+        if count < min {
+            return Err(format!("{}: fewer than {} items", path, min));
+        }
+    }
+    if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+        if count > max {
+            return Err(format!("{}: more than {} items", path, max));
+        }
+    }
+    if let Some(item_schema) = schema.get("items") {
+        for (index, item) in items.iter().enumerate() {
+            check(item_schema, item, &format!("{}[{}]", path, index))?;
+        }
+    }
+    Ok(())
+}
+
+fn check_object(
+    schema: &Map<String, Value>,
+    object: &Map<String, Value>,
+    path: &str,
+) -> Result<(), String> {
+    if let Some(required) = schema.get("required").and_then(Value::as_array) {
+        if let Some(missing) = required
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|name| !object.contains_key(*name))
+        {
+            return Err(format!("{}: missing field {}", path, missing));
+        }
+    }
+    let properties = schema.get("properties").and_then(Value::as_object);
+    for (name, field) in object {
+        let field_path = format!("{}.{}", path, name);
+        match properties.and_then(|p| p.get(name)) {
+            Some(field_schema) => check(field_schema, field, &field_path)?,
+            None => match schema.get("additionalProperties") {
+                Some(Value::Bool(false)) => {
+                    return Err(format!("{}: unexpected field", field_path));
+                }
+                Some(additional) => check(additional, field, &field_path)?,
+                None => {}
+            },
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn orders() -> ComponentId {
+        ComponentId::new("shop", "orders", "v1")
+    }
+
+    fn order_schema() -> WitType {
+        WitType::Record(vec![
+            ("sku".to_string(), WitType::String),
+            ("qty".to_string(), WitType::U32),
+            (
+                "tags".to_string(),
+                WitType::Option(Box::new(WitType::List(Box::new(WitType::String)))),
+            ),
+            (
+                "channel".to_string(),
+                WitType::Enum(vec!["web".to_string(), "store".to_string()]),
+            ),
+        ])
+    }
+
+    fn reason(result: Result<(), MessagingError>) -> String {
+        match result {
+            Err(MessagingError::SchemaViolation(reason)) => reason,
+            other => panic!("expected schema violation, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_wit_record_validation() {
+        let registry = SchemaRegistry::new();
+        registry
+            .register(
+                orders(),
+                "order.created",
+                PayloadSchema::Wit(order_schema()),
+            )
+            .unwrap();
+        let ct = Some("application/json; type=order.created");
+        let validate = |json: &str| {
+            registry.validate(
+                &orders(),
+                &MessagePayload::new(json.as_bytes().to_vec()),
+                ct,
+            )
+        };
+
+        assert!(validate(r#"{"sku":"A","qty":1,"channel":"web"}"#).is_ok());
+        assert!(validate(r#"{"sku":"A","qty":1,"channel":"web","tags":["x"]}"#).is_ok());
+        assert!(validate(r#"{"sku":"A","qty":1,"channel":"web","tags":null}"#).is_ok());
+
+        assert!(reason(validate(r#"{"sku":"A","channel":"web"}"#)).contains("missing field qty"));
+        assert!(reason(validate(r#"{"sku":"A","qty":1.5,"channel":"web"}"#)).contains("$.qty"));
+        assert!(reason(validate(r#"{"sku":"A","qty":1,"channel":"fax"}"#)).contains("$.channel"));
+        assert!(
+            reason(validate(r#"{"sku":"A","qty":1,"channel":"web","x":1}"#))
+                .contains("$.x: unexpected field")
+        );
+        assert!(reason(validate(
+            r#"{"sku":"A","qty":1,"channel":"web","tags":[1]}"#
+        ))
+        .contains("$.tags"));
+        assert!(reason(validate("not json")).contains("invalid JSON payload"));
+    }
+
+    #[test]
+    fn test_message_type_selects_schema() {
+        let registry = SchemaRegistry::new();
+        registry
+            .register(
+                orders(),
+                ANY_MESSAGE_TYPE,
+                PayloadSchema::JsonSchema(json!({ "type": "string" })),
+            )
+            .unwrap();
+        registry
+            .register(orders(), "count", PayloadSchema::Wit(WitType::U8))
+            .unwrap();
+        let number = MessagePayload::new(b"7".to_vec());
+        let text = MessagePayload::new(b"\"hi\"".to_vec());
+
+        assert!(registry
+            .validate(&orders(), &number, Some("application/json; type=count"))
+            .is_ok());
+        assert!(registry.validate(&orders(), &text, None).is_ok());
+        assert!(registry
+            .validate(&orders(), &text, Some("application/json; type=other"))
+            .is_ok());
+        assert!(reason(registry.validate(&orders(), &number, None)).contains("expected \"string\""));
+
+        // Unknown components and removed schemas accept anything
+        let other = ComponentId::new("shop", "other", "v1");
+        assert!(registry.validate(&other, &number, None).is_ok());
+        assert_eq!(
+            registry.message_types(&orders()).unwrap(),
+            vec!["*".to_string(), "count".to_string()]
+        );
+        assert!(registry.remove_component(&orders()).unwrap());
+        assert!(registry.validate(&orders(), &number, None).is_ok());
+    }
+
+    #[test]
+    fn test_cbor_and_unvalidatable_payloads() {
+        let registry = SchemaRegistry::new();
+        registry
+            .register(
+                orders(),
+                ANY_MESSAGE_TYPE,
+                PayloadSchema::JsonSchema(json!({
+                    "type": "object",
+                    "properties": { "id": { "type": "string", "pattern": "^o-[0-9]+$" } },
+                    "required": ["id"],
+                })),
+            )
+            .unwrap();
+        let cbor =
+            |id: &str| MessagePayload::new(serde_cbor::to_vec(&json!({ "id": id })).unwrap());
+
+        assert!(registry
+            .validate(&orders(), &cbor("o-12"), Some("application/cbor"))
+            .is_ok());
+        assert!(
+            reason(registry.validate(&orders(), &cbor("x"), Some("application/cbor")))
+                .contains("does not match")
+        );
+        assert!(reason(registry.validate(
+            &orders(),
+            &MessagePayload::new(vec![1, 2, 3]),
+            Some("application/octet-stream")
+        ))
+        .contains("cannot validate"));
+        assert!(registry
+            .validate(
+                &orders(),
+                &MessagePayload::new(b"{}".to_vec()),
+                Some(PAYLOAD_REF_CONTENT_TYPE)
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn test_register_rejects_invalid_schema() {
+        let registry = SchemaRegistry::new();
+        let result = registry.register(
+            orders(),
+            "bad",
+            PayloadSchema::JsonSchema(json!({ "items": { "pattern": "(" } })),
+        );
+        assert!(matches!(result, Err(MessagingError::InvalidMessage(_))));
+        let result = registry.register(orders(), "bad", PayloadSchema::JsonSchema(json!(3)));
+        assert!(matches!(result, Err(MessagingError::InvalidMessage(_))));
+        assert!(registry.message_types(&orders()).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,985 @@
+//! End-to-end payload encryption between federated hosts.
+//!
+//! Provides [`PayloadSealer`], which seals message payloads for a peer host
+//! and opens payloads sealed by peers, and [`SealingTransport`], a
+//! [`RemoteTransport`] wrapper sealing every forwarded envelope. Sealing is
+//! independent of transport security: relays between the hosts see only
+//! the routing information, never the payload or its content type.
+//!
+//! # Construction
+//!
+//! Each host holds a static X25519 [`HostKey`] and knows the public
+//! [`PeerKey`] of every peer it exchanges messages with. A payload is
+//! sealed under a key derived with HKDF-SHA256 from the X25519 shared
+//! secret, the two node names and the two key IDs, so each direction
+//! between two hosts uses its own key. Payloads are sealed with
+//! XChaCha20-Poly1305 under a random 192-bit nonce carried in the header,
+//! with the header and the sender and target component IDs as associated
+//! data, so a sealed payload cannot be moved to another conversation.
+//! Random nonces never depend on sender state, so a sender that restarts,
+//! or whose clock steps back, cannot reuse a nonce under the same key.
+//!
+//! # Replay Protection
+//!
+//! Every sealed payload carries a per-peer counter and a timestamp, both
+//! authenticated as part of the header. The receiver accepts each counter once,
+//! within a sliding window of [`REPLAY_WINDOW`] counters, and rejects
+//! payloads older than the configured maximum age. A sender's counter for a
+//! peer starts at the current time in microseconds and then increases by
+//! one per payload, so it keeps increasing across sender restarts. A
+//! restarted receiver starts with empty windows; the maximum age bounds
+//! how long a captured payload could be replayed to it.
+//!
+//! # Key Rotation
+//!
+//! [`PayloadSealer::rotate`] makes a new host key current while older keys
+//! keep opening payloads peers sealed before learning the new key, until
+//! they are retired with [`PayloadSealer::retire`]. Peer keys rotate the
+//! same way with [`PayloadSealer::set_peer_key`].
+//!
+//! # Architecture
+//!
+//! This module is part of `messaging/` (Layer 3B). It depends on
+//! `core/component/`, `core/messaging/` and the listener handler type of
+//! `messaging/transport`.
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use chrono::Utc;
+use curve25519_dalek::montgomery::MontgomeryPoint;
+use hkdf::Hkdf;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::MessagePayload;
+use crate::core::messaging::discovery::RemoteEndpoint;
+use crate::core::messaging::errors::MessagingError;
+use crate::core::messaging::transport::{RemoteEnvelope, RemoteTransport};
+use crate::messaging::transport::DeliveryHandler;
+
+/// Content type of a sealed payload.
+pub const SEALED_CONTENT_TYPE: &str = "application/x-airssys-sealed";
+
+/// Number of counters below the highest seen that are still accepted.
+pub const REPLAY_WINDOW: u64 = 128;
+
+/// Default maximum age of a sealed payload.
+pub const DEFAULT_MAX_AGE: Duration = Duration::from_secs(300);
+
+/// Format marker and version of a sealed payload.
+const MAGIC: &[u8; 4] = b"ASL3";
+
+/// HKDF salt separating these keys from any other use of the host keys.
+const KDF_SALT: &[u8] = b"airssys-federation-seal-v2";
+
+/// Length of the Poly1305 tag.
+const TAG_LEN: usize = 16;
+
+/// Length of the XChaCha20 nonce.
+const NONCE_LEN: usize = 24;
+
+/// Content type length marking a payload without content type.
+const NO_CONTENT_TYPE: u16 = u16::MAX;
+
+/// A host's static X25519 key pair.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::messaging::sealing::HostKey;
+///
+/// let key = HostKey::from_bytes(1, &[7; 32]);
+/// assert_eq!(key.key_id(), 1);
+/// assert_eq!(key.peer_key().key_id, 1);
+/// ```
+#[derive(Clone)]
+pub struct HostKey {
+    key_id: u32,
+    secret: [u8; 32],
+    public: [u8; 32],
+}
+
+impl HostKey {
+    /// Creates a key pair from a 32-byte X25519 secret key.
+    ///
+    /// `key_id` identifies the key to peers during rotation and must be
+    /// unique among this host's keys.
+    pub fn from_bytes(key_id: u32, secret: &[u8; 32]) -> Self {
+        Self {
+            key_id,
+            secret: *secret,
+            public: MontgomeryPoint::mul_base_clamped(*secret).to_bytes(),
+        }
+    }
+
+    /// ID of this key.
+    pub fn key_id(&self) -> u32 {
+        self.key_id
+    }
+
+    /// Public half of this key, to configure on peers.
+    pub fn peer_key(&self) -> PeerKey {
+        PeerKey {
+            key_id: self.key_id,
+            public: self.public,
+        }
+    }
+}
+
+impl std::fmt::Debug for HostKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HostKey")
+            .field("key_id", &self.key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A peer host's public X25519 key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeerKey {
+    /// ID of the key on the peer.
+    pub key_id: u32,
+    /// X25519 public key.
+    pub public: [u8; 32],
+}
+
+/// Seals payloads for peer hosts and opens payloads sealed by them.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::component::message::{ComponentMessage, MessageMetadata, MessagePayload};
+/// use airssys_wasm::core::messaging::transport::RemoteEnvelope;
+/// use airssys_wasm::messaging::sealing::{HostKey, PayloadSealer};
+///
+/// let key_a = HostKey::from_bytes(1, &[1; 32]);
+/// let key_b = HostKey::from_bytes(1, &[2; 32]);
+/// let node_a = PayloadSealer::new("node-a", key_a.clone());
+/// let node_b = PayloadSealer::new("node-b", key_b.clone());
+/// node_a.set_peer_key("node-b", key_b.peer_key()).unwrap();
+/// node_b.set_peer_key("node-a", key_a.peer_key()).unwrap();
+///
+/// let mut envelope = RemoteEnvelope {
+///     target: ComponentId::new("finance", "billing", "v1"),
+///     message: ComponentMessage::new(
+///         ComponentId::new("shop", "cart", "v1"),
+///         MessagePayload::new(b"card=4242".to_vec()),
+///         MessageMetadata::default(),
+///     ),
+/// };
+///
+/// node_a.seal_envelope("node-b", &mut envelope).unwrap();
+/// assert!(!envelope.message.payload.as_bytes().windows(4).any(|w| w == b"4242"));
+///
+/// node_b.open_envelope(&mut envelope).unwrap();
+/// assert_eq!(envelope.message.payload.as_bytes(), b"card=4242");
+/// ```
+pub struct PayloadSealer {
+    /// This host's node name, as peers know it
+    node: String,
+    /// Maximum age of accepted payloads
+    max_age: Duration,
+    /// Keys, counters and replay windows
+    state: Mutex<SealerState>,
+}
+
+#[derive(Default)]
+struct SealerState {
+    /// This host's keys, current first
+    local: Vec<HostKey>,
+    /// Peer keys by node, current first
+    peers: HashMap<String, Vec<PeerKey>>,
+    /// Last counter sent to each peer
+    sent: HashMap<String, u64>,
+    /// Counters received, by sender node and key pair
+    received: HashMap<(String, u32, u32), ReplayWindow>,
+}
+
+impl PayloadSealer {
+    /// Creates a sealer for the host `node` with its current key.
+    pub fn new(node: impl Into<String>, key: HostKey) -> Self {
+        Self {
+            node: node.into(),
+            max_age: DEFAULT_MAX_AGE,
+            state: Mutex::new(SealerState {
+                local: vec![key],
+                ..SealerState::default()
+            }),
+        }
+    }
+
+    /// Sets the maximum age of accepted payloads.
+    ///
+    /// Peers' clocks must agree within this bound.
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// This host's node name.
+    pub fn node(&self) -> &str {
+        &self.node
+    }
+
+    /// ID of the key payloads are sealed with.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::SealingFailed` if the state is poisoned.
+    pub fn current_key_id(&self) -> Result<u32, MessagingError> {
+        let state = self.lock()?;
+        state
+            .local
+            .first()
+            .map(HostKey::key_id)
+            .ok_or_else(|| MessagingError::SealingFailed("no host key".to_string()))
+    }
+
+    /// Makes `key` the current host key.
+    ///
+    /// Previous keys keep opening payloads until retired.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::SealingFailed` if a key with the same ID
+    /// exists or the state is poisoned.
+    pub fn rotate(&self, key: HostKey) -> Result<(), MessagingError> {
+        let mut state = self.lock()?;
+        if state.local.iter().any(|k| k.key_id == key.key_id) {
+            return Err(MessagingError::SealingFailed(format!(
+                "host key {} already exists",
+                key.key_id
+            )));
+        }
+        state.local.insert(0, key);
+        Ok(())
+    }
+
+    /// Stops accepting payloads sealed for the host key `key_id`.
+    ///
+    /// The current key cannot be retired. Returns whether the key existed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::SealingFailed` if `key_id` is the current
+    /// key or the state is poisoned.
+    pub fn retire(&self, key_id: u32) -> Result<bool, MessagingError> {
+        let mut state = self.lock()?;
+        if state.local.first().map(HostKey::key_id) == Some(key_id) {
+            return Err(MessagingError::SealingFailed(format!(
+                "host key {} is current",
+                key_id
+            )));
+        }
+        let before = state.local.len();
+        state.local.retain(|k| k.key_id != key_id);
+        state.received.retain(|(_, _, local), _| *local != key_id);
+        Ok(state.local.len() < before)
+    }
+
+    /// Makes `key` the current key of the peer `node`.
+    ///
+    /// Previous keys of the peer keep opening its payloads until removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::SealingFailed` if the state is poisoned.
+    pub fn set_peer_key(
+        &self,
+        node: impl Into<String>,
+        key: PeerKey,
+    ) -> Result<(), MessagingError> {
+        let mut state = self.lock()?;
+        let keys = state.peers.entry(node.into()).or_default();
+        keys.retain(|k| k.key_id != key.key_id);
+        keys.insert(0, key);
+        Ok(())
+    }
+
+    /// Stops accepting payloads the peer `node` sealed with `key_id`.
+    ///
+    /// Returns whether the key was known.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::SealingFailed` if the state is poisoned.
+    pub fn remove_peer_key(&self, node: &str, key_id: u32) -> Result<bool, MessagingError> {
+        let mut state = self.lock()?;
+        let Some(keys) = state.peers.get_mut(node) else {
+            return Ok(false);
+        };
+        let before = keys.len();
+        keys.retain(|k| k.key_id != key_id);
+        let removed = keys.len() < before;
+        state
+            .received
+            .retain(|(peer, remote, _), _| peer != node || *remote != key_id);
+        Ok(removed)
+    }
+
+    /// Seals the payload of `envelope` for the peer `recipient`.
+    ///
+    /// The payload and content type are replaced by the sealed payload and
+    /// [`SEALED_CONTENT_TYPE`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::SealingFailed` if no key is known for
+    /// `recipient` or the state is poisoned.
+    pub fn seal_envelope(
+        &self,
+        recipient: &str,
+        envelope: &mut RemoteEnvelope,
+    ) -> Result<(), MessagingError> {
+        let (local, peer, counter) = {
+            let mut state = self.lock()?;
+            let local = state
+                .local
+                .first()
+                .cloned()
+                .ok_or_else(|| MessagingError::SealingFailed("no host key".to_string()))?;
+            let peer = state
+                .peers
+                .get(recipient)
+                .and_then(|keys| keys.first().copied())
+                .ok_or_else(|| {
+                    MessagingError::SealingFailed(format!("no key for peer {}", recipient))
+                })?;
+            // Seeding from the clock keeps counters increasing across restarts
+            let sent = state
+                .sent
+                .entry(recipient.to_string())
+                .and_modify(|counter| *counter += 1)
+                .or_insert_with(|| Utc::now().timestamp_micros().max(1) as u64);
+            (local, peer, *sent)
+        };
+
+        let header = Header {
+            sender: self.node.clone(),
+            sender_key: local.key_id,
+            recipient_key: peer.key_id,
+            counter,
+            timestamp_ms: Utc::now().timestamp_millis().max(0) as u64,
+            nonce: XChaCha20Poly1305::generate_nonce(&mut OsRng).into(),
+        };
+        let keys = SessionKeys::derive(
+            &local,
+            &peer,
+            &self.node,
+            recipient,
+            (local.key_id, peer.key_id),
+        )?;
+
+        let message = &mut envelope.message;
+        let body = encode_plaintext(
+            message.metadata.content_type.as_deref(),
+            message.payload.as_bytes(),
+        );
+
+        let mut sealed = header.encode();
+        let ciphertext = keys.seal(
+            &header.nonce,
+            &sealed,
+            &body,
+            &message.sender,
+            &envelope.target,
+        )?;
+        sealed.extend_from_slice(&ciphertext);
+
+        message.payload = MessagePayload::new(sealed);
+        message.metadata.content_type = Some(SEALED_CONTENT_TYPE.to_string());
+        Ok(())
+    }
+
+    /// Opens the sealed payload of `envelope` in place.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::SealingFailed` if the payload is not
+    /// sealed, is sealed with an unknown key, fails authentication, is too
+    /// old or was already received.
+    pub fn open_envelope(&self, envelope: &mut RemoteEnvelope) -> Result<(), MessagingError> {
+        let message = &mut envelope.message;
+        if message.metadata.content_type.as_deref() != Some(SEALED_CONTENT_TYPE) {
+            return Err(MessagingError::SealingFailed(
+                "payload from peer is not sealed".to_string(),
+            ));
+        }
+        let sealed = message.payload.as_bytes();
+        let (header, header_len) = Header::decode(sealed)?;
+        if sealed.len() < header_len + TAG_LEN {
+            return Err(rejected("truncated payload"));
+        }
+        let (header_bytes, ciphertext) = sealed.split_at(header_len);
+
+        let (local, peer) = {
+            let state = self.lock()?;
+            let local = state
+                .local
+                .iter()
+                .find(|k| k.key_id == header.recipient_key)
+                .cloned()
+                .ok_or_else(|| rejected(&format!("unknown host key {}", header.recipient_key)))?;
+            let peer = state
+                .peers
+                .get(&header.sender)
+                .and_then(|keys| keys.iter().find(|k| k.key_id == header.sender_key))
+                .copied()
+                .ok_or_else(|| {
+                    rejected(&format!(
+                        "unknown key {} of peer {}",
+                        header.sender_key, header.sender
+                    ))
+                })?;
+            (local, peer)
+        };
+
+        let keys = SessionKeys::derive(
+            &local,
+            &peer,
+            &header.sender,
+            &self.node,
+            (header.sender_key, header.recipient_key),
+        )?;
+        let body = keys.open(
+            &header.nonce,
+            header_bytes,
+            ciphertext,
+            &message.sender,
+            &envelope.target,
+        )?;
+
+        let now_ms = Utc::now().timestamp_millis().max(0) as u64;
+        let age = Duration::from_millis(now_ms.saturating_sub(header.timestamp_ms));
+        if age > self.max_age {
+            return Err(rejected(&format!("payload is {}s old", age.as_secs())));
+        }
+        // Only authenticated counters may advance the window
+        self.lock()?
+            .received
+            .entry((
+                header.sender.clone(),
+                header.sender_key,
+                header.recipient_key,
+            ))
+            .or_default()
+            .accept(header.counter)?;
+
+        let (content_type, payload) = decode_plaintext(&body)?;
+        message.metadata.content_type = content_type;
+        message.payload = MessagePayload::new(payload);
+        Ok(())
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, SealerState>, MessagingError> {
+        self.state
+            .lock()
+            .map_err(|e| MessagingError::SealingFailed(format!("Lock poisoned: {}", e)))
+    }
+}
+
+impl std::fmt::Debug for PayloadSealer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PayloadSealer")
+            .field("node", &self.node)
+            .field("max_age", &self.max_age)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Seals every envelope before handing it to an inner transport.
+#[derive(Clone)]
+pub struct SealingTransport {
+    inner: Arc<dyn RemoteTransport>,
+    sealer: Arc<PayloadSealer>,
+}
+
+impl SealingTransport {
+    /// Wraps `inner`, sealing envelopes with `sealer`.
+    pub fn new(inner: Arc<dyn RemoteTransport>, sealer: Arc<PayloadSealer>) -> Self {
+        Self { inner, sealer }
+    }
+}
+
+impl RemoteTransport for SealingTransport {
+    fn forward(
+        &self,
+        endpoint: &RemoteEndpoint,
+        envelope: &RemoteEnvelope,
+    ) -> Result<(), MessagingError> {
+        let mut sealed = envelope.clone();
+        self.sealer.seal_envelope(&endpoint.node, &mut sealed)?;
+        self.inner.forward(endpoint, &sealed)
+    }
+}
+
+/// Wraps a listener's delivery handler so envelopes are opened first.
+///
+/// Envelopes that are not sealed, or fail to open, are rejected without
+/// reaching `handler`.
+pub fn opening_handler(sealer: Arc<PayloadSealer>, handler: DeliveryHandler) -> DeliveryHandler {
+    Arc::new(move |mut envelope: RemoteEnvelope| {
+        sealer.open_envelope(&mut envelope)?;
+        handler(envelope)
+    })
+}
+
+/// Sliding window of received counters.
+#[derive(Debug, Default)]
+struct ReplayWindow {
+    /// Highest counter received
+    highest: u64,
+    /// Bit `i` is set if `highest - i` was received
+    seen: u128,
+}
+
+impl ReplayWindow {
+    fn accept(&mut self, counter: u64) -> Result<(), MessagingError> {
+        if counter > self.highest {
+            let shift = counter - self.highest;
+            self.seen = if shift >= REPLAY_WINDOW {
+                0
+            } else {
+                self.seen << shift
+            };
+            self.seen |= 1;
+            self.highest = counter;
+            return Ok(());
+        }
+        let offset = self.highest - counter;
+        if offset >= REPLAY_WINDOW {
+            return Err(rejected(&format!(
+                "counter {} is outside the replay window",
+                counter
+            )));
+        }
+        let bit = 1u128 << offset;
+        if self.seen & bit != 0 {
+            return Err(rejected(&format!("counter {} was replayed", counter)));
+        }
+        self.seen |= bit;
+        Ok(())
+    }
+}
+
+/// Unencrypted header of a sealed payload.
+#[derive(Debug)]
+struct Header {
+    sender: String,
+    sender_key: u32,
+    recipient_key: u32,
+    counter: u64,
+    timestamp_ms: u64,
+    nonce: [u8; NONCE_LEN],
+}
+
+impl Header {
+    fn encode(&self) -> Vec<u8> {
+        let sender = self.sender.as_bytes();
+        let mut out = Vec::with_capacity(MAGIC.len() + 2 + sender.len() + 24 + NONCE_LEN);
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&(sender.len() as u16).to_be_bytes());
+        out.extend_from_slice(sender);
+        out.extend_from_slice(&self.sender_key.to_be_bytes());
+        out.extend_from_slice(&self.recipient_key.to_be_bytes());
+        out.extend_from_slice(&self.counter.to_be_bytes());
+        out.extend_from_slice(&self.timestamp_ms.to_be_bytes());
+        out.extend_from_slice(&self.nonce);
+        out
+    }
+
+    /// Decodes a header, returning it and its encoded length.
+    fn decode(data: &[u8]) -> Result<(Self, usize), MessagingError> {
+        let mut reader = Reader(data);
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(rejected("unknown sealed payload format"));
+        }
+        let sender_len = usize::from(u16::from_be_bytes(reader.array()?));
+        let sender = String::from_utf8(reader.take(sender_len)?.to_vec())
+            .map_err(|_| rejected("sender node is not UTF-8"))?;
+        let header = Self {
+            sender,
+            sender_key: u32::from_be_bytes(reader.array()?),
+            recipient_key: u32::from_be_bytes(reader.array()?),
+            counter: u64::from_be_bytes(reader.array()?),
+            timestamp_ms: u64::from_be_bytes(reader.array()?),
+            nonce: reader.array()?,
+        };
+        Ok((header, data.len() - reader.0.len()))
+    }
+}
+
+/// Cursor over a byte slice that fails on truncation.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], MessagingError> {
+        if self.0.len() < len {
+            return Err(rejected("truncated payload"));
+        }
+        let (head, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], MessagingError> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+}
+
+/// AEAD key for one direction between two hosts.
+struct SessionKeys {
+    cipher: XChaCha20Poly1305,
+}
+
+impl SessionKeys {
+    /// Derives the key for payloads `sender` seals for `recipient`; the
+    /// key IDs are the sealing and opening hosts' keys in that order.
+    fn derive(
+        local: &HostKey,
+        peer: &PeerKey,
+        sender: &str,
+        recipient: &str,
+        (sender_key, recipient_key): (u32, u32),
+    ) -> Result<Self, MessagingError> {
+        let shared = MontgomeryPoint(peer.public)
+            .mul_clamped(local.secret)
+            .to_bytes();
+        // A low-order peer key yields an all-zero secret known to anyone
+        if shared == [0u8; 32] {
+            return Err(rejected("peer key is not a valid X25519 public key"));
+        }
+
+        let mut info = Vec::with_capacity(sender.len() + recipient.len() + 10);
+        info.extend_from_slice(sender.as_bytes());
+        info.push(0);
+        info.extend_from_slice(recipient.as_bytes());
+        info.push(0);
+        info.extend_from_slice(&sender_key.to_be_bytes());
+        info.extend_from_slice(&recipient_key.to_be_bytes());
+
+        let mut key = [0u8; 32];
+        Hkdf::<Sha256>::new(Some(KDF_SALT), &shared)
+            .expand(&info, &mut key)
+            .map_err(|_| rejected("key derivation failed"))?;
+        Ok(Self {
+            cipher: XChaCha20Poly1305::new(Key::from_slice(&key)),
+        })
+    }
+
+    /// Encrypts `body` under `nonce`, authenticating `header` and the
+    /// conversation along with it.
+    fn seal(
+        &self,
+        nonce: &[u8; NONCE_LEN],
+        header: &[u8],
+        body: &[u8],
+        sender: &ComponentId,
+        target: &ComponentId,
+    ) -> Result<Vec<u8>, MessagingError> {
+        let aad = associated_data(header, sender, target);
+        self.cipher
+            .encrypt(
+                XNonce::from_slice(nonce),
+                Payload {
+                    msg: body,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| MessagingError::SealingFailed("encryption failed".to_string()))
+    }
+
+    /// Decrypts what [`seal`](Self::seal) produced for the same inputs.
+    fn open(
+        &self,
+        nonce: &[u8; NONCE_LEN],
+        header: &[u8],
+        ciphertext: &[u8],
+        sender: &ComponentId,
+        target: &ComponentId,
+    ) -> Result<Vec<u8>, MessagingError> {
+        let aad = associated_data(header, sender, target);
+        self.cipher
+            .decrypt(
+                XNonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| rejected("authentication failed"))
+    }
+}
+
+/// The header and both component IDs, each ID length-prefixed.
+fn associated_data(header: &[u8], sender: &ComponentId, target: &ComponentId) -> Vec<u8> {
+    let sender = sender.to_string_id();
+    let target = target.to_string_id();
+    let mut aad = Vec::with_capacity(header.len() + 16 + sender.len() + target.len());
+    aad.extend_from_slice(header);
+    aad.extend_from_slice(&(sender.len() as u64).to_be_bytes());
+    aad.extend_from_slice(sender.as_bytes());
+    aad.extend_from_slice(&(target.len() as u64).to_be_bytes());
+    aad.extend_from_slice(target.as_bytes());
+    aad
+}
+
+fn encode_plaintext(content_type: Option<&str>, payload: &[u8]) -> Vec<u8> {
+    let content_type = content_type.unwrap_or_default();
+    let mut out = Vec::with_capacity(2 + content_type.len() + payload.len());
+    match content_type.is_empty() {
+        true => out.extend_from_slice(&NO_CONTENT_TYPE.to_be_bytes()),
+        false => {
+            out.extend_from_slice(&(content_type.len() as u16).to_be_bytes());
+            out.extend_from_slice(content_type.as_bytes());
+        }
+    }
+    out.extend_from_slice(payload);
+    out
+}
+
+fn decode_plaintext(data: &[u8]) -> Result<(Option<String>, Vec<u8>), MessagingError> {
+    let mut reader = Reader(data);
+    let len = u16::from_be_bytes(reader.array()?);
+    let content_type = match len {
+        NO_CONTENT_TYPE => None,
+        len => Some(
+            String::from_utf8(reader.take(usize::from(len))?.to_vec())
+                .map_err(|_| rejected("content type is not UTF-8"))?,
+        ),
+    };
+    Ok((content_type, reader.0.to_vec()))
+}
+
+fn rejected(reason: &str) -> MessagingError {
+    MessagingError::SealingFailed(reason.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::component::message::{ComponentMessage, MessageMetadata};
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    fn pair() -> (PayloadSealer, PayloadSealer, HostKey) {
+        let key_a = HostKey::from_bytes(1, &[1; 32]);
+        let key_b = HostKey::from_bytes(1, &[2; 32]);
+        let a = PayloadSealer::new("node-a", key_a.clone());
+        let b = PayloadSealer::new("node-b", key_b.clone());
+        a.set_peer_key("node-b", key_b.peer_key()).unwrap();
+        b.set_peer_key("node-a", key_a.peer_key()).unwrap();
+        (a, b, key_a)
+    }
+
+    fn envelope(payload: &[u8]) -> RemoteEnvelope {
+        let metadata = MessageMetadata {
+            content_type: Some("application/json".to_string()),
+            ..MessageMetadata::default()
+        };
+        RemoteEnvelope {
+            target: ComponentId::new("app", "billing", "v1"),
+            message: ComponentMessage::new(
+                ComponentId::new("app", "cart", "v1"),
+                MessagePayload::new(payload.to_vec()),
+                metadata,
+            ),
+        }
+    }
+
+    #[test]
+    fn test_primitives_match_reference_vectors() {
+        // RFC 7748 section 6.1
+        let secret = [
+            0x77, 0x07, 0x6d, 0x0a, 0x73, 0x18, 0xa5, 0x7d, 0x3c, 0x16, 0xc1, 0x72, 0x51, 0xb2,
+            0x66, 0x45, 0xdf, 0x4c, 0x2f, 0x87, 0xeb, 0xc0, 0x99, 0x2a, 0xb1, 0x77, 0xfb, 0xa5,
+            0x1d, 0xb9, 0x2c, 0x2a,
+        ];
+        assert_eq!(
+            hex(&HostKey::from_bytes(1, &secret).peer_key().public),
+            "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+        );
+    }
+
+    #[test]
+    fn test_seal_open_roundtrip_and_tampering() {
+        let (a, b, _) = pair();
+        let mut sealed = envelope(b"{\"amount\":42}");
+        a.seal_envelope("node-b", &mut sealed).unwrap();
+        assert_eq!(
+            sealed.message.metadata.content_type.as_deref(),
+            Some(SEALED_CONTENT_TYPE)
+        );
+
+        // A relay cannot redirect the payload to another component
+        let mut redirected = sealed.clone();
+        redirected.target = ComponentId::new("app", "audit", "v1");
+        assert!(b.open_envelope(&mut redirected).is_err());
+
+        // Nor flip ciphertext bits
+        let mut flipped = sealed.clone();
+        let mut bytes = flipped.message.payload.as_bytes().to_vec();
+        let middle = bytes.len() / 2;
+        bytes[middle] ^= 1;
+        flipped.message.payload = MessagePayload::new(bytes);
+        assert!(b.open_envelope(&mut flipped).is_err());
+
+        // The sender cannot open what it sealed for the peer
+        assert!(a.open_envelope(&mut sealed.clone()).is_err());
+
+        b.open_envelope(&mut sealed).unwrap();
+        assert_eq!(sealed.message.payload.as_bytes(), b"{\"amount\":42}");
+        assert_eq!(
+            sealed.message.metadata.content_type.as_deref(),
+            Some("application/json")
+        );
+
+        let mut plain = envelope(b"{}");
+        assert!(b.open_envelope(&mut plain).is_err());
+    }
+
+    #[test]
+    fn test_replayed_and_stale_payloads_are_rejected() {
+        let (a, b, _) = pair();
+        let mut first = envelope(b"1");
+        let mut second = envelope(b"2");
+        a.seal_envelope("node-b", &mut first).unwrap();
+        a.seal_envelope("node-b", &mut second).unwrap();
+
+        // Out-of-order delivery within the window is fine, replays are not
+        b.open_envelope(&mut second.clone()).unwrap();
+        b.open_envelope(&mut first.clone()).unwrap();
+        let err = b.open_envelope(&mut first).unwrap_err();
+        assert!(err.to_string().contains("replayed"));
+
+        let strict = b.with_max_age(Duration::ZERO);
+        let mut late = envelope(b"3");
+        a.seal_envelope("node-b", &mut late).unwrap();
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(strict
+            .open_envelope(&mut late)
+            .unwrap_err()
+            .to_string()
+            .contains("old"));
+    }
+
+    #[test]
+    fn test_restarted_sender_never_reuses_a_nonce() {
+        let (a, b, key_a) = pair();
+        let mut before = envelope(b"before");
+        a.seal_envelope("node-b", &mut before).unwrap();
+
+        // A restart with the clock stepped back repeats the counter
+        let restarted = PayloadSealer::new("node-a", key_a);
+        restarted
+            .set_peer_key("node-b", HostKey::from_bytes(1, &[2; 32]).peer_key())
+            .unwrap();
+        restarted
+            .lock()
+            .unwrap()
+            .sent
+            .insert("node-b".to_string(), a.lock().unwrap().sent["node-b"] - 1);
+        let mut after = envelope(b"after!");
+        restarted.seal_envelope("node-b", &mut after).unwrap();
+
+        let (first, _) = Header::decode(before.message.payload.as_bytes()).unwrap();
+        let (second, _) = Header::decode(after.message.payload.as_bytes()).unwrap();
+        assert_eq!(first.counter, second.counter);
+        assert_ne!(first.nonce, second.nonce);
+
+        // The repeated counter is still caught as a replay
+        b.open_envelope(&mut before).unwrap();
+        assert!(b
+            .open_envelope(&mut after)
+            .unwrap_err()
+            .to_string()
+            .contains("replayed"));
+    }
+
+    #[test]
+    fn test_key_rotation_keeps_old_key_until_retired() {
+        let (a, b, _) = pair();
+        let mut in_flight = envelope(b"old");
+        a.seal_envelope("node-b", &mut in_flight).unwrap();
+
+        // node-b rotates; node-a still seals for the old key until told
+        let new_b = HostKey::from_bytes(2, &[3; 32]);
+        b.rotate(new_b.clone()).unwrap();
+        assert_eq!(b.current_key_id().unwrap(), 2);
+        assert!(b.retire(2).is_err());
+        b.open_envelope(&mut in_flight).unwrap();
+
+        a.set_peer_key("node-b", new_b.peer_key()).unwrap();
+        let mut fresh = envelope(b"new");
+        a.seal_envelope("node-b", &mut fresh).unwrap();
+
+        let mut stale = envelope(b"stale");
+        let old_a = PayloadSealer::new("node-a", HostKey::from_bytes(1, &[1; 32]));
+        old_a
+            .set_peer_key("node-b", HostKey::from_bytes(1, &[2; 32]).peer_key())
+            .unwrap();
+        old_a.seal_envelope("node-b", &mut stale).unwrap();
+
+        assert!(b.retire(1).unwrap());
+        b.open_envelope(&mut fresh).unwrap();
+        assert!(b
+            .open_envelope(&mut stale)
+            .unwrap_err()
+            .to_string()
+            .contains("unknown host key"));
+    }
+
+    #[test]
+    fn test_sealing_transport_and_opening_handler() {
+        use std::sync::Mutex as StdMutex;
+
+        struct Loopback(DeliveryHandler);
+
+        impl RemoteTransport for Loopback {
+            fn forward(
+                &self,
+                _endpoint: &RemoteEndpoint,
+                envelope: &RemoteEnvelope,
+            ) -> Result<(), MessagingError> {
+                assert_eq!(
+                    envelope.message.metadata.content_type.as_deref(),
+                    Some(SEALED_CONTENT_TYPE)
+                );
+                (self.0)(envelope.clone())
+            }
+        }
+
+        let (a, b, _) = pair();
+        let delivered = Arc::new(StdMutex::new(Vec::new()));
+        let sink = Arc::clone(&delivered);
+        let handler: DeliveryHandler = Arc::new(move |envelope: RemoteEnvelope| {
+            sink.lock()
+                .unwrap()
+                .push(envelope.message.payload.into_bytes());
+            Ok(())
+        });
+        let inbound = opening_handler(Arc::new(b), handler);
+        let transport = SealingTransport::new(Arc::new(Loopback(inbound)), Arc::new(a));
+
+        transport
+            .forward(
+                &RemoteEndpoint::new("node-b", "10.0.0.2:7400"),
+                &envelope(b"hi"),
+            )
+            .unwrap();
+        assert_eq!(*delivered.lock().unwrap(), vec![b"hi".to_vec()]);
+    }
+}
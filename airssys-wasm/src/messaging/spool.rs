@@ -0,0 +1,1256 @@
+//! Queued message spool with shutdown persistence.
+//!
+//! Provides [`MessageSpool`], an in-memory per-target queue for messages that
+//! have not yet been delivered to a component mailbox. On graceful host
+//! shutdown the spool is drained into a [`ComponentStorage`] backend, and on
+//! the next startup it is restored so queued messages survive the restart.
+//!
+//! Whether a target's messages are persisted is decided by its
+//! [`DeliveryGuarantee`]: `AtMostOnce` targets are discarded on shutdown,
+//! `AtLeastOnce` targets are saved.
+//!
+//! # Crash Durability
+//!
+//! Shutdown persistence only covers graceful stops. A spool built with
+//! [`MessageSpool::with_journal`] also writes every `AtLeastOnce` message to
+//! a durable [`ComponentStorage`] backend *before* accepting it, and deletes
+//! the record once the message reaches the target's mailbox. After a crash,
+//! [`MessageSpool::recover`] reloads everything still journaled so it is
+//! redelivered. The backend is pluggable: any `ComponentStorage` works,
+//! including sled- or SQLite-backed implementations provided by the host.
+//!
+//! # Acknowledged Delivery
+//!
+//! `Acknowledged` targets get everything `AtLeastOnce` does, and a message
+//! reaching the mailbox is not done yet: the spool keeps it until the
+//! component wrapper reports that `handle-message` returned `Ok` (see
+//! [`DeliveryAcknowledger`]). A failed attempt (trap or error) is retried
+//! after an exponential backoff, and a message not acknowledged within
+//! [`AckPolicy::timeout`] is retried as well; the host drives both with
+//! [`MessageSpool::spawn_redelivery`], which calls
+//! [`MessageSpool::redeliver_due`] and flushes periodically. Every attempt
+//! carries the same `idempotency_key`, assigned on enqueue, so guests can
+//! drop duplicates. After [`AckPolicy::max_attempts`] the message is
+//! abandoned and counted in [`SpoolMetrics::abandoned`].
+//!
+//! # Storage Layout
+//!
+//! Each target with pending messages is written as one JSON value under
+//! `{key_prefix}/{namespace}/{name}/{instance}`. Restoring deletes the keys
+//! it reads so a message is never restored twice.
+//!
+//! Journal records are written one per message under
+//! `{key_prefix}-journal/{sequence}`, with the sequence zero-padded so keys
+//! sort in enqueue order.
+//!
+//! # Architecture
+//!
+//! This module is part of `messaging/` (Layer 3B). It depends only on
+//! `core/component/`, `core/messaging/` and `core/storage/`. The `system/`
+//! module (Layer 4) owns the spool and calls [`MessageSpool::persist`] /
+//! [`MessageSpool::restore`] from its shutdown and startup paths.
+
+// Layer 1: Standard library imports
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, RwLock, RwLockWriteGuard};
+use std::time::{Duration, Instant};
+
+// Layer 2: Third-party crate imports
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinHandle;
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::ComponentMessage;
+use crate::core::messaging::errors::MessagingError;
+use crate::core::messaging::guarantee::DeliveryGuarantee;
+use crate::core::messaging::traits::DeliveryAcknowledger;
+use crate::core::storage::traits::ComponentStorage;
+use crate::core::storage::value::StorageValue;
+use crate::messaging::subscriber::ComponentSubscriber;
+
+/// Retry policy for `Acknowledged` targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AckPolicy {
+    /// How long a delivered message may go unacknowledged before it is
+    /// retried.
+    pub timeout: Duration,
+    /// Delay before the first retry of a failed attempt; doubled on every
+    /// further failure.
+    pub initial_backoff: Duration,
+    /// Upper bound on the retry delay.
+    pub max_backoff: Duration,
+    /// Attempts before a message is abandoned.
+    pub max_attempts: u32,
+}
+
+impl AckPolicy {
+    /// Delay before retrying a message that failed `attempts` times.
+    pub fn backoff(&self, attempts: u32) -> Duration {
+        let doublings = attempts.saturating_sub(1).min(31);
+        self.initial_backoff
+            .saturating_mul(1 << doublings)
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for AckPolicy {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(30),
+            max_attempts: 5,
+        }
+    }
+}
+
+/// Configuration for [`MessageSpool`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpoolConfig {
+    /// Guarantee for targets without an override.
+    pub default_guarantee: DeliveryGuarantee,
+    /// Per-target guarantee overrides.
+    pub overrides: HashMap<ComponentId, DeliveryGuarantee>,
+    /// Storage key prefix for persisted queues.
+    pub key_prefix: String,
+    /// Retry policy for `Acknowledged` targets.
+    pub ack: AckPolicy,
+}
+
+impl SpoolConfig {
+    /// Returns the guarantee that applies to `target`.
+    pub fn guarantee_for(&self, target: &ComponentId) -> DeliveryGuarantee {
+        self.overrides
+            .get(target)
+            .copied()
+            .unwrap_or(self.default_guarantee)
+    }
+}
+
+impl Default for SpoolConfig {
+    fn default() -> Self {
+        Self {
+            default_guarantee: DeliveryGuarantee::default(),
+            overrides: HashMap::new(),
+            key_prefix: "message-spool".to_string(),
+            ack: AckPolicy::default(),
+        }
+    }
+}
+
+/// Counters describing spool persistence activity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpoolMetrics {
+    /// Messages written to storage on shutdown.
+    pub saved: u64,
+    /// Messages discarded on shutdown because their target is `AtMostOnce`.
+    pub discarded: u64,
+    /// Messages read back from storage on startup.
+    pub restored: u64,
+    /// `Acknowledged` messages dropped after exhausting their attempts.
+    pub abandoned: u64,
+}
+
+/// On-disk representation of one target's queue.
+#[derive(Serialize, Deserialize)]
+struct PersistedQueue {
+    target: ComponentId,
+    messages: Vec<ComponentMessage>,
+}
+
+/// Journal record of one accepted durable message.
+#[derive(Serialize, Deserialize)]
+struct JournalRecord {
+    target: ComponentId,
+    message: ComponentMessage,
+}
+
+/// A queued message, its journal sequence (if journaled), and the
+/// delivery attempts already made.
+#[derive(Debug, Clone)]
+struct Queued {
+    seq: Option<u64>,
+    message: ComponentMessage,
+    attempts: u32,
+}
+
+impl Queued {
+    fn new(seq: Option<u64>, message: ComponentMessage) -> Self {
+        Self {
+            seq,
+            message,
+            attempts: 0,
+        }
+    }
+}
+
+/// A delivered `Acknowledged` message awaiting its outcome.
+#[derive(Debug)]
+struct Unacked {
+    queued: Queued,
+    retry_at: Instant,
+}
+
+type Queues = HashMap<ComponentId, VecDeque<Queued>>;
+type UnackedMap = HashMap<(ComponentId, String), Unacked>;
+
+/// In-memory queue of undelivered messages, keyed by target component.
+///
+/// # Thread Safety
+///
+/// Queues are held behind a `RwLock`; counters are atomics. All lock
+/// accesses map poisoning to `MessagingError::DeliveryFailed`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let spool = MessageSpool::new(SpoolConfig {
+///     default_guarantee: DeliveryGuarantee::AtLeastOnce,
+///     ..SpoolConfig::default()
+/// });
+///
+/// // Startup: reload what the previous run left behind.
+/// spool.restore(&storage)?;
+///
+/// // Or, to survive crashes as well as graceful restarts:
+/// let spool = MessageSpool::new(config).with_journal(Arc::new(durable_storage));
+/// spool.recover()?;
+///
+/// // Normal operation: queue and flush.
+/// spool.enqueue(target.clone(), message)?;
+/// spool.flush(&subscriber)?;
+///
+/// // Graceful shutdown: persist whatever is still queued.
+/// let saved = spool.persist(&storage)?;
+/// ```
+pub struct MessageSpool {
+    config: SpoolConfig,
+    queues: RwLock<Queues>,
+    unacked: Mutex<UnackedMap>,
+    journal: Option<Arc<dyn ComponentStorage>>,
+    next_seq: AtomicU64,
+    saved: AtomicU64,
+    discarded: AtomicU64,
+    restored: AtomicU64,
+    abandoned: AtomicU64,
+}
+
+impl MessageSpool {
+    /// Creates an empty spool.
+    pub fn new(config: SpoolConfig) -> Self {
+        Self {
+            config,
+            queues: RwLock::new(HashMap::new()),
+            unacked: Mutex::new(HashMap::new()),
+            journal: None,
+            next_seq: AtomicU64::new(0),
+            saved: AtomicU64::new(0),
+            discarded: AtomicU64::new(0),
+            restored: AtomicU64::new(0),
+            abandoned: AtomicU64::new(0),
+        }
+    }
+
+    /// Journals durable (`AtLeastOnce` and `Acknowledged`) messages to
+    /// `storage` as they are enqueued.
+    ///
+    /// Call [`recover`](Self::recover) before enqueueing so sequences
+    /// continue after those left by the previous run.
+    pub fn with_journal(mut self, storage: Arc<dyn ComponentStorage>) -> Self {
+        self.journal = Some(storage);
+        self
+    }
+
+    /// Returns the spool configuration.
+    pub fn config(&self) -> &SpoolConfig {
+        &self.config
+    }
+
+    /// Appends a message to `target`'s queue.
+    ///
+    /// With a journal and a durable target, the message is written to the
+    /// journal first and only queued once that write succeeds. Messages to
+    /// `Acknowledged` targets without an `idempotency_key` are given one.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` if the message cannot be serialized
+    /// - `MessagingError::DeliveryFailed` if the journal write fails or the
+    ///   lock is poisoned; the message is not queued
+    pub fn enqueue(
+        &self,
+        target: ComponentId,
+        mut message: ComponentMessage,
+    ) -> Result<(), MessagingError> {
+        let guarantee = self.config.guarantee_for(&target);
+        if guarantee == DeliveryGuarantee::Acknowledged {
+            message
+                .metadata
+                .idempotency_key
+                .get_or_insert_with(|| uuid::Uuid::new_v4().to_string());
+        }
+        let seq = match &self.journal {
+            Some(journal) if guarantee.is_durable() => {
+                let seq = self.next_seq.fetch_add(1, Ordering::Relaxed);
+                let record = JournalRecord {
+                    target: target.clone(),
+                    message,
+                };
+                let bytes = serde_json::to_vec(&record)
+                    .map_err(|e| MessagingError::InvalidMessage(e.to_string()))?;
+                journal
+                    .set(&self.journal_key(seq), StorageValue::new(bytes))
+                    .map_err(|e| {
+                        MessagingError::DeliveryFailed(format!("Failed to journal message: {}", e))
+                    })?;
+                return self.push(target, Some(seq), record.message);
+            }
+            _ => None,
+        };
+        self.push(target, seq, message)
+    }
+
+    /// Returns the number of queued messages across all targets.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn pending(&self) -> Result<usize, MessagingError> {
+        let queues = self
+            .queues
+            .read()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))?;
+        Ok(queues.values().map(VecDeque::len).sum())
+    }
+
+    /// Returns the number of delivered messages awaiting acknowledgement.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the lock is poisoned.
+    pub fn unacknowledged(&self) -> Result<usize, MessagingError> {
+        Ok(self.lock_unacked()?.len())
+    }
+
+    /// Requeues unacknowledged messages whose retry time has passed.
+    ///
+    /// That is messages that failed and waited out their backoff, and
+    /// messages not acknowledged within [`AckPolicy::timeout`]. Requeued
+    /// messages go ahead of anything queued for the same target and are
+    /// sent by the next [`flush`](Self::flush); messages out of attempts
+    /// are abandoned instead.
+    ///
+    /// # Returns
+    ///
+    /// The number of messages requeued.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if a lock is poisoned.
+    pub fn redeliver_due(&self, now: Instant) -> Result<usize, MessagingError> {
+        let mut due: Vec<(ComponentId, Queued)> = {
+            let mut unacked = self.lock_unacked()?;
+            let keys: Vec<_> = unacked
+                .iter()
+                .filter(|(_, entry)| entry.retry_at <= now)
+                .map(|(key, _)| key.clone())
+                .collect();
+            keys.into_iter()
+                .filter_map(|key| unacked.remove(&key).map(|entry| (key.0, entry.queued)))
+                .collect()
+        };
+        due.sort_by_key(|(_, queued)| std::cmp::Reverse(queued.seq));
+
+        let mut requeued = 0;
+        let mut queues = self.write_queues()?;
+        for (target, queued) in due {
+            if queued.attempts >= self.config.ack.max_attempts {
+                self.abandon(&target, &queued, "not acknowledged");
+                continue;
+            }
+            queues.entry(target).or_default().push_front(queued);
+            requeued += 1;
+        }
+        Ok(requeued)
+    }
+
+    /// Reloads messages journaled by a previous run, e.g. after a crash.
+    ///
+    /// Recovered messages are placed ahead of anything already queued for
+    /// the same target, in enqueue order. Journal records stay in storage
+    /// until the messages are delivered by [`flush`](Self::flush), so a
+    /// crash during recovery loses nothing. Records of messages the spool
+    /// still holds, queued or awaiting acknowledgement, are skipped, so
+    /// calling this again (e.g. from a retried start) queues nothing twice.
+    /// Without a journal this is a no-op.
+    ///
+    /// # Returns
+    ///
+    /// The number of messages recovered by this call.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` if a journal record is corrupt
+    /// - `MessagingError::DeliveryFailed` if storage fails or the lock is
+    ///   poisoned
+    pub fn recover(&self) -> Result<u64, MessagingError> {
+        let Some(journal) = &self.journal else {
+            return Ok(0);
+        };
+        let prefix = self.journal_prefix();
+        let mut keys = journal.list_keys(Some(&prefix)).map_err(|e| {
+            MessagingError::DeliveryFailed(format!("Failed to list journal: {}", e))
+        })?;
+        keys.sort();
+
+        let mut records = Vec::new();
+        for key in keys {
+            let Ok(seq) = key[prefix.len()..].parse::<u64>() else {
+                continue;
+            };
+            let Some(value) = journal.get(&key).map_err(|e| {
+                MessagingError::DeliveryFailed(format!("Failed to read journal: {}", e))
+            })?
+            else {
+                continue;
+            };
+            let record: JournalRecord = serde_json::from_slice(value.as_bytes())
+                .map_err(|e| MessagingError::InvalidMessage(format!("{}: {}", key, e)))?;
+            self.next_seq.fetch_max(seq + 1, Ordering::Relaxed);
+            records.push((seq, record));
+        }
+
+        // Same lock order as `flush`: queues, then the unacknowledged set
+        let mut queues = self.write_queues()?;
+        let held: HashSet<u64> = {
+            let unacked = self.lock_unacked()?;
+            queues
+                .values()
+                .flatten()
+                .chain(unacked.values().map(|entry| &entry.queued))
+                .filter_map(|queued| queued.seq)
+                .collect()
+        };
+        let mut recovered: HashMap<ComponentId, VecDeque<Queued>> = HashMap::new();
+        let mut count = 0;
+        for (seq, record) in records {
+            if held.contains(&seq) {
+                continue;
+            }
+            recovered
+                .entry(record.target)
+                .or_default()
+                .push_back(Queued::new(Some(seq), record.message));
+            count += 1;
+        }
+        for (target, messages) in recovered {
+            let queue = queues.entry(target).or_default();
+            for queued in messages.into_iter().rev() {
+                queue.push_front(queued);
+            }
+        }
+        self.restored.fetch_add(count, Ordering::Relaxed);
+        Ok(count)
+    }
+
+    /// Delivers queued messages in order through `subscriber`.
+    ///
+    /// Delivery to a target stops at its first failure; that message and
+    /// everything behind it stay queued for the next flush. Journal records
+    /// of delivered messages are deleted; one whose deletion fails is
+    /// delivered again after the next [`recover`](Self::recover).
+    /// Messages to `Acknowledged` targets are recorded as unacknowledged
+    /// before they are handed to the mailbox, so an acknowledgement can
+    /// never arrive for a message the spool does not know about; they are
+    /// kept until acknowledged.
+    ///
+    /// # Returns
+    ///
+    /// The number of messages delivered.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if a lock is poisoned. A
+    /// message is only taken off its queue once the unacknowledged set is
+    /// locked, so a poisoned lock never loses it.
+    pub fn flush(&self, subscriber: &ComponentSubscriber) -> Result<usize, MessagingError> {
+        let mut queues = self.write_queues()?;
+        let mut delivered = 0;
+        let mut failure = None;
+        'targets: for (target, queue) in queues.iter_mut() {
+            let acknowledged = self.config.guarantee_for(target) == DeliveryGuarantee::Acknowledged;
+            loop {
+                let key = match queue.front() {
+                    Some(queued) if acknowledged => queued.message.metadata.idempotency_key.clone(),
+                    Some(_) => None,
+                    None => break,
+                };
+                let outcome = match key {
+                    Some(key) => self.deliver_tracked(subscriber, target, queue, key),
+                    None => Ok(self.deliver_untracked(subscriber, target, queue)),
+                };
+                match outcome {
+                    Ok(true) => delivered += 1,
+                    Ok(false) => break,
+                    Err(e) => {
+                        failure = Some(e);
+                        break 'targets;
+                    }
+                }
+            }
+        }
+        queues.retain(|_, queue| !queue.is_empty());
+        match failure {
+            Some(e) => Err(e),
+            None => Ok(delivered),
+        }
+    }
+
+    /// Runs [`redeliver_due`](Self::redeliver_due) followed by
+    /// [`flush`](Self::flush) through `subscriber` every `period` on the
+    /// current Tokio runtime.
+    ///
+    /// This drives retries of `Acknowledged` messages whose timeout or
+    /// backoff has passed, and delivers messages that were queued while
+    /// their target had no mailbox. The task stops once the spool is
+    /// dropped; failed rounds are logged and retried on the next tick.
+    pub fn spawn_redelivery(
+        self: &Arc<Self>,
+        subscriber: Arc<ComponentSubscriber>,
+        period: Duration,
+    ) -> JoinHandle<()> {
+        let spool = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(period);
+            loop {
+                ticker.tick().await;
+                let Some(spool) = spool.upgrade() else {
+                    break;
+                };
+                let round = spool
+                    .redeliver_due(Instant::now())
+                    .and_then(|_| spool.flush(&subscriber));
+                if let Err(e) = round {
+                    tracing::warn!(error = %e, "spool redelivery failed");
+                }
+            }
+        })
+    }
+
+    /// Delivers the head of `queue` and clears its journal record, or
+    /// leaves it queued if the mailbox refuses it; returns whether it was
+    /// delivered.
+    fn deliver_untracked(
+        &self,
+        subscriber: &ComponentSubscriber,
+        target: &ComponentId,
+        queue: &mut VecDeque<Queued>,
+    ) -> bool {
+        let Some(queued) = queue.pop_front() else {
+            return false;
+        };
+        if subscriber.deliver(target, queued.message.clone()).is_err() {
+            queue.push_front(queued);
+            return false;
+        }
+        self.clear_journal(target, queued.seq);
+        true
+    }
+
+    /// Moves the head of `queue` to the unacknowledged set, then delivers
+    /// it. A refused message is moved back unless an ack or redelivery
+    /// already claimed it; returns whether it was delivered.
+    fn deliver_tracked(
+        &self,
+        subscriber: &ComponentSubscriber,
+        target: &ComponentId,
+        queue: &mut VecDeque<Queued>,
+        key: String,
+    ) -> Result<bool, MessagingError> {
+        let mut unacked = self.lock_unacked()?;
+        let Some(mut queued) = queue.pop_front() else {
+            return Ok(false);
+        };
+        let message = queued.message.clone();
+        queued.attempts += 1;
+        let key = (target.clone(), key);
+        unacked.insert(
+            key.clone(),
+            Unacked {
+                queued,
+                retry_at: Instant::now() + self.config.ack.timeout,
+            },
+        );
+        // The mailbox may acknowledge synchronously
+        drop(unacked);
+
+        if subscriber.deliver(target, message).is_ok() {
+            return Ok(true);
+        }
+        // If this lock fails the message stays unacknowledged and is
+        // retried by `redeliver_due`
+        if let Some(entry) = self.lock_unacked()?.remove(&key) {
+            let mut queued = entry.queued;
+            queued.attempts -= 1;
+            queue.push_front(queued);
+        }
+        Ok(false)
+    }
+
+    /// Drains every queue, persisting `AtLeastOnce` targets to `storage`.
+    ///
+    /// Call this from the host's graceful shutdown path after mailboxes
+    /// have stopped accepting messages. The spool is empty afterwards.
+    /// Journaled messages are already durable; they are left in the
+    /// journal for [`recover`](Self::recover) and counted as saved.
+    /// Unacknowledged messages are persisted with their target's queue.
+    ///
+    /// # Returns
+    ///
+    /// The number of messages saved.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` if a queue cannot be serialized
+    /// - `MessagingError::DeliveryFailed` if storage fails or the lock is
+    ///   poisoned; queues not yet written are kept in memory
+    pub fn persist<S: ComponentStorage + ?Sized>(
+        &self,
+        storage: &S,
+    ) -> Result<u64, MessagingError> {
+        let mut queues = self.write_queues()?;
+        for ((target, _), entry) in self.lock_unacked()?.drain() {
+            queues.entry(target).or_default().push_front(entry.queued);
+        }
+        let targets: Vec<ComponentId> = queues.keys().cloned().collect();
+        let mut saved = 0;
+
+        for target in targets {
+            let guarantee = self.config.guarantee_for(&target);
+            let Some(queue) = queues.remove(&target) else {
+                continue;
+            };
+            let (journaled, queue): (Vec<_>, Vec<_>) =
+                queue.into_iter().partition(|queued| queued.seq.is_some());
+            let journaled = journaled.len() as u64;
+            saved += journaled;
+            self.saved.fetch_add(journaled, Ordering::Relaxed);
+            if queue.is_empty() {
+                continue;
+            }
+            let count = queue.len() as u64;
+
+            if guarantee == DeliveryGuarantee::AtMostOnce {
+                self.discarded.fetch_add(count, Ordering::Relaxed);
+                continue;
+            }
+
+            let record = PersistedQueue {
+                target: target.clone(),
+                messages: queue.into_iter().map(|queued| queued.message).collect(),
+            };
+            let bytes = serde_json::to_vec(&record)
+                .map_err(|e| MessagingError::InvalidMessage(e.to_string()))?;
+            if let Err(e) = storage.set(&self.key_for(&target), StorageValue::new(bytes)) {
+                queues.insert(
+                    target,
+                    record
+                        .messages
+                        .into_iter()
+                        .map(|message| Queued::new(None, message))
+                        .collect(),
+                );
+                return Err(MessagingError::DeliveryFailed(format!(
+                    "Failed to persist queue: {}",
+                    e
+                )));
+            }
+            saved += count;
+            self.saved.fetch_add(count, Ordering::Relaxed);
+        }
+
+        Ok(saved)
+    }
+
+    /// Loads queues persisted by a previous [`persist`](Self::persist).
+    ///
+    /// Restored messages are placed ahead of anything already queued for
+    /// the same target, preserving original order. Each storage key is
+    /// deleted once its messages are back in memory.
+    ///
+    /// # Returns
+    ///
+    /// The number of messages restored.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` if a stored queue is corrupt
+    /// - `MessagingError::DeliveryFailed` if storage fails or the lock is
+    ///   poisoned
+    pub fn restore<S: ComponentStorage + ?Sized>(
+        &self,
+        storage: &S,
+    ) -> Result<u64, MessagingError> {
+        let prefix = format!("{}/", self.config.key_prefix);
+        let keys = storage
+            .list_keys(Some(&prefix))
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Failed to list queues: {}", e)))?;
+        let mut restored = 0;
+
+        for key in keys {
+            let Some(value) = storage.get(&key).map_err(|e| {
+                MessagingError::DeliveryFailed(format!("Failed to read queue: {}", e))
+            })?
+            else {
+                continue;
+            };
+            let record: PersistedQueue = serde_json::from_slice(value.as_bytes())
+                .map_err(|e| MessagingError::InvalidMessage(format!("{}: {}", key, e)))?;
+            let count = record.messages.len() as u64;
+
+            {
+                let mut queues = self.write_queues()?;
+                let queue = queues.entry(record.target).or_default();
+                for message in record.messages.into_iter().rev() {
+                    queue.push_front(Queued::new(None, message));
+                }
+            }
+
+            storage.delete(&key).map_err(|e| {
+                MessagingError::DeliveryFailed(format!("Failed to clear queue: {}", e))
+            })?;
+            restored += count;
+            self.restored.fetch_add(count, Ordering::Relaxed);
+        }
+
+        Ok(restored)
+    }
+
+    /// Returns a snapshot of the persistence counters.
+    pub fn metrics(&self) -> SpoolMetrics {
+        SpoolMetrics {
+            saved: self.saved.load(Ordering::Relaxed),
+            discarded: self.discarded.load(Ordering::Relaxed),
+            restored: self.restored.load(Ordering::Relaxed),
+            abandoned: self.abandoned.load(Ordering::Relaxed),
+        }
+    }
+
+    fn push(
+        &self,
+        target: ComponentId,
+        seq: Option<u64>,
+        message: ComponentMessage,
+    ) -> Result<(), MessagingError> {
+        let mut queues = self.write_queues()?;
+        queues
+            .entry(target)
+            .or_default()
+            .push_back(Queued::new(seq, message));
+        Ok(())
+    }
+
+    fn abandon(&self, target: &ComponentId, queued: &Queued, reason: &str) {
+        tracing::warn!(
+            target = %target,
+            attempts = queued.attempts,
+            reason,
+            "abandoning acknowledged message"
+        );
+        self.abandoned.fetch_add(1, Ordering::Relaxed);
+        self.clear_journal(target, queued.seq);
+    }
+
+    fn clear_journal(&self, target: &ComponentId, seq: Option<u64>) {
+        if let (Some(journal), Some(seq)) = (&self.journal, seq) {
+            if let Err(e) = journal.delete(&self.journal_key(seq)) {
+                tracing::warn!(target = %target, seq, error = %e, "failed to clear journaled message");
+            }
+        }
+    }
+
+    fn lock_unacked(&self) -> Result<MutexGuard<'_, UnackedMap>, MessagingError> {
+        self.unacked
+            .lock()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))
+    }
+
+    fn journal_prefix(&self) -> String {
+        format!("{}-journal/", self.config.key_prefix)
+    }
+
+    fn journal_key(&self, seq: u64) -> String {
+        format!("{}{:020}", self.journal_prefix(), seq)
+    }
+
+    fn key_for(&self, target: &ComponentId) -> String {
+        format!("{}/{}", self.config.key_prefix, target.to_string_id())
+    }
+
+    fn write_queues(&self) -> Result<RwLockWriteGuard<'_, Queues>, MessagingError> {
+        self.queues
+            .write()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))
+    }
+}
+
+impl DeliveryAcknowledger for MessageSpool {
+    fn ack(&self, target: &ComponentId, idempotency_key: &str) {
+        let Ok(mut unacked) = self.lock_unacked() else {
+            return;
+        };
+        if let Some(entry) = unacked.remove(&(target.clone(), idempotency_key.to_string())) {
+            drop(unacked);
+            self.clear_journal(target, entry.queued.seq);
+        }
+    }
+
+    fn nack(&self, target: &ComponentId, idempotency_key: &str, error: &str) {
+        let Ok(mut unacked) = self.lock_unacked() else {
+            return;
+        };
+        let key = (target.clone(), idempotency_key.to_string());
+        let Some(entry) = unacked.get_mut(&key) else {
+            return;
+        };
+        if entry.queued.attempts < self.config.ack.max_attempts {
+            entry.retry_at = Instant::now() + self.config.ack.backoff(entry.queued.attempts);
+            return;
+        }
+        if let Some(entry) = unacked.remove(&key) {
+            drop(unacked);
+            self.abandon(target, &entry.queued, error);
+        }
+    }
+}
+
+impl fmt::Debug for MessageSpool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MessageSpool")
+            .field("config", &self.config)
+            .field("queues", &self.queues)
+            .field("journaled", &self.journal.is_some())
+            .finish()
+    }
+}
+
+impl Default for MessageSpool {
+    fn default() -> Self {
+        Self::new(SpoolConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::component::message::{MessageMetadata, MessagePayload};
+    use crate::core::storage::errors::StorageError;
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct MemoryStorage {
+        data: Mutex<BTreeMap<String, StorageValue>>,
+        fail_writes: bool,
+    }
+
+    impl ComponentStorage for MemoryStorage {
+        fn get(&self, key: &str) -> Result<Option<StorageValue>, StorageError> {
+            Ok(self.data.lock().unwrap().get(key).cloned())
+        }
+
+        fn set(&self, key: &str, value: StorageValue) -> Result<(), StorageError> {
+            if self.fail_writes {
+                return Err(StorageError::IoError("disk full".to_string()));
+            }
+            self.data.lock().unwrap().insert(key.to_string(), value);
+            Ok(())
+        }
+
+        fn delete(&self, key: &str) -> Result<(), StorageError> {
+            self.data.lock().unwrap().remove(key);
+            Ok(())
+        }
+
+        fn exists(&self, key: &str) -> Result<bool, StorageError> {
+            Ok(self.data.lock().unwrap().contains_key(key))
+        }
+
+        fn list_keys(&self, prefix: Option<&str>) -> Result<Vec<String>, StorageError> {
+            let data = self.data.lock().unwrap();
+            Ok(data
+                .keys()
+                .filter(|k| prefix.is_none_or(|p| k.starts_with(p)))
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn target(name: &str) -> ComponentId {
+        ComponentId::new("app", name, "v1")
+    }
+
+    fn message(byte: u8) -> ComponentMessage {
+        ComponentMessage::new(
+            target("sender"),
+            MessagePayload::new(vec![byte]),
+            MessageMetadata::default(),
+        )
+    }
+
+    fn at_least_once() -> SpoolConfig {
+        SpoolConfig {
+            default_guarantee: DeliveryGuarantee::AtLeastOnce,
+            ..SpoolConfig::default()
+        }
+    }
+
+    fn collecting_subscriber(id: ComponentId) -> (ComponentSubscriber, Arc<Mutex<Vec<u8>>>) {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&received);
+        let subscriber = ComponentSubscriber::new();
+        subscriber
+            .register_mailbox(
+                id,
+                Box::new(move |msg| {
+                    sink.lock().unwrap().push(msg.payload.as_bytes()[0]);
+                    Ok(())
+                }),
+            )
+            .unwrap();
+        (subscriber, received)
+    }
+
+    #[test]
+    fn test_persist_and_restore_round_trip() {
+        let storage = MemoryStorage::default();
+        let spool = MessageSpool::new(at_least_once());
+        for i in 0..3 {
+            spool.enqueue(target("worker"), message(i)).unwrap();
+        }
+
+        assert_eq!(spool.persist(&storage).unwrap(), 3);
+        assert_eq!(spool.pending().unwrap(), 0);
+        assert!(storage.exists("message-spool/app/worker/v1").unwrap());
+
+        let next = MessageSpool::new(at_least_once());
+        assert_eq!(next.restore(&storage).unwrap(), 3);
+        assert!(storage.list_keys(None).unwrap().is_empty());
+
+        let (subscriber, received) = collecting_subscriber(target("worker"));
+        assert_eq!(next.flush(&subscriber).unwrap(), 3);
+        assert_eq!(*received.lock().unwrap(), vec![0, 1, 2]);
+        assert_eq!(
+            next.metrics(),
+            SpoolMetrics {
+                restored: 3,
+                ..SpoolMetrics::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_at_most_once_is_discarded() {
+        let storage = MemoryStorage::default();
+        let mut config = at_least_once();
+        config
+            .overrides
+            .insert(target("ephemeral"), DeliveryGuarantee::AtMostOnce);
+        let spool = MessageSpool::new(config);
+        spool.enqueue(target("ephemeral"), message(1)).unwrap();
+        spool.enqueue(target("ephemeral"), message(2)).unwrap();
+        spool.enqueue(target("durable"), message(3)).unwrap();
+
+        assert_eq!(spool.persist(&storage).unwrap(), 1);
+        assert_eq!(
+            spool.metrics(),
+            SpoolMetrics {
+                saved: 1,
+                discarded: 2,
+                restored: 0,
+                abandoned: 0,
+            }
+        );
+        assert_eq!(storage.list_keys(None).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_restored_messages_precede_new_ones() {
+        let storage = MemoryStorage::default();
+        let old = MessageSpool::new(at_least_once());
+        old.enqueue(target("worker"), message(1)).unwrap();
+        old.persist(&storage).unwrap();
+
+        let spool = MessageSpool::new(at_least_once());
+        spool.enqueue(target("worker"), message(2)).unwrap();
+        spool.restore(&storage).unwrap();
+
+        let (subscriber, received) = collecting_subscriber(target("worker"));
+        spool.flush(&subscriber).unwrap();
+        assert_eq!(*received.lock().unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_flush_keeps_undeliverable_messages() {
+        let spool = MessageSpool::default();
+        spool.enqueue(target("missing"), message(1)).unwrap();
+        let (subscriber, _) = collecting_subscriber(target("other"));
+
+        assert_eq!(spool.flush(&subscriber).unwrap(), 0);
+        assert_eq!(spool.pending().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_persist_failure_keeps_queue() {
+        let storage = MemoryStorage {
+            fail_writes: true,
+            ..MemoryStorage::default()
+        };
+        let spool = MessageSpool::new(at_least_once());
+        spool.enqueue(target("worker"), message(1)).unwrap();
+
+        let result = spool.persist(&storage);
+        assert!(matches!(result, Err(MessagingError::DeliveryFailed(_))));
+        assert_eq!(spool.pending().unwrap(), 1);
+        assert_eq!(spool.metrics().saved, 0);
+    }
+
+    #[test]
+    fn test_restore_rejects_corrupt_queue() {
+        let storage = MemoryStorage::default();
+        storage
+            .set(
+                "message-spool/app/worker/v1",
+                StorageValue::new(b"nope".to_vec()),
+            )
+            .unwrap();
+
+        let spool = MessageSpool::default();
+        let result = spool.restore(&storage);
+        assert!(matches!(result, Err(MessagingError::InvalidMessage(_))));
+    }
+
+    #[test]
+    fn test_journal_survives_crash_and_redelivers() {
+        let storage = Arc::new(MemoryStorage::default());
+        let mut config = at_least_once();
+        config
+            .overrides
+            .insert(target("ephemeral"), DeliveryGuarantee::AtMostOnce);
+
+        let crashed = MessageSpool::new(config.clone()).with_journal(storage.clone());
+        crashed.enqueue(target("worker"), message(1)).unwrap();
+        crashed.enqueue(target("worker"), message(2)).unwrap();
+        crashed.enqueue(target("ephemeral"), message(9)).unwrap();
+        assert_eq!(storage.list_keys(None).unwrap().len(), 2);
+        // No persist(): the host crashed
+        drop(crashed);
+
+        let spool = MessageSpool::new(config).with_journal(storage.clone());
+        assert_eq!(spool.recover().unwrap(), 2);
+        // Recovering again (e.g. a retried start) queues nothing twice
+        assert_eq!(spool.recover().unwrap(), 0);
+        assert_eq!(spool.pending().unwrap(), 2);
+        spool.enqueue(target("worker"), message(3)).unwrap();
+        assert_eq!(storage.list_keys(None).unwrap().len(), 3);
+
+        let (subscriber, received) = collecting_subscriber(target("worker"));
+        assert_eq!(spool.flush(&subscriber).unwrap(), 3);
+        assert_eq!(*received.lock().unwrap(), vec![1, 2, 3]);
+        assert!(storage.list_keys(None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_undelivered_journal_entries_are_kept() {
+        let storage = Arc::new(MemoryStorage::default());
+        let spool = MessageSpool::new(at_least_once()).with_journal(storage.clone());
+        spool.enqueue(target("missing"), message(1)).unwrap();
+        let (subscriber, _) = collecting_subscriber(target("other"));
+        spool.flush(&subscriber).unwrap();
+
+        // Shutdown leaves journaled messages in the journal only
+        assert_eq!(spool.persist(&*storage).unwrap(), 1);
+        assert_eq!(spool.pending().unwrap(), 0);
+        let next = MessageSpool::new(at_least_once()).with_journal(storage.clone());
+        assert_eq!(next.restore(&*storage).unwrap(), 0);
+        assert_eq!(next.recover().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_failed_journal_write_rejects_message() {
+        let storage = Arc::new(MemoryStorage {
+            fail_writes: true,
+            ..MemoryStorage::default()
+        });
+        let spool = MessageSpool::new(at_least_once()).with_journal(storage);
+        let result = spool.enqueue(target("worker"), message(1));
+        assert!(matches!(result, Err(MessagingError::DeliveryFailed(_))));
+        assert_eq!(spool.pending().unwrap(), 0);
+    }
+
+    fn acknowledged(max_attempts: u32) -> SpoolConfig {
+        SpoolConfig {
+            default_guarantee: DeliveryGuarantee::Acknowledged,
+            ack: AckPolicy {
+                timeout: Duration::from_secs(60),
+                initial_backoff: Duration::ZERO,
+                max_backoff: Duration::ZERO,
+                max_attempts,
+            },
+            ..SpoolConfig::default()
+        }
+    }
+
+    fn collecting_keys(id: ComponentId) -> (ComponentSubscriber, Arc<Mutex<Vec<String>>>) {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&received);
+        let subscriber = ComponentSubscriber::new();
+        subscriber
+            .register_mailbox(
+                id,
+                Box::new(move |msg| {
+                    let key = msg.metadata.idempotency_key.clone().unwrap_or_default();
+                    sink.lock().unwrap().push(key);
+                    Ok(())
+                }),
+            )
+            .unwrap();
+        (subscriber, received)
+    }
+
+    #[test]
+    fn test_acknowledged_message_retained_until_ack() {
+        let storage = Arc::new(MemoryStorage::default());
+        let spool = MessageSpool::new(acknowledged(3)).with_journal(storage.clone());
+        spool.enqueue(target("worker"), message(1)).unwrap();
+        let (subscriber, received) = collecting_keys(target("worker"));
+
+        assert_eq!(spool.flush(&subscriber).unwrap(), 1);
+        assert_eq!(spool.unacknowledged().unwrap(), 1);
+        assert_eq!(storage.list_keys(None).unwrap().len(), 1);
+
+        // Handler trapped: retried after the (zero) backoff, same key
+        let key = received.lock().unwrap()[0].clone();
+        assert!(!key.is_empty());
+        spool.nack(&target("worker"), &key, "trap");
+        assert_eq!(spool.redeliver_due(Instant::now()).unwrap(), 1);
+        spool.flush(&subscriber).unwrap();
+        assert_eq!(*received.lock().unwrap(), vec![key.clone(), key.clone()]);
+
+        spool.ack(&target("worker"), &key);
+        assert_eq!(spool.unacknowledged().unwrap(), 0);
+        assert!(storage.list_keys(None).unwrap().is_empty());
+        assert_eq!(spool.redeliver_due(Instant::now()).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_ack_during_delivery_is_not_lost() {
+        let spool = Arc::new(MessageSpool::new(acknowledged(3)));
+        spool.enqueue(target("worker"), message(1)).unwrap();
+        let acker = Arc::clone(&spool);
+        let subscriber = ComponentSubscriber::new();
+        subscriber
+            .register_mailbox(
+                target("worker"),
+                Box::new(move |msg| {
+                    let key = msg.metadata.idempotency_key.clone().unwrap_or_default();
+                    acker.ack(&target("worker"), &key);
+                    Ok(())
+                }),
+            )
+            .unwrap();
+
+        assert_eq!(spool.flush(&subscriber).unwrap(), 1);
+        assert_eq!(spool.unacknowledged().unwrap(), 0);
+        assert_eq!(spool.pending().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_refused_acknowledged_message_stays_queued() {
+        let spool = MessageSpool::new(acknowledged(3));
+        spool.enqueue(target("worker"), message(1)).unwrap();
+
+        assert_eq!(spool.flush(&ComponentSubscriber::new()).unwrap(), 0);
+        assert_eq!(spool.unacknowledged().unwrap(), 0);
+        assert_eq!(spool.pending().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_redelivery_task_retries_timed_out_messages() {
+        let mut config = acknowledged(5);
+        config.ack.timeout = Duration::ZERO;
+        let spool = Arc::new(MessageSpool::new(config));
+        spool.enqueue(target("worker"), message(1)).unwrap();
+        let (subscriber, received) = collecting_keys(target("worker"));
+
+        let task = spool.spawn_redelivery(Arc::new(subscriber), Duration::from_millis(5));
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        task.abort();
+
+        let received = received.lock().unwrap();
+        assert!(received.len() >= 2);
+        assert!(received.iter().all(|key| key == &received[0]));
+    }
+
+    #[test]
+    fn test_unacknowledged_message_times_out_then_is_abandoned() {
+        let spool = MessageSpool::new(acknowledged(2));
+        spool.enqueue(target("worker"), message(1)).unwrap();
+        let (subscriber, received) = collecting_keys(target("worker"));
+        spool.flush(&subscriber).unwrap();
+
+        // Not yet timed out
+        assert_eq!(spool.redeliver_due(Instant::now()).unwrap(), 0);
+        let later = Instant::now() + Duration::from_secs(61);
+        assert_eq!(spool.redeliver_due(later).unwrap(), 1);
+        spool.flush(&subscriber).unwrap();
+        assert_eq!(received.lock().unwrap().len(), 2);
+
+        // Second attempt also unanswered: out of attempts
+        let later = Instant::now() + Duration::from_secs(61);
+        assert_eq!(spool.redeliver_due(later).unwrap(), 0);
+        assert_eq!(spool.unacknowledged().unwrap(), 0);
+        assert_eq!(spool.pending().unwrap(), 0);
+        assert_eq!(spool.metrics().abandoned, 1);
+    }
+
+    #[test]
+    fn test_unacknowledged_messages_are_persisted() {
+        let storage = MemoryStorage::default();
+        let spool = MessageSpool::new(acknowledged(3));
+        spool.enqueue(target("worker"), message(1)).unwrap();
+        let (subscriber, _) = collecting_keys(target("worker"));
+        spool.flush(&subscriber).unwrap();
+
+        assert_eq!(spool.persist(&storage).unwrap(), 1);
+        let next = MessageSpool::new(acknowledged(3));
+        assert_eq!(next.restore(&storage).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_ack_backoff_doubles_up_to_cap() {
+        let policy = AckPolicy {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+            ..AckPolicy::default()
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(500));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_restore_ignores_other_prefixes() {
+        let storage = MemoryStorage::default();
+        storage
+            .set("message-spool-old/x", StorageValue::new(b"nope".to_vec()))
+            .unwrap();
+
+        let spool = MessageSpool::default();
+        assert_eq!(spool.restore(&storage).unwrap(), 0);
+    }
+}
@@ -0,0 +1,516 @@
+//! Streamed responses and transfers with credit-based flow control.
+//!
+//! Provides both ends of a stream:
+//!
+//! - [`ResponseStreams`] (producer side) tracks open streams, numbers each
+//!   chunk, and refuses new chunks with [`MessagingError::QueueFull`] once
+//!   `window` chunks are unacknowledged. Every accepted chunk comes back as
+//!   a [`StreamDelivery`] for `system/` to hand to the receiver: the
+//!   requester's `handle-callback` for a response stream, the target's
+//!   `handle-message` for a transfer opened with
+//!   [`ResponseStreams::open_transfer`].
+//! - [`StreamAssembler`] (receiver side) puts chunks back in order, reports
+//!   how many it has consumed (fed back through [`ResponseStreams::ack`]),
+//!   and detects the end-of-stream marker.
+//!
+//! The wire format of a chunk is defined by
+//! [`StreamChunk`](crate::core::messaging::stream::StreamChunk).
+//!
+//! # Architecture
+//!
+//! This module is part of `messaging/` (Layer 3B). It depends only on
+//! `core/component/` and `core/messaging/`.
+
+// Layer 1: Standard library imports
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+// Layer 2: Third-party crate imports
+// (none)
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::{ComponentMessage, MessagePayload};
+use crate::core::messaging::correlation::CorrelationId;
+use crate::core::messaging::errors::MessagingError;
+use crate::core::messaging::stream::{StreamChunk, StreamHandle, StreamKind};
+
+/// Default number of unacknowledged chunks allowed per stream.
+pub const DEFAULT_STREAM_WINDOW: u64 = 16;
+
+/// A chunk message ready for delivery to the receiver.
+#[derive(Debug, Clone)]
+pub struct StreamDelivery {
+    /// Requester of a response stream, or target of a transfer.
+    pub target: ComponentId,
+    /// Whether the message goes to `handle-callback` or `handle-message`.
+    pub kind: StreamKind,
+    /// Message carrying the chunk.
+    pub message: ComponentMessage,
+}
+
+/// Producer-side state of one open stream.
+#[derive(Debug)]
+struct OpenStream {
+    correlation_id: String,
+    receiver: ComponentId,
+    kind: StreamKind,
+    next_sequence: u64,
+    acknowledged: u64,
+}
+
+/// Producer-side registry of open response streams.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::component::message::MessagePayload;
+/// use airssys_wasm::messaging::stream::{ResponseStreams, StreamAssembler};
+///
+/// let producer = ComponentId::new("app", "query", "v1");
+/// let caller = ComponentId::new("app", "ui", "v1");
+/// let streams = ResponseStreams::new(producer).with_window(2);
+///
+/// let handle = streams.open("corr-1", caller).unwrap();
+/// let mut assembler = StreamAssembler::new("corr-1");
+///
+/// for part in [b"a".to_vec(), b"b".to_vec()] {
+///     let delivery = streams.push(handle, MessagePayload::new(part)).unwrap();
+///     assembler.accept(&delivery.message).unwrap();
+/// }
+/// // Window of 2 is exhausted until the caller acknowledges
+/// assert!(streams.push(handle, MessagePayload::new(b"c".to_vec())).is_err());
+/// streams.ack("corr-1", assembler.received()).unwrap();
+///
+/// let end = streams.close(handle).unwrap();
+/// assembler.accept(&end.message).unwrap();
+/// assert!(assembler.is_complete());
+/// ```
+#[derive(Debug)]
+pub struct ResponseStreams {
+    producer: ComponentId,
+    window: u64,
+    streams: Mutex<HashMap<StreamHandle, OpenStream>>,
+    next_handle: AtomicU64,
+}
+
+impl ResponseStreams {
+    /// Creates a registry for streams produced by `producer`.
+    pub fn new(producer: ComponentId) -> Self {
+        Self {
+            producer,
+            window: DEFAULT_STREAM_WINDOW,
+            streams: Mutex::new(HashMap::new()),
+            next_handle: AtomicU64::new(1),
+        }
+    }
+
+    /// Sets the number of unacknowledged chunks allowed per stream (at least 1).
+    pub fn with_window(mut self, window: u64) -> Self {
+        self.window = window.max(1);
+        self
+    }
+
+    /// Opens a stream answering request `correlation_id` from `caller`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the stream lock is poisoned.
+    pub fn open(
+        &self,
+        correlation_id: impl Into<String>,
+        caller: ComponentId,
+    ) -> Result<StreamHandle, MessagingError> {
+        self.insert(correlation_id.into(), caller, StreamKind::Response)
+    }
+
+    /// Opens a transfer stream to `target` under a fresh correlation ID.
+    ///
+    /// Returns the handle and the correlation ID the target acknowledges
+    /// chunks with.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the stream lock is poisoned.
+    pub fn open_transfer(
+        &self,
+        target: ComponentId,
+    ) -> Result<(StreamHandle, CorrelationId), MessagingError> {
+        let correlation_id = CorrelationId::generate();
+        let handle = self.insert(
+            correlation_id.as_str().to_owned(),
+            target,
+            StreamKind::Transfer,
+        )?;
+        Ok((handle, correlation_id))
+    }
+
+    fn insert(
+        &self,
+        correlation_id: String,
+        receiver: ComponentId,
+        kind: StreamKind,
+    ) -> Result<StreamHandle, MessagingError> {
+        let handle = self.next_handle.fetch_add(1, Ordering::Relaxed);
+        let stream = OpenStream {
+            correlation_id,
+            receiver,
+            kind,
+            next_sequence: 0,
+            acknowledged: 0,
+        };
+        self.lock()?.insert(handle, stream);
+        Ok(handle)
+    }
+
+    /// Numbers `payload` as the next chunk of `handle`.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` if the stream is not open
+    /// - `MessagingError::QueueFull` if `window` chunks are unacknowledged;
+    ///   the producer should retry after the receiver acknowledges
+    /// - `MessagingError::DeliveryFailed` if the stream lock is poisoned
+    pub fn push(
+        &self,
+        handle: StreamHandle,
+        payload: MessagePayload,
+    ) -> Result<StreamDelivery, MessagingError> {
+        let mut streams = self.lock()?;
+        let stream = streams
+            .get_mut(&handle)
+            .ok_or_else(|| unknown_stream(handle))?;
+        if stream.next_sequence - stream.acknowledged >= self.window {
+            return Err(MessagingError::QueueFull);
+        }
+        let chunk = StreamChunk::data(stream.correlation_id.clone(), stream.next_sequence, payload);
+        stream.next_sequence += 1;
+        Ok(self.delivery(stream, chunk))
+    }
+
+    /// Closes `handle` and returns the end-of-stream marker.
+    ///
+    /// The marker is not subject to the flow-control window.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` if the stream is not open
+    /// - `MessagingError::DeliveryFailed` if the stream lock is poisoned
+    pub fn close(&self, handle: StreamHandle) -> Result<StreamDelivery, MessagingError> {
+        let stream = self
+            .lock()?
+            .remove(&handle)
+            .ok_or_else(|| unknown_stream(handle))?;
+        let chunk = StreamChunk::end(stream.correlation_id.clone(), stream.next_sequence);
+        Ok(self.delivery(&stream, chunk))
+    }
+
+    /// Drops `handle` without an end-of-stream marker (e.g. on cancellation).
+    ///
+    /// Returns `false` if the stream was not open.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the stream lock is poisoned.
+    pub fn abort(&self, handle: StreamHandle) -> Result<bool, MessagingError> {
+        Ok(self.lock()?.remove(&handle).is_some())
+    }
+
+    /// Records that the receiver has consumed `received` chunks of the
+    /// stream identified by `correlation_id`, freeing window credit.
+    ///
+    /// Stale acknowledgements (lower than a previous one) are ignored.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` if no open stream answers
+    ///   `correlation_id`, or `received` exceeds the chunks sent
+    /// - `MessagingError::DeliveryFailed` if the stream lock is poisoned
+    pub fn ack(&self, correlation_id: &str, received: u64) -> Result<(), MessagingError> {
+        let mut streams = self.lock()?;
+        let stream = streams
+            .values_mut()
+            .find(|s| s.correlation_id == correlation_id)
+            .ok_or_else(|| {
+                MessagingError::InvalidMessage(format!(
+                    "no open stream for correlation {}",
+                    correlation_id
+                ))
+            })?;
+        if received > stream.next_sequence {
+            return Err(MessagingError::InvalidMessage(format!(
+                "acknowledged {} chunks but only {} were sent",
+                received, stream.next_sequence
+            )));
+        }
+        stream.acknowledged = stream.acknowledged.max(received);
+        Ok(())
+    }
+
+    /// Chunks sent but not yet acknowledged on `handle`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the stream lock is poisoned.
+    pub fn in_flight(&self, handle: StreamHandle) -> Result<Option<u64>, MessagingError> {
+        Ok(self
+            .lock()?
+            .get(&handle)
+            .map(|s| s.next_sequence - s.acknowledged))
+    }
+
+    /// Number of open streams.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the stream lock is poisoned.
+    pub fn open_streams(&self) -> Result<usize, MessagingError> {
+        Ok(self.lock()?.len())
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, HashMap<StreamHandle, OpenStream>>, MessagingError> {
+        self.streams
+            .lock()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))
+    }
+
+    fn delivery(&self, stream: &OpenStream, chunk: StreamChunk) -> StreamDelivery {
+        StreamDelivery {
+            target: stream.receiver.clone(),
+            kind: stream.kind,
+            message: chunk.into_message(self.producer.clone()),
+        }
+    }
+}
+
+fn unknown_stream(handle: StreamHandle) -> MessagingError {
+    MessagingError::InvalidMessage(format!("stream {} is not open", handle))
+}
+
+/// Receiver-side reassembly of one streamed response or transfer.
+///
+/// Chunks may arrive out of order; [`accept`](Self::accept) buffers them
+/// and releases payloads strictly in sequence.
+#[derive(Debug)]
+pub struct StreamAssembler {
+    correlation_id: String,
+    next_sequence: u64,
+    buffered: BTreeMap<u64, StreamChunk>,
+    complete: bool,
+}
+
+impl StreamAssembler {
+    /// Creates an assembler for the stream identified by `correlation_id`.
+    pub fn new(correlation_id: impl Into<String>) -> Self {
+        Self {
+            correlation_id: correlation_id.into(),
+            next_sequence: 0,
+            buffered: BTreeMap::new(),
+            complete: false,
+        }
+    }
+
+    /// Accepts a chunk message and returns the payloads now in order.
+    ///
+    /// Duplicates and chunks after the end marker are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::InvalidMessage` if the message is not a
+    /// stream chunk, is malformed, or belongs to another stream.
+    pub fn accept(
+        &mut self,
+        message: &ComponentMessage,
+    ) -> Result<Vec<MessagePayload>, MessagingError> {
+        let chunk = StreamChunk::from_message(message).unwrap_or_else(|| {
+            Err(MessagingError::InvalidMessage(
+                "message is not a stream chunk".to_string(),
+            ))
+        })?;
+        if chunk.correlation_id != self.correlation_id {
+            return Err(MessagingError::InvalidMessage(format!(
+                "chunk for correlation {} delivered to stream {}",
+                chunk.correlation_id, self.correlation_id
+            )));
+        }
+        if self.complete || chunk.sequence < self.next_sequence {
+            return Ok(Vec::new());
+        }
+        self.buffered.insert(chunk.sequence, chunk);
+
+        let mut ready = Vec::new();
+        while let Some(chunk) = self.buffered.remove(&self.next_sequence) {
+            if chunk.end_of_stream {
+                self.complete = true;
+                self.buffered.clear();
+                break;
+            }
+            self.next_sequence += 1;
+            ready.push(chunk.payload);
+        }
+        Ok(ready)
+    }
+
+    /// Number of data chunks released in order, for acknowledgement.
+    pub fn received(&self) -> u64 {
+        self.next_sequence
+    }
+
+    /// Whether the end-of-stream marker has been reached.
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn streams(window: u64) -> ResponseStreams {
+        ResponseStreams::new(ComponentId::new("app", "producer", "v1")).with_window(window)
+    }
+
+    fn caller() -> ComponentId {
+        ComponentId::new("app", "caller", "v1")
+    }
+
+    fn payload(byte: u8) -> MessagePayload {
+        MessagePayload::new(vec![byte])
+    }
+
+    #[test]
+    fn test_push_targets_caller_with_increasing_sequence() {
+        let streams = streams(4);
+        let handle = streams.open("corr-1", caller()).unwrap();
+
+        let first = streams.push(handle, payload(1)).unwrap();
+        let second = streams.push(handle, payload(2)).unwrap();
+        assert_eq!(first.target, caller());
+        assert_eq!(first.kind, StreamKind::Response);
+
+        let seqs: Vec<u64> = [first, second]
+            .iter()
+            .map(|d| {
+                StreamChunk::from_message(&d.message)
+                    .unwrap()
+                    .unwrap()
+                    .sequence
+            })
+            .collect();
+        assert_eq!(seqs, vec![0, 1]);
+        assert_eq!(streams.in_flight(handle).unwrap(), Some(2));
+    }
+
+    #[test]
+    fn test_window_blocks_until_ack() {
+        let streams = streams(2);
+        let handle = streams.open("corr-1", caller()).unwrap();
+        streams.push(handle, payload(1)).unwrap();
+        streams.push(handle, payload(2)).unwrap();
+
+        assert!(matches!(
+            streams.push(handle, payload(3)),
+            Err(MessagingError::QueueFull)
+        ));
+
+        streams.ack("corr-1", 1).unwrap();
+        assert!(streams.push(handle, payload(3)).is_ok());
+        // Stale ack does not take credit back
+        streams.ack("corr-1", 0).unwrap();
+        assert_eq!(streams.in_flight(handle).unwrap(), Some(2));
+        assert!(streams.ack("corr-1", 9).is_err());
+    }
+
+    #[test]
+    fn test_close_removes_stream() {
+        let streams = streams(2);
+        let handle = streams.open("corr-1", caller()).unwrap();
+        streams.push(handle, payload(1)).unwrap();
+
+        let end = streams.close(handle).unwrap();
+        let chunk = StreamChunk::from_message(&end.message).unwrap().unwrap();
+        assert!(chunk.end_of_stream);
+        assert_eq!(chunk.sequence, 1);
+        assert_eq!(streams.open_streams().unwrap(), 0);
+        assert!(streams.push(handle, payload(2)).is_err());
+        assert!(!streams.abort(handle).unwrap());
+    }
+
+    #[test]
+    fn test_assembler_reorders_and_completes() {
+        let streams = streams(8);
+        let handle = streams.open("corr-1", caller()).unwrap();
+        let mut deliveries: Vec<StreamDelivery> = (1..=3)
+            .map(|b| streams.push(handle, payload(b)).unwrap())
+            .collect();
+        deliveries.push(streams.close(handle).unwrap());
+
+        let mut assembler = StreamAssembler::new("corr-1");
+        assert!(assembler.accept(&deliveries[3].message).unwrap().is_empty());
+        assert!(assembler.accept(&deliveries[1].message).unwrap().is_empty());
+        let ready = assembler.accept(&deliveries[0].message).unwrap();
+        assert_eq!(ready, vec![payload(1), payload(2)]);
+        assert!(!assembler.is_complete());
+
+        // Duplicate is ignored
+        assert!(assembler.accept(&deliveries[0].message).unwrap().is_empty());
+
+        let ready = assembler.accept(&deliveries[2].message).unwrap();
+        assert_eq!(ready, vec![payload(3)]);
+        assert!(assembler.is_complete());
+        assert_eq!(assembler.received(), 3);
+    }
+
+    #[test]
+    fn test_transfer_delivers_to_target_with_flow_control() {
+        let streams = streams(2);
+        let target = ComponentId::new("app", "sink", "v1");
+        let (handle, correlation_id) = streams.open_transfer(target.clone()).unwrap();
+        let mut assembler = StreamAssembler::new(correlation_id.as_str());
+
+        let data = b"large file contents".to_vec();
+        let mut received = Vec::new();
+        for chunk in StreamChunk::split(correlation_id.as_str(), &data, 4) {
+            if chunk.end_of_stream {
+                let end = streams.close(handle).unwrap();
+                assert_eq!(end.kind, StreamKind::Transfer);
+                assembler.accept(&end.message).unwrap();
+                break;
+            }
+            let delivery = match streams.push(handle, chunk.payload.clone()) {
+                Err(MessagingError::QueueFull) => {
+                    // Receiver catches up and grants more credit
+                    streams
+                        .ack(correlation_id.as_str(), assembler.received())
+                        .unwrap();
+                    streams.push(handle, chunk.payload).unwrap()
+                }
+                other => other.unwrap(),
+            };
+            assert_eq!(delivery.target, target);
+            assert_eq!(delivery.kind, StreamKind::Transfer);
+            for payload in assembler.accept(&delivery.message).unwrap() {
+                received.extend_from_slice(payload.as_bytes());
+            }
+        }
+
+        assert!(assembler.is_complete());
+        assert_eq!(received, data);
+        assert_eq!(streams.open_streams().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_assembler_rejects_foreign_chunks() {
+        let streams = streams(2);
+        let handle = streams.open("corr-2", caller()).unwrap();
+        let delivery = streams.push(handle, payload(1)).unwrap();
+
+        let mut assembler = StreamAssembler::new("corr-1");
+        assert!(matches!(
+            assembler.accept(&delivery.message),
+            Err(MessagingError::InvalidMessage(_))
+        ));
+    }
+}
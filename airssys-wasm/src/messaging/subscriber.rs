@@ -3,12 +3,21 @@
 //! Provides [`ComponentSubscriber`] for managing message delivery channels
 //! to component actors. Each component registers a delivery function that
 //! enables the messaging layer to push messages to the component's mailbox.
+//! When a [`PayloadCompressor`] is attached, compressed payloads are
+//! restored before they reach the mailbox. When a [`SchemaRegistry`] is
+//! attached, payloads that do not match the target's schema are rejected.
+//! When a [`QueueAdmission`] is attached, messages that would miss their
+//! deadline in the target's queue are shed before they reach the mailbox.
 //!
 //! # Architecture
 //!
 //! This module is part of `messaging/` (Layer 3B). It depends on:
 //! - `core/component/` for `ComponentId`, `ComponentMessage`
-//! - `core/messaging/` for `MessagingError`
+//! - `core/messaging/` for `MessagingError`, `QueueAdmission`
+//! - `messaging/codec` for `PayloadCompressor`
+//! - `messaging/schema` for `SchemaRegistry`
+//! - `messaging/content_type` for `ContentTypeGuard`
+//! - `messaging/affinity` for `AffinityRouter`
 //!
 //! This module does NOT import from `component/` (Layer 3A), `runtime/`,
 //! `security/`, or `system/`.
@@ -24,7 +33,7 @@
 // Layer 1: Standard library imports
 use std::collections::HashMap;
 use std::fmt;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 
 // Layer 2: Third-party crate imports
 // (none)
@@ -33,6 +42,11 @@ use std::sync::RwLock;
 use crate::core::component::id::ComponentId;
 use crate::core::component::message::ComponentMessage;
 use crate::core::messaging::errors::MessagingError;
+use crate::core::messaging::traits::QueueAdmission;
+use crate::messaging::affinity::AffinityRouter;
+use crate::messaging::codec::PayloadCompressor;
+use crate::messaging::content_type::ContentTypeGuard;
+use crate::messaging::schema::SchemaRegistry;
 
 /// Type alias for the delivery function used to send messages to a component.
 ///
@@ -78,6 +92,16 @@ type DeliveryFn = Box<dyn Fn(ComponentMessage) -> Result<(), MessagingError> + S
 pub struct ComponentSubscriber {
     /// Maps component IDs to their delivery functions
     mailboxes: RwLock<HashMap<ComponentId, DeliveryFn>>,
+    /// Decompresses payloads before delivery, when attached
+    compressor: Option<Arc<PayloadCompressor>>,
+    /// Validates payloads before delivery, when attached
+    schemas: Option<Arc<SchemaRegistry>>,
+    /// Rejects content types the target cannot parse, when attached
+    content_types: Option<Arc<ContentTypeGuard>>,
+    /// Redirects keyed messages to their pool instance, when attached
+    affinity: Option<Arc<AffinityRouter>>,
+    /// Sheds messages that would miss their deadline, when attached
+    admission: Option<Arc<dyn QueueAdmission>>,
 }
 
 impl ComponentSubscriber {
@@ -85,9 +109,74 @@ impl ComponentSubscriber {
     pub fn new() -> Self {
         Self {
             mailboxes: RwLock::new(HashMap::new()),
+            compressor: None,
+            schemas: None,
+            content_types: None,
+            affinity: None,
+            admission: None,
         }
     }
 
+    /// Attaches a compressor used to restore compressed payloads on
+    /// delivery.
+    pub fn with_compressor(mut self, compressor: Arc<PayloadCompressor>) -> Self {
+        self.compressor = Some(compressor);
+        self
+    }
+
+    /// Returns the attached compressor, if any.
+    pub fn compressor(&self) -> Option<&Arc<PayloadCompressor>> {
+        self.compressor.as_ref()
+    }
+
+    /// Attaches a schema registry checked after decompression, so
+    /// malformed payloads never reach the target's mailbox.
+    pub fn with_schema_registry(mut self, schemas: Arc<SchemaRegistry>) -> Self {
+        self.schemas = Some(schemas);
+        self
+    }
+
+    /// Returns the attached schema registry, if any.
+    pub fn schema_registry(&self) -> Option<&Arc<SchemaRegistry>> {
+        self.schemas.as_ref()
+    }
+
+    /// Attaches a content type guard checked after decompression, so
+    /// payloads the target cannot parse never reach its mailbox.
+    pub fn with_content_type_guard(mut self, guard: Arc<ContentTypeGuard>) -> Self {
+        self.content_types = Some(guard);
+        self
+    }
+
+    /// Returns the attached content type guard, if any.
+    pub fn content_type_guard(&self) -> Option<&Arc<ContentTypeGuard>> {
+        self.content_types.as_ref()
+    }
+
+    /// Attaches an affinity router that delivers messages carrying a
+    /// partition key to the pool instance the key is assigned to.
+    pub fn with_affinity_router(mut self, affinity: Arc<AffinityRouter>) -> Self {
+        self.affinity = Some(affinity);
+        self
+    }
+
+    /// Returns the attached affinity router, if any.
+    pub fn affinity_router(&self) -> Option<&Arc<AffinityRouter>> {
+        self.affinity.as_ref()
+    }
+
+    /// Attaches a queue admission check run last, so only messages that
+    /// can still meet their deadline join the target's queue.
+    pub fn with_queue_admission(mut self, admission: Arc<dyn QueueAdmission>) -> Self {
+        self.admission = Some(admission);
+        self
+    }
+
+    /// Returns the attached queue admission check, if any.
+    pub fn queue_admission(&self) -> Option<&Arc<dyn QueueAdmission>> {
+        self.admission.as_ref()
+    }
+
     /// Registers a delivery function for a component.
     ///
     /// If the component ID already has a registered delivery function,
@@ -167,7 +256,13 @@ impl ComponentSubscriber {
 
     /// Delivers a message to a target component.
     ///
-    /// Looks up the target's delivery function and invokes it with the message.
+    /// Looks up the target's delivery function and invokes it with the
+    /// message, decompressing its payload first if a compressor is attached
+    /// and checking its content type and schema if a guard or schema
+    /// registry is attached. With an affinity router attached, a message
+    /// carrying a partition key goes to the pool instance of `target` the
+    /// key is assigned to. With a queue admission check attached, a message
+    /// that cannot meet its deadline is shed instead of delivered.
     ///
     /// # Arguments
     ///
@@ -177,13 +272,37 @@ impl ComponentSubscriber {
     /// # Errors
     ///
     /// - `MessagingError::TargetNotFound` if the target has no registered mailbox
+    /// - `MessagingError::InvalidMessage` if a compressed payload is corrupt
+    /// - `MessagingError::ContentTypeRejected` if the target does not accept
+    ///   the payload's content type
+    /// - `MessagingError::SchemaViolation` if the payload does not match the
+    ///   target's schema
+    /// - `MessagingError::Shed` if the message would miss its deadline
     /// - `MessagingError::DeliveryFailed` if the delivery function returns an error
     /// - `MessagingError::DeliveryFailed` if the lock is poisoned
     pub fn deliver(
         &self,
         target: &ComponentId,
-        message: ComponentMessage,
+        mut message: ComponentMessage,
     ) -> Result<(), MessagingError> {
+        let resolved;
+        let target = match &self.affinity {
+            Some(affinity) => {
+                resolved = affinity.resolve_message(target, &message)?;
+                &resolved
+            }
+            None => target,
+        };
+        if let Some(compressor) = &self.compressor {
+            compressor.decompress_message(&mut message)?;
+        }
+        if let Some(guard) = &self.content_types {
+            guard.check_message(target, &message)?;
+        }
+        if let Some(schemas) = &self.schemas {
+            schemas.validate_message(target, &message)?;
+        }
+
         let mailboxes = self
             .mailboxes
             .read()
@@ -193,7 +312,15 @@ impl ComponentSubscriber {
             .get(target)
             .ok_or_else(|| MessagingError::TargetNotFound(target.to_string_id()))?;
 
-        delivery_fn(message)
+        let Some(admission) = &self.admission else {
+            return delivery_fn(message);
+        };
+        admission.admit(target, &message)?;
+        let delivered = delivery_fn(message);
+        if delivered.is_err() {
+            admission.withdraw(target);
+        }
+        delivered
     }
 }
 
@@ -435,6 +562,201 @@ mod tests {
         assert!(was_called.load(Ordering::SeqCst));
     }
 
+    #[test]
+    fn test_deliver_decompresses_payload() {
+        use std::sync::Mutex;
+
+        let received = Arc::new(Mutex::new(None));
+        let sink = Arc::clone(&received);
+        let delivery: DeliveryFn = Box::new(move |msg| {
+            *sink.lock().unwrap() = Some(msg);
+            Ok(())
+        });
+
+        let compressor = Arc::new(PayloadCompressor::new().with_threshold(8));
+        let subscriber = ComponentSubscriber::new().with_compressor(Arc::clone(&compressor));
+        let id = ComponentId::new("app", "target", "v1");
+        subscriber.register_mailbox(id.clone(), delivery).unwrap();
+
+        let original = MessagePayload::new(vec![7; 1024]);
+        let (payload, content_type) = compressor.compress(original.clone(), None).unwrap();
+        let mut msg = make_test_message("sender");
+        msg.payload = payload;
+        msg.metadata.content_type = content_type;
+        subscriber.deliver(&id, msg).unwrap();
+
+        let delivered = received.lock().unwrap().take().unwrap();
+        assert_eq!(delivered.payload, original);
+        assert_eq!(compressor.stats().unwrap().decompressed, 1);
+    }
+
+    #[test]
+    fn test_deliver_rejects_payload_violating_schema() {
+        use crate::messaging::schema::{PayloadSchema, WitType, ANY_MESSAGE_TYPE};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let delivered = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&delivered);
+        let delivery: DeliveryFn = Box::new(move |_msg| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        });
+
+        let schemas = Arc::new(SchemaRegistry::new());
+        let subscriber = ComponentSubscriber::new().with_schema_registry(Arc::clone(&schemas));
+        let id = ComponentId::new("app", "target", "v1");
+        subscriber.register_mailbox(id.clone(), delivery).unwrap();
+        schemas
+            .register(
+                id.clone(),
+                ANY_MESSAGE_TYPE,
+                PayloadSchema::Wit(WitType::U8),
+            )
+            .unwrap();
+
+        let mut msg = make_test_message("sender");
+        msg.payload = MessagePayload::new(b"300".to_vec());
+        let result = subscriber.deliver(&id, msg);
+        assert!(matches!(result, Err(MessagingError::SchemaViolation(_))));
+
+        let mut msg = make_test_message("sender");
+        msg.payload = MessagePayload::new(b"30".to_vec());
+        subscriber.deliver(&id, msg).unwrap();
+        assert_eq!(delivered.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_deliver_rejects_undeclared_content_type() {
+        use crate::core::config::content_type::AcceptedContentTypes;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let delivered = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&delivered);
+        let delivery: DeliveryFn = Box::new(move |_msg| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        });
+
+        let guard = Arc::new(ContentTypeGuard::new());
+        let compressor = Arc::new(PayloadCompressor::new().with_threshold(8));
+        let subscriber = ComponentSubscriber::new()
+            .with_compressor(Arc::clone(&compressor))
+            .with_content_type_guard(Arc::clone(&guard));
+        let id = ComponentId::new("app", "target", "v1");
+        subscriber.register_mailbox(id.clone(), delivery).unwrap();
+        guard
+            .register(id.clone(), AcceptedContentTypes::new(["json"]).unwrap())
+            .unwrap();
+
+        let mut msg = make_test_message("sender");
+        msg.metadata.content_type = Some("text/plain".to_string());
+        let result = subscriber.deliver(&id, msg);
+        assert!(matches!(
+            result,
+            Err(MessagingError::ContentTypeRejected(_))
+        ));
+
+        // Checked against the content type restored by decompression
+        let (payload, content_type) = compressor
+            .compress(
+                MessagePayload::new(vec![b' '; 1024]),
+                Some("application/json".to_string()),
+            )
+            .unwrap();
+        let mut msg = make_test_message("sender");
+        msg.payload = payload;
+        msg.metadata.content_type = content_type;
+        subscriber.deliver(&id, msg).unwrap();
+
+        assert_eq!(delivered.load(Ordering::SeqCst), 1);
+        let stats = guard.stats().unwrap();
+        assert_eq!((stats.accepted, stats.rejected), (1, 1));
+    }
+
+    #[test]
+    fn test_deliver_routes_keyed_messages_to_pool_instance() {
+        use std::sync::Mutex;
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let affinity = Arc::new(AffinityRouter::new());
+        let subscriber = ComponentSubscriber::new().with_affinity_router(Arc::clone(&affinity));
+        for instance in ["0", "1", "2"] {
+            let id = ComponentId::new("shop", "cart", instance);
+            let sink = Arc::clone(&received);
+            let name = id.clone();
+            let delivery: DeliveryFn = Box::new(move |_msg| {
+                sink.lock().unwrap().push(name.clone());
+                Ok(())
+            });
+            subscriber.register_mailbox(id.clone(), delivery).unwrap();
+            affinity.add_instance(id).unwrap();
+        }
+
+        let addressed = ComponentId::new("shop", "cart", "0");
+        for _ in 0..3 {
+            let mut msg = make_test_message("sender");
+            msg.metadata.partition_key = Some("customer-7".to_string());
+            subscriber.deliver(&addressed, msg).unwrap();
+        }
+        subscriber
+            .deliver(&addressed, make_test_message("sender"))
+            .unwrap();
+
+        let received = received.lock().unwrap();
+        let expected = affinity.resolve(&addressed, Some("customer-7")).unwrap();
+        assert!(received[..3].iter().all(|id| id == &expected));
+        assert_eq!(received[3], addressed);
+    }
+
+    #[test]
+    fn test_deliver_sheds_refused_messages() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        /// Sheds every message carrying a deadline.
+        #[derive(Default)]
+        struct ShedDeadlines {
+            withdrawn: AtomicUsize,
+        }
+
+        impl QueueAdmission for ShedDeadlines {
+            fn admit(
+                &self,
+                target: &ComponentId,
+                message: &ComponentMessage,
+            ) -> Result<(), MessagingError> {
+                match message.metadata.deadline_ms {
+                    Some(_) => Err(MessagingError::Shed(target.to_string_id())),
+                    None => Ok(()),
+                }
+            }
+
+            fn withdraw(&self, _target: &ComponentId) {
+                self.withdrawn.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let admission = Arc::new(ShedDeadlines::default());
+        let subscriber = ComponentSubscriber::new().with_queue_admission(admission.clone());
+        let id = ComponentId::new("app", "target", "v1");
+        subscriber
+            .register_mailbox(id.clone(), Box::new(|_msg| Err(MessagingError::QueueFull)))
+            .unwrap();
+
+        let mut msg = make_test_message("sender");
+        msg.metadata.deadline_ms = Some(1);
+        assert!(matches!(
+            subscriber.deliver(&id, msg),
+            Err(MessagingError::Shed(_))
+        ));
+        assert_eq!(admission.withdrawn.load(Ordering::SeqCst), 0);
+
+        // Admitted but not enqueued: the admission is withdrawn
+        let result = subscriber.deliver(&id, make_test_message("sender"));
+        assert_eq!(result, Err(MessagingError::QueueFull));
+        assert_eq!(admission.withdrawn.load(Ordering::SeqCst), 1);
+        assert!(subscriber.queue_admission().is_some());
+    }
+
     // ---------------------------------------------------------------
     // Debug and trait tests
     // ---------------------------------------------------------------
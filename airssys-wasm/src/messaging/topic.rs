@@ -0,0 +1,1048 @@
+//! Topic broadcast with per-subscriber quality of service.
+//!
+//! Provides [`TopicBus`], which fans each message published to a topic out
+//! to every subscribed component. Each subscription owns a bounded
+//! [`QosBuffer`] with its own size and
+//! [`OverflowPolicy`](airssys_rt::broker::OverflowPolicy), so a component
+//! that falls behind only loses its own messages (or its own subscription)
+//! and never grows host memory without limit or delays other subscribers.
+//!
+//! Publishing only buffers; the host drains the buffers by calling
+//! [`TopicBus::flush`] with its [`MessageSender`]. A subscriber whose
+//! delivery fails keeps its message at the head of its buffer and is
+//! skipped until the next flush, while the remaining subscribers are
+//! still served.
+//!
+//! Subscriptions take a [`TopicFilter`], which may use MQTT-style wildcard
+//! levels: `+` matches exactly one level (`metrics/+/cpu`) and a trailing
+//! `#` matches any number of levels, including none (`events/#` matches
+//! `events` and `events/a/b`). Filters are indexed in a trie keyed by
+//! level, so publishing costs one lookup per level of the topic rather than
+//! one per subscription. A component whose filters overlap receives each
+//! message once.
+//!
+//! The bus counts, per published topic, the messages published, delivered
+//! and lost to overflow policies, and how far each subscriber lags behind:
+//! the number of the topic's messages buffered but not yet delivered to it.
+//! [`TopicBus::stats`] reports the slowest subscriber of every topic, so an
+//! operator can see which component is backpressuring it;
+//! [`TopicStats::samples`] turns the counters into host metrics.
+//!
+//! # Architecture
+//!
+//! This module is part of `messaging/` (Layer 3B). Buffering comes from
+//! `airssys_rt::broker::qos`; delivery goes through the [`MessageSender`]
+//! implementation supplied by `system/` (Layer 4).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use airssys_rt::broker::{OverflowPolicy, SubscriptionQos};
+//! use airssys_wasm::core::component::id::ComponentId;
+//! use airssys_wasm::core::component::message::MessagePayload;
+//! use airssys_wasm::messaging::topic::TopicBus;
+//!
+//! let bus = TopicBus::new();
+//! let dashboard = ComponentId::new("acme", "dashboard", "v1");
+//! bus.subscribe(
+//!     "metrics",
+//!     dashboard.clone(),
+//!     SubscriptionQos::new(2).with_overflow(OverflowPolicy::DropOldest),
+//! )
+//! .unwrap();
+//!
+//! for value in 0u8..3 {
+//!     bus.publish("metrics", MessagePayload::new(vec![value])).unwrap();
+//! }
+//! assert_eq!(bus.pending("metrics", &dashboard).unwrap(), 2);
+//! assert_eq!(bus.dropped("metrics", &dashboard).unwrap(), 1);
+//! ```
+
+// Layer 1: Standard library imports
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+// Layer 2: Third-party crate imports
+use airssys_rt::broker::{PushOutcome, QosBuffer, SubscriptionQos};
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::MessagePayload;
+use crate::core::management::metrics::{MetricSample, MetricValue};
+use crate::core::messaging::errors::MessagingError;
+use crate::core::messaging::traits::MessageSender;
+
+/// Separator between topic levels.
+pub const TOPIC_SEPARATOR: char = '/';
+
+/// Filter level matching exactly one topic level.
+pub const SINGLE_LEVEL_WILDCARD: &str = "+";
+
+/// Final filter level matching any number of remaining topic levels.
+pub const MULTI_LEVEL_WILDCARD: &str = "#";
+
+/// A validated subscription filter, optionally containing wildcard levels.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::messaging::topic::TopicFilter;
+///
+/// let cpu = TopicFilter::new("metrics/+/cpu").unwrap();
+/// assert!(cpu.matches("metrics/host-1/cpu"));
+/// assert!(!cpu.matches("metrics/host-1/mem"));
+///
+/// let events = TopicFilter::new("events/#").unwrap();
+/// assert!(events.matches("events"));
+/// assert!(events.matches("events/order/created"));
+///
+/// assert!(TopicFilter::new("events/#/created").is_err());
+/// assert!(TopicFilter::new("metrics/cpu+").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TopicFilter {
+    filter: String,
+}
+
+impl TopicFilter {
+    /// Parses a filter.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::InvalidMessage` if the filter is empty, a
+    /// wildcard shares a level with other characters, or `#` is not the
+    /// last level.
+    pub fn new(filter: &str) -> Result<Self, MessagingError> {
+        let invalid = |reason: &str| {
+            MessagingError::InvalidMessage(format!("invalid topic filter '{}': {}", filter, reason))
+        };
+        if filter.is_empty() {
+            return Err(invalid("empty filter"));
+        }
+        let mut levels = filter.split(TOPIC_SEPARATOR).peekable();
+        while let Some(level) = levels.next() {
+            match level {
+                SINGLE_LEVEL_WILDCARD => {}
+                MULTI_LEVEL_WILDCARD if levels.peek().is_some() => {
+                    return Err(invalid("'#' must be the last level"));
+                }
+                MULTI_LEVEL_WILDCARD => {}
+                _ if level.contains(['+', '#']) => {
+                    return Err(invalid("wildcards must occupy a whole level"));
+                }
+                _ => {}
+            }
+        }
+        Ok(Self {
+            filter: filter.to_string(),
+        })
+    }
+
+    /// The filter as given.
+    pub fn as_str(&self) -> &str {
+        &self.filter
+    }
+
+    /// Whether the filter contains a wildcard level.
+    pub fn is_wildcard(&self) -> bool {
+        self.filter
+            .split(TOPIC_SEPARATOR)
+            .any(|level| level == SINGLE_LEVEL_WILDCARD || level == MULTI_LEVEL_WILDCARD)
+    }
+
+    /// Whether a message published to `topic` matches this filter.
+    pub fn matches(&self, topic: &str) -> bool {
+        let mut topic_levels = topic.split(TOPIC_SEPARATOR);
+        for level in self.filter.split(TOPIC_SEPARATOR) {
+            if level == MULTI_LEVEL_WILDCARD {
+                return true;
+            }
+            match topic_levels.next() {
+                Some(t) if level == SINGLE_LEVEL_WILDCARD || level == t => {}
+                _ => return false,
+            }
+        }
+        topic_levels.next().is_none()
+    }
+
+    /// Levels up to, but excluding, a trailing `#`.
+    fn prefix_levels(&self) -> impl Iterator<Item = &str> {
+        let mut levels: Vec<&str> = self.filter.split(TOPIC_SEPARATOR).collect();
+        if self.is_multi_level() {
+            levels.pop();
+        }
+        levels.into_iter()
+    }
+
+    fn is_multi_level(&self) -> bool {
+        self.filter.split(TOPIC_SEPARATOR).next_back() == Some(MULTI_LEVEL_WILDCARD)
+    }
+}
+
+impl fmt::Display for TopicFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.filter)
+    }
+}
+
+/// Rejects topics that cannot be published to.
+fn check_topic(topic: &str) -> Result<(), MessagingError> {
+    if topic.is_empty() || topic.contains(['+', '#']) {
+        return Err(MessagingError::InvalidMessage(format!(
+            "invalid topic '{}': topics must be non-empty and contain no wildcards",
+            topic
+        )));
+    }
+    Ok(())
+}
+
+/// A buffered message and the topic it was published to.
+#[derive(Debug, Clone)]
+struct TopicMessage {
+    topic: Arc<str>,
+    payload: MessagePayload,
+}
+
+/// One component's subscription to a topic.
+#[derive(Debug)]
+struct TopicSubscription {
+    subscriber: ComponentId,
+    buffer: Arc<QosBuffer<TopicMessage>>,
+}
+
+/// Result of publishing one message.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PublishReport {
+    /// Subscribers that buffered the message.
+    pub queued: usize,
+    /// Subscribers that lost a message to `DropOldest` or `DropNewest`.
+    pub dropped: usize,
+    /// Subscribers removed by the `Disconnect` policy.
+    pub disconnected: Vec<ComponentId>,
+}
+
+/// Result of one [`TopicBus::flush`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FlushReport {
+    /// Messages delivered across all subscribers.
+    pub delivered: usize,
+    /// Subscribers whose delivery failed; their messages stay buffered.
+    pub blocked: Vec<ComponentId>,
+}
+
+/// Delivery counters for one published topic.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TopicStats {
+    /// Messages published to the topic.
+    pub published: u64,
+    /// Messages delivered to subscribers.
+    pub delivered: u64,
+    /// Messages subscribers lost to their overflow policy.
+    pub dropped: u64,
+    /// Subscriber with the most undelivered messages of the topic, if any
+    /// is behind.
+    pub slowest_subscriber: Option<ComponentId>,
+    /// Undelivered messages of the slowest subscriber.
+    pub lag: u64,
+}
+
+impl TopicStats {
+    /// The counters as host metric samples labelled with `topic`.
+    ///
+    /// The lag gauge is also labelled with the slowest subscriber.
+    pub fn samples(&self, topic: &str) -> Vec<MetricSample> {
+        let topic_label = ("topic".to_string(), topic.to_string());
+        let sample = |name: &str, value| MetricSample {
+            component: None,
+            name: name.to_string(),
+            labels: vec![topic_label.clone()],
+            value,
+        };
+        let mut lag = sample("messaging_topic_lag", MetricValue::Gauge(self.lag as f64));
+        if let Some(subscriber) = &self.slowest_subscriber {
+            lag.labels
+                .insert(0, ("subscriber".to_string(), subscriber.to_string_id()));
+        }
+        vec![
+            sample(
+                "messaging_topic_published_total",
+                MetricValue::Counter(self.published),
+            ),
+            sample(
+                "messaging_topic_delivered_total",
+                MetricValue::Counter(self.delivered),
+            ),
+            sample(
+                "messaging_topic_dropped_total",
+                MetricValue::Counter(self.dropped),
+            ),
+            lag,
+        ]
+    }
+}
+
+/// Running counters for one topic.
+#[derive(Debug, Default)]
+struct TopicCounters {
+    published: u64,
+    delivered: u64,
+    dropped: u64,
+    /// Undelivered messages per subscriber; entries at zero are removed
+    pending: HashMap<ComponentId, u64>,
+}
+
+impl TopicCounters {
+    fn queued(&mut self, subscriber: &ComponentId) {
+        *self.pending.entry(subscriber.clone()).or_insert(0) += 1;
+    }
+
+    fn dequeued(&mut self, subscriber: &ComponentId) {
+        if let Some(pending) = self.pending.get_mut(subscriber) {
+            *pending = pending.saturating_sub(1);
+            if *pending == 0 {
+                self.pending.remove(subscriber);
+            }
+        }
+    }
+
+    fn snapshot(&self) -> TopicStats {
+        let slowest = self.pending.iter().max_by(|(a_id, a), (b_id, b)| {
+            a.cmp(b)
+                .then_with(|| b_id.to_string_id().cmp(&a_id.to_string_id()))
+        });
+        TopicStats {
+            published: self.published,
+            delivered: self.delivered,
+            dropped: self.dropped,
+            slowest_subscriber: slowest.map(|(id, _)| id.clone()),
+            lag: slowest.map(|(_, lag)| *lag).unwrap_or(0),
+        }
+    }
+}
+
+type TopicCounterMap = HashMap<Arc<str>, TopicCounters>;
+
+/// Subscriptions indexed by filter level.
+///
+/// `+` is stored as an ordinary child key; published topics cannot contain
+/// it, so a lookup follows both the literal level and `+` at each node.
+#[derive(Debug, Default)]
+struct TopicTrie {
+    children: HashMap<String, TopicTrie>,
+    /// Subscriptions whose filter ends at this node
+    exact: Vec<TopicSubscription>,
+    /// Subscriptions whose filter ends at this node with `#`
+    descendants: Vec<TopicSubscription>,
+}
+
+impl TopicTrie {
+    /// Subscriptions of `filter`, creating the path if needed.
+    fn slot_mut(&mut self, filter: &TopicFilter) -> &mut Vec<TopicSubscription> {
+        let mut node = self;
+        for level in filter.prefix_levels() {
+            node = node.children.entry(level.to_string()).or_default();
+        }
+        node.own_slot_mut(filter)
+    }
+
+    /// Subscriptions of `filter`, if its path exists.
+    fn slot(&self, filter: &TopicFilter) -> Option<&Vec<TopicSubscription>> {
+        let mut node = self;
+        for level in filter.prefix_levels() {
+            node = node.children.get(level)?;
+        }
+        Some(match filter.is_multi_level() {
+            true => &node.descendants,
+            false => &node.exact,
+        })
+    }
+
+    fn existing_slot_mut(&mut self, filter: &TopicFilter) -> Option<&mut Vec<TopicSubscription>> {
+        let mut node = self;
+        for level in filter.prefix_levels() {
+            node = node.children.get_mut(level)?;
+        }
+        Some(node.own_slot_mut(filter))
+    }
+
+    fn own_slot_mut(&mut self, filter: &TopicFilter) -> &mut Vec<TopicSubscription> {
+        match filter.is_multi_level() {
+            true => &mut self.descendants,
+            false => &mut self.exact,
+        }
+    }
+
+    /// Appends the subscriptions matching the remaining topic `levels`.
+    fn collect<'a>(&'a self, levels: &[&str], out: &mut Vec<&'a TopicSubscription>) {
+        out.extend(&self.descendants);
+        let Some((level, rest)) = levels.split_first() else {
+            out.extend(&self.exact);
+            return;
+        };
+        if let Some(child) = self.children.get(*level) {
+            child.collect(rest, out);
+        }
+        if let Some(child) = self.children.get(SINGLE_LEVEL_WILDCARD) {
+            child.collect(rest, out);
+        }
+    }
+
+    /// Appends every subscription in the trie.
+    fn collect_all<'a>(&'a self, out: &mut Vec<&'a TopicSubscription>) {
+        out.extend(&self.exact);
+        out.extend(&self.descendants);
+        for child in self.children.values() {
+            child.collect_all(out);
+        }
+    }
+
+    /// Drops closed subscriptions and empty branches, forgetting their
+    /// buffered messages; returns whether this node is now empty.
+    fn prune(&mut self, counters: &mut TopicCounterMap) -> bool {
+        for subscriptions in [&mut self.exact, &mut self.descendants] {
+            subscriptions.retain(|s| {
+                let closed = s.buffer.is_closed();
+                if closed {
+                    discard(s, counters, true);
+                }
+                !closed
+            });
+        }
+        self.children.retain(|_, child| !child.prune(counters));
+        self.exact.is_empty() && self.descendants.is_empty() && self.children.is_empty()
+    }
+}
+
+/// Empties `subscription`'s buffer, removing its messages from the lag
+/// counters and, if they were `lost` to an overflow policy, counting them
+/// as dropped.
+fn discard(subscription: &TopicSubscription, counters: &mut TopicCounterMap, lost: bool) {
+    while let Some(message) = subscription.buffer.try_pop() {
+        if let Some(topic) = counters.get_mut(&message.topic) {
+            topic.dequeued(&subscription.subscriber);
+            if lost {
+                topic.dropped += 1;
+            }
+        }
+    }
+}
+
+/// Topic broadcast with a bounded buffer per subscriber.
+#[derive(Debug, Default)]
+pub struct TopicBus {
+    topics: Mutex<TopicTrie>,
+    /// Per-topic counters; locked after `topics` when both are needed
+    counters: Mutex<TopicCounterMap>,
+}
+
+impl TopicBus {
+    /// Creates a bus with no subscriptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribes `subscriber` to the topics matching `filter` with the
+    /// given buffer settings.
+    ///
+    /// An existing subscription of the same component to the same filter
+    /// is replaced; its undelivered messages are discarded.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` if `filter` is not a valid
+    ///   [`TopicFilter`]
+    /// - `MessagingError::DeliveryFailed` if the internal lock is poisoned
+    pub fn subscribe(
+        &self,
+        filter: &str,
+        subscriber: ComponentId,
+        qos: SubscriptionQos,
+    ) -> Result<(), MessagingError> {
+        let filter = TopicFilter::new(filter)?;
+        let mut topics = self.lock()?;
+        let mut counters = self.lock_counters()?;
+        let subscriptions = topics.slot_mut(&filter);
+        subscriptions.retain(|s| {
+            let replaced = s.subscriber == subscriber;
+            if replaced {
+                discard(s, &mut counters, false);
+            }
+            !replaced
+        });
+        subscriptions.push(TopicSubscription {
+            subscriber,
+            buffer: Arc::new(QosBuffer::new(qos)),
+        });
+        Ok(())
+    }
+
+    /// Removes the subscription of `subscriber` to `filter`.
+    ///
+    /// Returns whether a subscription existed.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` if `filter` is not a valid
+    ///   [`TopicFilter`]
+    /// - `MessagingError::DeliveryFailed` if the internal lock is poisoned
+    pub fn unsubscribe(
+        &self,
+        filter: &str,
+        subscriber: &ComponentId,
+    ) -> Result<bool, MessagingError> {
+        let filter = TopicFilter::new(filter)?;
+        let mut topics = self.lock()?;
+        let mut counters = self.lock_counters()?;
+        let Some(subscriptions) = topics.existing_slot_mut(&filter) else {
+            return Ok(false);
+        };
+        let before = subscriptions.len();
+        subscriptions.retain(|s| {
+            let removed = &s.subscriber == subscriber;
+            if removed {
+                discard(s, &mut counters, false);
+            }
+            !removed
+        });
+        let removed = subscriptions.len() != before;
+        topics.prune(&mut counters);
+        Ok(removed)
+    }
+
+    /// Components currently subscribed with exactly `filter`.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` if `filter` is not a valid
+    ///   [`TopicFilter`]
+    /// - `MessagingError::DeliveryFailed` if the internal lock is poisoned
+    pub fn subscribers(&self, filter: &str) -> Result<Vec<ComponentId>, MessagingError> {
+        let filter = TopicFilter::new(filter)?;
+        Ok(self
+            .lock()?
+            .slot(&filter)
+            .map(|subs| subs.iter().map(|s| s.subscriber.clone()).collect())
+            .unwrap_or_default())
+    }
+
+    /// Components that would receive a message published to `topic`.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` if `topic` is empty or contains
+    ///   a wildcard
+    /// - `MessagingError::DeliveryFailed` if the internal lock is poisoned
+    pub fn matching_subscribers(&self, topic: &str) -> Result<Vec<ComponentId>, MessagingError> {
+        check_topic(topic)?;
+        let topics = self.lock()?;
+        Ok(matching(&topics, topic)
+            .into_iter()
+            .map(|s| s.subscriber.clone())
+            .collect())
+    }
+
+    /// Buffers `payload` for every subscriber whose filter matches `topic`.
+    ///
+    /// Each subscriber applies its own overflow policy; subscribers removed
+    /// by `OverflowPolicy::Disconnect` are listed in the report and keep
+    /// no buffered messages.
+    ///
+    /// # Errors
+    ///
+    /// - `MessagingError::InvalidMessage` if `topic` is empty or contains
+    ///   a wildcard
+    /// - `MessagingError::DeliveryFailed` if the internal lock is poisoned
+    pub fn publish(
+        &self,
+        topic: &str,
+        payload: MessagePayload,
+    ) -> Result<PublishReport, MessagingError> {
+        check_topic(topic)?;
+        let mut topics = self.lock()?;
+        let mut counters = self.lock_counters()?;
+        let mut report = PublishReport::default();
+        let message = TopicMessage {
+            topic: Arc::from(topic),
+            payload,
+        };
+        counters
+            .entry(Arc::clone(&message.topic))
+            .or_default()
+            .published += 1;
+
+        for subscription in matching(&topics, topic) {
+            let subscriber = &subscription.subscriber;
+            let (outcome, discarded) = subscription.buffer.push_evicting(message.clone());
+            if matches!(outcome, PushOutcome::Queued | PushOutcome::DroppedOldest) {
+                report.queued += 1;
+                counters
+                    .entry(Arc::clone(&message.topic))
+                    .or_default()
+                    .queued(subscriber);
+            }
+            match outcome {
+                PushOutcome::Queued => {}
+                PushOutcome::DroppedOldest | PushOutcome::DroppedNewest => report.dropped += 1,
+                PushOutcome::Disconnected | PushOutcome::Closed => {
+                    report.disconnected.push(subscriber.clone());
+                }
+            }
+            if let Some(discarded) = discarded {
+                let lost = counters.entry(discarded.topic).or_default();
+                lost.dropped += 1;
+                if outcome == PushOutcome::DroppedOldest {
+                    lost.dequeued(subscriber);
+                }
+            }
+        }
+
+        if !report.disconnected.is_empty() {
+            topics.prune(&mut counters);
+        }
+        Ok(report)
+    }
+
+    /// Delivers buffered messages to every subscriber of every topic.
+    ///
+    /// Messages are delivered in publish order per subscriber. When a
+    /// delivery fails, that message goes back to the head of the
+    /// subscriber's buffer and the subscriber is skipped for the rest of
+    /// this flush; other subscribers are unaffected.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the internal lock is
+    /// poisoned. Delivery failures are reported in
+    /// [`FlushReport::blocked`], not as errors.
+    pub async fn flush<S: MessageSender>(&self, sender: &S) -> Result<FlushReport, MessagingError> {
+        // Snapshot the buffers so no lock is held across deliveries
+        let buffers: Vec<(ComponentId, Arc<QosBuffer<TopicMessage>>)> = {
+            let topics = self.lock()?;
+            let mut subscriptions = Vec::new();
+            topics.collect_all(&mut subscriptions);
+            subscriptions
+                .into_iter()
+                .map(|s| (s.subscriber.clone(), Arc::clone(&s.buffer)))
+                .collect()
+        };
+
+        let mut report = FlushReport::default();
+        for (subscriber, buffer) in buffers {
+            while let Some(message) = buffer.try_pop() {
+                if sender
+                    .send(&subscriber, message.payload.clone())
+                    .await
+                    .is_err()
+                {
+                    buffer.requeue(message);
+                    if !report.blocked.contains(&subscriber) {
+                        report.blocked.push(subscriber.clone());
+                    }
+                    break;
+                }
+                report.delivered += 1;
+                let mut counters = self.lock_counters()?;
+                let topic = counters.entry(message.topic).or_default();
+                topic.delivered += 1;
+                topic.dequeued(&subscriber);
+            }
+        }
+        Ok(report)
+    }
+
+    /// Number of messages buffered for `subscriber`'s subscription to
+    /// `filter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::TargetNotFound` if there is no such
+    /// subscription, `MessagingError::InvalidMessage` if `filter` is not a
+    /// valid [`TopicFilter`], or `MessagingError::DeliveryFailed` if the
+    /// internal lock is poisoned.
+    pub fn pending(&self, filter: &str, subscriber: &ComponentId) -> Result<usize, MessagingError> {
+        self.with_buffer(filter, subscriber, |buffer| buffer.len())
+    }
+
+    /// Number of messages `subscriber`'s subscription to `filter` has lost
+    /// to its overflow policy.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`pending`](Self::pending).
+    pub fn dropped(&self, filter: &str, subscriber: &ComponentId) -> Result<u64, MessagingError> {
+        self.with_buffer(filter, subscriber, |buffer| buffer.dropped())
+    }
+
+    /// Delivery counters of every topic published to so far, keyed by
+    /// topic.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the internal lock is
+    /// poisoned.
+    pub fn stats(&self) -> Result<BTreeMap<String, TopicStats>, MessagingError> {
+        Ok(self
+            .lock_counters()?
+            .iter()
+            .map(|(topic, counters)| (topic.to_string(), counters.snapshot()))
+            .collect())
+    }
+
+    /// Delivery counters of `topic`, if it was ever published to.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the internal lock is
+    /// poisoned.
+    pub fn topic_stats(&self, topic: &str) -> Result<Option<TopicStats>, MessagingError> {
+        Ok(self
+            .lock_counters()?
+            .get(topic)
+            .map(TopicCounters::snapshot))
+    }
+
+    /// Delivery counters of every topic as host metric samples.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the internal lock is
+    /// poisoned.
+    pub fn samples(&self) -> Result<Vec<MetricSample>, MessagingError> {
+        Ok(self
+            .stats()?
+            .iter()
+            .flat_map(|(topic, stats)| stats.samples(topic))
+            .collect())
+    }
+
+    fn with_buffer<T>(
+        &self,
+        filter: &str,
+        subscriber: &ComponentId,
+        f: impl FnOnce(&QosBuffer<TopicMessage>) -> T,
+    ) -> Result<T, MessagingError> {
+        let filter = TopicFilter::new(filter)?;
+        let topics = self.lock()?;
+        topics
+            .slot(&filter)
+            .and_then(|subs| subs.iter().find(|s| &s.subscriber == subscriber))
+            .map(|s| f(&s.buffer))
+            .ok_or_else(|| {
+                MessagingError::TargetNotFound(format!(
+                    "{} is not subscribed to {}",
+                    subscriber, filter
+                ))
+            })
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, TopicTrie>, MessagingError> {
+        self.topics
+            .lock()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Topic bus lock poisoned: {e}")))
+    }
+
+    fn lock_counters(&self) -> Result<MutexGuard<'_, TopicCounterMap>, MessagingError> {
+        self.counters
+            .lock()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Topic bus lock poisoned: {e}")))
+    }
+}
+
+/// Subscriptions matching `topic`, keeping only the first one per
+/// subscriber.
+fn matching<'a>(topics: &'a TopicTrie, topic: &str) -> Vec<&'a TopicSubscription> {
+    let levels: Vec<&str> = topic.split(TOPIC_SEPARATOR).collect();
+    let mut matched = Vec::new();
+    topics.collect(&levels, &mut matched);
+    let mut seen = HashSet::new();
+    matched.retain(|s| seen.insert(&s.subscriber));
+    matched
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use airssys_rt::broker::OverflowPolicy;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Records delivered payloads; fails for one target when switched on.
+    struct RecordingSender {
+        sent: Mutex<Vec<(ComponentId, MessagePayload)>>,
+        slow: ComponentId,
+        fail_slow: AtomicBool,
+    }
+
+    impl RecordingSender {
+        fn new(slow: ComponentId) -> Self {
+            Self {
+                sent: Mutex::new(Vec::new()),
+                slow,
+                fail_slow: AtomicBool::new(true),
+            }
+        }
+
+        fn received(&self, target: &ComponentId) -> Vec<u8> {
+            self.sent
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(t, _)| t == target)
+                .map(|(_, p)| p.as_bytes()[0])
+                .collect()
+        }
+    }
+
+    impl MessageSender for RecordingSender {
+        async fn send(
+            &self,
+            target: &ComponentId,
+            payload: MessagePayload,
+        ) -> Result<(), MessagingError> {
+            if target == &self.slow && self.fail_slow.load(Ordering::SeqCst) {
+                return Err(MessagingError::QueueFull);
+            }
+            self.sent.lock().unwrap().push((target.clone(), payload));
+            Ok(())
+        }
+
+        async fn send_with_correlation(
+            &self,
+            target: &ComponentId,
+            payload: MessagePayload,
+            _correlation_id: &str,
+        ) -> Result<(), MessagingError> {
+            self.send(target, payload).await
+        }
+    }
+
+    fn fast() -> ComponentId {
+        ComponentId::new("acme", "fast", "v1")
+    }
+
+    fn slow() -> ComponentId {
+        ComponentId::new("acme", "slow", "v1")
+    }
+
+    fn publish_all(bus: &TopicBus, values: std::ops::Range<u8>) {
+        for value in values {
+            bus.publish("events", MessagePayload::new(vec![value]))
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slow_subscriber_does_not_block_others() {
+        let bus = TopicBus::new();
+        bus.subscribe("events", fast(), SubscriptionQos::new(8))
+            .unwrap();
+        bus.subscribe(
+            "events",
+            slow(),
+            SubscriptionQos::new(2).with_overflow(OverflowPolicy::DropOldest),
+        )
+        .unwrap();
+        let sender = RecordingSender::new(slow());
+
+        publish_all(&bus, 0..4);
+        let report = bus.flush(&sender).await.unwrap();
+        assert_eq!(report.delivered, 4);
+        assert_eq!(report.blocked, vec![slow()]);
+        assert_eq!(sender.received(&fast()), vec![0, 1, 2, 3]);
+        assert_eq!(bus.pending("events", &slow()).unwrap(), 2);
+        assert_eq!(bus.dropped("events", &slow()).unwrap(), 2);
+
+        sender.fail_slow.store(false, Ordering::SeqCst);
+        bus.flush(&sender).await.unwrap();
+        assert_eq!(sender.received(&slow()), vec![2, 3]);
+        assert_eq!(bus.pending("events", &slow()).unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_topic_stats_track_delivery_and_slowest_subscriber() {
+        let bus = TopicBus::new();
+        bus.subscribe("events", fast(), SubscriptionQos::new(8))
+            .unwrap();
+        bus.subscribe(
+            "#",
+            slow(),
+            SubscriptionQos::new(2).with_overflow(OverflowPolicy::DropOldest),
+        )
+        .unwrap();
+        let sender = RecordingSender::new(slow());
+
+        bus.publish("audit", MessagePayload::new(vec![9])).unwrap();
+        publish_all(&bus, 0..3);
+        bus.flush(&sender).await.unwrap();
+
+        // The slow subscriber's "audit" message was evicted by "events" ones
+        let audit = bus.topic_stats("audit").unwrap().unwrap();
+        assert_eq!((audit.published, audit.delivered, audit.dropped), (1, 0, 1));
+        assert_eq!(audit.slowest_subscriber, None);
+
+        let events = bus.topic_stats("events").unwrap().unwrap();
+        assert_eq!(
+            (events.published, events.delivered, events.dropped),
+            (3, 3, 1)
+        );
+        assert_eq!(events.slowest_subscriber, Some(slow()));
+        assert_eq!(events.lag, 2);
+
+        let samples = events.samples("events");
+        let lag = samples
+            .iter()
+            .find(|s| s.name == "messaging_topic_lag")
+            .unwrap();
+        assert_eq!(lag.value, MetricValue::Gauge(2.0));
+        assert_eq!(
+            lag.labels[0],
+            ("subscriber".to_string(), slow().to_string_id())
+        );
+        assert_eq!(bus.samples().unwrap().len(), 8);
+
+        sender.fail_slow.store(false, Ordering::SeqCst);
+        bus.flush(&sender).await.unwrap();
+        let events = bus.stats().unwrap().remove("events").unwrap();
+        assert_eq!((events.delivered, events.lag), (5, 0));
+        assert_eq!(events.slowest_subscriber, None);
+    }
+
+    #[test]
+    fn test_topic_lag_forgets_discarded_subscriptions() {
+        let bus = TopicBus::new();
+        bus.subscribe("events", fast(), SubscriptionQos::new(8))
+            .unwrap();
+        bus.subscribe(
+            "events",
+            slow(),
+            SubscriptionQos::new(2).with_overflow(OverflowPolicy::Disconnect),
+        )
+        .unwrap();
+        publish_all(&bus, 0..3);
+
+        // Disconnect loses the rejected message and the two buffered ones
+        let stats = bus.topic_stats("events").unwrap().unwrap();
+        assert_eq!(stats.dropped, 3);
+        assert_eq!((stats.slowest_subscriber, stats.lag), (Some(fast()), 3));
+
+        assert!(bus.unsubscribe("events", &fast()).unwrap());
+        let stats = bus.topic_stats("events").unwrap().unwrap();
+        assert_eq!((stats.slowest_subscriber, stats.lag), (None, 0));
+        assert_eq!(stats.dropped, 3);
+        assert!(bus.topic_stats("other").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_drop_newest_and_disconnect_policies() {
+        let bus = TopicBus::new();
+        bus.subscribe("events", fast(), SubscriptionQos::new(1))
+            .unwrap();
+        bus.subscribe(
+            "events",
+            slow(),
+            SubscriptionQos::new(1).with_overflow(OverflowPolicy::Disconnect),
+        )
+        .unwrap();
+
+        let first = bus.publish("events", MessagePayload::new(vec![0])).unwrap();
+        assert_eq!(first.queued, 2);
+
+        let second = bus.publish("events", MessagePayload::new(vec![1])).unwrap();
+        assert_eq!(second.dropped, 1);
+        assert_eq!(second.disconnected, vec![slow()]);
+        assert_eq!(bus.subscribers("events").unwrap(), vec![fast()]);
+        assert!(matches!(
+            bus.pending("events", &slow()),
+            Err(MessagingError::TargetNotFound(_))
+        ));
+        assert_eq!(bus.dropped("events", &fast()).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_subscribe_replaces_and_unsubscribe_removes() {
+        let bus = TopicBus::new();
+        bus.subscribe("events", fast(), SubscriptionQos::new(4))
+            .unwrap();
+        publish_all(&bus, 0..2);
+        bus.subscribe("events", fast(), SubscriptionQos::new(4))
+            .unwrap();
+        assert_eq!(bus.pending("events", &fast()).unwrap(), 0);
+        assert_eq!(bus.subscribers("events").unwrap().len(), 1);
+
+        assert!(bus.unsubscribe("events", &fast()).unwrap());
+        assert!(!bus.unsubscribe("events", &fast()).unwrap());
+        assert_eq!(
+            bus.publish("events", MessagePayload::new(vec![9])).unwrap(),
+            PublishReport::default()
+        );
+    }
+
+    #[test]
+    fn test_wildcard_subscriptions_match_topic_families() {
+        let bus = TopicBus::new();
+        let all = ComponentId::new("acme", "all", "v1");
+        bus.subscribe("metrics/+/cpu", fast(), SubscriptionQos::new(8))
+            .unwrap();
+        bus.subscribe("metrics/#", slow(), SubscriptionQos::new(8))
+            .unwrap();
+        bus.subscribe("#", all.clone(), SubscriptionQos::new(8))
+            .unwrap();
+
+        let report = bus
+            .publish("metrics/host-1/cpu", MessagePayload::new(vec![0]))
+            .unwrap();
+        assert_eq!(report.queued, 3);
+        bus.publish("metrics/host-1/mem", MessagePayload::new(vec![1]))
+            .unwrap();
+        bus.publish("metrics", MessagePayload::new(vec![2]))
+            .unwrap();
+        bus.publish("events/x", MessagePayload::new(vec![3]))
+            .unwrap();
+
+        assert_eq!(bus.pending("metrics/+/cpu", &fast()).unwrap(), 1);
+        assert_eq!(bus.pending("metrics/#", &slow()).unwrap(), 3);
+        assert_eq!(bus.pending("#", &all).unwrap(), 4);
+        assert_eq!(bus.matching_subscribers("metrics/a/cpu").unwrap().len(), 3);
+        assert_eq!(
+            bus.matching_subscribers("metrics/a/cpu/x").unwrap(),
+            vec![all.clone(), slow()]
+        );
+
+        assert!(bus.unsubscribe("metrics/#", &slow()).unwrap());
+        assert!(!bus.unsubscribe("metrics/+", &fast()).unwrap());
+        assert_eq!(bus.matching_subscribers("metrics").unwrap(), vec![all]);
+    }
+
+    #[test]
+    fn test_overlapping_filters_deliver_once() {
+        let bus = TopicBus::new();
+        bus.subscribe("a/b", fast(), SubscriptionQos::new(8))
+            .unwrap();
+        bus.subscribe("a/+", fast(), SubscriptionQos::new(8))
+            .unwrap();
+
+        let report = bus.publish("a/b", MessagePayload::new(vec![0])).unwrap();
+        assert_eq!(report.queued, 1);
+        assert_eq!(bus.matching_subscribers("a/b").unwrap(), vec![fast()]);
+    }
+
+    #[test]
+    fn test_invalid_filters_and_topics_rejected() {
+        let bus = TopicBus::new();
+        for filter in ["", "a/#/b", "a/b#", "a+/b"] {
+            assert!(
+                matches!(
+                    bus.subscribe(filter, fast(), SubscriptionQos::new(1)),
+                    Err(MessagingError::InvalidMessage(_))
+                ),
+                "{filter}"
+            );
+        }
+        for topic in ["", "a/+", "a/#"] {
+            assert!(matches!(
+                bus.publish(topic, MessagePayload::new(vec![0])),
+                Err(MessagingError::InvalidMessage(_))
+            ));
+        }
+    }
+}
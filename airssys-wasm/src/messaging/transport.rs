@@ -0,0 +1,546 @@
+//! TCP transport for messages between federated hosts.
+//!
+//! Provides [`TcpTransport`], the [`RemoteTransport`] used by the router to
+//! forward envelopes to components on other hosts, and
+//! [`TransportListener`], which accepts envelopes from peers and hands them
+//! to local delivery.
+//!
+//! # Wire Format
+//!
+//! Each envelope is a frame: a 4-byte big-endian length followed by the
+//! JSON-encoded [`RemoteEnvelope`]. The receiver answers every frame with
+//! an acknowledgement frame carrying the local delivery result, so
+//! `forward` only succeeds once the peer has accepted the message.
+//!
+//! # Connections
+//!
+//! Connections are secured by a [`TransportSecurity`] before the first
+//! frame and pooled per peer address, up to
+//! [`TransportConfig::max_idle_per_peer`] idle connections each. A pooled
+//! connection that fails is discarded and the frame is retried once on a
+//! fresh connection. [`Plaintext`] leaves streams unencrypted and is meant
+//! for trusted networks and tests; production deployments plug in a TLS
+//! implementation of `TransportSecurity`.
+//!
+//! # Architecture
+//!
+//! This module is part of `messaging/` (Layer 3B). It depends only on
+//! `core/component/` and `core/messaging/`; `system/` (Layer 4) supplies
+//! the delivery handler of the listener.
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use serde::{Deserialize, Serialize};
+
+// Layer 3: Internal module imports
+use crate::core::messaging::discovery::RemoteEndpoint;
+use crate::core::messaging::errors::MessagingError;
+use crate::core::messaging::transport::{
+    Connection, RemoteEnvelope, RemoteTransport, TransportSecurity,
+};
+
+/// Delivers envelopes received by a [`TransportListener`] on this host.
+pub type DeliveryHandler = Arc<dyn Fn(RemoteEnvelope) -> Result<(), MessagingError> + Send + Sync>;
+
+/// Idle connections by peer address.
+type ConnectionPool = HashMap<String, Vec<Box<dyn Connection>>>;
+
+/// Timeouts and limits of the TCP transport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransportConfig {
+    /// How long to wait for a TCP connection to a peer.
+    pub connect_timeout: Duration,
+    /// Read and write timeout of an established connection.
+    pub io_timeout: Duration,
+    /// Idle connections kept per peer address.
+    pub max_idle_per_peer: usize,
+    /// Largest accepted frame in bytes.
+    pub max_frame_size: usize,
+}
+
+impl TransportConfig {
+    /// Default connect timeout.
+    pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+    /// Default read/write timeout.
+    pub const DEFAULT_IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Default idle connections per peer.
+    pub const DEFAULT_MAX_IDLE_PER_PEER: usize = 4;
+
+    /// Default frame size limit (16 MiB).
+    pub const DEFAULT_MAX_FRAME_SIZE: usize = 16 * 1024 * 1024;
+
+    /// Sets the connect timeout.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Sets the read/write timeout.
+    pub fn with_io_timeout(mut self, timeout: Duration) -> Self {
+        self.io_timeout = timeout;
+        self
+    }
+
+    /// Sets how many idle connections are kept per peer.
+    pub fn with_max_idle_per_peer(mut self, count: usize) -> Self {
+        self.max_idle_per_peer = count;
+        self
+    }
+
+    /// Sets the frame size limit.
+    pub fn with_max_frame_size(mut self, bytes: usize) -> Self {
+        self.max_frame_size = bytes;
+        self
+    }
+}
+
+impl Default for TransportConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Self::DEFAULT_CONNECT_TIMEOUT,
+            io_timeout: Self::DEFAULT_IO_TIMEOUT,
+            max_idle_per_peer: Self::DEFAULT_MAX_IDLE_PER_PEER,
+            max_frame_size: Self::DEFAULT_MAX_FRAME_SIZE,
+        }
+    }
+}
+
+/// Unencrypted connections, for trusted networks and tests.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Plaintext;
+
+impl TransportSecurity for Plaintext {
+    fn name(&self) -> &str {
+        "plaintext"
+    }
+
+    fn connect(
+        &self,
+        stream: TcpStream,
+        _peer: &RemoteEndpoint,
+    ) -> Result<Box<dyn Connection>, MessagingError> {
+        Ok(Box::new(stream))
+    }
+
+    fn accept(&self, stream: TcpStream) -> Result<Box<dyn Connection>, MessagingError> {
+        Ok(Box::new(stream))
+    }
+}
+
+/// Peer's answer to a delivered frame.
+#[derive(Debug, Serialize, Deserialize)]
+struct Ack {
+    /// Why local delivery failed, if it did.
+    error: Option<String>,
+}
+
+/// Forwards envelopes to peer hosts over pooled TCP connections.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::sync::Arc;
+///
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::component::message::{ComponentMessage, MessageMetadata, MessagePayload};
+/// use airssys_wasm::core::messaging::discovery::RemoteEndpoint;
+/// use airssys_wasm::core::messaging::transport::{RemoteEnvelope, RemoteTransport};
+/// use airssys_wasm::messaging::transport::{Plaintext, TcpTransport};
+///
+/// let transport = TcpTransport::new(Arc::new(Plaintext));
+/// let target = ComponentId::new("finance", "billing", "v1");
+/// let envelope = RemoteEnvelope {
+///     target: target.clone(),
+///     message: ComponentMessage::new(
+///         ComponentId::new("shop", "cart", "v1"),
+///         MessagePayload::new(b"{}".to_vec()),
+///         MessageMetadata::default(),
+///     ),
+/// };
+///
+/// transport
+///     .forward(&RemoteEndpoint::new("node-b", "10.0.0.8:7400"), &envelope)
+///     .unwrap();
+/// ```
+pub struct TcpTransport {
+    /// Secures each new connection
+    security: Arc<dyn TransportSecurity>,
+    /// Timeouts and limits
+    config: TransportConfig,
+    /// Idle connections by peer address
+    pool: Mutex<ConnectionPool>,
+}
+
+impl TcpTransport {
+    /// Creates a transport securing connections with `security`.
+    pub fn new(security: Arc<dyn TransportSecurity>) -> Self {
+        Self {
+            security,
+            config: TransportConfig::default(),
+            pool: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets timeouts and limits.
+    pub fn with_config(mut self, config: TransportConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Number of idle pooled connections to `address`.
+    pub fn idle_connections(&self, address: &str) -> usize {
+        self.lock()
+            .map(|pool| pool.get(address).map_or(0, Vec::len))
+            .unwrap_or(0)
+    }
+
+    /// Closes every idle connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the pool is poisoned.
+    pub fn close_idle(&self) -> Result<(), MessagingError> {
+        self.lock()?.clear();
+        Ok(())
+    }
+
+    fn connect(&self, endpoint: &RemoteEndpoint) -> Result<Box<dyn Connection>, MessagingError> {
+        let unreachable =
+            |e: std::io::Error| MessagingError::DeliveryFailed(format!("{}: {}", endpoint, e));
+        let addrs: Vec<SocketAddr> = endpoint
+            .address
+            .to_socket_addrs()
+            .map_err(unreachable)?
+            .collect();
+
+        let mut last_error = None;
+        for addr in addrs {
+            match TcpStream::connect_timeout(&addr, self.config.connect_timeout) {
+                Ok(stream) => {
+                    stream
+                        .set_read_timeout(Some(self.config.io_timeout))
+                        .and_then(|()| stream.set_write_timeout(Some(self.config.io_timeout)))
+                        .and_then(|()| stream.set_nodelay(true))
+                        .map_err(unreachable)?;
+                    return self.security.connect(stream, endpoint);
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(unreachable(last_error.unwrap_or_else(|| {
+            std::io::Error::new(ErrorKind::NotFound, "address resolved to nothing")
+        })))
+    }
+
+    fn checkout(&self, address: &str) -> Result<Option<Box<dyn Connection>>, MessagingError> {
+        Ok(self.lock()?.get_mut(address).and_then(|idle| idle.pop()))
+    }
+
+    fn checkin(
+        &self,
+        address: &str,
+        connection: Box<dyn Connection>,
+    ) -> Result<(), MessagingError> {
+        let mut pool = self.lock()?;
+        let idle = pool.entry(address.to_string()).or_default();
+        if idle.len() < self.config.max_idle_per_peer {
+            idle.push(connection);
+        }
+        Ok(())
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, ConnectionPool>, MessagingError> {
+        self.pool
+            .lock()
+            .map_err(|e| MessagingError::DeliveryFailed(format!("Lock poisoned: {}", e)))
+    }
+}
+
+impl RemoteTransport for TcpTransport {
+    fn forward(
+        &self,
+        endpoint: &RemoteEndpoint,
+        envelope: &RemoteEnvelope,
+    ) -> Result<(), MessagingError> {
+        let frame = serde_json::to_vec(envelope)
+            .map_err(|e| MessagingError::InvalidMessage(e.to_string()))?;
+        let io_failed =
+            |e: std::io::Error| MessagingError::DeliveryFailed(format!("{}: {}", endpoint, e));
+
+        // A pooled connection may have been closed by the peer; retry once
+        // on a fresh one before giving up
+        let (mut connection, pooled) = match self.checkout(&endpoint.address)? {
+            Some(connection) => (connection, true),
+            None => (self.connect(endpoint)?, false),
+        };
+        let ack = match exchange(&mut connection, &frame, self.config.max_frame_size) {
+            Ok(ack) => ack,
+            Err(_) if pooled => {
+                connection = self.connect(endpoint)?;
+                exchange(&mut connection, &frame, self.config.max_frame_size).map_err(io_failed)?
+            }
+            Err(e) => return Err(io_failed(e)),
+        };
+        self.checkin(&endpoint.address, connection)?;
+
+        let ack: Ack = serde_json::from_slice(&ack)
+            .map_err(|e| MessagingError::InvalidMessage(e.to_string()))?;
+        match ack.error {
+            None => Ok(()),
+            Some(error) => Err(MessagingError::DeliveryFailed(format!(
+                "{} rejected message for {}: {}",
+                endpoint, envelope.target, error
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Debug for TcpTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TcpTransport")
+            .field("security", &self.security.name())
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+/// Accepts envelopes from peer hosts and delivers them locally.
+///
+/// Each connection is served on its own thread. Dropping the listener
+/// stops accepting new connections.
+pub struct TransportListener {
+    /// Bound address
+    local_addr: SocketAddr,
+    /// Set to stop the accept loop
+    stopped: Arc<AtomicBool>,
+    /// Accept loop thread
+    accept_thread: Option<JoinHandle<()>>,
+}
+
+impl TransportListener {
+    /// Binds `address` and starts accepting connections.
+    ///
+    /// Every received envelope is passed to `handler`; its result is sent
+    /// back to the forwarding host.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagingError::DeliveryFailed` if the address cannot be bound.
+    pub fn bind(
+        address: &str,
+        security: Arc<dyn TransportSecurity>,
+        config: TransportConfig,
+        handler: DeliveryHandler,
+    ) -> Result<Self, MessagingError> {
+        let listener = TcpListener::bind(address)
+            .map_err(|e| MessagingError::DeliveryFailed(format!("bind {}: {}", address, e)))?;
+        let local_addr = listener
+            .local_addr()
+            .map_err(|e| MessagingError::DeliveryFailed(e.to_string()))?;
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        let stop = Arc::clone(&stopped);
+        let accept_thread = thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stop.load(Ordering::SeqCst) {
+                    break;
+                }
+                let Ok(stream) = stream else { continue };
+                let security = Arc::clone(&security);
+                let handler = Arc::clone(&handler);
+                thread::spawn(move || serve(stream, security.as_ref(), config, handler.as_ref()));
+            }
+        });
+
+        Ok(Self {
+            local_addr,
+            stopped,
+            accept_thread: Some(accept_thread),
+        })
+    }
+
+    /// Address the listener is bound to.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Stops accepting connections; established ones finish their current
+    /// frame.
+    pub fn shutdown(&mut self) {
+        if self.stopped.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        // Wake the blocking accept so the loop observes the flag
+        let _ = TcpStream::connect(self.local_addr);
+        if let Some(thread) = self.accept_thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for TransportListener {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+impl std::fmt::Debug for TransportListener {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransportListener")
+            .field("local_addr", &self.local_addr)
+            .field("stopped", &self.stopped.load(Ordering::SeqCst))
+            .finish()
+    }
+}
+
+/// Serves frames on one inbound connection until the peer closes it.
+fn serve(
+    stream: TcpStream,
+    security: &dyn TransportSecurity,
+    config: TransportConfig,
+    handler: &(dyn Fn(RemoteEnvelope) -> Result<(), MessagingError> + Send + Sync),
+) {
+    if stream.set_write_timeout(Some(config.io_timeout)).is_err() {
+        return;
+    }
+    let Ok(mut connection) = security.accept(stream) else {
+        return;
+    };
+    while let Ok(frame) = read_frame(&mut connection, config.max_frame_size) {
+        let result = serde_json::from_slice::<RemoteEnvelope>(&frame)
+            .map_err(|e| MessagingError::InvalidMessage(e.to_string()))
+            .and_then(handler);
+        let ack = Ack {
+            error: result.err().map(|e| e.to_string()),
+        };
+        let Ok(ack) = serde_json::to_vec(&ack) else {
+            return;
+        };
+        if write_frame(&mut connection, &ack).is_err() {
+            return;
+        }
+    }
+}
+
+/// Sends `frame` and reads the peer's answer.
+fn exchange(
+    connection: &mut Box<dyn Connection>,
+    frame: &[u8],
+    max_frame_size: usize,
+) -> std::io::Result<Vec<u8>> {
+    write_frame(connection, frame)?;
+    read_frame(connection, max_frame_size)
+}
+
+fn write_frame(writer: &mut impl Write, frame: &[u8]) -> std::io::Result<()> {
+    let len = u32::try_from(frame.len())
+        .map_err(|_| std::io::Error::new(ErrorKind::InvalidInput, "frame too large"))?;
+    writer.write_all(&len.to_be_bytes())?;
+    writer.write_all(frame)?;
+    writer.flush()
+}
+
+fn read_frame(reader: &mut impl Read, max_frame_size: usize) -> std::io::Result<Vec<u8>> {
+    let mut len = [0u8; 4];
+    reader.read_exact(&mut len)?;
+    let len = u32::from_be_bytes(len) as usize;
+    if len > max_frame_size {
+        return Err(std::io::Error::new(
+            ErrorKind::InvalidData,
+            format!("frame of {} bytes exceeds limit of {}", len, max_frame_size),
+        ));
+    }
+    let mut frame = vec![0u8; len];
+    reader.read_exact(&mut frame)?;
+    Ok(frame)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::component::id::ComponentId;
+    use crate::core::component::message::{ComponentMessage, MessageMetadata, MessagePayload};
+
+    fn envelope(target: &str) -> RemoteEnvelope {
+        RemoteEnvelope {
+            target: ComponentId::new("app", target, "v1"),
+            message: ComponentMessage::new(
+                ComponentId::new("app", "sender", "v1"),
+                MessagePayload::new(b"hello".to_vec()),
+                MessageMetadata::default(),
+            ),
+        }
+    }
+
+    fn listener(received: Arc<Mutex<Vec<RemoteEnvelope>>>) -> TransportListener {
+        let handler: DeliveryHandler = Arc::new(move |envelope: RemoteEnvelope| {
+            if envelope.target.name == "ghost" {
+                return Err(MessagingError::TargetNotFound(
+                    envelope.target.to_string_id(),
+                ));
+            }
+            received.lock().unwrap().push(envelope);
+            Ok(())
+        });
+        TransportListener::bind(
+            "127.0.0.1:0",
+            Arc::new(Plaintext),
+            TransportConfig::default(),
+            handler,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_forward_delivers_and_pools_connection() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let listener = listener(Arc::clone(&received));
+        let endpoint = RemoteEndpoint::new("node-b", listener.local_addr().to_string());
+        let transport = TcpTransport::new(Arc::new(Plaintext));
+
+        transport.forward(&endpoint, &envelope("billing")).unwrap();
+        transport.forward(&endpoint, &envelope("billing")).unwrap();
+        assert_eq!(transport.idle_connections(&endpoint.address), 1);
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 2);
+        assert_eq!(received[0].message.payload.as_bytes(), b"hello");
+    }
+
+    #[test]
+    fn test_forward_reports_remote_and_connection_errors() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut listener = listener(received);
+        let endpoint = RemoteEndpoint::new("node-b", listener.local_addr().to_string());
+        let transport = TcpTransport::new(Arc::new(Plaintext)).with_config(
+            TransportConfig::default().with_connect_timeout(Duration::from_millis(200)),
+        );
+
+        let err = transport
+            .forward(&endpoint, &envelope("ghost"))
+            .unwrap_err();
+        assert!(matches!(err, MessagingError::DeliveryFailed(ref m) if m.contains("not found")));
+
+        listener.shutdown();
+        transport.close_idle().unwrap();
+        assert!(transport.forward(&endpoint, &envelope("billing")).is_err());
+    }
+
+    #[test]
+    fn test_oversized_frame_is_rejected() {
+        let mut wire = Vec::new();
+        write_frame(&mut wire, &[0u8; 64]).unwrap();
+        assert!(read_frame(&mut wire.as_slice(), 16).is_err());
+        assert_eq!(read_frame(&mut wire.as_slice(), 64).unwrap().len(), 64);
+    }
+}
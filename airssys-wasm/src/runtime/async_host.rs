@@ -0,0 +1,864 @@
+//! WASI Preview 2 host support for components.
+//!
+//! Wires the `wasi:io`, `wasi:clocks`, and `wasi:filesystem` host
+//! implementations from `wasmtime-wasi` into the engine's async linker, so
+//! components built against WASI Preview 2 can be instantiated.
+//!
+//! # Capability Gating
+//!
+//! Access is granted per component through [`WasiGrants`], normally read
+//! from the `[capabilities]` table of its `Component.toml`:
+//!
+//! ```toml
+//! [capabilities.clocks]
+//! wall = true
+//! monotonic = true
+//!
+//! [capabilities.filesystem]
+//! preopen_dirs = ["/srv/app/assets"]
+//! preopen_writable_dirs = ["/srv/app/cache"]
+//! ```
+//!
+//! - `wasi:io` is always available; streams and pollables carry no
+//!   authority of their own.
+//! - `wasi:clocks/wall-clock` and `wasi:clocks/monotonic-clock` each require
+//!   an explicit grant.
+//! - `wasi:filesystem` requires at least one preopened directory, and only
+//!   preopened directories are visible to the guest.
+//!
+//! Components importing an ungranted or unsupported WASI interface are
+//! rejected when loaded, before any guest code runs.
+//!
+//! # Blocking Pools
+//!
+//! [`BlockingPools`] bound the blocking work the host starts on a
+//! component's behalf outside a guest call. Each component gets a
+//! [`BlockingPool`] bounding how many of its operations occupy Tokio's
+//! shared blocking threads at once; an operation started while the pool is
+//! full is rejected with [`BlockingPoolError::Saturated`] rather than
+//! queued, so one component stalling cannot starve the others.
+//! [`BlockingPoolStats`] reports each pool's load and rejections.
+//!
+//! The engine hands its pools to callers as a
+//! [`BlockingRunner`](crate::core::runtime::traits::BlockingRunner);
+//! `ComponentWrapper` refills the warm instance reserve through it. The
+//! `airssys:core` host functions are synchronous and run on the thread
+//! executing the guest call, so they do not go through the pools.
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+// Layer 2: Third-party crate imports
+use serde::Deserialize;
+use thiserror::Error;
+use tokio::sync::Semaphore;
+use tokio::task::JoinHandle;
+use wasmtime::component::{Linker, ResourceTable};
+use wasmtime_wasi::{DirPerms, FilePerms, WasiCtx, WasiCtxBuilder, WasiImpl, WasiView};
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::runtime::errors::WasmError;
+use crate::core::runtime::traits::BlockingRunner;
+use crate::runtime::deterministic::DeterministicConfig;
+use crate::runtime::engine::HostState;
+
+/// Interfaces available to every component.
+const WASI_IO_PREFIX: &str = "wasi:io/";
+/// Wall clock interface, gated by `wall`.
+const WASI_WALL_CLOCK_PREFIX: &str = "wasi:clocks/wall-clock";
+/// Monotonic clock interface, gated by `monotonic`.
+const WASI_MONOTONIC_CLOCK_PREFIX: &str = "wasi:clocks/monotonic-clock";
+/// Filesystem interfaces, gated by preopened directories.
+const WASI_FILESYSTEM_PREFIX: &str = "wasi:filesystem/";
+
+/// A host directory exposed to the guest through `wasi:filesystem`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasiPreopen {
+    /// Directory on the host.
+    pub host_path: PathBuf,
+    /// Path under which the guest sees the directory.
+    pub guest_path: String,
+    /// Whether the guest may create, modify, and delete entries.
+    pub writable: bool,
+}
+
+/// WASI access granted to one component.
+///
+/// The default grants only `wasi:io`.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::runtime::async_host::WasiGrants;
+///
+/// let grants = WasiGrants::from_component_toml(
+///     "[capabilities.clocks]\nmonotonic = true\n",
+/// )
+/// .unwrap();
+/// assert!(grants.monotonic_clock);
+/// assert!(!grants.wall_clock);
+///
+/// assert!(grants
+///     .check_imports(["wasi:clocks/wall-clock@0.2.0"])
+///     .is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WasiGrants {
+    /// Access to `wasi:clocks/wall-clock`.
+    pub wall_clock: bool,
+    /// Access to `wasi:clocks/monotonic-clock`.
+    pub monotonic_clock: bool,
+    /// Directories visible through `wasi:filesystem`.
+    pub preopens: Vec<WasiPreopen>,
+}
+
+impl WasiGrants {
+    /// Grants both clocks.
+    pub fn with_clocks(mut self) -> Self {
+        self.wall_clock = true;
+        self.monotonic_clock = true;
+        self
+    }
+
+    /// Exposes a host directory to the guest under the same path.
+    pub fn with_preopen(mut self, host_path: impl Into<PathBuf>, writable: bool) -> Self {
+        let host_path = host_path.into();
+        self.preopens.push(WasiPreopen {
+            guest_path: host_path.to_string_lossy().into_owned(),
+            host_path,
+            writable,
+        });
+        self
+    }
+
+    /// Reads WASI grants from the `[capabilities]` table of a
+    /// `Component.toml`.
+    ///
+    /// Clocks come from `[capabilities.clocks]` (`wall`, `monotonic`);
+    /// preopened directories from `preopen_dirs` (read-only) and
+    /// `preopen_writable_dirs` in `[capabilities.filesystem]`. Other keys
+    /// are ignored here and validated by the manifest schema instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::InvalidComponent` if the TOML is malformed.
+    pub fn from_component_toml(source: &str) -> Result<Self, WasmError> {
+        #[derive(Deserialize, Default)]
+        struct ComponentFile {
+            #[serde(default)]
+            capabilities: CapabilitiesSection,
+        }
+
+        #[derive(Deserialize, Default)]
+        struct CapabilitiesSection {
+            #[serde(default)]
+            clocks: ClocksSection,
+            #[serde(default)]
+            filesystem: FilesystemSection,
+        }
+
+        #[derive(Deserialize, Default)]
+        struct ClocksSection {
+            #[serde(default)]
+            wall: bool,
+            #[serde(default)]
+            monotonic: bool,
+        }
+
+        #[derive(Deserialize, Default)]
+        struct FilesystemSection {
+            #[serde(default)]
+            preopen_dirs: Vec<PathBuf>,
+            #[serde(default)]
+            preopen_writable_dirs: Vec<PathBuf>,
+        }
+
+        let file: ComponentFile = toml::from_str(source).map_err(|e| {
+            WasmError::InvalidComponent(format!("Invalid component manifest: {}", e))
+        })?;
+        let caps = file.capabilities;
+
+        let mut grants = Self {
+            wall_clock: caps.clocks.wall,
+            monotonic_clock: caps.clocks.monotonic,
+            preopens: Vec::new(),
+        };
+        for dir in caps.filesystem.preopen_dirs {
+            grants = grants.with_preopen(dir, false);
+        }
+        for dir in caps.filesystem.preopen_writable_dirs {
+            grants = grants.with_preopen(dir, true);
+        }
+        Ok(grants)
+    }
+
+    /// Checks a component's import names against the grants.
+    ///
+    /// Non-WASI imports are not checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::InstantiationFailed` naming the first WASI
+    /// import that is not granted or not provided by this host.
+    pub fn check_imports<'a>(
+        &self,
+        imports: impl IntoIterator<Item = &'a str>,
+    ) -> Result<(), WasmError> {
+        for import in imports {
+            if !import.starts_with("wasi:") || import.starts_with(WASI_IO_PREFIX) {
+                continue;
+            }
+            let missing = if import.starts_with(WASI_WALL_CLOCK_PREFIX) {
+                (!self.wall_clock).then_some("[capabilities.clocks] wall = true")
+            } else if import.starts_with(WASI_MONOTONIC_CLOCK_PREFIX) {
+                (!self.monotonic_clock).then_some("[capabilities.clocks] monotonic = true")
+            } else if import.starts_with(WASI_FILESYSTEM_PREFIX) {
+                self.preopens
+                    .is_empty()
+                    .then_some("a [capabilities.filesystem] preopened directory")
+            } else {
+                return Err(WasmError::InstantiationFailed(format!(
+                    "WASI interface {} is not provided by this host",
+                    import
+                )));
+            };
+            if let Some(grant) = missing {
+                return Err(WasmError::InstantiationFailed(format!(
+                    "WASI import {} requires {}",
+                    import, grant
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds the WASI context builder for one instance.
+    fn builder(&self) -> Result<WasiCtxBuilder, WasmError> {
+        let mut builder = WasiCtxBuilder::new();
+        for preopen in &self.preopens {
+            let (dir_perms, file_perms) = if preopen.writable {
+                (DirPerms::all(), FilePerms::all())
+            } else {
+                (DirPerms::READ, FilePerms::READ)
+            };
+            builder
+                .preopened_dir(
+                    &preopen.host_path,
+                    &preopen.guest_path,
+                    dir_perms,
+                    file_perms,
+                )
+                .map_err(|e| {
+                    WasmError::InstantiationFailed(format!(
+                        "Cannot preopen {}: {}",
+                        preopen.host_path.display(),
+                        e
+                    ))
+                })?;
+        }
+        Ok(builder)
+    }
+}
+
+/// WASI context and resource table of one instance.
+struct WasiParts {
+    ctx: WasiCtx,
+    table: ResourceTable,
+}
+
+/// Per-instance WASI state held in [`HostState`].
+///
+/// `WasiCtx` and `ResourceTable` are `Send` but not `Sync`. They sit behind
+/// a `Mutex` so `HostState` stays `Sync`; WASI host calls reach them through
+/// `&mut HostState` with `Mutex::get_mut`, which never locks.
+pub struct WasiState {
+    parts: Mutex<WasiParts>,
+}
+
+impl WasiState {
+    /// Creates the WASI state for an instance with `grants`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::InstantiationFailed` if a preopened directory
+    /// cannot be opened.
+    pub fn new(grants: &WasiGrants) -> Result<Self, WasmError> {
+        Ok(Self::from_ctx(grants.builder()?.build()))
+    }
+
+    /// Creates the WASI state for an instance of `id` with `grants`, using
+    /// seeded randomness and virtual clocks from `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::InstantiationFailed` if a preopened directory
+    /// cannot be opened.
+    pub fn deterministic(
+        grants: &WasiGrants,
+        config: &DeterministicConfig,
+        id: &ComponentId,
+    ) -> Result<Self, WasmError> {
+        let mut builder = grants.builder()?;
+        config.apply_to_wasi(&mut builder, id);
+        Ok(Self::from_ctx(builder.build()))
+    }
+
+    fn from_ctx(ctx: WasiCtx) -> Self {
+        Self {
+            parts: Mutex::new(WasiParts {
+                ctx,
+                table: ResourceTable::new(),
+            }),
+        }
+    }
+
+    fn parts(&mut self) -> &mut WasiParts {
+        // Never locked, so it can only be poisoned by a panic elsewhere
+        // holding `&mut self`; the parts are still consistent then
+        self.parts
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for WasiState {
+    /// State with only `wasi:io` usable (no preopens).
+    fn default() -> Self {
+        Self::from_ctx(WasiCtxBuilder::new().build())
+    }
+}
+
+impl std::fmt::Debug for WasiState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WasiState").finish_non_exhaustive()
+    }
+}
+
+impl WasiView for HostState {
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.wasi.parts().table
+    }
+
+    fn ctx(&mut self) -> &mut WasiCtx {
+        &mut self.wasi.parts().ctx
+    }
+}
+
+/// Default number of blocking operations one component may run at once.
+pub const DEFAULT_BLOCKING_LIMIT: usize = 4;
+
+/// Errors from running work on a [`BlockingPool`].
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum BlockingPoolError {
+    /// Every slot of the component's pool is busy.
+    #[error("Blocking pool of {component} is saturated ({limit} operations running)")]
+    Saturated {
+        /// Component whose pool is full.
+        component: ComponentId,
+        /// Size of the pool.
+        limit: usize,
+    },
+
+    /// The operation panicked or was cancelled.
+    #[error("Blocking operation of {component} failed: {reason}")]
+    Failed {
+        /// Component that started the operation.
+        component: ComponentId,
+        /// Why the operation did not complete.
+        reason: String,
+    },
+}
+
+/// Load and rejection counters of one [`BlockingPool`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlockingPoolStats {
+    /// Operations the pool runs at most at once.
+    pub limit: usize,
+    /// Operations running now.
+    pub running: usize,
+    /// Most operations that ran at once.
+    pub peak: usize,
+    /// Operations that ran to completion, including ones that panicked.
+    pub completed: u64,
+    /// Operations rejected because the pool was full.
+    pub rejected: u64,
+}
+
+impl BlockingPoolStats {
+    /// Fraction of the pool in use, from 0.0 to 1.0.
+    pub fn saturation(&self) -> f64 {
+        if self.limit == 0 {
+            return 1.0;
+        }
+        self.running as f64 / self.limit as f64
+    }
+
+    /// Whether a new operation would be rejected.
+    pub fn is_saturated(&self) -> bool {
+        self.running >= self.limit
+    }
+}
+
+/// Bounded share of Tokio's blocking threads owned by one component.
+///
+/// Obtained from [`BlockingPools::pool`].
+pub struct BlockingPool {
+    component: ComponentId,
+    limit: usize,
+    slots: Arc<Semaphore>,
+    peak: AtomicUsize,
+    completed: AtomicU64,
+    rejected: AtomicU64,
+}
+
+impl BlockingPool {
+    fn new(component: ComponentId, limit: usize) -> Self {
+        Self {
+            component,
+            limit,
+            slots: Arc::new(Semaphore::new(limit)),
+            peak: AtomicUsize::new(0),
+            completed: AtomicU64::new(0),
+            rejected: AtomicU64::new(0),
+        }
+    }
+
+    /// Runs `work` on a blocking thread if the pool has a free slot.
+    ///
+    /// The slot stays taken until `work` returns, even if the returned
+    /// future is dropped before then.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BlockingPoolError::Saturated` without running `work` if
+    /// every slot is busy, or `BlockingPoolError::Failed` if `work`
+    /// panicked.
+    pub async fn run<F, R>(self: &Arc<Self>, work: F) -> Result<R, BlockingPoolError>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        self.spawn(work)?
+            .await
+            .map_err(|e| BlockingPoolError::Failed {
+                component: self.component.clone(),
+                reason: e.to_string(),
+            })
+    }
+
+    /// Starts `work` on a blocking thread if the pool has a free slot,
+    /// without waiting for it.
+    ///
+    /// The slot stays taken until `work` returns.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BlockingPoolError::Saturated` without starting `work` if
+    /// every slot is busy.
+    pub fn spawn<F, R>(self: &Arc<Self>, work: F) -> Result<JoinHandle<R>, BlockingPoolError>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let Ok(slot) = Arc::clone(&self.slots).try_acquire_owned() else {
+            self.rejected.fetch_add(1, Ordering::Relaxed);
+            return Err(BlockingPoolError::Saturated {
+                component: self.component.clone(),
+                limit: self.limit,
+            });
+        };
+        self.peak.fetch_max(self.running(), Ordering::Relaxed);
+
+        let pool = Arc::clone(self);
+        Ok(tokio::task::spawn_blocking(move || {
+            let _slot = slot;
+            let _done = CompletionGuard(&pool.completed);
+            work()
+        }))
+    }
+
+    /// The component this pool belongs to.
+    pub fn component(&self) -> &ComponentId {
+        &self.component
+    }
+
+    /// Current counters.
+    pub fn stats(&self) -> BlockingPoolStats {
+        BlockingPoolStats {
+            limit: self.limit,
+            running: self.running(),
+            peak: self.peak.load(Ordering::Relaxed),
+            completed: self.completed.load(Ordering::Relaxed),
+            rejected: self.rejected.load(Ordering::Relaxed),
+        }
+    }
+
+    fn running(&self) -> usize {
+        self.limit.saturating_sub(self.slots.available_permits())
+    }
+}
+
+impl std::fmt::Debug for BlockingPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlockingPool")
+            .field("component", &self.component)
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+/// Counts an operation as completed when it returns or unwinds.
+struct CompletionGuard<'a>(&'a AtomicU64);
+
+impl Drop for CompletionGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Per-component [`BlockingPool`]s, created on first use.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::runtime::async_host::BlockingPools;
+///
+/// # tokio_test::block_on(async {
+/// let pools = BlockingPools::new(2).with_limit(ComponentId::new("shop", "indexer", "0"), 8);
+/// let cart = ComponentId::new("shop", "cart", "0");
+///
+/// let sum = pools.run(&cart, || 2 + 2).await.unwrap();
+/// assert_eq!(sum, 4);
+///
+/// let stats = pools.stats(&cart).unwrap();
+/// assert_eq!((stats.limit, stats.completed), (2, 1));
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct BlockingPools {
+    default_limit: usize,
+    limits: HashMap<ComponentId, usize>,
+    pools: Mutex<HashMap<ComponentId, Arc<BlockingPool>>>,
+}
+
+impl BlockingPools {
+    /// Creates pools of `default_limit` slots per component.
+    pub fn new(default_limit: usize) -> Self {
+        Self {
+            default_limit,
+            limits: HashMap::new(),
+            pools: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Gives `component` a pool of `limit` slots instead of the default.
+    pub fn with_limit(mut self, component: ComponentId, limit: usize) -> Self {
+        self.limits.insert(component, limit);
+        self
+    }
+
+    /// The pool of `component`, created if it has none yet.
+    pub fn pool(&self, component: &ComponentId) -> Arc<BlockingPool> {
+        let mut pools = self.lock();
+        let pool = pools.entry(component.clone()).or_insert_with(|| {
+            let limit = self
+                .limits
+                .get(component)
+                .copied()
+                .unwrap_or(self.default_limit);
+            Arc::new(BlockingPool::new(component.clone(), limit))
+        });
+        Arc::clone(pool)
+    }
+
+    /// Runs `work` in the pool of `component`.
+    ///
+    /// # Errors
+    ///
+    /// As [`BlockingPool::run`].
+    pub async fn run<F, R>(&self, component: &ComponentId, work: F) -> Result<R, BlockingPoolError>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        self.pool(component).run(work).await
+    }
+
+    /// Counters of `component`'s pool, if it has been used.
+    pub fn stats(&self, component: &ComponentId) -> Option<BlockingPoolStats> {
+        self.lock().get(component).map(|pool| pool.stats())
+    }
+
+    /// Counters of every pool.
+    pub fn all_stats(&self) -> Vec<(ComponentId, BlockingPoolStats)> {
+        self.lock()
+            .iter()
+            .map(|(id, pool)| (id.clone(), pool.stats()))
+            .collect()
+    }
+
+    /// Components whose pool is currently full.
+    pub fn saturated(&self) -> Vec<ComponentId> {
+        self.lock()
+            .iter()
+            .filter(|(_, pool)| pool.stats().is_saturated())
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    /// Drops the pool of `component`, e.g. when it is unloaded.
+    ///
+    /// Operations already running keep their slots until they return.
+    pub fn remove(&self, component: &ComponentId) {
+        self.lock().remove(component);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<ComponentId, Arc<BlockingPool>>> {
+        self.pools.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl BlockingRunner for BlockingPools {
+    fn spawn_blocking(
+        &self,
+        component: &ComponentId,
+        work: Box<dyn FnOnce() + Send>,
+    ) -> Result<(), WasmError> {
+        self.pool(component)
+            .spawn(work)
+            .map(drop)
+            .map_err(|e| WasmError::ResourceLimitExceeded(e.to_string()))
+    }
+}
+
+impl Default for BlockingPools {
+    /// Pools of [`DEFAULT_BLOCKING_LIMIT`] slots per component.
+    fn default() -> Self {
+        Self::new(DEFAULT_BLOCKING_LIMIT)
+    }
+}
+
+/// Pins the higher-ranked closure type expected by the WASI bindings.
+fn wasi_host<F>(getter: F) -> F
+where
+    F: Fn(&mut HostState) -> WasiImpl<&mut HostState>,
+{
+    getter
+}
+
+/// Registers the async `wasi:io`, `wasi:clocks`, and `wasi:filesystem`
+/// host implementations with `linker`.
+///
+/// Registration does not grant access; see [`WasiGrants::check_imports`].
+pub fn add_wasi_to_linker(linker: &mut Linker<HostState>) -> wasmtime::Result<()> {
+    use wasmtime_wasi::bindings::{clocks, filesystem, io};
+
+    let host = wasi_host(|state| WasiImpl(state));
+    io::error::add_to_linker_get_host(linker, host)?;
+    io::poll::add_to_linker_get_host(linker, host)?;
+    io::streams::add_to_linker_get_host(linker, host)?;
+    clocks::wall_clock::add_to_linker_get_host(linker, host)?;
+    clocks::monotonic_clock::add_to_linker_get_host(linker, host)?;
+    filesystem::types::add_to_linker_get_host(linker, host)?;
+    filesystem::preopens::add_to_linker_get_host(linker, host)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use wasmtime::Engine;
+
+    #[test]
+    fn test_default_grants_allow_only_io() {
+        let grants = WasiGrants::default();
+        assert!(grants
+            .check_imports(["wasi:io/streams@0.2.0", "airssys:core/host-messaging"])
+            .is_ok());
+
+        let err = grants
+            .check_imports(["wasi:clocks/monotonic-clock@0.2.0"])
+            .unwrap_err();
+        assert!(err.to_string().contains("monotonic = true"));
+        assert!(grants
+            .check_imports(["wasi:filesystem/types@0.2.0"])
+            .is_err());
+        assert!(grants.check_imports(["wasi:sockets/tcp@0.2.0"]).is_err());
+    }
+
+    #[test]
+    fn test_grants_from_component_toml() {
+        let grants = WasiGrants::from_component_toml(
+            r#"
+            [component]
+            name = "reader"
+
+            [capabilities.clocks]
+            wall = true
+
+            [capabilities.filesystem]
+            can_read_paths = ["/data/*"]
+            preopen_dirs = ["/data"]
+            preopen_writable_dirs = ["/scratch"]
+            "#,
+        )
+        .unwrap();
+
+        assert!(grants.wall_clock);
+        assert!(!grants.monotonic_clock);
+        assert_eq!(
+            grants.preopens,
+            vec![
+                WasiPreopen {
+                    host_path: PathBuf::from("/data"),
+                    guest_path: "/data".to_string(),
+                    writable: false,
+                },
+                WasiPreopen {
+                    host_path: PathBuf::from("/scratch"),
+                    guest_path: "/scratch".to_string(),
+                    writable: true,
+                },
+            ]
+        );
+        assert!(grants
+            .check_imports([
+                "wasi:clocks/wall-clock@0.2.0",
+                "wasi:filesystem/preopens@0.2.0"
+            ])
+            .is_ok());
+    }
+
+    #[test]
+    fn test_grants_reject_malformed_manifest() {
+        assert!(matches!(
+            WasiGrants::from_component_toml("[capabilities.clocks]\nwall = \"yes\"\n"),
+            Err(WasmError::InvalidComponent(_))
+        ));
+    }
+
+    #[test]
+    fn test_wasi_state_preopen_errors_are_reported() {
+        let grants = WasiGrants::default().with_preopen("/definitely/not/here", false);
+        assert!(matches!(
+            WasiState::new(&grants),
+            Err(WasmError::InstantiationFailed(_))
+        ));
+
+        let dir = std::env::temp_dir();
+        assert!(WasiState::new(&WasiGrants::default().with_preopen(dir, true)).is_ok());
+    }
+
+    #[test]
+    fn test_add_wasi_to_linker_alongside_host_functions() {
+        let mut config = wasmtime::Config::new();
+        config.async_support(true);
+        let engine = Engine::new(&config).unwrap();
+        let mut linker = Linker::<HostState>::new(&engine);
+
+        crate::runtime::host_functions::marker_traits::register_host_functions(&mut linker)
+            .unwrap();
+        assert!(add_wasi_to_linker(&mut linker).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_blocking_pool_rejects_when_component_exhausts_it() {
+        let pools = BlockingPools::new(1);
+        let cart = ComponentId::new("shop", "cart", "0");
+        let search = ComponentId::new("shop", "search", "0");
+
+        let (release, hold) = mpsc::channel::<()>();
+        let pool = pools.pool(&cart);
+        let busy = tokio::spawn(async move { pool.run(move || hold.recv().is_ok()).await });
+        while pools.stats(&cart).unwrap().running == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        assert_eq!(
+            pools.run(&cart, || ()).await,
+            Err(BlockingPoolError::Saturated {
+                component: cart.clone(),
+                limit: 1
+            })
+        );
+        assert_eq!(pools.saturated(), vec![cart.clone()]);
+        // Other components keep their own slots
+        assert_eq!(pools.run(&search, || 7).await, Ok(7));
+
+        release.send(()).unwrap();
+        assert_eq!(busy.await.unwrap(), Ok(true));
+        let stats = pools.stats(&cart).unwrap();
+        assert_eq!(
+            stats,
+            BlockingPoolStats {
+                limit: 1,
+                running: 0,
+                peak: 1,
+                completed: 1,
+                rejected: 1,
+            }
+        );
+        assert_eq!(stats.saturation(), 0.0);
+        assert!(pools.saturated().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_blocking_runner_shares_component_pool() {
+        let pools = BlockingPools::new(1);
+        let cart = ComponentId::new("shop", "cart", "0");
+
+        let (release, hold) = mpsc::channel::<()>();
+        let runner: &dyn BlockingRunner = &pools;
+        runner
+            .spawn_blocking(
+                &cart,
+                Box::new(move || {
+                    let _ = hold.recv();
+                }),
+            )
+            .unwrap();
+        assert!(matches!(
+            runner.spawn_blocking(&cart, Box::new(|| ())),
+            Err(WasmError::ResourceLimitExceeded(_))
+        ));
+        assert!(matches!(
+            pools.run(&cart, || ()).await,
+            Err(BlockingPoolError::Saturated { .. })
+        ));
+
+        release.send(()).unwrap();
+        while pools.stats(&cart).unwrap().running > 0 {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(pools.stats(&cart).unwrap().rejected, 2);
+    }
+
+    #[tokio::test]
+    async fn test_blocking_pool_limits_and_panics() {
+        let indexer = ComponentId::new("shop", "indexer", "0");
+        let pools = BlockingPools::default().with_limit(indexer.clone(), 8);
+        assert_eq!(pools.pool(&indexer).stats().limit, 8);
+        assert_eq!(
+            pools
+                .pool(&ComponentId::new("shop", "cart", "0"))
+                .stats()
+                .limit,
+            DEFAULT_BLOCKING_LIMIT
+        );
+
+        let result = pools.run(&indexer, || panic!("disk on fire")).await;
+        assert!(matches!(result, Err(BlockingPoolError::Failed { .. })));
+        let stats = pools.stats(&indexer).unwrap();
+        assert_eq!((stats.running, stats.completed), (0, 1));
+
+        pools.remove(&indexer);
+        assert!(pools.stats(&indexer).is_none());
+        assert_eq!(pools.all_stats().len(), 1);
+    }
+}
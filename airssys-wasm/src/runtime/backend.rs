@@ -0,0 +1,431 @@
+//! Execution engine selection.
+//!
+//! The layers above `runtime/` only depend on the `RuntimeEngine` trait, but
+//! hosts used to construct `WasmtimeEngine` directly. This module lets the
+//! engine be chosen by configuration instead:
+//!
+//! - [`EngineBackend`] names an execution engine (`"wasmtime"`, and
+//!   `"mock"` with the `mock-engine` feature)
+//! - [`RuntimeConfig`] holds the selection plus engine settings (code
+//!   cache, deterministic mode), and can be read from the `[runtime]` table
+//!   of a `Host.toml`
+//! - [`EngineFactory`] builds the configured engine as a [`RuntimeBackend`],
+//!   an enum implementing `RuntimeEngine` by static dispatch
+//!
+//! # Examples
+//!
+//! ```rust
+//! use airssys_wasm::runtime::backend::{EngineBackend, EngineFactory, RuntimeConfig};
+//!
+//! let config = RuntimeConfig::from_host_toml("[runtime]\nengine = \"wasmtime\"\n").unwrap();
+//! let engine = EngineFactory::create(&config).unwrap();
+//! assert_eq!(engine.backend(), EngineBackend::Wasmtime);
+//! ```
+
+// Layer 1: Standard library imports
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use serde::Deserialize;
+
+// Layer 3: Internal module imports
+use crate::core::component::handle::ComponentHandle;
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::{ComponentMessage, MessagePayload};
+use crate::core::config::values::ConfigValues;
+use crate::core::runtime::errors::WasmError;
+use crate::core::runtime::snapshot::ComponentSnapshot;
+use crate::core::runtime::traits::{BlockingRunner, RuntimeEngine};
+use crate::core::runtime::usage::ResourceUsage;
+
+use super::deterministic::DeterministicConfig;
+use super::engine::WasmtimeEngine;
+use super::loader::{CodeCacheConfig, CompiledArtifactCache};
+#[cfg(feature = "mock-engine")]
+use super::mock_engine::MockEngine;
+
+/// An execution engine implementation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum EngineBackend {
+    /// Wasmtime with the Component Model (default).
+    #[default]
+    Wasmtime,
+
+    /// [`MockEngine`], answering messages without executing WASM.
+    #[cfg(feature = "mock-engine")]
+    Mock,
+}
+
+impl EngineBackend {
+    /// Returns the backend's configuration name.
+    pub fn name(self) -> &'static str {
+        match self {
+            EngineBackend::Wasmtime => "wasmtime",
+            #[cfg(feature = "mock-engine")]
+            EngineBackend::Mock => "mock",
+        }
+    }
+
+    /// Parses a configuration name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::ConfigRejected` for unknown names and for
+    /// backends not compiled into this build.
+    pub fn from_name(name: &str) -> Result<Self, WasmError> {
+        match name {
+            "wasmtime" => Ok(EngineBackend::Wasmtime),
+            #[cfg(feature = "mock-engine")]
+            "mock" => Ok(EngineBackend::Mock),
+            #[cfg(not(feature = "mock-engine"))]
+            "mock" => Err(WasmError::ConfigRejected(
+                "Engine backend 'mock' requires the mock-engine feature".to_string(),
+            )),
+            other => Err(WasmError::ConfigRejected(format!(
+                "Unknown engine backend '{}'",
+                other
+            ))),
+        }
+    }
+
+    /// Backends compiled into this build.
+    pub fn available() -> &'static [EngineBackend] {
+        &[
+            EngineBackend::Wasmtime,
+            #[cfg(feature = "mock-engine")]
+            EngineBackend::Mock,
+        ]
+    }
+}
+
+impl fmt::Display for EngineBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Engine selection and settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuntimeConfig {
+    /// Engine to build.
+    pub backend: EngineBackend,
+
+    /// On-disk compiled code cache (Wasmtime only).
+    pub code_cache: Option<CodeCacheConfig>,
+
+    /// Replayable execution (Wasmtime only; the mock engine is always
+    /// deterministic).
+    pub deterministic: Option<DeterministicConfig>,
+}
+
+impl RuntimeConfig {
+    /// Creates a configuration selecting `backend`.
+    pub fn new(backend: EngineBackend) -> Self {
+        Self {
+            backend,
+            code_cache: None,
+            deterministic: None,
+        }
+    }
+
+    /// Enables the compiled code cache.
+    pub fn with_code_cache(mut self, code_cache: CodeCacheConfig) -> Self {
+        self.code_cache = Some(code_cache);
+        self
+    }
+
+    /// Enables deterministic execution.
+    pub fn with_deterministic(mut self, deterministic: DeterministicConfig) -> Self {
+        self.deterministic = Some(deterministic);
+        self
+    }
+
+    /// Reads `engine`, the code cache and the deterministic mode settings from the `[runtime]`
+    /// table of a `Host.toml`.
+    ///
+    /// A missing `engine` key selects Wasmtime.
+    ///
+    /// # Errors
+    ///
+    /// - `WasmError::RuntimeError` - the TOML is malformed
+    /// - `WasmError::ConfigRejected` - the engine is unknown or not compiled in
+    pub fn from_host_toml(source: &str) -> Result<Self, WasmError> {
+        #[derive(Deserialize, Default)]
+        struct HostFile {
+            #[serde(default)]
+            runtime: RuntimeSection,
+        }
+
+        #[derive(Deserialize, Default)]
+        struct RuntimeSection {
+            engine: Option<String>,
+        }
+
+        let file: HostFile = toml::from_str(source)
+            .map_err(|e| WasmError::RuntimeError(format!("Invalid host config: {}", e)))?;
+        let backend = match file.runtime.engine {
+            Some(name) => EngineBackend::from_name(&name)?,
+            None => EngineBackend::default(),
+        };
+        Ok(Self {
+            backend,
+            code_cache: CodeCacheConfig::from_host_toml(source)?,
+            deterministic: DeterministicConfig::from_host_toml(source)?,
+        })
+    }
+}
+
+/// Builds execution engines from a [`RuntimeConfig`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EngineFactory;
+
+impl EngineFactory {
+    /// Builds the engine selected by `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the engine's construction error, or the code cache's if its
+    /// directory cannot be opened.
+    pub fn create(config: &RuntimeConfig) -> Result<RuntimeBackend, WasmError> {
+        match config.backend {
+            EngineBackend::Wasmtime => {
+                let engine = match config.deterministic {
+                    Some(deterministic) => WasmtimeEngine::new_deterministic(deterministic)?,
+                    None => WasmtimeEngine::new()?,
+                };
+                let engine = match &config.code_cache {
+                    Some(cache) => {
+                        engine.with_code_cache(Arc::new(CompiledArtifactCache::new(cache.clone())?))
+                    }
+                    None => engine,
+                };
+                Ok(RuntimeBackend::Wasmtime(Box::new(engine)))
+            }
+            #[cfg(feature = "mock-engine")]
+            EngineBackend::Mock => Ok(RuntimeBackend::Mock(MockEngine::new())),
+        }
+    }
+}
+
+/// The engine built by [`EngineFactory`].
+///
+/// Delegates every `RuntimeEngine` method to the selected engine.
+/// Backend-specific configuration (e.g. `WasmtimeEngine::with_os_bridge`)
+/// is applied through the accessors before the engine is shared.
+pub enum RuntimeBackend {
+    /// Wasmtime engine.
+    Wasmtime(Box<WasmtimeEngine>),
+
+    /// Mock engine.
+    #[cfg(feature = "mock-engine")]
+    Mock(MockEngine),
+}
+
+macro_rules! delegate {
+    ($self:ident, $engine:ident => $call:expr) => {
+        match $self {
+            RuntimeBackend::Wasmtime($engine) => $call,
+            #[cfg(feature = "mock-engine")]
+            RuntimeBackend::Mock($engine) => $call,
+        }
+    };
+}
+
+impl RuntimeBackend {
+    /// Which backend this is.
+    pub fn backend(&self) -> EngineBackend {
+        match self {
+            RuntimeBackend::Wasmtime(_) => EngineBackend::Wasmtime,
+            #[cfg(feature = "mock-engine")]
+            RuntimeBackend::Mock(_) => EngineBackend::Mock,
+        }
+    }
+
+    /// The Wasmtime engine, if selected.
+    pub fn as_wasmtime(&self) -> Option<&WasmtimeEngine> {
+        match self {
+            RuntimeBackend::Wasmtime(engine) => Some(engine.as_ref()),
+            #[cfg(feature = "mock-engine")]
+            _ => None,
+        }
+    }
+
+    /// Applies `f` to the Wasmtime engine, if selected.
+    pub fn map_wasmtime(self, f: impl FnOnce(WasmtimeEngine) -> WasmtimeEngine) -> Self {
+        match self {
+            RuntimeBackend::Wasmtime(engine) => RuntimeBackend::Wasmtime(Box::new(f(*engine))),
+            #[cfg(feature = "mock-engine")]
+            other => other,
+        }
+    }
+}
+
+impl fmt::Debug for RuntimeBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RuntimeBackend")
+            .field(&self.backend())
+            .finish()
+    }
+}
+
+impl RuntimeEngine for RuntimeBackend {
+    fn load_component(&self, id: &ComponentId, bytes: &[u8]) -> Result<ComponentHandle, WasmError> {
+        delegate!(self, engine => engine.load_component(id, bytes))
+    }
+
+    fn unload_component(&self, handle: &ComponentHandle) -> Result<(), WasmError> {
+        delegate!(self, engine => engine.unload_component(handle))
+    }
+
+    fn call_handle_message(
+        &self,
+        handle: &ComponentHandle,
+        msg: &ComponentMessage,
+    ) -> Result<Option<MessagePayload>, WasmError> {
+        delegate!(self, engine => engine.call_handle_message(handle, msg))
+    }
+
+    fn call_handle_callback(
+        &self,
+        handle: &ComponentHandle,
+        msg: &ComponentMessage,
+    ) -> Result<(), WasmError> {
+        delegate!(self, engine => engine.call_handle_callback(handle, msg))
+    }
+
+    fn call_handle_message_with_usage(
+        &self,
+        handle: &ComponentHandle,
+        msg: &ComponentMessage,
+    ) -> Result<(Option<MessagePayload>, ResourceUsage), WasmError> {
+        delegate!(self, engine => engine.call_handle_message_with_usage(handle, msg))
+    }
+
+    fn call_reconfigure(
+        &self,
+        handle: &ComponentHandle,
+        values: &ConfigValues,
+    ) -> Result<(), WasmError> {
+        delegate!(self, engine => engine.call_reconfigure(handle, values))
+    }
+
+    fn call_prepare_shutdown(
+        &self,
+        handle: &ComponentHandle,
+        deadline: Duration,
+    ) -> Result<(), WasmError> {
+        delegate!(self, engine => engine.call_prepare_shutdown(handle, deadline))
+    }
+
+    fn prewarm(&self, id: &ComponentId, bytes: &[u8], count: usize) -> Result<usize, WasmError> {
+        delegate!(self, engine => engine.prewarm(id, bytes, count))
+    }
+
+    fn snapshot(&self, handle: &ComponentHandle) -> Result<ComponentSnapshot, WasmError> {
+        delegate!(self, engine => engine.snapshot(handle))
+    }
+
+    fn restore(
+        &self,
+        handle: &ComponentHandle,
+        snapshot: &ComponentSnapshot,
+    ) -> Result<(), WasmError> {
+        delegate!(self, engine => engine.restore(handle, snapshot))
+    }
+
+    fn grant_elevated(&self, id: &ComponentId, capability: &str) -> Result<(), WasmError> {
+        delegate!(self, engine => engine.grant_elevated(id, capability))
+    }
+
+    fn blocking_runner(&self) -> Option<Arc<dyn BlockingRunner>> {
+        delegate!(self, engine => engine.blocking_runner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_engine_names() {
+        assert_eq!(
+            EngineBackend::from_name("wasmtime").unwrap(),
+            EngineBackend::Wasmtime
+        );
+        assert!(matches!(
+            EngineBackend::from_name("wasmer"),
+            Err(WasmError::ConfigRejected(_))
+        ));
+        for backend in EngineBackend::available() {
+            assert_eq!(EngineBackend::from_name(backend.name()).unwrap(), *backend);
+        }
+    }
+
+    #[test]
+    fn test_config_from_host_toml() {
+        let config = RuntimeConfig::from_host_toml("").unwrap();
+        assert_eq!(config, RuntimeConfig::default());
+
+        let config = RuntimeConfig::from_host_toml(
+            "[runtime]\nengine = \"wasmtime\"\ncode_cache_dir = \"/tmp/cache\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.backend, EngineBackend::Wasmtime);
+        assert_eq!(config.code_cache.unwrap().dir.to_str(), Some("/tmp/cache"));
+
+        assert!(RuntimeConfig::from_host_toml("[runtime]\nengine = \"nope\"\n").is_err());
+    }
+
+    #[test]
+    fn test_factory_builds_deterministic_engine() {
+        let config = RuntimeConfig::from_host_toml(
+            "[runtime]\ndeterministic = true\ndeterministic_seed = 9\n",
+        )
+        .unwrap();
+        assert_eq!(config.deterministic, Some(DeterministicConfig::new(9)));
+
+        let engine = EngineFactory::create(&config).unwrap();
+        let wasmtime = engine.as_wasmtime().unwrap();
+        assert_eq!(wasmtime.deterministic(), Some(&DeterministicConfig::new(9)));
+
+        // NaN canonicalization changes the compiled code
+        let plain = WasmtimeEngine::new().unwrap();
+        assert_ne!(wasmtime.config_hash(), plain.config_hash());
+    }
+
+    #[test]
+    fn test_factory_builds_working_engine() {
+        let engine = EngineFactory::create(&RuntimeConfig::default()).unwrap();
+        assert!(engine.as_wasmtime().is_some());
+
+        // Calls reach wasmtime
+        let id = ComponentId::new("acme", "empty", "v1");
+        assert!(engine.load_component(&id, b"not wasm").is_err());
+        assert!(engine
+            .unload_component(&ComponentHandle::new(id, 999))
+            .is_ok());
+    }
+
+    #[cfg(feature = "mock-engine")]
+    #[test]
+    fn test_factory_builds_mock_engine() {
+        use crate::core::component::message::MessageMetadata;
+
+        let config = RuntimeConfig::from_host_toml("[runtime]\nengine = \"mock\"\n").unwrap();
+        let engine = EngineFactory::create(&config).unwrap();
+        assert_eq!(engine.backend(), EngineBackend::Mock);
+        assert!(engine.as_wasmtime().is_none());
+
+        let id = ComponentId::new("acme", "echo", "v1");
+        let handle = engine.load_component(&id, b"\0asm").unwrap();
+        let msg = ComponentMessage::new(
+            id,
+            MessagePayload::new(b"ping".to_vec()),
+            MessageMetadata::default(),
+        );
+        let reply = engine.call_handle_message(&handle, &msg).unwrap();
+        assert_eq!(reply.unwrap().as_bytes(), b"ping");
+    }
+}
@@ -0,0 +1,326 @@
+//! Deterministic execution for replay and debugging.
+//!
+//! With a [`DeterministicConfig`] the engine runs components so that the
+//! same inputs produce bit-for-bit the same execution, which lets an
+//! incident be reproduced from recorded messages:
+//!
+//! - **Randomness** - the instance's WASI random generators are seeded
+//!   from the configured seed and the component ID instead of the host's
+//!   entropy, so they stay reproducible once `wasi:random` is linked.
+//! - **Clocks** - `wasi:clocks` read a virtual clock that starts at a fixed
+//!   instant and advances by a fixed tick on every read, regardless of how
+//!   long the host takes.
+//! - **Host functions** - `host-os` calls fail with `unavailable`, and
+//!   components importing `wasi:filesystem` are rejected at load, since
+//!   both observe host state that a replay cannot reproduce.
+//! - **Floating point** - NaN results are canonicalized and relaxed SIMD
+//!   uses its deterministic lowering, so results do not depend on the CPU.
+//!
+//! Fuel budgets and the `host-services` clock stubs are already fixed and
+//! need no change.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use airssys_wasm::runtime::deterministic::DeterministicConfig;
+//!
+//! let config = DeterministicConfig::from_host_toml(
+//!     "[runtime]\ndeterministic = true\ndeterministic_seed = 42\n",
+//! )
+//! .unwrap()
+//! .unwrap();
+//! assert_eq!(config.seed, 42);
+//! ```
+
+// Layer 1: Standard library imports
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use wasmtime::Config;
+use wasmtime_wasi::{Deterministic, HostMonotonicClock, HostWallClock, WasiCtxBuilder};
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::runtime::errors::WasmError;
+
+/// Filesystem interfaces, unavailable in deterministic mode.
+const WASI_FILESYSTEM_PREFIX: &str = "wasi:filesystem/";
+
+/// Bytes of random stream generated per instance before it repeats.
+const RANDOM_STREAM_BYTES: usize = 64 * 1024;
+
+/// Settings for deterministic execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeterministicConfig {
+    /// Seed for every random generator.
+    pub seed: u64,
+
+    /// Virtual wall-clock time of the first read, in milliseconds since
+    /// the Unix epoch.
+    pub epoch_ms: u64,
+
+    /// Amount the virtual clocks advance on every read.
+    pub tick: Duration,
+}
+
+impl Default for DeterministicConfig {
+    /// Seed 0, starting at the Unix epoch with a 1 ms tick.
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            epoch_ms: 0,
+            tick: Duration::from_millis(1),
+        }
+    }
+}
+
+impl DeterministicConfig {
+    /// Creates a configuration with `seed` and default clock settings.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the virtual wall-clock start time.
+    pub fn with_epoch_ms(mut self, epoch_ms: u64) -> Self {
+        self.epoch_ms = epoch_ms;
+        self
+    }
+
+    /// Sets how far the virtual clocks advance per read.
+    pub fn with_tick(mut self, tick: Duration) -> Self {
+        self.tick = tick;
+        self
+    }
+
+    /// Reads `deterministic`, `deterministic_seed`, `deterministic_epoch_ms`
+    /// and `deterministic_tick_us` from the `[runtime]` table of a
+    /// `Host.toml`.
+    ///
+    /// Returns `None` unless `deterministic = true`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::RuntimeError` if the TOML is malformed.
+    pub fn from_host_toml(source: &str) -> Result<Option<Self>, WasmError> {
+        #[derive(Deserialize, Default)]
+        struct HostFile {
+            #[serde(default)]
+            runtime: RuntimeSection,
+        }
+
+        #[derive(Deserialize, Default)]
+        struct RuntimeSection {
+            #[serde(default)]
+            deterministic: bool,
+            deterministic_seed: Option<u64>,
+            deterministic_epoch_ms: Option<u64>,
+            deterministic_tick_us: Option<u64>,
+        }
+
+        let file: HostFile = toml::from_str(source)
+            .map_err(|e| WasmError::RuntimeError(format!("Invalid host config: {}", e)))?;
+        let runtime = file.runtime;
+        if !runtime.deterministic {
+            return Ok(None);
+        }
+
+        let defaults = Self::default();
+        Ok(Some(Self {
+            seed: runtime.deterministic_seed.unwrap_or(defaults.seed),
+            epoch_ms: runtime.deterministic_epoch_ms.unwrap_or(defaults.epoch_ms),
+            tick: runtime
+                .deterministic_tick_us
+                .map(Duration::from_micros)
+                .unwrap_or(defaults.tick),
+        }))
+    }
+
+    /// Applies the engine settings that make compiled code deterministic.
+    pub fn apply_to_engine(&self, config: &mut Config) {
+        config.cranelift_nan_canonicalization(true);
+        config.relaxed_simd_deterministic(true);
+    }
+
+    /// Rejects imports that observe host state a replay cannot reproduce.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::InstantiationFailed` naming the first such import.
+    pub fn check_imports<'a>(
+        &self,
+        imports: impl IntoIterator<Item = &'a str>,
+    ) -> Result<(), WasmError> {
+        for import in imports {
+            if import.starts_with(WASI_FILESYSTEM_PREFIX) {
+                return Err(WasmError::InstantiationFailed(format!(
+                    "WASI import {} is not available in deterministic mode",
+                    import
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Installs seeded randomness and virtual clocks for an instance of
+    /// `id`.
+    ///
+    /// Each instance gets fresh clocks, so a replay starts from the same
+    /// readings as the original run.
+    pub fn apply_to_wasi(&self, builder: &mut WasiCtxBuilder, id: &ComponentId) {
+        builder
+            .secure_random(Deterministic::new(self.random_stream(id, b"secure")))
+            .insecure_random(Deterministic::new(self.random_stream(id, b"insecure")))
+            .insecure_random_seed(u128::from_le_bytes(self.block(id, b"seed", 0)))
+            .wall_clock(VirtualClock::new(
+                Duration::from_millis(self.epoch_ms),
+                self.tick,
+            ))
+            .monotonic_clock(VirtualClock::new(Duration::ZERO, self.tick));
+    }
+
+    /// Random bytes for one generator of `id`.
+    ///
+    /// SHA-256 in counter mode keyed by the seed, the component and the
+    /// generator, so the stream is stable across platforms and releases.
+    fn random_stream(&self, id: &ComponentId, generator: &[u8]) -> Vec<u8> {
+        let mut stream = Vec::with_capacity(RANDOM_STREAM_BYTES);
+        let mut counter = 0u64;
+        while stream.len() < RANDOM_STREAM_BYTES {
+            stream.extend_from_slice(&self.block(id, generator, counter));
+            counter += 1;
+        }
+        stream
+    }
+
+    fn block(&self, id: &ComponentId, generator: &[u8], counter: u64) -> [u8; 16] {
+        let digest = Sha256::new()
+            .chain_update(self.seed.to_le_bytes())
+            .chain_update(id.to_string_id().as_bytes())
+            .chain_update(generator)
+            .chain_update(counter.to_le_bytes())
+            .finalize();
+        let mut block = [0u8; 16];
+        block.copy_from_slice(&digest[..16]);
+        block
+    }
+}
+
+/// Clock that advances by a fixed tick on every read.
+///
+/// Serves as both wall clock (starting at the configured epoch) and
+/// monotonic clock (starting at zero).
+#[derive(Debug)]
+pub struct VirtualClock {
+    next_ns: AtomicU64,
+    tick_ns: u64,
+}
+
+impl VirtualClock {
+    /// Creates a clock whose first reading is `start`.
+    pub fn new(start: Duration, tick: Duration) -> Self {
+        Self {
+            next_ns: AtomicU64::new(saturating_nanos(start)),
+            tick_ns: saturating_nanos(tick),
+        }
+    }
+
+    fn read_ns(&self) -> u64 {
+        self.next_ns.fetch_add(self.tick_ns, Ordering::Relaxed)
+    }
+}
+
+impl HostWallClock for VirtualClock {
+    fn resolution(&self) -> Duration {
+        Duration::from_nanos(self.tick_ns.max(1))
+    }
+
+    fn now(&self) -> Duration {
+        Duration::from_nanos(self.read_ns())
+    }
+}
+
+impl HostMonotonicClock for VirtualClock {
+    fn resolution(&self) -> u64 {
+        self.tick_ns.max(1)
+    }
+
+    fn now(&self) -> u64 {
+        self.read_ns()
+    }
+}
+
+fn saturating_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id() -> ComponentId {
+        ComponentId::new("acme", "replay", "v1")
+    }
+
+    #[test]
+    fn test_from_host_toml() {
+        assert_eq!(DeterministicConfig::from_host_toml("").unwrap(), None);
+        assert_eq!(
+            DeterministicConfig::from_host_toml("[runtime]\ndeterministic = false\n").unwrap(),
+            None
+        );
+
+        let config = DeterministicConfig::from_host_toml(
+            "[runtime]\ndeterministic = true\ndeterministic_epoch_ms = 1000\ndeterministic_tick_us = 10\n",
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(config.seed, 0);
+        assert_eq!(config.epoch_ms, 1000);
+        assert_eq!(config.tick, Duration::from_micros(10));
+
+        assert!(DeterministicConfig::from_host_toml("[runtime]\ndeterministic = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_virtual_clock_advances_per_read() {
+        let clock = VirtualClock::new(Duration::from_secs(5), Duration::from_millis(2));
+        assert_eq!(HostWallClock::now(&clock), Duration::from_secs(5));
+        assert_eq!(HostWallClock::now(&clock), Duration::from_millis(5002));
+        assert_eq!(HostMonotonicClock::now(&clock), 5_004_000_000);
+        assert_eq!(HostMonotonicClock::resolution(&clock), 2_000_000);
+    }
+
+    #[test]
+    fn test_random_stream_depends_on_seed_and_component() {
+        let config = DeterministicConfig::new(7);
+        let first = config.random_stream(&id(), b"secure");
+        assert_eq!(first.len(), RANDOM_STREAM_BYTES);
+        assert_eq!(first, config.random_stream(&id(), b"secure"));
+
+        assert_ne!(first, config.random_stream(&id(), b"insecure"));
+        assert_ne!(
+            first,
+            DeterministicConfig::new(8).random_stream(&id(), b"secure")
+        );
+        let other = ComponentId::new("acme", "replay", "v2");
+        assert_ne!(first, config.random_stream(&other, b"secure"));
+    }
+
+    #[test]
+    fn test_filesystem_imports_rejected() {
+        let config = DeterministicConfig::default();
+        assert!(config
+            .check_imports(["wasi:io/streams@0.2.0", "wasi:clocks/wall-clock@0.2.0"])
+            .is_ok());
+        assert!(matches!(
+            config.check_imports(["wasi:filesystem/types@0.2.0"]),
+            Err(WasmError::InstantiationFailed(_))
+        ));
+    }
+}
@@ -2,21 +2,37 @@
 
 // Layer 1: Standard library imports
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::sync::{Arc, RwLock};
+use std::time::Duration;
 
 // Layer 2: Third-party crate imports
 use wasmtime::component::{Component, Linker};
-use wasmtime::{Config, Engine, Store, StoreLimits, StoreLimitsBuilder};
+use wasmtime::{Config, Engine, ResourceLimiter, Store, StoreLimits, StoreLimitsBuilder};
 
 // Layer 3: Internal module imports
+use crate::core::bridge::traits::OsBridge;
 use crate::core::component::handle::ComponentHandle;
 use crate::core::component::id::ComponentId;
 use crate::core::component::message::{ComponentMessage, MessagePayload};
+use crate::core::config::values::ConfigValues;
+use crate::core::management::elevation::ElevationRequests;
+use crate::core::management::lockdown::HostLockdown;
+use crate::core::management::metrics::ComponentMetrics;
 use crate::core::messaging::traits::MessageRouter;
 use crate::core::runtime::errors::WasmError;
-use crate::core::runtime::traits::RuntimeEngine;
+use crate::core::runtime::limits::ResourceLimits;
+use crate::core::runtime::snapshot::ComponentSnapshot;
+use crate::core::runtime::traits::{BlockingRunner, RuntimeEngine};
+use crate::core::runtime::usage::ResourceUsage;
 use crate::runtime::host_functions::marker_traits::register_host_functions;
 
+use super::async_host::{add_wasi_to_linker, BlockingPools, WasiGrants, WasiState};
+use super::deterministic::DeterministicConfig;
+use super::kv_store::{GuestStorage, KvStore, StorageGrants};
+use super::limiter::apply_limits_to_store;
+use super::loader::CompiledArtifactCache;
+use super::pool::{InstancePool, PoolStats};
 use super::store::StoreManager;
 
 /// Convert wasmtime errors to WasmError
@@ -32,9 +48,8 @@ impl From<wasmtime::Error> for WasmError {
 
 /// Host state passed to WASM components
 ///
-/// NOTE: ResourceTable is intentionally omitted here because it is not Sync.
-/// It will be added in WASM-TASK-033 (StoreManager) when needed,
-/// using a different approach to satisfy Sync requirements.
+/// NOTE: The WASI `ResourceTable` is not Sync; it lives inside
+/// [`WasiState`], which keeps `HostState` Sync.
 pub struct HostState {
     /// The component ID for this instance
     pub component_id: ComponentId,
@@ -43,6 +58,62 @@ pub struct HostState {
     pub message_router: Option<Arc<dyn MessageRouter>>,
     /// Store limits for memory/table enforcement
     pub store_limits: StoreLimits,
+    /// Linear memory currently committed by the instance, in bytes.
+    ///
+    /// Updated by the `ResourceLimiter` impl below on every permitted
+    /// growth; used to report per-call memory deltas.
+    pub memory_bytes: usize,
+    /// Where `request-capability` calls are recorded for operator review
+    pub elevation_requests: Option<Arc<ElevationRequests>>,
+    /// Where `host-metrics` calls are recorded; `None` makes it unavailable
+    pub metrics: Option<Arc<ComponentMetrics>>,
+    /// Bridge serving the `host-os` interface; `None` makes it unavailable
+    pub os_bridge: Option<Arc<dyn OsBridge>>,
+    /// Namespace serving the `storage` interface; `None` denies every call
+    pub storage: Option<GuestStorage>,
+    /// WASI Preview 2 context and resources of this instance
+    pub wasi: WasiState,
+}
+
+/// Enforces `store_limits` while tracking committed linear memory.
+///
+/// Install with `store.limiter(|state| state)`.
+impl ResourceLimiter for HostState {
+    fn memory_growing(
+        &mut self,
+        current: usize,
+        desired: usize,
+        maximum: Option<usize>,
+    ) -> wasmtime::Result<bool> {
+        let allowed = self
+            .store_limits
+            .memory_growing(current, desired, maximum)?;
+        if allowed {
+            self.memory_bytes = self.memory_bytes.saturating_add(desired - current);
+        }
+        Ok(allowed)
+    }
+
+    fn table_growing(
+        &mut self,
+        current: u32,
+        desired: u32,
+        maximum: Option<u32>,
+    ) -> wasmtime::Result<bool> {
+        self.store_limits.table_growing(current, desired, maximum)
+    }
+
+    fn instances(&self) -> usize {
+        self.store_limits.instances()
+    }
+
+    fn tables(&self) -> usize {
+        self.store_limits.tables()
+    }
+
+    fn memories(&self) -> usize {
+        self.store_limits.memories()
+    }
 }
 
 /// WASM runtime engine using wasmtime Component Model
@@ -51,15 +122,41 @@ pub struct WasmtimeEngine {
     linker: Linker<HostState>,
     stores: RwLock<HashMap<u64, StoreManager>>,
     next_handle_id: RwLock<u64>,
+    code_cache: Option<Arc<CompiledArtifactCache>>,
+    elevation_requests: Option<Arc<ElevationRequests>>,
+    metrics: Option<Arc<ComponentMetrics>>,
+    os_bridge: Option<Arc<dyn OsBridge>>,
+    kv_store: Option<Arc<KvStore>>,
+    lockdown: Option<Arc<HostLockdown>>,
+    storage_grants: RwLock<HashMap<ComponentId, StorageGrants>>,
+    resource_limits: Option<ResourceLimits>,
+    wasi_grants: RwLock<HashMap<ComponentId, WasiGrants>>,
+    deterministic: Option<DeterministicConfig>,
+    pool: InstancePool,
+    blocking_pools: Arc<BlockingPools>,
 }
 
 impl WasmtimeEngine {
     /// Create a new WasmtimeEngine
     pub fn new() -> Result<Self, WasmError> {
+        Self::build(None)
+    }
+
+    /// Create an engine whose executions can be replayed bit-for-bit
+    ///
+    /// See [`DeterministicConfig`] for what changes.
+    pub fn new_deterministic(config: DeterministicConfig) -> Result<Self, WasmError> {
+        Self::build(Some(config))
+    }
+
+    fn build(deterministic: Option<DeterministicConfig>) -> Result<Self, WasmError> {
         let mut config = Config::new();
         config.wasm_component_model(true);
         config.async_support(true);
         config.consume_fuel(true);
+        if let Some(deterministic) = &deterministic {
+            deterministic.apply_to_engine(&mut config);
+        }
 
         let engine =
             Engine::new(&config).map_err(|e| WasmError::InstantiationFailed(e.to_string()))?;
@@ -69,15 +166,170 @@ impl WasmtimeEngine {
         // Register host functions
         register_host_functions(&mut linker)
             .map_err(|e| WasmError::InstantiationFailed(e.to_string()))?;
+        add_wasi_to_linker(&mut linker)
+            .map_err(|e| WasmError::InstantiationFailed(e.to_string()))?;
 
         Ok(Self {
             engine,
             linker,
             stores: RwLock::new(HashMap::new()),
             next_handle_id: RwLock::new(1),
+            code_cache: None,
+            elevation_requests: None,
+            metrics: None,
+            os_bridge: None,
+            kv_store: None,
+            lockdown: None,
+            storage_grants: RwLock::new(HashMap::new()),
+            resource_limits: None,
+            wasi_grants: RwLock::new(HashMap::new()),
+            deterministic,
+            pool: InstancePool::new(),
+            blocking_pools: Arc::new(BlockingPools::default()),
         })
     }
 
+    /// Attach a compiled-artifact cache so restarts skip recompilation
+    pub fn with_code_cache(mut self, cache: Arc<CompiledArtifactCache>) -> Self {
+        self.code_cache = Some(cache);
+        self
+    }
+
+    /// Record components' capability elevation requests in `requests`
+    pub fn with_elevation_requests(mut self, requests: Arc<ElevationRequests>) -> Self {
+        self.elevation_requests = Some(requests);
+        self
+    }
+
+    /// Record metrics emitted by components in `metrics`
+    pub fn with_component_metrics(mut self, metrics: Arc<ComponentMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Serve the `host-os` interface of loaded components with `bridge`
+    pub fn with_os_bridge(mut self, bridge: Arc<dyn OsBridge>) -> Self {
+        self.os_bridge = Some(bridge);
+        self
+    }
+
+    /// Serve the `storage` interface of loaded components from `store`
+    pub fn with_kv_store(mut self, store: Arc<KvStore>) -> Self {
+        self.kv_store = Some(store);
+        self
+    }
+
+    /// Refuse components' storage writes while `lockdown` is engaged
+    ///
+    /// Share the switch the coordinator engages (`set_lockdown`).
+    pub fn with_lockdown(mut self, lockdown: Arc<HostLockdown>) -> Self {
+        self.lockdown = Some(lockdown);
+        self
+    }
+
+    /// Apply `limits` to every instance and to snapshots
+    pub fn with_resource_limits(mut self, limits: ResourceLimits) -> Self {
+        self.resource_limits = Some(limits);
+        self
+    }
+
+    /// Run components' blocking host work in `pools` instead of the
+    /// default pools of `DEFAULT_BLOCKING_LIMIT` slots per component
+    pub fn with_blocking_pools(mut self, pools: Arc<BlockingPools>) -> Self {
+        self.blocking_pools = pools;
+        self
+    }
+
+    /// Set the WASI access of `id`, applied to instances created afterwards
+    ///
+    /// Components without grants may only use `wasi:io`.
+    pub fn grant_wasi(&self, id: ComponentId, grants: WasiGrants) {
+        self.wasi_grants.write().unwrap().insert(id, grants);
+    }
+
+    /// Set the storage access of `id`, applied to instances created afterwards
+    ///
+    /// Components without grants get no storage.
+    pub fn grant_storage(&self, id: ComponentId, grants: StorageGrants) {
+        self.storage_grants.write().unwrap().insert(id, grants);
+    }
+
+    /// Get the deterministic execution settings, if enabled
+    pub fn deterministic(&self) -> Option<&DeterministicConfig> {
+        self.deterministic.as_ref()
+    }
+
+    /// Get the compiled-artifact cache, if configured
+    pub fn code_cache(&self) -> Option<&Arc<CompiledArtifactCache>> {
+        self.code_cache.as_ref()
+    }
+
+    /// Warm instance pool counters
+    pub fn pool_stats(&self) -> PoolStats {
+        self.pool.stats()
+    }
+
+    /// Get the per-component pools for blocking host work
+    pub fn blocking_pools(&self) -> &Arc<BlockingPools> {
+        &self.blocking_pools
+    }
+
+    /// Hash of the engine settings that affect compiled code
+    ///
+    /// Artifacts compiled under a different hash are incompatible.
+    pub fn config_hash(&self) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.engine
+            .precompile_compatibility_hash()
+            .hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Compile a component, consulting the code cache when configured
+    fn compile_component(&self, bytes: &[u8]) -> Result<Component, WasmError> {
+        let Some(cache) = &self.code_cache else {
+            return Component::from_binary(&self.engine, bytes)
+                .map_err(|e| WasmError::InstantiationFailed(e.to_string()));
+        };
+
+        let digest = CompiledArtifactCache::module_digest(bytes);
+        let config_hash = self.config_hash();
+
+        if let Some(artifact) = cache.load(&digest, config_hash) {
+            // SAFETY: the artifact was produced by `Component::serialize` on an
+            // engine with the same compatibility hash. Its checksum only proves
+            // it was written by this host if the cache holds a secret;
+            // otherwise the cache directory must be writable by the host alone
+            // (see the `CompiledArtifactCache` trust notes).
+            #[allow(unsafe_code)]
+            let cached = unsafe { Component::deserialize(&self.engine, &artifact) };
+            match cached {
+                Ok(component) => return Ok(component),
+                Err(e) => {
+                    tracing::warn!(digest = %digest, error = %e, "discarding unusable cached artifact");
+                    cache.invalidate(&digest, config_hash);
+                }
+            }
+        }
+
+        let component = Component::from_binary(&self.engine, bytes)
+            .map_err(|e| WasmError::InstantiationFailed(e.to_string()))?;
+
+        // A cache write failure only costs the next restart a recompilation
+        match component.serialize() {
+            Ok(artifact) => {
+                if let Err(e) = cache.store(&digest, config_hash, &artifact) {
+                    tracing::warn!(digest = %digest, error = %e, "failed to persist compiled artifact");
+                }
+            }
+            Err(e) => {
+                tracing::warn!(digest = %digest, error = %e, "failed to serialize compiled component");
+            }
+        }
+
+        Ok(component)
+    }
+
     /// Get the wasmtime Engine
     pub fn engine(&self) -> &Engine {
         &self.engine
@@ -93,27 +345,67 @@ impl WasmtimeEngine {
         &mut self.linker
     }
 
-    fn allocate_handle_id(&self) -> u64 {
-        let mut id = self.next_handle_id.write().unwrap();
-        let current = *id;
-        *id += 1;
-        current
+    /// Open the storage namespace granted to `id`, if any
+    fn open_storage(&self, id: &ComponentId) -> Result<Option<GuestStorage>, WasmError> {
+        let Some(store) = &self.kv_store else {
+            return Ok(None);
+        };
+        let Some(grants) = self.storage_grants.read().unwrap().get(id).cloned() else {
+            return Ok(None);
+        };
+        GuestStorage::open(store, id, grants)
+            .map(|storage| match &self.lockdown {
+                Some(lockdown) => Some(storage.with_lockdown(Arc::clone(lockdown))),
+                None => Some(storage),
+            })
+            .map_err(|e| WasmError::InstantiationFailed(e.to_string()))
     }
-}
 
-impl RuntimeEngine for WasmtimeEngine {
-    fn load_component(&self, id: &ComponentId, bytes: &[u8]) -> Result<ComponentHandle, WasmError> {
-        let component = Component::from_binary(&self.engine, bytes)
-            .map_err(|e| WasmError::InstantiationFailed(e.to_string()))?;
+    /// Create and initialize a fresh store for `id`
+    fn instantiate(
+        &self,
+        id: &ComponentId,
+        component: Component,
+    ) -> Result<StoreManager, WasmError> {
+        let grants = self
+            .wasi_grants
+            .read()
+            .unwrap()
+            .get(id)
+            .cloned()
+            .unwrap_or_default();
+        let component_type = component.component_type();
+        let imports = || component_type.imports(&self.engine).map(|(name, _)| name);
+        grants.check_imports(imports())?;
+
+        // Deterministic instances see no host OS, no persistent storage,
+        // and virtual WASI clocks
+        let (os_bridge, storage, wasi) = match &self.deterministic {
+            Some(config) => {
+                config.check_imports(imports())?;
+                (None, None, WasiState::deterministic(&grants, config, id)?)
+            }
+            None => (
+                self.os_bridge.clone(),
+                self.open_storage(id)?,
+                WasiState::new(&grants)?,
+            ),
+        };
 
         let host_state = HostState {
             component_id: id.clone(),
             message_router: None,
             store_limits: StoreLimitsBuilder::new().build(),
+            memory_bytes: 0,
+            elevation_requests: self.elevation_requests.clone(),
+            metrics: self.metrics.clone(),
+            os_bridge,
+            storage,
+            wasi,
         };
 
         let mut store = Store::new(&self.engine, host_state);
-        store.limiter(|state| &mut state.store_limits);
+        store.limiter(|state| state);
 
         // Add fuel for WASM execution (consume_fuel is enabled in engine config).
         // Default fuel budget allows substantial execution; future phases will
@@ -121,6 +413,9 @@ impl RuntimeEngine for WasmtimeEngine {
         store
             .set_fuel(1_000_000)
             .map_err(|e| WasmError::RuntimeError(e.to_string()))?;
+        if let Some(limits) = &self.resource_limits {
+            apply_limits_to_store(&mut store, limits)?;
+        }
 
         let mut store_manager = StoreManager::new(store, component);
 
@@ -130,6 +425,33 @@ impl RuntimeEngine for WasmtimeEngine {
         // bridge from the synchronous RuntimeEngine trait.
         futures::executor::block_on(store_manager.initialize(&self.linker))?;
 
+        Ok(store_manager)
+    }
+
+    /// Reject snapshots larger than the configured memory limit
+    fn check_snapshot(&self, snapshot: &ComponentSnapshot) -> Result<(), WasmError> {
+        match &self.resource_limits {
+            Some(limits) => snapshot.check_limits(limits),
+            None => Ok(()),
+        }
+    }
+
+    fn allocate_handle_id(&self) -> u64 {
+        let mut id = self.next_handle_id.write().unwrap();
+        let current = *id;
+        *id += 1;
+        current
+    }
+}
+
+impl RuntimeEngine for WasmtimeEngine {
+    fn load_component(&self, id: &ComponentId, bytes: &[u8]) -> Result<ComponentHandle, WasmError> {
+        let digest = CompiledArtifactCache::module_digest(bytes);
+        let store_manager = match self.pool.take(id, &digest) {
+            Some(warm) => warm,
+            None => self.instantiate(id, self.compile_component(bytes)?)?,
+        };
+
         let handle_id = self.allocate_handle_id();
 
         {
@@ -146,6 +468,29 @@ impl RuntimeEngine for WasmtimeEngine {
         Ok(())
     }
 
+    fn prewarm(&self, id: &ComponentId, bytes: &[u8], count: usize) -> Result<usize, WasmError> {
+        let digest = CompiledArtifactCache::module_digest(bytes);
+        if count == 0 {
+            self.pool.remove(id);
+            return Ok(0);
+        }
+        let component = match self.pool.deficit(id) {
+            Some((component, pooled_digest, _)) if pooled_digest == digest => component,
+            _ => self.compile_component(bytes)?,
+        };
+        self.pool.set_target(id, &digest, &component, count);
+
+        // Instantiate outside the pool lock; concurrent loads keep taking
+        let missing = self.pool.deficit(id).map_or(0, |(_, _, missing)| missing);
+        for _ in 0..missing {
+            let store_manager = self.instantiate(id, component.clone())?;
+            if !self.pool.put(id, &digest, store_manager) {
+                break;
+            }
+        }
+        Ok(self.pool.ready(id))
+    }
+
     fn call_handle_message(
         &self,
         handle: &ComponentHandle,
@@ -160,6 +505,20 @@ impl RuntimeEngine for WasmtimeEngine {
         store_manager.call_handle_message(msg)
     }
 
+    fn call_handle_message_with_usage(
+        &self,
+        handle: &ComponentHandle,
+        msg: &ComponentMessage,
+    ) -> Result<(Option<MessagePayload>, ResourceUsage), WasmError> {
+        let mut stores = self.stores.write().unwrap();
+
+        let store_manager = stores
+            .get_mut(&handle.handle_id())
+            .ok_or_else(|| WasmError::ComponentNotFound(handle.id().to_string()))?;
+
+        store_manager.call_handle_message_with_usage(msg)
+    }
+
     fn call_handle_callback(
         &self,
         handle: &ComponentHandle,
@@ -173,6 +532,100 @@ impl RuntimeEngine for WasmtimeEngine {
 
         store_manager.call_handle_callback(msg)
     }
+
+    fn call_reconfigure(
+        &self,
+        handle: &ComponentHandle,
+        values: &ConfigValues,
+    ) -> Result<(), WasmError> {
+        let mut stores = self.stores.write().unwrap();
+
+        let store_manager = stores
+            .get_mut(&handle.handle_id())
+            .ok_or_else(|| WasmError::ComponentNotFound(handle.id().to_string()))?;
+
+        store_manager.call_reconfigure(values)
+    }
+
+    fn call_prepare_shutdown(
+        &self,
+        handle: &ComponentHandle,
+        deadline: Duration,
+    ) -> Result<(), WasmError> {
+        let mut stores = self.stores.write().unwrap();
+
+        let store_manager = stores
+            .get_mut(&handle.handle_id())
+            .ok_or_else(|| WasmError::ComponentNotFound(handle.id().to_string()))?;
+
+        store_manager.call_prepare_shutdown(deadline)
+    }
+
+    fn snapshot(&self, handle: &ComponentHandle) -> Result<ComponentSnapshot, WasmError> {
+        let mut stores = self.stores.write().unwrap();
+
+        let store_manager = stores
+            .get_mut(&handle.handle_id())
+            .ok_or_else(|| WasmError::ComponentNotFound(handle.id().to_string()))?;
+
+        let state = store_manager.call_snapshot()?;
+        let memory_bytes = store_manager.store().data().memory_bytes as u64;
+        let snapshot = ComponentSnapshot::new(handle.id().clone(), state, memory_bytes);
+        self.check_snapshot(&snapshot)?;
+        Ok(snapshot)
+    }
+
+    fn restore(
+        &self,
+        handle: &ComponentHandle,
+        snapshot: &ComponentSnapshot,
+    ) -> Result<(), WasmError> {
+        if snapshot.component_id != *handle.id() {
+            return Err(WasmError::RuntimeError(format!(
+                "Snapshot of {} cannot be restored into {}",
+                snapshot.component_id,
+                handle.id()
+            )));
+        }
+        self.check_snapshot(snapshot)?;
+
+        let mut stores = self.stores.write().unwrap();
+
+        let store_manager = stores
+            .get_mut(&handle.handle_id())
+            .ok_or_else(|| WasmError::ComponentNotFound(handle.id().to_string()))?;
+
+        store_manager.call_restore(&snapshot.state)
+    }
+
+    /// Adds approved `storage` elevations to the component's storage
+    /// grants and drops its pooled instances, which were created without
+    /// them. Other classes are left to the security validator.
+    fn grant_elevated(&self, id: &ComponentId, capability: &str) -> Result<(), WasmError> {
+        let Some(rest) = capability.strip_prefix("storage:") else {
+            return Ok(());
+        };
+        let unsupported =
+            || WasmError::ConfigRejected(format!("unsupported storage elevation '{capability}'"));
+        let (action, pattern) = rest.split_once(':').ok_or_else(unsupported)?;
+        {
+            let mut all = self.storage_grants.write().unwrap();
+            let grants = all.entry(id.clone()).or_default();
+            match action {
+                "read" => grants.can_read_keys.push(pattern.to_string()),
+                // Delete requires write
+                "write" | "delete" => grants.can_write_keys.push(pattern.to_string()),
+                _ => return Err(unsupported()),
+            }
+        }
+        self.pool.remove(id);
+        Ok(())
+    }
+
+    fn blocking_runner(&self) -> Option<Arc<dyn BlockingRunner>> {
+        let pools: Arc<dyn BlockingRunner> = self.blocking_pools.clone();
+        Some(pools)
+    }
 }
 
 #[cfg(test)]
@@ -231,6 +684,188 @@ mod tests {
 
         let result = engine.call_handle_message(&handle, &msg);
         assert!(matches!(result, Err(WasmError::ComponentNotFound(_))));
+
+        let result = engine.call_handle_message_with_usage(&handle, &msg);
+        assert!(matches!(result, Err(WasmError::ComponentNotFound(_))));
+    }
+
+    #[test]
+    fn test_snapshot_checks_target_and_limits() {
+        let engine = WasmtimeEngine::new()
+            .unwrap()
+            .with_resource_limits(ResourceLimits {
+                max_memory_bytes: 1024,
+                ..ResourceLimits::default()
+            });
+        let component_id = ComponentId::new("test", "counter", "0");
+        let handle = ComponentHandle::new(component_id.clone(), 999);
+
+        assert!(matches!(
+            engine.snapshot(&handle),
+            Err(WasmError::ComponentNotFound(_))
+        ));
+
+        // Another component's state is never restored
+        let other = ComponentSnapshot::new(ComponentId::new("test", "other", "0"), vec![1], 0);
+        assert!(matches!(
+            engine.restore(&handle, &other),
+            Err(WasmError::RuntimeError(_))
+        ));
+
+        let oversized = ComponentSnapshot::new(component_id, vec![0; 2048], 0);
+        assert!(matches!(
+            engine.restore(&handle, &oversized),
+            Err(WasmError::ResourceLimitExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn test_host_state_limiter_tracks_memory_growth() {
+        use wasmtime::{Instance, Module};
+
+        let engine = Engine::default();
+        let bytes = wat::parse_str(
+            r#"(module
+                (memory 1)
+                (func (export "grow") (result i32)
+                    (memory.grow (i32.const 2))))"#,
+        )
+        .unwrap();
+        let module = Module::new(&engine, bytes).unwrap();
+
+        let host_state = HostState {
+            component_id: ComponentId::new("test", "comp", "0"),
+            message_router: None,
+            store_limits: StoreLimitsBuilder::new().build(),
+            memory_bytes: 0,
+            elevation_requests: None,
+            metrics: None,
+            os_bridge: None,
+            storage: None,
+            wasi: WasiState::default(),
+        };
+        let mut store = Store::new(&engine, host_state);
+        store.limiter(|state| state);
+
+        let instance = Instance::new(&mut store, &module, &[]).unwrap();
+        assert_eq!(store.data().memory_bytes, 65_536);
+
+        let grow = instance
+            .get_typed_func::<(), i32>(&mut store, "grow")
+            .unwrap();
+        grow.call(&mut store, ()).unwrap();
+        assert_eq!(store.data().memory_bytes, 3 * 65_536);
+    }
+
+    #[test]
+    fn test_wasi_clock_import_requires_grant() {
+        let engine = WasmtimeEngine::new().unwrap();
+        let id = ComponentId::new("test", "clock-reader", "0");
+        let bytes = wat::parse_str(
+            r#"(component
+                (import "wasi:clocks/monotonic-clock@0.2.0" (instance
+                    (export "now" (func (result u64))))))"#,
+        )
+        .unwrap();
+        let component = engine.compile_component(&bytes).unwrap();
+
+        let denied = engine.instantiate(&id, component.clone()).err().unwrap();
+        assert!(denied.to_string().contains("monotonic = true"));
+
+        // Once granted, the import check passes and the clock links
+        engine.grant_wasi(id.clone(), WasiGrants::default().with_clocks());
+        if let Err(e) = engine.instantiate(&id, component) {
+            assert!(!e.to_string().contains("requires"), "{e}");
+            assert!(!e.to_string().contains("monotonic-clock"), "{e}");
+        }
+    }
+
+    #[test]
+    fn test_deterministic_engine_rejects_filesystem() {
+        let engine = WasmtimeEngine::new_deterministic(DeterministicConfig::new(1)).unwrap();
+        let id = ComponentId::new("test", "fs-reader", "0");
+        engine.grant_wasi(
+            id.clone(),
+            WasiGrants::default().with_preopen(std::env::temp_dir(), false),
+        );
+        let bytes = wat::parse_str(
+            r#"(component
+                (import "wasi:filesystem/preopens@0.2.0" (instance)))"#,
+        )
+        .unwrap();
+        let component = engine.compile_component(&bytes).unwrap();
+
+        let denied = engine.instantiate(&id, component).err().unwrap();
+        assert!(
+            denied.to_string().contains("deterministic mode"),
+            "{denied}"
+        );
+    }
+
+    #[test]
+    fn test_code_cache_reused_across_engines() {
+        use crate::runtime::loader::CodeCacheConfig;
+
+        let dir = std::env::temp_dir().join(format!("airssys-code-cache-{}", uuid::Uuid::new_v4()));
+        let cache = Arc::new(CompiledArtifactCache::new(CodeCacheConfig::new(&dir)).unwrap());
+        let bytes = wat::parse_str("(component)").unwrap();
+
+        let first = WasmtimeEngine::new()
+            .unwrap()
+            .with_code_cache(Arc::clone(&cache));
+        assert!(first.compile_component(&bytes).is_ok());
+        assert_eq!(cache.stats().stores, 1);
+
+        // A restarted host loads the artifact instead of recompiling
+        let second = WasmtimeEngine::new()
+            .unwrap()
+            .with_code_cache(Arc::clone(&cache));
+        assert_eq!(first.config_hash(), second.config_hash());
+        assert!(second.compile_component(&bytes).is_ok());
+        assert_eq!(cache.stats().hits, 1);
+        assert_eq!(cache.stats().stores, 1);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_prewarm_requires_lifecycle_export() {
+        let engine = WasmtimeEngine::new().unwrap();
+        let id = ComponentId::new("acme", "cache", "v1");
+        let bytes = wat::parse_str("(component)").unwrap();
+
+        assert!(matches!(
+            engine.prewarm(&id, &bytes, 2),
+            Err(WasmError::ExportNotFound(_))
+        ));
+        assert_eq!(engine.prewarm(&id, &bytes, 0).unwrap(), 0);
+        assert_eq!(engine.pool_stats().ready, 0);
+    }
+
+    #[test]
+    fn test_storage_elevation_extends_storage_grants() {
+        let engine = WasmtimeEngine::new().unwrap();
+        let id = ComponentId::new("acme", "cache", "v1");
+
+        engine.grant_elevated(&id, "network:outbound:*").unwrap();
+        assert!(engine.storage_grants.read().unwrap().get(&id).is_none());
+
+        engine.grant_elevated(&id, "storage:write:cache/*").unwrap();
+        engine.grant_elevated(&id, "storage:read:cfg/*").unwrap();
+        let grants = engine
+            .storage_grants
+            .read()
+            .unwrap()
+            .get(&id)
+            .cloned()
+            .unwrap();
+        assert_eq!(grants.can_write_keys, vec!["cache/*".to_string()]);
+        assert_eq!(grants.can_read_keys, vec!["cfg/*".to_string()]);
+
+        assert!(matches!(
+            engine.grant_elevated(&id, "storage:own:*"),
+            Err(WasmError::ConfigRejected(_))
+        ));
     }
 
     #[test]
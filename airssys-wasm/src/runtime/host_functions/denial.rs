@@ -0,0 +1,80 @@
+//! Conversion of host-side security denials into WIT error types.
+//!
+//! Host functions that fail a capability check return the structured
+//! `permission-denial` record instead of an opaque string, so guests can see
+//! which capability was required and which pattern failed to match.
+
+// Layer 1: Standard library imports
+// (none)
+
+// Layer 2: Third-party crate imports
+// (none)
+
+// Layer 3: Internal module imports
+use crate::core::security::errors::{
+    PermissionDenial as CoreDenial, SecurityError as CoreSecurityError,
+};
+
+// WIT-bindgen generated bindings
+use crate::airssys::core::errors::{MessagingError, PermissionDenial, StorageError};
+
+impl From<CoreDenial> for PermissionDenial {
+    fn from(denial: CoreDenial) -> Self {
+        Self {
+            capability: denial.capability,
+            action: denial.action,
+            pattern: denial.pattern,
+            reason: denial.reason,
+        }
+    }
+}
+
+impl From<CoreSecurityError> for MessagingError {
+    fn from(err: CoreSecurityError) -> Self {
+        match err {
+            CoreSecurityError::Denied(denial) => Self::PermissionDenied(denial.into()),
+            other => Self::DeliveryFailed(other.to_string()),
+        }
+    }
+}
+
+impl From<CoreSecurityError> for StorageError {
+    fn from(err: CoreSecurityError) -> Self {
+        match err {
+            CoreSecurityError::Denied(denial) => Self::PermissionDenied(denial.into()),
+            other => Self::IoError(other.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn denial() -> CoreDenial {
+        CoreDenial::new("messaging", "send", "org/forbidden/0", "no grant")
+    }
+
+    #[test]
+    fn test_denied_maps_to_structured_messaging_error() {
+        let err: MessagingError = CoreSecurityError::Denied(denial()).into();
+        match err {
+            MessagingError::PermissionDenied(d) => {
+                assert_eq!(d.capability, "messaging");
+                assert_eq!(d.action, "send");
+                assert_eq!(d.pattern, "org/forbidden/0");
+                assert_eq!(d.reason, "no grant");
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_denied_maps_to_structured_storage_error() {
+        let err: StorageError = CoreSecurityError::Denied(denial()).into();
+        assert!(matches!(err, StorageError::PermissionDenied(_)));
+
+        let err: StorageError = CoreSecurityError::PolicyViolation("x".to_string()).into();
+        assert!(matches!(err, StorageError::IoError(_)));
+    }
+}
@@ -0,0 +1,250 @@
+//! Host function implementations for namespace-scoped storage operations.
+//!
+//! This module implements the `host_storage::Host` trait generated by
+//! `wasmtime::component::bindgen!`. It serves the same component storage
+//! area as the `storage` interface, but every call names a namespace inside
+//! it: key `k` in namespace `n` is stored, and checked against the
+//! component's `can_read_keys` / `can_write_keys` grants, as `n/k`.
+//!
+//! # Functions
+//!
+//! - `get()` - Retrieve a value by namespace and key
+//! - `set()` - Store a value under a namespace and key
+//! - `delete()` - Remove a key from a namespace
+//! - `list_keys()` - List readable keys in a namespace (optionally by prefix)
+//!
+//! Reads and writes join the transaction opened by `storage.begin`, if any.
+
+// Layer 1: Standard library imports
+// (none)
+
+// Layer 2: Third-party crate imports
+// (none)
+
+// Layer 3: Internal module imports
+use crate::core::storage::traits::ComponentStorage;
+use crate::core::storage::value::StorageValue;
+use crate::runtime::engine::HostState;
+use crate::runtime::kv_store::GuestStorage;
+
+// WIT-bindgen generated bindings
+use crate::airssys::core::errors::StorageError;
+use crate::airssys::core::host_storage;
+
+/// Separator between a namespace and the keys inside it.
+const NAMESPACE_SEPARATOR: char = '/';
+
+/// The prefix every key in `namespace` is stored under.
+///
+/// Namespaces may not contain the separator or pattern characters, so a
+/// guest cannot widen a namespace into its siblings.
+fn namespace_prefix(namespace: &str) -> Result<String, StorageError> {
+    if namespace.is_empty() {
+        return Err(StorageError::InvalidKey("namespace is empty".to_string()));
+    }
+    if let Some(c) = namespace
+        .chars()
+        .find(|c| *c == NAMESPACE_SEPARATOR || matches!(c, '*' | '!'))
+    {
+        return Err(StorageError::InvalidKey(format!(
+            "namespace '{namespace}' contains '{c}'"
+        )));
+    }
+    Ok(format!("{namespace}{NAMESPACE_SEPARATOR}"))
+}
+
+/// The key `key` in `namespace` is stored under.
+fn scoped_key(namespace: &str, key: &str) -> Result<String, StorageError> {
+    let prefix = namespace_prefix(namespace)?;
+    if key.is_empty() {
+        return Err(StorageError::InvalidKey("key is empty".to_string()));
+    }
+    Ok(prefix + key)
+}
+
+fn get(
+    storage: &GuestStorage,
+    namespace: &str,
+    key: &str,
+) -> Result<Option<Vec<u8>>, StorageError> {
+    let key = scoped_key(namespace, key)?;
+    storage.authorize_read(&key)?;
+    Ok(storage.get(&key)?.map(|value| value.as_bytes().to_vec()))
+}
+
+fn set(
+    storage: &GuestStorage,
+    namespace: &str,
+    key: &str,
+    value: Vec<u8>,
+) -> Result<(), StorageError> {
+    let key = scoped_key(namespace, key)?;
+    storage.authorize_write(&key)?;
+    Ok(storage.set(&key, StorageValue::new(value))?)
+}
+
+fn delete(storage: &GuestStorage, namespace: &str, key: &str) -> Result<(), StorageError> {
+    let key = scoped_key(namespace, key)?;
+    storage.authorize_write(&key)?;
+    Ok(storage.delete(&key)?)
+}
+
+fn list_keys(
+    storage: &GuestStorage,
+    namespace: &str,
+    prefix: Option<&str>,
+) -> Result<Vec<String>, StorageError> {
+    let scope = namespace_prefix(namespace)?;
+    let filter = format!("{scope}{}", prefix.unwrap_or_default());
+    Ok(storage
+        .list_keys(Some(&filter))?
+        .into_iter()
+        .filter(|key| storage.grants().can_read_key(key))
+        .filter_map(|key| key.strip_prefix(&scope).map(str::to_string))
+        .collect())
+}
+
+/// Implementation of the host_storage Host trait for WASM components
+///
+/// Shares the component's storage grant with the `storage` interface; a
+/// component without one is denied every call.
+impl host_storage::Host for HostState {
+    /// Retrieve a value by key within `namespace`
+    ///
+    /// Requires a `can_read_keys` grant for `namespace/key`.
+    fn get(&mut self, namespace: String, key: String) -> Result<Option<Vec<u8>>, StorageError> {
+        get(self.guest_storage("read", &namespace)?, &namespace, &key)
+    }
+
+    /// Store a value by key within `namespace`
+    ///
+    /// Requires a `can_write_keys` grant for `namespace/key`; writes are
+    /// counted against the component's quota like any other.
+    fn set(&mut self, namespace: String, key: String, value: Vec<u8>) -> Result<(), StorageError> {
+        set(
+            self.guest_storage("write", &namespace)?,
+            &namespace,
+            &key,
+            value,
+        )
+    }
+
+    /// Delete a key within `namespace`
+    ///
+    /// Requires a `can_write_keys` grant for `namespace/key`.
+    fn delete(&mut self, namespace: String, key: String) -> Result<(), StorageError> {
+        delete(self.guest_storage("write", &namespace)?, &namespace, &key)
+    }
+
+    /// List keys within `namespace`, optionally filtered by prefix
+    ///
+    /// Keys the component may not read are left out; results exclude the
+    /// namespace.
+    fn list_keys(
+        &mut self,
+        namespace: String,
+        prefix: Option<String>,
+    ) -> Result<Vec<String>, StorageError> {
+        list_keys(
+            self.guest_storage("read", &namespace)?,
+            &namespace,
+            prefix.as_deref(),
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use crate::core::component::id::ComponentId;
+    use crate::core::management::lockdown::HostLockdown;
+    use crate::runtime::kv_store::{KvStore, StorageGrants};
+
+    fn guest(grants: StorageGrants) -> GuestStorage {
+        let store = Arc::new(KvStore::in_memory().unwrap());
+        GuestStorage::open(&store, &ComponentId::new("shop", "cart", "0"), grants).unwrap()
+    }
+
+    #[test]
+    fn test_scoped_key_rejects_escaping_namespaces() {
+        assert_eq!(scoped_key("cache", "a/b").unwrap(), "cache/a/b");
+        for namespace in ["", "cache/other", "*", "!cache"] {
+            assert!(matches!(
+                scoped_key(namespace, "k"),
+                Err(StorageError::InvalidKey(_))
+            ));
+        }
+        assert!(scoped_key("cache", "").is_err());
+    }
+
+    #[test]
+    fn test_namespaces_are_independent() {
+        let storage = guest(StorageGrants::default().with_keys("*"));
+
+        set(&storage, "cache", "user", b"1".to_vec()).unwrap();
+        set(&storage, "config", "user", b"2".to_vec()).unwrap();
+
+        assert_eq!(get(&storage, "cache", "user").unwrap(), Some(b"1".to_vec()));
+        assert_eq!(
+            get(&storage, "config", "user").unwrap(),
+            Some(b"2".to_vec())
+        );
+
+        delete(&storage, "cache", "user").unwrap();
+        assert_eq!(get(&storage, "cache", "user").unwrap(), None);
+        assert_eq!(list_keys(&storage, "config", None).unwrap(), vec!["user"]);
+    }
+
+    #[test]
+    fn test_grants_apply_to_scoped_keys() {
+        let storage = guest(StorageGrants {
+            can_read_keys: vec!["cache/*".to_string()],
+            can_write_keys: vec!["cache/*".to_string(), "secrets/*".to_string()],
+            ..StorageGrants::default()
+        });
+
+        set(&storage, "cache", "a", b"x".to_vec()).unwrap();
+        set(&storage, "cache", "ab", b"y".to_vec()).unwrap();
+        set(&storage, "secrets", "token", b"z".to_vec()).unwrap();
+
+        assert!(matches!(
+            get(&storage, "secrets", "token"),
+            Err(StorageError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            set(&storage, "config", "k", Vec::new()),
+            Err(StorageError::PermissionDenied(_))
+        ));
+        assert!(list_keys(&storage, "secrets", None).unwrap().is_empty());
+
+        let mut keys = list_keys(&storage, "cache", Some("a")).unwrap();
+        keys.sort();
+        assert_eq!(keys, vec!["a", "ab"]);
+    }
+
+    #[test]
+    fn test_writes_fail_during_lockdown() {
+        let lockdown = Arc::new(HostLockdown::new());
+        let storage =
+            guest(StorageGrants::default().with_keys("*")).with_lockdown(Arc::clone(&lockdown));
+        set(&storage, "cache", "a", b"1".to_vec()).unwrap();
+
+        lockdown.engage("ops", "incident").unwrap();
+        assert!(matches!(
+            set(&storage, "cache", "a", b"2".to_vec()),
+            Err(StorageError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            delete(&storage, "cache", "a"),
+            Err(StorageError::PermissionDenied(_))
+        ));
+        assert_eq!(get(&storage, "cache", "a").unwrap(), Some(b"1".to_vec()));
+
+        let window = lockdown.lift("ops").unwrap();
+        assert_eq!(window.denials.len(), 2);
+        set(&storage, "cache", "a", b"3".to_vec()).unwrap();
+    }
+}
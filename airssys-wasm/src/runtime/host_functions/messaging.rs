@@ -10,6 +10,9 @@
 //! - `request()` - Send a request message with timeout
 //! - `cancel_request()` - Cancel a pending request
 //! - `broadcast()` - Send a message to multiple components
+//! - `open_stream()` / `push_chunk()` / `close_stream()` - Stream a response
+//! - `open_transfer()` - Send a large payload as ordered chunks
+//! - `ack_stream()` - Acknowledge received stream chunks (flow control)
 //! - `self_id()` - Get this component's ID
 
 // Layer 1: Standard library imports
@@ -19,12 +22,38 @@
 // (none)
 
 // Layer 3: Internal module imports
+use crate::core::component::id::ComponentId as CoreComponentId;
+use crate::core::component::message::MessagePayload as CoreMessagePayload;
+use crate::core::messaging::correlation::CorrelationId as CoreCorrelationId;
+use crate::core::messaging::errors::MessagingError as CoreMessagingError;
+use crate::core::messaging::traits::MessageRouter;
 use crate::runtime::engine::HostState;
 
 // WIT-bindgen generated bindings
 use crate::airssys::core::errors::MessagingError;
 use crate::airssys::core::host_messaging;
-use crate::airssys::core::types::{ComponentId, CorrelationId, MessagePayload};
+use crate::airssys::core::types::{ComponentId, CorrelationId, MessagePayload, StreamHandle};
+
+impl From<CoreMessagingError> for MessagingError {
+    fn from(err: CoreMessagingError) -> Self {
+        match err {
+            CoreMessagingError::CorrelationTimeout(id) => Self::CorrelationTimeout(id),
+            CoreMessagingError::InvalidMessage(reason) => Self::InvalidMessage(reason),
+            CoreMessagingError::QueueFull => Self::QueueFull,
+            CoreMessagingError::CodecMismatch(_) => Self::InvalidMessage(err.to_string()),
+            other => Self::DeliveryFailed(other.to_string()),
+        }
+    }
+}
+
+impl HostState {
+    /// Returns the injected router, or `DeliveryFailed` if none is wired.
+    fn router(&self) -> Result<&dyn MessageRouter, MessagingError> {
+        self.message_router.as_deref().ok_or_else(|| {
+            MessagingError::DeliveryFailed("no message router available".to_string())
+        })
+    }
+}
 
 /// Implementation of the host_messaging Host trait for WASM components
 ///
@@ -130,6 +159,74 @@ impl host_messaging::Host for HostState {
         Ok(())
     }
 
+    /// Open a stream answering a received request
+    ///
+    /// # Parameters
+    /// - `correlation_id` - Correlation ID of the request being answered
+    ///
+    /// # Returns
+    /// - `Ok(handle)` used for `push_chunk` / `close_stream`
+    /// - `Err(MessagingError)` if no router is wired or streaming is unsupported
+    fn open_stream(
+        &mut self,
+        correlation_id: CorrelationId,
+    ) -> Result<StreamHandle, MessagingError> {
+        let correlation_id = CoreCorrelationId::new(correlation_id);
+        Ok(self
+            .router()?
+            .open_stream(&self.component_id, &correlation_id)?)
+    }
+
+    /// Open a transfer streaming a large payload to another component
+    ///
+    /// # Parameters
+    /// - `target` - The destination component ID
+    ///
+    /// # Returns
+    /// - `Ok((handle, correlation_id))`; the handle feeds `push_chunk` /
+    ///   `close_stream`, the target acknowledges under the correlation ID
+    /// - `Err(MessagingError)` if no router is wired, the target is unknown
+    ///   or streaming is unsupported
+    fn open_transfer(
+        &mut self,
+        target: ComponentId,
+    ) -> Result<(StreamHandle, CorrelationId), MessagingError> {
+        let target = CoreComponentId::new(target.namespace, target.name, target.instance);
+        let (handle, correlation_id) = self.router()?.open_transfer(&self.component_id, &target)?;
+        Ok((handle, correlation_id.as_str().to_owned()))
+    }
+
+    /// Push the next chunk of an open stream
+    ///
+    /// # Returns
+    /// - `Ok(())` once the chunk is queued for the receiver
+    /// - `Err(MessagingError::QueueFull)` while the flow-control window is exhausted
+    fn push_chunk(
+        &mut self,
+        handle: StreamHandle,
+        chunk: MessagePayload,
+    ) -> Result<(), MessagingError> {
+        Ok(self
+            .router()?
+            .push_chunk(handle, CoreMessagePayload::new(chunk))?)
+    }
+
+    /// Close an open stream, sending the end-of-stream marker
+    fn close_stream(&mut self, handle: StreamHandle) -> Result<(), MessagingError> {
+        Ok(self.router()?.close_stream(handle)?)
+    }
+
+    /// Acknowledge chunks consumed from a stream answering one of this
+    /// component's requests, or from a transfer sent to it
+    fn ack_stream(
+        &mut self,
+        correlation_id: CorrelationId,
+        received: u64,
+    ) -> Result<(), MessagingError> {
+        let correlation_id = CoreCorrelationId::new(correlation_id);
+        Ok(self.router()?.ack_stream(&correlation_id, received)?)
+    }
+
     /// Get this component's own ID
     ///
     /// # Returns
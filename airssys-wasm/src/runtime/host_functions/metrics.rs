@@ -0,0 +1,105 @@
+//! Host function implementations for component-emitted metrics.
+//!
+//! This module implements the `host_metrics::Host` trait generated by
+//! `wasmtime::component::bindgen!`. Each function records into the
+//! `ComponentMetrics` registry injected into `HostState`, under the calling
+//! component's ID.
+//!
+//! # Functions
+//!
+//! - `increment_counter()` - Add to a counter
+//! - `set_gauge()` - Set a gauge
+//! - `observe_histogram()` - Record a histogram observation
+
+// Layer 1: Standard library imports
+// (none)
+
+// Layer 2: Third-party crate imports
+// (none)
+
+// Layer 3: Internal module imports
+use crate::core::management::errors::MetricsError as CoreMetricsError;
+use crate::core::management::metrics::ComponentMetrics;
+use crate::runtime::engine::HostState;
+
+// WIT-bindgen generated bindings
+use crate::airssys::core::errors::MetricsError;
+use crate::airssys::core::host_metrics;
+
+impl From<CoreMetricsError> for MetricsError {
+    fn from(err: CoreMetricsError) -> Self {
+        match err {
+            CoreMetricsError::InvalidName(name) => Self::InvalidName(name),
+            CoreMetricsError::CardinalityExceeded { limit, .. } => {
+                Self::CardinalityExceeded(u32::try_from(limit).unwrap_or(u32::MAX))
+            }
+            err @ CoreMetricsError::KindMismatch { .. } => Self::KindMismatch(err.to_string()),
+            CoreMetricsError::LockPoisoned(_) => Self::Unavailable,
+        }
+    }
+}
+
+impl HostState {
+    /// The injected metrics registry, or `Unavailable` if the host collects
+    /// no component metrics.
+    fn metrics_registry(&self) -> Result<&ComponentMetrics, MetricsError> {
+        self.metrics.as_deref().ok_or(MetricsError::Unavailable)
+    }
+}
+
+/// Implementation of the host_metrics Host trait for WASM components
+impl host_metrics::Host for HostState {
+    /// Add `delta` to a counter of this component
+    fn increment_counter(
+        &mut self,
+        name: String,
+        labels: Vec<(String, String)>,
+        delta: u64,
+    ) -> Result<(), MetricsError> {
+        Ok(self
+            .metrics_registry()?
+            .increment_counter(&self.component_id, &name, &labels, delta)?)
+    }
+
+    /// Set a gauge of this component to `value`
+    fn set_gauge(
+        &mut self,
+        name: String,
+        labels: Vec<(String, String)>,
+        value: f64,
+    ) -> Result<(), MetricsError> {
+        Ok(self
+            .metrics_registry()?
+            .set_gauge(&self.component_id, &name, &labels, value)?)
+    }
+
+    /// Record one observation in a histogram of this component
+    fn observe_histogram(
+        &mut self,
+        name: String,
+        labels: Vec<(String, String)>,
+        value: f64,
+    ) -> Result<(), MetricsError> {
+        Ok(self
+            .metrics_registry()?
+            .observe_histogram(&self.component_id, &name, &labels, value)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics_errors_map_to_wit_errors() {
+        let err: MetricsError = CoreMetricsError::CardinalityExceeded {
+            component: "acme/cache/v1".to_string(),
+            limit: 8,
+        }
+        .into();
+        assert!(matches!(err, MetricsError::CardinalityExceeded(8)));
+
+        let err: MetricsError = CoreMetricsError::LockPoisoned("x".to_string()).into();
+        assert!(matches!(err, MetricsError::Unavailable));
+    }
+}
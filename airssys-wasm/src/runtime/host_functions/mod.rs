@@ -3,12 +3,20 @@
 //! This module provides the implementation of host functions that WASM components
 //! can call to interact with the host application. Functions are organized by category:
 //! - `messaging`: Message routing and publishing
+//! - `metrics`: Component-emitted counters, gauges and histograms
 //! - `services`: Service discovery and interaction
+//! - `os`: Capability-checked bridge to airssys-osl operations
 //! - `storage`: Component-isolated storage operations
+//! - `host_storage`: Namespace-scoped storage operations
 //! - `marker_traits`: Host trait implementations and registration
+//! - `denial`: Structured permission-denied errors returned to guests
 
 // Submodules (module declarations only per PROJECTS_STANDARD.md §4.3)
+pub mod denial;
+pub mod host_storage;
 pub mod marker_traits;
 pub mod messaging;
+pub mod metrics;
+pub mod os;
 pub mod services;
 pub mod storage;
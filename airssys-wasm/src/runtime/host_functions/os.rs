@@ -0,0 +1,109 @@
+//! Host function implementations for the OS bridge.
+//!
+//! This module implements the `host_os::Host` trait generated by
+//! `wasmtime::component::bindgen!`. Each function forwards to the
+//! `OsBridge` injected into `HostState`, which checks the component's
+//! capabilities before running the operation through airssys-osl.
+//!
+//! # Functions
+//!
+//! - `file_read()` - Read a whole file
+//! - `file_write()` - Create or truncate a file
+//! - `process_spawn()` - Spawn a process
+//! - `network_connect()` - Open an outbound TCP connection
+
+// Layer 1: Standard library imports
+// (none)
+
+// Layer 2: Third-party crate imports
+// (none)
+
+// Layer 3: Internal module imports
+use crate::core::bridge::errors::BridgeError;
+use crate::core::bridge::operation::OsOperation;
+use crate::core::security::errors::SecurityError as CoreSecurityError;
+use crate::runtime::engine::HostState;
+
+// WIT-bindgen generated bindings
+use crate::airssys::core::errors::OsError;
+use crate::airssys::core::host_os;
+
+impl From<BridgeError> for OsError {
+    fn from(err: BridgeError) -> Self {
+        match err {
+            BridgeError::NotExposed(kind) => Self::NotExposed(kind.to_string()),
+            BridgeError::InvalidOperation(reason) => Self::InvalidOperation(reason),
+            BridgeError::Security(CoreSecurityError::Denied(denial)) => {
+                Self::PermissionDenied(denial.into())
+            }
+            BridgeError::Security(other) => Self::OperationFailed(other.to_string()),
+            BridgeError::OperationFailed(reason) => Self::OperationFailed(reason),
+        }
+    }
+}
+
+impl HostState {
+    /// Runs `operation` through the injected bridge, or fails with
+    /// `Unavailable` if the host exposes no OS bridge.
+    fn bridge_execute(&self, operation: OsOperation) -> Result<Vec<u8>, OsError> {
+        let bridge = self.os_bridge.as_deref().ok_or(OsError::Unavailable)?;
+        Ok(bridge.execute(&self.component_id, operation)?)
+    }
+}
+
+/// Implementation of the host_os Host trait for WASM components
+impl host_os::Host for HostState {
+    /// Read a whole file
+    ///
+    /// Requires a filesystem read grant for `path`.
+    fn file_read(&mut self, path: String) -> Result<Vec<u8>, OsError> {
+        self.bridge_execute(OsOperation::FileRead { path })
+    }
+
+    /// Create or truncate a file with `content`
+    ///
+    /// Requires a filesystem write grant for `path`.
+    fn file_write(&mut self, path: String, content: Vec<u8>) -> Result<(), OsError> {
+        self.bridge_execute(OsOperation::FileWrite { path, content })
+            .map(|_| ())
+    }
+
+    /// Spawn a process and return its process ID
+    ///
+    /// Requires a filesystem execute grant for `program`.
+    fn process_spawn(&mut self, program: String, args: Vec<String>) -> Result<u32, OsError> {
+        let output = self.bridge_execute(OsOperation::ProcessSpawn { program, args })?;
+        String::from_utf8_lossy(&output)
+            .trim()
+            .parse()
+            .map_err(|_| OsError::OperationFailed("executor returned no process ID".to_string()))
+    }
+
+    /// Open an outbound TCP connection and return the peer address
+    ///
+    /// Requires a network grant for the address's host.
+    fn network_connect(&mut self, address: String) -> Result<String, OsError> {
+        let output = self.bridge_execute(OsOperation::NetworkConnect { address })?;
+        Ok(String::from_utf8_lossy(&output).into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bridge::operation::OsOperationKind;
+    use crate::core::security::errors::PermissionDenial;
+
+    #[test]
+    fn test_bridge_errors_map_to_wit_errors() {
+        let err: OsError = BridgeError::NotExposed(OsOperationKind::ProcessSpawn).into();
+        assert!(matches!(err, OsError::NotExposed(ref k) if k == "process-spawn"));
+
+        let denial = PermissionDenial::new("filesystem", "read", "/etc/shadow", "no grant");
+        let err: OsError = BridgeError::Security(CoreSecurityError::Denied(denial)).into();
+        match err {
+            OsError::PermissionDenied(d) => assert_eq!(d.capability, "filesystem"),
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+}
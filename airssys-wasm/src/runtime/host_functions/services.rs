@@ -12,6 +12,7 @@
 //! - `sleep_millis()` - Pause execution for a duration
 //! - `list_components()` - Get list of all component IDs
 //! - `get_component_metadata()` - Query metadata about a specific component
+//! - `request_capability()` - File a capability elevation request
 
 // Layer 1: Standard library imports
 // (none)
@@ -141,4 +142,30 @@ impl host_services::Host for HostState {
             "Not implemented".to_string(),
         ))
     }
+
+    /// Request an additional capability for this component
+    ///
+    /// # Parameters
+    /// - `capability` - Capability in `class:action:pattern` form
+    /// - `reason` - Why the component needs it (shown to the operator)
+    ///
+    /// # Returns
+    /// - `Ok(request_id)` once the request is recorded; it takes effect only
+    ///   after operator approval and a component restart
+    /// - `Err(ComponentError::CapabilityRequestRejected)` if the capability
+    ///   is malformed or the host does not accept elevation requests
+    fn request_capability(
+        &mut self,
+        capability: String,
+        reason: String,
+    ) -> Result<u64, ComponentError> {
+        let requests = self.elevation_requests.as_ref().ok_or_else(|| {
+            ComponentError::CapabilityRequestRejected(
+                "host does not accept capability requests".to_string(),
+            )
+        })?;
+        requests
+            .submit(&self.component_id, &capability, &reason)
+            .map_err(|e| ComponentError::CapabilityRequestRejected(e.to_string()))
+    }
 }
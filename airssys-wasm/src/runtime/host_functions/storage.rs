@@ -4,33 +4,70 @@
 //! `wasmtime::component::bindgen!`, providing WASM components with access to
 //! persistent, isolated key-value storage.
 //!
-//! Each component has its own storage namespace, preventing unauthorized access
-//! to other components' data. Storage operations are validated against the
-//! component's security capabilities.
+//! Each component has its own storage namespace in the host's `KvStore`,
+//! preventing access to other components' data. Every key is checked
+//! against the component's `can_read_keys` / `can_write_keys` grants, and
+//! writes against its storage quota. Writes are also denied while the host
+//! is in read-only lockdown. Components without a storage grant are denied
+//! every call.
 //!
 //! # Functions
 //!
 //! - `get()` - Retrieve a value by key
 //! - `set()` - Store a key-value pair
+//! - `set_with_ttl()` - Store a key-value pair that expires
 //! - `delete()` - Remove a key
 //! - `exists()` - Check if a key exists
 //! - `list_keys()` - List all keys (optionally by prefix)
 //! - `usage()` - Get storage usage statistics
+//! - `begin()` / `commit()` / `rollback()` - Group writes into a transaction
 
 // Layer 1: Standard library imports
-// (none)
+use std::time::Duration;
 
 // Layer 2: Third-party crate imports
 // (none)
 
 // Layer 3: Internal module imports
+use crate::core::storage::errors::StorageError as CoreStorageError;
+use crate::core::storage::traits::ComponentStorage;
+use crate::core::storage::value::StorageValue;
 use crate::runtime::engine::HostState;
+use crate::runtime::kv_store::{denied, GuestStorage};
 
 // WIT-bindgen generated bindings
 use crate::airssys::core::errors::StorageError;
 use crate::airssys::core::storage;
 use crate::airssys::core::storage::StorageUsage;
-use crate::airssys::core::types::MessagePayload;
+
+impl From<CoreStorageError> for StorageError {
+    fn from(err: CoreStorageError) -> Self {
+        match err {
+            CoreStorageError::NotFound(key) => Self::NotFound(key),
+            CoreStorageError::AlreadyExists(key) => Self::AlreadyExists(key),
+            CoreStorageError::QuotaExceeded => Self::QuotaExceeded,
+            CoreStorageError::InvalidKey(reason) => Self::InvalidKey(reason),
+            CoreStorageError::IoError(reason) => Self::IoError(reason),
+            CoreStorageError::Conflict(key) => Self::Conflict(key),
+            CoreStorageError::InvalidTransaction(reason) => Self::InvalidTransaction(reason),
+            CoreStorageError::Encryption(reason) => Self::Encryption(reason),
+        }
+    }
+}
+
+impl HostState {
+    /// The component's storage, or a denial of `action` on `key` if the
+    /// host granted it none.
+    pub(super) fn guest_storage(
+        &self,
+        action: &str,
+        key: &str,
+    ) -> Result<&GuestStorage, StorageError> {
+        self.storage
+            .as_ref()
+            .ok_or_else(|| denied(action, key, "component has no storage grant").into())
+    }
+}
 
 /// Implementation of the storage Host trait for WASM components
 ///
@@ -42,142 +79,133 @@ use crate::airssys::core::types::MessagePayload;
 ///
 /// Each component has isolated storage:
 /// - Component A cannot access Component B's keys
-/// - Storage is backed by the host system (filesystem, database, etc.)
-/// - All operations are validated against the component's capabilities
+/// - Storage is backed by the host's `KvStore` (a redb database)
+/// - Every key is checked against the component's storage grants
 impl storage::Host for HostState {
     /// Retrieve a value from storage by key
     ///
-    /// # Parameters
-    /// - `key` - The storage key to retrieve
+    /// Requires a `can_read_keys` grant for `key`.
     ///
     /// # Returns
-    /// - `Ok(Some(payload))` if the key exists
+    /// - `Ok(Some(value))` if the key exists
     /// - `Ok(None)` if the key doesn't exist
     /// - `Err(StorageError)` on failure (access denied, I/O error, etc.)
-    ///
-    /// # Phase 6 Tasks
-    /// - TODO(Phase 6 - WASM-TASK-037): Access storage backend with security validation
-    ///   - Check component capabilities for storage access
-    ///   - Construct namespaced key (component_id + "/" + key)
-    ///   - Query storage backend
-    ///   - Deserialize MessagePayload from stored value
-    ///   - Handle missing keys gracefully
-    fn get(&mut self, _key: String) -> Result<Option<MessagePayload>, StorageError> {
-        // Stub
-        Ok(None)
+    fn get(&mut self, key: String) -> Result<Option<Vec<u8>>, StorageError> {
+        let storage = self.guest_storage("read", &key)?;
+        storage.authorize_read(&key)?;
+        Ok(storage.get(&key)?.map(|value| value.as_bytes().to_vec()))
     }
 
     /// Store a key-value pair in storage
     ///
-    /// # Parameters
-    /// - `key` - The storage key
-    /// - `value` - The value to store as MessagePayload
+    /// Requires a `can_write_keys` grant for `key`.
     ///
     /// # Returns
     /// - `Ok(())` on success
-    /// - `Err(StorageError)` on failure (access denied, quota exceeded, etc.)
-    ///
-    /// # Phase 6 Tasks
-    /// - TODO(Phase 6 - WASM-TASK-037): Access storage backend with security validation
-    ///   - Check component capabilities for storage write
-    ///   - Validate key name (no special characters, etc.)
-    ///   - Construct namespaced key
-    ///   - Check quota before storing
-    ///   - Serialize and persist to storage backend
-    ///   - Return QuotaExceeded if storage limit would be violated
-    fn set(&mut self, _key: String, _value: MessagePayload) -> Result<(), StorageError> {
-        // Stub
-        Ok(())
+    /// - `Err(StorageError::QuotaExceeded)` if the write would exceed the
+    ///   component's quota; nothing is stored
+    /// - `Err(StorageError)` on other failures (access denied, I/O error)
+    fn set(&mut self, key: String, value: Vec<u8>) -> Result<(), StorageError> {
+        let storage = self.guest_storage("write", &key)?;
+        storage.authorize_write(&key)?;
+        Ok(storage.set(&key, StorageValue::new(value))?)
+    }
+
+    /// Store a key-value pair that expires after `ttl_ms` milliseconds
+    ///
+    /// Requires a `can_write_keys` grant for `key`. Not available while a
+    /// transaction is open.
+    fn set_with_ttl(
+        &mut self,
+        key: String,
+        value: Vec<u8>,
+        ttl_ms: u64,
+    ) -> Result<(), StorageError> {
+        let storage = self.guest_storage("write", &key)?;
+        storage.authorize_write(&key)?;
+        Ok(storage.set_with_ttl(
+            &key,
+            StorageValue::new(value),
+            Duration::from_millis(ttl_ms),
+        )?)
     }
 
     /// Delete a key from storage
     ///
-    /// # Parameters
-    /// - `key` - The storage key to delete
+    /// Requires a `can_write_keys` grant for `key`.
     ///
     /// # Returns
     /// - `Ok(())` on success (even if key didn't exist)
     /// - `Err(StorageError)` on failure (access denied, I/O error, etc.)
-    ///
-    /// # Phase 6 Tasks
-    /// - TODO(Phase 6 - WASM-TASK-037): Access storage backend with security validation
-    ///   - Check component capabilities for storage write
-    ///   - Construct namespaced key
-    ///   - Delete from storage backend
-    ///   - Handle non-existent keys gracefully
-    fn delete(&mut self, _key: String) -> Result<(), StorageError> {
-        // Stub
-        Ok(())
+    fn delete(&mut self, key: String) -> Result<(), StorageError> {
+        let storage = self.guest_storage("write", &key)?;
+        storage.authorize_write(&key)?;
+        Ok(storage.delete(&key)?)
     }
 
     /// Check if a key exists in storage
     ///
-    /// # Parameters
-    /// - `key` - The storage key to check
-    ///
-    /// # Returns
-    /// - `Ok(true)` if the key exists
-    /// - `Ok(false)` if the key doesn't exist
-    /// - `Err(StorageError)` on failure (access denied, I/O error, etc.)
-    ///
-    /// # Phase 6 Tasks
-    /// - TODO(Phase 6 - WASM-TASK-037): Access storage backend with security validation
-    ///   - Check component capabilities for storage access
-    ///   - Construct namespaced key
-    ///   - Query storage backend for key existence
-    fn exists(&mut self, _key: String) -> Result<bool, StorageError> {
-        // Stub
-        Ok(false)
+    /// Requires a `can_read_keys` grant for `key`.
+    fn exists(&mut self, key: String) -> Result<bool, StorageError> {
+        let storage = self.guest_storage("read", &key)?;
+        storage.authorize_read(&key)?;
+        Ok(storage.exists(&key)?)
     }
 
     /// List all keys in storage, optionally filtered by prefix
     ///
-    /// # Parameters
-    /// - `prefix` - Optional key prefix filter (e.g., "config:" to list all config keys)
-    ///
-    /// # Returns
-    /// - `Ok(keys)` - Vector of matching keys
-    /// - `Err(StorageError)` on failure (access denied, I/O error, etc.)
-    ///
-    /// # Note
-    /// Results do not include the namespace prefix (component_id/),
-    /// only the application-level keys.
-    ///
-    /// # Phase 6 Tasks
-    /// - TODO(Phase 6 - WASM-TASK-037): Access storage backend with security validation
-    ///   - Check component capabilities for storage access
-    ///   - Construct namespaced prefix
-    ///   - Query storage backend for matching keys
-    ///   - Strip namespace prefix from results
-    ///   - Return empty list if no keys match
-    fn list_keys(&mut self, _prefix: Option<String>) -> Result<Vec<String>, StorageError> {
-        // Stub
-        Ok(vec![])
+    /// Keys the component may not read are left out. Results do not
+    /// include the namespace, only the application-level keys.
+    fn list_keys(&mut self, prefix: Option<String>) -> Result<Vec<String>, StorageError> {
+        let storage = self.guest_storage("read", prefix.as_deref().unwrap_or("*"))?;
+        let mut keys = storage.list_keys(prefix.as_deref())?;
+        keys.retain(|key| storage.grants().can_read_key(key));
+        Ok(keys)
     }
 
     /// Get storage usage statistics for this component
     ///
-    /// # Returns
-    /// - `Ok(StorageUsage)` with used bytes, quota, and key count
-    /// - `Err(StorageError)` on failure
-    ///
     /// # Fields
-    /// - `used_bytes` - Total bytes currently used
-    /// - `quota_bytes` - Maximum allowed bytes (per component quota)
+    /// - `used_bytes` - Total size of keys plus values
+    /// - `quota_bytes` - Byte quota, or 0 when unlimited
     /// - `key_count` - Number of keys stored
-    ///
-    /// # Phase 6 Tasks
-    /// - TODO(Phase 6 - WASM-TASK-037): Access storage backend with security validation
-    ///   - Query storage backend for component's usage
-    ///   - Get quota from component metadata/capabilities
-    ///   - Count total bytes and keys
-    ///   - Return usage information
     fn usage(&mut self) -> Result<StorageUsage, StorageError> {
-        // Stub
+        let storage = self.guest_storage("usage", "*")?;
+        let usage = storage.kv().usage()?;
         Ok(StorageUsage {
-            used_bytes: 0,
-            quota_bytes: 0,
-            key_count: 0,
+            used_bytes: usage.used_bytes,
+            quota_bytes: storage.kv().quota().max_bytes.unwrap_or(0),
+            key_count: usage.key_count,
         })
     }
+
+    /// Start a transaction
+    ///
+    /// # Returns
+    /// - `Ok(())` on success
+    /// - `Err(StorageError::InvalidTransaction)` if one is already open
+    fn begin(&mut self) -> Result<(), StorageError> {
+        Ok(self.guest_storage("begin", "*")?.begin()?)
+    }
+
+    /// Commit the open transaction
+    ///
+    /// The transaction is closed either way.
+    ///
+    /// # Returns
+    /// - `Ok(())` if every write was applied
+    /// - `Err(StorageError::Conflict)` if a touched key changed meanwhile;
+    ///   nothing is applied
+    /// - `Err(StorageError::QuotaExceeded)` if the writes exceed the quota
+    /// - `Err(StorageError::InvalidTransaction)` if none is open
+    fn commit(&mut self) -> Result<(), StorageError> {
+        Ok(self.guest_storage("commit", "*")?.commit()?)
+    }
+
+    /// Discard the open transaction, if any
+    fn rollback(&mut self) {
+        if let Some(storage) = &self.storage {
+            storage.rollback();
+        }
+    }
 }
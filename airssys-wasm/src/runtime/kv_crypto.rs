@@ -0,0 +1,350 @@
+//! Encryption at rest for component storage.
+//!
+//! A [`KvStore`](super::kv_store::KvStore) opened with a
+//! [`StorageKeyProvider`] encrypts the values of every namespace the
+//! provider has a key for with AES-256-GCM, so secrets persisted by
+//! components are not readable from the database file. Keys are never
+//! encrypted: the runtime needs them to list, expire and account for
+//! entries. Namespaces without a key are stored in plaintext.
+//!
+//! # Format
+//!
+//! A sealed value is stored as
+//!
+//! ```text
+//! "AKV1" | key ID (u32, big endian) | nonce (12 bytes) | ciphertext | tag (16 bytes)
+//! ```
+//!
+//! The nonce is random per write. The namespace and storage key are
+//! authenticated as associated data, so a sealed value copied to another
+//! key or namespace fails to decrypt instead of being read there.
+//!
+//! # Key Rotation
+//!
+//! New writes use the namespace's current key; the key ID in each value
+//! selects the key that opens it, so older keys keep working as long as
+//! the provider still returns them. `ComponentKv::rekey` rewrites a
+//! namespace under its current key, after which older keys can be
+//! dropped. It also encrypts values written before the namespace had a
+//! key, which reads otherwise reject.
+//!
+//! Whether a namespace is encrypted is recorded by the store when its
+//! first value is sealed (or when it is rekeyed), never guessed from the
+//! stored bytes: plaintext that happens to start with `AKV1` is still
+//! plaintext.
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// Layer 2: Third-party crate imports
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+
+// Layer 3: Internal module imports
+use crate::core::storage::errors::StorageError;
+use crate::core::storage::value::StorageValue;
+
+/// Format marker and version of a sealed value.
+const MAGIC: &[u8; 4] = b"AKV1";
+
+/// Length of the AES-GCM nonce.
+const NONCE_LEN: usize = 12;
+
+/// Length of the header before the ciphertext.
+const HEADER_LEN: usize = MAGIC.len() + 4 + NONCE_LEN;
+
+/// Length of the AES-GCM authentication tag.
+const TAG_LEN: usize = 16;
+
+/// Bytes a sealed value takes beyond its plaintext.
+pub(crate) const SEAL_OVERHEAD: usize = HEADER_LEN + TAG_LEN;
+
+/// A 256-bit AES key for one namespace.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::runtime::kv_crypto::StorageKey;
+///
+/// let key = StorageKey::from_bytes(1, [7; 32]);
+/// assert_eq!(key.key_id(), 1);
+/// assert!(!format!("{:?}", key).contains('7'));
+/// ```
+#[derive(Clone)]
+pub struct StorageKey {
+    key_id: u32,
+    bytes: [u8; 32],
+}
+
+impl StorageKey {
+    /// Creates a key from 32 bytes of key material.
+    ///
+    /// `key_id` is stored with every value the key seals and must be
+    /// unique among the keys of a namespace.
+    pub fn from_bytes(key_id: u32, bytes: [u8; 32]) -> Self {
+        Self { key_id, bytes }
+    }
+
+    /// ID of this key.
+    pub fn key_id(&self) -> u32 {
+        self.key_id
+    }
+}
+
+impl std::fmt::Debug for StorageKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StorageKey")
+            .field("key_id", &self.key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Source of the keys that encrypt each storage namespace.
+///
+/// Implemented by the host, for example on top of a secrets manager or a
+/// KMS. Called when a namespace is opened and when a value sealed under a
+/// key other than the current one is read.
+pub trait StorageKeyProvider: Send + Sync {
+    /// The key new writes to `namespace` are sealed with, or `None` to
+    /// store the namespace in plaintext.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::Encryption` if the key cannot be fetched.
+    fn current_key(&self, namespace: &str) -> Result<Option<StorageKey>, StorageError>;
+
+    /// The key of `namespace` with ID `key_id`, or `None` if it is unknown.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::Encryption` if the key cannot be fetched.
+    fn key(&self, namespace: &str, key_id: u32) -> Result<Option<StorageKey>, StorageError>;
+}
+
+/// A [`StorageKeyProvider`] holding keys configured in memory.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::runtime::kv_crypto::{StaticKeyProvider, StorageKey, StorageKeyProvider};
+///
+/// let keys = StaticKeyProvider::new()
+///     .with_key("orders", StorageKey::from_bytes(1, [1; 32]))
+///     .with_key("orders", StorageKey::from_bytes(2, [2; 32]));
+///
+/// assert_eq!(keys.current_key("orders").unwrap().unwrap().key_id(), 2);
+/// assert!(keys.key("orders", 1).unwrap().is_some());
+/// assert!(keys.current_key("cache").unwrap().is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StaticKeyProvider {
+    /// Keys by namespace, current last
+    keys: HashMap<String, Vec<StorageKey>>,
+}
+
+impl StaticKeyProvider {
+    /// Creates a provider without keys.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `key` to `namespace` and makes it the current key; earlier
+    /// keys still open the values they sealed.
+    pub fn with_key(mut self, namespace: impl Into<String>, key: StorageKey) -> Self {
+        self.keys.entry(namespace.into()).or_default().push(key);
+        self
+    }
+}
+
+impl StorageKeyProvider for StaticKeyProvider {
+    fn current_key(&self, namespace: &str) -> Result<Option<StorageKey>, StorageError> {
+        Ok(self
+            .keys
+            .get(namespace)
+            .and_then(|keys| keys.last())
+            .cloned())
+    }
+
+    fn key(&self, namespace: &str, key_id: u32) -> Result<Option<StorageKey>, StorageError> {
+        Ok(self
+            .keys
+            .get(namespace)
+            .and_then(|keys| keys.iter().find(|key| key.key_id == key_id))
+            .cloned())
+    }
+}
+
+/// Seals and opens the values of one namespace.
+#[derive(Clone)]
+pub(crate) struct NamespaceCipher {
+    namespace: String,
+    provider: Arc<dyn StorageKeyProvider>,
+    current: StorageKey,
+}
+
+impl NamespaceCipher {
+    /// The cipher for `namespace`, or `None` if it is stored in plaintext.
+    pub(crate) fn for_namespace(
+        provider: &Arc<dyn StorageKeyProvider>,
+        namespace: &str,
+    ) -> Result<Option<Self>, StorageError> {
+        Ok(provider.current_key(namespace)?.map(|current| Self {
+            namespace: namespace.to_string(),
+            provider: Arc::clone(provider),
+            current,
+        }))
+    }
+
+    /// Encrypts `value`, stored under `key`, with the current key.
+    pub(crate) fn seal(
+        &self,
+        key: &str,
+        value: &StorageValue,
+    ) -> Result<StorageValue, StorageError> {
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&self.current.bytes));
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let aad = self.associated_data(key);
+        let ciphertext = cipher
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: value.as_bytes(),
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| encryption_error(key, "encryption failed"))?;
+
+        let mut sealed = Vec::with_capacity(HEADER_LEN + ciphertext.len());
+        sealed.extend_from_slice(MAGIC);
+        sealed.extend_from_slice(&self.current.key_id.to_be_bytes());
+        sealed.extend_from_slice(&nonce);
+        sealed.extend_from_slice(&ciphertext);
+        Ok(StorageValue::new(sealed))
+    }
+
+    /// Decrypts `sealed`, stored under `key`.
+    pub(crate) fn open(
+        &self,
+        key: &str,
+        sealed: &StorageValue,
+    ) -> Result<StorageValue, StorageError> {
+        let (key_id, nonce, ciphertext) = parse(sealed.as_bytes())
+            .ok_or_else(|| encryption_error(key, "value is not encrypted"))?;
+        let storage_key = if key_id == self.current.key_id {
+            self.current.clone()
+        } else {
+            self.provider
+                .key(&self.namespace, key_id)?
+                .ok_or_else(|| encryption_error(key, &format!("unknown key ID {key_id}")))?
+        };
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&storage_key.bytes));
+        let aad = self.associated_data(key);
+        let plaintext = cipher
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| encryption_error(key, "authentication failed"))?;
+        Ok(StorageValue::new(plaintext))
+    }
+
+    /// Whether `stored` is already sealed under the current key.
+    pub(crate) fn is_current(&self, stored: &StorageValue) -> bool {
+        parse(stored.as_bytes()).is_some_and(|(key_id, _, _)| key_id == self.current.key_id)
+    }
+
+    /// Binds a sealed value to its namespace and key.
+    fn associated_data(&self, key: &str) -> Vec<u8> {
+        let mut aad = Vec::with_capacity(4 + self.namespace.len() + key.len());
+        aad.extend_from_slice(&(self.namespace.len() as u32).to_be_bytes());
+        aad.extend_from_slice(self.namespace.as_bytes());
+        aad.extend_from_slice(key.as_bytes());
+        aad
+    }
+}
+
+/// Splits a sealed value into key ID, nonce and ciphertext.
+fn parse(sealed: &[u8]) -> Option<(u32, &[u8], &[u8])> {
+    let rest = sealed.strip_prefix(MAGIC.as_slice())?;
+    if rest.len() < HEADER_LEN - MAGIC.len() {
+        return None;
+    }
+    let (key_id, rest) = rest.split_at(4);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let key_id = u32::from_be_bytes(key_id.try_into().ok()?);
+    Some((key_id, nonce, ciphertext))
+}
+
+fn encryption_error(key: &str, reason: &str) -> StorageError {
+    StorageError::Encryption(format!("{key}: {reason}"))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn cipher(provider: StaticKeyProvider, namespace: &str) -> NamespaceCipher {
+        let provider: Arc<dyn StorageKeyProvider> = Arc::new(provider);
+        NamespaceCipher::for_namespace(&provider, namespace)
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_seal_open_roundtrip_and_binding() {
+        let keys = StaticKeyProvider::new().with_key("a", StorageKey::from_bytes(1, [1; 32]));
+        let a = cipher(keys.clone(), "a");
+        let value = StorageValue::new(b"token=secret".to_vec());
+
+        let sealed = a.seal("k", &value).unwrap();
+        assert!(!sealed
+            .as_bytes()
+            .windows(6)
+            .any(|window| window == b"secret"));
+        assert_ne!(a.seal("k", &value).unwrap(), sealed);
+        assert_eq!(a.open("k", &sealed).unwrap(), value);
+        assert!(a.is_current(&sealed));
+
+        // Moved to another key, tampered with, or never sealed
+        assert!(matches!(
+            a.open("other", &sealed),
+            Err(StorageError::Encryption(_))
+        ));
+        let mut tampered = sealed.as_bytes().to_vec();
+        if let Some(last) = tampered.last_mut() {
+            *last ^= 1;
+        }
+        assert!(a.open("k", &StorageValue::new(tampered)).is_err());
+        assert!(a.open("k", &value).is_err());
+
+        // Same key material, different namespace
+        let b = cipher(keys.with_key("b", StorageKey::from_bytes(1, [1; 32])), "b");
+        assert!(b.open("k", &sealed).is_err());
+    }
+
+    #[test]
+    fn test_rotated_keys_open_older_values() {
+        let old = StaticKeyProvider::new().with_key("a", StorageKey::from_bytes(1, [1; 32]));
+        let sealed = cipher(old.clone(), "a")
+            .seal("k", &StorageValue::new(b"v".to_vec()))
+            .unwrap();
+
+        let rotated = cipher(old.with_key("a", StorageKey::from_bytes(2, [2; 32])), "a");
+        assert!(!rotated.is_current(&sealed));
+        assert_eq!(rotated.open("k", &sealed).unwrap().as_bytes(), b"v");
+
+        let replaced = cipher(
+            StaticKeyProvider::new().with_key("a", StorageKey::from_bytes(2, [2; 32])),
+            "a",
+        );
+        assert!(matches!(
+            replaced.open("k", &sealed),
+            Err(StorageError::Encryption(reason)) if reason.contains("unknown key ID 1")
+        ));
+    }
+}
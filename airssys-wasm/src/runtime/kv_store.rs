@@ -0,0 +1,1747 @@
+//! Persistent, namespaced key-value storage for components.
+//!
+//! [`KvStore`] is the production [`ComponentStorage`] backend: one redb
+//! database shared by every component on the host, with each component's
+//! keys kept in a table of their own. [`KvStore::component`] returns a
+//! [`ComponentKv`], the view of one namespace, which enforces the
+//! namespace's [`StorageQuota`] inside the write transaction, so a write
+//! that would exceed it is rejected with `StorageError::QuotaExceeded`
+//! and leaves the stored data unchanged.
+//!
+//! Guests reach their storage through the `storage` host interface. What
+//! they may touch is declared in `Component.toml`:
+//!
+//! ```toml
+//! [storage]
+//! namespace = "orders"          # defaults to the component ID
+//!
+//! [capabilities.storage]
+//! can_read_keys = ["cart/*", "config/*"]
+//! can_write_keys = ["cart/*"]
+//! max_bytes = 1_048_576         # keys plus values
+//! max_keys = 10_000
+//! max_value_bytes = 65_536      # largest single value
+//! ```
+//!
+//! [`StorageGrants::from_component_toml`] reads these settings;
+//! `WasmtimeEngine::grant_storage` applies them to instances created
+//! afterwards. Components without grants get no storage.
+//!
+//! [`ComponentKv::begin`] groups updates into a [`KvTransaction`] that is
+//! applied in a single write transaction on commit. It remembers the value
+//! of every key it touches and refuses to commit if one of them changed in
+//! the meantime, so two instances sharing a namespace cannot silently
+//! overwrite each other's updates. A guest drives its transaction through
+//! [`GuestStorage`]; the runtime rolls back whatever is still open when a
+//! guest call returns or traps.
+//!
+//! # Expiry
+//!
+//! [`ComponentKv::set_with_ttl`] stores a key that disappears once its TTL
+//! passes; a plain `set` of the same key makes it permanent again. Expired
+//! keys are invisible to reads straight away, but keep counting against
+//! the quota until they are swept: [`KvStore::expire_due`] removes them
+//! from every namespace, and [`KvStore::spawn_expiry`] runs it periodically
+//! so components can cache data without leaving orphaned state behind.
+//!
+//! # Encryption
+//!
+//! A store opened with [`KvStore::open_encrypted`] seals the values of
+//! every namespace its [`StorageKeyProvider`] has a key for, as described
+//! in [`kv_crypto`](super::kv_crypto). Encryption is transparent to
+//! components; quotas, including `max_value_bytes`, count the sealed size.
+//! A namespace is marked encrypted when its first value is sealed. One that
+//! already holds plaintext must be migrated with [`ComponentKv::rekey`]
+//! before it can be used with a key, and an encrypted namespace refuses
+//! writes from a store that has no key for it.
+
+// Layer 1: Standard library imports
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use chrono::Utc;
+use redb::backends::InMemoryBackend;
+use redb::{Database, ReadOnlyTable, ReadableTable, TableDefinition, TableError, TableHandle};
+use serde::Deserialize;
+use tokio::task::JoinHandle;
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::management::lockdown::HostLockdown;
+use crate::core::runtime::errors::WasmError;
+use crate::core::security::errors::{PermissionDenial, SecurityError};
+use crate::core::storage::errors::StorageError;
+use crate::core::storage::traits::{ComponentStorage, StorageTransaction, TransactionalStorage};
+use crate::core::storage::value::StorageValue;
+use crate::runtime::kv_crypto::{NamespaceCipher, StorageKeyProvider, SEAL_OVERHEAD};
+use crate::security::capability::types::PatternMatcher;
+use crate::security::lockdown::check_lockdown;
+
+/// Longest accepted key, in bytes.
+pub const MAX_KEY_LEN: usize = 512;
+
+/// Bytes and keys stored per namespace, kept in step with the data.
+const USAGE_TABLE: TableDefinition<&str, (u64, u64)> = TableDefinition::new("kv_usage");
+
+/// Namespaces whose values are all sealed.
+const ENCRYPTED_TABLE: TableDefinition<&str, bool> = TableDefinition::new("kv_encrypted");
+
+/// Prefix of the per-namespace tables mapping keys to their expiry time.
+const TTL_TABLE_PREFIX: &str = "kv_ttl/";
+
+fn io_error(err: impl Display) -> StorageError {
+    StorageError::IoError(err.to_string())
+}
+
+fn validate_key(key: &str) -> Result<(), StorageError> {
+    if key.is_empty() {
+        return Err(StorageError::InvalidKey("key is empty".to_string()));
+    }
+    if key.len() > MAX_KEY_LEN {
+        return Err(StorageError::InvalidKey(format!(
+            "key is longer than {} bytes",
+            MAX_KEY_LEN
+        )));
+    }
+    if key.chars().any(char::is_control) {
+        return Err(StorageError::InvalidKey(format!(
+            "{:?} contains control characters",
+            key
+        )));
+    }
+    Ok(())
+}
+
+fn now_ms() -> u64 {
+    u64::try_from(Utc::now().timestamp_millis()).unwrap_or(0)
+}
+
+type DataTable<'txn> = redb::Table<'txn, &'static str, &'static [u8]>;
+type TtlTable<'txn> = redb::Table<'txn, &'static str, u64>;
+
+/// The tables of one namespace, open in a write transaction.
+struct Tables<'txn> {
+    data: DataTable<'txn>,
+    ttl: TtlTable<'txn>,
+    /// Whether the namespace was marked encrypted before this write.
+    encrypted: bool,
+}
+
+/// How a write relates to the namespace's encryption marker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sealing {
+    /// Stores values, sealed if the namespace has a key.
+    Store,
+    /// Only removes entries.
+    Remove,
+    /// Reseals every value and marks the namespace encrypted.
+    Rekey,
+}
+
+/// The value of `key` in `data`, or `None` if it expired by `now`.
+fn live_value<D, T>(
+    data: &D,
+    ttl: Option<&T>,
+    key: &str,
+    now: u64,
+) -> Result<Option<StorageValue>, StorageError>
+where
+    D: ReadableTable<&'static str, &'static [u8]>,
+    T: ReadableTable<&'static str, u64>,
+{
+    if let Some(ttl) = ttl {
+        if ttl
+            .get(key)
+            .map_err(io_error)?
+            .is_some_and(|expires| expires.value() <= now)
+        {
+            return Ok(None);
+        }
+    }
+    let value = data.get(key).map_err(io_error)?;
+    Ok(value.map(|guard| StorageValue::new(guard.value().to_vec())))
+}
+
+/// Stores `key = value`, expiring at `expires_at` (ms since Unix epoch) or
+/// never, and returns the updated usage.
+fn put_entry(
+    tables: &mut Tables<'_>,
+    key: &str,
+    value: &StorageValue,
+    expires_at: Option<u64>,
+    usage: KvUsage,
+) -> Result<KvUsage, StorageError> {
+    match expires_at {
+        Some(at) => tables.ttl.insert(key, at).map(|_| ()),
+        None => tables.ttl.remove(key).map(|_| ()),
+    }
+    .map_err(io_error)?;
+    let entry = (key.len() + value.as_bytes().len()) as u64;
+    let previous = tables
+        .data
+        .insert(key, value.as_bytes())
+        .map_err(io_error)?;
+    Ok(match previous {
+        Some(old) => KvUsage {
+            used_bytes: (usage.used_bytes + entry)
+                .saturating_sub((key.len() + old.value().len()) as u64),
+            key_count: usage.key_count,
+        },
+        None => KvUsage {
+            used_bytes: usage.used_bytes + entry,
+            key_count: usage.key_count + 1,
+        },
+    })
+}
+
+/// Removes `key`, returning the updated usage.
+fn remove_entry(
+    tables: &mut Tables<'_>,
+    key: &str,
+    usage: KvUsage,
+) -> Result<KvUsage, StorageError> {
+    tables.ttl.remove(key).map_err(io_error)?;
+    let removed = tables.data.remove(key).map_err(io_error)?;
+    Ok(match removed {
+        Some(old) => KvUsage {
+            used_bytes: usage
+                .used_bytes
+                .saturating_sub((key.len() + old.value().len()) as u64),
+            key_count: usage.key_count.saturating_sub(1),
+        },
+        None => usage,
+    })
+}
+
+/// Limits on one namespace. `None` means unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StorageQuota {
+    /// Total size of keys plus values, in bytes.
+    pub max_bytes: Option<u64>,
+    /// Number of keys.
+    pub max_keys: Option<u64>,
+    /// Size of any single value as stored (sealed, in encrypted
+    /// namespaces), in bytes.
+    pub max_value_bytes: Option<u64>,
+}
+
+impl StorageQuota {
+    /// Create an unlimited quota.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit the total size of keys plus values.
+    pub fn with_max_bytes(mut self, bytes: u64) -> Self {
+        self.max_bytes = Some(bytes);
+        self
+    }
+
+    /// Limit the number of keys.
+    pub fn with_max_keys(mut self, keys: u64) -> Self {
+        self.max_keys = Some(keys);
+        self
+    }
+
+    /// Limit the size of each value.
+    pub fn with_max_value_bytes(mut self, bytes: u64) -> Self {
+        self.max_value_bytes = Some(bytes);
+        self
+    }
+
+    /// Rejects a value taking `stored_bytes` if it is larger than a single
+    /// value may be.
+    fn check_value(&self, stored_bytes: u64) -> Result<(), StorageError> {
+        match self.max_value_bytes {
+            Some(max) if stored_bytes > max => Err(StorageError::QuotaExceeded),
+            _ => Ok(()),
+        }
+    }
+
+    fn allows(&self, usage: KvUsage) -> bool {
+        self.max_bytes.is_none_or(|max| usage.used_bytes <= max)
+            && self.max_keys.is_none_or(|max| usage.key_count <= max)
+    }
+}
+
+/// What one namespace currently holds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KvUsage {
+    /// Total size of keys plus values, in bytes.
+    pub used_bytes: u64,
+    /// Number of keys.
+    pub key_count: u64,
+}
+
+/// Database holding the storage of every component.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::storage::errors::StorageError;
+/// use airssys_wasm::core::storage::traits::ComponentStorage;
+/// use airssys_wasm::core::storage::value::StorageValue;
+/// use airssys_wasm::runtime::kv_store::{KvStore, StorageQuota};
+///
+/// let store = KvStore::in_memory().unwrap();
+/// let orders = store
+///     .component("orders", StorageQuota::new().with_max_keys(1))
+///     .unwrap();
+/// let billing = store.component("billing", StorageQuota::new()).unwrap();
+///
+/// orders.set("cart/1", StorageValue::new(vec![1, 2, 3])).unwrap();
+/// assert!(orders.exists("cart/1").unwrap());
+/// assert!(!billing.exists("cart/1").unwrap());
+///
+/// assert_eq!(
+///     orders.set("cart/2", StorageValue::new(vec![4])),
+///     Err(StorageError::QuotaExceeded)
+/// );
+/// assert_eq!(orders.usage().unwrap().key_count, 1);
+/// ```
+pub struct KvStore {
+    db: Database,
+    keys: Option<Arc<dyn StorageKeyProvider>>,
+}
+
+impl KvStore {
+    /// Opens the database at `path`, creating it if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::IoError` if the file cannot be opened or is
+    /// not a storage database.
+    pub fn open(path: impl AsRef<Path>) -> Result<Arc<Self>, StorageError> {
+        let db = Database::create(path).map_err(io_error)?;
+        Ok(Arc::new(Self { db, keys: None }))
+    }
+
+    /// Opens the database at `path` like [`open`](Self::open), encrypting
+    /// the namespaces `keys` has a key for.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::IoError` if the file cannot be opened or is
+    /// not a storage database.
+    pub fn open_encrypted(
+        path: impl AsRef<Path>,
+        keys: Arc<dyn StorageKeyProvider>,
+    ) -> Result<Arc<Self>, StorageError> {
+        let db = Database::create(path).map_err(io_error)?;
+        Ok(Arc::new(Self {
+            db,
+            keys: Some(keys),
+        }))
+    }
+
+    /// Creates a database that lives only as long as the store.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::IoError` if the database cannot be created.
+    pub fn in_memory() -> Result<Arc<Self>, StorageError> {
+        let db = Database::builder()
+            .create_with_backend(InMemoryBackend::new())
+            .map_err(io_error)?;
+        Ok(Arc::new(Self { db, keys: None }))
+    }
+
+    /// The view of `namespace`, limited by `quota`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::InvalidKey` if `namespace` is empty, or
+    /// `StorageError::Encryption` if its key cannot be fetched.
+    pub fn component(
+        self: &Arc<Self>,
+        namespace: &str,
+        quota: StorageQuota,
+    ) -> Result<ComponentKv, StorageError> {
+        if namespace.is_empty() {
+            return Err(StorageError::InvalidKey(
+                "storage namespace is empty".to_string(),
+            ));
+        }
+        let cipher = match &self.keys {
+            Some(keys) => NamespaceCipher::for_namespace(keys, namespace)?,
+            None => None,
+        };
+        Ok(ComponentKv {
+            store: Arc::clone(self),
+            namespace: namespace.to_string(),
+            table: format!("kv/{}", namespace),
+            ttl_table: format!("{TTL_TABLE_PREFIX}{namespace}"),
+            quota,
+            cipher,
+        })
+    }
+
+    /// Removes the expired keys of every namespace.
+    ///
+    /// Returns how many keys were removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::IoError` if the database cannot be updated;
+    /// namespaces swept before the failure stay swept.
+    pub fn expire_due(self: &Arc<Self>) -> Result<u64, StorageError> {
+        let txn = self.db.begin_read().map_err(io_error)?;
+        let namespaces: Vec<String> = txn
+            .list_tables()
+            .map_err(io_error)?
+            .filter_map(|table| {
+                let namespace = table.name().strip_prefix(TTL_TABLE_PREFIX)?;
+                Some(namespace.to_string())
+            })
+            .collect();
+        drop(txn);
+
+        let mut removed = 0;
+        for namespace in namespaces {
+            // Expiry only shrinks a namespace, so its quota does not matter
+            removed += self
+                .component(&namespace, StorageQuota::new())?
+                .expire_due()?;
+        }
+        Ok(removed)
+    }
+
+    /// Runs [`expire_due`](Self::expire_due) every `period` on the current
+    /// Tokio runtime.
+    ///
+    /// The task stops once the store is dropped; failed sweeps are logged
+    /// and retried on the next tick.
+    pub fn spawn_expiry(self: &Arc<Self>, period: Duration) -> JoinHandle<()> {
+        let store = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(period);
+            loop {
+                ticker.tick().await;
+                let Some(store) = store.upgrade() else {
+                    break;
+                };
+                match tokio::task::spawn_blocking(move || store.expire_due()).await {
+                    Ok(Ok(_)) => {}
+                    Ok(Err(e)) => tracing::warn!(error = %e, "storage expiry sweep failed"),
+                    Err(e) => tracing::warn!(error = %e, "storage expiry sweep panicked"),
+                }
+            }
+        })
+    }
+}
+
+/// One namespace of a [`KvStore`].
+///
+/// Keys are isolated per namespace: two views of different namespaces
+/// never see each other's keys, while views of the same namespace share
+/// them.
+#[derive(Clone)]
+pub struct ComponentKv {
+    store: Arc<KvStore>,
+    namespace: String,
+    table: String,
+    ttl_table: String,
+    quota: StorageQuota,
+    cipher: Option<NamespaceCipher>,
+}
+
+/// The tables of one namespace, open in a read transaction.
+struct Snapshot {
+    data: ReadOnlyTable<&'static str, &'static [u8]>,
+    ttl: Option<ReadOnlyTable<&'static str, u64>>,
+    encrypted: bool,
+    now: u64,
+}
+
+impl Snapshot {
+    fn get(&self, key: &str) -> Result<Option<StorageValue>, StorageError> {
+        live_value(&self.data, self.ttl.as_ref(), key, self.now)
+    }
+
+    fn is_expired(&self, key: &str) -> Result<bool, StorageError> {
+        let Some(ttl) = &self.ttl else {
+            return Ok(false);
+        };
+        Ok(ttl
+            .get(key)
+            .map_err(io_error)?
+            .is_some_and(|expires| expires.value() <= self.now))
+    }
+}
+
+impl ComponentKv {
+    /// The namespace this view reads and writes.
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// The limits enforced on writes.
+    pub fn quota(&self) -> StorageQuota {
+        self.quota
+    }
+
+    /// What the namespace currently holds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::IoError` if the database cannot be read.
+    pub fn usage(&self) -> Result<KvUsage, StorageError> {
+        let txn = self.store.db.begin_read().map_err(io_error)?;
+        let table = match txn.open_table(USAGE_TABLE) {
+            Ok(table) => table,
+            Err(TableError::TableDoesNotExist(_)) => return Ok(KvUsage::default()),
+            Err(e) => return Err(io_error(e)),
+        };
+        let usage = table.get(self.namespace.as_str()).map_err(io_error)?;
+        Ok(usage
+            .map(|guard| {
+                let (used_bytes, key_count) = guard.value();
+                KvUsage {
+                    used_bytes,
+                    key_count,
+                }
+            })
+            .unwrap_or_default())
+    }
+
+    /// Stores `key = value` until `ttl` has passed.
+    ///
+    /// # Errors
+    ///
+    /// As [`ComponentStorage::set`].
+    pub fn set_with_ttl(
+        &self,
+        key: &str,
+        value: StorageValue,
+        ttl: Duration,
+    ) -> Result<(), StorageError> {
+        validate_key(key)?;
+        self.check_value(&value)?;
+        let value = self.seal(key, value)?;
+        let ttl_ms = u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX);
+        let expires_at = now_ms().saturating_add(ttl_ms);
+        self.write(Sealing::Store, |tables, usage| {
+            put_entry(tables, key, &value, Some(expires_at), usage)
+        })
+    }
+
+    /// Removes the expired keys of this namespace.
+    ///
+    /// Returns how many keys were removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::IoError` if the database cannot be updated.
+    pub fn expire_due(&self) -> Result<u64, StorageError> {
+        let now = now_ms();
+        let mut removed = 0;
+        self.write(Sealing::Remove, |tables, mut usage| {
+            let mut expired = Vec::new();
+            for entry in tables.ttl.iter().map_err(io_error)? {
+                let (key, expires) = entry.map_err(io_error)?;
+                if expires.value() <= now {
+                    expired.push(key.value().to_string());
+                }
+            }
+            for key in &expired {
+                usage = remove_entry(tables, key, usage)?;
+            }
+            removed = expired.len() as u64;
+            Ok(usage)
+        })?;
+        Ok(removed)
+    }
+
+    /// Rewrites every value not yet sealed under the namespace's current
+    /// key with it, keeping expiry times, and marks the namespace encrypted.
+    ///
+    /// Run after rotating keys, before the older ones are dropped, and
+    /// after adding a key to a namespace that already holds plaintext
+    /// values; every value of a namespace not yet marked encrypted is
+    /// treated as plaintext. Returns how many values were rewritten; a
+    /// namespace without a key is left alone.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::Encryption` if a value cannot be opened, in
+    /// which case nothing is rewritten, or the errors of
+    /// [`ComponentStorage::set`].
+    pub fn rekey(&self) -> Result<u64, StorageError> {
+        let Some(cipher) = &self.cipher else {
+            return Ok(0);
+        };
+        let mut rewritten = 0;
+        self.write(Sealing::Rekey, |tables, mut usage| {
+            let mut stale = Vec::new();
+            for entry in tables.data.iter().map_err(io_error)? {
+                let (key, value) = entry.map_err(io_error)?;
+                let value = StorageValue::new(value.value().to_vec());
+                if !tables.encrypted || !cipher.is_current(&value) {
+                    stale.push((key.value().to_string(), value));
+                }
+            }
+            for (key, stored) in &stale {
+                let value = if tables.encrypted {
+                    cipher.open(key, stored)?
+                } else {
+                    stored.clone()
+                };
+                let expires_at = tables
+                    .ttl
+                    .get(key.as_str())
+                    .map_err(io_error)?
+                    .map(|guard| guard.value());
+                let sealed = cipher.seal(key, &value)?;
+                usage = put_entry(tables, key, &sealed, expires_at, usage)?;
+            }
+            rewritten = stale.len() as u64;
+            Ok(usage)
+        })?;
+        Ok(rewritten)
+    }
+
+    /// Rejects `value` if, as stored, it is larger than a single value may be.
+    fn check_value(&self, value: &StorageValue) -> Result<(), StorageError> {
+        let overhead = if self.cipher.is_some() {
+            SEAL_OVERHEAD
+        } else {
+            0
+        };
+        self.quota
+            .check_value((value.as_bytes().len() + overhead) as u64)
+    }
+
+    /// `value` as stored under `key`: sealed if the namespace has a key.
+    fn seal(&self, key: &str, value: StorageValue) -> Result<StorageValue, StorageError> {
+        match &self.cipher {
+            Some(cipher) => cipher.seal(key, &value),
+            None => Ok(value),
+        }
+    }
+
+    /// The value stored under `key` as the component wrote it.
+    ///
+    /// `encrypted` is the namespace's marker: with a key, values of an
+    /// unmarked namespace are plaintext that must be rekeyed first.
+    fn open(
+        &self,
+        key: &str,
+        stored: Option<StorageValue>,
+        encrypted: bool,
+    ) -> Result<Option<StorageValue>, StorageError> {
+        match (&self.cipher, stored) {
+            (Some(cipher), Some(stored)) if encrypted => cipher.open(key, &stored).map(Some),
+            (Some(_), Some(_)) => Err(self.needs_rekey()),
+            (_, stored) => Ok(stored),
+        }
+    }
+
+    /// Refuses a write that would mix sealed and plaintext values.
+    fn check_sealing(&self, encrypted: bool, usage: KvUsage) -> Result<(), StorageError> {
+        match (&self.cipher, encrypted) {
+            (Some(_), false) if usage.key_count > 0 => Err(self.needs_rekey()),
+            (None, true) => Err(StorageError::Encryption(format!(
+                "namespace {} is encrypted but no key is configured",
+                self.namespace
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    fn needs_rekey(&self) -> StorageError {
+        StorageError::Encryption(format!(
+            "namespace {} holds plaintext values; rekey it before use",
+            self.namespace
+        ))
+    }
+
+    fn data(&self) -> TableDefinition<'_, &'static str, &'static [u8]> {
+        TableDefinition::new(&self.table)
+    }
+
+    fn ttl(&self) -> TableDefinition<'_, &'static str, u64> {
+        TableDefinition::new(&self.ttl_table)
+    }
+
+    /// The namespace's tables, or `None` if nothing was ever written.
+    fn snapshot(&self) -> Result<Option<Snapshot>, StorageError> {
+        let txn = self.store.db.begin_read().map_err(io_error)?;
+        let data = match txn.open_table(self.data()) {
+            Ok(table) => table,
+            Err(TableError::TableDoesNotExist(_)) => return Ok(None),
+            Err(e) => return Err(io_error(e)),
+        };
+        let ttl = match txn.open_table(self.ttl()) {
+            Ok(table) => Some(table),
+            Err(TableError::TableDoesNotExist(_)) => None,
+            Err(e) => return Err(io_error(e)),
+        };
+        let encrypted = match txn.open_table(ENCRYPTED_TABLE) {
+            Ok(table) => table
+                .get(self.namespace.as_str())
+                .map_err(io_error)?
+                .is_some_and(|marked| marked.value()),
+            Err(TableError::TableDoesNotExist(_)) => false,
+            Err(e) => return Err(io_error(e)),
+        };
+        Ok(Some(Snapshot {
+            data,
+            ttl,
+            encrypted,
+            now: now_ms(),
+        }))
+    }
+
+    /// Runs `update` on the namespace's tables and applies the usage change
+    /// it returns, committing only if the new usage fits the quota.
+    ///
+    /// `sealing` says how the write treats the encryption marker, which is
+    /// set in the same transaction as the first sealed value.
+    fn write<F>(&self, sealing: Sealing, update: F) -> Result<(), StorageError>
+    where
+        F: FnOnce(&mut Tables<'_>, KvUsage) -> Result<KvUsage, StorageError>,
+    {
+        let txn = self.store.db.begin_write().map_err(io_error)?;
+        let within_quota = {
+            let mut encrypted_table = txn.open_table(ENCRYPTED_TABLE).map_err(io_error)?;
+            let encrypted = encrypted_table
+                .get(self.namespace.as_str())
+                .map_err(io_error)?
+                .is_some_and(|marked| marked.value());
+            let mut tables = Tables {
+                data: txn.open_table(self.data()).map_err(io_error)?,
+                ttl: txn.open_table(self.ttl()).map_err(io_error)?,
+                encrypted,
+            };
+            let mut usage_table = txn.open_table(USAGE_TABLE).map_err(io_error)?;
+            let before = usage_table
+                .get(self.namespace.as_str())
+                .map_err(io_error)?
+                .map(|guard| guard.value())
+                .unwrap_or_default();
+            let before = KvUsage {
+                used_bytes: before.0,
+                key_count: before.1,
+            };
+            if sealing == Sealing::Store {
+                self.check_sealing(encrypted, before)?;
+            }
+            if sealing != Sealing::Remove && self.cipher.is_some() && !encrypted {
+                encrypted_table
+                    .insert(self.namespace.as_str(), true)
+                    .map_err(io_error)?;
+            }
+            let after = update(&mut tables, before)?;
+            // Shrinking is always allowed, even over a lowered quota
+            let shrinking =
+                after.used_bytes <= before.used_bytes && after.key_count <= before.key_count;
+            let fits = shrinking || self.quota.allows(after);
+            if fits {
+                usage_table
+                    .insert(self.namespace.as_str(), (after.used_bytes, after.key_count))
+                    .map_err(io_error)?;
+            }
+            fits
+        };
+        if within_quota {
+            txn.commit().map_err(io_error)
+        } else {
+            txn.abort().map_err(io_error)?;
+            Err(StorageError::QuotaExceeded)
+        }
+    }
+}
+
+impl ComponentStorage for ComponentKv {
+    fn get(&self, key: &str) -> Result<Option<StorageValue>, StorageError> {
+        validate_key(key)?;
+        let Some(snapshot) = self.snapshot()? else {
+            return Ok(None);
+        };
+        self.open(key, snapshot.get(key)?, snapshot.encrypted)
+    }
+
+    /// Stores `key = value` with no expiry, clearing any earlier TTL.
+    fn set(&self, key: &str, value: StorageValue) -> Result<(), StorageError> {
+        validate_key(key)?;
+        self.check_value(&value)?;
+        let value = self.seal(key, value)?;
+        self.write(Sealing::Store, |tables, usage| {
+            put_entry(tables, key, &value, None, usage)
+        })
+    }
+
+    fn delete(&self, key: &str) -> Result<(), StorageError> {
+        validate_key(key)?;
+        self.write(Sealing::Remove, |tables, usage| {
+            remove_entry(tables, key, usage)
+        })
+    }
+
+    fn exists(&self, key: &str) -> Result<bool, StorageError> {
+        Ok(self.get(key)?.is_some())
+    }
+
+    fn list_keys(&self, prefix: Option<&str>) -> Result<Vec<String>, StorageError> {
+        let Some(snapshot) = self.snapshot()? else {
+            return Ok(Vec::new());
+        };
+        let prefix = prefix.unwrap_or("");
+        let mut keys = Vec::new();
+        for entry in snapshot.data.range(prefix..).map_err(io_error)? {
+            let (key, _) = entry.map_err(io_error)?;
+            let key = key.value();
+            if !key.starts_with(prefix) {
+                break;
+            }
+            if !snapshot.is_expired(key)? {
+                keys.push(key.to_string());
+            }
+        }
+        Ok(keys)
+    }
+}
+
+impl TransactionalStorage for ComponentKv {
+    fn begin(&self) -> Result<Box<dyn StorageTransaction>, StorageError> {
+        Ok(Box::new(KvTransaction {
+            kv: self.clone(),
+            observed: BTreeMap::new(),
+            writes: BTreeMap::new(),
+        }))
+    }
+}
+
+/// Buffered updates to one namespace, applied by [`commit`].
+///
+/// [`commit`]: StorageTransaction::commit
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::storage::errors::StorageError;
+/// use airssys_wasm::core::storage::traits::{ComponentStorage, TransactionalStorage};
+/// use airssys_wasm::core::storage::value::StorageValue;
+/// use airssys_wasm::runtime::kv_store::{KvStore, StorageQuota};
+///
+/// let store = KvStore::in_memory().unwrap();
+/// let kv = store.component("orders", StorageQuota::new()).unwrap();
+///
+/// let mut txn = kv.begin().unwrap();
+/// txn.set("cart/1", StorageValue::new(vec![1])).unwrap();
+/// txn.set("total", StorageValue::new(vec![1])).unwrap();
+/// assert!(!kv.exists("cart/1").unwrap());
+/// txn.commit().unwrap();
+/// assert!(kv.exists("total").unwrap());
+///
+/// // A concurrent writer invalidates a transaction that read the key
+/// let mut txn = kv.begin().unwrap();
+/// txn.get("total").unwrap();
+/// kv.set("total", StorageValue::new(vec![2])).unwrap();
+/// txn.set("total", StorageValue::new(vec![3])).unwrap();
+/// assert_eq!(
+///     txn.commit(),
+///     Err(StorageError::Conflict("total".to_string()))
+/// );
+/// ```
+pub struct KvTransaction {
+    kv: ComponentKv,
+    /// Stored value of each touched key when the transaction first saw it
+    observed: BTreeMap<String, Option<StorageValue>>,
+    /// Buffered writes; `None` deletes the key
+    writes: BTreeMap<String, Option<StorageValue>>,
+}
+
+impl KvTransaction {
+    /// The stored value of `key`, remembered for conflict detection.
+    fn observe(&mut self, key: &str) -> Result<Option<StorageValue>, StorageError> {
+        if let Some(seen) = self.observed.get(key) {
+            return Ok(seen.clone());
+        }
+        let seen = self.kv.get(key)?;
+        self.observed.insert(key.to_string(), seen.clone());
+        Ok(seen)
+    }
+}
+
+impl StorageTransaction for KvTransaction {
+    fn get(&mut self, key: &str) -> Result<Option<StorageValue>, StorageError> {
+        validate_key(key)?;
+        if let Some(pending) = self.writes.get(key) {
+            return Ok(pending.clone());
+        }
+        self.observe(key)
+    }
+
+    fn set(&mut self, key: &str, value: StorageValue) -> Result<(), StorageError> {
+        validate_key(key)?;
+        self.kv.check_value(&value)?;
+        self.observe(key)?;
+        self.writes.insert(key.to_string(), Some(value));
+        Ok(())
+    }
+
+    fn delete(&mut self, key: &str) -> Result<(), StorageError> {
+        validate_key(key)?;
+        self.observe(key)?;
+        self.writes.insert(key.to_string(), None);
+        Ok(())
+    }
+
+    fn exists(&mut self, key: &str) -> Result<bool, StorageError> {
+        Ok(self.get(key)?.is_some())
+    }
+
+    fn commit(self: Box<Self>) -> Result<(), StorageError> {
+        let KvTransaction {
+            kv,
+            observed,
+            writes,
+        } = *self;
+        if writes.is_empty() {
+            return Ok(());
+        }
+        let writes = writes
+            .into_iter()
+            .map(|(key, value)| {
+                let value = value.map(|value| kv.seal(&key, value)).transpose()?;
+                Ok((key, value))
+            })
+            .collect::<Result<Vec<_>, StorageError>>()?;
+        let now = now_ms();
+        let sealing = if writes.iter().any(|(_, value)| value.is_some()) {
+            Sealing::Store
+        } else {
+            Sealing::Remove
+        };
+        kv.write(sealing, |tables, mut usage| {
+            for (key, seen) in &observed {
+                let current = live_value(&tables.data, Some(&tables.ttl), key, now)?;
+                if kv.open(key, current, tables.encrypted)? != *seen {
+                    return Err(StorageError::Conflict(key.clone()));
+                }
+            }
+            for (key, value) in &writes {
+                usage = match value {
+                    Some(value) => put_entry(tables, key, value, None, usage)?,
+                    None => remove_entry(tables, key, usage)?,
+                };
+            }
+            Ok(usage)
+        })
+    }
+
+    fn rollback(self: Box<Self>) {}
+}
+
+/// Storage access granted to one component.
+///
+/// The default grants nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::runtime::kv_store::StorageGrants;
+///
+/// let grants = StorageGrants::from_component_toml(
+///     "[capabilities.storage]\ncan_write_keys = [\"cart/*\"]\nmax_keys = 100\n",
+/// )
+/// .unwrap();
+/// assert_eq!(grants.quota.max_keys, Some(100));
+/// assert!(grants.can_write_key("cart/1"));
+/// assert!(!grants.can_read_key("cart/1"));
+///
+/// let id = ComponentId::new("shop", "cart", "0");
+/// assert_eq!(grants.namespace_for(&id), "shop/cart/0");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageGrants {
+    /// Namespace to use instead of the component ID.
+    pub namespace: Option<String>,
+    /// Key patterns that may be read.
+    pub can_read_keys: Vec<String>,
+    /// Key patterns that may be written or deleted.
+    pub can_write_keys: Vec<String>,
+    /// Limits on the namespace.
+    pub quota: StorageQuota,
+}
+
+impl StorageGrants {
+    /// Grants reading and writing keys matching `pattern`.
+    pub fn with_keys(mut self, pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        self.can_read_keys.push(pattern.clone());
+        self.can_write_keys.push(pattern);
+        self
+    }
+
+    /// Limits the namespace with `quota`.
+    pub fn with_quota(mut self, quota: StorageQuota) -> Self {
+        self.quota = quota;
+        self
+    }
+
+    /// Reads storage grants from a `Component.toml`.
+    ///
+    /// The namespace comes from `[storage] namespace`; key patterns and
+    /// limits from `can_read_keys`, `can_write_keys`, `max_bytes`,
+    /// `max_keys`, and `max_value_bytes` in `[capabilities.storage]`. Other keys are ignored here
+    /// and validated by the manifest schema instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::InvalidComponent` if the TOML is malformed.
+    pub fn from_component_toml(source: &str) -> Result<Self, WasmError> {
+        #[derive(Deserialize, Default)]
+        struct ComponentFile {
+            #[serde(default)]
+            storage: StorageSection,
+            #[serde(default)]
+            capabilities: CapabilitiesSection,
+        }
+
+        #[derive(Deserialize, Default)]
+        struct StorageSection {
+            namespace: Option<String>,
+        }
+
+        #[derive(Deserialize, Default)]
+        struct CapabilitiesSection {
+            #[serde(default)]
+            storage: StorageCapsSection,
+        }
+
+        #[derive(Deserialize, Default)]
+        struct StorageCapsSection {
+            #[serde(default)]
+            can_read_keys: Vec<String>,
+            #[serde(default)]
+            can_write_keys: Vec<String>,
+            max_bytes: Option<u64>,
+            max_keys: Option<u64>,
+            max_value_bytes: Option<u64>,
+        }
+
+        let file: ComponentFile = toml::from_str(source).map_err(|e| {
+            WasmError::InvalidComponent(format!("Invalid component manifest: {}", e))
+        })?;
+        let caps = file.capabilities.storage;
+        Ok(Self {
+            namespace: file.storage.namespace,
+            can_read_keys: caps.can_read_keys,
+            can_write_keys: caps.can_write_keys,
+            quota: StorageQuota {
+                max_bytes: caps.max_bytes,
+                max_keys: caps.max_keys,
+                max_value_bytes: caps.max_value_bytes,
+            },
+        })
+    }
+
+    /// The namespace `id` stores its keys under.
+    pub fn namespace_for(&self, id: &ComponentId) -> String {
+        self.namespace.clone().unwrap_or_else(|| id.to_string_id())
+    }
+
+    /// Whether `key` matches a read pattern.
+    pub fn can_read_key(&self, key: &str) -> bool {
+        self.can_read_keys
+            .iter()
+            .any(|pattern| PatternMatcher::matches(pattern, key))
+    }
+
+    /// Whether `key` matches a write pattern.
+    pub fn can_write_key(&self, key: &str) -> bool {
+        self.can_write_keys
+            .iter()
+            .any(|pattern| PatternMatcher::matches(pattern, key))
+    }
+}
+
+/// A guest's storage: its namespace, checked against its grants.
+///
+/// Used by the `storage` host functions. While the guest has a
+/// transaction open, reads and writes through the [`ComponentStorage`]
+/// impl go to that transaction; `list_keys` always lists committed keys.
+/// Writes are also refused while the host lockdown, if any, is engaged.
+pub struct GuestStorage {
+    component: ComponentId,
+    kv: ComponentKv,
+    grants: StorageGrants,
+    lockdown: Option<Arc<HostLockdown>>,
+    transaction: Mutex<Option<Box<dyn StorageTransaction>>>,
+}
+
+impl GuestStorage {
+    /// Opens the namespace `grants` assign to `id` in `store`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::InvalidKey` if the namespace is empty.
+    pub fn open(
+        store: &Arc<KvStore>,
+        id: &ComponentId,
+        grants: StorageGrants,
+    ) -> Result<Self, StorageError> {
+        let kv = store.component(&grants.namespace_for(id), grants.quota)?;
+        Ok(Self {
+            component: id.clone(),
+            kv,
+            grants,
+            lockdown: None,
+            transaction: Mutex::new(None),
+        })
+    }
+
+    /// Refuse writes while `lockdown` is engaged.
+    pub fn with_lockdown(mut self, lockdown: Arc<HostLockdown>) -> Self {
+        self.lockdown = Some(lockdown);
+        self
+    }
+
+    /// The underlying namespace view.
+    pub fn kv(&self) -> &ComponentKv {
+        &self.kv
+    }
+
+    /// The grants the guest's accesses are checked against.
+    pub fn grants(&self) -> &StorageGrants {
+        &self.grants
+    }
+
+    /// Stores `key = value` until `ttl` has passed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::InvalidTransaction` while a transaction is
+    /// open, since transactions only buffer permanent writes; otherwise as
+    /// [`ComponentKv::set_with_ttl`].
+    pub fn set_with_ttl(
+        &self,
+        key: &str,
+        value: StorageValue,
+        ttl: Duration,
+    ) -> Result<(), StorageError> {
+        if self.in_transaction() {
+            return Err(StorageError::InvalidTransaction(
+                "set-with-ttl is not available inside a transaction".to_string(),
+            ));
+        }
+        self.kv.set_with_ttl(key, value, ttl)
+    }
+
+    /// Whether the guest has a transaction open.
+    pub fn in_transaction(&self) -> bool {
+        self.open_transaction().is_some()
+    }
+
+    /// Starts a transaction that later reads and writes go through.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::InvalidTransaction` if one is already open.
+    pub fn begin(&self) -> Result<(), StorageError> {
+        let mut open = self.open_transaction();
+        if open.is_some() {
+            return Err(StorageError::InvalidTransaction(
+                "a transaction is already open".to_string(),
+            ));
+        }
+        *open = Some(self.kv.begin()?);
+        Ok(())
+    }
+
+    /// Commits the open transaction.
+    ///
+    /// The transaction is closed whether or not the commit succeeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StorageError::InvalidTransaction` if none is open, or the
+    /// error of [`StorageTransaction::commit`].
+    pub fn commit(&self) -> Result<(), StorageError> {
+        let transaction = self.open_transaction().take().ok_or_else(|| {
+            StorageError::InvalidTransaction("no transaction is open".to_string())
+        })?;
+        transaction.commit()
+    }
+
+    /// Discards the open transaction, if any.
+    ///
+    /// Returns whether one was open.
+    pub fn rollback(&self) -> bool {
+        match self.open_transaction().take() {
+            Some(transaction) => {
+                transaction.rollback();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn open_transaction(&self) -> MutexGuard<'_, Option<Box<dyn StorageTransaction>>> {
+        self.transaction.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Checks that the guest may read `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecurityError::Denied` if no read pattern matches.
+    pub fn authorize_read(&self, key: &str) -> Result<(), SecurityError> {
+        if self.grants.can_read_key(key) {
+            Ok(())
+        } else {
+            Err(denied("read", key, "no can_read_keys pattern matches"))
+        }
+    }
+
+    /// Checks that the guest may write or delete `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecurityError::Denied` if the host is locked down or no
+    /// write pattern matches.
+    pub fn authorize_write(&self, key: &str) -> Result<(), SecurityError> {
+        if let Some(lockdown) = &self.lockdown {
+            check_lockdown(lockdown, &self.component, "storage", "write", key)?;
+        }
+        if self.grants.can_write_key(key) {
+            Ok(())
+        } else {
+            Err(denied("write", key, "no can_write_keys pattern matches"))
+        }
+    }
+}
+
+impl ComponentStorage for GuestStorage {
+    fn get(&self, key: &str) -> Result<Option<StorageValue>, StorageError> {
+        match self.open_transaction().as_mut() {
+            Some(transaction) => transaction.get(key),
+            None => self.kv.get(key),
+        }
+    }
+
+    fn set(&self, key: &str, value: StorageValue) -> Result<(), StorageError> {
+        match self.open_transaction().as_mut() {
+            Some(transaction) => transaction.set(key, value),
+            None => self.kv.set(key, value),
+        }
+    }
+
+    fn delete(&self, key: &str) -> Result<(), StorageError> {
+        match self.open_transaction().as_mut() {
+            Some(transaction) => transaction.delete(key),
+            None => self.kv.delete(key),
+        }
+    }
+
+    fn exists(&self, key: &str) -> Result<bool, StorageError> {
+        match self.open_transaction().as_mut() {
+            Some(transaction) => transaction.exists(key),
+            None => self.kv.exists(key),
+        }
+    }
+
+    fn list_keys(&self, prefix: Option<&str>) -> Result<Vec<String>, StorageError> {
+        self.kv.list_keys(prefix)
+    }
+}
+
+/// A storage denial for `action` on `key`.
+pub fn denied(action: &str, key: &str, reason: &str) -> SecurityError {
+    SecurityError::Denied(PermissionDenial::new("storage", action, key, reason))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn value(bytes: &[u8]) -> StorageValue {
+        StorageValue::new(bytes.to_vec())
+    }
+
+    #[test]
+    fn test_namespaces_are_isolated_and_usage_tracked() {
+        let store = KvStore::in_memory().unwrap();
+        let a = store.component("a", StorageQuota::new()).unwrap();
+        let b = store.component("b", StorageQuota::new()).unwrap();
+
+        assert_eq!(a.get("k").unwrap(), None);
+        assert!(a.list_keys(None).unwrap().is_empty());
+
+        a.set("cart/1", value(b"xy")).unwrap();
+        a.set("cart/2", value(b"z")).unwrap();
+        a.set("config", value(b"")).unwrap();
+        b.set("cart/1", value(b"other")).unwrap();
+
+        assert_eq!(a.get("cart/1").unwrap(), Some(value(b"xy")));
+        assert_eq!(b.get("cart/1").unwrap(), Some(value(b"other")));
+        assert_eq!(a.list_keys(Some("cart/")).unwrap(), ["cart/1", "cart/2"]);
+        assert_eq!(a.list_keys(None).unwrap().len(), 3);
+        assert_eq!(
+            a.usage().unwrap(),
+            KvUsage {
+                used_bytes: 8 + 7 + 6,
+                key_count: 3
+            }
+        );
+
+        // Overwrites replace the old size; deleting missing keys is a no-op
+        a.set("cart/1", value(b"x")).unwrap();
+        a.delete("cart/2").unwrap();
+        a.delete("missing").unwrap();
+        assert_eq!(
+            a.usage().unwrap(),
+            KvUsage {
+                used_bytes: 7 + 6,
+                key_count: 2
+            }
+        );
+        assert!(!a.exists("cart/2").unwrap());
+        assert_eq!(b.usage().unwrap().key_count, 1);
+
+        assert!(matches!(a.get(""), Err(StorageError::InvalidKey(_))));
+        assert!(matches!(
+            a.set("a\nb", value(b"")),
+            Err(StorageError::InvalidKey(_))
+        ));
+        assert!(store.component("", StorageQuota::new()).is_err());
+    }
+
+    #[test]
+    fn test_quota_rejects_writes_without_changing_data() {
+        let store = KvStore::in_memory().unwrap();
+        let quota = StorageQuota::new().with_max_bytes(10).with_max_keys(2);
+        let kv = store.component("q", quota).unwrap();
+
+        kv.set("a", value(b"1234")).unwrap();
+        assert_eq!(
+            kv.set("a", value(b"0123456789")),
+            Err(StorageError::QuotaExceeded)
+        );
+        assert_eq!(kv.get("a").unwrap(), Some(value(b"1234")));
+
+        kv.set("b", value(b"1")).unwrap();
+        assert_eq!(kv.set("c", value(b"")), Err(StorageError::QuotaExceeded));
+        assert!(!kv.exists("c").unwrap());
+
+        // Shrinking works even after the quota is lowered
+        let tighter = store
+            .component("q", StorageQuota::new().with_max_keys(1))
+            .unwrap();
+        tighter.set("a", value(b"")).unwrap();
+        tighter.delete("b").unwrap();
+        assert_eq!(
+            tighter.usage().unwrap(),
+            KvUsage {
+                used_bytes: 1,
+                key_count: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_max_value_bytes_rejects_large_values() {
+        let store = KvStore::in_memory().unwrap();
+        let kv = store
+            .component("v", StorageQuota::new().with_max_value_bytes(4))
+            .unwrap();
+
+        kv.set("a", value(b"1234")).unwrap();
+        assert_eq!(
+            kv.set("a", value(b"12345")),
+            Err(StorageError::QuotaExceeded)
+        );
+        assert_eq!(
+            kv.set_with_ttl("b", value(b"12345"), Duration::from_secs(60)),
+            Err(StorageError::QuotaExceeded)
+        );
+        let mut txn = kv.begin().unwrap();
+        assert_eq!(
+            txn.set("c", value(b"12345")),
+            Err(StorageError::QuotaExceeded)
+        );
+        assert_eq!(kv.get("a").unwrap(), Some(value(b"1234")));
+    }
+
+    #[test]
+    fn test_expired_keys_are_hidden_then_swept() {
+        let store = KvStore::in_memory().unwrap();
+        let a = store.component("a", StorageQuota::new()).unwrap();
+        let b = store.component("b", StorageQuota::new()).unwrap();
+
+        a.set_with_ttl("cache/1", value(b"x"), Duration::ZERO)
+            .unwrap();
+        a.set_with_ttl("cache/2", value(b"y"), Duration::from_secs(3600))
+            .unwrap();
+        a.set_with_ttl("pinned", value(b"z"), Duration::ZERO)
+            .unwrap();
+        a.set("pinned", value(b"z")).unwrap();
+        b.set_with_ttl("cache/1", value(b"x"), Duration::ZERO)
+            .unwrap();
+
+        assert_eq!(a.get("cache/1").unwrap(), None);
+        assert!(!a.exists("cache/1").unwrap());
+        assert_eq!(a.list_keys(None).unwrap(), ["cache/2", "pinned"]);
+        assert_eq!(a.get("pinned").unwrap(), Some(value(b"z")));
+
+        // Expired keys count against the quota until swept
+        assert_eq!(a.usage().unwrap().key_count, 3);
+        assert_eq!(store.expire_due().unwrap(), 2);
+        assert_eq!(
+            a.usage().unwrap(),
+            KvUsage {
+                used_bytes: 8 + 7,
+                key_count: 2
+            }
+        );
+        assert_eq!(b.usage().unwrap(), KvUsage::default());
+        assert_eq!(store.expire_due().unwrap(), 0);
+
+        // A transaction that saw the key as missing does not conflict
+        // with its expiry
+        a.set_with_ttl("lease", value(b"1"), Duration::ZERO)
+            .unwrap();
+        let mut txn = a.begin().unwrap();
+        assert_eq!(txn.get("lease").unwrap(), None);
+        txn.set("lease", value(b"2")).unwrap();
+        txn.commit().unwrap();
+        assert_eq!(store.expire_due().unwrap(), 0);
+        assert_eq!(a.get("lease").unwrap(), Some(value(b"2")));
+
+        let guest = GuestStorage::open(
+            &store,
+            &ComponentId::new("shop", "cart", "0"),
+            StorageGrants::default(),
+        )
+        .unwrap();
+        guest.begin().unwrap();
+        assert!(matches!(
+            guest.set_with_ttl("k", value(b""), Duration::ZERO),
+            Err(StorageError::InvalidTransaction(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_spawn_expiry_sweeps_and_stops_with_store() {
+        let store = KvStore::in_memory().unwrap();
+        let kv = store.component("a", StorageQuota::new()).unwrap();
+        kv.set_with_ttl("k", value(b"v"), Duration::ZERO).unwrap();
+
+        let task = store.spawn_expiry(Duration::from_millis(10));
+        for _ in 0..100 {
+            if kv.usage().unwrap().key_count == 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(kv.usage().unwrap().key_count, 0);
+
+        drop(kv);
+        drop(store);
+        tokio::time::timeout(Duration::from_secs(5), task)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_transaction_applies_all_writes_or_none() {
+        let store = KvStore::in_memory().unwrap();
+        let kv = store
+            .component("t", StorageQuota::new().with_max_keys(2))
+            .unwrap();
+        kv.set("a", value(b"1")).unwrap();
+
+        let mut txn = kv.begin().unwrap();
+        txn.set("b", value(b"2")).unwrap();
+        txn.delete("a").unwrap();
+        assert_eq!(txn.get("b").unwrap(), Some(value(b"2")));
+        assert!(!txn.exists("a").unwrap());
+        assert!(kv.exists("a").unwrap());
+        txn.commit().unwrap();
+        assert_eq!(kv.list_keys(None).unwrap(), ["b"]);
+        assert_eq!(kv.usage().unwrap().key_count, 1);
+
+        // Rolled back and quota-rejected transactions change nothing
+        let mut txn = kv.begin().unwrap();
+        txn.set("c", value(b"3")).unwrap();
+        txn.rollback();
+        let mut txn = kv.begin().unwrap();
+        txn.set("c", value(b"3")).unwrap();
+        txn.set("d", value(b"4")).unwrap();
+        assert_eq!(txn.commit(), Err(StorageError::QuotaExceeded));
+        assert_eq!(kv.list_keys(None).unwrap(), ["b"]);
+    }
+
+    #[test]
+    fn test_concurrent_transactions_conflict() {
+        let store = KvStore::in_memory().unwrap();
+        let first = store.component("shared", StorageQuota::new()).unwrap();
+        let second = store.component("shared", StorageQuota::new()).unwrap();
+        first.set("count", value(b"1")).unwrap();
+
+        let mut a = first.begin().unwrap();
+        let mut b = second.begin().unwrap();
+        a.get("count").unwrap();
+        b.get("count").unwrap();
+        a.set("count", value(b"2")).unwrap();
+        b.set("count", value(b"3")).unwrap();
+        b.set("other", value(b"x")).unwrap();
+        a.commit().unwrap();
+        assert_eq!(b.commit(), Err(StorageError::Conflict("count".to_string())));
+        assert_eq!(first.get("count").unwrap(), Some(value(b"2")));
+        assert!(!first.exists("other").unwrap());
+
+        // Disjoint keys do not conflict
+        let mut a = first.begin().unwrap();
+        let mut b = second.begin().unwrap();
+        a.set("x", value(b"1")).unwrap();
+        b.set("y", value(b"1")).unwrap();
+        a.commit().unwrap();
+        b.commit().unwrap();
+    }
+
+    #[test]
+    fn test_guest_storage_routes_through_open_transaction() {
+        let store = KvStore::in_memory().unwrap();
+        let id = ComponentId::new("shop", "cart", "0");
+        let guest = GuestStorage::open(&store, &id, StorageGrants::default()).unwrap();
+
+        assert!(matches!(
+            guest.commit(),
+            Err(StorageError::InvalidTransaction(_))
+        ));
+        guest.begin().unwrap();
+        assert!(guest.begin().is_err());
+        guest.set("a", value(b"1")).unwrap();
+        assert!(guest.exists("a").unwrap());
+        assert!(!guest.kv().exists("a").unwrap());
+
+        // A trap leaves the transaction to be rolled back
+        assert!(guest.rollback());
+        assert!(!guest.in_transaction());
+        assert!(!guest.exists("a").unwrap());
+
+        guest.begin().unwrap();
+        guest.set("a", value(b"1")).unwrap();
+        guest.commit().unwrap();
+        assert!(!guest.rollback());
+        assert_eq!(guest.kv().get("a").unwrap(), Some(value(b"1")));
+    }
+
+    #[test]
+    fn test_data_persists_across_reopen() {
+        let path = std::env::temp_dir().join(format!(
+            "airssys-kv-{}-{}.redb",
+            std::process::id(),
+            line!()
+        ));
+        let id = ComponentId::new("shop", "cart", "0");
+        let grants = StorageGrants::default().with_keys("*");
+        {
+            let store = KvStore::open(&path).unwrap();
+            let guest = GuestStorage::open(&store, &id, grants.clone()).unwrap();
+            guest.kv().set("state", value(b"42")).unwrap();
+        }
+        let store = KvStore::open(&path).unwrap();
+        let guest = GuestStorage::open(&store, &id, grants).unwrap();
+        assert_eq!(guest.kv().namespace(), "shop/cart/0");
+        assert_eq!(guest.kv().get("state").unwrap(), Some(value(b"42")));
+        drop(guest);
+        drop(store);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_encrypted_namespaces_migrate_and_rotate() {
+        use crate::runtime::kv_crypto::{StaticKeyProvider, StorageKey};
+
+        let path = std::env::temp_dir().join(format!(
+            "airssys-kv-{}-{}.redb",
+            std::process::id(),
+            line!()
+        ));
+        let raw = |key: &str| {
+            let store = KvStore::open(&path).unwrap();
+            let kv = store.component("secrets", StorageQuota::new()).unwrap();
+            kv.get(key).unwrap().unwrap()
+        };
+        let open = |keys: StaticKeyProvider| {
+            let store = KvStore::open_encrypted(&path, Arc::new(keys)).unwrap();
+            (
+                store.component("secrets", StorageQuota::new()).unwrap(),
+                store.component("public", StorageQuota::new()).unwrap(),
+            )
+        };
+        let v1 = StaticKeyProvider::new().with_key("secrets", StorageKey::from_bytes(1, [1; 32]));
+        let v2 = v1
+            .clone()
+            .with_key("secrets", StorageKey::from_bytes(2, [2; 32]));
+
+        // Plaintext written before the namespace had a key
+        {
+            let store = KvStore::open(&path).unwrap();
+            let kv = store.component("secrets", StorageQuota::new()).unwrap();
+            kv.set("legacy", value(b"old-secret")).unwrap();
+        }
+        {
+            let (secrets, public) = open(v1.clone());
+            assert!(matches!(
+                secrets.get("legacy"),
+                Err(StorageError::Encryption(_))
+            ));
+            assert_eq!(secrets.rekey().unwrap(), 1);
+            assert_eq!(secrets.rekey().unwrap(), 0);
+            assert_eq!(secrets.get("legacy").unwrap(), Some(value(b"old-secret")));
+
+            secrets.set("token", value(b"hunter2")).unwrap();
+            secrets
+                .set_with_ttl("session", value(b"s"), Duration::from_secs(3600))
+                .unwrap();
+            let mut txn = secrets.begin().unwrap();
+            assert_eq!(txn.get("token").unwrap(), Some(value(b"hunter2")));
+            txn.set("token", value(b"hunter3")).unwrap();
+            txn.commit().unwrap();
+            public.set("motd", value(b"hello")).unwrap();
+        }
+        let sealed = raw("token");
+        assert!(!sealed.as_bytes().windows(7).any(|w| w == b"hunter3"));
+        assert_eq!(
+            KvStore::open(&path)
+                .unwrap()
+                .component("public", StorageQuota::new())
+                .unwrap()
+                .get("motd")
+                .unwrap(),
+            Some(value(b"hello"))
+        );
+
+        // Rotation: the old key opens old values until they are rekeyed
+        {
+            let (secrets, _) = open(v2.clone());
+            assert_eq!(secrets.get("token").unwrap(), Some(value(b"hunter3")));
+            assert_eq!(secrets.rekey().unwrap(), 3);
+            assert_eq!(
+                secrets.list_keys(None).unwrap(),
+                ["legacy", "session", "token"]
+            );
+        }
+        let (secrets, _) =
+            open(StaticKeyProvider::new().with_key("secrets", StorageKey::from_bytes(2, [2; 32])));
+        assert_eq!(secrets.get("session").unwrap(), Some(value(b"s")));
+        assert_eq!(secrets.get("token").unwrap(), Some(value(b"hunter3")));
+        drop(secrets);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_encryption_marker_is_not_guessed_from_values() {
+        use crate::runtime::kv_crypto::{StaticKeyProvider, StorageKey};
+
+        let path = std::env::temp_dir().join(format!(
+            "airssys-kv-{}-{}.redb",
+            std::process::id(),
+            line!()
+        ));
+        let keys = StaticKeyProvider::new().with_key("secrets", StorageKey::from_bytes(1, [1; 32]));
+        // Plaintext that looks like a sealed value
+        let lookalike = value(b"AKV1\0\0\0\x01 twelve bytes and then some");
+        {
+            let store = KvStore::open(&path).unwrap();
+            let kv = store.component("secrets", StorageQuota::new()).unwrap();
+            kv.set("legacy", lookalike.clone()).unwrap();
+        }
+        {
+            let store = KvStore::open_encrypted(&path, Arc::new(keys)).unwrap();
+            let secrets = store.component("secrets", StorageQuota::new()).unwrap();
+            // Unmigrated plaintext can be neither read nor joined by sealed values
+            assert!(matches!(
+                secrets.set("token", value(b"t")),
+                Err(StorageError::Encryption(_))
+            ));
+            assert_eq!(secrets.rekey().unwrap(), 1);
+            assert_eq!(secrets.get("legacy").unwrap(), Some(lookalike));
+
+            // The value cap counts the sealed size, like the other quotas
+            let capped = store
+                .component(
+                    "secrets",
+                    StorageQuota::new().with_max_value_bytes((SEAL_OVERHEAD + 4) as u64),
+                )
+                .unwrap();
+            capped.set("a", value(b"1234")).unwrap();
+            assert_eq!(
+                capped.set("a", value(b"12345")),
+                Err(StorageError::QuotaExceeded)
+            );
+        }
+
+        // A store without the key cannot write plaintext into the namespace
+        let store = KvStore::open(&path).unwrap();
+        let kv = store.component("secrets", StorageQuota::new()).unwrap();
+        assert!(matches!(
+            kv.set("plain", value(b"p")),
+            Err(StorageError::Encryption(_))
+        ));
+        drop(kv);
+        drop(store);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_grants_from_manifest_check_keys() {
+        let grants = StorageGrants::from_component_toml(
+            r#"
+            [storage]
+            namespace = "orders"
+
+            [capabilities.storage]
+            can_read_keys = ["cart/*", "config/*"]
+            can_write_keys = ["cart/*"]
+            max_bytes = 1024
+            max_value_bytes = 256
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            grants.namespace_for(&ComponentId::new("a", "b", "c")),
+            "orders"
+        );
+        assert_eq!(
+            grants.quota,
+            StorageQuota::new()
+                .with_max_bytes(1024)
+                .with_max_value_bytes(256)
+        );
+
+        let store = KvStore::in_memory().unwrap();
+        let guest = GuestStorage::open(&store, &ComponentId::new("a", "b", "c"), grants).unwrap();
+        assert!(guest.authorize_read("config/x").is_ok());
+        assert!(guest.authorize_write("cart/1").is_ok());
+        match guest.authorize_write("config/x") {
+            Err(SecurityError::Denied(denial)) => {
+                assert_eq!(denial.capability, "storage");
+                assert_eq!(denial.action, "write");
+                assert_eq!(denial.pattern, "config/x");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        assert_eq!(
+            StorageGrants::from_component_toml("[component]\nname = \"a\"\n").unwrap(),
+            StorageGrants::default()
+        );
+        assert!(
+            StorageGrants::from_component_toml("[capabilities.storage]\nmax_keys = \"x\"\n")
+                .is_err()
+        );
+    }
+}
@@ -129,8 +129,8 @@ pub fn apply_limits_to_store(
     store.data_mut().store_limits = limiter.into_store_limits();
 
     // 2. Configure the store's limiter callback
-    // This tells Wasmtime to use HostState.store_limits for memory/table checks
-    store.limiter(|state| &mut state.store_limits);
+    // HostState enforces store_limits and tracks committed memory
+    store.limiter(|state| state);
 
     // 3. Set fuel if configured
     if let Some(fuel) = limits.max_fuel {
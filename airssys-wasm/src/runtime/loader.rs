@@ -6,6 +6,25 @@
 //! - [`FileComponentLoader`] - Loads components from filesystem
 //! - [`InMemoryComponentLoader`] - In-memory loader for testing (cfg(test))
 //!
+//! It also provides [`CompiledArtifactCache`], a disk cache of ahead-of-time
+//! compiled components used by `WasmtimeEngine` to skip recompilation on restart.
+//! The cache location can be set in the `[runtime]` table of `Host.toml`
+//! (see [`CodeCacheConfig::from_host_toml`]). Cached artifacts are executed
+//! as native code, so the directory must be host-owned unless entries are
+//! authenticated with [`CompiledArtifactCache::with_secret`].
+//!
+//! Large components can be installed from any `AsyncRead` with
+//! [`FileComponentLoader::load_component_from_reader`], which validates the
+//! binary framing chunk by chunk and streams it to disk instead of
+//! buffering it in memory.
+//!
+//! When the loader holds an operator key
+//! ([`FileComponentLoader::with_token_key`]), components must be installed
+//! with [`FileComponentLoader::install_signed_component`]: the signed
+//! capability token shipped with the component is verified by
+//! [`FileComponentLoader::install_capability_token`] before the binary is
+//! written, and stored next to it as `{instance}.caps.json`.
+//!
 //! # Architecture
 //!
 //! These loaders implement the [`ComponentLoader`] trait defined in
@@ -22,15 +41,20 @@
 //! are valid WASM binaries before attempting execution.
 
 // Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
-// None needed for production code
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
 
 // Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
-// None needed
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
 
 // Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
 use crate::core::component::id::ComponentId;
 use crate::core::runtime::errors::WasmError;
 use crate::core::runtime::traits::ComponentLoader;
+use crate::core::security::token::{CapabilityDiff, CapabilityToken};
 
 /// File-based component loader.
 ///
@@ -57,6 +81,8 @@ use crate::core::runtime::traits::ComponentLoader;
 /// ```
 pub struct FileComponentLoader {
     base_path: String,
+    max_component_bytes: u64,
+    token_key: Option<[u8; 32]>,
 }
 
 impl FileComponentLoader {
@@ -76,7 +102,256 @@ impl FileComponentLoader {
     pub fn new(base_path: impl Into<String>) -> Self {
         Self {
             base_path: base_path.into(),
+            max_component_bytes: Self::DEFAULT_MAX_COMPONENT_BYTES,
+            token_key: None,
+        }
+    }
+
+    /// Default size cap for components streamed from a reader (1 GiB).
+    pub const DEFAULT_MAX_COMPONENT_BYTES: u64 = 1024 * 1024 * 1024;
+
+    /// Sets the size cap for components streamed from a reader.
+    pub fn with_max_component_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_component_bytes = max_bytes;
+        self
+    }
+
+    /// Sets the operator public key that capability tokens must be signed with.
+    pub fn with_token_key(mut self, public_key: [u8; 32]) -> Self {
+        self.token_key = Some(public_key);
+        self
+    }
+
+    /// Verifies and installs the signed capability token shipped with `id`.
+    ///
+    /// The token must be signed with the key set by
+    /// [`with_token_key`](Self::with_token_key) and issued for `id`. Its
+    /// grants are compared with the `[capabilities]` declared in `manifest`
+    /// (the component's `Component.toml`); the differences are returned so
+    /// the caller can decide whether to accept them.
+    ///
+    /// # Errors
+    ///
+    /// - `WasmError::ConfigRejected` - no key configured, or the token is
+    ///   malformed, wrongly signed, issued for another component, or the
+    ///   manifest cannot be parsed
+    /// - `WasmError::RuntimeError` - writing the token to disk failed
+    pub fn install_capability_token(
+        &self,
+        id: &ComponentId,
+        token: &str,
+        manifest: &str,
+    ) -> Result<CapabilityDiff, WasmError> {
+        let rejected = |e: crate::core::security::token::CapabilityTokenError| {
+            WasmError::ConfigRejected(format!("{}: {}", id.to_string_id(), e))
+        };
+        let key = self.token_key.ok_or_else(|| {
+            WasmError::ConfigRejected("No capability token key configured".to_string())
+        })?;
+        let token = CapabilityToken::from_json(token).map_err(rejected)?;
+        token.verify_for(id, &key).map_err(rejected)?;
+        let diff = token.diff_against_manifest(manifest).map_err(rejected)?;
+
+        let path = self.token_path(id);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                WasmError::RuntimeError(format!("Failed to create {}: {}", parent.display(), e))
+            })?;
+        }
+        let json = token.to_json().map_err(rejected)?;
+        std::fs::write(&path, json).map_err(|e| {
+            WasmError::RuntimeError(format!("Failed to write {}: {}", path.display(), e))
+        })?;
+
+        if !diff.is_empty() {
+            tracing::warn!(
+                component = %id.to_string_id(),
+                undeclared = diff.undeclared.len(),
+                ungranted = diff.ungranted.len(),
+                "capability token differs from declared permissions"
+            );
+        }
+        Ok(diff)
+    }
+
+    /// Reads and re-verifies the installed capability token of `id`.
+    ///
+    /// Returns `Ok(None)` if no token is installed.
+    ///
+    /// # Errors
+    ///
+    /// - `WasmError::ConfigRejected` - no key configured, or the stored
+    ///   token no longer verifies
+    /// - `WasmError::RuntimeError` - the token file cannot be read
+    pub fn load_capability_token(
+        &self,
+        id: &ComponentId,
+    ) -> Result<Option<CapabilityToken>, WasmError> {
+        let key = self.token_key.ok_or_else(|| {
+            WasmError::ConfigRejected("No capability token key configured".to_string())
+        })?;
+        let path = self.token_path(id);
+        let json = match std::fs::read_to_string(&path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(WasmError::RuntimeError(format!(
+                    "Failed to read {}: {}",
+                    path.display(),
+                    e
+                )))
+            }
+        };
+        let token = CapabilityToken::from_json(&json)
+            .and_then(|token| token.verify_for(id, &key).map(|()| token))
+            .map_err(|e| WasmError::ConfigRejected(format!("{}: {}", id.to_string_id(), e)))?;
+        Ok(Some(token))
+    }
+
+    /// Verifies the capability token of `id` and installs its binary from `reader`.
+    ///
+    /// The token is checked with
+    /// [`install_capability_token`](Self::install_capability_token) before
+    /// any of the binary is read, so a wrongly signed token fails the
+    /// install without touching the disk. The binary is then streamed as in
+    /// [`load_component_from_reader`](Self::load_component_from_reader); if
+    /// that fails the token is removed again.
+    ///
+    /// Returns the differences between the granted and declared capabilities.
+    ///
+    /// # Errors
+    ///
+    /// - `WasmError::ConfigRejected` - the token does not verify (see
+    ///   [`install_capability_token`](Self::install_capability_token))
+    /// - `WasmError::InvalidComponent` - bad header, malformed or truncated sections
+    /// - `WasmError::ResourceLimitExceeded` - the binary exceeds the size cap
+    /// - `WasmError::RuntimeError` - reading from `reader` or writing to disk failed
+    pub async fn install_signed_component<R>(
+        &self,
+        id: &ComponentId,
+        reader: R,
+        token: &str,
+        manifest: &str,
+    ) -> Result<CapabilityDiff, WasmError>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let diff = self.install_capability_token(id, token, manifest)?;
+        if let Err(e) = self.stream_component(id, reader).await {
+            let _ = std::fs::remove_file(self.token_path(id));
+            return Err(e);
+        }
+        Ok(diff)
+    }
+
+    /// Streams a component binary from `reader` and installs it as `id`.
+    ///
+    /// The binary is validated incrementally as chunks arrive: the header
+    /// must be a WASM module or component preamble and every section must
+    /// be well framed and fit within the size cap. Only the current chunk is
+    /// held in memory; data goes to a temporary file next to the final path,
+    /// which is renamed into place once the whole binary has been validated.
+    /// Afterwards the component is available through
+    /// [`load_bytes`](ComponentLoader::load_bytes).
+    ///
+    /// Returns the number of bytes installed.
+    ///
+    /// # Errors
+    ///
+    /// - `WasmError::ConfigRejected` - a token key is configured; use
+    ///   [`install_signed_component`](Self::install_signed_component)
+    /// - `WasmError::InvalidComponent` - bad header, malformed or truncated sections
+    /// - `WasmError::ResourceLimitExceeded` - the binary exceeds the size cap
+    /// - `WasmError::RuntimeError` - reading from `reader` or writing to disk failed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use airssys_wasm::runtime::loader::FileComponentLoader;
+    /// use airssys_wasm::core::component::id::ComponentId;
+    ///
+    /// # async fn install() -> Result<(), airssys_wasm::core::runtime::errors::WasmError> {
+    /// let loader = FileComponentLoader::new("/wasm/components")
+    ///     .with_max_component_bytes(512 * 1024 * 1024);
+    /// let id = ComponentId::new("system", "database", "prod");
+    ///
+    /// let file = tokio::fs::File::open("/downloads/database.wasm").await.unwrap();
+    /// let installed = loader.load_component_from_reader(&id, file).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn load_component_from_reader<R>(
+        &self,
+        id: &ComponentId,
+        reader: R,
+    ) -> Result<u64, WasmError>
+    where
+        R: AsyncRead + Unpin,
+    {
+        if self.token_key.is_some() {
+            return Err(WasmError::ConfigRejected(format!(
+                "{}: a signed capability token is required to install this component",
+                id.to_string_id()
+            )));
         }
+        self.stream_component(id, reader).await
+    }
+
+    /// Validates and writes a component binary streamed from `reader`.
+    async fn stream_component<R>(&self, id: &ComponentId, mut reader: R) -> Result<u64, WasmError>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let path = PathBuf::from(self.component_path(id));
+        let io_error = |action: &str, e: std::io::Error| {
+            WasmError::RuntimeError(format!("Failed to {} {}: {}", action, path.display(), e))
+        };
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| io_error("create directory for", e))?;
+        }
+        let partial = path.with_extension(format!("wasm.partial-{}", uuid::Uuid::new_v4()));
+        let mut file = tokio::fs::File::create(&partial)
+            .await
+            .map_err(|e| io_error("create", e))?;
+
+        let mut validator = StreamingValidator::new(self.max_component_bytes);
+        let mut buffer = vec![0u8; READ_CHUNK_BYTES];
+        let result = async {
+            loop {
+                let read = reader
+                    .read(&mut buffer)
+                    .await
+                    .map_err(|e| io_error("read component for", e))?;
+                if read == 0 {
+                    break;
+                }
+                validator.feed(&buffer[..read])?;
+                file.write_all(&buffer[..read])
+                    .await
+                    .map_err(|e| io_error("write", e))?;
+            }
+            validator.finish()?;
+            file.sync_all().await.map_err(|e| io_error("sync", e))?;
+            tokio::fs::rename(&partial, &path)
+                .await
+                .map_err(|e| io_error("install", e))
+        }
+        .await;
+
+        if result.is_err() {
+            let _ = tokio::fs::remove_file(&partial).await;
+        }
+        result?;
+
+        tracing::debug!(
+            component = %id.to_string_id(),
+            bytes = validator.total,
+            "component installed from reader"
+        );
+        Ok(validator.total)
     }
 
     /// Constructs the filesystem path for a component.
@@ -97,6 +372,14 @@ impl FileComponentLoader {
             self.base_path, id.namespace, id.name, id.instance
         )
     }
+
+    /// Path of the installed capability token of a component.
+    fn token_path(&self, id: &ComponentId) -> PathBuf {
+        PathBuf::from(format!(
+            "{}/{}/{}/{}.caps.json",
+            self.base_path, id.namespace, id.name, id.instance
+        ))
+    }
 }
 
 impl ComponentLoader for FileComponentLoader {
@@ -141,6 +424,568 @@ impl ComponentLoader for FileComponentLoader {
     }
 }
 
+/// Read buffer size for [`FileComponentLoader::load_component_from_reader`].
+const READ_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Preamble version of a core module (version 1).
+const MODULE_VERSION: [u8; 4] = [0x01, 0x00, 0x00, 0x00];
+
+/// Preamble version of a component (version 0x0d, layer 1).
+const COMPONENT_VERSION: [u8; 4] = [0x0d, 0x00, 0x01, 0x00];
+
+/// Highest section ID defined by the module and component binary formats.
+const MAX_SECTION_ID: u8 = 13;
+
+#[derive(Debug, Clone, Copy)]
+enum FrameState {
+    /// Collecting the 8-byte preamble.
+    Preamble,
+    /// Expecting a section ID byte.
+    SectionId,
+    /// Decoding a section's LEB128 size.
+    SectionSize { size: u64, shift: u32 },
+    /// Skipping a section payload.
+    Payload { remaining: u64 },
+}
+
+/// Checks the top-level framing of a WASM binary as it arrives in chunks.
+///
+/// Section contents are not inspected (wasmtime validates them on
+/// compilation), but the preamble, section IDs and section sizes are, so a
+/// corrupted or oversized download is rejected without reading it to the end.
+struct StreamingValidator {
+    max_bytes: u64,
+    total: u64,
+    preamble: Vec<u8>,
+    state: FrameState,
+}
+
+impl StreamingValidator {
+    fn new(max_bytes: u64) -> Self {
+        Self {
+            max_bytes,
+            total: 0,
+            preamble: Vec::with_capacity(8),
+            state: FrameState::Preamble,
+        }
+    }
+
+    fn feed(&mut self, chunk: &[u8]) -> Result<(), WasmError> {
+        self.total += chunk.len() as u64;
+        if self.total > self.max_bytes {
+            return Err(WasmError::ResourceLimitExceeded(format!(
+                "Component exceeds the {} byte limit",
+                self.max_bytes
+            )));
+        }
+
+        let mut rest = chunk;
+        while !rest.is_empty() {
+            match self.state {
+                FrameState::Preamble => {
+                    let take = (8 - self.preamble.len()).min(rest.len());
+                    self.preamble.extend_from_slice(&rest[..take]);
+                    rest = &rest[take..];
+                    if self.preamble.len() >= 4 && &self.preamble[..4] != b"\0asm" {
+                        return Err(WasmError::InvalidComponent(
+                            "Invalid WASM magic number".to_string(),
+                        ));
+                    }
+                    if self.preamble.len() == 8 {
+                        let version = &self.preamble[4..8];
+                        if version != MODULE_VERSION && version != COMPONENT_VERSION {
+                            return Err(WasmError::InvalidComponent(format!(
+                                "Unsupported WASM version {:02x?}",
+                                version
+                            )));
+                        }
+                        self.state = FrameState::SectionId;
+                    }
+                }
+                FrameState::SectionId => {
+                    if rest[0] > MAX_SECTION_ID {
+                        return Err(WasmError::InvalidComponent(format!(
+                            "Unknown section ID {} at offset {}",
+                            rest[0],
+                            self.offset(rest)
+                        )));
+                    }
+                    rest = &rest[1..];
+                    self.state = FrameState::SectionSize { size: 0, shift: 0 };
+                }
+                FrameState::SectionSize { size, shift } => {
+                    let byte = rest[0];
+                    rest = &rest[1..];
+                    // Section sizes are u32: at most 5 LEB128 bytes
+                    if shift > 28 {
+                        return Err(WasmError::InvalidComponent(
+                            "Section size is not a valid u32".to_string(),
+                        ));
+                    }
+                    let size = size | (u64::from(byte & 0x7f) << shift);
+                    if byte & 0x80 != 0 {
+                        self.state = FrameState::SectionSize {
+                            size,
+                            shift: shift + 7,
+                        };
+                        continue;
+                    }
+                    let end = self.offset(rest) + size;
+                    if end > self.max_bytes {
+                        return Err(WasmError::ResourceLimitExceeded(format!(
+                            "Section ending at offset {} exceeds the {} byte limit",
+                            end, self.max_bytes
+                        )));
+                    }
+                    self.state = if size == 0 {
+                        FrameState::SectionId
+                    } else {
+                        FrameState::Payload { remaining: size }
+                    };
+                }
+                FrameState::Payload { remaining } => {
+                    let take = remaining.min(rest.len() as u64);
+                    rest = &rest[take as usize..];
+                    self.state = if remaining == take {
+                        FrameState::SectionId
+                    } else {
+                        FrameState::Payload {
+                            remaining: remaining - take,
+                        }
+                    };
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Offset in the stream of the first byte of `rest`.
+    fn offset(&self, rest: &[u8]) -> u64 {
+        self.total - rest.len() as u64
+    }
+
+    fn finish(&self) -> Result<(), WasmError> {
+        match self.state {
+            FrameState::SectionId => Ok(()),
+            FrameState::Preamble => Err(WasmError::InvalidComponent("File too small".to_string())),
+            _ => Err(WasmError::InvalidComponent(format!(
+                "Truncated section at offset {}",
+                self.total
+            ))),
+        }
+    }
+}
+
+/// Configuration for the compiled-artifact cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeCacheConfig {
+    /// Directory holding the cached artifacts.
+    pub dir: PathBuf,
+
+    /// Upper bound for the total size of cached artifacts, in bytes.
+    pub max_bytes: u64,
+}
+
+impl CodeCacheConfig {
+    /// Default size budget: 512 MiB.
+    pub const DEFAULT_MAX_BYTES: u64 = 512 * 1024 * 1024;
+
+    /// Creates a configuration with the default size budget.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            max_bytes: Self::DEFAULT_MAX_BYTES,
+        }
+    }
+
+    /// Sets the size budget for cached artifacts.
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Reads `code_cache_dir` and `code_cache_max_bytes` from the
+    /// `[runtime]` table of a `Host.toml`.
+    ///
+    /// Returns `None` if no cache directory is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::RuntimeError` if the TOML is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use airssys_wasm::runtime::loader::CodeCacheConfig;
+    ///
+    /// let config = CodeCacheConfig::from_host_toml(
+    ///     "[runtime]\ncode_cache_dir = \"/var/cache/airssys\"\n",
+    /// )
+    /// .unwrap()
+    /// .unwrap();
+    /// assert_eq!(config.max_bytes, CodeCacheConfig::DEFAULT_MAX_BYTES);
+    /// ```
+    pub fn from_host_toml(source: &str) -> Result<Option<Self>, WasmError> {
+        #[derive(Deserialize, Default)]
+        struct HostFile {
+            #[serde(default)]
+            runtime: RuntimeSection,
+        }
+
+        #[derive(Deserialize, Default)]
+        struct RuntimeSection {
+            code_cache_dir: Option<PathBuf>,
+            code_cache_max_bytes: Option<u64>,
+        }
+
+        let file: HostFile = toml::from_str(source)
+            .map_err(|e| WasmError::RuntimeError(format!("Invalid host config: {}", e)))?;
+        Ok(file.runtime.code_cache_dir.map(|dir| {
+            let config = Self::new(dir);
+            match file.runtime.code_cache_max_bytes {
+                Some(max_bytes) => config.with_max_bytes(max_bytes),
+                None => config,
+            }
+        }))
+    }
+}
+
+/// Counters describing cache effectiveness.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CodeCacheStats {
+    /// Lookups served from disk.
+    pub hits: u64,
+    /// Lookups that required compilation.
+    pub misses: u64,
+    /// Artifacts written to disk.
+    pub stores: u64,
+    /// Artifacts removed to stay within the size budget.
+    pub evictions: u64,
+    /// Artifacts discarded because they failed validation.
+    pub invalid: u64,
+}
+
+/// On-disk cache of ahead-of-time compiled components.
+///
+/// Wasmtime compiles every component from scratch on load. This cache keeps
+/// the serialized compilation output on disk so a restarted host can skip
+/// compilation for components it has seen before.
+///
+/// # Keying
+///
+/// Entries are keyed by the SHA-256 digest of the component binary plus a
+/// hash of the engine configuration. Changing engine settings or upgrading
+/// wasmtime therefore never reuses an incompatible artifact.
+///
+/// # Validation
+///
+/// Each file starts with a magic tag and a 32-byte checksum of the artifact
+/// body. Truncated or corrupted files are detected, deleted, and treated as
+/// a miss.
+///
+/// Without a secret the checksum is a plain SHA-256, which only detects
+/// accidental corruption. With [`with_secret`](Self::with_secret) it is an
+/// HMAC-SHA256 over the entry key and the body, so entries cannot be
+/// forged or swapped between keys by anyone who does not hold the secret.
+///
+/// # Eviction
+///
+/// After each store, the least recently used artifacts (by modification
+/// time, refreshed on every hit) are removed until the cache fits in
+/// `max_bytes`.
+///
+/// # Trust
+///
+/// Artifacts are native code that `WasmtimeEngine` maps and executes
+/// without further checks. An unkeyed cache trusts its directory: anyone
+/// able to write there can plant an artifact with a valid SHA-256 and have
+/// it run inside the host, so the directory must be owned by and writable
+/// only by the host user. Set a host secret (kept outside the cache
+/// directory) when that cannot be guaranteed.
+pub struct CompiledArtifactCache {
+    config: CodeCacheConfig,
+    secret: Option<[u8; 32]>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    stores: AtomicU64,
+    evictions: AtomicU64,
+    invalid: AtomicU64,
+}
+
+/// File header tag for cached artifacts (format version 1).
+const ARTIFACT_MAGIC: &[u8; 8] = b"AIRSCC01";
+
+/// File extension for cached artifacts.
+const ARTIFACT_EXTENSION: &str = "cwasm";
+
+impl CompiledArtifactCache {
+    /// Opens (creating if needed) a cache at `config.dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::RuntimeError` if the directory cannot be created.
+    pub fn new(config: CodeCacheConfig) -> Result<Self, WasmError> {
+        std::fs::create_dir_all(&config.dir).map_err(|e| {
+            WasmError::RuntimeError(format!(
+                "Failed to create code cache {}: {}",
+                config.dir.display(),
+                e
+            ))
+        })?;
+
+        Ok(Self {
+            config,
+            secret: None,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            stores: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+            invalid: AtomicU64::new(0),
+        })
+    }
+
+    /// Authenticates entries with an HMAC keyed by the host `secret`.
+    ///
+    /// Entries written without the secret (or with another one) fail
+    /// validation and are recompiled.
+    pub fn with_secret(mut self, secret: [u8; 32]) -> Self {
+        self.secret = Some(secret);
+        self
+    }
+
+    /// Returns the cache configuration.
+    pub fn config(&self) -> &CodeCacheConfig {
+        &self.config
+    }
+
+    /// Computes the hex-encoded SHA-256 digest of a component binary.
+    pub fn module_digest(bytes: &[u8]) -> String {
+        to_hex(&Sha256::digest(bytes))
+    }
+
+    /// Loads a validated artifact, or `None` on a miss.
+    ///
+    /// Invalid entries are deleted and counted in `CodeCacheStats::invalid`.
+    pub fn load(&self, digest: &str, config_hash: u64) -> Option<Vec<u8>> {
+        let path = self.entry_path(digest, config_hash);
+        let Ok(contents) = std::fs::read(&path) else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return None;
+        };
+
+        match self.split_artifact(digest, config_hash, &contents) {
+            Some(artifact) => {
+                // Refresh recency for LRU eviction; failure only affects ordering
+                if let Ok(file) = std::fs::File::options().append(true).open(&path) {
+                    let _ = file.set_modified(SystemTime::now());
+                }
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(artifact.to_vec())
+            }
+            None => {
+                let _ = std::fs::remove_file(&path);
+                self.invalid.fetch_add(1, Ordering::Relaxed);
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Persists an artifact and evicts old entries beyond the size budget.
+    ///
+    /// The file is written to a temporary name and renamed into place so
+    /// concurrent readers never observe a partial artifact.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::RuntimeError` if the artifact cannot be written.
+    pub fn store(&self, digest: &str, config_hash: u64, artifact: &[u8]) -> Result<(), WasmError> {
+        let path = self.entry_path(digest, config_hash);
+        let tmp = path.with_extension(format!("{}.tmp", std::process::id()));
+
+        let mut contents = Vec::with_capacity(ARTIFACT_MAGIC.len() + 32 + artifact.len());
+        contents.extend_from_slice(ARTIFACT_MAGIC);
+        contents.extend_from_slice(&self.checksum(digest, config_hash, artifact));
+        contents.extend_from_slice(artifact);
+
+        std::fs::write(&tmp, &contents)
+            .and_then(|()| std::fs::rename(&tmp, &path))
+            .map_err(|e| {
+                let _ = std::fs::remove_file(&tmp);
+                WasmError::RuntimeError(format!(
+                    "Failed to write code cache entry {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+        self.stores.fetch_add(1, Ordering::Relaxed);
+
+        self.evict()?;
+        Ok(())
+    }
+
+    /// Removes an entry, e.g. after the engine rejected it.
+    pub fn invalidate(&self, digest: &str, config_hash: u64) {
+        if std::fs::remove_file(self.entry_path(digest, config_hash)).is_ok() {
+            self.invalid.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Removes least recently used entries until the cache fits its budget.
+    ///
+    /// Returns the number of entries removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::RuntimeError` if the cache directory cannot be read.
+    pub fn evict(&self) -> Result<usize, WasmError> {
+        let mut entries = self.entries()?;
+        let mut total: u64 = entries.iter().map(|(_, size, _)| size).sum();
+        if total <= self.config.max_bytes {
+            return Ok(0);
+        }
+
+        // Oldest first
+        entries.sort_by_key(|(_, _, modified)| *modified);
+
+        let mut removed = 0;
+        for (path, size, _) in entries {
+            if total <= self.config.max_bytes {
+                break;
+            }
+            if std::fs::remove_file(&path).is_ok() {
+                total = total.saturating_sub(size);
+                removed += 1;
+            }
+        }
+        self.evictions.fetch_add(removed as u64, Ordering::Relaxed);
+        Ok(removed)
+    }
+
+    /// Returns the total size of cached artifacts, in bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WasmError::RuntimeError` if the cache directory cannot be read.
+    pub fn total_bytes(&self) -> Result<u64, WasmError> {
+        Ok(self.entries()?.iter().map(|(_, size, _)| size).sum())
+    }
+
+    /// Returns a snapshot of the cache counters.
+    pub fn stats(&self) -> CodeCacheStats {
+        CodeCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            stores: self.stores.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
+            invalid: self.invalid.load(Ordering::Relaxed),
+        }
+    }
+
+    fn entry_path(&self, digest: &str, config_hash: u64) -> PathBuf {
+        self.config.dir.join(format!(
+            "{}.{}",
+            entry_key(digest, config_hash),
+            ARTIFACT_EXTENSION
+        ))
+    }
+
+    /// Checksum stored in the header of the entry for `digest`/`config_hash`.
+    fn checksum(&self, digest: &str, config_hash: u64, artifact: &[u8]) -> [u8; 32] {
+        match &self.secret {
+            Some(secret) => hmac_sha256(
+                secret,
+                &[entry_key(digest, config_hash).as_bytes(), artifact],
+            ),
+            None => Sha256::digest(artifact).into(),
+        }
+    }
+
+    /// Validates the header of a cached file and returns the artifact body.
+    fn split_artifact<'a>(
+        &self,
+        digest: &str,
+        config_hash: u64,
+        contents: &'a [u8],
+    ) -> Option<&'a [u8]> {
+        let body = contents.strip_prefix(ARTIFACT_MAGIC.as_slice())?;
+        if body.len() < 32 {
+            return None;
+        }
+        let (checksum, artifact) = body.split_at(32);
+        let expected = self.checksum(digest, config_hash, artifact);
+        // Constant time, so a keyed checksum cannot be guessed byte by byte
+        let diff = expected
+            .iter()
+            .zip(checksum)
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+        (diff == 0).then_some(artifact)
+    }
+
+    /// Lists cached artifacts as `(path, size, modified)`.
+    fn entries(&self) -> Result<Vec<(PathBuf, u64, SystemTime)>, WasmError> {
+        let dir = std::fs::read_dir(&self.config.dir).map_err(|e| {
+            WasmError::RuntimeError(format!(
+                "Failed to read code cache {}: {}",
+                self.config.dir.display(),
+                e
+            ))
+        })?;
+
+        Ok(dir
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                entry.path().extension().and_then(|ext| ext.to_str()) == Some(ARTIFACT_EXTENSION)
+            })
+            .filter_map(|entry| {
+                let metadata = entry.metadata().ok()?;
+                let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                Some((entry.path(), metadata.len(), modified))
+            })
+            .collect())
+    }
+}
+
+impl std::fmt::Debug for CompiledArtifactCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompiledArtifactCache")
+            .field("config", &self.config)
+            .field("keyed", &self.secret.is_some())
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+/// Name of the cache entry for a component digest and engine configuration.
+fn entry_key(digest: &str, config_hash: u64) -> String {
+    format!("{}-{:016x}", digest, config_hash)
+}
+
+/// HMAC-SHA256 (RFC 2104) of the concatenated `parts`.
+fn hmac_sha256(key: &[u8; 32], parts: &[&[u8]]) -> [u8; 32] {
+    const BLOCK: usize = 64;
+    let mut inner_pad = [0x36u8; BLOCK];
+    let mut outer_pad = [0x5cu8; BLOCK];
+    for (i, byte) in key.iter().enumerate() {
+        inner_pad[i] ^= byte;
+        outer_pad[i] ^= byte;
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(inner_pad);
+    for part in parts {
+        inner.update(part);
+    }
+    let mut outer = Sha256::new();
+    outer.update(outer_pad);
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 /// In-memory component loader for testing.
 ///
 /// `InMemoryComponentLoader` stores WASM component binaries in memory,
@@ -247,6 +1092,76 @@ mod tests {
         assert_eq!(path, "/wasm/ns/comp/0.wasm");
     }
 
+    #[test]
+    fn test_capability_token_install_verifies_signature() {
+        use crate::core::security::capability::{Capability, StorageAction, StorageCapability};
+
+        let secret = [9u8; 32];
+        let id = ComponentId::new("ns", "comp", "0");
+        let (loader, dir) = temp_loader();
+        let loader = loader.with_token_key(CapabilityToken::public_key(&secret));
+        let grant = Capability::Storage(StorageCapability {
+            action: StorageAction::Read,
+            namespace_pattern: "data/*".to_string(),
+        });
+        let token = CapabilityToken::new(id.clone(), "ops", vec![grant]);
+        let manifest = "[capabilities.storage]\ncan_read_keys = [\"data/*\"]\n";
+
+        // Unsigned tokens are refused and nothing is installed
+        let unsigned = token.to_json().unwrap();
+        assert!(matches!(
+            loader.install_capability_token(&id, &unsigned, manifest),
+            Err(WasmError::ConfigRejected(_))
+        ));
+        assert!(loader.load_capability_token(&id).unwrap().is_none());
+
+        let signed = token.sign(&secret).to_json().unwrap();
+        let diff = loader
+            .install_capability_token(&id, &signed, manifest)
+            .unwrap();
+        assert!(diff.is_empty());
+        assert!(loader.load_capability_token(&id).unwrap().is_some());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn test_signed_install_fails_on_bad_signature() {
+        let secret = [9u8; 32];
+        let id = ComponentId::new("ns", "comp", "0");
+        let (loader, dir) = temp_loader();
+        let loader = loader.with_token_key(CapabilityToken::public_key(&secret));
+        let bytes = wat::parse_str("(component (core module (func)))").unwrap();
+        let token = CapabilityToken::new(id.clone(), "ops", Vec::new());
+
+        // Unsigned streams and wrongly signed tokens install nothing
+        let unsigned = loader.load_component_from_reader(&id, &bytes[..]).await;
+        assert!(matches!(unsigned, Err(WasmError::ConfigRejected(_))));
+        let forged = token.clone().sign(&[7u8; 32]).to_json().unwrap();
+        let rejected = loader
+            .install_signed_component(&id, &bytes[..], &forged, "")
+            .await;
+        assert!(matches!(rejected, Err(WasmError::ConfigRejected(_))));
+        assert!(loader.load_bytes(&id).is_err());
+        assert!(loader.load_capability_token(&id).unwrap().is_none());
+
+        // A failed binary stream removes the already verified token
+        let signed = token.sign(&secret).to_json().unwrap();
+        let truncated = loader
+            .install_signed_component(&id, &bytes[..bytes.len() - 1], &signed, "")
+            .await;
+        assert!(matches!(truncated, Err(WasmError::InvalidComponent(_))));
+        assert!(loader.load_capability_token(&id).unwrap().is_none());
+
+        let diff = loader
+            .install_signed_component(&id, &bytes[..], &signed, "")
+            .await
+            .unwrap();
+        assert!(diff.is_empty());
+        assert_eq!(loader.load_bytes(&id).unwrap(), bytes);
+        assert!(loader.load_capability_token(&id).unwrap().is_some());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
     #[test]
     fn test_validate_valid_wasm_magic() {
         let loader = FileComponentLoader::new("/wasm");
@@ -268,6 +1183,67 @@ mod tests {
         assert!(loader.validate(bytes).is_err());
     }
 
+    fn temp_loader() -> (FileComponentLoader, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("airssys-loader-{}", uuid::Uuid::new_v4()));
+        (
+            FileComponentLoader::new(dir.to_string_lossy().into_owned()),
+            dir,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_load_component_from_reader_in_chunks() {
+        let (loader, dir) = temp_loader();
+        let id = ComponentId::new("ns", "comp", "0");
+        let bytes = wat::parse_str("(component (core module (func)))").unwrap();
+
+        // Split inside the preamble and inside a section header
+        let mut reader = tokio_test::io::Builder::new()
+            .read(&bytes[..3])
+            .read(&bytes[3..9])
+            .read(&bytes[9..])
+            .build();
+        let installed = loader
+            .load_component_from_reader(&id, &mut reader)
+            .await
+            .unwrap();
+
+        assert_eq!(installed, bytes.len() as u64);
+        assert_eq!(loader.load_bytes(&id).unwrap(), bytes);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn test_load_component_from_reader_rejects_bad_streams() {
+        let (loader, dir) = temp_loader();
+        let id = ComponentId::new("ns", "comp", "0");
+        let bytes = wat::parse_str("(component (core module (func)))").unwrap();
+
+        let truncated = loader
+            .load_component_from_reader(&id, &bytes[..bytes.len() - 1])
+            .await;
+        assert!(matches!(truncated, Err(WasmError::InvalidComponent(_))));
+
+        let not_wasm = loader
+            .load_component_from_reader(&id, &b"#!/bin/sh\n"[..])
+            .await;
+        assert!(matches!(not_wasm, Err(WasmError::InvalidComponent(_))));
+
+        let capped = FileComponentLoader::new(dir.to_string_lossy().into_owned())
+            .with_max_component_bytes(bytes.len() as u64 - 1);
+        let oversized = capped.load_component_from_reader(&id, &bytes[..]).await;
+        assert!(matches!(
+            oversized,
+            Err(WasmError::ResourceLimitExceeded(_))
+        ));
+
+        // Nothing was installed and no partial files were left behind
+        assert!(loader.load_bytes(&id).is_err());
+        let leftovers = std::fs::read_dir(dir.join("ns/comp")).unwrap().count();
+        assert_eq!(leftovers, 0);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
     #[test]
     fn test_in_memory_loader() {
         let mut loader = InMemoryComponentLoader::new();
@@ -288,4 +1264,129 @@ mod tests {
         let result = loader.load_bytes(&id);
         assert!(matches!(result, Err(WasmError::ComponentNotFound(_))));
     }
+
+    fn temp_cache(max_bytes: u64) -> CompiledArtifactCache {
+        let dir = std::env::temp_dir().join(format!("airssys-cache-{}", uuid::Uuid::new_v4()));
+        CompiledArtifactCache::new(CodeCacheConfig::new(dir).with_max_bytes(max_bytes)).unwrap()
+    }
+
+    #[test]
+    fn test_code_cache_roundtrip() {
+        let cache = temp_cache(CodeCacheConfig::DEFAULT_MAX_BYTES);
+        let digest = CompiledArtifactCache::module_digest(b"\0asm");
+        assert_eq!(digest.len(), 64);
+
+        assert!(cache.load(&digest, 1).is_none());
+        cache.store(&digest, 1, b"artifact").unwrap();
+        assert_eq!(cache.load(&digest, 1), Some(b"artifact".to_vec()));
+
+        // Different engine configuration never shares artifacts
+        assert!(cache.load(&digest, 2).is_none());
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.stores), (1, 2, 1));
+        let _ = std::fs::remove_dir_all(&cache.config().dir);
+    }
+
+    #[test]
+    fn test_code_cache_config_from_host_toml() {
+        let config = CodeCacheConfig::from_host_toml(
+            "[host]\nname = \"edge\"\n[runtime]\ncode_cache_dir = \"/tmp/cc\"\ncode_cache_max_bytes = 1024\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            Some(CodeCacheConfig::new("/tmp/cc").with_max_bytes(1024))
+        );
+
+        assert_eq!(
+            CodeCacheConfig::from_host_toml("[host]\nname = \"edge\"\n").unwrap(),
+            None
+        );
+        assert!(CodeCacheConfig::from_host_toml("[runtime\n").is_err());
+    }
+
+    #[test]
+    fn test_code_cache_rejects_corrupted_entry() {
+        let cache = temp_cache(CodeCacheConfig::DEFAULT_MAX_BYTES);
+        cache.store("abc", 7, b"artifact").unwrap();
+
+        let path = cache.entry_path("abc", 7);
+        let mut contents = std::fs::read(&path).unwrap();
+        if let Some(last) = contents.last_mut() {
+            *last ^= 0xff;
+        }
+        std::fs::write(&path, contents).unwrap();
+
+        assert!(cache.load("abc", 7).is_none());
+        assert!(!path.exists());
+        assert_eq!(cache.stats().invalid, 1);
+        let _ = std::fs::remove_dir_all(&cache.config().dir);
+    }
+
+    #[test]
+    fn test_keyed_code_cache_rejects_forged_and_swapped_entries() {
+        let secret = [5u8; 32];
+        let dir = std::env::temp_dir().join(format!("airssys-cc-{}", uuid::Uuid::new_v4()));
+        let open = |secret: Option<[u8; 32]>| {
+            let cache = CompiledArtifactCache::new(CodeCacheConfig::new(&dir)).unwrap();
+            match secret {
+                Some(secret) => cache.with_secret(secret),
+                None => cache,
+            }
+        };
+        let keyed = open(Some(secret));
+        keyed.store("abc", 7, b"artifact").unwrap();
+        assert_eq!(keyed.load("abc", 7), Some(b"artifact".to_vec()));
+
+        // The checksum is bound to the entry key
+        assert_eq!(
+            keyed.checksum("abc", 7, b"artifact"),
+            hmac_sha256(&secret, &[b"abc-0000000000000007", b"artifact"])
+        );
+
+        // An entry planted with a valid SHA-256 is refused
+        open(None).store("abc", 7, b"planted").unwrap();
+        assert!(keyed.load("abc", 7).is_none());
+
+        // A genuine entry copied under another component's key is refused
+        keyed.store("abc", 7, b"artifact").unwrap();
+        std::fs::copy(keyed.entry_path("abc", 7), keyed.entry_path("def", 7)).unwrap();
+        assert!(keyed.load("def", 7).is_none());
+        assert!(open(Some([6u8; 32])).load("abc", 7).is_none());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_hmac_sha256_matches_reference() {
+        // RFC 4231 test case 1 message with the key widened to 32 bytes
+        let digest = hmac_sha256(&[0x0b; 32], &[b"Hi ", b"There"]);
+        assert_eq!(
+            to_hex(&digest),
+            "198a607eb44bfbc69903a0f1cf2bbdc5ba0aa3f3d9ae3c1c7a3b1696a0b68cf7"
+        );
+    }
+
+    #[test]
+    fn test_code_cache_evicts_oldest_over_budget() {
+        let entry_size = (ARTIFACT_MAGIC.len() + 32 + 100) as u64;
+        let cache = temp_cache(entry_size * 2);
+
+        cache.store("first", 0, &[1u8; 100]).unwrap();
+        let old = SystemTime::now() - std::time::Duration::from_secs(60);
+        std::fs::File::options()
+            .append(true)
+            .open(cache.entry_path("first", 0))
+            .unwrap()
+            .set_modified(old)
+            .unwrap();
+        cache.store("second", 0, &[2u8; 100]).unwrap();
+        cache.store("third", 0, &[3u8; 100]).unwrap();
+
+        assert!(cache.load("first", 0).is_none());
+        assert!(cache.load("third", 0).is_some());
+        assert!(cache.total_bytes().unwrap() <= entry_size * 2);
+        assert_eq!(cache.stats().evictions, 1);
+        let _ = std::fs::remove_dir_all(&cache.config().dir);
+    }
 }
@@ -0,0 +1,188 @@
+//! In-process mock execution engine.
+//!
+//! [`MockEngine`] implements `RuntimeEngine` without compiling or running
+//! WASM: loading only checks the binary header, and messages are answered by
+//! a plain Rust responder function (echo by default). It lets embedders and
+//! tests exercise the component, messaging, and system layers without
+//! Wasmtime, and is selected with `EngineBackend::Mock` (feature
+//! `mock-engine`).
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+// Layer 3: Internal module imports
+use crate::core::component::handle::ComponentHandle;
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::{ComponentMessage, MessagePayload};
+use crate::core::runtime::errors::WasmError;
+use crate::core::runtime::traits::RuntimeEngine;
+
+/// Produces a component's reply to a message.
+pub type MockResponder =
+    fn(&ComponentId, &ComponentMessage) -> Result<Option<MessagePayload>, WasmError>;
+
+/// Replies with the received payload.
+fn echo(_id: &ComponentId, msg: &ComponentMessage) -> Result<Option<MessagePayload>, WasmError> {
+    Ok(Some(msg.payload.clone()))
+}
+
+/// Runtime engine that answers messages with a Rust function.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::component::message::{ComponentMessage, MessageMetadata, MessagePayload};
+/// use airssys_wasm::core::runtime::traits::RuntimeEngine;
+/// use airssys_wasm::runtime::mock_engine::MockEngine;
+///
+/// let engine = MockEngine::new();
+/// let id = ComponentId::new("acme", "echo", "v1");
+/// let handle = engine.load_component(&id, b"\0asm\x0d\x00\x01\x00").unwrap();
+///
+/// let msg = ComponentMessage::new(id, MessagePayload::new(vec![1, 2]), MessageMetadata::default());
+/// let reply = engine.call_handle_message(&handle, &msg).unwrap();
+/// assert_eq!(reply.unwrap().as_bytes(), &[1, 2]);
+/// assert_eq!(engine.messages_handled(), 1);
+/// ```
+#[derive(Debug)]
+pub struct MockEngine {
+    responder: MockResponder,
+    loaded: Mutex<HashMap<u64, ComponentId>>,
+    next_handle_id: AtomicU64,
+    messages_handled: AtomicU64,
+}
+
+impl MockEngine {
+    /// Creates an engine that echoes every message.
+    pub fn new() -> Self {
+        Self::with_responder(echo)
+    }
+
+    /// Creates an engine that answers messages with `responder`.
+    pub fn with_responder(responder: MockResponder) -> Self {
+        Self {
+            responder,
+            loaded: Mutex::new(HashMap::new()),
+            next_handle_id: AtomicU64::new(1),
+            messages_handled: AtomicU64::new(0),
+        }
+    }
+
+    /// Number of components currently loaded.
+    pub fn loaded_count(&self) -> usize {
+        self.loaded().len()
+    }
+
+    /// Messages handled across all components.
+    pub fn messages_handled(&self) -> u64 {
+        self.messages_handled.load(Ordering::Relaxed)
+    }
+
+    fn component(&self, handle: &ComponentHandle) -> Result<ComponentId, WasmError> {
+        self.loaded()
+            .get(&handle.handle_id())
+            .cloned()
+            .ok_or_else(|| WasmError::ComponentNotFound(handle.id().to_string_id()))
+    }
+
+    fn loaded(&self) -> MutexGuard<'_, HashMap<u64, ComponentId>> {
+        self.loaded.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for MockEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RuntimeEngine for MockEngine {
+    fn load_component(&self, id: &ComponentId, bytes: &[u8]) -> Result<ComponentHandle, WasmError> {
+        if bytes.len() < 4 || &bytes[0..4] != b"\0asm" {
+            return Err(WasmError::InvalidComponent(
+                "Invalid WASM magic number".to_string(),
+            ));
+        }
+        let handle_id = self.next_handle_id.fetch_add(1, Ordering::Relaxed);
+        self.loaded().insert(handle_id, id.clone());
+        Ok(ComponentHandle::new(id.clone(), handle_id))
+    }
+
+    fn unload_component(&self, handle: &ComponentHandle) -> Result<(), WasmError> {
+        self.loaded()
+            .remove(&handle.handle_id())
+            .map(|_| ())
+            .ok_or_else(|| WasmError::ComponentNotFound(handle.id().to_string_id()))
+    }
+
+    fn call_handle_message(
+        &self,
+        handle: &ComponentHandle,
+        msg: &ComponentMessage,
+    ) -> Result<Option<MessagePayload>, WasmError> {
+        let id = self.component(handle)?;
+        self.messages_handled.fetch_add(1, Ordering::Relaxed);
+        (self.responder)(&id, msg)
+    }
+
+    fn call_handle_callback(
+        &self,
+        handle: &ComponentHandle,
+        _msg: &ComponentMessage,
+    ) -> Result<(), WasmError> {
+        self.component(handle).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::component::message::MessageMetadata;
+
+    fn message(id: &ComponentId, payload: &[u8]) -> ComponentMessage {
+        ComponentMessage::new(
+            id.clone(),
+            MessagePayload::new(payload.to_vec()),
+            MessageMetadata::default(),
+        )
+    }
+
+    #[test]
+    fn test_custom_responder_and_unload() {
+        fn refuse(
+            _id: &ComponentId,
+            msg: &ComponentMessage,
+        ) -> Result<Option<MessagePayload>, WasmError> {
+            match msg.payload.as_bytes() {
+                b"fail" => Err(WasmError::RuntimeError("refused".to_string())),
+                _ => Ok(None),
+            }
+        }
+
+        let engine = MockEngine::with_responder(refuse);
+        let id = ComponentId::new("acme", "gate", "v1");
+        assert!(engine.load_component(&id, b"nope").is_err());
+
+        let handle = engine.load_component(&id, b"\0asm").unwrap();
+        assert_eq!(
+            engine
+                .call_handle_message(&handle, &message(&id, b"ok"))
+                .unwrap(),
+            None
+        );
+        assert!(engine
+            .call_handle_message(&handle, &message(&id, b"fail"))
+            .is_err());
+        assert_eq!(engine.messages_handled(), 2);
+
+        engine.unload_component(&handle).unwrap();
+        assert_eq!(engine.loaded_count(), 0);
+        assert!(matches!(
+            engine.call_handle_message(&handle, &message(&id, b"ok")),
+            Err(WasmError::ComponentNotFound(_))
+        ));
+    }
+}
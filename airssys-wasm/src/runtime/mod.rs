@@ -11,13 +11,28 @@
 //! ## Submodules
 //!
 //! - [`engine`] - WasmtimeEngine (RuntimeEngine implementation)
+//! - [`async_host`] - WASI Preview 2 host interfaces and per-component blocking pools
+//! - [`backend`] - EngineFactory selecting the execution engine from RuntimeConfig
+//! - [`deterministic`] - Seeded randomness and virtual clocks for replayable execution
+//! - `mock_engine` - MockEngine, a non-executing RuntimeEngine (feature `mock-engine`)
+//! - [`kv_store`] - KvStore, the persistent namespaced key-value storage backend
+//! - [`kv_crypto`] - AES-GCM encryption of stored values with host-provided keys
 //! - [`loader`] - ComponentLoader implementations (FileComponentLoader, InMemoryComponentLoader)
 //! - [`store`] - StoreManager for WASM stores
+//! - [`pool`] - InstancePool of pre-instantiated stores for warm starts
 //! - [`limiter`] - ResourceLimiter for memory and fuel constraints
 
+pub mod async_host;
+pub mod backend;
+pub mod deterministic;
 pub mod engine;
+pub mod kv_crypto;
+pub mod kv_store;
 pub mod limiter;
 pub mod loader;
+#[cfg(feature = "mock-engine")]
+pub mod mock_engine;
+pub mod pool;
 pub mod store;
 
 pub mod host_functions;
@@ -0,0 +1,230 @@
+//! Warm instance pool for fast component (re)starts.
+//!
+//! Instantiating a component costs around a millisecond, paid again on
+//! every supervisor restart. `InstancePool` keeps a per-component reserve
+//! of already instantiated `StoreManager`s so `load_component` can hand one
+//! out immediately and refill the reserve off the hot path.
+//!
+//! Pooled instances are never reused after they were handed out: a guest
+//! may have mutated its state, so each instance serves one load only.
+//! A reserve is discarded when the component's bytes change.
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+use wasmtime::component::Component;
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use crate::core::component::id::ComponentId;
+
+use super::store::StoreManager;
+
+/// Pool counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Loads served by a warm instance.
+    pub hits: u64,
+    /// Loads of pooled components that found the reserve empty.
+    pub misses: u64,
+    /// Warm instances currently held across all components.
+    pub ready: usize,
+}
+
+struct Reserve {
+    digest: String,
+    component: Component,
+    target: usize,
+    ready: Vec<StoreManager>,
+}
+
+/// Per-component reserves of pre-instantiated stores.
+#[derive(Default)]
+pub struct InstancePool {
+    reserves: Mutex<HashMap<ComponentId, Reserve>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl InstancePool {
+    /// Creates an empty pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps `target` warm instances of `component` (with module `digest`)
+    /// for `id`.
+    ///
+    /// Changing the digest discards the instances built from the old bytes.
+    /// A target of 0 removes the reserve.
+    pub fn set_target(&self, id: &ComponentId, digest: &str, component: &Component, target: usize) {
+        let mut reserves = self.lock();
+        if target == 0 {
+            reserves.remove(id);
+            return;
+        }
+        match reserves.get_mut(id) {
+            Some(reserve) if reserve.digest == digest => {
+                reserve.target = target;
+                reserve.ready.truncate(target);
+            }
+            _ => {
+                reserves.insert(
+                    id.clone(),
+                    Reserve {
+                        digest: digest.to_string(),
+                        component: component.clone(),
+                        target,
+                        ready: Vec::with_capacity(target),
+                    },
+                );
+            }
+        }
+    }
+
+    /// Takes a warm instance of `id` built from `digest`, if one is ready.
+    pub fn take(&self, id: &ComponentId, digest: &str) -> Option<StoreManager> {
+        let mut reserves = self.lock();
+        let reserve = reserves.get_mut(id).filter(|r| r.digest == digest)?;
+        let taken = reserve.ready.pop();
+        let counter = if taken.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        taken
+    }
+
+    /// Returns the component and number of instances needed to refill
+    /// `id`'s reserve, or `None` if `id` is not pooled.
+    pub fn deficit(&self, id: &ComponentId) -> Option<(Component, String, usize)> {
+        let reserves = self.lock();
+        let reserve = reserves.get(id)?;
+        Some((
+            reserve.component.clone(),
+            reserve.digest.clone(),
+            reserve.target.saturating_sub(reserve.ready.len()),
+        ))
+    }
+
+    /// Adds a freshly instantiated store to `id`'s reserve.
+    ///
+    /// Returns `false` (dropping the store) if the reserve is full, gone,
+    /// or was rebuilt for different bytes in the meantime.
+    pub fn put(&self, id: &ComponentId, digest: &str, store: StoreManager) -> bool {
+        let mut reserves = self.lock();
+        match reserves.get_mut(id) {
+            Some(reserve) if reserve.digest == digest && reserve.ready.len() < reserve.target => {
+                reserve.ready.push(store);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Number of warm instances held for `id`.
+    pub fn ready(&self, id: &ComponentId) -> usize {
+        self.lock().get(id).map_or(0, |r| r.ready.len())
+    }
+
+    /// Drops `id`'s reserve.
+    pub fn remove(&self, id: &ComponentId) {
+        self.lock().remove(id);
+    }
+
+    /// Current counters.
+    pub fn stats(&self) -> PoolStats {
+        PoolStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            ready: self.lock().values().map(|r| r.ready.len()).sum(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<ComponentId, Reserve>> {
+        self.reserves.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl std::fmt::Debug for InstancePool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InstancePool")
+            .field("components", &self.lock().len())
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::async_host::WasiState;
+    use crate::runtime::engine::HostState;
+    use wasmtime::{Engine, Store, StoreLimitsBuilder};
+
+    fn store(engine: &Engine, component: &Component, id: &ComponentId) -> StoreManager {
+        let state = HostState {
+            component_id: id.clone(),
+            message_router: None,
+            store_limits: StoreLimitsBuilder::new().build(),
+            memory_bytes: 0,
+            elevation_requests: None,
+            metrics: None,
+            os_bridge: None,
+            storage: None,
+            wasi: WasiState::default(),
+        };
+        StoreManager::new(Store::new(engine, state), component.clone())
+    }
+
+    #[test]
+    fn test_reserve_fills_to_target_and_serves_hits() {
+        let engine = Engine::default();
+        let component = Component::new(&engine, "(component)").unwrap();
+        let id = ComponentId::new("acme", "cache", "v1");
+        let pool = InstancePool::new();
+
+        assert!(pool.deficit(&id).is_none());
+        pool.set_target(&id, "d1", &component, 2);
+        assert_eq!(pool.deficit(&id).unwrap().2, 2);
+
+        assert!(pool.put(&id, "d1", store(&engine, &component, &id)));
+        assert!(pool.put(&id, "d1", store(&engine, &component, &id)));
+        assert!(
+            !pool.put(&id, "d1", store(&engine, &component, &id)),
+            "full"
+        );
+        assert_eq!(pool.ready(&id), 2);
+
+        assert!(pool.take(&id, "d1").is_some());
+        assert_eq!(pool.deficit(&id).unwrap().2, 1);
+        assert!(pool.take(&id, "d1").is_some());
+        assert!(pool.take(&id, "d1").is_none());
+
+        let stats = pool.stats();
+        assert_eq!((stats.hits, stats.misses, stats.ready), (2, 1, 0));
+    }
+
+    #[test]
+    fn test_changed_bytes_discard_reserve() {
+        let engine = Engine::default();
+        let component = Component::new(&engine, "(component)").unwrap();
+        let id = ComponentId::new("acme", "cache", "v1");
+        let pool = InstancePool::new();
+
+        pool.set_target(&id, "d1", &component, 1);
+        assert!(pool.put(&id, "d1", store(&engine, &component, &id)));
+
+        // Stale digests neither take nor refill
+        assert!(pool.take(&id, "d2").is_none());
+        pool.set_target(&id, "d2", &component, 1);
+        assert_eq!(pool.ready(&id), 0);
+        assert!(!pool.put(&id, "d1", store(&engine, &component, &id)));
+
+        pool.set_target(&id, "d2", &component, 0);
+        assert!(pool.deficit(&id).is_none());
+    }
+}
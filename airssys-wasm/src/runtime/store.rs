@@ -10,28 +10,37 @@
 //! between internal types and WIT-generated types.
 
 // Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
-// (none)
+use std::time::{Duration, Instant};
 
 // Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
-use wasmtime::component::{Component, Linker};
+use wasmtime::component::{
+    Component, ComponentNamedList, Instance, Lift, Linker, Lower, TypedFunc,
+};
 use wasmtime::Store;
 
 // Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
 use crate::core::component::id::ComponentId;
 use crate::core::component::message::{ComponentMessage, MessageMetadata, MessagePayload};
+use crate::core::config::values::{ConfigValue, ConfigValues};
 use crate::core::runtime::errors::WasmError;
+use crate::core::runtime::usage::ResourceUsage;
 use crate::RuntimeHost;
 
 // WIT-generated types (aliased to avoid name collision per PROJECTS_STANDARD.md §2.2)
+use crate::airssys::core::errors::ComponentError as WitComponentError;
 use crate::airssys::core::errors::WasmError as WitWasmError;
 use crate::airssys::core::types::ComponentId as WitComponentId;
 use crate::airssys::core::types::ComponentMessage as WitComponentMessage;
+use crate::airssys::core::types::ConfigValue as WitConfigValue;
 use crate::airssys::core::types::MessageMetadata as WitMessageMetadata;
 use crate::airssys::core::types::Timestamp as WitTimestamp;
 use crate::exports::airssys::core::component_lifecycle::GuestPre;
 
 use super::engine::HostState;
 
+/// Component Model name of the optional `component-state` export.
+pub const COMPONENT_STATE_INTERFACE: &str = "airssys:core/component-state@1.0.0";
+
 /// Manages a WASM store and its associated component instance.
 ///
 /// Per ADR-WASM-034 Decision 4, StoreManager stores a `RuntimeHost`
@@ -42,6 +51,8 @@ pub struct StoreManager {
     store: Store<HostState>,
     component: Component,
     binding: Option<RuntimeHost>,
+    /// Kept for exports outside the `runtime-host` world
+    instance: Option<Instance>,
 }
 
 impl StoreManager {
@@ -55,6 +66,7 @@ impl StoreManager {
             store,
             component,
             binding: None,
+            instance: None,
         }
     }
 
@@ -84,6 +96,7 @@ impl StoreManager {
             .map_err(|e| WasmError::ExportNotFound(e.to_string()))?;
 
         self.binding = Some(RuntimeHost { interface0 });
+        self.instance = Some(instance);
         Ok(())
     }
 
@@ -119,8 +132,9 @@ impl StoreManager {
 
         // Call the actual guest export (async bridged to sync)
         let result =
-            futures::executor::block_on(lifecycle.call_handle_message(&mut self.store, &wasm_msg))
-                .map_err(|e| WasmError::RuntimeError(e.to_string()))?;
+            futures::executor::block_on(lifecycle.call_handle_message(&mut self.store, &wasm_msg));
+        self.end_call();
+        let result = result.map_err(|e| WasmError::RuntimeError(e.to_string()))?;
 
         // Convert WIT result back to internal type
         match result {
@@ -129,6 +143,30 @@ impl StoreManager {
         }
     }
 
+    /// Call handle-message and measure the resources the call consumed.
+    ///
+    /// Fuel is read from the store before and after the call (`None` when
+    /// fuel metering is disabled); memory growth comes from the counter
+    /// maintained by `HostState`'s resource limiter. Usage is only
+    /// returned for successful calls.
+    pub fn call_handle_message_with_usage(
+        &mut self,
+        msg: &ComponentMessage,
+    ) -> Result<(Option<MessagePayload>, ResourceUsage), WasmError> {
+        let fuel_before = self.store.get_fuel().ok();
+        let memory_before = self.store.data().memory_bytes;
+        let started = Instant::now();
+
+        let reply = self.call_handle_message(msg)?;
+
+        let mut usage = ResourceUsage::new(started.elapsed())
+            .with_memory_delta(self.store.data().memory_bytes as i64 - memory_before as i64);
+        if let (Some(before), Ok(after)) = (fuel_before, self.store.get_fuel()) {
+            usage = usage.with_fuel(before.saturating_sub(after));
+        }
+        Ok((reply, usage))
+    }
+
     /// Call handle-callback on the component.
     ///
     /// Same pattern as `call_handle_message` but for callback dispatch.
@@ -143,8 +181,9 @@ impl StoreManager {
 
         // Call the actual guest export (async bridged to sync)
         let result =
-            futures::executor::block_on(lifecycle.call_handle_callback(&mut self.store, &wasm_msg))
-                .map_err(|e| WasmError::RuntimeError(e.to_string()))?;
+            futures::executor::block_on(lifecycle.call_handle_callback(&mut self.store, &wasm_msg));
+        self.end_call();
+        let result = result.map_err(|e| WasmError::RuntimeError(e.to_string()))?;
 
         match result {
             Ok(()) => Ok(()),
@@ -152,6 +191,127 @@ impl StoreManager {
         }
     }
 
+    /// Call reconfigure on the component.
+    ///
+    /// Same pattern as `call_handle_message`. A `config-rejected` answer
+    /// from the guest maps to `WasmError::ConfigRejected`; any other
+    /// component error maps to `WasmError::RuntimeError`.
+    pub fn call_reconfigure(&mut self, values: &ConfigValues) -> Result<(), WasmError> {
+        let binding = self
+            .binding
+            .as_ref()
+            .ok_or(WasmError::StoreNotInitialized)?;
+
+        let lifecycle = binding.airssys_core_component_lifecycle();
+        let update = to_wasm_config_update(values);
+
+        // Call the actual guest export (async bridged to sync)
+        let result =
+            futures::executor::block_on(lifecycle.call_reconfigure(&mut self.store, &update));
+        self.end_call();
+        let result = result.map_err(|e| WasmError::RuntimeError(e.to_string()))?;
+
+        match result {
+            Ok(()) => Ok(()),
+            Err(WitComponentError::ConfigRejected(reason)) => {
+                Err(WasmError::ConfigRejected(reason))
+            }
+            Err(other) => Err(WasmError::RuntimeError(format!("{:?}", other))),
+        }
+    }
+
+    /// Call the guest's prepare-shutdown export.
+    ///
+    /// # Errors
+    ///
+    /// - `WasmError::StoreNotInitialized` - initialize() was not called
+    /// - `WasmError::RuntimeError` - The guest trapped or reported an error
+    pub fn call_prepare_shutdown(&mut self, deadline: Duration) -> Result<(), WasmError> {
+        let binding = self
+            .binding
+            .as_ref()
+            .ok_or(WasmError::StoreNotInitialized)?;
+
+        let lifecycle = binding.airssys_core_component_lifecycle();
+        let deadline_ms = u64::try_from(deadline.as_millis()).unwrap_or(u64::MAX);
+
+        // Call the actual guest export (async bridged to sync)
+        let result = futures::executor::block_on(
+            lifecycle.call_prepare_shutdown(&mut self.store, deadline_ms),
+        );
+        self.end_call();
+        result
+            .map_err(|e| WasmError::RuntimeError(e.to_string()))?
+            .map_err(|e| WasmError::RuntimeError(format!("{:?}", e)))
+    }
+
+    /// Call the guest's `component-state.snapshot` export.
+    ///
+    /// # Errors
+    ///
+    /// - `WasmError::StoreNotInitialized` - initialize() was not called
+    /// - `WasmError::ExportNotFound` - The guest does not export `component-state`
+    /// - `WasmError::RuntimeError` - The guest trapped
+    pub fn call_snapshot(&mut self) -> Result<Vec<u8>, WasmError> {
+        let func = self.state_func::<(), (Vec<u8>,)>("snapshot")?;
+        let result = futures::executor::block_on(func.call_async(&mut self.store, ()));
+        self.end_call();
+        let (state,) = result.map_err(|e| WasmError::RuntimeError(e.to_string()))?;
+        futures::executor::block_on(func.post_return_async(&mut self.store))
+            .map_err(|e| WasmError::RuntimeError(e.to_string()))?;
+        Ok(state)
+    }
+
+    /// Call the guest's `component-state.restore` export.
+    ///
+    /// # Errors
+    ///
+    /// - `WasmError::StoreNotInitialized` - initialize() was not called
+    /// - `WasmError::ExportNotFound` - The guest does not export `component-state`
+    /// - `WasmError::RuntimeError` - The guest trapped or rejected the state
+    pub fn call_restore(&mut self, state: &[u8]) -> Result<(), WasmError> {
+        let func = self.state_func::<(Vec<u8>,), (Result<(), WitComponentError>,)>("restore")?;
+        let result =
+            futures::executor::block_on(func.call_async(&mut self.store, (state.to_vec(),)));
+        self.end_call();
+        let (result,) = result.map_err(|e| WasmError::RuntimeError(e.to_string()))?;
+        futures::executor::block_on(func.post_return_async(&mut self.store))
+            .map_err(|e| WasmError::RuntimeError(e.to_string()))?;
+        result.map_err(|e| WasmError::RuntimeError(format!("{:?}", e)))
+    }
+
+    /// Roll back any storage transaction the guest left open.
+    ///
+    /// Transactions never outlive the call that began them, so a guest
+    /// that trapped (or returned) mid-update leaves storage unchanged.
+    fn end_call(&self) {
+        if let Some(storage) = &self.store.data().storage {
+            storage.rollback();
+        }
+    }
+
+    /// Look up a function of the optional `component-state` export.
+    fn state_func<Params, Results>(
+        &mut self,
+        name: &str,
+    ) -> Result<TypedFunc<Params, Results>, WasmError>
+    where
+        Params: ComponentNamedList + Lower,
+        Results: ComponentNamedList + Lift,
+    {
+        let instance = self.instance.ok_or(WasmError::StoreNotInitialized)?;
+        let not_found = || WasmError::ExportNotFound(format!("{COMPONENT_STATE_INTERFACE}#{name}"));
+        let interface = instance
+            .get_export(&mut self.store, None, COMPONENT_STATE_INTERFACE)
+            .ok_or_else(not_found)?;
+        let func = instance
+            .get_export(&mut self.store, Some(&interface), name)
+            .ok_or_else(not_found)?;
+        instance
+            .get_typed_func(&mut self.store, func)
+            .map_err(|e| WasmError::ExportNotFound(e.to_string()))
+    }
+
     /// Get the store.
     pub fn store(&self) -> &Store<HostState> {
         &self.store
@@ -204,9 +364,26 @@ fn to_wasm_message_metadata(meta: &MessageMetadata) -> WitMessageMetadata {
             nanoseconds: ((meta.timestamp_ms % 1000) * 1_000_000) as u32,
         },
         content_type: meta.content_type.clone(),
+        idempotency_key: meta.idempotency_key.clone(),
     }
 }
 
+/// Convert internal ConfigValues to the WIT config-update list.
+fn to_wasm_config_update(values: &ConfigValues) -> Vec<(String, WitConfigValue)> {
+    values
+        .iter()
+        .map(|(key, value)| {
+            let value = match value {
+                ConfigValue::Text(s) => WitConfigValue::Text(s.clone()),
+                ConfigValue::Integer(n) => WitConfigValue::Integer(*n),
+                ConfigValue::Float(n) => WitConfigValue::Float(*n),
+                ConfigValue::Boolean(b) => WitConfigValue::Boolean(*b),
+            };
+            (key.clone(), value)
+        })
+        .collect()
+}
+
 /// Convert WIT MessagePayload (Vec<u8>) back to internal MessagePayload.
 fn from_wasm_message_payload(payload: Vec<u8>) -> MessagePayload {
     MessagePayload::new(payload)
@@ -228,6 +405,7 @@ fn from_wasm_error(err: WitWasmError) -> WasmError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::runtime::async_host::WasiState;
     use crate::runtime::host_functions::marker_traits::register_host_functions;
     use std::path::Path;
     use wasmtime::{Config, Engine, StoreLimitsBuilder};
@@ -254,6 +432,12 @@ mod tests {
             component_id: component_id.clone(),
             message_router: None,
             store_limits: StoreLimitsBuilder::new().build(),
+            memory_bytes: 0,
+            elevation_requests: None,
+            metrics: None,
+            os_bridge: None,
+            storage: None,
+            wasi: WasiState::default(),
         };
         Store::new(engine, host_state)
     }
@@ -503,6 +687,10 @@ mod tests {
             reply_to: Some(ComponentId::new("ns2", "comp2", "inst2")),
             timestamp_ms: 1234567890,
             content_type: Some("application/json".to_string()),
+            idempotency_key: None,
+            partition_key: None,
+            deadline_ms: None,
+            priority: Default::default(),
         };
         let msg = ComponentMessage::new(
             sender.clone(),
@@ -19,6 +19,8 @@ use crossbeam_channel as crossbeam;
 use crate::core::component::id::ComponentId;
 use crate::core::security::traits::{SecurityAuditLogger, SecurityEvent};
 
+use super::redaction::Redactor;
+
 /// Console-based security audit logger with security features.
 ///
 /// Uses a bounded channel with backpressure to prevent DoS attacks via event flooding.
@@ -66,6 +68,8 @@ pub struct ConsoleSecurityAuditLogger {
     /// Recent events for deduplication (hash, timestamp_ms)
     /// Protected by Arc<Mutex<>> for thread-safe access
     recent_events: Arc<Mutex<VecDeque<(u64, u64)>>>,
+    /// Redaction rules applied before events are queued
+    redactor: Option<Arc<Redactor>>,
 }
 
 impl Clone for ConsoleSecurityAuditLogger {
@@ -79,6 +83,7 @@ impl Clone for ConsoleSecurityAuditLogger {
             shutdown_sender: self.shutdown_sender.clone(),
             thread_handle: None, // Only original handles shutdown
             recent_events: Arc::clone(&self.recent_events),
+            redactor: self.redactor.clone(),
         }
     }
 }
@@ -201,9 +206,32 @@ impl ConsoleSecurityAuditLogger {
             shutdown_sender,
             thread_handle: Some(thread_handle),
             recent_events,
+            redactor: None,
         }
     }
 
+    /// Applies redaction rules to every event before it is logged.
+    ///
+    /// Action and resource strings are passed through the redactor's regex
+    /// rules, so secrets embedded in resources (query tokens, credentials in
+    /// paths) never reach the audit output.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use airssys_wasm::security::audit::ConsoleSecurityAuditLogger;
+    /// use airssys_wasm::security::redaction::Redactor;
+    ///
+    /// let redactor = Redactor::new()
+    ///     .with_regex(r"token=[^&]+", "token=[REDACTED]")
+    ///     .unwrap();
+    /// let logger = ConsoleSecurityAuditLogger::new().with_redactor(redactor);
+    /// ```
+    pub fn with_redactor(mut self, redactor: Redactor) -> Self {
+        self.redactor = Some(Arc::new(redactor));
+        self
+    }
+
     /// Calculates a hash for an event excluding the timestamp.
     ///
     /// This ensures that the same action on the same resource by the same component
@@ -279,6 +307,11 @@ impl SecurityAuditLogger for ConsoleSecurityAuditLogger {
     /// logger.log_event(event);
     /// ```
     fn log_event(&self, event: SecurityEvent) {
+        let event = match &self.redactor {
+            Some(redactor) => redactor.redact_event(&event),
+            None => event,
+        };
+
         // Silently drop events when channel is full (backpressure)
         let _ = self.sender.try_send(event);
     }
@@ -575,4 +608,25 @@ mod tests {
 
         // Test passes if no panic occurred and thread exited cleanly
     }
+
+    #[test]
+    fn test_with_redactor_shared_by_clones() {
+        let redactor = Redactor::new()
+            .with_regex(r"token=[^&]+", "token=[REDACTED]")
+            .unwrap();
+        let logger = ConsoleSecurityAuditLogger::new().with_redactor(redactor);
+        let cloned = logger.clone();
+
+        assert!(cloned.redactor.is_some());
+
+        let event = create_security_event(
+            ComponentId::new("test", "component", "1"),
+            "connect",
+            "https://api/x?token=s3cret",
+            true,
+        );
+        cloned.log_event(event);
+
+        thread::sleep(Duration::from_millis(50));
+    }
 }
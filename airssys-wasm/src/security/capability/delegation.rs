@@ -0,0 +1,49 @@
+//! Capability delegation between components.
+//!
+//! A component may hand another component a [`DelegationToken`] carrying a
+//! strict subset of its own grants, e.g. narrowing `/data/*` to
+//! `/data/reports/*`. The host issues and stores every token (see
+//! `CapabilityValidator::delegate`), so a component can never mint one
+//! itself, and each token records its issuer and the token it was derived
+//! from. Revoking a token revokes every token delegated from it.
+
+use chrono::{DateTime, Utc};
+
+use super::set::CapabilitySet;
+use crate::core::component::id::ComponentId;
+
+/// Capabilities delegated from one component to another.
+#[derive(Debug, Clone)]
+pub struct DelegationToken {
+    /// Unique token identifier.
+    pub id: String,
+    /// Component that delegated the capabilities.
+    pub issuer: ComponentId,
+    /// Component that may use the capabilities.
+    pub holder: ComponentId,
+    /// Delegated capabilities; never wider than the issuer's.
+    pub capabilities: CapabilitySet,
+    /// Token the issuer delegated from, or `None` for its own grants.
+    pub parent: Option<String>,
+    /// When the token was issued.
+    pub issued_at: DateTime<Utc>,
+}
+
+impl DelegationToken {
+    /// Creates a token with a fresh identifier.
+    pub fn new(
+        issuer: ComponentId,
+        holder: ComponentId,
+        capabilities: CapabilitySet,
+        parent: Option<String>,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            issuer,
+            holder,
+            capabilities,
+            parent,
+            issued_at: Utc::now(),
+        }
+    }
+}
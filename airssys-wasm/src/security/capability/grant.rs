@@ -1,4 +1,11 @@
 //! Capability grant management.
+//!
+//! Grants added at runtime through `CapabilityValidator::add_grant` extend a
+//! component's registered capabilities until they expire or are revoked,
+//! so operators can hand out temporary access without restarting the
+//! component.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use super::set::CapabilitySet;
 use crate::core::component::id::ComponentId;
@@ -58,6 +65,29 @@ impl CapabilityGrant {
         }
     }
 
+    /// Create a grant that expires `ttl` from now.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use airssys_wasm::core::component::id::ComponentId;
+    /// use airssys_wasm::security::capability::{set::CapabilitySet, grant::CapabilityGrant};
+    ///
+    /// let component = ComponentId::new("test", "component", "1");
+    /// let grant = CapabilityGrant::expiring_after(component, CapabilitySet::new(), Duration::from_secs(60));
+    ///
+    /// assert!(!grant.is_expired_now());
+    /// ```
+    pub fn expiring_after(
+        component: ComponentId,
+        capabilities: CapabilitySet,
+        ttl: Duration,
+    ) -> Self {
+        let ttl_ms = u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX);
+        Self::with_expiration(component, capabilities, now_ms().saturating_add(ttl_ms))
+    }
+
     /// Check if the grant has expired.
     ///
     /// # Arguments
@@ -71,6 +101,11 @@ impl CapabilityGrant {
         self.expires_at.is_some_and(|exp| current_time_ms > exp)
     }
 
+    /// Check if the grant has expired against the system clock.
+    pub fn is_expired_now(&self) -> bool {
+        self.is_expired(now_ms())
+    }
+
     /// Get the component ID.
     pub fn component(&self) -> &ComponentId {
         &self.component
@@ -82,6 +117,14 @@ impl CapabilityGrant {
     }
 }
 
+/// Current time in milliseconds since the Unix epoch.
+pub(crate) fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+        .unwrap_or(0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,4 +173,18 @@ mod tests {
         assert_eq!(grant.component(), &component);
         assert!(grant.capabilities().can_send_to("any"));
     }
+
+    #[test]
+    fn test_expiring_after() {
+        let component = ComponentId::new("test", "component", "1");
+        let grant = CapabilityGrant::expiring_after(
+            component.clone(),
+            CapabilitySet::new(),
+            Duration::from_secs(60),
+        );
+        assert!(!grant.is_expired_now());
+
+        let expired = CapabilityGrant::with_expiration(component, CapabilitySet::new(), 1);
+        assert!(expired.is_expired_now());
+    }
 }
@@ -8,8 +8,14 @@
 //! - [`set`] - CapabilitySet for permission management
 //! - [`validator`] - CapabilityValidator for SecurityValidator trait implementation
 //! - [`grant`] - CapabilityGrant for permission grants
+//! - [`delegation`] - DelegationToken for attenuated capability delegation
+//! - [`simulate`] - Explanation of dry-run capability checks
+//! - [`usage`] - Per-grant usage counters and least-privilege reports
 
+pub mod delegation;
 pub mod grant;
 pub mod set;
+pub mod simulate;
 pub mod types;
+pub mod usage;
 pub mod validator;
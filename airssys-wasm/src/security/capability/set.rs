@@ -1,6 +1,20 @@
 //! Capability set management for components.
+//!
+//! Each check succeeds if any grant's pattern matches. Exclusions in a
+//! pattern (`/data/* !/data/secrets/*`) only restrict that grant; see
+//! [`PatternMatcher`] for the precedence rules.
 
+use std::collections::HashMap;
+
+use super::simulate::{DecisionSource, Explanation};
 use super::types::PatternMatcher;
+use super::usage::GrantKey;
+use crate::core::security::capability::{
+    Capability, ClockKind, EnvironmentAction, FilesystemAction, NetworkAction, ProcessAction,
+    RandomSource, StorageAction,
+};
+use crate::core::security::errors::{PermissionDenial, SecurityError};
+use crate::core::security::quota::{QuotaClass, ResourceQuota};
 
 /// Messaging permission configuration.
 #[derive(Debug, Clone, PartialEq)]
@@ -27,6 +41,8 @@ pub struct FilesystemPermission {
     pub can_read_paths: Vec<String>,
     /// Path patterns that can be written.
     pub can_write_paths: Vec<String>,
+    /// Program path patterns that can be executed.
+    pub can_execute_paths: Vec<String>,
 }
 
 /// Network permission configuration.
@@ -38,15 +54,55 @@ pub struct NetworkPermission {
     pub can_bind_ports: Vec<u16>,
 }
 
+/// Environment variable permission configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvironmentPermission {
+    /// Variable name patterns that can be read.
+    pub can_read_vars: Vec<String>,
+    /// Variable name patterns that can be set or unset.
+    pub can_write_vars: Vec<String>,
+}
+
+/// Clock permission configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClockPermission {
+    /// Clock name patterns that can be read (`"wall"`, `"monotonic"`, `"*"`).
+    pub can_read_clocks: Vec<String>,
+}
+
+/// Randomness permission configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RandomPermission {
+    /// Source name patterns that can be drawn from (`"secure"`,
+    /// `"insecure"`, `"*"`).
+    pub can_use_sources: Vec<String>,
+}
+
+/// Subprocess permission configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessPermission {
+    /// Program patterns that can be spawned.
+    pub can_spawn: Vec<String>,
+    /// Program patterns whose processes can be signalled.
+    pub can_signal: Vec<String>,
+}
+
 /// Set of capabilities granted to a component.
 ///
-/// Manages component permissions across messaging, storage, filesystem, and network.
+/// Manages component permissions across messaging, storage, filesystem,
+/// network, environment variables, clocks, randomness, and subprocesses.
+/// Filesystem and network grants may carry a [`ResourceQuota`].
 #[derive(Debug, Clone, Default)]
 pub struct CapabilitySet {
     messaging: Vec<MessagingPermission>,
     storage: Vec<StoragePermission>,
     filesystem: Vec<FilesystemPermission>,
     network: Vec<NetworkPermission>,
+    environment: Vec<EnvironmentPermission>,
+    clocks: Vec<ClockPermission>,
+    random: Vec<RandomPermission>,
+    process: Vec<ProcessPermission>,
+    quotas: HashMap<QuotaClass, ResourceQuota>,
 }
 
 impl CapabilitySet {
@@ -84,6 +140,84 @@ impl CapabilitySet {
         CapabilitySetBuilder::new()
     }
 
+    /// Create the set granted by an approved elevation request.
+    ///
+    /// `capability` has the `class:action:pattern` form of elevation
+    /// requests. Actions are those the validator checks: `send`,
+    /// `request`, `broadcast` (messaging); `read`, `write`, `delete`
+    /// (storage); `read`, `list`, `write`, `delete`, `execute`
+    /// (filesystem); `outbound` and `inbound` (network, where the pattern
+    /// of `inbound` is a port).
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecurityError::PolicyViolation` for any other class or
+    /// action, or an inbound port that is not a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airssys_wasm::security::capability::set::CapabilitySet;
+    ///
+    /// let set = CapabilitySet::from_elevation("storage:write:cache/*").unwrap();
+    /// assert!(set.can_write_key("cache/a"));
+    /// assert!(!set.can_read_key("cache/a"));
+    /// assert!(CapabilitySet::from_elevation("storage:own:cache/*").is_err());
+    /// ```
+    pub fn from_elevation(capability: &str) -> Result<Self, SecurityError> {
+        let invalid =
+            || SecurityError::PolicyViolation(format!("unsupported elevation '{}'", capability));
+        let mut parts = capability.splitn(3, ':');
+        let (Some(class), Some(action), Some(pattern)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        let patterns = vec![pattern.to_string()];
+        let mut set = Self::new();
+        match (class, action) {
+            ("messaging", "send" | "request" | "broadcast") => {
+                set.add_messaging(MessagingPermission {
+                    can_send_to: patterns,
+                    can_receive_from: vec![],
+                })
+            }
+            ("storage", "read") => set.add_storage(StoragePermission {
+                can_write_keys: vec![],
+                can_read_keys: patterns,
+            }),
+            // Delete requires write
+            ("storage", "write" | "delete") => set.add_storage(StoragePermission {
+                can_write_keys: patterns,
+                can_read_keys: vec![],
+            }),
+            ("filesystem", "read" | "list") => set.add_filesystem(FilesystemPermission {
+                can_read_paths: patterns,
+                can_write_paths: vec![],
+                can_execute_paths: vec![],
+            }),
+            ("filesystem", "write" | "delete") => set.add_filesystem(FilesystemPermission {
+                can_read_paths: vec![],
+                can_write_paths: patterns,
+                can_execute_paths: vec![],
+            }),
+            ("filesystem", "execute") => set.add_filesystem(FilesystemPermission {
+                can_read_paths: vec![],
+                can_write_paths: vec![],
+                can_execute_paths: patterns,
+            }),
+            ("network", "outbound") => set.add_network(NetworkPermission {
+                can_connect_to: patterns,
+                can_bind_ports: vec![],
+            }),
+            ("network", "inbound") => set.add_network(NetworkPermission {
+                can_connect_to: vec![],
+                can_bind_ports: vec![pattern.parse().map_err(|_| invalid())?],
+            }),
+            _ => return Err(invalid()),
+        }
+        Ok(set)
+    }
+
     /// Add a messaging permission.
     pub fn add_messaging(&mut self, perm: MessagingPermission) {
         self.messaging.push(perm);
@@ -104,6 +238,37 @@ impl CapabilitySet {
         self.network.push(perm);
     }
 
+    /// Add an environment variable permission.
+    pub fn add_environment(&mut self, perm: EnvironmentPermission) {
+        self.environment.push(perm);
+    }
+
+    /// Add a clock permission.
+    pub fn add_clock(&mut self, perm: ClockPermission) {
+        self.clocks.push(perm);
+    }
+
+    /// Add a randomness permission.
+    pub fn add_random(&mut self, perm: RandomPermission) {
+        self.random.push(perm);
+    }
+
+    /// Add a subprocess permission.
+    pub fn add_process(&mut self, perm: ProcessPermission) {
+        self.process.push(perm);
+    }
+
+    /// Attach a quota to this set's grants of `class`, replacing any
+    /// previous one.
+    pub fn set_quota(&mut self, class: QuotaClass, quota: ResourceQuota) {
+        self.quotas.insert(class, quota);
+    }
+
+    /// Quota attached to this set's grants of `class`.
+    pub fn quota(&self, class: QuotaClass) -> Option<ResourceQuota> {
+        self.quotas.get(&class).copied()
+    }
+
     /// Check if messaging to target is allowed.
     pub fn can_send_to(&self, target: &str) -> bool {
         for perm in &self.messaging {
@@ -176,6 +341,18 @@ impl CapabilitySet {
         false
     }
 
+    /// Check if a program can be executed.
+    pub fn can_execute_path(&self, path: &str) -> bool {
+        for perm in &self.filesystem {
+            for pattern in &perm.can_execute_paths {
+                if PatternMatcher::matches(pattern, path) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
     /// Check if network connection is allowed.
     pub fn can_connect_to(&self, host: &str) -> bool {
         for perm in &self.network {
@@ -197,6 +374,611 @@ impl CapabilitySet {
         }
         false
     }
+
+    /// Check if an environment variable can be read.
+    pub fn can_read_env(&self, variable: &str) -> bool {
+        any_match(&self.environment, |p| &p.can_read_vars, variable)
+    }
+
+    /// Check if an environment variable can be set or unset.
+    pub fn can_write_env(&self, variable: &str) -> bool {
+        any_match(&self.environment, |p| &p.can_write_vars, variable)
+    }
+
+    /// Check if a clock can be read.
+    pub fn can_read_clock(&self, clock: ClockKind) -> bool {
+        any_match(&self.clocks, |p| &p.can_read_clocks, clock.as_str())
+    }
+
+    /// Check if a randomness source can be used.
+    pub fn can_use_random(&self, source: RandomSource) -> bool {
+        any_match(&self.random, |p| &p.can_use_sources, source.as_str())
+    }
+
+    /// Check if a program can be spawned as a subprocess.
+    pub fn can_spawn(&self, program: &str) -> bool {
+        any_match(&self.process, |p| &p.can_spawn, program)
+    }
+
+    /// Check if processes running a program can be signalled.
+    pub fn can_signal(&self, program: &str) -> bool {
+        any_match(&self.process, |p| &p.can_signal, program)
+    }
+
+    /// Check that `requested` is an attenuation of this set.
+    ///
+    /// Every pattern in `requested` must be covered (see
+    /// [`PatternMatcher::covers`]) by a pattern of the same kind in this
+    /// set, and every port must be granted here.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecurityError::Denied` naming the first pattern that would
+    /// widen this set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airssys_wasm::security::capability::set::{CapabilitySet, FilesystemPermission};
+    ///
+    /// let fs = |paths: &[&str]| FilesystemPermission {
+    ///     can_read_paths: paths.iter().map(|p| p.to_string()).collect(),
+    ///     can_write_paths: vec![],
+    ///     can_execute_paths: vec![],
+    /// };
+    /// let granted = CapabilitySet::builder().filesystem(fs(&["/data/*"])).build();
+    ///
+    /// let narrowed = CapabilitySet::builder().filesystem(fs(&["/data/reports/*"])).build();
+    /// assert!(granted.attenuate(&narrowed).is_ok());
+    ///
+    /// let widened = CapabilitySet::builder().filesystem(fs(&["/*"])).build();
+    /// assert!(granted.attenuate(&widened).is_err());
+    /// ```
+    pub fn attenuate(&self, requested: &CapabilitySet) -> Result<(), SecurityError> {
+        fn check<'a, P>(
+            granted: &'a [P],
+            requested: &'a [P],
+            class: &str,
+            action: &str,
+            field: fn(&P) -> &Vec<String>,
+        ) -> Result<(), SecurityError> {
+            for pattern in requested.iter().flat_map(field) {
+                let covered = granted
+                    .iter()
+                    .flat_map(field)
+                    .any(|outer| PatternMatcher::covers(outer, pattern));
+                if !covered {
+                    return Err(SecurityError::Denied(PermissionDenial::new(
+                        class,
+                        action,
+                        pattern,
+                        "delegation would widen the delegator's grants",
+                    )));
+                }
+            }
+            Ok(())
+        }
+
+        check(
+            &self.messaging,
+            &requested.messaging,
+            "messaging",
+            "send",
+            |p| &p.can_send_to,
+        )?;
+        check(
+            &self.messaging,
+            &requested.messaging,
+            "messaging",
+            "receive",
+            |p| &p.can_receive_from,
+        )?;
+        check(&self.storage, &requested.storage, "storage", "read", |p| {
+            &p.can_read_keys
+        })?;
+        check(&self.storage, &requested.storage, "storage", "write", |p| {
+            &p.can_write_keys
+        })?;
+        check(
+            &self.filesystem,
+            &requested.filesystem,
+            "filesystem",
+            "read",
+            |p| &p.can_read_paths,
+        )?;
+        check(
+            &self.filesystem,
+            &requested.filesystem,
+            "filesystem",
+            "write",
+            |p| &p.can_write_paths,
+        )?;
+        check(
+            &self.filesystem,
+            &requested.filesystem,
+            "filesystem",
+            "execute",
+            |p| &p.can_execute_paths,
+        )?;
+        check(
+            &self.network,
+            &requested.network,
+            "network",
+            "connect",
+            |p| &p.can_connect_to,
+        )?;
+
+        check(
+            &self.environment,
+            &requested.environment,
+            "environment",
+            "read",
+            |p| &p.can_read_vars,
+        )?;
+        check(
+            &self.environment,
+            &requested.environment,
+            "environment",
+            "write",
+            |p| &p.can_write_vars,
+        )?;
+        check(&self.clocks, &requested.clocks, "clock", "read", |p| {
+            &p.can_read_clocks
+        })?;
+        check(&self.random, &requested.random, "random", "generate", |p| {
+            &p.can_use_sources
+        })?;
+        check(&self.process, &requested.process, "process", "spawn", |p| {
+            &p.can_spawn
+        })?;
+        check(
+            &self.process,
+            &requested.process,
+            "process",
+            "signal",
+            |p| &p.can_signal,
+        )?;
+
+        for port in requested.network.iter().flat_map(|p| &p.can_bind_ports) {
+            if !self.can_bind_port(*port) {
+                return Err(SecurityError::Denied(PermissionDenial::new(
+                    "network",
+                    "bind",
+                    port.to_string(),
+                    "delegation would widen the delegator's grants",
+                )));
+            }
+        }
+
+        for (class, outer) in &self.quotas {
+            let tighter = requested
+                .quotas
+                .get(class)
+                .is_some_and(|inner| inner.is_within(outer));
+            if !tighter {
+                return Err(SecurityError::Denied(PermissionDenial::new(
+                    class.as_str(),
+                    "quota",
+                    "*",
+                    "delegation would lift or loosen the delegator's quota",
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Explain whether `capability` would be allowed by this set, without
+    /// performing anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airssys_wasm::core::security::capability::{
+    ///     Capability, FilesystemAction, FilesystemCapability,
+    /// };
+    /// use airssys_wasm::security::capability::set::{CapabilitySet, FilesystemPermission};
+    /// use airssys_wasm::security::capability::simulate::DecisionSource;
+    ///
+    /// let set = CapabilitySet::builder()
+    ///     .filesystem(FilesystemPermission {
+    ///         can_read_paths: vec!["/data/*".to_string()],
+    ///         can_write_paths: vec![],
+    ///         can_execute_paths: vec![],
+    ///     })
+    ///     .build();
+    ///
+    /// let read = Capability::Filesystem(FilesystemCapability {
+    ///     action: FilesystemAction::Read,
+    ///     path_pattern: "/data/report.csv".to_string(),
+    /// });
+    /// let explanation = set.simulate(&read);
+    /// assert!(explanation.allowed);
+    /// assert_eq!(
+    ///     explanation.decided_by,
+    ///     DecisionSource::Grant { pattern: "/data/*".to_string() }
+    /// );
+    /// ```
+    pub fn simulate(&self, capability: &Capability) -> Explanation {
+        let decided_by = match self.matching_grant(capability) {
+            Some(pattern) => DecisionSource::Grant { pattern },
+            None => DecisionSource::NoMatchingGrant,
+        };
+        Explanation::new(capability, decided_by)
+    }
+
+    /// The first pattern (or port) in this set that grants `capability`.
+    pub fn matching_grant(&self, capability: &Capability) -> Option<String> {
+        fn first<'a, P>(
+            perms: &'a [P],
+            field: fn(&P) -> &Vec<String>,
+            target: &str,
+        ) -> Option<String> {
+            perms
+                .iter()
+                .flat_map(field)
+                .find(|pattern| PatternMatcher::matches(pattern, target))
+                .cloned()
+        }
+
+        match capability {
+            Capability::Messaging(cap) => {
+                first(&self.messaging, |p| &p.can_send_to, &cap.target_pattern)
+            }
+            Capability::Storage(cap) => {
+                let field: fn(&StoragePermission) -> &Vec<String> = match cap.action {
+                    StorageAction::Read => |p| &p.can_read_keys,
+                    // Delete requires write
+                    StorageAction::Write | StorageAction::Delete => |p| &p.can_write_keys,
+                };
+                first(&self.storage, field, &cap.namespace_pattern)
+            }
+            Capability::Filesystem(cap) => {
+                let field: fn(&FilesystemPermission) -> &Vec<String> = match cap.action {
+                    FilesystemAction::Read | FilesystemAction::ListDir => |p| &p.can_read_paths,
+                    // Delete requires write
+                    FilesystemAction::Write | FilesystemAction::Delete => |p| &p.can_write_paths,
+                    FilesystemAction::Execute => |p| &p.can_execute_paths,
+                };
+                first(&self.filesystem, field, &cap.path_pattern)
+            }
+            Capability::Network(cap) => match cap.action {
+                NetworkAction::Outbound => {
+                    first(&self.network, |p| &p.can_connect_to, &cap.host_pattern)
+                }
+                NetworkAction::Inbound => cap
+                    .port
+                    .filter(|port| self.can_bind_port(*port))
+                    .map(|port| port.to_string()),
+            },
+            Capability::Environment(cap) => {
+                let field: fn(&EnvironmentPermission) -> &Vec<String> = match cap.action {
+                    EnvironmentAction::Read => |p| &p.can_read_vars,
+                    EnvironmentAction::Write => |p| &p.can_write_vars,
+                };
+                first(&self.environment, field, &cap.variable_pattern)
+            }
+            Capability::Clock(cap) => {
+                first(&self.clocks, |p| &p.can_read_clocks, cap.clock.as_str())
+            }
+            Capability::Random(cap) => {
+                first(&self.random, |p| &p.can_use_sources, cap.source.as_str())
+            }
+            Capability::Process(cap) => {
+                let field: fn(&ProcessPermission) -> &Vec<String> = match cap.action {
+                    ProcessAction::Spawn => |p| &p.can_spawn,
+                    ProcessAction::Signal => |p| &p.can_signal,
+                };
+                first(&self.process, field, &cap.program_pattern)
+            }
+        }
+    }
+
+    /// The grant in this set that allows `capability`, identified by the
+    /// grant list it belongs to and its pattern.
+    ///
+    /// Storage and filesystem deletes are allowed by write grants and
+    /// directory listings by read grants, so they report those.
+    pub fn matching_grant_key(&self, capability: &Capability) -> Option<GrantKey> {
+        let pattern = self.matching_grant(capability)?;
+        let (class, action) = match capability {
+            Capability::Messaging(_) => ("messaging", "send"),
+            Capability::Storage(cap) => match cap.action {
+                StorageAction::Read => ("storage", "read"),
+                StorageAction::Write | StorageAction::Delete => ("storage", "write"),
+            },
+            Capability::Filesystem(cap) => match cap.action {
+                FilesystemAction::Read | FilesystemAction::ListDir => ("filesystem", "read"),
+                FilesystemAction::Write | FilesystemAction::Delete => ("filesystem", "write"),
+                FilesystemAction::Execute => ("filesystem", "execute"),
+            },
+            Capability::Network(cap) => match cap.action {
+                NetworkAction::Outbound => ("network", "connect"),
+                NetworkAction::Inbound => ("network", "bind"),
+            },
+            Capability::Environment(cap) => match cap.action {
+                EnvironmentAction::Read => ("environment", "read"),
+                EnvironmentAction::Write => ("environment", "write"),
+            },
+            Capability::Clock(_) => ("clock", "read"),
+            Capability::Random(_) => ("random", "generate"),
+            Capability::Process(cap) => match cap.action {
+                ProcessAction::Spawn => ("process", "spawn"),
+                ProcessAction::Signal => ("process", "signal"),
+            },
+        };
+        Some(GrantKey::new(class, action, pattern))
+    }
+
+    /// Every grant in this set, sorted and without duplicates.
+    pub fn grants(&self) -> Vec<GrantKey> {
+        fn push<P>(
+            keys: &mut Vec<GrantKey>,
+            perms: &[P],
+            class: &str,
+            action: &str,
+            field: fn(&P) -> &Vec<String>,
+        ) {
+            for pattern in perms.iter().flat_map(field) {
+                keys.push(GrantKey::new(class, action, pattern.clone()));
+            }
+        }
+
+        let mut keys = Vec::new();
+        push(&mut keys, &self.messaging, "messaging", "send", |p| {
+            &p.can_send_to
+        });
+        push(&mut keys, &self.messaging, "messaging", "receive", |p| {
+            &p.can_receive_from
+        });
+        push(&mut keys, &self.storage, "storage", "read", |p| {
+            &p.can_read_keys
+        });
+        push(&mut keys, &self.storage, "storage", "write", |p| {
+            &p.can_write_keys
+        });
+        push(&mut keys, &self.filesystem, "filesystem", "read", |p| {
+            &p.can_read_paths
+        });
+        push(&mut keys, &self.filesystem, "filesystem", "write", |p| {
+            &p.can_write_paths
+        });
+        push(&mut keys, &self.filesystem, "filesystem", "execute", |p| {
+            &p.can_execute_paths
+        });
+        push(&mut keys, &self.network, "network", "connect", |p| {
+            &p.can_connect_to
+        });
+        for port in self.network.iter().flat_map(|p| &p.can_bind_ports) {
+            keys.push(GrantKey::new("network", "bind", port.to_string()));
+        }
+        push(&mut keys, &self.environment, "environment", "read", |p| {
+            &p.can_read_vars
+        });
+        push(&mut keys, &self.environment, "environment", "write", |p| {
+            &p.can_write_vars
+        });
+        push(&mut keys, &self.clocks, "clock", "read", |p| {
+            &p.can_read_clocks
+        });
+        push(&mut keys, &self.random, "random", "generate", |p| {
+            &p.can_use_sources
+        });
+        push(&mut keys, &self.process, "process", "spawn", |p| {
+            &p.can_spawn
+        });
+        push(&mut keys, &self.process, "process", "signal", |p| {
+            &p.can_signal
+        });
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+
+    /// Whether `self` grants nothing beyond `other`.
+    pub fn is_subset_of(&self, other: &CapabilitySet) -> bool {
+        other.attenuate(self).is_ok()
+    }
+
+    /// Whether this set grants nothing.
+    pub fn is_empty(&self) -> bool {
+        self.grants().is_empty()
+    }
+
+    /// Everything granted by either set.
+    ///
+    /// A quota survives only if both sets limit the class, at the looser
+    /// of the two, since the other set's grants are otherwise unlimited.
+    pub fn union(&self, other: &CapabilitySet) -> CapabilitySet {
+        let mut set = self.combine(
+            other,
+            |a, b| a.into_iter().chain(b).collect(),
+            |a, b| a.into_iter().chain(b).collect(),
+        );
+        for (class, quota) in &self.quotas {
+            if let Some(theirs) = other.quotas.get(class) {
+                set.set_quota(*class, quota.loosest(theirs));
+            }
+        }
+        set
+    }
+
+    /// What both sets grant, e.g. the effective view of a component's
+    /// requested capabilities against the host's grants.
+    ///
+    /// Where one pattern covers the other (see [`PatternMatcher::covers`])
+    /// the narrower is kept. Partially overlapping patterns are dropped,
+    /// so the result never grants more than either set. Quotas combine at
+    /// the stricter limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airssys_wasm::security::capability::set::{CapabilitySet, FilesystemPermission};
+    ///
+    /// let fs = |paths: &[&str]| FilesystemPermission {
+    ///     can_read_paths: paths.iter().map(|p| p.to_string()).collect(),
+    ///     can_write_paths: vec![],
+    ///     can_execute_paths: vec![],
+    /// };
+    /// let requested = CapabilitySet::builder().filesystem(fs(&["/data/*", "/etc/*"])).build();
+    /// let granted = CapabilitySet::builder().filesystem(fs(&["/data/reports/*"])).build();
+    ///
+    /// let effective = requested.intersection(&granted);
+    /// assert!(effective.can_read_path("/data/reports/q1.csv"));
+    /// assert!(!effective.can_read_path("/etc/passwd"));
+    ///
+    /// let missing = requested.difference(&granted);
+    /// assert!(missing.can_read_path("/etc/passwd"));
+    /// assert!(effective.is_subset_of(&requested) && effective.is_subset_of(&granted));
+    /// ```
+    pub fn intersection(&self, other: &CapabilitySet) -> CapabilitySet {
+        let mut set = self.combine(
+            other,
+            |a, b| {
+                let mut narrower = Vec::new();
+                for x in &a {
+                    for y in &b {
+                        if PatternMatcher::covers(x, y) {
+                            narrower.push(y.clone());
+                        } else if PatternMatcher::covers(y, x) {
+                            narrower.push(x.clone());
+                        }
+                    }
+                }
+                narrower
+            },
+            |a, b| a.into_iter().filter(|port| b.contains(port)).collect(),
+        );
+        for (class, quota) in &self.quotas {
+            let quota = match other.quotas.get(class) {
+                Some(theirs) => quota.tightest(theirs),
+                None => *quota,
+            };
+            set.set_quota(*class, quota);
+        }
+        for (class, quota) in &other.quotas {
+            if !self.quotas.contains_key(class) {
+                set.set_quota(*class, *quota);
+            }
+        }
+        set
+    }
+
+    /// Grants of `self` not covered by `other`, e.g. the requested
+    /// capabilities an approver still has to decide on.
+    ///
+    /// Quotas are not carried over.
+    pub fn difference(&self, other: &CapabilitySet) -> CapabilitySet {
+        self.combine(
+            other,
+            |a, b| {
+                a.into_iter()
+                    .filter(|x| !b.iter().any(|y| PatternMatcher::covers(y, x)))
+                    .collect()
+            },
+            |a, b| a.into_iter().filter(|port| !b.contains(port)).collect(),
+        )
+    }
+
+    /// Build a set by applying `patterns` to every grant list of `self`
+    /// and `other`, and `ports` to their bind ports.
+    fn combine(
+        &self,
+        other: &CapabilitySet,
+        patterns: fn(Vec<String>, Vec<String>) -> Vec<String>,
+        ports: fn(Vec<u16>, Vec<u16>) -> Vec<u16>,
+    ) -> CapabilitySet {
+        fn list<P>(perms: &[P], field: fn(&P) -> &Vec<String>) -> Vec<String> {
+            perms.iter().flat_map(field).cloned().collect()
+        }
+        fn normalized<T: Ord>(mut items: Vec<T>) -> Vec<T> {
+            items.sort();
+            items.dedup();
+            items
+        }
+        let op = |field: fn(&CapabilitySet) -> Vec<String>| {
+            normalized(patterns(field(self), field(other)))
+        };
+
+        let mut set = CapabilitySet::new();
+        let can_send_to = op(|s| list(&s.messaging, |p| &p.can_send_to));
+        let can_receive_from = op(|s| list(&s.messaging, |p| &p.can_receive_from));
+        if !can_send_to.is_empty() || !can_receive_from.is_empty() {
+            set.add_messaging(MessagingPermission {
+                can_send_to,
+                can_receive_from,
+            });
+        }
+        let can_read_keys = op(|s| list(&s.storage, |p| &p.can_read_keys));
+        let can_write_keys = op(|s| list(&s.storage, |p| &p.can_write_keys));
+        if !can_read_keys.is_empty() || !can_write_keys.is_empty() {
+            set.add_storage(StoragePermission {
+                can_read_keys,
+                can_write_keys,
+            });
+        }
+        let can_read_paths = op(|s| list(&s.filesystem, |p| &p.can_read_paths));
+        let can_write_paths = op(|s| list(&s.filesystem, |p| &p.can_write_paths));
+        let can_execute_paths = op(|s| list(&s.filesystem, |p| &p.can_execute_paths));
+        if !can_read_paths.is_empty()
+            || !can_write_paths.is_empty()
+            || !can_execute_paths.is_empty()
+        {
+            set.add_filesystem(FilesystemPermission {
+                can_read_paths,
+                can_write_paths,
+                can_execute_paths,
+            });
+        }
+        let can_connect_to = op(|s| list(&s.network, |p| &p.can_connect_to));
+        let bind = |s: &CapabilitySet| -> Vec<u16> {
+            s.network
+                .iter()
+                .flat_map(|p| &p.can_bind_ports)
+                .copied()
+                .collect()
+        };
+        let can_bind_ports = normalized(ports(bind(self), bind(other)));
+        if !can_connect_to.is_empty() || !can_bind_ports.is_empty() {
+            set.add_network(NetworkPermission {
+                can_connect_to,
+                can_bind_ports,
+            });
+        }
+        let can_read_vars = op(|s| list(&s.environment, |p| &p.can_read_vars));
+        let can_write_vars = op(|s| list(&s.environment, |p| &p.can_write_vars));
+        if !can_read_vars.is_empty() || !can_write_vars.is_empty() {
+            set.add_environment(EnvironmentPermission {
+                can_read_vars,
+                can_write_vars,
+            });
+        }
+        let can_read_clocks = op(|s| list(&s.clocks, |p| &p.can_read_clocks));
+        if !can_read_clocks.is_empty() {
+            set.add_clock(ClockPermission { can_read_clocks });
+        }
+        let can_use_sources = op(|s| list(&s.random, |p| &p.can_use_sources));
+        if !can_use_sources.is_empty() {
+            set.add_random(RandomPermission { can_use_sources });
+        }
+        let can_spawn = op(|s| list(&s.process, |p| &p.can_spawn));
+        let can_signal = op(|s| list(&s.process, |p| &p.can_signal));
+        if !can_spawn.is_empty() || !can_signal.is_empty() {
+            set.add_process(ProcessPermission {
+                can_spawn,
+                can_signal,
+            });
+        }
+        set
+    }
+}
+
+/// Whether any pattern selected by `field` in `perms` matches `target`.
+fn any_match<P>(perms: &[P], field: fn(&P) -> &Vec<String>, target: &str) -> bool {
+    perms
+        .iter()
+        .flat_map(field)
+        .any(|pattern| PatternMatcher::matches(pattern, target))
 }
 
 /// Builder for constructing CapabilitySet instances.
@@ -208,6 +990,11 @@ pub struct CapabilitySetBuilder {
     storage: Vec<StoragePermission>,
     filesystem: Vec<FilesystemPermission>,
     network: Vec<NetworkPermission>,
+    environment: Vec<EnvironmentPermission>,
+    clocks: Vec<ClockPermission>,
+    random: Vec<RandomPermission>,
+    process: Vec<ProcessPermission>,
+    quotas: HashMap<QuotaClass, ResourceQuota>,
 }
 
 impl CapabilitySetBuilder {
@@ -255,6 +1042,72 @@ impl CapabilitySetBuilder {
         self
     }
 
+    /// Add an environment variable permission.
+    pub fn environment(mut self, perm: EnvironmentPermission) -> Self {
+        self.environment.push(perm);
+        self
+    }
+
+    /// Add a clock permission.
+    pub fn clock(mut self, perm: ClockPermission) -> Self {
+        self.clocks.push(perm);
+        self
+    }
+
+    /// Add a randomness permission.
+    pub fn random(mut self, perm: RandomPermission) -> Self {
+        self.random.push(perm);
+        self
+    }
+
+    /// Add a subprocess permission.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airssys_wasm::security::capability::set::{CapabilitySet, ProcessPermission};
+    ///
+    /// let capabilities = CapabilitySet::builder()
+    ///     .process(ProcessPermission {
+    ///         can_spawn: vec!["/usr/bin/git".to_string()],
+    ///         can_signal: vec![],
+    ///     })
+    ///     .build();
+    ///
+    /// assert!(capabilities.can_spawn("/usr/bin/git"));
+    /// assert!(!capabilities.can_signal("/usr/bin/git"));
+    /// ```
+    pub fn process(mut self, perm: ProcessPermission) -> Self {
+        self.process.push(perm);
+        self
+    }
+
+    /// Attach a quota to the grants of `class`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airssys_wasm::core::security::quota::{QuotaClass, ResourceQuota};
+    /// use airssys_wasm::security::capability::set::{CapabilitySet, NetworkPermission};
+    ///
+    /// let capabilities = CapabilitySet::builder()
+    ///     .network(NetworkPermission {
+    ///         can_connect_to: vec!["api.example.com".to_string()],
+    ///         can_bind_ports: vec![],
+    ///     })
+    ///     .quota(
+    ///         QuotaClass::Network,
+    ///         ResourceQuota::new().with_max_concurrent_connections(4),
+    ///     )
+    ///     .build();
+    ///
+    /// assert!(capabilities.quota(QuotaClass::Network).is_some());
+    /// ```
+    pub fn quota(mut self, class: QuotaClass, quota: ResourceQuota) -> Self {
+        self.quotas.insert(class, quota);
+        self
+    }
+
     /// Build the CapabilitySet.
     ///
     /// # Examples
@@ -282,6 +1135,11 @@ impl CapabilitySetBuilder {
             storage: self.storage,
             filesystem: self.filesystem,
             network: self.network,
+            environment: self.environment,
+            clocks: self.clocks,
+            random: self.random,
+            process: self.process,
+            quotas: self.quotas,
         }
     }
 }
@@ -331,12 +1189,15 @@ mod tests {
         set.add_filesystem(FilesystemPermission {
             can_read_paths: vec!["/safe/*".to_string()],
             can_write_paths: vec!["/data/*".to_string()],
+            can_execute_paths: vec!["/usr/bin/*".to_string()],
         });
 
         assert!(set.can_read_path("/safe/file.txt"));
         assert!(!set.can_read_path("/unsafe/file.txt"));
         assert!(set.can_write_path("/data/file.txt"));
         assert!(!set.can_write_path("/safe/file.txt"));
+        assert!(set.can_execute_path("/usr/bin/git"));
+        assert!(!set.can_execute_path("/data/file.txt"));
     }
 
     #[test]
@@ -444,6 +1305,7 @@ mod tests {
             .filesystem(FilesystemPermission {
                 can_read_paths: vec!["/safe/*".to_string()],
                 can_write_paths: vec![],
+                can_execute_paths: vec![],
             })
             .network(NetworkPermission {
                 can_connect_to: vec!["*.internal".to_string()],
@@ -468,4 +1330,191 @@ mod tests {
         assert!(!capabilities.can_read_path("/any"));
         assert!(!capabilities.can_connect_to("host"));
     }
+
+    #[test]
+    fn test_attenuate_accepts_narrowing_and_rejects_widening() {
+        let granted = CapabilitySet::builder()
+            .filesystem(FilesystemPermission {
+                can_read_paths: vec!["/data/*".to_string()],
+                can_write_paths: vec!["/data/out/*".to_string()],
+                can_execute_paths: vec![],
+            })
+            .network(NetworkPermission {
+                can_connect_to: vec!["*.internal".to_string()],
+                can_bind_ports: vec![8080],
+            })
+            .build();
+
+        let narrowed = CapabilitySet::builder()
+            .filesystem(FilesystemPermission {
+                can_read_paths: vec!["/data/reports/*".to_string()],
+                can_write_paths: vec![],
+                can_execute_paths: vec![],
+            })
+            .network(NetworkPermission {
+                can_connect_to: vec!["db.internal".to_string()],
+                can_bind_ports: vec![8080],
+            })
+            .build();
+        assert!(granted.attenuate(&narrowed).is_ok());
+        assert!(narrowed.is_subset_of(&granted));
+        assert!(CapabilitySet::new().is_subset_of(&granted));
+
+        let write_escalation = CapabilitySet::builder()
+            .filesystem(FilesystemPermission {
+                can_read_paths: vec![],
+                can_write_paths: vec!["/data/reports/*".to_string()],
+                can_execute_paths: vec![],
+            })
+            .build();
+        let err = granted.attenuate(&write_escalation).unwrap_err();
+        let denial = err.denial().unwrap();
+        assert_eq!(denial.action, "write");
+        assert_eq!(denial.pattern, "/data/reports/*");
+
+        let port_escalation = CapabilitySet::builder()
+            .network(NetworkPermission {
+                can_connect_to: vec![],
+                can_bind_ports: vec![22],
+            })
+            .build();
+        assert!(!port_escalation.is_subset_of(&granted));
+    }
+
+    #[test]
+    fn test_set_algebra() {
+        let requested = CapabilitySet::builder()
+            .network(NetworkPermission {
+                can_connect_to: vec!["*.example.com".to_string(), "db.internal".to_string()],
+                can_bind_ports: vec![8080, 9090],
+            })
+            .quota(
+                QuotaClass::Network,
+                ResourceQuota::new().with_max_requests_per_minute(100),
+            )
+            .build();
+        let granted = CapabilitySet::builder()
+            .network(NetworkPermission {
+                can_connect_to: vec!["api.example.com".to_string()],
+                can_bind_ports: vec![8080],
+            })
+            .quota(
+                QuotaClass::Network,
+                ResourceQuota::new().with_max_requests_per_minute(10),
+            )
+            .build();
+        let connect = |host: &str| GrantKey::new("network", "connect", host);
+        let bind = |port: &str| GrantKey::new("network", "bind", port);
+
+        let effective = requested.intersection(&granted);
+        assert_eq!(
+            effective.grants(),
+            vec![bind("8080"), connect("api.example.com")]
+        );
+        assert_eq!(
+            effective
+                .quota(QuotaClass::Network)
+                .and_then(|q| q.max_requests_per_minute),
+            Some(10)
+        );
+
+        let missing = requested.difference(&granted);
+        assert_eq!(
+            missing.grants(),
+            vec![
+                bind("9090"),
+                connect("*.example.com"),
+                connect("db.internal")
+            ]
+        );
+        assert!(missing.quota(QuotaClass::Network).is_none());
+
+        let union = requested.union(&granted);
+        assert!(requested.is_subset_of(&union) && granted.is_subset_of(&union));
+        assert_eq!(
+            union
+                .quota(QuotaClass::Network)
+                .and_then(|q| q.max_requests_per_minute),
+            Some(100)
+        );
+        assert!(granted.difference(&union).is_empty());
+        assert!(!requested.is_subset_of(&granted));
+    }
+
+    #[test]
+    fn test_exclusions_scoped_to_their_grant() {
+        let mut caps = CapabilitySet::new();
+        caps.add_filesystem(FilesystemPermission {
+            can_read_paths: vec!["/data/* !/data/secrets/*".to_string()],
+            can_write_paths: vec![],
+            can_execute_paths: vec![],
+        });
+        assert!(caps.can_read_path("/data/reports/q1.csv"));
+        assert!(!caps.can_read_path("/data/secrets/db"));
+
+        // A separate grant of the subtree still applies
+        caps.add_filesystem(FilesystemPermission {
+            can_read_paths: vec!["/data/secrets/public/*".to_string()],
+            can_write_paths: vec![],
+            can_execute_paths: vec![],
+        });
+        assert!(caps.can_read_path("/data/secrets/public/ca.pem"));
+        assert!(!caps.can_read_path("/data/secrets/db"));
+    }
+
+    #[test]
+    fn test_host_function_classes_deny_by_default() {
+        let empty = CapabilitySet::new();
+        assert!(!empty.can_read_env("HOME"));
+        assert!(!empty.can_read_clock(ClockKind::Wall));
+        assert!(!empty.can_use_random(RandomSource::Secure));
+        assert!(!empty.can_spawn("/bin/sh"));
+
+        let granted = CapabilitySet::builder()
+            .environment(EnvironmentPermission {
+                can_read_vars: vec!["APP_MODE".to_string()],
+                can_write_vars: vec![],
+            })
+            .clock(ClockPermission {
+                can_read_clocks: vec!["monotonic".to_string()],
+            })
+            .random(RandomPermission {
+                can_use_sources: vec!["*".to_string()],
+            })
+            .build();
+        assert!(granted.can_read_env("APP_MODE"));
+        assert!(!granted.can_write_env("APP_MODE"));
+        assert!(granted.can_read_clock(ClockKind::Monotonic));
+        assert!(!granted.can_read_clock(ClockKind::Wall));
+        assert!(granted.can_use_random(RandomSource::Insecure));
+
+        let widened = CapabilitySet::builder()
+            .clock(ClockPermission {
+                can_read_clocks: vec!["wall".to_string()],
+            })
+            .build();
+        let err = granted.attenuate(&widened).unwrap_err();
+        assert_eq!(err.denial().unwrap().capability, "clock");
+    }
+
+    #[test]
+    fn test_attenuate_keeps_quotas() {
+        let quota = ResourceQuota::new().with_max_bytes_read(1024);
+        let granted = CapabilitySet::builder()
+            .quota(QuotaClass::Filesystem, quota)
+            .build();
+
+        let tighter = CapabilitySet::builder()
+            .quota(QuotaClass::Filesystem, quota.with_max_bytes_read(512))
+            .build();
+        assert!(granted.attenuate(&tighter).is_ok());
+
+        let err = granted.attenuate(&CapabilitySet::new()).unwrap_err();
+        assert_eq!(err.denial().unwrap().action, "quota");
+
+        let looser = CapabilitySet::builder()
+            .quota(QuotaClass::Filesystem, quota.with_max_bytes_read(4096))
+            .build();
+        assert!(!looser.is_subset_of(&granted));
+    }
 }
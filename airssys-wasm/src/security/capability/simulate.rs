@@ -0,0 +1,249 @@
+//! Capability dry runs.
+//!
+//! [`CapabilitySet::simulate`](super::set::CapabilitySet::simulate) and
+//! `CapabilityValidator::simulate` answer "would this be allowed, and what
+//! decided it" without running anything. The answer is an [`Explanation`]
+//! naming the grant pattern, delegation token, runtime grant, or policy
+//! that decided the outcome, so tooling and CI pipelines can validate a
+//! manifest's grants against the operations a component is expected to
+//! perform.
+
+use std::fmt;
+
+use crate::core::component::id::ComponentId;
+use crate::core::security::capability::{
+    Capability, EnvironmentAction, FilesystemAction, MessagingAction, NetworkAction, ProcessAction,
+    StorageAction,
+};
+use crate::security::policy::engine::PolicyEngine;
+
+/// What decided a simulated capability check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecisionSource {
+    /// A pattern in the component's registered capability set.
+    Grant {
+        /// The matching pattern (or port).
+        pattern: String,
+    },
+    /// A pattern in a delegation token held by the component.
+    Delegation {
+        /// Token identifier.
+        token_id: String,
+        /// Component that delegated the capability.
+        issuer: ComponentId,
+        /// The matching pattern (or port).
+        pattern: String,
+    },
+    /// A pattern in an unexpired runtime grant.
+    RuntimeGrant {
+        /// Grant identifier.
+        grant_id: String,
+        /// The matching pattern (or port).
+        pattern: String,
+    },
+    /// A deny rule of a security policy.
+    Policy {
+        /// Name of the denying policy.
+        policy: String,
+    },
+    /// No grant covers the request.
+    NoMatchingGrant,
+    /// The component has no registered capabilities.
+    NotRegistered,
+}
+
+/// Outcome of a simulated capability check.
+///
+/// `capability`, `action` and `resource` use the same names as a
+/// `PermissionDenial` for the real check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    /// Whether the check would pass.
+    pub allowed: bool,
+    /// Capability class ("messaging", "storage", "filesystem", "network",
+    /// "environment", "clock", "random", "process").
+    pub capability: String,
+    /// Action within the class (e.g. "read", "connect").
+    pub action: String,
+    /// Requested target, key, path, host, or port.
+    pub resource: String,
+    /// What decided the outcome.
+    pub decided_by: DecisionSource,
+}
+
+impl Explanation {
+    pub(crate) fn new(capability: &Capability, decided_by: DecisionSource) -> Self {
+        let (class, action, resource) = describe(capability);
+        let allowed = matches!(
+            decided_by,
+            DecisionSource::Grant { .. }
+                | DecisionSource::Delegation { .. }
+                | DecisionSource::RuntimeGrant { .. }
+        );
+        Self {
+            allowed,
+            capability: class.to_string(),
+            action: action.to_string(),
+            resource,
+            decided_by,
+        }
+    }
+
+    /// Apply the deny rules of `policies` to an allowed outcome.
+    ///
+    /// Policies only ever deny, so a denied outcome is returned unchanged.
+    pub fn with_policies(self, policies: &PolicyEngine, component: &ComponentId) -> Self {
+        if !self.allowed {
+            return self;
+        }
+        match policies.denying_policy(component, &self.action, &self.resource) {
+            Some(policy) => Self {
+                allowed: false,
+                decided_by: DecisionSource::Policy {
+                    policy: policy.name.clone(),
+                },
+                ..self
+            },
+            None => self,
+        }
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = if self.allowed { "allowed" } else { "denied" };
+        write!(
+            f,
+            "{verdict}: {} {} on '{}' ",
+            self.capability, self.action, self.resource
+        )?;
+        match &self.decided_by {
+            DecisionSource::Grant { pattern } => write!(f, "by grant '{pattern}'"),
+            DecisionSource::Delegation {
+                token_id,
+                issuer,
+                pattern,
+            } => write!(
+                f,
+                "by grant '{pattern}' delegated by {issuer} (token {token_id})"
+            ),
+            DecisionSource::RuntimeGrant { grant_id, pattern } => {
+                write!(f, "by runtime grant {grant_id} ('{pattern}')")
+            }
+            DecisionSource::Policy { policy } => write!(f, "by policy '{policy}'"),
+            DecisionSource::NoMatchingGrant => f.write_str("because no grant matches"),
+            DecisionSource::NotRegistered => f.write_str("because the component is not registered"),
+        }
+    }
+}
+
+/// Capability class, action, and resource of `capability`, named as in
+/// denials.
+fn describe(capability: &Capability) -> (&'static str, &'static str, String) {
+    match capability {
+        Capability::Messaging(cap) => {
+            let action = match cap.action {
+                MessagingAction::Send => "send",
+                MessagingAction::Request => "request",
+                MessagingAction::Broadcast => "broadcast",
+            };
+            ("messaging", action, cap.target_pattern.clone())
+        }
+        Capability::Storage(cap) => {
+            let action = match cap.action {
+                StorageAction::Read => "read",
+                StorageAction::Write => "write",
+                StorageAction::Delete => "delete",
+            };
+            ("storage", action, cap.namespace_pattern.clone())
+        }
+        Capability::Filesystem(cap) => {
+            let action = match cap.action {
+                FilesystemAction::Read => "read",
+                FilesystemAction::ListDir => "list",
+                FilesystemAction::Write => "write",
+                FilesystemAction::Delete => "delete",
+                FilesystemAction::Execute => "execute",
+            };
+            ("filesystem", action, cap.path_pattern.clone())
+        }
+        Capability::Network(cap) => match cap.action {
+            NetworkAction::Outbound => ("network", "connect", cap.host_pattern.clone()),
+            NetworkAction::Inbound => (
+                "network",
+                "bind",
+                cap.port
+                    .map(|port| port.to_string())
+                    .unwrap_or_else(|| cap.host_pattern.clone()),
+            ),
+        },
+        Capability::Environment(cap) => {
+            let action = match cap.action {
+                EnvironmentAction::Read => "read",
+                EnvironmentAction::Write => "write",
+            };
+            ("environment", action, cap.variable_pattern.clone())
+        }
+        Capability::Clock(cap) => ("clock", "read", cap.clock.as_str().to_string()),
+        Capability::Random(cap) => ("random", "generate", cap.source.as_str().to_string()),
+        Capability::Process(cap) => {
+            let action = match cap.action {
+                ProcessAction::Spawn => "spawn",
+                ProcessAction::Signal => "signal",
+            };
+            ("process", action, cap.program_pattern.clone())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::security::capability::StorageCapability;
+    use crate::security::policy::rules::{PolicyEffect, PolicyRule, SecurityPolicy};
+
+    fn write(key: &str) -> Capability {
+        Capability::Storage(StorageCapability {
+            action: StorageAction::Write,
+            namespace_pattern: key.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_policies_override_allowed_outcomes() {
+        let component = ComponentId::new("org", "svc", "1");
+        let mut policy = SecurityPolicy::new("no-secrets", "*");
+        policy.add_rule(PolicyRule {
+            action: "write".to_string(),
+            resource_pattern: "secret/*".to_string(),
+            effect: PolicyEffect::Deny,
+        });
+        let mut engine = PolicyEngine::new();
+        engine.add_policy(policy);
+
+        let grant = DecisionSource::Grant {
+            pattern: "*".to_string(),
+        };
+        let denied = Explanation::new(&write("secret/key"), grant.clone())
+            .with_policies(&engine, &component);
+        assert!(!denied.allowed);
+        assert_eq!(
+            denied.decided_by,
+            DecisionSource::Policy {
+                policy: "no-secrets".to_string()
+            }
+        );
+        assert_eq!(
+            denied.to_string(),
+            "denied: storage write on 'secret/key' by policy 'no-secrets'"
+        );
+
+        let allowed =
+            Explanation::new(&write("public/key"), grant).with_policies(&engine, &component);
+        assert!(allowed.allowed);
+        assert_eq!(
+            allowed.to_string(),
+            "allowed: storage write on 'public/key' by grant '*'"
+        );
+    }
+}
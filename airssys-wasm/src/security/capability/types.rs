@@ -6,7 +6,16 @@ pub use crate::core::security::capability::*;
 /// Pattern matcher for capability validation.
 ///
 /// Provides glob-like pattern matching for capability patterns.
-/// Supports wildcard (`*`), prefix patterns (`prefix/*`), and suffix patterns (`*.suffix`).
+/// Supports wildcard (`*`), prefix patterns (`prefix/*`), and suffix patterns (`*.suffix`),
+/// each optionally followed by `!`-prefixed exclusions (see [`split_exclusions`]).
+///
+/// # Exclusion Precedence
+///
+/// - Within one grant, exclusions win over the include pattern:
+///   `/data/* !/data/secrets/*` never matches `/data/secrets/key`.
+/// - Exclusions are scoped to the grant they are written in. Grants are
+///   unioned, so a separate grant of `/data/secrets/*` still allows the
+///   subtree; `CapabilitySet` checks succeed if any grant matches.
 pub struct PatternMatcher;
 
 impl PatternMatcher {
@@ -18,6 +27,9 @@ impl PatternMatcher {
     /// - `"prefix/*"` - Matches any target starting with `prefix/` (requires at least one character after /)
     /// - `"*.suffix"` - Matches any target ending with `.suffix` (requires at least one character before .)
     /// - `"exact"` - Matches exactly the target string
+    /// - `"include !excluded"` - Matches `include` except targets matching any exclusion
+    ///
+    /// Malformed exclusion syntax matches nothing.
     ///
     /// # Examples
     ///
@@ -34,8 +46,23 @@ impl PatternMatcher {
     /// // Exact match
     /// assert!(PatternMatcher::matches("exact", "exact"));
     /// assert!(!PatternMatcher::matches("exact", "different"));
+    ///
+    /// // Exclusions carve out a subtree
+    /// assert!(PatternMatcher::matches("/data/* !/data/secrets/*", "/data/reports/q1"));
+    /// assert!(!PatternMatcher::matches("/data/* !/data/secrets/*", "/data/secrets/key"));
     /// ```
     pub fn matches(pattern: &str, target: &str) -> bool {
+        let Some((include, exclusions)) = split_exclusions(pattern) else {
+            return false;
+        };
+        Self::matches_single(include, target)
+            && !exclusions
+                .iter()
+                .any(|excluded| Self::matches_single(excluded, target))
+    }
+
+    /// Match a target against a pattern without exclusions.
+    fn matches_single(pattern: &str, target: &str) -> bool {
         if pattern == "*" {
             return true;
         }
@@ -49,6 +76,86 @@ impl PatternMatcher {
         }
         pattern == target
     }
+
+    /// Whether every target matched by `inner` is also matched by `outer`.
+    ///
+    /// Used to check that a delegated pattern only narrows a grant, e.g.
+    /// `/data/reports/*` is covered by `/data/*` but not the reverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airssys_wasm::security::capability::types::PatternMatcher;
+    ///
+    /// assert!(PatternMatcher::covers("/data/*", "/data/reports/*"));
+    /// assert!(PatternMatcher::covers("*.internal", "api.internal"));
+    /// assert!(!PatternMatcher::covers("/data/reports/*", "/data/*"));
+    /// assert!(!PatternMatcher::covers("/data/*", "*"));
+    ///
+    /// // An exclusion of `outer` must stay excluded in `inner`
+    /// assert!(PatternMatcher::covers("/data/* !/data/secrets/*", "/data/reports/*"));
+    /// assert!(!PatternMatcher::covers("/data/* !/data/secrets/*", "/data/*"));
+    /// assert!(PatternMatcher::covers("/data/* !/data/secrets/*", "/data/* !/data/secrets/*"));
+    /// ```
+    pub fn covers(outer: &str, inner: &str) -> bool {
+        let (Some((outer, outer_exclusions)), Some((inner, inner_exclusions))) =
+            (split_exclusions(outer), split_exclusions(inner))
+        else {
+            return false;
+        };
+        // Each outer exclusion must be disjoint from inner or excluded by it
+        Self::covers_single(outer, inner)
+            && outer_exclusions.iter().all(|excluded| {
+                !Self::overlaps(excluded, inner)
+                    || inner_exclusions
+                        .iter()
+                        .any(|own| Self::covers_single(own, excluded))
+            })
+    }
+
+    /// Whether some target may match both patterns (conservatively true
+    /// for mixed prefix/suffix patterns).
+    fn overlaps(a: &str, b: &str) -> bool {
+        if a == "*" || b == "*" {
+            return true;
+        }
+        match (a.strip_suffix("/*"), b.strip_suffix("/*")) {
+            (Some(pa), Some(pb)) => return pa.starts_with(pb) || pb.starts_with(pa),
+            (Some(_), None) if !b.starts_with("*.") => return Self::matches_single(a, b),
+            (None, Some(_)) if !a.starts_with("*.") => return Self::matches_single(b, a),
+            _ => {}
+        }
+        match (a.strip_prefix("*."), b.strip_prefix("*.")) {
+            (Some(sa), Some(sb)) => sa.ends_with(sb) || sb.ends_with(sa),
+            (Some(_), None) if b.strip_suffix("/*").is_none() => Self::matches_single(a, b),
+            (None, Some(_)) if a.strip_suffix("/*").is_none() => Self::matches_single(b, a),
+            (None, None) => a == b,
+            _ => true,
+        }
+    }
+
+    /// Coverage check for patterns without exclusions.
+    fn covers_single(outer: &str, inner: &str) -> bool {
+        if outer == "*" {
+            return true;
+        }
+        if inner == "*" {
+            return false;
+        }
+        if let Some(inner_prefix) = inner.strip_suffix("/*") {
+            return match outer.strip_suffix("/*") {
+                Some(outer_prefix) => inner_prefix.starts_with(outer_prefix),
+                None => false,
+            };
+        }
+        if let Some(inner_suffix) = inner.strip_prefix("*.") {
+            return match outer.strip_prefix("*.") {
+                Some(outer_suffix) => inner_suffix.ends_with(outer_suffix),
+                None => false,
+            };
+        }
+        Self::matches_single(outer, inner)
+    }
 }
 
 #[cfg(test)]
@@ -98,4 +205,77 @@ mod tests {
         // Must have at least one character before the dot
         assert!(!PatternMatcher::matches("*.internal", "internal"));
     }
+
+    #[test]
+    fn test_exclusions_win_within_a_grant() {
+        let pattern = "/data/* !/data/secrets/* !/data/keys.pem";
+        assert!(PatternMatcher::matches(pattern, "/data/reports/q1.csv"));
+        assert!(!PatternMatcher::matches(pattern, "/data/secrets/db"));
+        assert!(!PatternMatcher::matches(pattern, "/data/keys.pem"));
+        assert!(PatternMatcher::matches(
+            "*.example.com !admin.example.com",
+            "api.example.com"
+        ));
+        assert!(!PatternMatcher::matches(
+            "*.example.com !admin.example.com",
+            "admin.example.com"
+        ));
+        // Malformed exclusions fail closed
+        assert!(!PatternMatcher::matches("/data/* /etc/*", "/data/x"));
+        assert!(!PatternMatcher::matches("/data/* !", "/data/x"));
+    }
+
+    #[test]
+    fn test_patterns_with_spaces() {
+        let pattern = "/data/My Files/* !/data/My Files/private/*";
+        assert!(PatternMatcher::matches(
+            pattern,
+            "/data/My Files/report.txt"
+        ));
+        assert!(!PatternMatcher::matches(
+            pattern,
+            "/data/My Files/private/key"
+        ));
+        assert!(!PatternMatcher::matches(pattern, "/data/My/report.txt"));
+        assert!(PatternMatcher::matches(
+            "/data/My Files/*",
+            "/data/My Files/a b"
+        ));
+    }
+
+    #[test]
+    fn test_covers_respects_exclusions() {
+        let outer = "/data/* !/data/secrets/*";
+        assert!(PatternMatcher::covers(outer, "/data/reports/*"));
+        assert!(PatternMatcher::covers(outer, "/data/reports/q1.csv"));
+        assert!(!PatternMatcher::covers(outer, "/data/secrets/db"));
+        assert!(!PatternMatcher::covers(outer, "/data/*"));
+        assert!(PatternMatcher::covers(outer, "/data/* !/data/secrets/*"));
+        assert!(PatternMatcher::covers(
+            outer,
+            "/data/* !/data/secrets/*  !/data/tmp/*"
+        ));
+        assert!(PatternMatcher::covers(
+            "/data/*",
+            "/data/* !/data/secrets/*"
+        ));
+        assert!(!PatternMatcher::covers(
+            "*.example.com !admin.example.com",
+            "*.example.com"
+        ));
+    }
+
+    #[test]
+    fn test_covers_only_narrower_patterns() {
+        assert!(PatternMatcher::covers("*", "*"));
+        assert!(PatternMatcher::covers("/data/*", "/data/*"));
+        assert!(PatternMatcher::covers("/data/*", "/data/reports/*"));
+        assert!(PatternMatcher::covers("/data/*", "/data/reports/q1.csv"));
+        assert!(!PatternMatcher::covers("/data/reports/*", "/data/*"));
+        assert!(!PatternMatcher::covers("/data/*", "/etc/*"));
+        assert!(PatternMatcher::covers("*.internal", "*.db.internal"));
+        assert!(!PatternMatcher::covers("*.db.internal", "*.internal"));
+        assert!(!PatternMatcher::covers("exact", "exact/*"));
+        assert!(PatternMatcher::covers("exact", "exact"));
+    }
 }
@@ -0,0 +1,269 @@
+//! Per-grant usage counters for least-privilege reporting.
+//!
+//! [`UsageTracker`] counts, per component, how often each granted pattern
+//! actually allowed a capability check and when it last did. A
+//! [`UsageReport`] joins the counters with the registered grants so grants
+//! that were never or no longer exercised show up with zero uses; an
+//! `audit --usage` command prints the report and
+//! [`UsageReport::unused_for_days`] lists the candidates for removal.
+//!
+//! Only grants from a component's registered capability set are counted;
+//! delegated capabilities and runtime grants are temporary by design.
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+use chrono::DateTime;
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use crate::core::component::id::ComponentId;
+
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// One granted pattern of a capability set.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GrantKey {
+    /// Capability class ("storage", "network", ...).
+    pub capability: String,
+    /// Grant list within the class ("read", "write", "connect", ...).
+    pub action: String,
+    /// Granted pattern (or port).
+    pub pattern: String,
+}
+
+impl GrantKey {
+    /// Creates a grant key.
+    pub fn new(
+        capability: impl Into<String>,
+        action: impl Into<String>,
+        pattern: impl Into<String>,
+    ) -> Self {
+        Self {
+            capability: capability.into(),
+            action: action.into(),
+            pattern: pattern.into(),
+        }
+    }
+}
+
+impl fmt::Display for GrantKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} '{}'", self.capability, self.action, self.pattern)
+    }
+}
+
+/// How often a grant was exercised.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GrantUsage {
+    /// Checks the grant allowed.
+    pub count: u64,
+    /// Time of the last allowed check, in milliseconds since the epoch.
+    pub last_used_ms: Option<u64>,
+}
+
+#[derive(Debug, Default)]
+struct ComponentUsage {
+    registered_at_ms: u64,
+    grants: HashMap<GrantKey, GrantUsage>,
+}
+
+/// Usage counters of every registered component.
+#[derive(Debug, Default)]
+pub struct UsageTracker {
+    components: Mutex<HashMap<ComponentId, ComponentUsage>>,
+}
+
+impl UsageTracker {
+    /// Creates an empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts tracking `component`, registered at `now_ms`.
+    ///
+    /// Re-registering (e.g. after an update) resets its counters.
+    pub fn register(&self, component: &ComponentId, now_ms: u64) {
+        self.components.lock().unwrap().insert(
+            component.clone(),
+            ComponentUsage {
+                registered_at_ms: now_ms,
+                grants: HashMap::new(),
+            },
+        );
+    }
+
+    /// Stops tracking `component`.
+    pub fn forget(&self, component: &ComponentId) {
+        self.components.lock().unwrap().remove(component);
+    }
+
+    /// Counts one allowed check by `grant` at `now_ms`.
+    pub fn record(&self, component: &ComponentId, grant: GrantKey, now_ms: u64) {
+        let mut components = self.components.lock().unwrap();
+        if let Some(usage) = components.get_mut(component) {
+            let entry = usage.grants.entry(grant).or_default();
+            entry.count += 1;
+            entry.last_used_ms = Some(now_ms);
+        }
+    }
+
+    /// Usage of `grant` by `component`.
+    pub fn usage(&self, component: &ComponentId, grant: &GrantKey) -> GrantUsage {
+        self.components
+            .lock()
+            .unwrap()
+            .get(component)
+            .and_then(|usage| usage.grants.get(grant).copied())
+            .unwrap_or_default()
+    }
+
+    /// Report rows for `grants` of `component`, including unused ones.
+    pub(crate) fn rows(&self, component: &ComponentId, grants: Vec<GrantKey>) -> Vec<UsageRow> {
+        let components = self.components.lock().unwrap();
+        let usage = components.get(component);
+        grants
+            .into_iter()
+            .map(|grant| {
+                let counters = usage
+                    .and_then(|usage| usage.grants.get(&grant).copied())
+                    .unwrap_or_default();
+                UsageRow {
+                    component: component.clone(),
+                    grant,
+                    count: counters.count,
+                    last_used_ms: counters.last_used_ms,
+                    registered_at_ms: usage.map_or(0, |usage| usage.registered_at_ms),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Usage of one grant of one component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageRow {
+    /// Component holding the grant.
+    pub component: ComponentId,
+    /// The grant.
+    pub grant: GrantKey,
+    /// Checks the grant allowed.
+    pub count: u64,
+    /// Time of the last allowed check, in milliseconds since the epoch.
+    pub last_used_ms: Option<u64>,
+    /// When the component's capabilities were registered.
+    pub registered_at_ms: u64,
+}
+
+impl UsageRow {
+    /// Start of the current idle period: the last use, or the
+    /// registration if the grant was never used.
+    pub fn idle_since_ms(&self) -> u64 {
+        self.last_used_ms.unwrap_or(self.registered_at_ms)
+    }
+}
+
+/// Least-privilege report over all registered grants.
+///
+/// # Examples
+///
+/// ```
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::security::capability::{Capability, StorageAction, StorageCapability};
+/// use airssys_wasm::core::security::traits::SecurityValidator;
+/// use airssys_wasm::security::capability::set::{CapabilitySet, StoragePermission};
+/// use airssys_wasm::security::capability::validator::CapabilityValidator;
+///
+/// let validator = CapabilityValidator::new();
+/// let id = ComponentId::new("acme", "reports", "v1");
+/// validator.register_component(
+///     id.clone(),
+///     CapabilitySet::builder()
+///         .storage(StoragePermission {
+///             can_read_keys: vec!["reports/*".to_string()],
+///             can_write_keys: vec!["*".to_string()],
+///         })
+///         .build(),
+/// );
+/// let read = Capability::Storage(StorageCapability {
+///     action: StorageAction::Read,
+///     namespace_pattern: "reports/q1".to_string(),
+/// });
+/// validator.validate_capability(&id, &read).unwrap();
+///
+/// let report = validator.usage_report();
+/// assert_eq!(report.rows.len(), 2);
+/// // Nothing was written: the write grant is a removal candidate
+/// let far_future = u64::MAX;
+/// let unused = report.unused_for_days(0, far_future);
+/// assert!(unused.iter().any(|row| row.grant.action == "write" && row.count == 0));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UsageReport {
+    /// One row per registered grant, sorted by component and grant.
+    pub rows: Vec<UsageRow>,
+}
+
+impl UsageReport {
+    /// Grants idle for at least `days` days at `now_ms`.
+    pub fn unused_for_days(&self, days: u32, now_ms: u64) -> Vec<&UsageRow> {
+        let threshold = u64::from(days).saturating_mul(DAY_MS);
+        self.rows
+            .iter()
+            .filter(|row| now_ms.saturating_sub(row.idle_since_ms()) >= threshold)
+            .collect()
+    }
+}
+
+impl fmt::Display for UsageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.rows {
+            let last_used = row
+                .last_used_ms
+                .and_then(|ms| DateTime::from_timestamp_millis(i64::try_from(ms).ok()?))
+                .map_or_else(|| "never".to_string(), |at| at.to_rfc3339());
+            writeln!(
+                f,
+                "{}  {}  uses={}  last_used={}",
+                row.component, row.grant, row.count, last_used
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idle_period_starts_at_registration_or_last_use() {
+        let tracker = UsageTracker::new();
+        let id = ComponentId::new("acme", "reports", "v1");
+        let read = GrantKey::new("storage", "read", "reports/*");
+        let write = GrantKey::new("storage", "write", "*");
+        tracker.register(&id, 0);
+        tracker.record(&id, read.clone(), 5 * DAY_MS);
+        tracker.record(&id, read.clone(), 6 * DAY_MS);
+
+        let report = UsageReport {
+            rows: tracker.rows(&id, vec![read.clone(), write.clone()]),
+        };
+        let unused: Vec<_> = report
+            .unused_for_days(3, 8 * DAY_MS)
+            .into_iter()
+            .map(|row| &row.grant)
+            .collect();
+        assert_eq!(unused, vec![&write]);
+        assert_eq!(tracker.usage(&id, &read).count, 2);
+        assert!(report
+            .to_string()
+            .contains("storage write '*'  uses=0  last_used=never"));
+
+        // Counters reset when the component is registered again
+        tracker.register(&id, 9 * DAY_MS);
+        assert_eq!(tracker.usage(&id, &read), GrantUsage::default());
+    }
+}
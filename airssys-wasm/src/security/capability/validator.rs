@@ -2,6 +2,14 @@
 //!
 //! Provides thread-safe capability validation for components using a
 //! read-write lock protected HashMap of ComponentId -> CapabilitySet.
+//!
+//! On top of the registered sets, a component may hold delegated
+//! capabilities and runtime grants. Runtime grants can expire and be
+//! revoked; expiry is checked against the clock on every validation.
+//!
+//! Every check allowed by a component's registered set is counted against
+//! the grant that allowed it (see [`usage`](super::usage)), so
+//! [`CapabilityValidator::usage_report`] can point out unused grants.
 
 // Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
 use std::collections::HashMap;
@@ -9,11 +17,19 @@ use std::sync::RwLock;
 
 // Layer 3: Internal module imports
 use crate::core::component::id::ComponentId;
-use crate::core::security::capability::{Capability, MessagingAction, StorageAction};
-use crate::core::security::errors::SecurityError;
+use crate::core::security::capability::{
+    Capability, FilesystemAction, MessagingAction, MessagingCapability, NetworkAction,
+    StorageAction,
+};
+use crate::core::security::errors::{PermissionDenial, SecurityError};
+use crate::core::security::quota::{QuotaClass, ResourceQuota};
 use crate::core::security::traits::SecurityValidator;
 
+use super::delegation::DelegationToken;
+use super::grant::{now_ms, CapabilityGrant};
 use super::set::CapabilitySet;
+use super::simulate::{DecisionSource, Explanation};
+use super::usage::{UsageReport, UsageTracker};
 
 /// Implementation of SecurityValidator trait.
 ///
@@ -50,6 +66,12 @@ use super::set::CapabilitySet;
 pub struct CapabilityValidator {
     /// Registered capabilities per component.
     capabilities: RwLock<HashMap<ComponentId, CapabilitySet>>,
+    /// Delegation tokens by ID.
+    delegations: RwLock<HashMap<String, DelegationToken>>,
+    /// Runtime grants by ID.
+    grants: RwLock<HashMap<String, CapabilityGrant>>,
+    /// Use counts of registered grants.
+    usage: UsageTracker,
 }
 
 impl CapabilityValidator {
@@ -65,6 +87,9 @@ impl CapabilityValidator {
     pub fn new() -> Self {
         Self {
             capabilities: RwLock::new(HashMap::new()),
+            delegations: RwLock::new(HashMap::new()),
+            grants: RwLock::new(HashMap::new()),
+            usage: UsageTracker::new(),
         }
     }
 
@@ -90,6 +115,7 @@ impl CapabilityValidator {
     /// ```
     pub fn register_component(&self, id: ComponentId, capabilities: CapabilitySet) {
         let mut caps = self.capabilities.write().unwrap();
+        self.usage.register(&id, now_ms());
         caps.insert(id, capabilities);
     }
 
@@ -112,9 +138,396 @@ impl CapabilityValidator {
     /// validator.register_component(component_id.clone(), CapabilitySet::new());
     /// validator.unregister_component(&component_id);
     /// ```
+    ///
+    /// Tokens the component issued or holds are revoked with it, as are its
+    /// runtime grants.
     pub fn unregister_component(&self, id: &ComponentId) {
         let mut caps = self.capabilities.write().unwrap();
         caps.remove(id);
+        drop(caps);
+        self.usage.forget(id);
+        self.revoke_grants_for(id);
+
+        let affected: Vec<String> = self
+            .delegations
+            .read()
+            .unwrap()
+            .values()
+            .filter(|token| &token.issuer == id || &token.holder == id)
+            .map(|token| token.id.clone())
+            .collect();
+        for token_id in affected {
+            self.revoke_delegation(&token_id);
+        }
+    }
+
+    /// Add a runtime grant on top of the component's registered
+    /// capabilities and return its ID.
+    ///
+    /// The grant applies to every check until it expires (see
+    /// [`CapabilityGrant::expiring_after`]) or is revoked with
+    /// [`revoke_grant`](Self::revoke_grant); the component does not need to
+    /// be restarted either way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use airssys_wasm::core::component::id::ComponentId;
+    /// use airssys_wasm::core::security::capability::{Capability, NetworkAction, NetworkCapability};
+    /// use airssys_wasm::core::security::traits::SecurityValidator;
+    /// use airssys_wasm::security::capability::grant::CapabilityGrant;
+    /// use airssys_wasm::security::capability::set::{CapabilitySet, NetworkPermission};
+    /// use airssys_wasm::security::capability::validator::CapabilityValidator;
+    ///
+    /// let validator = CapabilityValidator::new();
+    /// let id = ComponentId::new("org", "exporter", "1");
+    /// validator.register_component(id.clone(), CapabilitySet::new());
+    ///
+    /// let temporary = CapabilitySet::builder()
+    ///     .network(NetworkPermission {
+    ///         can_connect_to: vec!["backup.internal".to_string()],
+    ///         can_bind_ports: vec![],
+    ///     })
+    ///     .build();
+    /// let grant_id = validator.add_grant(CapabilityGrant::expiring_after(
+    ///     id.clone(),
+    ///     temporary,
+    ///     Duration::from_secs(15 * 60),
+    /// ));
+    ///
+    /// let connect = Capability::Network(NetworkCapability {
+    ///     action: NetworkAction::Outbound,
+    ///     host_pattern: "backup.internal".to_string(),
+    ///     port: None,
+    /// });
+    /// assert!(validator.validate_capability(&id, &connect).is_ok());
+    ///
+    /// validator.revoke_grant(&grant_id);
+    /// assert!(validator.validate_capability(&id, &connect).is_err());
+    /// ```
+    pub fn add_grant(&self, grant: CapabilityGrant) -> String {
+        let id = uuid::Uuid::new_v4().to_string();
+        tracing::info!(
+            grant = %id,
+            component = %grant.component,
+            expires_at = ?grant.expires_at,
+            "capability grant added"
+        );
+        self.grants.write().unwrap().insert(id.clone(), grant);
+        id
+    }
+
+    /// Revoke a runtime grant, returning it if it existed.
+    pub fn revoke_grant(&self, grant_id: &str) -> Option<CapabilityGrant> {
+        let grant = self.grants.write().unwrap().remove(grant_id);
+        if let Some(grant) = &grant {
+            tracing::info!(grant = %grant_id, component = %grant.component, "capability grant revoked");
+        }
+        grant
+    }
+
+    /// Revoke every runtime grant of `component`, returning how many were
+    /// removed.
+    pub fn revoke_grants_for(&self, component: &ComponentId) -> usize {
+        let mut grants = self.grants.write().unwrap();
+        let before = grants.len();
+        grants.retain(|_, grant| &grant.component != component);
+        before - grants.len()
+    }
+
+    /// Unexpired runtime grants of `component`, keyed by grant ID.
+    pub fn grants_for(&self, component: &ComponentId) -> Vec<(String, CapabilityGrant)> {
+        let now = now_ms();
+        self.grants
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, grant)| &grant.component == component && !grant.is_expired(now))
+            .map(|(id, grant)| (id.clone(), grant.clone()))
+            .collect()
+    }
+
+    /// Drop expired runtime grants, returning how many were removed.
+    ///
+    /// Expired grants are already ignored by every check; this only frees
+    /// them.
+    pub fn purge_expired_grants(&self) -> usize {
+        let now = now_ms();
+        let mut grants = self.grants.write().unwrap();
+        let before = grants.len();
+        grants.retain(|_, grant| !grant.is_expired(now));
+        before - grants.len()
+    }
+
+    /// Explain whether `component` would be allowed `capability`, without
+    /// performing anything.
+    ///
+    /// Sources are consulted in the order real checks use them: the
+    /// registered set, delegation tokens, then unexpired runtime grants.
+    /// Combine with [`Explanation::with_policies`] to include policy deny
+    /// rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airssys_wasm::core::component::id::ComponentId;
+    /// use airssys_wasm::core::security::capability::{
+    ///     Capability, NetworkAction, NetworkCapability,
+    /// };
+    /// use airssys_wasm::security::capability::set::CapabilitySet;
+    /// use airssys_wasm::security::capability::simulate::DecisionSource;
+    /// use airssys_wasm::security::capability::validator::CapabilityValidator;
+    ///
+    /// let validator = CapabilityValidator::new();
+    /// let id = ComponentId::new("org", "fetcher", "1");
+    /// validator.register_component(id.clone(), CapabilitySet::new());
+    ///
+    /// let connect = Capability::Network(NetworkCapability {
+    ///     action: NetworkAction::Outbound,
+    ///     host_pattern: "api.example.com".to_string(),
+    ///     port: None,
+    /// });
+    /// let explanation = validator.simulate(&id, &connect);
+    /// assert!(!explanation.allowed);
+    /// assert_eq!(explanation.decided_by, DecisionSource::NoMatchingGrant);
+    /// ```
+    pub fn simulate(&self, component: &ComponentId, capability: &Capability) -> Explanation {
+        let own = match self.capabilities.read().unwrap().get(component) {
+            Some(set) => set.matching_grant(capability),
+            None => return Explanation::new(capability, DecisionSource::NotRegistered),
+        };
+        if let Some(pattern) = own {
+            return Explanation::new(capability, DecisionSource::Grant { pattern });
+        }
+
+        let delegated = self
+            .delegations
+            .read()
+            .unwrap()
+            .values()
+            .filter(|token| &token.holder == component)
+            .find_map(|token| {
+                token
+                    .capabilities
+                    .matching_grant(capability)
+                    .map(|pattern| DecisionSource::Delegation {
+                        token_id: token.id.clone(),
+                        issuer: token.issuer.clone(),
+                        pattern,
+                    })
+            });
+        if let Some(source) = delegated {
+            return Explanation::new(capability, source);
+        }
+
+        let now = now_ms();
+        let granted = self
+            .grants
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, grant)| &grant.component == component && !grant.is_expired(now))
+            .find_map(|(id, grant)| {
+                grant
+                    .capabilities
+                    .matching_grant(capability)
+                    .map(|pattern| DecisionSource::RuntimeGrant {
+                        grant_id: id.clone(),
+                        pattern,
+                    })
+            });
+        Explanation::new(
+            capability,
+            granted.unwrap_or(DecisionSource::NoMatchingGrant),
+        )
+    }
+
+    /// Whether a delegated token or an unexpired runtime grant held by
+    /// `component` satisfies `allows`.
+    fn extra_allows(
+        &self,
+        component: &ComponentId,
+        allows: impl Fn(&CapabilitySet) -> bool,
+    ) -> bool {
+        let delegated = self
+            .delegations
+            .read()
+            .unwrap()
+            .values()
+            .filter(|token| &token.holder == component)
+            .any(|token| allows(&token.capabilities));
+        if delegated {
+            return true;
+        }
+
+        let now = now_ms();
+        self.grants
+            .read()
+            .unwrap()
+            .values()
+            .filter(|grant| &grant.component == component && !grant.is_expired(now))
+            .any(|grant| allows(&grant.capabilities))
+    }
+
+    /// Delegate `requested` capabilities from `issuer` to `holder`.
+    ///
+    /// `requested` must be an attenuation of either the issuer's own grants
+    /// or a single token the issuer holds; in the latter case the new token
+    /// records that token as its parent. The delegation is logged for
+    /// audit.
+    ///
+    /// # Errors
+    ///
+    /// - `SecurityError::CapabilityDenied` if either component is not
+    ///   registered
+    /// - `SecurityError::Denied` if `requested` would widen the issuer's
+    ///   grants
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airssys_wasm::core::component::id::ComponentId;
+    /// use airssys_wasm::core::security::capability::{
+    ///     Capability, FilesystemAction, FilesystemCapability,
+    /// };
+    /// use airssys_wasm::core::security::traits::SecurityValidator;
+    /// use airssys_wasm::security::capability::set::{CapabilitySet, FilesystemPermission};
+    /// use airssys_wasm::security::capability::validator::CapabilityValidator;
+    ///
+    /// let reads = |path: &str| FilesystemPermission {
+    ///     can_read_paths: vec![path.to_string()],
+    ///     can_write_paths: vec![],
+    ///     can_execute_paths: vec![],
+    /// };
+    /// let validator = CapabilityValidator::new();
+    /// let a = ComponentId::new("org", "exporter", "1");
+    /// let b = ComponentId::new("org", "reporter", "1");
+    /// validator.register_component(a.clone(), CapabilitySet::builder().filesystem(reads("/data/*")).build());
+    /// validator.register_component(b.clone(), CapabilitySet::new());
+    ///
+    /// let narrowed = CapabilitySet::builder().filesystem(reads("/data/reports/*")).build();
+    /// validator.delegate(&a, &b, narrowed).unwrap();
+    ///
+    /// let read = |path: &str| Capability::Filesystem(FilesystemCapability {
+    ///     action: FilesystemAction::Read,
+    ///     path_pattern: path.to_string(),
+    /// });
+    /// assert!(validator.validate_capability(&b, &read("/data/reports/q1.csv")).is_ok());
+    /// assert!(validator.validate_capability(&b, &read("/data/secrets.env")).is_err());
+    /// ```
+    pub fn delegate(
+        &self,
+        issuer: &ComponentId,
+        holder: &ComponentId,
+        requested: CapabilitySet,
+    ) -> Result<DelegationToken, SecurityError> {
+        let caps = self.capabilities.read().unwrap();
+        let issuer_caps = caps.get(issuer).ok_or_else(|| {
+            SecurityError::CapabilityDenied(format!("Issuer {} not registered", issuer))
+        })?;
+        if !caps.contains_key(holder) {
+            return Err(SecurityError::CapabilityDenied(format!(
+                "Holder {} not registered",
+                holder
+            )));
+        }
+
+        let mut delegations = self.delegations.write().unwrap();
+        let parent = match issuer_caps.attenuate(&requested) {
+            Ok(()) => None,
+            Err(denied) => Some(
+                delegations
+                    .values()
+                    .find(|token| {
+                        &token.holder == issuer && token.capabilities.attenuate(&requested).is_ok()
+                    })
+                    .map(|token| token.id.clone())
+                    .ok_or(denied)?,
+            ),
+        };
+
+        let token = DelegationToken::new(issuer.clone(), holder.clone(), requested, parent);
+        tracing::info!(
+            token = %token.id,
+            issuer = %issuer,
+            holder = %holder,
+            parent = ?token.parent,
+            "capabilities delegated"
+        );
+        delegations.insert(token.id.clone(), token.clone());
+        Ok(token)
+    }
+
+    /// Revoke a delegation token and every token delegated from it.
+    ///
+    /// Returns the IDs of all revoked tokens (empty if `token_id` is
+    /// unknown).
+    pub fn revoke_delegation(&self, token_id: &str) -> Vec<String> {
+        let mut delegations = self.delegations.write().unwrap();
+        let mut revoked = Vec::new();
+        let mut pending = vec![token_id.to_string()];
+        while let Some(id) = pending.pop() {
+            if delegations.remove(&id).is_none() {
+                continue;
+            }
+            pending.extend(
+                delegations
+                    .values()
+                    .filter(|token| token.parent.as_deref() == Some(id.as_str()))
+                    .map(|token| token.id.clone()),
+            );
+            revoked.push(id);
+        }
+        if !revoked.is_empty() {
+            tracing::info!(token = %token_id, revoked = revoked.len(), "delegation revoked");
+        }
+        revoked
+    }
+
+    /// Tokens currently held by `holder`.
+    pub fn delegations_held_by(&self, holder: &ComponentId) -> Vec<DelegationToken> {
+        self.delegations
+            .read()
+            .unwrap()
+            .values()
+            .filter(|token| &token.holder == holder)
+            .cloned()
+            .collect()
+    }
+
+    /// Delegation chain of a token for audit, starting with the token and
+    /// ending with the one delegated from the original grant.
+    ///
+    /// Empty if the token is unknown or revoked.
+    pub fn provenance(&self, token_id: &str) -> Vec<DelegationToken> {
+        let delegations = self.delegations.read().unwrap();
+        let mut chain = Vec::new();
+        let mut next = delegations.get(token_id);
+        while let Some(token) = next {
+            chain.push(token.clone());
+            next = token.parent.as_ref().and_then(|id| delegations.get(id));
+        }
+        chain
+    }
+
+    /// Usage counters of registered grants.
+    pub fn usage_tracker(&self) -> &UsageTracker {
+        &self.usage
+    }
+
+    /// Usage of every registered grant of every registered component.
+    ///
+    /// See [`UsageReport`] for an example.
+    pub fn usage_report(&self) -> UsageReport {
+        let caps = self.capabilities.read().unwrap();
+        let mut components: Vec<_> = caps.iter().collect();
+        components.sort_by_key(|(id, _)| id.to_string_id());
+        let rows = components
+            .into_iter()
+            .flat_map(|(id, set)| self.usage.rows(id, set.grants()))
+            .collect();
+        UsageReport { rows }
     }
 }
 
@@ -141,74 +554,23 @@ impl SecurityValidator for CapabilityValidator {
             SecurityError::CapabilityDenied(format!("Component {} not registered", component))
         })?;
 
-        match capability {
-            // For messaging capabilities, we verify that the target pattern
-            // matches a pattern in the component's send permissions
-            Capability::Messaging(msg_cap) => {
-                // Convert action to string for error messages
-                let action_str = match msg_cap.action {
-                    MessagingAction::Send => "send",
-                    MessagingAction::Request => "request",
-                    MessagingAction::Broadcast => "broadcast",
-                };
-
-                // Check if component has permission to send to this target pattern
-                if !component_caps.can_send_to(&msg_cap.target_pattern) {
-                    return Err(SecurityError::CapabilityDenied(format!(
-                        "Messaging capability denied for {}: cannot {} to {}",
-                        component, action_str, msg_cap.target_pattern
-                    )));
-                }
-            }
-
-            // For storage capabilities, we verify that the namespace pattern
-            // matches a pattern in the component's read/write permissions
-            Capability::Storage(storage_cap) => {
-                // Convert action to string for error messages
-                let action_str = match storage_cap.action {
-                    StorageAction::Read => "read",
-                    StorageAction::Write => "write",
-                    StorageAction::Delete => "delete",
-                };
-
-                // Check if component has permission for this namespace pattern
-                let has_permission = match storage_cap.action {
-                    StorageAction::Read => {
-                        component_caps.can_read_key(&storage_cap.namespace_pattern)
-                    }
-                    StorageAction::Write => {
-                        component_caps.can_write_key(&storage_cap.namespace_pattern)
-                    }
-                    StorageAction::Delete => {
-                        component_caps.can_write_key(&storage_cap.namespace_pattern)
-                    } // Delete requires write
-                };
-
-                if !has_permission {
-                    return Err(SecurityError::CapabilityDenied(format!(
-                        "Storage capability denied for {}: cannot {} namespace {}",
-                        component, action_str, storage_cap.namespace_pattern
-                    )));
+        let denied = match check_capability(component_caps, component, capability) {
+            Ok(()) => {
+                if let Some(grant) = component_caps.matching_grant_key(capability) {
+                    self.usage.record(component, grant, now_ms());
                 }
+                return Ok(());
             }
+            Err(e) => e,
+        };
 
-            // Other capability types (Filesystem, Network) are not yet supported
-            Capability::Filesystem(_) => {
-                return Err(SecurityError::CapabilityDenied(format!(
-                    "Filesystem capability not supported for {}",
-                    component
-                )));
-            }
-
-            Capability::Network(_) => {
-                return Err(SecurityError::CapabilityDenied(format!(
-                    "Network capability not supported for {}",
-                    component
-                )));
-            }
+        // Fall back to delegated capabilities and runtime grants
+        if self.extra_allows(component, |set| {
+            check_capability(set, component, capability).is_ok()
+        }) {
+            return Ok(());
         }
-
-        Ok(())
+        Err(denied)
     }
 
     /// Check if component can send message to target.
@@ -230,22 +592,212 @@ impl SecurityValidator for CapabilityValidator {
         })?;
 
         let target_str = target.to_string_id();
-        if !sender_caps.can_send_to(&target_str) {
-            return Err(SecurityError::PermissionDenied(format!(
-                "{} cannot send to {}",
-                sender, target
+        let send = Capability::Messaging(MessagingCapability {
+            action: MessagingAction::Send,
+            target_pattern: target_str.clone(),
+        });
+        if let Some(grant) = sender_caps.matching_grant_key(&send) {
+            self.usage.record(sender, grant, now_ms());
+        } else if !self.extra_allows(sender, |set| set.can_send_to(&target_str)) {
+            return Err(SecurityError::Denied(PermissionDenial::new(
+                "messaging",
+                "send",
+                target_str,
+                format!("no messaging grant for {} matches", sender),
             )));
         }
 
         Ok(())
     }
+
+    /// Quota of the component's registered grants of `class`.
+    ///
+    /// A component without its own quota inherits the quota of a
+    /// delegation it holds, so delegated grants cannot escape their
+    /// delegator's limits.
+    fn quota(&self, component: &ComponentId, class: QuotaClass) -> Option<ResourceQuota> {
+        let own = self
+            .capabilities
+            .read()
+            .unwrap()
+            .get(component)
+            .and_then(|set| set.quota(class));
+        own.or_else(|| {
+            self.delegations
+                .read()
+                .unwrap()
+                .values()
+                .filter(|token| &token.holder == component)
+                .find_map(|token| token.capabilities.quota(class))
+        })
+    }
+
+    /// Add the elevation as a runtime grant without expiry.
+    ///
+    /// See [`CapabilitySet::from_elevation`] for the accepted capabilities.
+    fn grant_elevated(
+        &self,
+        component: &ComponentId,
+        capability: &str,
+    ) -> Result<(), SecurityError> {
+        let capabilities = CapabilitySet::from_elevation(capability)?;
+        self.add_grant(CapabilityGrant::new(component.clone(), capabilities));
+        Ok(())
+    }
+}
+
+/// Checks `capability` against one capability set.
+fn check_capability(
+    component_caps: &CapabilitySet,
+    component: &ComponentId,
+    capability: &Capability,
+) -> Result<(), SecurityError> {
+    match capability {
+        // For messaging capabilities, we verify that the target pattern
+        // matches a pattern in the component's send permissions
+        Capability::Messaging(msg_cap) => {
+            // Convert action to string for error messages
+            let action_str = match msg_cap.action {
+                MessagingAction::Send => "send",
+                MessagingAction::Request => "request",
+                MessagingAction::Broadcast => "broadcast",
+            };
+
+            // Check if component has permission to send to this target pattern
+            if !component_caps.can_send_to(&msg_cap.target_pattern) {
+                return Err(SecurityError::Denied(PermissionDenial::new(
+                    "messaging",
+                    action_str,
+                    &msg_cap.target_pattern,
+                    format!("no messaging grant for {} matches", component),
+                )));
+            }
+        }
+
+        // For storage capabilities, we verify that the namespace pattern
+        // matches a pattern in the component's read/write permissions
+        Capability::Storage(storage_cap) => {
+            // Convert action to string for error messages
+            let action_str = match storage_cap.action {
+                StorageAction::Read => "read",
+                StorageAction::Write => "write",
+                StorageAction::Delete => "delete",
+            };
+
+            // Check if component has permission for this namespace pattern
+            let has_permission = match storage_cap.action {
+                StorageAction::Read => component_caps.can_read_key(&storage_cap.namespace_pattern),
+                StorageAction::Write => {
+                    component_caps.can_write_key(&storage_cap.namespace_pattern)
+                }
+                StorageAction::Delete => {
+                    component_caps.can_write_key(&storage_cap.namespace_pattern)
+                } // Delete requires write
+            };
+
+            if !has_permission {
+                return Err(SecurityError::Denied(PermissionDenial::new(
+                    "storage",
+                    action_str,
+                    &storage_cap.namespace_pattern,
+                    format!("no storage grant for {} matches", component),
+                )));
+            }
+        }
+
+        // For filesystem capabilities, the path must match a pattern
+        // granted for the action
+        Capability::Filesystem(fs_cap) => {
+            let (action_str, has_permission) = match fs_cap.action {
+                FilesystemAction::Read => {
+                    ("read", component_caps.can_read_path(&fs_cap.path_pattern))
+                }
+                FilesystemAction::ListDir => {
+                    ("list", component_caps.can_read_path(&fs_cap.path_pattern))
+                }
+                FilesystemAction::Write => {
+                    ("write", component_caps.can_write_path(&fs_cap.path_pattern))
+                }
+                // Delete requires write
+                FilesystemAction::Delete => (
+                    "delete",
+                    component_caps.can_write_path(&fs_cap.path_pattern),
+                ),
+                FilesystemAction::Execute => (
+                    "execute",
+                    component_caps.can_execute_path(&fs_cap.path_pattern),
+                ),
+            };
+
+            if !has_permission {
+                return Err(SecurityError::Denied(PermissionDenial::new(
+                    "filesystem",
+                    action_str,
+                    &fs_cap.path_pattern,
+                    format!("no filesystem grant for {} matches", component),
+                )));
+            }
+        }
+
+        // For network capabilities, outbound connections need a matching
+        // host pattern and inbound ones a granted port
+        Capability::Network(net_cap) => {
+            let (action_str, has_permission) = match net_cap.action {
+                NetworkAction::Outbound => (
+                    "connect",
+                    component_caps.can_connect_to(&net_cap.host_pattern),
+                ),
+                NetworkAction::Inbound => (
+                    "bind",
+                    net_cap
+                        .port
+                        .is_some_and(|port| component_caps.can_bind_port(port)),
+                ),
+            };
+
+            if !has_permission {
+                return Err(SecurityError::Denied(PermissionDenial::new(
+                    "network",
+                    action_str,
+                    &net_cap.host_pattern,
+                    format!("no network grant for {} matches", component),
+                )));
+            }
+        }
+
+        // Environment, clock, randomness, and process grants are plain
+        // pattern lists: the request must match one of them
+        Capability::Environment(_)
+        | Capability::Clock(_)
+        | Capability::Random(_)
+        | Capability::Process(_) => {
+            let explanation = component_caps.simulate(capability);
+            if !explanation.allowed {
+                return Err(SecurityError::Denied(PermissionDenial::new(
+                    &explanation.capability,
+                    explanation.action,
+                    explanation.resource,
+                    format!(
+                        "no {} grant for {} matches",
+                        explanation.capability, component
+                    ),
+                )));
+            }
+        }
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::security::capability::{MessagingCapability, StorageCapability};
-    use crate::security::capability::set::{MessagingPermission, StoragePermission};
+    use crate::core::security::capability::{
+        FilesystemCapability, MessagingCapability, NetworkCapability, StorageCapability,
+    };
+    use crate::security::capability::set::{
+        FilesystemPermission, MessagingPermission, NetworkPermission, StoragePermission,
+    };
 
     #[test]
     fn test_new_validator_has_empty_capabilities() {
@@ -359,7 +911,11 @@ mod tests {
 
         let result = validator.validate_capability(&component_id, &Capability::Messaging(msg_cap));
         assert!(result.is_err());
-        assert!(matches!(result, Err(SecurityError::CapabilityDenied(_))));
+        let err = result.unwrap_err();
+        let denial = err.denial().expect("structured denial");
+        assert_eq!(denial.capability, "messaging");
+        assert_eq!(denial.action, "send");
+        assert_eq!(denial.pattern, "org.denied/inst-2");
     }
 
     #[test]
@@ -408,7 +964,149 @@ mod tests {
         let result =
             validator.validate_capability(&component_id, &Capability::Storage(storage_cap));
         assert!(result.is_err());
-        assert!(matches!(result, Err(SecurityError::CapabilityDenied(_))));
+        let err = result.unwrap_err();
+        let denial = err.denial().expect("structured denial");
+        assert_eq!(denial.capability, "storage");
+        assert_eq!(denial.action, "read");
+        assert_eq!(denial.pattern, "system/data");
+    }
+
+    #[test]
+    fn test_validate_filesystem_and_network_capabilities() {
+        let validator = CapabilityValidator::new();
+        let component_id = ComponentId::new("org", "service", "inst-1");
+
+        let capabilities = CapabilitySet::builder()
+            .filesystem(FilesystemPermission {
+                can_read_paths: vec!["/data/*".to_string()],
+                can_write_paths: vec![],
+                can_execute_paths: vec!["/usr/bin/*".to_string()],
+            })
+            .network(NetworkPermission {
+                can_connect_to: vec!["*.internal".to_string()],
+                can_bind_ports: vec![],
+            })
+            .build();
+        validator.register_component(component_id.clone(), capabilities);
+
+        let fs = |action, path: &str| {
+            Capability::Filesystem(FilesystemCapability {
+                action,
+                path_pattern: path.to_string(),
+            })
+        };
+        assert!(validator
+            .validate_capability(&component_id, &fs(FilesystemAction::Read, "/data/a"))
+            .is_ok());
+        assert!(validator
+            .validate_capability(
+                &component_id,
+                &fs(FilesystemAction::Execute, "/usr/bin/git")
+            )
+            .is_ok());
+        let err = validator
+            .validate_capability(&component_id, &fs(FilesystemAction::Write, "/data/a"))
+            .unwrap_err();
+        assert_eq!(err.denial().expect("structured denial").action, "write");
+
+        let connect = |host: &str| {
+            Capability::Network(NetworkCapability {
+                action: NetworkAction::Outbound,
+                host_pattern: host.to_string(),
+                port: Some(443),
+            })
+        };
+        assert!(validator
+            .validate_capability(&component_id, &connect("db.internal"))
+            .is_ok());
+        let err = validator
+            .validate_capability(&component_id, &connect("example.com"))
+            .unwrap_err();
+        assert_eq!(
+            err.denial().expect("structured denial").capability,
+            "network"
+        );
+    }
+
+    #[test]
+    fn test_validate_process_and_environment_capabilities() {
+        use crate::core::security::capability::{
+            EnvironmentAction, EnvironmentCapability, ProcessAction, ProcessCapability,
+        };
+        use crate::security::capability::set::{EnvironmentPermission, ProcessPermission};
+
+        let validator = CapabilityValidator::new();
+        let component_id = ComponentId::new("org", "service", "inst-1");
+        let capabilities = CapabilitySet::builder()
+            .environment(EnvironmentPermission {
+                can_read_vars: vec!["APP_MODE".to_string()],
+                can_write_vars: vec![],
+            })
+            .process(ProcessPermission {
+                can_spawn: vec!["/usr/bin/git".to_string()],
+                can_signal: vec![],
+            })
+            .build();
+        validator.register_component(component_id.clone(), capabilities);
+
+        let spawn = |program: &str| {
+            Capability::Process(ProcessCapability {
+                action: ProcessAction::Spawn,
+                program_pattern: program.to_string(),
+            })
+        };
+        assert!(validator
+            .validate_capability(&component_id, &spawn("/usr/bin/git"))
+            .is_ok());
+        let err = validator
+            .validate_capability(&component_id, &spawn("/bin/sh"))
+            .unwrap_err();
+        let denial = err.denial().expect("structured denial");
+        assert_eq!(
+            (denial.capability.as_str(), denial.action.as_str()),
+            ("process", "spawn")
+        );
+
+        let write_env = Capability::Environment(EnvironmentCapability {
+            action: EnvironmentAction::Write,
+            variable_pattern: "APP_MODE".to_string(),
+        });
+        assert!(validator
+            .validate_capability(&component_id, &write_env)
+            .is_err());
+    }
+
+    #[test]
+    fn test_usage_report_counts_allowed_checks_per_grant() {
+        let validator = CapabilityValidator::new();
+        let sender = ComponentId::new("org", "sender", "inst-1");
+        let target = ComponentId::new("org", "target", "inst-2");
+        let mut caps = CapabilitySet::new();
+        caps.add_messaging(MessagingPermission {
+            can_send_to: vec![target.to_string_id()],
+            can_receive_from: vec![],
+        });
+        caps.add_storage(StoragePermission {
+            can_read_keys: vec!["*".to_string()],
+            can_write_keys: vec![],
+        });
+        validator.register_component(sender.clone(), caps);
+
+        assert!(validator.can_send_to(&sender, &target).is_ok());
+        assert!(validator.can_send_to(&sender, &target).is_ok());
+        let other = ComponentId::new("org", "other", "inst-3");
+        assert!(validator.can_send_to(&sender, &other).is_err());
+
+        let report = validator.usage_report();
+        let counts: Vec<_> = report
+            .rows
+            .iter()
+            .map(|row| (row.grant.capability.as_str(), row.count))
+            .collect();
+        assert_eq!(counts, vec![("messaging", 2), ("storage", 0)]);
+
+        validator.unregister_component(&sender);
+        assert!(validator.usage_report().rows.is_empty());
     }
 
     #[test]
@@ -447,7 +1145,10 @@ mod tests {
 
         let result = validator.can_send_to(&sender_id, &target_id);
         assert!(result.is_err());
-        assert!(matches!(result, Err(SecurityError::PermissionDenied(_))));
+        let err = result.unwrap_err();
+        let denial = err.denial().expect("structured denial");
+        assert_eq!(denial.action, "send");
+        assert_eq!(denial.pattern, "org/forbidden/inst-2");
     }
 
     #[test]
@@ -494,4 +1195,166 @@ mod tests {
 
         // If we got here without panicking, thread safety is verified
     }
+
+    fn read_paths(paths: &[&str]) -> CapabilitySet {
+        CapabilitySet::builder()
+            .filesystem(FilesystemPermission {
+                can_read_paths: paths.iter().map(|p| p.to_string()).collect(),
+                can_write_paths: vec![],
+                can_execute_paths: vec![],
+            })
+            .build()
+    }
+
+    fn read(path: &str) -> Capability {
+        Capability::Filesystem(FilesystemCapability {
+            action: FilesystemAction::Read,
+            path_pattern: path.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_delegation_chain_attenuates_and_revokes_transitively() {
+        let validator = CapabilityValidator::new();
+        let a = ComponentId::new("org", "a", "1");
+        let b = ComponentId::new("org", "b", "1");
+        let c = ComponentId::new("org", "c", "1");
+        validator.register_component(a.clone(), read_paths(&["/data/*"]));
+        validator.register_component(b.clone(), CapabilitySet::new());
+        validator.register_component(c.clone(), CapabilitySet::new());
+
+        // Widening is refused
+        assert!(matches!(
+            validator.delegate(&a, &b, read_paths(&["/*"])),
+            Err(SecurityError::Denied(_))
+        ));
+
+        let ab = validator
+            .delegate(&a, &b, read_paths(&["/data/reports/*"]))
+            .unwrap();
+        assert!(ab.parent.is_none());
+        assert!(validator
+            .validate_capability(&b, &read("/data/reports/q1"))
+            .is_ok());
+        assert!(validator
+            .validate_capability(&b, &read("/data/other"))
+            .is_err());
+
+        // B re-delegates a narrower slice of its token; B cannot widen it
+        assert!(validator
+            .delegate(&b, &c, read_paths(&["/data/*"]))
+            .is_err());
+        let bc = validator
+            .delegate(&b, &c, read_paths(&["/data/reports/2024/*"]))
+            .unwrap();
+        assert_eq!(bc.parent.as_deref(), Some(ab.id.as_str()));
+
+        let chain = validator.provenance(&bc.id);
+        let issuers: Vec<_> = chain.iter().map(|t| t.issuer.clone()).collect();
+        assert_eq!(issuers, vec![b.clone(), a.clone()]);
+
+        let mut revoked = validator.revoke_delegation(&ab.id);
+        revoked.sort();
+        let mut expected = vec![ab.id.clone(), bc.id.clone()];
+        expected.sort();
+        assert_eq!(revoked, expected);
+        assert!(validator
+            .validate_capability(&c, &read("/data/reports/2024/x"))
+            .is_err());
+        assert!(validator.delegations_held_by(&b).is_empty());
+    }
+
+    #[test]
+    fn test_unregister_revokes_delegations() {
+        let validator = CapabilityValidator::new();
+        let a = ComponentId::new("org", "a", "1");
+        let b = ComponentId::new("org", "b", "1");
+        validator.register_component(a.clone(), read_paths(&["/data/*"]));
+        validator.register_component(b.clone(), CapabilitySet::new());
+        validator
+            .delegate(&a, &b, read_paths(&["/data/x"]))
+            .unwrap();
+
+        validator.unregister_component(&a);
+        assert!(validator.delegations_held_by(&b).is_empty());
+        assert!(validator.validate_capability(&b, &read("/data/x")).is_err());
+    }
+
+    #[test]
+    fn test_runtime_grants_expire_and_revoke() {
+        let validator = CapabilityValidator::new();
+        let id = ComponentId::new("org", "svc", "1");
+        validator.register_component(id.clone(), CapabilitySet::new());
+
+        let expired = validator.add_grant(CapabilityGrant::with_expiration(
+            id.clone(),
+            read_paths(&["/tmp/*"]),
+            1,
+        ));
+        assert!(validator.validate_capability(&id, &read("/tmp/x")).is_err());
+        assert!(validator.grants_for(&id).is_empty());
+
+        let active = validator.add_grant(CapabilityGrant::expiring_after(
+            id.clone(),
+            read_paths(&["/tmp/*"]),
+            std::time::Duration::from_secs(60),
+        ));
+        assert!(validator.validate_capability(&id, &read("/tmp/x")).is_ok());
+        assert_eq!(validator.grants_for(&id).len(), 1);
+
+        assert_eq!(validator.purge_expired_grants(), 1);
+        assert!(validator.revoke_grant(&expired).is_none());
+        assert!(validator.revoke_grant(&active).is_some());
+        assert!(validator.validate_capability(&id, &read("/tmp/x")).is_err());
+    }
+
+    #[test]
+    fn test_simulate_names_deciding_source() {
+        let validator = CapabilityValidator::new();
+        let a = ComponentId::new("org", "a", "1");
+        let b = ComponentId::new("org", "b", "1");
+        validator.register_component(a.clone(), read_paths(&["/data/*"]));
+        validator.register_component(b.clone(), read_paths(&["/own/*"]));
+
+        let token = validator
+            .delegate(&a, &b, read_paths(&["/data/reports/*"]))
+            .unwrap();
+        let grant_id =
+            validator.add_grant(CapabilityGrant::new(b.clone(), read_paths(&["/tmp/*"])));
+
+        let own = validator.simulate(&b, &read("/own/x"));
+        assert!(own.allowed);
+        assert!(
+            matches!(own.decided_by, DecisionSource::Grant { ref pattern } if pattern == "/own/*")
+        );
+
+        let delegated = validator.simulate(&b, &read("/data/reports/q1"));
+        assert!(delegated.allowed);
+        assert_eq!(
+            delegated.decided_by,
+            DecisionSource::Delegation {
+                token_id: token.id,
+                issuer: a.clone(),
+                pattern: "/data/reports/*".to_string(),
+            }
+        );
+
+        let runtime = validator.simulate(&b, &read("/tmp/x"));
+        assert!(
+            matches!(runtime.decided_by, DecisionSource::RuntimeGrant { grant_id: ref id, .. } if *id == grant_id)
+        );
+
+        let denied = validator.simulate(&b, &read("/data/secret"));
+        assert!(!denied.allowed);
+        assert_eq!(denied.decided_by, DecisionSource::NoMatchingGrant);
+        assert!(validator
+            .validate_capability(&b, &read("/data/secret"))
+            .is_err());
+
+        let stranger = ComponentId::new("org", "c", "1");
+        assert_eq!(
+            validator.simulate(&stranger, &read("/own/x")).decided_by,
+            DecisionSource::NotRegistered
+        );
+    }
 }
@@ -0,0 +1,608 @@
+//! Operator signatures for security-critical host configuration files.
+//!
+//! `Host.toml` and the exported installed-component registry decide what
+//! components may run and with which capabilities, so an attacker who can
+//! edit them on disk could grant themselves anything. The operator signs
+//! these files with an Ed25519 key; the signature is stored next to the
+//! file as `<file>.sig`. The host holds only the public key and checks
+//! every file at startup and on every reload:
+//!
+//! - a matching signature verifies the file,
+//! - a missing signature marks the file *unsigned*,
+//! - a signature that does not match marks the file *tampered* and raises a
+//!   tamper alert in the log.
+//!
+//! In [`SignatureMode::Strict`] unsigned and tampered files are refused; in
+//! [`SignatureMode::Warn`] they are logged and accepted, which eases
+//! migration of existing hosts.
+//!
+//! # Signed Statement
+//!
+//! A signature covers the file's name and a version chosen by the operator
+//! along with its contents (see [`config_statement`]), and the signature
+//! file holds the version followed by the hex-encoded signature. A signed
+//! `Host.toml` therefore cannot be passed off as another configuration
+//! file. Versions must increase with every signed change: the verifier
+//! refuses a file whose version is lower than the last one it accepted
+//! under that name, in either mode, so an older, once valid file cannot be
+//! rolled back into place. With [`ConfigVerifier::with_version_file`] the
+//! accepted versions survive host restarts.
+//!
+//! Verification returns the bytes it checked as a [`VerifiedConfig`];
+//! callers parse those instead of reading the file again, so an edit made
+//! after verification cannot slip in.
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use thiserror::Error;
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+// (none)
+
+/// Suffix appended to a file name to locate its signature.
+pub const SIGNATURE_SUFFIX: &str = ".sig";
+
+/// Domain separator of signed configuration statements.
+const STATEMENT_DOMAIN: &[u8] = b"airssys-config-signature-v2\0";
+
+/// Returns the signature path for `path` (`Host.toml` → `Host.toml.sig`).
+pub fn signature_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(SIGNATURE_SUFFIX);
+    PathBuf::from(name)
+}
+
+/// Returns the statement signed for version `version` of the configuration
+/// file named `name` (its file name, e.g. `Host.toml`) with `contents`.
+pub fn config_statement(name: &str, version: u64, contents: &[u8]) -> Vec<u8> {
+    let mut statement =
+        Vec::with_capacity(STATEMENT_DOMAIN.len() + name.len() + 9 + contents.len());
+    statement.extend_from_slice(STATEMENT_DOMAIN);
+    statement.extend_from_slice(name.as_bytes());
+    statement.push(0);
+    statement.extend_from_slice(&version.to_be_bytes());
+    statement.extend_from_slice(contents);
+    statement
+}
+
+/// The name a configuration file is signed under.
+fn config_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Errors raised while signing or verifying configuration files.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum ConfigSigningError {
+    /// A file or its signature could not be read or written.
+    #[error("Cannot access '{path}': {reason}")]
+    Io {
+        /// File that failed.
+        path: PathBuf,
+        /// Underlying I/O error.
+        reason: String,
+    },
+
+    /// The key bytes do not form a valid Ed25519 public key.
+    #[error("Invalid operator key: {0}")]
+    InvalidKey(String),
+
+    /// The file has no signature (strict mode only).
+    #[error("Configuration file '{0}' is not signed")]
+    Unsigned(PathBuf),
+
+    /// The signature does not match the file contents (strict mode only).
+    #[error("Configuration file '{0}' does not match its signature")]
+    Tampered(PathBuf),
+
+    /// The file is signed with an older version than one already accepted.
+    #[error(
+        "Configuration file '{path}' has version {version}, older than accepted version {accepted}"
+    )]
+    RolledBack {
+        /// File that was refused.
+        path: PathBuf,
+        /// Version the file is signed with.
+        version: u64,
+        /// Highest version accepted for the file.
+        accepted: u64,
+    },
+
+    /// The accepted-versions file cannot be parsed.
+    #[error("Version file '{0}' is malformed")]
+    MalformedVersions(PathBuf),
+
+    /// The accepted versions lock was poisoned.
+    #[error("Config verifier lock poisoned: {0}")]
+    LockPoisoned(String),
+
+    /// The verified contents are not valid UTF-8.
+    #[error("Configuration file '{0}' is not valid UTF-8")]
+    NotUtf8(PathBuf),
+}
+
+impl ConfigSigningError {
+    fn io(path: &Path, err: io::Error) -> Self {
+        Self::Io {
+            path: path.to_path_buf(),
+            reason: err.to_string(),
+        }
+    }
+}
+
+/// How the host treats files that fail verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SignatureMode {
+    /// Refuse unsigned and tampered files.
+    #[default]
+    Strict,
+    /// Log unsigned and tampered files but accept them.
+    Warn,
+}
+
+/// Verification result for one file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigStatus {
+    /// The signature matches the contents.
+    Verified,
+    /// No signature file exists.
+    Unsigned,
+    /// The signature is malformed or does not match the contents.
+    Tampered,
+}
+
+/// A configuration file as read and checked by [`ConfigVerifier::verify_file`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedConfig {
+    /// File that was verified.
+    pub path: PathBuf,
+    /// Verification result.
+    pub status: ConfigStatus,
+    /// Signed version, if the signature matched.
+    pub version: Option<u64>,
+    /// Exact bytes the signature was checked against.
+    pub contents: Vec<u8>,
+}
+
+impl VerifiedConfig {
+    /// The verified contents as text, for TOML or JSON parsing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigSigningError::NotUtf8` if the contents are not UTF-8.
+    pub fn text(&self) -> Result<&str, ConfigSigningError> {
+        std::str::from_utf8(&self.contents)
+            .map_err(|_| ConfigSigningError::NotUtf8(self.path.clone()))
+    }
+}
+
+/// Signs configuration files with the operator's private key.
+///
+/// Used by operator tooling; the host itself only needs a
+/// [`ConfigVerifier`].
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::security::config_signing::{ConfigSigner, ConfigVerifier, SignatureMode};
+///
+/// let signer = ConfigSigner::from_bytes(&[7; 32]);
+/// let signature = signer.sign(b"[host]\nprofile = \"prod\"\n");
+///
+/// let verifier = ConfigVerifier::new(&signer.public_key(), SignatureMode::Strict).unwrap();
+/// assert!(verifier.verify_bytes(b"[host]\nprofile = \"prod\"\n", &signature));
+/// assert!(!verifier.verify_bytes(b"[host]\nprofile = \"dev\"\n", &signature));
+/// ```
+pub struct ConfigSigner {
+    key: SigningKey,
+}
+
+impl ConfigSigner {
+    /// Creates a signer from a 32-byte Ed25519 secret key.
+    pub fn from_bytes(secret: &[u8; 32]) -> Self {
+        Self {
+            key: SigningKey::from_bytes(secret),
+        }
+    }
+
+    /// Public key to configure on hosts.
+    pub fn public_key(&self) -> [u8; 32] {
+        self.key.verifying_key().to_bytes()
+    }
+
+    /// Signs `contents` and returns the hex-encoded signature.
+    pub fn sign(&self, contents: &[u8]) -> String {
+        to_hex(&self.key.sign(contents).to_bytes())
+    }
+
+    /// Signs version `version` of the file at `path` and writes
+    /// `<path>.sig`.
+    ///
+    /// `version` must be higher than that of every earlier signed edit of
+    /// the file, or hosts refuse it. Returns the signature path.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigSigningError::Io` if the file cannot be read or the
+    /// signature cannot be written.
+    pub fn sign_file(&self, path: &Path, version: u64) -> Result<PathBuf, ConfigSigningError> {
+        let contents = fs::read(path).map_err(|e| ConfigSigningError::io(path, e))?;
+        let statement = config_statement(&config_name(path), version, &contents);
+        let sig_path = signature_path(path);
+        fs::write(&sig_path, format!("{version} {}\n", self.sign(&statement)))
+            .map_err(|e| ConfigSigningError::io(&sig_path, e))?;
+        Ok(sig_path)
+    }
+}
+
+impl std::fmt::Debug for ConfigSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConfigSigner")
+            .field("public_key", &to_hex(&self.public_key()))
+            .finish_non_exhaustive()
+    }
+}
+
+/// Verifies configuration file signatures against the operator's public key.
+///
+/// Clones share the versions accepted so far.
+#[derive(Debug, Clone)]
+pub struct ConfigVerifier {
+    key: VerifyingKey,
+    mode: SignatureMode,
+    /// Where accepted versions are persisted, if anywhere
+    version_file: Option<PathBuf>,
+    /// Highest version accepted per file name
+    accepted: Arc<Mutex<BTreeMap<String, u64>>>,
+}
+
+impl ConfigVerifier {
+    /// Creates a verifier for the operator's 32-byte public key.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigSigningError::InvalidKey` if the bytes are not a valid
+    /// Ed25519 public key.
+    pub fn new(public_key: &[u8; 32], mode: SignatureMode) -> Result<Self, ConfigSigningError> {
+        let key = VerifyingKey::from_bytes(public_key)
+            .map_err(|e| ConfigSigningError::InvalidKey(e.to_string()))?;
+        Ok(Self {
+            key,
+            mode,
+            version_file: None,
+            accepted: Arc::new(Mutex::new(BTreeMap::new())),
+        })
+    }
+
+    /// Persists accepted versions in `path`, loading those already there.
+    ///
+    /// Without a version file, rollbacks are only detected until the host
+    /// restarts.
+    ///
+    /// # Errors
+    ///
+    /// - `ConfigSigningError::Io` if the file exists but cannot be read
+    /// - `ConfigSigningError::MalformedVersions` if it cannot be parsed
+    pub fn with_version_file(
+        mut self,
+        path: impl Into<PathBuf>,
+    ) -> Result<Self, ConfigSigningError> {
+        let path = path.into();
+        let mut accepted = BTreeMap::new();
+        match fs::read_to_string(&path) {
+            Ok(text) => {
+                for line in text.lines().filter(|line| !line.trim().is_empty()) {
+                    let (version, name) = line
+                        .split_once(' ')
+                        .and_then(|(version, name)| Some((version.parse().ok()?, name)))
+                        .ok_or_else(|| ConfigSigningError::MalformedVersions(path.clone()))?;
+                    accepted.insert(name.to_string(), version);
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(ConfigSigningError::io(&path, e)),
+        }
+        self.accepted = Arc::new(Mutex::new(accepted));
+        self.version_file = Some(path);
+        Ok(self)
+    }
+
+    /// How failures are treated.
+    pub fn mode(&self) -> SignatureMode {
+        self.mode
+    }
+
+    /// Highest version accepted for the file named `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigSigningError::LockPoisoned` if the versions are poisoned.
+    pub fn accepted_version(&self, name: &str) -> Result<Option<u64>, ConfigSigningError> {
+        Ok(self.lock()?.get(name).copied())
+    }
+
+    /// Whether the hex-encoded `signature` matches `contents`.
+    pub fn verify_bytes(&self, contents: &[u8], signature: &str) -> bool {
+        let Some(bytes) = from_hex(signature.trim()) else {
+            return false;
+        };
+        let Ok(bytes) = <[u8; 64]>::try_from(bytes.as_slice()) else {
+            return false;
+        };
+        self.key
+            .verify(contents, &Signature::from_bytes(&bytes))
+            .is_ok()
+    }
+
+    /// Verifies the file at `path` against `<path>.sig`.
+    ///
+    /// The file is read once and the checked bytes are returned; parse
+    /// [`VerifiedConfig::contents`] rather than reading `path` again.
+    /// Tampered files raise an error-level tamper alert and unsigned files a
+    /// warning, whatever the mode. A verified file becomes the accepted
+    /// version for its name.
+    ///
+    /// # Errors
+    ///
+    /// - `ConfigSigningError::Io` if the file cannot be read or the accepted
+    ///   version cannot be persisted
+    /// - `ConfigSigningError::Unsigned` / `Tampered` in strict mode
+    /// - `ConfigSigningError::RolledBack` if a newer version was accepted
+    /// - `ConfigSigningError::LockPoisoned` if the versions are poisoned
+    pub fn verify_file(&self, path: &Path) -> Result<VerifiedConfig, ConfigSigningError> {
+        let contents = fs::read(path).map_err(|e| ConfigSigningError::io(path, e))?;
+        let name = config_name(path);
+        let sig_path = signature_path(path);
+        let version = match fs::read_to_string(&sig_path) {
+            Ok(signature) => Some(self.verify_signature(&name, &contents, &signature)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(ConfigSigningError::io(&sig_path, e)),
+        };
+        let status = match version {
+            Some(Some(_)) => ConfigStatus::Verified,
+            Some(None) => ConfigStatus::Tampered,
+            None => ConfigStatus::Unsigned,
+        };
+
+        match status {
+            ConfigStatus::Verified => {}
+            ConfigStatus::Unsigned => {
+                tracing::warn!(path = %path.display(), mode = ?self.mode, "configuration file is not signed");
+                if self.mode == SignatureMode::Strict {
+                    return Err(ConfigSigningError::Unsigned(path.to_path_buf()));
+                }
+            }
+            ConfigStatus::Tampered => {
+                tracing::error!(path = %path.display(), mode = ?self.mode, "TAMPER ALERT: configuration file does not match its signature");
+                if self.mode == SignatureMode::Strict {
+                    return Err(ConfigSigningError::Tampered(path.to_path_buf()));
+                }
+            }
+        }
+
+        let version = version.flatten();
+        if let Some(version) = version {
+            self.accept(path, &name, version)?;
+        }
+        Ok(VerifiedConfig {
+            path: path.to_path_buf(),
+            status,
+            version,
+            contents,
+        })
+    }
+
+    /// The version `signature` (`<version> <hex>`) signs for `name` with
+    /// `contents`, if it matches.
+    fn verify_signature(&self, name: &str, contents: &[u8], signature: &str) -> Option<u64> {
+        let (version, signature) = signature.trim().split_once(' ')?;
+        let version = version.parse().ok()?;
+        self.verify_bytes(&config_statement(name, version, contents), signature)
+            .then_some(version)
+    }
+
+    /// Records `version` as accepted for `name`, refusing rollbacks.
+    fn accept(&self, path: &Path, name: &str, version: u64) -> Result<(), ConfigSigningError> {
+        let mut accepted = self.lock()?;
+        match accepted.get(name) {
+            Some(&latest) if version < latest => {
+                tracing::error!(path = %path.display(), version, accepted = latest, "TAMPER ALERT: configuration file was rolled back");
+                return Err(ConfigSigningError::RolledBack {
+                    path: path.to_path_buf(),
+                    version,
+                    accepted: latest,
+                });
+            }
+            Some(&latest) if version == latest => return Ok(()),
+            _ => {}
+        }
+
+        let mut updated = accepted.clone();
+        updated.insert(name.to_string(), version);
+        if let Some(file) = &self.version_file {
+            let text: String = updated
+                .iter()
+                .map(|(name, version)| format!("{version} {name}\n"))
+                .collect();
+            fs::write(file, text).map_err(|e| ConfigSigningError::io(file, e))?;
+        }
+        *accepted = updated;
+        Ok(())
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, BTreeMap<String, u64>>, ConfigSigningError> {
+        self.accepted
+            .lock()
+            .map_err(|e| ConfigSigningError::LockPoisoned(e.to_string()))
+    }
+
+    /// Verifies every file in `paths`, stopping at the first refusal.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`verify_file`](Self::verify_file).
+    pub fn verify_files<P: AsRef<Path>>(
+        &self,
+        paths: &[P],
+    ) -> Result<Vec<VerifiedConfig>, ConfigSigningError> {
+        paths.iter().map(|p| self.verify_file(p.as_ref())).collect()
+    }
+}
+
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+pub(crate) fn from_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "airssys-config-signing-{name}-{}",
+            uuid::Uuid::new_v4()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn signer() -> ConfigSigner {
+        ConfigSigner::from_bytes(&[42; 32])
+    }
+
+    #[test]
+    fn test_signed_file_verifies_and_edit_is_detected() {
+        let dir = temp_dir("tamper");
+        let host = dir.join("Host.toml");
+        fs::write(&host, "[host]\nprofile = \"prod\"\n").unwrap();
+        let sig = signer().sign_file(&host, 1).unwrap();
+        assert_eq!(sig, dir.join("Host.toml.sig"));
+
+        let verifier = ConfigVerifier::new(&signer().public_key(), SignatureMode::Strict).unwrap();
+        let verified = verifier.verify_file(&host).unwrap();
+        assert_eq!(verified.status, ConfigStatus::Verified);
+        assert_eq!(verified.version, Some(1));
+        assert_eq!(verified.text().unwrap(), "[host]\nprofile = \"prod\"\n");
+
+        fs::write(&host, "[host]\nprofile = \"dev\"\n").unwrap();
+        assert_eq!(
+            verifier.verify_file(&host),
+            Err(ConfigSigningError::Tampered(host.clone()))
+        );
+
+        let lenient = ConfigVerifier::new(&signer().public_key(), SignatureMode::Warn).unwrap();
+        let accepted = lenient.verify_file(&host).unwrap();
+        assert_eq!(accepted.status, ConfigStatus::Tampered);
+        assert_eq!(accepted.contents, b"[host]\nprofile = \"dev\"\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_unsigned_and_foreign_signatures() {
+        let dir = temp_dir("unsigned");
+        let registry = dir.join("components.jsonl");
+        fs::write(&registry, "{}\n").unwrap();
+
+        let verifier = ConfigVerifier::new(&signer().public_key(), SignatureMode::Strict).unwrap();
+        assert_eq!(
+            verifier.verify_files(&[&registry]),
+            Err(ConfigSigningError::Unsigned(registry.clone()))
+        );
+
+        // Signed with someone else's key
+        ConfigSigner::from_bytes(&[1; 32])
+            .sign_file(&registry, 1)
+            .unwrap();
+        assert!(matches!(
+            verifier.verify_file(&registry),
+            Err(ConfigSigningError::Tampered(_))
+        ));
+
+        fs::write(signature_path(&registry), "not hex").unwrap();
+        assert!(!verifier.verify_bytes(b"{}\n", "not hex"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_signature_is_bound_to_file_name() {
+        let dir = temp_dir("renamed");
+        let host = dir.join("Host.toml");
+        let registry = dir.join("components.jsonl");
+        fs::write(&host, "{}\n").unwrap();
+        fs::write(&registry, "{}\n").unwrap();
+        signer().sign_file(&host, 1).unwrap();
+        fs::copy(signature_path(&host), signature_path(&registry)).unwrap();
+
+        let verifier = ConfigVerifier::new(&signer().public_key(), SignatureMode::Strict).unwrap();
+        assert!(verifier.verify_file(&host).is_ok());
+        assert_eq!(
+            verifier.verify_file(&registry),
+            Err(ConfigSigningError::Tampered(registry.clone()))
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_rolled_back_file_is_refused_across_restarts() {
+        let dir = temp_dir("rollback");
+        let host = dir.join("Host.toml");
+        let versions = dir.join("accepted-versions");
+        fs::write(&host, "[host]\nprofile = \"old\"\n").unwrap();
+        signer().sign_file(&host, 1).unwrap();
+        let old_signature = fs::read(signature_path(&host)).unwrap();
+
+        let verifier = ConfigVerifier::new(&signer().public_key(), SignatureMode::Warn)
+            .unwrap()
+            .with_version_file(&versions)
+            .unwrap();
+        verifier.verify_file(&host).unwrap();
+        fs::write(&host, "[host]\nprofile = \"new\"\n").unwrap();
+        signer().sign_file(&host, 2).unwrap();
+        assert_eq!(verifier.verify_file(&host).unwrap().version, Some(2));
+        assert_eq!(verifier.accepted_version("Host.toml").unwrap(), Some(2));
+
+        // The old file and its genuine signature, restored after a restart
+        fs::write(&host, "[host]\nprofile = \"old\"\n").unwrap();
+        fs::write(signature_path(&host), old_signature).unwrap();
+        let restarted = ConfigVerifier::new(&signer().public_key(), SignatureMode::Warn)
+            .unwrap()
+            .with_version_file(&versions)
+            .unwrap();
+        assert_eq!(
+            restarted.verify_file(&host),
+            Err(ConfigSigningError::RolledBack {
+                path: host.clone(),
+                version: 1,
+                accepted: 2,
+            })
+        );
+
+        fs::write(&versions, "two Host.toml\n").unwrap();
+        assert_eq!(
+            ConfigVerifier::new(&signer().public_key(), SignatureMode::Warn)
+                .unwrap()
+                .with_version_file(&versions)
+                .unwrap_err(),
+            ConfigSigningError::MalformedVersions(versions.clone())
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,850 @@
+//! Egress proxy for component outbound network traffic.
+//!
+//! All outbound connections requested by components (HTTP host functions
+//! and raw sockets) are opened by the host through [`EgressProxy`], never by
+//! the guest directly. The proxy:
+//!
+//! - rejects IP-literal destinations so grants cannot be bypassed by
+//!   connecting to an address instead of a name,
+//! - checks the host name against the component's `can_connect_to` domain
+//!   patterns,
+//! - resolves the name itself via a [`HostResolver`] and pins the first
+//!   answer, so later lookups that rebind the name to new addresses are
+//!   limited to the pinned set,
+//! - refuses private, loopback and link-local addresses unless explicitly
+//!   allowed,
+//! - records bytes sent and received per component and destination,
+//! - enforces the quota attached to the component's network grants
+//!   (requests per minute, concurrent connections, bytes received),
+//! - refuses all outbound connections while an attached
+//!   [`HostLockdown`] is engaged.
+//!
+//! The proxy is a library component: the runtime does not route any host
+//! function through it yet. The `host-os` `network-connect` call goes
+//! through the OS bridge, which checks network grants and quotas but does
+//! not pin resolutions. Hosts that expose their own network host functions
+//! should open connections with [`EgressProxy::connect`].
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::net::{IpAddr, SocketAddr, TcpStream, ToSocketAddrs};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+use thiserror::Error;
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use super::capability::set::CapabilitySet;
+use super::lockdown::check_lockdown;
+use super::quota::{ConnectionPermit, QuotaLedger};
+use crate::core::component::id::ComponentId;
+use crate::core::management::lockdown::HostLockdown;
+use crate::core::security::errors::{PermissionDenial, SecurityError};
+use crate::core::security::quota::{QuotaClass, ResourceQuota};
+use crate::core::security::traits::HostResolver;
+
+/// Errors returned by the [`EgressProxy`].
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum EgressError {
+    /// The connection was refused.
+    #[error(transparent)]
+    Security(#[from] SecurityError),
+
+    /// A pin or usage lock was poisoned by a panic in another thread.
+    #[error("Egress proxy lock poisoned: {0}")]
+    LockPoisoned(String),
+}
+
+impl EgressError {
+    /// The structured denial, if the connection was refused by a grant,
+    /// quota or lockdown.
+    pub fn denial(&self) -> Option<&PermissionDenial> {
+        match self {
+            Self::Security(err) => err.denial(),
+            Self::LockPoisoned(_) => None,
+        }
+    }
+}
+
+/// Resolver backed by the operating system's name service.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemResolver;
+
+impl HostResolver for SystemResolver {
+    fn resolve(&self, host: &str) -> Result<Vec<IpAddr>, SecurityError> {
+        let addrs = (host, 0).to_socket_addrs().map_err(|e| {
+            SecurityError::PermissionDenied(format!("DNS resolution failed for {}: {}", host, e))
+        })?;
+        Ok(addrs.map(|addr| addr.ip()).collect())
+    }
+}
+
+/// Destination host and port of an egress connection.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EgressDestination {
+    /// Host name as requested by the component.
+    pub host: String,
+    /// Destination port.
+    pub port: u16,
+}
+
+/// Bytes transferred to and from a destination.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EgressByteCounts {
+    /// Bytes written by the component.
+    pub sent: u64,
+    /// Bytes read by the component.
+    pub received: u64,
+}
+
+/// An authorized egress destination with its pinned socket addresses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EgressTarget {
+    /// Requested destination.
+    pub destination: EgressDestination,
+    /// Addresses the host may connect to, in resolver order.
+    pub addrs: Vec<SocketAddr>,
+}
+
+/// Host-side egress proxy enforcing domain grants on outbound traffic.
+///
+/// Generic over the resolver for static dispatch (§6.2); production code
+/// uses [`SystemResolver`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::security::capability::set::{CapabilitySet, NetworkPermission};
+/// use airssys_wasm::security::egress::{EgressProxy, SystemResolver};
+///
+/// let proxy = EgressProxy::new(SystemResolver);
+/// let component = ComponentId::new("org", "fetcher", "1");
+/// let caps = CapabilitySet::builder()
+///     .network(NetworkPermission {
+///         can_connect_to: vec!["*.example.com".to_string()],
+///         can_bind_ports: vec![],
+///     })
+///     .build();
+///
+/// let target = proxy.authorize(&component, &caps, "api.example.com", 443).unwrap();
+/// assert!(!target.addrs.is_empty());
+/// ```
+pub struct EgressProxy<R: HostResolver> {
+    resolver: R,
+    allow_private: bool,
+    pins: RwLock<HashMap<String, Vec<IpAddr>>>,
+    usage: RwLock<HashMap<ComponentId, HashMap<EgressDestination, EgressByteCounts>>>,
+    lockdown: Option<Arc<HostLockdown>>,
+    quotas: Arc<QuotaLedger>,
+}
+
+impl<R: HostResolver> EgressProxy<R> {
+    /// Create a proxy that resolves names through `resolver`.
+    pub fn new(resolver: R) -> Self {
+        Self {
+            resolver,
+            allow_private: false,
+            pins: RwLock::new(HashMap::new()),
+            usage: RwLock::new(HashMap::new()),
+            lockdown: None,
+            quotas: Arc::new(QuotaLedger::new()),
+        }
+    }
+
+    /// Allow names that resolve to private, loopback or link-local addresses.
+    ///
+    /// Disabled by default so a granted name cannot be pointed at internal
+    /// services.
+    pub fn with_private_addresses(mut self, allow: bool) -> Self {
+        self.allow_private = allow;
+        self
+    }
+
+    /// Refuse every connection while `lockdown` is engaged.
+    pub fn with_lockdown(mut self, lockdown: Arc<HostLockdown>) -> Self {
+        self.lockdown = Some(lockdown);
+        self
+    }
+
+    /// Count network quota usage in `ledger`, e.g. one shared with the OS
+    /// bridge.
+    pub fn with_quota_ledger(mut self, ledger: Arc<QuotaLedger>) -> Self {
+        self.quotas = ledger;
+        self
+    }
+
+    /// The ledger network quota usage is counted in.
+    pub fn quota_ledger(&self) -> &Arc<QuotaLedger> {
+        &self.quotas
+    }
+
+    /// Authorize an outbound connection and return the addresses to dial.
+    ///
+    /// # Errors
+    ///
+    /// - `SecurityError::PermissionDenied` - `host` is an IP literal or fails to resolve
+    /// - `SecurityError::Denied` - `host` matches no `can_connect_to` pattern,
+    ///   or the host is in lockdown
+    /// - `SecurityError::PolicyViolation` - No resolved address is permitted or pinned
+    /// - `EgressError::LockPoisoned` - The pin table is poisoned
+    ///
+    /// Security errors are returned as `EgressError::Security`.
+    pub fn authorize(
+        &self,
+        component: &ComponentId,
+        capabilities: &CapabilitySet,
+        host: &str,
+        port: u16,
+    ) -> Result<EgressTarget, EgressError> {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+
+        if is_ip_literal(&host) {
+            return Err(SecurityError::PermissionDenied(format!(
+                "Component {} may not connect to IP literal {}",
+                component, host
+            ))
+            .into());
+        }
+
+        if let Some(lockdown) = &self.lockdown {
+            check_lockdown(lockdown, component, "network", "outbound", &host)?;
+        }
+
+        if !capabilities.can_connect_to(&host) {
+            return Err(SecurityError::Denied(PermissionDenial::new(
+                "network",
+                "connect",
+                &host,
+                format!("no network grant for {} matches", component),
+            ))
+            .into());
+        }
+
+        let resolved: Vec<IpAddr> = self
+            .resolver
+            .resolve(&host)?
+            .into_iter()
+            .filter(|ip| self.allow_private || is_public(ip))
+            .collect();
+
+        let addrs = {
+            let mut pins = self
+                .pins
+                .write()
+                .map_err(|e| EgressError::LockPoisoned(e.to_string()))?;
+            match pins.get(&host) {
+                // Re-resolution may only narrow to previously pinned addresses
+                Some(pinned) => resolved
+                    .into_iter()
+                    .filter(|ip| pinned.contains(ip))
+                    .collect::<Vec<_>>(),
+                None => {
+                    if !resolved.is_empty() {
+                        pins.insert(host.clone(), resolved.clone());
+                    }
+                    resolved
+                }
+            }
+        };
+
+        if addrs.is_empty() {
+            return Err(SecurityError::PolicyViolation(format!(
+                "No permitted address for {} (component {})",
+                host, component
+            ))
+            .into());
+        }
+
+        Ok(EgressTarget {
+            destination: EgressDestination { host, port },
+            addrs: addrs
+                .into_iter()
+                .map(|ip| SocketAddr::new(ip, port))
+                .collect(),
+        })
+    }
+
+    /// Authorize and open a TCP connection through the proxy.
+    ///
+    /// Bytes read and written on the returned stream are counted against
+    /// the component and destination when the stream is dropped. With a
+    /// network quota, the stream holds a connection slot until dropped and
+    /// reads fail with `PermissionDenied` once the byte limit is reached.
+    ///
+    /// # Errors
+    ///
+    /// - Any error from [`authorize`](Self::authorize)
+    /// - `SecurityError::Denied` - The component's network quota is exhausted
+    /// - `SecurityError::PermissionDenied` - No pinned address accepted the connection
+    pub fn connect(
+        &self,
+        component: &ComponentId,
+        capabilities: &CapabilitySet,
+        host: &str,
+        port: u16,
+        timeout: Duration,
+    ) -> Result<EgressStream<'_, R>, EgressError> {
+        let target = self.authorize(component, capabilities, host, port)?;
+        let quota = capabilities.quota(QuotaClass::Network);
+        let permit = match &quota {
+            Some(quota) => {
+                let resource = &target.destination.host;
+                self.quotas
+                    .admit_request(component, QuotaClass::Network, quota, resource)?;
+                Some(self.quotas.acquire_connection(
+                    component,
+                    QuotaClass::Network,
+                    quota,
+                    resource,
+                )?)
+            }
+            None => None,
+        };
+
+        let mut last_error = None;
+        for addr in &target.addrs {
+            match TcpStream::connect_timeout(addr, timeout) {
+                Ok(stream) => {
+                    return Ok(EgressStream {
+                        proxy: self,
+                        component: component.clone(),
+                        destination: target.destination,
+                        stream,
+                        counts: EgressByteCounts::default(),
+                        quota,
+                        _permit: permit,
+                    })
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        Err(SecurityError::PermissionDenied(format!(
+            "Connection to {}:{} failed: {}",
+            target.destination.host,
+            target.destination.port,
+            last_error.map(|e| e.to_string()).unwrap_or_default()
+        ))
+        .into())
+    }
+
+    /// Add transferred bytes to a component's per-destination totals.
+    ///
+    /// Host functions that perform their own I/O (e.g. HTTP clients) call
+    /// this after each exchange with an authorized destination.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EgressError::LockPoisoned` if the usage table is poisoned.
+    pub fn record(
+        &self,
+        component: &ComponentId,
+        destination: &EgressDestination,
+        sent: u64,
+        received: u64,
+    ) -> Result<(), EgressError> {
+        let mut usage = self
+            .usage
+            .write()
+            .map_err(|e| EgressError::LockPoisoned(e.to_string()))?;
+        let counts = usage
+            .entry(component.clone())
+            .or_default()
+            .entry(destination.clone())
+            .or_default();
+        counts.sent += sent;
+        counts.received += received;
+        Ok(())
+    }
+
+    /// Per-destination byte counts for a component.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EgressError::LockPoisoned` if the usage table is poisoned.
+    pub fn usage(
+        &self,
+        component: &ComponentId,
+    ) -> Result<HashMap<EgressDestination, EgressByteCounts>, EgressError> {
+        Ok(self
+            .usage
+            .read()
+            .map_err(|e| EgressError::LockPoisoned(e.to_string()))?
+            .get(component)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    /// Addresses pinned for `host`, if it has been resolved.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EgressError::LockPoisoned` if the pin table is poisoned.
+    pub fn pinned(&self, host: &str) -> Result<Option<Vec<IpAddr>>, EgressError> {
+        Ok(self
+            .pins
+            .read()
+            .map_err(|e| EgressError::LockPoisoned(e.to_string()))?
+            .get(&host.trim_end_matches('.').to_ascii_lowercase())
+            .cloned())
+    }
+
+    /// Forget all pinned resolutions, e.g. after a grant change.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EgressError::LockPoisoned` if the pin table is poisoned.
+    pub fn clear_pins(&self) -> Result<(), EgressError> {
+        self.pins
+            .write()
+            .map_err(|e| EgressError::LockPoisoned(e.to_string()))?
+            .clear();
+        Ok(())
+    }
+}
+
+/// TCP stream opened through an [`EgressProxy`].
+///
+/// Counts bytes in both directions and reports them to the proxy on drop.
+pub struct EgressStream<'a, R: HostResolver> {
+    proxy: &'a EgressProxy<R>,
+    component: ComponentId,
+    destination: EgressDestination,
+    stream: TcpStream,
+    counts: EgressByteCounts,
+    quota: Option<ResourceQuota>,
+    _permit: Option<ConnectionPermit>,
+}
+
+impl<R: HostResolver> EgressStream<'_, R> {
+    /// Destination this stream is connected to.
+    pub fn destination(&self) -> &EgressDestination {
+        &self.destination
+    }
+
+    /// Address of the connected peer.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying I/O error if the socket is no longer connected.
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        self.stream.peer_addr()
+    }
+
+    /// Bytes transferred so far on this stream.
+    pub fn counts(&self) -> EgressByteCounts {
+        self.counts
+    }
+}
+
+impl<R: HostResolver> Read for EgressStream<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.stream.read(buf)?;
+        if let Some(quota) = &self.quota {
+            self.proxy
+                .quotas
+                .charge_bytes_read(
+                    &self.component,
+                    QuotaClass::Network,
+                    quota,
+                    n as u64,
+                    &self.destination.host,
+                )
+                .map_err(|e| io::Error::new(io::ErrorKind::PermissionDenied, e))?;
+        }
+        self.counts.received += n as u64;
+        Ok(n)
+    }
+}
+
+impl<R: HostResolver> Write for EgressStream<'_, R> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.stream.write(buf)?;
+        self.counts.sent += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+impl<R: HostResolver> Drop for EgressStream<'_, R> {
+    fn drop(&mut self) {
+        let recorded = self.proxy.record(
+            &self.component,
+            &self.destination,
+            self.counts.sent,
+            self.counts.received,
+        );
+        if let Err(e) = recorded {
+            tracing::warn!(component = %self.component, error = %e, "egress usage not recorded");
+        }
+    }
+}
+
+/// Returns `true` for hosts that name an address rather than a domain.
+///
+/// Besides standard IPv4/IPv6 notation this rejects bracketed IPv6 and the
+/// numeric shorthands some resolvers accept (`2130706433`, `0x7f.1`).
+fn is_ip_literal(host: &str) -> bool {
+    let unbracketed = host.trim_start_matches('[').trim_end_matches(']');
+    if unbracketed.parse::<IpAddr>().is_ok() {
+        return true;
+    }
+    host.split('.').all(|label| {
+        !label.is_empty()
+            && (label.chars().all(|c| c.is_ascii_digit())
+                || (label.starts_with("0x") && label[2..].chars().all(|c| c.is_ascii_hexdigit())))
+    })
+}
+
+/// Returns `true` if the address is routable on the public internet.
+fn is_public(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => {
+            !(v4.is_loopback()
+                || v4.is_private()
+                || v4.is_link_local()
+                || v4.is_unspecified()
+                || v4.is_broadcast()
+                || v4.is_documentation())
+        }
+        IpAddr::V6(v6) => {
+            if let Some(mapped) = v6.to_ipv4_mapped() {
+                return is_public(&IpAddr::V4(mapped));
+            }
+            let first = v6.segments()[0];
+            !(v6.is_loopback()
+                || v6.is_unspecified()
+                || (first & 0xfe00) == 0xfc00 // unique local fc00::/7
+                || (first & 0xffc0) == 0xfe80) // link local fe80::/10
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::capability::set::NetworkPermission;
+    use std::net::{Ipv4Addr, TcpListener};
+    use std::sync::Mutex;
+
+    /// Resolver returning a configurable answer per call.
+    struct ScriptedResolver {
+        answer: Mutex<Vec<IpAddr>>,
+    }
+
+    impl ScriptedResolver {
+        fn new(answer: Vec<IpAddr>) -> Self {
+            Self {
+                answer: Mutex::new(answer),
+            }
+        }
+
+        fn set(&self, answer: Vec<IpAddr>) {
+            *self.answer.lock().unwrap() = answer;
+        }
+    }
+
+    impl HostResolver for ScriptedResolver {
+        fn resolve(&self, _host: &str) -> Result<Vec<IpAddr>, SecurityError> {
+            Ok(self.answer.lock().unwrap().clone())
+        }
+    }
+
+    fn v4(a: u8, b: u8, c: u8, d: u8) -> IpAddr {
+        IpAddr::V4(Ipv4Addr::new(a, b, c, d))
+    }
+
+    fn component() -> ComponentId {
+        ComponentId::new("org", "fetcher", "1")
+    }
+
+    fn caps(patterns: &[&str]) -> CapabilitySet {
+        CapabilitySet::builder()
+            .network(NetworkPermission {
+                can_connect_to: patterns.iter().map(|p| p.to_string()).collect(),
+                can_bind_ports: vec![],
+            })
+            .build()
+    }
+
+    #[test]
+    fn test_poisoned_pins_are_reported() {
+        let proxy = EgressProxy::new(ScriptedResolver::new(vec![v4(93, 184, 216, 34)]));
+        let _ = std::thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    let _pins = proxy.pins.write().unwrap();
+                    panic!("poison the pin table");
+                })
+                .join()
+        });
+
+        let err = proxy
+            .authorize(
+                &component(),
+                &caps(&["*.example.com"]),
+                "api.example.com",
+                443,
+            )
+            .unwrap_err();
+        assert!(matches!(err, EgressError::LockPoisoned(_)));
+        assert!(err.denial().is_none());
+        assert!(proxy.clear_pins().is_err());
+    }
+
+    #[test]
+    fn test_authorize_granted_domain() {
+        let proxy = EgressProxy::new(ScriptedResolver::new(vec![v4(93, 184, 216, 34)]));
+        let target = proxy
+            .authorize(
+                &component(),
+                &caps(&["*.example.com"]),
+                "api.example.com",
+                443,
+            )
+            .unwrap();
+
+        assert_eq!(target.destination.host, "api.example.com");
+        assert_eq!(
+            target.addrs,
+            vec![SocketAddr::new(v4(93, 184, 216, 34), 443)]
+        );
+        assert_eq!(
+            proxy.pinned("API.example.com."),
+            Ok(Some(vec![v4(93, 184, 216, 34)]))
+        );
+    }
+
+    #[test]
+    fn test_authorize_denies_ungranted_domain() {
+        let proxy = EgressProxy::new(ScriptedResolver::new(vec![v4(1, 1, 1, 1)]));
+        let err = proxy
+            .authorize(&component(), &caps(&["*.example.com"]), "evil.test", 443)
+            .unwrap_err();
+        let denial = err.denial().unwrap();
+        assert_eq!(denial.capability, "network");
+        assert_eq!(denial.pattern, "evil.test");
+    }
+
+    #[test]
+    fn test_authorize_refused_during_lockdown() {
+        let lockdown = Arc::new(HostLockdown::new());
+        let proxy = EgressProxy::new(ScriptedResolver::new(vec![v4(93, 184, 216, 34)]))
+            .with_lockdown(Arc::clone(&lockdown));
+        let granted = caps(&["*.example.com"]);
+
+        lockdown.engage("oncall", "exfiltration alert").unwrap();
+        let err = proxy
+            .authorize(&component(), &granted, "api.example.com", 443)
+            .unwrap_err();
+        assert_eq!(err.denial().unwrap().action, "outbound");
+        assert_eq!(lockdown.current().unwrap().unwrap().denials.len(), 1);
+
+        lockdown.lift("oncall").unwrap();
+        assert!(proxy
+            .authorize(&component(), &granted, "api.example.com", 443)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_authorize_blocks_ip_literals() {
+        let proxy = EgressProxy::new(ScriptedResolver::new(vec![]));
+        let all = caps(&["*"]);
+        for host in ["10.0.0.1", "[::1]", "::1", "2130706433", "0x7f.0x0.0x0.0x1"] {
+            let err = proxy.authorize(&component(), &all, host, 80).unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    EgressError::Security(SecurityError::PermissionDenied(_))
+                ),
+                "{} should be rejected",
+                host
+            );
+        }
+    }
+
+    #[test]
+    fn test_authorize_rejects_private_resolution() {
+        let proxy = EgressProxy::new(ScriptedResolver::new(vec![v4(127, 0, 0, 1)]));
+        let err = proxy
+            .authorize(&component(), &caps(&["*"]), "internal.example.com", 80)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            EgressError::Security(SecurityError::PolicyViolation(_))
+        ));
+
+        let proxy = proxy.with_private_addresses(true);
+        assert!(proxy
+            .authorize(&component(), &caps(&["*"]), "internal.example.com", 80)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_rebinding_limited_to_pinned_addresses() {
+        let resolver = ScriptedResolver::new(vec![v4(93, 184, 216, 34)]);
+        let proxy = EgressProxy::new(resolver);
+        let grants = caps(&["*.example.com"]);
+        proxy
+            .authorize(&component(), &grants, "api.example.com", 443)
+            .unwrap();
+
+        // Name now points elsewhere: connection refused
+        proxy.resolver.set(vec![v4(8, 8, 8, 8)]);
+        let err = proxy
+            .authorize(&component(), &grants, "api.example.com", 443)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            EgressError::Security(SecurityError::PolicyViolation(_))
+        ));
+
+        // Mixed answer: only the pinned address survives
+        proxy
+            .resolver
+            .set(vec![v4(8, 8, 8, 8), v4(93, 184, 216, 34)]);
+        let target = proxy
+            .authorize(&component(), &grants, "api.example.com", 443)
+            .unwrap();
+        assert_eq!(
+            target.addrs,
+            vec![SocketAddr::new(v4(93, 184, 216, 34), 443)]
+        );
+
+        // Clearing pins accepts the new answer
+        proxy.clear_pins().unwrap();
+        proxy.resolver.set(vec![v4(8, 8, 8, 8)]);
+        assert!(proxy
+            .authorize(&component(), &grants, "api.example.com", 443)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_record_accumulates_per_destination() {
+        let proxy = EgressProxy::new(ScriptedResolver::new(vec![]));
+        let dest = EgressDestination {
+            host: "api.example.com".to_string(),
+            port: 443,
+        };
+        proxy.record(&component(), &dest, 10, 100).unwrap();
+        proxy.record(&component(), &dest, 5, 50).unwrap();
+
+        let usage = proxy.usage(&component()).unwrap();
+        assert_eq!(
+            usage.get(&dest),
+            Some(&EgressByteCounts {
+                sent: 15,
+                received: 150
+            })
+        );
+        assert!(proxy
+            .usage(&ComponentId::new("org", "other", "1"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_connect_counts_bytes() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 4];
+            socket.read_exact(&mut buf).unwrap();
+            socket.write_all(b"pong!").unwrap();
+        });
+
+        let proxy = EgressProxy::new(ScriptedResolver::new(vec![v4(127, 0, 0, 1)]))
+            .with_private_addresses(true);
+        {
+            let mut stream = proxy
+                .connect(
+                    &component(),
+                    &caps(&["local.test"]),
+                    "local.test",
+                    port,
+                    Duration::from_secs(1),
+                )
+                .unwrap();
+            stream.write_all(b"ping").unwrap();
+            let mut buf = [0u8; 5];
+            stream.read_exact(&mut buf).unwrap();
+            assert_eq!(&buf, b"pong!");
+        }
+        server.join().unwrap();
+
+        let dest = EgressDestination {
+            host: "local.test".to_string(),
+            port,
+        };
+        assert_eq!(
+            proxy.usage(&component()).unwrap().get(&dest),
+            Some(&EgressByteCounts {
+                sent: 4,
+                received: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_connect_enforces_network_quota() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            socket.write_all(b"0123456789").unwrap();
+        });
+
+        let proxy = EgressProxy::new(ScriptedResolver::new(vec![v4(127, 0, 0, 1)]))
+            .with_private_addresses(true);
+        let mut grants = caps(&["local.test"]);
+        grants.set_quota(
+            QuotaClass::Network,
+            ResourceQuota::new()
+                .with_max_requests_per_minute(10)
+                .with_max_concurrent_connections(1)
+                .with_max_bytes_read(4),
+        );
+        let connect = || {
+            proxy.connect(
+                &component(),
+                &grants,
+                "local.test",
+                port,
+                Duration::from_secs(1),
+            )
+        };
+
+        let mut stream = connect().unwrap();
+        let err = connect().err().unwrap();
+        assert_eq!(err.denial().unwrap().action, "connect");
+
+        let mut buf = [0u8; 10];
+        let err = stream.read_exact(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        drop(stream);
+        server.join().unwrap();
+
+        let usage = proxy
+            .quota_ledger()
+            .usage(&component(), QuotaClass::Network);
+        assert_eq!(usage.open_connections, 0);
+        assert_eq!(usage.requests_last_minute, 2);
+    }
+
+    #[test]
+    fn test_is_public() {
+        assert!(is_public(&v4(93, 184, 216, 34)));
+        assert!(!is_public(&v4(192, 168, 1, 1)));
+        assert!(!is_public(&v4(169, 254, 0, 1)));
+        assert!(!is_public(&"fd00::1".parse().unwrap()));
+        assert!(!is_public(&"::ffff:127.0.0.1".parse().unwrap()));
+        assert!(is_public(&"2606:2800:220:1::".parse().unwrap()));
+    }
+}
@@ -0,0 +1,332 @@
+//! Enforcement of the host-wide read-only lockdown.
+//!
+//! [`LockdownValidator`] wraps the host's `SecurityValidator`. While the
+//! shared [`HostLockdown`] switch is engaged it refuses every write-class
+//! capability before the wrapped validator is consulted, records the refusal
+//! in the open lockdown window and, if configured, in the security audit log.
+//! All other checks pass straight through.
+//!
+//! Write-class capabilities are:
+//!
+//! - filesystem write, delete and execute (a spawned process can write
+//!   anywhere the host can)
+//! - storage write and delete
+//! - outbound network connections
+//! - environment variable writes
+//! - subprocess spawning and signalling
+//!
+//! Messaging, reads, directory listings, inbound network, clocks and
+//! randomness stay available.
+
+// Layer 1: Standard library imports
+use std::sync::Arc;
+
+// Layer 2: Third-party crate imports
+// (none)
+
+// Layer 3: Internal module imports
+use super::audit::create_security_event;
+use crate::core::component::id::ComponentId;
+use crate::core::management::lockdown::HostLockdown;
+use crate::core::security::capability::{
+    Capability, EnvironmentAction, FilesystemAction, NetworkAction, ProcessAction, StorageAction,
+};
+use crate::core::security::errors::{PermissionDenial, SecurityError};
+use crate::core::security::quota::{QuotaClass, ResourceQuota};
+use crate::core::security::traits::{SecurityAuditLogger, SecurityValidator};
+
+/// Returns `(class, action, pattern)` if `capability` is write-class.
+pub fn write_class(capability: &Capability) -> Option<(&'static str, &'static str, &str)> {
+    match capability {
+        Capability::Filesystem(cap) => {
+            let action = match cap.action {
+                FilesystemAction::Write => "write",
+                FilesystemAction::Delete => "delete",
+                FilesystemAction::Execute => "execute",
+                FilesystemAction::Read | FilesystemAction::ListDir => return None,
+            };
+            Some(("filesystem", action, &cap.path_pattern))
+        }
+        Capability::Storage(cap) => {
+            let action = match cap.action {
+                StorageAction::Write => "write",
+                StorageAction::Delete => "delete",
+                StorageAction::Read => return None,
+            };
+            Some(("storage", action, &cap.namespace_pattern))
+        }
+        Capability::Network(cap) => match cap.action {
+            NetworkAction::Outbound => Some(("network", "outbound", &cap.host_pattern)),
+            NetworkAction::Inbound => None,
+        },
+        Capability::Environment(cap) => match cap.action {
+            EnvironmentAction::Write => Some(("environment", "write", &cap.variable_pattern)),
+            EnvironmentAction::Read => None,
+        },
+        Capability::Process(cap) => {
+            let action = match cap.action {
+                ProcessAction::Spawn => "spawn",
+                ProcessAction::Signal => "signal",
+            };
+            Some(("process", action, &cap.program_pattern))
+        }
+        Capability::Messaging(_) | Capability::Clock(_) | Capability::Random(_) => None,
+    }
+}
+
+/// Refuses `class`/`action` on `pattern` for `component` if `lockdown` is
+/// engaged, recording the refusal in the open window.
+///
+/// Shared by [`LockdownValidator`] and enforcement points that do not go
+/// through a `SecurityValidator`, such as the egress proxy.
+///
+/// # Errors
+///
+/// Returns `SecurityError::Denied` while the lockdown is engaged.
+pub fn check_lockdown(
+    lockdown: &HostLockdown,
+    component: &ComponentId,
+    class: &str,
+    action: &str,
+    pattern: &str,
+) -> Result<(), SecurityError> {
+    if !lockdown.is_engaged() {
+        return Ok(());
+    }
+    let capability = format!("{class}:{action}:{pattern}");
+    lockdown
+        .record_denial(component, &capability)
+        .map_err(|e| SecurityError::PolicyViolation(e.to_string()))?;
+    Err(SecurityError::Denied(PermissionDenial::new(
+        class,
+        action,
+        pattern,
+        "host is in read-only lockdown",
+    )))
+}
+
+/// `SecurityValidator` that denies write-class capabilities while the host
+/// is locked down.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::management::lockdown::HostLockdown;
+/// use airssys_wasm::core::security::capability::{
+///     Capability, StorageAction, StorageCapability,
+/// };
+/// use airssys_wasm::core::security::traits::SecurityValidator;
+/// use airssys_wasm::security::capability::set::{CapabilitySet, StoragePermission};
+/// use airssys_wasm::security::capability::validator::CapabilityValidator;
+/// use airssys_wasm::security::lockdown::LockdownValidator;
+///
+/// let inner = CapabilityValidator::new();
+/// let id = ComponentId::new("acme", "cache", "v1");
+/// inner.register_component(
+///     id.clone(),
+///     CapabilitySet::builder()
+///         .storage(StoragePermission {
+///             can_write_keys: vec!["cache/*".to_string()],
+///             can_read_keys: vec!["cache/*".to_string()],
+///         })
+///         .build(),
+/// );
+///
+/// let lockdown = Arc::new(HostLockdown::new());
+/// let validator = LockdownValidator::new(inner, Arc::clone(&lockdown));
+/// let put = Capability::Storage(StorageCapability {
+///     action: StorageAction::Write,
+///     namespace_pattern: "cache/a".to_string(),
+/// });
+///
+/// assert!(validator.validate_capability(&id, &put).is_ok());
+/// lockdown.engage("oncall", "incident 42").unwrap();
+/// assert!(validator.validate_capability(&id, &put).is_err());
+/// ```
+pub struct LockdownValidator<V: SecurityValidator> {
+    inner: V,
+    lockdown: Arc<HostLockdown>,
+    audit_logger: Option<Arc<dyn SecurityAuditLogger>>,
+}
+
+impl<V: SecurityValidator> LockdownValidator<V> {
+    /// Wraps `inner`, enforcing `lockdown`.
+    pub fn new(inner: V, lockdown: Arc<HostLockdown>) -> Self {
+        Self {
+            inner,
+            lockdown,
+            audit_logger: None,
+        }
+    }
+
+    /// Also log every lockdown refusal to `logger`.
+    pub fn with_audit_logger(mut self, logger: Arc<dyn SecurityAuditLogger>) -> Self {
+        self.audit_logger = Some(logger);
+        self
+    }
+
+    /// The wrapped validator.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    /// The lockdown switch this validator enforces.
+    pub fn lockdown(&self) -> &Arc<HostLockdown> {
+        &self.lockdown
+    }
+}
+
+impl<V: SecurityValidator> SecurityValidator for LockdownValidator<V> {
+    fn validate_capability(
+        &self,
+        component: &ComponentId,
+        capability: &Capability,
+    ) -> Result<(), SecurityError> {
+        if let Some((class, action, pattern)) = write_class(capability) {
+            let checked = check_lockdown(&self.lockdown, component, class, action, pattern);
+            if checked.is_err() {
+                if let Some(logger) = &self.audit_logger {
+                    logger.log_event(create_security_event(
+                        component.clone(),
+                        &format!("lockdown:{class}:{action}"),
+                        pattern,
+                        false,
+                    ));
+                }
+            }
+            checked?;
+        }
+        self.inner.validate_capability(component, capability)
+    }
+
+    fn can_send_to(&self, sender: &ComponentId, target: &ComponentId) -> Result<(), SecurityError> {
+        self.inner.can_send_to(sender, target)
+    }
+
+    fn quota(&self, component: &ComponentId, class: QuotaClass) -> Option<ResourceQuota> {
+        self.inner.quota(component, class)
+    }
+
+    fn grant_elevated(
+        &self,
+        component: &ComponentId,
+        capability: &str,
+    ) -> Result<(), SecurityError> {
+        self.inner.grant_elevated(component, capability)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use crate::core::security::capability::{
+        FilesystemCapability, MessagingAction, MessagingCapability, NetworkCapability,
+    };
+    use crate::core::security::traits::SecurityEvent;
+
+    struct AllowAll;
+
+    impl SecurityValidator for AllowAll {
+        fn validate_capability(
+            &self,
+            _component: &ComponentId,
+            _capability: &Capability,
+        ) -> Result<(), SecurityError> {
+            Ok(())
+        }
+
+        fn can_send_to(
+            &self,
+            _sender: &ComponentId,
+            _target: &ComponentId,
+        ) -> Result<(), SecurityError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingLogger {
+        events: Mutex<Vec<SecurityEvent>>,
+    }
+
+    impl SecurityAuditLogger for RecordingLogger {
+        fn log_event(&self, event: SecurityEvent) {
+            self.events.lock().unwrap().push(event);
+        }
+    }
+
+    fn fs(action: FilesystemAction) -> Capability {
+        Capability::Filesystem(FilesystemCapability {
+            action,
+            path_pattern: "/data/report.csv".to_string(),
+        })
+    }
+
+    fn component() -> ComponentId {
+        ComponentId::new("acme", "reports", "v1")
+    }
+
+    #[test]
+    fn test_write_class_classification() {
+        assert!(write_class(&fs(FilesystemAction::Write)).is_some());
+        assert!(write_class(&fs(FilesystemAction::Execute)).is_some());
+        assert!(write_class(&fs(FilesystemAction::Read)).is_none());
+        assert!(write_class(&fs(FilesystemAction::ListDir)).is_none());
+
+        let outbound = Capability::Network(NetworkCapability {
+            action: NetworkAction::Outbound,
+            host_pattern: "api.example.com".to_string(),
+            port: Some(443),
+        });
+        assert_eq!(
+            write_class(&outbound),
+            Some(("network", "outbound", "api.example.com"))
+        );
+        let send = Capability::Messaging(MessagingCapability {
+            action: MessagingAction::Send,
+            target_pattern: "*".to_string(),
+        });
+        assert!(write_class(&send).is_none());
+    }
+
+    #[test]
+    fn test_lockdown_denies_writes_and_audits() {
+        let lockdown = Arc::new(HostLockdown::new());
+        let logger = Arc::new(RecordingLogger::default());
+        let validator = LockdownValidator::new(AllowAll, Arc::clone(&lockdown))
+            .with_audit_logger(Arc::clone(&logger) as Arc<dyn SecurityAuditLogger>);
+
+        assert!(validator
+            .validate_capability(&component(), &fs(FilesystemAction::Write))
+            .is_ok());
+
+        lockdown.engage("oncall", "incident").unwrap();
+        let err = validator
+            .validate_capability(&component(), &fs(FilesystemAction::Write))
+            .unwrap_err();
+        assert_eq!(err.denial().unwrap().action, "write");
+        assert!(validator
+            .validate_capability(&component(), &fs(FilesystemAction::Read))
+            .is_ok());
+        assert!(validator.can_send_to(&component(), &component()).is_ok());
+
+        let window = lockdown.lift("oncall").unwrap();
+        assert_eq!(window.denials.len(), 1);
+        assert_eq!(
+            window.denials[0].capability,
+            "filesystem:write:/data/report.csv"
+        );
+        let events = logger.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].action, "lockdown:filesystem:write");
+        assert!(!events[0].granted);
+
+        assert!(validator
+            .validate_capability(&component(), &fs(FilesystemAction::Write))
+            .is_ok());
+    }
+}
@@ -5,5 +5,11 @@
 
 pub mod audit;
 pub mod capability;
+pub mod config_signing;
+pub mod egress;
+pub mod lockdown;
+pub mod os_bridge;
 pub mod osl;
 pub mod policy;
+pub mod quota;
+pub mod redaction;
@@ -0,0 +1,372 @@
+//! OS bridge backed by airssys-osl executors.
+//!
+//! [`OslOperationBridge`] implements `core::bridge::traits::OsBridge`:
+//! every request is mapped to its required capability through a
+//! `CapabilityMapping`, checked by the host's `SecurityValidator`, and only
+//! then executed by the executor airssys-osl's `ExecutorRegistry` resolves
+//! for the operation. Quotas attached to the component's filesystem and
+//! network grants are enforced through a [`QuotaLedger`]: every operation
+//! counts against the per-minute request limit, file reads against the
+//! byte limit, and connects hold a connection slot while they run.
+//!
+//! Host functions are synchronous while airssys-osl executors are async, so
+//! the bridge drives executors on its own small Tokio runtime and blocks the
+//! calling thread until the result arrives. This works whether or not the
+//! caller is itself inside a Tokio runtime.
+
+// Layer 1: Standard library imports
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+
+// Layer 2: Third-party crate imports
+use airssys_osl::core::context::{ExecutionContext, SecurityContext};
+use airssys_osl::core::executor::{ExecutionResult, OSExecutor};
+use airssys_osl::core::operation::Operation;
+use airssys_osl::core::result::{OSError, OSResult};
+use airssys_osl::executors::ExecutorRegistry;
+use airssys_osl::operations::{
+    FileReadOperation, FileWriteOperation, NetworkConnectOperation, ProcessSpawnOperation,
+};
+use tokio::runtime::Runtime;
+
+// Layer 3: Internal module imports
+use crate::core::bridge::errors::BridgeError;
+use crate::core::bridge::mapping::CapabilityMapping;
+use crate::core::bridge::operation::{OsOperation, OsOperationKind};
+use crate::core::bridge::traits::OsBridge;
+use crate::core::component::id::ComponentId;
+use crate::core::security::quota::QuotaClass;
+use crate::core::security::traits::SecurityValidator;
+use crate::security::quota::QuotaLedger;
+
+/// Executes bridged operations through airssys-osl after a capability check.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use airssys_wasm::core::bridge::mapping::CapabilityMapping;
+/// use airssys_wasm::core::bridge::operation::OsOperation;
+/// use airssys_wasm::core::bridge::traits::OsBridge;
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::security::capability::set::CapabilitySet;
+/// use airssys_wasm::security::capability::validator::CapabilityValidator;
+/// use airssys_wasm::security::os_bridge::OslOperationBridge;
+///
+/// let validator = Arc::new(CapabilityValidator::new());
+/// let id = ComponentId::new("acme", "reports", "v1");
+/// validator.register_component(id.clone(), CapabilitySet::new());
+///
+/// let bridge = OslOperationBridge::new(validator, CapabilityMapping::default()).unwrap();
+///
+/// // No filesystem grant: denied before anything touches the disk
+/// let read = OsOperation::FileRead { path: "/etc/hostname".to_string() };
+/// assert!(bridge.execute(&id, read).is_err());
+/// ```
+pub struct OslOperationBridge {
+    validator: Arc<dyn SecurityValidator>,
+    mapping: CapabilityMapping,
+    quotas: Arc<QuotaLedger>,
+    runtime: Option<Runtime>,
+}
+
+impl OslOperationBridge {
+    /// Creates a bridge that checks requests with `validator` and exposes
+    /// the operations in `mapping`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BridgeError::OperationFailed` if the executor runtime cannot
+    /// be started.
+    pub fn new(
+        validator: Arc<dyn SecurityValidator>,
+        mapping: CapabilityMapping,
+    ) -> Result<Self, BridgeError> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("airssys-os-bridge")
+            .enable_all()
+            .build()
+            .map_err(|e| {
+                BridgeError::OperationFailed(format!("cannot start bridge runtime: {e}"))
+            })?;
+        Ok(Self {
+            validator,
+            mapping,
+            quotas: Arc::new(QuotaLedger::new()),
+            runtime: Some(runtime),
+        })
+    }
+
+    /// Share `ledger` so quota usage is counted across bridges (e.g. with
+    /// the egress proxy).
+    pub fn with_quota_ledger(mut self, ledger: Arc<QuotaLedger>) -> Self {
+        self.quotas = ledger;
+        self
+    }
+
+    /// The operations this bridge exposes.
+    pub fn mapping(&self) -> &CapabilityMapping {
+        &self.mapping
+    }
+
+    /// The ledger quota usage is counted in.
+    pub fn quota_ledger(&self) -> &Arc<QuotaLedger> {
+        &self.quotas
+    }
+
+    /// Resolves and runs `operation` on the bridge runtime.
+    fn run<O>(&self, principal: &str, operation: O) -> Result<ExecutionResult, BridgeError>
+    where
+        O: Operation + 'static,
+    {
+        let executor = ExecutorRegistry::global()
+            .resolve(&operation)
+            .map_err(|e| BridgeError::OperationFailed(e.to_string()))?;
+        let context = ExecutionContext::new(SecurityContext::new(principal.to_string()));
+
+        self.block_on(async move {
+            executor.validate_operation(&operation, &context).await?;
+            executor.execute(operation, &context).await
+        })
+        .map_err(|e| BridgeError::OperationFailed(e.to_string()))
+    }
+
+    fn block_on<F, T>(&self, future: F) -> OSResult<T>
+    where
+        F: Future<Output = OSResult<T>> + Send + 'static,
+        T: Send + 'static,
+    {
+        let runtime = self
+            .runtime
+            .as_ref()
+            .ok_or_else(|| OSError::execution_failed("bridge runtime is shut down"))?;
+        let task = runtime.spawn(future);
+        futures::executor::block_on(task)
+            .map_err(|e| OSError::execution_failed(format!("bridge task failed: {e}")))?
+    }
+}
+
+impl OsBridge for OslOperationBridge {
+    fn execute(
+        &self,
+        component: &ComponentId,
+        operation: OsOperation,
+    ) -> Result<Vec<u8>, BridgeError> {
+        let capability = self.mapping.required_capability(&operation)?;
+        self.validator.validate_capability(component, &capability)?;
+
+        let kind = operation.kind();
+        let class = match kind {
+            OsOperationKind::NetworkConnect => QuotaClass::Network,
+            _ => QuotaClass::Filesystem,
+        };
+        let quota = self.validator.quota(component, class);
+        let resource = match &operation {
+            OsOperation::FileRead { path } | OsOperation::FileWrite { path, .. } => path.clone(),
+            OsOperation::ProcessSpawn { program, .. } => program.clone(),
+            OsOperation::NetworkConnect { address } => address.clone(),
+        };
+        let _permit = match &quota {
+            Some(quota) => {
+                self.quotas
+                    .admit_request(component, class, quota, &resource)?;
+                match kind {
+                    OsOperationKind::NetworkConnect => Some(
+                        self.quotas
+                            .acquire_connection(component, class, quota, &resource)?,
+                    ),
+                    _ => None,
+                }
+            }
+            None => None,
+        };
+
+        let principal = component.to_string_id();
+        let result = match operation {
+            OsOperation::FileRead { path } => self.run(&principal, FileReadOperation::new(path)),
+            OsOperation::FileWrite { path, content } => {
+                self.run(&principal, FileWriteOperation::new(path, content))
+            }
+            OsOperation::ProcessSpawn { program, args } => self.run(
+                &principal,
+                ProcessSpawnOperation::new(program).with_args(args),
+            ),
+            OsOperation::NetworkConnect { address } => {
+                self.run(&principal, NetworkConnectOperation::new(address))
+            }
+        };
+
+        match &result {
+            Ok(_) => {
+                tracing::debug!(component = %principal, operation = %kind, "os bridge operation completed")
+            }
+            Err(e) => {
+                tracing::warn!(component = %principal, operation = %kind, error = %e, "os bridge operation failed")
+            }
+        }
+
+        let result = result?;
+        if let (Some(quota), OsOperationKind::FileRead) = (&quota, kind) {
+            self.quotas.charge_bytes_read(
+                component,
+                class,
+                quota,
+                result.output.len() as u64,
+                &resource,
+            )?;
+        }
+        Ok(match kind {
+            // The executor reports the written byte count, not file contents
+            OsOperationKind::FileWrite => Vec::new(),
+            OsOperationKind::NetworkConnect => result
+                .metadata
+                .get("peer_address")
+                .map(|peer| peer.clone().into_bytes())
+                .unwrap_or(result.output),
+            _ => result.output,
+        })
+    }
+}
+
+impl Drop for OslOperationBridge {
+    fn drop(&mut self) {
+        // Dropping a runtime from async code panics; don't wait for tasks
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+impl fmt::Debug for OslOperationBridge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OslOperationBridge")
+            .field("mapping", &self.mapping)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::security::quota::ResourceQuota;
+    use crate::security::capability::set::{CapabilitySet, FilesystemPermission};
+    use crate::security::capability::validator::CapabilityValidator;
+
+    fn bridge_for(id: &ComponentId, dir: &str) -> OslOperationBridge {
+        let validator = Arc::new(CapabilityValidator::new());
+        validator.register_component(
+            id.clone(),
+            CapabilitySet::builder()
+                .filesystem(FilesystemPermission {
+                    can_read_paths: vec![format!("{dir}/*")],
+                    can_write_paths: vec![format!("{dir}/*")],
+                    can_execute_paths: vec![],
+                })
+                .build(),
+        );
+        OslOperationBridge::new(validator, CapabilityMapping::default()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_file_reads_are_limited_by_quota() {
+        let dir = std::env::temp_dir().join(format!("airssys-os-quota-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir_str = dir.to_string_lossy().into_owned();
+        let path = format!("{dir_str}/data.txt");
+        std::fs::write(&path, b"0123456789").unwrap();
+
+        let id = ComponentId::new("acme", "reports", "v1");
+        let validator = Arc::new(CapabilityValidator::new());
+        validator.register_component(
+            id.clone(),
+            CapabilitySet::builder()
+                .filesystem(FilesystemPermission {
+                    can_read_paths: vec![format!("{dir_str}/*")],
+                    can_write_paths: vec![],
+                    can_execute_paths: vec![],
+                })
+                .quota(
+                    QuotaClass::Filesystem,
+                    ResourceQuota::new().with_max_bytes_read(15),
+                )
+                .build(),
+        );
+        let bridge = OslOperationBridge::new(validator, CapabilityMapping::default()).unwrap();
+
+        let read = || OsOperation::FileRead { path: path.clone() };
+        assert_eq!(bridge.execute(&id, read()).unwrap(), b"0123456789");
+        match bridge.execute(&id, read()) {
+            Err(BridgeError::Security(e)) => assert_eq!(e.denial().unwrap().action, "read"),
+            other => panic!("unexpected result: {other:?}"),
+        }
+        assert_eq!(
+            bridge
+                .quota_ledger()
+                .usage(&id, QuotaClass::Filesystem)
+                .bytes_read,
+            10
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_granted_file_operations_run_through_osl() {
+        let dir = std::env::temp_dir().join(format!("airssys-os-bridge-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir_str = dir.to_string_lossy().into_owned();
+        let id = ComponentId::new("acme", "reports", "v1");
+        let bridge = bridge_for(&id, &dir_str);
+
+        let path = format!("{dir_str}/out.txt");
+        let written = bridge
+            .execute(
+                &id,
+                OsOperation::FileWrite {
+                    path: path.clone(),
+                    content: b"hello".to_vec(),
+                },
+            )
+            .unwrap();
+        assert!(written.is_empty());
+
+        let read = bridge.execute(&id, OsOperation::FileRead { path }).unwrap();
+        assert_eq!(read, b"hello");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_ungranted_and_withheld_operations_are_refused() {
+        let id = ComponentId::new("acme", "reports", "v1");
+        let bridge = bridge_for(&id, "/srv/reports");
+
+        let outside = OsOperation::FileRead {
+            path: "/etc/shadow".to_string(),
+        };
+        match bridge.execute(&id, outside) {
+            Err(BridgeError::Security(e)) => {
+                assert_eq!(e.denial().unwrap().capability, "filesystem")
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        // No execute grant
+        let spawn = OsOperation::ProcessSpawn {
+            program: "/bin/sh".to_string(),
+            args: vec![],
+        };
+        assert!(matches!(
+            bridge.execute(&id, spawn),
+            Err(BridgeError::Security(_))
+        ));
+
+        let stranger = ComponentId::new("acme", "other", "v1");
+        let read = OsOperation::FileRead {
+            path: "/srv/reports/a".to_string(),
+        };
+        assert!(bridge.execute(&stranger, read).is_err());
+    }
+}
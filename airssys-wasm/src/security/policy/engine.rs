@@ -129,6 +129,22 @@ impl PolicyEngine {
         }
         Ok(())
     }
+
+    /// Returns the first applicable policy that denies `action` on
+    /// `resource`, if any.
+    ///
+    /// Same decision as [`evaluate`](Self::evaluate), naming the policy
+    /// instead of failing; used for dry runs.
+    pub fn denying_policy(
+        &self,
+        component: &ComponentId,
+        action: &str,
+        resource: &str,
+    ) -> Option<&SecurityPolicy> {
+        self.policies.iter().find(|policy| {
+            policy.applies_to(component) && policy.evaluate(action, resource).is_err()
+        })
+    }
 }
 
 impl Default for PolicyEngine {
@@ -0,0 +1,337 @@
+//! Quota enforcement for capability grants.
+//!
+//! [`QuotaLedger`] tracks what each component has consumed under the
+//! [`ResourceQuota`] attached to its filesystem and network grants, and
+//! refuses operations that would exceed it:
+//!
+//! - **Bytes read** accumulate for the lifetime of the ledger entry; a read
+//!   that would cross the limit is refused and its data withheld.
+//! - **Requests per minute** are counted over a sliding one-minute window.
+//! - **Concurrent connections** are held by a [`ConnectionPermit`] and
+//!   released when it is dropped.
+//!
+//! The host function layer (`OslOperationBridge`, `EgressProxy`) charges
+//! the ledger after the capability check passes. Refusals are
+//! `SecurityError::Denied` so guests see a regular `permission-denied`.
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use crate::core::component::id::ComponentId;
+use crate::core::security::errors::{PermissionDenial, SecurityError};
+use crate::core::security::quota::{QuotaClass, ResourceQuota};
+
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// Consumption of one component under one quota class.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QuotaUsage {
+    /// Bytes read so far.
+    pub bytes_read: u64,
+    /// Operations admitted in the last minute.
+    pub requests_last_minute: u32,
+    /// Connections currently held open.
+    pub open_connections: u32,
+}
+
+#[derive(Debug, Default)]
+struct Usage {
+    bytes_read: u64,
+    requests: VecDeque<Instant>,
+    connections: u32,
+}
+
+impl Usage {
+    fn prune(&mut self, now: Instant) {
+        while self
+            .requests
+            .front()
+            .is_some_and(|at| now.duration_since(*at) >= RATE_WINDOW)
+        {
+            self.requests.pop_front();
+        }
+    }
+}
+
+/// Per-component quota consumption.
+///
+/// # Examples
+///
+/// ```
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::security::quota::{QuotaClass, ResourceQuota};
+/// use airssys_wasm::security::quota::QuotaLedger;
+///
+/// let ledger = QuotaLedger::new();
+/// let id = ComponentId::new("acme", "reports", "v1");
+/// let quota = ResourceQuota::new().with_max_bytes_read(10);
+///
+/// assert!(ledger.charge_bytes_read(&id, QuotaClass::Filesystem, &quota, 8, "/data/a").is_ok());
+/// assert!(ledger.charge_bytes_read(&id, QuotaClass::Filesystem, &quota, 8, "/data/b").is_err());
+/// assert_eq!(ledger.usage(&id, QuotaClass::Filesystem).bytes_read, 8);
+/// ```
+#[derive(Debug, Default)]
+pub struct QuotaLedger {
+    usage: Mutex<HashMap<(ComponentId, QuotaClass), Usage>>,
+}
+
+impl QuotaLedger {
+    /// Create an empty ledger.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count one operation against the per-minute request limit.
+    ///
+    /// # Errors
+    ///
+    /// `SecurityError::Denied` if the component already used its requests
+    /// for the current window; the refused request is not counted.
+    pub fn admit_request(
+        &self,
+        component: &ComponentId,
+        class: QuotaClass,
+        quota: &ResourceQuota,
+        resource: &str,
+    ) -> Result<(), SecurityError> {
+        let Some(max) = quota.max_requests_per_minute else {
+            return Ok(());
+        };
+        let now = Instant::now();
+        let mut usage = self.usage.lock().unwrap();
+        let entry = usage.entry((component.clone(), class)).or_default();
+        entry.prune(now);
+        if entry.requests.len() >= max as usize {
+            return Err(exceeded(
+                class,
+                "request",
+                resource,
+                format!("quota of {max} requests per minute exceeded"),
+            ));
+        }
+        entry.requests.push_back(now);
+        Ok(())
+    }
+
+    /// Charge `bytes` read against the byte limit.
+    ///
+    /// # Errors
+    ///
+    /// `SecurityError::Denied` if the read would exceed the limit; nothing
+    /// is charged and the caller must withhold the data.
+    pub fn charge_bytes_read(
+        &self,
+        component: &ComponentId,
+        class: QuotaClass,
+        quota: &ResourceQuota,
+        bytes: u64,
+        resource: &str,
+    ) -> Result<(), SecurityError> {
+        let mut usage = self.usage.lock().unwrap();
+        let entry = usage.entry((component.clone(), class)).or_default();
+        let total = entry.bytes_read.saturating_add(bytes);
+        if let Some(max) = quota.max_bytes_read {
+            if total > max {
+                return Err(exceeded(
+                    class,
+                    "read",
+                    resource,
+                    format!(
+                        "quota of {max} bytes read exceeded ({} used, {bytes} requested)",
+                        entry.bytes_read
+                    ),
+                ));
+            }
+        }
+        entry.bytes_read = total;
+        Ok(())
+    }
+
+    /// Take one connection slot, released when the permit is dropped.
+    ///
+    /// # Errors
+    ///
+    /// `SecurityError::Denied` if the component already holds the maximum
+    /// number of connections.
+    pub fn acquire_connection(
+        self: &Arc<Self>,
+        component: &ComponentId,
+        class: QuotaClass,
+        quota: &ResourceQuota,
+        resource: &str,
+    ) -> Result<ConnectionPermit, SecurityError> {
+        let mut usage = self.usage.lock().unwrap();
+        let entry = usage.entry((component.clone(), class)).or_default();
+        if let Some(max) = quota.max_concurrent_connections {
+            if entry.connections >= max {
+                return Err(exceeded(
+                    class,
+                    "connect",
+                    resource,
+                    format!("quota of {max} concurrent connections exceeded"),
+                ));
+            }
+        }
+        entry.connections += 1;
+        Ok(ConnectionPermit {
+            ledger: Arc::clone(self),
+            component: component.clone(),
+            class,
+        })
+    }
+
+    /// Current consumption of `component` under `class`.
+    pub fn usage(&self, component: &ComponentId, class: QuotaClass) -> QuotaUsage {
+        let now = Instant::now();
+        let mut usage = self.usage.lock().unwrap();
+        match usage.get_mut(&(component.clone(), class)) {
+            Some(entry) => {
+                entry.prune(now);
+                QuotaUsage {
+                    bytes_read: entry.bytes_read,
+                    requests_last_minute: entry.requests.len() as u32,
+                    open_connections: entry.connections,
+                }
+            }
+            None => QuotaUsage::default(),
+        }
+    }
+
+    /// Forget the byte and request counts of `component`, e.g. when it is
+    /// reloaded. Open connections stay counted until their permits drop.
+    pub fn reset(&self, component: &ComponentId) {
+        let mut usage = self.usage.lock().unwrap();
+        usage.retain(|(id, _), entry| id != component || entry.connections > 0);
+        for ((id, _), entry) in usage.iter_mut() {
+            if id == component {
+                entry.bytes_read = 0;
+                entry.requests.clear();
+            }
+        }
+    }
+
+    fn release_connection(&self, component: &ComponentId, class: QuotaClass) {
+        // Runs from Drop: never panic on a poisoned lock
+        if let Ok(mut usage) = self.usage.lock() {
+            if let Some(entry) = usage.get_mut(&(component.clone(), class)) {
+                entry.connections = entry.connections.saturating_sub(1);
+            }
+        }
+    }
+}
+
+/// One open connection counted against a component's quota.
+#[derive(Debug)]
+pub struct ConnectionPermit {
+    ledger: Arc<QuotaLedger>,
+    component: ComponentId,
+    class: QuotaClass,
+}
+
+impl Drop for ConnectionPermit {
+    fn drop(&mut self) {
+        self.ledger.release_connection(&self.component, self.class);
+    }
+}
+
+fn exceeded(class: QuotaClass, action: &str, resource: &str, reason: String) -> SecurityError {
+    SecurityError::Denied(PermissionDenial::new(
+        class.as_str(),
+        action,
+        resource,
+        reason,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component() -> ComponentId {
+        ComponentId::new("acme", "fetcher", "v1")
+    }
+
+    #[test]
+    fn test_request_rate_is_limited_per_class() {
+        let ledger = QuotaLedger::new();
+        let quota = ResourceQuota::new().with_max_requests_per_minute(2);
+        for _ in 0..2 {
+            ledger
+                .admit_request(&component(), QuotaClass::Network, &quota, "api.example.com")
+                .unwrap();
+        }
+        let err = ledger
+            .admit_request(&component(), QuotaClass::Network, &quota, "api.example.com")
+            .unwrap_err();
+        assert_eq!(err.denial().unwrap().action, "request");
+
+        // Other classes and unlimited quotas are unaffected
+        assert!(ledger
+            .admit_request(&component(), QuotaClass::Filesystem, &quota, "/a")
+            .is_ok());
+        assert!(ledger
+            .admit_request(
+                &component(),
+                QuotaClass::Network,
+                &ResourceQuota::new(),
+                "x"
+            )
+            .is_ok());
+        assert_eq!(
+            ledger
+                .usage(&component(), QuotaClass::Network)
+                .requests_last_minute,
+            2
+        );
+    }
+
+    #[test]
+    fn test_connection_permits_release_on_drop() {
+        let ledger = Arc::new(QuotaLedger::new());
+        let quota = ResourceQuota::new().with_max_concurrent_connections(1);
+        let permit = ledger
+            .acquire_connection(&component(), QuotaClass::Network, &quota, "db")
+            .unwrap();
+        assert!(ledger
+            .acquire_connection(&component(), QuotaClass::Network, &quota, "db")
+            .is_err());
+
+        drop(permit);
+        assert_eq!(
+            ledger
+                .usage(&component(), QuotaClass::Network)
+                .open_connections,
+            0
+        );
+        assert!(ledger
+            .acquire_connection(&component(), QuotaClass::Network, &quota, "db")
+            .is_ok());
+    }
+
+    #[test]
+    fn test_reset_clears_counts_but_keeps_connections() {
+        let ledger = Arc::new(QuotaLedger::new());
+        let quota = ResourceQuota::new().with_max_bytes_read(4);
+        ledger
+            .charge_bytes_read(&component(), QuotaClass::Filesystem, &quota, 4, "/a")
+            .unwrap();
+        let _permit = ledger
+            .acquire_connection(&component(), QuotaClass::Network, &quota, "db")
+            .unwrap();
+
+        ledger.reset(&component());
+        assert_eq!(
+            ledger.usage(&component(), QuotaClass::Filesystem),
+            QuotaUsage::default()
+        );
+        assert_eq!(
+            ledger
+                .usage(&component(), QuotaClass::Network)
+                .open_connections,
+            1
+        );
+    }
+}
@@ -0,0 +1,361 @@
+//! Payload redaction for logging and audit paths.
+//!
+//! Message payloads and audited resources can carry secrets (tokens,
+//! passwords) or personal data. A [`Redactor`] holds the host's redaction
+//! rules and turns raw bytes into a safe, bounded *preview* before anything
+//! reaches a log, an audit record, or a crash dump:
+//!
+//! 1. **JSON-path rules** replace the value at a path (e.g. `$.user.password`,
+//!    `$.items[*].token`) when the payload is a JSON document.
+//! 2. **Regex rules** replace every match in the rendered text, whatever the
+//!    payload format.
+//! 3. The result is truncated to the configured preview length.
+//!
+//! Non-UTF-8 payloads are rendered as hex and only truncated, since regex
+//! rules are written against text.
+
+// Layer 1: Standard library imports (per PROJECTS_STANDARD.md §2.1)
+use std::fmt;
+
+// Layer 2: Third-party crate imports (per PROJECTS_STANDARD.md §2.1)
+use regex::Regex;
+use serde_json::Value;
+use thiserror::Error;
+
+// Layer 3: Internal module imports (per PROJECTS_STANDARD.md §2.1)
+use crate::core::security::traits::SecurityEvent;
+
+/// Replacement text used by JSON-path rules.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Errors raised while building redaction rules.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum RedactionError {
+    /// The regex pattern does not compile.
+    #[error("Invalid redaction pattern '{pattern}': {reason}")]
+    InvalidPattern {
+        /// Offending pattern.
+        pattern: String,
+        /// Compiler error.
+        reason: String,
+    },
+
+    /// The JSON path is not of the form `$.a.b[*].c`.
+    #[error("Invalid JSON path: {0}")]
+    InvalidPath(String),
+}
+
+/// One step of a JSON path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathSegment {
+    /// Object member by name.
+    Key(String),
+    /// Every element of an array, or every member of an object.
+    Wildcard,
+}
+
+/// Parses `$.a.b[*].c` / `$.a.*.c` into segments.
+fn parse_json_path(path: &str) -> Result<Vec<PathSegment>, RedactionError> {
+    let invalid = || RedactionError::InvalidPath(path.to_string());
+    let rest = path.strip_prefix("$.").ok_or_else(invalid)?;
+
+    let mut segments = Vec::new();
+    for part in rest.split('.') {
+        let (key, wildcard) = match part.strip_suffix("[*]") {
+            Some(key) => (key, true),
+            None => (part, false),
+        };
+        match key {
+            "" if wildcard => {}
+            "" => return Err(invalid()),
+            "*" => segments.push(PathSegment::Wildcard),
+            _ if key.contains(['[', ']']) => return Err(invalid()),
+            _ => segments.push(PathSegment::Key(key.to_string())),
+        }
+        if wildcard {
+            segments.push(PathSegment::Wildcard);
+        }
+    }
+    Ok(segments)
+}
+
+/// Replaces every value reached by `segments`; returns true if any was replaced.
+fn redact_path(value: &mut Value, segments: &[PathSegment]) -> bool {
+    let Some((first, rest)) = segments.split_first() else {
+        *value = Value::String(REDACTED.to_string());
+        return true;
+    };
+
+    match (first, value) {
+        (PathSegment::Key(key), Value::Object(map)) => map
+            .get_mut(key)
+            .is_some_and(|child| redact_path(child, rest)),
+        (PathSegment::Wildcard, Value::Array(items)) => redact_all(items.iter_mut(), rest),
+        (PathSegment::Wildcard, Value::Object(map)) => redact_all(map.values_mut(), rest),
+        _ => false,
+    }
+}
+
+/// Redacts every child (no short-circuit); returns true if any was replaced.
+fn redact_all<'a>(children: impl Iterator<Item = &'a mut Value>, rest: &[PathSegment]) -> bool {
+    let mut hit = false;
+    for child in children {
+        hit |= redact_path(child, rest);
+    }
+    hit
+}
+
+struct RegexRule {
+    regex: Regex,
+    replacement: String,
+}
+
+/// Redaction rules applied to payload previews and audit records.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::security::redaction::Redactor;
+///
+/// let redactor = Redactor::new()
+///     .with_json_path("$.password").unwrap()
+///     .with_regex(r"Bearer [A-Za-z0-9._-]+", "Bearer [REDACTED]").unwrap();
+///
+/// let preview = redactor.preview(br#"{"user":"ada","password":"hunter2"}"#);
+/// assert_eq!(preview, r#"{"password":"[REDACTED]","user":"ada"}"#);
+///
+/// let header = redactor.redact_text("Authorization: Bearer abc.def");
+/// assert_eq!(header, "Authorization: Bearer [REDACTED]");
+/// ```
+pub struct Redactor {
+    json_paths: Vec<Vec<PathSegment>>,
+    regexes: Vec<RegexRule>,
+    max_preview_len: usize,
+}
+
+impl fmt::Debug for Redactor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Redactor")
+            .field("json_paths", &self.json_paths.len())
+            .field(
+                "regexes",
+                &self
+                    .regexes
+                    .iter()
+                    .map(|r| r.regex.as_str())
+                    .collect::<Vec<_>>(),
+            )
+            .field("max_preview_len", &self.max_preview_len)
+            .finish()
+    }
+}
+
+impl Default for Redactor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Redactor {
+    /// Default maximum preview length in bytes.
+    pub const DEFAULT_MAX_PREVIEW_LEN: usize = 256;
+
+    /// Creates a redactor with no rules and the default preview length.
+    pub fn new() -> Self {
+        Self {
+            json_paths: Vec::new(),
+            regexes: Vec::new(),
+            max_preview_len: Self::DEFAULT_MAX_PREVIEW_LEN,
+        }
+    }
+
+    /// Adds a regex rule replacing every match with `replacement`.
+    ///
+    /// `replacement` may reference capture groups (`$1`, `${name}`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `RedactionError::InvalidPattern` if the pattern does not compile.
+    pub fn with_regex(
+        mut self,
+        pattern: &str,
+        replacement: impl Into<String>,
+    ) -> Result<Self, RedactionError> {
+        let regex = Regex::new(pattern).map_err(|e| RedactionError::InvalidPattern {
+            pattern: pattern.to_string(),
+            reason: e.to_string(),
+        })?;
+        self.regexes.push(RegexRule {
+            regex,
+            replacement: replacement.into(),
+        });
+        Ok(self)
+    }
+
+    /// Adds a JSON-path rule replacing the addressed values with `"[REDACTED]"`.
+    ///
+    /// Supported syntax: `$.key.key`, `[*]` for every array element, and `*`
+    /// for every object member.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RedactionError::InvalidPath` for unsupported paths.
+    pub fn with_json_path(mut self, path: &str) -> Result<Self, RedactionError> {
+        self.json_paths.push(parse_json_path(path)?);
+        Ok(self)
+    }
+
+    /// Sets the maximum preview length in bytes.
+    pub fn with_max_preview_len(mut self, len: usize) -> Self {
+        self.max_preview_len = len;
+        self
+    }
+
+    /// Returns true if no rule is configured.
+    pub fn is_empty(&self) -> bool {
+        self.json_paths.is_empty() && self.regexes.is_empty()
+    }
+
+    /// Applies the regex rules to `text`.
+    pub fn redact_text(&self, text: &str) -> String {
+        self.regexes.iter().fold(text.to_string(), |text, rule| {
+            rule.regex
+                .replace_all(&text, rule.replacement.as_str())
+                .into_owned()
+        })
+    }
+
+    /// Renders a redacted, bounded preview of a payload.
+    pub fn preview(&self, payload: &[u8]) -> String {
+        let Ok(text) = std::str::from_utf8(payload) else {
+            let limit = payload.len().min(self.max_preview_len / 2);
+            let hex: String = payload[..limit]
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect();
+            return Self::mark_truncated(hex, limit < payload.len());
+        };
+
+        let text = self.redact_json(text).unwrap_or_else(|| text.to_string());
+        let redacted = self.redact_text(&text);
+        self.truncate(redacted)
+    }
+
+    /// Returns a copy of an audit event with its action and resource redacted.
+    pub fn redact_event(&self, event: &SecurityEvent) -> SecurityEvent {
+        SecurityEvent {
+            action: self.redact_text(&event.action),
+            resource: self.redact_text(&event.resource),
+            ..event.clone()
+        }
+    }
+
+    /// Applies JSON-path rules; `None` if there are none or `text` is not JSON.
+    fn redact_json(&self, text: &str) -> Option<String> {
+        if self.json_paths.is_empty() {
+            return None;
+        }
+        let mut value: Value = serde_json::from_str(text).ok()?;
+        for path in &self.json_paths {
+            redact_path(&mut value, path);
+        }
+        serde_json::to_string(&value).ok()
+    }
+
+    fn truncate(&self, mut text: String) -> String {
+        if text.len() <= self.max_preview_len {
+            return text;
+        }
+        let mut end = self.max_preview_len;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+        Self::mark_truncated(text, true)
+    }
+
+    fn mark_truncated(mut text: String, truncated: bool) -> String {
+        if truncated {
+            text.push('…');
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::component::id::ComponentId;
+
+    #[test]
+    fn test_json_path_redaction_with_wildcards() {
+        let redactor = Redactor::new()
+            .with_json_path("$.items[*].token")
+            .unwrap()
+            .with_json_path("$.profile.*")
+            .unwrap();
+
+        let payload =
+            br#"{"items":[{"id":1,"token":"a"},{"id":2,"token":"b"}],"profile":{"email":"x@y.z"}}"#;
+        let preview = redactor.preview(payload);
+
+        assert!(!preview.contains("\"a\"") && !preview.contains("x@y.z"));
+        assert_eq!(preview.matches(REDACTED).count(), 3);
+        assert!(preview.contains("\"id\":2"));
+    }
+
+    #[test]
+    fn test_regex_applies_to_non_json_text_and_truncates() {
+        let redactor = Redactor::new()
+            .with_regex(r"\d{4}-\d{4}-\d{4}-(\d{4})", "****-$1")
+            .unwrap()
+            .with_max_preview_len(24);
+
+        let preview = redactor.preview(b"card=1234-5678-9012-3456 amount=10 currency=EUR");
+        assert_eq!(preview, "card=****-3456 amount=10…");
+    }
+
+    #[test]
+    fn test_binary_payload_is_hex_and_bounded() {
+        let redactor = Redactor::new().with_max_preview_len(8);
+        assert_eq!(
+            redactor.preview(&[0xff, 0x00, 0x01, 0x02, 0x03]),
+            "ff000102…"
+        );
+    }
+
+    #[test]
+    fn test_invalid_rules_rejected() {
+        assert!(matches!(
+            Redactor::new().with_regex("(", ""),
+            Err(RedactionError::InvalidPattern { .. })
+        ));
+        for path in ["password", "$.", "$.a..b", "$.a[0]"] {
+            assert!(
+                matches!(
+                    Redactor::new().with_json_path(path),
+                    Err(RedactionError::InvalidPath(_))
+                ),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_redact_event() {
+        let redactor = Redactor::new()
+            .with_regex(r"token=[^&]+", "token=[REDACTED]")
+            .unwrap();
+        let event = SecurityEvent {
+            component: ComponentId::new("test", "comp", "1"),
+            action: "connect".to_string(),
+            resource: "https://api/x?token=s3cret&page=2".to_string(),
+            granted: true,
+            timestamp_ms: 1,
+        };
+
+        let redacted = redactor.redact_event(&event);
+        assert_eq!(redacted.resource, "https://api/x?token=[REDACTED]&page=2");
+        assert_eq!(redacted.timestamp_ms, 1);
+    }
+}
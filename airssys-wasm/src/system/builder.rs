@@ -26,7 +26,9 @@
 
 // Layer 1: Standard library imports
 use std::fmt;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 // Layer 2: Third-party crate imports
 use airssys_rt::broker::MessageBroker;
@@ -34,9 +36,20 @@ use airssys_rt::system::SystemConfig;
 
 // Layer 3: Internal module imports
 use super::coordinator::SystemCoordinator;
+use super::reservation::HostCapacity;
+use super::selftest::SelfTest;
+use crate::component::shedding::LoadShedder;
 use crate::component::wrapper::ComponentActorMessage;
+use crate::core::management::elevation::ElevationRequests;
+use crate::core::management::metrics::ComponentMetrics;
 use crate::core::runtime::traits::{ComponentLoader, RuntimeEngine};
 use crate::core::security::traits::{SecurityAuditLogger, SecurityValidator};
+use crate::messaging::affinity::AffinityRouter;
+use crate::messaging::codec::PayloadCompressor;
+use crate::messaging::content_type::ContentTypeGuard;
+use crate::messaging::schema::SchemaRegistry;
+use crate::messaging::topic::TopicBus;
+use crate::security::config_signing::ConfigVerifier;
 
 /// Builder for constructing a fully-configured [`SystemCoordinator`].
 ///
@@ -94,6 +107,18 @@ where
 
     // Optional configuration
     actor_system_config: SystemConfig,
+    host_capacity: Option<HostCapacity>,
+    elevation_requests: Option<Arc<ElevationRequests>>,
+    component_metrics: Option<Arc<ComponentMetrics>>,
+    topic_bus: Option<Arc<TopicBus>>,
+    payload_compressor: Option<Arc<PayloadCompressor>>,
+    schema_registry: Option<Arc<SchemaRegistry>>,
+    content_type_guard: Option<Arc<ContentTypeGuard>>,
+    affinity_router: Option<Arc<AffinityRouter>>,
+    load_shedder: Option<Arc<LoadShedder>>,
+    self_test: Option<SelfTest>,
+    config_verifier: Option<(ConfigVerifier, Vec<PathBuf>)>,
+    drain_deadline: Option<Duration>,
 }
 
 impl<E, L, V, A, B> SystemBuilder<E, L, V, A, B>
@@ -130,6 +155,18 @@ where
             audit_logger,
             broker,
             actor_system_config: SystemConfig::default(),
+            host_capacity: None,
+            elevation_requests: None,
+            component_metrics: None,
+            topic_bus: None,
+            payload_compressor: None,
+            schema_registry: None,
+            content_type_guard: None,
+            affinity_router: None,
+            load_shedder: None,
+            self_test: None,
+            config_verifier: None,
+            drain_deadline: None,
         }
     }
 
@@ -145,6 +182,130 @@ where
         self
     }
 
+    /// Enables host resource admission with the given capacity.
+    ///
+    /// If not called, components are admitted without capacity checks and
+    /// critical reservations are unavailable.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Host totals, typically from the `[capacity]` manifest section
+    pub fn with_host_capacity(mut self, capacity: HostCapacity) -> Self {
+        self.host_capacity = Some(capacity);
+        self
+    }
+
+    /// Shares a capability elevation registry with the runtime engine.
+    ///
+    /// Pass the same registry to `WasmtimeEngine::with_elevation_requests`
+    /// so requests filed by components can be approved through the
+    /// coordinator. If not called, the coordinator keeps its own registry.
+    pub fn with_elevation_requests(mut self, requests: Arc<ElevationRequests>) -> Self {
+        self.elevation_requests = Some(requests);
+        self
+    }
+
+    /// Shares a component metrics registry with the runtime engine.
+    ///
+    /// Pass the same registry to `WasmtimeEngine::with_component_metrics`
+    /// so metrics emitted by components appear in
+    /// `SystemCoordinator::metrics_snapshot()`. If not called, the
+    /// coordinator keeps its own registry.
+    pub fn with_component_metrics(mut self, metrics: Arc<ComponentMetrics>) -> Self {
+        self.component_metrics = Some(metrics);
+        self
+    }
+
+    /// Reports the per-topic delivery counters of `bus` in
+    /// `SystemCoordinator::metrics_snapshot()`.
+    ///
+    /// If not called, no topic metrics are reported.
+    pub fn with_topic_bus(mut self, bus: Arc<TopicBus>) -> Self {
+        self.topic_bus = Some(bus);
+        self
+    }
+
+    /// Decompresses payloads compressed with `compressor` on delivery.
+    ///
+    /// Pass the same compressor to `ResponseRouter::with_compressor`; its
+    /// counters appear in `SystemCoordinator::metrics_snapshot()`. If not
+    /// called, payloads are delivered as sent.
+    pub fn with_payload_compressor(mut self, compressor: Arc<PayloadCompressor>) -> Self {
+        self.payload_compressor = Some(compressor);
+        self
+    }
+
+    /// Validates payloads against the schemas registered in `schemas` on
+    /// delivery.
+    ///
+    /// Pass the same registry to `ResponseRouter::with_schema_registry` to
+    /// also reject malformed messages at the sender. If not called, payloads
+    /// are delivered unchecked.
+    pub fn with_schema_registry(mut self, schemas: Arc<SchemaRegistry>) -> Self {
+        self.schema_registry = Some(schemas);
+        self
+    }
+
+    /// Rejects payloads whose content type the target did not declare on
+    /// delivery.
+    ///
+    /// Components register their `[messaging] accepts` list with `guard`
+    /// when loaded. Pass the same guard to
+    /// `ResponseRouter::with_content_type_guard` to also reject mismatched
+    /// messages at the sender. If not called, any content type is delivered.
+    pub fn with_content_type_guard(mut self, guard: Arc<ContentTypeGuard>) -> Self {
+        self.content_type_guard = Some(guard);
+        self
+    }
+
+    /// Delivers messages carrying a partition key to the pool instance
+    /// `affinity` assigns the key to.
+    ///
+    /// Loaded components join the pool of their namespace and name. Pass
+    /// the same router to `ResponseRouter::with_affinity_router` to address
+    /// the instance when sending. If not called, keys are ignored.
+    pub fn with_affinity_router(mut self, affinity: Arc<AffinityRouter>) -> Self {
+        self.affinity_router = Some(affinity);
+        self
+    }
+
+    /// Sheds queued messages that can no longer meet their deadline to the
+    /// dead-letter queue of `shedder`'s ledger.
+    ///
+    /// Components register their `[shedding]` policy with `shedder` when
+    /// loaded. If not called, every message is handled however late.
+    pub fn with_load_shedder(mut self, shedder: Arc<LoadShedder>) -> Self {
+        self.load_shedder = Some(shedder);
+        self
+    }
+
+    /// Sets the startup checks run by `SystemCoordinator::start()`.
+    ///
+    /// If not called, the coordinator starts without checks.
+    pub fn with_self_test(mut self, self_test: SelfTest) -> Self {
+        self.self_test = Some(self_test);
+        self
+    }
+
+    /// Requires operator signatures on the given configuration files.
+    ///
+    /// The files are verified on `SystemCoordinator::start()` and
+    /// `SystemCoordinator::verify_config()`. If not called, configuration
+    /// files are not checked.
+    pub fn with_config_verifier(mut self, verifier: ConfigVerifier, paths: Vec<PathBuf>) -> Self {
+        self.config_verifier = Some((verifier, paths));
+        self
+    }
+
+    /// Sets how long shutdown waits for components to answer
+    /// `prepare-shutdown`.
+    ///
+    /// If not called, defaults to `DEFAULT_DRAIN_DEADLINE` (5 seconds).
+    pub fn with_drain_deadline(mut self, deadline: Duration) -> Self {
+        self.drain_deadline = Some(deadline);
+        self
+    }
+
     /// Builds the SystemCoordinator with the configured dependencies.
     ///
     /// Consumes the builder and delegates to `SystemCoordinator::new()` to
@@ -153,14 +314,51 @@ where
     /// This method is infallible because all required dependencies are
     /// guaranteed to be present (enforced by the type system at `new()`).
     pub fn build(self) -> SystemCoordinator<E, L, V, A, B> {
-        SystemCoordinator::new(
+        let mut coordinator = SystemCoordinator::new(
             self.engine,
             self.loader,
             self.security_validator,
             self.audit_logger,
             self.actor_system_config,
             self.broker,
-        )
+        );
+        if let Some(capacity) = self.host_capacity {
+            coordinator.set_host_capacity(capacity);
+        }
+        if let Some(requests) = self.elevation_requests {
+            coordinator.set_elevation_requests(requests);
+        }
+        if let Some(metrics) = self.component_metrics {
+            coordinator.set_component_metrics(metrics);
+        }
+        if let Some(bus) = self.topic_bus {
+            coordinator.set_topic_bus(bus);
+        }
+        if let Some(compressor) = self.payload_compressor {
+            coordinator.set_payload_compressor(compressor);
+        }
+        if let Some(schemas) = self.schema_registry {
+            coordinator.set_schema_registry(schemas);
+        }
+        if let Some(guard) = self.content_type_guard {
+            coordinator.set_content_type_guard(guard);
+        }
+        if let Some(affinity) = self.affinity_router {
+            coordinator.set_affinity_router(affinity);
+        }
+        if let Some(shedder) = self.load_shedder {
+            coordinator.set_load_shedder(shedder);
+        }
+        if let Some(self_test) = self.self_test {
+            coordinator.set_self_test(self_test);
+        }
+        if let Some((verifier, paths)) = self.config_verifier {
+            coordinator.set_config_verifier(verifier, paths);
+        }
+        if let Some(deadline) = self.drain_deadline {
+            coordinator.set_drain_deadline(deadline);
+        }
+        coordinator
     }
 }
 
@@ -179,6 +377,10 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("SystemBuilder")
             .field("actor_system_config", &self.actor_system_config)
+            .field("host_capacity", &self.host_capacity)
+            .field("self_test", &self.self_test)
+            .field("config_verifier", &self.config_verifier)
+            .field("drain_deadline", &self.drain_deadline)
             .finish_non_exhaustive()
     }
 }
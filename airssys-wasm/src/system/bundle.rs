@@ -0,0 +1,734 @@
+//! # Offline Bundles - Air-Gapped Component Deployment
+//!
+//! Packages components on a connected machine and installs them on a host
+//! that has no network access.
+//!
+//! Workflow:
+//!
+//! 1. [`BundleBuilder`] collects component artifacts, their optional
+//!    `Component.toml`, and the components each one depends on, then signs
+//!    every artifact with the publisher's Ed25519 key.
+//! 2. [`Bundle::write`] stores the result as a single self-contained JSON
+//!    archive: artifacts, signatures, dependency graph and the publisher's
+//!    trust metadata.
+//! 3. [`BundleInstaller::install`] reads the archive on the target host and
+//!    verifies it entirely offline before writing anything: format version,
+//!    artifact digests, that the publisher key is one the host already
+//!    trusts, every signature, and that the dependency graph is complete and
+//!    acyclic. Components are then written in dependency order.
+//!
+//! Each signature covers a length-prefixed statement of every field the
+//! installer relies on: the format version, the trust metadata, the
+//! creation time and the whole entry (see [`Bundle::signed_statement`]).
+//!
+//! The trust metadata inside a bundle only names the signer; it never
+//! establishes trust by itself. The installer accepts a bundle only if its
+//! publisher key is among the host's configured trust anchors.
+//!
+//! This module is the engine behind a `bundle create|install` command;
+//! command-line wiring lives outside this crate.
+//!
+//! # References
+//!
+//! - ADR-WASM-032: System Module Design
+//! - ADR-WASM-023: Module Boundary Enforcement (Layer 4)
+
+// Layer 1: Standard library imports
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+use crate::security::config_signing::{
+    from_hex, to_hex, ConfigSigner, ConfigVerifier, SignatureMode,
+};
+
+/// Bundle format produced by this version of the host.
+pub const BUNDLE_FORMAT_VERSION: u32 = 2;
+
+/// File name of an installed component artifact.
+pub const ARTIFACT_FILE: &str = "component.wasm";
+
+/// File name of an installed component manifest.
+pub const MANIFEST_FILE: &str = "Component.toml";
+
+/// Domain separator at the start of every signed statement.
+const STATEMENT_DOMAIN: &[u8] = b"airssys-bundle-entry-v2";
+
+// ============================================================================
+// BundleError
+// ============================================================================
+
+/// Errors raised while creating, reading or installing a bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BundleError {
+    /// Filesystem access failed.
+    #[error("Bundle I/O error: {0}")]
+    Io(String),
+
+    /// The archive could not be parsed or serialized.
+    #[error("Invalid bundle format: {0}")]
+    Format(String),
+
+    /// The archive was produced by an incompatible host version.
+    #[error("Unsupported bundle format version {0}")]
+    UnsupportedVersion(u32),
+
+    /// Two entries share a component name.
+    #[error("Component '{0}' appears more than once in the bundle")]
+    DuplicateComponent(String),
+
+    /// A component depends on one that is not in the bundle.
+    #[error("Component '{component}' depends on '{dependency}', which is not in the bundle")]
+    MissingDependency {
+        /// Component declaring the dependency.
+        component: String,
+        /// Dependency that is absent.
+        dependency: String,
+    },
+
+    /// A component name or version is not a single plain path component.
+    #[error("'{0}' cannot be used as a component name or version")]
+    InvalidPathComponent(String),
+
+    /// The dependency graph contains a cycle through this component.
+    #[error("Dependency cycle through component '{0}'")]
+    DependencyCycle(String),
+
+    /// An artifact does not match its recorded digest.
+    #[error("Artifact of component '{0}' does not match its digest")]
+    DigestMismatch(String),
+
+    /// The bundle was signed by a key the host does not trust.
+    #[error("Bundle publisher '{0}' is not a trusted signer on this host")]
+    UntrustedPublisher(String),
+
+    /// An artifact signature does not verify.
+    #[error("Signature of component '{0}' is invalid")]
+    InvalidSignature(String),
+}
+
+// ============================================================================
+// Bundle contents
+// ============================================================================
+
+/// A component to package, as supplied to [`BundleBuilder`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleComponent {
+    /// Component name, unique within the bundle.
+    pub name: String,
+    /// Component version.
+    pub version: String,
+    /// Compiled component bytes.
+    pub artifact: Vec<u8>,
+    /// `Component.toml` contents, if the component has one.
+    pub manifest: Option<String>,
+    /// Names of components that must be installed first.
+    pub depends_on: Vec<String>,
+}
+
+impl BundleComponent {
+    /// Creates a component with no manifest and no dependencies.
+    pub fn new(name: impl Into<String>, version: impl Into<String>, artifact: Vec<u8>) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            artifact,
+            manifest: None,
+            depends_on: Vec::new(),
+        }
+    }
+
+    /// Attaches the component's `Component.toml`.
+    pub fn with_manifest(mut self, manifest: impl Into<String>) -> Self {
+        self.manifest = Some(manifest.into());
+        self
+    }
+
+    /// Declares a dependency on another component in the bundle.
+    pub fn with_dependency(mut self, name: impl Into<String>) -> Self {
+        self.depends_on.push(name.into());
+        self
+    }
+}
+
+/// Who signed a bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrustMetadata {
+    /// Publisher name, for operators and audit logs.
+    pub publisher: String,
+    /// Publisher's Ed25519 public key, lowercase hex.
+    pub public_key: String,
+}
+
+/// One packaged component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleEntry {
+    /// Component name.
+    pub name: String,
+    /// Component version.
+    pub version: String,
+    /// SHA-256 of the artifact, lowercase hex.
+    pub sha256: String,
+    /// Publisher signature over [`Bundle::signed_statement`], hex.
+    pub signature: String,
+    /// Names of components that must be installed first.
+    pub depends_on: Vec<String>,
+    /// `Component.toml` contents, if any.
+    pub manifest: Option<String>,
+    /// Artifact bytes, lowercase hex.
+    pub artifact: String,
+}
+
+impl BundleEntry {
+    /// Decodes the artifact bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BundleError::Format` if the artifact is not valid hex.
+    pub fn artifact_bytes(&self) -> Result<Vec<u8>, BundleError> {
+        from_hex(&self.artifact).ok_or_else(|| {
+            BundleError::Format(format!("Artifact of '{}' is not valid hex", self.name))
+        })
+    }
+}
+
+/// A self-contained offline bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bundle {
+    /// Format version ([`BUNDLE_FORMAT_VERSION`] when created).
+    pub format_version: u32,
+    /// When the bundle was created.
+    pub created_at: DateTime<Utc>,
+    /// Signer of every entry.
+    pub trust: TrustMetadata,
+    /// Packaged components, in the order they were added.
+    pub components: Vec<BundleEntry>,
+}
+
+impl Bundle {
+    /// Bytes covered by the signature of `entry`.
+    ///
+    /// Every field is length-prefixed, so no value can be shifted into its
+    /// neighbour. Binds the artifact digest to the name, version,
+    /// dependencies and manifest, and to the bundle's format version,
+    /// trust metadata and creation time, so an entry cannot be relabelled,
+    /// rewired, granted other capabilities or attributed to another
+    /// publisher without invalidating it.
+    pub fn signed_statement(&self, entry: &BundleEntry) -> Vec<u8> {
+        let mut out = Vec::new();
+        put_field(&mut out, STATEMENT_DOMAIN);
+        put_field(&mut out, &self.format_version.to_be_bytes());
+        put_field(&mut out, self.trust.publisher.as_bytes());
+        put_field(&mut out, self.trust.public_key.as_bytes());
+        put_field(&mut out, &self.created_at.timestamp().to_be_bytes());
+        put_field(
+            &mut out,
+            &self.created_at.timestamp_subsec_nanos().to_be_bytes(),
+        );
+        put_field(&mut out, entry.name.as_bytes());
+        put_field(&mut out, entry.version.as_bytes());
+        put_field(&mut out, entry.sha256.as_bytes());
+        put_field(&mut out, &(entry.depends_on.len() as u64).to_be_bytes());
+        for dep in &entry.depends_on {
+            put_field(&mut out, dep.as_bytes());
+        }
+        put_field(&mut out, &[u8::from(entry.manifest.is_some())]);
+        put_field(
+            &mut out,
+            entry.manifest.as_deref().unwrap_or_default().as_bytes(),
+        );
+        out
+    }
+
+    /// Serializes the bundle to its archive form.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BundleError::Format` if serialization fails.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, BundleError> {
+        serde_json::to_vec(self).map_err(|e| BundleError::Format(e.to_string()))
+    }
+
+    /// Parses an archive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BundleError::Format` if the archive is malformed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BundleError> {
+        serde_json::from_slice(bytes).map_err(|e| BundleError::Format(e.to_string()))
+    }
+
+    /// Writes the archive to `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BundleError::Io` if the file cannot be written.
+    pub fn write(&self, path: &Path) -> Result<(), BundleError> {
+        fs::write(path, self.to_bytes()?)
+            .map_err(|e| BundleError::Io(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Reads an archive from `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BundleError::Io` if the file cannot be read, or
+    /// `BundleError::Format` if it is malformed.
+    pub fn read(path: &Path) -> Result<Self, BundleError> {
+        let bytes =
+            fs::read(path).map_err(|e| BundleError::Io(format!("{}: {}", path.display(), e)))?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Component names in an order where every dependency precedes its
+    /// dependents.
+    ///
+    /// # Errors
+    ///
+    /// - `BundleError::InvalidPathComponent` if a name or version is not a
+    ///   single plain path component
+    /// - `BundleError::DuplicateComponent` if a name repeats
+    /// - `BundleError::MissingDependency` if a dependency is absent
+    /// - `BundleError::DependencyCycle` if the graph has a cycle
+    pub fn install_order(&self) -> Result<Vec<&BundleEntry>, BundleError> {
+        let mut by_name = BTreeMap::new();
+        for entry in &self.components {
+            check_path_component(&entry.name)?;
+            check_path_component(&entry.version)?;
+            if by_name.insert(entry.name.as_str(), entry).is_some() {
+                return Err(BundleError::DuplicateComponent(entry.name.clone()));
+            }
+        }
+        for entry in &self.components {
+            if let Some(missing) = entry
+                .depends_on
+                .iter()
+                .find(|dep| !by_name.contains_key(dep.as_str()))
+            {
+                return Err(BundleError::MissingDependency {
+                    component: entry.name.clone(),
+                    dependency: missing.clone(),
+                });
+            }
+        }
+
+        // Depth-first topological sort; `visiting` detects back edges
+        let mut order = Vec::with_capacity(self.components.len());
+        let mut done = BTreeSet::new();
+        let mut visiting = BTreeSet::new();
+        for entry in &self.components {
+            visit(entry, &by_name, &mut visiting, &mut done, &mut order)?;
+        }
+        Ok(order)
+    }
+}
+
+/// Appends `field` with a big-endian `u64` length prefix.
+fn put_field(out: &mut Vec<u8>, field: &[u8]) {
+    out.extend_from_slice(&(field.len() as u64).to_be_bytes());
+    out.extend_from_slice(field);
+}
+
+/// Accepts only values that stay one directory level below the install
+/// destination: no separators, no `.`/`..`, no roots or prefixes.
+fn check_path_component(value: &str) -> Result<(), BundleError> {
+    let mut components = Path::new(value).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(part)), None) if part == value => Ok(()),
+        _ => Err(BundleError::InvalidPathComponent(value.to_string())),
+    }
+}
+
+fn visit<'a>(
+    entry: &'a BundleEntry,
+    by_name: &BTreeMap<&str, &'a BundleEntry>,
+    visiting: &mut BTreeSet<&'a str>,
+    done: &mut BTreeSet<&'a str>,
+    order: &mut Vec<&'a BundleEntry>,
+) -> Result<(), BundleError> {
+    if done.contains(entry.name.as_str()) {
+        return Ok(());
+    }
+    if !visiting.insert(entry.name.as_str()) {
+        return Err(BundleError::DependencyCycle(entry.name.clone()));
+    }
+    for dep in &entry.depends_on {
+        if let Some(dep_entry) = by_name.get(dep.as_str()) {
+            visit(dep_entry, by_name, visiting, done, order)?;
+        }
+    }
+    visiting.remove(entry.name.as_str());
+    done.insert(entry.name.as_str());
+    order.push(entry);
+    Ok(())
+}
+
+// ============================================================================
+// BundleBuilder
+// ============================================================================
+
+/// Creates bundles on a connected machine (`bundle create`).
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::security::config_signing::ConfigSigner;
+/// use airssys_wasm::system::bundle::{BundleBuilder, BundleComponent, BundleInstaller};
+///
+/// let signer = ConfigSigner::from_bytes(&[7; 32]);
+/// let trusted = signer.public_key();
+///
+/// let bundle = BundleBuilder::new("acme-release", signer)
+///     .with_component(BundleComponent::new("codec", "1.0.0", vec![0x00, 0x61, 0x73, 0x6d]))
+///     .with_component(
+///         BundleComponent::new("gateway", "2.1.0", vec![0x00, 0x61, 0x73, 0x6d])
+///             .with_dependency("codec"),
+///     )
+///     .build()
+///     .unwrap();
+///
+/// let installer = BundleInstaller::new(vec![trusted]);
+/// let order = installer.verify(&bundle).unwrap();
+/// assert_eq!(order[0].name, "codec");
+/// ```
+#[derive(Debug)]
+pub struct BundleBuilder {
+    publisher: String,
+    signer: ConfigSigner,
+    components: Vec<BundleComponent>,
+}
+
+impl BundleBuilder {
+    /// Creates a builder signing as `publisher` with `signer`.
+    pub fn new(publisher: impl Into<String>, signer: ConfigSigner) -> Self {
+        Self {
+            publisher: publisher.into(),
+            signer,
+            components: Vec::new(),
+        }
+    }
+
+    /// Adds a component to the bundle.
+    pub fn with_component(mut self, component: BundleComponent) -> Self {
+        self.components.push(component);
+        self
+    }
+
+    /// Signs every component and assembles the bundle.
+    ///
+    /// # Errors
+    ///
+    /// Fails with the same errors as [`Bundle::install_order`], so a bundle
+    /// that could not be installed is never produced.
+    pub fn build(self) -> Result<Bundle, BundleError> {
+        let components = self
+            .components
+            .into_iter()
+            .map(|component| BundleEntry {
+                name: component.name,
+                version: component.version,
+                sha256: to_hex(&Sha256::digest(&component.artifact)),
+                signature: String::new(),
+                depends_on: component.depends_on,
+                manifest: component.manifest,
+                artifact: to_hex(&component.artifact),
+            })
+            .collect();
+
+        let mut bundle = Bundle {
+            format_version: BUNDLE_FORMAT_VERSION,
+            created_at: Utc::now(),
+            trust: TrustMetadata {
+                publisher: self.publisher,
+                public_key: to_hex(&self.signer.public_key()),
+            },
+            components,
+        };
+        bundle.install_order()?;
+        let signatures: Vec<String> = bundle
+            .components
+            .iter()
+            .map(|entry| self.signer.sign(&bundle.signed_statement(entry)))
+            .collect();
+        for (entry, signature) in bundle.components.iter_mut().zip(signatures) {
+            entry.signature = signature;
+        }
+        Ok(bundle)
+    }
+}
+
+// ============================================================================
+// BundleInstaller
+// ============================================================================
+
+/// A component written by [`BundleInstaller::install`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledComponent {
+    /// Component name.
+    pub name: String,
+    /// Component version.
+    pub version: String,
+    /// Directory holding the artifact and manifest.
+    pub path: PathBuf,
+}
+
+/// Verifies and installs bundles without network access
+/// (`bundle install`).
+#[derive(Debug, Clone)]
+pub struct BundleInstaller {
+    trusted_keys: Vec<[u8; 32]>,
+}
+
+impl BundleInstaller {
+    /// Creates an installer accepting bundles signed by any of
+    /// `trusted_keys`.
+    pub fn new(trusted_keys: Vec<[u8; 32]>) -> Self {
+        Self { trusted_keys }
+    }
+
+    /// Verifies `bundle` and returns its components in install order.
+    ///
+    /// Nothing outside the bundle and the configured keys is consulted.
+    ///
+    /// # Errors
+    ///
+    /// - `BundleError::UnsupportedVersion` for an unknown format version
+    /// - `BundleError::UntrustedPublisher` if the signer is not trusted
+    /// - `BundleError::DigestMismatch` / `InvalidSignature` for an entry
+    ///   or trust metadata that was altered
+    /// - any error of [`Bundle::install_order`]
+    pub fn verify<'a>(&self, bundle: &'a Bundle) -> Result<Vec<&'a BundleEntry>, BundleError> {
+        if bundle.format_version != BUNDLE_FORMAT_VERSION {
+            return Err(BundleError::UnsupportedVersion(bundle.format_version));
+        }
+
+        let untrusted = || BundleError::UntrustedPublisher(bundle.trust.publisher.clone());
+        let key = from_hex(&bundle.trust.public_key)
+            .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+            .filter(|key| self.trusted_keys.contains(key))
+            .ok_or_else(untrusted)?;
+        let verifier = ConfigVerifier::new(&key, SignatureMode::Strict).map_err(|_| untrusted())?;
+
+        for entry in &bundle.components {
+            let artifact = entry.artifact_bytes()?;
+            if to_hex(&Sha256::digest(&artifact)) != entry.sha256 {
+                return Err(BundleError::DigestMismatch(entry.name.clone()));
+            }
+            if !verifier.verify_bytes(&bundle.signed_statement(entry), &entry.signature) {
+                return Err(BundleError::InvalidSignature(entry.name.clone()));
+            }
+        }
+
+        bundle.install_order()
+    }
+
+    /// Verifies `bundle`, then writes each component to
+    /// `<dest>/<name>/<version>/` in dependency order.
+    ///
+    /// The artifact is written as [`ARTIFACT_FILE`] with its publisher
+    /// signature, and the manifest (if any) as [`MANIFEST_FILE`].
+    ///
+    /// # Errors
+    ///
+    /// Any error of [`verify`](Self::verify), in which case nothing is
+    /// written, or `BundleError::Io` if writing fails. Names and versions
+    /// that could escape `dest` fail verification.
+    pub fn install(
+        &self,
+        bundle: &Bundle,
+        dest: &Path,
+    ) -> Result<Vec<InstalledComponent>, BundleError> {
+        let order = self.verify(bundle)?;
+        let io =
+            |path: &Path, e: std::io::Error| BundleError::Io(format!("{}: {}", path.display(), e));
+
+        let mut installed = Vec::with_capacity(order.len());
+        for entry in order {
+            let dir = dest.join(&entry.name).join(&entry.version);
+            fs::create_dir_all(&dir).map_err(|e| io(&dir, e))?;
+
+            let artifact = dir.join(ARTIFACT_FILE);
+            fs::write(&artifact, entry.artifact_bytes()?).map_err(|e| io(&artifact, e))?;
+            let signature = dir.join(format!("{ARTIFACT_FILE}.sig"));
+            fs::write(&signature, &entry.signature).map_err(|e| io(&signature, e))?;
+            if let Some(manifest) = &entry.manifest {
+                let path = dir.join(MANIFEST_FILE);
+                fs::write(&path, manifest).map_err(|e| io(&path, e))?;
+            }
+
+            tracing::info!(
+                component = %entry.name,
+                version = %entry.version,
+                publisher = %bundle.trust.publisher,
+                "installed component from offline bundle"
+            );
+            installed.push(InstalledComponent {
+                name: entry.name.clone(),
+                version: entry.version.clone(),
+                path: dir,
+            });
+        }
+        Ok(installed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signer() -> ConfigSigner {
+        ConfigSigner::from_bytes(&[3; 32])
+    }
+
+    fn bundle() -> Bundle {
+        BundleBuilder::new("acme", signer())
+            .with_component(
+                BundleComponent::new("app", "1.0.0", b"app-bytes".to_vec())
+                    .with_dependency("lib")
+                    .with_manifest("[component]\nname = \"app\"\n"),
+            )
+            .with_component(BundleComponent::new("lib", "0.3.0", b"lib-bytes".to_vec()))
+            .build()
+            .unwrap()
+    }
+
+    fn installer() -> BundleInstaller {
+        BundleInstaller::new(vec![signer().public_key()])
+    }
+
+    #[test]
+    fn test_round_trip_and_install_in_dependency_order() {
+        let dir = std::env::temp_dir().join(format!("airssys-bundle-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let archive = dir.join("release.bundle");
+        bundle().write(&archive).unwrap();
+
+        let read = Bundle::read(&archive).unwrap();
+        let installed = installer().install(&read, &dir.join("components")).unwrap();
+        let names: Vec<_> = installed.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["lib", "app"]);
+
+        let app = &installed[1].path;
+        assert_eq!(fs::read(app.join(ARTIFACT_FILE)).unwrap(), b"app-bytes");
+        assert!(fs::read_to_string(app.join(MANIFEST_FILE))
+            .unwrap()
+            .contains("app"));
+        let verifier = ConfigVerifier::new(&signer().public_key(), SignatureMode::Strict).unwrap();
+        let signature = fs::read_to_string(app.join("component.wasm.sig")).unwrap();
+        assert!(verifier.verify_bytes(&read.signed_statement(&read.components[0]), &signature));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_untrusted_or_tampered_bundle_rejected() {
+        let other = BundleInstaller::new(vec![ConfigSigner::from_bytes(&[9; 32]).public_key()]);
+        assert_eq!(
+            other.verify(&bundle()).unwrap_err(),
+            BundleError::UntrustedPublisher("acme".to_string())
+        );
+
+        let mut altered = bundle();
+        altered.components[1].artifact = to_hex(b"evil-bytes");
+        assert_eq!(
+            installer().verify(&altered).unwrap_err(),
+            BundleError::DigestMismatch("lib".to_string())
+        );
+
+        let mut rewired = bundle();
+        rewired.components[0].depends_on.clear();
+        assert_eq!(
+            installer().verify(&rewired).unwrap_err(),
+            BundleError::InvalidSignature("app".to_string())
+        );
+
+        let mut regranted = bundle();
+        regranted.components[0].manifest = Some("[capabilities]\nnetwork = [\"*\"]\n".into());
+        assert_eq!(
+            installer().verify(&regranted).unwrap_err(),
+            BundleError::InvalidSignature("app".to_string())
+        );
+
+        let mut relabelled = bundle();
+        relabelled.trust.publisher = "trusted-vendor".to_string();
+        assert_eq!(
+            installer().verify(&relabelled).unwrap_err(),
+            BundleError::InvalidSignature("app".to_string())
+        );
+
+        let mut backdated = bundle();
+        backdated.created_at -= chrono::Duration::days(365);
+        assert_eq!(
+            installer().verify(&backdated).unwrap_err(),
+            BundleError::InvalidSignature("app".to_string())
+        );
+
+        // Dependency lists that joined to the same text are distinct
+        let original = bundle();
+        let mut joined = original.components[0].clone();
+        joined.depends_on = vec!["a,b".to_string()];
+        let mut split = joined.clone();
+        split.depends_on = vec!["a".to_string(), "b".to_string()];
+        assert_ne!(
+            original.signed_statement(&joined),
+            original.signed_statement(&split)
+        );
+
+        let mut future = bundle();
+        future.format_version = BUNDLE_FORMAT_VERSION + 1;
+        assert_eq!(
+            installer().verify(&future).unwrap_err(),
+            BundleError::UnsupportedVersion(BUNDLE_FORMAT_VERSION + 1)
+        );
+    }
+
+    #[test]
+    fn test_names_escaping_destination_rejected() {
+        for name in ["../evil", "a/b", "..", ".", "/etc", ""] {
+            let built = BundleBuilder::new("acme", signer())
+                .with_component(BundleComponent::new(name, "1.0.0", vec![1]))
+                .build();
+            assert_eq!(
+                built.unwrap_err(),
+                BundleError::InvalidPathComponent(name.to_string())
+            );
+        }
+
+        // A signed bundle whose version was rewritten is refused before
+        // anything is written
+        let dir = std::env::temp_dir().join(format!("airssys-bundle-{}", uuid::Uuid::new_v4()));
+        let mut escaping = bundle();
+        escaping.components[1].version = "../../outside".to_string();
+        assert!(installer().install(&escaping, &dir).is_err());
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_incomplete_or_cyclic_graph_rejected() {
+        let missing = BundleBuilder::new("acme", signer())
+            .with_component(BundleComponent::new("app", "1", vec![1]).with_dependency("lib"))
+            .build();
+        assert_eq!(
+            missing.unwrap_err(),
+            BundleError::MissingDependency {
+                component: "app".to_string(),
+                dependency: "lib".to_string()
+            }
+        );
+
+        let cyclic = BundleBuilder::new("acme", signer())
+            .with_component(BundleComponent::new("a", "1", vec![1]).with_dependency("b"))
+            .with_component(BundleComponent::new("b", "1", vec![2]).with_dependency("a"))
+            .build();
+        assert!(matches!(
+            cyclic.unwrap_err(),
+            BundleError::DependencyCycle(_)
+        ));
+    }
+}
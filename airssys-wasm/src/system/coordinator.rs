@@ -16,8 +16,9 @@
 //!
 //! # S6.2 Compliance
 //!
-//! Uses full static dispatch via generics `<E, L, V, A, B>`. Zero `dyn` usage
-//! in library code, consistent with the pattern used throughout the codebase.
+//! Uses full static dispatch via generics `<E, L, V, A, B>`, consistent with the
+//! pattern used throughout the codebase. The only `dyn` usage is the host plugin
+//! list (see [`super::plugin`]), which needs heterogeneous storage.
 //!
 //! # References
 //!
@@ -26,26 +27,63 @@
 //! - KNOWLEDGE-WASM-037: Dependency Inversion Principle
 
 // Layer 1: Standard library imports
+use std::collections::HashMap;
 use std::fmt;
-use std::sync::Arc;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock, Weak};
+use std::time::Duration;
 
 // Layer 2: Third-party crate imports
 use airssys_rt::broker::MessageBroker;
+use airssys_rt::message::MessageEnvelope;
 use airssys_rt::system::{ActorSystem, SystemConfig};
 use airssys_rt::SystemError as RtSystemError;
 use chrono::{DateTime, Utc};
 use thiserror::Error;
+use tokio::task::JoinHandle;
 
 // Layer 3: Internal module imports
+use crate::component::drain::{DrainReport, ShutdownDrain};
+use crate::component::live_config::LiveConfig;
 use crate::component::registry::{ComponentRegistry, RegistryError};
+use crate::component::requeue::{RequeueLedger, RequeuePolicy};
+use crate::component::shedding::LoadShedder;
 use crate::component::spawner::{ComponentSpawner, SpawnerError};
 use crate::component::wrapper::ComponentActorMessage;
 use crate::core::component::id::ComponentId;
+use crate::core::component::message::ComponentMessage;
+use crate::core::config::component::ComponentConfig;
+use crate::core::config::values::{ConfigUpdateError, ConfigValues};
+use crate::core::management::elevation::{ElevationRequest, ElevationRequests};
+use crate::core::management::errors::{ElevationError, EventLogError, LockdownError, MetricsError};
+use crate::core::management::event::HostEvent;
+use crate::core::management::lockdown::{HostLockdown, LockdownCommand, LockdownWindow};
+use crate::core::management::log::HostEventLog;
+use crate::core::management::metrics::{
+    ComponentMetrics, MetricSample, MetricValue, MetricsSnapshot,
+};
 use crate::core::messaging::errors::MessagingError as CoreMessagingError;
 use crate::core::runtime::traits::{ComponentLoader, RuntimeEngine};
 use crate::core::security::traits::{SecurityAuditLogger, SecurityValidator};
+use crate::core::storage::traits::ComponentStorage;
+use crate::messaging::affinity::AffinityRouter;
+use crate::messaging::codec::PayloadCompressor;
+use crate::messaging::content_type::ContentTypeGuard;
 use crate::messaging::correlation::CorrelationTrackerImpl;
+use crate::messaging::response_cache::ResponseCache;
+use crate::messaging::schema::SchemaRegistry;
+use crate::messaging::spool::MessageSpool;
 use crate::messaging::subscriber::ComponentSubscriber;
+use crate::messaging::topic::TopicBus;
+use crate::security::config_signing::{ConfigSigningError, ConfigVerifier, VerifiedConfig};
+use crate::security::lockdown::LockdownValidator;
+
+use super::deprecation::{DeprecatedCall, DeprecationTracker};
+use super::plugin::{HostPlugin, InterceptAction, PluginError, PluginMetric, PluginRegistry};
+use super::query::StateView;
+use super::reservation::{AdmissionController, HostCapacity, ReservationError};
+use super::selftest::{SelfTest, SelfTestReport};
+use super::top::ComponentSample;
 
 // ============================================================================
 // SystemError
@@ -85,6 +123,90 @@ pub enum SystemError {
     /// System initialization failed.
     #[error("Initialization failed: {0}")]
     InitializationFailed(String),
+
+    /// Host plugin registration or hook failed.
+    #[error("Plugin error: {0}")]
+    Plugin(#[source] PluginError),
+
+    /// Component was refused by host resource admission.
+    #[error("Reservation error: {0}")]
+    Reservation(#[source] ReservationError),
+
+    /// Live config update was invalid or rejected by the component.
+    #[error("Reconfigure failed: {0}")]
+    Reconfigure(#[source] ConfigUpdateError),
+
+    /// Host event log error.
+    #[error("Event log error: {0}")]
+    EventLog(#[source] EventLogError),
+
+    /// Capability elevation request could not be decided.
+    #[error("Elevation error: {0}")]
+    Elevation(#[source] ElevationError),
+
+    /// Host lockdown could not be engaged or lifted.
+    #[error("Lockdown error: {0}")]
+    Lockdown(#[source] LockdownError),
+
+    /// Component metrics could not be read.
+    #[error("Metrics error: {0}")]
+    Metrics(#[source] MetricsError),
+
+    /// One or more startup checks failed; the report lists every check.
+    #[error("Startup self-test failed: {0}")]
+    SelfTestFailed(SelfTestReport),
+
+    /// A signed configuration file is unsigned or was modified on disk.
+    #[error("Configuration signature error: {0}")]
+    ConfigSignature(#[source] ConfigSigningError),
+}
+
+impl From<ConfigSigningError> for SystemError {
+    fn from(err: ConfigSigningError) -> Self {
+        SystemError::ConfigSignature(err)
+    }
+}
+
+impl From<ElevationError> for SystemError {
+    fn from(err: ElevationError) -> Self {
+        SystemError::Elevation(err)
+    }
+}
+
+impl From<MetricsError> for SystemError {
+    fn from(err: MetricsError) -> Self {
+        SystemError::Metrics(err)
+    }
+}
+
+impl From<LockdownError> for SystemError {
+    fn from(err: LockdownError) -> Self {
+        SystemError::Lockdown(err)
+    }
+}
+
+impl From<EventLogError> for SystemError {
+    fn from(err: EventLogError) -> Self {
+        SystemError::EventLog(err)
+    }
+}
+
+impl From<ConfigUpdateError> for SystemError {
+    fn from(err: ConfigUpdateError) -> Self {
+        SystemError::Reconfigure(err)
+    }
+}
+
+impl From<ReservationError> for SystemError {
+    fn from(err: ReservationError) -> Self {
+        SystemError::Reservation(err)
+    }
+}
+
+impl From<PluginError> for SystemError {
+    fn from(err: PluginError) -> Self {
+        SystemError::Plugin(err)
+    }
 }
 
 impl From<SpawnerError> for SystemError {
@@ -115,6 +237,15 @@ impl From<RtSystemError> for SystemError {
 // SystemCoordinator
 // ============================================================================
 
+/// Default time `shutdown()` waits for components to answer `prepare-shutdown`.
+pub const DEFAULT_DRAIN_DEADLINE: Duration = Duration::from_secs(5);
+
+/// Default interval at which the message spool retries due messages.
+pub const DEFAULT_SPOOL_REDELIVERY_PERIOD: Duration = Duration::from_secs(1);
+
+/// Default interval at which dead letters are routed to their targets.
+pub const DEFAULT_DEAD_LETTER_ROUTING_PERIOD: Duration = Duration::from_secs(1);
+
 /// System coordinator - the composition root for airssys-wasm.
 ///
 /// Wires all dependencies together following the Dependency Inversion Principle.
@@ -154,15 +285,70 @@ where
     // Injected dependencies (static dispatch via generics, S6.2)
     engine: Arc<E>,
     loader: Arc<L>,
-    security_validator: Arc<V>,
     audit_logger: Arc<A>,
 
+    // Injected validator behind the host-wide read-only switch
+    security_validator: Arc<LockdownValidator<Arc<V>>>,
+
     // Internal components (created by coordinator)
     registry: Arc<ComponentRegistry>,
     spawner: ComponentSpawner<E, L>,
     subscriber: Arc<ComponentSubscriber>,
     correlation_tracker: Arc<CorrelationTrackerImpl>,
 
+    // Host extensions
+    plugins: PluginRegistry,
+
+    // Host resource admission (critical component headroom)
+    admission: Option<AdmissionController>,
+
+    // Live `[config]` state per loaded component
+    live_configs: RwLock<HashMap<ComponentId, Arc<LiveConfig>>>,
+
+    // Cached responses of components declared `pure`
+    response_cache: Arc<ResponseCache>,
+
+    // `prepare-shutdown` answers from every spawned component
+    shutdown_drain: Arc<ShutdownDrain>,
+    drain_deadline: Duration,
+
+    // Broker handle for host-to-actor control messages
+    broker: B,
+
+    // Append-only history of host state changes
+    event_log: Arc<HostEventLog>,
+
+    // Deprecated exports and the components still calling them
+    deprecations: Arc<DeprecationTracker>,
+
+    // Capability requests filed by running components
+    elevations: Arc<ElevationRequests>,
+
+    // Counters, gauges and histograms emitted by components
+    component_metrics: Arc<ComponentMetrics>,
+
+    // Topic broadcast whose per-topic delivery counters are reported
+    topic_bus: Option<Arc<TopicBus>>,
+
+    // Deadline-aware shedding of queued messages
+    load_shedder: Option<Arc<LoadShedder>>,
+
+    // Requeue of messages that crashed a component
+    requeue: Option<(RequeuePolicy, Arc<RequeueLedger>)>,
+    dead_letter_routing_period: Duration,
+    dead_letter_routing: Option<JoinHandle<()>>,
+
+    // Undelivered messages and the storage they survive restarts in
+    spool: Option<(Arc<MessageSpool>, Arc<dyn ComponentStorage>)>,
+    spool_redelivery_period: Duration,
+    spool_redelivery: Option<JoinHandle<()>>,
+
+    // Sanity checks run by start()
+    self_test: SelfTest,
+
+    // Operator-signed config files, checked on start and reload
+    config_verifier: Option<(ConfigVerifier, Vec<PathBuf>)>,
+
     // Actor system (from airssys-rt)
     actor_system: ActorSystem<ComponentActorMessage, B>,
 
@@ -193,7 +379,8 @@ where
     ///
     /// * `engine` - WASM runtime engine
     /// * `loader` - Component binary loader
-    /// * `security_validator` - Capability validator
+    /// * `security_validator` - Capability validator, wrapped so it also
+    ///   enforces the coordinator's lockdown
     /// * `audit_logger` - Security audit logger
     /// * `actor_system_config` - Configuration for the airssys-rt ActorSystem
     /// * `broker` - Message broker for the actor system
@@ -211,14 +398,21 @@ where
         let correlation_tracker = Arc::new(CorrelationTrackerImpl::new());
 
         // Create actor system (ready immediately, no start() needed)
-        let actor_system = ActorSystem::new(actor_system_config, broker);
+        let actor_system = ActorSystem::new(actor_system_config, broker.clone());
 
         // Create spawner with same generic types (static dispatch)
+        let shutdown_drain = Arc::new(ShutdownDrain::new());
         let spawner = ComponentSpawner::new(
             Arc::clone(&engine),
             Arc::clone(&loader),
             Arc::clone(&registry),
-        );
+        )
+        .with_shutdown_drain(Arc::clone(&shutdown_drain));
+
+        let security_validator = Arc::new(LockdownValidator::new(
+            security_validator,
+            Arc::new(HostLockdown::new()),
+        ));
 
         Self {
             engine,
@@ -229,6 +423,27 @@ where
             spawner,
             subscriber,
             correlation_tracker,
+            plugins: PluginRegistry::new(),
+            admission: None,
+            live_configs: RwLock::new(HashMap::new()),
+            response_cache: Arc::new(ResponseCache::default()),
+            shutdown_drain,
+            drain_deadline: DEFAULT_DRAIN_DEADLINE,
+            broker,
+            event_log: Arc::new(HostEventLog::new()),
+            deprecations: Arc::new(DeprecationTracker::new()),
+            elevations: Arc::new(ElevationRequests::new()),
+            component_metrics: Arc::new(ComponentMetrics::new()),
+            topic_bus: None,
+            load_shedder: None,
+            requeue: None,
+            dead_letter_routing_period: DEFAULT_DEAD_LETTER_ROUTING_PERIOD,
+            dead_letter_routing: None,
+            spool: None,
+            spool_redelivery_period: DEFAULT_SPOOL_REDELIVERY_PERIOD,
+            spool_redelivery: None,
+            self_test: SelfTest::new(),
+            config_verifier: None,
             actor_system,
             is_running: false,
             is_shutdown: false,
@@ -251,6 +466,14 @@ where
     ///
     /// - `SystemError::AlreadyShutDown` if the system was previously shut down.
     /// - `SystemError::AlreadyRunning` if the system is already started.
+    /// - `SystemError::SelfTestFailed` if any startup check fails; the
+    ///   system stays stopped.
+    /// - `SystemError::ConfigSignature` if a signed configuration file is
+    ///   refused; the system stays stopped.
+    /// - `SystemError::Messaging` if the message spool cannot be restored;
+    ///   the system stays stopped.
+    /// - `SystemError::Plugin` if a plugin's `on_start` hook fails; the
+    ///   system stays stopped.
     pub fn start(&mut self) -> Result<(), SystemError> {
         if self.is_shutdown {
             return Err(SystemError::AlreadyShutDown);
@@ -259,6 +482,18 @@ where
             return Err(SystemError::AlreadyRunning);
         }
 
+        let report = self.self_test.run();
+        if !report.is_ok() {
+            return Err(SystemError::SelfTestFailed(report));
+        }
+        self.verify_config()?;
+        self.restore_spool()?;
+        self.plugins.start_all()?;
+
+        // Background tasks start only once nothing else can fail
+        self.spool_redelivery = self.spawn_spool_redelivery();
+        self.dead_letter_routing = self.spawn_dead_letter_routing();
+
         self.is_running = true;
         self.started_at = Some(Utc::now());
         Ok(())
@@ -266,21 +501,29 @@ where
 
     /// Gracefully shutdown the system coordinator.
     ///
-    /// Stops all registered components, cleans up subscriber mailboxes,
-    /// and shuts down the underlying actor system.
+    /// Sends every registered component a `prepare-shutdown` notification
+    /// and waits up to the drain deadline for their answers, then stops all
+    /// components, cleans up subscriber mailboxes, persists the message
+    /// spool, and shuts down the underlying actor system.
     ///
     /// If the system is not running, this is a no-op (returns Ok).
     ///
     /// # Errors
     ///
-    /// Returns `SystemError::ShutdownFailed` if the actor system shutdown fails.
+    /// - `SystemError::ShutdownFailed` if the actor system shutdown fails.
+    /// - `SystemError::Messaging` if the message spool could not be
+    ///   persisted; the rest of the shutdown still completes.
     pub async fn shutdown(&mut self) -> Result<(), SystemError> {
         if !self.is_running {
             return Ok(());
         }
 
-        // Step 1: Get list of all registered components
+        // Step 1: Get list of all registered components and let them drain
         let component_ids = self.registry.list()?;
+        let report = self
+            .drain_components(&component_ids, self.drain_deadline)
+            .await?;
+        log_drain_report(&report);
 
         // Step 2: Stop each component (best-effort, count failures)
         let mut failed_stops: usize = 0;
@@ -291,13 +534,30 @@ where
             let _ = self.subscriber.unregister_mailbox(id);
         }
 
-        // Step 3: Shutdown the actor system
+        // Step 3: Save what mailboxes no longer accept for the next start
+        for task in [
+            self.spool_redelivery.take(),
+            self.dead_letter_routing.take(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            task.abort();
+        }
+        let persisted = self.persist_spool();
+
+        // Step 4: Notify plugins (best-effort, reverse registration order)
+        for err in self.plugins.shutdown_all() {
+            tracing::warn!(error = %err, "plugin shutdown hook failed");
+        }
+
+        // Step 5: Shutdown the actor system
         self.actor_system.shutdown().await?;
 
         self.is_running = false;
         self.is_shutdown = true;
         self.shutdown_failed_stops = failed_stops;
-        Ok(())
+        persisted
     }
 
     // ========================================================================
@@ -306,9 +566,8 @@ where
 
     /// Load and spawn a component in the actor system.
     ///
-    /// Delegates to ComponentSpawner to load the WASM binary, create
-    /// a ComponentWrapper actor, spawn it in the ActorSystem, and
-    /// register it in the ComponentRegistry.
+    /// Equivalent to [`load_component_with_config`](Self::load_component_with_config)
+    /// with a default (non-critical) `ComponentConfig`.
     ///
     /// # Arguments
     ///
@@ -317,13 +576,100 @@ where
     /// # Errors
     ///
     /// - `SystemError::NotRunning` if the system has not been started
+    /// - `SystemError::Reservation` if host admission refuses the component
     /// - `SystemError::ComponentError` if spawning fails (load, validation, etc.)
     pub async fn load_component(&self, id: ComponentId) -> Result<(), SystemError> {
+        self.load_component_with_config(&ComponentConfig::new(id))
+            .await
+    }
+
+    /// Load and spawn a component using its manifest configuration.
+    ///
+    /// When host capacity is configured, the component is first admitted
+    /// against it: admission fails if starting the component would eat into
+    /// headroom reserved for a critical component. The admission is rolled
+    /// back if spawning fails.
+    ///
+    /// Delegates to ComponentSpawner to load the WASM binary, create
+    /// a ComponentWrapper actor, spawn it in the ActorSystem, and
+    /// register it in the ComponentRegistry.
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::NotRunning` if the system has not been started
+    /// - `SystemError::Reservation` if host admission refuses the component
+    /// - `SystemError::ComponentError` if spawning fails (load, validation, etc.)
+    pub async fn load_component_with_config(
+        &self,
+        config: &ComponentConfig,
+    ) -> Result<(), SystemError> {
         if !self.is_running {
             return Err(SystemError::NotRunning);
         }
 
-        self.spawner.spawn(&self.actor_system, id).await?;
+        let id = config.id();
+        if let Some(admission) = &self.admission {
+            admission.admit(config)?;
+        }
+
+        // Approved elevations must reach enforcement before the instance
+        // is created, so its first host call already sees them
+        let elevated = self.elevations.apply_approved(id)?;
+        for request in &elevated {
+            self.grant_elevated(id, &request.capability)?;
+        }
+
+        let live_config = Arc::new(LiveConfig::new(config.config_values().clone()));
+        if let Err(err) = self
+            .spawner
+            .spawn_with_live_config(&self.actor_system, id.clone(), Arc::clone(&live_config))
+            .await
+        {
+            if let Some(admission) = &self.admission {
+                admission.release(id)?;
+            }
+            return Err(err.into());
+        }
+        self.live_configs_mut()?.insert(id.clone(), live_config);
+        // A (re)load may bring new code: start from an empty cache
+        if config.is_pure() {
+            self.response_cache.enable(id, None)?;
+        } else {
+            self.response_cache.disable(id)?;
+        }
+        if let Some(guard) = self.subscriber.content_type_guard() {
+            match config.accepted_content_types() {
+                Some(accepted) => guard.register(id.clone(), accepted.clone())?,
+                None => {
+                    guard.remove_component(id)?;
+                }
+            }
+        }
+        if let Some(affinity) = self.subscriber.affinity_router() {
+            affinity.add_instance(id.clone())?;
+        }
+        if let Some(shedder) = &self.load_shedder {
+            match config.shed_policy() {
+                Some(policy) => shedder.set_policy(id.clone(), policy.clone()),
+                None => {
+                    shedder.remove_component(id);
+                }
+            }
+        }
+        self.flush_spool()?;
+        self.event_log.append(HostEvent::ComponentSpawned {
+            component: id.clone(),
+        })?;
+        for request in elevated {
+            self.event_log.append(HostEvent::CapabilityGranted {
+                component: id.clone(),
+                capability: request.capability,
+            })?;
+        }
+
+        for err in self.plugins.component_loaded(id) {
+            tracing::warn!(component = %id.to_string_id(), error = %err, "plugin load hook failed");
+        }
         Ok(())
     }
 
@@ -348,601 +694,2608 @@ where
         // Step 1: Unregister from spawner/registry
         self.spawner.stop(id)?;
 
-        // Step 2: Clean up subscriber mailbox (best-effort)
+        // Step 2: Return admitted resources (critical reservations are kept)
+        if let Some(admission) = &self.admission {
+            admission.release(id)?;
+        }
+        self.live_configs_mut()?.remove(id);
+        self.response_cache.disable(id)?;
+        self.component_metrics.remove(id)?;
+        self.event_log.append(HostEvent::ComponentStopped {
+            component: id.clone(),
+        })?;
+
+        // Step 3: Clean up subscriber mailbox, schemas, content types, pool membership and shedding (best-effort)
         let _ = self.subscriber.unregister_mailbox(id);
+        if let Some(schemas) = self.subscriber.schema_registry() {
+            let _ = schemas.remove_component(id);
+        }
+        if let Some(guard) = self.subscriber.content_type_guard() {
+            let _ = guard.remove_component(id);
+        }
+        if let Some(affinity) = self.subscriber.affinity_router() {
+            let _ = affinity.remove_instance(id);
+        }
+        if let Some(shedder) = &self.load_shedder {
+            shedder.remove_component(id);
+        }
+
+        // Step 4: Notify plugins (best-effort)
+        for err in self.plugins.component_unloaded(id) {
+            tracing::warn!(component = %id.to_string_id(), error = %err, "plugin unload hook failed");
+        }
 
         Ok(())
     }
 
+    /// Drain a component, then unload it.
+    ///
+    /// The component gets a `prepare-shutdown` notification and up to
+    /// `deadline` to answer; it is unloaded on its answer or when the
+    /// deadline expires.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`unload_component`](Self::unload_component), plus
+    /// `SystemError::Messaging` if the notification cannot be delivered.
+    pub async fn stop_component(
+        &self,
+        id: &ComponentId,
+        deadline: Duration,
+    ) -> Result<DrainReport, SystemError> {
+        if !self.is_running {
+            return Err(SystemError::NotRunning);
+        }
+        let report = self
+            .drain_components(std::slice::from_ref(id), deadline)
+            .await?;
+        log_drain_report(&report);
+        self.unload_component(id)?;
+        Ok(report)
+    }
+
+    /// Send `prepare-shutdown` to `ids` and wait for their answers.
+    ///
+    /// Returns once every component answered or `deadline` expired.
+    /// Components that are not loaded are reported as timed out.
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::Registry` if the registry cannot be read
+    /// - `SystemError::Messaging` if a notification cannot be delivered
+    pub async fn drain_components(
+        &self,
+        ids: &[ComponentId],
+        deadline: Duration,
+    ) -> Result<DrainReport, SystemError> {
+        if ids.is_empty() {
+            return Ok(DrainReport::default());
+        }
+        let round = self.shutdown_drain.begin(ids);
+        let deadline_ms = u64::try_from(deadline.as_millis()).unwrap_or(u64::MAX);
+
+        for id in ids {
+            let Some(address) = self.registry.get(id)? else {
+                continue;
+            };
+            let message = ComponentActorMessage::PrepareShutdown { round, deadline_ms };
+            self.broker
+                .publish(MessageEnvelope::new(message).with_reply_to(address))
+                .await
+                .map_err(|e| {
+                    SystemError::Messaging(CoreMessagingError::DeliveryFailed(e.to_string()))
+                })?;
+        }
+
+        Ok(self.shutdown_drain.wait(round, deadline).await)
+    }
+
+    /// Set how long `shutdown()` waits for components to drain.
+    pub fn set_drain_deadline(&mut self, deadline: Duration) {
+        self.drain_deadline = deadline;
+    }
+
+    /// How long `shutdown()` waits for components to drain.
+    pub fn drain_deadline(&self) -> Duration {
+        self.drain_deadline
+    }
+
     // ========================================================================
-    // Accessor Methods
+    // Live Config
     // ========================================================================
 
-    /// Returns a reference to the runtime engine.
-    pub fn engine(&self) -> &Arc<E> {
-        &self.engine
-    }
+    /// Push updated `[config]` values to a running component.
+    ///
+    /// The update is validated against the component's declared values
+    /// first, then delivered to its `reconfigure` export. Returns once the
+    /// component accepts the update; on rejection the component keeps its
+    /// previous values and keeps running.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The component to reconfigure
+    /// * `update` - Values to change (keys must be declared in `[config]`)
+    /// * `timeout` - How long to wait for the component's answer
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::NotRunning` if the system has not been started
+    /// - `SystemError::ComponentSpawner` if the component is not loaded
+    /// - `SystemError::Reconfigure` if the update is invalid, rejected, or
+    ///   not acknowledged within `timeout`
+    pub async fn reconfigure_component(
+        &self,
+        id: &ComponentId,
+        update: ConfigValues,
+        timeout: Duration,
+    ) -> Result<(), SystemError> {
+        if !self.is_running {
+            return Err(SystemError::NotRunning);
+        }
 
-    /// Returns a reference to the component loader.
-    pub fn loader(&self) -> &Arc<L> {
-        &self.loader
+        let not_loaded = || SystemError::ComponentSpawner(SpawnerError::NotSpawned(id.to_string()));
+        let live_config = self
+            .live_configs()?
+            .get(id)
+            .cloned()
+            .ok_or_else(not_loaded)?;
+        let address = self.registry.get(id)?.ok_or_else(not_loaded)?;
+
+        let generation = live_config.begin(&update)?;
+        let message = ComponentActorMessage::Reconfigure {
+            generation,
+            values: update.clone(),
+        };
+        self.broker
+            .publish(MessageEnvelope::new(message).with_reply_to(address))
+            .await
+            .map_err(|e| {
+                SystemError::Messaging(CoreMessagingError::DeliveryFailed(e.to_string()))
+            })?;
+
+        live_config.wait_for(generation, timeout).await?;
+        self.response_cache.invalidate(id)?;
+        self.event_log.append(HostEvent::ConfigChanged {
+            component: id.clone(),
+            values: update,
+        })?;
+        Ok(())
     }
 
-    /// Returns a reference to the security validator.
-    pub fn security_validator(&self) -> &Arc<V> {
-        &self.security_validator
+    // ========================================================================
+    // Response Cache
+    // ========================================================================
+
+    /// Share `cache` with the routers that answer requests from it.
+    ///
+    /// Replaces the coordinator's own cache; call before loading
+    /// components.
+    pub fn set_response_cache(&mut self, cache: Arc<ResponseCache>) {
+        self.response_cache = cache;
     }
 
-    /// Returns a reference to the audit logger.
-    pub fn audit_logger(&self) -> &Arc<A> {
-        &self.audit_logger
+    /// Get the response cache.
+    ///
+    /// Caching is enabled for components loaded with a `pure` config and
+    /// everything cached for a component is dropped when it is reloaded,
+    /// reconfigured, or unloaded.
+    pub fn response_cache(&self) -> &Arc<ResponseCache> {
+        &self.response_cache
     }
 
-    /// Returns a reference to the component registry.
-    pub fn registry(&self) -> &Arc<ComponentRegistry> {
-        &self.registry
+    // ========================================================================
+    // Event Log
+    // ========================================================================
+
+    /// Get the host event log.
+    ///
+    /// Spawns, stops, and accepted config changes are recorded
+    /// automatically. Hosts record installs and capability grants with
+    /// [`record_event`](Self::record_event).
+    pub fn event_log(&self) -> &Arc<HostEventLog> {
+        &self.event_log
     }
 
-    /// Returns a reference to the component subscriber.
-    pub fn subscriber(&self) -> &Arc<ComponentSubscriber> {
-        &self.subscriber
+    /// Append a host state change to the event log.
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::EventLog` if the event cannot be recorded
+    pub fn record_event(&self, event: HostEvent) -> Result<u64, SystemError> {
+        Ok(self.event_log.append(event)?)
     }
 
-    /// Returns a reference to the correlation tracker.
-    pub fn correlation_tracker(&self) -> &Arc<CorrelationTrackerImpl> {
-        &self.correlation_tracker
+    // ========================================================================
+    // Deprecations
+    // ========================================================================
+
+    /// Get the deprecation tracker.
+    ///
+    /// Hosts declare each component's deprecated exports (from its
+    /// manifest and WIT) when loading it; the tracker's report lists the
+    /// callers that must migrate.
+    pub fn deprecations(&self) -> &Arc<DeprecationTracker> {
+        &self.deprecations
     }
 
-    /// Returns whether the system is currently running.
-    pub fn is_running(&self) -> bool {
-        self.is_running
+    /// Record that `caller` invoked `export` on `component`.
+    ///
+    /// The first call of a deprecated export by a caller is appended to
+    /// the event log as `HostEvent::DeprecatedExportCalled`.
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::EventLog` if the event cannot be recorded
+    pub fn record_export_call(
+        &self,
+        caller: &ComponentId,
+        component: &ComponentId,
+        export: &str,
+    ) -> Result<DeprecatedCall, SystemError> {
+        let call = self
+            .deprecations
+            .record_call(caller, component, export, Utc::now());
+        if let DeprecatedCall::FirstCall(_) = &call {
+            self.event_log.append(HostEvent::DeprecatedExportCalled {
+                component: component.clone(),
+                export: export.to_string(),
+                caller: caller.clone(),
+            })?;
+        }
+        Ok(call)
     }
 
-    /// Returns when the coordinator was created.
-    pub fn created_at(&self) -> DateTime<Utc> {
-        self.created_at
+    /// Get the `[config]` values currently applied by a component.
+    ///
+    /// Returns `None` if the component is not loaded.
+    pub fn component_config_values(&self, id: &ComponentId) -> Option<ConfigValues> {
+        self.live_configs
+            .read()
+            .ok()?
+            .get(id)
+            .map(|live_config| live_config.applied())
     }
 
-    /// Returns when the coordinator was started, if it has been started.
-    pub fn started_at(&self) -> Option<DateTime<Utc>> {
-        self.started_at
+    fn live_configs(
+        &self,
+    ) -> Result<std::sync::RwLockReadGuard<'_, HashMap<ComponentId, Arc<LiveConfig>>>, SystemError>
+    {
+        self.live_configs
+            .read()
+            .map_err(|e| SystemError::InitializationFailed(format!("lock poisoned: {}", e)))
     }
 
-    /// Returns the number of component stops that failed during the last shutdown.
-    pub fn shutdown_failed_stops(&self) -> usize {
-        self.shutdown_failed_stops
+    fn live_configs_mut(
+        &self,
+    ) -> Result<std::sync::RwLockWriteGuard<'_, HashMap<ComponentId, Arc<LiveConfig>>>, SystemError>
+    {
+        self.live_configs
+            .write()
+            .map_err(|e| SystemError::InitializationFailed(format!("lock poisoned: {}", e)))
     }
 
-    /// Returns the number of currently registered components.
-    pub fn component_count(&self) -> Result<usize, SystemError> {
-        Ok(self.registry.count()?)
+    // ========================================================================
+    // Resource Reservation
+    // ========================================================================
+
+    /// Enable host resource admission with the given capacity.
+    ///
+    /// Replaces any existing admission state, so call this before loading
+    /// components.
+    pub fn set_host_capacity(&mut self, capacity: HostCapacity) {
+        self.admission = Some(AdmissionController::new(capacity));
     }
-}
 
-// ============================================================================
-// Debug Implementation
-// ============================================================================
+    /// Reserve host headroom for a component marked `critical`.
+    ///
+    /// Call this for every critical component when the host reads its
+    /// manifests, before starting anything, so lower-priority components
+    /// cannot consume the reserved resources.
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::InitializationFailed` if no host capacity is configured
+    /// - `SystemError::Reservation` if the component is not critical or the
+    ///   reservation does not fit
+    pub fn reserve_critical(&self, config: &ComponentConfig) -> Result<(), SystemError> {
+        let admission = self.admission.as_ref().ok_or_else(|| {
+            SystemError::InitializationFailed("host capacity not configured".to_string())
+        })?;
+        admission.reserve(config)?;
+        Ok(())
+    }
 
-impl<E, L, V, A, B> fmt::Debug for SystemCoordinator<E, L, V, A, B>
-where
-    E: RuntimeEngine + 'static,
-    L: ComponentLoader + 'static,
-    V: SecurityValidator,
-    A: SecurityAuditLogger,
-    B: MessageBroker<ComponentActorMessage> + Clone + Send + Sync + 'static,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("SystemCoordinator")
-            .field("is_running", &self.is_running)
-            .field("is_shutdown", &self.is_shutdown)
-            .field("created_at", &self.created_at)
-            .field("started_at", &self.started_at)
-            .field("registry", &self.registry)
-            .field("subscriber", &self.subscriber)
-            .finish_non_exhaustive()
+    /// Get the host admission controller, if capacity is configured.
+    pub fn admission(&self) -> Option<&AdmissionController> {
+        self.admission.as_ref()
     }
-}
 
-// ============================================================================
-// Tests
-// ============================================================================
+    // ========================================================================
+    // Signed Configuration
+    // ========================================================================
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Require operator signatures on `paths` (e.g. `Host.toml` and the
+    /// exported component registry).
+    ///
+    /// The files are verified by [`start`](Self::start) and by every
+    /// [`verify_config`](Self::verify_config) call.
+    pub fn set_config_verifier(&mut self, verifier: ConfigVerifier, paths: Vec<PathBuf>) {
+        self.config_verifier = Some((verifier, paths));
+    }
 
-    use airssys_rt::broker::InMemoryMessageBroker;
+    /// Verify the signed configuration files.
+    ///
+    /// Call before applying a reloaded configuration and parse the returned
+    /// contents, not the files on disk, so edits made after verification
+    /// are never applied. Returns one entry per file, or an empty list if
+    /// no verifier is set.
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::ConfigSignature` if a file cannot be read, or is
+    ///   unsigned or tampered and the verifier is in strict mode
+    pub fn verify_config(&self) -> Result<Vec<VerifiedConfig>, SystemError> {
+        match &self.config_verifier {
+            Some((verifier, paths)) => Ok(verifier.verify_files(paths)?),
+            None => Ok(Vec::new()),
+        }
+    }
 
-    use crate::core::component::handle::ComponentHandle;
-    use crate::core::component::message::{ComponentMessage, MessagePayload};
-    use crate::core::runtime::errors::WasmError;
-    use crate::core::security::capability::Capability;
-    use crate::core::security::errors::SecurityError;
-    use crate::core::security::traits::SecurityEvent;
+    // ========================================================================
+    // Startup Self-Test
+    // ========================================================================
+
+    /// Set the checks [`start`](Self::start) runs before starting.
+    pub fn set_self_test(&mut self, self_test: SelfTest) {
+        self.self_test = self_test;
+    }
+
+    /// Run the startup checks without starting, e.g. for a dry run.
+    pub fn run_self_test(&self) -> SelfTestReport {
+        self.self_test.run()
+    }
+
+    // ========================================================================
+    // Diagnostics
+    // ========================================================================
+
+    /// Snapshot host state for read-only SQL queries.
+    ///
+    /// Components and capabilities come from replaying the event log,
+    /// in-flight messages from the correlation tracker, and metrics from
+    /// `samples` collected by the caller (as for the `top` view).
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::EventLog` if the event log cannot be replayed
+    /// - `SystemError::Messaging` if pending requests cannot be listed
+    pub fn state_view(&self, samples: Vec<ComponentSample>) -> Result<StateView, SystemError> {
+        Ok(StateView::new()
+            .with_host_state(&self.event_log.replay()?)
+            .with_in_flight(self.correlation_tracker.pending_requests()?)
+            .with_metrics(samples))
+    }
+
+    // ========================================================================
+    // Capability Elevation
+    // ========================================================================
+
+    /// Share `requests` with the runtime engine so components' elevation
+    /// requests land where the operator can decide them.
+    ///
+    /// Replaces the coordinator's own registry; call before loading
+    /// components.
+    pub fn set_elevation_requests(&mut self, requests: Arc<ElevationRequests>) {
+        self.elevations = requests;
+    }
+
+    /// Get the capability elevation requests filed by components.
+    pub fn elevation_requests(&self) -> &Arc<ElevationRequests> {
+        &self.elevations
+    }
+
+    /// Approve an elevation request on behalf of `operator`.
+    ///
+    /// The running component is unaffected: the capability is granted to
+    /// the security validator and engine (and recorded as
+    /// `CapabilityGranted` in the event log) the next time the component is
+    /// loaded.
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::Elevation` if the request is unknown or already decided
+    pub fn approve_elevation(
+        &self,
+        id: u64,
+        operator: &str,
+    ) -> Result<ElevationRequest, SystemError> {
+        Ok(self.elevations.approve(id, operator)?)
+    }
+
+    /// Deny an elevation request on behalf of `operator`.
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::Elevation` if the request is unknown or already decided
+    pub fn deny_elevation(
+        &self,
+        id: u64,
+        operator: &str,
+        note: &str,
+    ) -> Result<ElevationRequest, SystemError> {
+        Ok(self.elevations.deny(id, operator, note)?)
+    }
+
+    /// Grant an approved elevation to both enforcement points: the
+    /// security validator (host calls) and the engine (storage grants).
+    fn grant_elevated(&self, id: &ComponentId, capability: &str) -> Result<(), SystemError> {
+        let not_enforced = |reason: String| ElevationError::NotEnforced {
+            capability: capability.to_string(),
+            reason,
+        };
+        self.security_validator
+            .grant_elevated(id, capability)
+            .map_err(|e| not_enforced(e.to_string()))?;
+        self.engine
+            .grant_elevated(id, capability)
+            .map_err(|e| not_enforced(e.to_string()))?;
+        Ok(())
+    }
+
+    // ========================================================================
+    // Lockdown
+    // ========================================================================
+
+    /// Replace the coordinator's lockdown switch with `lockdown`, e.g. to
+    /// share it with the engine's guest storage
+    /// (`WasmtimeEngine::with_lockdown`) or an egress proxy
+    /// (`EgressProxy::with_lockdown`).
+    ///
+    /// Rewraps the security validator around the new switch; call before
+    /// handing out [`security_validator`](Self::security_validator).
+    pub fn set_lockdown(&mut self, lockdown: Arc<HostLockdown>) {
+        let inner = Arc::clone(self.security_validator.inner());
+        self.security_validator = Arc::new(LockdownValidator::new(inner, lockdown));
+    }
+
+    /// Get the host lockdown switch and its window history.
+    pub fn lockdown(&self) -> &Arc<HostLockdown> {
+        self.security_validator.lockdown()
+    }
+
+    /// Deny all write-class capabilities across every component until
+    /// [`lift_lockdown`](Self::lift_lockdown) is called.
+    ///
+    /// Running components are not stopped; their writes fail with a
+    /// permission denial and are recorded in the lockdown window.
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::Lockdown` if a lockdown is already engaged
+    pub fn engage_lockdown(
+        &self,
+        operator: &str,
+        reason: &str,
+    ) -> Result<LockdownWindow, SystemError> {
+        let window = self.lockdown().engage(operator, reason)?;
+        tracing::warn!(operator = %operator, reason = %reason, "host lockdown engaged");
+        Ok(window)
+    }
+
+    /// Lift the lockdown and return the closed window with every request
+    /// refused while it was open.
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::Lockdown` if no lockdown is engaged
+    pub fn lift_lockdown(&self, operator: &str) -> Result<LockdownWindow, SystemError> {
+        let window = self.lockdown().lift(operator)?;
+        tracing::warn!(
+            operator = %operator,
+            denied = window.denials.len(),
+            "host lockdown lifted"
+        );
+        Ok(window)
+    }
+
+    /// Run an operator `lockdown on|off|status` command.
+    ///
+    /// Returns the affected window; `status` returns the open window, or
+    /// `None` if the host is not locked down.
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::Lockdown` if the switch is already in the requested
+    ///   state
+    pub fn run_lockdown_command(
+        &self,
+        command: &LockdownCommand,
+        operator: &str,
+    ) -> Result<Option<LockdownWindow>, SystemError> {
+        match command {
+            LockdownCommand::On { reason } => self.engage_lockdown(operator, reason).map(Some),
+            LockdownCommand::Off => self.lift_lockdown(operator).map(Some),
+            LockdownCommand::Status => Ok(self.lockdown().current()?),
+        }
+    }
+
+    // ========================================================================
+    // Plugins
+    // ========================================================================
+
+    /// Register a host plugin.
+    ///
+    /// Plugins must be registered before [`start`](Self::start) to receive
+    /// the start hook; later registrations only see subsequent events.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SystemError::Plugin` if a plugin with the same name exists.
+    pub fn register_plugin(&mut self, plugin: Box<dyn HostPlugin>) -> Result<(), SystemError> {
+        self.plugins.register(plugin)?;
+        Ok(())
+    }
+
+    /// Deliver a message to `target` after running plugin interceptors.
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::NotRunning` if the system has not been started
+    /// - `SystemError::Messaging` with `DeliveryFailed` if a plugin drops the
+    ///   message, or any error from the subscriber delivery
+    pub fn deliver_message(
+        &self,
+        target: &ComponentId,
+        message: ComponentMessage,
+    ) -> Result<(), SystemError> {
+        if !self.is_running {
+            return Err(SystemError::NotRunning);
+        }
+
+        match self.plugins.intercept(target, message) {
+            InterceptAction::Continue(message) => {
+                self.subscriber.deliver(target, message)?;
+                Ok(())
+            }
+            InterceptAction::Drop(reason) => Err(SystemError::Messaging(
+                CoreMessagingError::DeliveryFailed(format!("Dropped by plugin {}", reason)),
+            )),
+        }
+    }
+
+    /// Route a control-API request to a plugin endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SystemError::Plugin` if the plugin or endpoint is unknown or
+    /// the handler fails.
+    pub fn handle_plugin_request(
+        &self,
+        plugin: &str,
+        endpoint: &str,
+        request: serde_json::Value,
+    ) -> Result<serde_json::Value, SystemError> {
+        Ok(self.plugins.handle_endpoint(plugin, endpoint, request)?)
+    }
+
+    /// Control-API endpoints exposed by plugins, as `plugin/endpoint`.
+    pub fn plugin_endpoints(&self) -> Vec<String> {
+        self.plugins.endpoints()
+    }
+
+    /// Custom metrics published by plugins, named `plugin.metric`.
+    pub fn plugin_metrics(&self) -> Vec<PluginMetric> {
+        self.plugins.metrics()
+    }
+
+    /// Share `metrics` with the runtime engine so metrics emitted by
+    /// components are included in [`metrics_snapshot`](Self::metrics_snapshot).
+    ///
+    /// Replaces the coordinator's own registry; call before loading
+    /// components.
+    pub fn set_component_metrics(&mut self, metrics: Arc<ComponentMetrics>) {
+        self.component_metrics = metrics;
+    }
+
+    /// Get the registry of component-emitted metrics.
+    pub fn component_metrics(&self) -> &Arc<ComponentMetrics> {
+        &self.component_metrics
+    }
+
+    /// Report the per-topic delivery counters of `bus` (published,
+    /// delivered, dropped, and the slowest subscriber's lag) in
+    /// [`metrics_snapshot`](Self::metrics_snapshot).
+    pub fn set_topic_bus(&mut self, bus: Arc<TopicBus>) {
+        self.topic_bus = Some(bus);
+    }
+
+    /// Get the topic bus whose counters are reported, if any.
+    pub fn topic_bus(&self) -> Option<&Arc<TopicBus>> {
+        self.topic_bus.as_ref()
+    }
+
+    /// Decompress payloads compressed by routers sharing `compressor`
+    /// before they reach component mailboxes.
+    ///
+    /// Replaces the subscriber, dropping its mailbox registrations; call
+    /// before loading components.
+    pub fn set_payload_compressor(&mut self, compressor: Arc<PayloadCompressor>) {
+        self.replace_subscriber(|subscriber| subscriber.with_compressor(compressor));
+    }
+
+    /// Reject payloads that do not match the schema their target
+    /// registered in `schemas` before they reach component mailboxes.
+    ///
+    /// Schemas of unloaded components are removed. Replaces the subscriber,
+    /// dropping its mailbox registrations; call before loading components.
+    pub fn set_schema_registry(&mut self, schemas: Arc<SchemaRegistry>) {
+        self.replace_subscriber(|subscriber| subscriber.with_schema_registry(schemas));
+    }
+
+    /// Reject payloads whose content type their target did not declare in
+    /// its `[messaging] accepts` list before they reach component mailboxes.
+    ///
+    /// Loading a component registers its
+    /// [`accepted_content_types`](ComponentConfig::accepted_content_types)
+    /// with `guard`; unloading removes them. Rejection counters appear in
+    /// [`metrics_snapshot`](Self::metrics_snapshot). Replaces the
+    /// subscriber, dropping its mailbox registrations; call before loading
+    /// components.
+    pub fn set_content_type_guard(&mut self, guard: Arc<ContentTypeGuard>) {
+        self.replace_subscriber(|subscriber| subscriber.with_content_type_guard(guard));
+    }
+
+    /// Deliver messages carrying a partition key to the pool instance
+    /// `affinity` assigns the key to.
+    ///
+    /// Every loaded component joins the pool of its namespace and name;
+    /// unloading it moves its keys to the remaining instances. Replaces the
+    /// subscriber, dropping its mailbox registrations; call before loading
+    /// components.
+    pub fn set_affinity_router(&mut self, affinity: Arc<AffinityRouter>) {
+        self.replace_subscriber(|subscriber| subscriber.with_affinity_router(affinity));
+    }
+
+    /// Shed messages that can no longer meet their deadline to the
+    /// dead-letter queue of `shedder`'s ledger.
+    ///
+    /// Loading a component registers its
+    /// [`shed_policy`](ComponentConfig::shed_policy) with `shedder`;
+    /// components without one are never shed from. While the system runs,
+    /// dead letters with a target are delivered to it every dead-letter
+    /// routing period (see [`route_dead_letters`](Self::route_dead_letters)).
+    /// Replaces the subscriber and spawner, dropping mailbox registrations;
+    /// call before loading components.
+    pub fn set_load_shedder(&mut self, shedder: Arc<LoadShedder>) {
+        let admission = Arc::clone(&shedder);
+        self.replace_subscriber(|subscriber| subscriber.with_queue_admission(admission));
+        self.load_shedder = Some(shedder);
+        self.rebuild_spawner();
+    }
+
+    /// Requeue the message that crashed a component per `policy`, carrying
+    /// it across the restart in `ledger`.
+    ///
+    /// Messages that exhaust their attempts are dead-lettered in `ledger`
+    /// and routed to the policy's dead-letter target like shed messages
+    /// (see [`route_dead_letters`](Self::route_dead_letters)). Replaces the
+    /// spawner; call before loading components.
+    pub fn set_requeue(&mut self, policy: RequeuePolicy, ledger: Arc<RequeueLedger>) {
+        self.requeue = Some((policy, ledger));
+        self.rebuild_spawner();
+    }
+
+    /// Recreates the spawner with the current drain, shedding and requeue
+    /// settings.
+    fn rebuild_spawner(&mut self) {
+        let mut spawner = ComponentSpawner::new(
+            Arc::clone(&self.engine),
+            Arc::clone(&self.loader),
+            Arc::clone(&self.registry),
+        )
+        .with_shutdown_drain(Arc::clone(&self.shutdown_drain));
+        if let Some(shedder) = &self.load_shedder {
+            spawner = spawner.with_load_shedder(Arc::clone(shedder));
+        }
+        if let Some((policy, ledger)) = &self.requeue {
+            spawner = spawner.with_requeue(policy.clone(), Arc::clone(ledger));
+        }
+        self.spawner = spawner;
+    }
+
+    /// Get the load shedder, if any.
+    pub fn load_shedder(&self) -> Option<&Arc<LoadShedder>> {
+        self.load_shedder.as_ref()
+    }
+
+    /// Set how often dead letters are routed to their targets.
+    ///
+    /// Defaults to [`DEFAULT_DEAD_LETTER_ROUTING_PERIOD`]; call before
+    /// starting.
+    pub fn set_dead_letter_routing_period(&mut self, period: Duration) {
+        self.dead_letter_routing_period = period;
+    }
+
+    /// Deliver the dead letters of the load shedder and requeue ledgers to
+    /// their `route_to` targets.
+    ///
+    /// Letters whose target has no mailbox stay queued for the next round;
+    /// letters without a target are kept for inspection. Returns the number
+    /// delivered.
+    pub fn route_dead_letters(&self) -> usize {
+        self.dead_letter_ledgers()
+            .iter()
+            .map(|ledger| {
+                ledger
+                    .route_dead_letters(|target, message| self.subscriber.deliver(target, message))
+            })
+            .sum()
+    }
+
+    /// The distinct ledgers dead letters are filed in.
+    fn dead_letter_ledgers(&self) -> Vec<Arc<RequeueLedger>> {
+        let mut ledgers: Vec<Arc<RequeueLedger>> = Vec::new();
+        let shed = self.load_shedder.as_ref().map(|shedder| shedder.ledger());
+        let requeued = self.requeue.as_ref().map(|(_, ledger)| ledger);
+        for ledger in shed.into_iter().chain(requeued) {
+            if !ledgers.iter().any(|known| Arc::ptr_eq(known, ledger)) {
+                ledgers.push(Arc::clone(ledger));
+            }
+        }
+        ledgers
+    }
+
+    /// Starts the periodic dead-letter routing on the current Tokio runtime.
+    fn spawn_dead_letter_routing(&self) -> Option<JoinHandle<()>> {
+        let ledgers: Vec<Weak<RequeueLedger>> = self
+            .dead_letter_ledgers()
+            .iter()
+            .map(Arc::downgrade)
+            .collect();
+        if ledgers.is_empty() {
+            return None;
+        }
+        if tokio::runtime::Handle::try_current().is_err() {
+            tracing::warn!("no Tokio runtime; dead letters are only routed on request");
+            return None;
+        }
+        let subscriber = Arc::clone(&self.subscriber);
+        let period = self.dead_letter_routing_period;
+        Some(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(period);
+            loop {
+                ticker.tick().await;
+                let live: Vec<Arc<RequeueLedger>> =
+                    ledgers.iter().filter_map(Weak::upgrade).collect();
+                if live.is_empty() {
+                    break;
+                }
+                for ledger in live {
+                    ledger
+                        .route_dead_letters(|target, message| subscriber.deliver(target, message));
+                }
+            }
+        }))
+    }
+
+    /// Keep undelivered messages in `spool` across restarts, persisting
+    /// them to `storage`.
+    ///
+    /// `start()` recovers the spool's journal and restores what the last
+    /// `shutdown()` persisted; every load flushes queued messages to the
+    /// mailboxes registered so far, and a background task retries due
+    /// messages every spool redelivery period (see
+    /// [`MessageSpool::spawn_redelivery`]); `shutdown()` persists what is
+    /// left after mailboxes are unregistered. Call before starting.
+    pub fn set_message_spool(
+        &mut self,
+        spool: Arc<MessageSpool>,
+        storage: Arc<dyn ComponentStorage>,
+    ) {
+        self.spool = Some((spool, storage));
+    }
+
+    /// Set how often the message spool retries due messages.
+    ///
+    /// Defaults to [`DEFAULT_SPOOL_REDELIVERY_PERIOD`]; call before starting.
+    pub fn set_spool_redelivery_period(&mut self, period: Duration) {
+        self.spool_redelivery_period = period;
+    }
+
+    /// Get the message spool, if any.
+    pub fn message_spool(&self) -> Option<&Arc<MessageSpool>> {
+        self.spool.as_ref().map(|(spool, _)| spool)
+    }
+
+    /// Reloads journaled and persisted messages, then delivers what the
+    /// registered mailboxes accept.
+    fn restore_spool(&self) -> Result<(), SystemError> {
+        let Some((spool, storage)) = &self.spool else {
+            return Ok(());
+        };
+        let recovered = spool.recover()?;
+        let restored = spool.restore(storage.as_ref())?;
+        if recovered + restored > 0 {
+            tracing::info!(recovered, restored, "restored queued messages");
+        }
+        self.flush_spool()
+    }
+
+    /// Starts the periodic spool redelivery on the current Tokio runtime.
+    fn spawn_spool_redelivery(&self) -> Option<JoinHandle<()>> {
+        let (spool, _) = self.spool.as_ref()?;
+        if tokio::runtime::Handle::try_current().is_err() {
+            tracing::warn!("no Tokio runtime; queued messages are only retried on load");
+            return None;
+        }
+        Some(spool.spawn_redelivery(Arc::clone(&self.subscriber), self.spool_redelivery_period))
+    }
+
+    /// Delivers queued messages to the registered mailboxes.
+    fn flush_spool(&self) -> Result<(), SystemError> {
+        if let Some((spool, _)) = &self.spool {
+            spool.flush(&self.subscriber)?;
+        }
+        Ok(())
+    }
+
+    /// Persists whatever is still queued.
+    fn persist_spool(&self) -> Result<(), SystemError> {
+        let Some((spool, storage)) = &self.spool else {
+            return Ok(());
+        };
+        match spool.persist(storage.as_ref()) {
+            Ok(saved) => {
+                tracing::info!(saved, "persisted queued messages");
+                Ok(())
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "failed to persist queued messages");
+                Err(e.into())
+            }
+        }
+    }
+
+    /// Replaces the subscriber with one keeping the current compressor,
+    /// schema registry, content type guard, affinity router and queue
+    /// admission, then applies `configure`.
+    fn replace_subscriber(
+        &mut self,
+        configure: impl FnOnce(ComponentSubscriber) -> ComponentSubscriber,
+    ) {
+        let mut subscriber = ComponentSubscriber::new();
+        if let Some(compressor) = self.subscriber.compressor() {
+            subscriber = subscriber.with_compressor(Arc::clone(compressor));
+        }
+        if let Some(schemas) = self.subscriber.schema_registry() {
+            subscriber = subscriber.with_schema_registry(Arc::clone(schemas));
+        }
+        if let Some(guard) = self.subscriber.content_type_guard() {
+            subscriber = subscriber.with_content_type_guard(Arc::clone(guard));
+        }
+        if let Some(affinity) = self.subscriber.affinity_router() {
+            subscriber = subscriber.with_affinity_router(Arc::clone(affinity));
+        }
+        if let Some(admission) = self.subscriber.queue_admission() {
+            subscriber = subscriber.with_queue_admission(Arc::clone(admission));
+        }
+        self.subscriber = Arc::new(configure(subscriber));
+    }
+
+    /// Host-wide metrics: plugin metrics as gauges, payload compression
+    /// and content type counters (when a compressor or guard is attached),
+    /// per-topic delivery counters (when a topic bus is set), then every
+    /// component's own series labelled with its ID.
+    ///
+    /// # Errors
+    ///
+    /// - `SystemError::Metrics` if the component metrics registry is poisoned
+    /// - `SystemError::Messaging` if the compressor's, guard's or topic bus's
+    ///   counters are poisoned
+    pub fn metrics_snapshot(&self) -> Result<MetricsSnapshot, SystemError> {
+        let plugins = self
+            .plugin_metrics()
+            .into_iter()
+            .map(|m| MetricSample {
+                component: None,
+                name: m.name,
+                labels: Vec::new(),
+                value: MetricValue::Gauge(m.value),
+            })
+            .collect();
+        let mut snapshot = MetricsSnapshot::new(plugins);
+        if let Some(compressor) = self.subscriber.compressor() {
+            snapshot.merge(MetricsSnapshot::new(compressor.stats()?.samples()));
+        }
+        if let Some(guard) = self.subscriber.content_type_guard() {
+            snapshot.merge(MetricsSnapshot::new(guard.stats()?.samples()));
+        }
+        if let Some(bus) = &self.topic_bus {
+            snapshot.merge(MetricsSnapshot::new(bus.samples()?));
+        }
+        snapshot.merge(self.component_metrics.snapshot()?);
+        Ok(snapshot)
+    }
+
+    /// Registered plugin registry.
+    pub fn plugins(&self) -> &PluginRegistry {
+        &self.plugins
+    }
+
+    // ========================================================================
+    // Accessor Methods
+    // ========================================================================
+
+    /// Returns a reference to the runtime engine.
+    pub fn engine(&self) -> &Arc<E> {
+        &self.engine
+    }
+
+    /// Returns a reference to the component loader.
+    pub fn loader(&self) -> &Arc<L> {
+        &self.loader
+    }
+
+    /// Returns a reference to the security validator.
+    ///
+    /// The validator enforces the host lockdown; hand it to the OS bridge
+    /// and other enforcement points so writes stop while it is engaged.
+    pub fn security_validator(&self) -> &Arc<LockdownValidator<Arc<V>>> {
+        &self.security_validator
+    }
+
+    /// Returns a reference to the audit logger.
+    pub fn audit_logger(&self) -> &Arc<A> {
+        &self.audit_logger
+    }
+
+    /// Returns a reference to the component registry.
+    pub fn registry(&self) -> &Arc<ComponentRegistry> {
+        &self.registry
+    }
+
+    /// Returns a reference to the component subscriber.
+    pub fn subscriber(&self) -> &Arc<ComponentSubscriber> {
+        &self.subscriber
+    }
+
+    /// Returns a reference to the correlation tracker.
+    pub fn correlation_tracker(&self) -> &Arc<CorrelationTrackerImpl> {
+        &self.correlation_tracker
+    }
+
+    /// Returns whether the system is currently running.
+    pub fn is_running(&self) -> bool {
+        self.is_running
+    }
+
+    /// Returns when the coordinator was created.
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// Returns when the coordinator was started, if it has been started.
+    pub fn started_at(&self) -> Option<DateTime<Utc>> {
+        self.started_at
+    }
+
+    /// Returns the number of component stops that failed during the last shutdown.
+    pub fn shutdown_failed_stops(&self) -> usize {
+        self.shutdown_failed_stops
+    }
+
+    /// Returns the number of currently registered components.
+    pub fn component_count(&self) -> Result<usize, SystemError> {
+        Ok(self.registry.count()?)
+    }
+}
+
+/// Warns about components that did not confirm they are ready to stop.
+fn log_drain_report(report: &DrainReport) {
+    for (id, reason) in &report.failed {
+        tracing::warn!(component = %id.to_string_id(), reason = %reason, "component failed to prepare for shutdown");
+    }
+    for id in &report.timed_out {
+        tracing::warn!(component = %id.to_string_id(), "component did not answer prepare-shutdown before the deadline");
+    }
+}
+
+// ============================================================================
+// Debug Implementation
+// ============================================================================
+
+impl<E, L, V, A, B> fmt::Debug for SystemCoordinator<E, L, V, A, B>
+where
+    E: RuntimeEngine + 'static,
+    L: ComponentLoader + 'static,
+    V: SecurityValidator,
+    A: SecurityAuditLogger,
+    B: MessageBroker<ComponentActorMessage> + Clone + Send + Sync + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SystemCoordinator")
+            .field("is_running", &self.is_running)
+            .field("is_shutdown", &self.is_shutdown)
+            .field("created_at", &self.created_at)
+            .field("started_at", &self.started_at)
+            .field("registry", &self.registry)
+            .field("subscriber", &self.subscriber)
+            .field("plugins", &self.plugins)
+            .field("admission", &self.admission)
+            .finish_non_exhaustive()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+
+    use airssys_rt::broker::InMemoryMessageBroker;
+
+    use crate::core::bridge::mapping::CapabilityMapping;
+    use crate::core::bridge::operation::OsOperation;
+    use crate::core::bridge::traits::OsBridge;
+    use crate::core::component::handle::ComponentHandle;
+    use crate::core::component::message::{MessageMetadata, MessagePayload};
+    use crate::core::config::deprecation::{Deprecation, Deprecations};
+    use crate::core::config::values::ConfigValue;
+    use crate::core::messaging::guarantee::DeliveryGuarantee;
+    use crate::core::runtime::errors::WasmError;
+    use crate::core::security::capability::Capability;
+    use crate::core::security::capability::{StorageAction, StorageCapability};
+    use crate::core::security::errors::SecurityError;
+    use crate::core::security::traits::SecurityEvent;
+    use crate::core::storage::errors::StorageError;
+    use crate::core::storage::value::StorageValue;
+    use crate::messaging::spool::SpoolConfig;
+    use crate::security::capability::set::{
+        CapabilitySet, FilesystemPermission, StoragePermission,
+    };
+    use crate::security::capability::validator::CapabilityValidator;
+    use crate::security::os_bridge::OslOperationBridge;
+
+    // ========================================
+    // Mock RuntimeEngine
+    // ========================================
+    struct MockRuntimeEngine;
+
+    impl RuntimeEngine for MockRuntimeEngine {
+        fn load_component(
+            &self,
+            id: &ComponentId,
+            _bytes: &[u8],
+        ) -> Result<ComponentHandle, WasmError> {
+            Ok(ComponentHandle::new(id.clone(), 1))
+        }
+
+        fn unload_component(&self, _handle: &ComponentHandle) -> Result<(), WasmError> {
+            Ok(())
+        }
+
+        fn call_handle_message(
+            &self,
+            _handle: &ComponentHandle,
+            _msg: &ComponentMessage,
+        ) -> Result<Option<MessagePayload>, WasmError> {
+            Ok(None)
+        }
+
+        fn call_handle_callback(
+            &self,
+            _handle: &ComponentHandle,
+            _msg: &ComponentMessage,
+        ) -> Result<(), WasmError> {
+            Ok(())
+        }
+
+        fn call_reconfigure(
+            &self,
+            _handle: &ComponentHandle,
+            values: &ConfigValues,
+        ) -> Result<(), WasmError> {
+            match values.get("threshold") {
+                Some(ConfigValue::Integer(n)) if *n < 0 => {
+                    Err(WasmError::ConfigRejected("negative threshold".to_string()))
+                }
+                _ => Ok(()),
+            }
+        }
+    }
+
+    // ========================================
+    // Mock ComponentLoader
+    // ========================================
+    struct MockComponentLoader;
+
+    impl ComponentLoader for MockComponentLoader {
+        fn load_bytes(&self, _id: &ComponentId) -> Result<Vec<u8>, WasmError> {
+            Ok(vec![0u8; 100])
+        }
+
+        fn validate(&self, _bytes: &[u8]) -> Result<(), WasmError> {
+            Ok(())
+        }
+    }
+
+    // ========================================
+    // Mock SecurityValidator
+    // ========================================
+    struct MockSecurityValidator;
+
+    impl SecurityValidator for MockSecurityValidator {
+        fn validate_capability(
+            &self,
+            _component: &ComponentId,
+            _capability: &Capability,
+        ) -> Result<(), SecurityError> {
+            Ok(())
+        }
+
+        fn can_send_to(
+            &self,
+            _sender: &ComponentId,
+            _target: &ComponentId,
+        ) -> Result<(), SecurityError> {
+            Ok(())
+        }
+
+        fn grant_elevated(
+            &self,
+            _component: &ComponentId,
+            _capability: &str,
+        ) -> Result<(), SecurityError> {
+            Ok(())
+        }
+    }
+
+    // ========================================
+    // Mock SecurityAuditLogger
+    // ========================================
+    struct MockAuditLogger;
+
+    impl SecurityAuditLogger for MockAuditLogger {
+        fn log_event(&self, _event: SecurityEvent) {}
+    }
+
+    // ========================================
+    // Type alias for test coordinator
+    // ========================================
+    type TestCoordinator = SystemCoordinator<
+        MockRuntimeEngine,
+        MockComponentLoader,
+        MockSecurityValidator,
+        MockAuditLogger,
+        InMemoryMessageBroker<ComponentActorMessage>,
+    >;
+
+    // ========================================
+    // Helper: create coordinator with mocks
+    // ========================================
+    fn create_test_coordinator() -> TestCoordinator {
+        let engine = Arc::new(MockRuntimeEngine);
+        let loader = Arc::new(MockComponentLoader);
+        let validator = Arc::new(MockSecurityValidator);
+        let logger = Arc::new(MockAuditLogger);
+        let broker = InMemoryMessageBroker::<ComponentActorMessage>::new();
+
+        SystemCoordinator::new(
+            engine,
+            loader,
+            validator,
+            logger,
+            SystemConfig::default(),
+            broker,
+        )
+    }
+
+    fn create_test_id(name: &str) -> ComponentId {
+        ComponentId::new("test", name, "v1")
+    }
+
+    // ========================================
+    // 1. Constructor Tests
+    // ========================================
+
+    #[tokio::test]
+    async fn test_coordinator_creation() {
+        let coordinator = create_test_coordinator();
+        assert!(!coordinator.is_running());
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_coordinator_created_at_is_set() {
+        let before = Utc::now();
+        let coordinator = create_test_coordinator();
+        let after = Utc::now();
+
+        assert!(coordinator.created_at() >= before);
+        assert!(coordinator.created_at() <= after);
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_coordinator_started_at_is_none_initially() {
+        let coordinator = create_test_coordinator();
+        assert!(coordinator.started_at().is_none());
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    // ========================================
+    // 2. Start/Stop Lifecycle Tests
+    // ========================================
+
+    #[tokio::test]
+    async fn test_start_succeeds() {
+        let mut coordinator = create_test_coordinator();
+        let result = coordinator.start();
+        assert!(result.is_ok());
+        assert!(coordinator.is_running());
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_start_fails_fast_on_self_test_failure() {
+        use crate::system::selftest::{CheckOutcome, EngineConfigCheck, TrustAnchorCheck};
+
+        let mut coordinator = create_test_coordinator();
+        coordinator.set_self_test(
+            SelfTest::new()
+                .with_check(TrustAnchorCheck::new(vec![]).require_anchors(true))
+                .with_check(EngineConfigCheck::new(false, false)),
+        );
+
+        match coordinator.start() {
+            Err(SystemError::SelfTestFailed(report)) => {
+                assert_eq!(report.failures().count(), 2);
+                assert!(report
+                    .results
+                    .iter()
+                    .all(|r| matches!(r.outcome, CheckOutcome::Failed(_))));
+            }
+            other => panic!("expected self-test failure, got {other:?}"),
+        }
+        assert!(!coordinator.is_running());
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_start_refuses_tampered_config_and_reload_detects_edits() {
+        use crate::security::config_signing::{ConfigSigner, ConfigStatus, SignatureMode};
+
+        let dir =
+            std::env::temp_dir().join(format!("airssys-signed-config-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let host = dir.join("Host.toml");
+        std::fs::write(&host, "[host]\nprofile = \"prod\"\n").unwrap();
+        let signer = ConfigSigner::from_bytes(&[9; 32]);
+        signer.sign_file(&host, 1).unwrap();
+        let verifier = ConfigVerifier::new(&signer.public_key(), SignatureMode::Strict).unwrap();
+
+        let mut coordinator = create_test_coordinator();
+        coordinator.set_config_verifier(verifier, vec![host.clone()]);
+        let verified = coordinator.verify_config().unwrap();
+        assert_eq!(verified.len(), 1);
+        assert_eq!(verified[0].status, ConfigStatus::Verified);
+        assert_eq!(verified[0].text().unwrap(), "[host]\nprofile = \"prod\"\n");
+
+        std::fs::write(&host, "[host]\nprofile = \"dev\"\n").unwrap();
+        assert!(matches!(
+            coordinator.verify_config(),
+            Err(SystemError::ConfigSignature(ConfigSigningError::Tampered(
+                _
+            )))
+        ));
+        assert!(matches!(
+            coordinator.start(),
+            Err(SystemError::ConfigSignature(_))
+        ));
+        assert!(!coordinator.is_running());
+
+        coordinator.actor_system.force_shutdown().await;
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_start_sets_started_at() {
+        let mut coordinator = create_test_coordinator();
+        let before = Utc::now();
+        coordinator.start().unwrap();
+        let after = Utc::now();
+
+        let started_at = coordinator.started_at().unwrap();
+        assert!(started_at >= before);
+        assert!(started_at <= after);
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_start_when_already_running_returns_error() {
+        let mut coordinator = create_test_coordinator();
+        coordinator.start().unwrap();
+
+        let result = coordinator.start();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("already running"));
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_when_not_running_is_noop() {
+        let mut coordinator = create_test_coordinator();
+        // Not started, shutdown should be a no-op
+        let result = coordinator.shutdown().await;
+        assert!(result.is_ok());
+        assert!(!coordinator.is_running());
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_sets_not_running() {
+        let mut coordinator = create_test_coordinator();
+        coordinator.start().unwrap();
+        assert!(coordinator.is_running());
+
+        let result = coordinator.shutdown().await;
+        assert!(result.is_ok());
+        assert!(!coordinator.is_running());
+    }
+
+    #[tokio::test]
+    async fn test_start_after_shutdown_returns_error() {
+        let mut coordinator = create_test_coordinator();
+        coordinator.start().unwrap();
+        coordinator.shutdown().await.unwrap();
+        assert!(!coordinator.is_running());
+
+        // Cannot restart after shutdown — actor system is no longer usable.
+        let result = coordinator.start();
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("already shut down"));
+    }
+
+    // ========================================
+    // 3. Component Load/Unload Tests
+    // ========================================
+
+    #[tokio::test]
+    async fn test_load_component_when_not_running_returns_error() {
+        let coordinator = create_test_coordinator();
+        let id = create_test_id("test-comp");
+
+        let result = coordinator.load_component(id).await;
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("not running"));
+    }
+
+    #[tokio::test]
+    async fn test_load_component_succeeds() {
+        let mut coordinator = create_test_coordinator();
+        coordinator.start().unwrap();
+
+        let id = create_test_id("my-component");
+        let result = coordinator.load_component(id.clone()).await;
+        assert!(result.is_ok());
+
+        // Verify component is in the registry
+        assert!(coordinator.registry().contains(&id).unwrap());
+
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_load_multiple_components() {
+        let mut coordinator = create_test_coordinator();
+        coordinator.start().unwrap();
+
+        let id1 = create_test_id("comp-1");
+        let id2 = create_test_id("comp-2");
+        let id3 = create_test_id("comp-3");
+
+        coordinator.load_component(id1.clone()).await.unwrap();
+        coordinator.load_component(id2.clone()).await.unwrap();
+        coordinator.load_component(id3.clone()).await.unwrap();
+
+        assert_eq!(coordinator.registry().count().unwrap(), 3);
+        assert!(coordinator.registry().contains(&id1).unwrap());
+        assert!(coordinator.registry().contains(&id2).unwrap());
+        assert!(coordinator.registry().contains(&id3).unwrap());
+
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_unload_component_succeeds() {
+        let mut coordinator = create_test_coordinator();
+        coordinator.start().unwrap();
+
+        let id = create_test_id("unload-me");
+        coordinator.load_component(id.clone()).await.unwrap();
+        assert!(coordinator.registry().contains(&id).unwrap());
+
+        let result = coordinator.unload_component(&id);
+        assert!(result.is_ok());
+        assert!(!coordinator.registry().contains(&id).unwrap());
+
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_unload_component_when_not_running_returns_error() {
+        let coordinator = create_test_coordinator();
+        let id = create_test_id("test-comp");
+
+        let result = coordinator.unload_component(&id);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("not running"));
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_unload_nonexistent_component_returns_error() {
+        let mut coordinator = create_test_coordinator();
+        coordinator.start().unwrap();
+
+        let id = create_test_id("nonexistent");
+        let result = coordinator.unload_component(&id);
+        assert!(result.is_err());
+        // SpawnerError::NotSpawned wraps into SystemError::ComponentSpawner
+        assert!(result.unwrap_err().to_string().contains("Component error"));
+
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    // ========================================
+    // 4. Accessor Tests
+    // ========================================
+
+    #[tokio::test]
+    async fn test_accessor_registry() {
+        let coordinator = create_test_coordinator();
+        let registry = coordinator.registry();
+        assert_eq!(registry.count().unwrap(), 0);
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_accessor_subscriber() {
+        let coordinator = create_test_coordinator();
+        let subscriber = coordinator.subscriber();
+        assert_eq!(subscriber.count().unwrap(), 0);
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_accessor_correlation_tracker() {
+        let coordinator = create_test_coordinator();
+        let _tracker = coordinator.correlation_tracker();
+        // CorrelationTrackerImpl exists and is accessible
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_accessor_is_running() {
+        let mut coordinator = create_test_coordinator();
+        assert!(!coordinator.is_running());
+
+        coordinator.start().unwrap();
+        assert!(coordinator.is_running());
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_component_count_empty() {
+        let coordinator = create_test_coordinator();
+        assert_eq!(coordinator.component_count().unwrap(), 0);
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_component_count_after_load() {
+        let mut coordinator = create_test_coordinator();
+        coordinator.start().unwrap();
+
+        coordinator
+            .load_component(create_test_id("c1"))
+            .await
+            .unwrap();
+        assert_eq!(coordinator.component_count().unwrap(), 1);
+
+        coordinator
+            .load_component(create_test_id("c2"))
+            .await
+            .unwrap();
+        assert_eq!(coordinator.component_count().unwrap(), 2);
+
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_failed_stops_initially_zero() {
+        let coordinator = create_test_coordinator();
+        assert_eq!(coordinator.shutdown_failed_stops(), 0);
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    // ========================================
+    // 5. Error Type Tests
+    // ========================================
+
+    #[test]
+    fn test_system_error_display_already_running() {
+        let err = SystemError::AlreadyRunning;
+        assert_eq!(err.to_string(), "System already running");
+    }
+
+    #[test]
+    fn test_system_error_display_not_running() {
+        let err = SystemError::NotRunning;
+        assert_eq!(err.to_string(), "System not running");
+    }
+
+    #[test]
+    fn test_system_error_display_already_shut_down() {
+        let err = SystemError::AlreadyShutDown;
+        assert_eq!(err.to_string(), "System already shut down");
+    }
+
+    #[test]
+    fn test_system_error_display_shutdown_failed() {
+        let rt_err = RtSystemError::ShuttingDown;
+        let err = SystemError::ShutdownFailed(rt_err);
+        assert!(err.to_string().contains("Shutdown failed"));
+    }
+
+    #[test]
+    fn test_system_error_display_component_spawner() {
+        let spawner_err = SpawnerError::NotSpawned("test/comp/v1".to_string());
+        let err = SystemError::ComponentSpawner(spawner_err);
+        assert!(err.to_string().contains("Component error"));
+    }
+
+    #[test]
+    fn test_system_error_display_component_registry() {
+        let reg_err = RegistryError::LockPoisoned("test".to_string());
+        let err = SystemError::ComponentRegistry(reg_err);
+        assert!(err.to_string().contains("Registry error"));
+    }
+
+    #[test]
+    fn test_system_error_from_spawner_error() {
+        let spawner_err = SpawnerError::NotSpawned("test/comp/v1".to_string());
+        let system_err: SystemError = spawner_err.into();
+        assert!(system_err.to_string().contains("Component error"));
+        // Source error is preserved (not stringified)
+        assert!(std::error::Error::source(&system_err).is_some());
+    }
+
+    #[test]
+    fn test_system_error_from_registry_error() {
+        let reg_err = RegistryError::LockPoisoned("test".to_string());
+        let system_err: SystemError = reg_err.into();
+        assert!(system_err.to_string().contains("Registry error"));
+        assert!(std::error::Error::source(&system_err).is_some());
+    }
+
+    #[test]
+    fn test_system_error_from_messaging_error() {
+        let messaging_err = CoreMessagingError::DeliveryFailed("connection lost".to_string());
+        let system_err: SystemError = messaging_err.into();
+        assert!(system_err.to_string().contains("Messaging error"));
+        assert!(std::error::Error::source(&system_err).is_some());
+    }
+
+    #[test]
+    fn test_system_error_from_rt_system_error() {
+        let rt_err = RtSystemError::ShuttingDown;
+        let system_err: SystemError = rt_err.into();
+        assert!(system_err.to_string().contains("Shutdown failed"));
+        assert!(std::error::Error::source(&system_err).is_some());
+    }
+
+    // ========================================
+    // 6. Debug Trait Test
+    // ========================================
+
+    #[tokio::test]
+    async fn test_coordinator_debug_format() {
+        let coordinator = create_test_coordinator();
+        let debug_str = format!("{:?}", coordinator);
+        assert!(debug_str.contains("SystemCoordinator"));
+        assert!(debug_str.contains("is_running"));
+        assert!(debug_str.contains("is_shutdown"));
+        assert!(debug_str.contains("created_at"));
+        // finish_non_exhaustive() appends ".." to indicate omitted fields
+        assert!(debug_str.contains(".."));
+        coordinator.actor_system.force_shutdown().await;
+    }
+
+    // ========================================
+    // 7. Thread Safety Test
+    // ========================================
+
+    #[test]
+    fn test_coordinator_is_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<TestCoordinator>();
+    }
 
     // ========================================
-    // Mock RuntimeEngine
+    // 8. Plugin Tests
     // ========================================
-    struct MockRuntimeEngine;
 
-    impl RuntimeEngine for MockRuntimeEngine {
-        fn load_component(
-            &self,
-            id: &ComponentId,
-            _bytes: &[u8],
-        ) -> Result<ComponentHandle, WasmError> {
-            Ok(ComponentHandle::new(id.clone(), 1))
-        }
+    struct GatePlugin {
+        fail_start: Arc<AtomicBool>,
+        loaded: std::sync::Mutex<Vec<String>>,
+    }
 
-        fn unload_component(&self, _handle: &ComponentHandle) -> Result<(), WasmError> {
-            Ok(())
+    impl GatePlugin {
+        fn new(fail_start: bool) -> Self {
+            Self {
+                fail_start: Arc::new(AtomicBool::new(fail_start)),
+                loaded: std::sync::Mutex::new(Vec::new()),
+            }
         }
+    }
 
-        fn call_handle_message(
-            &self,
-            _handle: &ComponentHandle,
-            _msg: &ComponentMessage,
-        ) -> Result<Option<MessagePayload>, WasmError> {
-            Ok(None)
+    impl HostPlugin for GatePlugin {
+        fn name(&self) -> &str {
+            "gate"
         }
 
-        fn call_handle_callback(
-            &self,
-            _handle: &ComponentHandle,
-            _msg: &ComponentMessage,
-        ) -> Result<(), WasmError> {
+        fn on_start(&self) -> Result<(), PluginError> {
+            if self.fail_start.load(Ordering::SeqCst) {
+                return Err(PluginError::HookFailed {
+                    plugin: "gate".to_string(),
+                    reason: "not ready".to_string(),
+                });
+            }
             Ok(())
         }
-    }
-
-    // ========================================
-    // Mock ComponentLoader
-    // ========================================
-    struct MockComponentLoader;
 
-    impl ComponentLoader for MockComponentLoader {
-        fn load_bytes(&self, _id: &ComponentId) -> Result<Vec<u8>, WasmError> {
-            Ok(vec![0u8; 100])
+        fn on_component_loaded(&self, id: &ComponentId) -> Result<(), PluginError> {
+            self.loaded.lock().unwrap().push(id.to_string_id());
+            Ok(())
         }
 
-        fn validate(&self, _bytes: &[u8]) -> Result<(), WasmError> {
-            Ok(())
+        fn intercept_message(
+            &self,
+            _target: &ComponentId,
+            message: ComponentMessage,
+        ) -> InterceptAction {
+            if message.payload.is_empty() {
+                InterceptAction::Drop("empty payload".to_string())
+            } else {
+                InterceptAction::Continue(message)
+            }
         }
-    }
 
-    // ========================================
-    // Mock SecurityValidator
-    // ========================================
-    struct MockSecurityValidator;
+        fn endpoints(&self) -> Vec<String> {
+            vec!["loaded".to_string()]
+        }
 
-    impl SecurityValidator for MockSecurityValidator {
-        fn validate_capability(
+        fn handle_endpoint(
             &self,
-            _component: &ComponentId,
-            _capability: &Capability,
-        ) -> Result<(), SecurityError> {
-            Ok(())
+            _endpoint: &str,
+            _request: serde_json::Value,
+        ) -> Result<serde_json::Value, PluginError> {
+            Ok(serde_json::json!(self.loaded.lock().unwrap().clone()))
         }
 
-        fn can_send_to(
-            &self,
-            _sender: &ComponentId,
-            _target: &ComponentId,
-        ) -> Result<(), SecurityError> {
-            Ok(())
+        fn metrics(&self) -> Vec<PluginMetric> {
+            vec![PluginMetric::new(
+                "loaded",
+                self.loaded.lock().unwrap().len() as f64,
+            )]
         }
     }
 
-    // ========================================
-    // Mock SecurityAuditLogger
-    // ========================================
-    struct MockAuditLogger;
+    #[tokio::test]
+    async fn test_plugin_start_failure_keeps_system_stopped() {
+        let mut coordinator = create_test_coordinator();
+        coordinator
+            .register_plugin(Box::new(GatePlugin::new(true)))
+            .unwrap();
 
-    impl SecurityAuditLogger for MockAuditLogger {
-        fn log_event(&self, _event: SecurityEvent) {}
+        let result = coordinator.start();
+        assert!(matches!(result, Err(SystemError::Plugin(_))));
+        assert!(!coordinator.is_running());
+        coordinator.actor_system.force_shutdown().await;
     }
 
-    // ========================================
-    // Type alias for test coordinator
-    // ========================================
-    type TestCoordinator = SystemCoordinator<
-        MockRuntimeEngine,
-        MockComponentLoader,
-        MockSecurityValidator,
-        MockAuditLogger,
-        InMemoryMessageBroker<ComponentActorMessage>,
-    >;
+    #[tokio::test]
+    async fn test_plugin_start_failure_spawns_no_background_tasks() {
+        let storage = Arc::new(MemoryStorage::default());
+        let (mut coordinator, spool) = spooled_coordinator(&storage);
+        let ledger = Arc::new(RequeueLedger::new());
+        coordinator.set_load_shedder(Arc::new(LoadShedder::new(Arc::clone(&ledger))));
+        let plugin = GatePlugin::new(true);
+        let fail_start = Arc::clone(&plugin.fail_start);
+        coordinator.register_plugin(Box::new(plugin)).unwrap();
+
+        // Each running task holds a weak reference to what it serves
+        assert!(coordinator.start().is_err());
+        assert!(coordinator.spool_redelivery.is_none());
+        assert!(coordinator.dead_letter_routing.is_none());
+        assert_eq!(Arc::weak_count(&spool), 0);
+        assert_eq!(Arc::weak_count(&ledger), 0);
+
+        fail_start.store(false, Ordering::SeqCst);
+        coordinator.start().unwrap();
+        assert_eq!(Arc::weak_count(&spool), 1);
+        assert_eq!(Arc::weak_count(&ledger), 1);
 
-    // ========================================
-    // Helper: create coordinator with mocks
-    // ========================================
-    fn create_test_coordinator() -> TestCoordinator {
-        let engine = Arc::new(MockRuntimeEngine);
-        let loader = Arc::new(MockComponentLoader);
-        let validator = Arc::new(MockSecurityValidator);
-        let logger = Arc::new(MockAuditLogger);
-        let broker = InMemoryMessageBroker::<ComponentActorMessage>::new();
+        coordinator.shutdown().await.unwrap();
+    }
 
-        SystemCoordinator::new(
-            engine,
-            loader,
-            validator,
-            logger,
-            SystemConfig::default(),
-            broker,
-        )
+    #[tokio::test]
+    async fn test_register_duplicate_plugin_fails() {
+        let mut coordinator = create_test_coordinator();
+        coordinator
+            .register_plugin(Box::new(GatePlugin::new(false)))
+            .unwrap();
+        let result = coordinator.register_plugin(Box::new(GatePlugin::new(false)));
+        assert!(matches!(
+            result,
+            Err(SystemError::Plugin(PluginError::AlreadyRegistered(_)))
+        ));
+        coordinator.actor_system.force_shutdown().await;
     }
 
-    fn create_test_id(name: &str) -> ComponentId {
-        ComponentId::new("test", name, "v1")
+    #[tokio::test]
+    async fn test_plugin_hooks_endpoints_and_metrics() {
+        let mut coordinator = create_test_coordinator();
+        coordinator
+            .register_plugin(Box::new(GatePlugin::new(false)))
+            .unwrap();
+        coordinator.start().unwrap();
+
+        coordinator
+            .load_component(create_test_id("comp-a"))
+            .await
+            .unwrap();
+
+        assert_eq!(coordinator.plugin_endpoints(), vec!["gate/loaded"]);
+        let response = coordinator
+            .handle_plugin_request("gate", "loaded", serde_json::Value::Null)
+            .unwrap();
+        assert_eq!(response, serde_json::json!(["test/comp-a/v1"]));
+        assert_eq!(
+            coordinator.plugin_metrics(),
+            vec![PluginMetric::new("gate.loaded", 1.0)]
+        );
+
+        coordinator.shutdown().await.unwrap();
     }
 
-    // ========================================
-    // 1. Constructor Tests
-    // ========================================
+    #[tokio::test]
+    async fn test_metrics_snapshot_includes_compression_stats() {
+        let mut coordinator = create_test_coordinator();
+        coordinator.set_payload_compressor(Arc::new(PayloadCompressor::new()));
+
+        let snapshot = coordinator.metrics_snapshot().unwrap();
+        assert!(snapshot
+            .samples()
+            .iter()
+            .any(|s| s.name == "messaging_compression_ratio"));
+        coordinator.actor_system.force_shutdown().await;
+    }
 
     #[tokio::test]
-    async fn test_coordinator_creation() {
-        let coordinator = create_test_coordinator();
-        assert!(!coordinator.is_running());
+    async fn test_metrics_snapshot_includes_topic_stats() {
+        let mut coordinator = create_test_coordinator();
+        let bus = Arc::new(TopicBus::new());
+        bus.publish("events", MessagePayload::new(vec![1])).unwrap();
+        coordinator.set_topic_bus(Arc::clone(&bus));
+
+        let snapshot = coordinator.metrics_snapshot().unwrap();
+        assert!(snapshot.samples().iter().any(|s| {
+            s.name == "messaging_topic_published_total"
+                && s.labels == [("topic".to_string(), "events".to_string())]
+                && s.value == MetricValue::Counter(1)
+        }));
         coordinator.actor_system.force_shutdown().await;
     }
 
     #[tokio::test]
-    async fn test_coordinator_created_at_is_set() {
-        let before = Utc::now();
-        let coordinator = create_test_coordinator();
-        let after = Utc::now();
+    async fn test_schema_registry_survives_compressor_change() {
+        let mut coordinator = create_test_coordinator();
+        let schemas = Arc::new(SchemaRegistry::new());
+        coordinator.set_schema_registry(Arc::clone(&schemas));
+        coordinator.set_payload_compressor(Arc::new(PayloadCompressor::new()));
 
-        assert!(coordinator.created_at() >= before);
-        assert!(coordinator.created_at() <= after);
+        let subscriber = coordinator.subscriber();
+        assert!(subscriber.compressor().is_some());
+        assert!(Arc::ptr_eq(subscriber.schema_registry().unwrap(), &schemas));
         coordinator.actor_system.force_shutdown().await;
     }
 
     #[tokio::test]
-    async fn test_coordinator_started_at_is_none_initially() {
-        let coordinator = create_test_coordinator();
-        assert!(coordinator.started_at().is_none());
+    async fn test_subscriber_settings_survive_changes_and_report_metrics() {
+        let mut coordinator = create_test_coordinator();
+        let guard = Arc::new(ContentTypeGuard::new());
+        coordinator.set_content_type_guard(Arc::clone(&guard));
+        coordinator.set_affinity_router(Arc::new(AffinityRouter::new()));
+        coordinator.set_schema_registry(Arc::new(SchemaRegistry::new()));
+        coordinator.set_payload_compressor(Arc::new(PayloadCompressor::new()));
+
+        let subscriber = coordinator.subscriber();
+        assert!(subscriber.compressor().is_some());
+        assert!(subscriber.schema_registry().is_some());
+        assert!(subscriber.affinity_router().is_some());
+        assert!(Arc::ptr_eq(
+            subscriber.content_type_guard().unwrap(),
+            &guard
+        ));
+
+        let snapshot = coordinator.metrics_snapshot().unwrap();
+        assert!(snapshot
+            .samples()
+            .iter()
+            .any(|s| s.name == "messaging_content_type_rejected_total"));
         coordinator.actor_system.force_shutdown().await;
     }
 
-    // ========================================
-    // 2. Start/Stop Lifecycle Tests
-    // ========================================
-
     #[tokio::test]
-    async fn test_start_succeeds() {
+    async fn test_load_shedder_tracks_component_policies() {
+        use crate::core::config::shedding::ShedPolicy;
+
         let mut coordinator = create_test_coordinator();
-        let result = coordinator.start();
-        assert!(result.is_ok());
-        assert!(coordinator.is_running());
+        let shedder = Arc::new(LoadShedder::new(Arc::new(RequeueLedger::new())));
+        coordinator.set_load_shedder(Arc::clone(&shedder));
+        coordinator.set_payload_compressor(Arc::new(PayloadCompressor::new()));
+        assert!(coordinator.subscriber().queue_admission().is_some());
+        coordinator.start().unwrap();
+
+        let shed = create_test_id("shed");
+        let config = ComponentConfig::new(shed.clone()).with_shed_policy(ShedPolicy::new());
+        coordinator
+            .load_component_with_config(&config)
+            .await
+            .unwrap();
+        let plain = create_test_id("plain");
+        coordinator.load_component(plain.clone()).await.unwrap();
+
+        assert_eq!(shedder.policy(&shed), Some(ShedPolicy::new()));
+        assert!(shedder.policy(&plain).is_none());
+
+        coordinator.unload_component(&shed).unwrap();
+        assert!(shedder.policy(&shed).is_none());
         coordinator.actor_system.force_shutdown().await;
     }
 
     #[tokio::test]
-    async fn test_start_sets_started_at() {
+    async fn test_dead_letters_routed_while_started() {
+        use crate::component::requeue::{DeadLetter, DeadLetterReason};
+
         let mut coordinator = create_test_coordinator();
-        let before = Utc::now();
+        let ledger = Arc::new(RequeueLedger::new());
+        coordinator.set_load_shedder(Arc::new(LoadShedder::new(Arc::clone(&ledger))));
+        coordinator.set_dead_letter_routing_period(Duration::from_millis(5));
         coordinator.start().unwrap();
-        let after = Utc::now();
 
-        let started_at = coordinator.started_at().unwrap();
-        assert!(started_at >= before);
-        assert!(started_at <= after);
+        let dlq = create_test_id("dlq");
+        let delivered = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&delivered);
+        coordinator
+            .subscriber()
+            .register_mailbox(
+                dlq.clone(),
+                Box::new(move |msg| {
+                    sink.lock().unwrap().push(msg.payload.as_bytes().to_vec());
+                    Ok(())
+                }),
+            )
+            .unwrap();
+        ledger.dead_letter(DeadLetter {
+            component: create_test_id("worker"),
+            message: ComponentMessage::new(
+                create_test_id("sender"),
+                MessagePayload::new(vec![7]),
+                MessageMetadata::default(),
+            ),
+            reason: DeadLetterReason::Shedded,
+            attempts: 0,
+            last_error: None,
+            route_to: Some(dlq),
+        });
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert_eq!(*delivered.lock().unwrap(), vec![vec![7]]);
+        assert!(ledger.dead_letters().is_empty());
+
+        coordinator.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_requeued_messages_routed_to_dead_letter_target() {
+        use crate::component::requeue::PendingMessage;
+
+        let mut coordinator = create_test_coordinator();
+        let ledger = Arc::new(RequeueLedger::new());
+        let dlq = create_test_id("dlq");
+        let policy = RequeuePolicy::new(1).with_dead_letter(dlq.clone());
+        coordinator.set_requeue(policy.clone(), Arc::clone(&ledger));
+
+        let delivered = Arc::new(Mutex::new(0));
+        let sink = Arc::clone(&delivered);
+        coordinator
+            .subscriber()
+            .register_mailbox(
+                dlq,
+                Box::new(move |_msg| {
+                    *sink.lock().unwrap() += 1;
+                    Ok(())
+                }),
+            )
+            .unwrap();
+
+        // A restart finds the message already failed once: it is poison
+        let worker = create_test_id("worker");
+        ledger.record_failure(
+            &worker,
+            vec![PendingMessage {
+                message: ComponentMessage::new(
+                    create_test_id("sender"),
+                    MessagePayload::new(vec![1]),
+                    MessageMetadata::default(),
+                ),
+                attempts: 1,
+                last_error: Some("boom".to_string()),
+            }],
+        );
+        assert!(ledger.take_for_restart(&worker, &policy).is_empty());
+
+        assert_eq!(coordinator.route_dead_letters(), 1);
+        assert_eq!(*delivered.lock().unwrap(), 1);
+        assert!(ledger.dead_letters().is_empty());
         coordinator.actor_system.force_shutdown().await;
     }
 
     #[tokio::test]
-    async fn test_start_when_already_running_returns_error() {
+    async fn test_metrics_snapshot_merges_component_metrics() {
         let mut coordinator = create_test_coordinator();
+        coordinator
+            .register_plugin(Box::new(GatePlugin::new(false)))
+            .unwrap();
         coordinator.start().unwrap();
 
-        let result = coordinator.start();
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("already running"));
-        coordinator.actor_system.force_shutdown().await;
+        let id = create_test_id("comp-a");
+        coordinator.load_component(id.clone()).await.unwrap();
+        coordinator
+            .component_metrics()
+            .increment_counter(&id, "jobs_done", &[], 2)
+            .unwrap();
+
+        let snapshot = coordinator.metrics_snapshot().unwrap();
+        assert_eq!(snapshot.samples().len(), 2);
+        assert_eq!(snapshot.samples()[0].name, "gate.loaded");
+        let text = snapshot.to_prometheus();
+        assert!(text.contains("jobs_done{component=\"test/comp-a/v1\"} 2"));
+
+        coordinator.unload_component(&id).unwrap();
+        assert_eq!(coordinator.metrics_snapshot().unwrap().samples().len(), 1);
+
+        coordinator.shutdown().await.unwrap();
     }
 
     #[tokio::test]
-    async fn test_shutdown_when_not_running_is_noop() {
+    async fn test_plugin_interceptor_drops_message() {
         let mut coordinator = create_test_coordinator();
-        // Not started, shutdown should be a no-op
-        let result = coordinator.shutdown().await;
-        assert!(result.is_ok());
-        assert!(!coordinator.is_running());
+        coordinator
+            .register_plugin(Box::new(GatePlugin::new(false)))
+            .unwrap();
+        coordinator.start().unwrap();
+
+        let target = create_test_id("target");
+        let empty = ComponentMessage::new(
+            create_test_id("sender"),
+            MessagePayload::new(Vec::new()),
+            MessageMetadata::default(),
+        );
+        let result = coordinator.deliver_message(&target, empty);
+        assert!(matches!(
+            result,
+            Err(SystemError::Messaging(CoreMessagingError::DeliveryFailed(ref reason)))
+                if reason.contains("gate: empty payload")
+        ));
+
+        let message = ComponentMessage::new(
+            create_test_id("sender"),
+            MessagePayload::new(vec![1]),
+            MessageMetadata::default(),
+        );
+        let result = coordinator.deliver_message(&target, message);
+        assert!(matches!(
+            result,
+            Err(SystemError::Messaging(CoreMessagingError::TargetNotFound(
+                _
+            )))
+        ));
+
+        coordinator.shutdown().await.unwrap();
+    }
+
+    // ========================================================================
+    // Resource Reservation Tests
+    // ========================================================================
+
+    fn sized_config(name: &str, memory_mib: u64, critical: bool) -> ComponentConfig {
+        ComponentConfig::new(create_test_id(name))
+            .with_max_memory(memory_mib * 1024 * 1024)
+            .with_critical(critical)
+    }
+
+    #[tokio::test]
+    async fn test_reserve_critical_requires_capacity() {
+        let coordinator = create_test_coordinator();
+        let result = coordinator.reserve_critical(&sized_config("db", 16, true));
+        assert!(matches!(result, Err(SystemError::InitializationFailed(_))));
         coordinator.actor_system.force_shutdown().await;
     }
 
     #[tokio::test]
-    async fn test_shutdown_sets_not_running() {
+    async fn test_critical_reservation_refuses_lower_priority() {
         let mut coordinator = create_test_coordinator();
+        coordinator.set_host_capacity(HostCapacity::new(64 * 1024 * 1024, 3, 0));
         coordinator.start().unwrap();
-        assert!(coordinator.is_running());
 
-        let result = coordinator.shutdown().await;
-        assert!(result.is_ok());
-        assert!(!coordinator.is_running());
+        let db = sized_config("db", 32, true);
+        coordinator.reserve_critical(&db).unwrap();
+
+        coordinator
+            .load_component_with_config(&sized_config("cache", 32, false))
+            .await
+            .unwrap();
+        let refused = coordinator
+            .load_component_with_config(&sized_config("batch", 16, false))
+            .await;
+        assert!(matches!(refused, Err(SystemError::Reservation(_))));
+        assert!(!coordinator
+            .registry()
+            .contains(&create_test_id("batch"))
+            .unwrap());
+
+        coordinator.load_component_with_config(&db).await.unwrap();
+        assert_eq!(coordinator.registry().count().unwrap(), 2);
+
+        coordinator.actor_system.force_shutdown().await;
     }
 
     #[tokio::test]
-    async fn test_start_after_shutdown_returns_error() {
+    async fn test_unload_keeps_critical_reservation() {
         let mut coordinator = create_test_coordinator();
+        coordinator.set_host_capacity(HostCapacity::new(0, 2, 0));
         coordinator.start().unwrap();
-        coordinator.shutdown().await.unwrap();
-        assert!(!coordinator.is_running());
 
-        // Cannot restart after shutdown — actor system is no longer usable.
-        let result = coordinator.start();
-        assert!(result.is_err());
-        assert!(result
-            .unwrap_err()
-            .to_string()
-            .contains("already shut down"));
+        let db = sized_config("db", 1, true);
+        coordinator.load_component_with_config(&db).await.unwrap();
+        coordinator.unload_component(db.id()).unwrap();
+
+        let admission = coordinator.admission().unwrap();
+        assert!(admission.is_reserved(db.id()).unwrap());
+        assert!(!admission.is_admitted(db.id()).unwrap());
+
+        coordinator
+            .load_component(create_test_id("a"))
+            .await
+            .unwrap();
+        let refused = coordinator.load_component(create_test_id("b")).await;
+        assert!(matches!(refused, Err(SystemError::Reservation(_))));
+
+        coordinator.actor_system.force_shutdown().await;
     }
 
-    // ========================================
-    // 3. Component Load/Unload Tests
-    // ========================================
-
-    #[tokio::test]
-    async fn test_load_component_when_not_running_returns_error() {
-        let coordinator = create_test_coordinator();
-        let id = create_test_id("test-comp");
+    // ========================================================================
+    // Live Config Tests
+    // ========================================================================
 
-        let result = coordinator.load_component(id).await;
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("not running"));
+    fn threshold(n: i64) -> ConfigValues {
+        ConfigValues::new().with("threshold", ConfigValue::Integer(n))
     }
 
     #[tokio::test]
-    async fn test_load_component_succeeds() {
+    async fn test_reconfigure_component_accept_and_reject() {
         let mut coordinator = create_test_coordinator();
         coordinator.start().unwrap();
 
-        let id = create_test_id("my-component");
-        let result = coordinator.load_component(id.clone()).await;
-        assert!(result.is_ok());
+        let id = create_test_id("tunable");
+        let config = ComponentConfig::new(id.clone()).with_config_values(threshold(10));
+        coordinator
+            .load_component_with_config(&config)
+            .await
+            .unwrap();
+        // Give the actor system's router task time to subscribe to the broker
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let wait = Duration::from_secs(2);
 
-        // Verify component is in the registry
-        assert!(coordinator.registry().contains(&id).unwrap());
+        coordinator
+            .reconfigure_component(&id, threshold(20), wait)
+            .await
+            .unwrap();
+        assert_eq!(
+            coordinator.component_config_values(&id),
+            Some(threshold(20))
+        );
+
+        let rejected = coordinator
+            .reconfigure_component(&id, threshold(-5), wait)
+            .await;
+        assert!(matches!(
+            rejected,
+            Err(SystemError::Reconfigure(ConfigUpdateError::Rejected(_)))
+        ));
+        assert_eq!(
+            coordinator.component_config_values(&id),
+            Some(threshold(20))
+        );
 
         coordinator.actor_system.force_shutdown().await;
     }
 
     #[tokio::test]
-    async fn test_load_multiple_components() {
+    async fn test_reconfigure_component_validates_first() {
         let mut coordinator = create_test_coordinator();
         coordinator.start().unwrap();
 
-        let id1 = create_test_id("comp-1");
-        let id2 = create_test_id("comp-2");
-        let id3 = create_test_id("comp-3");
+        let id = create_test_id("tunable");
+        let config = ComponentConfig::new(id.clone()).with_config_values(threshold(10));
+        coordinator
+            .load_component_with_config(&config)
+            .await
+            .unwrap();
 
-        coordinator.load_component(id1.clone()).await.unwrap();
-        coordinator.load_component(id2.clone()).await.unwrap();
-        coordinator.load_component(id3.clone()).await.unwrap();
+        let unknown = ConfigValues::new().with("mode", ConfigValue::Text("fast".into()));
+        let result = coordinator
+            .reconfigure_component(&id, unknown, Duration::from_millis(10))
+            .await;
+        assert!(matches!(
+            result,
+            Err(SystemError::Reconfigure(ConfigUpdateError::UnknownKey(_)))
+        ));
 
-        assert_eq!(coordinator.registry().count().unwrap(), 3);
-        assert!(coordinator.registry().contains(&id1).unwrap());
-        assert!(coordinator.registry().contains(&id2).unwrap());
-        assert!(coordinator.registry().contains(&id3).unwrap());
+        let missing = coordinator
+            .reconfigure_component(&create_test_id("missing"), threshold(1), Duration::ZERO)
+            .await;
+        assert!(matches!(missing, Err(SystemError::ComponentSpawner(_))));
 
         coordinator.actor_system.force_shutdown().await;
     }
 
     #[tokio::test]
-    async fn test_unload_component_succeeds() {
+    async fn test_stop_component_drains_before_unload() {
         let mut coordinator = create_test_coordinator();
+        coordinator.set_drain_deadline(Duration::from_secs(2));
         coordinator.start().unwrap();
 
-        let id = create_test_id("unload-me");
+        let id = create_test_id("drained");
         coordinator.load_component(id.clone()).await.unwrap();
-        assert!(coordinator.registry().contains(&id).unwrap());
+        // Give the actor system's router task time to subscribe to the broker
+        tokio::time::sleep(Duration::from_millis(20)).await;
 
-        let result = coordinator.unload_component(&id);
-        assert!(result.is_ok());
+        let report = coordinator
+            .stop_component(&id, Duration::from_secs(2))
+            .await
+            .unwrap();
+        assert!(report.all_ready());
+        assert_eq!(report.ready, vec![id.clone()]);
         assert!(!coordinator.registry().contains(&id).unwrap());
 
-        coordinator.actor_system.force_shutdown().await;
-    }
-
-    #[tokio::test]
-    async fn test_unload_component_when_not_running_returns_error() {
-        let coordinator = create_test_coordinator();
-        let id = create_test_id("test-comp");
+        // Unknown components cannot answer and are reported at the deadline
+        let report = coordinator
+            .drain_components(&[create_test_id("missing")], Duration::from_millis(10))
+            .await
+            .unwrap();
+        assert_eq!(report.timed_out.len(), 1);
 
-        let result = coordinator.unload_component(&id);
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("not running"));
         coordinator.actor_system.force_shutdown().await;
     }
 
+    // ========================================================================
+    // Event Log Tests
+    // ========================================================================
+
     #[tokio::test]
-    async fn test_unload_nonexistent_component_returns_error() {
+    async fn test_event_log_records_lifecycle_and_replays() {
         let mut coordinator = create_test_coordinator();
         coordinator.start().unwrap();
 
-        let id = create_test_id("nonexistent");
-        let result = coordinator.unload_component(&id);
-        assert!(result.is_err());
-        // SpawnerError::NotSpawned wraps into SystemError::ComponentSpawner
-        assert!(result.unwrap_err().to_string().contains("Component error"));
+        let id = create_test_id("tunable");
+        coordinator
+            .record_event(HostEvent::ComponentInstalled {
+                component: id.clone(),
+                source: "file:///plugins/tunable.wasm".to_string(),
+            })
+            .unwrap();
+        let config = ComponentConfig::new(id.clone()).with_config_values(threshold(10));
+        coordinator
+            .load_component_with_config(&config)
+            .await
+            .unwrap();
+        // Give the actor system's router task time to subscribe to the broker
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        coordinator
+            .reconfigure_component(&id, threshold(30), Duration::from_secs(2))
+            .await
+            .unwrap();
 
-        coordinator.actor_system.force_shutdown().await;
-    }
+        let state = coordinator.event_log().replay().unwrap();
+        let tunable = state.component(&id).unwrap();
+        assert!(tunable.running);
+        assert_eq!(tunable.config, threshold(30));
 
-    // ========================================
-    // 4. Accessor Tests
-    // ========================================
+        coordinator.unload_component(&id).unwrap();
+        let state = coordinator.event_log().replay().unwrap();
+        assert!(!state.component(&id).unwrap().running);
+        assert_eq!(coordinator.event_log().len(), 4);
 
-    #[tokio::test]
-    async fn test_accessor_registry() {
-        let coordinator = create_test_coordinator();
-        let registry = coordinator.registry();
-        assert_eq!(registry.count().unwrap(), 0);
         coordinator.actor_system.force_shutdown().await;
     }
 
     #[tokio::test]
-    async fn test_accessor_subscriber() {
+    async fn test_first_deprecated_call_is_logged_once() {
         let coordinator = create_test_coordinator();
-        let subscriber = coordinator.subscriber();
-        assert_eq!(subscriber.count().unwrap(), 0);
-        coordinator.actor_system.force_shutdown().await;
-    }
+        let users = create_test_id("users");
+        let billing = create_test_id("billing");
+        coordinator.deprecations().declare(
+            users.clone(),
+            Deprecations::new().with(Deprecation::new("get-user", "1.4.0")),
+        );
+
+        for _ in 0..3 {
+            coordinator
+                .record_export_call(&billing, &users, "get-user")
+                .unwrap();
+        }
+        coordinator
+            .record_export_call(&billing, &users, "get-user-v2")
+            .unwrap();
+
+        let records = coordinator.event_log().records().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            records[0].event,
+            HostEvent::DeprecatedExportCalled {
+                component: users.clone(),
+                export: "get-user".to_string(),
+                caller: billing.clone(),
+            }
+        );
+        // Not a state change
+        assert!(coordinator
+            .event_log()
+            .replay()
+            .unwrap()
+            .component(&users)
+            .is_none());
+        assert_eq!(coordinator.deprecations().report().rows[0].calls, 3);
 
-    #[tokio::test]
-    async fn test_accessor_correlation_tracker() {
-        let coordinator = create_test_coordinator();
-        let _tracker = coordinator.correlation_tracker();
-        // CorrelationTrackerImpl exists and is accessible
         coordinator.actor_system.force_shutdown().await;
     }
 
     #[tokio::test]
-    async fn test_accessor_is_running() {
+    async fn test_state_view_answers_queries() {
         let mut coordinator = create_test_coordinator();
-        assert!(!coordinator.is_running());
-
         coordinator.start().unwrap();
-        assert!(coordinator.is_running());
-        coordinator.actor_system.force_shutdown().await;
-    }
+        let id = create_test_id("queried");
+        coordinator.load_component(id.clone()).await.unwrap();
+        coordinator
+            .record_event(HostEvent::CapabilityGranted {
+                component: id.clone(),
+                capability: "storage:read:*".to_string(),
+            })
+            .unwrap();
+
+        let view = coordinator.state_view(Vec::new()).unwrap();
+        let result = view
+            .query("SELECT component, capabilities FROM components WHERE running = TRUE")
+            .unwrap();
+        assert_eq!(result.rows.len(), 1);
+        assert_eq!(
+            result.rows[0][0],
+            crate::system::query::Value::Text(id.to_string_id())
+        );
+        assert_eq!(result.rows[0][1], crate::system::query::Value::Int(1));
+        assert!(view.query("UPDATE components SET running = FALSE").is_err());
 
-    #[tokio::test]
-    async fn test_component_count_empty() {
-        let coordinator = create_test_coordinator();
-        assert_eq!(coordinator.component_count().unwrap(), 0);
         coordinator.actor_system.force_shutdown().await;
     }
 
     #[tokio::test]
-    async fn test_component_count_after_load() {
+    async fn test_approved_elevation_applies_on_restart() {
         let mut coordinator = create_test_coordinator();
         coordinator.start().unwrap();
+        let id = create_test_id("elevated");
+        coordinator.load_component(id.clone()).await.unwrap();
 
-        coordinator
-            .load_component(create_test_id("c1"))
-            .await
+        // Filed by the running component through `request-capability`
+        let request = coordinator
+            .elevation_requests()
+            .submit(&id, "storage:write:cache/*", "persist cache")
             .unwrap();
-        assert_eq!(coordinator.component_count().unwrap(), 1);
+        coordinator.approve_elevation(request, "ops").unwrap();
+        assert!(coordinator.deny_elevation(request, "ops", "late").is_err());
 
-        coordinator
-            .load_component(create_test_id("c2"))
-            .await
-            .unwrap();
-        assert_eq!(coordinator.component_count().unwrap(), 2);
+        // Running instance is unchanged until restart
+        let state = coordinator.event_log().replay().unwrap();
+        assert!(state.component(&id).unwrap().capabilities.is_empty());
+
+        coordinator.unload_component(&id).unwrap();
+        coordinator.load_component(id.clone()).await.unwrap();
+
+        let state = coordinator.event_log().replay().unwrap();
+        assert!(state
+            .component(&id)
+            .unwrap()
+            .capabilities
+            .contains("storage:write:cache/*"));
+        assert!(coordinator
+            .elevation_requests()
+            .pending()
+            .unwrap()
+            .is_empty());
 
         coordinator.actor_system.force_shutdown().await;
     }
 
     #[tokio::test]
-    async fn test_shutdown_failed_stops_initially_zero() {
-        let coordinator = create_test_coordinator();
-        assert_eq!(coordinator.shutdown_failed_stops(), 0);
-        coordinator.actor_system.force_shutdown().await;
-    }
+    async fn test_approved_elevation_allows_guarded_host_call_after_restart() {
+        let dir = std::env::temp_dir().join(format!("airssys-elevate-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir_str = dir.to_string_lossy().into_owned();
+        let path = format!("{dir_str}/data.txt");
+        std::fs::write(&path, b"report").unwrap();
+
+        let id = create_test_id("reader");
+        let validator = Arc::new(CapabilityValidator::new());
+        validator.register_component(id.clone(), CapabilitySet::new());
+        let bridge =
+            OslOperationBridge::new(validator.clone(), CapabilityMapping::default()).unwrap();
+        let read = || OsOperation::FileRead { path: path.clone() };
+
+        let mut coordinator = SystemCoordinator::new(
+            Arc::new(MockRuntimeEngine),
+            Arc::new(MockComponentLoader),
+            validator,
+            Arc::new(MockAuditLogger),
+            SystemConfig::default(),
+            InMemoryMessageBroker::<ComponentActorMessage>::new(),
+        );
+        coordinator.start().unwrap();
+        coordinator.load_component(id.clone()).await.unwrap();
+        assert!(bridge.execute(&id, read()).is_err());
 
-    // ========================================
-    // 5. Error Type Tests
-    // ========================================
+        let capability = format!("filesystem:read:{dir_str}/*");
+        let request = coordinator
+            .elevation_requests()
+            .submit(&id, &capability, "read reports")
+            .unwrap();
+        coordinator.approve_elevation(request, "ops").unwrap();
+        assert!(bridge.execute(&id, read()).is_err());
 
-    #[test]
-    fn test_system_error_display_already_running() {
-        let err = SystemError::AlreadyRunning;
-        assert_eq!(err.to_string(), "System already running");
-    }
+        coordinator.unload_component(&id).unwrap();
+        coordinator.load_component(id.clone()).await.unwrap();
+        assert_eq!(bridge.execute(&id, read()).unwrap(), b"report");
 
-    #[test]
-    fn test_system_error_display_not_running() {
-        let err = SystemError::NotRunning;
-        assert_eq!(err.to_string(), "System not running");
+        coordinator.actor_system.force_shutdown().await;
+        std::fs::remove_dir_all(&dir).unwrap();
     }
 
-    #[test]
-    fn test_system_error_display_already_shut_down() {
-        let err = SystemError::AlreadyShutDown;
-        assert_eq!(err.to_string(), "System already shut down");
-    }
+    #[derive(Default)]
+    struct MemoryStorage(Mutex<HashMap<String, StorageValue>>);
 
-    #[test]
-    fn test_system_error_display_shutdown_failed() {
-        let rt_err = RtSystemError::ShuttingDown;
-        let err = SystemError::ShutdownFailed(rt_err);
-        assert!(err.to_string().contains("Shutdown failed"));
+    impl ComponentStorage for MemoryStorage {
+        fn get(&self, key: &str) -> Result<Option<StorageValue>, StorageError> {
+            Ok(self.0.lock().unwrap().get(key).cloned())
+        }
+        fn set(&self, key: &str, value: StorageValue) -> Result<(), StorageError> {
+            self.0.lock().unwrap().insert(key.to_string(), value);
+            Ok(())
+        }
+        fn delete(&self, key: &str) -> Result<(), StorageError> {
+            self.0.lock().unwrap().remove(key);
+            Ok(())
+        }
+        fn exists(&self, key: &str) -> Result<bool, StorageError> {
+            Ok(self.0.lock().unwrap().contains_key(key))
+        }
+        fn list_keys(&self, prefix: Option<&str>) -> Result<Vec<String>, StorageError> {
+            let data = self.0.lock().unwrap();
+            Ok(data
+                .keys()
+                .filter(|k| prefix.is_none_or(|p| k.starts_with(p)))
+                .cloned()
+                .collect())
+        }
     }
 
-    #[test]
-    fn test_system_error_display_component_spawner() {
-        let spawner_err = SpawnerError::NotSpawned("test/comp/v1".to_string());
-        let err = SystemError::ComponentSpawner(spawner_err);
-        assert!(err.to_string().contains("Component error"));
+    fn spooled_coordinator(storage: &Arc<MemoryStorage>) -> (TestCoordinator, Arc<MessageSpool>) {
+        let spool = Arc::new(MessageSpool::new(SpoolConfig {
+            default_guarantee: DeliveryGuarantee::AtLeastOnce,
+            ..SpoolConfig::default()
+        }));
+        let mut coordinator = create_test_coordinator();
+        coordinator.set_message_spool(Arc::clone(&spool), storage.clone());
+        (coordinator, spool)
     }
 
-    #[test]
-    fn test_system_error_display_component_registry() {
-        let reg_err = RegistryError::LockPoisoned("test".to_string());
-        let err = SystemError::ComponentRegistry(reg_err);
-        assert!(err.to_string().contains("Registry error"));
-    }
+    #[tokio::test]
+    async fn test_spooled_messages_survive_coordinator_restart() {
+        let storage = Arc::new(MemoryStorage::default());
+        let id = create_test_id("inbox");
+        let message = ComponentMessage::new(
+            create_test_id("sender"),
+            MessagePayload::new(vec![7]),
+            MessageMetadata::default(),
+        );
 
-    #[test]
-    fn test_system_error_from_spawner_error() {
-        let spawner_err = SpawnerError::NotSpawned("test/comp/v1".to_string());
-        let system_err: SystemError = spawner_err.into();
-        assert!(system_err.to_string().contains("Component error"));
-        // Source error is preserved (not stringified)
-        assert!(std::error::Error::source(&system_err).is_some());
-    }
+        let (mut first, spool) = spooled_coordinator(&storage);
+        first.start().unwrap();
+        spool.enqueue(id.clone(), message).unwrap();
+        first.shutdown().await.unwrap();
+        assert_eq!(spool.pending().unwrap(), 0);
+        assert_eq!(spool.metrics().saved, 1);
+
+        let (mut second, spool) = spooled_coordinator(&storage);
+        second.start().unwrap();
+        assert_eq!(spool.pending().unwrap(), 1);
+        assert!(storage.0.lock().unwrap().is_empty());
+
+        let delivered = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&delivered);
+        second
+            .subscriber()
+            .register_mailbox(
+                id.clone(),
+                Box::new(move |msg| {
+                    sink.lock().unwrap().push(msg.payload.as_bytes().to_vec());
+                    Ok(())
+                }),
+            )
+            .unwrap();
+        second.load_component(id).await.unwrap();
+        assert_eq!(spool.pending().unwrap(), 0);
+        assert_eq!(*delivered.lock().unwrap(), vec![vec![7]]);
 
-    #[test]
-    fn test_system_error_from_registry_error() {
-        let reg_err = RegistryError::LockPoisoned("test".to_string());
-        let system_err: SystemError = reg_err.into();
-        assert!(system_err.to_string().contains("Registry error"));
-        assert!(std::error::Error::source(&system_err).is_some());
+        second.actor_system.force_shutdown().await;
     }
 
-    #[test]
-    fn test_system_error_from_messaging_error() {
-        let messaging_err = CoreMessagingError::DeliveryFailed("connection lost".to_string());
-        let system_err: SystemError = messaging_err.into();
-        assert!(system_err.to_string().contains("Messaging error"));
-        assert!(std::error::Error::source(&system_err).is_some());
-    }
+    #[tokio::test]
+    async fn test_spool_redelivery_runs_while_started() {
+        let storage = Arc::new(MemoryStorage::default());
+        let (mut coordinator, spool) = spooled_coordinator(&storage);
+        coordinator.set_spool_redelivery_period(Duration::from_millis(5));
+        coordinator.start().unwrap();
 
-    #[test]
-    fn test_system_error_from_rt_system_error() {
-        let rt_err = RtSystemError::ShuttingDown;
-        let system_err: SystemError = rt_err.into();
-        assert!(system_err.to_string().contains("Shutdown failed"));
-        assert!(std::error::Error::source(&system_err).is_some());
-    }
+        let id = create_test_id("late");
+        let delivered = Arc::new(Mutex::new(0));
+        let sink = Arc::clone(&delivered);
+        coordinator
+            .subscriber()
+            .register_mailbox(
+                id.clone(),
+                Box::new(move |_msg| {
+                    *sink.lock().unwrap() += 1;
+                    Ok(())
+                }),
+            )
+            .unwrap();
+        spool
+            .enqueue(
+                id,
+                ComponentMessage::new(
+                    create_test_id("sender"),
+                    MessagePayload::new(vec![1]),
+                    MessageMetadata::default(),
+                ),
+            )
+            .unwrap();
 
-    // ========================================
-    // 6. Debug Trait Test
-    // ========================================
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert_eq!(*delivered.lock().unwrap(), 1);
+        assert_eq!(spool.pending().unwrap(), 0);
+
+        coordinator.shutdown().await.unwrap();
+    }
 
     #[tokio::test]
-    async fn test_coordinator_debug_format() {
+    async fn test_lockdown_command_round_trip() {
         let coordinator = create_test_coordinator();
-        let debug_str = format!("{:?}", coordinator);
-        assert!(debug_str.contains("SystemCoordinator"));
-        assert!(debug_str.contains("is_running"));
-        assert!(debug_str.contains("is_shutdown"));
-        assert!(debug_str.contains("created_at"));
-        // finish_non_exhaustive() appends ".." to indicate omitted fields
-        assert!(debug_str.contains(".."));
-        coordinator.actor_system.force_shutdown().await;
+        let status = LockdownCommand::Status;
+        assert!(coordinator
+            .run_lockdown_command(&status, "ops")
+            .unwrap()
+            .is_none());
+
+        let on: LockdownCommand = "on credential leak".parse().unwrap();
+        coordinator.run_lockdown_command(&on, "ops").unwrap();
+        assert!(coordinator.lockdown().is_engaged());
+        assert!(matches!(
+            coordinator.run_lockdown_command(&on, "ops"),
+            Err(SystemError::Lockdown(LockdownError::AlreadyEngaged(_)))
+        ));
+
+        let window = coordinator
+            .run_lockdown_command(&LockdownCommand::Off, "lead")
+            .unwrap()
+            .unwrap();
+        assert_eq!(window.reason, "credential leak");
+        assert_eq!(window.lifted_by.as_deref(), Some("lead"));
+        assert!(!coordinator.lockdown().is_engaged());
     }
 
-    // ========================================
-    // 7. Thread Safety Test
-    // ========================================
-
-    #[test]
-    fn test_coordinator_is_send() {
-        fn assert_send<T: Send>() {}
-        assert_send::<TestCoordinator>();
+    #[tokio::test]
+    async fn test_engaged_lockdown_denies_bridged_and_storage_writes() {
+        let dir = std::env::temp_dir().join(format!("airssys-lockdown-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir_str = dir.to_string_lossy().into_owned();
+        let path = format!("{dir_str}/out.txt");
+
+        let id = create_test_id("writer");
+        let validator = Arc::new(CapabilityValidator::new());
+        validator.register_component(
+            id.clone(),
+            CapabilitySet::builder()
+                .filesystem(FilesystemPermission {
+                    can_read_paths: vec![],
+                    can_write_paths: vec![format!("{dir_str}/*")],
+                    can_execute_paths: vec![],
+                })
+                .storage(StoragePermission {
+                    can_write_keys: vec!["cache/*".to_string()],
+                    can_read_keys: vec!["cache/*".to_string()],
+                })
+                .build(),
+        );
+        let coordinator = SystemCoordinator::new(
+            Arc::new(MockRuntimeEngine),
+            Arc::new(MockComponentLoader),
+            validator,
+            Arc::new(MockAuditLogger),
+            SystemConfig::default(),
+            InMemoryMessageBroker::<ComponentActorMessage>::new(),
+        );
+        let bridge = OslOperationBridge::new(
+            coordinator.security_validator().clone(),
+            CapabilityMapping::default(),
+        )
+        .unwrap();
+        let write = |content: &[u8]| OsOperation::FileWrite {
+            path: path.clone(),
+            content: content.to_vec(),
+        };
+        let put = Capability::Storage(StorageCapability {
+            action: StorageAction::Write,
+            namespace_pattern: "cache/a".to_string(),
+        });
+
+        coordinator.engage_lockdown("ops", "incident").unwrap();
+        assert!(bridge.execute(&id, write(b"locked")).is_err());
+        assert!(!std::path::Path::new(&path).exists());
+        assert!(coordinator
+            .security_validator()
+            .validate_capability(&id, &put)
+            .is_err());
+
+        let window = coordinator.lift_lockdown("ops").unwrap();
+        assert_eq!(window.denials.len(), 2);
+        bridge.execute(&id, write(b"open")).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"open");
+        assert!(coordinator
+            .security_validator()
+            .validate_capability(&id, &put)
+            .is_ok());
+
+        std::fs::remove_dir_all(&dir).unwrap();
     }
 }
@@ -0,0 +1,285 @@
+//! Tracking calls to deprecated component exports.
+//!
+//! [`DeprecationTracker`] holds each component's
+//! [`Deprecations`](crate::core::config::deprecation::Deprecations) and
+//! counts calls to deprecated exports per caller. The first call from a
+//! caller is logged through `tracing` and reported as new so the host can
+//! record a `DeprecatedExportCalled` event; [`DeprecationTracker::report`]
+//! lists the callers that must migrate, for an `audit` command to print.
+
+// Layer 1: Standard library imports
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::RwLock;
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Utc};
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::config::deprecation::{Deprecation, Deprecations};
+
+/// Outcome of recording one call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeprecatedCall {
+    /// The export is not deprecated.
+    Current,
+    /// The export is deprecated and the caller has used it before.
+    Repeated(Deprecation),
+    /// First call of the deprecated export by this caller.
+    FirstCall(Deprecation),
+}
+
+#[derive(Debug, Clone)]
+struct CallerUsage {
+    calls: u64,
+    first_seen: DateTime<Utc>,
+    last_seen: DateTime<Utc>,
+}
+
+type CallKey = (ComponentId, String, ComponentId);
+
+/// Per-host registry of deprecated exports and their callers.
+#[derive(Debug, Default)]
+pub struct DeprecationTracker {
+    declared: RwLock<HashMap<ComponentId, Deprecations>>,
+    calls: RwLock<HashMap<CallKey, CallerUsage>>,
+}
+
+impl DeprecationTracker {
+    /// Creates an empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the deprecated exports of `component`, replacing earlier ones.
+    ///
+    /// Call counts are kept, so a reload does not hide past callers.
+    pub fn declare(&self, component: ComponentId, deprecations: Deprecations) {
+        if let Ok(mut declared) = self.declared.write() {
+            if deprecations.is_empty() {
+                declared.remove(&component);
+            } else {
+                declared.insert(component, deprecations);
+            }
+        }
+    }
+
+    /// Drops `component`'s deprecations and the calls made to it.
+    pub fn forget(&self, component: &ComponentId) {
+        if let Ok(mut declared) = self.declared.write() {
+            declared.remove(component);
+        }
+        if let Ok(mut calls) = self.calls.write() {
+            calls.retain(|(callee, _, _), _| callee != component);
+        }
+    }
+
+    /// Records that `caller` invoked `export` on `component` at `at`.
+    pub fn record_call(
+        &self,
+        caller: &ComponentId,
+        component: &ComponentId,
+        export: &str,
+        at: DateTime<Utc>,
+    ) -> DeprecatedCall {
+        let Some(deprecation) = self
+            .declared
+            .read()
+            .ok()
+            .and_then(|declared| declared.get(component)?.get(export).cloned())
+        else {
+            return DeprecatedCall::Current;
+        };
+        let Ok(mut calls) = self.calls.write() else {
+            return DeprecatedCall::Repeated(deprecation);
+        };
+
+        let key = (component.clone(), export.to_string(), caller.clone());
+        match calls.get_mut(&key) {
+            Some(usage) => {
+                usage.calls += 1;
+                usage.last_seen = at;
+                DeprecatedCall::Repeated(deprecation)
+            }
+            None => {
+                calls.insert(
+                    key,
+                    CallerUsage {
+                        calls: 1,
+                        first_seen: at,
+                        last_seen: at,
+                    },
+                );
+                tracing::warn!(
+                    caller = %caller,
+                    component = %component,
+                    export,
+                    "{}",
+                    deprecation
+                );
+                DeprecatedCall::FirstCall(deprecation)
+            }
+        }
+    }
+
+    /// Callers of deprecated exports, sorted by component, export, caller.
+    pub fn report(&self) -> DeprecationReport {
+        let declared = self.declared.read().ok();
+        let mut rows: BTreeMap<(String, String, String), DeprecationRow> = BTreeMap::new();
+        if let (Some(declared), Ok(calls)) = (declared, self.calls.read()) {
+            for ((component, export, caller), usage) in calls.iter() {
+                let Some(deprecation) = declared
+                    .get(component)
+                    .and_then(|deprecations| deprecations.get(export))
+                else {
+                    continue;
+                };
+                rows.insert(
+                    (
+                        component.to_string_id(),
+                        export.clone(),
+                        caller.to_string_id(),
+                    ),
+                    DeprecationRow {
+                        component: component.clone(),
+                        caller: caller.clone(),
+                        deprecation: deprecation.clone(),
+                        calls: usage.calls,
+                        first_seen: usage.first_seen,
+                        last_seen: usage.last_seen,
+                    },
+                );
+            }
+        }
+        DeprecationReport {
+            rows: rows.into_values().collect(),
+        }
+    }
+}
+
+/// One caller of one deprecated export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeprecationRow {
+    /// Component owning the export.
+    pub component: ComponentId,
+    /// Component calling it.
+    pub caller: ComponentId,
+    /// The export's deprecation notice.
+    pub deprecation: Deprecation,
+    /// Calls recorded.
+    pub calls: u64,
+    /// First recorded call.
+    pub first_seen: DateTime<Utc>,
+    /// Latest recorded call.
+    pub last_seen: DateTime<Utc>,
+}
+
+/// Callers that still depend on deprecated exports.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeprecationReport {
+    /// Rows sorted by component, export, caller.
+    pub rows: Vec<DeprecationRow>,
+}
+
+impl DeprecationReport {
+    /// Rows whose export is removed in major version `major` or earlier.
+    ///
+    /// Exports with an unparseable version are always included.
+    pub fn due_by_major(&self, major: u64) -> Vec<&DeprecationRow> {
+        self.rows
+            .iter()
+            .filter(|row| {
+                row.deprecation
+                    .removal_major()
+                    .is_none_or(|removal| removal <= major)
+            })
+            .collect()
+    }
+
+    /// Callers that must migrate, sorted and deduplicated.
+    pub fn callers(&self) -> Vec<&ComponentId> {
+        let mut callers: Vec<_> = self.rows.iter().map(|row| &row.caller).collect();
+        callers.sort_by_key(|id| id.to_string_id());
+        callers.dedup();
+        callers
+    }
+}
+
+impl fmt::Display for DeprecationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.rows {
+            write!(
+                f,
+                "{}  {}  {}  calls={}  last_called={}",
+                row.caller,
+                row.component,
+                row.deprecation.export,
+                row.calls,
+                row.last_seen.to_rfc3339()
+            )?;
+            if let Some(version) = &row.deprecation.removed_in {
+                write!(f, "  removed_in={}", version)?;
+            }
+            if let Some(replacement) = &row.deprecation.replacement {
+                write!(f, "  use={}", replacement)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_calls_to_deprecated_exports_are_reported_per_caller() {
+        let tracker = DeprecationTracker::new();
+        let users = ComponentId::new("acme", "users", "v1");
+        let billing = ComponentId::new("acme", "billing", "v1");
+        let search = ComponentId::new("acme", "search", "v1");
+        tracker.declare(
+            users.clone(),
+            Deprecations::new()
+                .with(Deprecation::new("get-user", "1.4.0").with_replacement("get-user-v2"))
+                .with(Deprecation::new("list-users", "1.0.0").with_removed_in("4.0.0")),
+        );
+        let at = |minute| Utc.with_ymd_and_hms(2025, 1, 8, 10, minute, 0).unwrap();
+
+        assert_eq!(
+            tracker.record_call(&billing, &users, "get-user-v2", at(0)),
+            DeprecatedCall::Current
+        );
+        assert!(matches!(
+            tracker.record_call(&billing, &users, "get-user", at(1)),
+            DeprecatedCall::FirstCall(_)
+        ));
+        assert!(matches!(
+            tracker.record_call(&billing, &users, "get-user", at(2)),
+            DeprecatedCall::Repeated(_)
+        ));
+        tracker.record_call(&search, &users, "list-users", at(3));
+
+        let report = tracker.report();
+        assert_eq!(report.rows.len(), 2);
+        assert_eq!(report.rows[0].caller, billing);
+        assert_eq!(report.rows[0].calls, 2);
+        assert_eq!(report.rows[0].first_seen, at(1));
+        assert_eq!(report.callers(), vec![&billing, &search]);
+
+        // get-user goes away in 2.x, list-users only in 4.x
+        let due: Vec<_> = report
+            .due_by_major(2)
+            .into_iter()
+            .map(|r| &r.caller)
+            .collect();
+        assert_eq!(due, vec![&billing]);
+        assert!(report.to_string().contains("use=get-user-v2"));
+
+        tracker.forget(&users);
+        assert!(tracker.report().rows.is_empty());
+    }
+}
@@ -0,0 +1,550 @@
+//! # Golden Fixtures - Component Regression Suites
+//!
+//! Records how a loaded component responds to a corpus of request payloads
+//! and replays the corpus later to detect behavioural drift.
+//!
+//! Workflow:
+//!
+//! 1. [`load_corpus`] reads one request payload per file from a directory.
+//! 2. [`FixtureRunner::generate`] invokes the component with each payload and
+//!    captures the result as a [`FixtureSuite`] (saved as pretty JSON).
+//! 3. [`FixtureRunner::check`] re-runs every recorded request and reports
+//!    each case whose outcome no longer matches the golden file.
+//!
+//! This module is the engine behind a `fixtures` command (generate, and
+//! `--check` to verify); command-line wiring lives outside this crate.
+//!
+//! # Determinism
+//!
+//! Requests are delivered with [`MessageMetadata::default()`] so the
+//! envelope is identical between generation and check runs.
+//!
+//! # References
+//!
+//! - ADR-WASM-032: System Module Design
+//! - ADR-WASM-023: Module Boundary Enforcement (Layer 4)
+
+// Layer 1: Standard library imports
+use std::fs;
+use std::path::Path;
+
+// Layer 2: Third-party crate imports
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+use crate::core::component::handle::ComponentHandle;
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::{ComponentMessage, MessageMetadata, MessagePayload};
+use crate::core::runtime::traits::RuntimeEngine;
+
+// ============================================================================
+// FixtureError
+// ============================================================================
+
+/// Errors raised while reading or writing fixture files.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum FixtureError {
+    /// Filesystem access failed.
+    #[error("Fixture I/O error: {0}")]
+    Io(String),
+
+    /// Fixture file could not be parsed or serialized.
+    #[error("Invalid fixture format: {0}")]
+    Format(String),
+}
+
+// ============================================================================
+// FixturePayload / FixtureOutcome
+// ============================================================================
+
+/// Payload bytes as stored in a fixture file.
+///
+/// UTF-8 payloads are kept as text so golden files stay reviewable in diffs;
+/// anything else is hex-encoded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "encoding", content = "data", rename_all = "lowercase")]
+pub enum FixturePayload {
+    /// Valid UTF-8 payload.
+    Utf8(String),
+    /// Arbitrary bytes, lowercase hex.
+    Hex(String),
+}
+
+impl FixturePayload {
+    /// Encodes raw bytes, preferring the UTF-8 form when possible.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        match std::str::from_utf8(bytes) {
+            Ok(text) => Self::Utf8(text.to_string()),
+            Err(_) => Self::Hex(bytes.iter().map(|b| format!("{:02x}", b)).collect()),
+        }
+    }
+
+    /// Decodes the stored payload back to raw bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FixtureError::Format` if a hex payload is malformed.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, FixtureError> {
+        match self {
+            Self::Utf8(text) => Ok(text.as_bytes().to_vec()),
+            Self::Hex(hex) => {
+                if hex.len() % 2 != 0 {
+                    return Err(FixtureError::Format(format!(
+                        "Odd-length hex payload: {}",
+                        hex
+                    )));
+                }
+                (0..hex.len())
+                    .step_by(2)
+                    .map(|i| {
+                        hex.get(i..i + 2)
+                            .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                            .ok_or_else(|| {
+                                FixtureError::Format(format!("Invalid hex payload: {}", hex))
+                            })
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Observed result of delivering one request to the component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum FixtureOutcome {
+    /// Component returned a response payload.
+    Reply(FixturePayload),
+    /// Component handled the message without replying.
+    NoReply,
+    /// Component invocation failed; holds the error message.
+    Error(String),
+}
+
+// ============================================================================
+// FixtureSuite
+// ============================================================================
+
+/// A single recorded request/response pair.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixtureCase {
+    /// Case name, taken from the corpus entry.
+    pub name: String,
+    /// Request payload delivered to the component.
+    pub request: FixturePayload,
+    /// Golden outcome.
+    pub response: FixtureOutcome,
+}
+
+/// Golden fixture file for one component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixtureSuite {
+    /// Component the fixtures were recorded against.
+    pub component: String,
+    /// Recorded cases, in corpus order.
+    pub cases: Vec<FixtureCase>,
+}
+
+impl FixtureSuite {
+    /// Reads a fixture suite from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// - `FixtureError::Io` - File cannot be read
+    /// - `FixtureError::Format` - File is not a valid fixture suite
+    pub fn load(path: &Path) -> Result<Self, FixtureError> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| FixtureError::Io(format!("{}: {}", path.display(), e)))?;
+        serde_json::from_str(&text)
+            .map_err(|e| FixtureError::Format(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Writes the suite to `path` as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// - `FixtureError::Format` - Suite cannot be serialized
+    /// - `FixtureError::Io` - File cannot be written
+    pub fn save(&self, path: &Path) -> Result<(), FixtureError> {
+        let mut text =
+            serde_json::to_string_pretty(self).map_err(|e| FixtureError::Format(e.to_string()))?;
+        text.push('\n');
+        fs::write(path, text).map_err(|e| FixtureError::Io(format!("{}: {}", path.display(), e)))
+    }
+}
+
+// ============================================================================
+// Corpus
+// ============================================================================
+
+/// One named request payload from a corpus.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorpusEntry {
+    /// Case name.
+    pub name: String,
+    /// Raw request payload.
+    pub payload: Vec<u8>,
+}
+
+impl CorpusEntry {
+    /// Creates a corpus entry.
+    pub fn new(name: impl Into<String>, payload: impl Into<Vec<u8>>) -> Self {
+        Self {
+            name: name.into(),
+            payload: payload.into(),
+        }
+    }
+}
+
+/// Loads a corpus from `dir`, one entry per regular file.
+///
+/// Entries are named after the file name and sorted by name so generated
+/// suites are stable across platforms.
+///
+/// # Errors
+///
+/// Returns `FixtureError::Io` if the directory or any file cannot be read.
+pub fn load_corpus(dir: &Path) -> Result<Vec<CorpusEntry>, FixtureError> {
+    let io_err = |e: std::io::Error| FixtureError::Io(format!("{}: {}", dir.display(), e));
+
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(dir).map_err(io_err)? {
+        let path = entry.map_err(io_err)?.path();
+        if !path.is_file() {
+            continue;
+        }
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let payload =
+            fs::read(&path).map_err(|e| FixtureError::Io(format!("{}: {}", path.display(), e)))?;
+        entries.push(CorpusEntry::new(name, payload));
+    }
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(entries)
+}
+
+// ============================================================================
+// FixtureRunner
+// ============================================================================
+
+/// A case whose current outcome differs from the golden file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixtureMismatch {
+    /// Case name.
+    pub case: String,
+    /// Outcome recorded in the golden file.
+    pub expected: FixtureOutcome,
+    /// Outcome observed on this run.
+    pub actual: FixtureOutcome,
+}
+
+/// Result of checking a component against a fixture suite.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FixtureReport {
+    /// Number of cases replayed.
+    pub checked: usize,
+    /// Cases whose outcome changed.
+    pub mismatches: Vec<FixtureMismatch>,
+}
+
+impl FixtureReport {
+    /// Returns `true` if every case matched its golden outcome.
+    pub fn passed(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// Drives a loaded component through a corpus of requests.
+///
+/// Generic over the runtime engine (S6.2 static dispatch).
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let handle = engine.load_component(&id, &bytes)?;
+/// let runner = FixtureRunner::new(&engine, &handle);
+///
+/// // Record
+/// let suite = runner.generate(&load_corpus(Path::new("corpus"))?);
+/// suite.save(Path::new("fixtures.json"))?;
+///
+/// // --check
+/// let report = runner.check(&FixtureSuite::load(Path::new("fixtures.json"))?)?;
+/// assert!(report.passed());
+/// ```
+pub struct FixtureRunner<'a, E: RuntimeEngine> {
+    engine: &'a E,
+    handle: &'a ComponentHandle,
+    sender: ComponentId,
+}
+
+impl<'a, E: RuntimeEngine> FixtureRunner<'a, E> {
+    /// Creates a runner for the component behind `handle`.
+    ///
+    /// Requests are sent from a fixed `system/fixtures/golden` identity.
+    pub fn new(engine: &'a E, handle: &'a ComponentHandle) -> Self {
+        Self {
+            engine,
+            handle,
+            sender: ComponentId::new("system", "fixtures", "golden"),
+        }
+    }
+
+    /// Overrides the sender identity used for requests.
+    pub fn with_sender(mut self, sender: ComponentId) -> Self {
+        self.sender = sender;
+        self
+    }
+
+    /// Delivers one payload and captures the outcome.
+    pub fn invoke(&self, payload: &[u8]) -> FixtureOutcome {
+        let msg = ComponentMessage::new(
+            self.sender.clone(),
+            MessagePayload::new(payload.to_vec()),
+            MessageMetadata::default(),
+        );
+        match self.engine.call_handle_message(self.handle, &msg) {
+            Ok(Some(reply)) => FixtureOutcome::Reply(FixturePayload::from_bytes(reply.as_bytes())),
+            Ok(None) => FixtureOutcome::NoReply,
+            Err(e) => FixtureOutcome::Error(e.to_string()),
+        }
+    }
+
+    /// Invokes the component with every corpus entry and records the results.
+    pub fn generate(&self, corpus: &[CorpusEntry]) -> FixtureSuite {
+        let cases = corpus
+            .iter()
+            .map(|entry| FixtureCase {
+                name: entry.name.clone(),
+                request: FixturePayload::from_bytes(&entry.payload),
+                response: self.invoke(&entry.payload),
+            })
+            .collect();
+
+        FixtureSuite {
+            component: self.handle.id().to_string(),
+            cases,
+        }
+    }
+
+    /// Replays every case in `suite` and reports outcomes that changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FixtureError::Format` if a recorded request cannot be decoded.
+    pub fn check(&self, suite: &FixtureSuite) -> Result<FixtureReport, FixtureError> {
+        let mut report = FixtureReport::default();
+        for case in &suite.cases {
+            let actual = self.invoke(&case.request.to_bytes()?);
+            report.checked += 1;
+            if actual != case.response {
+                report.mismatches.push(FixtureMismatch {
+                    case: case.name.clone(),
+                    expected: case.response.clone(),
+                    actual,
+                });
+            }
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::runtime::errors::WasmError;
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    // ========================================
+    // Mock RuntimeEngine
+    // ========================================
+
+    /// Echoes payloads back, prefixed by "v2:" once `changed` is set.
+    /// Empty payloads produce no reply; "fail" produces an error.
+    struct EchoEngine {
+        changed: AtomicBool,
+    }
+
+    impl EchoEngine {
+        fn new() -> Self {
+            Self {
+                changed: AtomicBool::new(false),
+            }
+        }
+    }
+
+    impl RuntimeEngine for EchoEngine {
+        fn load_component(
+            &self,
+            id: &ComponentId,
+            _bytes: &[u8],
+        ) -> Result<ComponentHandle, WasmError> {
+            Ok(ComponentHandle::new(id.clone(), 1))
+        }
+
+        fn unload_component(&self, _handle: &ComponentHandle) -> Result<(), WasmError> {
+            Ok(())
+        }
+
+        fn call_handle_message(
+            &self,
+            _handle: &ComponentHandle,
+            msg: &ComponentMessage,
+        ) -> Result<Option<MessagePayload>, WasmError> {
+            let bytes = msg.payload.as_bytes();
+            if bytes.is_empty() {
+                return Ok(None);
+            }
+            if bytes == b"fail" {
+                return Err(WasmError::RuntimeError("boom".to_string()));
+            }
+            let mut reply = Vec::new();
+            if self.changed.load(Ordering::SeqCst) {
+                reply.extend_from_slice(b"v2:");
+            }
+            reply.extend_from_slice(bytes);
+            Ok(Some(MessagePayload::new(reply)))
+        }
+
+        fn call_handle_callback(
+            &self,
+            _handle: &ComponentHandle,
+            _msg: &ComponentMessage,
+        ) -> Result<(), WasmError> {
+            Ok(())
+        }
+    }
+
+    fn handle() -> ComponentHandle {
+        ComponentHandle::new(ComponentId::new("app", "echo", "1"), 1)
+    }
+
+    fn corpus() -> Vec<CorpusEntry> {
+        vec![
+            CorpusEntry::new("binary", vec![0xff, 0x00]),
+            CorpusEntry::new("empty", Vec::new()),
+            CorpusEntry::new("fail", b"fail".to_vec()),
+            CorpusEntry::new("hello", b"hello".to_vec()),
+        ]
+    }
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("airssys-fixtures-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_payload_utf8_and_hex_roundtrip() {
+        let text = FixturePayload::from_bytes(b"hi");
+        assert_eq!(text, FixturePayload::Utf8("hi".to_string()));
+        assert_eq!(text.to_bytes().unwrap(), b"hi");
+
+        let bin = FixturePayload::from_bytes(&[0xde, 0xad, 0xff]);
+        assert_eq!(bin, FixturePayload::Hex("deadff".to_string()));
+        assert_eq!(bin.to_bytes().unwrap(), vec![0xde, 0xad, 0xff]);
+    }
+
+    #[test]
+    fn test_payload_invalid_hex() {
+        assert!(FixturePayload::Hex("abc".to_string()).to_bytes().is_err());
+        assert!(FixturePayload::Hex("zz".to_string()).to_bytes().is_err());
+    }
+
+    #[test]
+    fn test_generate_records_all_outcomes() {
+        let engine = EchoEngine::new();
+        let handle = handle();
+        let suite = FixtureRunner::new(&engine, &handle).generate(&corpus());
+
+        assert_eq!(suite.component, handle.id().to_string());
+        assert_eq!(suite.cases.len(), 4);
+        assert_eq!(
+            suite.cases[0].response,
+            FixtureOutcome::Reply(FixturePayload::Hex("ff00".to_string()))
+        );
+        assert_eq!(suite.cases[1].response, FixtureOutcome::NoReply);
+        assert!(
+            matches!(suite.cases[2].response, FixtureOutcome::Error(ref e) if e.contains("boom"))
+        );
+        assert_eq!(
+            suite.cases[3].response,
+            FixtureOutcome::Reply(FixturePayload::Utf8("hello".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_check_passes_when_unchanged() {
+        let engine = EchoEngine::new();
+        let handle = handle();
+        let runner = FixtureRunner::new(&engine, &handle);
+        let suite = runner.generate(&corpus());
+
+        let report = runner.check(&suite).unwrap();
+        assert!(report.passed());
+        assert_eq!(report.checked, 4);
+    }
+
+    #[test]
+    fn test_check_reports_drift() {
+        let engine = EchoEngine::new();
+        let handle = handle();
+        let runner = FixtureRunner::new(&engine, &handle);
+        let suite = runner.generate(&corpus());
+
+        engine.changed.store(true, Ordering::SeqCst);
+        let report = runner.check(&suite).unwrap();
+
+        assert!(!report.passed());
+        let names: Vec<_> = report.mismatches.iter().map(|m| m.case.as_str()).collect();
+        assert_eq!(names, vec!["binary", "hello"]);
+        assert_eq!(
+            report.mismatches[1].actual,
+            FixtureOutcome::Reply(FixturePayload::Utf8("v2:hello".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_suite_save_load_roundtrip() {
+        let engine = EchoEngine::new();
+        let handle = handle();
+        let suite = FixtureRunner::new(&engine, &handle).generate(&corpus());
+
+        let dir = temp_dir();
+        let path = dir.join("fixtures.json");
+        suite.save(&path).unwrap();
+        let loaded = FixtureSuite::load(&path).unwrap();
+        assert_eq!(loaded, suite);
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_load_missing_suite_is_io_error() {
+        let err = FixtureSuite::load(Path::new("/nonexistent/fixtures.json")).unwrap_err();
+        assert!(matches!(err, FixtureError::Io(_)));
+    }
+
+    #[test]
+    fn test_load_corpus_sorted_and_skips_dirs() {
+        let dir = temp_dir();
+        fs::write(dir.join("b.json"), b"{\"b\":1}").unwrap();
+        fs::write(dir.join("a.bin"), [1u8, 2]).unwrap();
+        fs::create_dir(dir.join("nested")).unwrap();
+
+        let entries = load_corpus(&dir).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                CorpusEntry::new("a.bin", vec![1u8, 2]),
+                CorpusEntry::new("b.json", b"{\"b\":1}".to_vec()),
+            ]
+        );
+
+        fs::remove_dir_all(dir).ok();
+    }
+}
@@ -6,6 +6,16 @@
 //!
 //! - [`SystemCoordinator`]: Composition root that wires all dependencies together
 //! - [`SystemBuilder`]: Constructs SystemCoordinator with dependency injection (Phase 7)
+//! - [`bundle`]: Offline bundle creation and verified installation for air-gapped hosts
+//! - [`deprecation`]: Calls to deprecated component exports and the callers that must migrate
+//! - [`fixtures`]: Golden request/response fixtures for component regression suites
+//! - [`plugin`]: Host plugins extending the coordinator (hooks, interceptors, endpoints, metrics)
+//! - [`query`]: Read-only SQL over host state (components, capabilities, in-flight messages, metrics)
+//! - [`reservation`]: Host headroom reservation and admission for critical components
+//! - [`saga`]: Multi-component workflows with per-step compensation and timeouts
+//! - [`scaling`]: Scheduled and queue-depth scaling of warm instance pools
+//! - [`selftest`]: Startup self-test with an aggregated report of failed checks
+//! - [`top`]: Live per-component resource view (fuel rate, memory, message rate, queue, restarts)
 //!
 //! ## Module Position
 //!
@@ -28,5 +38,15 @@
 //! - KNOWLEDGE-WASM-037: Rebuild Architecture - Clean Slate Design
 
 pub mod builder; // SystemBuilder (WASM-TASK-049)
+pub mod bundle; // Offline bundle create/install
 pub mod coordinator; // SystemCoordinator
+pub mod deprecation; // DeprecationTracker for deprecated exports
+pub mod fixtures; // Golden fixture generation and checking
 pub mod lifecycle; // LifecycleManager (WASM-TASK-048)
+pub mod plugin; // HostPlugin and PluginRegistry
+pub mod query; // StateView for `query` diagnostics
+pub mod reservation; // AdmissionController for critical components
+pub mod saga; // SagaCoordinator for compensated multi-step workflows
+pub mod scaling; // PoolScaler for warm instance pools
+pub mod selftest; // Startup sanity checks run by start()
+pub mod top; // Live resource view model
@@ -0,0 +1,562 @@
+//! Host plugins for extending the system coordinator.
+//!
+//! A [`HostPlugin`] hooks into host orchestration the way OSL middleware
+//! hooks into operation execution: downstream products register plugins on
+//! a [`SystemCoordinator`](super::coordinator::SystemCoordinator) instead of
+//! forking it.
+//!
+//! Plugins can:
+//!
+//! - react to host and component lifecycle events
+//! - inspect, rewrite or drop messages before delivery
+//! - expose extra control-API endpoints
+//! - publish custom metrics
+//!
+//! Every hook has a no-op default, so a plugin only implements what it needs.
+//!
+//! # Ordering
+//!
+//! Plugins run in registration order for start, load and message hooks, and
+//! in reverse order for shutdown and unload hooks, mirroring how
+//! middleware unwinds.
+//!
+//! # Architecture Note
+//!
+//! Plugins are stored as `Box<dyn HostPlugin>` for heterogeneous storage.
+//! This is acceptable in Layer 4 (composition root) per S6.2 exception, as
+//! with `LifecycleListener`.
+
+// Layer 1: Standard library imports
+use std::fmt;
+
+// Layer 2: Third-party crate imports
+use serde_json::Value as JsonValue;
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::ComponentMessage;
+
+// ============================================================================
+// PluginError
+// ============================================================================
+
+/// Errors raised by host plugins or the plugin registry.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum PluginError {
+    /// A plugin with the same name is already registered.
+    #[error("Plugin already registered: {0}")]
+    AlreadyRegistered(String),
+
+    /// No plugin with the given name is registered.
+    #[error("Plugin not found: {0}")]
+    NotFound(String),
+
+    /// The plugin does not expose the requested control endpoint.
+    #[error("Plugin '{plugin}' has no endpoint '{endpoint}'")]
+    UnknownEndpoint {
+        /// Plugin name.
+        plugin: String,
+        /// Requested endpoint.
+        endpoint: String,
+    },
+
+    /// A plugin hook failed.
+    #[error("Plugin '{plugin}' failed: {reason}")]
+    HookFailed {
+        /// Plugin name.
+        plugin: String,
+        /// Failure description.
+        reason: String,
+    },
+}
+
+// ============================================================================
+// Plugin Types
+// ============================================================================
+
+/// Result of a message interceptor.
+// Boxing the message would cost an allocation on every delivery
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub enum InterceptAction {
+    /// Continue delivery with the (possibly rewritten) message.
+    Continue(ComponentMessage),
+    /// Stop delivery. The reason is reported to the sender.
+    Drop(String),
+}
+
+/// A metric published by a plugin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginMetric {
+    /// Metric name, unique within the plugin.
+    pub name: String,
+    /// Current value.
+    pub value: f64,
+}
+
+impl PluginMetric {
+    /// Creates a metric sample.
+    pub fn new(name: impl Into<String>, value: f64) -> Self {
+        Self {
+            name: name.into(),
+            value,
+        }
+    }
+}
+
+// ============================================================================
+// HostPlugin
+// ============================================================================
+
+/// Extension point for host orchestration.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::system::plugin::{HostPlugin, PluginError, PluginMetric};
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// #[derive(Default)]
+/// struct LoadCounter(AtomicU64);
+///
+/// impl HostPlugin for LoadCounter {
+///     fn name(&self) -> &str {
+///         "load-counter"
+///     }
+///
+///     fn on_component_loaded(&self, _id: &ComponentId) -> Result<(), PluginError> {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///         Ok(())
+///     }
+///
+///     fn metrics(&self) -> Vec<PluginMetric> {
+///         vec![PluginMetric::new("loads", self.0.load(Ordering::Relaxed) as f64)]
+///     }
+/// }
+/// ```
+pub trait HostPlugin: Send + Sync {
+    /// Unique plugin name, used for registration and endpoint routing.
+    fn name(&self) -> &str;
+
+    /// Called when the host starts. An error aborts the start.
+    fn on_start(&self) -> Result<(), PluginError> {
+        Ok(())
+    }
+
+    /// Called when the host shuts down. Errors are logged by the caller
+    /// but do not stop the shutdown.
+    fn on_shutdown(&self) -> Result<(), PluginError> {
+        Ok(())
+    }
+
+    /// Called after a component is loaded.
+    fn on_component_loaded(&self, _id: &ComponentId) -> Result<(), PluginError> {
+        Ok(())
+    }
+
+    /// Called after a component is unloaded.
+    fn on_component_unloaded(&self, _id: &ComponentId) -> Result<(), PluginError> {
+        Ok(())
+    }
+
+    /// Inspects a message before it is delivered to `target`.
+    fn intercept_message(
+        &self,
+        _target: &ComponentId,
+        message: ComponentMessage,
+    ) -> InterceptAction {
+        InterceptAction::Continue(message)
+    }
+
+    /// Control-API endpoints served by this plugin.
+    fn endpoints(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Handles a request to one of [`endpoints`](Self::endpoints).
+    fn handle_endpoint(
+        &self,
+        endpoint: &str,
+        _request: JsonValue,
+    ) -> Result<JsonValue, PluginError> {
+        Err(PluginError::UnknownEndpoint {
+            plugin: self.name().to_string(),
+            endpoint: endpoint.to_string(),
+        })
+    }
+
+    /// Current values of the plugin's custom metrics.
+    fn metrics(&self) -> Vec<PluginMetric> {
+        Vec::new()
+    }
+}
+
+// ============================================================================
+// PluginRegistry
+// ============================================================================
+
+/// Ordered set of registered host plugins.
+#[derive(Default)]
+pub struct PluginRegistry {
+    plugins: Vec<Box<dyn HostPlugin>>,
+}
+
+impl PluginRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a plugin.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PluginError::AlreadyRegistered`] if the name is taken.
+    pub fn register(&mut self, plugin: Box<dyn HostPlugin>) -> Result<(), PluginError> {
+        if self.get(plugin.name()).is_some() {
+            return Err(PluginError::AlreadyRegistered(plugin.name().to_string()));
+        }
+        self.plugins.push(plugin);
+        Ok(())
+    }
+
+    /// Number of registered plugins.
+    pub fn len(&self) -> usize {
+        self.plugins.len()
+    }
+
+    /// Returns `true` if no plugins are registered.
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
+    /// Names of registered plugins, in registration order.
+    pub fn names(&self) -> Vec<&str> {
+        self.plugins.iter().map(|p| p.name()).collect()
+    }
+
+    fn get(&self, name: &str) -> Option<&dyn HostPlugin> {
+        self.plugins
+            .iter()
+            .find(|p| p.name() == name)
+            .map(|p| p.as_ref())
+    }
+
+    /// Runs `on_start` in registration order, stopping at the first error.
+    ///
+    /// Plugins that already started are sent `on_shutdown` in reverse
+    /// order before the error is returned.
+    pub fn start_all(&self) -> Result<(), PluginError> {
+        for (started, plugin) in self.plugins.iter().enumerate() {
+            if let Err(e) = plugin.on_start() {
+                for earlier in self.plugins[..started].iter().rev() {
+                    let _ = earlier.on_shutdown();
+                }
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs `on_shutdown` in reverse order, collecting every failure.
+    pub fn shutdown_all(&self) -> Vec<PluginError> {
+        self.plugins
+            .iter()
+            .rev()
+            .filter_map(|p| p.on_shutdown().err())
+            .collect()
+    }
+
+    /// Runs `on_component_loaded` in registration order, collecting failures.
+    pub fn component_loaded(&self, id: &ComponentId) -> Vec<PluginError> {
+        self.plugins
+            .iter()
+            .filter_map(|p| p.on_component_loaded(id).err())
+            .collect()
+    }
+
+    /// Runs `on_component_unloaded` in reverse order, collecting failures.
+    pub fn component_unloaded(&self, id: &ComponentId) -> Vec<PluginError> {
+        self.plugins
+            .iter()
+            .rev()
+            .filter_map(|p| p.on_component_unloaded(id).err())
+            .collect()
+    }
+
+    /// Passes `message` through every interceptor in registration order.
+    ///
+    /// Returns the first `Drop`, prefixed with the dropping plugin's name.
+    pub fn intercept(&self, target: &ComponentId, message: ComponentMessage) -> InterceptAction {
+        let mut message = message;
+        for plugin in &self.plugins {
+            match plugin.intercept_message(target, message) {
+                InterceptAction::Continue(next) => message = next,
+                InterceptAction::Drop(reason) => {
+                    return InterceptAction::Drop(format!("{}: {}", plugin.name(), reason));
+                }
+            }
+        }
+        InterceptAction::Continue(message)
+    }
+
+    /// Lists every control endpoint as `plugin/endpoint`.
+    pub fn endpoints(&self) -> Vec<String> {
+        self.plugins
+            .iter()
+            .flat_map(|p| {
+                p.endpoints()
+                    .into_iter()
+                    .map(move |e| format!("{}/{}", p.name(), e))
+            })
+            .collect()
+    }
+
+    /// Routes a control request to `plugin`'s `endpoint`.
+    ///
+    /// # Errors
+    ///
+    /// - [`PluginError::NotFound`] if no such plugin is registered
+    /// - [`PluginError::UnknownEndpoint`] if the plugin does not list it
+    /// - any error returned by the plugin's handler
+    pub fn handle_endpoint(
+        &self,
+        plugin: &str,
+        endpoint: &str,
+        request: JsonValue,
+    ) -> Result<JsonValue, PluginError> {
+        let target = self
+            .get(plugin)
+            .ok_or_else(|| PluginError::NotFound(plugin.to_string()))?;
+        if !target.endpoints().iter().any(|e| e == endpoint) {
+            return Err(PluginError::UnknownEndpoint {
+                plugin: plugin.to_string(),
+                endpoint: endpoint.to_string(),
+            });
+        }
+        target.handle_endpoint(endpoint, request)
+    }
+
+    /// Collects metrics from all plugins, named `plugin.metric`.
+    pub fn metrics(&self) -> Vec<PluginMetric> {
+        self.plugins
+            .iter()
+            .flat_map(|p| {
+                p.metrics()
+                    .into_iter()
+                    .map(move |m| PluginMetric::new(format!("{}.{}", p.name(), m.name), m.value))
+            })
+            .collect()
+    }
+}
+
+impl fmt::Debug for PluginRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PluginRegistry")
+            .field("plugins", &self.names())
+            .finish()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::component::message::{MessageMetadata, MessagePayload};
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+
+    type Journal = Arc<Mutex<Vec<String>>>;
+
+    struct Recorder {
+        name: &'static str,
+        journal: Journal,
+        fail_start: bool,
+    }
+
+    impl Recorder {
+        fn boxed(name: &'static str, journal: &Journal) -> Box<dyn HostPlugin> {
+            Box::new(Self {
+                name,
+                journal: Arc::clone(journal),
+                fail_start: false,
+            })
+        }
+
+        fn log(&self, event: &str) {
+            self.journal
+                .lock()
+                .unwrap()
+                .push(format!("{}:{}", self.name, event));
+        }
+    }
+
+    impl HostPlugin for Recorder {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn on_start(&self) -> Result<(), PluginError> {
+            self.log("start");
+            if self.fail_start {
+                return Err(PluginError::HookFailed {
+                    plugin: self.name.to_string(),
+                    reason: "boom".to_string(),
+                });
+            }
+            Ok(())
+        }
+
+        fn on_shutdown(&self) -> Result<(), PluginError> {
+            self.log("shutdown");
+            Ok(())
+        }
+
+        fn intercept_message(
+            &self,
+            _target: &ComponentId,
+            mut message: ComponentMessage,
+        ) -> InterceptAction {
+            if message.payload.as_bytes() == b"blocked" {
+                return InterceptAction::Drop("blocked payload".to_string());
+            }
+            message.metadata.content_type = Some(self.name.to_string());
+            InterceptAction::Continue(message)
+        }
+
+        fn endpoints(&self) -> Vec<String> {
+            vec!["echo".to_string()]
+        }
+
+        fn handle_endpoint(
+            &self,
+            _endpoint: &str,
+            request: JsonValue,
+        ) -> Result<JsonValue, PluginError> {
+            Ok(json!({ "plugin": self.name, "request": request }))
+        }
+
+        fn metrics(&self) -> Vec<PluginMetric> {
+            vec![PluginMetric::new("calls", 1.0)]
+        }
+    }
+
+    fn message(payload: &[u8]) -> ComponentMessage {
+        ComponentMessage::new(
+            ComponentId::new("test", "sender", "v1"),
+            MessagePayload::new(payload.to_vec()),
+            MessageMetadata::default(),
+        )
+    }
+
+    #[test]
+    fn test_register_rejects_duplicate_names() {
+        let journal = Journal::default();
+        let mut registry = PluginRegistry::new();
+        registry.register(Recorder::boxed("a", &journal)).unwrap();
+
+        let result = registry.register(Recorder::boxed("a", &journal));
+        assert_eq!(result, Err(PluginError::AlreadyRegistered("a".to_string())));
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn test_lifecycle_ordering() {
+        let journal = Journal::default();
+        let mut registry = PluginRegistry::new();
+        registry.register(Recorder::boxed("a", &journal)).unwrap();
+        registry.register(Recorder::boxed("b", &journal)).unwrap();
+
+        registry.start_all().unwrap();
+        assert!(registry.shutdown_all().is_empty());
+        assert_eq!(
+            *journal.lock().unwrap(),
+            vec!["a:start", "b:start", "b:shutdown", "a:shutdown"]
+        );
+    }
+
+    #[test]
+    fn test_failed_start_rolls_back_started_plugins() {
+        let journal = Journal::default();
+        let mut registry = PluginRegistry::new();
+        registry.register(Recorder::boxed("a", &journal)).unwrap();
+        registry
+            .register(Box::new(Recorder {
+                name: "b",
+                journal: Arc::clone(&journal),
+                fail_start: true,
+            }))
+            .unwrap();
+
+        assert!(matches!(
+            registry.start_all(),
+            Err(PluginError::HookFailed { .. })
+        ));
+        assert_eq!(
+            *journal.lock().unwrap(),
+            vec!["a:start", "b:start", "a:shutdown"]
+        );
+    }
+
+    #[test]
+    fn test_intercept_chains_and_drops() {
+        let journal = Journal::default();
+        let mut registry = PluginRegistry::new();
+        registry.register(Recorder::boxed("a", &journal)).unwrap();
+        registry.register(Recorder::boxed("b", &journal)).unwrap();
+        let target = ComponentId::new("test", "target", "v1");
+
+        match registry.intercept(&target, message(b"ok")) {
+            InterceptAction::Continue(msg) => {
+                assert_eq!(msg.metadata.content_type.as_deref(), Some("b"));
+            }
+            InterceptAction::Drop(reason) => unreachable!("unexpected drop: {reason}"),
+        }
+
+        assert!(matches!(
+            registry.intercept(&target, message(b"blocked")),
+            InterceptAction::Drop(reason) if reason == "a: blocked payload"
+        ));
+    }
+
+    #[test]
+    fn test_endpoint_routing() {
+        let journal = Journal::default();
+        let mut registry = PluginRegistry::new();
+        registry.register(Recorder::boxed("a", &journal)).unwrap();
+
+        assert_eq!(registry.endpoints(), vec!["a/echo"]);
+        let response = registry.handle_endpoint("a", "echo", json!(1)).unwrap();
+        assert_eq!(response, json!({ "plugin": "a", "request": 1 }));
+
+        assert_eq!(
+            registry.handle_endpoint("missing", "echo", json!(null)),
+            Err(PluginError::NotFound("missing".to_string()))
+        );
+        assert!(matches!(
+            registry.handle_endpoint("a", "nope", json!(null)),
+            Err(PluginError::UnknownEndpoint { .. })
+        ));
+    }
+
+    #[test]
+    fn test_metrics_are_namespaced() {
+        let journal = Journal::default();
+        let mut registry = PluginRegistry::new();
+        registry.register(Recorder::boxed("a", &journal)).unwrap();
+        registry.register(Recorder::boxed("b", &journal)).unwrap();
+
+        assert_eq!(
+            registry.metrics(),
+            vec![
+                PluginMetric::new("a.calls", 1.0),
+                PluginMetric::new("b.calls", 1.0)
+            ]
+        );
+    }
+}
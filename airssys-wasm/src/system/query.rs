@@ -0,0 +1,867 @@
+//! # Diagnostic State Queries
+//!
+//! Read-only SQL over a snapshot of host state, for an operator `query`
+//! command (`airssys-wasm query "<sql>"`) to answer ad-hoc questions
+//! without a bespoke API per question.
+//!
+//! A [`StateView`] holds these virtual tables:
+//!
+//! | Table                | Columns |
+//! |----------------------|---------|
+//! | `components`         | `component`, `installed_from`, `running`, `capabilities` |
+//! | `capabilities`       | `component`, `capability` |
+//! | `messages_in_flight` | `correlation_id`, `remaining_ms` |
+//! | `metrics`            | `component`, `fuel_consumed`, `memory_bytes`, `messages_handled`, `queue_depth`, `restarts` |
+//!
+//! Only a `SELECT` subset is understood:
+//!
+//! ```text
+//! SELECT * | COUNT(*) | col [, col ...] FROM table
+//!     [WHERE col op value [AND|OR col op value ...]]
+//!     [ORDER BY col [ASC|DESC]]
+//!     [LIMIT n]
+//! ```
+//!
+//! `op` is one of `=`, `!=`, `<>`, `<`, `<=`, `>`, `>=`, `LIKE` (with `%`
+//! and `_` wildcards), `IS NULL` and `IS NOT NULL`. `AND` binds tighter
+//! than `OR`; parentheses are not supported. Anything else, including any
+//! statement that would modify state, is rejected.
+
+// Layer 1: Standard library imports
+use std::cmp::Ordering;
+use std::fmt;
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+use crate::core::management::state::HostState;
+use crate::system::top::ComponentSample;
+
+/// Errors raised while running a query.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum QueryError {
+    /// The statement is not valid in the supported SQL subset.
+    #[error("Syntax error: {0}")]
+    Syntax(String),
+
+    /// The statement is not a `SELECT`.
+    #[error("Only SELECT statements are allowed, got '{0}'")]
+    ReadOnly(String),
+
+    /// No virtual table with this name.
+    #[error("Unknown table '{0}'")]
+    UnknownTable(String),
+
+    /// The table has no such column.
+    #[error("Unknown column '{column}' in table '{table}'")]
+    UnknownColumn {
+        /// Queried table.
+        table: String,
+        /// Missing column.
+        column: String,
+    },
+}
+
+/// One cell of a query result.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// SQL `NULL`.
+    Null,
+    /// Boolean.
+    Bool(bool),
+    /// Integer.
+    Int(i64),
+    /// Floating-point number.
+    Float(f64),
+    /// Text.
+    Text(String),
+}
+
+impl Value {
+    fn compare(&self, other: &Value) -> Option<Ordering> {
+        match (self, other) {
+            (Value::Int(a), Value::Int(b)) => Some(a.cmp(b)),
+            (Value::Int(a), Value::Float(b)) => (*a as f64).partial_cmp(b),
+            (Value::Float(a), Value::Int(b)) => a.partial_cmp(&(*b as f64)),
+            (Value::Float(a), Value::Float(b)) => a.partial_cmp(b),
+            (Value::Text(a), Value::Text(b)) => Some(a.cmp(b)),
+            (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "NULL"),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Int(i) => write!(f, "{}", i),
+            Value::Float(x) => write!(f, "{}", x),
+            Value::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<u64> for Value {
+    fn from(value: u64) -> Self {
+        Value::Int(i64::try_from(value).unwrap_or(i64::MAX))
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Text(value)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Table {
+    name: &'static str,
+    columns: &'static [&'static str],
+    rows: Vec<Vec<Value>>,
+}
+
+impl Table {
+    fn new(name: &'static str, columns: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            columns,
+            rows: Vec::new(),
+        }
+    }
+
+    fn column(&self, column: &str) -> Result<usize, QueryError> {
+        self.columns
+            .iter()
+            .position(|c| c.eq_ignore_ascii_case(column))
+            .ok_or_else(|| QueryError::UnknownColumn {
+                table: self.name.to_string(),
+                column: column.to_string(),
+            })
+    }
+}
+
+/// Snapshot of host state exposed as virtual tables.
+#[derive(Debug, Clone)]
+pub struct StateView {
+    tables: Vec<Table>,
+}
+
+impl Default for StateView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StateView {
+    /// Creates a view with every table empty.
+    pub fn new() -> Self {
+        Self {
+            tables: vec![
+                Table::new(
+                    "components",
+                    &["component", "installed_from", "running", "capabilities"],
+                ),
+                Table::new("capabilities", &["component", "capability"]),
+                Table::new("messages_in_flight", &["correlation_id", "remaining_ms"]),
+                Table::new(
+                    "metrics",
+                    &[
+                        "component",
+                        "fuel_consumed",
+                        "memory_bytes",
+                        "messages_handled",
+                        "queue_depth",
+                        "restarts",
+                    ],
+                ),
+            ],
+        }
+    }
+
+    /// Fills `components` and `capabilities` from replayed host state.
+    pub fn with_host_state(mut self, state: &HostState) -> Self {
+        let mut components: Vec<_> = state.components().collect();
+        components.sort_by_key(|(id, _)| id.to_string_id());
+        for (id, component) in components {
+            self.rows("components").push(vec![
+                Value::Text(id.to_string_id()),
+                component
+                    .installed_from
+                    .clone()
+                    .map_or(Value::Null, Value::Text),
+                Value::Bool(component.running),
+                Value::from(component.capabilities.len() as u64),
+            ]);
+            for capability in &component.capabilities {
+                self.rows("capabilities").push(vec![
+                    Value::Text(id.to_string_id()),
+                    Value::Text(capability.clone()),
+                ]);
+            }
+        }
+        self
+    }
+
+    /// Fills `messages_in_flight` with pending requests and the time each
+    /// has left before timing out.
+    pub fn with_in_flight(mut self, pending: Vec<(String, Duration)>) -> Self {
+        for (correlation_id, remaining) in pending {
+            self.rows("messages_in_flight").push(vec![
+                Value::Text(correlation_id),
+                Value::from(remaining.as_millis() as u64),
+            ]);
+        }
+        self
+    }
+
+    /// Fills `metrics` with per-component resource samples.
+    pub fn with_metrics(mut self, samples: Vec<ComponentSample>) -> Self {
+        for sample in samples {
+            self.rows("metrics").push(vec![
+                Value::Text(sample.component.to_string_id()),
+                Value::from(sample.fuel_consumed),
+                Value::from(sample.memory_bytes),
+                Value::from(sample.messages_handled),
+                Value::from(sample.queue_depth),
+                Value::from(u64::from(sample.restarts)),
+            ]);
+        }
+        self
+    }
+
+    /// Names of the virtual tables.
+    pub fn tables(&self) -> Vec<&'static str> {
+        self.tables.iter().map(|t| t.name).collect()
+    }
+
+    /// Runs a read-only `SELECT` against the view.
+    ///
+    /// # Errors
+    ///
+    /// - `QueryError::ReadOnly` if the statement is not a `SELECT`
+    /// - `QueryError::Syntax` if it is outside the supported subset
+    /// - `QueryError::UnknownTable` / `QueryError::UnknownColumn` for
+    ///   names the view does not have
+    pub fn query(&self, sql: &str) -> Result<QueryResult, QueryError> {
+        let select = Parser::new(tokenize(sql)?).select()?;
+        let table = self
+            .tables
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(&select.table))
+            .ok_or_else(|| QueryError::UnknownTable(select.table.clone()))?;
+        select.execute(table)
+    }
+
+    fn rows(&mut self, name: &str) -> &mut Vec<Vec<Value>> {
+        let index = self
+            .tables
+            .iter()
+            .position(|t| t.name == name)
+            .unwrap_or_default();
+        &mut self.tables[index].rows
+    }
+}
+
+/// Rows returned by [`StateView::query`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryResult {
+    /// Column names, in projection order.
+    pub columns: Vec<String>,
+    /// Result rows.
+    pub rows: Vec<Vec<Value>>,
+}
+
+impl fmt::Display for QueryResult {
+    /// Renders an aligned text table with a row count footer.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(Value::to_string).collect())
+            .collect();
+        let widths: Vec<usize> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                cells
+                    .iter()
+                    .map(|row| row[i].len())
+                    .fold(name.len(), usize::max)
+            })
+            .collect();
+
+        let line = |f: &mut fmt::Formatter<'_>, values: Vec<&str>| {
+            let padded: Vec<String> = values
+                .iter()
+                .zip(&widths)
+                .map(|(value, width)| format!("{:<width$}", value, width = width))
+                .collect();
+            writeln!(f, "{}", padded.join("  ").trim_end())
+        };
+        line(f, self.columns.iter().map(String::as_str).collect())?;
+        for row in &cells {
+            line(f, row.iter().map(String::as_str).collect())?;
+        }
+        let n = self.rows.len();
+        write!(f, "({} row{})", n, if n == 1 { "" } else { "s" })
+    }
+}
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Number(String),
+    Text(String),
+    Symbol(&'static str),
+}
+
+fn tokenize(sql: &str) -> Result<Vec<Token>, QueryError> {
+    let mut tokens = Vec::new();
+    let mut chars = sql.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let mut word = String::new();
+            while let Some(&c) = chars
+                .peek()
+                .filter(|c| c.is_ascii_alphanumeric() || **c == '_')
+            {
+                word.push(c);
+                chars.next();
+            }
+            tokens.push(Token::Word(word));
+        } else if c.is_ascii_digit() || c == '-' || c == '.' {
+            let mut number = String::new();
+            number.push(c);
+            chars.next();
+            while let Some(&c) = chars.peek().filter(|c| c.is_ascii_digit() || **c == '.') {
+                number.push(c);
+                chars.next();
+            }
+            tokens.push(Token::Number(number));
+        } else if c == '\'' {
+            chars.next();
+            let mut text = String::new();
+            loop {
+                match chars.next() {
+                    Some('\'') if chars.peek() == Some(&'\'') => {
+                        chars.next();
+                        text.push('\'');
+                    }
+                    Some('\'') => break,
+                    Some(c) => text.push(c),
+                    None => return Err(QueryError::Syntax("unterminated string literal".into())),
+                }
+            }
+            tokens.push(Token::Text(text));
+        } else {
+            chars.next();
+            let symbol = match (c, chars.peek()) {
+                ('<', Some('=')) => "<=",
+                ('>', Some('=')) => ">=",
+                ('<', Some('>')) => "<>",
+                ('!', Some('=')) => "!=",
+                ('<', _) => "<",
+                ('>', _) => ">",
+                ('=', _) => "=",
+                (',', _) => ",",
+                ('*', _) => "*",
+                ('(', _) => "(",
+                (')', _) => ")",
+                (';', _) => ";",
+                _ => return Err(QueryError::Syntax(format!("unexpected character '{}'", c))),
+            };
+            if symbol.len() == 2 {
+                chars.next();
+            }
+            tokens.push(Token::Symbol(symbol));
+        }
+    }
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Like,
+    IsNull,
+    IsNotNull,
+}
+
+#[derive(Debug, Clone)]
+struct Condition {
+    column: String,
+    op: CompareOp,
+    value: Value,
+}
+
+impl Condition {
+    fn matches(&self, cell: &Value) -> bool {
+        let ordering = cell.compare(&self.value);
+        match self.op {
+            CompareOp::IsNull => *cell == Value::Null,
+            CompareOp::IsNotNull => *cell != Value::Null,
+            CompareOp::Eq => ordering == Some(Ordering::Equal),
+            CompareOp::Ne => ordering.is_some_and(Ordering::is_ne),
+            CompareOp::Lt => ordering == Some(Ordering::Less),
+            CompareOp::Le => ordering.is_some_and(Ordering::is_le),
+            CompareOp::Gt => ordering == Some(Ordering::Greater),
+            CompareOp::Ge => ordering.is_some_and(Ordering::is_ge),
+            CompareOp::Like => match (cell, &self.value) {
+                (Value::Text(text), Value::Text(pattern)) => like(text, pattern),
+                _ => false,
+            },
+        }
+    }
+}
+
+/// SQL `LIKE`: `%` matches any run of characters, `_` exactly one.
+///
+/// Greedy two-pointer match: on a mismatch, backtrack to the last `%` and
+/// let it swallow one more character. Runs in O(text × pattern) time.
+fn like(text: &str, pattern: &str) -> bool {
+    let text: Vec<char> = text.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    let (mut t, mut p) = (0, 0);
+    // Position after the last `%` seen, and the text position it resumes at
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('%') => {
+                p += 1;
+                backtrack = Some((p, t));
+            }
+            Some(&c) if c == '_' || c == text[t] => {
+                t += 1;
+                p += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_t)) => {
+                    p = star_p;
+                    t = star_t + 1;
+                    backtrack = Some((star_p, t));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '%')
+}
+
+#[derive(Debug, Clone)]
+enum Projection {
+    All,
+    Count,
+    Columns(Vec<String>),
+}
+
+#[derive(Debug, Clone)]
+struct Select {
+    projection: Projection,
+    table: String,
+    /// Disjunction of conjunctions (`AND` binds tighter than `OR`).
+    filter: Vec<Vec<Condition>>,
+    order_by: Option<(String, bool)>,
+    limit: Option<usize>,
+}
+
+impl Select {
+    fn execute(&self, table: &Table) -> Result<QueryResult, QueryError> {
+        let filter = self
+            .filter
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .map(|c| Ok((table.column(&c.column)?, c)))
+                    .collect::<Result<Vec<_>, QueryError>>()
+            })
+            .collect::<Result<Vec<_>, QueryError>>()?;
+        let mut rows: Vec<&Vec<Value>> = table
+            .rows
+            .iter()
+            .filter(|row| {
+                filter.is_empty()
+                    || filter
+                        .iter()
+                        .any(|group| group.iter().all(|(i, c)| c.matches(&row[*i])))
+            })
+            .collect();
+
+        if let Some((column, descending)) = &self.order_by {
+            let index = table.column(column)?;
+            rows.sort_by(|a, b| {
+                let ordering = match (&a[index], &b[index]) {
+                    (Value::Null, Value::Null) => Ordering::Equal,
+                    (Value::Null, _) => Ordering::Less,
+                    (_, Value::Null) => Ordering::Greater,
+                    (x, y) => x.compare(y).unwrap_or(Ordering::Equal),
+                };
+                if *descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+        if let Some(limit) = self.limit {
+            rows.truncate(limit);
+        }
+
+        let (columns, indices): (Vec<String>, Vec<usize>) = match &self.projection {
+            Projection::Count => {
+                return Ok(QueryResult {
+                    columns: vec!["count".to_string()],
+                    rows: vec![vec![Value::from(rows.len() as u64)]],
+                })
+            }
+            Projection::All => table
+                .columns
+                .iter()
+                .enumerate()
+                .map(|(i, c)| (c.to_string(), i))
+                .unzip(),
+            Projection::Columns(names) => names
+                .iter()
+                .map(|name| Ok((name.clone(), table.column(name)?)))
+                .collect::<Result<Vec<_>, QueryError>>()?
+                .into_iter()
+                .unzip(),
+        };
+        Ok(QueryResult {
+            columns,
+            rows: rows
+                .into_iter()
+                .map(|row| indices.iter().map(|&i| row[i].clone()).collect())
+                .collect(),
+        })
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn new(tokens: Vec<Token>) -> Self {
+        Self { tokens, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn at_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.at_keyword(keyword);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn eat_symbol(&mut self, symbol: &str) -> bool {
+        let found = matches!(self.peek(), Some(Token::Symbol(s)) if *s == symbol);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), QueryError> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            Err(QueryError::Syntax(format!("expected {}", keyword)))
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<(), QueryError> {
+        if self.eat_symbol(symbol) {
+            Ok(())
+        } else {
+            Err(QueryError::Syntax(format!("expected '{}'", symbol)))
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, QueryError> {
+        match self.next() {
+            Some(Token::Word(word)) => Ok(word),
+            _ => Err(QueryError::Syntax("expected a name".into())),
+        }
+    }
+
+    fn select(mut self) -> Result<Select, QueryError> {
+        match self.peek() {
+            Some(Token::Word(w)) if w.eq_ignore_ascii_case("SELECT") => self.pos += 1,
+            Some(Token::Word(w)) => return Err(QueryError::ReadOnly(w.to_uppercase())),
+            _ => return Err(QueryError::Syntax("expected SELECT".into())),
+        }
+
+        let projection = if self.eat_symbol("*") {
+            Projection::All
+        } else if self.eat_keyword("COUNT") {
+            self.expect_symbol("(")?;
+            self.expect_symbol("*")?;
+            self.expect_symbol(")")?;
+            Projection::Count
+        } else {
+            let mut columns = vec![self.identifier()?];
+            while self.eat_symbol(",") {
+                columns.push(self.identifier()?);
+            }
+            Projection::Columns(columns)
+        };
+
+        self.expect_keyword("FROM")?;
+        let table = self.identifier()?;
+
+        let mut filter = Vec::new();
+        if self.eat_keyword("WHERE") {
+            let mut group = vec![self.condition()?];
+            loop {
+                if self.eat_keyword("AND") {
+                    group.push(self.condition()?);
+                } else if self.eat_keyword("OR") {
+                    filter.push(std::mem::take(&mut group));
+                    group.push(self.condition()?);
+                } else {
+                    break;
+                }
+            }
+            filter.push(group);
+        }
+
+        let mut order_by = None;
+        if self.eat_keyword("ORDER") {
+            self.expect_keyword("BY")?;
+            let column = self.identifier()?;
+            let descending = if self.eat_keyword("DESC") {
+                true
+            } else {
+                self.eat_keyword("ASC");
+                false
+            };
+            order_by = Some((column, descending));
+        }
+
+        let mut limit = None;
+        if self.eat_keyword("LIMIT") {
+            limit = match self.next() {
+                Some(Token::Number(n)) => n.parse().ok(),
+                _ => None,
+            };
+            if limit.is_none() {
+                return Err(QueryError::Syntax("LIMIT expects a count".into()));
+            }
+        }
+
+        self.eat_symbol(";");
+        if let Some(token) = self.peek() {
+            return Err(QueryError::Syntax(format!("unexpected {:?}", token)));
+        }
+        Ok(Select {
+            projection,
+            table,
+            filter,
+            order_by,
+            limit,
+        })
+    }
+
+    fn condition(&mut self) -> Result<Condition, QueryError> {
+        let column = self.identifier()?;
+        if self.eat_keyword("IS") {
+            let op = if self.eat_keyword("NOT") {
+                CompareOp::IsNotNull
+            } else {
+                CompareOp::IsNull
+            };
+            self.expect_keyword("NULL")?;
+            return Ok(Condition {
+                column,
+                op,
+                value: Value::Null,
+            });
+        }
+
+        let op = match self.next() {
+            Some(Token::Symbol("=")) => CompareOp::Eq,
+            Some(Token::Symbol("!=")) | Some(Token::Symbol("<>")) => CompareOp::Ne,
+            Some(Token::Symbol("<")) => CompareOp::Lt,
+            Some(Token::Symbol("<=")) => CompareOp::Le,
+            Some(Token::Symbol(">")) => CompareOp::Gt,
+            Some(Token::Symbol(">=")) => CompareOp::Ge,
+            Some(Token::Word(w)) if w.eq_ignore_ascii_case("LIKE") => CompareOp::Like,
+            _ => return Err(QueryError::Syntax("expected a comparison".into())),
+        };
+        let value = match self.next() {
+            Some(Token::Text(text)) => Value::Text(text),
+            Some(Token::Number(n)) => match n.parse::<i64>() {
+                Ok(i) => Value::Int(i),
+                Err(_) => n
+                    .parse::<f64>()
+                    .map(Value::Float)
+                    .map_err(|_| QueryError::Syntax(format!("invalid number '{}'", n)))?,
+            },
+            Some(Token::Word(w)) if w.eq_ignore_ascii_case("TRUE") => Value::Bool(true),
+            Some(Token::Word(w)) if w.eq_ignore_ascii_case("FALSE") => Value::Bool(false),
+            Some(Token::Word(w)) if w.eq_ignore_ascii_case("NULL") => Value::Null,
+            _ => return Err(QueryError::Syntax("expected a literal".into())),
+        };
+        Ok(Condition { column, op, value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::component::id::ComponentId;
+    use crate::core::management::event::HostEvent;
+
+    fn view() -> StateView {
+        let cache = ComponentId::new("acme", "cache", "v1");
+        let users = ComponentId::new("acme", "users", "v1");
+        let mut state = HostState::new();
+        for event in [
+            HostEvent::ComponentInstalled {
+                component: cache.clone(),
+                source: "registry://acme/cache".to_string(),
+            },
+            HostEvent::CapabilityGranted {
+                component: cache.clone(),
+                capability: "storage:write:cache/*".to_string(),
+            },
+            HostEvent::CapabilityGranted {
+                component: cache.clone(),
+                capability: "messaging:send:*".to_string(),
+            },
+            HostEvent::ComponentSpawned {
+                component: users.clone(),
+            },
+        ] {
+            state.apply(&event);
+        }
+
+        let sample = |component: ComponentId, queue_depth, restarts| ComponentSample {
+            component,
+            fuel_consumed: 1000,
+            memory_bytes: 2048,
+            messages_handled: 10,
+            queue_depth,
+            restarts,
+        };
+        StateView::new()
+            .with_host_state(&state)
+            .with_in_flight(vec![
+                ("req-1".to_string(), Duration::from_millis(1500)),
+                ("req-2".to_string(), Duration::from_millis(20)),
+            ])
+            .with_metrics(vec![sample(cache, 40, 2), sample(users, 3, 0)])
+    }
+
+    #[test]
+    fn test_select_filters_orders_and_projects() {
+        let view = view();
+
+        let result = view
+            .query("select component, queue_depth from metrics where queue_depth > 5 or restarts >= 1 order by queue_depth desc limit 1;")
+            .unwrap();
+        assert_eq!(result.columns, vec!["component", "queue_depth"]);
+        assert_eq!(
+            result.rows,
+            vec![vec![Value::Text("acme/cache/v1".into()), Value::Int(40)]]
+        );
+
+        let result = view
+            .query("SELECT capability FROM capabilities WHERE component = 'acme/cache/v1' AND capability LIKE 'storage:%'")
+            .unwrap();
+        assert_eq!(
+            result.rows,
+            vec![vec![Value::Text("storage:write:cache/*".into())]]
+        );
+
+        let result = view
+            .query(
+                "SELECT COUNT(*) FROM components WHERE running = TRUE AND installed_from IS NULL",
+            )
+            .unwrap();
+        assert_eq!(result.rows, vec![vec![Value::Int(1)]]);
+
+        let result = view
+            .query("SELECT * FROM messages_in_flight WHERE remaining_ms < 100")
+            .unwrap();
+        assert_eq!(result.columns, vec!["correlation_id", "remaining_ms"]);
+        assert_eq!(result.rows.len(), 1);
+        assert!(result.to_string().ends_with("(1 row)"));
+    }
+
+    #[test]
+    fn test_like_wildcards() {
+        assert!(like("storage:write:cache/*", "storage:%"));
+        assert!(like("abc", "a_c"));
+        assert!(like("abc", "%"));
+        assert!(like("", "%%"));
+        assert!(like("aXbYc", "a%b%c"));
+        assert!(like("mississippi", "%iss%ppi"));
+        assert!(!like("abc", "a_"));
+        assert!(!like("abc", "%d%"));
+        assert!(!like("ab", "a%bc"));
+
+        // Many `%` against a long non-matching text stays fast
+        let text = "a".repeat(10_000);
+        let pattern = format!("{}b", "%a".repeat(50));
+        assert!(!like(&text, &pattern));
+    }
+
+    #[test]
+    fn test_rejects_writes_and_unknown_names() {
+        let view = view();
+        assert_eq!(
+            view.query("DELETE FROM components"),
+            Err(QueryError::ReadOnly("DELETE".into()))
+        );
+        assert_eq!(
+            view.query("SELECT * FROM secrets"),
+            Err(QueryError::UnknownTable("secrets".into()))
+        );
+        assert!(matches!(
+            view.query("SELECT password FROM components"),
+            Err(QueryError::UnknownColumn { .. })
+        ));
+        assert!(matches!(
+            view.query("SELECT * FROM components; DROP TABLE components"),
+            Err(QueryError::Syntax(_))
+        ));
+        assert!(matches!(
+            view.query("SELECT * FROM components WHERE component = 'open"),
+            Err(QueryError::Syntax(_))
+        ));
+    }
+}
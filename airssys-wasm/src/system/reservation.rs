@@ -0,0 +1,444 @@
+//! Host resource reservation for system-critical components.
+//!
+//! Components marked `critical = true` in `Component.toml` get host headroom
+//! reserved for them: memory, one instance slot, and their fuel budget. The
+//! [`AdmissionController`] then refuses to admit lower-priority components
+//! whenever doing so would leave a critical component without the resources
+//! it reserved, so essential plugins can always (re)start under load.
+//!
+//! # Accounting Model
+//!
+//! - **Capacity**: totals declared in the host's `[capacity]` section.
+//! - **In use**: demand of every admitted component.
+//! - **Outstanding reservations**: demand of critical components that hold a
+//!   reservation but are not currently admitted.
+//!
+//! A component is admitted only if `in use + outstanding + demand <= capacity`
+//! for every resource, where a critical component's own reservation is not
+//! counted against itself.
+
+// Layer 1: Standard library imports
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::RwLock;
+
+// Layer 2: Third-party crate imports
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::config::component::ComponentConfig;
+
+// ============================================================================
+// ReservationError
+// ============================================================================
+
+/// Host resource kinds tracked by the admission controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HostResource {
+    /// Linear memory, in bytes.
+    Memory,
+    /// Component instance slots.
+    InstanceSlots,
+    /// Fuel (CPU budget).
+    Fuel,
+}
+
+impl fmt::Display for HostResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HostResource::Memory => "memory",
+            HostResource::InstanceSlots => "instance slots",
+            HostResource::Fuel => "fuel",
+        })
+    }
+}
+
+/// Errors raised by reservation and admission.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum ReservationError {
+    /// Admitting the component would violate capacity or a reservation.
+    #[error(
+        "Insufficient {resource} for {component}: requested {requested}, available {available}"
+    )]
+    InsufficientCapacity {
+        /// Component that was refused.
+        component: String,
+        /// Resource that ran out.
+        resource: HostResource,
+        /// Amount requested.
+        requested: u64,
+        /// Amount available after honoring reservations.
+        available: u64,
+    },
+
+    /// Only components marked critical may hold reservations.
+    #[error("Component is not marked critical: {0}")]
+    NotCritical(String),
+
+    /// The component is already admitted.
+    #[error("Component already admitted: {0}")]
+    AlreadyAdmitted(String),
+
+    /// Internal lock was poisoned.
+    #[error("Reservation lock poisoned: {0}")]
+    LockPoisoned(String),
+}
+
+// ============================================================================
+// Capacity and Demand
+// ============================================================================
+
+/// Amount of each host resource, used both for capacity and per-component demand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResourceAmounts {
+    /// Memory in bytes.
+    pub memory_bytes: u64,
+    /// Instance slots.
+    pub instance_slots: u64,
+    /// Fuel units.
+    pub fuel: u64,
+}
+
+impl ResourceAmounts {
+    /// Creates an amount from its parts.
+    pub fn new(memory_bytes: u64, instance_slots: u64, fuel: u64) -> Self {
+        Self {
+            memory_bytes,
+            instance_slots,
+            fuel,
+        }
+    }
+
+    /// Demand of a component: its memory limit, one slot, and its fuel limit.
+    pub fn demand_of(config: &ComponentConfig) -> Self {
+        Self {
+            memory_bytes: config.max_memory_bytes(),
+            instance_slots: 1,
+            fuel: config.max_fuel().unwrap_or(0),
+        }
+    }
+
+    fn saturating_add(self, other: Self) -> Self {
+        Self {
+            memory_bytes: self.memory_bytes.saturating_add(other.memory_bytes),
+            instance_slots: self.instance_slots.saturating_add(other.instance_slots),
+            fuel: self.fuel.saturating_add(other.fuel),
+        }
+    }
+
+    fn saturating_sub(self, other: Self) -> Self {
+        Self {
+            memory_bytes: self.memory_bytes.saturating_sub(other.memory_bytes),
+            instance_slots: self.instance_slots.saturating_sub(other.instance_slots),
+            fuel: self.fuel.saturating_sub(other.fuel),
+        }
+    }
+
+    fn get(&self, resource: HostResource) -> u64 {
+        match resource {
+            HostResource::Memory => self.memory_bytes,
+            HostResource::InstanceSlots => self.instance_slots,
+            HostResource::Fuel => self.fuel,
+        }
+    }
+}
+
+/// Host capacity. A resource with capacity 0 is not metered.
+pub type HostCapacity = ResourceAmounts;
+
+// ============================================================================
+// AdmissionController
+// ============================================================================
+
+#[derive(Debug, Default)]
+struct Ledger {
+    reservations: HashMap<ComponentId, ResourceAmounts>,
+    admitted: HashMap<ComponentId, ResourceAmounts>,
+}
+
+impl Ledger {
+    fn in_use(&self) -> ResourceAmounts {
+        self.admitted
+            .values()
+            .fold(ResourceAmounts::default(), |acc, d| acc.saturating_add(*d))
+    }
+
+    /// Reservations of critical components not currently admitted, excluding `except`.
+    fn outstanding(&self, except: &ComponentId) -> ResourceAmounts {
+        self.reservations
+            .iter()
+            .filter(|(id, _)| *id != except && !self.admitted.contains_key(*id))
+            .fold(ResourceAmounts::default(), |acc, (_, d)| {
+                acc.saturating_add(*d)
+            })
+    }
+}
+
+/// Tracks host capacity, critical reservations, and admitted components.
+///
+/// # Thread Safety
+///
+/// Internal state is guarded by `RwLock`; all methods take `&self`.
+pub struct AdmissionController {
+    capacity: HostCapacity,
+    ledger: RwLock<Ledger>,
+}
+
+impl fmt::Debug for AdmissionController {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AdmissionController")
+            .field("capacity", &self.capacity)
+            .field("in_use", &self.in_use().ok())
+            .finish()
+    }
+}
+
+impl AdmissionController {
+    /// Creates a controller for the given host capacity.
+    pub fn new(capacity: HostCapacity) -> Self {
+        Self {
+            capacity,
+            ledger: RwLock::new(Ledger::default()),
+        }
+    }
+
+    /// Returns the host capacity.
+    pub fn capacity(&self) -> HostCapacity {
+        self.capacity
+    }
+
+    /// Reserves headroom for a critical component.
+    ///
+    /// Call this when the host reads a component's manifest, before anything
+    /// is started, so later non-critical admissions respect the reservation.
+    /// Re-reserving replaces the previous reservation.
+    ///
+    /// # Errors
+    ///
+    /// - `ReservationError::NotCritical` if the config is not marked critical
+    /// - `ReservationError::InsufficientCapacity` if the reservation does not fit
+    pub fn reserve(&self, config: &ComponentConfig) -> Result<(), ReservationError> {
+        if !config.is_critical() {
+            return Err(ReservationError::NotCritical(config.id().to_string_id()));
+        }
+
+        let demand = ResourceAmounts::demand_of(config);
+        let mut ledger = self.write()?;
+        // A running critical component's own usage is not counted against it
+        let own = ledger
+            .admitted
+            .get(config.id())
+            .copied()
+            .unwrap_or_default();
+        let in_use = ledger.in_use().saturating_sub(own);
+        self.check_fit(config.id(), demand, in_use, ledger.outstanding(config.id()))?;
+        ledger.reservations.insert(config.id().clone(), demand);
+        Ok(())
+    }
+
+    /// Drops a component's reservation.
+    pub fn release_reservation(&self, id: &ComponentId) -> Result<(), ReservationError> {
+        self.write()?.reservations.remove(id);
+        Ok(())
+    }
+
+    /// Admits a component for starting.
+    ///
+    /// Critical components without a reservation are reserved on admission.
+    ///
+    /// # Errors
+    ///
+    /// - `ReservationError::AlreadyAdmitted` if the component is running
+    /// - `ReservationError::InsufficientCapacity` if admission would violate
+    ///   capacity or another component's reservation
+    pub fn admit(&self, config: &ComponentConfig) -> Result<(), ReservationError> {
+        let id = config.id();
+        let demand = ResourceAmounts::demand_of(config);
+        let mut ledger = self.write()?;
+
+        if ledger.admitted.contains_key(id) {
+            return Err(ReservationError::AlreadyAdmitted(id.to_string_id()));
+        }
+
+        self.check_fit(id, demand, ledger.in_use(), ledger.outstanding(id))?;
+
+        if config.is_critical() {
+            ledger.reservations.insert(id.clone(), demand);
+        }
+        ledger.admitted.insert(id.clone(), demand);
+        Ok(())
+    }
+
+    /// Releases an admitted component's resources.
+    ///
+    /// Critical components keep their reservation so they can restart.
+    pub fn release(&self, id: &ComponentId) -> Result<(), ReservationError> {
+        self.write()?.admitted.remove(id);
+        Ok(())
+    }
+
+    /// Returns the resources used by admitted components.
+    pub fn in_use(&self) -> Result<ResourceAmounts, ReservationError> {
+        Ok(self.read()?.in_use())
+    }
+
+    /// Returns the resources held for critical components that are not running.
+    pub fn outstanding_reservations(&self) -> Result<ResourceAmounts, ReservationError> {
+        let ledger = self.read()?;
+        Ok(ledger
+            .reservations
+            .iter()
+            .filter(|(id, _)| !ledger.admitted.contains_key(*id))
+            .fold(ResourceAmounts::default(), |acc, (_, d)| {
+                acc.saturating_add(*d)
+            }))
+    }
+
+    /// Returns true if the component holds a reservation.
+    pub fn is_reserved(&self, id: &ComponentId) -> Result<bool, ReservationError> {
+        Ok(self.read()?.reservations.contains_key(id))
+    }
+
+    /// Returns true if the component is admitted.
+    pub fn is_admitted(&self, id: &ComponentId) -> Result<bool, ReservationError> {
+        Ok(self.read()?.admitted.contains_key(id))
+    }
+
+    fn check_fit(
+        &self,
+        id: &ComponentId,
+        demand: ResourceAmounts,
+        in_use: ResourceAmounts,
+        outstanding: ResourceAmounts,
+    ) -> Result<(), ReservationError> {
+        for resource in [
+            HostResource::Memory,
+            HostResource::InstanceSlots,
+            HostResource::Fuel,
+        ] {
+            let capacity = self.capacity.get(resource);
+            if capacity == 0 {
+                continue; // Not metered
+            }
+            let available = capacity
+                .saturating_sub(in_use.get(resource))
+                .saturating_sub(outstanding.get(resource));
+            if demand.get(resource) > available {
+                return Err(ReservationError::InsufficientCapacity {
+                    component: id.to_string_id(),
+                    resource,
+                    requested: demand.get(resource),
+                    available,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn read(&self) -> Result<std::sync::RwLockReadGuard<'_, Ledger>, ReservationError> {
+        self.ledger
+            .read()
+            .map_err(|e| ReservationError::LockPoisoned(e.to_string()))
+    }
+
+    fn write(&self) -> Result<std::sync::RwLockWriteGuard<'_, Ledger>, ReservationError> {
+        self.ledger
+            .write()
+            .map_err(|e| ReservationError::LockPoisoned(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIB: u64 = 1024 * 1024;
+
+    fn config(name: &str, memory_mib: u64, critical: bool) -> ComponentConfig {
+        ComponentConfig::new(ComponentId::new("test", name, "0"))
+            .with_max_memory(memory_mib * MIB)
+            .with_critical(critical)
+    }
+
+    #[test]
+    fn test_reservation_blocks_lower_priority_admission() {
+        let controller = AdmissionController::new(ResourceAmounts::new(100 * MIB, 10, 0));
+        controller.reserve(&config("db", 60, true)).unwrap();
+
+        assert!(controller.admit(&config("cache", 40, false)).is_ok());
+        let err = controller.admit(&config("batch", 10, false)).unwrap_err();
+        assert!(matches!(
+            err,
+            ReservationError::InsufficientCapacity {
+                resource: HostResource::Memory,
+                available: 0,
+                ..
+            }
+        ));
+
+        // The critical component still starts within its reservation
+        assert!(controller.admit(&config("db", 60, true)).is_ok());
+    }
+
+    #[test]
+    fn test_release_keeps_critical_reservation() {
+        let controller = AdmissionController::new(ResourceAmounts::new(100 * MIB, 10, 0));
+        let db = config("db", 60, true);
+        controller.admit(&db).unwrap();
+        controller.release(db.id()).unwrap();
+
+        assert!(controller.is_reserved(db.id()).unwrap());
+        assert!(!controller.is_admitted(db.id()).unwrap());
+        assert_eq!(
+            controller.outstanding_reservations().unwrap().memory_bytes,
+            60 * MIB
+        );
+        assert!(controller.admit(&config("other", 50, false)).is_err());
+
+        controller.release_reservation(db.id()).unwrap();
+        assert!(controller.admit(&config("other", 50, false)).is_ok());
+    }
+
+    #[test]
+    fn test_instance_slots_reserved() {
+        let controller = AdmissionController::new(ResourceAmounts::new(0, 2, 0));
+        controller.reserve(&config("db", 1, true)).unwrap();
+        controller.admit(&config("a", 1, false)).unwrap();
+
+        let err = controller.admit(&config("b", 1, false)).unwrap_err();
+        assert!(matches!(
+            err,
+            ReservationError::InsufficientCapacity {
+                resource: HostResource::InstanceSlots,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_reserve_rejects_non_critical_and_oversized() {
+        let controller = AdmissionController::new(ResourceAmounts::new(100 * MIB, 10, 0));
+        assert!(matches!(
+            controller.reserve(&config("a", 10, false)),
+            Err(ReservationError::NotCritical(_))
+        ));
+
+        controller.admit(&config("busy", 80, false)).unwrap();
+        assert!(matches!(
+            controller.reserve(&config("db", 30, true)),
+            Err(ReservationError::InsufficientCapacity { .. })
+        ));
+    }
+
+    #[test]
+    fn test_double_admit_rejected() {
+        let controller = AdmissionController::new(HostCapacity::default());
+        let a = config("a", 1, false);
+        controller.admit(&a).unwrap();
+        assert!(matches!(
+            controller.admit(&a),
+            Err(ReservationError::AlreadyAdmitted(_))
+        ));
+    }
+}
@@ -0,0 +1,902 @@
+//! Sagas: multi-component workflows with compensation.
+//!
+//! A [`SagaDefinition`] lists the steps of a workflow that spans several
+//! components. The [`SagaCoordinator`] runs the steps one at a time: it sends
+//! a step's action message to its component and waits for the host to report
+//! the outcome with [`SagaCoordinator::complete_step`] before moving on.
+//!
+//! If a step fails, or does not answer within its timeout, the saga turns
+//! around: every step that already succeeded and declares a compensation
+//! message gets it, most recent first, and each compensation is awaited like
+//! a step. A saga ends in one of three states:
+//!
+//! - [`SagaStatus::Completed`]: every step succeeded
+//! - [`SagaStatus::Compensated`]: a step failed and every compensation ran
+//! - [`SagaStatus::Failed`]: a compensation failed or timed out; the saga is
+//!   kept until an operator [`forget`](SagaCoordinator::forget)s it
+//!
+//! # Correlation
+//!
+//! Every message of a saga, actions and compensations alike, is sent with
+//! the saga ID as its correlation ID, so components and logs can tie them
+//! together. Replies are matched on the saga ID and the replying component,
+//! so a late answer from an earlier step is not mistaken for the current one.
+//!
+//! # Timeouts
+//!
+//! Each step has its own timeout (default [`DEFAULT_STEP_TIMEOUT`]). The host
+//! calls [`SagaCoordinator::expire_overdue`] periodically; a step whose
+//! deadline passed is treated as failed.
+//!
+//! # Persistence
+//!
+//! Saga state is written to a [`ComponentStorage`] backend as JSON under
+//! `{key_prefix}/{saga id}` before each message is sent, and deleted once the
+//! saga completes or is compensated. After a restart,
+//! [`SagaCoordinator::recover`] reloads the sagas still in flight; their
+//! deadlines are wall-clock times, so overdue steps expire on the next tick.
+
+// Layer 1: Standard library imports
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+// Layer 2: Third-party crate imports
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::component::message::MessagePayload;
+use crate::core::messaging::correlation::CorrelationId;
+use crate::core::messaging::errors::MessagingError;
+use crate::core::messaging::traits::MessageSender;
+use crate::core::storage::errors::StorageError;
+use crate::core::storage::traits::ComponentStorage;
+use crate::core::storage::value::StorageValue;
+
+/// Time a step may take when its definition does not say.
+pub const DEFAULT_STEP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Storage key prefix used unless overridden.
+pub const DEFAULT_KEY_PREFIX: &str = "saga";
+
+/// Errors raised by the saga coordinator.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum SagaError {
+    /// The definition has no steps or repeats a step name.
+    #[error("Invalid saga definition: {0}")]
+    InvalidDefinition(String),
+
+    /// No saga with this ID is known.
+    #[error("Unknown saga: {0}")]
+    UnknownSaga(String),
+
+    /// The reply does not match the step the saga is waiting on.
+    #[error("Unexpected reply for saga {saga}: {reason}")]
+    UnexpectedReply {
+        /// Saga the reply was addressed to.
+        saga: String,
+        /// Why it was rejected.
+        reason: String,
+    },
+
+    /// A stored saga could not be decoded.
+    #[error("Corrupt saga record {key}: {reason}")]
+    Corrupt {
+        /// Storage key of the record.
+        key: String,
+        /// Decoding error.
+        reason: String,
+    },
+
+    /// Saga state could not be persisted or loaded.
+    #[error("Saga storage error: {0}")]
+    Storage(#[from] StorageError),
+}
+
+/// One step of a saga.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SagaStep {
+    /// Name of the step, unique within its saga.
+    pub name: String,
+    /// Component that performs the step.
+    pub target: ComponentId,
+    /// Message asking the component to perform the step.
+    pub action: MessagePayload,
+    /// Message asking the component to undo the step, if it can be undone.
+    pub compensation: Option<MessagePayload>,
+    /// How long the component may take to report the outcome.
+    pub timeout: Duration,
+}
+
+impl SagaStep {
+    /// A step without compensation and with the default timeout.
+    pub fn new(name: impl Into<String>, target: ComponentId, action: MessagePayload) -> Self {
+        Self {
+            name: name.into(),
+            target,
+            action,
+            compensation: None,
+            timeout: DEFAULT_STEP_TIMEOUT,
+        }
+    }
+
+    /// Sends `compensation` to undo this step if a later one fails.
+    pub fn with_compensation(mut self, compensation: MessagePayload) -> Self {
+        self.compensation = Some(compensation);
+        self
+    }
+
+    /// Fails the step (or its compensation) if it takes longer than `timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// A multi-step workflow across components.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::core::component::message::MessagePayload;
+/// use airssys_wasm::system::saga::{SagaDefinition, SagaStep};
+///
+/// let order = SagaDefinition::new("place-order")
+///     .with_step(
+///         SagaStep::new(
+///             "reserve",
+///             ComponentId::new("shop", "inventory", "0"),
+///             MessagePayload::new(b"reserve".to_vec()),
+///         )
+///         .with_compensation(MessagePayload::new(b"release".to_vec())),
+///     )
+///     .with_step(
+///         SagaStep::new(
+///             "charge",
+///             ComponentId::new("shop", "billing", "0"),
+///             MessagePayload::new(b"charge".to_vec()),
+///         )
+///         .with_timeout(Duration::from_secs(5)),
+///     );
+/// assert!(order.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SagaDefinition {
+    /// Name of the workflow.
+    pub name: String,
+    /// Steps in execution order.
+    pub steps: Vec<SagaStep>,
+}
+
+impl SagaDefinition {
+    /// A definition without steps.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Appends `step`.
+    pub fn with_step(mut self, step: SagaStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Checks that the saga has steps and that their names are unique.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SagaError::InvalidDefinition` describing the problem.
+    pub fn validate(&self) -> Result<(), SagaError> {
+        if self.steps.is_empty() {
+            return Err(SagaError::InvalidDefinition(format!(
+                "saga '{}' has no steps",
+                self.name
+            )));
+        }
+        let mut names = HashSet::new();
+        for step in &self.steps {
+            if !names.insert(step.name.as_str()) {
+                return Err(SagaError::InvalidDefinition(format!(
+                    "saga '{}' has two steps named '{}'",
+                    self.name, step.name
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Where a saga is in its lifecycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SagaStatus {
+    /// Running steps forward.
+    Running,
+    /// A step failed; compensating the steps that succeeded.
+    Compensating,
+    /// Every step succeeded.
+    Completed,
+    /// A step failed and every compensation succeeded.
+    Compensated,
+    /// A compensation failed; needs an operator.
+    Failed,
+}
+
+impl SagaStatus {
+    /// Whether the saga will send no more messages.
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Completed | Self::Compensated | Self::Failed)
+    }
+}
+
+/// Outcome of a step, as reported by the host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepOutcome {
+    /// The component performed the step.
+    Succeeded,
+    /// The component could not perform the step.
+    Failed(String),
+}
+
+/// The persisted state of one saga.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SagaRecord {
+    /// Saga ID, also the correlation ID of its messages.
+    pub id: String,
+    /// The workflow being run.
+    pub definition: SagaDefinition,
+    /// Lifecycle state.
+    pub status: SagaStatus,
+    /// Index of the step whose action (or compensation) is awaited.
+    pub current: usize,
+    /// When the awaited step times out (ms since Unix epoch).
+    pub deadline_ms: Option<u64>,
+    /// Why the saga started compensating or failed.
+    pub failure: Option<String>,
+    /// When the saga started (ms since Unix epoch).
+    pub started_at_ms: u64,
+}
+
+impl SagaRecord {
+    /// The step whose action (or compensation) is awaited.
+    pub fn awaited_step(&self) -> Option<&SagaStep> {
+        self.deadline_ms?;
+        self.definition.steps.get(self.current)
+    }
+
+    /// The next step below `current` that can be compensated.
+    fn next_compensation(&self) -> Option<usize> {
+        (0..self.current).rev().find(|&index| {
+            self.definition
+                .steps
+                .get(index)
+                .is_some_and(|step| step.compensation.is_some())
+        })
+    }
+
+    fn fail(&mut self, reason: String) {
+        self.status = SagaStatus::Failed;
+        self.deadline_ms = None;
+        self.failure = Some(reason);
+    }
+}
+
+/// Runs sagas and keeps their state.
+///
+/// # Thread Safety
+///
+/// Saga state is guarded by a `Mutex` that is never held across a send.
+/// Each saga waits on one step at a time, so the host should report the
+/// outcomes of one saga from one task.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use airssys_wasm::core::component::message::ComponentMessage;
+/// use airssys_wasm::core::messaging::traits::MessageSender;
+/// use airssys_wasm::runtime::kv_store::{KvStore, StorageQuota};
+/// use airssys_wasm::system::saga::{SagaCoordinator, SagaDefinition, StepOutcome};
+///
+/// # async fn example(sender: &impl MessageSender, order: SagaDefinition, reply: ComponentMessage) {
+/// let store = KvStore::open("sagas.redb").unwrap();
+/// let storage = Arc::new(store.component("sagas", StorageQuota::new()).unwrap());
+/// let sagas = SagaCoordinator::new(storage);
+/// sagas.recover().unwrap();
+///
+/// let id = sagas.start(order, sender).await.unwrap();
+///
+/// // When a component replies with the saga ID as correlation ID:
+/// sagas
+///     .complete_step(&id, &reply.sender, StepOutcome::Succeeded, sender)
+///     .await
+///     .unwrap();
+///
+/// // From the host's periodic tick:
+/// sagas.expire_overdue(sender).await.unwrap();
+/// # }
+/// ```
+pub struct SagaCoordinator {
+    storage: Arc<dyn ComponentStorage>,
+    key_prefix: String,
+    sagas: Mutex<HashMap<String, SagaRecord>>,
+}
+
+impl SagaCoordinator {
+    /// A coordinator persisting saga state to `storage`.
+    pub fn new(storage: Arc<dyn ComponentStorage>) -> Self {
+        Self {
+            storage,
+            key_prefix: DEFAULT_KEY_PREFIX.to_string(),
+            sagas: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Stores saga state under `prefix` instead of [`DEFAULT_KEY_PREFIX`].
+    pub fn with_key_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.key_prefix = prefix.into();
+        self
+    }
+
+    /// Starts `definition` and sends its first step.
+    ///
+    /// Returns the saga ID. If the first step cannot be delivered the saga
+    /// has nothing to compensate and ends `Compensated` at once.
+    ///
+    /// # Errors
+    ///
+    /// - `SagaError::InvalidDefinition` if the definition is invalid
+    /// - `SagaError::Storage` if the saga state cannot be persisted
+    pub async fn start(
+        &self,
+        definition: SagaDefinition,
+        sender: &impl MessageSender,
+    ) -> Result<String, SagaError> {
+        definition.validate()?;
+        let record = SagaRecord {
+            id: CorrelationId::generate().as_str().to_string(),
+            definition,
+            status: SagaStatus::Running,
+            current: 0,
+            deadline_ms: None,
+            failure: None,
+            started_at_ms: now_ms(),
+        };
+        let id = record.id.clone();
+        self.dispatch(record, sender).await?;
+        Ok(id)
+    }
+
+    /// Reports the outcome of the step saga `id` is waiting on, and sends
+    /// the next message.
+    ///
+    /// `from` must be the component performing the awaited step. Returns the
+    /// saga's status afterwards.
+    ///
+    /// # Errors
+    ///
+    /// - `SagaError::UnknownSaga` if the saga is not known
+    /// - `SagaError::UnexpectedReply` if the saga is not waiting on `from`
+    /// - `SagaError::Storage` if the saga state cannot be persisted
+    pub async fn complete_step(
+        &self,
+        id: &str,
+        from: &ComponentId,
+        outcome: StepOutcome,
+        sender: &impl MessageSender,
+    ) -> Result<SagaStatus, SagaError> {
+        let mut record = self
+            .get(id)
+            .ok_or_else(|| SagaError::UnknownSaga(id.to_string()))?;
+        let Some(step) = record.awaited_step() else {
+            return Err(SagaError::UnexpectedReply {
+                saga: id.to_string(),
+                reason: format!("saga is {:?} and waits on no step", record.status),
+            });
+        };
+        if step.target != *from {
+            return Err(SagaError::UnexpectedReply {
+                saga: id.to_string(),
+                reason: format!(
+                    "step '{}' is performed by {}, not {}",
+                    step.name,
+                    step.target.to_string_id(),
+                    from.to_string_id()
+                ),
+            });
+        }
+
+        let name = step.name.clone();
+        record.deadline_ms = None;
+        match (record.status, outcome) {
+            (SagaStatus::Running, StepOutcome::Succeeded) => record.current += 1,
+            (SagaStatus::Running, StepOutcome::Failed(reason)) => {
+                record.status = SagaStatus::Compensating;
+                record.failure = Some(format!("step '{name}' failed: {reason}"));
+            }
+            (_, StepOutcome::Succeeded) => {}
+            (_, StepOutcome::Failed(reason)) => {
+                record.fail(format!("compensation of '{name}' failed: {reason}"));
+            }
+        }
+        self.dispatch(record, sender).await
+    }
+
+    /// Fails every awaited step whose deadline has passed.
+    ///
+    /// Returns how many steps timed out.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SagaError::Storage` if saga state cannot be persisted; sagas
+    /// after the failing one are left for the next call.
+    pub async fn expire_overdue(&self, sender: &impl MessageSender) -> Result<usize, SagaError> {
+        let now = now_ms();
+        let overdue: Vec<(String, ComponentId, Duration)> = self
+            .lock()
+            .values()
+            .filter(|record| record.deadline_ms.is_some_and(|deadline| deadline <= now))
+            .filter_map(|record| {
+                let step = record.awaited_step()?;
+                Some((record.id.clone(), step.target.clone(), step.timeout))
+            })
+            .collect();
+
+        for (id, target, timeout) in &overdue {
+            let reason = format!("timed out after {}ms", timeout.as_millis());
+            self.complete_step(id, target, StepOutcome::Failed(reason), sender)
+                .await?;
+        }
+        Ok(overdue.len())
+    }
+
+    /// Reloads the sagas persisted by an earlier run.
+    ///
+    /// Returns how many were loaded.
+    ///
+    /// # Errors
+    ///
+    /// - `SagaError::Storage` if the storage cannot be read
+    /// - `SagaError::Corrupt` if a record cannot be decoded
+    pub fn recover(&self) -> Result<usize, SagaError> {
+        let prefix = format!("{}/", self.key_prefix);
+        let mut loaded = 0;
+        for key in self.storage.list_keys(Some(&prefix))? {
+            let Some(value) = self.storage.get(&key)? else {
+                continue;
+            };
+            let record: SagaRecord =
+                serde_json::from_slice(value.as_bytes()).map_err(|e| SagaError::Corrupt {
+                    key: key.clone(),
+                    reason: e.to_string(),
+                })?;
+            self.lock().insert(record.id.clone(), record);
+            loaded += 1;
+        }
+        Ok(loaded)
+    }
+
+    /// The state of saga `id`, if it is in flight or failed.
+    pub fn get(&self, id: &str) -> Option<SagaRecord> {
+        self.lock().get(id).cloned()
+    }
+
+    /// Number of sagas still sending messages.
+    pub fn in_flight(&self) -> usize {
+        self.lock()
+            .values()
+            .filter(|record| !record.status.is_finished())
+            .count()
+    }
+
+    /// Sagas that ended `Failed` and await an operator.
+    pub fn failed(&self) -> Vec<SagaRecord> {
+        self.lock()
+            .values()
+            .filter(|record| record.status == SagaStatus::Failed)
+            .cloned()
+            .collect()
+    }
+
+    /// Drops saga `id` and its persisted state.
+    ///
+    /// Returns whether it was known.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SagaError::Storage` if the persisted state cannot be deleted.
+    pub fn forget(&self, id: &str) -> Result<bool, SagaError> {
+        self.storage.delete(&self.key_for(id))?;
+        Ok(self.lock().remove(id).is_some())
+    }
+
+    /// Sends the next message of `record`, retrying past undeliverable
+    /// steps, and stores the result.
+    async fn dispatch(
+        &self,
+        mut record: SagaRecord,
+        sender: &impl MessageSender,
+    ) -> Result<SagaStatus, SagaError> {
+        loop {
+            let (index, payload) = match record.status {
+                SagaStatus::Running => {
+                    let Some(step) = record.definition.steps.get(record.current) else {
+                        record.status = SagaStatus::Completed;
+                        break;
+                    };
+                    (record.current, step.action.clone())
+                }
+                SagaStatus::Compensating => {
+                    let next = record.next_compensation().and_then(|index| {
+                        let compensation = record.definition.steps.get(index)?.compensation.clone();
+                        compensation.map(|payload| (index, payload))
+                    });
+                    let Some(next) = next else {
+                        record.status = SagaStatus::Compensated;
+                        break;
+                    };
+                    next
+                }
+                _ => break,
+            };
+
+            // Persist before sending so a crash never loses a sent step
+            let Some(step) = record.definition.steps.get(index) else {
+                break;
+            };
+            let (target, name) = (step.target.clone(), step.name.clone());
+            let deadline = now_ms().saturating_add(duration_ms(step.timeout));
+            record.current = index;
+            record.deadline_ms = Some(deadline);
+            self.store(&record)?;
+
+            if let Err(e) = sender
+                .send_with_correlation(&target, payload, &record.id)
+                .await
+            {
+                record.deadline_ms = None;
+                if record.status == SagaStatus::Running {
+                    record.status = SagaStatus::Compensating;
+                    record.failure = Some(undeliverable(&name, &e));
+                } else {
+                    record.fail(undeliverable(&name, &e));
+                }
+                continue;
+            }
+            return Ok(record.status);
+        }
+
+        let status = record.status;
+        if status == SagaStatus::Failed {
+            self.store(&record)?;
+        } else {
+            self.storage.delete(&self.key_for(&record.id))?;
+            self.lock().remove(&record.id);
+        }
+        Ok(status)
+    }
+
+    /// Persists `record` and makes it the current state of its saga.
+    fn store(&self, record: &SagaRecord) -> Result<(), SagaError> {
+        let bytes = serde_json::to_vec(record)
+            .map_err(|e| SagaError::Storage(StorageError::IoError(e.to_string())))?;
+        self.storage
+            .set(&self.key_for(&record.id), StorageValue::new(bytes))?;
+        self.lock().insert(record.id.clone(), record.clone());
+        Ok(())
+    }
+
+    fn key_for(&self, id: &str) -> String {
+        format!("{}/{}", self.key_prefix, id)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, SagaRecord>> {
+        self.sagas.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl fmt::Debug for SagaCoordinator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SagaCoordinator")
+            .field("key_prefix", &self.key_prefix)
+            .field("sagas", &self.lock().len())
+            .finish()
+    }
+}
+
+fn undeliverable(step: &str, error: &MessagingError) -> String {
+    format!("step '{step}' could not be delivered: {error}")
+}
+
+fn duration_ms(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+fn now_ms() -> u64 {
+    u64::try_from(Utc::now().timestamp_millis()).unwrap_or(0)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    use crate::runtime::kv_store::{KvStore, StorageQuota};
+
+    #[derive(Default)]
+    struct RecordingSender {
+        sent: Mutex<Vec<(ComponentId, Vec<u8>, String)>>,
+        unreachable: Mutex<Option<ComponentId>>,
+    }
+
+    impl RecordingSender {
+        fn payloads(&self) -> Vec<Vec<u8>> {
+            let sent = self.sent.lock().unwrap();
+            sent.iter().map(|(_, payload, _)| payload.clone()).collect()
+        }
+    }
+
+    impl MessageSender for RecordingSender {
+        async fn send(
+            &self,
+            target: &ComponentId,
+            payload: MessagePayload,
+        ) -> Result<(), MessagingError> {
+            self.send_with_correlation(target, payload, "").await
+        }
+
+        async fn send_with_correlation(
+            &self,
+            target: &ComponentId,
+            payload: MessagePayload,
+            correlation_id: &str,
+        ) -> Result<(), MessagingError> {
+            if self.unreachable.lock().unwrap().as_ref() == Some(target) {
+                return Err(MessagingError::TargetNotFound(target.to_string_id()));
+            }
+            self.sent.lock().unwrap().push((
+                target.clone(),
+                payload.into_bytes(),
+                correlation_id.to_string(),
+            ));
+            Ok(())
+        }
+    }
+
+    fn component(name: &str) -> ComponentId {
+        ComponentId::new("shop", name, "0")
+    }
+
+    fn step(name: &str, compensation: Option<&str>) -> SagaStep {
+        let step = SagaStep::new(name, component(name), MessagePayload::from(name.as_bytes()));
+        match compensation {
+            Some(undo) => step.with_compensation(MessagePayload::from(undo.as_bytes())),
+            None => step,
+        }
+    }
+
+    fn order() -> SagaDefinition {
+        SagaDefinition::new("order")
+            .with_step(step("inventory", Some("release")))
+            .with_step(step("audit", None))
+            .with_step(step("billing", Some("refund")))
+            .with_step(step("shipping", Some("cancel")))
+    }
+
+    fn storage() -> Arc<dyn ComponentStorage> {
+        let store = KvStore::in_memory().unwrap();
+        Arc::new(store.component("sagas", StorageQuota::new()).unwrap())
+    }
+
+    #[test]
+    fn test_definition_validation() {
+        assert!(order().validate().is_ok());
+        assert!(SagaDefinition::new("empty").validate().is_err());
+        let twice = SagaDefinition::new("twice")
+            .with_step(step("a", None))
+            .with_step(step("a", None));
+        assert!(matches!(
+            twice.validate(),
+            Err(SagaError::InvalidDefinition(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_successful_saga_runs_every_step_with_one_correlation() {
+        let storage = storage();
+        let sagas = SagaCoordinator::new(Arc::clone(&storage));
+        let sender = RecordingSender::default();
+
+        let id = sagas.start(order(), &sender).await.unwrap();
+        assert!(storage.exists(&format!("saga/{id}")).unwrap());
+
+        for name in ["inventory", "audit", "billing"] {
+            let status = sagas
+                .complete_step(&id, &component(name), StepOutcome::Succeeded, &sender)
+                .await
+                .unwrap();
+            assert_eq!(status, SagaStatus::Running);
+        }
+        let status = sagas
+            .complete_step(&id, &component("shipping"), StepOutcome::Succeeded, &sender)
+            .await
+            .unwrap();
+
+        assert_eq!(status, SagaStatus::Completed);
+        assert_eq!(
+            sender.payloads(),
+            [&b"inventory"[..], b"audit", b"billing", b"shipping"]
+        );
+        assert!(sender
+            .sent
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|(_, _, correlation)| *correlation == id));
+        assert_eq!(sagas.in_flight(), 0);
+        assert!(!storage.exists(&format!("saga/{id}")).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_failed_step_compensates_completed_steps_in_reverse() {
+        let sagas = SagaCoordinator::new(storage());
+        let sender = RecordingSender::default();
+        let id = sagas.start(order(), &sender).await.unwrap();
+
+        for name in ["inventory", "audit", "billing"] {
+            sagas
+                .complete_step(&id, &component(name), StepOutcome::Succeeded, &sender)
+                .await
+                .unwrap();
+        }
+
+        // A reply from a step the saga is not waiting on is rejected
+        assert!(matches!(
+            sagas
+                .complete_step(&id, &component("billing"), StepOutcome::Succeeded, &sender)
+                .await,
+            Err(SagaError::UnexpectedReply { .. })
+        ));
+
+        let failure = StepOutcome::Failed("address unknown".to_string());
+        let status = sagas
+            .complete_step(&id, &component("shipping"), failure, &sender)
+            .await
+            .unwrap();
+        assert_eq!(status, SagaStatus::Compensating);
+        assert_eq!(
+            sagas.get(&id).unwrap().failure.as_deref(),
+            Some("step 'shipping' failed: address unknown")
+        );
+
+        sagas
+            .complete_step(&id, &component("billing"), StepOutcome::Succeeded, &sender)
+            .await
+            .unwrap();
+        let status = sagas
+            .complete_step(
+                &id,
+                &component("inventory"),
+                StepOutcome::Succeeded,
+                &sender,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(status, SagaStatus::Compensated);
+        assert_eq!(sender.payloads()[4..], [&b"refund"[..], b"release"]);
+        assert!(sagas.get(&id).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_timeouts_and_failed_compensation_leave_saga_for_operator() {
+        let sagas = SagaCoordinator::new(storage());
+        let sender = RecordingSender::default();
+        let definition = SagaDefinition::new("order")
+            .with_step(step("inventory", Some("release")).with_timeout(Duration::ZERO))
+            .with_step(step("billing", Some("refund")).with_timeout(Duration::ZERO));
+        let id = sagas.start(definition, &sender).await.unwrap();
+
+        sagas
+            .complete_step(
+                &id,
+                &component("inventory"),
+                StepOutcome::Succeeded,
+                &sender,
+            )
+            .await
+            .unwrap();
+        // billing times out, then so does the release of inventory
+        assert_eq!(sagas.expire_overdue(&sender).await.unwrap(), 1);
+        assert_eq!(sagas.get(&id).unwrap().status, SagaStatus::Compensating);
+        assert_eq!(sagas.expire_overdue(&sender).await.unwrap(), 1);
+
+        let failed = sagas.failed();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].status, SagaStatus::Failed);
+        assert_eq!(
+            failed[0].failure.as_deref(),
+            Some("compensation of 'inventory' failed: timed out after 0ms")
+        );
+        assert_eq!(sagas.expire_overdue(&sender).await.unwrap(), 0);
+
+        assert!(sagas.forget(&id).unwrap());
+        assert!(sagas.failed().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_undeliverable_step_starts_compensation() {
+        let sagas = SagaCoordinator::new(storage());
+        let sender = RecordingSender::default();
+        *sender.unreachable.lock().unwrap() = Some(component("billing"));
+
+        let id = sagas.start(order(), &sender).await.unwrap();
+        sagas
+            .complete_step(
+                &id,
+                &component("inventory"),
+                StepOutcome::Succeeded,
+                &sender,
+            )
+            .await
+            .unwrap();
+        let status = sagas
+            .complete_step(&id, &component("audit"), StepOutcome::Succeeded, &sender)
+            .await
+            .unwrap();
+
+        assert_eq!(status, SagaStatus::Compensating);
+        assert_eq!(
+            sagas.get(&id).unwrap().awaited_step().unwrap().name,
+            "inventory"
+        );
+        assert_eq!(sender.payloads().last().unwrap(), b"release");
+    }
+
+    #[tokio::test]
+    async fn test_in_flight_sagas_survive_restart() {
+        let storage = storage();
+        let sender = RecordingSender::default();
+        let id = {
+            let sagas = SagaCoordinator::new(Arc::clone(&storage));
+            let id = sagas.start(order(), &sender).await.unwrap();
+            sagas
+                .complete_step(
+                    &id,
+                    &component("inventory"),
+                    StepOutcome::Succeeded,
+                    &sender,
+                )
+                .await
+                .unwrap();
+            id
+        };
+
+        let sagas = SagaCoordinator::new(Arc::clone(&storage));
+        assert_eq!(sagas.recover().unwrap(), 1);
+        let record = sagas.get(&id).unwrap();
+        assert_eq!(record.status, SagaStatus::Running);
+        assert_eq!(record.awaited_step().unwrap().name, "audit");
+
+        sagas
+            .complete_step(&id, &component("audit"), StepOutcome::Succeeded, &sender)
+            .await
+            .unwrap();
+        assert_eq!(sender.payloads().last().unwrap(), b"billing");
+
+        storage
+            .set("saga/broken", StorageValue::new(b"{".to_vec()))
+            .unwrap();
+        assert!(matches!(
+            SagaCoordinator::new(storage).recover(),
+            Err(SagaError::Corrupt { .. })
+        ));
+    }
+}
@@ -0,0 +1,418 @@
+//! Scheduled and reactive scaling of warm instance pools.
+//!
+//! [`PoolScaler`] applies each managed component's
+//! [`ScalingPolicy`](crate::core::config::scaling::ScalingPolicy) to the
+//! engine's warm instance pool. The host calls [`PoolScaler::evaluate`]
+//! periodically (e.g. once a minute, or whenever queue depths are sampled
+//! for the [`top`](super::top) view); every resize is logged through
+//! `tracing` and kept as a [`ScalingEvent`] for operators.
+
+// Layer 1: Standard library imports
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+// Layer 2: Third-party crate imports
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::config::scaling::{ScalingPolicy, ScalingReason};
+use crate::core::runtime::traits::RuntimeEngine;
+
+/// Scaling events kept for operators.
+pub const MAX_SCALING_EVENTS: usize = 256;
+
+/// Errors from [`PoolScaler`].
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum ScalingError {
+    /// Internal lock was poisoned.
+    #[error("Scaling lock poisoned: {0}")]
+    LockPoisoned(String),
+}
+
+/// One resize of a component's warm pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScalingEvent {
+    /// Resized component.
+    pub component: ComponentId,
+    /// When the pool was resized.
+    pub at: DateTime<Utc>,
+    /// Previous target size.
+    pub from: usize,
+    /// New target size.
+    pub to: usize,
+    /// Rule that decided the new size.
+    pub reason: ScalingReason,
+}
+
+struct Managed {
+    bytes: Arc<[u8]>,
+    policy: ScalingPolicy,
+    current: usize,
+}
+
+/// Resizes warm pools according to per-component scaling policies.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let scaler = PoolScaler::new(Arc::clone(&engine));
+/// scaler.manage(id.clone(), bytes, ScalingPolicy::from_manifest(&toml)?.unwrap_or_default())?;
+///
+/// // Every minute
+/// for event in scaler.evaluate(Utc::now(), |id| queue_depths.get(id).copied().unwrap_or(0))? {
+///     println!("{} {} -> {} ({})", event.component, event.from, event.to, event.reason);
+/// }
+/// ```
+pub struct PoolScaler<E: RuntimeEngine> {
+    engine: Arc<E>,
+    components: Mutex<HashMap<ComponentId, Managed>>,
+    events: Mutex<VecDeque<ScalingEvent>>,
+}
+
+impl<E: RuntimeEngine> PoolScaler<E> {
+    /// Creates a scaler resizing `engine`'s pools.
+    pub fn new(engine: Arc<E>) -> Self {
+        Self {
+            engine,
+            components: Mutex::new(HashMap::new()),
+            events: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Starts scaling `id`'s pool with `policy`.
+    ///
+    /// The pool is sized on the next [`evaluate`](Self::evaluate). Managing
+    /// an already managed component replaces its bytes and policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScalingError::LockPoisoned` if the component lock is poisoned.
+    pub fn manage(
+        &self,
+        id: ComponentId,
+        bytes: impl Into<Arc<[u8]>>,
+        policy: ScalingPolicy,
+    ) -> Result<(), ScalingError> {
+        let mut components = self.components()?;
+        let current = components.get(&id).map_or(0, |managed| managed.current);
+        components.insert(
+            id,
+            Managed {
+                bytes: bytes.into(),
+                policy,
+                current,
+            },
+        );
+        Ok(())
+    }
+
+    /// Stops scaling `id` and drops its warm pool.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScalingError::LockPoisoned` if the component lock is poisoned.
+    pub fn release(&self, id: &ComponentId) -> Result<(), ScalingError> {
+        let removed = self.components()?.remove(id);
+        if let Some(managed) = removed {
+            if let Err(e) = self.engine.prewarm(id, &managed.bytes, 0) {
+                tracing::warn!(component = %id, error = %e, "failed to drop warm instance pool");
+            }
+        }
+        Ok(())
+    }
+
+    /// Current target pool size of `id`, if managed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScalingError::LockPoisoned` if the component lock is poisoned.
+    pub fn current_size(&self, id: &ComponentId) -> Result<Option<usize>, ScalingError> {
+        Ok(self.components()?.get(id).map(|managed| managed.current))
+    }
+
+    /// Applies every policy at `now`, with queue depths from `queue_depth`.
+    ///
+    /// Returns the resizes performed. A component whose pool cannot be
+    /// resized keeps its previous target and is retried next time. Pools
+    /// are resized after the component lock is released, so `manage` and
+    /// `release` are not blocked by instantiation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScalingError::LockPoisoned` if an internal lock is poisoned.
+    pub fn evaluate(
+        &self,
+        now: DateTime<Utc>,
+        queue_depth: impl Fn(&ComponentId) -> usize,
+    ) -> Result<Vec<ScalingEvent>, ScalingError> {
+        let plan: Vec<_> = self
+            .components()?
+            .iter()
+            .filter_map(|(id, managed)| {
+                let (size, reason) = managed.policy.desired_size(now, queue_depth(id));
+                (size != managed.current).then(|| {
+                    let bytes = Arc::clone(&managed.bytes);
+                    (id.clone(), bytes, managed.current, size, reason)
+                })
+            })
+            .collect();
+
+        let mut resized = Vec::new();
+        for (id, bytes, from, to, reason) in plan {
+            if let Err(e) = self.engine.prewarm(&id, &bytes, to) {
+                tracing::warn!(component = %id, size = to, error = %e, "failed to resize warm instance pool");
+                continue;
+            }
+            tracing::info!(
+                component = %id,
+                from,
+                to,
+                reason = %reason,
+                "resized warm instance pool"
+            );
+            resized.push(ScalingEvent {
+                component: id,
+                at: now,
+                from,
+                to,
+                reason,
+            });
+        }
+
+        let mut components = self.components()?;
+        for event in &resized {
+            // Skip components released or re-managed while their pool was resized
+            if let Some(managed) = components.get_mut(&event.component) {
+                if managed.current == event.from {
+                    managed.current = event.to;
+                }
+            }
+        }
+        drop(components);
+
+        let mut events = self.scaling_events()?;
+        for event in &resized {
+            if events.len() == MAX_SCALING_EVENTS {
+                events.pop_front();
+            }
+            events.push_back(event.clone());
+        }
+        Ok(resized)
+    }
+
+    /// Recent resizes, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScalingError::LockPoisoned` if the event lock is poisoned.
+    pub fn events(&self) -> Result<Vec<ScalingEvent>, ScalingError> {
+        Ok(self.scaling_events()?.iter().cloned().collect())
+    }
+
+    fn components(&self) -> Result<MutexGuard<'_, HashMap<ComponentId, Managed>>, ScalingError> {
+        self.components
+            .lock()
+            .map_err(|e| ScalingError::LockPoisoned(e.to_string()))
+    }
+
+    fn scaling_events(&self) -> Result<MutexGuard<'_, VecDeque<ScalingEvent>>, ScalingError> {
+        self.events
+            .lock()
+            .map_err(|e| ScalingError::LockPoisoned(e.to_string()))
+    }
+}
+
+impl<E: RuntimeEngine> fmt::Debug for PoolScaler<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoolScaler")
+            .field("components", &self.components().ok().map(|c| c.len()))
+            .field("events", &self.scaling_events().ok().map(|e| e.len()))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::component::handle::ComponentHandle;
+    use crate::core::component::message::{ComponentMessage, MessagePayload};
+    use crate::core::config::scaling::{QueueDepthRule, ScheduleRule};
+    use crate::core::runtime::errors::WasmError;
+    use chrono::{NaiveTime, TimeZone};
+    use std::sync::{OnceLock, Weak};
+
+    /// Records the pool size requested per component.
+    #[derive(Default)]
+    struct PoolEngine {
+        sizes: Mutex<HashMap<ComponentId, usize>>,
+    }
+
+    impl RuntimeEngine for PoolEngine {
+        fn load_component(
+            &self,
+            id: &ComponentId,
+            _bytes: &[u8],
+        ) -> Result<ComponentHandle, WasmError> {
+            Ok(ComponentHandle::new(id.clone(), 1))
+        }
+
+        fn unload_component(&self, _handle: &ComponentHandle) -> Result<(), WasmError> {
+            Ok(())
+        }
+
+        fn call_handle_message(
+            &self,
+            _handle: &ComponentHandle,
+            _msg: &ComponentMessage,
+        ) -> Result<Option<MessagePayload>, WasmError> {
+            Ok(None)
+        }
+
+        fn call_handle_callback(
+            &self,
+            _handle: &ComponentHandle,
+            _msg: &ComponentMessage,
+        ) -> Result<(), WasmError> {
+            Ok(())
+        }
+
+        fn prewarm(
+            &self,
+            id: &ComponentId,
+            _bytes: &[u8],
+            count: usize,
+        ) -> Result<usize, WasmError> {
+            self.sizes.lock().unwrap().insert(id.clone(), count);
+            Ok(count)
+        }
+    }
+
+    /// Reads the scaler back from inside `prewarm`.
+    #[derive(Default)]
+    struct ReentrantEngine {
+        scaler: OnceLock<Weak<PoolScaler<ReentrantEngine>>>,
+        observed: Mutex<Option<usize>>,
+    }
+
+    impl RuntimeEngine for ReentrantEngine {
+        fn load_component(
+            &self,
+            id: &ComponentId,
+            _bytes: &[u8],
+        ) -> Result<ComponentHandle, WasmError> {
+            Ok(ComponentHandle::new(id.clone(), 1))
+        }
+
+        fn unload_component(&self, _handle: &ComponentHandle) -> Result<(), WasmError> {
+            Ok(())
+        }
+
+        fn call_handle_message(
+            &self,
+            _handle: &ComponentHandle,
+            _msg: &ComponentMessage,
+        ) -> Result<Option<MessagePayload>, WasmError> {
+            Ok(None)
+        }
+
+        fn call_handle_callback(
+            &self,
+            _handle: &ComponentHandle,
+            _msg: &ComponentMessage,
+        ) -> Result<(), WasmError> {
+            Ok(())
+        }
+
+        fn prewarm(
+            &self,
+            id: &ComponentId,
+            _bytes: &[u8],
+            count: usize,
+        ) -> Result<usize, WasmError> {
+            let scaler = self.scaler.get().and_then(Weak::upgrade).unwrap();
+            *self.observed.lock().unwrap() = scaler.current_size(id).unwrap();
+            Ok(count)
+        }
+    }
+
+    #[test]
+    fn test_pools_resized_outside_component_lock() {
+        let engine = Arc::new(ReentrantEngine::default());
+        let scaler = Arc::new(PoolScaler::new(Arc::clone(&engine)));
+        engine.scaler.set(Arc::downgrade(&scaler)).unwrap();
+        let id = ComponentId::new("acme", "api", "v1");
+        scaler
+            .manage(id.clone(), vec![0u8], ScalingPolicy::new(3))
+            .unwrap();
+
+        let events = scaler.evaluate(Utc::now(), |_| 0).unwrap();
+        assert_eq!(events.len(), 1);
+        // The previous target was still visible while the pool was resized
+        assert_eq!(*engine.observed.lock().unwrap(), Some(0));
+        assert_eq!(scaler.current_size(&id).unwrap(), Some(3));
+    }
+
+    #[test]
+    fn test_poisoned_lock_returns_error() {
+        let scaler = Arc::new(PoolScaler::new(Arc::new(PoolEngine::default())));
+        let poisoner = Arc::clone(&scaler);
+        let _ = std::thread::spawn(move || {
+            let _guard = poisoner.components.lock().unwrap();
+            panic!("poison the component lock");
+        })
+        .join();
+
+        assert!(matches!(
+            scaler.evaluate(Utc::now(), |_| 0),
+            Err(ScalingError::LockPoisoned(_))
+        ));
+        assert!(format!("{:?}", scaler).contains("components: None"));
+    }
+
+    #[test]
+    fn test_schedule_and_queue_depth_resize_pool() {
+        let engine = Arc::new(PoolEngine::default());
+        let scaler = PoolScaler::new(Arc::clone(&engine));
+        let id = ComponentId::new("acme", "api", "v1");
+        let hours = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+        let policy = ScalingPolicy::new(2)
+            .with_schedule(ScheduleRule::new(hours(9), hours(17), 8))
+            .with_queue_rule(QueueDepthRule {
+                above: 100,
+                size: 12,
+            });
+        scaler.manage(id.clone(), vec![0u8], policy).unwrap();
+        let size = |id: &ComponentId| engine.sizes.lock().unwrap().get(id).copied();
+
+        let morning = Utc.with_ymd_and_hms(2025, 1, 8, 10, 0, 0).unwrap();
+        let events = scaler.evaluate(morning, |_| 0).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!((events[0].from, events[0].to), (0, 8));
+        assert_eq!(size(&id), Some(8));
+
+        // Unchanged size: no event
+        assert!(scaler.evaluate(morning, |_| 0).unwrap().is_empty());
+
+        let backlog = scaler.evaluate(morning, |_| 150).unwrap();
+        assert_eq!(
+            backlog[0].reason,
+            ScalingReason::QueueDepth {
+                depth: 150,
+                above: 100
+            }
+        );
+        assert_eq!(scaler.current_size(&id).unwrap(), Some(12));
+
+        let night = Utc.with_ymd_and_hms(2025, 1, 8, 22, 0, 0).unwrap();
+        scaler.evaluate(night, |_| 0).unwrap();
+        assert_eq!(size(&id), Some(2));
+        assert_eq!(scaler.events().unwrap().len(), 3);
+
+        scaler.release(&id).unwrap();
+        assert_eq!(size(&id), Some(0));
+        assert_eq!(scaler.current_size(&id).unwrap(), None);
+    }
+}
@@ -0,0 +1,514 @@
+//! Host startup self-test.
+//!
+//! Before the coordinator starts, a [`SelfTest`] runs every registered
+//! [`StartupCheck`] and collects the outcomes into one [`SelfTestReport`].
+//! Any failure aborts [`SystemCoordinator::start`](super::coordinator::SystemCoordinator::start)
+//! with the full report, so a misconfigured host fails at boot with every
+//! problem listed instead of with an obscure error on first use.
+//!
+//! # Built-in Checks
+//!
+//! - [`StorageCheck`]: the storage backend accepts a write, read, and delete
+//! - [`TrustAnchorCheck`]: signature trust anchor files exist and are non-empty
+//! - [`EngineConfigCheck`]: component fuel and time limits can be enforced
+//!   by the engine's fuel metering / epoch interruption settings
+//! - [`PortCheck`]: the ports the host will listen on are free
+
+// Layer 1: Standard library imports
+use std::fmt;
+use std::net::{SocketAddr, TcpListener};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+// Layer 2: Third-party crate imports
+// (none)
+
+// Layer 3: Internal module imports
+use crate::core::config::component::ComponentConfig;
+use crate::core::storage::traits::ComponentStorage;
+use crate::core::storage::value::StorageValue;
+
+/// Key written and removed by [`StorageCheck`].
+pub const STORAGE_PROBE_KEY: &str = "__airssys_selftest_probe";
+
+// ============================================================================
+// Outcomes and Report
+// ============================================================================
+
+/// Outcome of a single startup check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckOutcome {
+    /// The check passed.
+    Passed,
+    /// The check found a problem that must be fixed before starting.
+    Failed(String),
+    /// The check does not apply to this host.
+    Skipped(String),
+}
+
+/// A check's name paired with its outcome.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+    /// Check name (e.g. `"storage"`).
+    pub name: String,
+    /// What the check found.
+    pub outcome: CheckOutcome,
+}
+
+/// Aggregated outcome of all startup checks.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SelfTestReport {
+    /// Results in the order the checks ran.
+    pub results: Vec<CheckResult>,
+}
+
+impl SelfTestReport {
+    /// Whether no check failed.
+    pub fn is_ok(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// Results of the checks that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &CheckResult> {
+        self.results
+            .iter()
+            .filter(|r| matches!(r.outcome, CheckOutcome::Failed(_)))
+    }
+}
+
+impl fmt::Display for SelfTestReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failed = self.failures().count();
+        write!(
+            f,
+            "{} of {} startup checks failed",
+            failed,
+            self.results.len()
+        )?;
+        for result in &self.results {
+            match &result.outcome {
+                CheckOutcome::Passed => write!(f, "\n  [ok]   {}", result.name)?,
+                CheckOutcome::Failed(reason) => {
+                    write!(f, "\n  [FAIL] {}: {}", result.name, reason)?
+                }
+                CheckOutcome::Skipped(reason) => {
+                    write!(f, "\n  [skip] {}: {}", result.name, reason)?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// StartupCheck and SelfTest
+// ============================================================================
+
+/// A sanity check run before the host starts.
+///
+/// Checks report every problem they find in their outcome rather than
+/// stopping at the first one.
+pub trait StartupCheck: Send + Sync {
+    /// Short name shown in the report.
+    fn name(&self) -> &str;
+
+    /// Runs the check.
+    fn run(&self) -> CheckOutcome;
+}
+
+/// Ordered set of startup checks.
+///
+/// # Examples
+///
+/// ```rust
+/// use airssys_wasm::system::selftest::{PortCheck, SelfTest, TrustAnchorCheck};
+///
+/// let report = SelfTest::new()
+///     .with_check(TrustAnchorCheck::new(vec!["/no/such/anchor.pem".into()]))
+///     .with_check(PortCheck::new(Vec::new()))
+///     .run();
+///
+/// assert!(!report.is_ok());
+/// assert_eq!(report.failures().next().unwrap().name, "trust-anchors");
+/// ```
+#[derive(Default)]
+pub struct SelfTest {
+    checks: Vec<Box<dyn StartupCheck>>,
+}
+
+impl SelfTest {
+    /// Creates an empty self-test.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a check.
+    pub fn with_check(mut self, check: impl StartupCheck + 'static) -> Self {
+        self.checks.push(Box::new(check));
+        self
+    }
+
+    /// Number of registered checks.
+    pub fn len(&self) -> usize {
+        self.checks.len()
+    }
+
+    /// Whether no checks are registered.
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty()
+    }
+
+    /// Runs every check, in registration order.
+    pub fn run(&self) -> SelfTestReport {
+        SelfTestReport {
+            results: self
+                .checks
+                .iter()
+                .map(|check| CheckResult {
+                    name: check.name().to_string(),
+                    outcome: check.run(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Debug for SelfTest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<&str> = self.checks.iter().map(|c| c.name()).collect();
+        f.debug_struct("SelfTest").field("checks", &names).finish()
+    }
+}
+
+fn outcome_from(problems: Vec<String>) -> CheckOutcome {
+    if problems.is_empty() {
+        CheckOutcome::Passed
+    } else {
+        CheckOutcome::Failed(problems.join("; "))
+    }
+}
+
+// ============================================================================
+// Built-in Checks
+// ============================================================================
+
+/// Verifies the storage backend round-trips a probe value.
+pub struct StorageCheck {
+    storage: Arc<dyn ComponentStorage>,
+}
+
+impl StorageCheck {
+    /// Creates a check against `storage`.
+    pub fn new(storage: Arc<dyn ComponentStorage>) -> Self {
+        Self { storage }
+    }
+}
+
+impl StartupCheck for StorageCheck {
+    fn name(&self) -> &str {
+        "storage"
+    }
+
+    fn run(&self) -> CheckOutcome {
+        let probe = StorageValue::new(b"ok".to_vec());
+        if let Err(e) = self.storage.set(STORAGE_PROBE_KEY, probe.clone()) {
+            return CheckOutcome::Failed(format!("write failed: {e}"));
+        }
+        let read = self.storage.get(STORAGE_PROBE_KEY);
+        let cleanup = self.storage.delete(STORAGE_PROBE_KEY);
+        match (read, cleanup) {
+            (Err(e), _) => CheckOutcome::Failed(format!("read failed: {e}")),
+            (Ok(value), _) if value.as_ref() != Some(&probe) => {
+                CheckOutcome::Failed("read back a different value".to_string())
+            }
+            (Ok(_), Err(e)) => CheckOutcome::Failed(format!("delete failed: {e}")),
+            (Ok(_), Ok(())) => CheckOutcome::Passed,
+        }
+    }
+}
+
+/// Verifies signature trust anchor files exist and are non-empty.
+///
+/// With no anchors configured the check is skipped, unless signatures are
+/// required (see [`require_anchors`](Self::require_anchors)).
+#[derive(Debug, Clone)]
+pub struct TrustAnchorCheck {
+    anchors: Vec<PathBuf>,
+    required: bool,
+}
+
+impl TrustAnchorCheck {
+    /// Creates a check for the given anchor files.
+    pub fn new(anchors: Vec<PathBuf>) -> Self {
+        Self {
+            anchors,
+            required: false,
+        }
+    }
+
+    /// Fail when no anchors are configured (e.g. the install profile
+    /// requires signed components).
+    pub fn require_anchors(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+}
+
+impl StartupCheck for TrustAnchorCheck {
+    fn name(&self) -> &str {
+        "trust-anchors"
+    }
+
+    fn run(&self) -> CheckOutcome {
+        if self.anchors.is_empty() {
+            return if self.required {
+                CheckOutcome::Failed(
+                    "signatures are required but no trust anchors are configured".to_string(),
+                )
+            } else {
+                CheckOutcome::Skipped("no trust anchors configured".to_string())
+            };
+        }
+        let problems = self
+            .anchors
+            .iter()
+            .filter_map(|path| match std::fs::metadata(path) {
+                Ok(meta) if meta.is_file() && meta.len() > 0 => None,
+                Ok(_) => Some(format!("{} is empty or not a file", path.display())),
+                Err(e) => Some(format!("{}: {}", path.display(), e)),
+            })
+            .collect();
+        outcome_from(problems)
+    }
+}
+
+/// Verifies component limits can be enforced by the engine.
+///
+/// - A fuel limit needs fuel metering.
+/// - Without a fuel limit, the execution time limit can only interrupt
+///   guest code through epoch interruption.
+///
+/// `WasmtimeEngine` meters fuel and does not enable epoch interruption.
+#[derive(Debug, Clone)]
+pub struct EngineConfigCheck {
+    fuel_metering: bool,
+    epoch_interruption: bool,
+    components: Vec<ComponentConfig>,
+}
+
+impl EngineConfigCheck {
+    /// Creates a check for an engine with the given settings.
+    pub fn new(fuel_metering: bool, epoch_interruption: bool) -> Self {
+        Self {
+            fuel_metering,
+            epoch_interruption,
+            components: Vec::new(),
+        }
+    }
+
+    /// Components whose limits must be enforceable.
+    pub fn with_components(mut self, components: Vec<ComponentConfig>) -> Self {
+        self.components = components;
+        self
+    }
+}
+
+impl StartupCheck for EngineConfigCheck {
+    fn name(&self) -> &str {
+        "engine-config"
+    }
+
+    fn run(&self) -> CheckOutcome {
+        if !self.fuel_metering && !self.epoch_interruption {
+            return CheckOutcome::Failed(
+                "engine has neither fuel metering nor epoch interruption; runaway guests cannot be stopped"
+                    .to_string(),
+            );
+        }
+        let mut problems = Vec::new();
+        for config in &self.components {
+            let id = config.id().to_string_id();
+            if let Err(e) = config.validate() {
+                problems.push(format!("{id}: {e}"));
+                continue;
+            }
+            match config.max_fuel() {
+                Some(_) if !self.fuel_metering => problems.push(format!(
+                    "{id}: max_fuel is set but the engine does not meter fuel"
+                )),
+                None if !self.epoch_interruption => problems.push(format!(
+                    "{id}: no max_fuel and no epoch interruption; max_execution_time_ms cannot interrupt guest code"
+                )),
+                _ => {}
+            }
+        }
+        outcome_from(problems)
+    }
+}
+
+/// Verifies the ports the host will listen on can be bound.
+#[derive(Debug, Clone)]
+pub struct PortCheck {
+    addresses: Vec<SocketAddr>,
+}
+
+impl PortCheck {
+    /// Creates a check for the given listen addresses.
+    pub fn new(addresses: Vec<SocketAddr>) -> Self {
+        Self { addresses }
+    }
+}
+
+impl StartupCheck for PortCheck {
+    fn name(&self) -> &str {
+        "ports"
+    }
+
+    fn run(&self) -> CheckOutcome {
+        if self.addresses.is_empty() {
+            return CheckOutcome::Skipped("no listen addresses configured".to_string());
+        }
+        let problems = self
+            .addresses
+            .iter()
+            .filter_map(|addr| {
+                TcpListener::bind(addr)
+                    .err()
+                    .map(|e| format!("{addr}: {e}"))
+            })
+            .collect();
+        outcome_from(problems)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::component::id::ComponentId;
+    use crate::core::storage::errors::StorageError;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemoryStorage(Mutex<HashMap<String, StorageValue>>);
+
+    impl ComponentStorage for MemoryStorage {
+        fn get(&self, key: &str) -> Result<Option<StorageValue>, StorageError> {
+            Ok(self.0.lock().unwrap().get(key).cloned())
+        }
+        fn set(&self, key: &str, value: StorageValue) -> Result<(), StorageError> {
+            self.0.lock().unwrap().insert(key.to_string(), value);
+            Ok(())
+        }
+        fn delete(&self, key: &str) -> Result<(), StorageError> {
+            self.0.lock().unwrap().remove(key);
+            Ok(())
+        }
+        fn exists(&self, key: &str) -> Result<bool, StorageError> {
+            Ok(self.0.lock().unwrap().contains_key(key))
+        }
+        fn list_keys(&self, _prefix: Option<&str>) -> Result<Vec<String>, StorageError> {
+            Ok(self.0.lock().unwrap().keys().cloned().collect())
+        }
+    }
+
+    struct BrokenStorage;
+
+    impl ComponentStorage for BrokenStorage {
+        fn get(&self, _key: &str) -> Result<Option<StorageValue>, StorageError> {
+            Ok(None)
+        }
+        fn set(&self, _key: &str, _value: StorageValue) -> Result<(), StorageError> {
+            Err(StorageError::IoError("connection refused".to_string()))
+        }
+        fn delete(&self, _key: &str) -> Result<(), StorageError> {
+            Ok(())
+        }
+        fn exists(&self, _key: &str) -> Result<bool, StorageError> {
+            Ok(false)
+        }
+        fn list_keys(&self, _prefix: Option<&str>) -> Result<Vec<String>, StorageError> {
+            Ok(vec![])
+        }
+    }
+
+    #[test]
+    fn test_storage_check() {
+        let storage = Arc::new(MemoryStorage::default());
+        let check = StorageCheck::new(storage.clone());
+        assert_eq!(check.run(), CheckOutcome::Passed);
+        assert!(!storage.exists(STORAGE_PROBE_KEY).unwrap());
+
+        let broken = StorageCheck::new(Arc::new(BrokenStorage));
+        assert!(matches!(broken.run(), CheckOutcome::Failed(reason) if reason.contains("refused")));
+    }
+
+    #[test]
+    fn test_trust_anchor_check() {
+        assert!(matches!(
+            TrustAnchorCheck::new(vec![]).run(),
+            CheckOutcome::Skipped(_)
+        ));
+        assert!(matches!(
+            TrustAnchorCheck::new(vec![]).require_anchors(true).run(),
+            CheckOutcome::Failed(_)
+        ));
+
+        let anchor = std::env::temp_dir().join(format!("anchor-{}.pem", uuid::Uuid::new_v4()));
+        std::fs::write(&anchor, b"-----BEGIN PUBLIC KEY-----").unwrap();
+        let outcome = TrustAnchorCheck::new(vec![anchor.clone()]).run();
+        std::fs::remove_file(&anchor).unwrap();
+        assert_eq!(outcome, CheckOutcome::Passed);
+    }
+
+    #[test]
+    fn test_engine_config_check() {
+        let metered =
+            ComponentConfig::new(ComponentId::new("app", "metered", "v1")).with_fuel_limit(1_000);
+        let unmetered = ComponentConfig::new(ComponentId::new("app", "free", "v1"));
+
+        let fuel_only = EngineConfigCheck::new(true, false)
+            .with_components(vec![metered.clone(), unmetered.clone()]);
+        assert!(
+            matches!(fuel_only.run(), CheckOutcome::Failed(reason) if reason.contains("app/free"))
+        );
+
+        let epoch_only = EngineConfigCheck::new(false, true).with_components(vec![metered]);
+        assert!(
+            matches!(epoch_only.run(), CheckOutcome::Failed(reason) if reason.contains("meter fuel"))
+        );
+
+        let both = EngineConfigCheck::new(true, true).with_components(vec![unmetered]);
+        assert_eq!(both.run(), CheckOutcome::Passed);
+        assert!(matches!(
+            EngineConfigCheck::new(false, false).run(),
+            CheckOutcome::Failed(_)
+        ));
+    }
+
+    #[test]
+    fn test_port_check_detects_bound_port() {
+        let taken = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = taken.local_addr().unwrap();
+        assert!(matches!(
+            PortCheck::new(vec![addr]).run(),
+            CheckOutcome::Failed(_)
+        ));
+        drop(taken);
+        assert_eq!(PortCheck::new(vec![addr]).run(), CheckOutcome::Passed);
+    }
+
+    #[test]
+    fn test_report_aggregates_all_failures() {
+        let report = SelfTest::new()
+            .with_check(TrustAnchorCheck::new(vec![]).require_anchors(true))
+            .with_check(PortCheck::new(vec![]))
+            .with_check(EngineConfigCheck::new(false, false))
+            .run();
+        assert!(!report.is_ok());
+        assert_eq!(report.failures().count(), 2);
+        let text = report.to_string();
+        assert!(text.starts_with("2 of 3 startup checks failed"));
+        assert!(text.contains("[skip] ports"));
+    }
+}
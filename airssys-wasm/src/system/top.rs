@@ -0,0 +1,319 @@
+//! # Live Resource View
+//!
+//! Model behind a `top`-like operator view of running components. A front
+//! end (terminal, control endpoint) periodically collects one
+//! [`ComponentSample`] per component, feeds them to a [`TopView`], and
+//! redraws the [`TopView::frame`] in place:
+//!
+//! ```text
+//! COMPONENT                  FUEL/s    MEMORY   MSG/s  QUEUE  RESTARTS
+//! acme/cache/v1             120000.0    2.0MiB    35.0      4         1
+//! ```
+//!
+//! Samples carry cumulative counters (fuel burned, messages handled); the
+//! view turns them into per-second rates using the previous sample of the
+//! same component. Restart counts can be taken from the host event log
+//! with [`restart_counts`].
+
+// Layer 1: Standard library imports
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::time::Instant;
+
+// Layer 2: Third-party crate imports
+use serde::{Deserialize, Serialize};
+
+// Layer 3: Internal module imports
+use crate::core::component::id::ComponentId;
+use crate::core::management::errors::EventLogError;
+use crate::core::management::event::HostEvent;
+use crate::core::management::log::HostEventLog;
+
+/// ANSI sequence that moves the cursor home and clears the screen.
+pub const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+/// Point-in-time resource counters of one component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentSample {
+    /// Sampled component.
+    pub component: ComponentId,
+    /// Total fuel burned since the component was loaded.
+    pub fuel_consumed: u64,
+    /// Current linear memory size in bytes.
+    pub memory_bytes: u64,
+    /// Total messages handled since the component was loaded.
+    pub messages_handled: u64,
+    /// Messages waiting in the component's mailbox.
+    pub queue_depth: u64,
+    /// Times the component has been restarted.
+    pub restarts: u32,
+}
+
+/// One line of the view.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopRow {
+    /// Component shown on this line.
+    pub component: ComponentId,
+    /// Fuel burned per second since the previous sample (CPU proxy).
+    pub fuel_per_sec: f64,
+    /// Current linear memory size in bytes.
+    pub memory_bytes: u64,
+    /// Messages handled per second since the previous sample.
+    pub messages_per_sec: f64,
+    /// Messages waiting in the mailbox.
+    pub queue_depth: u64,
+    /// Times the component has been restarted.
+    pub restarts: u32,
+}
+
+/// Column the view is sorted by, descending.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TopSortKey {
+    /// Fuel rate.
+    #[default]
+    Fuel,
+    /// Memory size.
+    Memory,
+    /// Message rate.
+    Messages,
+    /// Mailbox depth.
+    QueueDepth,
+    /// Restart count.
+    Restarts,
+}
+
+/// Turns successive samples into rate-based rows.
+///
+/// The first sample of a component has no predecessor, so its rates are
+/// shown as zero. Components missing from a refresh are dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+/// use airssys_wasm::core::component::id::ComponentId;
+/// use airssys_wasm::system::top::{ComponentSample, TopView};
+///
+/// let component = ComponentId::new("acme", "cache", "v1");
+/// let sample = |fuel, messages| ComponentSample {
+///     component: component.clone(),
+///     fuel_consumed: fuel,
+///     memory_bytes: 65_536,
+///     messages_handled: messages,
+///     queue_depth: 0,
+///     restarts: 0,
+/// };
+///
+/// let mut view = TopView::new();
+/// let start = Instant::now();
+/// view.update(vec![sample(1_000, 10)], start);
+/// let rows = view.update(vec![sample(3_000, 30)], start + Duration::from_secs(2));
+///
+/// assert_eq!(rows[0].fuel_per_sec, 1_000.0);
+/// assert_eq!(rows[0].messages_per_sec, 10.0);
+/// ```
+#[derive(Debug, Default)]
+pub struct TopView {
+    sort: TopSortKey,
+    previous: HashMap<ComponentId, (ComponentSample, Instant)>,
+}
+
+impl TopView {
+    /// Creates a view sorted by fuel rate.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sorts rows by `key` instead.
+    pub fn with_sort(mut self, key: TopSortKey) -> Self {
+        self.sort = key;
+        self
+    }
+
+    /// Changes the sort column, e.g. on a key press.
+    pub fn set_sort(&mut self, key: TopSortKey) {
+        self.sort = key;
+    }
+
+    /// Records a refresh taken at `at` and returns the sorted rows.
+    pub fn update(&mut self, samples: Vec<ComponentSample>, at: Instant) -> Vec<TopRow> {
+        let mut previous = HashMap::with_capacity(samples.len());
+        let mut rows = Vec::with_capacity(samples.len());
+
+        for sample in samples {
+            let (fuel_per_sec, messages_per_sec) = match self.previous.get(&sample.component) {
+                Some((prev, prev_at)) => {
+                    let secs = at.saturating_duration_since(*prev_at).as_secs_f64();
+                    (
+                        rate(prev.fuel_consumed, sample.fuel_consumed, secs),
+                        rate(prev.messages_handled, sample.messages_handled, secs),
+                    )
+                }
+                None => (0.0, 0.0),
+            };
+            rows.push(TopRow {
+                component: sample.component.clone(),
+                fuel_per_sec,
+                memory_bytes: sample.memory_bytes,
+                messages_per_sec,
+                queue_depth: sample.queue_depth,
+                restarts: sample.restarts,
+            });
+            previous.insert(sample.component.clone(), (sample, at));
+        }
+
+        self.previous = previous;
+        self.sort_rows(&mut rows);
+        rows
+    }
+
+    /// Renders rows as a fixed-width table.
+    pub fn render(rows: &[TopRow]) -> String {
+        let mut out = format!(
+            "{:<24} {:>12} {:>9} {:>7} {:>6} {:>9}\n",
+            "COMPONENT", "FUEL/s", "MEMORY", "MSG/s", "QUEUE", "RESTARTS"
+        );
+        for row in rows {
+            let _ = writeln!(
+                out,
+                "{:<24} {:>12.1} {:>9} {:>7.1} {:>6} {:>9}",
+                row.component.to_string_id(),
+                row.fuel_per_sec,
+                format_bytes(row.memory_bytes),
+                row.messages_per_sec,
+                row.queue_depth,
+                row.restarts
+            );
+        }
+        out
+    }
+
+    /// Renders rows prefixed with [`CLEAR_SCREEN`] so a terminal redraws
+    /// the table in place.
+    pub fn frame(rows: &[TopRow]) -> String {
+        format!("{CLEAR_SCREEN}{}", Self::render(rows))
+    }
+
+    fn sort_rows(&self, rows: &mut [TopRow]) {
+        match self.sort {
+            TopSortKey::Fuel => rows.sort_by(|a, b| b.fuel_per_sec.total_cmp(&a.fuel_per_sec)),
+            TopSortKey::Memory => rows.sort_by_key(|r| Reverse(r.memory_bytes)),
+            TopSortKey::Messages => {
+                rows.sort_by(|a, b| b.messages_per_sec.total_cmp(&a.messages_per_sec))
+            }
+            TopSortKey::QueueDepth => rows.sort_by_key(|r| Reverse(r.queue_depth)),
+            TopSortKey::Restarts => rows.sort_by_key(|r| Reverse(r.restarts)),
+        }
+    }
+}
+
+/// Counts restarts per component from the host event log: every spawn
+/// after the first one is a restart.
+///
+/// # Errors
+///
+/// Returns `EventLogError` if the log cannot be read.
+pub fn restart_counts(log: &HostEventLog) -> Result<HashMap<ComponentId, u32>, EventLogError> {
+    let mut spawns: HashMap<ComponentId, u32> = HashMap::new();
+    for record in log.records()? {
+        if let HostEvent::ComponentSpawned { component } = record.event {
+            *spawns.entry(component).or_default() += 1;
+        }
+    }
+    Ok(spawns
+        .into_iter()
+        .map(|(component, count)| (component, count.saturating_sub(1)))
+        .collect())
+}
+
+fn rate(before: u64, after: u64, secs: f64) -> f64 {
+    if secs <= 0.0 {
+        return 0.0;
+    }
+    // Counters restart from zero when the component restarts
+    after.saturating_sub(before) as f64 / secs
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes}B")
+    } else {
+        format!("{value:.1}{}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn sample(name: &str, fuel: u64, memory: u64, queue: u64) -> ComponentSample {
+        ComponentSample {
+            component: ComponentId::new("acme", name, "v1"),
+            fuel_consumed: fuel,
+            memory_bytes: memory,
+            messages_handled: 0,
+            queue_depth: queue,
+            restarts: 0,
+        }
+    }
+
+    #[test]
+    fn test_rows_sorted_and_departed_components_dropped() {
+        let start = Instant::now();
+        let mut view = TopView::new();
+        view.update(
+            vec![
+                sample("a", 0, 10, 0),
+                sample("b", 0, 20, 0),
+                sample("c", 0, 0, 0),
+            ],
+            start,
+        );
+
+        let later = start + Duration::from_secs(1);
+        let rows = view.update(
+            vec![sample("a", 500, 10, 7), sample("b", 100, 20, 1)],
+            later,
+        );
+        let names: Vec<_> = rows.iter().map(|r| r.component.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+
+        view.set_sort(TopSortKey::Memory);
+        let rows = view.update(
+            vec![sample("a", 500, 10, 7), sample("b", 100, 20, 1)],
+            later,
+        );
+        assert_eq!(rows[0].component.name, "b");
+        // Same instant: no elapsed time, no rate
+        assert_eq!(rows[0].fuel_per_sec, 0.0);
+    }
+
+    #[test]
+    fn test_render_and_restart_counts() {
+        let rows =
+            TopView::new().update(vec![sample("cache", 0, 3 * 1024 * 1024, 2)], Instant::now());
+        let frame = TopView::frame(&rows);
+        assert!(frame.starts_with(CLEAR_SCREEN));
+        assert!(frame.contains("acme/cache/v1"));
+        assert!(frame.contains("3.0MiB"));
+
+        let log = HostEventLog::new();
+        let component = ComponentId::new("acme", "cache", "v1");
+        for _ in 0..3 {
+            log.append(HostEvent::ComponentSpawned {
+                component: component.clone(),
+            })
+            .unwrap();
+        }
+        assert_eq!(restart_counts(&log).unwrap()[&component], 2);
+    }
+}
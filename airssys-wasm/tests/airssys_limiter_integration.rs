@@ -5,6 +5,7 @@
 
 use airssys_wasm::core::component::id::ComponentId;
 use airssys_wasm::core::runtime::limits::ResourceLimits;
+use airssys_wasm::runtime::async_host::WasiState;
 use airssys_wasm::runtime::engine::HostState;
 use airssys_wasm::runtime::limiter::{apply_limits_to_store, WasmResourceLimiter};
 use wasmtime::{Config, Engine, Store, StoreLimitsBuilder};
@@ -26,6 +27,12 @@ fn test_apply_limits_sets_store_limits() {
         component_id: ComponentId::new("test", "limiter", "test"),
         message_router: None,
         store_limits: StoreLimitsBuilder::new().build(),
+        memory_bytes: 0,
+        elevation_requests: None,
+        metrics: None,
+        os_bridge: None,
+        storage: None,
+        wasi: WasiState::default(),
     };
 
     let mut store = Store::new(&engine, host_state);
@@ -55,6 +62,12 @@ fn test_apply_limits_with_no_fuel() {
         component_id: ComponentId::new("test", "nofuel", "test"),
         message_router: None,
         store_limits: StoreLimitsBuilder::new().build(),
+        memory_bytes: 0,
+        elevation_requests: None,
+        metrics: None,
+        os_bridge: None,
+        storage: None,
+        wasi: WasiState::default(),
     };
 
     let mut store = Store::new(&engine, host_state);
@@ -82,6 +95,12 @@ fn test_apply_limits_multiple_times() {
         component_id: ComponentId::new("test", "multiple", "test"),
         message_router: None,
         store_limits: StoreLimitsBuilder::new().build(),
+        memory_bytes: 0,
+        elevation_requests: None,
+        metrics: None,
+        os_bridge: None,
+        storage: None,
+        wasi: WasiState::default(),
     };
 
     let mut store = Store::new(&engine, host_state);
@@ -144,6 +163,12 @@ fn test_apply_limits_end_to_end() {
         component_id: ComponentId::new("test", "e2e", "test"),
         message_router: None,
         store_limits: StoreLimitsBuilder::new().build(),
+        memory_bytes: 0,
+        elevation_requests: None,
+        metrics: None,
+        os_bridge: None,
+        storage: None,
+        wasi: WasiState::default(),
     };
 
     let mut store = Store::new(&engine, host_state);
@@ -15,6 +15,7 @@ use airssys_wasm::core::component::id::ComponentId;
 use airssys_wasm::core::component::message::{ComponentMessage, MessageMetadata, MessagePayload};
 use airssys_wasm::core::runtime::errors::WasmError;
 use airssys_wasm::core::runtime::traits::RuntimeEngine;
+use airssys_wasm::runtime::async_host::WasiState;
 use airssys_wasm::runtime::engine::{HostState, WasmtimeEngine};
 
 /// Load a real WASM component binary from fixtures.
@@ -185,6 +186,12 @@ fn test_host_state_initialization() {
         component_id: component_id.clone(),
         message_router: None,
         store_limits: wasmtime::StoreLimitsBuilder::new().build(),
+        memory_bytes: 0,
+        elevation_requests: None,
+        metrics: None,
+        os_bridge: None,
+        storage: None,
+        wasi: WasiState::default(),
     };
 
     assert_eq!(host_state.component_id, component_id);
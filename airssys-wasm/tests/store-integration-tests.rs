@@ -13,6 +13,7 @@ use std::path::Path;
 use airssys_wasm::core::component::id::ComponentId;
 use airssys_wasm::core::component::message::{ComponentMessage, MessageMetadata, MessagePayload};
 use airssys_wasm::core::runtime::errors::WasmError;
+use airssys_wasm::runtime::async_host::WasiState;
 use airssys_wasm::runtime::engine::HostState;
 use airssys_wasm::runtime::host_functions::marker_traits::register_host_functions;
 use airssys_wasm::runtime::store::StoreManager;
@@ -67,6 +68,12 @@ fn create_initialized_store_manager(
         component_id: component_id.clone(),
         message_router: None,
         store_limits: wasmtime::StoreLimitsBuilder::new().build(),
+        memory_bytes: 0,
+        elevation_requests: None,
+        metrics: None,
+        os_bridge: None,
+        storage: None,
+        wasi: WasiState::default(),
     };
     let store = Store::new(engine, host_state);
     let mut manager = StoreManager::new(store, component);
@@ -196,6 +203,12 @@ fn test_uninitialized_store_returns_error() {
         component_id: component_id.clone(),
         message_router: None,
         store_limits: wasmtime::StoreLimitsBuilder::new().build(),
+        memory_bytes: 0,
+        elevation_requests: None,
+        metrics: None,
+        os_bridge: None,
+        storage: None,
+        wasi: WasiState::default(),
     };
     let store = Store::new(&engine, host_state);
 
@@ -260,6 +273,12 @@ fn test_store_lifecycle_create_init_dispatch() {
         component_id: component_id.clone(),
         message_router: None,
         store_limits: wasmtime::StoreLimitsBuilder::new().build(),
+        memory_bytes: 0,
+        elevation_requests: None,
+        metrics: None,
+        os_bridge: None,
+        storage: None,
+        wasi: WasiState::default(),
     };
     let store = Store::new(&engine, host_state);
 
@@ -296,6 +315,12 @@ fn test_store_manager_accessors_with_real_component() {
         component_id: component_id.clone(),
         message_router: None,
         store_limits: wasmtime::StoreLimitsBuilder::new().build(),
+        memory_bytes: 0,
+        elevation_requests: None,
+        metrics: None,
+        os_bridge: None,
+        storage: None,
+        wasi: WasiState::default(),
     };
     let store = Store::new(&engine, host_state);
 